@@ -7,7 +7,8 @@
 //! ```
 //!
 //! This overwrites every `tests/fixtures/<name>.rs` listed in
-//! `xorpl::fixture_defs::ALL_FIXTURES`.  Run it after changing the emitter
+//! `xorpl::fixture_defs::ALL_FIXTURES`, and
+//! `tests/fixtures/verifier_bundle.rs`.  Run it after changing the emitter
 //! or after adding a new fixture definition.  Commit the updated files
 //! alongside any emitter changes.
 
@@ -16,9 +17,9 @@ use rand::rngs::StdRng;
 
 use xorpl::{
     emit::{emit_rust, emit_verifier_rust},
-    expr::expr_digest,
-    fixture_defs::ALL_FIXTURES,
-    lower::lower_to_circuit,
+    expr::exprs_digest,
+    fixture_defs::{verifier_bundle, ALL_FIXTURES},
+    lower::lower_to_circuit_multi,
     mask::MaskedCircuit,
 };
 
@@ -29,11 +30,11 @@ fn main() {
         // The digest and verifier are always derived from the original
         // (pre-strong_rotate) expression so they are stable across rotations.
         let original = (def.build)();
-        let digest   = expr_digest(&original, None);
+        let digest   = exprs_digest(&original, None);
 
-        // Browser (obfuscated) fixture: use def.expr() which applies
-        // strong_rotate when structure_seed is set.
-        let circuit = lower_to_circuit(&def.expr());
+        // Browser (obfuscated) fixture: def.circuit() lowers def.exprs(),
+        // which applies strong_rotate when structure_seed is set.
+        let circuit = def.circuit();
         let mut rng = StdRng::seed_from_u64(def.seed);
         let masked  = MaskedCircuit::from_circuit(&circuit, &mut rng);
         let source  = emit_rust(&masked, &circuit, def.name, &mut rng, &digest);
@@ -44,7 +45,7 @@ fn main() {
         wrote += 1;
 
         // Verifier fixture: lower the original (canonical) expression.
-        let canonical    = lower_to_circuit(&original);
+        let canonical    = lower_to_circuit_multi(&original);
         let verify_name  = format!("{}_verify", def.name);
         let verifier     = emit_verifier_rust(&canonical, &verify_name, &digest);
         let verify_path  = format!("tests/fixtures/{}_verify.rs", def.name);
@@ -54,5 +55,11 @@ fn main() {
         wrote += 1;
     }
 
+    let bundle_path = "tests/fixtures/verifier_bundle.rs";
+    std::fs::write(bundle_path, verifier_bundle())
+        .unwrap_or_else(|e| panic!("failed to write {bundle_path}: {e}"));
+    println!("wrote {bundle_path}");
+    wrote += 1;
+
     println!("{wrote} fixture(s) written — commit the updated files");
 }
@@ -0,0 +1,369 @@
+//! `xorplc` — command-line driver for the full pipeline.
+//!
+//! ```text
+//! xorplc <command> <source.xorpl> [options]
+//! ```
+//!
+//! Reads a `.xorpl` source file (see `xorpl::dsl`) and writes artifacts named
+//! after the function into the output directory:
+//!
+//! | Command | Writes |
+//! |---------|--------|
+//! | `compile` | `<name>.rs` (browser), `<name>_verify.rs` (verifier), `<name>.circuit.json`, `<name>.manifest.json` |
+//! | `rotate-cheap` | `<name>.rs` re-masked from a saved `--circuit` (or the `--base-seed` compilation), manifest |
+//! | `rotate-strong` | `<name>.rs` with fresh structure from `--seed`, `<name>.circuit.json`, manifest |
+//! | `verifier` | `<name>_verify.rs`, manifest |
+//! | `inspect` | nothing — prints the manifest to stdout |
+//!
+//! Every command embeds the same `EXPR_DIGEST` for the same source and key,
+//! so a rotated browser artifact keeps working against an existing verifier.
+
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::rc::Rc;
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+use xorpl::circuit::{byte_inputs, Circuit, CircuitStats};
+use xorpl::dsl;
+use xorpl::expr::{exprs_digest, Expr};
+use xorpl::emit::{check_fn_name, param_identifier};
+use xorpl::lower::try_lower_to_circuit_multi;
+use xorpl::mask::MaskedCircuit;
+use xorpl::pipeline::{rotate_cheap_circuit, try_compile_verifier_with, try_compile_with, CompileOptions};
+use xorpl::serialize::{hex, json_str, unhex};
+
+const USAGE: &str = "\
+usage: xorplc <command> <source.xorpl> [options]
+
+commands:
+  compile         browser source, verifier source, saved circuit and manifest
+  rotate-cheap    new masks for a saved --circuit, or the one compiled with --base-seed
+  rotate-strong   fresh structure and masks from --seed (verifier unchanged)
+  verifier        verifier source and manifest
+  inspect         print the manifest to stdout; writes nothing
+
+options:
+  --seed N        RNG seed, decimal or 0x-hex (compile, rotate-*; optional for inspect)
+  --circuit FILE  rotate-cheap: saved circuit (.json, else binary) to re-mask
+  --base-seed N   rotate-cheap: seed of the compilation being rotated
+  --key HEX       HMAC-SHA-256 key for EXPR_DIGEST (default: plain SHA-256)
+  --name NAME     emitted function name (default: source file stem)
+  --out DIR       output directory (default: .)";
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Command {
+    Compile,
+    RotateCheap,
+    RotateStrong,
+    Verifier,
+    Inspect,
+}
+
+impl Command {
+    fn parse(s: &str) -> Option<Command> {
+        match s {
+            "compile"       => Some(Command::Compile),
+            "rotate-cheap"  => Some(Command::RotateCheap),
+            "rotate-strong" => Some(Command::RotateStrong),
+            "verifier"      => Some(Command::Verifier),
+            "inspect"       => Some(Command::Inspect),
+            _ => None,
+        }
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            Command::Compile      => "compile",
+            Command::RotateCheap  => "rotate-cheap",
+            Command::RotateStrong => "rotate-strong",
+            Command::Verifier     => "verifier",
+            Command::Inspect      => "inspect",
+        }
+    }
+}
+
+struct Args {
+    command:   Command,
+    source:    PathBuf,
+    seed:      Option<u64>,
+    base_seed: Option<u64>,
+    circuit:   Option<PathBuf>,
+    key:       Option<Vec<u8>>,
+    name:      String,
+    out:       PathBuf,
+}
+
+fn parse_u64(flag: &str, s: &str) -> Result<u64, String> {
+    let parsed = match s.strip_prefix("0x") {
+        Some(hex) => u64::from_str_radix(&hex.replace('_', ""), 16),
+        None      => s.replace('_', "").parse(),
+    };
+    parsed.map_err(|_| format!("{flag}: invalid number {s:?}"))
+}
+
+fn parse_hex(flag: &str, s: &str) -> Result<Vec<u8>, String> {
+    match unhex(s) {
+        Some(bytes) if !bytes.is_empty() => Ok(bytes),
+        _ => Err(format!("{flag}: expected a non-empty, even number of hex digits, found {s:?}")),
+    }
+}
+
+fn parse_args(argv: &[String]) -> Result<Args, String> {
+    let [command, source, rest @ ..] = argv else {
+        return Err("expected a command and a source file".to_string());
+    };
+    let command = Command::parse(command).ok_or_else(|| format!("unknown command {command:?}"))?;
+    let source  = PathBuf::from(source);
+
+    let mut args = Args {
+        command,
+        name: source.file_stem().map(|s| s.to_string_lossy().into_owned()).unwrap_or_default(),
+        source,
+        seed: None,
+        base_seed: None,
+        circuit: None,
+        key: None,
+        out: PathBuf::from("."),
+    };
+
+    let mut it = rest.iter();
+    while let Some(flag) = it.next() {
+        let value = it.next().ok_or_else(|| format!("{flag}: missing value"))?;
+        match flag.as_str() {
+            "--seed"      => args.seed      = Some(parse_u64(flag, value)?),
+            "--base-seed" => args.base_seed = Some(parse_u64(flag, value)?),
+            "--circuit"   => args.circuit   = Some(PathBuf::from(value)),
+            "--key"       => args.key       = Some(parse_hex(flag, value)?),
+            "--name"      => args.name      = value.clone(),
+            "--out"       => args.out       = PathBuf::from(value),
+            _ => return Err(format!("unknown option {flag:?}")),
+        }
+    }
+
+    let needs_seed = matches!(command, Command::Compile | Command::RotateCheap | Command::RotateStrong);
+    if needs_seed && args.seed.is_none() {
+        return Err(format!("{} requires --seed", command.as_str()));
+    }
+    if command == Command::RotateCheap && args.base_seed.is_some() == args.circuit.is_some() {
+        return Err("rotate-cheap requires exactly one of --circuit and --base-seed".to_string());
+    }
+    if command != Command::RotateCheap && (args.base_seed.is_some() || args.circuit.is_some()) {
+        return Err("--circuit and --base-seed only apply to rotate-cheap".to_string());
+    }
+    if args.name.is_empty() {
+        return Err("cannot derive a function name from the source path; pass --name".to_string());
+    }
+    Ok(args)
+}
+
+// ---------------------------------------------------------------------------
+// Manifest
+// ---------------------------------------------------------------------------
+
+fn json_opt<T: ToString>(v: Option<T>) -> String {
+    v.map_or("null".to_string(), |v| v.to_string())
+}
+
+fn stats_json(stats: &CircuitStats, pool_len: Option<usize>) -> String {
+    let by_kind: Vec<String> = stats.by_kind.iter()
+        .map(|(kind, n)| format!("{}: {n}", json_str(kind)))
+        .collect();
+    let mut fields = vec![
+        format!("\"gadgets\": {}", stats.gadgets),
+        format!("\"wires\": {}", stats.wires),
+        format!("\"generators\": {}", stats.generators),
+        format!("\"and_triples\": {}", stats.and_triples),
+    ];
+    if let Some(n) = pool_len {
+        fields.push(format!("\"pool_len\": {n}"));
+    }
+    fields.push(format!("\"by_kind\": {{{}}}", by_kind.join(", ")));
+    format!("{{{}}}", fields.join(", "))
+}
+
+struct Manifest<'a> {
+    args:      &'a Args,
+    digest:    [u8; 32],
+    canonical: &'a Circuit,
+    browser:   Option<(&'a Circuit, &'a MaskedCircuit)>,
+    files:     BTreeMap<&'static str, String>,
+}
+
+impl Manifest<'_> {
+    fn to_json(&self) -> String {
+        let inputs: Vec<String> = self.canonical.params().iter()
+            .map(|(name, len)| {
+                let param = json_str(&param_identifier(name));
+                match len {
+                    None      => format!("{{\"name\": {}, \"param\": {param}, \"type\": \"u32\"}}", json_str(name)),
+                    Some(len) => format!("{{\"name\": {}, \"param\": {param}, \"type\": \"bytes\", \"len\": {len}}}", json_str(name)),
+                }
+            })
+            .collect();
+        let files: Vec<String> = self.files.iter()
+            .map(|(role, file)| format!("{}: {}", json_str(role), json_str(file)))
+            .collect();
+        let browser = self.browser
+            .map(|(c, m)| stats_json(&c.stats(), Some(m.pool_len())))
+            .unwrap_or_else(|| "null".to_string());
+
+        let fields = [
+            ("command",          json_str(self.args.command.as_str())),
+            ("name",             json_str(&self.args.name)),
+            ("source",           json_str(&self.args.source.to_string_lossy())),
+            ("expr_digest",      json_str(&hex(&self.digest))),
+            ("digest_keyed",     self.args.key.is_some().to_string()),
+            ("seed",             json_opt(self.args.seed)),
+            ("base_seed",        json_opt(self.args.base_seed)),
+            ("base_circuit",     self.args.circuit.as_ref().map_or("null".to_string(), |p| json_str(&p.to_string_lossy()))),
+            ("inputs",           format!("[{}]", inputs.join(", "))),
+            ("outputs",          self.canonical.n_outputs().to_string()),
+            ("files",            format!("{{{}}}", files.join(", "))),
+            ("verifier_circuit", stats_json(&self.canonical.stats(), None)),
+            ("browser_circuit",  browser),
+        ];
+        let body: Vec<String> = fields.iter()
+            .map(|(k, v)| format!("  {}: {v}", json_str(k)))
+            .collect();
+        format!("{{\n{}\n}}\n", body.join(",\n"))
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Driver
+// ---------------------------------------------------------------------------
+
+fn write(dir: &Path, file: &str, contents: &str) -> Result<(), String> {
+    let path = dir.join(file);
+    std::fs::write(&path, contents).map_err(|e| format!("failed to write {}: {e}", path.display()))?;
+    println!("wrote {}", path.display());
+    Ok(())
+}
+
+/// Load a saved circuit and check it computes the source: the same
+/// signature, and the same outputs as `canonical` on a few random inputs.
+fn load_circuit(path: &Path, canonical: &Circuit) -> Result<Circuit, String> {
+    let circuit = if path.extension().is_some_and(|e| e == "json") {
+        let text = std::fs::read_to_string(path).map_err(|e| format!("failed to read {}: {e}", path.display()))?;
+        Circuit::from_json(&text)
+    } else {
+        let bytes = std::fs::read(path).map_err(|e| format!("failed to read {}: {e}", path.display()))?;
+        Circuit::from_bytes(&bytes)
+    }
+    .map_err(|e| format!("{}: {e}", path.display()))?;
+
+    if circuit.params() != canonical.params() || circuit.n_outputs() != canonical.n_outputs() {
+        return Err(format!("{}: circuit signature does not match the source", path.display()));
+    }
+    let mut rng = StdRng::seed_from_u64(0);
+    for _ in 0..8 {
+        let mut inputs = HashMap::new();
+        for (name, len) in canonical.params() {
+            match len {
+                None      => { inputs.insert(name, rng.random()); }
+                Some(len) => inputs.extend(byte_inputs(&name, &(0..len).map(|_| rng.random()).collect::<Vec<u8>>())),
+            }
+        }
+        if circuit.eval_outputs(&inputs) != canonical.eval_outputs(&inputs) {
+            return Err(format!("{}: circuit does not compute the source", path.display()));
+        }
+    }
+    Ok(circuit)
+}
+
+fn run(args: &Args) -> Result<(), String> {
+    let src = std::fs::read_to_string(&args.source)
+        .map_err(|e| format!("failed to read {}: {e}", args.source.display()))?;
+    let exprs: Vec<Rc<Expr>> = dsl::parse(&src)
+        .map_err(|e| format!("{}:{e}", args.source.display()))?;
+
+    let options   = CompileOptions { key: args.key.clone(), ..CompileOptions::default() };
+    let name      = args.name.as_str();
+    let digest    = exprs_digest(&exprs, options.key.as_deref());
+    let canonical = try_lower_to_circuit_multi(&exprs).map_err(|e| e.to_string())?;
+    check_fn_name(name).map_err(|e| e.to_string())?;
+
+    // The browser artifact, for commands that produce (or inspect) one.
+    let browser = match (args.command, args.seed) {
+        (Command::Verifier, _) | (Command::Inspect, None) => None,
+        (Command::RotateCheap, Some(seed)) => {
+            let circuit = match (&args.circuit, args.base_seed) {
+                (Some(path), _) => load_circuit(path, &canonical)?,
+                (None, Some(base)) => {
+                    try_compile_with(exprs.clone(), name, &options, &mut StdRng::seed_from_u64(base)).map_err(|e| e.to_string())?.circuit
+                }
+                (None, None) => unreachable!("checked in parse_args"),
+            };
+            let (masked, code) = rotate_cheap_circuit(&circuit, &digest, name, &mut StdRng::seed_from_u64(seed));
+            Some((circuit, masked, code))
+        }
+        (_, Some(seed)) => {
+            let c = try_compile_with(exprs.clone(), name, &options, &mut StdRng::seed_from_u64(seed)).map_err(|e| e.to_string())?;
+            Some((c.circuit, c.masked, c.code))
+        }
+        (_, None) => unreachable!("checked in parse_args"),
+    };
+
+    let mut files = BTreeMap::new();
+    if args.command != Command::Inspect {
+        std::fs::create_dir_all(&args.out)
+            .map_err(|e| format!("failed to create {}: {e}", args.out.display()))?;
+        if let Some((circuit, _, code)) = &browser {
+            let file = format!("{name}.rs");
+            write(&args.out, &file, code)?;
+            files.insert("browser", file);
+            if args.command != Command::RotateCheap {
+                let file = format!("{name}.circuit.json");
+                write(&args.out, &file, &circuit.to_json())?;
+                files.insert("circuit", file);
+            }
+        }
+        if matches!(args.command, Command::Compile | Command::Verifier) {
+            let file = format!("{name}_verify.rs");
+            let verifier = try_compile_verifier_with(&exprs, &format!("{name}_verify"), &options).map_err(|e| e.to_string())?;
+            write(&args.out, &file, &verifier)?;
+            files.insert("verifier", file);
+        }
+    }
+
+    let manifest = Manifest {
+        args,
+        digest,
+        canonical: &canonical,
+        browser: browser.as_ref().map(|(c, m, _)| (c, m)),
+        files,
+    }
+    .to_json();
+
+    if args.command == Command::Inspect {
+        print!("{manifest}");
+    } else {
+        write(&args.out, &format!("{name}.manifest.json"), &manifest)?;
+    }
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    let argv: Vec<String> = std::env::args().skip(1).collect();
+    if argv.iter().any(|a| a == "-h" || a == "--help") {
+        println!("{USAGE}");
+        return ExitCode::SUCCESS;
+    }
+    let args = match parse_args(&argv) {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("xorplc: {e}\n\n{USAGE}");
+            return ExitCode::from(2);
+        }
+    };
+    match run(&args) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("xorplc: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -12,14 +12,19 @@
 //!
 //! Each logical value `X` is stored at runtime as `X ^ m` where `m` is a
 //! per-wire mask sampled during concretization (see `mask`).  Free ops
-//! (XOR, rotation, NOT, AndConst, XorConst, Rotl) propagate masks
+//! (XOR, rotation, NOT, AndConst, XorConst, Rotl, Shl, Shr) propagate masks
 //! analytically.  Only bitwise AND is metered — it requires a fresh Beaver
 //! triple per gate.  `Remask` re-randomises a wire's mask without changing
 //! its value.
 
 #![allow(dead_code)]
 
-use std::collections::{HashMap, HashSet};
+use std::borrow::Cow;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fmt;
+
+use crate::error::Error;
+use crate::schema::InputSchema;
 
 // ---------------------------------------------------------------------------
 // ID types
@@ -39,6 +44,40 @@ pub enum Wire {
     Internal,
 }
 
+// ---------------------------------------------------------------------------
+// Byte-slice inputs
+// ---------------------------------------------------------------------------
+
+/// Where an `Ingest` word comes from when the input is a byte slice.
+///
+/// Word `word` of `param` packs bytes `4*word .. 4*word + 4` little-endian;
+/// bytes at or past `len` read as zero.  Emitters group all words of one
+/// `param` into a single `&[u8; len]` parameter.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ByteWord {
+    pub param: String,
+    pub len:   usize,
+    pub word:  usize,
+}
+
+/// `Circuit::eval` input key for word `word` of byte input `param`.
+pub fn byte_word_name(param: &str, word: usize) -> String {
+    format!("{param}[{word}]")
+}
+
+/// Pack `bytes` into the `Circuit::eval` input entries for byte input
+/// `param`, using the same padding as the emitted code.
+pub fn byte_inputs(param: &str, bytes: &[u8]) -> Vec<(String, u32)> {
+    bytes.chunks(4)
+        .enumerate()
+        .map(|(i, c)| {
+            let mut w = [0u8; 4];
+            w[..c.len()].copy_from_slice(c);
+            (byte_word_name(param, i), u32::from_le_bytes(w))
+        })
+        .collect()
+}
+
 // ---------------------------------------------------------------------------
 // Gadget catalog
 // ---------------------------------------------------------------------------
@@ -50,14 +89,19 @@ pub enum Gadget {
     // --- sources ---
     PublicConst  { k: u32,                    out: WireId },
     SecretConst  { k: u32,  gen: GenId,       out: WireId },
-    Ingest       { name: String, gen: GenId,  out: WireId },
+    /// `bytes` is set for one word of a byte-slice input; `name` is then
+    /// `byte_word_name(param, word)`.
+    Ingest       { name: String, bytes: Option<ByteWord>, gen: GenId, out: WireId },
     // --- free / linear ---
     Xor          { a: WireId, b: WireId,      out: WireId },
     XorConst     { a: WireId, k: u32,         out: WireId },
     AndConst     { a: WireId, k: u32,         out: WireId },
     Rotl         { a: WireId, r: u32,         out: WireId },
+    Shl          { a: WireId, r: u32,         out: WireId },
+    Shr          { a: WireId, r: u32,         out: WireId },
     // --- metered ---
-    And          { a: WireId, b: WireId, gen: GenId, out: WireId },
+    /// `share` draws the triple's operand-mask share; see `MaskedCircuit::bake`.
+    And          { a: WireId, b: WireId, gen: GenId, share: GenId, out: WireId },
     // --- utility ---
     Remask       { a: WireId,           gen: GenId, out: WireId },
     Egress       { a: WireId },
@@ -73,18 +117,38 @@ impl Gadget {
             Gadget::XorConst    { .. } => "XOR_CONST",
             Gadget::AndConst    { .. } => "AND_CONST",
             Gadget::Rotl        { .. } => "ROTL",
+            Gadget::Shl         { .. } => "SHL",
+            Gadget::Shr         { .. } => "SHR",
             Gadget::And         { .. } => "AND",
             Gadget::Remask      { .. } => "REMASK",
             Gadget::Egress      { .. } => "EGRESS",
         }
     }
 
+    /// Number of `POOL` entries the gadget bakes: one per mask, constant or
+    /// delta, four per Beaver triple.  Matches `MaskedCircuit::from_circuit`.
+    pub fn pool_entries(&self) -> usize {
+        match self {
+            Gadget::Xor { .. } | Gadget::Rotl { .. } | Gadget::Shl { .. } | Gadget::Shr { .. } => 0,
+            Gadget::PublicConst { .. }
+            | Gadget::SecretConst { .. }
+            | Gadget::Ingest { .. }
+            | Gadget::XorConst { .. }
+            | Gadget::AndConst { .. }
+            | Gadget::Remask { .. }
+            | Gadget::Egress { .. } => 1,
+            Gadget::And { .. } => 4,
+        }
+    }
+
     pub(crate) fn input_wires(&self) -> Vec<WireId> {
         match self {
             Gadget::Xor    { a, b, .. } | Gadget::And { a, b, .. } => vec![*a, *b],
             Gadget::XorConst { a, .. }
             | Gadget::AndConst { a, .. }
             | Gadget::Rotl   { a, .. }
+            | Gadget::Shl    { a, .. }
+            | Gadget::Shr    { a, .. }
             | Gadget::Remask { a, .. }
             | Gadget::Egress { a }      => vec![*a],
             Gadget::PublicConst { .. }
@@ -97,8 +161,8 @@ impl Gadget {
         match self {
             Gadget::SecretConst { gen, .. }
             | Gadget::Ingest    { gen, .. }
-            | Gadget::And       { gen, .. }
             | Gadget::Remask    { gen, .. } => vec![*gen],
+            Gadget::And { gen, share, .. }  => vec![*gen, *share],
             _ => vec![],
         }
     }
@@ -112,6 +176,8 @@ impl Gadget {
             | Gadget::XorConst { out, .. }
             | Gadget::AndConst { out, .. }
             | Gadget::Rotl    { out, .. }
+            | Gadget::Shl     { out, .. }
+            | Gadget::Shr     { out, .. }
             | Gadget::And     { out, .. }
             | Gadget::Remask  { out, .. } => Some(*out),
             Gadget::Egress { .. }         => None,
@@ -125,7 +191,8 @@ impl Gadget {
 
 #[derive(Clone, Debug)]
 pub struct Generator {
-    pub(crate) purpose: &'static str,
+    /// Debug label only; owned when loaded from a serialized circuit.
+    pub(crate) purpose: Cow<'static, str>,
 }
 
 // ---------------------------------------------------------------------------
@@ -137,11 +204,180 @@ pub struct Circuit {
     pub(crate) gadgets:    Vec<Gadget>,
     pub(crate) wires:      Vec<Wire>,
     pub(crate) generators: Vec<Generator>,
-    pub(crate) egress:     WireId,
+    /// Output wires, in return order.  Each is revealed by one trailing
+    /// `Gadget::Egress`.
+    pub(crate) egress:     Vec<WireId>,
+    /// Explicit parameter order, if one was attached with
+    /// [`Circuit::with_schema`]; otherwise parameters are sorted by name.
+    pub(crate) schema:     Option<InputSchema>,
+}
+
+/// Gadget and triple counts for one circuit; see [`Circuit::stats`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CircuitStats {
+    pub gadgets:     usize,
+    pub wires:       usize,
+    pub generators:  usize,
+    pub outputs:     usize,
+    /// Beaver triples consumed: one per `AND`.
+    pub and_triples: usize,
+    /// `POOL` length once masked; see [`Gadget::pool_entries`].
+    pub pool_entries: usize,
+    /// Gadget count per [`Gadget::kind`].
+    pub by_kind:     BTreeMap<&'static str, usize>,
+}
+
+impl CircuitStats {
+    /// How `after` differs from `self`: positive deltas mean `after` has more.
+    pub fn diff(&self, after: &CircuitStats) -> StatsDiff {
+        let delta = |before: usize, after: usize| after as isize - before as isize;
+        let by_kind = self.by_kind.keys().chain(after.by_kind.keys())
+            .map(|&kind| {
+                let count = |stats: &CircuitStats| stats.by_kind.get(kind).copied().unwrap_or(0);
+                (kind, delta(count(self), count(after)))
+            })
+            .filter(|&(_, d)| d != 0)
+            .collect();
+        StatsDiff {
+            gadgets:     delta(self.gadgets, after.gadgets),
+            wires:       delta(self.wires, after.wires),
+            generators:  delta(self.generators, after.generators),
+            and_triples: delta(self.and_triples, after.and_triples),
+            pool_entries: delta(self.pool_entries, after.pool_entries),
+            by_kind,
+        }
+    }
+}
+
+/// Structural change between two circuits; see [`CircuitStats::diff`].
+///
+/// Displays as e.g. `gadgets +12, triples +4 (AND +4, REMASK -2, XOR +10)`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StatsDiff {
+    pub gadgets:     isize,
+    pub wires:       isize,
+    pub generators:  isize,
+    pub and_triples: isize,
+    pub pool_entries: isize,
+    /// Count change per [`Gadget::kind`], for kinds whose count changed.
+    pub by_kind:     BTreeMap<&'static str, isize>,
+}
+
+impl StatsDiff {
+    /// Whether every count is unchanged.  The circuits may still differ in
+    /// wiring.
+    pub fn is_empty(&self) -> bool {
+        self.gadgets == 0 && self.wires == 0 && self.generators == 0 && self.pool_entries == 0
+            && self.by_kind.is_empty()
+    }
+}
+
+impl fmt::Display for StatsDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "gadgets {:+}, triples {:+}", self.gadgets, self.and_triples)?;
+        if !self.by_kind.is_empty() {
+            let kinds: Vec<String> = self.by_kind.iter().map(|(k, d)| format!("{k} {d:+}")).collect();
+            write!(f, " ({})", kinds.join(", "))?;
+        }
+        Ok(())
+    }
 }
 
 impl Circuit {
+    /// Number of output words.
+    pub fn n_outputs(&self) -> usize {
+        self.egress.len()
+    }
+
+    /// The emitted function's parameters: `(name, None)` for a `u32` input,
+    /// `(name, Some(len))` for a `len`-byte input.  In the order of the
+    /// circuit's [`InputSchema`] if it has one, sorted by name otherwise.
+    pub fn params(&self) -> Vec<(String, Option<usize>)> {
+        match &self.schema {
+            Some(schema) => schema.params().to_vec(),
+            None         => self.ingested_params(),
+        }
+    }
+
+    /// The parameters read by `Ingest` gadgets, sorted by name, ignoring any
+    /// schema.
+    pub(crate) fn ingested_params(&self) -> Vec<(String, Option<usize>)> {
+        let mut params: Vec<(String, Option<usize>)> = self.gadgets.iter()
+            .filter_map(|g| match g {
+                Gadget::Ingest { name, bytes: None, .. } => Some((name.clone(), None)),
+                Gadget::Ingest { bytes: Some(bw), .. }   => Some((bw.param.clone(), Some(bw.len))),
+                _ => None,
+            })
+            .collect();
+        params.sort_unstable();
+        params.dedup();
+        params
+    }
+
+    /// The explicit parameter order attached with [`Circuit::with_schema`].
+    pub fn schema(&self) -> Option<&InputSchema> {
+        self.schema.as_ref()
+    }
+
+    /// Attach an explicit parameter order, replacing any previous one.
+    /// Fails with [`Error::SchemaMismatch`] unless `schema` lists exactly
+    /// this circuit's inputs (see [`InputSchema::check`]).
+    pub fn with_schema(mut self, schema: &InputSchema) -> Result<Circuit, Error> {
+        schema.check(&self)?;
+        self.schema = Some(schema.clone());
+        Ok(self)
+    }
+
+    /// [`Circuit::with_schema`] for a circuit lowered from transformed
+    /// expressions: a declared input whose every read was folded away is
+    /// ingested anyway, unread, so it keeps its place in the signature.
+    pub(crate) fn with_schema_keeping_inputs(mut self, schema: &InputSchema) -> Result<Circuit, Error> {
+        let read = self.ingested_params();
+        let mut ingests = Vec::new();
+        for (param, len) in schema.params() {
+            if read.iter().any(|(name, _)| name == param) {
+                continue;
+            }
+            let (name, bytes) = match *len {
+                None      => (param.clone(), None),
+                Some(len) => (byte_word_name(param, 0), Some(ByteWord { param: param.clone(), len, word: 0 })),
+            };
+            let gen = self.generators.len();
+            self.generators.push(Generator { purpose: Cow::Borrowed("ingest") });
+            let out = self.wires.len();
+            self.wires.push(Wire::Ingest);
+            ingests.push(Gadget::Ingest { name, bytes, gen, out });
+        }
+        self.gadgets.splice(0..0, ingests);
+        self.with_schema(schema)
+    }
+
+    /// Structural summary: gadget counts by kind and metered AND triples.
+    pub fn stats(&self) -> CircuitStats {
+        let mut by_kind: BTreeMap<&'static str, usize> = BTreeMap::new();
+        for g in &self.gadgets {
+            *by_kind.entry(g.kind()).or_default() += 1;
+        }
+        CircuitStats {
+            gadgets:    self.gadgets.len(),
+            wires:      self.wires.len(),
+            generators: self.generators.len(),
+            outputs:    self.egress.len(),
+            and_triples: by_kind.get("AND").copied().unwrap_or(0),
+            pool_entries: self.gadgets.iter().map(Gadget::pool_entries).sum(),
+            by_kind,
+        }
+    }
+
+    /// Evaluate F and return the output words in order.
+    pub fn eval_outputs(&self, inputs: &HashMap<String, u32>) -> Vec<u32> {
+        let v = self.eval(inputs);
+        self.egress.iter().map(|w| v[w]).collect()
+    }
+
     /// Evaluate the unmasked function F (server-side spec).
+    ///
+    /// Panics if an input is missing; see [`Circuit::try_eval`].
     pub fn eval(&self, inputs: &HashMap<String, u32>) -> HashMap<WireId, u32> {
         let mut v: HashMap<WireId, u32> = HashMap::new();
         for g in &self.gadgets {
@@ -153,6 +389,8 @@ impl Circuit {
                 Gadget::XorConst { a, k, out }     => { v.insert(*out, v[a] ^ *k); }
                 Gadget::AndConst { a, k, out }     => { v.insert(*out, v[a] & *k); }
                 Gadget::Rotl { a, r, out }         => { v.insert(*out, v[a].rotate_left(*r)); }
+                Gadget::Shl { a, r, out }          => { v.insert(*out, v[a] << *r); }
+                Gadget::Shr { a, r, out }          => { v.insert(*out, v[a] >> *r); }
                 Gadget::And { a, b, out, .. }      => { v.insert(*out, v[a] & v[b]); }
                 Gadget::Remask { a, out, .. }      => { v.insert(*out, v[a]); }
                 Gadget::Egress { .. }              => {}
@@ -161,86 +399,167 @@ impl Circuit {
         v
     }
 
+    /// [`Circuit::eval`], rejecting a missing input or a value for an input
+    /// the circuit does not ingest.
+    pub fn try_eval(&self, inputs: &HashMap<String, u32>) -> Result<HashMap<WireId, u32>, Error> {
+        let ingested: HashSet<&str> = self.gadgets.iter()
+            .filter_map(|g| match g {
+                Gadget::Ingest { name, .. } => Some(name.as_str()),
+                _ => None,
+            })
+            .collect();
+        if let Some(name) = ingested.iter().find(|n| !inputs.contains_key(**n)) {
+            return Err(Error::MissingInput(name.to_string()));
+        }
+        let mut unknown: Vec<&String> = inputs.keys().filter(|k| !ingested.contains(k.as_str())).collect();
+        unknown.sort_unstable();
+        if let Some(name) = unknown.first() {
+            return Err(Error::UnknownInput(name.to_string()));
+        }
+        Ok(self.eval(inputs))
+    }
+
+    /// [`Circuit::eval_outputs`] with [`Circuit::try_eval`]'s input checks.
+    pub fn try_eval_outputs(&self, inputs: &HashMap<String, u32>) -> Result<Vec<u32>, Error> {
+        let v = self.try_eval(inputs)?;
+        Ok(self.egress.iter().map(|w| v[w]).collect())
+    }
+
     /// Full structural validation.
     ///
-    /// Checks: egress wire role, exactly one Egress gadget, single-assignment,
-    /// topological order (inputs written before read), GenId uniqueness
-    /// (no triple reuse), and all ID ranges.
+    /// Checks: egress wire roles, one trailing Egress gadget per output (in
+    /// order, no duplicates), single-assignment,
+    /// topological order (inputs written by an earlier gadget), GenId uniqueness
+    /// (no triple reuse), shift amounts below 32, byte-input words within
+    /// one consistent length per parameter (and not clashing with a `u32`
+    /// input's name), all ID ranges, and that the schema, if any, matches
+    /// the ingested inputs.
     ///
-    /// `Builder::build` calls this and panics on failure.  Circuit transforms
-    /// that construct a `Circuit` directly should call it too.
-    pub(crate) fn validate(&self) -> Result<(), String> {
+    /// `Builder::build` calls this and panics on failure (`try_build_multi`
+    /// returns it).  Circuit transforms that construct a `Circuit` directly
+    /// should call it too.
+    pub(crate) fn validate(&self) -> Result<(), Error> {
         let nw = self.wires.len();
         let ng = self.generators.len();
 
-        if self.egress >= nw {
-            return Err(format!("egress={} out of range ({nw} wires)", self.egress));
+        if self.egress.is_empty() {
+            return Err(invalid("no egress wires".to_string()));
         }
-        if self.wires[self.egress] != Wire::Egress {
-            return Err(format!(
-                "egress={} has role {:?}, expected Egress",
-                self.egress, self.wires[self.egress]
-            ));
+        let mut seen_egress: HashSet<WireId> = HashSet::new();
+        for &e in &self.egress {
+            if e >= nw {
+                return Err(invalid(format!("egress={e} out of range ({nw} wires)")));
+            }
+            if self.wires[e] != Wire::Egress {
+                return Err(invalid(format!("egress={e} has role {:?}, expected Egress", self.wires[e])));
+            }
+            if !seen_egress.insert(e) {
+                return Err(invalid(format!("egress={e} listed twice")));
+            }
         }
+        let tail_start = self.gadgets.len().saturating_sub(self.egress.len());
 
         let mut written:    HashSet<WireId> = HashSet::new();
         let mut used_gens:  HashSet<GenId>  = HashSet::new();
         let mut egress_count = 0usize;
+        let mut byte_lens:  HashMap<&str, usize> = HashMap::new();
+        let word_names: HashSet<&str> = self.gadgets.iter()
+            .filter_map(|g| match g {
+                Gadget::Ingest { name, bytes: None, .. } => Some(name.as_str()),
+                _ => None,
+            })
+            .collect();
 
         for (idx, g) in self.gadgets.iter().enumerate() {
-            let label = || format!("gadget[{idx}] {}", g.kind());
+            let label = || g.kind();
+            let at = |message: String| Error::Validation { gadget: Some(idx), message };
 
             if matches!(g, Gadget::Egress { .. }) {
                 egress_count += 1;
+                if idx < tail_start {
+                    return Err(at(format!("{}: Egress gadgets must form the tail of the schedule", label())));
+                }
             }
 
-            if let Some(out) = g.out() {
-                if out >= nw {
-                    return Err(format!("{}: output WireId {out} out of range", label()));
+            if let Gadget::Ingest { bytes: Some(bw), .. } = g {
+                if bw.word * 4 >= bw.len {
+                    return Err(at(format!("{}: word {} past end of {}-byte input {:?}", label(), bw.word, bw.len, bw.param)));
                 }
-                if !written.insert(out) {
-                    return Err(format!("{}: output WireId {out} already written", label()));
+                if *byte_lens.entry(&bw.param).or_insert(bw.len) != bw.len {
+                    return Err(at(format!("{}: byte input {:?} has inconsistent lengths", label(), bw.param)));
+                }
+                if word_names.contains(bw.param.as_str()) {
+                    return Err(at(format!("{}: byte input {:?} clashes with a u32 input", label(), bw.param)));
+                }
+            }
+
+            if let Gadget::Shl { r, .. } | Gadget::Shr { r, .. } = g {
+                if *r >= 32 {
+                    return Err(at(format!("{}: shift amount {r} out of range", label())));
                 }
             }
 
             for a in g.input_wires() {
                 if a >= nw {
-                    return Err(format!("{}: input WireId {a} out of range", label()));
+                    return Err(at(format!("{}: input WireId {a} out of range", label())));
                 }
                 if matches!(g, Gadget::Egress { .. }) {
-                    if a != self.egress {
-                        return Err(format!("{}: reads wire {a}, expected egress {}", label(), self.egress));
+                    let expected = self.egress[idx - tail_start];
+                    if a != expected {
+                        return Err(at(format!("{}: reads wire {a}, expected egress {expected}", label())));
                     }
                 } else if self.wires[a] == Wire::Egress {
-                    return Err(format!("{}: input WireId {a} has role Egress", label()));
+                    return Err(at(format!("{}: input WireId {a} has role Egress", label())));
                 }
                 if !written.contains(&a) {
-                    return Err(format!("{}: input WireId {a} read before written (topo order)", label()));
+                    return Err(at(format!("{}: input WireId {a} read before written (topo order)", label())));
+                }
+            }
+
+            if let Some(out) = g.out() {
+                if out >= nw {
+                    return Err(at(format!("{}: output WireId {out} out of range", label())));
+                }
+                if !written.insert(out) {
+                    return Err(at(format!("{}: output WireId {out} already written", label())));
                 }
             }
 
             for gen in g.gen_refs() {
                 if gen >= ng {
-                    return Err(format!("{}: GenId {gen} out of range", label()));
+                    return Err(at(format!("{}: GenId {gen} out of range", label())));
                 }
                 if !used_gens.insert(gen) {
-                    return Err(format!("{}: GenId {gen} already used (triple reuse)", label()));
+                    return Err(at(format!("{}: GenId {gen} already used (triple reuse)", label())));
                 }
             }
         }
 
-        if egress_count != 1 {
-            return Err(format!("expected exactly 1 Egress gadget, found {egress_count}"));
+        if egress_count != self.egress.len() {
+            return Err(invalid(format!(
+                "expected {} Egress gadget(s), found {egress_count}",
+                self.egress.len()
+            )));
+        }
+
+        if let Some(schema) = &self.schema {
+            schema.check(self)?;
         }
 
         Ok(())
     }
 }
 
+/// A circuit-level (not gadget-specific) validation failure.
+fn invalid(message: String) -> Error {
+    Error::Validation { gadget: None, message }
+}
+
 // ---------------------------------------------------------------------------
 // Builder
 // ---------------------------------------------------------------------------
 
+#[derive(Default)]
 pub struct Builder {
     wires:      Vec<Wire>,
     gadgets:    Vec<Gadget>,
@@ -260,14 +579,24 @@ impl Builder {
 
     fn alloc_gen(&mut self, purpose: &'static str) -> GenId {
         let id = self.generators.len();
-        self.generators.push(Generator { purpose });
+        self.generators.push(Generator { purpose: Cow::Borrowed(purpose) });
         id
     }
 
     pub fn ingest(&mut self, name: &str) -> WireId {
         let gen = self.alloc_gen("ingest");
         let out = self.alloc_wire(Wire::Ingest);
-        self.gadgets.push(Gadget::Ingest { name: name.to_string(), gen, out });
+        self.gadgets.push(Gadget::Ingest { name: name.to_string(), bytes: None, gen, out });
+        out
+    }
+
+    /// Ingest word `word` of the `len`-byte input `param`.
+    pub fn ingest_word(&mut self, param: &str, len: usize, word: usize) -> WireId {
+        let gen = self.alloc_gen("ingest");
+        let out = self.alloc_wire(Wire::Ingest);
+        let name = byte_word_name(param, word);
+        let bytes = Some(ByteWord { param: param.to_string(), len, word });
+        self.gadgets.push(Gadget::Ingest { name, bytes, gen, out });
         out
     }
 
@@ -308,13 +637,47 @@ impl Builder {
         out
     }
 
-    pub fn and(&mut self, a: WireId, b: WireId) -> WireId {
-        let gen = self.alloc_gen("AND");
+    /// Logical left shift.  `r` must be below 32 (checked by `validate`).
+    pub fn shl(&mut self, a: WireId, r: u32) -> WireId {
         let out = self.alloc_wire(Wire::Internal);
-        self.gadgets.push(Gadget::And { a, b, gen, out });
+        self.gadgets.push(Gadget::Shl { a, r, out });
         out
     }
 
+    /// Logical right shift.  `r` must be below 32 (checked by `validate`).
+    pub fn shr(&mut self, a: WireId, r: u32) -> WireId {
+        let out = self.alloc_wire(Wire::Internal);
+        self.gadgets.push(Gadget::Shr { a, r, out });
+        out
+    }
+
+    pub fn and(&mut self, a: WireId, b: WireId) -> WireId {
+        let gen   = self.alloc_gen("AND");
+        let share = self.alloc_gen("AND share");
+        let out   = self.alloc_wire(Wire::Internal);
+        self.gadgets.push(Gadget::And { a, b, gen, share, out });
+        out
+    }
+
+    /// Data-dependent rotation `a.rotate_left(amt & 31)` as a 5-stage barrel
+    /// shifter.  Stage `i` is the mux `x ^ (c & (rotl(x, 1 << i) ^ x))` with
+    /// `c = broadcast_bit(amt, i)`.  Both AND operands are remasked first: the
+    /// broadcast's mask is itself `0` or `0xffff_ffff`, and the difference's
+    /// mask is a fixed function of `x`'s.  Cost: 5 triples.
+    pub fn rotl_var(&mut self, a: WireId, amt: WireId) -> WireId {
+        let mut x = a;
+        for i in 0..5 {
+            let bit  = self.broadcast_bit(amt, i);
+            let cond = self.remask(bit);
+            let rot  = self.rotl(x, 1 << i);
+            let diff = self.xor(rot, x);
+            let diff = self.remask(diff);
+            let pick = self.and(cond, diff);
+            x = self.xor(x, pick);
+        }
+        x
+    }
+
     pub fn remask(&mut self, a: WireId) -> WireId {
         let gen = self.alloc_gen("remask");
         let out = self.alloc_wire(Wire::Internal);
@@ -325,17 +688,30 @@ impl Builder {
     /// 32-bit wrapping addition using the word-level generate optimization.
     /// Cost: 31 triples (1 for generate bits, 30 for carry-propagate chain).
     pub fn add32(&mut self, a: WireId, b: WireId) -> WireId {
+        self.add32_from(a, b, 0)
+    }
+
+    /// `add32` for a `b` whose bits below `lo` are known to be zero (e.g. a
+    /// shifted partial product).  No carry can exist below bit `lo`, so the
+    /// chain starts there.
+    /// Cost: `31 - lo` triples for `lo <= 30`, 0 for `lo == 31`.
+    pub fn add32_from(&mut self, a: WireId, b: WireId, lo: u32) -> WireId {
+        if lo >= 31 {
+            // At most the top bit of b is set; its carry-out is discarded.
+            return self.xor(a, b);
+        }
         let g_word = self.and(a, b);
         let p_word = self.xor(a, b);
 
-        let s_0 = self.and_const(p_word, 1);
-        let g_0 = self.and_const(g_word, 1);
-        let c_1 = self.rotl(g_0, 1);
+        // Sum bits 0..=lo see no carry-in, so they are just the propagate bits.
+        let s_lo = self.and_const(p_word, (1u32 << (lo + 1)) - 1);
+        let g_lo = self.and_const(g_word, 1 << lo);
+        let c_1  = self.rotl(g_lo, 1);
 
         let mut carry = c_1;
-        let mut sum   = s_0;
+        let mut sum   = s_lo;
 
-        for i in 1u32..=31 {
+        for i in (lo + 1)..=31 {
             let mask  = 1u32 << i;
             let p_i   = self.and_const(p_word, mask);
             let s_i   = self.xor(p_i, carry);
@@ -351,19 +727,190 @@ impl Builder {
         sum
     }
 
+    /// 32-bit wrapping addition of a public constant, `a + k`.
+    ///
+    /// With one operand known, the generate word `a & k` and propagate word
+    /// `a ^ k` are free (`AndConst` / `XorConst`), and no carry can exist
+    /// below the lowest set bit of `k`, so the chain starts there.  Bits
+    /// where `k` is clear have no generate term to fold in.
+    /// Cost: `30 - k.trailing_zeros()` triples (0 when `k` has no bits below
+    /// bit 31 set).
+    pub fn add32_const(&mut self, a: WireId, k: u32) -> WireId {
+        if k == 0 {
+            return a;
+        }
+        let p_word = self.xor_const(a, k);
+        let t = k.trailing_zeros();
+        if t == 31 {
+            // Only the top bit is added; its carry-out is discarded.
+            return p_word;
+        }
+        let g_word = self.and_const(a, k);
+
+        // Sum bits 0..=t see no carry-in, so they are just the propagate bits.
+        let low   = (1u32 << (t + 1)) - 1;
+        let mut sum   = self.and_const(p_word, low);
+        let g_t       = self.and_const(g_word, 1 << t);
+        let mut carry = self.rotl(g_t, 1);
+
+        for i in (t + 1)..=31 {
+            let mask = 1u32 << i;
+            let p_i  = self.and_const(p_word, mask);
+            let s_i  = self.xor(p_i, carry);
+            sum      = self.xor(sum, s_i);
+            if i < 31 {
+                let p_and_c    = self.and(p_i, carry);
+                let carry_at_i = if k & mask != 0 {
+                    let g_i = self.and_const(g_word, mask);
+                    self.xor(g_i, p_and_c)
+                } else {
+                    p_and_c
+                };
+                carry = self.rotl(carry_at_i, 1);
+            }
+        }
+
+        sum
+    }
+
+    /// Broadcast bit `i` of `a` to all 32 bits: `0xffff_ffff` if it is set,
+    /// `0` otherwise.  Free — isolates the bit with `AndConst`, then doubles
+    /// the run with five rotate-and-XOR steps (the runs never overlap).
+    pub fn broadcast_bit(&mut self, a: WireId, i: u32) -> WireId {
+        let mut x = self.and_const(a, 1 << (i % 32));
+        for s in [1u32, 2, 4, 8, 16] {
+            let rot = self.rotl(x, s);
+            x = self.xor(x, rot);
+        }
+        x
+    }
+
+    /// `0xffff_ffff` if `a == 0`, else `0` — a `Mux`-ready condition.
+    ///
+    /// ORs the word with its rotations by 16, 8, 4, 2, 1 so every bit holds
+    /// the OR of all 32 bits, then inverts.  Cost: 5 triples (one per OR).
+    pub fn is_zero(&mut self, a: WireId) -> WireId {
+        let mut y = a;
+        for s in [16u32, 8, 4, 2, 1] {
+            let rot     = self.rotl(y, s);
+            let xor_y   = self.xor(y, rot);
+            let and_y   = self.and(y, rot);
+            y = self.xor(xor_y, and_y);
+        }
+        self.xor_const(y, 0xffff_ffff)
+    }
+
+    /// `0xffff_ffff` if `a == b`, else `0`.  Cost: 5 triples.
+    pub fn eq32(&mut self, a: WireId, b: WireId) -> WireId {
+        let diff = self.xor(a, b);
+        self.is_zero(diff)
+    }
+
+    /// `0xffff_ffff` if `a < b` (unsigned), else `0`.
+    ///
+    /// `a < b` exactly when `a - b` borrows out of bit 31, so this runs the
+    /// `sub32` borrow chain one step further (without the difference bits)
+    /// and broadcasts the final borrow.  Cost: 32 triples.
+    pub fn ltu32(&mut self, a: WireId, b: WireId) -> WireId {
+        let not_a  = self.xor_const(a, 0xffff_ffff);
+        let g_word = self.and(not_a, b);
+        let p_word = self.xor(a, b);
+        let q_word = self.xor_const(p_word, 0xffff_ffff);
+
+        let g_0 = self.and_const(g_word, 1);
+        let mut borrow = self.rotl(g_0, 1);
+
+        for i in 1u32..=31 {
+            let mask        = 1u32 << i;
+            let g_i         = self.and_const(g_word, mask);
+            let q_i         = self.and_const(q_word, mask);
+            let q_and_w     = self.and(q_i, borrow);
+            let borrow_at_i = self.xor(g_i, q_and_w);
+            if i < 31 {
+                borrow = self.rotl(borrow_at_i, 1);
+            } else {
+                borrow = borrow_at_i;
+            }
+        }
+
+        self.broadcast_bit(borrow, 31)
+    }
+
+    /// 32-bit wrapping subtraction `a - b` using the same word-level trick as
+    /// `add32`, with borrows in place of carries.
+    ///
+    /// The borrow-generate word is `!a & b` (one word AND) and the
+    /// borrow-propagate word is `!(a ^ b)` (free).  Generate and propagate are
+    /// mutually exclusive per bit, so each borrow step is
+    /// `g_i ^ (q_i & borrow_i)` — one triple.
+    /// Cost: 31 triples (1 for generate bits, 30 for the borrow chain).
+    pub fn sub32(&mut self, a: WireId, b: WireId) -> WireId {
+        let not_a  = self.xor_const(a, 0xffff_ffff);
+        let g_word = self.and(not_a, b);
+        let p_word = self.xor(a, b);
+        let q_word = self.xor_const(p_word, 0xffff_ffff);
+
+        let d_0 = self.and_const(p_word, 1);
+        let g_0 = self.and_const(g_word, 1);
+        let w_1 = self.rotl(g_0, 1);
+
+        let mut borrow = w_1;
+        let mut diff   = d_0;
+
+        for i in 1u32..=31 {
+            let mask = 1u32 << i;
+            let p_i  = self.and_const(p_word, mask);
+            let d_i  = self.xor(p_i, borrow);
+            diff     = self.xor(diff, d_i);
+            if i < 31 {
+                let g_i         = self.and_const(g_word, mask);
+                let q_i         = self.and_const(q_word, mask);
+                let q_and_w     = self.and(q_i, borrow);
+                let borrow_at_i = self.xor(g_i, q_and_w);
+                borrow          = self.rotl(borrow_at_i, 1);
+            }
+        }
+
+        diff
+    }
+
     /// Finalise the circuit.  Calls `validate` and panics on failure — a bug
     /// here is a programming error, not a runtime condition.
-    pub fn build(mut self, result: WireId) -> Circuit {
-        self.wires[result] = Wire::Egress;
-        self.gadgets.push(Gadget::Egress { a: result });
+    pub fn build(self, result: WireId) -> Circuit {
+        self.build_multi(&[result])
+    }
+
+    /// Finalise a circuit with several outputs, returned in `results` order.
+    ///
+    /// Egress wires may not feed other gadgets, so a result that is also read
+    /// internally (or listed twice) is first copied through a `Remask`.
+    pub fn build_multi(self, results: &[WireId]) -> Circuit {
+        self.try_build_multi(results)
+            .unwrap_or_else(|e| panic!("Builder::build produced an invalid circuit: {e}"))
+    }
+
+    /// [`Builder::build_multi`], returning a validation failure instead of
+    /// panicking.
+    pub fn try_build_multi(mut self, results: &[WireId]) -> Result<Circuit, Error> {
+        let read: HashSet<WireId> = self.gadgets.iter().flat_map(|g| g.input_wires()).collect();
+        let mut egress: Vec<WireId> = Vec::with_capacity(results.len());
+        for &w in results {
+            let shared = read.contains(&w) || results.iter().filter(|&&r| r == w).count() > 1;
+            egress.push(if shared { self.remask(w) } else { w });
+        }
+        for &w in &egress {
+            self.wires[w] = Wire::Egress;
+            self.gadgets.push(Gadget::Egress { a: w });
+        }
         let c = Circuit {
-            egress:     result,
+            egress,
             gadgets:    self.gadgets,
             wires:      self.wires,
             generators: self.generators,
+            schema:     None,
         };
-        c.validate().expect("Builder::build produced an invalid circuit");
-        c
+        c.validate()?;
+        Ok(c)
     }
 }
 
@@ -386,6 +933,14 @@ pub(crate) fn build_example() -> Circuit {
     b.build(result)
 }
 
+pub(crate) fn build_sub32_example() -> Circuit {
+    let mut b  = Builder::new();
+    let wa     = b.ingest("a");
+    let wb     = b.ingest("b");
+    let result = b.sub32(wa, wb);
+    b.build(result)
+}
+
 pub(crate) fn build_add32_example() -> Circuit {
     let mut b  = Builder::new();
     let wa     = b.ingest("a");
@@ -413,6 +968,41 @@ mod tests {
         assert_eq!(unique.len(), and_gens.len(), "AND gates share a generator");
     }
 
+    #[test]
+    #[should_panic(expected = "shift amount 32 out of range")]
+    fn shift_amount_validated() {
+        let mut b = Builder::new();
+        let wa = b.ingest("a");
+        let result = b.shl(wa, 32);
+        b.build(result);
+    }
+
+    #[test]
+    fn build_multi_copies_shared_results() {
+        let mut b = Builder::new();
+        let wa = b.ingest("a");
+        let wx = b.rotl(wa, 8);
+        let wy = b.xor(wx, wa);
+        // wx feeds wy, and wy is requested twice: each occurrence gets a copy.
+        let c = b.build_multi(&[wx, wy, wy]);
+        assert_eq!(c.n_outputs(), 3);
+        assert_eq!(c.gadgets.iter().filter(|g| matches!(g, Gadget::Remask { .. })).count(), 3);
+        assert_ne!(c.egress[1], c.egress[2]);
+
+        let a = 0x1234_5678u32;
+        let out = c.eval_outputs(&[("a".to_string(), a)].into());
+        let y = a.rotate_left(8) ^ a;
+        assert_eq!(out, vec![a.rotate_left(8), y, y]);
+    }
+
+    #[test]
+    fn egress_must_be_trailing() {
+        let mut c = build_example();
+        let egress = c.gadgets.pop().unwrap();
+        c.gadgets.insert(c.gadgets.len() - 1, egress);
+        assert!(c.validate().unwrap_err().to_string().contains("tail"));
+    }
+
     #[test]
     fn add32_uses_31_triples() {
         let c = build_add32_example();
@@ -420,4 +1010,117 @@ mod tests {
         assert_eq!(count, 31, "ADD32 triple count");
     }
 
+    #[test]
+    fn add32_const_triple_count() {
+        for k in [1u32, 0x9e37_79b9, 0x0000_0100, 0x4000_0000, 0x8000_0000, 0] {
+            let mut b  = Builder::new();
+            let wa     = b.ingest("a");
+            let result = b.add32_const(wa, k);
+            let c      = b.build(result);
+            let count  = c.gadgets.iter().filter(|g| matches!(g, Gadget::And { .. })).count();
+            let expected = if k == 0 { 0 } else { 30u32.saturating_sub(k.trailing_zeros()) as usize };
+            assert_eq!(count, expected, "ADD32_CONST triple count for k={k:#010x}");
+            for a in [0u32, 1, 0x7FFF_FFFF, 0xFFFF_FFFF, 0xDEAD_BEEF] {
+                let vals = c.eval(&[("a".to_string(), a)].into());
+                assert_eq!(vals[&c.egress[0]], a.wrapping_add(k), "a={a:#010x} k={k:#010x}");
+            }
+        }
+    }
+
+    #[test]
+    fn add32_from_skips_known_zero_low_bits() {
+        for lo in [1u32, 7, 30, 31] {
+            let mut b  = Builder::new();
+            let wa     = b.ingest("a");
+            let wb     = b.ingest("b");
+            let wbs    = b.shl(wb, lo);
+            let result = b.add32_from(wa, wbs, lo);
+            let c      = b.build(result);
+            let count  = c.gadgets.iter().filter(|g| matches!(g, Gadget::And { .. })).count();
+            assert_eq!(count, 31 - lo as usize, "ADD32_FROM triple count for lo={lo}");
+            for (a, bv) in [(0xFFFF_FFFFu32, 0xFFFF_FFFFu32), (0x1234_5678, 0x8765_4321)] {
+                let vals = c.eval(&[("a".to_string(), a), ("b".to_string(), bv)].into());
+                assert_eq!(vals[&c.egress[0]], a.wrapping_add(bv << lo), "lo={lo}");
+            }
+        }
+    }
+
+    #[test]
+    fn broadcast_bit_is_free() {
+        let mut b  = Builder::new();
+        let wa     = b.ingest("a");
+        let result = b.broadcast_bit(wa, 13);
+        let c      = b.build(result);
+        assert!(!c.gadgets.iter().any(|g| matches!(g, Gadget::And { .. })));
+        for a in [0u32, 1 << 13, !(1 << 13), 0xFFFF_FFFF] {
+            let vals = c.eval(&[("a".to_string(), a)].into());
+            let expected = if a & (1 << 13) != 0 { 0xFFFF_FFFF } else { 0 };
+            assert_eq!(vals[&c.egress[0]], expected, "a={a:#010x}");
+        }
+    }
+
+    #[test]
+    fn comparisons_produce_broadcast_masks() {
+        let mut b  = Builder::new();
+        let wa     = b.ingest("a");
+        let wb     = b.ingest("b");
+        let eq     = b.eq32(wa, wb);
+        let lt     = b.ltu32(wa, wb);
+        let z      = b.is_zero(wa);
+        let eq_lt  = b.xor(eq, lt);
+        let result = b.xor(eq_lt, z);
+        let c      = b.build(result);
+        let count  = c.gadgets.iter().filter(|g| matches!(g, Gadget::And { .. })).count();
+        assert_eq!(count, 5 + 32 + 5, "EQ + LTU + IS_ZERO triple count");
+        let cases = [(0u32, 0u32), (0, 1), (1, 0), (5, 5), (0x8000_0000, 0x7FFF_FFFF),
+                     (0x7FFF_FFFF, 0x8000_0000), (0xFFFF_FFFF, 0xFFFF_FFFF), (0xFFFF_FFFE, 0xFFFF_FFFF)];
+        let mask = |p: bool| if p { 0xFFFF_FFFFu32 } else { 0 };
+        for (a, bv) in cases {
+            let vals = c.eval(&[("a".to_string(), a), ("b".to_string(), bv)].into());
+            assert_eq!(vals[&eq], mask(a == bv), "eq a={a:#x} b={bv:#x}");
+            assert_eq!(vals[&lt], mask(a < bv),  "ltu a={a:#x} b={bv:#x}");
+            assert_eq!(vals[&z],  mask(a == 0),  "is_zero a={a:#x}");
+        }
+    }
+
+    #[test]
+    fn sub32_uses_31_triples() {
+        let c = build_sub32_example();
+        let count = c.gadgets.iter().filter(|g| matches!(g, Gadget::And { .. })).count();
+        assert_eq!(count, 31, "SUB32 triple count");
+    }
+
+
+    #[test]
+    fn try_eval_checks_inputs() {
+        let mut b = Builder::new();
+        let a = b.ingest("a");
+        let m = b.ingest_word("m", 2, 0);
+        let x = b.xor(a, m);
+        let c = b.build(x);
+
+        let mut inputs: HashMap<String, u32> = [("a".to_string(), 5)].into();
+        assert_eq!(c.try_eval_outputs(&inputs), Err(Error::MissingInput("m[0]".to_string())));
+        inputs.insert("m[0]".to_string(), 3);
+        assert_eq!(c.try_eval_outputs(&inputs), Ok(vec![6]));
+        inputs.insert("b".to_string(), 1);
+        assert_eq!(c.try_eval_outputs(&inputs), Err(Error::UnknownInput("b".to_string())));
+    }
+
+    #[test]
+    fn stats_diff_counts_changes() {
+        let before = build_example().stats();
+        let after  = build_add32_example().stats();
+        let diff   = before.diff(&after);
+        assert_eq!(diff.gadgets, after.gadgets as isize - before.gadgets as isize);
+        assert_eq!(diff.and_triples, after.and_triples as isize - before.and_triples as isize);
+        for (kind, d) in &diff.by_kind {
+            let count = |s: &CircuitStats| s.by_kind.get(kind).copied().unwrap_or(0) as isize;
+            assert_eq!(*d, count(&after) - count(&before), "{kind}");
+            assert_ne!(*d, 0);
+        }
+        assert!(before.diff(&before).is_empty());
+        assert_eq!(before.diff(&before).to_string(), "gadgets +0, triples +0");
+        assert!(diff.to_string().starts_with(&format!("gadgets {:+}, triples {:+} (", diff.gadgets, diff.and_triples)));
+    }
 }
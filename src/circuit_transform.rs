@@ -17,6 +17,7 @@ use std::collections::HashMap;
 use rand::RngCore;
 
 use crate::circuit::{Builder, Circuit, Gadget, WireId};
+use crate::expr_transform::Chance;
 
 // ---------------------------------------------------------------------------
 // inject_remasks
@@ -25,13 +26,13 @@ use crate::circuit::{Builder, Circuit, Gadget, WireId};
 /// Probabilistically insert `Remask` gadgets after internal wire outputs.
 ///
 /// Each AND output is always a remask candidate; other internal outputs are
-/// included with probability `1/rate` (pass `rate = 4` for ~25%).  Source
-/// gadgets (`Ingest`, `PublicConst`, `SecretConst`) are never remasked —
-/// their masks are already independently fresh.
+/// included with probability `chance` (pass `Chance::new(1, 4)` for 25%).
+/// Source gadgets (`Ingest`, `PublicConst`, `SecretConst`) are never
+/// remasked — their masks are already independently fresh.
 ///
 /// Downstream consumers of remasked wires transparently receive the remasked
 /// wire through the remap table; no other change to circuit topology occurs.
-pub fn inject_remasks(circuit: &Circuit, rng: &mut impl RngCore, rate: u32) -> Circuit {
+pub fn inject_remasks(circuit: &Circuit, rng: &mut impl RngCore, chance: Chance) -> Circuit {
     let mut builder = Builder::new();
     let mut remap: HashMap<WireId, WireId> = HashMap::new();
 
@@ -41,7 +42,8 @@ pub fn inject_remasks(circuit: &Circuit, rng: &mut impl RngCore, rate: u32) -> C
 
         let new_out: Option<WireId> = match g {
             // --- sources: emit as-is, record remap ---
-            Gadget::Ingest { name, .. } => Some(builder.ingest(name)),
+            Gadget::Ingest { name, bytes: None, .. } => Some(builder.ingest(name)),
+            Gadget::Ingest { bytes: Some(bw), .. } => Some(builder.ingest_word(&bw.param, bw.len, bw.word)),
             Gadget::PublicConst { k, .. } => Some(builder.public_const(*k)),
             Gadget::SecretConst { k, .. } => Some(builder.secret_const(*k)),
 
@@ -50,12 +52,14 @@ pub fn inject_remasks(circuit: &Circuit, rng: &mut impl RngCore, rate: u32) -> C
             Gadget::XorConst { a, k, .. } => Some(builder.xor_const(r(*a), *k)),
             Gadget::AndConst { a, k, .. } => Some(builder.and_const(r(*a), *k)),
             Gadget::Rotl { a, r: rot, .. } => Some(builder.rotl(r(*a), *rot)),
+            Gadget::Shl { a, r: sh, .. } => Some(builder.shl(r(*a), *sh)),
+            Gadget::Shr { a, r: sh, .. } => Some(builder.shr(r(*a), *sh)),
 
             // --- nonlinear: AND outputs are prime remask candidates ---
             Gadget::And { a, b, .. } => {
                 let w = builder.and(r(*a), r(*b));
-                // Always eligible; apply with probability 1/rate.
-                if chance(rng, rate) {
+                // Always eligible; apply with probability `chance`.
+                if chance.roll(rng) {
                     Some(builder.remask(w))
                 } else {
                     Some(w)
@@ -65,7 +69,7 @@ pub fn inject_remasks(circuit: &Circuit, rng: &mut impl RngCore, rate: u32) -> C
             // --- existing Remask: re-emit (preserve existing structure) ---
             Gadget::Remask { a, .. } => Some(builder.remask(r(*a))),
 
-            // --- egress: handled by builder.build() below ---
+            // --- egress: handled by builder.build_multi() below ---
             Gadget::Egress { .. } => None,
         };
 
@@ -78,9 +82,9 @@ pub fn inject_remasks(circuit: &Circuit, rng: &mut impl RngCore, rate: u32) -> C
                 | Gadget::PublicConst { .. }
                 | Gadget::SecretConst { .. }
                 | Gadget::Remask { .. } => w,
-                // Linear ops: apply remask with probability 1/rate.
+                // Linear ops: apply remask with probability `chance`.
                 _ => {
-                    if chance(rng, rate) {
+                    if chance.roll(rng) {
                         builder.remask(w)
                     } else {
                         w
@@ -96,11 +100,10 @@ pub fn inject_remasks(circuit: &Circuit, rng: &mut impl RngCore, rate: u32) -> C
         let _ = new_out; // consumed via remap above
     }
 
-    builder.build(remap[&circuit.egress])
-}
-
-fn chance(rng: &mut impl RngCore, rate: u32) -> bool {
-    rng.next_u32() % rate == 0
+    let outputs: Vec<WireId> = circuit.egress.iter().map(|w| remap[w]).collect();
+    let mut out = builder.build_multi(&outputs);
+    out.schema = circuit.schema.clone();
+    out
 }
 
 // ---------------------------------------------------------------------------
@@ -110,14 +113,14 @@ fn chance(rng: &mut impl RngCore, rate: u32) -> bool {
 /// Probabilistically replace each `SecretConst(k)` with
 /// `Xor(SecretConst(a), SecretConst(k ^ a))` for a fresh random `a`.
 ///
-/// Each occurrence is split independently with probability `1/rate`.  The
+/// Each occurrence is split independently with probability `chance`.  The
 /// resulting circuit is semantically identical — the two halves XOR back to
 /// `k` — but adds one generator and one `Xor` gadget per split, changing the
 /// constant pool size and generator count between rotations.
 ///
 /// This has no expression-level analog: `SecretConst` as a distinct gadget
 /// type only exists after lowering.
-pub fn split_secret_consts(circuit: &Circuit, rng: &mut impl RngCore, rate: u32) -> Circuit {
+pub fn split_secret_consts(circuit: &Circuit, rng: &mut impl RngCore, chance: Chance) -> Circuit {
     let mut builder = Builder::new();
     let mut remap: HashMap<WireId, WireId> = HashMap::new();
 
@@ -126,7 +129,7 @@ pub fn split_secret_consts(circuit: &Circuit, rng: &mut impl RngCore, rate: u32)
 
         match g {
             Gadget::SecretConst { k, out, .. } => {
-                let new_out = if chance(rng, rate) {
+                let new_out = if chance.roll(rng) {
                     let a: u32 = rng.next_u32();
                     let wa = builder.secret_const(a);
                     let wb = builder.secret_const(k ^ a);
@@ -137,19 +140,163 @@ pub fn split_secret_consts(circuit: &Circuit, rng: &mut impl RngCore, rate: u32)
                 remap.insert(*out, new_out);
             }
             // All other gadgets pass through with remapped inputs.
-            Gadget::Ingest { name, out, .. }     => { remap.insert(*out, builder.ingest(name)); }
+            Gadget::Ingest { name, bytes: None, out, .. } => { remap.insert(*out, builder.ingest(name)); }
+            Gadget::Ingest { bytes: Some(bw), out, .. } => {
+                remap.insert(*out, builder.ingest_word(&bw.param, bw.len, bw.word));
+            }
             Gadget::PublicConst { k, out }        => { remap.insert(*out, builder.public_const(*k)); }
             Gadget::Xor { a, b, out }             => { remap.insert(*out, builder.xor(t(*a), t(*b))); }
             Gadget::XorConst { a, k, out }        => { remap.insert(*out, builder.xor_const(t(*a), *k)); }
             Gadget::AndConst { a, k, out }        => { remap.insert(*out, builder.and_const(t(*a), *k)); }
             Gadget::Rotl { a, r, out }            => { remap.insert(*out, builder.rotl(t(*a), *r)); }
+            Gadget::Shl { a, r, out }             => { remap.insert(*out, builder.shl(t(*a), *r)); }
+            Gadget::Shr { a, r, out }             => { remap.insert(*out, builder.shr(t(*a), *r)); }
             Gadget::And { a, b, out, .. }         => { remap.insert(*out, builder.and(t(*a), t(*b))); }
             Gadget::Remask { a, out, .. }         => { remap.insert(*out, builder.remask(t(*a))); }
             Gadget::Egress { .. }                 => {}
         }
     }
 
-    builder.build(remap[&circuit.egress])
+    let outputs: Vec<WireId> = circuit.egress.iter().map(|w| remap[w]).collect();
+    let mut out = builder.build_multi(&outputs);
+    out.schema = circuit.schema.clone();
+    out
+}
+
+// ---------------------------------------------------------------------------
+// hide_public_consts
+// ---------------------------------------------------------------------------
+
+/// Replace every constant that `POOL` would hold in the clear with a masked
+/// `SecretConst`:
+///
+/// | Before | After | Cost |
+/// |--------|-------|------|
+/// | `PublicConst(k)` | `SecretConst(k)` | free |
+/// | `XorConst(a, k)` | `Xor(a, SecretConst(k))` | free |
+/// | `AndConst(a, k)` | `And(a, SecretConst(k))` | one triple |
+///
+/// `POOL` then holds `k ^ m` for a fresh mask `m` instead of `k`, so the
+/// `1 << i` bit selectors of a carry chain, and with them the adder
+/// structure, no longer show.  Each gadget gets its own `SecretConst`, so
+/// repeated constants do not repeat in `POOL` either.
+///
+/// An `AndConst`'s `m` becomes an AND operand mask, which its triple bakes
+/// only as shares (see `MaskedCircuit::bake`): were it baked verbatim,
+/// `POOL` would give `k` back as the XOR of two entries.  As it is, no XOR
+/// of up to three entries is `k`, and [`remask_operands`] moves the
+/// operand off `m` altogether.  Adders are where the cost lands: `add32`
+/// selects each carry bit with `AndConst`, so `a + b` goes from 31 triples
+/// to 94.
+pub fn hide_public_consts(circuit: &Circuit) -> Circuit {
+    let mut builder = Builder::new();
+    let mut remap: HashMap<WireId, WireId> = HashMap::new();
+
+    for g in &circuit.gadgets {
+        let t = |id: WireId| remap[&id];
+
+        let new_out = match g {
+            Gadget::PublicConst { k, .. } => builder.secret_const(*k),
+            Gadget::XorConst { a, k, .. } => {
+                let k = builder.secret_const(*k);
+                builder.xor(t(*a), k)
+            }
+            Gadget::AndConst { a, k, .. } => {
+                let k = builder.secret_const(*k);
+                builder.and(t(*a), k)
+            }
+            Gadget::Ingest { name, bytes: None, .. } => builder.ingest(name),
+            Gadget::Ingest { bytes: Some(bw), .. } => builder.ingest_word(&bw.param, bw.len, bw.word),
+            Gadget::SecretConst { k, .. }   => builder.secret_const(*k),
+            Gadget::Xor { a, b, .. }        => builder.xor(t(*a), t(*b)),
+            Gadget::Rotl { a, r, .. }       => builder.rotl(t(*a), *r),
+            Gadget::Shl { a, r, .. }        => builder.shl(t(*a), *r),
+            Gadget::Shr { a, r, .. }        => builder.shr(t(*a), *r),
+            Gadget::And { a, b, .. }        => builder.and(t(*a), t(*b)),
+            Gadget::Remask { a, .. }        => builder.remask(t(*a)),
+            Gadget::Egress { .. }           => continue,
+        };
+        remap.insert(g.out().expect("non-egress gadget has an output"), new_out);
+    }
+
+    let outputs: Vec<WireId> = circuit.egress.iter().map(|w| remap[w]).collect();
+    let mut out = builder.build_multi(&outputs);
+    out.schema = circuit.schema.clone();
+    out
+}
+
+// ---------------------------------------------------------------------------
+// remask_operands
+// ---------------------------------------------------------------------------
+
+/// A mask that takes at least this many `POOL` entries to rebuild is left
+/// alone by [`remask_operands`].
+const REBUILD_COST: usize = 3;
+
+/// Insert fresh `Remask`s in front of every operand of every `And` and
+/// `Egress` gadget.
+///
+/// An egress bakes its wire's mask into `POOL`, and an AND's operand masks
+/// are three entries of its triple XORed together.  Without this pass an
+/// AND fed straight from an ingest computes with that ingest's mask, so the
+/// triple ties the input to its unmasking.  Here every such operand is first
+/// moved to a mask drawn for that one use, so no two triples share a mask
+/// either.
+///
+/// A remask only moves the problem one delta along: the new mask is the old
+/// one XOR the delta.  So the pass tracks, per wire, how many pool entries
+/// rebuild its mask (none for a constant's or an `AndConst`-cut mask, one
+/// for an ingest's, [`REBUILD_COST`] for anything behind an AND output or a
+/// secret constant) and chains remasks until no XOR of two entries gives an
+/// operand mask away.
+///
+/// Deterministic: costs one to three pool entries per operand and draws no
+/// randomness.
+pub fn remask_operands(circuit: &Circuit) -> Circuit {
+    let mut builder = Builder::new();
+    let mut remap: HashMap<WireId, WireId> = HashMap::new();
+    // Entries needed to rebuild each new wire's mask, capped at REBUILD_COST.
+    let mut cost: HashMap<WireId, usize> = HashMap::new();
+
+    let fresh = |builder: &mut Builder, cost: &HashMap<WireId, usize>, w: WireId| {
+        let mut out = builder.remask(w);
+        for _ in cost[&w] + 1..REBUILD_COST {
+            out = builder.remask(out);
+        }
+        out
+    };
+
+    for g in &circuit.gadgets {
+        let t = |id: WireId| remap[&id];
+        let c = |id: WireId| cost[&remap[&id]];
+
+        let (new_out, new_cost) = match g {
+            Gadget::Ingest { name, bytes: None, .. } => (builder.ingest(name), 1),
+            Gadget::Ingest { bytes: Some(bw), .. } => (builder.ingest_word(&bw.param, bw.len, bw.word), 1),
+            Gadget::PublicConst { k, .. }   => (builder.public_const(*k), 0),
+            Gadget::SecretConst { k, .. }   => (builder.secret_const(*k), REBUILD_COST),
+            Gadget::Xor { a, b, .. }        => (builder.xor(t(*a), t(*b)), (c(*a) + c(*b)).min(REBUILD_COST)),
+            Gadget::XorConst { a, k, .. }   => (builder.xor_const(t(*a), *k), c(*a)),
+            Gadget::AndConst { a, k, .. }   => (builder.and_const(t(*a), *k), 0),
+            Gadget::Rotl { a, r, .. }       => (builder.rotl(t(*a), *r), c(*a)),
+            Gadget::Shl { a, r, .. }        => (builder.shl(t(*a), *r), c(*a)),
+            Gadget::Shr { a, r, .. }        => (builder.shr(t(*a), *r), c(*a)),
+            Gadget::And { a, b, .. } => {
+                let a = fresh(&mut builder, &cost, t(*a));
+                let b = fresh(&mut builder, &cost, t(*b));
+                (builder.and(a, b), REBUILD_COST)
+            }
+            Gadget::Remask { a, .. }        => (builder.remask(t(*a)), (c(*a) + 1).min(REBUILD_COST)),
+            Gadget::Egress { .. }           => continue,
+        };
+        remap.insert(g.out().expect("non-egress gadget has an output"), new_out);
+        cost.insert(new_out, new_cost);
+    }
+
+    let outputs: Vec<WireId> = circuit.egress.iter().map(|w| fresh(&mut builder, &cost, remap[w])).collect();
+    let mut out = builder.build_multi(&outputs);
+    out.schema = circuit.schema.clone();
+    out
 }
 
 // ---------------------------------------------------------------------------
@@ -159,6 +306,8 @@ pub fn split_secret_consts(circuit: &Circuit, rng: &mut impl RngCore, rate: u32)
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::collections::HashSet;
+
     use rand::SeedableRng;
 
     use crate::expr::Expr;
@@ -169,10 +318,10 @@ mod tests {
         let input_map: HashMap<String, u32> = inputs.iter().map(|&(k, v)| (k.to_string(), v)).collect();
 
         let orig_vals = circuit.eval(&input_map);
-        assert_eq!(orig_vals[&circuit.egress], expected, "original circuit wrong");
+        assert_eq!(orig_vals[&circuit.egress[0]], expected, "original circuit wrong");
 
         let new_vals = transformed.eval(&input_map);
-        assert_eq!(new_vals[&transformed.egress], expected, "transformed circuit wrong");
+        assert_eq!(new_vals[&transformed.egress[0]], expected, "transformed circuit wrong");
 
         for seed in 0u64..4 {
             let mut rng = rand::rngs::StdRng::seed_from_u64(seed);
@@ -196,7 +345,7 @@ mod tests {
 
         for seed in 0u64..8 {
             let mut rng = rand::rngs::StdRng::seed_from_u64(seed);
-            let transformed = inject_remasks(&circuit, &mut rng, 3);
+            let transformed = inject_remasks(&circuit, &mut rng, Chance::new(1, 3));
             verify_transform(&circuit, &transformed, &[("a", av), ("b", bv)], expected);
         }
     }
@@ -210,7 +359,7 @@ mod tests {
 
         // With rate=1 every eligible wire gets remasked — there must be more gadgets.
         let mut rng = rand::rngs::StdRng::seed_from_u64(0);
-        let transformed = inject_remasks(&circuit, &mut rng, 1);
+        let transformed = inject_remasks(&circuit, &mut rng, Chance::ALWAYS);
         let remask_count = transformed.gadgets.iter()
             .filter(|g| matches!(g, Gadget::Remask { .. }))
             .count();
@@ -243,11 +392,11 @@ mod tests {
             &[("a", av), ("b", bv), ("c", cv), ("d", dv)]
                 .iter().map(|&(k, v)| (k.to_string(), v)).collect()
         );
-        let expected = orig_vals[&circuit.egress];
+        let expected = orig_vals[&circuit.egress[0]];
 
         for seed in 0u64..4 {
             let mut rng = rand::rngs::StdRng::seed_from_u64(seed);
-            let transformed = inject_remasks(&circuit, &mut rng, 4);
+            let transformed = inject_remasks(&circuit, &mut rng, Chance::new(1, 4));
             verify_transform(&circuit, &transformed,
                 &[("a", av), ("b", bv), ("c", cv), ("d", dv)], expected);
         }
@@ -267,7 +416,7 @@ mod tests {
 
         for seed in 0u64..8 {
             let mut rng = rand::rngs::StdRng::seed_from_u64(seed);
-            let transformed = split_secret_consts(&circuit, &mut rng, 1);
+            let transformed = split_secret_consts(&circuit, &mut rng, Chance::ALWAYS);
             verify_transform(&circuit, &transformed, &[("a", av), ("b", bv)], expected);
         }
     }
@@ -282,7 +431,7 @@ mod tests {
 
         // rate=1 always splits — should produce two SecretConst gadgets + one Xor.
         let mut rng = rand::rngs::StdRng::seed_from_u64(0);
-        let transformed = split_secret_consts(&circuit, &mut rng, 1);
+        let transformed = split_secret_consts(&circuit, &mut rng, Chance::ALWAYS);
         let secret_count_after = transformed.gadgets.iter()
             .filter(|g| matches!(g, Gadget::SecretConst { .. }))
             .count();
@@ -293,6 +442,139 @@ mod tests {
             "split should insert a Xor gadget");
     }
 
+    /// Ingest, secret-constant and AND-operand masks that a pool entry, or
+    /// the XOR of two pool entries, equals, as `(gadget indices, value)`.
+    /// An ingest's own entry is its mask by construction and only counts
+    /// when that mask is also an AND operand's.  Three entries of one AND
+    /// triple XOR to an operand mask, so those count when they give back
+    /// an ingest or secret-constant mask.
+    fn exposed_masks(circuit: &Circuit, masked: &MaskedCircuit) -> Vec<(Vec<usize>, u32)> {
+        let secret: HashSet<u32> = MaskedCircuit::secret_gens(circuit).iter().map(|g| masked.gen_values[g]).collect();
+        let ingest: HashSet<u32> = circuit.gadgets.iter()
+            .filter_map(|g| match g {
+                Gadget::Ingest { out, .. } => Some(masked.masks[out]),
+                _ => None,
+            })
+            .collect();
+        let operand: HashSet<u32> = circuit.gadgets.iter()
+            .flat_map(|g| match g {
+                Gadget::And { a, b, .. } => vec![masked.masks[a], masked.masks[b]],
+                _ => vec![],
+            })
+            .collect();
+        let pool: Vec<(usize, u32)> = masked.baked_consts().enumerate()
+            .flat_map(|(idx, consts)| consts.iter().map(move |&v| (idx, v)))
+            .collect();
+
+        let mut exposed = vec![];
+        for (i, &(gi, vi)) in pool.iter().enumerate() {
+            let own_ingest = matches!(circuit.gadgets[gi], Gadget::Ingest { .. });
+            if operand.contains(&vi) || (!own_ingest && secret.contains(&vi)) {
+                exposed.push((vec![gi], vi));
+            }
+            for &(gj, vj) in &pool[i + 1..] {
+                if operand.contains(&(vi ^ vj)) || secret.contains(&(vi ^ vj)) {
+                    exposed.push((vec![gi, gj], vi ^ vj));
+                }
+            }
+        }
+        for (idx, consts) in masked.baked_consts().enumerate() {
+            if !matches!(circuit.gadgets[idx], Gadget::And { .. }) {
+                continue;
+            }
+            for i in 0..consts.len() {
+                for j in i + 1..consts.len() {
+                    for &vk in &consts[j + 1..] {
+                        let v = consts[i] ^ consts[j] ^ vk;
+                        if ingest.contains(&v) || secret.contains(&v) {
+                            exposed.push((vec![idx; 3], v));
+                        }
+                    }
+                }
+            }
+        }
+        exposed
+    }
+
+    #[test]
+    fn remask_operands_hides_ingest_and_secret_masks() {
+        let a = Expr::input("a");
+        let b = Expr::input("b");
+        let c = Expr::secret_const(0x9e37_79b9);
+        let msg = Expr::input_bytes("msg", 4);
+        let exprs = [
+            Expr::rotl(Expr::xor(Expr::or(a.clone(), b.clone()), c.clone()), 5),
+            Expr::and(Expr::and(a.clone(), c.clone()), Expr::rotl_var(msg[0].clone(), b.clone())),
+            Expr::add(a.clone(), a.clone()),
+            Expr::add(a.clone(), b.clone()),
+            Expr::mux(
+                Expr::ltu(a.clone(), b.clone()),
+                Expr::shr(msg[0].clone(), 3),
+                Expr::sub(b.clone(), Expr::public_const(9)),
+            ),
+            a.clone(),
+        ];
+        let inputs: HashMap<String, u32> = [("a".to_string(), 0x1234_5678u32), ("b".to_string(), 0xDEAD_BEEF)]
+            .into_iter().chain(crate::circuit::byte_inputs("msg", b"\x01\x02\x03\x04"))
+            .collect();
+
+        // The plain lowering of (a | b) feeds ingests straight into an AND.
+        let demo = lower_to_circuit(&exprs[0]);
+        let masked = MaskedCircuit::from_circuit(&demo, &mut rand::rngs::StdRng::seed_from_u64(0));
+        let exposed = exposed_masks(&demo, &masked);
+        assert!(exposed.iter().any(|(at, _)| at.len() == 3), "{exposed:?}");
+
+        for expr in &exprs {
+            let circuit = lower_to_circuit(expr);
+            let expected = circuit.eval_outputs(&inputs);
+            let hidden = remask_operands(&circuit);
+            assert_eq!(hidden.eval_outputs(&inputs), expected);
+            for seed in 0u64..8 {
+                let masked = MaskedCircuit::from_circuit(&hidden, &mut rand::rngs::StdRng::seed_from_u64(seed));
+                assert_eq!(exposed_masks(&hidden, &masked), vec![], "{expr:?} seed {seed}");
+                assert_eq!(masked.eval_multi(&hidden, &inputs).1, expected);
+            }
+        }
+    }
+
+    #[test]
+    fn hide_public_consts_clears_pool_of_constants() {
+        let a = Expr::input("a");
+        let b = Expr::input("b");
+        let expr = Expr::xor(Expr::add(a, Expr::not(b)), Expr::public_const(0x0bad_f00d));
+        let inputs: HashMap<String, u32> = [("a".to_string(), 0x1234_5678u32), ("b".to_string(), 0xDEAD_BEEF)].into();
+        let circuit = lower_to_circuit(&expr);
+        let expected = circuit.eval_outputs(&inputs);
+
+        let public: Vec<u32> = circuit.gadgets.iter()
+            .filter_map(|g| match g {
+                Gadget::PublicConst { k, .. } | Gadget::XorConst { k, .. } | Gadget::AndConst { k, .. } => Some(*k),
+                _ => None,
+            })
+            .collect();
+        assert!(public.contains(&(1 << 7)) && public.contains(&0xffff_ffff));
+
+        let hidden = remask_operands(&hide_public_consts(&circuit));
+        assert!(!hidden.gadgets.iter().any(|g| matches!(g,
+            Gadget::PublicConst { .. } | Gadget::XorConst { .. } | Gadget::AndConst { .. })));
+        assert_eq!(hidden.eval_outputs(&inputs), expected);
+        for seed in 0u64..4 {
+            let masked = MaskedCircuit::from_circuit(&hidden, &mut rand::rngs::StdRng::seed_from_u64(seed));
+            let pool: Vec<u32> = masked.baked_consts().flatten().copied().collect();
+            assert!(!pool.iter().any(|v| public.contains(v)), "seed {seed}");
+            // Nor the XOR of two or three entries: the constant's mask only
+            // reaches the triple as shares.
+            let one_away: HashSet<u32> = public.iter()
+                .flat_map(|&k| pool.iter().map(move |&e| e ^ k).chain([k]))
+                .collect();
+            for (i, x) in pool.iter().enumerate() {
+                assert!(!pool[i + 1..].iter().any(|y| one_away.contains(&(x ^ y))), "seed {seed}: {x:#010x}");
+            }
+            assert_eq!(exposed_masks(&hidden, &masked), vec![]);
+            assert_eq!(masked.eval_multi(&hidden, &inputs).1, expected);
+        }
+    }
+
     #[test]
     fn split_secret_consts_no_secret_consts_is_noop() {
         // A circuit with no SecretConst gadgets should be structurally unchanged.
@@ -301,7 +583,7 @@ mod tests {
         let gadget_count_before = circuit.gadgets.len();
 
         let mut rng = rand::rngs::StdRng::seed_from_u64(0);
-        let transformed = split_secret_consts(&circuit, &mut rng, 1);
+        let transformed = split_secret_consts(&circuit, &mut rng, Chance::ALWAYS);
         assert_eq!(transformed.gadgets.len(), gadget_count_before,
             "no SecretConst gadgets means nothing to split");
     }
@@ -0,0 +1,777 @@
+//! `.xorpl` text syntax for mixing functions.
+//!
+//! A reviewer-friendly alternative to building `Expr` trees in Rust.
+//! [`parse`] turns source text into the output roots; [`print()`] turns
+//! roots back into canonical source.  `parse(print(roots))` lowers to the
+//! same circuit, and printing it again gives the same text.
+//!
+//! # Syntax
+//!
+//! ```text
+//! // ChaCha-style mixing step
+//! input a, b, nonce: bytes[7];
+//! let k  = secret 0x9e3779b9;
+//! let t  = (a | b) ^ k;
+//! let u  = t + nonce[1];
+//! output u <<< 7, mux(eq(t, a), u, public 0x1);
+//! ```
+//!
+//! | Form | `Expr` |
+//! |------|--------|
+//! | `input a, b;` | `Input` — one node per name, shared by every use |
+//! | `input m: bytes[N];` then `m[i]` | `InputWord` — word `i` of an `N`-byte input (little-endian, not byte `i`) |
+//! | `public N` / `secret N` | `PublicConst` / `SecretConst` (decimal or `0x` hex) |
+//! | `let x = e;` | Names `e`; every use of `x` is the same `Rc` |
+//! | `a \| b`, `a ^ b`, `a & b` | `Or`, `Xor`, `And` |
+//! | `a <<< 5`, `a >>> 5` | `Rotl` (static amount; `>>>` is `Expr::rotr`) |
+//! | `a <<< e` | `RotlVar` — any non-literal amount |
+//! | `a << 5`, `a >> 5` | `Shl`, `Shr` |
+//! | `a + b`, `a - b`, `a * b` | `Add`, `Sub`, `Mul` |
+//! | `!a`, `-a` | `Not`, `Neg` |
+//! | `mux(c, t, f)`, `eq(a, b)`, `ltu(a, b)`, `is_zero(a)` | `Mux`, `Eq`, `Ltu`, `IsZero` |
+//! | `output e, ...;` | The roots, in order — must be the last statement |
+//!
+//! Binary operators follow Rust precedence, loosest first:
+//! `|`, `^`, `&`, shifts/rotations, `+ -`, `*`; all are left-associative.
+//! `//` starts a comment.
+//!
+//! # Printing
+//!
+//! [`print()`] lists inputs sorted by name, then binds every node used more
+//! than once (other than inputs) to `let tN`, in post-order, so sharing
+//! survives the round trip.  Inputs are identified by name: two separately
+//! constructed `Expr::input("a")` nodes print as one input.  Lowering merges
+//! them the same way, but [`exprs_digest`](crate::expr::exprs_digest) does
+//! not, so such roots keep their circuit through the round trip but not
+//! their digest.  Roots from [`parse`] share one node per input and keep
+//! both.
+
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::fmt;
+use std::rc::Rc;
+
+use crate::expr::Expr;
+
+const KEYWORDS: &[&str] =
+    &["input", "let", "output", "public", "secret", "bytes", "mux", "eq", "ltu", "is_zero"];
+
+// ---------------------------------------------------------------------------
+// Errors
+// ---------------------------------------------------------------------------
+
+/// A parse failure at a 1-based line and column.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub col: usize,
+    pub message: String,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}: {}", self.line, self.col, self.message)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+// ---------------------------------------------------------------------------
+// Lexer
+// ---------------------------------------------------------------------------
+
+#[derive(Clone, Debug, PartialEq)]
+enum Tok {
+    Ident(String),
+    Int(u64),
+    Punct(&'static str),
+    Eof,
+}
+
+#[derive(Clone, Debug)]
+struct Token {
+    tok: Tok,
+    line: usize,
+    col: usize,
+}
+
+// Longest first so `<<<` wins over `<<`.
+const PUNCTS: &[&str] = &[
+    "<<<", ">>>", "<<", ">>", "|", "^", "&", "!", "+", "-", "*",
+    "(", ")", "[", "]", ",", ";", "=", ":",
+];
+
+fn lex(src: &str) -> Result<Vec<Token>, ParseError> {
+    let chars: Vec<char> = src.chars().collect();
+    let mut tokens = Vec::new();
+    let (mut i, mut line, mut col) = (0, 1, 1);
+
+    while i < chars.len() {
+        let c = chars[i];
+        if c == '\n' {
+            i += 1;
+            line += 1;
+            col = 1;
+            continue;
+        }
+        if c.is_whitespace() {
+            i += 1;
+            col += 1;
+            continue;
+        }
+        if c == '/' && chars.get(i + 1) == Some(&'/') {
+            while i < chars.len() && chars[i] != '\n' {
+                i += 1;
+            }
+            continue;
+        }
+
+        let start = i;
+        let tok = if c.is_ascii_alphabetic() || c == '_' {
+            while i < chars.len() && (chars[i].is_ascii_alphanumeric() || chars[i] == '_') {
+                i += 1;
+            }
+            Tok::Ident(chars[start..i].iter().collect())
+        } else if c.is_ascii_digit() {
+            while i < chars.len() && (chars[i].is_ascii_alphanumeric() || chars[i] == '_') {
+                i += 1;
+            }
+            let text: String = chars[start..i].iter().filter(|&&c| c != '_').collect();
+            let value = match text.strip_prefix("0x").or_else(|| text.strip_prefix("0X")) {
+                Some(hex) => u64::from_str_radix(hex, 16),
+                None      => text.parse(),
+            };
+            match value {
+                Ok(v) => Tok::Int(v),
+                Err(_) => return Err(ParseError {
+                    line, col,
+                    message: format!("invalid integer literal `{}`", chars[start..i].iter().collect::<String>()),
+                }),
+            }
+        } else {
+            let rest: String = chars[i..chars.len().min(i + 3)].iter().collect();
+            match PUNCTS.iter().find(|p| rest.starts_with(*p)) {
+                Some(p) => {
+                    i += p.len();
+                    Tok::Punct(p)
+                }
+                None => return Err(ParseError { line, col, message: format!("unexpected character `{c}`") }),
+            }
+        };
+        tokens.push(Token { tok, line, col });
+        col += i - start;
+    }
+    tokens.push(Token { tok: Tok::Eof, line, col });
+    Ok(tokens)
+}
+
+// ---------------------------------------------------------------------------
+// Parser
+// ---------------------------------------------------------------------------
+
+/// Parse `.xorpl` source into its output roots, in `output` order.
+pub fn parse(src: &str) -> Result<Vec<Rc<Expr>>, ParseError> {
+    let mut p = Parser { tokens: lex(src)?, pos: 0, scope: HashMap::new(), words: HashMap::new() };
+    p.program()
+}
+
+enum Binding {
+    Value(Rc<Expr>),
+    /// A byte input of the given length; its words live in `Parser::words`.
+    Bytes(usize),
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    pos: usize,
+    scope: HashMap<String, Binding>,
+    words: HashMap<(String, usize), Rc<Expr>>,
+}
+
+/// Binary operators by precedence level, loosest first.
+const LEVELS: &[&[&str]] = &[&["|"], &["^"], &["&"], &["<<<", ">>>", "<<", ">>"], &["+", "-"], &["*"]];
+const SHIFT_LEVEL: usize = 3;
+
+impl Parser {
+    fn peek(&self) -> &Token {
+        &self.tokens[self.pos]
+    }
+
+    fn next(&mut self) -> Token {
+        let t = self.tokens[self.pos].clone();
+        if t.tok != Tok::Eof {
+            self.pos += 1;
+        }
+        t
+    }
+
+    fn error_at<T>(&self, t: &Token, message: String) -> Result<T, ParseError> {
+        Err(ParseError { line: t.line, col: t.col, message })
+    }
+
+    fn describe(tok: &Tok) -> String {
+        match tok {
+            Tok::Ident(s) => format!("`{s}`"),
+            Tok::Int(v)   => format!("`{v}`"),
+            Tok::Punct(p) => format!("`{p}`"),
+            Tok::Eof      => "end of input".to_string(),
+        }
+    }
+
+    fn is_punct(&self, p: &str) -> bool {
+        matches!(&self.peek().tok, Tok::Punct(q) if *q == p)
+    }
+
+    fn expect(&mut self, p: &str) -> Result<(), ParseError> {
+        if self.is_punct(p) {
+            self.next();
+            Ok(())
+        } else {
+            let t = self.peek().clone();
+            self.error_at(&t, format!("expected `{p}`, found {}", Self::describe(&t.tok)))
+        }
+    }
+
+    fn int(&mut self, what: &str) -> Result<(u64, Token), ParseError> {
+        let t = self.next();
+        match t.tok {
+            Tok::Int(v) => Ok((v, t)),
+            _ => self.error_at(&t, format!("expected {what}, found {}", Self::describe(&t.tok))),
+        }
+    }
+
+    fn u32_value(&mut self, what: &str) -> Result<u32, ParseError> {
+        let (v, t) = self.int(what)?;
+        u32::try_from(v).or_else(|_| self.error_at(&t, format!("{v} does not fit in 32 bits")))
+    }
+
+    /// A fresh, non-keyword identifier for `input` or `let`.
+    fn binder(&mut self) -> Result<(String, Token), ParseError> {
+        let t = self.next();
+        let name = match &t.tok {
+            Tok::Ident(s) if !KEYWORDS.contains(&s.as_str()) => s.clone(),
+            Tok::Ident(s) => return self.error_at(&t, format!("`{s}` is a keyword")),
+            other => return self.error_at(&t, format!("expected a name, found {}", Self::describe(other))),
+        };
+        if self.scope.contains_key(&name) {
+            return self.error_at(&t, format!("`{name}` is already defined"));
+        }
+        Ok((name, t))
+    }
+
+    fn program(&mut self) -> Result<Vec<Rc<Expr>>, ParseError> {
+        loop {
+            let t = self.peek().clone();
+            match &t.tok {
+                Tok::Ident(kw) if kw == "input" => {
+                    self.next();
+                    self.input_decl()?;
+                }
+                Tok::Ident(kw) if kw == "let" => {
+                    self.next();
+                    let (name, _) = self.binder()?;
+                    self.expect("=")?;
+                    let value = self.expr()?;
+                    self.expect(";")?;
+                    self.scope.insert(name, Binding::Value(value));
+                }
+                Tok::Ident(kw) if kw == "output" => {
+                    self.next();
+                    let mut outputs = vec![self.expr()?];
+                    while self.is_punct(",") {
+                        self.next();
+                        outputs.push(self.expr()?);
+                    }
+                    self.expect(";")?;
+                    let t = self.peek().clone();
+                    if t.tok != Tok::Eof {
+                        return self.error_at(&t, "`output` must be the last statement".to_string());
+                    }
+                    return Ok(outputs);
+                }
+                Tok::Eof => return self.error_at(&t, "missing `output` statement".to_string()),
+                other => {
+                    return self.error_at(
+                        &t,
+                        format!("expected `input`, `let` or `output`, found {}", Self::describe(other)),
+                    );
+                }
+            }
+        }
+    }
+
+    fn input_decl(&mut self) -> Result<(), ParseError> {
+        loop {
+            let (name, _) = self.binder()?;
+            if self.is_punct(":") {
+                self.next();
+                let t = self.next();
+                if t.tok != Tok::Ident("bytes".to_string()) {
+                    return self.error_at(&t, format!("expected `bytes`, found {}", Self::describe(&t.tok)));
+                }
+                self.expect("[")?;
+                let (len, lt) = self.int("a byte length")?;
+                if len == 0 {
+                    return self.error_at(&lt, format!("byte input `{name}` must be non-empty"));
+                }
+                self.expect("]")?;
+                self.scope.insert(name, Binding::Bytes(len as usize));
+            } else {
+                let node = Expr::input(&name);
+                self.scope.insert(name, Binding::Value(node));
+            }
+            if !self.is_punct(",") {
+                break;
+            }
+            self.next();
+        }
+        self.expect(";")
+    }
+
+    fn expr(&mut self) -> Result<Rc<Expr>, ParseError> {
+        self.binary(0)
+    }
+
+    fn binary(&mut self, level: usize) -> Result<Rc<Expr>, ParseError> {
+        if level == LEVELS.len() {
+            return self.unary();
+        }
+        let mut lhs = self.binary(level + 1)?;
+        loop {
+            let op = match &self.peek().tok {
+                Tok::Punct(p) if LEVELS[level].contains(p) => *p,
+                _ => return Ok(lhs),
+            };
+            self.next();
+            if level == SHIFT_LEVEL {
+                lhs = self.shift(lhs, op)?;
+                continue;
+            }
+            let rhs = self.binary(level + 1)?;
+            lhs = match op {
+                "|" => Expr::or(lhs, rhs),
+                "^" => Expr::xor(lhs, rhs),
+                "&" => Expr::and(lhs, rhs),
+                "+" => Expr::add(lhs, rhs),
+                "-" => Expr::sub(lhs, rhs),
+                "*" => Expr::mul(lhs, rhs),
+                _ => unreachable!("operator {op} has no level"),
+            };
+        }
+    }
+
+    /// The right-hand side of a shift or rotation: a literal amount, or (for
+    /// `<<<` only) an expression giving a data-dependent rotation.
+    fn shift(&mut self, lhs: Rc<Expr>, op: &str) -> Result<Rc<Expr>, ParseError> {
+        if matches!(self.peek().tok, Tok::Int(_)) {
+            let r = self.u32_value("a shift amount")?;
+            return Ok(match op {
+                "<<<" => Expr::rotl(lhs, r),
+                ">>>" => Expr::rotr(lhs, r),
+                "<<"  => Expr::shl(lhs, r),
+                ">>"  => Expr::shr(lhs, r),
+                _ => unreachable!("{op} is not a shift"),
+            });
+        }
+        if op != "<<<" {
+            let t = self.peek().clone();
+            return self.error_at(&t, format!("`{op}` needs a literal amount"));
+        }
+        let amount = self.binary(SHIFT_LEVEL + 1)?;
+        Ok(Expr::rotl_var(lhs, amount))
+    }
+
+    fn unary(&mut self) -> Result<Rc<Expr>, ParseError> {
+        if self.is_punct("!") {
+            self.next();
+            return Ok(Expr::not(self.unary()?));
+        }
+        if self.is_punct("-") {
+            self.next();
+            return Ok(Expr::neg(self.unary()?));
+        }
+        self.atom()
+    }
+
+    fn args(&mut self, n: usize) -> Result<Vec<Rc<Expr>>, ParseError> {
+        self.expect("(")?;
+        let mut args = Vec::with_capacity(n);
+        for i in 0..n {
+            if i > 0 {
+                self.expect(",")?;
+            }
+            args.push(self.expr()?);
+        }
+        self.expect(")")?;
+        Ok(args)
+    }
+
+    fn atom(&mut self) -> Result<Rc<Expr>, ParseError> {
+        let t = self.next();
+        match &t.tok {
+            Tok::Punct("(") => {
+                let e = self.expr()?;
+                self.expect(")")?;
+                Ok(e)
+            }
+            Tok::Ident(kw) if kw == "public" => Ok(Expr::public_const(self.u32_value("a constant")?)),
+            Tok::Ident(kw) if kw == "secret" => Ok(Expr::secret_const(self.u32_value("a constant")?)),
+            Tok::Ident(kw) if kw == "mux" => {
+                let [c, on_true, on_false]: [Rc<Expr>; 3] = self.args(3)?.try_into().unwrap();
+                Ok(Expr::mux(c, on_true, on_false))
+            }
+            Tok::Ident(kw) if kw == "eq" || kw == "ltu" => {
+                let [a, b]: [Rc<Expr>; 2] = self.args(2)?.try_into().unwrap();
+                Ok(if kw == "eq" { Expr::eq(a, b) } else { Expr::ltu(a, b) })
+            }
+            Tok::Ident(kw) if kw == "is_zero" => {
+                let [a]: [Rc<Expr>; 1] = self.args(1)?.try_into().unwrap();
+                Ok(Expr::is_zero(a))
+            }
+            Tok::Ident(name) if KEYWORDS.contains(&name.as_str()) => {
+                self.error_at(&t, format!("unexpected keyword `{name}`"))
+            }
+            Tok::Ident(name) => match self.scope.get(name) {
+                Some(Binding::Value(e)) => {
+                    let e = e.clone();
+                    if self.is_punct("[") {
+                        let t = self.peek().clone();
+                        return self.error_at(&t, format!("`{name}` is not a byte input"));
+                    }
+                    Ok(e)
+                }
+                Some(&Binding::Bytes(len)) => {
+                    if !self.is_punct("[") {
+                        return self.error_at(&t, format!("byte input `{name}` must be indexed by word, as `{name}[i]`"));
+                    }
+                    self.next();
+                    let (word, wt) = self.int("a word index")?;
+                    self.expect("]")?;
+                    let n_words = len.div_ceil(4);
+                    if word as usize >= n_words {
+                        return self.error_at(
+                            &wt,
+                            format!("word {word} out of range for `{name}` ({len} bytes, {n_words} words)"),
+                        );
+                    }
+                    let word = word as usize;
+                    let node = self.words.entry((name.clone(), word))
+                        .or_insert_with(|| Rc::new(Expr::InputWord { name: name.clone(), len, word }));
+                    Ok(node.clone())
+                }
+                None => self.error_at(&t, format!("undefined name `{name}`")),
+            },
+            other => self.error_at(&t, format!("expected an expression, found {}", Self::describe(other))),
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Printer
+// ---------------------------------------------------------------------------
+
+// Precedence of a printed form; matches `LEVELS` (offset by one).
+const P_OR: u8 = 1;
+const P_XOR: u8 = 2;
+const P_AND: u8 = 3;
+const P_SHIFT: u8 = 4;
+const P_ADD: u8 = 5;
+const P_MUL: u8 = 6;
+const P_UNARY: u8 = 7;
+const P_ATOM: u8 = 8;
+
+/// Print `roots` as canonical `.xorpl` source.
+///
+/// # Panics
+///
+/// If one name is used both as a `u32` input and a byte input, or as byte
+/// inputs of different lengths — such a DAG cannot be lowered either.  Also
+/// if an input name is a keyword or not an identifier (`event-type`), since
+/// the syntax has no way to spell it.
+pub fn print(roots: &[Rc<Expr>]) -> String {
+    // Post-order walk counting references to each node.
+    let mut uses: HashMap<*const Expr, usize> = HashMap::new();
+    let mut order: Vec<Rc<Expr>> = Vec::new();
+    let mut visited: HashSet<*const Expr> = HashSet::new();
+    let mut stack: Vec<(Rc<Expr>, bool)> = roots.iter().rev().map(|r| (r.clone(), false)).collect();
+    for r in roots {
+        *uses.entry(Rc::as_ptr(r)).or_default() += 1;
+    }
+    while let Some((node, children_done)) = stack.pop() {
+        if children_done {
+            order.push(node);
+            continue;
+        }
+        if !visited.insert(Rc::as_ptr(&node)) {
+            continue;
+        }
+        stack.push((node.clone(), true));
+        for child in children(&node).into_iter().rev() {
+            *uses.entry(Rc::as_ptr(&child)).or_default() += 1;
+            stack.push((child, false));
+        }
+    }
+
+    let mut words: BTreeSet<String> = BTreeSet::new();
+    let mut bytes: BTreeMap<String, usize> = BTreeMap::new();
+    for node in &order {
+        if let Expr::Input(name) | Expr::InputWord { name, .. } = node.as_ref() {
+            assert!(is_name(name), "input name {name:?} cannot be written in .xorpl source");
+        }
+        match node.as_ref() {
+            Expr::Input(name) => {
+                assert!(!bytes.contains_key(name), "`{name}` is both a u32 and a byte input");
+                words.insert(name.clone());
+            }
+            Expr::InputWord { name, len, .. } => {
+                assert!(!words.contains(name), "`{name}` is both a u32 and a byte input");
+                let prev = *bytes.entry(name.clone()).or_insert(*len);
+                assert_eq!(prev, *len, "byte input `{name}` has inconsistent lengths");
+            }
+            _ => {}
+        }
+    }
+
+    let mut printer = Printer { names: HashMap::new() };
+    let mut out = String::new();
+    if !words.is_empty() {
+        let list: Vec<&str> = words.iter().map(String::as_str).collect();
+        out.push_str(&format!("input {};\n", list.join(", ")));
+    }
+    for (name, len) in &bytes {
+        out.push_str(&format!("input {name}: bytes[{len}];\n"));
+    }
+
+    let mut next = 0usize;
+    for node in &order {
+        let leaf = matches!(node.as_ref(), Expr::Input(_) | Expr::InputWord { .. });
+        if leaf || uses[&Rc::as_ptr(node)] < 2 {
+            continue;
+        }
+        let name = loop {
+            let candidate = format!("t{next}");
+            next += 1;
+            if !words.contains(&candidate) && !bytes.contains_key(&candidate) {
+                break candidate;
+            }
+        };
+        let (text, _) = printer.node(node);
+        out.push_str(&format!("let {name} = {text};\n"));
+        printer.names.insert(Rc::as_ptr(node), name);
+    }
+
+    let outputs: Vec<String> = roots.iter().map(|r| printer.term(r).0).collect();
+    out.push_str(&format!("output {};\n", outputs.join(", ")));
+    out
+}
+
+/// Whether `name` lexes as one identifier that is not a keyword.
+fn is_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    matches!(chars.next(), Some(c) if c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+        && !KEYWORDS.contains(&name)
+}
+
+fn children(e: &Expr) -> Vec<Rc<Expr>> {
+    match e {
+        Expr::Xor(a, b) | Expr::And(a, b) | Expr::Or(a, b) | Expr::Add(a, b)
+        | Expr::Sub(a, b) | Expr::Mul(a, b) | Expr::Eq(a, b) | Expr::Ltu(a, b)
+        | Expr::RotlVar(a, b) => vec![a.clone(), b.clone()],
+        Expr::Not(a) | Expr::Neg(a) | Expr::IsZero(a) | Expr::Rotl(a, _) | Expr::Shl(a, _)
+        | Expr::Shr(a, _) => vec![a.clone()],
+        Expr::Mux { cond, on_true, on_false } => vec![cond.clone(), on_true.clone(), on_false.clone()],
+        Expr::Input(_) | Expr::InputWord { .. } | Expr::PublicConst(_) | Expr::SecretConst(_) => vec![],
+    }
+}
+
+struct Printer {
+    /// `let`-bound nodes.
+    names: HashMap<*const Expr, String>,
+}
+
+impl Printer {
+    /// A reference to `e`: its `let` name if bound, else its inline form.
+    fn term(&self, e: &Rc<Expr>) -> (String, u8) {
+        match self.names.get(&Rc::as_ptr(e)) {
+            Some(name) => (name.clone(), P_ATOM),
+            None => self.node(e),
+        }
+    }
+
+    /// `e` as an operand that must bind at least as tightly as `min`.
+    fn operand(&self, e: &Rc<Expr>, min: u8) -> String {
+        let (text, prec) = self.term(e);
+        if prec >= min { text } else { format!("({text})") }
+    }
+
+    fn binary(&self, a: &Rc<Expr>, op: &str, b: &Rc<Expr>, prec: u8) -> (String, u8) {
+        (format!("{} {op} {}", self.operand(a, prec), self.operand(b, prec + 1)), prec)
+    }
+
+    fn call(&self, f: &str, args: &[&Rc<Expr>]) -> (String, u8) {
+        let args: Vec<String> = args.iter().map(|a| self.term(a).0).collect();
+        (format!("{f}({})", args.join(", ")), P_ATOM)
+    }
+
+    /// The inline form of `e` itself, ignoring any `let` binding of `e`.
+    fn node(&self, e: &Rc<Expr>) -> (String, u8) {
+        match e.as_ref() {
+            Expr::Input(name) => (name.clone(), P_ATOM),
+            Expr::InputWord { name, word, .. } => (format!("{name}[{word}]"), P_ATOM),
+            Expr::PublicConst(k) => (format!("public {k:#010x}"), P_ATOM),
+            Expr::SecretConst(k) => (format!("secret {k:#010x}"), P_ATOM),
+            Expr::Or(a, b)  => self.binary(a, "|", b, P_OR),
+            Expr::Xor(a, b) => self.binary(a, "^", b, P_XOR),
+            Expr::And(a, b) => self.binary(a, "&", b, P_AND),
+            Expr::Add(a, b) => self.binary(a, "+", b, P_ADD),
+            Expr::Sub(a, b) => self.binary(a, "-", b, P_ADD),
+            Expr::Mul(a, b) => self.binary(a, "*", b, P_MUL),
+            Expr::RotlVar(a, b) => self.binary(a, "<<<", b, P_SHIFT),
+            Expr::Rotl(a, r) => (format!("{} <<< {r}", self.operand(a, P_SHIFT)), P_SHIFT),
+            Expr::Shl(a, r)  => (format!("{} << {r}", self.operand(a, P_SHIFT)), P_SHIFT),
+            Expr::Shr(a, r)  => (format!("{} >> {r}", self.operand(a, P_SHIFT)), P_SHIFT),
+            Expr::Not(a) => (format!("!{}", self.operand(a, P_UNARY)), P_UNARY),
+            Expr::Neg(a) => (format!("-{}", self.operand(a, P_UNARY)), P_UNARY),
+            Expr::Eq(a, b)  => self.call("eq", &[a, b]),
+            Expr::Ltu(a, b) => self.call("ltu", &[a, b]),
+            Expr::IsZero(a) => self.call("is_zero", &[a]),
+            Expr::Mux { cond, on_true, on_false } => self.call("mux", &[cond, on_true, on_false]),
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::expr::exprs_digest;
+    use crate::lower::lower_to_circuit_multi;
+    use crate::mixers;
+
+    const SAMPLE: &str = "
+        // mixing step
+        input a, b, nonce: bytes[7];
+        let k = secret 0x9e3779b9;
+        let t = (a | b) ^ k;
+        let u = t + nonce[1] * public 3;
+        output u <<< 7, mux(eq(t, a), u - -b, public 0x1), !u >>> 3 << 2, a <<< b & public 31;
+    ";
+
+    fn err(src: &str) -> (usize, usize, String) {
+        let e = parse(src).unwrap_err();
+        (e.line, e.col, e.message)
+    }
+
+    #[test]
+    fn parse_builds_expected_dag() {
+        let roots = parse(SAMPLE).unwrap();
+        assert_eq!(roots.len(), 4);
+
+        let a = Expr::input("a");
+        let b = Expr::input("b");
+        let nonce = Expr::input_bytes("nonce", 7);
+        let t = Expr::xor(Expr::or(a.clone(), b.clone()), Expr::secret_const(0x9e37_79b9));
+        let u = Expr::add(t.clone(), Expr::mul(nonce[1].clone(), Expr::public_const(3)));
+        let expected = vec![
+            Expr::rotl(u.clone(), 7),
+            Expr::mux(Expr::eq(t, a.clone()), Expr::sub(u.clone(), Expr::neg(b.clone())), Expr::public_const(1)),
+            Expr::shl(Expr::rotr(Expr::not(u), 3), 2),
+            Expr::and(Expr::rotl_var(a, b), Expr::public_const(31)),
+        ];
+        assert_eq!(exprs_digest(&roots, None), exprs_digest(&expected, None));
+    }
+
+    #[test]
+    fn let_bindings_preserve_sharing() {
+        let roots = parse("input a; let t = a + a; output t ^ t;").unwrap();
+        let Expr::Xor(l, r) = roots[0].as_ref() else { panic!("expected Xor") };
+        assert!(Rc::ptr_eq(l, r));
+        let Expr::Add(x, y) = l.as_ref() else { panic!("expected Add") };
+        assert!(Rc::ptr_eq(x, y));
+    }
+
+    #[test]
+    fn print_round_trips() {
+        let roots = parse(SAMPLE).unwrap();
+        let text = print(&roots);
+        let again = parse(&text).unwrap();
+        assert_eq!(print(&again), text);
+        assert_eq!(exprs_digest(&again, None), exprs_digest(&roots, None));
+    }
+
+    #[test]
+    fn print_round_trips_shared_mixer() {
+        let state: [Rc<Expr>; 16] = std::array::from_fn(|i| Expr::input(&format!("s{i}")));
+        let roots = mixers::chacha_double_round(&state).to_vec();
+        let text = print(&roots);
+        let again = parse(&text).unwrap();
+        assert_eq!(exprs_digest(&again, None), exprs_digest(&roots, None));
+
+        let inputs: HashMap<String, u32> =
+            (0..16).map(|i| (format!("s{i}"), 0x0101_0101u32.wrapping_mul(i + 1))).collect();
+        assert_eq!(
+            lower_to_circuit_multi(&again).eval_outputs(&inputs),
+            lower_to_circuit_multi(&roots).eval_outputs(&inputs),
+        );
+    }
+
+    #[test]
+    fn print_merges_inputs_by_name() {
+        // Three separate `Input` nodes, two of them named `a`.
+        let roots = vec![Expr::xor(Expr::input("a"), Expr::and(Expr::input("a"), Expr::input("b")))];
+        let again = parse(&print(&roots)).unwrap();
+        assert_ne!(exprs_digest(&again, None), exprs_digest(&roots, None));
+        let inputs: HashMap<String, u32> = [("a".to_string(), 0xF0F0_1234), ("b".to_string(), 0x0FF0_FFFF)].into();
+        assert_eq!(
+            lower_to_circuit_multi(&again).eval_outputs(&inputs),
+            lower_to_circuit_multi(&roots).eval_outputs(&inputs),
+        );
+        // From parsed roots on, the round trip is exact.
+        let third = parse(&print(&again)).unwrap();
+        assert_eq!(exprs_digest(&third, None), exprs_digest(&again, None));
+    }
+
+    #[test]
+    #[should_panic(expected = "input name \"event-type\" cannot be written")]
+    fn print_rejects_unspellable_input_names() {
+        print(&[Expr::input("event-type")]);
+    }
+
+    #[test]
+    #[should_panic(expected = "input name \"output\" cannot be written")]
+    fn print_rejects_keyword_input_names() {
+        print(&Expr::input_bytes("output", 4));
+    }
+
+    #[test]
+    fn print_binds_shared_nodes_and_parenthesises() {
+        let a = Expr::input("a");
+        let t0 = Expr::input("t0");
+        let s = Expr::xor(a.clone(), t0);
+        let e = Expr::and(Expr::add(s.clone(), s.clone()), Expr::rotl(Expr::or(a, Expr::public_const(5)), 3));
+        assert_eq!(
+            print(&[e.clone(), e]),
+            "input a, t0;\n\
+             let t1 = a ^ t0;\n\
+             let t2 = t1 + t1 & (a | public 0x00000005) <<< 3;\n\
+             output t2, t2;\n",
+        );
+    }
+
+    #[test]
+    fn errors_report_line_and_column() {
+        assert_eq!(err("input a;\noutput a ^ b;"), (2, 12, "undefined name `b`".into()));
+        assert_eq!(err("input a;\n  output a $ a;"), (2, 12, "unexpected character `$`".into()));
+        assert_eq!(err("input a;\nlet a = a;"), (2, 5, "`a` is already defined".into()));
+        assert_eq!(err("input a;"), (1, 9, "missing `output` statement".into()));
+        assert_eq!(err("input a;\noutput a;\nlet b = a;"), (3, 1, "`output` must be the last statement".into()));
+        assert_eq!(err("input m: bytes[7];\noutput m[2];"), (2, 10, "word 2 out of range for `m` (7 bytes, 2 words)".into()));
+        assert_eq!(err("input a;\noutput a << a;"), (2, 13, "`<<` needs a literal amount".into()));
+        assert_eq!(err("input a;\noutput a ^ public 0x1_0000_0000;"), (2, 19, "4294967296 does not fit in 32 bits".into()));
+        assert_eq!(err("input let;"), (1, 7, "`let` is a keyword".into()));
+        assert_eq!(err("input a;\noutput (a ^ a;"), (2, 14, "expected `)`, found `;`".into()));
+    }
+}
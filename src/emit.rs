@@ -2,7 +2,7 @@
 //!
 //! This module takes a baked, concrete VM and emits a standalone Rust function
 //! that implements the same computation without any VM machinery — just array
-//! accesses, XOR, AND, and rotation on `u32` values.
+//! accesses, XOR, AND, shifts, and rotation on `u32` values.
 //!
 //! The emitted function is what gets shipped to the client (compiled to Wasm
 //! or included in a JS bundle). A new rotation means re-running
@@ -23,7 +23,8 @@
 //!     r[2] = POOL[2];                  // SECRET_CONST  (k^mask baked in)
 //!     r[3] = r[0] ^ r[1];             // XOR  (free)
 //!     r[4] = {                         // AND  (Beaver triple expansion)
-//!         let (t, ma, mb) = (POOL[3], POOL[4], POOL[5]);
+//!         let (t, s) = (POOL[3], POOL[4]);
+//!         let (ma, mb) = (t ^ s ^ POOL[5], t ^ s ^ POOL[6]);
 //!         let mut z = t;
 //!         z ^= r[0] & mb;
 //!         z ^= r[1] & ma;
@@ -55,14 +56,18 @@
 //! slice. Each gadget records how many pool entries it owns (already tracked
 //! in `BakedGadget::consts`), so indexing is straightforward.
 //!
-//! # Future: structured output
+//! # Other targets
 //!
-//! The current design returns a `String`. If the emission target changes (e.g.
-//! emitting JavaScript or Wasm bytecode directly), this function's signature
-//! should change to return a `proc_macro2::TokenStream` or a target-specific
-//! IR, keeping the gadget-walking logic intact.
-
-use crate::circuit::{Circuit, Gadget, WireId};
+//! Other browser targets live in their own modules and reuse
+//! `allocate_registers` and `build_pool`, so every target walks the same
+//! schedule with the same registers and `POOL` layout: [`crate::emit_js`]
+//! emits an ES module, [`crate::emit_wasm`] a WebAssembly module in
+//! binary or WAT form, and [`crate::emit_c`] C99.  The C and TypeScript
+//! counterparts of [`emit_verifier_rust`] share its gadget walk,
+//! `emit_plain_gadget`, through the `PlainSyntax` trait.
+
+use crate::circuit::{ByteWord, Circuit, Gadget, WireId};
+use crate::error::Error;
 use crate::mask::MaskedCircuit;
 
 fn format_digest_const(digest: &[u8; 32]) -> String {
@@ -76,6 +81,152 @@ fn format_digest_const(digest: &[u8; 32]) -> String {
     s
 }
 
+/// Rust keywords (strict and reserved, 2021 edition, plus `gen`) that
+/// cannot be used as plain identifiers.
+const RUST_KEYWORDS: &[&str] = &[
+    "abstract", "as", "async", "await", "become", "box", "break", "const",
+    "continue", "crate", "do", "dyn", "else", "enum", "extern", "false",
+    "final", "fn", "for", "gen", "if", "impl", "in", "let", "loop", "macro",
+    "match", "mod", "move", "mut", "override", "priv", "pub", "ref", "return",
+    "self", "Self", "static", "struct", "super", "trait", "true", "try",
+    "type", "typeof", "unsafe", "unsized", "use", "virtual", "where", "while",
+    "yield",
+];
+
+/// Prefix of every mangled parameter name.  Valid names that already start
+/// with it are mangled too, which keeps the mapping injective.
+const MANGLE_PREFIX: &str = "q_";
+
+/// Whether `name` is a Rust identifier: ASCII letters, digits and `_`, not
+/// starting with a digit, not `_` alone and not a keyword.
+pub fn is_rust_identifier(name: &str) -> bool {
+    let mut chars = name.chars();
+    let Some(first) = chars.next() else { return false };
+    (first.is_ascii_alphabetic() || first == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+        && name != "_"
+        && !RUST_KEYWORDS.contains(&name)
+}
+
+/// Names the browser code uses for itself: the register array `r`, the
+/// constant pool `POOL` and the `EXPR_DIGEST` constant.
+fn is_browser_name(name: &str) -> bool {
+    name == "r" || name == "POOL" || name == "EXPR_DIGEST"
+}
+
+/// A verifier wire name, `w{n}`.
+fn is_wire_name(name: &str) -> bool {
+    name.strip_prefix('w').is_some_and(|n| !n.is_empty() && n.bytes().all(|b| b.is_ascii_digit()))
+}
+
+/// Check that `fn_name` can name an emitted function: a Rust identifier
+/// other than `r`, `POOL`, `EXPR_DIGEST` and `w{n}`, the names the emitted
+/// code uses.
+///
+/// Function names are never mangled, since the caller has to call the
+/// function by the name it asked for.
+pub fn check_fn_name(fn_name: &str) -> Result<(), Error> {
+    if is_rust_identifier(fn_name) && !is_browser_name(fn_name) && !is_wire_name(fn_name) {
+        Ok(())
+    } else {
+        Err(Error::InvalidIdentifier(fn_name.to_string()))
+    }
+}
+
+/// The emitted parameter name for input (or byte-input parameter) `name`.
+///
+/// A Rust identifier other than `r`, `POOL` and `EXPR_DIGEST` that does not
+/// start with `q_` is used verbatim; `w{n}` needs no mangling, since the verifier
+/// prefixes its parameters with `input_`.  Anything else becomes `q_`
+/// followed by `name` with `_` doubled and every other non-alphanumeric
+/// character written as `_{hex}_`, so `event-type` is emitted as
+/// `q_event_2d_type` and `fn` as `q_fn`.  Distinct names always map to
+/// distinct identifiers.
+pub fn param_identifier(name: &str) -> String {
+    if is_rust_identifier(name) && !is_browser_name(name) && !name.starts_with(MANGLE_PREFIX) {
+        return name.to_string();
+    }
+    mangle(name)
+}
+
+/// `name` escaped into a `q_`-prefixed identifier, as [`param_identifier`]
+/// does for names it cannot use verbatim.
+pub(crate) fn mangle(name: &str) -> String {
+    let mut id = String::from(MANGLE_PREFIX);
+    for c in name.chars() {
+        match c {
+            '_' => id.push_str("__"),
+            c if c.is_ascii_alphanumeric() => id.push(c),
+            c => id.push_str(&format!("_{:x}_", c as u32)),
+        }
+    }
+    id
+}
+
+/// `(input name, emitted parameter name)` for every parameter of `circuit`,
+/// in signature order.  Byte-slice inputs appear once, under their parameter
+/// name.  The verifier prefixes each emitted name with `input_`.
+pub fn param_identifiers(circuit: &Circuit) -> Vec<(String, String)> {
+    circuit.params().into_iter()
+        .map(|(name, _)| {
+            let id = param_identifier(&name);
+            (name, id)
+        })
+        .collect()
+}
+
+/// The emitted parameter list in [`Circuit::params`] order — the schema's,
+/// or sorted by input name without one: one `u32` per word input and one
+/// `&[u8; N]` per byte-slice input.  Names are mangled by
+/// [`param_identifier`] and get `prefix` prepended.
+fn sig_params(circuit: &Circuit, prefix: &str) -> String {
+    circuit.params().iter()
+        .map(|(name, len)| {
+            let id = param_identifier(name);
+            match len {
+                None      => format!("{prefix}{id}: u32"),
+                Some(len) => format!("{prefix}{id}: &[u8; {len}]"),
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Little-endian load of one byte-input word from slice variable `var`,
+/// with literal zeros past the input's length.
+fn byte_word_load(var: &str, bw: &ByteWord) -> String {
+    let bytes = (0..4)
+        .map(|i| {
+            let at = bw.word * 4 + i;
+            if at < bw.len { format!("{var}[{at}]") } else { "0".to_string() }
+        })
+        .collect::<Vec<_>>()
+        .join(", ");
+    format!("u32::from_le_bytes([{bytes}])")
+}
+
+/// `u32` for a single-output circuit, `[u32; N]` otherwise.
+fn return_type(circuit: &Circuit) -> String {
+    match circuit.egress.len() {
+        1 => "u32".to_string(),
+        n => format!("[u32; {n}]"),
+    }
+}
+
+/// The function's tail expression: the lone output, or an array literal of
+/// all outputs in egress order.
+fn emit_outputs(tails: &[String]) -> String {
+    if let [only] = tails {
+        return format!("    {only}\n");
+    }
+    let mut s = String::from("    [\n");
+    for t in tails {
+        s.push_str(&format!("        {t},\n"));
+    }
+    s.push_str("    ]\n");
+    s
+}
+
 // ---------------------------------------------------------------------------
 // Browser Wasm entry point
 // ---------------------------------------------------------------------------
@@ -86,38 +237,37 @@ fn format_digest_const(digest: &[u8; 32]) -> String {
 /// `fn_name` — the name of the emitted function (also the file stem).
 ///
 /// The function's parameter list is derived directly from the circuit's
-/// `Gadget::Ingest` entries in topological order — each ingest's `name`
-/// field becomes a `u32` parameter.  The caller must ensure those names are
-/// valid Rust identifiers.
+/// `Gadget::Ingest` entries, in the order of the circuit's
+/// [`InputSchema`](crate::schema::InputSchema) if it has one and sorted by
+/// name otherwise — each ingest's `name` field becomes a `u32` parameter,
+/// and each byte-slice input a single
+/// `&[u8; N]` parameter unpacked word by word (little-endian, zero-padded).
+/// Input names that are not usable identifiers are mangled by
+/// [`param_identifier`].
+///
+/// Panics if `fn_name` fails [`check_fn_name`].
+///
+/// A single-output circuit returns `u32`; one with `N` egress wires returns
+/// `[u32; N]` in egress order, each word unmasked by its own pool delta.
 ///
 /// Returns a `String` containing a complete Rust source file that can be
 /// written to disk and compiled independently of this crate.
 pub fn emit_rust(masked: &MaskedCircuit, circuit: &Circuit, fn_name: &str, rng: &mut impl rand::RngCore, expr_digest: &[u8; 32]) -> String {
+    check_fn_name(fn_name).unwrap_or_else(|e| panic!("{e}"));
     let slot = allocate_registers(circuit, rng);
 
     let (pool, pool_starts) = build_pool(masked);
 
-    let n_regs = circuit.gadgets.iter()
-        .filter_map(|g| g.out())
-        .map(|w| slot[w])
-        .max()
-        .map(|m| m + 1)
-        .unwrap_or(0);
+    let n_regs = register_count(circuit, &slot);
 
-    let mut ingest_names: Vec<&str> = circuit.gadgets.iter()
-        .filter_map(|g| if let Gadget::Ingest { name, .. } = g { Some(name.as_str()) } else { None })
-        .collect();
-    ingest_names.sort_unstable();
-    let sig_params = ingest_names.iter()
-        .map(|name| format!("{name}: u32"))
-        .collect::<Vec<_>>()
-        .join(", ");
+    let sig_params = sig_params(circuit, "");
 
     let mut out = String::new();
 
     out.push_str("// Generated by `cargo run --bin regen_fixtures` — do not edit manually.\n");
     out.push_str(&format_digest_const(expr_digest));
-    out.push_str(&format!("pub fn {fn_name}({sig_params}) -> u32 {{\n"));
+    let ret = return_type(circuit);
+    out.push_str(&format!("pub fn {fn_name}({sig_params}) -> {ret} {{\n"));
 
     out.push_str("    const POOL: &[u32] = &[\n");
     for &v in &pool {
@@ -127,9 +277,17 @@ pub fn emit_rust(masked: &MaskedCircuit, circuit: &Circuit, fn_name: &str, rng:
 
     out.push_str(&format!("    let mut r = [0u32; {n_regs}];\n"));
 
+    let mut tails = Vec::new();
     for (idx, g) in circuit.gadgets.iter().enumerate() {
-        out.push_str(&emit_gadget(g, pool_starts[idx], &slot));
+        match g {
+            Gadget::Egress { a } => {
+                let (sa, p) = (slot[*a], pool_starts[idx]);
+                tails.push(format!("r[{sa}] ^ POOL[{p}]"));
+            }
+            _ => out.push_str(&emit_gadget(g, pool_starts[idx], &slot)),
+        }
     }
+    out.push_str(&emit_outputs(&tails));
 
     out.push_str("}\n");
     out
@@ -144,7 +302,8 @@ pub fn emit_rust(masked: &MaskedCircuit, circuit: &Circuit, fn_name: &str, rng:
 ///
 /// The emitted function evaluates F(inputs) plaintext — no POOL, no Beaver
 /// triples, no masking.  It is the server's oracle: given the same inputs as
-/// the browser, it must produce the same `u32` checksum.
+/// the browser, it must produce the same checksum (`u32`, or `[u32; N]` for a
+/// multi-output circuit).
 ///
 /// Intended use: write the output to `generated/verifier.rs` inside a
 /// `workers-rs` Cloudflare Worker crate, then `include!` it.  The crate has
@@ -154,55 +313,181 @@ pub fn emit_rust(masked: &MaskedCircuit, circuit: &Circuit, fn_name: &str, rng:
 /// which the compiler eliminates.  All other gadgets map directly to their
 /// unmasked arithmetic.
 ///
-/// Parameters are named `input_{name}` (where `name` is the circuit's Ingest
-/// name), avoiding any collision with the `w{wire_id}` namespace used for
+/// Parameters are named `input_{id}`, where `id` is [`param_identifier`] of
+/// the circuit's Ingest name (or the parameter name of a byte-slice input),
+/// avoiding any collision with the `w{wire_id}` namespace used for
 /// intermediate variables.
+///
+/// Panics if `fn_name` fails [`check_fn_name`].
 pub fn emit_verifier_rust(circuit: &Circuit, fn_name: &str, expr_digest: &[u8; 32]) -> String {
-    let mut ingest_names: Vec<&str> = circuit.gadgets.iter()
-        .filter_map(|g| if let Gadget::Ingest { name, .. } = g { Some(name.as_str()) } else { None })
-        .collect();
-    ingest_names.sort_unstable();
-    let sig_params = ingest_names.iter()
-        .map(|name| format!("input_{name}: u32"))
-        .collect::<Vec<_>>()
-        .join(", ");
+    check_fn_name(fn_name).unwrap_or_else(|e| panic!("{e}"));
+    let mut out = String::from("// Generated by xorpl — do not edit manually.\n");
+    out.push_str(&verifier_items(circuit, fn_name, expr_digest));
+    out
+}
 
-    let mut out = String::new();
-    out.push_str("// Generated by xorpl — do not edit manually.\n");
-    out.push_str(&format_digest_const(expr_digest));
-    out.push_str(&format!("pub fn {fn_name}({sig_params}) -> u32 {{\n"));
+/// The `EXPR_DIGEST` constant and verifier function of
+/// [`emit_verifier_rust`], without the file header.
+fn verifier_items(circuit: &Circuit, fn_name: &str, expr_digest: &[u8; 32]) -> String {
+    let sig_params = sig_params(circuit, "input_");
 
+    let mut out = format_digest_const(expr_digest);
+    let ret = return_type(circuit);
+    out.push_str(&format!("pub fn {fn_name}({sig_params}) -> {ret} {{\n"));
+
+    let mut tails = Vec::new();
     for g in &circuit.gadgets {
-        out.push_str(&emit_plain_gadget(g));
+        match g {
+            Gadget::Egress { a } => tails.push(format!("w{a}")),
+            _ => out.push_str(&emit_plain_gadget(g, &RustSyntax)),
+        }
     }
+    out.push_str(&emit_outputs(&tails));
 
     out.push_str("}\n");
     out
 }
 
-fn emit_plain_gadget(g: &Gadget) -> String {
+/// Target syntax for the plaintext verifier walk in [`emit_plain_gadget`].
+///
+/// Every verifier backend implements this, so they all share one mapping
+/// from gadgets to unmasked arithmetic and differ only in spelling.
+pub(crate) trait PlainSyntax {
+    /// The statement binding wire `out` to `expr`, indented and terminated.
+    fn bind(&self, out: WireId, expr: &str) -> String;
+    /// A `u32` literal.
+    fn word(&self, k: u32) -> String;
+    /// `x` rotated left by the constant `r`.
+    fn rotl(&self, x: &str, r: u32) -> String;
+    /// `x` shifted right (logically) by the constant `r < 32`.
+    fn shr(&self, x: &str, r: u32) -> String;
+    /// Little-endian load of one byte-input word from array variable `var`.
+    fn byte_word(&self, var: &str, bw: &ByteWord) -> String;
+}
+
+struct RustSyntax;
+
+impl PlainSyntax for RustSyntax {
+    fn bind(&self, out: WireId, expr: &str) -> String { format!("    let w{out} = {expr};\n") }
+    fn word(&self, k: u32) -> String { format!("0x{k:08x}u32") }
+    fn rotl(&self, x: &str, r: u32) -> String { format!("{x}.rotate_left({r})") }
+    fn shr(&self, x: &str, r: u32) -> String { format!("{x} >> {r}") }
+    fn byte_word(&self, var: &str, bw: &ByteWord) -> String { byte_word_load(var, bw) }
+}
+
+/// Emit the verifier statement for one gadget in `syntax`.  Wires are named
+/// `w{wire_id}` and parameters `input_{id}` with `id` from
+/// [`param_identifier`], in every target.
+pub(crate) fn emit_plain_gadget(g: &Gadget, syntax: &impl PlainSyntax) -> String {
+    let w = |wire: &WireId| format!("w{wire}");
     match g {
-        Gadget::Ingest { name, out, .. } =>
-            format!("    let w{out} = input_{name};\n"),
-        Gadget::PublicConst { k, out } =>
-            format!("    let w{out} = 0x{k:08x}u32;\n"),
-        Gadget::SecretConst { k, out, .. } =>
-            format!("    let w{out} = 0x{k:08x}u32;\n"),
+        Gadget::Ingest { name, bytes: None, out, .. } =>
+            syntax.bind(*out, &format!("input_{}", param_identifier(name))),
+        Gadget::Ingest { bytes: Some(bw), out, .. } =>
+            syntax.bind(*out, &syntax.byte_word(&format!("input_{}", param_identifier(&bw.param)), bw)),
+        Gadget::PublicConst { k, out } | Gadget::SecretConst { k, out, .. } =>
+            syntax.bind(*out, &syntax.word(*k)),
         Gadget::Xor { a, b, out } =>
-            format!("    let w{out} = w{a} ^ w{b};\n"),
+            syntax.bind(*out, &format!("w{a} ^ w{b}")),
         Gadget::XorConst { a, k, out } =>
-            format!("    let w{out} = w{a} ^ 0x{k:08x}u32;\n"),
+            syntax.bind(*out, &format!("w{a} ^ {}", syntax.word(*k))),
         Gadget::AndConst { a, k, out } =>
-            format!("    let w{out} = w{a} & 0x{k:08x}u32;\n"),
+            syntax.bind(*out, &format!("w{a} & {}", syntax.word(*k))),
         Gadget::Rotl { a, r, out } =>
-            format!("    let w{out} = w{a}.rotate_left({r});\n"),
+            syntax.bind(*out, &syntax.rotl(&w(a), *r)),
+        Gadget::Shl { a, r, out } =>
+            syntax.bind(*out, &format!("w{a} << {r}")),
+        Gadget::Shr { a, r, out } =>
+            syntax.bind(*out, &syntax.shr(&w(a), *r)),
         Gadget::And { a, b, out, .. } =>
-            format!("    let w{out} = w{a} & w{b};\n"),
+            syntax.bind(*out, &format!("w{a} & w{b}")),
         Gadget::Remask { a, out, .. } =>
-            format!("    let w{out} = w{a};\n"),
-        Gadget::Egress { a } =>
-            format!("    w{a}\n"),
+            syntax.bind(*out, &w(a)),
+        Gadget::Egress { .. } =>
+            unreachable!("egress is emitted by the caller"),
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Server verifier bundle
+// ---------------------------------------------------------------------------
+
+/// Emit one Rust module holding several verifiers and a dispatcher that
+/// picks one by expression digest:
+///
+/// ```text
+/// pub const INPUT_ARITY: usize = 2;
+///
+/// pub fn verify(digest: &[u8; 32], inputs: &[u32]) -> Option<u32> { ... }
+///
+/// pub mod checksum_v1 {
+///     pub const EXPR_DIGEST: [u8; 32] = [...];
+///     pub fn checksum_v1(input_a: u32, input_b: u32) -> u32 { ... }
+/// }
+/// ```
+///
+/// Each `(digest, circuit, name)` entry becomes `pub mod {name}` holding
+/// exactly what [`emit_verifier_rust`] emits for it.  `verify` runs the
+/// verifier whose `EXPR_DIGEST` equals `digest` on `inputs`, in that
+/// verifier's parameter order, and returns `None` for an unknown digest or
+/// when `inputs.len()` is not `INPUT_ARITY`.
+///
+/// Every entry must take `INPUT_ARITY` `u32` inputs and return one `u32`,
+/// so one request shape serves every digest.  Errors with
+/// [`Error::InvalidIdentifier`] for a name that fails [`check_fn_name`],
+/// and with [`Error::Bundle`] for no entries, a repeated digest or name, a
+/// byte-slice input, more than one output, or an input count that differs
+/// from the first entry's.
+pub fn emit_verifier_bundle(entries: &[(&[u8; 32], &Circuit, &str)]) -> Result<String, Error> {
+    let Some(&(_, first, _)) = entries.first() else {
+        return Err(Error::Bundle("no verifiers".to_string()));
+    };
+    let arity = first.params().len();
+    for (i, &(digest, circuit, name)) in entries.iter().enumerate() {
+        check_fn_name(name)?;
+        if let Some((_, _, other)) = entries[..i].iter().find(|(d, _, _)| *d == digest) {
+            return Err(Error::Bundle(format!("{name} and {other} have the same digest")));
+        }
+        if entries[..i].iter().any(|(_, _, n)| *n == name) {
+            return Err(Error::Bundle(format!("{name} appears twice")));
+        }
+        if let Some((param, _)) = circuit.params().into_iter().find(|(_, len)| len.is_some()) {
+            return Err(Error::Bundle(format!("{name} takes byte-slice input {param:?}")));
+        }
+        if circuit.egress.len() != 1 {
+            return Err(Error::Bundle(format!("{name} returns {} words", circuit.egress.len())));
+        }
+        if circuit.params().len() != arity {
+            return Err(Error::Bundle(format!(
+                "{name} takes {} inputs, {} takes {arity}", circuit.params().len(), entries[0].2)));
+        }
+    }
+
+    let args = (0..arity).map(|i| format!("i{i}")).collect::<Vec<_>>().join(", ");
+    let mut out = String::from("// Generated by xorpl — do not edit manually.\n");
+    out.push_str(&format!("pub const INPUT_ARITY: usize = {arity};\n\n"));
+    out.push_str("pub fn verify(digest: &[u8; 32], inputs: &[u32]) -> Option<u32> {\n");
+    out.push_str(&format!("    let &[{args}] = inputs else {{ return None }};\n"));
+    out.push_str("    match *digest {\n");
+    for &(_, _, name) in entries {
+        out.push_str(&format!("        {name}::EXPR_DIGEST => Some({name}::{name}({args})),\n"));
+    }
+    out.push_str("        _ => None,\n");
+    out.push_str("    }\n");
+    out.push_str("}\n");
+
+    for &(digest, circuit, name) in entries {
+        out.push_str(&format!("\npub mod {name} {{\n"));
+        for line in verifier_items(circuit, name, digest).lines() {
+            if line.is_empty() {
+                out.push('\n');
+            } else {
+                out.push_str(&format!("    {line}\n"));
+            }
+        }
+        out.push_str("}\n");
     }
+    Ok(out)
 }
 
 // ---------------------------------------------------------------------------
@@ -242,7 +527,7 @@ fn last_use_steps(circuit: &Circuit) -> Vec<usize> {
 ///
 /// The shuffle permutes slot indices with `rng` so the `r[k]` values in the
 /// emitted code differ between rotations without changing circuit semantics.
-fn allocate_registers(circuit: &Circuit, rng: &mut impl rand::RngCore) -> Vec<usize> {
+pub(crate) fn allocate_registers(circuit: &Circuit, rng: &mut impl rand::RngCore) -> Vec<usize> {
     let last_use = last_use_steps(circuit);
     let n        = last_use.len();
     let n_steps  = circuit.gadgets.len();
@@ -296,6 +581,16 @@ fn allocate_registers(circuit: &Circuit, rng: &mut impl rand::RngCore) -> Vec<us
     slot
 }
 
+/// Length of the register array `r` for the slots from [`allocate_registers`].
+pub(crate) fn register_count(circuit: &Circuit, slot: &[usize]) -> usize {
+    circuit.gadgets.iter()
+        .filter_map(|g| g.out())
+        .map(|w| slot[w])
+        .max()
+        .map(|m| m + 1)
+        .unwrap_or(0)
+}
+
 // ---------------------------------------------------------------------------
 // Constant pool and gadget emission
 // ---------------------------------------------------------------------------
@@ -303,7 +598,7 @@ fn allocate_registers(circuit: &Circuit, rng: &mut impl rand::RngCore) -> Vec<us
 /// Flatten every `BakedGadget::consts` into one pool `Vec` and return a
 /// parallel `Vec` of starting indices so gadget `i`'s constants are
 /// `pool[starts[i]..starts[i+1]]`.
-fn build_pool(masked: &MaskedCircuit) -> (Vec<u32>, Vec<usize>) {
+pub(crate) fn build_pool(masked: &MaskedCircuit) -> (Vec<u32>, Vec<usize>) {
     let mut pool = Vec::new();
     let mut starts = Vec::new();
     for mg in masked.baked_consts() {
@@ -319,7 +614,8 @@ fn build_pool(masked: &MaskedCircuit) -> (Vec<u32>, Vec<usize>) {
 /// `p` is the gadget's starting index in the flattened constant pool.
 /// `slot` maps each WireId to its register array index.
 /// AND emits a Beaver expansion block; all other gadgets emit a single
-/// assignment.  Egress emits the tail expression (no semicolon).
+/// assignment.  Egress tails are collected by the caller and emitted by
+/// `emit_outputs`.
 fn emit_gadget(g: &Gadget, p: usize, slot: &[usize]) -> String {
     match g {
         Gadget::PublicConst { out, .. } => {
@@ -330,9 +626,14 @@ fn emit_gadget(g: &Gadget, p: usize, slot: &[usize]) -> String {
             let so = slot[*out];
             format!("    r[{so}] = POOL[{p}];\n")
         }
-        Gadget::Ingest { name, out, .. } => {
+        Gadget::Ingest { name, bytes: None, out, .. } => {
+            let (so, id) = (slot[*out], param_identifier(name));
+            format!("    r[{so}] = {id} ^ POOL[{p}];\n")
+        }
+        Gadget::Ingest { bytes: Some(bw), out, .. } => {
             let so = slot[*out];
-            format!("    r[{so}] = {name} ^ POOL[{p}];\n")
+            let load = byte_word_load(&param_identifier(&bw.param), bw);
+            format!("    r[{so}] = {load} ^ POOL[{p}];\n")
         }
         Gadget::Xor { a, b, out } => {
             let (sa, sb, so) = (slot[*a], slot[*b], slot[*out]);
@@ -350,12 +651,21 @@ fn emit_gadget(g: &Gadget, p: usize, slot: &[usize]) -> String {
             let (sa, so) = (slot[*a], slot[*out]);
             format!("    r[{so}] = r[{sa}].rotate_left({r});\n")
         }
+        Gadget::Shl { a, r, out } => {
+            let (sa, so) = (slot[*a], slot[*out]);
+            format!("    r[{so}] = r[{sa}] << {r};\n")
+        }
+        Gadget::Shr { a, r, out } => {
+            let (sa, so) = (slot[*a], slot[*out]);
+            format!("    r[{so}] = r[{sa}] >> {r};\n")
+        }
         Gadget::And { a, b, out, .. } => {
             let (sa, sb, so) = (slot[*a], slot[*b], slot[*out]);
-            let (p1, p2) = (p + 1, p + 2);
+            let (p1, p2, p3) = (p + 1, p + 2, p + 3);
             let mut s = String::new();
             s.push_str(&format!("    r[{so}] = {{\n"));
-            s.push_str(&format!("        let (t, ma, mb) = (POOL[{p}], POOL[{p1}], POOL[{p2}]);\n"));
+            s.push_str(&format!("        let (t, s) = (POOL[{p}], POOL[{p1}]);\n"));
+            s.push_str(&format!("        let (ma, mb) = (t ^ s ^ POOL[{p2}], t ^ s ^ POOL[{p3}]);\n"));
             s.push_str(         "        let mut z = t;\n");
             s.push_str(&format!("        z ^= r[{sa}] & mb;\n"));
             s.push_str(&format!("        z ^= r[{sb}] & ma;\n"));
@@ -368,10 +678,7 @@ fn emit_gadget(g: &Gadget, p: usize, slot: &[usize]) -> String {
             let (sa, so) = (slot[*a], slot[*out]);
             format!("    r[{so}] = r[{sa}] ^ POOL[{p}];\n")
         }
-        Gadget::Egress { a } => {
-            let sa = slot[*a];
-            format!("    r[{sa}] ^ POOL[{p}]\n")
-        }
+        Gadget::Egress { .. } => unreachable!("egress is emitted by emit_outputs"),
     }
 }
 
@@ -414,7 +721,7 @@ mod tests {
             [("a", 0x1234_5678u32), ("b", 0xDEAD_BEEFu32)]
             .iter().map(|&(k, v)| (k.to_string(), v)).collect();
         let vals = circuit.eval(&inputs);
-        let expected = vals[&circuit.egress];
+        let expected = vals[&circuit.egress[0]];
 
         // Cross-check: formula matches known output.
         let known = ((0x1234_5678u32 | 0xDEAD_BEEFu32) ^ 0x9e37_79b9u32).rotate_left(5);
@@ -445,7 +752,7 @@ mod tests {
         let circuit = or_rotl_circuit();
         let emitted = emit_verifier_rust(&circuit, "f", &[0u8; 32]);
         assert!(!emitted.contains("POOL"),  "verifier must not reference POOL");
-        assert!(!emitted.contains("let (t, ma, mb)"), "verifier must not use Beaver triples");
+        assert!(!emitted.contains("let (ma, mb)"), "verifier must not use Beaver triples");
     }
 
     /// Extract sorted parameter names from a `pub fn` signature line.
@@ -498,4 +805,55 @@ mod tests {
                 "parameter order mismatch at seed {seed}: verifier={verifier_params:?} browser={browser_params:?}");
         }
     }
+
+    #[test]
+    fn identifiers_are_checked_and_mangled() {
+        for ok in ["checksum", "f_verify", "_x", "w"] {
+            assert_eq!(check_fn_name(ok), Ok(()), "{ok}");
+        }
+        for bad in ["", "_", "1f", "fn", "gen", "a-b", "f(x)", "r", "POOL", "EXPR_DIGEST", "w12"] {
+            assert_eq!(check_fn_name(bad), Err(Error::InvalidIdentifier(bad.to_string())), "{bad:?}");
+        }
+
+        let cases = [
+            ("nonce", "nonce"), ("w3", "w3"), ("r", "q_r"), ("POOL", "q_POOL"), ("EXPR_DIGEST", "q_EXPR__DIGEST"),
+            ("type", "q_type"), ("", "q_"), ("q_x", "q_q__x"), ("a-b", "q_a_2d_b"),
+            ("a) { evil(); (", "q_a_29__20__7b__20_evil_28__29__3b__20__28_"), ("é", "q__e9_"),
+        ];
+        for (name, id) in cases {
+            assert_eq!(param_identifier(name), id, "{name:?}");
+            assert!(is_rust_identifier(id) && !is_browser_name(id), "{id}");
+        }
+        // Names that escape to similar-looking strings stay distinct.
+        let ids: std::collections::HashSet<String> =
+            ["a_b", "a-b", "a__b", "q_a_b", "q_a-b", "a_2d_b"].iter().map(|n| param_identifier(n)).collect();
+        assert_eq!(ids.len(), 6);
+    }
+
+    #[test]
+    fn verifier_bundle_rejects_clashes_at_generation() {
+        let (a, b) = (Expr::input("a"), Expr::input("b"));
+        let two = or_rotl_circuit();
+        let three = lower_to_circuit(&Expr::xor(a.clone(), Expr::and(b.clone(), Expr::input("c"))));
+        let bytes = lower_to_circuit(&Expr::xor(a.clone(), Expr::input_bytes("m", 4)[0].clone()));
+        let pair = crate::lower::lower_to_circuit_multi(&[a, b]);
+        let (d1, d2, d3) = ([1u8; 32], [2u8; 32], [3u8; 32]);
+
+        let ok = emit_verifier_bundle(&[(&d1, &two, "f"), (&d2, &two, "g")]).unwrap();
+        assert!(ok.contains("pub const INPUT_ARITY: usize = 2;"), "{ok}");
+        assert!(ok.contains("        g::EXPR_DIGEST => Some(g::g(i0, i1)),\n"), "{ok}");
+        assert!(ok.contains("\npub mod f {\n    pub const EXPR_DIGEST: [u8; 32] = [\n        0x01, "), "{ok}");
+
+        let err = |entries: &[(&[u8; 32], &Circuit, &str)]| match emit_verifier_bundle(entries) {
+            Err(Error::Bundle(message)) => message,
+            other => panic!("expected Error::Bundle, got {other:?}"),
+        };
+        assert_eq!(err(&[]), "no verifiers");
+        assert_eq!(err(&[(&d1, &two, "f"), (&d1, &two, "g")]), "g and f have the same digest");
+        assert_eq!(err(&[(&d1, &two, "f"), (&d2, &two, "f")]), "f appears twice");
+        assert_eq!(err(&[(&d1, &two, "f"), (&d2, &three, "g")]), "g takes 3 inputs, f takes 2");
+        assert_eq!(err(&[(&d1, &bytes, "f")]), "f takes byte-slice input \"m\"");
+        assert_eq!(err(&[(&d3, &pair, "f")]), "f returns 2 words");
+        assert_eq!(emit_verifier_bundle(&[(&d1, &two, "r")]), Err(Error::InvalidIdentifier("r".to_string())));
+    }
 }
@@ -0,0 +1,319 @@
+//! Emission: `MaskedCircuit` → C99 source.
+//!
+//! [`emit_c`] writes the browser function and [`emit_verifier_c`] the
+//! plaintext verifier, as self-contained C99 files for services and fuzzing
+//! harnesses written in C.  They mirror [`emit_rust`](crate::emit::emit_rust)
+//! and [`emit_verifier_rust`](crate::emit::emit_verifier_rust): the same
+//! gadget schedule, the same registers and `POOL` layout for the same `rng`
+//! state, and the same parameter names, including the verifier's `input_`
+//! prefix.
+//!
+//! # Emitted shape
+//!
+//! ```c
+//! #include <stdint.h>
+//!
+//! static const uint8_t EXPR_DIGEST[32] = {
+//!     0x3f, 0x0a, /* ... */
+//! };
+//!
+//! uint32_t checksum(uint32_t a, const uint8_t msg[7]) {
+//!     static const uint32_t POOL[] = {
+//!         0xdeadbeefu, /* ... */
+//!     };
+//!     uint32_t r[N];
+//!     r[0] = a ^ POOL[0];
+//!     /* ... */
+//!     return r[K] ^ POOL[M];
+//! }
+//! ```
+//!
+//! A circuit with `N` egress wires emits a `void` function with a trailing
+//! `uint32_t out[N]` parameter instead, written in egress order.
+//!
+//! # Defined behaviour
+//!
+//! All arithmetic is on `uint32_t`, so it wraps.  Shifts by a constant are
+//! below 32 (circuit validation rejects the rest) and constant rotations are
+//! reduced mod 32 at emission time.  Bytes are widened to `uint32_t` before
+//! they are shifted into a word.
+
+use crate::circuit::{ByteWord, Circuit, Gadget, WireId};
+use crate::emit::{allocate_registers, build_pool, check_fn_name, emit_plain_gadget, mangle, param_identifier, register_count, PlainSyntax};
+use crate::error::Error;
+use crate::mask::MaskedCircuit;
+
+/// C99 keywords, the `stdint.h` types and names the emitted file declares,
+/// which parameters and functions cannot use.  `out` is the multi-output
+/// parameter; `bool`, `true` and `false` are keywords from C23 on.
+const C_RESERVED: &[&str] = &[
+    "auto", "bool", "break", "case", "char", "const", "continue", "default",
+    "do", "double", "else", "enum", "extern", "false", "float", "for", "goto",
+    "if", "inline", "int", "long", "register", "restrict", "return", "short",
+    "signed", "sizeof", "static", "struct", "switch", "true", "typedef",
+    "union", "unsigned", "void", "volatile", "while",
+    "EXPR_DIGEST", "out", "uint32_t", "uint8_t",
+];
+
+/// Whether C reserves `name` everywhere: `__` or `_` and a capital letter.
+fn is_c_reserved_prefix(name: &str) -> bool {
+    let b = name.as_bytes();
+    b.first() == Some(&b'_') && b.get(1).is_some_and(|&c| c == b'_' || c.is_ascii_uppercase())
+}
+
+/// Check that `fn_name` can name an emitted C function: it must pass
+/// [`check_fn_name`], not be a C keyword or a name the file declares, and
+/// not start with `__` or `_` and a capital letter.
+pub fn check_c_fn_name(fn_name: &str) -> Result<(), Error> {
+    check_fn_name(fn_name)?;
+    if C_RESERVED.contains(&fn_name) || is_c_reserved_prefix(fn_name) {
+        return Err(Error::InvalidIdentifier(fn_name.to_string()));
+    }
+    Ok(())
+}
+
+/// The emitted C parameter name for input `name` in [`emit_c`]:
+/// [`param_identifier`], except that C keywords, the names in the emitted
+/// file and reserved identifiers are mangled as Rust keywords are (`int`
+/// becomes `q_int`).  The verifier uses `input_` and [`param_identifier`],
+/// as [`emit_verifier_rust`](crate::emit::emit_verifier_rust) does.
+pub fn c_param_identifier(name: &str) -> String {
+    if C_RESERVED.contains(&name) || is_c_reserved_prefix(name) { mangle(name) } else { param_identifier(name) }
+}
+
+/// The parameter list: `uint32_t` per word input and `const uint8_t id[N]`
+/// per byte-slice input, followed by `uint32_t out[N]` for a multi-output
+/// circuit.
+fn sig_params(circuit: &Circuit, id: impl Fn(&str) -> String) -> String {
+    let mut params: Vec<String> = circuit.params().iter()
+        .map(|(name, len)| match len {
+            None      => format!("uint32_t {}", id(name)),
+            Some(len) => format!("const uint8_t {}[{len}]", id(name)),
+        })
+        .collect();
+    match circuit.egress.len() {
+        1 => {}
+        n => params.push(format!("uint32_t out[{n}]")),
+    }
+    if params.is_empty() { "void".to_string() } else { params.join(", ") }
+}
+
+/// The file header and the `EXPR_DIGEST` array.
+fn header(expr_digest: &[u8; 32]) -> String {
+    let mut out = String::new();
+    out.push_str("/* Generated by xorpl — do not edit manually. */\n");
+    out.push_str("#include <stdint.h>\n\n");
+    out.push_str("static const uint8_t EXPR_DIGEST[32] = {\n");
+    for row in expr_digest.chunks(8) {
+        out.push_str(&format!("    {},\n", row.iter().map(|b| format!("0x{b:02x}")).collect::<Vec<_>>().join(", ")));
+    }
+    out.push_str("};\n\n");
+    out
+}
+
+/// The function head up to the opening brace.
+fn fn_head(circuit: &Circuit, fn_name: &str, params: &str) -> String {
+    let ret = match circuit.egress.len() {
+        1 => "uint32_t",
+        _ => "void",
+    };
+    format!("{ret} {fn_name}({params}) {{\n")
+}
+
+/// `return` for a single output, one `out[i]` store per output otherwise.
+fn emit_outputs(tails: &[String]) -> String {
+    if let [only] = tails {
+        return format!("    return {only};\n");
+    }
+    tails.iter().enumerate().map(|(i, t)| format!("    out[{i}] = {t};\n")).collect()
+}
+
+/// Little-endian load of one byte-input word from array variable `var`.
+/// Bytes past the input's length are zero and left out.
+fn byte_word_load(var: &str, bw: &ByteWord) -> String {
+    let terms = (0..4)
+        .filter(|i| bw.word * 4 + i < bw.len)
+        .map(|i| match i {
+            0 => format!("(uint32_t){var}[{}]", bw.word * 4),
+            i => format!("((uint32_t){var}[{}] << {})", bw.word * 4 + i, 8 * i),
+        })
+        .collect::<Vec<_>>();
+    format!("({})", terms.join(" | "))
+}
+
+/// `x` rotated left by the constant `r`, reduced mod 32.
+fn rotl(x: &str, r: u32) -> String {
+    match r % 32 {
+        0 => x.to_string(),
+        r => format!("(({x} << {r}) | ({x} >> {}))", 32 - r),
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Browser function
+// ---------------------------------------------------------------------------
+
+/// Emit a self-contained C99 file containing `EXPR_DIGEST` and one function
+/// for the given masked circuit.
+///
+/// The C counterpart of [`emit_rust`](crate::emit::emit_rust), with the same
+/// arguments and the same parameter order.  A `u32` input is a `uint32_t`;
+/// a byte-slice input of length `N` is a `const uint8_t name[N]`.  A
+/// single-output circuit returns `uint32_t`; one with `N` egress wires
+/// returns `void` and writes `uint32_t out[N]` in egress order.  Input names
+/// are mangled by [`c_param_identifier`].
+///
+/// Panics if `fn_name` fails [`check_c_fn_name`].
+pub fn emit_c(masked: &MaskedCircuit, circuit: &Circuit, fn_name: &str, rng: &mut impl rand::RngCore, expr_digest: &[u8; 32]) -> String {
+    check_c_fn_name(fn_name).unwrap_or_else(|e| panic!("{e}"));
+    let slot = allocate_registers(circuit, rng);
+    let (pool, pool_starts) = build_pool(masked);
+    let n_regs = register_count(circuit, &slot);
+
+    let mut out = header(expr_digest);
+    out.push_str(&fn_head(circuit, fn_name, &sig_params(circuit, c_param_identifier)));
+
+    out.push_str("    static const uint32_t POOL[] = {\n");
+    for &v in &pool {
+        out.push_str(&format!("        0x{v:08x}u,\n"));
+    }
+    out.push_str("    };\n");
+    out.push_str(&format!("    uint32_t r[{n_regs}];\n"));
+
+    let mut tails = Vec::new();
+    for (idx, g) in circuit.gadgets.iter().enumerate() {
+        match g {
+            Gadget::Egress { a } => {
+                let (sa, p) = (slot[*a], pool_starts[idx]);
+                tails.push(format!("r[{sa}] ^ POOL[{p}]"));
+            }
+            _ => out.push_str(&emit_gadget(g, pool_starts[idx], &slot)),
+        }
+    }
+    out.push_str(&emit_outputs(&tails));
+    out.push_str("}\n");
+    out
+}
+
+/// Emit the C statement(s) for one gadget, as `emit::emit_gadget` does for
+/// Rust.  AND is a single Beaver expansion expression.
+fn emit_gadget(g: &Gadget, p: usize, slot: &[usize]) -> String {
+    match g {
+        Gadget::PublicConst { out, .. } | Gadget::SecretConst { out, .. } => {
+            format!("    r[{}] = POOL[{p}];\n", slot[*out])
+        }
+        Gadget::Ingest { name, bytes: None, out, .. } => {
+            format!("    r[{}] = {} ^ POOL[{p}];\n", slot[*out], c_param_identifier(name))
+        }
+        Gadget::Ingest { bytes: Some(bw), out, .. } => {
+            let load = byte_word_load(&c_param_identifier(&bw.param), bw);
+            format!("    r[{}] = {load} ^ POOL[{p}];\n", slot[*out])
+        }
+        Gadget::Xor { a, b, out } => {
+            format!("    r[{}] = r[{}] ^ r[{}];\n", slot[*out], slot[*a], slot[*b])
+        }
+        Gadget::XorConst { a, out, .. } | Gadget::Remask { a, out, .. } => {
+            format!("    r[{}] = r[{}] ^ POOL[{p}];\n", slot[*out], slot[*a])
+        }
+        Gadget::AndConst { a, out, .. } => {
+            format!("    r[{}] = r[{}] & POOL[{p}];\n", slot[*out], slot[*a])
+        }
+        Gadget::Rotl { a, r, out } => {
+            format!("    r[{}] = {};\n", slot[*out], rotl(&format!("r[{}]", slot[*a]), *r))
+        }
+        Gadget::Shl { a, r, out } => {
+            format!("    r[{}] = r[{}] << {r};\n", slot[*out], slot[*a])
+        }
+        Gadget::Shr { a, r, out } => {
+            format!("    r[{}] = r[{}] >> {r};\n", slot[*out], slot[*a])
+        }
+        Gadget::And { a, b, out, .. } => {
+            let (sa, sb, so) = (slot[*a], slot[*b], slot[*out]);
+            let (p1, p2, p3) = (p + 1, p + 2, p + 3);
+            let k = format!("POOL[{p}] ^ POOL[{p1}]");
+            format!("    r[{so}] = POOL[{p}] ^ (r[{sa}] & ({k} ^ POOL[{p3}])) ^ (r[{sb}] & ({k} ^ POOL[{p2}])) ^ (r[{sa}] & r[{sb}]);\n")
+        }
+        Gadget::Egress { .. } => unreachable!("egress is emitted by emit_outputs"),
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Server verifier
+// ---------------------------------------------------------------------------
+
+/// Emit a self-contained, unmasked C99 evaluation function for server-side
+/// verification: the C counterpart of
+/// [`emit_verifier_rust`](crate::emit::emit_verifier_rust).
+///
+/// Parameters are named `input_{id}` with `id` from [`param_identifier`],
+/// and intermediate values `w{wire_id}`, exactly as in the Rust verifier;
+/// the signature and return convention are those of [`emit_c`].
+///
+/// Panics if `fn_name` fails [`check_c_fn_name`].
+pub fn emit_verifier_c(circuit: &Circuit, fn_name: &str, expr_digest: &[u8; 32]) -> String {
+    check_c_fn_name(fn_name).unwrap_or_else(|e| panic!("{e}"));
+    let params = sig_params(circuit, |name| format!("input_{}", param_identifier(name)));
+
+    let mut out = header(expr_digest);
+    out.push_str(&fn_head(circuit, fn_name, &params));
+
+    let mut tails = Vec::new();
+    for g in &circuit.gadgets {
+        match g {
+            Gadget::Egress { a } => tails.push(format!("w{a}")),
+            _ => out.push_str(&emit_plain_gadget(g, &CSyntax)),
+        }
+    }
+    out.push_str(&emit_outputs(&tails));
+    out.push_str("}\n");
+    out
+}
+
+struct CSyntax;
+
+impl PlainSyntax for CSyntax {
+    fn bind(&self, out: WireId, expr: &str) -> String { format!("    const uint32_t w{out} = {expr};\n") }
+    fn word(&self, k: u32) -> String { format!("0x{k:08x}u") }
+    fn rotl(&self, x: &str, r: u32) -> String { rotl(x, r) }
+    fn shr(&self, x: &str, r: u32) -> String { format!("{x} >> {r}") }
+    fn byte_word(&self, var: &str, bw: &ByteWord) -> String { byte_word_load(var, bw) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::expr::Expr;
+    use crate::lower::{lower_to_circuit, lower_to_circuit_multi};
+    use rand::SeedableRng;
+
+    #[test]
+    fn names_avoid_c_keywords() {
+        assert_eq!(check_c_fn_name("checksum"), Ok(()));
+        for bad in ["int", "static", "out", "EXPR_DIGEST", "uint32_t", "__x", "_X", "fn", "r"] {
+            assert_eq!(check_c_fn_name(bad), Err(Error::InvalidIdentifier(bad.to_string())), "{bad}");
+        }
+        assert_eq!(c_param_identifier("int"), "q_int");
+        assert_eq!(c_param_identifier("_Bool"), "q___Bool");
+        assert_eq!(c_param_identifier("nonce"), "nonce");
+
+        let circuit = lower_to_circuit(&Expr::xor(Expr::input("int"), Expr::input_bytes("out", 2)[0].clone()));
+        let mut rng = rand::rngs::StdRng::seed_from_u64(0);
+        let masked = MaskedCircuit::from_circuit(&circuit, &mut rng);
+        let c = emit_c(&masked, &circuit, "f", &mut rng, &[0; 32]);
+        assert!(c.contains("uint32_t f(uint32_t q_int, const uint8_t q_out[2]) {"), "{c}");
+        assert!(c.contains("((uint32_t)q_out[0] | ((uint32_t)q_out[1] << 8)) ^ POOL["), "{c}");
+        let v = emit_verifier_c(&circuit, "f", &[0; 32]);
+        assert!(v.contains("uint32_t f(uint32_t input_int, const uint8_t input_out[2]) {"), "{v}");
+    }
+
+    #[test]
+    fn multi_output_writes_out_array() {
+        let (a, b) = (Expr::input("a"), Expr::input("b"));
+        let circuit = lower_to_circuit_multi(&[Expr::rotl(a.clone(), 32), Expr::and(a, b)]);
+        let v = emit_verifier_c(&circuit, "tag", &[7; 32]);
+        assert!(v.contains("static const uint8_t EXPR_DIGEST[32] = {\n    0x07, "), "{v}");
+        assert!(v.contains("void tag(uint32_t input_a, uint32_t input_b, uint32_t out[2]) {"), "{v}");
+        assert!(v.contains("    out[0] = w") && v.contains("    out[1] = w"), "{v}");
+        assert!(!v.contains("<< 32") && !v.contains(">> 32"), "{v}");
+    }
+}
@@ -0,0 +1,341 @@
+//! Emission: `MaskedCircuit` → JavaScript (ES module) source.
+//!
+//! The same gadget schedule as [`emit_rust`](crate::emit::emit_rust), for
+//! deployments that ship JavaScript instead of Wasm: no toolchain run per
+//! rotation, just a new module.  Given the same `rng` state both emitters
+//! allocate the same registers and read the same `POOL` layout, so the two
+//! outputs differ only in syntax.
+//!
+//! # Emitted shape
+//!
+//! ```js
+//! export const EXPR_DIGEST = Uint8Array.of(
+//!     0x3f, 0x0a, /* ... */
+//! );
+//!
+//! const POOL = Uint32Array.of(
+//!     0xdeadbeef, /* ... */
+//! );
+//!
+//! export function checksum(a, b) {
+//!     const r = new Uint32Array(N);
+//!     r[0] = a ^ POOL[0];                                        // INGEST "a"
+//!     r[1] = b ^ POOL[1];                                        // INGEST "b"
+//!     r[2] = POOL[3] ^ (r[0] & POOL[5]) ^ (r[1] & POOL[4]) ^ (r[0] & r[1]);  // AND
+//!     r[3] = (r[2] << 5) | (r[2] >>> 27);                        // ROTL 5
+//!     // ...
+//!     return (r[K] ^ POOL[M]) >>> 0;                             // EGRESS
+//! }
+//! ```
+//!
+//! # uint32 semantics
+//!
+//! JavaScript's bitwise operators work on signed 32-bit integers, so `^`,
+//! `&`, `|` and `<<` may produce negative numbers.  Their bits are still the
+//! right ones: every intermediate is stored into the `Uint32Array` register
+//! file, which truncates to `u32`, rotations use `>>>`, and each returned
+//! word is normalized with `>>> 0`.  The masked computation needs no
+//! multiplication, so there is no `Math.imul` either.
+//!
+//! # TypeScript verifier
+//!
+//! [`emit_verifier_ts`] is the TypeScript counterpart of
+//! [`emit_verifier_rust`](crate::emit::emit_verifier_rust), for Workers that
+//! verify small circuits without a Wasm module.  Both walk the circuit with
+//! the shared `emit::emit_plain_gadget`, so they differ only in syntax.  Its
+//! intermediates are plain `number`s and may be negative; as above, only the
+//! returned words need `>>> 0`.
+
+use crate::circuit::{ByteWord, Circuit, Gadget, WireId};
+use crate::emit::{allocate_registers, build_pool, check_fn_name, emit_plain_gadget, mangle, param_identifier, register_count, PlainSyntax};
+use crate::error::Error;
+use crate::mask::MaskedCircuit;
+
+/// JavaScript reserved words, literals and globals an emitted module cannot
+/// use as parameter or function names.  `Uint32Array` is listed because the
+/// function body allocates its registers with it.
+const JS_RESERVED: &[&str] = &[
+    "arguments", "await", "break", "case", "catch", "class", "const",
+    "continue", "debugger", "default", "delete", "do", "else", "enum", "eval",
+    "export", "extends", "false", "finally", "for", "function", "if",
+    "implements", "import", "in", "Infinity", "instanceof", "interface",
+    "let", "NaN", "new", "null", "package", "private", "protected", "public",
+    "return", "static", "super", "switch", "this", "throw", "true", "try",
+    "typeof", "Uint32Array", "Uint8Array", "undefined", "var", "void",
+    "while", "with", "yield",
+];
+
+/// Check that `fn_name` can name an emitted JavaScript function: it must
+/// pass [`check_fn_name`] and not be a JavaScript reserved word.
+pub fn check_js_fn_name(fn_name: &str) -> Result<(), Error> {
+    check_fn_name(fn_name)?;
+    if JS_RESERVED.contains(&fn_name) {
+        return Err(Error::InvalidIdentifier(fn_name.to_string()));
+    }
+    Ok(())
+}
+
+/// The emitted JavaScript parameter name for input `name`:
+/// [`param_identifier`], except that JavaScript reserved words are mangled
+/// as Rust keywords are (`new` becomes `q_new`).
+pub fn js_param_identifier(name: &str) -> String {
+    if JS_RESERVED.contains(&name) { mangle(name) } else { param_identifier(name) }
+}
+
+/// Little-endian load of one byte-input word from array variable `var`.
+/// Bytes past the input's length are zero and left out.
+fn byte_word_load(var: &str, bw: &ByteWord) -> String {
+    let terms = (0..4)
+        .filter(|i| bw.word * 4 + i < bw.len)
+        .map(|i| match i {
+            0 => format!("{var}[{}]", bw.word * 4),
+            i => format!("({var}[{}] << {})", bw.word * 4 + i, 8 * i),
+        })
+        .collect::<Vec<_>>();
+    format!("({})", terms.join(" | "))
+}
+
+/// Emit a self-contained ES module exporting `EXPR_DIGEST` and one function
+/// for the given masked circuit.
+///
+/// The JavaScript counterpart of [`emit_rust`](crate::emit::emit_rust), with
+/// the same arguments and the same parameter order.  A `u32` input is a
+/// `number`; a byte-slice input is a `Uint8Array` (or any array of byte
+/// values) of its declared length.  A single-output circuit returns a
+/// `number`, one with `N` egress wires an array of `N` numbers in egress
+/// order — all in `0..2**32`.  Input names are mangled by
+/// [`js_param_identifier`].
+///
+/// Panics if `fn_name` fails [`check_js_fn_name`].
+pub fn emit_js(masked: &MaskedCircuit, circuit: &Circuit, fn_name: &str, rng: &mut impl rand::RngCore, expr_digest: &[u8; 32]) -> String {
+    check_js_fn_name(fn_name).unwrap_or_else(|e| panic!("{e}"));
+    let slot = allocate_registers(circuit, rng);
+    let (pool, pool_starts) = build_pool(masked);
+    let n_regs = register_count(circuit, &slot);
+
+    let mut out = String::new();
+    out.push_str("// Generated by xorpl — do not edit manually.\n");
+    out.push_str("export const EXPR_DIGEST = Uint8Array.of(\n");
+    for row in expr_digest.chunks(8) {
+        out.push_str(&format!("    {},\n", row.iter().map(|b| format!("0x{b:02x}")).collect::<Vec<_>>().join(", ")));
+    }
+    out.push_str(");\n\n");
+
+    out.push_str("const POOL = Uint32Array.of(\n");
+    for &v in &pool {
+        out.push_str(&format!("    0x{v:08x},\n"));
+    }
+    out.push_str(");\n\n");
+
+    let params = circuit.params();
+    out.push_str("/**\n");
+    for (name, len) in &params {
+        let id = js_param_identifier(name);
+        match len {
+            None      => out.push_str(&format!(" * @param {{number}} {id}\n")),
+            Some(len) => out.push_str(&format!(" * @param {{Uint8Array}} {id} {len} bytes\n")),
+        }
+    }
+    let ret = match circuit.egress.len() {
+        1 => "number",
+        _ => "number[]",
+    };
+    out.push_str(&format!(" * @returns {{{ret}}}\n */\n"));
+    let sig = params.iter().map(|(name, _)| js_param_identifier(name)).collect::<Vec<_>>().join(", ");
+    out.push_str(&format!("export function {fn_name}({sig}) {{\n"));
+    out.push_str(&format!("    const r = new Uint32Array({n_regs});\n"));
+
+    let mut tails = Vec::new();
+    for (idx, g) in circuit.gadgets.iter().enumerate() {
+        match g {
+            Gadget::Egress { a } => {
+                let (sa, p) = (slot[*a], pool_starts[idx]);
+                tails.push(format!("(r[{sa}] ^ POOL[{p}]) >>> 0"));
+            }
+            _ => out.push_str(&emit_gadget(g, pool_starts[idx], &slot)),
+        }
+    }
+    out.push_str(&emit_return(&tails));
+    out.push_str("}\n");
+    out
+}
+
+/// `return` of the lone output, or of an array literal of all outputs in
+/// egress order.
+fn emit_return(tails: &[String]) -> String {
+    if let [only] = tails {
+        return format!("    return {only};\n");
+    }
+    let mut s = String::from("    return [\n");
+    for t in tails {
+        s.push_str(&format!("        {t},\n"));
+    }
+    s.push_str("    ];\n");
+    s
+}
+
+/// `x` rotated left by the constant `r`, reduced mod 32.
+fn rotl(x: &str, r: u32) -> String {
+    match r % 32 {
+        0 => x.to_string(),
+        r => format!("({x} << {r}) | ({x} >>> {})", 32 - r),
+    }
+}
+
+/// Emit the JavaScript statement(s) for one gadget, as `emit::emit_gadget`
+/// does for Rust.  AND is a single Beaver expansion expression.
+fn emit_gadget(g: &Gadget, p: usize, slot: &[usize]) -> String {
+    match g {
+        Gadget::PublicConst { out, .. } | Gadget::SecretConst { out, .. } => {
+            format!("    r[{}] = POOL[{p}];\n", slot[*out])
+        }
+        Gadget::Ingest { name, bytes: None, out, .. } => {
+            format!("    r[{}] = {} ^ POOL[{p}];\n", slot[*out], js_param_identifier(name))
+        }
+        Gadget::Ingest { bytes: Some(bw), out, .. } => {
+            let load = byte_word_load(&js_param_identifier(&bw.param), bw);
+            format!("    r[{}] = {load} ^ POOL[{p}];\n", slot[*out])
+        }
+        Gadget::Xor { a, b, out } => {
+            format!("    r[{}] = r[{}] ^ r[{}];\n", slot[*out], slot[*a], slot[*b])
+        }
+        Gadget::XorConst { a, out, .. } | Gadget::Remask { a, out, .. } => {
+            format!("    r[{}] = r[{}] ^ POOL[{p}];\n", slot[*out], slot[*a])
+        }
+        Gadget::AndConst { a, out, .. } => {
+            format!("    r[{}] = r[{}] & POOL[{p}];\n", slot[*out], slot[*a])
+        }
+        Gadget::Rotl { a, r, out } => {
+            format!("    r[{}] = {};\n", slot[*out], rotl(&format!("r[{}]", slot[*a]), *r))
+        }
+        Gadget::Shl { a, r, out } => {
+            format!("    r[{}] = r[{}] << {r};\n", slot[*out], slot[*a])
+        }
+        Gadget::Shr { a, r, out } => {
+            format!("    r[{}] = r[{}] >>> {r};\n", slot[*out], slot[*a])
+        }
+        Gadget::And { a, b, out, .. } => {
+            let (sa, sb, so) = (slot[*a], slot[*b], slot[*out]);
+            let (p1, p2, p3) = (p + 1, p + 2, p + 3);
+            let k = format!("POOL[{p}] ^ POOL[{p1}]");
+            format!("    r[{so}] = POOL[{p}] ^ (r[{sa}] & ({k} ^ POOL[{p3}])) ^ (r[{sb}] & ({k} ^ POOL[{p2}])) ^ (r[{sa}] & r[{sb}]);\n")
+        }
+        Gadget::Egress { .. } => unreachable!("egress is emitted by emit_js"),
+    }
+}
+
+// ---------------------------------------------------------------------------
+// TypeScript verifier
+// ---------------------------------------------------------------------------
+
+struct TsSyntax;
+
+impl PlainSyntax for TsSyntax {
+    fn bind(&self, out: WireId, expr: &str) -> String { format!("    const w{out} = {expr};\n") }
+    fn word(&self, k: u32) -> String { format!("0x{k:08x}") }
+    fn rotl(&self, x: &str, r: u32) -> String { rotl(x, r) }
+    fn shr(&self, x: &str, r: u32) -> String { format!("{x} >>> {r}") }
+    fn byte_word(&self, var: &str, bw: &ByteWord) -> String { byte_word_load(var, bw) }
+}
+
+/// Emit a self-contained TypeScript module exporting `EXPR_DIGEST` as a
+/// `Uint8Array` and the unmasked verifier function.
+///
+/// The TypeScript counterpart of
+/// [`emit_verifier_rust`](crate::emit::emit_verifier_rust), generated by the
+/// same gadget walk: parameters are named `input_{id}` with `id` from
+/// [`param_identifier`], `number` for a `u32` input and `Uint8Array` for a
+/// byte-slice input.  A single-output circuit returns `number`, one with `N`
+/// egress wires a tuple of `N` numbers in egress order — all in `0..2**32`.
+///
+/// Panics if `fn_name` fails [`check_js_fn_name`].
+pub fn emit_verifier_ts(circuit: &Circuit, fn_name: &str, expr_digest: &[u8; 32]) -> String {
+    check_js_fn_name(fn_name).unwrap_or_else(|e| panic!("{e}"));
+
+    let mut out = String::new();
+    out.push_str("// Generated by xorpl — do not edit manually.\n");
+    out.push_str("export const EXPR_DIGEST: Uint8Array = Uint8Array.of(\n");
+    for row in expr_digest.chunks(8) {
+        out.push_str(&format!("    {},\n", row.iter().map(|b| format!("0x{b:02x}")).collect::<Vec<_>>().join(", ")));
+    }
+    out.push_str(");\n\n");
+
+    let params = circuit.params().iter()
+        .map(|(name, len)| {
+            let ty = if len.is_some() { "Uint8Array" } else { "number" };
+            format!("input_{}: {ty}", param_identifier(name))
+        })
+        .collect::<Vec<_>>()
+        .join(", ");
+    let ret = match circuit.egress.len() {
+        1 => "number".to_string(),
+        n => format!("[{}]", vec!["number"; n].join(", ")),
+    };
+    out.push_str(&format!("export function {fn_name}({params}): {ret} {{\n"));
+
+    let mut tails = Vec::new();
+    for g in &circuit.gadgets {
+        match g {
+            Gadget::Egress { a } => tails.push(format!("w{a} >>> 0")),
+            _ => out.push_str(&emit_plain_gadget(g, &TsSyntax)),
+        }
+    }
+    out.push_str(&emit_return(&tails));
+    out.push_str("}\n");
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::expr::Expr;
+    use crate::lower::lower_to_circuit;
+    use rand::SeedableRng;
+
+    #[test]
+    fn names_avoid_javascript_reserved_words() {
+        assert_eq!(check_js_fn_name("checksum"), Ok(()));
+        for bad in ["new", "function", "Uint32Array", "fn", "r"] {
+            assert_eq!(check_js_fn_name(bad), Err(Error::InvalidIdentifier(bad.to_string())), "{bad}");
+        }
+        assert_eq!(js_param_identifier("new"), "q_new");
+        assert_eq!(js_param_identifier("fn"), "q_fn");
+        assert_eq!(js_param_identifier("nonce"), "nonce");
+
+        let circuit = lower_to_circuit(&Expr::xor(Expr::input("new"), Expr::input_bytes("this", 2)[0].clone()));
+        let mut rng = rand::rngs::StdRng::seed_from_u64(0);
+        let masked = MaskedCircuit::from_circuit(&circuit, &mut rng);
+        let js = emit_js(&masked, &circuit, "f", &mut rng, &[0; 32]);
+        assert!(js.contains("export function f(q_new, q_this) {"), "{js}");
+        assert!(js.contains("(q_this[0] | (q_this[1] << 8)) ^ POOL["), "{js}");
+    }
+
+    #[test]
+    fn same_rng_gives_same_registers_as_rust() {
+        let circuit = lower_to_circuit(&Expr::rotl(Expr::or(Expr::input("a"), Expr::input("b")), 5));
+        let masked = MaskedCircuit::from_circuit(&circuit, &mut rand::rngs::StdRng::seed_from_u64(1));
+        let rust = crate::emit::emit_rust(&masked, &circuit, "f", &mut rand::rngs::StdRng::seed_from_u64(2), &[0; 32]);
+        let js = emit_js(&masked, &circuit, "f", &mut rand::rngs::StdRng::seed_from_u64(2), &[0; 32]);
+        let ingests = |code: &str| code.lines().filter(|l| l.contains(" ^ POOL[0]") || l.contains(" ^ POOL[1]"))
+            .map(|l| l.trim().trim_end_matches(';').to_string()).collect::<Vec<_>>();
+        assert_eq!(ingests(&rust), ingests(&js));
+        assert_eq!(ingests(&js).len(), 2);
+    }
+
+    #[test]
+    fn verifier_ts_binds_same_wires_as_rust() {
+        let (a, b) = (Expr::input("a"), Expr::input("b"));
+        let circuit = crate::lower::lower_to_circuit_multi(&[
+            Expr::rotl_var(Expr::add(a.clone(), Expr::input_bytes("m", 3)[0].clone()), b.clone()),
+            Expr::shr(Expr::and(a, b), 7),
+        ]);
+        let rust = crate::emit::emit_verifier_rust(&circuit, "f", &[0; 32]);
+        let ts = emit_verifier_ts(&circuit, "f", &[0; 32]);
+        let wires = |code: &str, kw: &str| code.lines()
+            .filter_map(|l| l.trim().strip_prefix(kw)?.split(' ').next().map(str::to_string))
+            .collect::<Vec<_>>();
+        assert_eq!(wires(&rust, "let "), wires(&ts, "const w").iter().map(|w| format!("w{w}")).collect::<Vec<_>>());
+        assert!(ts.contains(" >>> 7;"), "{ts}");
+        assert!(ts.contains("): [number, number] {"), "{ts}");
+    }
+}
@@ -0,0 +1,451 @@
+//! Emission: `MaskedCircuit` → WebAssembly module, binary or text.
+//!
+//! [`emit_wasm`] writes a complete `.wasm` module straight from the masked
+//! circuit, without a Rust toolchain in between; [`emit_wat`] prints the
+//! same module as WAT for review.  Both walk the gadget schedule once into a
+//! small instruction list and then encode or print it, so the two always
+//! describe the same code.  Registers and `POOL` layout are those of
+//! [`emit_rust`](crate::emit::emit_rust) for the same `rng` state.
+//!
+//! # Module layout
+//!
+//! ```text
+//! memory (export "memory")            one page or more
+//!   [0, 32)                           EXPR_DIGEST
+//!   [32, 32 + 4 * POOL.len())         POOL, little-endian u32s
+//!   [INPUT_BASE, ..)                  free: byte inputs go here
+//! global (export "EXPR_DIGEST") i32   0, the digest's address
+//! global (export "INPUT_BASE")  i32   first free address, 16-aligned
+//! func   (export "<fn_name>")         (param i32 ...) (result i32 ...)
+//! ```
+//!
+//! Parameters come in the order of [`Circuit::params`]: a `u32` input is an
+//! `i32`, a byte-slice input the `i32` address of its bytes in `memory`
+//! (the caller writes them at or above `INPUT_BASE`, growing the memory if
+//! needed).  The function returns one `i32` per output word, using the
+//! multi-value extension for multi-output circuits.  Registers are locals;
+//! `POOL` entries are read with `i32.load` from the data segment.
+
+use crate::circuit::{ByteWord, Circuit, Gadget};
+use crate::emit::{allocate_registers, build_pool, check_fn_name, param_identifier, register_count};
+use crate::error::Error;
+use crate::mask::MaskedCircuit;
+
+/// Address of `EXPR_DIGEST` in linear memory; `POOL` follows it.
+const DIGEST_ADDR: u32 = 0;
+/// Address of `POOL[0]`.
+const POOL_ADDR: u32 = 32;
+/// WebAssembly page size.
+const PAGE: u32 = 65536;
+
+/// Export names the module uses besides the function.
+const WASM_EXPORTS: &[&str] = &["memory", "EXPR_DIGEST", "INPUT_BASE"];
+
+/// Check that `fn_name` can name the exported function: it must pass
+/// [`check_fn_name`] and not clash with the module's other exports
+/// (`memory`, `EXPR_DIGEST`, `INPUT_BASE`).
+pub fn check_wasm_fn_name(fn_name: &str) -> Result<(), Error> {
+    check_fn_name(fn_name)?;
+    if WASM_EXPORTS.contains(&fn_name) {
+        return Err(Error::InvalidIdentifier(fn_name.to_string()));
+    }
+    Ok(())
+}
+
+// ---------------------------------------------------------------------------
+// Instructions
+// ---------------------------------------------------------------------------
+
+/// The instructions the emitted function body uses.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Instr {
+    LocalGet(u32),
+    LocalSet(u32),
+    Const(u32),
+    /// `i32.load` with the given alignment exponent and static offset.
+    Load { align: u32, offset: u32 },
+    Load8U { offset: u32 },
+    And,
+    Or,
+    Xor,
+    Shl,
+    ShrU,
+    Rotl,
+}
+
+impl Instr {
+    fn encode(self, out: &mut Vec<u8>) {
+        match self {
+            Instr::LocalGet(i) => { out.push(0x20); leb_u32(out, i); }
+            Instr::LocalSet(i) => { out.push(0x21); leb_u32(out, i); }
+            Instr::Const(k)    => { out.push(0x41); leb_i32(out, k as i32); }
+            Instr::Load { align, offset } => { out.push(0x28); leb_u32(out, align); leb_u32(out, offset); }
+            Instr::Load8U { offset }      => { out.push(0x2d); leb_u32(out, 0); leb_u32(out, offset); }
+            Instr::And  => out.push(0x71),
+            Instr::Or   => out.push(0x72),
+            Instr::Xor  => out.push(0x73),
+            Instr::Shl  => out.push(0x74),
+            Instr::ShrU => out.push(0x76),
+            Instr::Rotl => out.push(0x77),
+        }
+    }
+
+    fn wat(self, locals: &[String]) -> String {
+        match self {
+            Instr::LocalGet(i) => format!("local.get ${}", locals[i as usize]),
+            Instr::LocalSet(i) => format!("local.set ${}", locals[i as usize]),
+            Instr::Const(k)    => format!("i32.const {}", k as i32),
+            Instr::Load { align: 2, offset: 0 } => "i32.load".to_string(),
+            Instr::Load { align, offset }       => format!("i32.load offset={offset} align={}", 1u32 << align),
+            Instr::Load8U { offset }            => format!("i32.load8_u offset={offset}"),
+            Instr::And  => "i32.and".to_string(),
+            Instr::Or   => "i32.or".to_string(),
+            Instr::Xor  => "i32.xor".to_string(),
+            Instr::Shl  => "i32.shl".to_string(),
+            Instr::ShrU => "i32.shr_u".to_string(),
+            Instr::Rotl => "i32.rotl".to_string(),
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Lowering the schedule
+// ---------------------------------------------------------------------------
+
+/// Everything both encodings need: the function body as one instruction
+/// list per gadget, the local names, and the memory image.
+struct Module {
+    /// Local names in index order: parameters, then registers.
+    locals:     Vec<String>,
+    n_params:   usize,
+    n_results:  usize,
+    /// `(gadget kind, instructions)` per gadget, egress last in schedule order.
+    body:       Vec<(&'static str, Vec<Instr>)>,
+    data:       Vec<u8>,
+    input_base: u32,
+    pages:      u32,
+}
+
+fn lower_module(masked: &MaskedCircuit, circuit: &Circuit, rng: &mut impl rand::RngCore, expr_digest: &[u8; 32]) -> Module {
+    let slot = allocate_registers(circuit, rng);
+    let (pool, pool_starts) = build_pool(masked);
+    let n_regs = register_count(circuit, &slot);
+    let params = circuit.params();
+
+    let mut locals: Vec<String> = params.iter().map(|(name, _)| format!("input_{}", param_identifier(name))).collect();
+    let reg = |s: usize| (params.len() + s) as u32;
+    locals.extend((0..n_regs).map(|s| format!("r{s}")));
+    let param_index = |name: &str| params.iter().position(|(n, _)| n == name).expect("ingest of an unknown parameter") as u32;
+    let pool_at = |p: usize| [Instr::Const(POOL_ADDR + 4 * p as u32), Instr::Load { align: 2, offset: 0 }];
+
+    let mut body = Vec::new();
+    for (idx, g) in circuit.gadgets.iter().enumerate() {
+        let p = pool_starts[idx];
+        let mut code = Vec::new();
+        match g {
+            Gadget::PublicConst { out, .. } | Gadget::SecretConst { out, .. } => {
+                code.extend(pool_at(p));
+                code.push(Instr::LocalSet(reg(slot[*out])));
+            }
+            Gadget::Ingest { name, bytes: None, out, .. } => {
+                code.push(Instr::LocalGet(param_index(name)));
+                code.extend(pool_at(p));
+                code.extend([Instr::Xor, Instr::LocalSet(reg(slot[*out]))]);
+            }
+            Gadget::Ingest { bytes: Some(bw), out, .. } => {
+                code.extend(byte_word_load(param_index(&bw.param), bw));
+                code.extend(pool_at(p));
+                code.extend([Instr::Xor, Instr::LocalSet(reg(slot[*out]))]);
+            }
+            Gadget::Xor { a, b, out } => {
+                code.extend([Instr::LocalGet(reg(slot[*a])), Instr::LocalGet(reg(slot[*b])), Instr::Xor]);
+                code.push(Instr::LocalSet(reg(slot[*out])));
+            }
+            Gadget::XorConst { a, out, .. } | Gadget::Remask { a, out, .. } => {
+                code.push(Instr::LocalGet(reg(slot[*a])));
+                code.extend(pool_at(p));
+                code.extend([Instr::Xor, Instr::LocalSet(reg(slot[*out]))]);
+            }
+            Gadget::AndConst { a, out, .. } => {
+                code.push(Instr::LocalGet(reg(slot[*a])));
+                code.extend(pool_at(p));
+                code.extend([Instr::And, Instr::LocalSet(reg(slot[*out]))]);
+            }
+            Gadget::Rotl { a, r, out } | Gadget::Shl { a, r, out } | Gadget::Shr { a, r, out } => {
+                let op = match g {
+                    Gadget::Rotl { .. } => Instr::Rotl,
+                    Gadget::Shl { .. }  => Instr::Shl,
+                    _                   => Instr::ShrU,
+                };
+                code.extend([Instr::LocalGet(reg(slot[*a])), Instr::Const(*r), op, Instr::LocalSet(reg(slot[*out]))]);
+            }
+            Gadget::And { a, b, out, .. } => {
+                // z = T ^ (a & mb) ^ (b & ma) ^ (a & b), all on the stack,
+                // with `ma = T ^ s ^ POOL[p + 2]` and `mb = T ^ s ^ POOL[p + 3]`.
+                let (ra, rb) = (reg(slot[*a]), reg(slot[*b]));
+                let mask = |share: usize| -> Vec<Instr> {
+                    let mut m = pool_at(p).to_vec();
+                    m.extend(pool_at(p + 1));
+                    m.push(Instr::Xor);
+                    m.extend(pool_at(p + share));
+                    m.push(Instr::Xor);
+                    m
+                };
+                code.extend(pool_at(p));
+                code.push(Instr::LocalGet(ra));
+                code.extend(mask(3));
+                code.extend([Instr::And, Instr::Xor, Instr::LocalGet(rb)]);
+                code.extend(mask(2));
+                code.extend([Instr::And, Instr::Xor]);
+                code.extend([Instr::LocalGet(ra), Instr::LocalGet(rb), Instr::And, Instr::Xor]);
+                code.push(Instr::LocalSet(reg(slot[*out])));
+            }
+            Gadget::Egress { a } => {
+                // Results stay on the stack in egress order.
+                code.push(Instr::LocalGet(reg(slot[*a])));
+                code.extend(pool_at(p));
+                code.push(Instr::Xor);
+            }
+        }
+        body.push((g.kind(), code));
+    }
+
+    let mut data = expr_digest.to_vec();
+    data.extend(pool.iter().flat_map(|v| v.to_le_bytes()));
+    debug_assert_eq!(data.len() as u32, POOL_ADDR + 4 * pool.len() as u32);
+    let input_base = (data.len() as u32).next_multiple_of(16);
+    let input_bytes: usize = params.iter().filter_map(|(_, len)| *len).sum();
+    let pages = (input_base + input_bytes as u32).div_ceil(PAGE).max(1);
+
+    Module {
+        locals,
+        n_params: params.len(),
+        n_results: circuit.egress.len(),
+        body,
+        data,
+        input_base,
+        pages,
+    }
+}
+
+/// Little-endian load of one byte-input word from the address in local
+/// `ptr`: a single unaligned `i32.load` for a full word, byte loads for the
+/// zero-padded final word.
+fn byte_word_load(ptr: u32, bw: &ByteWord) -> Vec<Instr> {
+    let base = (bw.word * 4) as u32;
+    let n = (bw.len - bw.word * 4).min(4) as u32;
+    if n == 4 {
+        return vec![Instr::LocalGet(ptr), Instr::Load { align: 0, offset: base }];
+    }
+    let mut code = Vec::new();
+    for i in 0..n {
+        code.extend([Instr::LocalGet(ptr), Instr::Load8U { offset: base + i }]);
+        if i > 0 {
+            code.extend([Instr::Const(8 * i), Instr::Shl, Instr::Or]);
+        }
+    }
+    code
+}
+
+// ---------------------------------------------------------------------------
+// Binary encoding
+// ---------------------------------------------------------------------------
+
+fn leb_u32(out: &mut Vec<u8>, mut v: u32) {
+    loop {
+        let byte = (v & 0x7f) as u8;
+        v >>= 7;
+        if v == 0 {
+            out.push(byte);
+            return;
+        }
+        out.push(byte | 0x80);
+    }
+}
+
+fn leb_i32(out: &mut Vec<u8>, mut v: i32) {
+    loop {
+        let byte = (v & 0x7f) as u8;
+        v >>= 7;
+        if (v == 0 && byte & 0x40 == 0) || (v == -1 && byte & 0x40 != 0) {
+            out.push(byte);
+            return;
+        }
+        out.push(byte | 0x80);
+    }
+}
+
+fn name(out: &mut Vec<u8>, s: &str) {
+    leb_u32(out, s.len() as u32);
+    out.extend_from_slice(s.as_bytes());
+}
+
+fn section(out: &mut Vec<u8>, id: u8, body: Vec<u8>) {
+    out.push(id);
+    leb_u32(out, body.len() as u32);
+    out.extend(body);
+}
+
+const I32: u8 = 0x7f;
+const END: u8 = 0x0b;
+
+/// Emit a complete WebAssembly module (binary format) for the given masked
+/// circuit; see the module docs for its layout and calling convention.
+///
+/// Takes the same arguments as [`emit_rust`](crate::emit::emit_rust).
+/// Panics if `fn_name` fails [`check_wasm_fn_name`].
+pub fn emit_wasm(masked: &MaskedCircuit, circuit: &Circuit, fn_name: &str, rng: &mut impl rand::RngCore, expr_digest: &[u8; 32]) -> Vec<u8> {
+    check_wasm_fn_name(fn_name).unwrap_or_else(|e| panic!("{e}"));
+    let m = lower_module(masked, circuit, rng, expr_digest);
+
+    let mut out = b"\0asm".to_vec();
+    out.extend(1u32.to_le_bytes());
+
+    // Type: the one function type.
+    let mut types = vec![1, 0x60];
+    leb_u32(&mut types, m.n_params as u32);
+    types.extend(std::iter::repeat_n(I32, m.n_params));
+    leb_u32(&mut types, m.n_results as u32);
+    types.extend(std::iter::repeat_n(I32, m.n_results));
+    section(&mut out, 1, types);
+
+    // Function: function 0 has type 0.
+    section(&mut out, 3, vec![1, 0]);
+
+    // Memory: one memory, no maximum.
+    let mut memory = vec![1, 0x00];
+    leb_u32(&mut memory, m.pages);
+    section(&mut out, 5, memory);
+
+    // Global: immutable EXPR_DIGEST and INPUT_BASE addresses.
+    let mut globals = vec![2];
+    for addr in [DIGEST_ADDR, m.input_base] {
+        globals.extend([I32, 0x00, 0x41]);
+        leb_i32(&mut globals, addr as i32);
+        globals.push(END);
+    }
+    section(&mut out, 6, globals);
+
+    // Export: function, memory, globals.
+    let mut exports = vec![4];
+    name(&mut exports, fn_name);
+    exports.extend([0x00, 0]);
+    name(&mut exports, "memory");
+    exports.extend([0x02, 0]);
+    name(&mut exports, "EXPR_DIGEST");
+    exports.extend([0x03, 0]);
+    name(&mut exports, "INPUT_BASE");
+    exports.extend([0x03, 1]);
+    section(&mut out, 7, exports);
+
+    // Code: one body, all non-parameter locals in a single i32 group.
+    let mut func = Vec::new();
+    let n_locals = (m.locals.len() - m.n_params) as u32;
+    if n_locals == 0 {
+        func.push(0);
+    } else {
+        func.push(1);
+        leb_u32(&mut func, n_locals);
+        func.push(I32);
+    }
+    for (_, code) in &m.body {
+        for instr in code {
+            instr.encode(&mut func);
+        }
+    }
+    func.push(END);
+    let mut code = vec![1];
+    leb_u32(&mut code, func.len() as u32);
+    code.extend(func);
+    section(&mut out, 10, code);
+
+    // Data: one active segment at address 0 holding digest and POOL.
+    let mut data = vec![1, 0x00, 0x41];
+    leb_i32(&mut data, DIGEST_ADDR as i32);
+    data.push(END);
+    leb_u32(&mut data, m.data.len() as u32);
+    data.extend(&m.data);
+    section(&mut out, 11, data);
+
+    out
+}
+
+/// The module [`emit_wasm`] writes, in WebAssembly text format.
+///
+/// Given the same `rng` state the two encode the same module: WAT assembles
+/// to the bytes of [`emit_wasm`] up to the names it keeps for review (`$r3`,
+/// `$input_msg`, one `;; KIND` comment per gadget).
+///
+/// Panics if `fn_name` fails [`check_wasm_fn_name`].
+pub fn emit_wat(masked: &MaskedCircuit, circuit: &Circuit, fn_name: &str, rng: &mut impl rand::RngCore, expr_digest: &[u8; 32]) -> String {
+    check_wasm_fn_name(fn_name).unwrap_or_else(|e| panic!("{e}"));
+    let m = lower_module(masked, circuit, rng, expr_digest);
+    let escaped = |bytes: &[u8]| bytes.iter().map(|b| format!("\\{b:02x}")).collect::<String>();
+
+    let mut out = String::new();
+    out.push_str(";; Generated by xorpl — do not edit manually.\n");
+    out.push_str("(module\n");
+    out.push_str(&format!("  (memory (export \"memory\") {})\n", m.pages));
+    out.push_str(&format!("  (global (export \"EXPR_DIGEST\") i32 (i32.const {DIGEST_ADDR}))\n"));
+    out.push_str(&format!("  (global (export \"INPUT_BASE\") i32 (i32.const {}))\n", m.input_base));
+    out.push_str(&format!("  (data (i32.const {DIGEST_ADDR})\n"));
+    out.push_str(&format!("    \"{}\" ;; EXPR_DIGEST\n", escaped(&m.data[..32])));
+    for (i, word) in m.data[POOL_ADDR as usize..].chunks(4).enumerate() {
+        let v = u32::from_le_bytes(word.try_into().unwrap());
+        out.push_str(&format!("    \"{}\" ;; POOL[{i}] = 0x{v:08x}\n", escaped(word)));
+    }
+    out.push_str("  )\n");
+
+    out.push_str(&format!("  (func (export \"{fn_name}\")"));
+    for p in &m.locals[..m.n_params] {
+        out.push_str(&format!(" (param ${p} i32)"));
+    }
+    if m.n_results > 0 {
+        out.push_str(" (result");
+        for _ in 0..m.n_results {
+            out.push_str(" i32");
+        }
+        out.push(')');
+    }
+    out.push('\n');
+    for l in &m.locals[m.n_params..] {
+        out.push_str(&format!("    (local ${l} i32)\n"));
+    }
+    for (kind, code) in &m.body {
+        out.push_str(&format!("    ;; {kind}\n"));
+        for instr in code {
+            out.push_str(&format!("    {}\n", instr.wat(&m.locals)));
+        }
+    }
+    out.push_str("  )\n");
+    out.push_str(")\n");
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn leb128_encodings() {
+        let enc_u = |v| { let mut o = Vec::new(); leb_u32(&mut o, v); o };
+        let enc_i = |v| { let mut o = Vec::new(); leb_i32(&mut o, v); o };
+        assert_eq!(enc_u(0), [0x00]);
+        assert_eq!(enc_u(127), [0x7f]);
+        assert_eq!(enc_u(128), [0x80, 0x01]);
+        assert_eq!(enc_u(624_485), [0xe5, 0x8e, 0x26]);
+        assert_eq!(enc_i(63), [0x3f]);
+        assert_eq!(enc_i(64), [0xc0, 0x00]);
+        assert_eq!(enc_i(-1), [0x7f]);
+        assert_eq!(enc_i(-123_456), [0xc0, 0xbb, 0x78]);
+        assert_eq!(enc_i(i32::MIN), [0x80, 0x80, 0x80, 0x80, 0x78]);
+    }
+
+    #[test]
+    fn fn_name_must_not_clash_with_exports() {
+        assert_eq!(check_wasm_fn_name("tag"), Ok(()));
+        for bad in ["memory", "INPUT_BASE", "EXPR_DIGEST", "fn"] {
+            assert_eq!(check_wasm_fn_name(bad), Err(Error::InvalidIdentifier(bad.to_string())));
+        }
+    }
+}
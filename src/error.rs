@@ -0,0 +1,51 @@
+//! Crate-wide error type for the `try_*` pipeline entry points.
+//!
+//! The plain entry points (`compile`, `lower_to_circuit`, `Circuit::eval`,
+//! ...) keep panicking on misuse, which suits tests and fixed expressions.
+//! Their `try_*` counterparts return [`Error`] instead, for callers compiling
+//! expressions they did not write.
+
+use std::fmt;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// `Circuit::try_eval` was given no value for this input.
+    MissingInput(String),
+    /// `Circuit::try_eval` was given a value for an input the circuit does
+    /// not ingest.
+    UnknownInput(String),
+    /// A function name that is not a Rust identifier or is one of the names
+    /// the emitted code uses itself (see `emit::check_fn_name`).
+    InvalidIdentifier(String),
+    /// `Circuit::validate` failed, at gadget index `gadget` when the failure
+    /// is specific to one gadget.
+    Validation { gadget: Option<usize>, message: String },
+    /// An `InputSchema` that does not list exactly the circuit's inputs.
+    SchemaMismatch(String),
+    /// An expression the pipeline cannot lower.
+    Unsupported(String),
+    /// `emit::emit_verifier_bundle` entries that clash with each other or do
+    /// not fit its `verify` signature.
+    Bundle(String),
+    /// A saved circuit or rotation key that cannot be decoded, or a rotation
+    /// key that does not fit the circuit it is applied to.
+    Load(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::MissingInput(name)      => write!(f, "missing value for input {name:?}"),
+            Error::UnknownInput(name)      => write!(f, "unknown input {name:?}"),
+            Error::InvalidIdentifier(name) => write!(f, "{name:?} is not a Rust identifier or is reserved by the emitter"),
+            Error::Validation { gadget: Some(idx), message } => write!(f, "gadget[{idx}] {message}"),
+            Error::Validation { gadget: None, message }      => write!(f, "{message}"),
+            Error::SchemaMismatch(message) => write!(f, "input schema mismatch: {message}"),
+            Error::Unsupported(message)    => write!(f, "unsupported: {message}"),
+            Error::Bundle(message)         => write!(f, "verifier bundle: {message}"),
+            Error::Load(message)           => write!(f, "cannot load: {message}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
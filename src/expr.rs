@@ -18,24 +18,38 @@
 //! | Variant | Notes |
 //! |---------|-------|
 //! | `Input` | Named runtime input; becomes `Gadget::Ingest` |
+//! | `InputWord` | One little-endian word of a byte-slice input; becomes `Gadget::Ingest` |
 //! | `PublicConst` | Compile-time constant the server also knows; mask = 0 |
 //! | `SecretConst` | Compile-time constant hidden by a fresh mask |
 //! | `Xor` | Free (mask propagates linearly) |
 //! | `And` | Metered — consumes one Beaver triple |
 //! | `Or` | Expanded to `Xor(Xor(a,b), And(a,b))` during lowering |
 //! | `Not` | Expanded to `XorConst(a, 0xffff_ffff)` |
-//! | `Add` | Expanded to `Builder::add32` (31 triples, word-level opt.) |
+//! | `Add` | Expanded to `Builder::add32` (31 triples, word-level opt.), or `Builder::add32_const` with a `PublicConst` operand |
+//! | `Sub` | Expanded to `Builder::sub32` (31 triples, borrow chain) |
+//! | `Neg` | Expanded to `Builder::add32_const(!a, 1)` (30 triples) |
+//! | `Mul` | Shift-and-add over `Builder::add32_from` (497 triples; fewer with a `PublicConst` operand) |
 //! | `Rotl` | Free |
+//! | `RotlVar` | `Builder::rotl_var` — masked 5-stage barrel shifter, 5 triples |
+//! | `Shl` / `Shr` | Free (logical shift; mask shifts with value) |
+//! | `Eq` | `Builder::eq32` — broadcast mask, 5 triples |
+//! | `Ltu` | `Builder::ltu32` — broadcast mask, 32 triples |
+//! | `IsZero` | `Builder::is_zero` — broadcast mask, 5 triples |
 //! | `Mux` | Expanded to `Xor(f, And(c, Xor(t, f)))` |
 
 use std::collections::{HashMap, HashSet};
 use std::rc::Rc;
 
+use crate::schema::InputSchema;
+
 #[derive(Clone, Debug)]
 pub enum Expr {
     // --- sources ---
     /// Named runtime input. Each unique name becomes one `Gadget::Ingest`.
     Input(String),
+    /// Word `word` of the `len`-byte input `name`, packed little-endian with
+    /// zero padding past `len`.  Build with [`Expr::input_bytes`].
+    InputWord { name: String, len: usize, word: usize },
     /// Compile-time constant visible to the server (mask is always 0).
     PublicConst(u32),
     /// Compile-time constant hidden from the image by a fresh per-rotation mask.
@@ -52,10 +66,36 @@ pub enum Expr {
     // --- arithmetic ---
     /// 32-bit wrapping addition. Lowered to `Builder::add32` (31 triples).
     Add(Rc<Expr>, Rc<Expr>),
+    /// 32-bit wrapping subtraction. Lowered to `Builder::sub32` (31 triples).
+    Sub(Rc<Expr>, Rc<Expr>),
+    /// 32-bit wrapping multiplication. Lowered to shift-and-add; see the
+    /// `lower` module for costs.
+    Mul(Rc<Expr>, Rc<Expr>),
+    /// 32-bit wrapping negation (`0 - a`). Lowered to `!a + 1` via
+    /// `Builder::add32_const` (30 triples).
+    Neg(Rc<Expr>),
 
     // --- shifts / rotations ---
     /// Left-rotation by a static amount. Free (mask rotates with value).
     Rotl(Rc<Expr>, u32),
+    /// Data-dependent left-rotation by `amount & 31`.  Lowered to a masked
+    /// barrel shifter: five `Mux` stages on the low bits of `amount`
+    /// (5 triples).
+    RotlVar(Rc<Expr>, Rc<Expr>),
+    /// Logical left shift by a static amount. Free (mask shifts with value).
+    /// Amounts of 32 or more produce 0.
+    Shl(Rc<Expr>, u32),
+    /// Logical right shift by a static amount. Free (mask shifts with value).
+    /// Amounts of 32 or more produce 0.
+    Shr(Rc<Expr>, u32),
+
+    // --- comparisons (produce Mux-ready broadcast masks) ---
+    /// `0xffff_ffff` if `a == b`, else `0`.
+    Eq(Rc<Expr>, Rc<Expr>),
+    /// `0xffff_ffff` if `a < b` (unsigned), else `0`.
+    Ltu(Rc<Expr>, Rc<Expr>),
+    /// `0xffff_ffff` if `a == 0`, else `0`.
+    IsZero(Rc<Expr>),
 
     // --- control flow (if-converted) ---
     /// Bitwise select: `cond & on_true | ~cond & on_false`.
@@ -74,6 +114,16 @@ impl Expr {
     pub fn input(name: &str) -> Rc<Self> {
         Rc::new(Self::Input(name.to_string()))
     }
+    /// A `len`-byte input, returned as its `ceil(len / 4)` words in order.
+    /// The emitted browser function and verifier take it as `&[u8; len]`.
+    /// There is no unsized `&[u8]` form: the word count is fixed at lowering,
+    /// so a caller with a slice converts it with `<&[u8; len]>::try_from`.
+    pub fn input_bytes(name: &str, len: usize) -> Vec<Rc<Self>> {
+        assert!(len > 0, "byte input {name:?} must be non-empty");
+        (0..len.div_ceil(4))
+            .map(|word| Rc::new(Self::InputWord { name: name.to_string(), len, word }))
+            .collect()
+    }
     pub fn public_const(k: u32) -> Rc<Self> {
         Rc::new(Self::PublicConst(k))
     }
@@ -89,18 +139,71 @@ impl Expr {
     pub fn or(a: Rc<Self>, b: Rc<Self>) -> Rc<Self> {
         Rc::new(Self::Or(a, b))
     }
+    #[allow(clippy::should_implement_trait)]
     pub fn not(a: Rc<Self>) -> Rc<Self> {
         Rc::new(Self::Not(a))
     }
+    #[allow(clippy::should_implement_trait)]
     pub fn add(a: Rc<Self>, b: Rc<Self>) -> Rc<Self> {
         Rc::new(Self::Add(a, b))
     }
+    #[allow(clippy::should_implement_trait)]
+    pub fn sub(a: Rc<Self>, b: Rc<Self>) -> Rc<Self> {
+        Rc::new(Self::Sub(a, b))
+    }
+    #[allow(clippy::should_implement_trait)]
+    pub fn mul(a: Rc<Self>, b: Rc<Self>) -> Rc<Self> {
+        Rc::new(Self::Mul(a, b))
+    }
+    /// `a * k` for a public constant `k` — the cheap multiplication form.
+    pub fn mul_const(a: Rc<Self>, k: u32) -> Rc<Self> {
+        Rc::new(Self::Mul(a, Self::public_const(k)))
+    }
+    #[allow(clippy::should_implement_trait)]
+    pub fn neg(a: Rc<Self>) -> Rc<Self> {
+        Rc::new(Self::Neg(a))
+    }
     pub fn rotl(a: Rc<Self>, r: u32) -> Rc<Self> {
         Rc::new(Self::Rotl(a, r))
     }
+    pub fn rotl_var(a: Rc<Self>, amount: Rc<Self>) -> Rc<Self> {
+        Rc::new(Self::RotlVar(a, amount))
+    }
+    /// Right-rotation, expressed as the equivalent `Rotl`.
+    pub fn rotr(a: Rc<Self>, r: u32) -> Rc<Self> {
+        Rc::new(Self::Rotl(a, (32 - r % 32) % 32))
+    }
+    #[allow(clippy::should_implement_trait)]
+    pub fn shl(a: Rc<Self>, r: u32) -> Rc<Self> {
+        Rc::new(Self::Shl(a, r))
+    }
+    #[allow(clippy::should_implement_trait)]
+    pub fn shr(a: Rc<Self>, r: u32) -> Rc<Self> {
+        Rc::new(Self::Shr(a, r))
+    }
+    pub fn eq(a: Rc<Self>, b: Rc<Self>) -> Rc<Self> {
+        Rc::new(Self::Eq(a, b))
+    }
+    pub fn ltu(a: Rc<Self>, b: Rc<Self>) -> Rc<Self> {
+        Rc::new(Self::Ltu(a, b))
+    }
+    pub fn is_zero(a: Rc<Self>) -> Rc<Self> {
+        Rc::new(Self::IsZero(a))
+    }
     pub fn mux(cond: Rc<Self>, on_true: Rc<Self>, on_false: Rc<Self>) -> Rc<Self> {
         Rc::new(Self::Mux { cond, on_true, on_false })
     }
+
+    /// Broadcast bit `i` of `x` to a full `Mux` condition (`0xffff_ffff` or
+    /// `0`).  Free: shifts the bit down to bit 0 and doubles it across the
+    /// word with rotate-and-XOR steps.
+    pub fn broadcast_bit(x: Rc<Self>, i: u32) -> Rc<Self> {
+        let mut b = Self::shr(Self::shl(x, 31 - i % 32), 31);
+        for s in [1u32, 2, 4, 8, 16] {
+            b = Self::xor(b.clone(), Self::rotl(b, s));
+        }
+        b
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -119,11 +222,31 @@ impl Expr {
 /// rotation) because it is computed from the original expression before any
 /// transforms are applied.
 pub fn expr_digest(root: &Rc<Expr>, key: Option<&[u8]>) -> [u8; 32] {
+    exprs_digest(std::slice::from_ref(root), key)
+}
+
+/// Digest of a multi-output function: every root, in output order.
+///
+/// Roots share one post-order serialization (so sub-expressions shared
+/// between outputs appear once), followed by a trailer listing the root
+/// indices.  A single root has no trailer, so `exprs_digest(&[e], key)`
+/// equals `expr_digest(&e, key)`.
+pub fn exprs_digest(roots: &[Rc<Expr>], key: Option<&[u8]>) -> [u8; 32] {
+    exprs_digest_with_schema(roots, None, key)
+}
+
+/// [`exprs_digest`] of a function with an explicit parameter order.
+///
+/// A schema appends a trailer listing each parameter's name and byte length
+/// (zero for a `u32` input) in order, so reordering or renaming parameters
+/// changes the digest.  `None` gives exactly [`exprs_digest`].
+pub fn exprs_digest_with_schema(roots: &[Rc<Expr>], schema: Option<&InputSchema>, key: Option<&[u8]>) -> [u8; 32] {
     // Collect nodes in post-order (children before parents), deduplicating by
     // Rc pointer identity so shared sub-expressions appear exactly once.
     let mut order: Vec<Rc<Expr>> = Vec::new();
     let mut visited: HashSet<*const Expr> = HashSet::new();
-    let mut stack: Vec<(Rc<Expr>, bool)> = vec![(Rc::clone(root), false)];
+    let mut stack: Vec<(Rc<Expr>, bool)> =
+        roots.iter().rev().map(|r| (Rc::clone(r), false)).collect();
 
     while let Some((node, children_done)) = stack.pop() {
         if children_done {
@@ -138,11 +261,14 @@ pub fn expr_digest(root: &Rc<Expr>, key: Option<&[u8]>) -> [u8; 32] {
         // so the first child is processed first).
         stack.push((Rc::clone(&node), true));
         match node.as_ref() {
-            Expr::Xor(a, b) | Expr::And(a, b) | Expr::Or(a, b) | Expr::Add(a, b) => {
+            Expr::Xor(a, b) | Expr::And(a, b) | Expr::Or(a, b) | Expr::Add(a, b)
+            | Expr::Sub(a, b) | Expr::Mul(a, b) | Expr::Eq(a, b) | Expr::Ltu(a, b)
+            | Expr::RotlVar(a, b) => {
                 stack.push((Rc::clone(b), false));
                 stack.push((Rc::clone(a), false));
             }
-            Expr::Not(a) | Expr::Rotl(a, _) => {
+            Expr::Not(a) | Expr::Neg(a) | Expr::IsZero(a) | Expr::Rotl(a, _) | Expr::Shl(a, _)
+            | Expr::Shr(a, _) => {
                 stack.push((Rc::clone(a), false));
             }
             Expr::Mux { cond, on_true, on_false } => {
@@ -150,7 +276,8 @@ pub fn expr_digest(root: &Rc<Expr>, key: Option<&[u8]>) -> [u8; 32] {
                 stack.push((Rc::clone(on_true), false));
                 stack.push((Rc::clone(cond), false));
             }
-            Expr::Input(_) | Expr::PublicConst(_) | Expr::SecretConst(_) => {}
+            Expr::Input(_) | Expr::InputWord { .. } | Expr::PublicConst(_)
+            | Expr::SecretConst(_) => {}
         }
     }
 
@@ -170,6 +297,13 @@ pub fn expr_digest(root: &Rc<Expr>, key: Option<&[u8]>) -> [u8; 32] {
                 bytes.extend_from_slice(&(name.len() as u32).to_le_bytes());
                 bytes.extend_from_slice(name.as_bytes());
             }
+            Expr::InputWord { name, len, word } => {
+                bytes.push(0x14);
+                bytes.extend_from_slice(&(name.len() as u32).to_le_bytes());
+                bytes.extend_from_slice(name.as_bytes());
+                bytes.extend_from_slice(&(*len as u32).to_le_bytes());
+                bytes.extend_from_slice(&(*word as u32).to_le_bytes());
+            }
             Expr::PublicConst(k) => {
                 bytes.push(0x02);
                 bytes.extend_from_slice(&k.to_le_bytes());
@@ -207,6 +341,49 @@ pub fn expr_digest(root: &Rc<Expr>, key: Option<&[u8]>) -> [u8; 32] {
                 bytes.extend_from_slice(&index[&Rc::as_ptr(a)].to_le_bytes());
                 bytes.extend_from_slice(&r.to_le_bytes());
             }
+            Expr::Shl(a, r) => {
+                bytes.push(0x0b);
+                bytes.extend_from_slice(&index[&Rc::as_ptr(a)].to_le_bytes());
+                bytes.extend_from_slice(&r.to_le_bytes());
+            }
+            Expr::Shr(a, r) => {
+                bytes.push(0x0c);
+                bytes.extend_from_slice(&index[&Rc::as_ptr(a)].to_le_bytes());
+                bytes.extend_from_slice(&r.to_le_bytes());
+            }
+            Expr::Sub(a, b) => {
+                bytes.push(0x0d);
+                bytes.extend_from_slice(&index[&Rc::as_ptr(a)].to_le_bytes());
+                bytes.extend_from_slice(&index[&Rc::as_ptr(b)].to_le_bytes());
+            }
+            Expr::Mul(a, b) => {
+                bytes.push(0x0f);
+                bytes.extend_from_slice(&index[&Rc::as_ptr(a)].to_le_bytes());
+                bytes.extend_from_slice(&index[&Rc::as_ptr(b)].to_le_bytes());
+            }
+            Expr::Neg(a) => {
+                bytes.push(0x0e);
+                bytes.extend_from_slice(&index[&Rc::as_ptr(a)].to_le_bytes());
+            }
+            Expr::Eq(a, b) => {
+                bytes.push(0x10);
+                bytes.extend_from_slice(&index[&Rc::as_ptr(a)].to_le_bytes());
+                bytes.extend_from_slice(&index[&Rc::as_ptr(b)].to_le_bytes());
+            }
+            Expr::Ltu(a, b) => {
+                bytes.push(0x11);
+                bytes.extend_from_slice(&index[&Rc::as_ptr(a)].to_le_bytes());
+                bytes.extend_from_slice(&index[&Rc::as_ptr(b)].to_le_bytes());
+            }
+            Expr::IsZero(a) => {
+                bytes.push(0x12);
+                bytes.extend_from_slice(&index[&Rc::as_ptr(a)].to_le_bytes());
+            }
+            Expr::RotlVar(a, amount) => {
+                bytes.push(0x13);
+                bytes.extend_from_slice(&index[&Rc::as_ptr(a)].to_le_bytes());
+                bytes.extend_from_slice(&index[&Rc::as_ptr(amount)].to_le_bytes());
+            }
             Expr::Mux { cond, on_true, on_false } => {
                 bytes.push(0x0a);
                 bytes.extend_from_slice(&index[&Rc::as_ptr(cond)].to_le_bytes());
@@ -216,6 +393,24 @@ pub fn expr_digest(root: &Rc<Expr>, key: Option<&[u8]>) -> [u8; 32] {
         }
     }
 
+    if roots.len() > 1 {
+        bytes.push(0xf0);
+        bytes.extend_from_slice(&(roots.len() as u32).to_le_bytes());
+        for r in roots {
+            bytes.extend_from_slice(&index[&Rc::as_ptr(r)].to_le_bytes());
+        }
+    }
+
+    if let Some(schema) = schema {
+        bytes.push(0xf1);
+        bytes.extend_from_slice(&(schema.params().len() as u32).to_le_bytes());
+        for (name, len) in schema.params() {
+            bytes.extend_from_slice(&(name.len() as u32).to_le_bytes());
+            bytes.extend_from_slice(name.as_bytes());
+            bytes.extend_from_slice(&(len.unwrap_or(0) as u32).to_le_bytes());
+        }
+    }
+
     use sha2::Digest as _;
     match key {
         None => {
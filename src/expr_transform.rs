@@ -9,20 +9,73 @@
 //! - `constant_fold`    — evaluate constant sub-expressions at compile time
 //! - `reassociate`      — flatten XOR/AND chains, shuffle operands, re-bracket
 //! - `inject_decoys`    — splice in dead sub-expressions (XOR-zero or MUX dead
-//!                        branch) that pad the circuit with AND-triple noise
+//!   branch) that pad the circuit with AND-triple noise; `inject_decoys_at`
+//!   splices an exact number of them
 //! - `apply_identities` — randomly apply algebraic identities
-//!                        (double-NOT, De Morgan, XOR flip)
+//!   (double-NOT, De Morgan, XOR flip)
 //! - `strong_rotate`    — pipeline entry point:
-//!                        `constant_fold → reassociate → inject_decoys
-//!                         → apply_identities → constant_fold`
+//!   `constant_fold → reassociate → inject_decoys → apply_identities → constant_fold`
 //!
 //! `decoy_xor_zero` and `decoy_mux` are also exposed as standalone public
 //! helpers for deterministic decoy construction (used directly in fixture
 //! builders).
+//!
+//! [`strong_rotate_multi_with`] takes a [`RotateOptions`] to disable
+//! `reassociate` or change the per-node decoy and identity probabilities;
+//! the defaults are what `strong_rotate` uses.
 
 use std::rc::Rc;
 use crate::expr::Expr;
 
+// ---------------------------------------------------------------------------
+// Options
+// ---------------------------------------------------------------------------
+
+/// A probability `num / den`, drawn as `rng.next_u32() % den < num`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Chance {
+    num: u32,
+    den: u32,
+}
+
+impl Chance {
+    pub const NEVER:  Chance = Chance { num: 0, den: 1 };
+    pub const ALWAYS: Chance = Chance { num: 1, den: 1 };
+
+    /// `num / den`.  Panics if `den` is zero.
+    pub const fn new(num: u32, den: u32) -> Chance {
+        assert!(den > 0, "Chance denominator must be non-zero");
+        Chance { num, den }
+    }
+
+    pub(crate) fn roll(self, rng: &mut impl rand::RngCore) -> bool {
+        rng.next_u32() % self.den < self.num
+    }
+}
+
+/// Knobs for [`strong_rotate_multi_with`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RotateOptions {
+    /// Run `reassociate` (shuffle and re-bracket XOR/AND chains).
+    pub reassociate: bool,
+    /// Per-node probability that `inject_decoys` splices in a decoy
+    /// (default 1/7).  [`Chance::NEVER`] skips the pass.
+    pub decoys: Chance,
+    /// Per-node probability of each `apply_identities` rewrite (default
+    /// 3/10).  [`Chance::NEVER`] skips the pass.
+    pub identities: Chance,
+}
+
+impl Default for RotateOptions {
+    fn default() -> Self {
+        RotateOptions {
+            reassociate: true,
+            decoys:      Chance::new(1, 7),
+            identities:  Chance::new(3, 10),
+        }
+    }
+}
+
 // ---------------------------------------------------------------------------
 // constant_fold
 // ---------------------------------------------------------------------------
@@ -56,6 +109,18 @@ use crate::expr::Expr;
 /// | `Or(x, PublicConst(0))` | `x` |
 /// | `Not(PublicConst(k))` | `PublicConst(!k)` |
 /// | `Rotl(PublicConst(k), r)` | `PublicConst(k.rotate_left(r))` |
+/// | `RotlVar(x, PublicConst(k))` | `Rotl(x, k & 31)` |
+/// | `Sub(x, PublicConst(0))` | `x` |
+/// | `Sub(PublicConst(0), x)` | `Neg(x)` |
+/// | `Sub(x, x)` (same node) | `PublicConst(0)` |
+/// | `Mul(x, PublicConst(0))` | `PublicConst(0)` |
+/// | `Mul(x, PublicConst(1))` | `x` |
+/// | `Neg(Neg(x))` | `x` |
+/// | `Eq` / `Ltu` / `IsZero` of constants | `PublicConst(0xffff_ffff or 0)` |
+/// | `Eq(x, x)` / `Ltu(x, x)` (same node) | `PublicConst(0xffff_ffff)` / `PublicConst(0)` |
+/// | `Neg(PublicConst(k))` | `PublicConst(k.wrapping_neg())` |
+/// | `Shl(x, 0)` / `Shr(x, 0)` | `x` |
+/// | `Shl(PublicConst(k), r)` | `PublicConst(k << r)` (0 for `r >= 32`) |
 pub fn constant_fold(expr: &Rc<Expr>) -> Rc<Expr> {
     let mut memo = std::collections::HashMap::new();
     fold_node(expr, &mut memo)
@@ -72,7 +137,9 @@ fn fold_node(
 
     let result = match expr.as_ref() {
         // Leaves: return as-is.
-        Expr::Input(_) | Expr::PublicConst(_) | Expr::SecretConst(_) => expr.clone(),
+        Expr::Input(_) | Expr::InputWord { .. } | Expr::PublicConst(_) | Expr::SecretConst(_) => {
+            expr.clone()
+        }
 
         Expr::Xor(a, b) => {
             let a = fold_node(a, memo);
@@ -155,6 +222,91 @@ fn fold_node(
             }
         }
 
+        Expr::Sub(a, b) => {
+            let a = fold_node(a, memo);
+            let b = fold_node(b, memo);
+            match (a.as_ref(), b.as_ref()) {
+                // identity: x - 0 = x
+                (_, Expr::PublicConst(0)) => a,
+                // 0 - x = -x
+                (Expr::PublicConst(0), _) => fold_node(&Expr::neg(b), memo),
+                // constant folding
+                (Expr::PublicConst(x), Expr::PublicConst(y)) => {
+                    Expr::public_const(x.wrapping_sub(*y))
+                }
+                (Expr::SecretConst(x), Expr::SecretConst(y)) |
+                (Expr::SecretConst(x), Expr::PublicConst(y)) |
+                (Expr::PublicConst(x), Expr::SecretConst(y)) => {
+                    Expr::secret_const(x.wrapping_sub(*y))
+                }
+                // x - x = 0
+                _ if Rc::ptr_eq(&a, &b) => Expr::public_const(0),
+                _ => Expr::sub(a, b),
+            }
+        }
+
+        Expr::Mul(a, b) => {
+            let a = fold_node(a, memo);
+            let b = fold_node(b, memo);
+            match (a.as_ref(), b.as_ref()) {
+                // annihilator: x * 0 = 0
+                (_, Expr::PublicConst(0)) | (Expr::PublicConst(0), _) => Expr::public_const(0),
+                // identity: x * 1 = x  (either order)
+                (_, Expr::PublicConst(1)) => a,
+                (Expr::PublicConst(1), _) => b,
+                // constant folding
+                (Expr::PublicConst(x), Expr::PublicConst(y)) => {
+                    Expr::public_const(x.wrapping_mul(*y))
+                }
+                (Expr::SecretConst(x), Expr::SecretConst(y)) => {
+                    Expr::secret_const(x.wrapping_mul(*y))
+                }
+                (Expr::SecretConst(x), Expr::PublicConst(y)) |
+                (Expr::PublicConst(y), Expr::SecretConst(x)) => {
+                    Expr::secret_const(x.wrapping_mul(*y))
+                }
+                _ => Expr::mul(a, b),
+            }
+        }
+
+        Expr::Eq(a, b) => {
+            let a = fold_node(a, memo);
+            let b = fold_node(b, memo);
+            match (const_value(&a), const_value(&b)) {
+                (Some((x, sx)), Some((y, sy))) => mask_const(x == y, sx || sy),
+                _ if Rc::ptr_eq(&a, &b) => Expr::public_const(0xffff_ffff),
+                _ => Expr::eq(a, b),
+            }
+        }
+
+        Expr::Ltu(a, b) => {
+            let a = fold_node(a, memo);
+            let b = fold_node(b, memo);
+            match (const_value(&a), const_value(&b)) {
+                (Some((x, sx)), Some((y, sy))) => mask_const(x < y, sx || sy),
+                _ if Rc::ptr_eq(&a, &b) => Expr::public_const(0),
+                _ => Expr::ltu(a, b),
+            }
+        }
+
+        Expr::IsZero(a) => {
+            let a = fold_node(a, memo);
+            match const_value(&a) {
+                Some((x, secret)) => mask_const(x == 0, secret),
+                None => Expr::is_zero(a),
+            }
+        }
+
+        Expr::Neg(a) => {
+            let a = fold_node(a, memo);
+            match a.as_ref() {
+                Expr::PublicConst(k) => Expr::public_const(k.wrapping_neg()),
+                Expr::SecretConst(k) => Expr::secret_const(k.wrapping_neg()),
+                Expr::Neg(x) => x.clone(),
+                _ => Expr::neg(a),
+            }
+        }
+
         Expr::Rotl(a, r) => {
             let a = fold_node(a, memo);
             let r = *r;
@@ -165,6 +317,41 @@ fn fold_node(
             }
         }
 
+        Expr::RotlVar(a, amount) => {
+            let a = fold_node(a, memo);
+            let amount = fold_node(amount, memo);
+            match (const_value(&a), amount.as_ref()) {
+                (Some((k, secret)), Expr::PublicConst(n)) =>
+                    if secret { Expr::secret_const(k.rotate_left(n & 31)) }
+                    else { Expr::public_const(k.rotate_left(n & 31)) },
+                (Some((k, _)), Expr::SecretConst(n)) => Expr::secret_const(k.rotate_left(n & 31)),
+                (None, Expr::PublicConst(n)) => Expr::rotl(a, n & 31),
+                _ => Expr::rotl_var(a, amount),
+            }
+        }
+
+        Expr::Shl(a, r) => {
+            let a = fold_node(a, memo);
+            let r = *r;
+            match a.as_ref() {
+                _ if r == 0 => a,
+                Expr::PublicConst(k) => Expr::public_const(k.checked_shl(r).unwrap_or(0)),
+                Expr::SecretConst(k) => Expr::secret_const(k.checked_shl(r).unwrap_or(0)),
+                _ => Expr::shl(a, r),
+            }
+        }
+
+        Expr::Shr(a, r) => {
+            let a = fold_node(a, memo);
+            let r = *r;
+            match a.as_ref() {
+                _ if r == 0 => a,
+                Expr::PublicConst(k) => Expr::public_const(k.checked_shr(r).unwrap_or(0)),
+                Expr::SecretConst(k) => Expr::secret_const(k.checked_shr(r).unwrap_or(0)),
+                _ => Expr::shr(a, r),
+            }
+        }
+
         Expr::Mux { cond, on_true, on_false } => {
             let cond     = fold_node(cond, memo);
             let on_true  = fold_node(on_true, memo);
@@ -181,6 +368,21 @@ fn fold_node(
     result
 }
 
+/// `Some((value, is_secret))` for a constant node.
+fn const_value(e: &Rc<Expr>) -> Option<(u32, bool)> {
+    match e.as_ref() {
+        Expr::PublicConst(k) => Some((*k, false)),
+        Expr::SecretConst(k) => Some((*k, true)),
+        _ => None,
+    }
+}
+
+/// Broadcast-mask constant for a folded comparison.
+fn mask_const(pred: bool, secret: bool) -> Rc<Expr> {
+    let k = if pred { 0xffff_ffff } else { 0 };
+    if secret { Expr::secret_const(k) } else { Expr::public_const(k) }
+}
+
 // ---------------------------------------------------------------------------
 // reassociate
 // ---------------------------------------------------------------------------
@@ -233,7 +435,9 @@ fn reassoc_node(
         }
 
         // All other nodes: recurse into children, rebuild structurally.
-        Expr::Input(_) | Expr::PublicConst(_) | Expr::SecretConst(_) => expr.clone(),
+        Expr::Input(_) | Expr::InputWord { .. } | Expr::PublicConst(_) | Expr::SecretConst(_) => {
+            expr.clone()
+        }
         Expr::Or(a, b) => {
             let a = reassoc_node(a, rng, memo);
             let b = reassoc_node(b, rng, memo);
@@ -245,7 +449,36 @@ fn reassoc_node(
             let b = reassoc_node(b, rng, memo);
             Expr::add(a, b)
         }
+        Expr::Sub(a, b) => {
+            let a = reassoc_node(a, rng, memo);
+            let b = reassoc_node(b, rng, memo);
+            Expr::sub(a, b)
+        }
+        Expr::Mul(a, b) => {
+            let a = reassoc_node(a, rng, memo);
+            let b = reassoc_node(b, rng, memo);
+            Expr::mul(a, b)
+        }
+        Expr::Eq(a, b) => {
+            let a = reassoc_node(a, rng, memo);
+            let b = reassoc_node(b, rng, memo);
+            Expr::eq(a, b)
+        }
+        Expr::Ltu(a, b) => {
+            let a = reassoc_node(a, rng, memo);
+            let b = reassoc_node(b, rng, memo);
+            Expr::ltu(a, b)
+        }
+        Expr::IsZero(a) => Expr::is_zero(reassoc_node(a, rng, memo)),
+        Expr::Neg(a) => Expr::neg(reassoc_node(a, rng, memo)),
         Expr::Rotl(a, r) => Expr::rotl(reassoc_node(a, rng, memo), *r),
+        Expr::RotlVar(a, b) => {
+            let a = reassoc_node(a, rng, memo);
+            let b = reassoc_node(b, rng, memo);
+            Expr::rotl_var(a, b)
+        }
+        Expr::Shl(a, r) => Expr::shl(reassoc_node(a, rng, memo), *r),
+        Expr::Shr(a, r) => Expr::shr(reassoc_node(a, rng, memo), *r),
         Expr::Mux { cond, on_true, on_false } => Expr::mux(
             reassoc_node(cond, rng, memo),
             reassoc_node(on_true, rng, memo),
@@ -330,7 +563,8 @@ fn fold_chain(mut operands: Vec<Rc<Expr>>, is_and: bool) -> Rc<Expr> {
 /// 1. Pre-pass: collect a pool of candidate wire `Rc<Expr>` nodes (any node
 ///    that is not a constant).
 /// 2. For each node in the tree (bottom-up, memo'd), with probability ~1/7
-///    splice in one decoy chosen randomly from the two styles above.
+///    (see [`RotateOptions::decoys`]) splice in one decoy chosen randomly
+///    from the two styles above.
 /// 3. `constant_fold` run after this pass will *not* collapse Style B decoys
 ///    because `secret_const` conditions are not folded (only `public_const`
 ///    conditions are).
@@ -348,7 +582,81 @@ pub fn inject_decoys(expr: &Rc<Expr>, rng: &mut impl rand::RngCore) -> Rc<Expr>
         return expr.clone();
     }
     let mut memo = std::collections::HashMap::new();
-    decoy_node(expr, rng, &pool, &mut memo)
+    decoy_node(expr, rng, &pool, DecoySites::Random(RotateOptions::default().decoys), &mut memo)
+}
+
+/// Splice exactly `count` decoys into a multi-output function, at distinct
+/// nodes drawn uniformly from its non-constant nodes (fewer if there are
+/// fewer such nodes).  Decoy styles and operands are chosen as in
+/// [`inject_decoys`].
+///
+/// Each decoy adds two AND triples once lowered.  Used by the pipeline's
+/// budgeted mode, which calls it repeatedly until the lowered circuit lands
+/// in a target range.
+pub fn inject_decoys_at(roots: &[Rc<Expr>], count: usize, rng: &mut impl rand::RngCore) -> Vec<Rc<Expr>> {
+    let mut pool: Vec<Rc<Expr>> = Vec::new();
+    let mut seen: std::collections::HashSet<*const Expr> = Default::default();
+    for r in roots {
+        collect_candidates(r, &mut pool, &mut seen);
+    }
+    if pool.len() < 2 || count == 0 {
+        return roots.to_vec();
+    }
+    // Partial Fisher-Yates: the first `count` entries become the sites.
+    let mut order: Vec<usize> = (0..pool.len()).collect();
+    let count = count.min(pool.len());
+    for i in 0..count {
+        let j = i + (rng.next_u32() as usize) % (order.len() - i);
+        order.swap(i, j);
+    }
+    let sites: std::collections::HashSet<*const Expr> =
+        order[..count].iter().map(|&i| Rc::as_ptr(&pool[i])).collect();
+
+    let mut memo = std::collections::HashMap::new();
+    roots.iter().map(|r| decoy_node(r, rng, &pool, DecoySites::Chosen(&sites), &mut memo)).collect()
+}
+
+/// Where [`decoy_node`] splices decoys.
+#[derive(Clone, Copy)]
+enum DecoySites<'a> {
+    /// At each node independently, with this probability.
+    Random(Chance),
+    /// At exactly these nodes.
+    Chosen(&'a std::collections::HashSet<*const Expr>),
+}
+
+impl DecoySites<'_> {
+    fn fires(self, node: *const Expr, rng: &mut impl rand::RngCore) -> bool {
+        match self {
+            DecoySites::Random(chance) => chance.roll(rng),
+            DecoySites::Chosen(sites)  => sites.contains(&node),
+        }
+    }
+
+    /// Two decoy operands for the decoy about to wrap `node` (already
+    /// rebuilt).  At random sites they come from the whole candidate pool;
+    /// at chosen sites only from nodes already rebuilt (or `node` itself),
+    /// so that an operand never lowers a second copy of a sub-expression
+    /// and each decoy costs about the same.
+    fn operands(
+        self,
+        pool: &[Rc<Expr>],
+        node: &Rc<Expr>,
+        memo: &std::collections::HashMap<*const Expr, Rc<Expr>>,
+        rng:  &mut impl rand::RngCore,
+    ) -> (Rc<Expr>, Rc<Expr>) {
+        let mut pick = |from: &[Rc<Expr>]| from[(rng.next_u32() as usize) % from.len()].clone();
+        match self {
+            DecoySites::Random(_) => (pick(pool), pick(pool)),
+            DecoySites::Chosen(_) => {
+                let built: Vec<Rc<Expr>> = pool.iter()
+                    .filter_map(|e| memo.get(&Rc::as_ptr(e)).cloned())
+                    .chain(std::iter::once(node.clone()))
+                    .collect();
+                (pick(&built), pick(&built))
+            }
+        }
+    }
 }
 
 fn collect_candidates(
@@ -356,19 +664,24 @@ fn collect_candidates(
     pool: &mut Vec<Rc<Expr>>,
     seen: &mut std::collections::HashSet<*const Expr>,
 ) {
-    let ptr = Rc::as_ptr(expr) as *const Expr;
+    let ptr = Rc::as_ptr(expr);
     if !seen.insert(ptr) { return; }
     match expr.as_ref() {
         Expr::PublicConst(_) | Expr::SecretConst(_) => {}
         _ => {
             pool.push(expr.clone());
             match expr.as_ref() {
-                Expr::Input(_) => {}
-                Expr::Xor(a, b) | Expr::And(a, b) | Expr::Or(a, b) | Expr::Add(a, b) => {
+                Expr::Input(_) | Expr::InputWord { .. } => {}
+                Expr::Xor(a, b) | Expr::And(a, b) | Expr::Or(a, b) | Expr::Add(a, b)
+                | Expr::Sub(a, b) | Expr::Mul(a, b) | Expr::Eq(a, b) | Expr::Ltu(a, b)
+                | Expr::RotlVar(a, b) => {
                     collect_candidates(a, pool, seen);
                     collect_candidates(b, pool, seen);
                 }
-                Expr::Not(a) | Expr::Rotl(a, _) => collect_candidates(a, pool, seen),
+                Expr::Not(a) | Expr::Neg(a) | Expr::IsZero(a) | Expr::Rotl(a, _)
+                | Expr::Shl(a, _) | Expr::Shr(a, _) => {
+                    collect_candidates(a, pool, seen)
+                }
                 Expr::Mux { cond, on_true, on_false } => {
                     collect_candidates(cond, pool, seen);
                     collect_candidates(on_true, pool, seen);
@@ -402,10 +715,11 @@ pub fn decoy_mux(node: Rc<Expr>, garbage: Rc<Expr>) -> Rc<Expr> {
 }
 
 fn decoy_node(
-    expr: &Rc<Expr>,
-    rng:  &mut impl rand::RngCore,
-    pool: &[Rc<Expr>],
-    memo: &mut std::collections::HashMap<*const Expr, Rc<Expr>>,
+    expr:  &Rc<Expr>,
+    rng:   &mut impl rand::RngCore,
+    pool:  &[Rc<Expr>],
+    sites: DecoySites<'_>,
+    memo:  &mut std::collections::HashMap<*const Expr, Rc<Expr>>,
 ) -> Rc<Expr> {
     let ptr = Rc::as_ptr(expr);
     if let Some(cached) = memo.get(&ptr) {
@@ -413,42 +727,65 @@ fn decoy_node(
     }
 
     let result = match expr.as_ref() {
-        Expr::Input(_) | Expr::PublicConst(_) | Expr::SecretConst(_) => expr.clone(),
+        Expr::Input(_) | Expr::InputWord { .. } | Expr::PublicConst(_) | Expr::SecretConst(_) => {
+            expr.clone()
+        }
         Expr::Xor(a, b) => Expr::xor(
-            decoy_node(a, rng, pool, memo),
-            decoy_node(b, rng, pool, memo),
+            decoy_node(a, rng, pool, sites, memo),
+            decoy_node(b, rng, pool, sites, memo),
         ),
         Expr::And(a, b) => Expr::and(
-            decoy_node(a, rng, pool, memo),
-            decoy_node(b, rng, pool, memo),
+            decoy_node(a, rng, pool, sites, memo),
+            decoy_node(b, rng, pool, sites, memo),
         ),
         Expr::Or(a, b) => Expr::or(
-            decoy_node(a, rng, pool, memo),
-            decoy_node(b, rng, pool, memo),
+            decoy_node(a, rng, pool, sites, memo),
+            decoy_node(b, rng, pool, sites, memo),
         ),
-        Expr::Not(a) => Expr::not(decoy_node(a, rng, pool, memo)),
+        Expr::Not(a) => Expr::not(decoy_node(a, rng, pool, sites, memo)),
         Expr::Add(a, b) => Expr::add(
-            decoy_node(a, rng, pool, memo),
-            decoy_node(b, rng, pool, memo),
+            decoy_node(a, rng, pool, sites, memo),
+            decoy_node(b, rng, pool, sites, memo),
+        ),
+        Expr::Sub(a, b) => Expr::sub(
+            decoy_node(a, rng, pool, sites, memo),
+            decoy_node(b, rng, pool, sites, memo),
+        ),
+        Expr::Mul(a, b) => Expr::mul(
+            decoy_node(a, rng, pool, sites, memo),
+            decoy_node(b, rng, pool, sites, memo),
+        ),
+        Expr::Eq(a, b) => Expr::eq(
+            decoy_node(a, rng, pool, sites, memo),
+            decoy_node(b, rng, pool, sites, memo),
+        ),
+        Expr::Ltu(a, b) => Expr::ltu(
+            decoy_node(a, rng, pool, sites, memo),
+            decoy_node(b, rng, pool, sites, memo),
+        ),
+        Expr::IsZero(a) => Expr::is_zero(decoy_node(a, rng, pool, sites, memo)),
+        Expr::Neg(a) => Expr::neg(decoy_node(a, rng, pool, sites, memo)),
+        Expr::Rotl(a, r) => Expr::rotl(decoy_node(a, rng, pool, sites, memo), *r),
+        Expr::Shl(a, r) => Expr::shl(decoy_node(a, rng, pool, sites, memo), *r),
+        Expr::Shr(a, r) => Expr::shr(decoy_node(a, rng, pool, sites, memo), *r),
+        Expr::RotlVar(a, b) => Expr::rotl_var(
+            decoy_node(a, rng, pool, sites, memo),
+            decoy_node(b, rng, pool, sites, memo),
         ),
-        Expr::Rotl(a, r) => Expr::rotl(decoy_node(a, rng, pool, memo), *r),
         Expr::Mux { cond, on_true, on_false } => Expr::mux(
-            decoy_node(cond, rng, pool, memo),
-            decoy_node(on_true, rng, pool, memo),
-            decoy_node(on_false, rng, pool, memo),
+            decoy_node(cond, rng, pool, sites, memo),
+            decoy_node(on_true, rng, pool, sites, memo),
+            decoy_node(on_false, rng, pool, sites, memo),
         ),
     };
 
-    // ~1/7 ≈ 14% chance: inject a decoy. Style chosen at random from two options.
-    let r = rng.next_u32();
-    let result = if r % 7 == 0 {
-        let style = rng.next_u32();
-        let i = (rng.next_u32() as usize) % pool.len();
-        let j = (rng.next_u32() as usize) % pool.len();
-        let p = pool[i].clone();
-        let q = pool[j].clone();
+    // At a chosen site, or with probability `chance` (by default 1/7 ≈ 14%):
+    // inject a decoy. Style chosen at random from two options.
+    let result = if sites.fires(ptr, rng) {
+        let style  = rng.next_u32();
+        let (p, q) = sites.operands(pool, &result, memo, rng);
 
-        if style % 2 == 0 {
+        if style.is_multiple_of(2) {
             // Style A: XOR-zero.
             decoy_xor_zero(result, p, q)
         } else {
@@ -476,7 +813,7 @@ fn decoy_node(
 
 /// Randomly apply local algebraic identities at each node.
 ///
-/// # Identities (each applied with independent probability `p ≈ 0.3`)
+/// # Identities (each applied with independent probability `p ≈ 0.3`, see [`RotateOptions::identities`])
 ///
 /// | Pattern | Replacement | Cost delta |
 /// |---------|-------------|------------|
@@ -493,13 +830,14 @@ fn decoy_node(
 /// identity rewrites may have introduced.
 pub fn apply_identities(expr: &Rc<Expr>, rng: &mut impl rand::RngCore) -> Rc<Expr> {
     let mut memo = std::collections::HashMap::new();
-    apply_ident_node(expr, rng, &mut memo)
+    apply_ident_node(expr, rng, RotateOptions::default().identities, &mut memo)
 }
 
 fn apply_ident_node(
-    expr: &Rc<Expr>,
-    rng:  &mut impl rand::RngCore,
-    memo: &mut std::collections::HashMap<*const Expr, Rc<Expr>>,
+    expr:   &Rc<Expr>,
+    rng:    &mut impl rand::RngCore,
+    chance: Chance,
+    memo:   &mut std::collections::HashMap<*const Expr, Rc<Expr>>,
 ) -> Rc<Expr> {
     let ptr = Rc::as_ptr(expr);
     if let Some(cached) = memo.get(&ptr) {
@@ -508,72 +846,97 @@ fn apply_ident_node(
 
     // Bottom-up: transform children first.
     let result = match expr.as_ref() {
-        Expr::Input(_) | Expr::PublicConst(_) | Expr::SecretConst(_) => expr.clone(),
-        Expr::Not(a) => Expr::not(apply_ident_node(a, rng, memo)),
+        Expr::Input(_) | Expr::InputWord { .. } | Expr::PublicConst(_) | Expr::SecretConst(_) => {
+            expr.clone()
+        }
+        Expr::Not(a) => Expr::not(apply_ident_node(a, rng, chance, memo)),
         Expr::Xor(a, b) => Expr::xor(
-            apply_ident_node(a, rng, memo),
-            apply_ident_node(b, rng, memo),
+            apply_ident_node(a, rng, chance, memo),
+            apply_ident_node(b, rng, chance, memo),
         ),
         Expr::And(a, b) => Expr::and(
-            apply_ident_node(a, rng, memo),
-            apply_ident_node(b, rng, memo),
+            apply_ident_node(a, rng, chance, memo),
+            apply_ident_node(b, rng, chance, memo),
         ),
         Expr::Or(a, b) => Expr::or(
-            apply_ident_node(a, rng, memo),
-            apply_ident_node(b, rng, memo),
+            apply_ident_node(a, rng, chance, memo),
+            apply_ident_node(b, rng, chance, memo),
         ),
         Expr::Add(a, b) => Expr::add(
-            apply_ident_node(a, rng, memo),
-            apply_ident_node(b, rng, memo),
+            apply_ident_node(a, rng, chance, memo),
+            apply_ident_node(b, rng, chance, memo),
+        ),
+        Expr::Sub(a, b) => Expr::sub(
+            apply_ident_node(a, rng, chance, memo),
+            apply_ident_node(b, rng, chance, memo),
+        ),
+        Expr::Mul(a, b) => Expr::mul(
+            apply_ident_node(a, rng, chance, memo),
+            apply_ident_node(b, rng, chance, memo),
+        ),
+        Expr::Eq(a, b) => Expr::eq(
+            apply_ident_node(a, rng, chance, memo),
+            apply_ident_node(b, rng, chance, memo),
+        ),
+        Expr::Ltu(a, b) => Expr::ltu(
+            apply_ident_node(a, rng, chance, memo),
+            apply_ident_node(b, rng, chance, memo),
+        ),
+        Expr::IsZero(a) => Expr::is_zero(apply_ident_node(a, rng, chance, memo)),
+        Expr::Neg(a) => Expr::neg(apply_ident_node(a, rng, chance, memo)),
+        Expr::Rotl(a, r) => Expr::rotl(apply_ident_node(a, rng, chance, memo), *r),
+        Expr::Shl(a, r) => Expr::shl(apply_ident_node(a, rng, chance, memo), *r),
+        Expr::Shr(a, r) => Expr::shr(apply_ident_node(a, rng, chance, memo), *r),
+        Expr::RotlVar(a, b) => Expr::rotl_var(
+            apply_ident_node(a, rng, chance, memo),
+            apply_ident_node(b, rng, chance, memo),
         ),
-        Expr::Rotl(a, r) => Expr::rotl(apply_ident_node(a, rng, memo), *r),
         Expr::Mux { cond, on_true, on_false } => Expr::mux(
-            apply_ident_node(cond, rng, memo),
-            apply_ident_node(on_true, rng, memo),
-            apply_ident_node(on_false, rng, memo),
+            apply_ident_node(cond, rng, chance, memo),
+            apply_ident_node(on_true, rng, chance, memo),
+            apply_ident_node(on_false, rng, chance, memo),
         ),
     };
 
-    // Apply identities to `result` in order, each with ~30% probability.
-    // Each fires independently so multiple can stack.
-    let result = try_identities(result, rng);
+    // Apply identities to `result` in order, each with probability `chance`
+    // (by default ~30%).  Each fires independently so multiple can stack.
+    let result = try_identities(result, rng, chance);
 
     memo.insert(ptr, result.clone());
     result
 }
 
-// Probability threshold: rng.next_u32() % 10 < 3 ≈ 30%.
-fn try_identities(result: Rc<Expr>, rng: &mut impl rand::RngCore) -> Rc<Expr> {
+fn try_identities(result: Rc<Expr>, rng: &mut impl rand::RngCore, chance: Chance) -> Rc<Expr> {
     // Not(Not(x)) → x
     let result = if let Expr::Not(inner) = result.as_ref() {
         if let Expr::Not(x) = inner.as_ref() {
-            if rng.next_u32() % 10 < 3 { x.clone() } else { result }
+            if chance.roll(rng) { x.clone() } else { result }
         } else { result }
     } else { result };
 
     // Or(a,b) → Not(And(Not(a), Not(b)))
     let result = if let Expr::Or(a, b) = result.as_ref() {
-        if rng.next_u32() % 10 < 3 {
+        if chance.roll(rng) {
             Expr::not(Expr::and(Expr::not(a.clone()), Expr::not(b.clone())))
         } else { result }
     } else { result };
 
     // And(a,b) → Not(Or(Not(a), Not(b)))
     let result = if let Expr::And(a, b) = result.as_ref() {
-        if rng.next_u32() % 10 < 3 {
+        if chance.roll(rng) {
             Expr::not(Expr::or(Expr::not(a.clone()), Expr::not(b.clone())))
         } else { result }
     } else { result };
 
     // Xor(a,b) → Not(Xor(Not(a), b))
     let result = if let Expr::Xor(a, b) = result.as_ref() {
-        if rng.next_u32() % 10 < 3 {
+        if chance.roll(rng) {
             Expr::not(Expr::xor(Expr::not(a.clone()), b.clone()))
         } else { result }
     } else { result };
 
     // x → Not(Not(x))  (double-NOT introduction)
-    if rng.next_u32() % 10 < 3 {
+    if chance.roll(rng) {
         Expr::not(Expr::not(result))
     } else {
         result
@@ -614,6 +977,58 @@ pub fn strong_rotate(expr: &Rc<Expr>, rng: &mut impl rand::RngCore) -> Rc<Expr>
     constant_fold(&e)
 }
 
+/// [`strong_rotate`] over the roots of a multi-output function.
+///
+/// Each pass shares one memo across all roots, so sub-expressions common to
+/// several outputs stay shared, and decoy operands may be drawn from any
+/// output's subtree.  For a single root this consumes `rng` exactly like
+/// `strong_rotate`.
+pub fn strong_rotate_multi(roots: &[Rc<Expr>], rng: &mut impl rand::RngCore) -> Vec<Rc<Expr>> {
+    strong_rotate_multi_with(roots, &RotateOptions::default(), rng)
+}
+
+/// [`strong_rotate_multi`] with explicit knobs.  A disabled pass (`reassociate:
+/// false`, or a [`Chance::NEVER`] probability) is skipped entirely and draws
+/// nothing from `rng`.
+pub fn strong_rotate_multi_with(
+    roots: &[Rc<Expr>], options: &RotateOptions, rng: &mut impl rand::RngCore,
+) -> Vec<Rc<Expr>> {
+    let fold = |roots: &[Rc<Expr>]| {
+        let mut memo = std::collections::HashMap::new();
+        roots.iter().map(|r| fold_node(r, &mut memo)).collect::<Vec<_>>()
+    };
+
+    let es = fold(roots);
+
+    let es: Vec<_> = if options.reassociate {
+        let mut memo = std::collections::HashMap::new();
+        es.iter().map(|r| reassoc_node(r, rng, &mut memo)).collect()
+    } else {
+        es
+    };
+
+    let mut pool: Vec<Rc<Expr>> = Vec::new();
+    let mut seen: std::collections::HashSet<*const Expr> = Default::default();
+    for r in &es {
+        collect_candidates(r, &mut pool, &mut seen);
+    }
+    let es: Vec<_> = if pool.len() < 2 || options.decoys == Chance::NEVER {
+        es
+    } else {
+        let mut memo = std::collections::HashMap::new();
+        es.iter().map(|r| decoy_node(r, rng, &pool, DecoySites::Random(options.decoys), &mut memo)).collect()
+    };
+
+    let es: Vec<_> = if options.identities == Chance::NEVER {
+        es
+    } else {
+        let mut memo = std::collections::HashMap::new();
+        es.iter().map(|r| apply_ident_node(r, rng, options.identities, &mut memo)).collect()
+    };
+
+    fold(&es)
+}
+
 // ---------------------------------------------------------------------------
 // Tests
 // ---------------------------------------------------------------------------
@@ -715,6 +1130,71 @@ mod tests {
         assert!(matches!(folded.as_ref(), Expr::PublicConst(16)));
     }
 
+    #[test]
+    fn fold_sub_and_neg() {
+        let expr = Expr::sub(Expr::public_const(1), Expr::public_const(2));
+        assert!(matches!(constant_fold(&expr).as_ref(), Expr::PublicConst(0xFFFF_FFFF)));
+        let expr = Expr::sub(Expr::public_const(1), Expr::secret_const(2));
+        assert!(matches!(constant_fold(&expr).as_ref(), Expr::SecretConst(0xFFFF_FFFF)));
+        let expr = Expr::neg(Expr::public_const(1));
+        assert!(matches!(constant_fold(&expr).as_ref(), Expr::PublicConst(0xFFFF_FFFF)));
+
+        let a = Expr::input("a");
+        assert!(Rc::ptr_eq(&constant_fold(&Expr::sub(a.clone(), Expr::public_const(0))), &a));
+        assert!(Rc::ptr_eq(&constant_fold(&Expr::neg(Expr::neg(a.clone()))), &a));
+        assert!(matches!(constant_fold(&Expr::sub(a.clone(), a.clone())).as_ref(), Expr::PublicConst(0)));
+        let zero_minus = constant_fold(&Expr::sub(Expr::public_const(0), a.clone()));
+        assert!(matches!(zero_minus.as_ref(), Expr::Neg(x) if Rc::ptr_eq(x, &a)));
+    }
+
+    #[test]
+    fn fold_mul() {
+        let expr = Expr::mul(Expr::public_const(0x9E37_79B1), Expr::public_const(3));
+        assert!(matches!(constant_fold(&expr).as_ref(), Expr::PublicConst(k) if *k == 0x9E37_79B1u32.wrapping_mul(3)));
+        let a = Expr::input("a");
+        assert!(Rc::ptr_eq(&constant_fold(&Expr::mul_const(a.clone(), 1)), &a));
+        assert!(matches!(constant_fold(&Expr::mul_const(a, 0)).as_ref(), Expr::PublicConst(0)));
+    }
+
+    #[test]
+    fn fold_comparisons() {
+        let eq = Expr::eq(Expr::public_const(3), Expr::public_const(3));
+        assert!(matches!(constant_fold(&eq).as_ref(), Expr::PublicConst(0xFFFF_FFFF)));
+        let lt = Expr::ltu(Expr::secret_const(4), Expr::public_const(3));
+        assert!(matches!(constant_fold(&lt).as_ref(), Expr::SecretConst(0)));
+        let z = Expr::is_zero(Expr::public_const(0));
+        assert!(matches!(constant_fold(&z).as_ref(), Expr::PublicConst(0xFFFF_FFFF)));
+        let a = Expr::input("a");
+        let same_eq = constant_fold(&Expr::eq(a.clone(), a.clone()));
+        assert!(matches!(same_eq.as_ref(), Expr::PublicConst(0xFFFF_FFFF)));
+        let same_lt = constant_fold(&Expr::ltu(a.clone(), a));
+        assert!(matches!(same_lt.as_ref(), Expr::PublicConst(0)));
+    }
+
+    #[test]
+    fn fold_rotl_var_public_amount() {
+        let x = Expr::input("x");
+        let e = constant_fold(&Expr::rotl_var(x, Expr::public_const(33)));
+        assert!(matches!(e.as_ref(), Expr::Rotl(_, 1)));
+        let k = constant_fold(&Expr::rotl_var(Expr::public_const(1), Expr::secret_const(4)));
+        assert!(matches!(k.as_ref(), Expr::SecretConst(16)));
+        let n = Expr::input("n");
+        let v = constant_fold(&Expr::rotl_var(Expr::input("x"), n));
+        assert!(matches!(v.as_ref(), Expr::RotlVar(..)));
+    }
+
+    #[test]
+    fn fold_shift_const() {
+        let expr = Expr::shl(Expr::public_const(0x8000_0001), 4);
+        assert!(matches!(constant_fold(&expr).as_ref(), Expr::PublicConst(0x0000_0010)));
+        let expr = Expr::shr(Expr::secret_const(0x8000_0001), 31);
+        assert!(matches!(constant_fold(&expr).as_ref(), Expr::SecretConst(1)));
+        let expr = Expr::shr(Expr::public_const(0xFFFF_FFFF), 32);
+        assert!(matches!(constant_fold(&expr).as_ref(), Expr::PublicConst(0)));
+        let a = Expr::input("a");
+        assert!(Rc::ptr_eq(&constant_fold(&Expr::shl(a.clone(), 0)), &a));
+    }
+
     #[test]
     fn fold_mux_const_cond() {
         let t = Expr::input("t");
@@ -907,6 +1387,41 @@ mod tests {
         }
     }
 
+    #[test]
+    fn strong_rotate_multi_single_root_matches() {
+        let expr = Expr::add(
+            Expr::xor(Expr::input("a"), Expr::input("b")),
+            Expr::and(Expr::input("a"), Expr::secret_const(7)),
+        );
+        for seed in 0u64..10 {
+            let one   = strong_rotate(&expr, &mut seeded_rng(seed));
+            let multi = strong_rotate_multi(std::slice::from_ref(&expr), &mut seeded_rng(seed));
+            assert_eq!(
+                crate::expr::expr_digest(&one, None),
+                crate::expr::exprs_digest(&multi, None),
+                "seed {seed}",
+            );
+        }
+    }
+
+    #[test]
+    fn strong_rotate_multi_preserves_each_output() {
+        let a = Expr::input("a");
+        let b = Expr::input("b");
+        let s = Expr::add(a.clone(), b.clone());
+        let roots = vec![s.clone(), Expr::rotl(Expr::xor(s, b), 7), a];
+        let input_map: std::collections::HashMap<String, u32> =
+            [("a".to_string(), 0x1234_5678), ("b".to_string(), 0xDEAD_BEEF)].into();
+        let expected = crate::lower::lower_to_circuit_multi(&roots).eval_outputs(&input_map);
+        for seed in 0u64..10 {
+            let r = strong_rotate_multi(&roots, &mut seeded_rng(seed));
+            let c = crate::lower::lower_to_circuit_multi(&r);
+            assert_eq!(c.eval_outputs(&input_map), expected, "seed {seed}");
+            let vm = MaskedCircuit::from_circuit(&c, &mut seeded_rng(seed));
+            assert_eq!(vm.eval_multi(&c, &input_map).1, expected, "seed {seed}");
+        }
+    }
+
     #[test]
     fn strong_rotate_varies_circuit_shape() {
         let expr = Expr::or(
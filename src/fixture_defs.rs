@@ -2,7 +2,8 @@
 //!
 //! # How to add a new fixture
 //!
-//! 1. Write a `build_*()` function below that returns an `Rc<Expr>`.
+//! 1. Write a `build_*()` function below that returns an `Rc<Expr>` (or one
+//!    root per output word for a multi-output fixture).
 //! 2. Add a `FixtureDef` entry to `ALL_FIXTURES`.
 //! 3. Run `cargo run --bin regen_fixtures` to generate `tests/fixtures/<name>.rs`
 //!    and `tests/fixtures/<name>_verify.rs`.
@@ -17,7 +18,11 @@
 
 use std::rc::Rc;
 
+use crate::circuit::Circuit;
+use crate::circuit_transform::remask_operands;
 use crate::expr::Expr;
+use crate::lower::lower_to_circuit_multi;
+use crate::mixers::chacha_quarter_round as qr_outputs;
 
 // ---------------------------------------------------------------------------
 // Fixture descriptor
@@ -34,24 +39,31 @@ pub struct FixtureDef {
     /// this seed before lowering.  Use distinct values per rotated fixture to
     /// get distinct circuit shapes.
     pub structure_seed: Option<u64>,
-    /// Builds the expression tree for this circuit.
-    pub build: fn() -> Rc<Expr>,
+    /// Builds the expression roots for this circuit, one per output word.
+    /// The emitted function returns `u32` for one root, `[u32; N]` otherwise.
+    pub build: fn() -> Vec<Rc<Expr>>,
 }
 
 impl FixtureDef {
-    /// Return the expression to lower, applying `strong_rotate` when
+    /// Return the expression roots to lower, applying `strong_rotate` when
     /// `structure_seed` is set.  Both `regen_fixtures` and the skew check call
     /// this so they always agree on what to emit.
-    pub fn expr(&self) -> Rc<Expr> {
+    pub fn exprs(&self) -> Vec<Rc<Expr>> {
         let base = (self.build)();
         if let Some(ss) = self.structure_seed {
             use rand::SeedableRng;
             let mut rng = rand::rngs::StdRng::seed_from_u64(ss);
-            crate::expr_transform::strong_rotate(&base, &mut rng)
+            crate::expr_transform::strong_rotate_multi(&base, &mut rng)
         } else {
             base
         }
     }
+
+    /// The circuit the browser fixture masks and emits: `exprs()` lowered,
+    /// then `remask_operands` as in the default pipeline.
+    pub fn circuit(&self) -> Circuit {
+        remask_operands(&lower_to_circuit_multi(&self.exprs()))
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -63,16 +75,37 @@ impl FixtureDef {
 /// Add entries here to extend the skew check, structural tests, and the
 /// regen binary.  See the module-level doc for the full checklist.
 pub static ALL_FIXTURES: &[FixtureDef] = &[
-    FixtureDef { name: "or_rotl_demo",      seed: 0, structure_seed: None,     build: build_or_rotl_demo  },
-    FixtureDef { name: "add32_demo",         seed: 0, structure_seed: None,     build: build_add32_demo    },
-    FixtureDef { name: "mux_demo",           seed: 0, structure_seed: None,     build: build_mux_demo      },
-    FixtureDef { name: "chacha_qr",          seed: 0, structure_seed: None,     build: build_chacha_qr     },
-    FixtureDef { name: "chacha_qr_rotated",  seed: 0, structure_seed: Some(42), build: build_chacha_qr     },
-    FixtureDef { name: "or_rotl_mux_decoy", seed: 0, structure_seed: None,     build: build_or_rotl_mux_decoy },
-    FixtureDef { name: "sha256_qr",          seed: 0, structure_seed: None,     build: build_sha256_qr         },
+    FixtureDef { name: "or_rotl_demo",      seed: 0, structure_seed: None,     build: || vec![build_or_rotl_demo()]  },
+    FixtureDef { name: "add32_demo",         seed: 0, structure_seed: None,     build: || vec![build_add32_demo()]    },
+    FixtureDef { name: "mux_demo",           seed: 0, structure_seed: None,     build: || vec![build_mux_demo()]      },
+    FixtureDef { name: "chacha_qr",          seed: 0, structure_seed: None,     build: || vec![build_chacha_qr()]     },
+    FixtureDef { name: "chacha_qr_rotated",  seed: 0, structure_seed: Some(42), build: || vec![build_chacha_qr()]     },
+    FixtureDef { name: "or_rotl_mux_decoy", seed: 0, structure_seed: None,     build: || vec![build_or_rotl_mux_decoy()] },
+    FixtureDef { name: "sha256_qr",          seed: 0, structure_seed: None,     build: || vec![build_sha256_qr()]         },
+    FixtureDef { name: "rc5_half_round",     seed: 0, structure_seed: None,     build: || vec![build_rc5_half_round()]    },
+    FixtureDef { name: "chacha_qr_words",    seed: 0, structure_seed: None,     build: build_chacha_qr_words   },
+    FixtureDef { name: "sha256_qr_bytes",    seed: 0, structure_seed: None,     build: || vec![build_sha256_qr_bytes()]  },
+    FixtureDef { name: "bytes_padding_demo", seed: 0, structure_seed: None,     build: || vec![build_bytes_padding_demo()] },
     // Add new fixtures here ↑
 ];
 
+/// Fixtures bundled into `tests/fixtures/verifier_bundle.rs`: the
+/// two-input, single-output ones with distinct digests.
+pub static BUNDLE_FIXTURES: &[&str] = &["or_rotl_demo", "add32_demo", "or_rotl_mux_decoy"];
+
+/// The `emit_verifier_bundle` source for `BUNDLE_FIXTURES`, each verifier
+/// named after its fixture.  Both `regen_fixtures` and the skew check call
+/// this.
+pub fn verifier_bundle() -> String {
+    let defs: Vec<&FixtureDef> = BUNDLE_FIXTURES.iter()
+        .map(|name| ALL_FIXTURES.iter().find(|d| d.name == *name).expect("bundled fixture is registered"))
+        .collect();
+    let digests: Vec<[u8; 32]> = defs.iter().map(|d| crate::expr::exprs_digest(&(d.build)(), None)).collect();
+    let circuits: Vec<_> = defs.iter().map(|d| crate::lower::lower_to_circuit_multi(&(d.build)())).collect();
+    let entries: Vec<_> = defs.iter().enumerate().map(|(i, d)| (&digests[i], &circuits[i], d.name)).collect();
+    crate::emit::emit_verifier_bundle(&entries).unwrap_or_else(|e| panic!("{e}"))
+}
+
 // ---------------------------------------------------------------------------
 // Circuit builders
 // ---------------------------------------------------------------------------
@@ -148,19 +181,26 @@ fn build_chacha_qr() -> Rc<Expr> {
     Expr::xor(Expr::xor(a2, b4), Expr::xor(c2, d4))
 }
 
-/// One ChaCha quarter-round returning the four output words as separate Exprs.
-fn qr_outputs(
-    a: Rc<Expr>, b: Rc<Expr>, c: Rc<Expr>, d: Rc<Expr>,
-) -> (Rc<Expr>, Rc<Expr>, Rc<Expr>, Rc<Expr>) {
-    let a1 = Expr::add(a,          b.clone());
-    let d2 = Expr::rotl(Expr::xor(d,          a1.clone()), 16);
-    let c1 = Expr::add(c,          d2.clone());
-    let b2 = Expr::rotl(Expr::xor(b,          c1.clone()), 12);
-    let a2 = Expr::add(a1,         b2.clone());
-    let d4 = Expr::rotl(Expr::xor(d2,         a2.clone()),  8);
-    let c2 = Expr::add(c1,         d4.clone());
-    let b4 = Expr::rotl(Expr::xor(b2,         c2.clone()),  7);
-    (a2, b4, c2, d4)
+/// F(a, b, s) = rotl(a ^ b, b & 31) + s — one RC5 half-round.
+///
+/// Exercises the data-dependent rotation (`RotlVar`, 5-stage barrel shifter)
+/// feeding an add32 carry chain.
+fn build_rc5_half_round() -> Rc<Expr> {
+    let a = Expr::input("a");
+    let b = Expr::input("b");
+    let s = Expr::input("s");
+    Expr::add(Expr::rotl_var(Expr::xor(a, b.clone()), b), s)
+}
+
+/// F(a,b,c,d) = all four ChaCha quarter-round output words — a 128-bit tag.
+///
+/// Exercises multi-output emission (`-> [u32; 4]`).  `a2` and `c2` also feed
+/// later words, so `Builder::build_multi` copies them through a `Remask`.
+fn build_chacha_qr_words() -> Vec<Rc<Expr>> {
+    let (a, b, c, d) = qr_outputs(
+        Expr::input("a"), Expr::input("b"), Expr::input("c"), Expr::input("d"),
+    );
+    vec![a, b, c, d]
 }
 
 /// F(w0..w7) — full 256-bit (8 × u32) digest checksum.
@@ -178,14 +218,27 @@ fn qr_outputs(
 ///
 /// AND-triple cost: 31 × 3 = 93 (three add32 carry chains).
 fn build_sha256_qr() -> Rc<Expr> {
-    let (a, b, c, d) = qr_outputs(
-        Expr::input("w0"), Expr::input("w1"),
-        Expr::input("w2"), Expr::input("w3"),
-    );
-    let (e, f, g, h) = qr_outputs(
-        Expr::input("w4"), Expr::input("w5"),
-        Expr::input("w6"), Expr::input("w7"),
-    );
+    sha256_qr_over((0..8).map(|i| Expr::input(&format!("w{i}"))).collect())
+}
+
+/// F(digest: &[u8; 32]) = sha256_qr over the digest's 8 little-endian words.
+///
+/// Same circuit as `sha256_qr`, but the words are packed from a byte-slice
+/// input inside the circuit, each masked at ingest.
+fn build_sha256_qr_bytes() -> Rc<Expr> {
+    sha256_qr_over(Expr::input_bytes("digest", 32))
+}
+
+/// F(msg: &[u8; 7]) = rotl(m0, 7) + m1 where m1 holds bytes 4..7 and one
+/// zero pad byte.
+fn build_bytes_padding_demo() -> Rc<Expr> {
+    let m = Expr::input_bytes("msg", 7);
+    Expr::add(Expr::rotl(m[0].clone(), 7), m[1].clone())
+}
+
+fn sha256_qr_over(w: Vec<Rc<Expr>>) -> Rc<Expr> {
+    let (a, b, c, d) = qr_outputs(w[0].clone(), w[1].clone(), w[2].clone(), w[3].clone());
+    let (e, f, g, h) = qr_outputs(w[4].clone(), w[5].clone(), w[6].clone(), w[7].clone());
 
     let m0 = Expr::xor(a, e);
     let m1 = Expr::xor(b, f);
@@ -14,12 +14,22 @@
 
 pub mod circuit;
 pub mod circuit_transform;
+pub mod dsl;
 pub mod emit;
+pub mod emit_c;
+pub mod emit_js;
+pub mod emit_wasm;
+pub mod error;
 #[cfg(feature = "fixture-defs")]
 pub mod fixture_defs;
 pub mod lower;
 pub mod mask;
+pub mod mixers;
 pub mod pipeline;
 pub mod prelude;
+pub mod schema;
+pub mod serialize;
 pub mod expr;
 pub mod expr_transform;
+
+pub use error::Error;
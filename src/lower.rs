@@ -22,7 +22,8 @@
 //! - `ingest_map: HashMap<String, WireId>` — keyed on input name.  Ensures
 //!   that two separately-constructed `Expr::Input("a")` nodes (different `Rc`s,
 //!   same name) map to the same `Gadget::Ingest` rather than producing two
-//!   ingests with different masks.
+//!   ingests with different masks.  Byte-input words are keyed on
+//!   `byte_word_name(name, word)`.
 //!
 //! # Expansions
 //!
@@ -34,12 +35,26 @@
 //! | `Or(a, b)` | `Xor(Xor(a,b), And(a,b))` — standard OR from XOR+AND |
 //! | `Not(a)` | `XorConst(a, 0xffff_ffff)` — free, no triple |
 //! | `Add(a, b)` | `Builder::add32(a, b)` — 31 triples |
+//! | `Add(a, PublicConst(k))` | `Builder::add32_const(a, k)` — `30 - tz(k)` triples |
+//! | `Sub(a, b)` | `Builder::sub32(a, b)` — 31 triples |
+//! | `Sub(a, PublicConst(k))` | `Builder::add32_const(a, -k)` |
+//! | `Sub(PublicConst(k), a)` | `Builder::add32_const(!a, k + 1)` |
+//! | `Neg(a)` | `Builder::add32_const(!a, 1)` — 30 triples |
+//! | `Mul(a, b)` | Shift-and-add: 32 partial products `(a << i) & bcast(b_i)` (32 triples) summed with `Builder::add32_from` (465 triples) — 497 triples |
+//! | `Mul(a, PublicConst(k))` | Sum of `a << i` over set bits `i` of `k` — `Σ (31 - i)` over all but the lowest set bit `i < 31` |
+//! | `Eq(a, b)` | `Builder::eq32(a, b)` — 5 triples |
+//! | `Ltu(a, b)` | `Builder::ltu32(a, b)` — 32 triples |
+//! | `IsZero(a)` | `Builder::is_zero(a)` — 5 triples |
+//! | `RotlVar(a, b)` | `Builder::rotl_var(a, b)` — five `Mux` stages over `broadcast_bit(b, i)`, 5 triples |
+//! | `RotlVar(a, PublicConst(k))` | `Rotl(a, k & 31)` — free |
 //! | `Mux{c,t,f}` | `Xor(f, And(c, Xor(t, f)))` — 1 triple |
+//! | `Shl(a, r)` / `Shr(a, r)`, `r >= 32` | `AndConst(a, 0)` — logical shift out |
 
 use std::collections::HashMap;
 use std::rc::Rc;
 
-use crate::circuit::{Builder, Circuit, WireId};
+use crate::circuit::{byte_word_name, Builder, Circuit, WireId};
+use crate::error::Error;
 use crate::expr::Expr;
 
 // ---------------------------------------------------------------------------
@@ -57,6 +72,34 @@ pub fn lower_to_circuit(expr: &Rc<Expr>) -> Circuit {
     builder.build(result)
 }
 
+/// Lower a multi-output function to a `Circuit` with one egress per root,
+/// in order.  Nodes shared between roots are lowered once.
+pub fn lower_to_circuit_multi(roots: &[Rc<Expr>]) -> Circuit {
+    try_lower_to_circuit_multi(roots).unwrap_or_else(|e| panic!("lowering produced an invalid circuit: {e}"))
+}
+
+/// [`lower_to_circuit`], returning an error instead of panicking.
+pub fn try_lower_to_circuit(expr: &Rc<Expr>) -> Result<Circuit, Error> {
+    try_lower_to_circuit_multi(std::slice::from_ref(expr))
+}
+
+/// [`lower_to_circuit_multi`], returning an error instead of panicking:
+/// [`Error::Unsupported`] for an empty `roots`, [`Error::Validation`] when
+/// the inputs are inconsistent (a byte input ingested with two lengths, or
+/// sharing its name with a `u32` input).
+pub fn try_lower_to_circuit_multi(roots: &[Rc<Expr>]) -> Result<Circuit, Error> {
+    if roots.is_empty() {
+        return Err(Error::Unsupported("a function needs at least one output".to_string()));
+    }
+    let mut builder = Builder::new();
+    let mut memo: HashMap<*const Expr, WireId> = HashMap::new();
+    let mut ingest_map: HashMap<String, WireId> = HashMap::new();
+    let results: Vec<WireId> = roots.iter()
+        .map(|r| lower_expr(r, &mut builder, &mut memo, &mut ingest_map))
+        .collect();
+    builder.try_build_multi(&results)
+}
+
 // ---------------------------------------------------------------------------
 // Recursive lowering
 // ---------------------------------------------------------------------------
@@ -91,6 +134,16 @@ fn lower_expr(
                 w
             }
         }
+        Expr::InputWord { name, len, word } => {
+            let key = byte_word_name(name, *word);
+            if let Some(&existing) = ingest_map.get(&key) {
+                existing
+            } else {
+                let w = builder.ingest_word(name, *len, *word);
+                ingest_map.insert(key, w);
+                w
+            }
+        }
         Expr::PublicConst(k) => builder.public_const(*k),
         Expr::SecretConst(k) => builder.secret_const(*k),
 
@@ -109,6 +162,24 @@ fn lower_expr(
             let wa = lower_expr(a, builder, memo, ingest_map);
             builder.rotl(wa, *r)
         }
+        Expr::RotlVar(a, amount) => {
+            let wa = lower_expr(a, builder, memo, ingest_map);
+            match amount.as_ref() {
+                Expr::PublicConst(k) => builder.rotl(wa, k & 31),
+                _ => {
+                    let wm = lower_expr(amount, builder, memo, ingest_map);
+                    builder.rotl_var(wa, wm)
+                }
+            }
+        }
+        Expr::Shl(a, r) => {
+            let wa = lower_expr(a, builder, memo, ingest_map);
+            if *r >= 32 { builder.and_const(wa, 0) } else { builder.shl(wa, *r) }
+        }
+        Expr::Shr(a, r) => {
+            let wa = lower_expr(a, builder, memo, ingest_map);
+            if *r >= 32 { builder.and_const(wa, 0) } else { builder.shr(wa, *r) }
+        }
 
         // --- expansions ---
         Expr::Or(a, b) => {
@@ -124,10 +195,74 @@ fn lower_expr(
             let wa = lower_expr(a, builder, memo, ingest_map);
             builder.xor_const(wa, 0xffff_ffff)
         }
-        Expr::Add(a, b) => {
+        Expr::Add(a, b) => match (a.as_ref(), b.as_ref()) {
+            // A public operand makes generate/propagate free.
+            (_, Expr::PublicConst(k)) => {
+                let wa = lower_expr(a, builder, memo, ingest_map);
+                builder.add32_const(wa, *k)
+            }
+            (Expr::PublicConst(k), _) => {
+                let wb = lower_expr(b, builder, memo, ingest_map);
+                builder.add32_const(wb, *k)
+            }
+            _ => {
+                let wa = lower_expr(a, builder, memo, ingest_map);
+                let wb = lower_expr(b, builder, memo, ingest_map);
+                builder.add32(wa, wb)
+            }
+        },
+        Expr::Sub(a, b) => match (a.as_ref(), b.as_ref()) {
+            // a - k  =  a + (-k)
+            (_, Expr::PublicConst(k)) => {
+                let wa = lower_expr(a, builder, memo, ingest_map);
+                builder.add32_const(wa, k.wrapping_neg())
+            }
+            // k - b  =  !b + (k + 1)
+            (Expr::PublicConst(k), _) => {
+                let wb    = lower_expr(b, builder, memo, ingest_map);
+                let not_b = builder.xor_const(wb, 0xffff_ffff);
+                builder.add32_const(not_b, k.wrapping_add(1))
+            }
+            _ => {
+                let wa = lower_expr(a, builder, memo, ingest_map);
+                let wb = lower_expr(b, builder, memo, ingest_map);
+                builder.sub32(wa, wb)
+            }
+        },
+        Expr::Mul(a, b) => match (a.as_ref(), b.as_ref()) {
+            (_, Expr::PublicConst(k)) => {
+                let wa = lower_expr(a, builder, memo, ingest_map);
+                lower_mul_const(builder, wa, *k)
+            }
+            (Expr::PublicConst(k), _) => {
+                let wb = lower_expr(b, builder, memo, ingest_map);
+                lower_mul_const(builder, wb, *k)
+            }
+            _ => {
+                let wa = lower_expr(a, builder, memo, ingest_map);
+                let wb = lower_expr(b, builder, memo, ingest_map);
+                lower_mul(builder, wa, wb)
+            }
+        },
+        Expr::Neg(a) => {
+            // -a  =  !a + 1
+            let wa    = lower_expr(a, builder, memo, ingest_map);
+            let not_a = builder.xor_const(wa, 0xffff_ffff);
+            builder.add32_const(not_a, 1)
+        }
+        Expr::Eq(a, b) => {
             let wa = lower_expr(a, builder, memo, ingest_map);
             let wb = lower_expr(b, builder, memo, ingest_map);
-            builder.add32(wa, wb)
+            builder.eq32(wa, wb)
+        }
+        Expr::Ltu(a, b) => {
+            let wa = lower_expr(a, builder, memo, ingest_map);
+            let wb = lower_expr(b, builder, memo, ingest_map);
+            builder.ltu32(wa, wb)
+        }
+        Expr::IsZero(a) => {
+            let wa = lower_expr(a, builder, memo, ingest_map);
+            builder.is_zero(wa)
         }
         Expr::Mux { cond, on_true, on_false } => {
             // select(c, t, f)  =  f ^ (c & (t ^ f))  — 1 triple
@@ -144,6 +279,50 @@ fn lower_expr(
     wire
 }
 
+// ---------------------------------------------------------------------------
+// Multiplication
+// ---------------------------------------------------------------------------
+
+/// `a * b` by shift-and-add.
+///
+/// Partial product `i` is `(a << i) & bcast(b_i)`, where `bcast` is the free
+/// `Builder::broadcast_bit`, so each costs one triple.  Partial `i` is zero
+/// below bit `i`, which lets `Builder::add32_from` skip that part of the
+/// carry chain: adding it costs `31 - i` triples (0 for the top bit).
+fn lower_mul(builder: &mut Builder, a: WireId, b: WireId) -> WireId {
+    let mut acc: Option<WireId> = None;
+    for i in 0u32..32 {
+        let b_i     = builder.broadcast_bit(b, i);
+        let shifted = if i == 0 { a } else { builder.shl(a, i) };
+        let partial = builder.and(shifted, b_i);
+        acc = Some(match acc {
+            None      => partial,
+            Some(sum) => builder.add32_from(sum, partial, i),
+        });
+    }
+    acc.expect("32 partial products")
+}
+
+/// `a * k` for a public constant `k`: sum `a << i` over the set bits of `k`.
+///
+/// Shifts are free and clear bits contribute nothing, so only the additions
+/// cost triples, each starting its carry chain at the shift amount.
+fn lower_mul_const(builder: &mut Builder, a: WireId, k: u32) -> WireId {
+    if k == 0 {
+        // Keep `a` in the circuit so its input still appears in the signature.
+        return builder.and_const(a, 0);
+    }
+    let mut acc: Option<WireId> = None;
+    for i in (0u32..32).filter(|i| k & (1 << i) != 0) {
+        let shifted = if i == 0 { a } else { builder.shl(a, i) };
+        acc = Some(match acc {
+            None      => shifted,
+            Some(sum) => builder.add32_from(sum, shifted, i),
+        });
+    }
+    acc.expect("k != 0 has a set bit")
+}
+
 // ---------------------------------------------------------------------------
 // Tests
 // ---------------------------------------------------------------------------
@@ -200,6 +379,80 @@ mod tests {
         verify(&expr, &[("a", 0x1234_5678), ("b", 0x8765_4321)], 0x1234_5678u32.wrapping_add(0x8765_4321));
     }
 
+    #[test]
+    fn sub_and_neg_expansion() {
+        let a = Expr::input("a");
+        let b = Expr::input("b");
+        let sub = Expr::sub(a.clone(), b);
+        verify(&sub, &[("a", 3), ("b", 1)], 2);
+        verify(&sub, &[("a", 0), ("b", 1)], 0xFFFF_FFFF); // wrapping
+        verify(&sub, &[("a", 0x1234_5678), ("b", 0x8765_4321)], 0x1234_5678u32.wrapping_sub(0x8765_4321));
+        let neg = Expr::neg(a);
+        verify(&neg, &[("a", 0)], 0);
+        verify(&neg, &[("a", 1)], 0xFFFF_FFFF);
+        verify(&neg, &[("a", 0x8000_0000)], 0x8000_0000);
+        verify(&neg, &[("a", 0xDEAD_BEEF)], 0xDEAD_BEEFu32.wrapping_neg());
+    }
+
+    #[test]
+    fn add_public_const_uses_add32_const() {
+        let triples = |e: &Rc<Expr>| lower_to_circuit(e).gadgets.iter()
+            .filter(|g| matches!(g, crate::circuit::Gadget::And { .. }))
+            .count();
+        let a = Expr::input("a");
+        let k = 0x9e37_79b8u32; // trailing_zeros = 3
+        let lhs = Expr::add(a.clone(), Expr::public_const(k));
+        let rhs = Expr::add(Expr::public_const(k), a.clone());
+        assert_eq!(triples(&lhs), 27);
+        assert_eq!(triples(&rhs), 27);
+        verify(&lhs, &[("a", 0xFFFF_FFFF)], k.wrapping_sub(1));
+        verify(&rhs, &[("a", 0x1234_5678)], 0x1234_5678u32.wrapping_add(k));
+
+        let sub_k = Expr::sub(a.clone(), Expr::public_const(5));
+        let k_sub = Expr::sub(Expr::public_const(5), a.clone());
+        verify(&sub_k, &[("a", 3)], 3u32.wrapping_sub(5));
+        verify(&k_sub, &[("a", 3)], 2);
+        verify(&k_sub, &[("a", 0xDEAD_BEEF)], 5u32.wrapping_sub(0xDEAD_BEEF));
+        assert_eq!(triples(&Expr::neg(a)), 30);
+    }
+
+    fn triple_count(expr: &Rc<Expr>) -> usize {
+        lower_to_circuit(expr).gadgets.iter()
+            .filter(|g| matches!(g, crate::circuit::Gadget::And { .. }))
+            .count()
+    }
+
+    #[test]
+    fn mul_expansion() {
+        let a = Expr::input("a");
+        let b = Expr::input("b");
+        let expr = Expr::mul(a, b);
+        assert_eq!(triple_count(&expr), 497);
+        verify(&expr, &[("a", 3), ("b", 7)], 21);
+        verify(&expr, &[("a", 0xFFFF_FFFF), ("b", 0xFFFF_FFFF)], 1);
+        verify(&expr, &[("a", 0x1234_5678), ("b", 0x9E37_79B1)], 0x1234_5678u32.wrapping_mul(0x9E37_79B1));
+    }
+
+    #[test]
+    fn mul_const_expansion() {
+        let a = Expr::input("a");
+        // murmur3 fmix32 / xxhash32 primes.
+        for k in [0x85eb_ca6bu32, 0xc2b2_ae35, 0x9E37_79B1, 0x0100_0193, 8, 1, 0] {
+            let expr = Expr::mul_const(a.clone(), k);
+            let expected_triples: usize = (0u32..31)
+                .filter(|i| k & (1 << i) != 0)
+                .skip(1)
+                .map(|i| 31 - i as usize)
+                .sum();
+            assert_eq!(triple_count(&expr), expected_triples, "k={k:#010x}");
+            for av in [0u32, 1, 0xDEAD_BEEF, 0xFFFF_FFFF] {
+                verify(&expr, &[("a", av)], av.wrapping_mul(k));
+            }
+        }
+        let swapped = Expr::mul(Expr::public_const(5), a);
+        verify(&swapped, &[("a", 0x3333_3333)], 0x3333_3333u32.wrapping_mul(5));
+    }
+
     #[test]
     fn mux_expansion() {
         let c = Expr::input("c");
@@ -211,6 +464,110 @@ mod tests {
         verify(&expr, &[("c", 0xFFFF_0000), ("t", 0xDEAD_BEEF), ("f", 0xCAFE_BABE)], 0xDEAD_BABE);
     }
 
+    #[test]
+    fn shift_lowering() {
+        let a = Expr::input("a");
+        verify(&Expr::shl(a.clone(), 3),  &[("a", 0x8000_0001)], 0x0000_0008);
+        verify(&Expr::shr(a.clone(), 7),  &[("a", 0x8000_0080)], 0x0100_0001);
+        verify(&Expr::shl(a.clone(), 32), &[("a", 0xFFFF_FFFF)], 0);
+        verify(&Expr::shr(a.clone(), 40), &[("a", 0xFFFF_FFFF)], 0);
+        // SHA-256 small sigma0: rotr 7 ^ rotr 18 ^ shr 3.
+        let x: u32 = 0x6a09_e667;
+        let sigma0 = Expr::xor(
+            Expr::xor(Expr::rotr(a.clone(), 7), Expr::rotr(a.clone(), 18)),
+            Expr::shr(a, 3),
+        );
+        verify(&sigma0, &[("a", x)], x.rotate_right(7) ^ x.rotate_right(18) ^ (x >> 3));
+    }
+
+    #[test]
+    fn comparisons_drive_mux() {
+        // if event == 7 { a } else if a < b { b } else { a ^ b }
+        let a = Expr::input("a");
+        let b = Expr::input("b");
+        let e = Expr::input("event");
+        let expr = Expr::mux(
+            Expr::eq(e.clone(), Expr::public_const(7)),
+            a.clone(),
+            Expr::mux(Expr::ltu(a.clone(), b.clone()), b.clone(), Expr::xor(a, b)),
+        );
+        verify(&expr, &[("a", 1), ("b", 2), ("event", 7)], 1);
+        verify(&expr, &[("a", 1), ("b", 2), ("event", 6)], 2);
+        verify(&expr, &[("a", 3), ("b", 2), ("event", 6)], 1);
+        verify(&expr, &[("a", 0xFFFF_FFFF), ("b", 0), ("event", 0)], 0xFFFF_FFFF);
+
+        let z = Expr::is_zero(Expr::input("x"));
+        verify(&z, &[("x", 0)], 0xFFFF_FFFF);
+        verify(&z, &[("x", 0x8000_0000)], 0);
+    }
+
+    #[test]
+    fn rotl_var_barrel_shifter() {
+        let x = Expr::input("x");
+        let n = Expr::input("n");
+        let expr = Expr::rotl_var(x.clone(), n);
+        assert_eq!(triple_count(&expr), 5);
+        let circuit = lower_to_circuit(&expr);
+        for amt in [0u32, 1, 5, 16, 31, 32, 37, 0xFFFF_FFFF] {
+            let v = 0x8123_4567u32;
+            verify(&expr, &[("x", v), ("n", amt)], v.rotate_left(amt & 31));
+            let plain = circuit.eval(&str_map(&[("x", v), ("n", amt)]));
+            assert_eq!(plain[&circuit.egress[0]], v.rotate_left(amt & 31));
+        }
+
+        // A public amount degrades to a free static rotation.
+        let fixed = Expr::rotl_var(x, Expr::public_const(35));
+        let circuit = lower_to_circuit(&fixed);
+        assert!(circuit.gadgets.iter().any(|g| matches!(g, crate::circuit::Gadget::Rotl { r: 3, .. })));
+        verify(&fixed, &[("x", 0x8000_0001)], 0x0000_000C);
+    }
+
+    #[test]
+    fn broadcast_bit_helper_is_free() {
+        let x = Expr::input("x");
+        let expr = Expr::broadcast_bit(x, 5);
+        assert_eq!(triple_count(&expr), 0);
+        verify(&expr, &[("x", 0x20)], 0xFFFF_FFFF);
+        verify(&expr, &[("x", !0x20)], 0);
+    }
+
+    #[test]
+    fn byte_input_words_packed_and_padded() {
+        let w = Expr::input_bytes("msg", 6);
+        assert_eq!(w.len(), 2);
+        // Separately-built words of the same input share one ingest each.
+        let again = Expr::input_bytes("msg", 6);
+        let expr = Expr::xor(Expr::add(w[0].clone(), w[1].clone()), again[1].clone());
+        let circuit = lower_to_circuit(&expr);
+        let ingests = circuit.gadgets.iter()
+            .filter(|g| matches!(g, crate::circuit::Gadget::Ingest { .. }))
+            .count();
+        assert_eq!(ingests, 2);
+
+        let inputs = crate::circuit::byte_inputs("msg", &[1, 2, 3, 4, 5, 6]);
+        assert_eq!(inputs[1], ("msg[1]".to_string(), 0x0000_0605));
+        let pairs: Vec<(&str, u32)> = inputs.iter().map(|(k, v)| (k.as_str(), *v)).collect();
+        let expected = 0x0403_0201u32.wrapping_add(0x0605) ^ 0x0605;
+        verify(&expr, &pairs, expected);
+    }
+
+    #[test]
+    #[should_panic(expected = "clashes with a u32 input")]
+    fn byte_input_name_clash_rejected() {
+        let w = Expr::input_bytes("a", 4);
+        lower_to_circuit(&Expr::xor(w[0].clone(), Expr::input("a")));
+    }
+
+    #[test]
+    fn try_lower_reports_errors() {
+        let w = Expr::input_bytes("a", 4);
+        let err = try_lower_to_circuit(&Expr::xor(w[0].clone(), Expr::input("a"))).unwrap_err();
+        assert!(matches!(err, Error::Validation { gadget: Some(_), .. }), "{err:?}");
+        assert!(err.to_string().contains("clashes with a u32 input"), "{err}");
+        assert!(matches!(try_lower_to_circuit_multi(&[]), Err(Error::Unsupported(_))));
+        assert!(try_lower_to_circuit(&Expr::input("a")).is_ok());
+    }
+
     #[test]
     fn shared_node_not_duplicated() {
         let a = Expr::input("a");
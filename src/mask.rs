@@ -13,6 +13,7 @@ use rand::{Rng, RngCore, SeedableRng};
 use rand::rngs::StdRng;
 
 use crate::circuit::{Circuit, Gadget, GenId, WireId};
+use crate::error::Error;
 
 // ---------------------------------------------------------------------------
 // MaskedGadget
@@ -37,7 +38,7 @@ struct MaskedGadget {
 pub struct MaskedCircuit {
     baked:      Vec<MaskedGadget>,
     pub(crate) masks:      HashMap<WireId, u32>, // debug / sanity — not shipped
-    gen_values: HashMap<GenId, u32>,             // the rotation key
+    pub(crate) gen_values: HashMap<GenId, u32>,  // the rotation key
 }
 
 impl MaskedCircuit {
@@ -50,6 +51,11 @@ impl MaskedCircuit {
         self.baked.iter().map(|mg| mg.consts.as_slice())
     }
 
+    /// Number of `u32` entries in the emitted `POOL`.
+    pub fn pool_len(&self) -> usize {
+        self.baked.iter().map(|mg| mg.consts.len()).sum()
+    }
+
     // =========================================================================
     // Concretization
     // =========================================================================
@@ -59,15 +65,19 @@ impl MaskedCircuit {
     /// Samples a fresh mask for every generator, retrying until all
     /// secret-carrying masks (Ingest, SecretConst) are non-zero.  Then
     /// propagates masks through the gadget schedule and bakes constants.
+    ///
+    /// Panics if `circuit` fails validation; see
+    /// [`MaskedCircuit::try_from_circuit`].
     pub fn from_circuit(circuit: &Circuit, rng: &mut impl RngCore) -> MaskedCircuit {
-        circuit.validate().expect("invalid circuit");
+        Self::try_from_circuit(circuit, rng).unwrap_or_else(|e| panic!("invalid circuit: {e}"))
+    }
 
-        let secret_gens: Vec<GenId> = circuit.gadgets.iter()
-            .filter_map(|g| match g {
-                Gadget::Ingest { gen, .. } | Gadget::SecretConst { gen, .. } => Some(*gen),
-                _ => None,
-            })
-            .collect();
+    /// [`MaskedCircuit::from_circuit`], returning a validation failure
+    /// instead of panicking.
+    pub fn try_from_circuit(circuit: &Circuit, rng: &mut impl RngCore) -> Result<MaskedCircuit, Error> {
+        circuit.validate()?;
+
+        let secret_gens = Self::secret_gens(circuit);
 
         // Sample generators, retrying until no secret mask is zero.
         let gen_values: HashMap<GenId, u32> = loop {
@@ -80,6 +90,24 @@ impl MaskedCircuit {
             }
         };
 
+        Ok(Self::bake(circuit, gen_values))
+    }
+
+    /// Generators whose mask hides a secret (Ingest, SecretConst) and so must
+    /// be non-zero.
+    pub(crate) fn secret_gens(circuit: &Circuit) -> Vec<GenId> {
+        circuit.gadgets.iter()
+            .filter_map(|g| match g {
+                Gadget::Ingest { gen, .. } | Gadget::SecretConst { gen, .. } => Some(*gen),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Propagate masks through `circuit` under fixed generator values and
+    /// bake every gadget's constants.  Deterministic: the same circuit and
+    /// `gen_values` always produce the same `MaskedCircuit`.
+    pub(crate) fn bake(circuit: &Circuit, gen_values: HashMap<GenId, u32>) -> MaskedCircuit {
         let mut masks: HashMap<WireId, u32> = HashMap::new();
         let mut baked: Vec<MaskedGadget>    = Vec::new();
 
@@ -95,7 +123,7 @@ impl MaskedCircuit {
                     masks.insert(*out, m);
                     (vec![*k ^ m], format!("bake k^mask (mask=gen#{})", gen))
                 }
-                Gadget::Ingest { name, gen, out } => {
+                Gadget::Ingest { name, gen, out, .. } => {
                     let m = gen_values[gen];
                     masks.insert(*out, m);
                     (vec![m], format!("XOR \"{}\" with ingest mask gen#{}", name, gen))
@@ -116,11 +144,24 @@ impl MaskedCircuit {
                     masks.insert(*out, masks[a].rotate_left(*r));
                     (vec![], "free; mask rotated".to_string())
                 }
-                Gadget::And { a, b, gen, out } => {
+                Gadget::Shl { a, r, out } => {
+                    masks.insert(*out, masks[a] << *r);
+                    (vec![], "free; mask shifted".to_string())
+                }
+                Gadget::Shr { a, r, out } => {
+                    masks.insert(*out, masks[a] >> *r);
+                    (vec![], "free; mask shifted".to_string())
+                }
+                Gadget::And { a, b, gen, share, out } => {
+                    // The operand masks are baked as `ma ^ k`, `mb ^ k` with
+                    // `k = T ^ s`, so neither appears as an entry, nor as the
+                    // XOR of two entries of the triple.
                     let (ma, mb, mz) = (masks[a], masks[b], gen_values[gen]);
                     masks.insert(*out, mz);
                     let t = (ma & mb) ^ mz;
-                    (vec![t, ma, mb], format!("triple [T, ma, mb], out mask=gen#{}", gen))
+                    let s = gen_values[share];
+                    let k = t ^ s;
+                    (vec![t, s, ma ^ k, mb ^ k], format!("triple [T, s, ma^T^s, mb^T^s], out mask=gen#{}", gen))
                 }
                 Gadget::Remask { a, gen, out } => {
                     let target = gen_values[gen];
@@ -129,6 +170,7 @@ impl MaskedCircuit {
                     (vec![delta], format!("XOR remask delta -> gen#{}", gen))
                 }
                 Gadget::Egress { a } => {
+                    // One unmask delta per output word.
                     (vec![masks[a]], "unmask & reveal".to_string())
                 }
             };
@@ -144,14 +186,24 @@ impl MaskedCircuit {
 
     /// Run the masked computation.  Returns `(registers, revealed)` where
     /// every register holds `value ^ mask` and `revealed` is the plaintext
-    /// output.
+    /// output (the first output of a multi-output circuit).
     pub(crate) fn eval(
         &self,
         circuit: &Circuit,
         inputs:  &HashMap<String, u32>,
     ) -> (HashMap<WireId, u32>, u32) {
+        let (regs, revealed) = self.eval_multi(circuit, inputs);
+        (regs, revealed[0])
+    }
+
+    /// Like [`MaskedCircuit::eval`], revealing every output in egress order.
+    pub(crate) fn eval_multi(
+        &self,
+        circuit: &Circuit,
+        inputs:  &HashMap<String, u32>,
+    ) -> (HashMap<WireId, u32>, Vec<u32>) {
         let mut regs: HashMap<WireId, u32> = HashMap::new();
-        let mut revealed: u32 = 0;
+        let mut revealed: Vec<u32> = Vec::with_capacity(circuit.egress.len());
 
         for (idx, g) in circuit.gadgets.iter().enumerate() {
             let k = &self.baked[idx].consts;
@@ -163,8 +215,11 @@ impl MaskedCircuit {
                 Gadget::XorConst { a, out, .. }  => { regs.insert(*out, regs[a] ^ k[0]); }
                 Gadget::AndConst { a, out, .. }  => { regs.insert(*out, regs[a] & k[0]); }
                 Gadget::Rotl { a, r, out }       => { regs.insert(*out, regs[a].rotate_left(*r)); }
+                Gadget::Shl { a, r, out }        => { regs.insert(*out, regs[a] << *r); }
+                Gadget::Shr { a, r, out }        => { regs.insert(*out, regs[a] >> *r); }
                 Gadget::And { a, b, out, .. } => {
-                    let (t, ma, mb) = (k[0], k[1], k[2]);
+                    let t = k[0];
+                    let (ma, mb) = (t ^ k[1] ^ k[2], t ^ k[1] ^ k[3]);
                     let (ra, rb) = (regs[a], regs[b]);
                     let mut z = t;
                     z ^= ra & mb;
@@ -173,7 +228,7 @@ impl MaskedCircuit {
                     regs.insert(*out, z);
                 }
                 Gadget::Remask { a, out, .. } => { regs.insert(*out, regs[a] ^ k[0]); }
-                Gadget::Egress { a }          => { revealed = regs[a] ^ k[0]; }
+                Gadget::Egress { a }          => { revealed.push(regs[a] ^ k[0]); }
             }
         }
         (regs, revealed)
@@ -247,7 +302,7 @@ mod tests {
     use super::*;
     use rand::SeedableRng;
     use rand::rngs::StdRng;
-    use crate::circuit::{build_add32_example, build_example, Builder, Gadget, Wire};
+    use crate::circuit::{build_add32_example, build_example, build_sub32_example, Builder, Gadget, Wire};
 
     fn single_input(a: u32) -> HashMap<String, u32> {
         [("a".to_string(), a)].into()
@@ -308,6 +363,30 @@ mod tests {
         }
     }
 
+    #[test]
+    fn shifts_compute_correctly() {
+        let mut b = Builder::new();
+        let wa  = b.ingest("a");
+        let shl = b.shl(wa, 3);
+        let shr = b.shr(wa, 7);
+        let result = b.xor(shl, shr);
+        let c = b.build(result);
+        let mut outer = rng(0x5555);
+        for _ in 0..20 {
+            let seed: u64 = outer.random();
+            let vm = MaskedCircuit::from_circuit(&c, &mut rng(seed));
+            for _ in 0..20 {
+                let a: u32 = outer.random();
+                let inputs = single_input(a);
+                let values = c.eval(&inputs);
+                let (regs, revealed) = vm.eval(&c, &inputs);
+                assert_eq!(revealed, (a << 3) ^ (a >> 7), "seed={seed:#x}");
+                assert_eq!(regs[&shl], values[&shl] ^ vm.masks[&shl], "seed={seed:#x}");
+                assert_eq!(regs[&shr], values[&shr] ^ vm.masks[&shr], "seed={seed:#x}");
+            }
+        }
+    }
+
     #[test]
     fn remask_preserves_value() {
         let mut b = Builder::new();
@@ -330,6 +409,26 @@ mod tests {
         }
     }
 
+    #[test]
+    fn multi_output_reveals_each_word() {
+        let mut b = Builder::new();
+        let wa  = b.ingest("a");
+        let wb  = b.ingest("b");
+        let sum = b.add32(wa, wb);
+        let mix = b.xor(sum, wb);
+        let c   = b.build_multi(&[sum, mix]);
+        let mut outer = rng(0x7777);
+        for _ in 0..20 {
+            let seed: u64 = outer.random();
+            let vm = MaskedCircuit::from_circuit(&c, &mut rng(seed));
+            let (a, bv): (u32, u32) = (outer.random(), outer.random());
+            let (_, revealed) = vm.eval_multi(&c, &inputs_of(a, bv));
+            let s = a.wrapping_add(bv);
+            assert_eq!(revealed, vec![s, s ^ bv], "seed={seed:#x}");
+            assert_eq!(revealed, c.eval_outputs(&inputs_of(a, bv)));
+        }
+    }
+
     #[test]
     fn egress_matches_plaintext() {
         let c = build_example();
@@ -343,7 +442,7 @@ mod tests {
                 let inputs = inputs_of(a, b);
                 let values = c.eval(&inputs);
                 let (_, revealed) = vm.eval(&c, &inputs);
-                assert_eq!(values[&c.egress], expected(a, b), "value graph != ref");
+                assert_eq!(values[&c.egress[0]], expected(a, b), "value graph != ref");
                 assert_eq!(revealed, expected(a, b), "seed={seed:#x}");
             }
         }
@@ -381,12 +480,49 @@ mod tests {
                 let inputs = inputs_of(a, b);
                 let values = c.eval(&inputs);
                 let (_, revealed) = vm.eval(&c, &inputs);
-                assert_eq!(values[&c.egress], a.wrapping_add(b), "value graph");
+                assert_eq!(values[&c.egress[0]], a.wrapping_add(b), "value graph");
                 assert_eq!(revealed, a.wrapping_add(b), "seed={seed:#x}");
             }
         }
     }
 
+    #[test]
+    fn add32_const_matches_wrapping_add() {
+        let mut outer = rng(0xabad_1dea);
+        for _ in 0..20 {
+            let k: u32 = outer.random::<u32>() >> (outer.random::<u32>() % 32);
+            let mut b = Builder::new();
+            let wa = b.ingest("a");
+            let result = b.add32_const(wa, k);
+            let c = b.build(result);
+            let seed: u64 = outer.random();
+            let vm = MaskedCircuit::from_circuit(&c, &mut rng(seed));
+            for _ in 0..20 {
+                let a: u32 = outer.random();
+                let (_, revealed) = vm.eval(&c, &single_input(a));
+                assert_eq!(revealed, a.wrapping_add(k), "k={k:#010x} seed={seed:#x}");
+            }
+        }
+    }
+
+    #[test]
+    fn sub32_matches_wrapping_sub() {
+        let c = build_sub32_example();
+        let mut outer = rng(0xfeed_f00d);
+        for _ in 0..20 {
+            let seed: u64 = outer.random();
+            let vm = MaskedCircuit::from_circuit(&c, &mut rng(seed));
+            for _ in 0..20 {
+                let (a, b): (u32, u32) = (outer.random(), outer.random());
+                let inputs = inputs_of(a, b);
+                let values = c.eval(&inputs);
+                let (_, revealed) = vm.eval(&c, &inputs);
+                assert_eq!(values[&c.egress[0]], a.wrapping_sub(b), "value graph");
+                assert_eq!(revealed, a.wrapping_sub(b), "seed={seed:#x}");
+            }
+        }
+    }
+
     #[test]
     fn ingest_wires_are_masked() {
         let c = build_example();
@@ -411,4 +547,23 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn stats_predict_pool_len() {
+        use crate::circuit_transform::{inject_remasks, split_secret_consts};
+        use crate::expr_transform::Chance;
+        use crate::expr::Expr;
+        use crate::lower::lower_to_circuit_multi;
+
+        let a = Expr::input("a");
+        let t = Expr::rotl_var(Expr::xor(a.clone(), Expr::secret_const(5)), Expr::input("b"));
+        let lowered = lower_to_circuit_multi(&[Expr::and(Expr::not(t.clone()), Expr::public_const(0xff)), t]);
+        let mut rng = StdRng::seed_from_u64(2);
+        let remasked = inject_remasks(&lowered, &mut rng, Chance::ALWAYS);
+        let split    = split_secret_consts(&remasked, &mut rng, Chance::ALWAYS);
+        for c in [build_example(), build_add32_example(), lowered, split] {
+            let masked = MaskedCircuit::from_circuit(&c, &mut rng);
+            assert_eq!(c.stats().pool_entries, masked.pool_len());
+        }
+    }
 }
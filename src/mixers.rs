@@ -0,0 +1,430 @@
+//! Reusable mixing-function builders.
+//!
+//! Each builder takes `Rc<Expr>` operands and returns the mixed words as
+//! `Rc<Expr>`, so rounds compose freely with inputs, secret constants, and
+//! each other.  All builders are checked against plain-Rust reference
+//! implementations (and published test vectors where they exist).
+//!
+//! | Builder | Words | AND-triple cost |
+//! |---------|-------|-----------------|
+//! | [`chacha_quarter_round`] | 4 × 32-bit | 124 (4 adds) |
+//! | [`chacha_double_round`] | 16 × 32-bit state | 992 (8 quarter-rounds) |
+//! | [`half_sip_round`] | 4 × 32-bit (HalfSipHash) | 124 (4 adds) |
+//! | [`sip_round`] | 4 × 64-bit as [`Word64`] | 500 (4 × 125 per carry-linked 64-bit add) |
+//! | [`speck32_round`] | 2 × 16-bit block + 16-bit round key | 31 (one add) |
+//! | [`speck32_64_encrypt`] | 32-bit block, 64-bit key, in-circuit key schedule | 31 × (2 × rounds − 1) |
+//! | [`xxh32_round`] | accumulator + input lane | 578 (one add, two multiplications by public primes) |
+//! | [`xxh32_avalanche`] | 32-bit hash | 549 (two multiplications by public primes) |
+//!
+//! 16-bit (Speck) words live in the low half of a 32-bit word with the high
+//! half zero; the builders keep it that way.
+
+use std::rc::Rc;
+
+use crate::expr::Expr;
+
+// ---------------------------------------------------------------------------
+// ChaCha
+// ---------------------------------------------------------------------------
+
+/// One ChaCha quarter-round (RFC 8439 §2.1), returning `(a, b, c, d)`.
+///
+/// ```text
+/// a += b; d ^= a; d <<<= 16;
+/// c += d; b ^= c; b <<<= 12;
+/// a += b; d ^= a; d <<<= 8;
+/// c += d; b ^= c; b <<<= 7;
+/// ```
+pub fn chacha_quarter_round(
+    a: Rc<Expr>, b: Rc<Expr>, c: Rc<Expr>, d: Rc<Expr>,
+) -> (Rc<Expr>, Rc<Expr>, Rc<Expr>, Rc<Expr>) {
+    let a1 = Expr::add(a,          b.clone());
+    let d2 = Expr::rotl(Expr::xor(d,          a1.clone()), 16);
+    let c1 = Expr::add(c,          d2.clone());
+    let b2 = Expr::rotl(Expr::xor(b,          c1.clone()), 12);
+    let a2 = Expr::add(a1,         b2.clone());
+    let d4 = Expr::rotl(Expr::xor(d2,         a2.clone()),  8);
+    let c2 = Expr::add(c1,         d4.clone());
+    let b4 = Expr::rotl(Expr::xor(b2,         c2.clone()),  7);
+    (a2, b4, c2, d4)
+}
+
+/// One ChaCha double round over a 16-word state: four column
+/// quarter-rounds followed by four diagonal quarter-rounds.
+pub fn chacha_double_round(state: &[Rc<Expr>; 16]) -> [Rc<Expr>; 16] {
+    const COLUMNS:   [[usize; 4]; 4] = [[0, 4, 8, 12], [1, 5, 9, 13], [2, 6, 10, 14], [3, 7, 11, 15]];
+    const DIAGONALS: [[usize; 4]; 4] = [[0, 5, 10, 15], [1, 6, 11, 12], [2, 7, 8, 13], [3, 4, 9, 14]];
+
+    let mut s = state.clone();
+    for [a, b, c, d] in COLUMNS.into_iter().chain(DIAGONALS) {
+        let (na, nb, nc, nd) = chacha_quarter_round(
+            s[a].clone(), s[b].clone(), s[c].clone(), s[d].clone(),
+        );
+        (s[a], s[b], s[c], s[d]) = (na, nb, nc, nd);
+    }
+    s
+}
+
+/// `double_rounds` ChaCha double rounds (ChaCha20 is 10).  Does not add the
+/// input state back in; callers wanting the block function can `Expr::add`
+/// the original words.
+pub fn chacha_rounds(state: &[Rc<Expr>; 16], double_rounds: usize) -> [Rc<Expr>; 16] {
+    let mut s = state.clone();
+    for _ in 0..double_rounds {
+        s = chacha_double_round(&s);
+    }
+    s
+}
+
+// ---------------------------------------------------------------------------
+// SipHash
+// ---------------------------------------------------------------------------
+
+/// A 64-bit word as two 32-bit halves.
+#[derive(Clone, Debug)]
+pub struct Word64 {
+    pub hi: Rc<Expr>,
+    pub lo: Rc<Expr>,
+}
+
+impl Word64 {
+    pub fn new(hi: Rc<Expr>, lo: Rc<Expr>) -> Self {
+        Self { hi, lo }
+    }
+
+    pub fn xor(&self, other: &Word64) -> Word64 {
+        Word64::new(
+            Expr::xor(self.hi.clone(), other.hi.clone()),
+            Expr::xor(self.lo.clone(), other.lo.clone()),
+        )
+    }
+
+    /// 64-bit wrapping addition.  The low-half carry is recovered as
+    /// `lo < self.lo` (a broadcast mask, shifted down to one bit for free)
+    /// and added into the high half.
+    pub fn add(&self, other: &Word64) -> Word64 {
+        let lo    = Expr::add(self.lo.clone(), other.lo.clone());
+        let carry = Expr::shr(Expr::ltu(lo.clone(), self.lo.clone()), 31);
+        let hi    = Expr::add(Expr::add(self.hi.clone(), other.hi.clone()), carry);
+        Word64::new(hi, lo)
+    }
+
+    /// 64-bit left rotation.  Free: shifts and XORs of disjoint bit ranges.
+    pub fn rotl(&self, r: u32) -> Word64 {
+        let r = r % 64;
+        let (hi, lo) = if r >= 32 {
+            (self.lo.clone(), self.hi.clone())
+        } else {
+            (self.hi.clone(), self.lo.clone())
+        };
+        match r % 32 {
+            0 => Word64::new(hi, lo),
+            s => Word64::new(
+                Expr::xor(Expr::shl(hi.clone(), s), Expr::shr(lo.clone(), 32 - s)),
+                Expr::xor(Expr::shl(lo, s), Expr::shr(hi, 32 - s)),
+            ),
+        }
+    }
+}
+
+/// The SipRound shared by SipHash and HalfSipHash, parameterised by `add`,
+/// `xor`, `rotl` and the six rotation amounts.
+fn sip_round_with<W: Clone>(
+    [v0, v1, v2, v3]: [W; 4],
+    rot: [u32; 6],
+    add: impl Fn(&W, &W) -> W,
+    xor: impl Fn(&W, &W) -> W,
+    rotl: impl Fn(&W, u32) -> W,
+) -> [W; 4] {
+    let v0 = add(&v0, &v1); let v1 = rotl(&v1, rot[0]); let v1 = xor(&v1, &v0); let v0 = rotl(&v0, rot[1]);
+    let v2 = add(&v2, &v3); let v3 = rotl(&v3, rot[2]); let v3 = xor(&v3, &v2);
+    let v0 = add(&v0, &v3); let v3 = rotl(&v3, rot[3]); let v3 = xor(&v3, &v0);
+    let v2 = add(&v2, &v1); let v1 = rotl(&v1, rot[4]); let v1 = xor(&v1, &v2); let v2 = rotl(&v2, rot[5]);
+    [v0, v1, v2, v3]
+}
+
+/// One SipHash SipRound over 64-bit words `[v0, v1, v2, v3]`.
+pub fn sip_round(v: [Word64; 4]) -> [Word64; 4] {
+    sip_round_with(v, [13, 32, 16, 21, 17, 32], Word64::add, Word64::xor, Word64::rotl)
+}
+
+/// One HalfSipHash SipRound over 32-bit words `[v0, v1, v2, v3]`.
+pub fn half_sip_round(v: [Rc<Expr>; 4]) -> [Rc<Expr>; 4] {
+    sip_round_with(
+        v,
+        [5, 16, 8, 7, 13, 16],
+        |a, b| Expr::add(a.clone(), b.clone()),
+        |a, b| Expr::xor(a.clone(), b.clone()),
+        |a, r| Expr::rotl(a.clone(), r),
+    )
+}
+
+// ---------------------------------------------------------------------------
+// Speck32/64
+// ---------------------------------------------------------------------------
+
+/// The low half of `x`, cleared with free shifts rather than an AND.
+fn low16(x: Rc<Expr>) -> Rc<Expr> {
+    Expr::shr(Expr::shl(x, 16), 16)
+}
+
+fn add16(a: Rc<Expr>, b: Rc<Expr>) -> Rc<Expr> {
+    low16(Expr::add(a, b))
+}
+
+/// 16-bit left rotation of a word whose high half is zero.
+fn rotl16(a: Rc<Expr>, r: u32) -> Rc<Expr> {
+    let r = r % 16;
+    if r == 0 {
+        return a;
+    }
+    Expr::xor(
+        low16(Expr::shl(a.clone(), r)),
+        Expr::shr(a, 16 - r),
+    )
+}
+
+/// One Speck32 round: `x = ((x >>> 7) + y) ^ k; y = (y <<< 2) ^ x`.
+/// Returns `(x, y)`.
+pub fn speck32_round(x: Rc<Expr>, y: Rc<Expr>, k: Rc<Expr>) -> (Rc<Expr>, Rc<Expr>) {
+    let x = Expr::xor(add16(rotl16(x, 9), y.clone()), k);
+    let y = Expr::xor(rotl16(y, 2), x.clone());
+    (x, y)
+}
+
+/// Speck32/64 encryption of block `(x, y)` under `key = [l2, l1, l0, k0]`
+/// (the order the key is written in the Speck paper), for `rounds` rounds
+/// (the standard is 22).  The key schedule runs in-circuit, so key words
+/// may be inputs or secret constants.  Returns `(x, y)`.
+pub fn speck32_64_encrypt(
+    x: Rc<Expr>, y: Rc<Expr>, key: [Rc<Expr>; 4], rounds: usize,
+) -> (Rc<Expr>, Rc<Expr>) {
+    let [l2, l1, l0, k0] = key;
+    let mut l = vec![l0, l1, l2];
+    let mut k = k0;
+    let (mut x, mut y) = (x, y);
+    for i in 0..rounds {
+        (x, y) = speck32_round(x, y, k.clone());
+        if i + 1 < rounds {
+            // The schedule is the round function with the counter as key.
+            let (nl, nk) = speck32_round(l[i].clone(), k, Expr::public_const(i as u32));
+            l.push(nl);
+            k = nk;
+        }
+    }
+    (x, y)
+}
+
+// ---------------------------------------------------------------------------
+// xxHash32
+// ---------------------------------------------------------------------------
+
+pub const XXH_PRIME32_1: u32 = 0x9E37_79B1;
+pub const XXH_PRIME32_2: u32 = 0x85EB_CA77;
+pub const XXH_PRIME32_3: u32 = 0xC2B2_AE3D;
+
+/// One xxHash32 lane round: `acc = rotl(acc + input * P2, 13) * P1`.
+pub fn xxh32_round(acc: Rc<Expr>, input: Rc<Expr>) -> Rc<Expr> {
+    let acc = Expr::add(acc, Expr::mul_const(input, XXH_PRIME32_2));
+    Expr::mul_const(Expr::rotl(acc, 13), XXH_PRIME32_1)
+}
+
+/// The xxHash32 final avalanche:
+/// `h ^= h >> 15; h *= P2; h ^= h >> 13; h *= P3; h ^= h >> 16`.
+pub fn xxh32_avalanche(h: Rc<Expr>) -> Rc<Expr> {
+    let h = Expr::xor(h.clone(), Expr::shr(h, 15));
+    let h = Expr::mul_const(h, XXH_PRIME32_2);
+    let h = Expr::xor(h.clone(), Expr::shr(h, 13));
+    let h = Expr::mul_const(h, XXH_PRIME32_3);
+    Expr::xor(h.clone(), Expr::shr(h, 16))
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+    use rand::{Rng, SeedableRng};
+    use crate::lower::lower_to_circuit_multi;
+
+    fn inputs(prefix: &str, n: usize) -> Vec<Rc<Expr>> {
+        (0..n).map(|i| Expr::input(&format!("{prefix}{i}"))).collect()
+    }
+
+    fn eval(roots: &[Rc<Expr>], prefix: &str, values: &[u32]) -> Vec<u32> {
+        let map: HashMap<String, u32> = values.iter().enumerate()
+            .map(|(i, v)| (format!("{prefix}{i}"), *v))
+            .collect();
+        lower_to_circuit_multi(roots).eval_outputs(&map)
+    }
+
+
+    fn rng() -> rand::rngs::StdRng { rand::rngs::StdRng::seed_from_u64(0x5eed) }
+
+    // --- references ---
+
+    fn ref_qr(s: &mut [u32; 16], a: usize, b: usize, c: usize, d: usize) {
+        s[a] = s[a].wrapping_add(s[b]); s[d] = (s[d] ^ s[a]).rotate_left(16);
+        s[c] = s[c].wrapping_add(s[d]); s[b] = (s[b] ^ s[c]).rotate_left(12);
+        s[a] = s[a].wrapping_add(s[b]); s[d] = (s[d] ^ s[a]).rotate_left(8);
+        s[c] = s[c].wrapping_add(s[d]); s[b] = (s[b] ^ s[c]).rotate_left(7);
+    }
+
+    fn ref_double_round(s: &mut [u32; 16]) {
+        ref_qr(s, 0, 4, 8, 12); ref_qr(s, 1, 5, 9, 13); ref_qr(s, 2, 6, 10, 14); ref_qr(s, 3, 7, 11, 15);
+        ref_qr(s, 0, 5, 10, 15); ref_qr(s, 1, 6, 11, 12); ref_qr(s, 2, 7, 8, 13); ref_qr(s, 3, 4, 9, 14);
+    }
+
+    fn ref_sip_round(v: &mut [u64; 4]) {
+        v[0] = v[0].wrapping_add(v[1]); v[1] = v[1].rotate_left(13); v[1] ^= v[0]; v[0] = v[0].rotate_left(32);
+        v[2] = v[2].wrapping_add(v[3]); v[3] = v[3].rotate_left(16); v[3] ^= v[2];
+        v[0] = v[0].wrapping_add(v[3]); v[3] = v[3].rotate_left(21); v[3] ^= v[0];
+        v[2] = v[2].wrapping_add(v[1]); v[1] = v[1].rotate_left(17); v[1] ^= v[2]; v[2] = v[2].rotate_left(32);
+    }
+
+    fn ref_half_sip_round(v: &mut [u32; 4]) {
+        v[0] = v[0].wrapping_add(v[1]); v[1] = v[1].rotate_left(5); v[1] ^= v[0]; v[0] = v[0].rotate_left(16);
+        v[2] = v[2].wrapping_add(v[3]); v[3] = v[3].rotate_left(8); v[3] ^= v[2];
+        v[0] = v[0].wrapping_add(v[3]); v[3] = v[3].rotate_left(7); v[3] ^= v[0];
+        v[2] = v[2].wrapping_add(v[1]); v[1] = v[1].rotate_left(13); v[1] ^= v[2]; v[2] = v[2].rotate_left(16);
+    }
+
+    fn ref_xxh32_avalanche(mut h: u32) -> u32 {
+        h ^= h >> 15; h = h.wrapping_mul(XXH_PRIME32_2);
+        h ^= h >> 13; h = h.wrapping_mul(XXH_PRIME32_3);
+        h ^ (h >> 16)
+    }
+
+    // --- ChaCha ---
+
+    #[test]
+    fn chacha_quarter_round_rfc8439_vector() {
+        // RFC 8439 §2.1.1.
+        let w = inputs("s", 4);
+        let (a, b, c, d) = chacha_quarter_round(w[0].clone(), w[1].clone(), w[2].clone(), w[3].clone());
+        let out = eval(&[a, b, c, d], "s", &[0x1111_1111, 0x0102_0304, 0x9b8d_6f43, 0x0123_4567]);
+        assert_eq!(out, vec![0xea2a_92f4, 0xcb1c_f8ce, 0x4581_472e, 0x5881_c4bb]);
+    }
+
+    #[test]
+    fn chacha_double_round_matches_reference() {
+        let w: [Rc<Expr>; 16] = inputs("s", 16).try_into().unwrap();
+        let roots = chacha_rounds(&w, 2);
+        let mut rng = rng();
+        for _ in 0..4 {
+            let mut s: [u32; 16] = rng.random();
+            let got = eval(&roots, "s", &s);
+            ref_double_round(&mut s);
+            ref_double_round(&mut s);
+            assert_eq!(got, s.to_vec());
+        }
+    }
+
+    #[test]
+    fn triple_costs_match_table() {
+        let triples = |roots: &[Rc<Expr>]| lower_to_circuit_multi(roots).stats().and_triples;
+        let w = inputs("s", 16);
+        let (a, b, c, d) = chacha_quarter_round(w[0].clone(), w[1].clone(), w[2].clone(), w[3].clone());
+        assert_eq!(triples(&[a, b, c, d]), 124);
+        assert_eq!(triples(&chacha_double_round(&w.clone().try_into().unwrap())), 992);
+        let v: [Word64; 4] = std::array::from_fn(|i| Word64::new(w[2 * i].clone(), w[2 * i + 1].clone()));
+        let sip: Vec<Rc<Expr>> = sip_round(v).iter().flat_map(|x| [x.hi.clone(), x.lo.clone()]).collect();
+        assert_eq!(triples(&sip), 500);
+        assert_eq!(triples(&half_sip_round(std::array::from_fn(|i| w[i].clone()))), 124);
+        let (x, y) = speck32_round(w[0].clone(), w[1].clone(), w[2].clone());
+        assert_eq!(triples(&[x, y]), 31);
+        let key = std::array::from_fn(|i| w[2 + i].clone());
+        let (x, y) = speck32_64_encrypt(w[0].clone(), w[1].clone(), key, 22);
+        assert_eq!(triples(&[x, y]), 31 * 43);
+        assert_eq!(triples(&[xxh32_round(w[0].clone(), w[1].clone())]), 578);
+        assert_eq!(triples(&[xxh32_avalanche(w[0].clone())]), 549);
+    }
+
+    // --- SipHash ---
+
+    #[test]
+    fn sip_round_matches_reference() {
+        let w = inputs("v", 8);
+        let v: [Word64; 4] = std::array::from_fn(|i| Word64::new(w[2 * i].clone(), w[2 * i + 1].clone()));
+        let out = sip_round(v);
+        let roots: Vec<Rc<Expr>> = out.iter().flat_map(|x| [x.hi.clone(), x.lo.clone()]).collect();
+        let mut rng = rng();
+        for case in 0..8 {
+            // Include carries that ripple across the half boundary.
+            let mut v: [u64; 4] = if case == 0 { [u64::MAX, 1, u64::MAX, 1] } else { rng.random() };
+            let halves: Vec<u32> = v.iter().flat_map(|x| [(x >> 32) as u32, *x as u32]).collect();
+            let got = eval(&roots, "v", &halves);
+            ref_sip_round(&mut v);
+            let want: Vec<u32> = v.iter().flat_map(|x| [(x >> 32) as u32, *x as u32]).collect();
+            assert_eq!(got, want, "case {case}");
+        }
+    }
+
+    #[test]
+    fn word64_rotl_all_amounts() {
+        let w = inputs("v", 2);
+        let x = Word64::new(w[0].clone(), w[1].clone());
+        let v = 0x0123_4567_89ab_cdefu64;
+        for r in 0..64 {
+            let y = x.rotl(r);
+            let got = eval(&[y.hi, y.lo], "v", &[(v >> 32) as u32, v as u32]);
+            let want = v.rotate_left(r);
+            assert_eq!(got, vec![(want >> 32) as u32, want as u32], "r={r}");
+        }
+    }
+
+    #[test]
+    fn half_sip_round_matches_reference() {
+        let w: [Rc<Expr>; 4] = inputs("v", 4).try_into().unwrap();
+        let roots = half_sip_round(w);
+        let mut rng = rng();
+        for _ in 0..8 {
+            let mut v: [u32; 4] = rng.random();
+            let got = eval(&roots, "v", &v);
+            ref_half_sip_round(&mut v);
+            assert_eq!(got, v.to_vec());
+        }
+    }
+
+    // --- Speck ---
+
+    #[test]
+    fn speck32_64_paper_vector() {
+        // Speck paper, Appendix C: key 1918 1110 0908 0100,
+        // plaintext 6574 694c, ciphertext a868 42f2.
+        let w = inputs("w", 6);
+        let key = [w[0].clone(), w[1].clone(), w[2].clone(), w[3].clone()];
+        let (x, y) = speck32_64_encrypt(w[4].clone(), w[5].clone(), key, 22);
+        let out = eval(&[x, y], "w", &[0x1918, 0x1110, 0x0908, 0x0100, 0x6574, 0x694c]);
+        assert_eq!(out, vec![0xa868, 0x42f2]);
+    }
+
+    // --- xxHash32 ---
+
+    #[test]
+    fn xxh32_avalanche_matches_reference() {
+        let h = Expr::input("h0");
+        let root = xxh32_avalanche(h);
+        let mut rng = rng();
+        for v in [0u32, 1, 0xFFFF_FFFF, rng.random(), rng.random()] {
+            assert_eq!(eval(std::slice::from_ref(&root), "h", &[v]), vec![ref_xxh32_avalanche(v)]);
+        }
+    }
+
+    #[test]
+    fn xxh32_round_matches_reference() {
+        let w = inputs("x", 2);
+        let root = xxh32_round(w[0].clone(), w[1].clone());
+        let mut rng = rng();
+        for _ in 0..8 {
+            let (acc, lane): (u32, u32) = (rng.random(), rng.random());
+            let want = acc.wrapping_add(lane.wrapping_mul(XXH_PRIME32_2))
+                .rotate_left(13)
+                .wrapping_mul(XXH_PRIME32_1);
+            assert_eq!(eval(std::slice::from_ref(&root), "x", &[acc, lane]), vec![want]);
+        }
+    }
+}
@@ -14,26 +14,69 @@
 //!       │         (canonical, no transforms)
 //!       │
 //!       └─► strong_rotate ──► Expr' ──lower──► Circuit'
-//!             ──inject_remasks──► ──split_secret_consts──►
+//!             ──inject_remasks──► ──hide_public_consts──►
+//!             ──split_secret_consts──► ──remask_operands──►
 //!             ──from_circuit──► MaskedCircuit ──► emit_rust       (browser)
 //! ```
 //!
+//! `hide_public_consts` runs only when [`CompileOptions`] turns it on.
+//!
+//! [`rotate_cheap`] re-masks the existing circuit; [`rotate_strong`] reruns
+//! the whole pipeline on the original expressions for a new structure.
+//!
 //! The `EXPR_DIGEST` embedded in both artifacts comes from the original
 //! expression *before* any transforms.  This means cheap rotation, strong
 //! rotation, and any future obfuscation variant all produce the same digest,
 //! so the server verifier never needs to be redeployed for a rotation.
+//!
+//! # Multiple outputs
+//!
+//! [`compile_with`] and [`compile_verifier_with`] take one root per output
+//! word (e.g. four roots for a 128-bit tag).  The emitted functions return
+//! `[u32; N]` in root order, and the digest covers every root and their order.
+//!
+//! # Options
+//!
+//! [`compile_with`] runs the same stages under a [`CompileOptions`]: each
+//! pass can be disabled and its probability changed, trading code size
+//! against obfuscation strength per deployment.  The options also carry the
+//! HMAC key and the parameter order, so [`compile_verifier_with`] takes the
+//! same value.
+//!
+//! # Parameter order
+//!
+//! By default the emitted parameters are sorted by input name.
+//! [`CompileOptions::schema`] takes an [`InputSchema`] fixing the order
+//! instead; the schema is part of the digest, so both sides must use the
+//! same one.
+//!
+//! # Fallible entry points
+//!
+//! The plain entry points panic on misuse (inconsistent inputs, an empty
+//! root list).  [`try_compile`], [`try_compile_with`],
+//! [`try_compile_verifier`], [`try_compile_verifier_with`] and
+//! [`try_rotate_cheap`] check their arguments first and return an [`Error`]
+//! instead, including for a function name that fails [`check_fn_name`].
+//!
+//! Input names are never rejected: the emitters mangle any name that is not
+//! a usable identifier (see [`crate::emit::param_identifier`]), and
+//! [`Compilation::param_names`] records the mapping.
 
+use std::fmt;
+use std::ops::RangeInclusive;
 use std::rc::Rc;
 
 use rand::RngCore;
 
-use crate::circuit::Circuit;
-use crate::circuit_transform::{inject_remasks, split_secret_consts};
-use crate::emit::{emit_rust, emit_verifier_rust};
-use crate::expr::{expr_digest, Expr};
-use crate::expr_transform::strong_rotate;
-use crate::lower::lower_to_circuit;
+use crate::circuit::{Circuit, StatsDiff};
+use crate::circuit_transform::{hide_public_consts, inject_remasks, remask_operands, split_secret_consts};
+use crate::emit::{check_fn_name, emit_rust, emit_verifier_rust, param_identifiers};
+use crate::error::Error;
+use crate::expr::{exprs_digest_with_schema, Expr};
+use crate::expr_transform::{inject_decoys_at, strong_rotate_multi_with, Chance, RotateOptions};
+use crate::lower::try_lower_to_circuit_multi;
 use crate::mask::MaskedCircuit;
+use crate::schema::InputSchema;
 
 // ---------------------------------------------------------------------------
 // Compilation artifact
@@ -41,20 +84,209 @@ use crate::mask::MaskedCircuit;
 
 /// The artifacts produced by one compilation run.
 pub struct Compilation {
-    /// Pre-transform expression — the canonical definition of F.
+    /// Pre-transform expression — the canonical definition of F.  For a
+    /// multi-output compilation this is the first root; see
+    /// [`Compilation::original_exprs`].
     pub original_expr: Rc<Expr>,
+    /// Pre-transform expressions, one per output word.
+    pub original_exprs: Vec<Rc<Expr>>,
     /// Post-transform circuit.  The server mirrors this and calls
     /// [`Circuit::eval`] to verify client checksums.
     pub circuit: Circuit,
     /// Concretized client artifact — baked masks, constants, and triples.
     pub masked: MaskedCircuit,
-    /// Stable digest of [`Compilation::original_expr`].  Identical for every
+    /// Stable digest of [`Compilation::original_exprs`].  Identical for every
     /// rotation (cheap or strong) of the same expression.  Embedded in both
     /// the browser artifact and the server verifier so the server can match
     /// incoming reports to the right verifier without redeployment.
     pub expr_digest: [u8; 32],
     /// Emitted Rust source — the deployable client function.
     pub code: String,
+    /// `(input name, emitted parameter name)` per parameter, in signature
+    /// order.  The names differ only for inputs that had to be mangled; the
+    /// verifier's parameters are the same names prefixed with `input_`.
+    pub param_names: Vec<(String, String)>,
+    /// The options this compilation ran with, including its HMAC key and
+    /// schema.  [`rotate_strong`] reruns the pipeline with them.
+    pub options: CompileOptions,
+}
+
+// ---------------------------------------------------------------------------
+// Options
+// ---------------------------------------------------------------------------
+
+/// Pass selection and probabilities for [`compile_with`].
+///
+/// The default is the standard pipeline run by [`compile`]: every pass
+/// enabled at its standard probability, no HMAC key, and no schema.
+/// Disabling passes shrinks the emitted code at the cost of obfuscation
+/// strength.  None of the options changes the digest except `key` and
+/// `schema`, so browser artifacts built with different options still match
+/// one verifier.
+///
+/// `Debug` prints `key` as `<redacted>`, so options can be logged.
+#[derive(Clone)]
+pub struct CompileOptions {
+    /// Expression-level transforms (`strong_rotate`); `None` skips them.
+    pub strong_rotate: Option<RotateOptions>,
+    /// Probability that `inject_remasks` remasks each eligible wire
+    /// (default 1/4).  [`Chance::NEVER`] skips the pass.
+    pub remasks: Chance,
+    /// Probability that `split_secret_consts` splits each secret constant
+    /// (default 1/3).  [`Chance::NEVER`] skips the pass.
+    pub const_splits: Chance,
+    /// HMAC key for the digest, as for [`compile`].
+    pub key: Option<Vec<u8>>,
+    /// Explicit parameter order for both emitted signatures, checked
+    /// against the untransformed expressions.  A declared input that the
+    /// transforms fold away stays in the signature, unread.  Covered by the
+    /// digest; see [`exprs_digest_with_schema`].
+    pub schema: Option<InputSchema>,
+    /// Inject decoys until the shipped circuit lands in this budget (see
+    /// [`DecoyBudget`]), instead of at `strong_rotate`'s random rate, which
+    /// is then ignored.  `None` (the default) leaves decoys to
+    /// `strong_rotate`.
+    pub decoy_budget: Option<DecoyBudget>,
+    /// Run `hide_public_consts` after `inject_remasks`, so that `POOL`
+    /// holds no public constant in the clear.  Off by default: it roughly
+    /// triples the AND triples of every addition, which `decoy_budget`
+    /// counts.
+    pub hide_public_consts: bool,
+    /// Run `remask_operands` last, so that no `POOL` entry, nor the XOR of
+    /// two, gives away an ingest, secret-constant or AND-operand mask.  On
+    /// by default.
+    pub remask_operands: bool,
+}
+
+impl Default for CompileOptions {
+    fn default() -> Self {
+        CompileOptions {
+            strong_rotate:      Some(RotateOptions::default()),
+            remasks:            Chance::new(1, 4),
+            const_splits:       Chance::new(1, 3),
+            key:                None,
+            schema:             None,
+            decoy_budget:       None,
+            hide_public_consts: false,
+            remask_operands:    true,
+        }
+    }
+}
+
+impl CompileOptions {
+    /// No obfuscation passes: the lowered circuit is masked and emitted
+    /// with only `remask_operands`, which stays on because without it three
+    /// entries of an AND triple XOR to an operand's mask.  The smallest
+    /// output that keeps every input mask out of `POOL`.
+    pub fn minimal() -> Self {
+        CompileOptions {
+            strong_rotate: None,
+            remasks:       Chance::NEVER,
+            const_splits:  Chance::NEVER,
+            ..Self::default()
+        }
+    }
+}
+
+impl fmt::Debug for CompileOptions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CompileOptions")
+            .field("strong_rotate", &self.strong_rotate)
+            .field("remasks", &self.remasks)
+            .field("const_splits", &self.const_splits)
+            .field("key", &self.key.as_ref().map(|_| "<redacted>"))
+            .field("schema", &self.schema)
+            .field("decoy_budget", &self.decoy_budget)
+            .field("hide_public_consts", &self.hide_public_consts)
+            .field("remask_operands", &self.remask_operands)
+            .finish()
+    }
+}
+
+/// Target ranges for the shipped circuit's size, for
+/// [`CompileOptions::decoy_budget`].
+///
+/// Both counts are those of [`Circuit::stats`] on the circuit that is
+/// masked and emitted: [`compile_with`] measures each candidate after the
+/// schema's unread inputs, `hide_public_consts` and `remask_operands`.
+/// `inject_remasks` and `split_secret_consts` add a random number of pool
+/// entries and nothing else, so a budget that caps `pool_entries` is
+/// refused unless both are [`Chance::NEVER`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DecoyBudget {
+    /// Beaver triples, i.e. `CircuitStats::and_triples`.
+    pub and_triples: RangeInclusive<usize>,
+    /// Pool entries, i.e. `CircuitStats::pool_entries`.
+    pub pool_entries: RangeInclusive<usize>,
+}
+
+/// Splice decoys into `roots` until the lowered circuit's AND triples and
+/// pool entries both fall within `budget`.
+///
+/// Each round lowers the current roots, and if they fall short estimates
+/// how many decoys are missing and injects that many with
+/// [`inject_decoys_at`] (each costs two triples and eight or nine pool
+/// entries), halving the batch whenever it would overshoot either upper
+/// bound.  Only the counts change: the result computes the same function as
+/// `roots`.
+///
+/// Fails with [`Error::Unsupported`] if `roots` is already over budget, if
+/// the ranges are too narrow for a single decoy to land in them, or if
+/// there are too few nodes to hang decoys on.
+pub fn inject_decoys_to_budget(
+    roots: &[Rc<Expr>], budget: &DecoyBudget, rng: &mut impl RngCore,
+) -> Result<Vec<Rc<Expr>>, Error> {
+    inject_decoys_measured(roots, budget, rng, try_lower_to_circuit_multi)
+}
+
+/// [`inject_decoys_to_budget`], measuring the circuit `build` makes of each
+/// candidate instead of the plain lowering.
+fn inject_decoys_measured(
+    roots: &[Rc<Expr>], budget: &DecoyBudget, rng: &mut impl RngCore,
+    build: impl Fn(&[Rc<Expr>]) -> Result<Circuit, Error>,
+) -> Result<Vec<Rc<Expr>>, Error> {
+    let measure = |exprs: &[Rc<Expr>]| -> Result<(usize, usize), Error> {
+        let stats = build(exprs)?.stats();
+        Ok((stats.and_triples, stats.pool_entries))
+    };
+    let fits = |(triples, pool): (usize, usize)| {
+        triples <= *budget.and_triples.end() && pool <= *budget.pool_entries.end()
+    };
+
+    let mut current = roots.to_vec();
+    let mut counts  = measure(&current)?;
+    loop {
+        let (triples, pool) = counts;
+        if budget.and_triples.contains(&triples) && budget.pool_entries.contains(&pool) {
+            return Ok(current);
+        }
+        if !fits(counts) {
+            return Err(Error::Unsupported(format!(
+                "circuit has {triples} AND triples and {pool} pool entries before any decoys, over budget {budget:?}"
+            )));
+        }
+        let missing_triples = budget.and_triples.start().saturating_sub(triples).div_ceil(2);
+        let missing_pool    = budget.pool_entries.start().saturating_sub(pool).div_ceil(9);
+        let mut batch = missing_triples.max(missing_pool).max(1);
+        loop {
+            let candidate = inject_decoys_at(&current, batch, rng);
+            let next      = measure(&candidate)?;
+            if next == counts {
+                return Err(Error::Unsupported("too few nodes to inject decoys into".to_string()));
+            }
+            if fits(next) {
+                current = candidate;
+                counts  = next;
+                break;
+            }
+            if batch == 1 {
+                return Err(Error::Unsupported(format!(
+                    "no decoy lands in budget {budget:?} from {triples} AND triples and {pool} pool entries"
+                )));
+            }
+            batch /= 2;
+        }
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -69,9 +301,12 @@ pub struct Compilation {
 /// 2. `strong_rotate` — structural expression-level obfuscation.
 /// 3. `lower_to_circuit` — deterministic lowering to a value graph.
 /// 4. `inject_remasks` at rate 1-in-4 — post-lowering mask re-randomization.
-/// 5. `split_secret_consts` at rate 1-in-3 — probabilistic constant splitting.
-/// 6. `MaskedCircuit::from_circuit` — concretization.
-/// 7. `emit_rust` — code generation into `Compilation::code`.
+/// 5. `hide_public_consts` — only when [`CompileOptions::hide_public_consts`]
+///    is set, which `compile` never does.
+/// 6. `split_secret_consts` at rate 1-in-3 — probabilistic constant splitting.
+/// 7. `remask_operands` — fresh masks on every AND and egress operand.
+/// 8. `MaskedCircuit::from_circuit` — concretization.
+/// 9. `emit_rust` — code generation into `Compilation::code`.
 ///
 /// `fn_name` becomes the emitted function's name and must be a valid Rust
 /// identifier.  All randomness comes from `rng`; the caller seeds it however
@@ -83,14 +318,92 @@ pub struct Compilation {
 /// embedded digest.  Use the same key for `compile` and `compile_verifier` so
 /// the digests match.
 pub fn compile(expr: Rc<Expr>, fn_name: &str, rng: &mut impl RngCore, key: Option<&[u8]>) -> Compilation {
-    let digest      = expr_digest(&expr, key);
-    let transformed = strong_rotate(&expr, rng);
-    let circuit     = lower_to_circuit(&transformed);
-    let circuit     = inject_remasks(&circuit, rng, 4);
-    let circuit     = split_secret_consts(&circuit, rng, 3);
+    compile_with(vec![expr], fn_name, &keyed(key), rng)
+}
+
+/// [`compile`] with one root per output word and explicit pass selection,
+/// key and schema; see [`CompileOptions`].
+///
+/// The emitted function returns `[u32; N]` in `exprs` order (plain `u32` when
+/// `N == 1`).  The digest covers all roots.  `CompileOptions::default()` on
+/// one root gives exactly [`compile`] with no key.
+///
+/// Panics if `exprs` is empty, if `options.schema` does not list exactly the
+/// inputs of `exprs`, or if `options.decoy_budget` cannot be met; see
+/// [`try_compile_with`].
+pub fn compile_with(exprs: Vec<Rc<Expr>>, fn_name: &str, options: &CompileOptions, rng: &mut impl RngCore) -> Compilation {
+    compile_passes(exprs, options, fn_name, rng).unwrap_or_else(|e| panic!("{e}"))
+}
+
+/// The pipeline proper.  Fails if `exprs` is empty, `options.schema` does
+/// not match them, or `options.decoy_budget` cannot be met.
+fn compile_passes(
+    exprs: Vec<Rc<Expr>>, options: &CompileOptions, fn_name: &str, rng: &mut impl RngCore,
+) -> Result<Compilation, Error> {
+    let schema = options.schema.as_ref();
+    if let Some(schema) = schema {
+        schema.check(&try_lower_to_circuit_multi(&exprs)?)?;
+    }
+    let random_pool = options.remasks != Chance::NEVER || options.const_splits != Chance::NEVER;
+    if let Some(budget) = options.decoy_budget.as_ref().filter(|b| random_pool && *b.pool_entries.end() != usize::MAX) {
+        return Err(Error::Unsupported(format!(
+            "decoy budget {budget:?} caps pool entries, but inject_remasks and split_secret_consts add a random \
+             number of them: set remasks and const_splits to Chance::NEVER"
+        )));
+    }
+    let lower = |exprs: &[Rc<Expr>]| -> Result<Circuit, Error> {
+        let circuit = try_lower_to_circuit_multi(exprs)?;
+        match schema {
+            Some(schema) => circuit.with_schema_keeping_inputs(schema),
+            None         => Ok(circuit),
+        }
+    };
+    let digest          = exprs_digest_with_schema(&exprs, schema, options.key.as_deref());
+    let mut transformed = match (&options.strong_rotate, &options.decoy_budget) {
+        (Some(rotate), None)    => strong_rotate_multi_with(&exprs, rotate, rng),
+        (Some(rotate), Some(_)) => strong_rotate_multi_with(&exprs, &RotateOptions { decoys: Chance::NEVER, ..*rotate }, rng),
+        (None, _)               => exprs.clone(),
+    };
+    if let Some(budget) = &options.decoy_budget {
+        // The random passes only add pool entries, so measuring without them
+        // gives the shipped triples exactly, and the shipped pool whenever
+        // the budget caps it.
+        transformed = inject_decoys_measured(&transformed, budget, rng, |exprs| {
+            let mut circuit = lower(exprs)?;
+            if options.hide_public_consts {
+                circuit = hide_public_consts(&circuit);
+            }
+            if options.remask_operands {
+                circuit = remask_operands(&circuit);
+            }
+            Ok(circuit)
+        })?;
+    }
+    let mut circuit = lower(&transformed)?;
+    if options.remasks != Chance::NEVER {
+        circuit = inject_remasks(&circuit, rng, options.remasks);
+    }
+    if options.hide_public_consts {
+        circuit = hide_public_consts(&circuit);
+    }
+    if options.const_splits != Chance::NEVER {
+        circuit = split_secret_consts(&circuit, rng, options.const_splits);
+    }
+    if options.remask_operands {
+        circuit = remask_operands(&circuit);
+    }
     let masked      = MaskedCircuit::from_circuit(&circuit, rng);
     let code        = emit_rust(&masked, &circuit, fn_name, rng, &digest);
-    Compilation { original_expr: expr, circuit, masked, expr_digest: digest, code }
+    let param_names = param_identifiers(&circuit);
+    Ok(Compilation {
+        original_expr: exprs[0].clone(), original_exprs: exprs, circuit, masked, expr_digest: digest, code, param_names,
+        options: options.clone(),
+    })
+}
+
+/// The default options with `key`, for the entry points that take a bare key.
+fn keyed(key: Option<&[u8]>) -> CompileOptions {
+    CompileOptions { key: key.map(<[u8]>::to_vec), ..CompileOptions::default() }
 }
 
 /// Emit the plaintext server verifier for `expr`.
@@ -99,9 +412,17 @@ pub fn compile(expr: Rc<Expr>, fn_name: &str, rng: &mut impl RngCore, key: Optio
 /// emits an unmasked evaluation function.  The embedded `EXPR_DIGEST` matches
 /// that produced by [`compile`] for the same `expr` and `key`.
 pub fn compile_verifier(expr: &Rc<Expr>, fn_name: &str, key: Option<&[u8]>) -> String {
-    let digest  = expr_digest(expr, key);
-    let circuit = lower_to_circuit(expr);
-    emit_verifier_rust(&circuit, fn_name, &digest)
+    compile_verifier_with(std::slice::from_ref(expr), fn_name, &keyed(key))
+}
+
+/// The verifier matching [`compile_with`] for the same roots and `options`
+/// (only `options.key` and `options.schema` matter: the verifier is never
+/// transformed).
+///
+/// Panics if `exprs` is empty or `options.schema` does not list exactly the
+/// inputs of `exprs`; see [`try_compile_verifier_with`].
+pub fn compile_verifier_with(exprs: &[Rc<Expr>], fn_name: &str, options: &CompileOptions) -> String {
+    try_compile_verifier_with(exprs, fn_name, options).unwrap_or_else(|e| panic!("{e}"))
 }
 
 /// Re-concretize an existing circuit with fresh randomness — a cheap rotation.
@@ -113,11 +434,98 @@ pub fn compile_verifier(expr: &Rc<Expr>, fn_name: &str, key: Option<&[u8]>) -> S
 ///
 /// Returns the new `MaskedCircuit` and emitted browser source.
 pub fn rotate_cheap(compilation: &Compilation, fn_name: &str, rng: &mut impl RngCore) -> (MaskedCircuit, String) {
-    let masked = MaskedCircuit::from_circuit(&compilation.circuit, rng);
-    let code   = emit_rust(&masked, &compilation.circuit, fn_name, rng, &compilation.expr_digest);
+    rotate_cheap_circuit(&compilation.circuit, &compilation.expr_digest, fn_name, rng)
+}
+
+/// Rebuild a compilation from its original expressions with fresh
+/// randomness — a strong rotation.
+///
+/// Reruns every stage of the pipeline on [`Compilation::original_exprs`]
+/// with the compilation's own [`Compilation::options`] (same passes,
+/// probabilities, key and schema), so the gadget structure changes while
+/// [`Compilation::expr_digest`] does not: the server verifier stays valid.
+///
+/// Returns the new compilation and how its circuit differs from the old one,
+/// e.g. to log `gadgets +40, triples +12 (AND +12, ...)` per deployment.
+///
+/// Panics if the recomputed digest differs from `compilation.expr_digest`,
+/// i.e. if the original expressions or options were modified, or if the
+/// options' decoy budget cannot be met this time.
+pub fn rotate_strong(compilation: &Compilation, fn_name: &str, rng: &mut impl RngCore) -> (Compilation, StatsDiff) {
+    let rotated = compile_with(compilation.original_exprs.clone(), fn_name, &compilation.options, rng);
+    assert_eq!(rotated.expr_digest, compilation.expr_digest,
+        "strong rotation changed EXPR_DIGEST: original_exprs or options were modified");
+    let diff = compilation.circuit.stats().diff(&rotated.circuit.stats());
+    (rotated, diff)
+}
+
+/// [`rotate_cheap`] from a saved artifact: the post-transform circuit (e.g.
+/// loaded with [`Circuit::from_json`]) and its digest, without the original
+/// `Compilation`.
+pub fn rotate_cheap_circuit(
+    circuit: &Circuit, expr_digest: &[u8; 32], fn_name: &str, rng: &mut impl RngCore,
+) -> (MaskedCircuit, String) {
+    let masked = MaskedCircuit::from_circuit(circuit, rng);
+    let code   = emit_rust(&masked, circuit, fn_name, rng, expr_digest);
     (masked, code)
 }
 
+// ---------------------------------------------------------------------------
+// Fallible entry points
+// ---------------------------------------------------------------------------
+
+/// [`compile`], returning an error instead of panicking.
+pub fn try_compile(expr: Rc<Expr>, fn_name: &str, rng: &mut impl RngCore, key: Option<&[u8]>) -> Result<Compilation, Error> {
+    try_compile_with(vec![expr], fn_name, &keyed(key), rng)
+}
+
+/// [`compile_with`], returning an error instead of panicking — including
+/// [`Error::InvalidIdentifier`] for a bad `fn_name`, [`Error::Unsupported`]
+/// for an empty `exprs` or an unreachable decoy budget, and
+/// [`Error::SchemaMismatch`].
+///
+/// `fn_name` and the schema are checked before any randomness is drawn, so
+/// on success the result is identical to [`compile_with`] with the same
+/// `rng` state.
+pub fn try_compile_with(
+    exprs: Vec<Rc<Expr>>, fn_name: &str, options: &CompileOptions, rng: &mut impl RngCore,
+) -> Result<Compilation, Error> {
+    check_fn_name(fn_name)?;
+    compile_passes(exprs, options, fn_name, rng)
+}
+
+/// [`compile_verifier`], returning an error instead of panicking.
+pub fn try_compile_verifier(expr: &Rc<Expr>, fn_name: &str, key: Option<&[u8]>) -> Result<String, Error> {
+    try_compile_verifier_with(std::slice::from_ref(expr), fn_name, &keyed(key))
+}
+
+/// [`compile_verifier_with`], returning an error instead of panicking — for
+/// an invalid `fn_name`, an empty `exprs`, a circuit that fails validation,
+/// or [`Error::SchemaMismatch`].
+pub fn try_compile_verifier_with(
+    exprs: &[Rc<Expr>], fn_name: &str, options: &CompileOptions,
+) -> Result<String, Error> {
+    check_fn_name(fn_name)?;
+    let schema      = options.schema.as_ref();
+    let mut circuit = try_lower_to_circuit_multi(exprs)?;
+    if let Some(schema) = schema {
+        circuit = circuit.with_schema(schema)?;
+    }
+    Ok(emit_verifier_rust(&circuit, fn_name, &exprs_digest_with_schema(exprs, schema, options.key.as_deref())))
+}
+
+/// [`rotate_cheap`], returning an error instead of panicking — for a bad
+/// `fn_name`, or a `Compilation` whose circuit was modified and no longer
+/// validates.
+pub fn try_rotate_cheap(
+    compilation: &Compilation, fn_name: &str, rng: &mut impl RngCore,
+) -> Result<(MaskedCircuit, String), Error> {
+    check_fn_name(fn_name)?;
+    let masked = MaskedCircuit::try_from_circuit(&compilation.circuit, rng)?;
+    let code   = emit_rust(&masked, &compilation.circuit, fn_name, rng, &compilation.expr_digest);
+    Ok((masked, code))
+}
+
 // ---------------------------------------------------------------------------
 // Tests
 // ---------------------------------------------------------------------------
@@ -126,6 +534,8 @@ pub fn rotate_cheap(compilation: &Compilation, fn_name: &str, rng: &mut impl Rng
 mod tests {
     use super::*;
     use rand::SeedableRng;
+    use crate::expr::exprs_digest;
+    use crate::lower::{lower_to_circuit, lower_to_circuit_multi};
 
     fn run(expr: Rc<Expr>, inputs: &[(&str, u32)], expected: u32) {
         let input_map: std::collections::HashMap<String, u32> =
@@ -136,7 +546,7 @@ mod tests {
             let c = compile(Rc::clone(&expr), "checksum", &mut rng, None);
 
             let vals = c.circuit.eval(&input_map);
-            assert_eq!(vals[&c.circuit.egress], expected,
+            assert_eq!(vals[&c.circuit.egress[0]], expected,
                 "circuit eval wrong (pipeline_seed={pipeline_seed})");
 
             let (_regs, revealed) = c.masked.eval(&c.circuit, &input_map);
@@ -189,7 +599,7 @@ mod tests {
             &[("a", av), ("b", bv), ("c", cv), ("d", dv)]
                 .iter().map(|&(k, v)| (k.to_string(), v)).collect()
         );
-        let expected = vals[&circuit.egress];
+        let expected = vals[&circuit.egress[0]];
 
         run(Rc::clone(&expr), &[("a", av), ("b", bv), ("c", cv), ("d", dv)], expected);
     }
@@ -282,6 +692,48 @@ mod tests {
             "keyed digest must differ from unkeyed digest");
     }
 
+    #[test]
+    fn pipeline_multi_output() {
+        let a = Expr::input("a");
+        let b = Expr::input("b");
+        let s = Expr::add(a.clone(), b.clone());
+        let roots = vec![s.clone(), Expr::xor(s, b.clone()), Expr::and(a, b)];
+        let input_map: std::collections::HashMap<String, u32> =
+            [("a", 0x1234_5678u32), ("b", 0xDEAD_BEEFu32)]
+            .iter().map(|&(k, v)| (k.to_string(), v)).collect();
+        let sum = 0x1234_5678u32.wrapping_add(0xDEAD_BEEF);
+        let expected = vec![sum, sum ^ 0xDEAD_BEEF, 0x1234_5678 & 0xDEAD_BEEF];
+
+        for pipeline_seed in 0u64..4 {
+            let mut rng = rand::rngs::StdRng::seed_from_u64(pipeline_seed);
+            let c = compile_with(roots.clone(), "tag", &CompileOptions::default(), &mut rng);
+            assert_eq!(c.circuit.eval_outputs(&input_map), expected);
+            assert_eq!(c.masked.eval_multi(&c.circuit, &input_map).1, expected);
+            assert!(c.code.contains("pub fn tag(a: u32, b: u32) -> [u32; 3] {"), "{}", c.code);
+            assert!(Rc::ptr_eq(&c.original_expr, &roots[0]));
+        }
+
+        let verifier = compile_verifier_with(&roots, "tag_verify", &CompileOptions::default());
+        assert!(verifier.contains("-> [u32; 3] {"), "{verifier}");
+
+        let mut rng = rand::rngs::StdRng::seed_from_u64(0);
+        assert!(matches!(try_compile_with(vec![], "tag", &CompileOptions::default(), &mut rng), Err(Error::Unsupported(_))));
+        assert!(matches!(try_compile_verifier_with(&[], "tag_verify", &CompileOptions::default()), Err(Error::Unsupported(_))));
+    }
+
+    #[test]
+    fn multi_digest_covers_every_output_in_order() {
+        let a = Expr::input("a");
+        let b = Expr::input("b");
+        let single = exprs_digest(std::slice::from_ref(&a), None);
+        assert_eq!(single, crate::expr::expr_digest(&a, None));
+        let ab = exprs_digest(&[a.clone(), b.clone()], None);
+        let ba = exprs_digest(&[b.clone(), a.clone()], None);
+        assert_ne!(ab, ba, "output order must change the digest");
+        assert_ne!(ab, single);
+        assert_ne!(ab, exprs_digest(&[a.clone(), b, a], None));
+    }
+
     #[test]
     fn compile_verifier_matches_compile_digest() {
         let a = Expr::input("a");
@@ -302,4 +754,290 @@ mod tests {
         assert_eq!(digest_line(&compilation.code), digest_line(&verifier),
             "browser artifact and verifier must embed the same EXPR_DIGEST");
     }
+
+    #[test]
+    fn rotate_cheap_from_saved_circuit() {
+        let expr = Expr::add(Expr::rotl(Expr::input("a"), 3), Expr::secret_const(0x1234_5678));
+        let mut rng = rand::rngs::StdRng::seed_from_u64(3);
+        let compilation = compile(expr, "f", &mut rng, None);
+
+        let saved = Circuit::from_json(&compilation.circuit.to_json()).unwrap();
+        let (masked, code) = rotate_cheap_circuit(&saved, &compilation.expr_digest, "f", &mut rng);
+        let inputs = [("a".to_string(), 0x8000_0001u32)].into();
+        assert_eq!(masked.eval(&saved, &inputs).1, 0x0000_000cu32.wrapping_add(0x1234_5678));
+        assert!(code.contains(&format!("0x{:02x}", compilation.expr_digest[0])));
+    }
+
+    #[test]
+    fn try_entry_points_report_errors() {
+        let expr = Expr::xor(Expr::input("a"), Expr::secret_const(7));
+        let mut rng = rand::rngs::StdRng::seed_from_u64(5);
+
+        assert_eq!(try_compile(Rc::clone(&expr), "fn", &mut rng, None).err(),
+                   Some(Error::InvalidIdentifier("fn".to_string())));
+        assert_eq!(try_compile_verifier(&expr, "w3", None),
+                   Err(Error::InvalidIdentifier("w3".to_string())));
+        assert!(matches!(try_compile_verifier_with(&[], "f", &CompileOptions::default()), Err(Error::Unsupported(_))));
+
+        let checked = try_compile(Rc::clone(&expr), "f", &mut rand::rngs::StdRng::seed_from_u64(5), None).unwrap();
+        let plain   = compile(Rc::clone(&expr), "f", &mut rand::rngs::StdRng::seed_from_u64(5), None);
+        assert_eq!(checked.code, plain.code);
+        assert_eq!(try_compile_verifier(&expr, "f_verify", None).unwrap(),
+                   compile_verifier(&expr, "f_verify", None));
+
+        assert!(try_rotate_cheap(&checked, "f", &mut rng).is_ok());
+        assert_eq!(try_rotate_cheap(&checked, "1f", &mut rng).err(),
+                   Some(Error::InvalidIdentifier("1f".to_string())));
+    }
+
+    #[test]
+    fn awkward_input_names_are_mangled() {
+        let expr = Expr::xor(
+            Expr::add(Expr::input("event-type"), Expr::input("POOL")),
+            Expr::xor(Expr::input("r"), Expr::input("fn")),
+        );
+        let mut rng = rand::rngs::StdRng::seed_from_u64(9);
+        let c = compile(Rc::clone(&expr), "tag", &mut rng, None);
+        let names: Vec<(&str, &str)> = c.param_names.iter().map(|(n, id)| (n.as_str(), id.as_str())).collect();
+        assert_eq!(names, [("POOL", "q_POOL"), ("event-type", "q_event_2d_type"), ("fn", "q_fn"), ("r", "q_r")]);
+        assert!(c.code.contains("pub fn tag(q_POOL: u32, q_event_2d_type: u32, q_fn: u32, q_r: u32) -> u32 {"), "{}", c.code);
+
+        let verifier = compile_verifier(&expr, "tag_verify", None);
+        assert!(verifier.contains("input_q_event_2d_type: u32"), "{verifier}");
+
+        // Evaluation still uses the original names.
+        let inputs = [("event-type", 5u32), ("POOL", 6), ("r", 7), ("fn", 8)]
+            .map(|(k, v)| (k.to_string(), v)).into();
+        assert_eq!(c.circuit.try_eval_outputs(&inputs), Ok(vec![11 ^ 7 ^ 8]));
+    }
+
+    #[test]
+    fn schema_fixes_parameter_order() {
+        let msg  = Expr::input_bytes("msg", 5);
+        let expr = Expr::add(Expr::xor(Expr::input("zeta"), msg[1].clone()), Expr::input("alpha"));
+        let exprs = vec![Rc::clone(&expr)];
+        let schema = InputSchema::new().input("zeta").input_bytes("msg", 5).input("alpha");
+
+        let options = CompileOptions { schema: Some(schema.clone()), ..CompileOptions::default() };
+
+        let mut rng = rand::rngs::StdRng::seed_from_u64(11);
+        let c = compile_with(exprs.clone(), "tag", &options, &mut rng);
+        let verifier = compile_verifier_with(&exprs, "tag_verify", &options);
+        assert!(c.code.contains("pub fn tag(zeta: u32, msg: &[u8; 5], alpha: u32) -> u32 {"), "{}", c.code);
+        assert!(verifier.contains("pub fn tag_verify(input_zeta: u32, input_msg: &[u8; 5], input_alpha: u32) -> u32 {"));
+        assert_eq!(c.param_names[0], ("zeta".to_string(), "zeta".to_string()));
+
+        // The order survives a cheap rotation and is covered by the digest.
+        let (_, rotated) = rotate_cheap(&c, "tag", &mut rng);
+        assert!(rotated.contains("pub fn tag(zeta: u32, msg: &[u8; 5], alpha: u32) -> u32 {"));
+        let reordered = InputSchema::new().input("alpha").input_bytes("msg", 5).input("zeta");
+        assert_eq!(c.expr_digest, exprs_digest_with_schema(&exprs, Some(&schema), None));
+        assert_ne!(c.expr_digest, exprs_digest_with_schema(&exprs, Some(&reordered), None));
+        assert_ne!(c.expr_digest, exprs_digest(&exprs, None));
+
+        // A schema that disagrees with the expression is refused.
+        let missing = CompileOptions { schema: Some(InputSchema::new().input("zeta").input_bytes("msg", 5)), ..options.clone() };
+        assert!(matches!(try_compile_with(exprs.clone(), "tag", &missing, &mut rng),
+                         Err(Error::SchemaMismatch(_))));
+        assert!(matches!(try_compile_verifier_with(&exprs, "tag_verify", &missing),
+                         Err(Error::SchemaMismatch(_))));
+        assert!(try_compile_with(exprs, "tag", &options, &mut rng).is_ok());
+    }
+
+    #[test]
+    fn schema_keeps_inputs_the_transforms_fold_away() {
+        // `strong_rotate` folds `a & 0` to `0`, so the transformed circuit
+        // never reads `a`; the signature keeps it all the same.
+        let expr = Expr::xor(Expr::and(Expr::input("a"), Expr::public_const(0)), Expr::input("b"));
+        let options = CompileOptions { schema: Some(InputSchema::new().input("a").input("b")), ..CompileOptions::default() };
+        let inputs = [("a".to_string(), 0xdead_beefu32), ("b".to_string(), 0x1234)].into();
+        for seed in 0..8 {
+            let c = try_compile_with(vec![Rc::clone(&expr)], "tag", &options, &mut rand::rngs::StdRng::seed_from_u64(seed))
+                .unwrap();
+            assert!(c.code.contains("pub fn tag(a: u32, b: u32) -> u32 {"), "{}", c.code);
+            assert_eq!(c.circuit.params(), [("a".to_string(), None), ("b".to_string(), None)]);
+            assert_eq!(c.masked.eval(&c.circuit, &inputs).1, 0x1234);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "input schema mismatch: declared input \"b\" is never read")]
+    fn schema_mismatch_panics() {
+        let options = CompileOptions { schema: Some(InputSchema::new().input("a").input("b")), ..CompileOptions::default() };
+        compile_verifier_with(&[Expr::input("a")], "f", &options);
+    }
+
+    #[test]
+    fn compile_with_options() {
+        let expr = Expr::add(Expr::or(Expr::input("a"), Expr::input("b")), Expr::secret_const(0x9e37_79b9));
+        let inputs = [("a".to_string(), 0x1234u32), ("b".to_string(), 0xf0f0_0000)].into();
+        let expected = (0x1234u32 | 0xf0f0_0000).wrapping_add(0x9e37_79b9);
+        let seeded = || rand::rngs::StdRng::seed_from_u64(21);
+
+        let default = compile_with(vec![Rc::clone(&expr)], "f", &CompileOptions::default(), &mut seeded());
+        assert_eq!(default.code, compile(Rc::clone(&expr), "f", &mut seeded(), None).code);
+
+        let minimal = compile_with(vec![Rc::clone(&expr)], "f", &CompileOptions::minimal(), &mut seeded());
+        let stats = minimal.circuit.stats();
+        assert_eq!(stats.by_kind["REMASK"], remask_operands(&lower_to_circuit(&expr)).stats().by_kind["REMASK"]);
+        assert_eq!(stats.by_kind["SECRET_CONST"], 1);
+        assert_eq!(stats.and_triples, lower_to_circuit(&expr).stats().and_triples);
+        assert!(minimal.code.len() < default.code.len());
+
+        let heavy = CompileOptions {
+            strong_rotate: Some(RotateOptions { decoys: Chance::ALWAYS, ..RotateOptions::default() }),
+            remasks:       Chance::ALWAYS,
+            ..CompileOptions::default()
+        };
+        let heavy = compile_with(vec![Rc::clone(&expr)], "f", &heavy, &mut seeded());
+        assert!(heavy.circuit.stats().and_triples > default.circuit.stats().and_triples);
+
+        for c in [&default, &minimal, &heavy] {
+            assert_eq!(c.circuit.eval_outputs(&inputs), vec![expected]);
+            assert_eq!(c.masked.eval(&c.circuit, &inputs).1, expected);
+            assert_eq!(c.expr_digest, default.expr_digest);
+        }
+
+        let keyed = CompileOptions { key: Some(b"k".to_vec()), ..CompileOptions::minimal() };
+        let debug = format!("{keyed:?}");
+        assert!(debug.contains("key: Some(\"<redacted>\")") && !debug.contains("107"), "{debug}");
+        let c = compile_with(vec![Rc::clone(&expr)], "f", &keyed, &mut seeded());
+        assert_eq!(c.expr_digest, exprs_digest(&[Rc::clone(&expr)], Some(b"k")));
+        assert_eq!(compile_verifier_with(&[expr], "f_verify", &keyed),
+                   compile_verifier(&c.original_exprs[0], "f_verify", Some(b"k")));
+    }
+
+    #[test]
+    fn remask_operands_option() {
+        let expr = Expr::rotl(Expr::or(Expr::input("a"), Expr::input("b")), 5);
+        let inputs = [("a".to_string(), 0x1234u32), ("b".to_string(), 0xf0f0_0000)].into();
+        let off = CompileOptions { remask_operands: false, ..CompileOptions::minimal() };
+        let plain = compile_with(vec![Rc::clone(&expr)], "f", &off, &mut rand::rngs::StdRng::seed_from_u64(4));
+        let c = compile_with(vec![expr], "f", &CompileOptions::minimal(), &mut rand::rngs::StdRng::seed_from_u64(4));
+        // Two AND operands straight from ingests, behind two remasks each,
+        // and one output behind its own.
+        assert!(!plain.circuit.stats().by_kind.contains_key("REMASK"));
+        assert_eq!(c.circuit.stats().by_kind["REMASK"], 5);
+        assert_eq!(c.masked.pool_len(), plain.masked.pool_len() + 5);
+        assert_eq!(c.masked.eval(&c.circuit, &inputs).1, (0x1234u32 | 0xf0f0_0000).rotate_left(5));
+        assert_eq!(c.expr_digest, plain.expr_digest);
+    }
+
+    #[test]
+    fn hide_public_consts_option() {
+        let expr = Expr::add(Expr::input("a"), Expr::public_const(0x9e37_79b9));
+        let inputs = [("a".to_string(), 0x1234u32)].into();
+        let options = CompileOptions { hide_public_consts: true, ..CompileOptions::default() };
+        let c = compile_with(vec![Rc::clone(&expr)], "f", &options, &mut rand::rngs::StdRng::seed_from_u64(8));
+        let stats = c.circuit.stats();
+        assert!(["PUBLIC_CONST", "XOR_CONST", "AND_CONST"].iter().all(|kind| !stats.by_kind.contains_key(kind)));
+        assert!(compile_with(vec![Rc::clone(&expr)], "f", &CompileOptions::minimal(), &mut rand::rngs::StdRng::seed_from_u64(8))
+            .code.contains("0x9e3779b9,"));
+        assert!(!c.code.contains("0x9e3779b9"));
+        assert_eq!(c.masked.eval(&c.circuit, &inputs).1, 0x1234u32.wrapping_add(0x9e37_79b9));
+        assert_eq!(c.expr_digest, exprs_digest(&[expr], None));
+    }
+
+    #[test]
+    fn decoy_budget_lands_in_range() {
+        let expr = Expr::add(Expr::or(Expr::input("a"), Expr::input("b")), Expr::secret_const(0x9e37_79b9));
+        let inputs = [("a".to_string(), 0x1234u32), ("b".to_string(), 0xf0f0_0000)].into();
+        let expected = (0x1234u32 | 0xf0f0_0000).wrapping_add(0x9e37_79b9);
+        let budget = DecoyBudget { and_triples: 150..=160, pool_entries: 0..=usize::MAX };
+        let options = CompileOptions { decoy_budget: Some(budget.clone()), ..CompileOptions::default() };
+
+        for seed in 0..8 {
+            let mut rng = rand::rngs::StdRng::seed_from_u64(seed);
+            let c = compile_with(vec![Rc::clone(&expr)], "f", &options, &mut rng);
+            let lowered = lower_to_circuit_multi(&inject_decoys_to_budget(&c.original_exprs, &budget, &mut rng).unwrap());
+            assert!(budget.and_triples.contains(&lowered.stats().and_triples));
+            assert!(budget.and_triples.contains(&c.circuit.stats().and_triples), "seed {seed}");
+            assert_eq!(c.masked.eval(&c.circuit, &inputs).1, expected);
+        }
+
+        let mut rng = rand::rngs::StdRng::seed_from_u64(3);
+        let exact = DecoyBudget { and_triples: 0..=usize::MAX, pool_entries: 400..=420 };
+        let minimal = CompileOptions { decoy_budget: Some(exact), ..CompileOptions::minimal() };
+        let c = compile_with(vec![Rc::clone(&expr)], "f", &minimal, &mut rng);
+        assert!((400..=420).contains(&c.masked.baked_consts().map(<[u32]>::len).sum::<usize>()));
+        assert_eq!(c.masked.eval(&c.circuit, &inputs).1, expected);
+
+        // The budget holds for the shipped circuit, after the passes that
+        // grow it.
+        let budget = DecoyBudget { and_triples: 280..=360, pool_entries: 1500..=1900 };
+        let grown = CompileOptions {
+            decoy_budget: Some(budget.clone()), hide_public_consts: true, ..CompileOptions::minimal()
+        };
+        for seed in 0..4 {
+            let c = compile_with(vec![Rc::clone(&expr)], "f", &grown, &mut rand::rngs::StdRng::seed_from_u64(seed));
+            let stats = c.circuit.stats();
+            assert!(budget.and_triples.contains(&stats.and_triples), "seed {seed}: {stats:?}");
+            assert!(budget.pool_entries.contains(&stats.pool_entries), "seed {seed}: {stats:?}");
+            assert_eq!(c.masked.eval(&c.circuit, &inputs).1, expected);
+        }
+    }
+
+    #[test]
+    fn decoy_budget_reports_unreachable_ranges() {
+        let expr = Expr::add(Expr::or(Expr::input("a"), Expr::input("b")), Expr::secret_const(0x9e37_79b9));
+        let triples = lower_to_circuit(&expr).stats().and_triples;
+        let mut rng = rand::rngs::StdRng::seed_from_u64(5);
+        let budget = |and_triples| DecoyBudget { and_triples, pool_entries: 0..=usize::MAX };
+
+        let over = inject_decoys_to_budget(&[Rc::clone(&expr)], &budget(0..=triples - 1), &mut rng);
+        assert!(matches!(over, Err(Error::Unsupported(m)) if m.contains("over budget")));
+        let narrow = inject_decoys_to_budget(&[Rc::clone(&expr)], &budget(triples + 1..=triples + 1), &mut rng);
+        assert!(matches!(narrow, Err(Error::Unsupported(m)) if m.contains("no decoy lands")));
+        let bare = inject_decoys_to_budget(&[Expr::input("a")], &budget(10..=20), &mut rng);
+        assert!(matches!(bare, Err(Error::Unsupported(m)) if m.contains("too few nodes")));
+
+        let options = CompileOptions { decoy_budget: Some(budget(0..=triples - 1)), ..CompileOptions::default() };
+        assert!(try_compile_with(vec![Rc::clone(&expr)], "f", &options, &mut rng).is_err());
+
+        // A pool cap cannot be honoured while remasks and splits add a
+        // random number of entries.
+        let capped = DecoyBudget { and_triples: 0..=usize::MAX, pool_entries: 0..=10_000 };
+        let options = CompileOptions { decoy_budget: Some(capped), ..CompileOptions::default() };
+        let refused = try_compile_with(vec![expr], "f", &options, &mut rng);
+        assert!(matches!(refused, Err(Error::Unsupported(m)) if m.contains("Chance::NEVER")));
+    }
+
+    #[test]
+    fn rotate_strong_keeps_digest_and_reports_diff() {
+        let msg = Expr::input_bytes("msg", 4);
+        let expr = Expr::xor(Expr::add(Expr::input("a"), msg[0].clone()), Expr::secret_const(0x0bad_f00d));
+        let options = CompileOptions {
+            key:    Some(b"key".to_vec()),
+            schema: Some(InputSchema::new().input_bytes("msg", 4).input("a")),
+            ..CompileOptions::default()
+        };
+        let mut rng = rand::rngs::StdRng::seed_from_u64(4);
+        let base = compile_with(vec![Rc::clone(&expr)], "f", &options, &mut rng);
+
+        let inputs = [("a".to_string(), 7u32), ("msg[0]".to_string(), 0x0403_0201)].into();
+        let expected = 7u32.wrapping_add(0x0403_0201) ^ 0x0bad_f00d;
+        let mut prev = base;
+        let mut changed = false;
+        for _ in 0..4 {
+            let (next, diff) = rotate_strong(&prev, "f", &mut rng);
+            assert_eq!(next.expr_digest, prev.expr_digest);
+            assert_eq!(next.options.key, options.key);
+            assert_eq!(next.circuit.params(), prev.circuit.params());
+            assert_eq!(next.masked.eval(&next.circuit, &inputs).1, expected);
+            assert_eq!(diff, prev.circuit.stats().diff(&next.circuit.stats()));
+            assert_eq!(diff.and_triples, next.circuit.stats().and_triples as isize - prev.circuit.stats().and_triples as isize);
+            changed |= !diff.is_empty();
+            prev = next;
+        }
+        assert!(changed, "four strong rotations never changed the structure");
+    }
+
+    #[test]
+    #[should_panic(expected = "strong rotation changed EXPR_DIGEST")]
+    fn rotate_strong_rejects_modified_exprs() {
+        let mut rng = rand::rngs::StdRng::seed_from_u64(4);
+        let mut c = compile(Expr::input("a"), "f", &mut rng, None);
+        c.original_exprs = vec![Expr::not(Expr::input("a"))];
+        rotate_strong(&c, "f", &mut rng);
+    }
 }
@@ -4,9 +4,16 @@
 //! use xorpl::prelude::*;
 //! ```
 
-pub use crate::circuit::Circuit;
-pub use crate::emit::{emit_rust, emit_verifier_rust};
-pub use crate::expr::{expr_digest, Expr};
-pub use crate::lower::lower_to_circuit;
+pub use crate::circuit::{byte_inputs, Circuit};
+pub use crate::emit::{emit_rust, emit_verifier_bundle, emit_verifier_rust};
+pub use crate::emit_c::{emit_c, emit_verifier_c};
+pub use crate::emit_js::{emit_js, emit_verifier_ts};
+pub use crate::emit_wasm::{emit_wasm, emit_wat};
+pub use crate::expr::{expr_digest, exprs_digest, exprs_digest_with_schema, Expr};
+pub use crate::error::Error;
+pub use crate::lower::{lower_to_circuit, lower_to_circuit_multi, try_lower_to_circuit, try_lower_to_circuit_multi};
 pub use crate::mask::MaskedCircuit;
-pub use crate::pipeline::{compile, compile_verifier, rotate_cheap, Compilation};
+pub use crate::schema::InputSchema;
+pub use crate::pipeline::{compile, compile_verifier, rotate_cheap, rotate_strong, Compilation};
+pub use crate::pipeline::{compile_verifier_with, compile_with, CompileOptions, DecoyBudget};
+pub use crate::pipeline::{try_compile, try_compile_verifier, try_compile_verifier_with, try_compile_with, try_rotate_cheap};
@@ -0,0 +1,120 @@
+//! Explicit parameter order for emitted functions.
+//!
+//! Without a schema, both emitters list a circuit's inputs sorted by name
+//! (see [`Circuit::params`]), so renaming an input can silently reorder the
+//! positional arguments of the browser function and the verifier.  An
+//! [`InputSchema`] fixes the order instead:
+//!
+//! ```rust,ignore
+//! let schema = InputSchema::new().input("nonce").input_bytes("digest", 32).input("event");
+//! let options = CompileOptions { schema: Some(schema), ..CompileOptions::default() };
+//! let compilation = compile_with(exprs, "tag", &options, &mut rng);
+//! // pub fn tag(nonce: u32, digest: &[u8; 32], event: u32) -> ...
+//! ```
+//!
+//! A schema must list exactly the circuit's inputs — same names, same kinds,
+//! same byte lengths — or attaching it fails with [`Error::SchemaMismatch`].
+//! It is part of the circuit from then on: transforms, saved artifacts and
+//! cheap rotations keep it, and [`crate::expr::exprs_digest_with_schema`]
+//! covers it, so the verifier and browser agree on the order as well as on
+//! the expression.
+
+use std::collections::HashSet;
+
+use crate::circuit::Circuit;
+use crate::error::Error;
+
+/// An ordered list of function parameters: `(name, None)` for a `u32` input,
+/// `(name, Some(len))` for a `len`-byte input.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct InputSchema {
+    params: Vec<(String, Option<usize>)>,
+}
+
+impl InputSchema {
+    /// An empty schema; add parameters in signature order.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Append a `u32` parameter, as read by `Expr::input(name)`.
+    pub fn input(mut self, name: &str) -> Self {
+        self.params.push((name.to_string(), None));
+        self
+    }
+
+    /// Append a `&[u8; len]` parameter, as read by
+    /// `Expr::input_bytes(name, len)`.
+    pub fn input_bytes(mut self, name: &str, len: usize) -> Self {
+        self.params.push((name.to_string(), Some(len)));
+        self
+    }
+
+    /// A schema from parameters already in signature order.
+    pub fn from_params(params: Vec<(String, Option<usize>)>) -> Self {
+        Self { params }
+    }
+
+    /// The parameters, in signature order.
+    pub fn params(&self) -> &[(String, Option<usize>)] {
+        &self.params
+    }
+
+    /// Check that the schema lists each of `circuit`'s inputs exactly once,
+    /// with the right kind and byte length, and nothing else.
+    pub fn check(&self, circuit: &Circuit) -> Result<(), Error> {
+        let mut seen = HashSet::new();
+        if let Some((name, _)) = self.params.iter().find(|(name, _)| !seen.insert(name.as_str())) {
+            return Err(Error::SchemaMismatch(format!("input {name:?} is declared twice")));
+        }
+        let actual = circuit.ingested_params();
+        for (name, len) in &actual {
+            match self.params.iter().find(|(n, _)| n == name) {
+                None => return Err(Error::SchemaMismatch(format!("input {name:?} is not declared"))),
+                Some((_, declared)) if declared != len => return Err(Error::SchemaMismatch(format!(
+                    "input {name:?} is declared as {} but read as {}", describe(*declared), describe(*len),
+                ))),
+                Some(_) => {}
+            }
+        }
+        if let Some((name, _)) = self.params.iter().find(|(n, _)| !actual.iter().any(|(a, _)| a == n)) {
+            return Err(Error::SchemaMismatch(format!("declared input {name:?} is never read")));
+        }
+        Ok(())
+    }
+}
+
+fn describe(len: Option<usize>) -> String {
+    match len {
+        None      => "u32".to_string(),
+        Some(len) => format!("bytes[{len}]"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::expr::Expr;
+    use crate::lower::lower_to_circuit;
+
+    #[test]
+    fn check_reports_each_mismatch() {
+        let msg = Expr::input_bytes("msg", 6);
+        let circuit = lower_to_circuit(&Expr::xor(Expr::input("b"), Expr::add(msg[1].clone(), Expr::input("a"))));
+
+        let ok = InputSchema::new().input("b").input_bytes("msg", 6).input("a");
+        assert_eq!(ok.check(&circuit), Ok(()));
+
+        let mismatch = |schema: InputSchema| match schema.check(&circuit) {
+            Err(Error::SchemaMismatch(m)) => m,
+            other => panic!("expected a mismatch, got {other:?}"),
+        };
+        assert!(mismatch(InputSchema::new().input("b").input_bytes("msg", 6)).contains("\"a\" is not declared"));
+        assert!(mismatch(ok.clone().input("c")).contains("\"c\" is never read"));
+        assert!(mismatch(ok.clone().input("a")).contains("\"a\" is declared twice"));
+        assert!(mismatch(InputSchema::new().input("b").input("msg").input("a"))
+            .contains("\"msg\" is declared as u32 but read as bytes[6]"));
+        assert!(mismatch(InputSchema::new().input("b").input_bytes("msg", 8).input("a"))
+            .contains("declared as bytes[8] but read as bytes[6]"));
+    }
+}
@@ -0,0 +1,986 @@
+//! Versioned on-disk formats for [`Circuit`] and the rotation key.
+//!
+//! A saved `Circuit` is everything a later job needs to run a cheap rotation
+//! (see [`crate::pipeline::rotate_cheap_circuit`]); a saved [`RotationKey`]
+//! re-bakes one exact `MaskedCircuit` against its circuit.  Each has a
+//! compact binary form and a human-readable JSON form carrying the same data.
+//!
+//! # Binary layout (all integers `u32` little-endian)
+//!
+//! ```text
+//! Circuit:     "XPLC" version
+//!              n_wires     [role: u8]*          0 = ingest, 1 = egress, 2 = internal
+//!              n_gens      [purpose: str]*      str = len + UTF-8 bytes
+//!              n_gadgets   [tag: u8, fields]*   see GADGET_TAGS
+//!              n_egress    [wire]*
+//!              schema: u8           0 = none, 1 = n_params [name: str, len]*
+//!                                   len = 0 for a u32 input, else byte length
+//! RotationKey: "XPLK" version  circuit_fingerprint: [u8; 32]  n_gens [value]*
+//! ```
+//!
+//! Gadget fields follow the `Gadget` variant's field order; `Ingest` writes
+//! `name`, a `0`/`1` byte for `bytes`, then `param len word` when present.
+//!
+//! # Loading
+//!
+//! Loaders reject a wrong magic or `format`, any version other than
+//! [`FORMAT_VERSION`], and truncated or trailing data with [`Error::Load`],
+//! and pass on the error of any circuit that fails `Circuit::validate`.  A rotation key is bound to its circuit by
+//! [`Circuit::fingerprint`] and is only accepted for that circuit.
+
+use std::borrow::Cow;
+use std::collections::HashMap;
+
+use crate::circuit::{ByteWord, Circuit, Gadget, Generator, Wire, WireId};
+use crate::error::Error;
+use crate::mask::MaskedCircuit;
+use crate::schema::InputSchema;
+
+/// Version written by every serializer and the only one loaders accept.
+pub const FORMAT_VERSION: u32 = 1;
+
+const CIRCUIT_MAGIC: &[u8; 4] = b"XPLC";
+const KEY_MAGIC:     &[u8; 4] = b"XPLK";
+const CIRCUIT_FORMAT: &str = "xorpl-circuit";
+const KEY_FORMAT:     &str = "xorpl-rotation-key";
+
+/// Binary tag per `Gadget::kind`.  Append-only: tags are part of the format,
+/// so retired tags (`0x0b`) are never reused.
+const GADGET_TAGS: &[(&str, u8)] = &[
+    ("PUBLIC_CONST", 0x01), ("SECRET_CONST", 0x02), ("INGEST", 0x03),
+    ("XOR", 0x04), ("XOR_CONST", 0x05), ("AND_CONST", 0x06),
+    ("ROTL", 0x07), ("SHL", 0x08), ("SHR", 0x09),
+    ("AND", 0x0a), ("REMASK", 0x0c), ("EGRESS", 0x0d),
+];
+
+fn role_name(w: Wire) -> &'static str {
+    match w {
+        Wire::Ingest   => "ingest",
+        Wire::Egress   => "egress",
+        Wire::Internal => "internal",
+    }
+}
+
+fn role_code(w: Wire) -> u8 {
+    match w {
+        Wire::Ingest   => 0,
+        Wire::Egress   => 1,
+        Wire::Internal => 2,
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Circuit
+// ---------------------------------------------------------------------------
+
+impl Circuit {
+    /// SHA-256 of [`Circuit::to_bytes`] — identifies the circuit a
+    /// [`RotationKey`] belongs to.
+    pub fn fingerprint(&self) -> [u8; 32] {
+        use sha2::Digest as _;
+        sha2::Sha256::digest(self.to_bytes()).into()
+    }
+
+    /// Encode in the versioned binary format.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut w = Writer::new(CIRCUIT_MAGIC);
+        w.u32(self.wires.len() as u32);
+        for &role in &self.wires {
+            w.u8(role_code(role));
+        }
+        w.u32(self.generators.len() as u32);
+        for g in &self.generators {
+            w.str(&g.purpose);
+        }
+        w.u32(self.gadgets.len() as u32);
+        for g in &self.gadgets {
+            w.u8(tag_of(g.kind()));
+            for field in gadget_fields(g) {
+                match field {
+                    Field::Id(_, v) | Field::Int(_, v) => w.u32(v),
+                    Field::Str(_, s) => w.str(s),
+                    Field::Bytes(None) => w.u8(0),
+                    Field::Bytes(Some(bw)) => {
+                        w.u8(1);
+                        w.str(&bw.param);
+                        w.u32(bw.len as u32);
+                        w.u32(bw.word as u32);
+                    }
+                }
+            }
+        }
+        w.u32(self.egress.len() as u32);
+        for &e in &self.egress {
+            w.u32(e as u32);
+        }
+        match &self.schema {
+            None => w.u8(0),
+            Some(schema) => {
+                w.u8(1);
+                w.u32(schema.params().len() as u32);
+                for (name, len) in schema.params() {
+                    w.str(name);
+                    w.u32(len.unwrap_or(0) as u32);
+                }
+            }
+        }
+        w.bytes
+    }
+
+    /// Decode the binary format and validate the result.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Circuit, Error> {
+        let circuit = Self::decode_bytes(bytes).map_err(Error::Load)?;
+        circuit.validate()?;
+        Ok(circuit)
+    }
+
+    fn decode_bytes(bytes: &[u8]) -> Result<Circuit, String> {
+        let mut r = Reader::new(bytes, CIRCUIT_MAGIC)?;
+        let wires = (0..r.count()?)
+            .map(|_| match r.u8()? {
+                0 => Ok(Wire::Ingest),
+                1 => Ok(Wire::Egress),
+                2 => Ok(Wire::Internal),
+                b => Err(format!("unknown wire role {b}")),
+            })
+            .collect::<Result<Vec<_>, String>>()?;
+        let generators = (0..r.count()?)
+            .map(|_| Ok(Generator { purpose: Cow::Owned(r.str()?) }))
+            .collect::<Result<Vec<_>, String>>()?;
+        let gadgets = (0..r.count()?)
+            .map(|_| {
+                let tag  = r.u8()?;
+                let kind = GADGET_TAGS.iter().find(|(_, t)| *t == tag)
+                    .map(|(k, _)| *k)
+                    .ok_or_else(|| format!("unknown gadget tag {tag:#04x}"))?;
+                decode_gadget(kind, &mut BinarySource(&mut r))
+            })
+            .collect::<Result<Vec<_>, String>>()?;
+        let egress = (0..r.count()?).map(|_| r.id()).collect::<Result<Vec<_>, String>>()?;
+        let schema = match r.u8()? {
+            0 => None,
+            1 => Some(InputSchema::from_params(
+                (0..r.count()?)
+                    .map(|_| Ok((r.str()?, Some(r.id()?).filter(|&len| len != 0))))
+                    .collect::<Result<Vec<_>, String>>()?,
+            )),
+            b => return Err(format!("bad schema flag {b}")),
+        };
+        r.finish()?;
+        Ok(Circuit { gadgets, wires, generators, egress, schema })
+    }
+
+    /// Encode as JSON: one gadget object per line, fields named as in
+    /// `Gadget`, `kind` as in [`Gadget::kind`].
+    pub fn to_json(&self) -> String {
+        let wires: Vec<String> = self.wires.iter().map(|&w| json_str(role_name(w))).collect();
+        let gens: Vec<String> = self.generators.iter().map(|g| json_str(&g.purpose)).collect();
+        let gadgets: Vec<String> = self.gadgets.iter()
+            .map(|g| {
+                let mut fields = vec![format!("\"kind\": {}", json_str(g.kind()))];
+                for field in gadget_fields(g) {
+                    fields.push(match field {
+                        Field::Id(k, v) | Field::Int(k, v) => format!("\"{k}\": {v}"),
+                        Field::Str(k, s) => format!("\"{k}\": {}", json_str(s)),
+                        Field::Bytes(None) => "\"bytes\": null".to_string(),
+                        Field::Bytes(Some(bw)) => format!(
+                            "\"bytes\": {{\"param\": {}, \"len\": {}, \"word\": {}}}",
+                            json_str(&bw.param), bw.len, bw.word,
+                        ),
+                    });
+                }
+                format!("    {{{}}}", fields.join(", "))
+            })
+            .collect();
+        let egress: Vec<u32> = self.egress.iter().map(|&e| e as u32).collect();
+        let schema = match &self.schema {
+            None => "null".to_string(),
+            Some(schema) => {
+                let params: Vec<String> = schema.params().iter()
+                    .map(|(name, len)| match len {
+                        None      => format!("{{\"name\": {}, \"len\": null}}", json_str(name)),
+                        Some(len) => format!("{{\"name\": {}, \"len\": {len}}}", json_str(name)),
+                    })
+                    .collect();
+                format!("[{}]", params.join(", "))
+            }
+        };
+        format!(
+            "{{\n  \"format\": {},\n  \"version\": {FORMAT_VERSION},\n  \"wires\": [{}],\n  \
+             \"generators\": [{}],\n  \"gadgets\": [\n{}\n  ],\n  \"egress\": {},\n  \"schema\": {}\n}}\n",
+            json_str(CIRCUIT_FORMAT),
+            wires.join(", "),
+            gens.join(", "),
+            gadgets.join(",\n"),
+            json_list(egress),
+            schema,
+        )
+    }
+
+    /// Decode [`Circuit::to_json`] output and validate the result.
+    pub fn from_json(text: &str) -> Result<Circuit, Error> {
+        let circuit = Self::decode_json(text).map_err(Error::Load)?;
+        circuit.validate()?;
+        Ok(circuit)
+    }
+
+    fn decode_json(text: &str) -> Result<Circuit, String> {
+        let root = Json::parse(text)?;
+        check_header(&root, CIRCUIT_FORMAT)?;
+        let wires = root.get("wires")?.as_array()?.iter()
+            .map(|w| match w.as_str()? {
+                "ingest"   => Ok(Wire::Ingest),
+                "egress"   => Ok(Wire::Egress),
+                "internal" => Ok(Wire::Internal),
+                other      => Err(format!("unknown wire role {other:?}")),
+            })
+            .collect::<Result<Vec<_>, String>>()?;
+        let generators = root.get("generators")?.as_array()?.iter()
+            .map(|g| Ok(Generator { purpose: Cow::Owned(g.as_str()?.to_string()) }))
+            .collect::<Result<Vec<_>, String>>()?;
+        let gadgets = root.get("gadgets")?.as_array()?.iter()
+            .enumerate()
+            .map(|(i, g)| {
+                let kind = g.get("kind")?.as_str()?;
+                let kind = GADGET_TAGS.iter().find(|(k, _)| *k == kind)
+                    .map(|(k, _)| *k)
+                    .ok_or_else(|| format!("unknown gadget kind {kind:?}"))?;
+                decode_gadget(kind, &mut JsonSource(g)).map_err(|e| format!("gadgets[{i}]: {e}"))
+            })
+            .collect::<Result<Vec<_>, String>>()?;
+        let egress = root.get("egress")?.as_array()?.iter()
+            .map(Json::as_usize)
+            .collect::<Result<Vec<_>, String>>()?;
+        let schema = match root.get("schema")? {
+            Json::Null => None,
+            params => Some(InputSchema::from_params(
+                params.as_array()?.iter()
+                    .map(|p| Ok((p.get("name")?.as_str()?.to_string(), match p.get("len")? {
+                        Json::Null => None,
+                        len        => Some(len.as_usize()?),
+                    })))
+                    .collect::<Result<Vec<_>, String>>()?,
+            )),
+        };
+        Ok(Circuit { gadgets, wires, generators, egress, schema })
+    }
+}
+
+fn tag_of(kind: &str) -> u8 {
+    GADGET_TAGS.iter().find(|(k, _)| *k == kind).map(|(_, t)| *t).expect("every kind has a tag")
+}
+
+/// One serialized gadget field, named as in the `Gadget` variant.
+enum Field<'a> {
+    Id(&'static str, u32),
+    Int(&'static str, u32),
+    Str(&'static str, &'a str),
+    Bytes(Option<&'a ByteWord>),
+}
+
+fn gadget_fields(g: &Gadget) -> Vec<Field<'_>> {
+    use Field::*;
+    let id = |w: usize| w as u32;
+    match g {
+        Gadget::PublicConst { k, out }       => vec![Int("k", *k), Id("out", id(*out))],
+        Gadget::SecretConst { k, gen, out }  => vec![Int("k", *k), Id("gen", id(*gen)), Id("out", id(*out))],
+        Gadget::Ingest { name, bytes, gen, out } => vec![
+            Str("name", name), Bytes(bytes.as_ref()), Id("gen", id(*gen)), Id("out", id(*out)),
+        ],
+        Gadget::Xor { a, b, out }      => vec![Id("a", id(*a)), Id("b", id(*b)), Id("out", id(*out))],
+        Gadget::XorConst { a, k, out } => vec![Id("a", id(*a)), Int("k", *k), Id("out", id(*out))],
+        Gadget::AndConst { a, k, out } => vec![Id("a", id(*a)), Int("k", *k), Id("out", id(*out))],
+        Gadget::Rotl { a, r, out }     => vec![Id("a", id(*a)), Int("r", *r), Id("out", id(*out))],
+        Gadget::Shl { a, r, out }      => vec![Id("a", id(*a)), Int("r", *r), Id("out", id(*out))],
+        Gadget::Shr { a, r, out }      => vec![Id("a", id(*a)), Int("r", *r), Id("out", id(*out))],
+        Gadget::And { a, b, gen, share, out } => vec![
+            Id("a", id(*a)), Id("b", id(*b)), Id("gen", id(*gen)), Id("share", id(*share)), Id("out", id(*out)),
+        ],
+        Gadget::Remask { a, gen, out } => vec![Id("a", id(*a)), Id("gen", id(*gen)), Id("out", id(*out))],
+        Gadget::Egress { a }           => vec![Id("a", id(*a))],
+    }
+}
+
+/// Field reader shared by the binary and JSON decoders; fields are requested
+/// in `gadget_fields` order.
+trait FieldSource {
+    fn int(&mut self, key: &str) -> Result<u32, String>;
+    fn string(&mut self, key: &str) -> Result<String, String>;
+    fn bytes(&mut self) -> Result<Option<ByteWord>, String>;
+
+    fn id(&mut self, key: &str) -> Result<WireId, String> {
+        self.int(key).map(|v| v as usize)
+    }
+}
+
+fn decode_gadget(kind: &str, src: &mut dyn FieldSource) -> Result<Gadget, String> {
+    Ok(match kind {
+        "PUBLIC_CONST" => Gadget::PublicConst { k: src.int("k")?, out: src.id("out")? },
+        "SECRET_CONST" => Gadget::SecretConst { k: src.int("k")?, gen: src.id("gen")?, out: src.id("out")? },
+        "INGEST" => Gadget::Ingest {
+            name:  src.string("name")?,
+            bytes: src.bytes()?,
+            gen:   src.id("gen")?,
+            out:   src.id("out")?,
+        },
+        "XOR"       => Gadget::Xor      { a: src.id("a")?, b: src.id("b")?, out: src.id("out")? },
+        "XOR_CONST" => Gadget::XorConst { a: src.id("a")?, k: src.int("k")?, out: src.id("out")? },
+        "AND_CONST" => Gadget::AndConst { a: src.id("a")?, k: src.int("k")?, out: src.id("out")? },
+        "ROTL"      => Gadget::Rotl     { a: src.id("a")?, r: src.int("r")?, out: src.id("out")? },
+        "SHL"       => Gadget::Shl      { a: src.id("a")?, r: src.int("r")?, out: src.id("out")? },
+        "SHR"       => Gadget::Shr      { a: src.id("a")?, r: src.int("r")?, out: src.id("out")? },
+        "AND" => Gadget::And {
+            a:     src.id("a")?,
+            b:     src.id("b")?,
+            gen:   src.id("gen")?,
+            share: src.id("share")?,
+            out:   src.id("out")?,
+        },
+        "REMASK" => Gadget::Remask { a: src.id("a")?, gen: src.id("gen")?, out: src.id("out")? },
+        "EGRESS" => Gadget::Egress { a: src.id("a")? },
+        _ => unreachable!("kind {kind} comes from GADGET_TAGS"),
+    })
+}
+
+struct BinarySource<'r, 'a>(&'r mut Reader<'a>);
+
+impl FieldSource for BinarySource<'_, '_> {
+    fn int(&mut self, _key: &str) -> Result<u32, String> {
+        self.0.u32()
+    }
+    fn string(&mut self, _key: &str) -> Result<String, String> {
+        self.0.str()
+    }
+    fn bytes(&mut self) -> Result<Option<ByteWord>, String> {
+        match self.0.u8()? {
+            0 => Ok(None),
+            1 => Ok(Some(ByteWord { param: self.0.str()?, len: self.0.id()?, word: self.0.id()? })),
+            b => Err(format!("invalid byte-input flag {b}")),
+        }
+    }
+}
+
+struct JsonSource<'a>(&'a Json);
+
+impl FieldSource for JsonSource<'_> {
+    fn int(&mut self, key: &str) -> Result<u32, String> {
+        self.0.get(key)?.as_u32()
+    }
+    fn string(&mut self, key: &str) -> Result<String, String> {
+        Ok(self.0.get(key)?.as_str()?.to_string())
+    }
+    fn bytes(&mut self) -> Result<Option<ByteWord>, String> {
+        match self.0.get("bytes")? {
+            Json::Null => Ok(None),
+            bw => Ok(Some(ByteWord {
+                param: bw.get("param")?.as_str()?.to_string(),
+                len:   bw.get("len")?.as_usize()?,
+                word:  bw.get("word")?.as_usize()?,
+            })),
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Rotation key
+// ---------------------------------------------------------------------------
+
+/// The secret behind one concretization: every generator's value, in
+/// `GenId` order, plus the [`Circuit::fingerprint`] it was sampled for.
+/// Together with the circuit it determines the `MaskedCircuit` exactly.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RotationKey {
+    pub circuit_fingerprint: [u8; 32],
+    pub gen_values: Vec<u32>,
+}
+
+impl RotationKey {
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut w = Writer::new(KEY_MAGIC);
+        w.bytes.extend_from_slice(&self.circuit_fingerprint);
+        w.u32(self.gen_values.len() as u32);
+        for &v in &self.gen_values {
+            w.u32(v);
+        }
+        w.bytes
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<RotationKey, Error> {
+        Self::decode_bytes(bytes).map_err(Error::Load)
+    }
+
+    fn decode_bytes(bytes: &[u8]) -> Result<RotationKey, String> {
+        let mut r = Reader::new(bytes, KEY_MAGIC)?;
+        let circuit_fingerprint = r.take(32)?.try_into().expect("took 32 bytes");
+        let gen_values = (0..r.count()?).map(|_| r.u32()).collect::<Result<Vec<_>, String>>()?;
+        r.finish()?;
+        Ok(RotationKey { circuit_fingerprint, gen_values })
+    }
+
+    pub fn to_json(&self) -> String {
+        format!(
+            "{{\n  \"format\": {},\n  \"version\": {FORMAT_VERSION},\n  \"circuit_fingerprint\": {},\n  \
+             \"gen_values\": {}\n}}\n",
+            json_str(KEY_FORMAT),
+            json_str(&hex(&self.circuit_fingerprint)),
+            json_list(self.gen_values.iter().copied()),
+        )
+    }
+
+    pub fn from_json(text: &str) -> Result<RotationKey, Error> {
+        Self::decode_json(text).map_err(Error::Load)
+    }
+
+    fn decode_json(text: &str) -> Result<RotationKey, String> {
+        let root = Json::parse(text)?;
+        check_header(&root, KEY_FORMAT)?;
+        let fp = root.get("circuit_fingerprint")?.as_str()?;
+        let circuit_fingerprint = unhex(fp)
+            .and_then(|b| <[u8; 32]>::try_from(b).ok())
+            .ok_or_else(|| "\"circuit_fingerprint\": expected 64 hex digits".to_string())?;
+        let gen_values = root.get("gen_values")?.as_array()?.iter()
+            .map(Json::as_u32)
+            .collect::<Result<Vec<_>, String>>()?;
+        Ok(RotationKey { circuit_fingerprint, gen_values })
+    }
+}
+
+impl MaskedCircuit {
+    /// The rotation key of this concretization of `circuit`.
+    pub fn rotation_key(&self, circuit: &Circuit) -> RotationKey {
+        RotationKey {
+            circuit_fingerprint: circuit.fingerprint(),
+            gen_values: (0..circuit.generators.len()).map(|g| self.gen_values[&g]).collect(),
+        }
+    }
+
+    /// Re-bake the concretization `key` describes.  Rejects a key for a
+    /// different circuit, the wrong number of generators, or a zero mask on
+    /// an ingest or secret constant.
+    pub fn from_rotation_key(circuit: &Circuit, key: &RotationKey) -> Result<MaskedCircuit, Error> {
+        circuit.validate()?;
+        if key.circuit_fingerprint != circuit.fingerprint() {
+            return Err(Error::Load("rotation key belongs to a different circuit".to_string()));
+        }
+        if key.gen_values.len() != circuit.generators.len() {
+            return Err(Error::Load(format!(
+                "rotation key has {} generator values, circuit has {}",
+                key.gen_values.len(), circuit.generators.len(),
+            )));
+        }
+        if let Some(g) = MaskedCircuit::secret_gens(circuit).into_iter().find(|&g| key.gen_values[g] == 0) {
+            return Err(Error::Load(format!("generator {g} masks a secret but is zero")));
+        }
+        let gen_values: HashMap<usize, u32> = key.gen_values.iter().copied().enumerate().collect();
+        Ok(MaskedCircuit::bake(circuit, gen_values))
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Binary reader / writer
+// ---------------------------------------------------------------------------
+
+struct Writer {
+    bytes: Vec<u8>,
+}
+
+impl Writer {
+    fn new(magic: &[u8; 4]) -> Self {
+        let mut w = Writer { bytes: magic.to_vec() };
+        w.u32(FORMAT_VERSION);
+        w
+    }
+    fn u8(&mut self, v: u8) {
+        self.bytes.push(v);
+    }
+    fn u32(&mut self, v: u32) {
+        self.bytes.extend_from_slice(&v.to_le_bytes());
+    }
+    fn str(&mut self, s: &str) {
+        self.u32(s.len() as u32);
+        self.bytes.extend_from_slice(s.as_bytes());
+    }
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+    pos:   usize,
+}
+
+impl<'a> Reader<'a> {
+    fn new(bytes: &'a [u8], magic: &[u8; 4]) -> Result<Self, String> {
+        let mut r = Reader { bytes, pos: 0 };
+        if r.take(4).ok() != Some(magic.as_slice()) {
+            return Err(format!("bad magic: expected {:?}", String::from_utf8_lossy(magic)));
+        }
+        let version = r.u32()?;
+        if version != FORMAT_VERSION {
+            return Err(format!("unsupported format version {version} (expected {FORMAT_VERSION})"));
+        }
+        Ok(r)
+    }
+    fn take(&mut self, n: usize) -> Result<&'a [u8], String> {
+        let end = self.pos.checked_add(n).filter(|&e| e <= self.bytes.len())
+            .ok_or_else(|| format!("truncated at byte {}", self.pos))?;
+        let s = &self.bytes[self.pos..end];
+        self.pos = end;
+        Ok(s)
+    }
+    fn u8(&mut self) -> Result<u8, String> {
+        Ok(self.take(1)?[0])
+    }
+    fn u32(&mut self) -> Result<u32, String> {
+        Ok(u32::from_le_bytes(self.take(4)?.try_into().expect("took 4 bytes")))
+    }
+    fn id(&mut self) -> Result<usize, String> {
+        self.u32().map(|v| v as usize)
+    }
+    /// An element count, bounded by the remaining input so a corrupt count
+    /// cannot trigger a huge allocation.
+    fn count(&mut self) -> Result<usize, String> {
+        let n = self.id()?;
+        if n > self.bytes.len() - self.pos {
+            return Err(format!("count {n} at byte {} exceeds remaining input", self.pos - 4));
+        }
+        Ok(n)
+    }
+    fn str(&mut self) -> Result<String, String> {
+        let n = self.count()?;
+        String::from_utf8(self.take(n)?.to_vec()).map_err(|_| format!("invalid UTF-8 before byte {}", self.pos))
+    }
+    fn finish(&self) -> Result<(), String> {
+        if self.pos != self.bytes.len() {
+            return Err(format!("{} trailing byte(s)", self.bytes.len() - self.pos));
+        }
+        Ok(())
+    }
+}
+
+// ---------------------------------------------------------------------------
+// JSON
+// ---------------------------------------------------------------------------
+
+// `json_str`, `hex` and `unhex` are public only for the `xorplc` binary,
+// which is a separate crate; they are not part of the library API.
+
+/// `s` as a JSON string literal.
+#[doc(hidden)]
+pub fn json_str(s: &str) -> String {
+    let mut out = String::from("\"");
+    for c in s.chars() {
+        match c {
+            '"'  => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+fn json_list(values: impl IntoIterator<Item = u32>) -> String {
+    let items: Vec<String> = values.into_iter().map(|v| v.to_string()).collect();
+    format!("[{}]", items.join(", "))
+}
+
+/// Lowercase hex, two digits per byte.
+#[doc(hidden)]
+pub fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{b:02x}")).collect()
+}
+
+/// The inverse of [`hex`] (either case); `None` for anything else.
+#[doc(hidden)]
+pub fn unhex(s: &str) -> Option<Vec<u8>> {
+    if !s.is_ascii() || !s.len().is_multiple_of(2) {
+        return None;
+    }
+    (0..s.len()).step_by(2).map(|i| u8::from_str_radix(&s[i..i + 2], 16).ok()).collect()
+}
+
+fn check_header(root: &Json, format: &str) -> Result<(), String> {
+    let found = root.get("format")?.as_str()?;
+    if found != format {
+        return Err(format!("expected format {format:?}, found {found:?}"));
+    }
+    let version = root.get("version")?.as_u32()?;
+    if version != FORMAT_VERSION {
+        return Err(format!("unsupported format version {version} (expected {FORMAT_VERSION})"));
+    }
+    Ok(())
+}
+
+/// Just enough JSON for the formats above: no booleans or fractions, and
+/// integers are non-negative.
+#[derive(Debug)]
+enum Json {
+    Null,
+    Num(u64),
+    Str(String),
+    Array(Vec<Json>),
+    Object(Vec<(String, Json)>),
+}
+
+impl Json {
+    fn parse(text: &str) -> Result<Json, String> {
+        let mut p = JsonParser { s: text.as_bytes(), pos: 0 };
+        let v = p.value()?;
+        p.ws();
+        if p.pos != p.s.len() {
+            return Err(format!("JSON: trailing characters at byte {}", p.pos));
+        }
+        Ok(v)
+    }
+
+    fn get(&self, key: &str) -> Result<&Json, String> {
+        match self {
+            Json::Object(fields) => fields.iter().find(|(k, _)| k == key).map(|(_, v)| v)
+                .ok_or_else(|| format!("missing field \"{key}\"")),
+            _ => Err(format!("expected an object with field \"{key}\"")),
+        }
+    }
+
+    fn as_u32(&self) -> Result<u32, String> {
+        match self {
+            Json::Num(n) => u32::try_from(*n).map_err(|_| format!("{n} does not fit in 32 bits")),
+            other => Err(format!("expected an integer, found {other:?}")),
+        }
+    }
+
+    fn as_usize(&self) -> Result<usize, String> {
+        self.as_u32().map(|v| v as usize)
+    }
+
+    fn as_str(&self) -> Result<&str, String> {
+        match self {
+            Json::Str(s) => Ok(s),
+            other => Err(format!("expected a string, found {other:?}")),
+        }
+    }
+
+    fn as_array(&self) -> Result<&[Json], String> {
+        match self {
+            Json::Array(items) => Ok(items),
+            other => Err(format!("expected an array, found {other:?}")),
+        }
+    }
+}
+
+struct JsonParser<'a> {
+    s:   &'a [u8],
+    pos: usize,
+}
+
+impl JsonParser<'_> {
+    fn ws(&mut self) {
+        while self.pos < self.s.len() && self.s[self.pos].is_ascii_whitespace() {
+            self.pos += 1;
+        }
+    }
+
+    fn err<T>(&self, what: &str) -> Result<T, String> {
+        Err(format!("JSON: {what} at byte {}", self.pos))
+    }
+
+    fn eat(&mut self, c: u8) -> bool {
+        self.ws();
+        if self.s.get(self.pos) == Some(&c) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn literal(&mut self, word: &str, v: Json) -> Result<Json, String> {
+        if self.s[self.pos..].starts_with(word.as_bytes()) {
+            self.pos += word.len();
+            Ok(v)
+        } else {
+            self.err("invalid literal")
+        }
+    }
+
+    fn value(&mut self) -> Result<Json, String> {
+        self.ws();
+        match self.s.get(self.pos) {
+            Some(b'{') => {
+                self.pos += 1;
+                let mut fields = Vec::new();
+                if !self.eat(b'}') {
+                    loop {
+                        self.ws();
+                        let key = self.string()?;
+                        if !self.eat(b':') {
+                            return self.err("expected ':'");
+                        }
+                        fields.push((key, self.value()?));
+                        if self.eat(b'}') {
+                            break;
+                        }
+                        if !self.eat(b',') {
+                            return self.err("expected ',' or '}'");
+                        }
+                    }
+                }
+                Ok(Json::Object(fields))
+            }
+            Some(b'[') => {
+                self.pos += 1;
+                let mut items = Vec::new();
+                if !self.eat(b']') {
+                    loop {
+                        items.push(self.value()?);
+                        if self.eat(b']') {
+                            break;
+                        }
+                        if !self.eat(b',') {
+                            return self.err("expected ',' or ']'");
+                        }
+                    }
+                }
+                Ok(Json::Array(items))
+            }
+            Some(b'"') => Ok(Json::Str(self.string()?)),
+            Some(b'0'..=b'9') => {
+                let start = self.pos;
+                while self.pos < self.s.len() && self.s[self.pos].is_ascii_digit() {
+                    self.pos += 1;
+                }
+                if matches!(self.s.get(self.pos), Some(b'.' | b'e' | b'E')) {
+                    return self.err("only integers are supported");
+                }
+                std::str::from_utf8(&self.s[start..self.pos]).unwrap().parse()
+                    .map(Json::Num)
+                    .or_else(|_| self.err("integer out of range"))
+            }
+            Some(b'n') => self.literal("null", Json::Null),
+            Some(_) => self.err("unexpected character"),
+            None => self.err("unexpected end of input"),
+        }
+    }
+
+    fn string(&mut self) -> Result<String, String> {
+        if self.s.get(self.pos) != Some(&b'"') {
+            return self.err("expected a string");
+        }
+        self.pos += 1;
+        let mut out = Vec::new();
+        loop {
+            match self.s.get(self.pos) {
+                None => return self.err("unterminated string"),
+                Some(b'"') => {
+                    self.pos += 1;
+                    break;
+                }
+                Some(b'\\') => {
+                    let c = match self.s.get(self.pos + 1) {
+                        Some(b'"')  => '"',
+                        Some(b'\\') => '\\',
+                        Some(b'/')  => '/',
+                        Some(b'n')  => '\n',
+                        Some(b't')  => '\t',
+                        Some(b'r')  => '\r',
+                        Some(b'b')  => '\u{8}',
+                        Some(b'f')  => '\u{c}',
+                        Some(b'u') => {
+                            let code = self.s.get(self.pos + 2..self.pos + 6)
+                                .and_then(|h| u32::from_str_radix(std::str::from_utf8(h).ok()?, 16).ok())
+                                .and_then(char::from_u32);
+                            match code {
+                                Some(c) => {
+                                    self.pos += 4;
+                                    c
+                                }
+                                None => return self.err("invalid \\u escape"),
+                            }
+                        }
+                        _ => return self.err("invalid escape"),
+                    };
+                    self.pos += 2;
+                    out.extend_from_slice(c.encode_utf8(&mut [0; 4]).as_bytes());
+                }
+                Some(&b) => {
+                    out.push(b);
+                    self.pos += 1;
+                }
+            }
+        }
+        String::from_utf8(out).or_else(|_| self.err("invalid UTF-8 in string"))
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+    use crate::circuit::{build_example, Builder};
+    use crate::circuit_transform::{inject_remasks, split_secret_consts};
+    use crate::expr_transform::Chance;
+    use crate::expr::Expr;
+    use crate::lower::lower_to_circuit_multi;
+
+    /// Exercises every gadget kind, byte inputs, and two outputs.
+    fn kitchen_sink() -> Circuit {
+        let msg = Expr::input_bytes("msg", 6);
+        let a   = Expr::input("a");
+        let t   = Expr::xor(Expr::add(a.clone(), msg[1].clone()), Expr::secret_const(0x9e37_79b9));
+        let u   = Expr::rotl_var(Expr::shl(t.clone(), 3), Expr::and(msg[0].clone(), a.clone()));
+        let v   = Expr::not(Expr::and(Expr::shr(u.clone(), 2), Expr::public_const(0xff)));
+        let c   = lower_to_circuit_multi(&[Expr::rotl(u, 7), v, Expr::public_const(7)]);
+        let mut rng = StdRng::seed_from_u64(5);
+        let c = inject_remasks(&c, &mut rng, Chance::new(1, 2));
+        split_secret_consts(&c, &mut rng, Chance::ALWAYS)
+    }
+
+    /// [`kitchen_sink`] with an explicit, non-alphabetical parameter order.
+    fn with_schema() -> Circuit {
+        kitchen_sink().with_schema(&InputSchema::new().input_bytes("msg", 6).input("a")).unwrap()
+    }
+
+    fn inputs() -> HashMap<String, u32> {
+        let mut m: HashMap<String, u32> = crate::circuit::byte_inputs("msg", &[1, 2, 3, 4, 5, 6]).into_iter().collect();
+        m.insert("a".into(), 0xdead_beef);
+        m
+    }
+
+    #[test]
+    fn kitchen_sink_covers_every_kind() {
+        let kinds = kitchen_sink().stats().by_kind;
+        for (kind, _) in GADGET_TAGS {
+            assert!(kinds.contains_key(kind), "{kind} missing");
+        }
+    }
+
+    #[test]
+    fn binary_round_trip() {
+        for c in [build_example(), kitchen_sink(), with_schema()] {
+            let bytes = c.to_bytes();
+            let back  = Circuit::from_bytes(&bytes).unwrap();
+            assert_eq!(back.to_bytes(), bytes);
+            assert_eq!(back.fingerprint(), c.fingerprint());
+        }
+        let c = kitchen_sink();
+        assert_eq!(Circuit::from_bytes(&c.to_bytes()).unwrap().eval_outputs(&inputs()), c.eval_outputs(&inputs()));
+    }
+
+    #[test]
+    fn json_round_trip() {
+        for c in [build_example(), kitchen_sink(), with_schema()] {
+            let text = c.to_json();
+            let back = Circuit::from_json(&text).unwrap();
+            assert_eq!(back.to_bytes(), c.to_bytes());
+            assert_eq!(back.to_json(), text);
+            assert_eq!(back.params(), c.params());
+        }
+        assert_eq!(with_schema().params()[0], ("msg".to_string(), Some(6)));
+    }
+
+    #[test]
+    fn load_checks_schema() {
+        let text = with_schema().to_json();
+        let bad = text.replace("{\"name\": \"a\", \"len\": null}", "{\"name\": \"b\", \"len\": null}");
+        assert_ne!(bad, text);
+        let err = Circuit::from_json(&bad).unwrap_err();
+        assert!(matches!(err, Error::SchemaMismatch(_)), "{err}");
+    }
+
+    #[test]
+    fn loaders_reject_bad_input() {
+        let bytes = build_example().to_bytes();
+
+        let mut bad = bytes.clone();
+        bad[0] = b'Y';
+        assert!(Circuit::from_bytes(&bad).unwrap_err().to_string().contains("bad magic"));
+
+        let mut bad = bytes.clone();
+        bad[4] = 2;
+        assert!(Circuit::from_bytes(&bad).unwrap_err().to_string().contains("unsupported format version 2"));
+
+        assert!(Circuit::from_bytes(&bytes[..bytes.len() - 1]).unwrap_err().to_string().contains("truncated"));
+
+        let mut bad = bytes.clone();
+        bad.push(0);
+        assert!(Circuit::from_bytes(&bad).unwrap_err().to_string().contains("1 trailing byte(s)"));
+
+        let text = build_example().to_json();
+        let bad = text.replace("\"version\": 1", "\"version\": 9");
+        assert!(Circuit::from_json(&bad).unwrap_err().to_string().contains("unsupported format version 9"));
+        let bad = text.replace("xorpl-circuit", "xorpl-rotation-key");
+        assert!(Circuit::from_json(&bad).unwrap_err().to_string().contains("expected format"));
+        assert!(Circuit::from_json("{\"format\": \"xorpl-circuit\"").is_err());
+    }
+
+    #[test]
+    fn load_runs_validate() {
+        // Structurally well-formed, but two ANDs share one triple.
+        let mut b = Builder::new();
+        let (x, y) = (b.ingest("x"), b.ingest("y"));
+        let z = b.and(x, y);
+        let z = b.and(z, y);
+        let mut c = b.build(z);
+        let Gadget::And { gen, .. } = c.gadgets[2] else { panic!("expected AND") };
+        if let Gadget::And { gen: g, .. } = &mut c.gadgets[3] {
+            *g = gen;
+        }
+        let err = Circuit::from_json(&c.to_json()).unwrap_err();
+        assert!(matches!(err, Error::Validation { gadget: Some(3), .. }), "{err}");
+        assert_eq!(Circuit::from_bytes(&c.to_bytes()).unwrap_err(), err);
+    }
+
+    #[test]
+    fn load_rejects_gadget_reading_its_own_output() {
+        let mut b = Builder::new();
+        let (x, y) = (b.ingest("x"), b.ingest("y"));
+        let z = b.and(x, y);
+        let z = b.xor(z, x);
+        let mut c = b.build(z);
+        if let Gadget::And { b, out, .. } = &mut c.gadgets[2] {
+            *b = *out;
+        }
+        let err = Circuit::from_bytes(&c.to_bytes()).unwrap_err();
+        assert!(matches!(err, Error::Validation { gadget: Some(2), .. }), "{err}");
+        assert_eq!(Circuit::from_json(&c.to_json()).unwrap_err(), err);
+    }
+
+    #[test]
+    fn rotation_key_round_trip_rebakes_identically() {
+        let c  = kitchen_sink();
+        let mc = MaskedCircuit::from_circuit(&c, &mut StdRng::seed_from_u64(9));
+        let key = mc.rotation_key(&c);
+
+        assert_eq!(RotationKey::from_bytes(&key.to_bytes()).unwrap(), key);
+        assert_eq!(RotationKey::from_json(&key.to_json()).unwrap(), key);
+
+        let loaded = Circuit::from_json(&c.to_json()).unwrap();
+        let again  = MaskedCircuit::from_rotation_key(&loaded, &key).unwrap();
+        let pool = |m: &MaskedCircuit| m.baked_consts().flatten().copied().collect::<Vec<u32>>();
+        assert_eq!(pool(&again), pool(&mc));
+        assert_eq!(again.eval_multi(&loaded, &inputs()).1, c.eval_outputs(&inputs()));
+    }
+
+    #[test]
+    fn rotation_key_rejects_mismatch() {
+        let c   = kitchen_sink();
+        let key = MaskedCircuit::from_circuit(&c, &mut StdRng::seed_from_u64(1)).rotation_key(&c);
+
+        let other = build_example();
+        assert!(MaskedCircuit::from_rotation_key(&other, &key).unwrap_err().to_string().contains("different circuit"));
+
+        let mut short = key.clone();
+        short.gen_values.pop();
+        assert!(MaskedCircuit::from_rotation_key(&c, &short).unwrap_err().to_string().contains("generator values"));
+
+        let ingest_gen = MaskedCircuit::secret_gens(&c)[0];
+        let mut zero = key.clone();
+        zero.gen_values[ingest_gen] = 0;
+        assert!(MaskedCircuit::from_rotation_key(&c, &zero).unwrap_err().to_string().contains("is zero"));
+    }
+}
@@ -1,28 +1,32 @@
-//! Example: consume a &[u8] digest, unpack to u32s, and feed into an xorpl circuit.
+//! Example: consume a &[u8] digest and feed it into an xorpl circuit.
 //!
 //! Expected production callsite pattern:
 //!   1. Compute SHA-256 of the raw event data outside the circuit.
-//!   2. Unpack the 32-byte digest to 8 little-endian u32s.
-//!   3. Pass each u32 as one circuit input (INGEST wire).
-//!   4. Receive a single u32 checksum.
+//!   2. Pass the 32-byte digest straight to the emitted function.
+//!   3. Receive a single u32 checksum.
 //!
-//! `sha256_qr` takes exactly 8 × u32 = 32 bytes, matching a SHA-256 digest.
+//! `sha256_qr_bytes` is built from `Expr::input_bytes("digest", 32)`: the
+//! circuit packs the 8 little-endian words itself and masks each at ingest.
+//! It computes the same function as the positional `sha256_qr` fixture.
 
-include!("fixtures/sha256_qr.rs");
+#![allow(clippy::too_many_arguments)] // emitted fixture style
 
-/// Unpack a 32-byte digest into 8 little-endian u32s and checksum them.
-///
-/// Panics if `data.len() != 32`.
-fn checksum_bytes(data: &[u8]) -> u32 {
-    assert_eq!(data.len(), 32, "sha256_qr takes 8 × u32 = 32 bytes");
-    let words: [u32; 8] = std::array::from_fn(|i| {
+include!("fixtures/sha256_qr_bytes.rs");
+
+#[allow(dead_code)] // only the function is used; EXPR_DIGEST comes along with the include
+mod positional { include!("fixtures/sha256_qr.rs"); }
+
+/// The pre-`input_bytes` call pattern: unpack by hand, then call with 8
+/// positional words.
+fn checksum_words(data: &[u8; 32]) -> u32 {
+    let w: [u32; 8] = std::array::from_fn(|i| {
         u32::from_le_bytes(data[i * 4..][..4].try_into().unwrap())
     });
-    sha256_qr(words[0], words[1], words[2], words[3], words[4], words[5], words[6], words[7])
+    positional::sha256_qr(w[0], w[1], w[2], w[3], w[4], w[5], w[6], w[7])
 }
 
 #[test]
-fn roundtrip_matches_direct_call() {
+fn byte_input_matches_manual_unpacking() {
     let cases: &[[u32; 8]] = &[
         [0x0000_0000; 8],
         [0xFFFF_FFFF; 8],
@@ -35,8 +39,8 @@ fn roundtrip_matches_direct_call() {
             bytes[i * 4..][..4].copy_from_slice(&word.to_le_bytes());
         }
         assert_eq!(
-            checksum_bytes(&bytes),
-            sha256_qr(w[0], w[1], w[2], w[3], w[4], w[5], w[6], w[7]),
+            sha256_qr_bytes(&bytes),
+            checksum_words(&bytes),
             "byte ingestion must match direct call for {w:08x?}",
         );
     }
@@ -45,21 +49,12 @@ fn roundtrip_matches_direct_call() {
 #[test]
 fn sha256_hello_digest() {
     // SHA-256("hello") — hard-coded, no external crate needed.
-    // In production: sha256(event_bytes) runs before calling checksum_bytes.
+    // In production: sha256(event_bytes) runs before calling sha256_qr_bytes.
     let digest: [u8; 32] = [
         0x2c, 0xf2, 0x4d, 0xba, 0x5f, 0xb0, 0xa3, 0x0e,
         0x26, 0xe8, 0x3b, 0x2a, 0xc5, 0xb9, 0xe2, 0x9e,
         0x1b, 0x16, 0x1e, 0x5c, 0x1f, 0xa7, 0x42, 0x5e,
         0x73, 0x04, 0x33, 0x62, 0x93, 0x8b, 0x98, 0x24,
     ];
-
-    let result = checksum_bytes(&digest);
-
-    let words: [u32; 8] = std::array::from_fn(|i| {
-        u32::from_le_bytes(digest[i * 4..][..4].try_into().unwrap())
-    });
-    assert_eq!(result, sha256_qr(
-        words[0], words[1], words[2], words[3],
-        words[4], words[5], words[6], words[7],
-    ));
+    assert_eq!(sha256_qr_bytes(&digest), checksum_words(&digest));
 }
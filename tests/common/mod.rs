@@ -0,0 +1,80 @@
+//! Shared by the browser-backend tests (`emit_js`, `emit_wasm`, `emit_c`),
+//! so that every backend runs the same circuits on the same kind of inputs.
+
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+use xorpl::circuit::{byte_inputs, Circuit};
+use xorpl::expr::{exprs_digest, Expr};
+use xorpl::expr_transform::Chance;
+use xorpl::fixture_defs::ALL_FIXTURES;
+use xorpl::mask::MaskedCircuit;
+use xorpl::pipeline::{compile_with, Compilation, CompileOptions};
+
+/// One entry of `ALL_FIXTURES`, masked as `regen_fixtures` masks it.  `rng`
+/// has drawn the masks and is next used to emit.
+pub struct Fixture {
+    pub name:    &'static str,
+    pub digest:  [u8; 32],
+    pub circuit: Circuit,
+    pub masked:  MaskedCircuit,
+    pub rng:     StdRng,
+}
+
+pub fn fixtures() -> impl Iterator<Item = Fixture> {
+    ALL_FIXTURES.iter().map(|def| {
+        let circuit = def.circuit();
+        let mut rng = StdRng::seed_from_u64(def.seed);
+        let masked  = MaskedCircuit::from_circuit(&circuit, &mut rng);
+        Fixture { name: def.name, digest: exprs_digest(&(def.build)(), None), circuit, masked, rng }
+    })
+}
+
+/// `tag(a, b, msg: &[u8; 7]) -> [u32; 2]` compiled with every pass on, once
+/// per seed, with the RNG to emit from.  `b` names the second word input;
+/// pass a reserved word of the backend to exercise its mangling.
+pub fn every_pass(b: &str) -> impl Iterator<Item = (u64, Compilation, StdRng)> {
+    let (a, b) = (Expr::input("a"), Expr::input(b));
+    let msg = Expr::input_bytes("msg", 7);
+    let exprs: Vec<Rc<Expr>> = vec![
+        Expr::rotl_var(Expr::add(a.clone(), msg[1].clone()), b.clone()),
+        Expr::mux(Expr::ltu(a, b.clone()), Expr::shr(msg[0].clone(), 3), Expr::sub(b, Expr::public_const(9))),
+    ];
+    let options = CompileOptions { hide_public_consts: true, remasks: Chance::ALWAYS, ..CompileOptions::default() };
+    (0..4).map(move |seed| {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let c = compile_with(exprs.clone(), "tag", &options, &mut rng);
+        (seed, c, rng)
+    })
+}
+
+/// One argument of an emitted function.
+pub enum Arg {
+    Word(u32),
+    Bytes(Vec<u8>),
+}
+
+/// Random arguments in parameter order, and the matching `Circuit::eval`
+/// inputs.
+pub fn random_inputs(circuit: &Circuit, rng: &mut StdRng) -> (Vec<Arg>, HashMap<String, u32>) {
+    let mut args = Vec::new();
+    let mut inputs = HashMap::new();
+    for (name, len) in circuit.params() {
+        match len {
+            None => {
+                let v: u32 = rng.random();
+                args.push(Arg::Word(v));
+                inputs.insert(name, v);
+            }
+            Some(len) => {
+                let bytes: Vec<u8> = (0..len).map(|_| rng.random()).collect();
+                inputs.extend(byte_inputs(&name, &bytes));
+                args.push(Arg::Bytes(bytes));
+            }
+        }
+    }
+    (args, inputs)
+}
@@ -0,0 +1,140 @@
+//! Integration tests for the C emitter.
+//!
+//! Every fixture in `ALL_FIXTURES` is emitted as a browser function and a
+//! verifier, linked into one C99 program together with random inputs, and
+//! compiled with the system C compiler (`$CC`, default `cc`) under
+//! `-std=c99 -pedantic -Wall -Wextra -Werror`.  The program prints both
+//! functions' outputs and both `EXPR_DIGEST`s, which must match
+//! `Circuit::eval` and the expression digest.
+//!
+//! The tests are skipped, with a note on stderr, when no C compiler runs.
+
+use std::fmt::Write as _;
+use std::path::PathBuf;
+use std::process::Command;
+
+use rand::rngs::StdRng;
+
+use xorpl::circuit::Circuit;
+use xorpl::emit_c::{c_param_identifier, emit_c, emit_verifier_c};
+
+mod common;
+use common::{random_inputs, Arg};
+
+/// Builds one C program out of many emitted files and the expected output.
+struct Harness {
+    sources:  String,
+    main:     String,
+    expected: String,
+    files:    usize,
+}
+
+impl Harness {
+    fn new() -> Harness {
+        let mut sources = String::from("#include <stdint.h>\n#include <stdio.h>\n");
+        sources.push_str("static void put(const char *tag, const uint32_t *o, int n) {\n");
+        sources.push_str("    int i;\n    printf(\"%s\", tag);\n");
+        sources.push_str("    for (i = 0; i < n; i++) printf(\" %08lx\", (unsigned long)o[i]);\n");
+        sources.push_str("    printf(\"\\n\");\n}\n");
+        sources.push_str("static void put_digest(const char *tag, const uint8_t *d) {\n");
+        sources.push_str("    int i;\n    printf(\"%s\", tag);\n");
+        sources.push_str("    for (i = 0; i < 32; i++) printf(\"%02x\", d[i]);\n");
+        sources.push_str("    printf(\"\\n\");\n}\n");
+        Harness { sources, main: String::new(), expected: String::new(), files: 0 }
+    }
+
+    /// Add an emitted file.  Each file declares its own `EXPR_DIGEST`, so it
+    /// is renamed per file and printed under `tag`.
+    fn add_file(&mut self, tag: &str, source: &str, digest: &[u8; 32]) {
+        let n = self.files;
+        self.files += 1;
+        writeln!(self.sources, "#define EXPR_DIGEST digest_{n}\n{source}#undef EXPR_DIGEST").unwrap();
+        writeln!(self.main, "    put_digest(\"{tag} \", digest_{n});").unwrap();
+        let hex: String = digest.iter().map(|b| format!("{b:02x}")).collect();
+        writeln!(self.expected, "{tag} {hex}").unwrap();
+    }
+
+    /// Call each of `fn_names`, emitted for `circuit`, on random inputs and
+    /// expect `Circuit::eval`.
+    fn add_calls(&mut self, circuit: &Circuit, fn_names: &[&str], rng: &mut StdRng) {
+        for _ in 0..8 {
+            let (args, inputs) = random_inputs(circuit, rng);
+            let args: Vec<String> = args.iter()
+                .map(|arg| match arg {
+                    Arg::Word(v) => format!("0x{v:08x}u"),
+                    Arg::Bytes(bytes) => {
+                        let list = bytes.iter().map(|b| b.to_string()).collect::<Vec<_>>().join(", ");
+                        format!("(const uint8_t[{}]){{{list}}}", bytes.len())
+                    }
+                })
+                .collect();
+            let outputs = circuit.eval_outputs(&inputs);
+            let n_out = outputs.len();
+            let want: String = outputs.iter().map(|w| format!(" {w:08x}")).collect();
+            for f in fn_names {
+                if n_out == 1 {
+                    writeln!(self.main, "    {{ uint32_t o = {f}({}); put(\"{f}\", &o, 1); }}", args.join(", ")).unwrap();
+                } else {
+                    let args: Vec<_> = args.iter().map(String::as_str).chain(["o"]).collect();
+                    writeln!(self.main, "    {{ uint32_t o[{n_out}]; {f}({}); put(\"{f}\", o, {n_out}); }}", args.join(", ")).unwrap();
+                }
+                writeln!(self.expected, "{f}{want}").unwrap();
+            }
+        }
+    }
+
+    /// Compile and run the program and compare its output, or return
+    /// without checking if there is no C compiler.
+    fn run(self, test: &str) {
+        let cc = std::env::var("CC").unwrap_or_else(|_| "cc".to_string());
+        if Command::new(&cc).arg("--version").output().is_err() {
+            eprintln!("{test}: skipped, no C compiler ({cc})");
+            return;
+        }
+        let dir: PathBuf = std::env::temp_dir().join(format!("xorpl-emit-c-{}-{test}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let (src, exe) = (dir.join("main.c"), dir.join("main"));
+        std::fs::write(&src, format!("{}\nint main(void) {{\n{}    return 0;\n}}\n", self.sources, self.main)).unwrap();
+
+        let build = Command::new(&cc)
+            .args(["-std=c99", "-pedantic", "-Wall", "-Wextra", "-Werror", "-O1", "-o"])
+            .arg(&exe).arg(&src)
+            .output().unwrap();
+        assert!(build.status.success(), "{test}: cc failed:\n{}", String::from_utf8_lossy(&build.stderr));
+        let run = Command::new(&exe).output().unwrap();
+        assert!(run.status.success(), "{test}: program failed");
+        let got = String::from_utf8(run.stdout).unwrap();
+        for (g, w) in got.lines().zip(self.expected.lines()) {
+            assert_eq!(g, w, "{test}");
+        }
+        assert_eq!(got.lines().count(), self.expected.lines().count(), "{test}");
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
+
+#[test]
+fn fixtures_agree_with_circuit_eval() {
+    let mut h = Harness::new();
+    for mut f in common::fixtures() {
+        let verify = format!("{}_verify", f.name);
+        h.add_file(f.name, &emit_c(&f.masked, &f.circuit, f.name, &mut f.rng, &f.digest), &f.digest);
+        h.add_file(&verify, &emit_verifier_c(&f.circuit, &verify, &f.digest), &f.digest);
+        h.add_calls(&f.circuit, &[f.name, &verify], &mut f.rng);
+    }
+    h.run("fixtures");
+}
+
+#[test]
+fn every_pass_agrees_with_circuit_eval() {
+    let mut h = Harness::new();
+    for (seed, c, mut rng) in common::every_pass("int") {
+        let (tag, verify) = (format!("tag{seed}"), format!("tag{seed}_verify"));
+        let source = emit_c(&c.masked, &c.circuit, &tag, &mut rng, &c.expr_digest);
+        let int = c_param_identifier("int");
+        assert!(source.contains(&format!("void {tag}(uint32_t a, uint32_t {int}, const uint8_t msg[7], uint32_t out[2]) {{")), "{source}");
+        h.add_file(&tag, &source, &c.expr_digest);
+        h.add_file(&verify, &emit_verifier_c(&c.circuit, &verify, &c.expr_digest), &c.expr_digest);
+        h.add_calls(&c.circuit, &[&tag, &verify], &mut rng);
+    }
+    h.run("every_pass");
+}
@@ -0,0 +1,486 @@
+//! Integration tests for the JavaScript emitter.
+//!
+//! No JavaScript engine is available to `cargo test`, so the emitted module
+//! runs on a small interpreter for the subset of JavaScript that `emit_js`
+//! produces: `const`/`let`, assignment and `^=`, blocks, `return`, the
+//! bitwise operators with JavaScript's int32/uint32 semantics, typed arrays
+//! and array literals.  Anything outside that subset fails the test.  Type
+//! annotations on declarations, parameters and return types are skipped, so
+//! the same interpreter runs the TypeScript verifier.
+//!
+//! Every fixture in `ALL_FIXTURES` is emitted and interpreted on random
+//! inputs, and must agree with `Circuit::eval`.
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+use xorpl::circuit::Circuit;
+use xorpl::emit_js::{emit_js, emit_verifier_ts, js_param_identifier};
+use xorpl::expr::Expr;
+use xorpl::pipeline::{compile_with, CompileOptions};
+
+mod common;
+use common::{random_inputs, Arg};
+
+// ---------------------------------------------------------------------------
+// Interpreter
+// ---------------------------------------------------------------------------
+
+#[derive(Clone, Debug, PartialEq)]
+enum Tok {
+    Num(i64),
+    Ident(String),
+    Punct(&'static str),
+}
+
+const PUNCTS: &[&str] = &[">>>", "<<", "^=", "(", ")", "[", "]", "{", "}", ",", ";", "=", "^", "&", "|", "-", ".", ":"];
+
+fn tokenize(src: &str) -> Vec<Tok> {
+    let mut toks = Vec::new();
+    let mut rest = src;
+    while let Some(c) = rest.chars().next() {
+        if c.is_whitespace() {
+            rest = &rest[c.len_utf8()..];
+        } else if let Some(after) = rest.strip_prefix("//") {
+            rest = after.find('\n').map_or("", |i| &after[i..]);
+        } else if let Some(after) = rest.strip_prefix("/*") {
+            rest = &after[after.find("*/").expect("unterminated comment") + 2..];
+        } else if let Some(hex) = rest.strip_prefix("0x") {
+            let end = hex.find(|c: char| !c.is_ascii_hexdigit()).unwrap_or(hex.len());
+            toks.push(Tok::Num(i64::from_str_radix(&hex[..end], 16).unwrap()));
+            rest = &hex[end..];
+        } else if c.is_ascii_digit() {
+            let end = rest.find(|c: char| !c.is_ascii_digit()).unwrap_or(rest.len());
+            toks.push(Tok::Num(rest[..end].parse().unwrap()));
+            rest = &rest[end..];
+        } else if c.is_ascii_alphabetic() || c == '_' {
+            let end = rest.find(|c: char| !c.is_ascii_alphanumeric() && c != '_').unwrap_or(rest.len());
+            toks.push(Tok::Ident(rest[..end].to_string()));
+            rest = &rest[end..];
+        } else {
+            let p = PUNCTS.iter().find(|p| rest.starts_with(**p)).unwrap_or_else(|| panic!("unexpected {c:?}"));
+            toks.push(Tok::Punct(p));
+            rest = &rest[p.len()..];
+        }
+    }
+    toks
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum ArrayKind { Plain, U8, U32 }
+
+#[derive(Clone, Debug)]
+enum Val {
+    Num(i64),
+    Array(ArrayKind, Rc<RefCell<Vec<i64>>>),
+}
+
+impl Val {
+    fn num(&self) -> i64 {
+        match self {
+            Val::Num(n) => *n,
+            other => panic!("expected a number, got {other:?}"),
+        }
+    }
+}
+
+fn store(kind: ArrayKind, v: i64) -> i64 {
+    match kind {
+        ArrayKind::Plain => v,
+        ArrayKind::U8    => v as u8 as i64,
+        ArrayKind::U32   => v as u32 as i64,
+    }
+}
+
+fn i32_of(v: i64) -> i32 { v as u32 as i32 }
+
+struct Function {
+    params: Vec<String>,
+    body:   Vec<Tok>,
+}
+
+struct Interp {
+    toks:   Vec<Tok>,
+    pos:    usize,
+    scopes: Vec<HashMap<String, Val>>,
+}
+
+impl Interp {
+    fn peek(&self) -> Option<&Tok> { self.toks.get(self.pos) }
+
+    fn next(&mut self) -> Tok {
+        let t = self.toks.get(self.pos).cloned().expect("unexpected end of input");
+        self.pos += 1;
+        t
+    }
+
+    fn eat(&mut self, p: &str) -> bool {
+        if self.peek() == Some(&Tok::Punct(PUNCTS.iter().find(|q| **q == p).unwrap())) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, p: &str) {
+        assert!(self.eat(p), "expected {p:?} at {:?}", self.peek());
+    }
+
+    fn ident(&mut self) -> String {
+        match self.next() {
+            Tok::Ident(s) => s,
+            other => panic!("expected an identifier, got {other:?}"),
+        }
+    }
+
+    /// Skip a type annotation, `: T` or `: [T, ...]`, if there is one.
+    fn skip_type(&mut self) {
+        if !self.eat(":") {
+            return;
+        }
+        if self.eat("[") {
+            while !self.eat("]") {
+                self.ident();
+                self.eat(",");
+            }
+        } else {
+            self.ident();
+        }
+    }
+
+    fn keyword(&mut self, kw: &str) -> bool {
+        if self.peek() == Some(&Tok::Ident(kw.to_string())) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn lookup(&self, name: &str) -> Val {
+        self.scopes.iter().rev().find_map(|s| s.get(name)).cloned()
+            .unwrap_or_else(|| panic!("undefined name {name}"))
+    }
+
+    fn assign(&mut self, name: &str, v: Val) {
+        let scope = self.scopes.iter_mut().rev().find(|s| s.contains_key(name))
+            .unwrap_or_else(|| panic!("assignment to undeclared {name}"));
+        scope.insert(name.to_string(), v);
+    }
+
+    fn declare(&mut self, name: String, v: Val) {
+        let scope = self.scopes.last_mut().unwrap();
+        assert!(scope.insert(name.clone(), v).is_none(), "{name} declared twice in one scope");
+    }
+
+    fn args(&mut self, close: &str) -> Vec<Val> {
+        let mut args = Vec::new();
+        while !self.eat(close) {
+            args.push(self.expr());
+            if !self.eat(",") {
+                self.expect(close);
+                break;
+            }
+        }
+        args
+    }
+
+    fn typed_array(kind: &str) -> ArrayKind {
+        match kind {
+            "Uint8Array"  => ArrayKind::U8,
+            "Uint32Array" => ArrayKind::U32,
+            other => panic!("unsupported constructor {other}"),
+        }
+    }
+
+    fn primary(&mut self) -> Val {
+        match self.next() {
+            Tok::Num(n) => Val::Num(n),
+            Tok::Punct("(") => {
+                let v = self.expr();
+                self.expect(")");
+                v
+            }
+            Tok::Punct("[") => {
+                let items = self.args("]").iter().map(Val::num).collect();
+                Val::Array(ArrayKind::Plain, Rc::new(RefCell::new(items)))
+            }
+            Tok::Ident(kw) if kw == "new" => {
+                let kind = Self::typed_array(&self.ident());
+                self.expect("(");
+                let len = self.args(")")[0].num() as usize;
+                Val::Array(kind, Rc::new(RefCell::new(vec![0; len])))
+            }
+            Tok::Ident(name) if self.peek() == Some(&Tok::Punct(".")) => {
+                let kind = Self::typed_array(&name);
+                self.expect(".");
+                assert_eq!(self.ident(), "of");
+                self.expect("(");
+                let items = self.args(")").iter().map(|v| store(kind, v.num())).collect();
+                Val::Array(kind, Rc::new(RefCell::new(items)))
+            }
+            Tok::Ident(name) => self.lookup(&name),
+            other => panic!("unexpected token {other:?}"),
+        }
+    }
+
+    fn postfix(&mut self) -> Val {
+        let mut v = self.primary();
+        while self.eat("[") {
+            let i = self.expr().num() as usize;
+            self.expect("]");
+            v = match v {
+                Val::Array(_, items) => Val::Num(*items.borrow().get(i).unwrap_or_else(|| panic!("index {i} out of range"))),
+                other => panic!("indexing a non-array {other:?}"),
+            };
+        }
+        v
+    }
+
+    fn unary(&mut self) -> Val {
+        if self.eat("-") {
+            Val::Num(-self.unary().num())
+        } else {
+            self.postfix()
+        }
+    }
+
+    fn shift(&mut self) -> Val {
+        let mut v = self.unary();
+        loop {
+            if self.eat("<<") {
+                let n = self.unary().num() as u32 & 31;
+                v = Val::Num(i32_of(v.num()).wrapping_shl(n) as i64);
+            } else if self.eat(">>>") {
+                let n = self.unary().num() as u32 & 31;
+                v = Val::Num((v.num() as u32 >> n) as i64);
+            } else {
+                return v;
+            }
+        }
+    }
+
+    fn binary(&mut self, level: usize) -> Val {
+        const OPS: [&str; 3] = ["|", "^", "&"];
+        if level == OPS.len() {
+            return self.shift();
+        }
+        let mut v = self.binary(level + 1);
+        while self.eat(OPS[level]) {
+            let (a, b) = (i32_of(v.num()), i32_of(self.binary(level + 1).num()));
+            v = Val::Num(match level { 0 => a | b, 1 => a ^ b, _ => a & b } as i64);
+        }
+        v
+    }
+
+    fn expr(&mut self) -> Val { self.binary(0) }
+
+    /// Run one statement; `Some` once a `return` has run.
+    fn stmt(&mut self) -> Option<Val> {
+        if self.eat("{") {
+            self.scopes.push(HashMap::new());
+            let mut ret = None;
+            while !self.eat("}") {
+                if ret.is_none() {
+                    ret = self.stmt();
+                } else {
+                    panic!("statement after return");
+                }
+            }
+            self.scopes.pop();
+            return ret;
+        }
+        if self.keyword("return") {
+            let v = self.expr();
+            self.expect(";");
+            return Some(v);
+        }
+        if self.keyword("let") || self.keyword("const") {
+            let name = self.ident();
+            self.skip_type();
+            self.expect("=");
+            let v = self.expr();
+            self.declare(name, v);
+            self.expect(";");
+            return None;
+        }
+        let name = self.ident();
+        let index = if self.eat("[") {
+            let i = self.expr().num() as usize;
+            self.expect("]");
+            Some(i)
+        } else {
+            None
+        };
+        let xor = if self.eat("^=") { true } else { self.expect("="); false };
+        let rhs = self.expr().num();
+        self.expect(";");
+        match index {
+            None => {
+                let v = if xor { i32_of(self.lookup(&name).num()) as i64 ^ i32_of(rhs) as i64 } else { rhs };
+                self.assign(&name, Val::Num(v));
+            }
+            Some(i) => match self.lookup(&name) {
+                Val::Array(kind, items) => {
+                    let mut items = items.borrow_mut();
+                    let v = if xor { (i32_of(items[i]) ^ i32_of(rhs)) as i64 } else { rhs };
+                    items[i] = store(kind, v);
+                }
+                other => panic!("indexing a non-array {other:?}"),
+            },
+        }
+        None
+    }
+}
+
+/// A loaded ES module: its top-level constants and functions.
+struct Module {
+    globals:   HashMap<String, Val>,
+    functions: HashMap<String, Function>,
+}
+
+impl Module {
+    fn load(src: &str) -> Module {
+        let mut it = Interp { toks: tokenize(src), pos: 0, scopes: vec![HashMap::new()] };
+        let mut functions = HashMap::new();
+        while it.peek().is_some() {
+            it.keyword("export");
+            if it.keyword("function") {
+                let name = it.ident();
+                it.expect("(");
+                let mut params = Vec::new();
+                while !it.eat(")") {
+                    params.push(it.ident());
+                    it.skip_type();
+                    it.eat(",");
+                }
+                it.skip_type();
+                let start = it.pos;
+                let mut depth = 0;
+                loop {
+                    match it.next() {
+                        Tok::Punct("{") => depth += 1,
+                        Tok::Punct("}") if depth == 1 => break,
+                        Tok::Punct("}") => depth -= 1,
+                        _ => {}
+                    }
+                }
+                let body = it.toks[start..it.pos].to_vec();
+                functions.insert(name, Function { params, body });
+            } else {
+                assert!(it.stmt().is_none(), "top-level return");
+            }
+        }
+        Module { globals: it.scopes.pop().unwrap(), functions }
+    }
+
+    fn call(&self, name: &str, args: Vec<Val>) -> Val {
+        let f = &self.functions[name];
+        assert_eq!(f.params.len(), args.len(), "arity of {name}");
+        let locals = f.params.iter().cloned().zip(args).collect();
+        let mut it = Interp { toks: f.body.clone(), pos: 0, scopes: vec![self.globals.clone(), locals] };
+        let ret = it.stmt().expect("function did not return");
+        assert_eq!(it.pos, it.toks.len());
+        ret
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+/// A returned word, which must already be normalized to `0..2**32`.
+fn word(v: i64) -> u32 {
+    u32::try_from(v).unwrap_or_else(|_| panic!("{v} is not a uint32"))
+}
+
+/// Interpret `js` on random inputs and compare against `circuit`.
+fn check_against_eval(js: &str, circuit: &Circuit, fn_name: &str, rng: &mut StdRng) {
+    let module = Module::load(js);
+    for _ in 0..8 {
+        let (args, inputs) = random_inputs(circuit, rng);
+        let args = args.into_iter()
+            .map(|arg| match arg {
+                Arg::Word(v)      => Val::Num(v as i64),
+                Arg::Bytes(bytes) => Val::Array(ArrayKind::U8, Rc::new(RefCell::new(bytes.iter().map(|&b| b as i64).collect()))),
+            })
+            .collect();
+        let expected = circuit.eval_outputs(&inputs);
+        let got = match module.call(fn_name, args) {
+            Val::Num(v) => vec![word(v)],
+            Val::Array(ArrayKind::Plain, items) => items.borrow().iter().map(|&v| word(v)).collect(),
+            other => panic!("unexpected return value {other:?}"),
+        };
+        assert_eq!(got, expected, "{fn_name} on {inputs:?}");
+    }
+}
+
+#[test]
+fn fixtures_agree_with_circuit_eval() {
+    for mut f in common::fixtures() {
+        let js = emit_js(&f.masked, &f.circuit, f.name, &mut f.rng, &f.digest);
+
+        let module = Module::load(&js);
+        match &module.globals["EXPR_DIGEST"] {
+            Val::Array(ArrayKind::U8, bytes) => {
+                assert_eq!(*bytes.borrow(), f.digest.iter().map(|&b| b as i64).collect::<Vec<_>>());
+            }
+            other => panic!("[{}] EXPR_DIGEST is {other:?}", f.name),
+        }
+        check_against_eval(&js, &f.circuit, f.name, &mut f.rng);
+    }
+}
+
+#[test]
+fn every_pass_agrees_with_circuit_eval() {
+    for (_, c, mut rng) in common::every_pass("new") {
+        let js = emit_js(&c.masked, &c.circuit, "tag", &mut rng, &c.expr_digest);
+        assert!(js.contains(&format!("export function tag(a, msg, {}) {{", js_param_identifier("new"))), "{js}");
+        check_against_eval(&js, &c.circuit, "tag", &mut rng);
+    }
+}
+
+#[test]
+fn rotations_of_32_or_more_agree_with_circuit_eval() {
+    let a = Expr::input("a");
+    let exprs = vec![Expr::rotl(a.clone(), 32), Expr::rotl(a, 33)];
+    for seed in 0..4 {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let c = compile_with(exprs.clone(), "tag", &CompileOptions::default(), &mut rng);
+        let js = emit_js(&c.masked, &c.circuit, "tag", &mut rng, &c.expr_digest);
+        check_against_eval(&js, &c.circuit, "tag", &mut rng);
+    }
+}
+
+#[test]
+fn verifier_ts_agrees_with_circuit_eval() {
+    for mut f in common::fixtures() {
+        let ts = emit_verifier_ts(&f.circuit, f.name, &f.digest);
+
+        match &Module::load(&ts).globals["EXPR_DIGEST"] {
+            Val::Array(ArrayKind::U8, bytes) => {
+                assert_eq!(*bytes.borrow(), f.digest.iter().map(|&b| b as i64).collect::<Vec<_>>());
+            }
+            other => panic!("[{}] EXPR_DIGEST is {other:?}", f.name),
+        }
+        check_against_eval(&ts, &f.circuit, f.name, &mut f.rng);
+    }
+
+    let (a, b) = (Expr::input("a"), Expr::input("new"));
+    let msg = Expr::input_bytes("msg", 7);
+    let exprs = vec![
+        Expr::rotl_var(Expr::add(a.clone(), msg[1].clone()), b.clone()),
+        Expr::shr(Expr::mux(Expr::ltu(a, b.clone()), msg[0].clone(), Expr::rotl(b, 32)), 3),
+        Expr::rotl(msg[0].clone(), 33),
+    ];
+    let mut rng = StdRng::seed_from_u64(0);
+    let c = compile_with(exprs, "tag", &CompileOptions::default(), &mut rng);
+    let ts = emit_verifier_ts(&c.circuit, "tag", &c.expr_digest);
+    assert!(ts.contains("export function tag(input_a: number, input_msg: Uint8Array, input_new: number): [number, number, number] {"), "{ts}");
+    check_against_eval(&ts, &c.circuit, "tag", &mut rng);
+}
@@ -1,4 +1,5 @@
 #![allow(dead_code)] // ROTATION_TAG in fixture includes is used by the skew check, not the correctness modules
+#![allow(clippy::let_and_return, clippy::too_many_arguments)] // emitted fixture style
 //! Integration tests for the emit pipeline.
 //!
 //! Test categories:
@@ -12,7 +13,8 @@
 //! - **Skew check** (`fixtures_not_out_of_sync`): re-emit every fixture and
 //!   assert the output matches the file on disk.  Catches changing the emitter
 //!   without regenerating the fixtures.  Covers both `{name}.rs` and
-//!   `{name}_verify.rs` for every entry in `ALL_FIXTURES`.
+//!   `{name}_verify.rs` for every entry in `ALL_FIXTURES`, and the
+//!   `verifier_bundle.rs` dispatch module.
 //!
 //! - **Structural** (`structural_properties`): check properties of the emitted
 //!   string (function signature, POOL constant) without needing to compile it.
@@ -25,8 +27,8 @@ use rand::SeedableRng;
 use rand::rngs::StdRng;
 
 use xorpl::prelude::*;
-use xorpl::fixture_defs::ALL_FIXTURES;
-use xorpl::expr::expr_digest;
+use xorpl::fixture_defs::{verifier_bundle, ALL_FIXTURES};
+use xorpl::expr::exprs_digest;
 
 // ---------------------------------------------------------------------------
 // Correctness tests
@@ -98,6 +100,7 @@ mod mux_demo {
     include!("fixtures/mux_demo.rs");
     mod verify { include!("fixtures/mux_demo_verify.rs"); }
 
+    // Emitted parameters are sorted by name: (cond, f, t).
     const CASES: &[(u32, u32, u32)] = &[
         (0xFFFF_FFFF, 0xAAAA_AAAA, 0x5555_5555),
         (0x0000_0000, 0xAAAA_AAAA, 0x5555_5555),
@@ -107,16 +110,16 @@ mod mux_demo {
 
     #[test]
     fn gives_right_answer() {
-        assert_eq!(mux_demo(0xFFFF_FFFF, 0xAAAA_AAAA, 0x5555_5555), 0xAAAA_AAAA);
-        assert_eq!(mux_demo(0x0000_0000, 0xAAAA_AAAA, 0x5555_5555), 0x5555_5555);
-        assert_eq!(mux_demo(0xFFFF_0000, 0xDEAD_BEEF, 0xCAFE_BABE), 0xDEAD_BABE);
-        assert_eq!(mux_demo(0x0000_FFFF, 0xDEAD_BEEF, 0xCAFE_BABE), 0xCAFE_BEEF);
+        assert_eq!(mux_demo(0xFFFF_FFFF, 0x5555_5555, 0xAAAA_AAAA), 0xAAAA_AAAA);
+        assert_eq!(mux_demo(0x0000_0000, 0x5555_5555, 0xAAAA_AAAA), 0x5555_5555);
+        assert_eq!(mux_demo(0xFFFF_0000, 0xCAFE_BABE, 0xDEAD_BEEF), 0xDEAD_BABE);
+        assert_eq!(mux_demo(0x0000_FFFF, 0xCAFE_BABE, 0xDEAD_BEEF), 0xCAFE_BEEF);
     }
 
     #[test]
     fn verifier_agrees() {
         for &(cond, t, f) in CASES {
-            assert_eq!(mux_demo(cond, t, f), verify::mux_demo_verify(cond, t, f),
+            assert_eq!(mux_demo(cond, f, t), verify::mux_demo_verify(cond, f, t),
                 "inputs ({cond:#010x}, {t:#010x}, {f:#010x})");
         }
     }
@@ -274,6 +277,154 @@ mod sha256_qr {
     }
 }
 
+mod rc5_half_round {
+    include!("fixtures/rc5_half_round.rs");
+    mod verify { include!("fixtures/rc5_half_round_verify.rs"); }
+
+    const CASES: &[(u32, u32, u32)] = &[
+        (0x0000_0000, 0x0000_0000, 0x0000_0000),
+        (0xFFFF_FFFF, 0x0000_001F, 0x0000_0001),
+        (0x1234_5678, 0xDEAD_BEEF, 0xB7E1_5163),
+        (0xAAAA_AAAA, 0x5555_5555, 0x9E37_79B9),
+    ];
+
+    #[test]
+    fn gives_right_answer() {
+        for &(a, b, s) in CASES {
+            let expected = (a ^ b).rotate_left(b & 31).wrapping_add(s);
+            assert_eq!(rc5_half_round(a, b, s), expected, "inputs ({a:#010x}, {b:#010x}, {s:#010x})");
+        }
+    }
+
+    #[test]
+    fn verifier_agrees() {
+        for &(a, b, s) in CASES {
+            assert_eq!(rc5_half_round(a, b, s), verify::rc5_half_round_verify(a, b, s),
+                "inputs ({a:#010x}, {b:#010x}, {s:#010x})");
+        }
+    }
+}
+
+mod chacha_qr_words {
+    use super::qr_outputs;
+    include!("fixtures/chacha_qr_words.rs");
+    mod verify { include!("fixtures/chacha_qr_words_verify.rs"); }
+
+    const CASES: &[(u32, u32, u32, u32)] = &[
+        (0x0000_0000, 0x0000_0000, 0x0000_0000, 0x0000_0000),
+        (0xFFFF_FFFF, 0xFFFF_FFFF, 0xFFFF_FFFF, 0xFFFF_FFFF),
+        (0x6170_7865, 0x3320_646e, 0x7962_2d32, 0x6b20_6574),
+    ];
+
+    #[test]
+    fn gives_right_answer() {
+        for &(a, b, c, d) in CASES {
+            let (w0, w1, w2, w3) = qr_outputs(a, b, c, d);
+            assert_eq!(chacha_qr_words(a, b, c, d), [w0, w1, w2, w3],
+                "inputs ({a:#010x}, {b:#010x}, {c:#010x}, {d:#010x})");
+        }
+    }
+
+    #[test]
+    fn verifier_agrees() {
+        for &(a, b, c, d) in CASES {
+            assert_eq!(chacha_qr_words(a, b, c, d), verify::chacha_qr_words_verify(a, b, c, d),
+                "inputs ({a:#010x}, {b:#010x}, {c:#010x}, {d:#010x})");
+        }
+    }
+}
+
+mod sha256_qr_bytes {
+    use super::sha256_qr_expected as expected;
+    include!("fixtures/sha256_qr_bytes.rs");
+    mod verify { include!("fixtures/sha256_qr_bytes_verify.rs"); }
+
+    const CASES: &[[u8; 32]] = &[
+        [0x00; 32],
+        [0xFF; 32],
+        *b"xorpl byte ingestion test vector",
+    ];
+
+    fn words(bytes: &[u8; 32]) -> [u32; 8] {
+        std::array::from_fn(|i| u32::from_le_bytes(bytes[i * 4..][..4].try_into().unwrap()))
+    }
+
+    #[test]
+    fn gives_right_answer() {
+        for digest in CASES {
+            assert_eq!(sha256_qr_bytes(digest), expected(words(digest)), "inputs {digest:02x?}");
+        }
+    }
+
+    #[test]
+    fn verifier_agrees() {
+        for digest in CASES {
+            assert_eq!(sha256_qr_bytes(digest), verify::sha256_qr_bytes_verify(digest),
+                "inputs {digest:02x?}");
+        }
+    }
+}
+
+mod bytes_padding_demo {
+    include!("fixtures/bytes_padding_demo.rs");
+    mod verify { include!("fixtures/bytes_padding_demo_verify.rs"); }
+
+    const CASES: &[[u8; 7]] = &[
+        [0x00; 7],
+        [0xFF; 7],
+        [0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07],
+    ];
+
+    #[test]
+    fn gives_right_answer() {
+        for msg in CASES {
+            let m0 = u32::from_le_bytes([msg[0], msg[1], msg[2], msg[3]]);
+            let m1 = u32::from_le_bytes([msg[4], msg[5], msg[6], 0]);
+            assert_eq!(bytes_padding_demo(msg), m0.rotate_left(7).wrapping_add(m1), "inputs {msg:02x?}");
+        }
+    }
+
+    #[test]
+    fn verifier_agrees() {
+        for msg in CASES {
+            assert_eq!(bytes_padding_demo(msg), verify::bytes_padding_demo_verify(msg),
+                "inputs {msg:02x?}");
+        }
+    }
+}
+
+/// Every verifier in the bundle is looked up by the digest its browser
+/// fixture embeds.
+mod verifier_bundle {
+    include!("fixtures/verifier_bundle.rs");
+
+    const CASES: &[(u32, u32)] = &[
+        (0x0000_0000, 0x0000_0000),
+        (0xFFFF_FFFF, 0x0000_0001),
+        (0x1234_5678, 0xDEAD_BEEF),
+    ];
+
+    #[test]
+    fn dispatches_by_digest() {
+        for &(a, b) in CASES {
+            assert_eq!(verify(&super::or_rotl_demo::EXPR_DIGEST, &[a, b]),
+                Some(super::or_rotl_demo::or_rotl_demo(a, b)), "inputs ({a:#010x}, {b:#010x})");
+            assert_eq!(verify(&super::add32_demo::EXPR_DIGEST, &[a, b]),
+                Some(a.wrapping_add(b)), "inputs ({a:#010x}, {b:#010x})");
+            assert_eq!(verify(&super::or_rotl_mux_decoy::EXPR_DIGEST, &[a, b]),
+                Some(super::or_rotl_mux_decoy::or_rotl_mux_decoy(a, b)), "inputs ({a:#010x}, {b:#010x})");
+        }
+    }
+
+    #[test]
+    fn rejects_unknown_digest_and_wrong_arity() {
+        assert_eq!(INPUT_ARITY, 2);
+        assert_eq!(verify(&super::mux_demo::EXPR_DIGEST, &[1, 2]), None);
+        assert_eq!(verify(&add32_demo::EXPR_DIGEST, &[1]), None);
+        assert_eq!(verify(&add32_demo::EXPR_DIGEST, &[1, 2, 3]), None);
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Skew check
 // ---------------------------------------------------------------------------
@@ -282,9 +433,9 @@ mod sha256_qr {
 fn fixtures_not_out_of_sync() {
     for def in ALL_FIXTURES {
         let original = (def.build)();
-        let digest   = expr_digest(&original, None);
+        let digest   = exprs_digest(&original, None);
 
-        let circuit = lower_to_circuit(&def.expr());
+        let circuit = def.circuit();
         let mut rng = StdRng::seed_from_u64(def.seed);
         let masked  = MaskedCircuit::from_circuit(&circuit, &mut rng);
         let emitted = emit_rust(&masked, &circuit, def.name, &mut rng, &digest);
@@ -297,7 +448,7 @@ fn fixtures_not_out_of_sync() {
             "fixture `{}.rs` is out of sync — run `cargo run --bin regen_fixtures`",
             def.name);
 
-        let canonical    = lower_to_circuit(&original);
+        let canonical    = lower_to_circuit_multi(&original);
         let verify_name  = format!("{}_verify", def.name);
         let verifier     = emit_verifier_rust(&canonical, &verify_name, &digest);
         let verify_path  = format!("tests/fixtures/{}_verify.rs", def.name);
@@ -308,6 +459,12 @@ fn fixtures_not_out_of_sync() {
             "fixture `{}_verify.rs` is out of sync — run `cargo run --bin regen_fixtures`",
             def.name);
     }
+
+    let bundle_on_disk = std::fs::read_to_string("tests/fixtures/verifier_bundle.rs").unwrap_or_else(|e| {
+        panic!("cannot read tests/fixtures/verifier_bundle.rs: {e}\nRun `cargo run --bin regen_fixtures`")
+    });
+    assert_eq!(verifier_bundle(), bundle_on_disk,
+        "fixture `verifier_bundle.rs` is out of sync — run `cargo run --bin regen_fixtures`");
 }
 
 // ---------------------------------------------------------------------------
@@ -335,8 +492,9 @@ fn verifier_parameters_use_input_prefix() {
 #[test]
 fn structural_properties() {
     for def in ALL_FIXTURES {
-        let digest  = expr_digest(&(def.build)(), None);
-        let circuit = lower_to_circuit(&def.expr());
+        let roots   = (def.build)();
+        let digest  = exprs_digest(&roots, None);
+        let circuit = def.circuit();
         let mut rng = StdRng::seed_from_u64(def.seed);
         let masked  = MaskedCircuit::from_circuit(&circuit, &mut rng);
         let emitted = emit_rust(&masked, &circuit, def.name, &mut rng, &digest);
@@ -347,7 +505,11 @@ fn structural_properties() {
             "[{}] missing `const POOL`", def.name);
         assert!(emitted.contains(&format!("pub fn {}(", def.name)),
             "[{}] wrong or missing function name", def.name);
-        assert!(emitted.contains("-> u32"),
+        let ret = match roots.len() {
+            1 => "-> u32 {".to_string(),
+            n => format!("-> [u32; {n}] {{"),
+        };
+        assert!(emitted.contains(&ret),
             "[{}] wrong return type", def.name);
     }
 }
@@ -0,0 +1,357 @@
+//! Integration tests for the WebAssembly emitter.
+//!
+//! The emitted binary is decoded, validated and run by a small interpreter
+//! for the subset of WebAssembly that `emit_wasm` produces: sections must
+//! come in order and be consumed exactly, every instruction is checked
+//! against the operand stack and the declared locals, and every load must
+//! stay inside the declared memory.  Anything outside the subset fails the
+//! test.
+//!
+//! Every fixture in `ALL_FIXTURES` is emitted and run on random inputs and
+//! must agree with `Circuit::eval`; the WAT text must list the same
+//! instructions as the binary.
+
+use std::collections::HashMap;
+
+use rand::rngs::StdRng;
+
+use xorpl::circuit::Circuit;
+use xorpl::emit_wasm::{emit_wasm, emit_wat};
+use xorpl::mask::MaskedCircuit;
+
+mod common;
+use common::{random_inputs, Arg};
+
+// ---------------------------------------------------------------------------
+// Decoder and validator
+// ---------------------------------------------------------------------------
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Op {
+    LocalGet(u32),
+    LocalSet(u32),
+    Const(i32),
+    Load { align: u32, offset: u32 },
+    Load8U { offset: u32 },
+    Bin(u8),
+}
+
+const BIN_OPS: &[(u8, &str)] = &[
+    (0x71, "i32.and"), (0x72, "i32.or"), (0x73, "i32.xor"),
+    (0x74, "i32.shl"), (0x76, "i32.shr_u"), (0x77, "i32.rotl"),
+];
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+    pos:   usize,
+}
+
+impl Reader<'_> {
+    fn byte(&mut self) -> u8 {
+        let b = *self.bytes.get(self.pos).expect("unexpected end of module");
+        self.pos += 1;
+        b
+    }
+
+    fn u32(&mut self) -> u32 {
+        let (mut v, mut shift) = (0u64, 0);
+        loop {
+            let b = self.byte();
+            v |= ((b & 0x7f) as u64) << shift;
+            if b & 0x80 == 0 {
+                return u32::try_from(v).expect("u32 LEB128 out of range");
+            }
+            shift += 7;
+            assert!(shift < 35, "u32 LEB128 too long");
+        }
+    }
+
+    fn i32(&mut self) -> i32 {
+        let (mut v, mut shift) = (0i64, 0);
+        loop {
+            let b = self.byte();
+            v |= ((b & 0x7f) as i64) << shift;
+            shift += 7;
+            if b & 0x80 == 0 {
+                if shift < 64 && b & 0x40 != 0 {
+                    v |= -1i64 << shift;
+                }
+                return i32::try_from(v).expect("i32 LEB128 out of range");
+            }
+            assert!(shift < 35, "i32 LEB128 too long");
+        }
+    }
+
+    fn take(&mut self, n: usize) -> &[u8] {
+        let s = &self.bytes[self.pos..self.pos + n];
+        self.pos += n;
+        s
+    }
+
+    fn name(&mut self) -> String {
+        let n = self.u32() as usize;
+        String::from_utf8(self.take(n).to_vec()).expect("export name is not UTF-8")
+    }
+
+    fn expect(&mut self, b: u8) {
+        assert_eq!(self.byte(), b, "at byte {}", self.pos - 1);
+    }
+
+    /// A constant expression: `i32.const k end`.
+    fn const_expr(&mut self) -> i32 {
+        self.expect(0x41);
+        let k = self.i32();
+        self.expect(0x0b);
+        k
+    }
+}
+
+struct Wasm {
+    params:  usize,
+    results: usize,
+    locals:  usize,
+    code:    Vec<Op>,
+    memory:  Vec<u8>,
+    exports: HashMap<String, (u8, u32)>,
+    globals: Vec<i32>,
+}
+
+fn decode(bytes: &[u8]) -> Wasm {
+    let mut r = Reader { bytes, pos: 0 };
+    assert_eq!(r.take(8), b"\0asm\x01\0\0\0");
+
+    let mut last_id = 0;
+    let (mut params, mut results, mut locals, mut pages) = (None, 0, 0, None);
+    let (mut code, mut data, mut exports, mut globals) = (Vec::new(), Vec::new(), HashMap::new(), Vec::new());
+    while r.pos < bytes.len() {
+        let id = r.byte();
+        assert!(id > last_id, "section {id} out of order");
+        last_id = id;
+        let size = r.u32() as usize;
+        let end = r.pos + size;
+        match id {
+            1 => {
+                r.expect(1);
+                r.expect(0x60);
+                let n = r.u32() as usize;
+                (0..n).for_each(|_| r.expect(0x7f));
+                params = Some(n);
+                results = r.u32() as usize;
+                (0..results).for_each(|_| r.expect(0x7f));
+            }
+            3 => { r.expect(1); r.expect(0); }
+            5 => { r.expect(1); r.expect(0x00); pages = Some(r.u32()); }
+            6 => {
+                for _ in 0..r.u32() {
+                    r.expect(0x7f);
+                    r.expect(0x00);
+                    globals.push(r.const_expr());
+                }
+            }
+            7 => {
+                for _ in 0..r.u32() {
+                    let name = r.name();
+                    let kind = r.byte();
+                    let index = r.u32();
+                    assert!(exports.insert(name.clone(), (kind, index)).is_none(), "export {name} twice");
+                }
+            }
+            10 => {
+                r.expect(1);
+                let body_end = r.u32() as usize + r.pos;
+                for _ in 0..r.u32() {
+                    locals += r.u32() as usize;
+                    r.expect(0x7f);
+                }
+                loop {
+                    let op = match r.byte() {
+                        0x0b => break,
+                        0x20 => Op::LocalGet(r.u32()),
+                        0x21 => Op::LocalSet(r.u32()),
+                        0x41 => Op::Const(r.i32()),
+                        0x28 => Op::Load { align: r.u32(), offset: r.u32() },
+                        0x2d => { r.expect(0); Op::Load8U { offset: r.u32() } }
+                        b if BIN_OPS.iter().any(|&(o, _)| o == b) => Op::Bin(b),
+                        b => panic!("unsupported opcode {b:#04x}"),
+                    };
+                    code.push(op);
+                }
+                assert_eq!(r.pos, body_end, "function body size");
+            }
+            11 => {
+                r.expect(1);
+                r.expect(0x00);
+                let at = r.const_expr() as usize;
+                let n = r.u32() as usize;
+                data.push((at, r.take(n).to_vec()));
+            }
+            other => panic!("unexpected section {other}"),
+        }
+        assert_eq!(r.pos, end, "section {id} size");
+    }
+
+    let mut memory = vec![0u8; pages.expect("no memory") as usize * 65536];
+    for (at, bytes) in data {
+        memory[at..at + bytes.len()].copy_from_slice(&bytes);
+    }
+    let params = params.expect("no type section");
+    let wasm = Wasm { params, results, locals: params + locals, code, memory, exports, globals };
+    wasm.validate();
+    wasm
+}
+
+impl Wasm {
+    /// Check stack discipline and local indices: no instruction pops an
+    /// empty stack, and the body ends with exactly the results on it.
+    fn validate(&self) {
+        let mut depth = 0usize;
+        let pop = |n: usize, depth: &mut usize| {
+            assert!(*depth >= n, "stack underflow");
+            *depth -= n;
+        };
+        for op in &self.code {
+            match *op {
+                Op::LocalGet(i) => { assert!((i as usize) < self.locals); depth += 1; }
+                Op::LocalSet(i) => { assert!((i as usize) < self.locals); pop(1, &mut depth); }
+                Op::Const(_) => depth += 1,
+                Op::Load { align, .. } => { assert!(align <= 2); pop(1, &mut depth); depth += 1; }
+                Op::Load8U { .. } => { pop(1, &mut depth); depth += 1; }
+                Op::Bin(_) => { pop(2, &mut depth); depth += 1; }
+            }
+        }
+        assert_eq!(depth, self.results, "values left on the stack");
+    }
+
+    fn global(&self, name: &str) -> i32 {
+        match self.exports.get(name) {
+            Some(&(0x03, i)) => self.globals[i as usize],
+            other => panic!("no global export {name}: {other:?}"),
+        }
+    }
+
+    fn call(&self, name: &str, args: &[u32], memory: &[u8]) -> Vec<u32> {
+        assert_eq!(self.exports.get(name), Some(&(0x00, 0)), "no function export {name}");
+        assert_eq!(args.len(), self.params);
+        let mut locals = args.to_vec();
+        locals.resize(self.locals, 0);
+        let load = |addr: u32, offset: u32, n: usize| {
+            let at = addr as usize + offset as usize;
+            assert!(at + n <= memory.len(), "load out of bounds at {at}");
+            let mut word = [0u8; 4];
+            word[..n].copy_from_slice(&memory[at..at + n]);
+            u32::from_le_bytes(word)
+        };
+        let mut stack: Vec<u32> = Vec::new();
+        for op in &self.code {
+            match *op {
+                Op::LocalGet(i) => stack.push(locals[i as usize]),
+                Op::LocalSet(i) => locals[i as usize] = stack.pop().unwrap(),
+                Op::Const(k) => stack.push(k as u32),
+                Op::Load { offset, .. } => { let a = stack.pop().unwrap(); stack.push(load(a, offset, 4)); }
+                Op::Load8U { offset } => { let a = stack.pop().unwrap(); stack.push(load(a, offset, 1)); }
+                Op::Bin(b) => {
+                    let (y, x) = (stack.pop().unwrap(), stack.pop().unwrap());
+                    stack.push(match b {
+                        0x71 => x & y,
+                        0x72 => x | y,
+                        0x73 => x ^ y,
+                        0x74 => x << (y & 31),
+                        0x76 => x >> (y & 31),
+                        _    => x.rotate_left(y & 31),
+                    });
+                }
+            }
+        }
+        stack
+    }
+
+    /// The function body in WAT syntax, locals by index.
+    fn disassemble(&self) -> Vec<String> {
+        self.code.iter().map(|op| match *op {
+            Op::LocalGet(i) => format!("local.get {i}"),
+            Op::LocalSet(i) => format!("local.set {i}"),
+            Op::Const(k) => format!("i32.const {k}"),
+            Op::Load { align: 2, offset: 0 } => "i32.load".to_string(),
+            Op::Load { align, offset } => format!("i32.load offset={offset} align={}", 1u32 << align),
+            Op::Load8U { offset } => format!("i32.load8_u offset={offset}"),
+            Op::Bin(b) => BIN_OPS.iter().find(|&&(o, _)| o == b).unwrap().1.to_string(),
+        }).collect()
+    }
+}
+
+/// The function body of a WAT module, locals renamed to their indices.
+fn wat_body(wat: &str) -> Vec<String> {
+    let mut names: Vec<String> = Vec::new();
+    let mut body = Vec::new();
+    for line in wat.lines().map(str::trim) {
+        if line.starts_with("(func ") {
+            names.extend(line.split("(param $").skip(1).map(|p| p.split(' ').next().unwrap().to_string()));
+        } else if let Some(local) = line.strip_prefix("(local $") {
+            names.push(local.split(' ').next().unwrap().to_string());
+        } else if line.starts_with("local.") || line.starts_with("i32.") {
+            body.push(match line.split_once(" $") {
+                Some((op, name)) => format!("{op} {}", names.iter().position(|n| n == name).expect("unknown local")),
+                None => line.to_string(),
+            });
+        }
+    }
+    body
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+/// Run `wasm` on random inputs and compare against `circuit`; byte inputs
+/// are written to memory from `INPUT_BASE` on.
+fn check_against_eval(wasm: &Wasm, circuit: &Circuit, fn_name: &str, rng: &mut StdRng) {
+    for _ in 0..8 {
+        let mut memory = wasm.memory.clone();
+        let mut next = wasm.global("INPUT_BASE") as usize;
+        let (args, inputs) = random_inputs(circuit, rng);
+        let args: Vec<u32> = args.into_iter()
+            .map(|arg| match arg {
+                Arg::Word(v) => v,
+                Arg::Bytes(bytes) => {
+                    let at = next;
+                    memory[at..at + bytes.len()].copy_from_slice(&bytes);
+                    // Garbage after the input must not leak into the padding.
+                    memory[at + bytes.len()] = 0xa5;
+                    next += bytes.len() + 1;
+                    at as u32
+                }
+            })
+            .collect();
+        assert_eq!(wasm.call(fn_name, &args, &memory), circuit.eval_outputs(&inputs), "{fn_name} on {inputs:?}");
+    }
+}
+
+fn emit_both(masked: &MaskedCircuit, circuit: &Circuit, name: &str, rng: &mut StdRng, digest: &[u8; 32]) -> (Wasm, String) {
+    let mut text_rng = rng.clone();
+    let wasm = decode(&emit_wasm(masked, circuit, name, rng, digest));
+    let wat = emit_wat(masked, circuit, name, &mut text_rng, digest);
+    assert_eq!(wasm.disassemble(), wat_body(&wat), "[{name}] WAT and binary differ");
+    (wasm, wat)
+}
+
+#[test]
+fn fixtures_agree_with_circuit_eval() {
+    for mut f in common::fixtures() {
+        let (wasm, wat) = emit_both(&f.masked, &f.circuit, f.name, &mut f.rng, &f.digest);
+
+        let at = wasm.global("EXPR_DIGEST") as usize;
+        assert_eq!(&wasm.memory[at..at + 32], &f.digest, "[{}] EXPR_DIGEST", f.name);
+        assert_eq!(wasm.exports["memory"], (0x02, 0));
+        assert!(wat.contains(&format!("(func (export \"{}\")", f.name)));
+        check_against_eval(&wasm, &f.circuit, f.name, &mut f.rng);
+    }
+}
+
+#[test]
+fn every_pass_agrees_with_circuit_eval() {
+    for (_, c, mut rng) in common::every_pass("b") {
+        let (wasm, wat) = emit_both(&c.masked, &c.circuit, "tag", &mut rng, &c.expr_digest);
+        assert!(wat.contains("(func (export \"tag\") (param $input_a i32) (param $input_b i32) (param $input_msg i32) (result i32 i32)"), "{wat}");
+        check_against_eval(&wasm, &c.circuit, "tag", &mut rng);
+    }
+}
@@ -0,0 +1,926 @@
+// Generated by `cargo run --bin regen_fixtures` — do not edit manually.
+pub const EXPR_DIGEST: [u8; 32] = [
+    0xe9, 0xca, 0xc4, 0x7a, 0x43, 0x17, 0x13, 0xe4,
+    0x3b, 0x2a, 0x1a, 0x6f, 0x86, 0x8b, 0x1e, 0x7a,
+    0xe0, 0x72, 0x4b, 0x37, 0x78, 0x3a, 0xa0, 0x43,
+    0x7d, 0x2f, 0xa0, 0xfd, 0x44, 0x4a, 0x6b, 0x3f,
+];
+pub fn bytes_padding_demo(msg: &[u8; 7]) -> u32 {
+    const POOL: &[u32] = &[
+        0xcd2c6f7f,
+        0xbb2a3fb2,
+        0x1810d69d,
+        0x482615ef,
+        0x741b35a4,
+        0xc9accb14,
+        0xade4b6d0,
+        0x958b761d,
+        0xfe6ebc59,
+        0x3ef201cf,
+        0x00000001,
+        0x00000001,
+        0x00000002,
+        0x00000002,
+        0xdee17b11,
+        0x9dfce645,
+        0x5c6c5976,
+        0xc5a0ef11,
+        0xd7a39602,
+        0x25fffc5c,
+        0xde8fe5c5,
+        0xcb30ce1a,
+        0x0aceeffd,
+        0x2243ae90,
+        0x00000004,
+        0x00000004,
+        0x9e0d7fa8,
+        0x21d9db02,
+        0x386c9cfa,
+        0x6bf28e6c,
+        0x5990773e,
+        0xff0ea77d,
+        0x2126e817,
+        0x5e929da0,
+        0x00000008,
+        0x00000008,
+        0x48501800,
+        0x6bfe347b,
+        0x287a9bc0,
+        0xafd44686,
+        0xf552b66e,
+        0xf6ff78ef,
+        0x0879793a,
+        0x1f4976fa,
+        0x00000010,
+        0x00000010,
+        0x34bb13e0,
+        0x381e6892,
+        0x629eadc0,
+        0x97d6c32f,
+        0xcadbf41a,
+        0x75e88c63,
+        0xd108aedb,
+        0xd3c9b7fd,
+        0x00000020,
+        0x00000020,
+        0x30f0a328,
+        0xdc6cd914,
+        0x12493486,
+        0x77dd30e5,
+        0x5f6d584d,
+        0x8f3066bc,
+        0x2e88704b,
+        0xea426b01,
+        0x00000040,
+        0x00000040,
+        0xaf976aab,
+        0x8850fff4,
+        0x9f55357b,
+        0x1f137583,
+        0xe66d4fd1,
+        0x345f0997,
+        0x6aa0e622,
+        0x07d10a3f,
+        0x00000080,
+        0x00000080,
+        0x135f3ef3,
+        0x93922a5b,
+        0x61935a25,
+        0x4e9d1432,
+        0x1e150e58,
+        0x7a95e009,
+        0x85dea0dc,
+        0xc7c36581,
+        0x00000100,
+        0x00000100,
+        0x582b922f,
+        0xb86d04c8,
+        0x2b6fb3cd,
+        0xa04d91cd,
+        0x7143007e,
+        0x102ff028,
+        0xaa45d57c,
+        0xbf8f692a,
+        0x00000200,
+        0x00000200,
+        0xecdca315,
+        0x10787f2d,
+        0xd5ff8f4b,
+        0x41440926,
+        0x4edc7d04,
+        0xfa202be2,
+        0x9da70595,
+        0x837c5d6d,
+        0x00000400,
+        0x00000400,
+        0x4c0bcc72,
+        0xa6d6549c,
+        0x89bf384b,
+        0x72e5cd1f,
+        0x404e4995,
+        0x03d8ae10,
+        0x20f44720,
+        0xeecbd294,
+        0x00000800,
+        0x00000800,
+        0xd192ea97,
+        0xc02a68ad,
+        0xcaa57fd9,
+        0x5c23ba00,
+        0x2974f98d,
+        0x9d1ffa92,
+        0x6f76fefc,
+        0x2a546237,
+        0x00001000,
+        0x00001000,
+        0xabf1be91,
+        0x62f4e8f3,
+        0x9a2a910a,
+        0xcca49d49,
+        0xdf7f0d8e,
+        0x159ba8db,
+        0x99cb623d,
+        0x60930947,
+        0x00002000,
+        0x00002000,
+        0xd0efd947,
+        0xc5916a57,
+        0xe1150f7e,
+        0x3e7b7e07,
+        0x582170dc,
+        0xc0c08aa8,
+        0x6c8a461a,
+        0x1c2bb76e,
+        0x00004000,
+        0x00004000,
+        0x410142c3,
+        0x9f273616,
+        0xf4494190,
+        0xaebbfc29,
+        0x3e34c19f,
+        0x59e3238a,
+        0x4db8d750,
+        0x71baa791,
+        0x00008000,
+        0x00008000,
+        0xe5db18b8,
+        0xca2dd6a3,
+        0xcd6fa50b,
+        0xfd0dcc2e,
+        0x1f1d334d,
+        0xfa077d26,
+        0x0783a57b,
+        0x60a48b73,
+        0x00010000,
+        0x00010000,
+        0x69184a02,
+        0x2a90dc39,
+        0x24cb3a0d,
+        0x7f00c5ab,
+        0x3bb28dde,
+        0xfc2699b7,
+        0xa0d6b85f,
+        0x879e3579,
+        0x00020000,
+        0x00020000,
+        0xffd75ad9,
+        0x55a8892c,
+        0xb2a00188,
+        0x5b6fcfd5,
+        0x97ab989b,
+        0xcab2a0b3,
+        0x45c6ea55,
+        0xf115ac61,
+        0x00040000,
+        0x00040000,
+        0xb254cb87,
+        0xf8b597e4,
+        0x87670bc2,
+        0x9d228d8e,
+        0x6a01310a,
+        0x08c8f3bd,
+        0xaf4b9516,
+        0xc0a55e9c,
+        0x00080000,
+        0x00080000,
+        0xeab01afa,
+        0xffde0d69,
+        0x169df7b4,
+        0xe586af0d,
+        0xbec548fd,
+        0xa7e71c18,
+        0x1ad9b4c2,
+        0x28aeb1bf,
+        0x00100000,
+        0x00100000,
+        0xbe0bb62a,
+        0x2ac13dd1,
+        0x9ba6806e,
+        0x5154eb23,
+        0xd2bb08e2,
+        0x375b2523,
+        0xea9c2654,
+        0xca2f971d,
+        0x00200000,
+        0x00200000,
+        0xba649cea,
+        0xebf40308,
+        0x1c050a9a,
+        0xe18d27bf,
+        0xd563d410,
+        0x8e89bfb9,
+        0x167ffed1,
+        0x018948fb,
+        0x00400000,
+        0x00400000,
+        0x3267ea3d,
+        0x900a252f,
+        0xd116e8a3,
+        0x3e1a91eb,
+        0x93a20846,
+        0x154b75d8,
+        0xf5925a2f,
+        0x827646f4,
+        0x00800000,
+        0x00800000,
+        0x30a0e916,
+        0x62485f06,
+        0xe9cbef6c,
+        0x191d2b11,
+        0x316329d0,
+        0x09edd789,
+        0x83ada725,
+        0x06618385,
+        0x01000000,
+        0x01000000,
+        0x990b1fc7,
+        0x725c62d7,
+        0xabcb7e9f,
+        0x669fc3e7,
+        0x692457af,
+        0x9c2b1c95,
+        0xb49348b5,
+        0x841069c5,
+        0x02000000,
+        0x02000000,
+        0x6e53e9e1,
+        0x6263be6c,
+        0x3d468452,
+        0x9f774eaf,
+        0x8ad41446,
+        0xd0541386,
+        0x6bf6d41f,
+        0x9787e32f,
+        0x04000000,
+        0x04000000,
+        0x5d6cfbb1,
+        0x8e35a4a9,
+        0x18d20cf2,
+        0xf9d2c946,
+        0x3f8d1a21,
+        0xc3fe4c62,
+        0x33f805a9,
+        0x16043616,
+        0x08000000,
+        0x08000000,
+        0x07d0104d,
+        0xeee5d8eb,
+        0xe4c32a6a,
+        0xb13faf90,
+        0xf39f5c3b,
+        0xe40ce9ae,
+        0x12655759,
+        0x4db0aec6,
+        0x10000000,
+        0x10000000,
+        0x2659f3d5,
+        0xacd14cbb,
+        0x7849750e,
+        0xec2236d8,
+        0x9ea51cb3,
+        0x49876c8c,
+        0x25e3ba5f,
+        0xcc7afa10,
+        0x20000000,
+        0x20000000,
+        0xfbc6d480,
+        0x87b005ce,
+        0xe0dcda69,
+        0xdcf3a8df,
+        0x8f10a011,
+        0xdb8bc196,
+        0xe8316aa0,
+        0x91a3e47f,
+        0x40000000,
+        0x40000000,
+        0x76ea4d61,
+        0x5a6fee6d,
+        0x7a0f9a84,
+        0xd58e944a,
+        0xa656a4cf,
+        0x32e5c923,
+        0xc2395464,
+        0x174cbbc4,
+        0x80000000,
+        0x446d5fae,
+        0x66a817f7,
+    ];
+    let mut r = [0u32; 7];
+    r[0] = u32::from_le_bytes([msg[0], msg[1], msg[2], msg[3]]) ^ POOL[0];
+    r[2] = r[0].rotate_left(7);
+    r[0] = u32::from_le_bytes([msg[4], msg[5], msg[6], 0]) ^ POOL[1];
+    r[6] = r[2] ^ POOL[2];
+    r[4] = r[6] ^ POOL[3];
+    r[6] = r[0] ^ POOL[4];
+    r[1] = r[6] ^ POOL[5];
+    r[6] = {
+        let (t, s) = (POOL[6], POOL[7]);
+        let (ma, mb) = (t ^ s ^ POOL[8], t ^ s ^ POOL[9]);
+        let mut z = t;
+        z ^= r[4] & mb;
+        z ^= r[1] & ma;
+        z ^= r[4] & r[1];
+        z
+    };
+    r[4] = r[2] ^ r[0];
+    r[0] = r[4] & POOL[10];
+    r[2] = r[6] & POOL[11];
+    r[1] = r[2].rotate_left(1);
+    r[2] = r[4] & POOL[12];
+    r[5] = r[2] ^ r[1];
+    r[3] = r[0] ^ r[5];
+    r[0] = r[6] & POOL[13];
+    r[5] = r[2] ^ POOL[14];
+    r[2] = r[5] ^ POOL[15];
+    r[5] = r[2] ^ POOL[16];
+    r[2] = r[1] ^ POOL[17];
+    r[1] = r[2] ^ POOL[18];
+    r[2] = r[1] ^ POOL[19];
+    r[1] = {
+        let (t, s) = (POOL[20], POOL[21]);
+        let (ma, mb) = (t ^ s ^ POOL[22], t ^ s ^ POOL[23]);
+        let mut z = t;
+        z ^= r[5] & mb;
+        z ^= r[2] & ma;
+        z ^= r[5] & r[2];
+        z
+    };
+    r[2] = r[0] ^ r[1];
+    r[0] = r[2].rotate_left(1);
+    r[2] = r[4] & POOL[24];
+    r[1] = r[2] ^ r[0];
+    r[5] = r[3] ^ r[1];
+    r[1] = r[6] & POOL[25];
+    r[3] = r[2] ^ POOL[26];
+    r[2] = r[3] ^ POOL[27];
+    r[3] = r[2] ^ POOL[28];
+    r[2] = r[0] ^ POOL[29];
+    r[0] = {
+        let (t, s) = (POOL[30], POOL[31]);
+        let (ma, mb) = (t ^ s ^ POOL[32], t ^ s ^ POOL[33]);
+        let mut z = t;
+        z ^= r[3] & mb;
+        z ^= r[2] & ma;
+        z ^= r[3] & r[2];
+        z
+    };
+    r[2] = r[1] ^ r[0];
+    r[0] = r[2].rotate_left(1);
+    r[2] = r[4] & POOL[34];
+    r[1] = r[2] ^ r[0];
+    r[3] = r[5] ^ r[1];
+    r[1] = r[6] & POOL[35];
+    r[5] = r[2] ^ POOL[36];
+    r[2] = r[5] ^ POOL[37];
+    r[5] = r[2] ^ POOL[38];
+    r[2] = r[0] ^ POOL[39];
+    r[0] = {
+        let (t, s) = (POOL[40], POOL[41]);
+        let (ma, mb) = (t ^ s ^ POOL[42], t ^ s ^ POOL[43]);
+        let mut z = t;
+        z ^= r[5] & mb;
+        z ^= r[2] & ma;
+        z ^= r[5] & r[2];
+        z
+    };
+    r[2] = r[1] ^ r[0];
+    r[0] = r[2].rotate_left(1);
+    r[2] = r[4] & POOL[44];
+    r[1] = r[2] ^ r[0];
+    r[5] = r[3] ^ r[1];
+    r[1] = r[6] & POOL[45];
+    r[3] = r[2] ^ POOL[46];
+    r[2] = r[3] ^ POOL[47];
+    r[3] = r[2] ^ POOL[48];
+    r[2] = r[0] ^ POOL[49];
+    r[0] = {
+        let (t, s) = (POOL[50], POOL[51]);
+        let (ma, mb) = (t ^ s ^ POOL[52], t ^ s ^ POOL[53]);
+        let mut z = t;
+        z ^= r[3] & mb;
+        z ^= r[2] & ma;
+        z ^= r[3] & r[2];
+        z
+    };
+    r[2] = r[1] ^ r[0];
+    r[0] = r[2].rotate_left(1);
+    r[2] = r[4] & POOL[54];
+    r[1] = r[2] ^ r[0];
+    r[3] = r[5] ^ r[1];
+    r[1] = r[6] & POOL[55];
+    r[5] = r[2] ^ POOL[56];
+    r[2] = r[5] ^ POOL[57];
+    r[5] = r[2] ^ POOL[58];
+    r[2] = r[0] ^ POOL[59];
+    r[0] = {
+        let (t, s) = (POOL[60], POOL[61]);
+        let (ma, mb) = (t ^ s ^ POOL[62], t ^ s ^ POOL[63]);
+        let mut z = t;
+        z ^= r[5] & mb;
+        z ^= r[2] & ma;
+        z ^= r[5] & r[2];
+        z
+    };
+    r[2] = r[1] ^ r[0];
+    r[0] = r[2].rotate_left(1);
+    r[2] = r[4] & POOL[64];
+    r[1] = r[2] ^ r[0];
+    r[5] = r[3] ^ r[1];
+    r[1] = r[6] & POOL[65];
+    r[3] = r[2] ^ POOL[66];
+    r[2] = r[3] ^ POOL[67];
+    r[3] = r[2] ^ POOL[68];
+    r[2] = r[0] ^ POOL[69];
+    r[0] = {
+        let (t, s) = (POOL[70], POOL[71]);
+        let (ma, mb) = (t ^ s ^ POOL[72], t ^ s ^ POOL[73]);
+        let mut z = t;
+        z ^= r[3] & mb;
+        z ^= r[2] & ma;
+        z ^= r[3] & r[2];
+        z
+    };
+    r[2] = r[1] ^ r[0];
+    r[0] = r[2].rotate_left(1);
+    r[2] = r[4] & POOL[74];
+    r[1] = r[2] ^ r[0];
+    r[3] = r[5] ^ r[1];
+    r[1] = r[6] & POOL[75];
+    r[5] = r[2] ^ POOL[76];
+    r[2] = r[5] ^ POOL[77];
+    r[5] = r[2] ^ POOL[78];
+    r[2] = r[0] ^ POOL[79];
+    r[0] = {
+        let (t, s) = (POOL[80], POOL[81]);
+        let (ma, mb) = (t ^ s ^ POOL[82], t ^ s ^ POOL[83]);
+        let mut z = t;
+        z ^= r[5] & mb;
+        z ^= r[2] & ma;
+        z ^= r[5] & r[2];
+        z
+    };
+    r[2] = r[1] ^ r[0];
+    r[0] = r[2].rotate_left(1);
+    r[2] = r[4] & POOL[84];
+    r[1] = r[2] ^ r[0];
+    r[5] = r[3] ^ r[1];
+    r[1] = r[6] & POOL[85];
+    r[3] = r[2] ^ POOL[86];
+    r[2] = r[3] ^ POOL[87];
+    r[3] = r[2] ^ POOL[88];
+    r[2] = r[0] ^ POOL[89];
+    r[0] = {
+        let (t, s) = (POOL[90], POOL[91]);
+        let (ma, mb) = (t ^ s ^ POOL[92], t ^ s ^ POOL[93]);
+        let mut z = t;
+        z ^= r[3] & mb;
+        z ^= r[2] & ma;
+        z ^= r[3] & r[2];
+        z
+    };
+    r[2] = r[1] ^ r[0];
+    r[0] = r[2].rotate_left(1);
+    r[2] = r[4] & POOL[94];
+    r[1] = r[2] ^ r[0];
+    r[3] = r[5] ^ r[1];
+    r[1] = r[6] & POOL[95];
+    r[5] = r[2] ^ POOL[96];
+    r[2] = r[5] ^ POOL[97];
+    r[5] = r[2] ^ POOL[98];
+    r[2] = r[0] ^ POOL[99];
+    r[0] = {
+        let (t, s) = (POOL[100], POOL[101]);
+        let (ma, mb) = (t ^ s ^ POOL[102], t ^ s ^ POOL[103]);
+        let mut z = t;
+        z ^= r[5] & mb;
+        z ^= r[2] & ma;
+        z ^= r[5] & r[2];
+        z
+    };
+    r[2] = r[1] ^ r[0];
+    r[0] = r[2].rotate_left(1);
+    r[2] = r[4] & POOL[104];
+    r[1] = r[2] ^ r[0];
+    r[5] = r[3] ^ r[1];
+    r[1] = r[6] & POOL[105];
+    r[3] = r[2] ^ POOL[106];
+    r[2] = r[3] ^ POOL[107];
+    r[3] = r[2] ^ POOL[108];
+    r[2] = r[0] ^ POOL[109];
+    r[0] = {
+        let (t, s) = (POOL[110], POOL[111]);
+        let (ma, mb) = (t ^ s ^ POOL[112], t ^ s ^ POOL[113]);
+        let mut z = t;
+        z ^= r[3] & mb;
+        z ^= r[2] & ma;
+        z ^= r[3] & r[2];
+        z
+    };
+    r[2] = r[1] ^ r[0];
+    r[0] = r[2].rotate_left(1);
+    r[2] = r[4] & POOL[114];
+    r[1] = r[2] ^ r[0];
+    r[3] = r[5] ^ r[1];
+    r[1] = r[6] & POOL[115];
+    r[5] = r[2] ^ POOL[116];
+    r[2] = r[5] ^ POOL[117];
+    r[5] = r[2] ^ POOL[118];
+    r[2] = r[0] ^ POOL[119];
+    r[0] = {
+        let (t, s) = (POOL[120], POOL[121]);
+        let (ma, mb) = (t ^ s ^ POOL[122], t ^ s ^ POOL[123]);
+        let mut z = t;
+        z ^= r[5] & mb;
+        z ^= r[2] & ma;
+        z ^= r[5] & r[2];
+        z
+    };
+    r[2] = r[1] ^ r[0];
+    r[0] = r[2].rotate_left(1);
+    r[2] = r[4] & POOL[124];
+    r[1] = r[2] ^ r[0];
+    r[5] = r[3] ^ r[1];
+    r[1] = r[6] & POOL[125];
+    r[3] = r[2] ^ POOL[126];
+    r[2] = r[3] ^ POOL[127];
+    r[3] = r[2] ^ POOL[128];
+    r[2] = r[0] ^ POOL[129];
+    r[0] = {
+        let (t, s) = (POOL[130], POOL[131]);
+        let (ma, mb) = (t ^ s ^ POOL[132], t ^ s ^ POOL[133]);
+        let mut z = t;
+        z ^= r[3] & mb;
+        z ^= r[2] & ma;
+        z ^= r[3] & r[2];
+        z
+    };
+    r[2] = r[1] ^ r[0];
+    r[0] = r[2].rotate_left(1);
+    r[2] = r[4] & POOL[134];
+    r[1] = r[2] ^ r[0];
+    r[3] = r[5] ^ r[1];
+    r[1] = r[6] & POOL[135];
+    r[5] = r[2] ^ POOL[136];
+    r[2] = r[5] ^ POOL[137];
+    r[5] = r[2] ^ POOL[138];
+    r[2] = r[0] ^ POOL[139];
+    r[0] = {
+        let (t, s) = (POOL[140], POOL[141]);
+        let (ma, mb) = (t ^ s ^ POOL[142], t ^ s ^ POOL[143]);
+        let mut z = t;
+        z ^= r[5] & mb;
+        z ^= r[2] & ma;
+        z ^= r[5] & r[2];
+        z
+    };
+    r[2] = r[1] ^ r[0];
+    r[0] = r[2].rotate_left(1);
+    r[2] = r[4] & POOL[144];
+    r[1] = r[2] ^ r[0];
+    r[5] = r[3] ^ r[1];
+    r[1] = r[6] & POOL[145];
+    r[3] = r[2] ^ POOL[146];
+    r[2] = r[3] ^ POOL[147];
+    r[3] = r[2] ^ POOL[148];
+    r[2] = r[0] ^ POOL[149];
+    r[0] = {
+        let (t, s) = (POOL[150], POOL[151]);
+        let (ma, mb) = (t ^ s ^ POOL[152], t ^ s ^ POOL[153]);
+        let mut z = t;
+        z ^= r[3] & mb;
+        z ^= r[2] & ma;
+        z ^= r[3] & r[2];
+        z
+    };
+    r[2] = r[1] ^ r[0];
+    r[0] = r[2].rotate_left(1);
+    r[2] = r[4] & POOL[154];
+    r[1] = r[2] ^ r[0];
+    r[3] = r[5] ^ r[1];
+    r[1] = r[6] & POOL[155];
+    r[5] = r[2] ^ POOL[156];
+    r[2] = r[5] ^ POOL[157];
+    r[5] = r[2] ^ POOL[158];
+    r[2] = r[0] ^ POOL[159];
+    r[0] = {
+        let (t, s) = (POOL[160], POOL[161]);
+        let (ma, mb) = (t ^ s ^ POOL[162], t ^ s ^ POOL[163]);
+        let mut z = t;
+        z ^= r[5] & mb;
+        z ^= r[2] & ma;
+        z ^= r[5] & r[2];
+        z
+    };
+    r[2] = r[1] ^ r[0];
+    r[0] = r[2].rotate_left(1);
+    r[2] = r[4] & POOL[164];
+    r[1] = r[2] ^ r[0];
+    r[5] = r[3] ^ r[1];
+    r[1] = r[6] & POOL[165];
+    r[3] = r[2] ^ POOL[166];
+    r[2] = r[3] ^ POOL[167];
+    r[3] = r[2] ^ POOL[168];
+    r[2] = r[0] ^ POOL[169];
+    r[0] = {
+        let (t, s) = (POOL[170], POOL[171]);
+        let (ma, mb) = (t ^ s ^ POOL[172], t ^ s ^ POOL[173]);
+        let mut z = t;
+        z ^= r[3] & mb;
+        z ^= r[2] & ma;
+        z ^= r[3] & r[2];
+        z
+    };
+    r[2] = r[1] ^ r[0];
+    r[0] = r[2].rotate_left(1);
+    r[2] = r[4] & POOL[174];
+    r[1] = r[2] ^ r[0];
+    r[3] = r[5] ^ r[1];
+    r[1] = r[6] & POOL[175];
+    r[5] = r[2] ^ POOL[176];
+    r[2] = r[5] ^ POOL[177];
+    r[5] = r[2] ^ POOL[178];
+    r[2] = r[0] ^ POOL[179];
+    r[0] = {
+        let (t, s) = (POOL[180], POOL[181]);
+        let (ma, mb) = (t ^ s ^ POOL[182], t ^ s ^ POOL[183]);
+        let mut z = t;
+        z ^= r[5] & mb;
+        z ^= r[2] & ma;
+        z ^= r[5] & r[2];
+        z
+    };
+    r[2] = r[1] ^ r[0];
+    r[0] = r[2].rotate_left(1);
+    r[2] = r[4] & POOL[184];
+    r[1] = r[2] ^ r[0];
+    r[5] = r[3] ^ r[1];
+    r[1] = r[6] & POOL[185];
+    r[3] = r[2] ^ POOL[186];
+    r[2] = r[3] ^ POOL[187];
+    r[3] = r[2] ^ POOL[188];
+    r[2] = r[0] ^ POOL[189];
+    r[0] = {
+        let (t, s) = (POOL[190], POOL[191]);
+        let (ma, mb) = (t ^ s ^ POOL[192], t ^ s ^ POOL[193]);
+        let mut z = t;
+        z ^= r[3] & mb;
+        z ^= r[2] & ma;
+        z ^= r[3] & r[2];
+        z
+    };
+    r[2] = r[1] ^ r[0];
+    r[0] = r[2].rotate_left(1);
+    r[2] = r[4] & POOL[194];
+    r[1] = r[2] ^ r[0];
+    r[3] = r[5] ^ r[1];
+    r[1] = r[6] & POOL[195];
+    r[5] = r[2] ^ POOL[196];
+    r[2] = r[5] ^ POOL[197];
+    r[5] = r[2] ^ POOL[198];
+    r[2] = r[0] ^ POOL[199];
+    r[0] = {
+        let (t, s) = (POOL[200], POOL[201]);
+        let (ma, mb) = (t ^ s ^ POOL[202], t ^ s ^ POOL[203]);
+        let mut z = t;
+        z ^= r[5] & mb;
+        z ^= r[2] & ma;
+        z ^= r[5] & r[2];
+        z
+    };
+    r[2] = r[1] ^ r[0];
+    r[0] = r[2].rotate_left(1);
+    r[2] = r[4] & POOL[204];
+    r[1] = r[2] ^ r[0];
+    r[5] = r[3] ^ r[1];
+    r[1] = r[6] & POOL[205];
+    r[3] = r[2] ^ POOL[206];
+    r[2] = r[3] ^ POOL[207];
+    r[3] = r[2] ^ POOL[208];
+    r[2] = r[0] ^ POOL[209];
+    r[0] = {
+        let (t, s) = (POOL[210], POOL[211]);
+        let (ma, mb) = (t ^ s ^ POOL[212], t ^ s ^ POOL[213]);
+        let mut z = t;
+        z ^= r[3] & mb;
+        z ^= r[2] & ma;
+        z ^= r[3] & r[2];
+        z
+    };
+    r[2] = r[1] ^ r[0];
+    r[0] = r[2].rotate_left(1);
+    r[2] = r[4] & POOL[214];
+    r[1] = r[2] ^ r[0];
+    r[3] = r[5] ^ r[1];
+    r[1] = r[6] & POOL[215];
+    r[5] = r[2] ^ POOL[216];
+    r[2] = r[5] ^ POOL[217];
+    r[5] = r[2] ^ POOL[218];
+    r[2] = r[0] ^ POOL[219];
+    r[0] = {
+        let (t, s) = (POOL[220], POOL[221]);
+        let (ma, mb) = (t ^ s ^ POOL[222], t ^ s ^ POOL[223]);
+        let mut z = t;
+        z ^= r[5] & mb;
+        z ^= r[2] & ma;
+        z ^= r[5] & r[2];
+        z
+    };
+    r[2] = r[1] ^ r[0];
+    r[0] = r[2].rotate_left(1);
+    r[2] = r[4] & POOL[224];
+    r[1] = r[2] ^ r[0];
+    r[5] = r[3] ^ r[1];
+    r[1] = r[6] & POOL[225];
+    r[3] = r[2] ^ POOL[226];
+    r[2] = r[3] ^ POOL[227];
+    r[3] = r[2] ^ POOL[228];
+    r[2] = r[0] ^ POOL[229];
+    r[0] = {
+        let (t, s) = (POOL[230], POOL[231]);
+        let (ma, mb) = (t ^ s ^ POOL[232], t ^ s ^ POOL[233]);
+        let mut z = t;
+        z ^= r[3] & mb;
+        z ^= r[2] & ma;
+        z ^= r[3] & r[2];
+        z
+    };
+    r[2] = r[1] ^ r[0];
+    r[0] = r[2].rotate_left(1);
+    r[2] = r[4] & POOL[234];
+    r[1] = r[2] ^ r[0];
+    r[3] = r[5] ^ r[1];
+    r[1] = r[6] & POOL[235];
+    r[5] = r[2] ^ POOL[236];
+    r[2] = r[5] ^ POOL[237];
+    r[5] = r[2] ^ POOL[238];
+    r[2] = r[0] ^ POOL[239];
+    r[0] = {
+        let (t, s) = (POOL[240], POOL[241]);
+        let (ma, mb) = (t ^ s ^ POOL[242], t ^ s ^ POOL[243]);
+        let mut z = t;
+        z ^= r[5] & mb;
+        z ^= r[2] & ma;
+        z ^= r[5] & r[2];
+        z
+    };
+    r[2] = r[1] ^ r[0];
+    r[0] = r[2].rotate_left(1);
+    r[2] = r[4] & POOL[244];
+    r[1] = r[2] ^ r[0];
+    r[5] = r[3] ^ r[1];
+    r[1] = r[6] & POOL[245];
+    r[3] = r[2] ^ POOL[246];
+    r[2] = r[3] ^ POOL[247];
+    r[3] = r[2] ^ POOL[248];
+    r[2] = r[0] ^ POOL[249];
+    r[0] = {
+        let (t, s) = (POOL[250], POOL[251]);
+        let (ma, mb) = (t ^ s ^ POOL[252], t ^ s ^ POOL[253]);
+        let mut z = t;
+        z ^= r[3] & mb;
+        z ^= r[2] & ma;
+        z ^= r[3] & r[2];
+        z
+    };
+    r[2] = r[1] ^ r[0];
+    r[0] = r[2].rotate_left(1);
+    r[2] = r[4] & POOL[254];
+    r[1] = r[2] ^ r[0];
+    r[3] = r[5] ^ r[1];
+    r[1] = r[6] & POOL[255];
+    r[5] = r[2] ^ POOL[256];
+    r[2] = r[5] ^ POOL[257];
+    r[5] = r[2] ^ POOL[258];
+    r[2] = r[0] ^ POOL[259];
+    r[0] = {
+        let (t, s) = (POOL[260], POOL[261]);
+        let (ma, mb) = (t ^ s ^ POOL[262], t ^ s ^ POOL[263]);
+        let mut z = t;
+        z ^= r[5] & mb;
+        z ^= r[2] & ma;
+        z ^= r[5] & r[2];
+        z
+    };
+    r[2] = r[1] ^ r[0];
+    r[0] = r[2].rotate_left(1);
+    r[2] = r[4] & POOL[264];
+    r[1] = r[2] ^ r[0];
+    r[5] = r[3] ^ r[1];
+    r[1] = r[6] & POOL[265];
+    r[3] = r[2] ^ POOL[266];
+    r[2] = r[3] ^ POOL[267];
+    r[3] = r[2] ^ POOL[268];
+    r[2] = r[0] ^ POOL[269];
+    r[0] = {
+        let (t, s) = (POOL[270], POOL[271]);
+        let (ma, mb) = (t ^ s ^ POOL[272], t ^ s ^ POOL[273]);
+        let mut z = t;
+        z ^= r[3] & mb;
+        z ^= r[2] & ma;
+        z ^= r[3] & r[2];
+        z
+    };
+    r[2] = r[1] ^ r[0];
+    r[0] = r[2].rotate_left(1);
+    r[2] = r[4] & POOL[274];
+    r[1] = r[2] ^ r[0];
+    r[3] = r[5] ^ r[1];
+    r[1] = r[6] & POOL[275];
+    r[5] = r[2] ^ POOL[276];
+    r[2] = r[5] ^ POOL[277];
+    r[5] = r[2] ^ POOL[278];
+    r[2] = r[0] ^ POOL[279];
+    r[0] = {
+        let (t, s) = (POOL[280], POOL[281]);
+        let (ma, mb) = (t ^ s ^ POOL[282], t ^ s ^ POOL[283]);
+        let mut z = t;
+        z ^= r[5] & mb;
+        z ^= r[2] & ma;
+        z ^= r[5] & r[2];
+        z
+    };
+    r[2] = r[1] ^ r[0];
+    r[0] = r[2].rotate_left(1);
+    r[2] = r[4] & POOL[284];
+    r[1] = r[2] ^ r[0];
+    r[5] = r[3] ^ r[1];
+    r[1] = r[6] & POOL[285];
+    r[3] = r[2] ^ POOL[286];
+    r[2] = r[3] ^ POOL[287];
+    r[3] = r[2] ^ POOL[288];
+    r[2] = r[0] ^ POOL[289];
+    r[0] = {
+        let (t, s) = (POOL[290], POOL[291]);
+        let (ma, mb) = (t ^ s ^ POOL[292], t ^ s ^ POOL[293]);
+        let mut z = t;
+        z ^= r[3] & mb;
+        z ^= r[2] & ma;
+        z ^= r[3] & r[2];
+        z
+    };
+    r[2] = r[1] ^ r[0];
+    r[0] = r[2].rotate_left(1);
+    r[2] = r[4] & POOL[294];
+    r[1] = r[2] ^ r[0];
+    r[3] = r[5] ^ r[1];
+    r[1] = r[6] & POOL[295];
+    r[5] = r[2] ^ POOL[296];
+    r[2] = r[5] ^ POOL[297];
+    r[5] = r[2] ^ POOL[298];
+    r[2] = r[0] ^ POOL[299];
+    r[0] = {
+        let (t, s) = (POOL[300], POOL[301]);
+        let (ma, mb) = (t ^ s ^ POOL[302], t ^ s ^ POOL[303]);
+        let mut z = t;
+        z ^= r[5] & mb;
+        z ^= r[2] & ma;
+        z ^= r[5] & r[2];
+        z
+    };
+    r[2] = r[1] ^ r[0];
+    r[0] = r[2].rotate_left(1);
+    r[2] = r[4] & POOL[304];
+    r[1] = r[2] ^ r[0];
+    r[5] = r[3] ^ r[1];
+    r[1] = r[6] & POOL[305];
+    r[6] = r[2] ^ POOL[306];
+    r[2] = r[6] ^ POOL[307];
+    r[6] = r[2] ^ POOL[308];
+    r[2] = r[0] ^ POOL[309];
+    r[0] = {
+        let (t, s) = (POOL[310], POOL[311]);
+        let (ma, mb) = (t ^ s ^ POOL[312], t ^ s ^ POOL[313]);
+        let mut z = t;
+        z ^= r[6] & mb;
+        z ^= r[2] & ma;
+        z ^= r[6] & r[2];
+        z
+    };
+    r[2] = r[1] ^ r[0];
+    r[0] = r[2].rotate_left(1);
+    r[2] = r[4] & POOL[314];
+    r[6] = r[2] ^ r[0];
+    r[0] = r[5] ^ r[6];
+    r[2] = r[0] ^ POOL[315];
+    r[2] ^ POOL[316]
+}
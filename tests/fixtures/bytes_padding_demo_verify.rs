@@ -0,0 +1,231 @@
+// Generated by xorpl — do not edit manually.
+pub const EXPR_DIGEST: [u8; 32] = [
+    0xe9, 0xca, 0xc4, 0x7a, 0x43, 0x17, 0x13, 0xe4,
+    0x3b, 0x2a, 0x1a, 0x6f, 0x86, 0x8b, 0x1e, 0x7a,
+    0xe0, 0x72, 0x4b, 0x37, 0x78, 0x3a, 0xa0, 0x43,
+    0x7d, 0x2f, 0xa0, 0xfd, 0x44, 0x4a, 0x6b, 0x3f,
+];
+pub fn bytes_padding_demo_verify(input_msg: &[u8; 7]) -> u32 {
+    let w0 = u32::from_le_bytes([input_msg[0], input_msg[1], input_msg[2], input_msg[3]]);
+    let w1 = w0.rotate_left(7);
+    let w2 = u32::from_le_bytes([input_msg[4], input_msg[5], input_msg[6], 0]);
+    let w3 = w1 & w2;
+    let w4 = w1 ^ w2;
+    let w5 = w4 & 0x00000001u32;
+    let w6 = w3 & 0x00000001u32;
+    let w7 = w6.rotate_left(1);
+    let w8 = w4 & 0x00000002u32;
+    let w9 = w8 ^ w7;
+    let w10 = w5 ^ w9;
+    let w11 = w3 & 0x00000002u32;
+    let w12 = w8 & w7;
+    let w13 = w11 ^ w12;
+    let w14 = w13.rotate_left(1);
+    let w15 = w4 & 0x00000004u32;
+    let w16 = w15 ^ w14;
+    let w17 = w10 ^ w16;
+    let w18 = w3 & 0x00000004u32;
+    let w19 = w15 & w14;
+    let w20 = w18 ^ w19;
+    let w21 = w20.rotate_left(1);
+    let w22 = w4 & 0x00000008u32;
+    let w23 = w22 ^ w21;
+    let w24 = w17 ^ w23;
+    let w25 = w3 & 0x00000008u32;
+    let w26 = w22 & w21;
+    let w27 = w25 ^ w26;
+    let w28 = w27.rotate_left(1);
+    let w29 = w4 & 0x00000010u32;
+    let w30 = w29 ^ w28;
+    let w31 = w24 ^ w30;
+    let w32 = w3 & 0x00000010u32;
+    let w33 = w29 & w28;
+    let w34 = w32 ^ w33;
+    let w35 = w34.rotate_left(1);
+    let w36 = w4 & 0x00000020u32;
+    let w37 = w36 ^ w35;
+    let w38 = w31 ^ w37;
+    let w39 = w3 & 0x00000020u32;
+    let w40 = w36 & w35;
+    let w41 = w39 ^ w40;
+    let w42 = w41.rotate_left(1);
+    let w43 = w4 & 0x00000040u32;
+    let w44 = w43 ^ w42;
+    let w45 = w38 ^ w44;
+    let w46 = w3 & 0x00000040u32;
+    let w47 = w43 & w42;
+    let w48 = w46 ^ w47;
+    let w49 = w48.rotate_left(1);
+    let w50 = w4 & 0x00000080u32;
+    let w51 = w50 ^ w49;
+    let w52 = w45 ^ w51;
+    let w53 = w3 & 0x00000080u32;
+    let w54 = w50 & w49;
+    let w55 = w53 ^ w54;
+    let w56 = w55.rotate_left(1);
+    let w57 = w4 & 0x00000100u32;
+    let w58 = w57 ^ w56;
+    let w59 = w52 ^ w58;
+    let w60 = w3 & 0x00000100u32;
+    let w61 = w57 & w56;
+    let w62 = w60 ^ w61;
+    let w63 = w62.rotate_left(1);
+    let w64 = w4 & 0x00000200u32;
+    let w65 = w64 ^ w63;
+    let w66 = w59 ^ w65;
+    let w67 = w3 & 0x00000200u32;
+    let w68 = w64 & w63;
+    let w69 = w67 ^ w68;
+    let w70 = w69.rotate_left(1);
+    let w71 = w4 & 0x00000400u32;
+    let w72 = w71 ^ w70;
+    let w73 = w66 ^ w72;
+    let w74 = w3 & 0x00000400u32;
+    let w75 = w71 & w70;
+    let w76 = w74 ^ w75;
+    let w77 = w76.rotate_left(1);
+    let w78 = w4 & 0x00000800u32;
+    let w79 = w78 ^ w77;
+    let w80 = w73 ^ w79;
+    let w81 = w3 & 0x00000800u32;
+    let w82 = w78 & w77;
+    let w83 = w81 ^ w82;
+    let w84 = w83.rotate_left(1);
+    let w85 = w4 & 0x00001000u32;
+    let w86 = w85 ^ w84;
+    let w87 = w80 ^ w86;
+    let w88 = w3 & 0x00001000u32;
+    let w89 = w85 & w84;
+    let w90 = w88 ^ w89;
+    let w91 = w90.rotate_left(1);
+    let w92 = w4 & 0x00002000u32;
+    let w93 = w92 ^ w91;
+    let w94 = w87 ^ w93;
+    let w95 = w3 & 0x00002000u32;
+    let w96 = w92 & w91;
+    let w97 = w95 ^ w96;
+    let w98 = w97.rotate_left(1);
+    let w99 = w4 & 0x00004000u32;
+    let w100 = w99 ^ w98;
+    let w101 = w94 ^ w100;
+    let w102 = w3 & 0x00004000u32;
+    let w103 = w99 & w98;
+    let w104 = w102 ^ w103;
+    let w105 = w104.rotate_left(1);
+    let w106 = w4 & 0x00008000u32;
+    let w107 = w106 ^ w105;
+    let w108 = w101 ^ w107;
+    let w109 = w3 & 0x00008000u32;
+    let w110 = w106 & w105;
+    let w111 = w109 ^ w110;
+    let w112 = w111.rotate_left(1);
+    let w113 = w4 & 0x00010000u32;
+    let w114 = w113 ^ w112;
+    let w115 = w108 ^ w114;
+    let w116 = w3 & 0x00010000u32;
+    let w117 = w113 & w112;
+    let w118 = w116 ^ w117;
+    let w119 = w118.rotate_left(1);
+    let w120 = w4 & 0x00020000u32;
+    let w121 = w120 ^ w119;
+    let w122 = w115 ^ w121;
+    let w123 = w3 & 0x00020000u32;
+    let w124 = w120 & w119;
+    let w125 = w123 ^ w124;
+    let w126 = w125.rotate_left(1);
+    let w127 = w4 & 0x00040000u32;
+    let w128 = w127 ^ w126;
+    let w129 = w122 ^ w128;
+    let w130 = w3 & 0x00040000u32;
+    let w131 = w127 & w126;
+    let w132 = w130 ^ w131;
+    let w133 = w132.rotate_left(1);
+    let w134 = w4 & 0x00080000u32;
+    let w135 = w134 ^ w133;
+    let w136 = w129 ^ w135;
+    let w137 = w3 & 0x00080000u32;
+    let w138 = w134 & w133;
+    let w139 = w137 ^ w138;
+    let w140 = w139.rotate_left(1);
+    let w141 = w4 & 0x00100000u32;
+    let w142 = w141 ^ w140;
+    let w143 = w136 ^ w142;
+    let w144 = w3 & 0x00100000u32;
+    let w145 = w141 & w140;
+    let w146 = w144 ^ w145;
+    let w147 = w146.rotate_left(1);
+    let w148 = w4 & 0x00200000u32;
+    let w149 = w148 ^ w147;
+    let w150 = w143 ^ w149;
+    let w151 = w3 & 0x00200000u32;
+    let w152 = w148 & w147;
+    let w153 = w151 ^ w152;
+    let w154 = w153.rotate_left(1);
+    let w155 = w4 & 0x00400000u32;
+    let w156 = w155 ^ w154;
+    let w157 = w150 ^ w156;
+    let w158 = w3 & 0x00400000u32;
+    let w159 = w155 & w154;
+    let w160 = w158 ^ w159;
+    let w161 = w160.rotate_left(1);
+    let w162 = w4 & 0x00800000u32;
+    let w163 = w162 ^ w161;
+    let w164 = w157 ^ w163;
+    let w165 = w3 & 0x00800000u32;
+    let w166 = w162 & w161;
+    let w167 = w165 ^ w166;
+    let w168 = w167.rotate_left(1);
+    let w169 = w4 & 0x01000000u32;
+    let w170 = w169 ^ w168;
+    let w171 = w164 ^ w170;
+    let w172 = w3 & 0x01000000u32;
+    let w173 = w169 & w168;
+    let w174 = w172 ^ w173;
+    let w175 = w174.rotate_left(1);
+    let w176 = w4 & 0x02000000u32;
+    let w177 = w176 ^ w175;
+    let w178 = w171 ^ w177;
+    let w179 = w3 & 0x02000000u32;
+    let w180 = w176 & w175;
+    let w181 = w179 ^ w180;
+    let w182 = w181.rotate_left(1);
+    let w183 = w4 & 0x04000000u32;
+    let w184 = w183 ^ w182;
+    let w185 = w178 ^ w184;
+    let w186 = w3 & 0x04000000u32;
+    let w187 = w183 & w182;
+    let w188 = w186 ^ w187;
+    let w189 = w188.rotate_left(1);
+    let w190 = w4 & 0x08000000u32;
+    let w191 = w190 ^ w189;
+    let w192 = w185 ^ w191;
+    let w193 = w3 & 0x08000000u32;
+    let w194 = w190 & w189;
+    let w195 = w193 ^ w194;
+    let w196 = w195.rotate_left(1);
+    let w197 = w4 & 0x10000000u32;
+    let w198 = w197 ^ w196;
+    let w199 = w192 ^ w198;
+    let w200 = w3 & 0x10000000u32;
+    let w201 = w197 & w196;
+    let w202 = w200 ^ w201;
+    let w203 = w202.rotate_left(1);
+    let w204 = w4 & 0x20000000u32;
+    let w205 = w204 ^ w203;
+    let w206 = w199 ^ w205;
+    let w207 = w3 & 0x20000000u32;
+    let w208 = w204 & w203;
+    let w209 = w207 ^ w208;
+    let w210 = w209.rotate_left(1);
+    let w211 = w4 & 0x40000000u32;
+    let w212 = w211 ^ w210;
+    let w213 = w206 ^ w212;
+    let w214 = w3 & 0x40000000u32;
+    let w215 = w211 & w210;
+    let w216 = w214 ^ w215;
+    let w217 = w216.rotate_left(1);
+    let w218 = w4 & 0x80000000u32;
+    let w219 = w218 ^ w217;
+    let w220 = w213 ^ w219;
+    w220
+}
@@ -11,4231 +11,6427 @@ pub fn chacha_qr_rotated(a: u32, b: u32, c: u32, d: u32) -> u32 {
         0xbb2a3fb2,
         0xffffffff,
         0xffffffff,
-        0x070f4649,
-        0xcd2c6f7f,
-        0xbb2a3fb2,
+        0x430b0604,
+        0x482615ef,
+        0x741b35a4,
+        0xc9accb14,
+        0xade4b6d0,
+        0x958b761d,
+        0xfe6ebc59,
+        0x3ef201cf,
         0x00000001,
         0x00000001,
         0x00000002,
         0x00000002,
-        0xc6017c94,
-        0x00000000,
-        0x00000002,
-        0x00000004,
+        0xdee17b11,
+        0x9dfce645,
+        0x5c6c5976,
+        0xc5a0ef11,
+        0xd7a39602,
+        0x25fffc5c,
+        0xde8fe5c5,
+        0xcb30ce1a,
+        0x0aceeffd,
+        0x2243ae90,
         0x00000004,
-        0xcf310a12,
         0x00000004,
-        0x8c02f92d,
-        0x00000008,
+        0x9e0d7fa8,
+        0x21d9db02,
+        0x386c9cfa,
+        0x6bf28e6c,
+        0x5990773e,
+        0xff0ea77d,
+        0x2126e817,
+        0x5e929da0,
         0x00000008,
-        0x069dc10a,
         0x00000008,
-        0x9e62142d,
+        0x48501808,
+        0x6bfe347b,
+        0x287a9bc0,
+        0xafd44686,
+        0xf552b66e,
+        0xf6ff78ef,
+        0x0879793a,
+        0x1f4976fa,
         0x00000010,
         0x00000010,
-        0xabe5f6d0,
-        0x00000000,
-        0x0d3b8214,
+        0x34bb13f0,
+        0x381e6892,
+        0x629eadc0,
+        0x97d6c32f,
+        0xcadbf41a,
+        0x75e88c63,
+        0xd108aedb,
+        0xd3c9b7fd,
         0x00000020,
         0x00000020,
-        0x958b761d,
-        0x00000000,
-        0x57cbed81,
-        0x00000040,
+        0x30f0a328,
+        0xdc6cd914,
+        0x12493486,
+        0x77dd30e5,
+        0x5f6d584d,
+        0x8f3066bc,
+        0x2e88704b,
+        0xea426b01,
         0x00000040,
-        0xdee17b51,
         0x00000040,
-        0x2b16ec7b,
-        0x00000080,
+        0xaf976aab,
+        0x8850fff4,
+        0x9f55357b,
+        0x1f137583,
+        0xe66d4fd1,
+        0x345f0997,
+        0x6aa0e622,
+        0x07d10a3f,
         0x00000080,
-        0x431d9dd4,
         0x00000080,
-        0xbdc2f6a3,
+        0x135f3e73,
+        0x93922a5b,
+        0x61935a25,
+        0x4e9d1432,
+        0x1e150e58,
+        0x7a95e009,
+        0x85dea0dc,
+        0xc7c36581,
         0x00000100,
         0x00000100,
-        0x1f71c422,
-        0x00000000,
-        0x863b3aa8,
+        0x582b922f,
+        0xb86d04c8,
+        0x2b6fb3cd,
+        0xa04d91cd,
+        0x7143007e,
+        0x102ff028,
+        0xaa45d57c,
+        0xbf8f692a,
         0x00000200,
         0x00000200,
-        0xc5a0ef11,
-        0x00000000,
-        0x3ee38a44,
+        0xecdca315,
+        0x10787f2d,
+        0xd5ff8f4b,
+        0x41440926,
+        0x4edc7d04,
+        0xfa202be2,
+        0x9da70595,
+        0x837c5d6d,
         0x00000400,
         0x00000400,
-        0x12037913,
-        0x00000000,
-        0x8b41de23,
+        0x4c0bcc72,
+        0xa6d6549c,
+        0x89bf384b,
+        0x72e5cd1f,
+        0x404e4995,
+        0x03d8ae10,
+        0x20f44720,
+        0xeecbd294,
         0x00000800,
         0x00000800,
-        0x37fc854f,
-        0x00000000,
-        0x2406f226,
-        0x00001000,
+        0xd192ea97,
+        0xc02a68ad,
+        0xcaa57fd9,
+        0x5c23ba00,
+        0x2974f98d,
+        0x9d1ffa92,
+        0x6f76fefc,
+        0x2a546237,
         0x00001000,
-        0xc9ff71c7,
         0x00001000,
-        0x6ff91a9e,
+        0xabf1ae91,
+        0x62f4e8f3,
+        0x9a2a910a,
+        0xcca49d49,
+        0xdf7f0d8e,
+        0x159ba8db,
+        0x99cb623d,
+        0x60930947,
         0x00002000,
         0x00002000,
-        0xcb30ce1a,
-        0x00000000,
-        0x93fec38f,
-        0x00004000,
+        0xd0efd947,
+        0xc5916a57,
+        0xe1150f7e,
+        0x3e7b7e07,
+        0x582170dc,
+        0xc0c08aa8,
+        0x6c8a461a,
+        0x1c2bb76e,
         0x00004000,
-        0x9e0d3fac,
         0x00004000,
-        0x9661dc35,
+        0x410102c3,
+        0x9f273616,
+        0xf4494190,
+        0xaebbfc29,
+        0x3e34c19f,
+        0x59e3238a,
+        0x4db8d750,
+        0x71baa791,
         0x00008000,
         0x00008000,
-        0xbfd4a4ae,
-        0x00000000,
-        0x3c1a7f59,
+        0xe5db98b8,
+        0xca2dd6a3,
+        0xcd6fa50b,
+        0xfd0dcc2e,
+        0x1f1d334d,
+        0xfa077d26,
+        0x0783a57b,
+        0x60a48b73,
         0x00010000,
         0x00010000,
-        0x87b83854,
-        0x00000000,
-        0x7fa9495d,
-        0x00020000,
+        0x69194a02,
+        0x2a90dc39,
+        0x24cb3a0d,
+        0x7f00c5ab,
+        0x3bb28dde,
+        0xfc2699b7,
+        0xa0d6b85f,
+        0x879e3579,
         0x00020000,
-        0xf80e4de3,
         0x00020000,
-        0x0f7270a9,
-        0x00040000,
+        0xffd55ad9,
+        0x55a8892c,
+        0xb2a00188,
+        0x5b6fcfd5,
+        0x97ab989b,
+        0xcab2a0b3,
+        0x45c6ea55,
+        0xf115ac61,
         0x00040000,
-        0xd99c7f7e,
         0x00040000,
-        0xf01c9bc7,
+        0xb254cb87,
+        0xf8b597e4,
+        0x87670bc2,
+        0x9d228d8e,
+        0x6a01310a,
+        0x08c8f3bd,
+        0xaf4b9516,
+        0xc0a55e9c,
         0x00080000,
         0x00080000,
-        0xff0ea77d,
-        0x00000000,
-        0xb338fefd,
+        0xeab81afa,
+        0xffde0d69,
+        0x169df7b4,
+        0xe586af0d,
+        0xbec548fd,
+        0xa7e71c18,
+        0x1ad9b4c2,
+        0x28aeb1bf,
         0x00100000,
         0x00100000,
-        0x48501800,
-        0x00000000,
-        0xfe1d4efb,
+        0xbe1bb62a,
+        0x2ac13dd1,
+        0x9ba6806e,
+        0x5154eb23,
+        0xd2bb08e2,
+        0x375b2523,
+        0xea9c2654,
+        0xca2f971d,
         0x00200000,
         0x00200000,
-        0x23ae2c7b,
-        0x00000000,
-        0x90a03000,
+        0xba649cea,
+        0xebf40308,
+        0x1c050a9a,
+        0xe18d27bf,
+        0xd563d410,
+        0x8e89bfb9,
+        0x167ffed1,
+        0x018948fb,
         0x00400000,
         0x00400000,
-        0x0bd4b7bb,
-        0x00000000,
-        0x471c58f6,
+        0x3267ea3d,
+        0x900a252f,
+        0xd116e8a3,
+        0x3e1a91eb,
+        0x93a20846,
+        0x154b75d8,
+        0xf5925a2f,
+        0x827646f4,
         0x00800000,
         0x00800000,
-        0x1ce4b87b,
-        0x00000000,
-        0x17a96f76,
+        0x30a0e916,
+        0x62485f06,
+        0xe9cbef6c,
+        0x191d2b11,
+        0x316329d0,
+        0x09edd789,
+        0x83ada725,
+        0x06618385,
         0x01000000,
         0x01000000,
-        0xfd960655,
-        0x00000000,
-        0x39c970f6,
-        0x02000000,
+        0x980b1fc7,
+        0x725c62d7,
+        0xabcb7e9f,
+        0x669fc3e7,
+        0x692457af,
+        0x9c2b1c95,
+        0xb49348b5,
+        0x841069c5,
         0x02000000,
-        0xf4ff78ef,
         0x02000000,
-        0xfb2c0cab,
+        0x6c53e9e1,
+        0x6263be6c,
+        0x3d468452,
+        0x9f774eaf,
+        0x8ad41446,
+        0xd0541386,
+        0x6bf6d41f,
+        0x9787e32f,
         0x04000000,
         0x04000000,
-        0x34bb13f0,
-        0x04000000,
-        0xe9fef1df,
+        0x5d6cfbb1,
+        0x8e35a4a9,
+        0x18d20cf2,
+        0xf9d2c946,
+        0x3f8d1a21,
+        0xc3fe4c62,
+        0x33f805a9,
+        0x16043616,
         0x08000000,
         0x08000000,
-        0x0ca57b62,
-        0x00000000,
-        0x617627e0,
-        0x10000000,
+        0x0fd0104d,
+        0xeee5d8eb,
+        0xe4c32a6a,
+        0xb13faf90,
+        0xf39f5c3b,
+        0xe40ce9ae,
+        0x12655759,
+        0x4db0aec6,
         0x10000000,
-        0x6e3bd6a2,
         0x10000000,
-        0x094af6c4,
-        0x20000000,
+        0x3659f3d5,
+        0xacd14cbb,
+        0x7849750e,
+        0xec2236d8,
+        0x9ea51cb3,
+        0x49876c8c,
+        0x25e3ba5f,
+        0xcc7afa10,
         0x20000000,
-        0x6cfacf84,
         0x20000000,
-        0xdc77ad44,
-        0x40000000,
+        0xfbc6d480,
+        0x87b005ce,
+        0xe0dcda69,
+        0xdcf3a8df,
+        0x8f10a011,
+        0xdb8bc196,
+        0xe8316aa0,
+        0x91a3e47f,
         0x40000000,
-        0xe6e1329a,
         0x40000000,
-        0xd9f59f08,
+        0x36ea4d61,
+        0x5a6fee6d,
+        0x7a0f9a84,
+        0xd58e944a,
+        0xa656a4cf,
+        0x32e5c923,
+        0xc2395464,
+        0x174cbbc4,
         0x80000000,
         0xffffffff,
-        0x75e88c63,
-        0x30f0a328,
-        0xfddcf67d,
-        0x75e88c63,
-        0x9354dfc1,
+        0x66a817f7,
+        0x1b6e1a98,
+        0x1dec15a7,
+        0x3367a31f,
+        0xd21f2402,
+        0x12cd93a6,
+        0xfbdad444,
+        0xa134e6ad,
+        0xb9500150,
         0x00000001,
         0x00000001,
         0x00000002,
         0x00000002,
-        0xfed54eba,
-        0x00000002,
-        0x00000000,
+        0x4806d463,
+        0x81850aad,
+        0x863ae8c7,
+        0xee7f88eb,
+        0x39838c58,
+        0x9af2c296,
+        0x92112f29,
+        0x1143f20e,
+        0xccebeb2c,
+        0xce5c1b02,
         0x00000004,
         0x00000004,
-        0x3a1f55f0,
-        0x00000000,
-        0xfdaa9d75,
+        0x7f8ad0dd,
+        0xc93ee0ca,
+        0x38c59019,
+        0x91ed5508,
+        0xc87663b3,
+        0x3bd545db,
+        0x7dd28662,
+        0xdc7c2131,
         0x00000008,
         0x00000008,
-        0x65781cfd,
-        0x00000000,
-        0x743eabe8,
+        0x85729031,
+        0x9be5d92d,
+        0x0ade631d,
+        0xe68ed082,
+        0xfa2bbd72,
+        0x88a3d149,
+        0x66c1463a,
+        0x18487bc6,
         0x00000010,
         0x00000010,
-        0x8f3066bc,
-        0x00000000,
-        0xcaf039ea,
-        0x00000020,
+        0xc1e1c809,
+        0xf60022a8,
+        0x01853ebe,
+        0xd3eb9979,
+        0x9ead7556,
+        0x49527f66,
+        0xe19bde2f,
+        0xf0c3edae,
         0x00000020,
-        0xaf976aeb,
         0x00000020,
-        0x1e60cd59,
+        0xfc8a2528,
+        0xad14bb9c,
+        0x7dbab48f,
+        0xfcc451a7,
+        0x4cdcce0f,
+        0x970ff281,
+        0xf7f716b5,
+        0x56040fb8,
         0x00000040,
         0x00000040,
-        0x27c7951f,
-        0x00000000,
-        0x5f2ed597,
-        0x00000080,
+        0x58421aee,
+        0x1f2c0625,
+        0x0ff622e3,
+        0x224fd1a3,
+        0xe2ef3a4e,
+        0x7da149ec,
+        0xd7d64dca,
+        0x3cb07a3b,
         0x00000080,
-        0xb892a064,
         0x00000080,
-        0x4f8f2a3e,
+        0x9946bde5,
+        0xb5faae23,
+        0x1fd5b244,
+        0xbedaec40,
+        0xbb45cfd9,
+        0x4365c98a,
+        0xcb49a7d1,
+        0x82148e9e,
         0x00000100,
         0x00000100,
-        0xd5e34c79,
-        0x00000100,
-        0x712540c9,
-        0x00000200,
+        0x7f614465,
+        0xb7220c8f,
+        0xe8944c55,
+        0x787e3045,
+        0xf254bf8c,
+        0xab4a8935,
+        0x79c93306,
+        0x33aa994f,
         0x00000200,
-        0x76ef4fb1,
         0x00000200,
-        0xabc698f3,
+        0x4fc6bd72,
+        0x49abf6ad,
+        0xcf017e3b,
+        0x62def82a,
+        0x67b1a8f1,
+        0x02fd55ad,
+        0xac20c8b8,
+        0xa2137303,
         0x00000400,
         0x00000400,
-        0x345f0997,
-        0x00000000,
-        0xedde9b62,
+        0xe1feeae5,
+        0x84bbd76e,
+        0xf13ff557,
+        0xd0e049a8,
+        0xe74dce53,
+        0xee87013f,
+        0x9db003b0,
+        0x94d1dbaf,
         0x00000800,
         0x00000800,
-        0x135f3ef3,
-        0x00000000,
-        0x68be132e,
-        0x00001000,
+        0x371c60ab,
+        0x76fee18b,
+        0x20663df0,
+        0x9e60e908,
+        0xee3579c6,
+        0xd49e40d8,
+        0x5b2f85ce,
+        0x42644530,
         0x00001000,
-        0x80cd14a8,
         0x00001000,
-        0x26be6de6,
+        0x9df059c2,
+        0xf3c756a0,
+        0x2e6a2070,
+        0x7c73c1c5,
+        0xbabbd7eb,
+        0x588fdd3b,
+        0xa26935c2,
+        0x83254098,
         0x00002000,
         0x00002000,
-        0xe15e4e8d,
-        0x00000000,
-        0x019a0951,
+        0x1155f7e0,
+        0x55870dad,
+        0x4aeb6199,
+        0x72a2a1b3,
+        0xd03e8158,
+        0x7d1931c4,
+        0xa31e0b48,
+        0x2ad08af8,
         0x00004000,
         0x00004000,
-        0xa343cbd0,
-        0x00004000,
-        0xc2bcdd1b,
+        0x1d579506,
+        0xfd872b28,
+        0xa5ae8c2f,
+        0xb203f268,
+        0x0369cb56,
+        0x29a4223f,
+        0x6fb39b68,
+        0x34d16d38,
         0x00008000,
         0x00008000,
-        0xbf5704d8,
-        0x00000000,
-        0x468797a1,
+        0xa72528e9,
+        0x1e5d062f,
+        0xbfd2fc5b,
+        0x863b985c,
+        0x0716470b,
+        0x0e57a183,
+        0x0feb3415,
+        0x8191e87a,
         0x00010000,
         0x00010000,
-        0x7a95e009,
-        0x00000000,
-        0x7eae09b1,
+        0x65f59809,
+        0x23362b8e,
+        0x7e768b1e,
+        0x9f58e53f,
+        0x46b5f48c,
+        0xd6c119bd,
+        0xa8c1d5a8,
+        0x00018338,
         0x00020000,
         0x00020000,
-        0x582b922f,
-        0x00000000,
-        0xf52bc012,
-        0x00040000,
+        0xe0253871,
+        0xbf178939,
+        0x60bb2afc,
+        0x00fced19,
+        0x98079290,
+        0xa21606fd,
+        0x05980fd9,
+        0x97ecc07e,
         0x00040000,
-        0xe04296e7,
         0x00040000,
-        0xb057245e,
+        0x24229b92,
+        0xebe87e35,
+        0x66dece64,
+        0x1fcec93f,
+        0x82f350d3,
+        0xd0316f86,
+        0xfbd61496,
+        0x2615f36b,
         0x00080000,
         0x00080000,
-        0xcb29252a,
-        0x00080000,
-        0xc0852dcf,
-        0x00100000,
+        0xddf17ac9,
+        0xa1426e6b,
+        0x7ce77629,
+        0xeb9b5533,
+        0x5df55eaf,
+        0x6088b870,
+        0x3d298454,
+        0x9320024f,
         0x00100000,
-        0xdee3997c,
         0x00100000,
-        0x96424a55,
+        0xbd74afbc,
+        0x9344b0f8,
+        0x242d26d0,
+        0xd4461181,
+        0xa37e8995,
+        0xc6a145f0,
+        0x6fd2f5f1,
+        0x0acba1ba,
         0x00200000,
         0x00200000,
-        0xbb420156,
-        0x00200000,
-        0xbde732f9,
+        0x4e038b8d,
+        0x3ffd3146,
+        0xe01f83d5,
+        0x7fb05f16,
+        0x86956195,
+        0x8a272803,
+        0x9d737088,
+        0x21f75683,
         0x00400000,
         0x00400000,
-        0x102ff028,
-        0x00000000,
-        0x76c402ad,
+        0xf3246c2b,
+        0x06797bae,
+        0x53eabfa9,
+        0x22289457,
+        0x3287a10d,
+        0xb084c2d6,
+        0x24f4cbf7,
+        0xaf83068f,
         0x00800000,
         0x00800000,
-        0xecdca315,
-        0x00800000,
-        0x205fe050,
+        0xb3c0f0c6,
+        0x9400bb47,
+        0xce18aebd,
+        0xe377d05b,
+        0x668059d9,
+        0x41aefd37,
+        0xce7641d2,
+        0xe8d676a7,
         0x01000000,
         0x01000000,
-        0xfca4dc38,
-        0x00000000,
-        0xd8b9462b,
+        0x230e219c,
+        0x878c0927,
+        0x39ab0a7a,
+        0xcbe0f74a,
+        0x4f254ba9,
+        0x30c45281,
+        0xe2c83be9,
+        0xeab0ddc1,
         0x02000000,
         0x02000000,
-        0x295b5373,
-        0x02000000,
-        0xf949b871,
-        0x04000000,
+        0xa250c46e,
+        0xfa45c569,
+        0x23fc495b,
+        0xb3909210,
+        0x888db01c,
+        0xe3894f12,
+        0x10edb752,
+        0x6cdcfbcf,
         0x04000000,
-        0x37800b8b,
         0x04000000,
-        0x52b6a6e6,
+        0x84604602,
+        0x04abf9d5,
+        0x9284ca3c,
+        0xad4ac19e,
+        0x7b1cd65f,
+        0x998adf27,
+        0xf4d97c93,
+        0x5d57a85f,
         0x08000000,
         0x08000000,
-        0x6fdc7e07,
-        0x00000000,
-        0x67001716,
+        0x7d3743b1,
+        0xf15ca7e9,
+        0x1d2b832f,
+        0xfb55c3a9,
+        0x9c4daca3,
+        0xbc9062af,
+        0xb19da97b,
+        0x0133e35d,
         0x10000000,
         0x10000000,
-        0xfa202be2,
-        0x00000000,
-        0xcfb8fc0e,
-        0x20000000,
+        0x23187b20,
+        0x5123bb21,
+        0x797132bf,
+        0xfdfe47d2,
+        0x71ec6f24,
+        0x435e86ef,
+        0x39f81b75,
+        0xf557bdfc,
         0x20000000,
-        0x6c0bcc72,
         0x20000000,
-        0xf44057c5,
+        0x39c1730d,
+        0x009c18d6,
+        0xb092bba0,
+        0x31535b5d,
+        0xb63df6c5,
+        0xbdaad458,
+        0xa258f2e6,
+        0xff9c07e4,
         0x40000000,
         0x40000000,
-        0xaadd98ee,
-        0x40000000,
-        0xd81798e4,
+        0x6289825a,
+        0xee0ca41a,
+        0x6b98bfbc,
+        0xb2e45cd7,
+        0x6b59a1f4,
+        0xb54fd9bf,
+        0x790be1b7,
+        0x409fc9e4,
         0x80000000,
         0xffffffff,
         0xffffffff,
         0xffffffff,
         0xffffffff,
         0xffffffff,
-        0x206390a9,
-        0xef31307c,
-        0x438d7c8d,
+        0x2e1afbb5,
+        0x8036186d,
+        0xc40f9c17,
+        0xedd197e1,
+        0x7e1a6883,
+        0xfb2342f6,
         0x00000001,
         0x00000001,
         0x00000002,
         0x00000002,
-        0xad5d3511,
-        0x00000000,
-        0x00000002,
+        0x946f0435,
+        0x96eb81a4,
+        0x0fb9d7f7,
+        0x223add86,
+        0x90630a7d,
+        0xf2b6115c,
+        0x0e1a89ae,
+        0x6e24903a,
+        0x6d034bf2,
+        0x20d1df31,
         0x00000004,
         0x00000004,
-        0x610e6994,
-        0x00000000,
-        0x5aba6a23,
+        0x005c532e,
+        0x669cd1cf,
+        0x7c52821d,
+        0xcd78ca74,
+        0xae91c607,
+        0xeecb1c03,
+        0x5ac8dafc,
+        0x914d8760,
         0x00000008,
         0x00000008,
-        0x03d8ae10,
-        0x00000000,
-        0xc21cd320,
-        0x00000010,
+        0x25752591,
+        0x7558eeda,
+        0xbc6a764e,
+        0x45a468d1,
+        0xca190275,
+        0xdde793c3,
+        0xfbb92cbb,
+        0x2f5d75a0,
         0x00000010,
-        0xd192ea97,
         0x00000010,
-        0x07b15c20,
-        0x00000020,
+        0x29163d1a,
+        0x289d463e,
+        0x68cdda15,
+        0x083b40fb,
+        0x9cb83b26,
+        0xcac18325,
+        0x3f3f1932,
+        0x9a77b41b,
         0x00000020,
-        0x11b8821a,
         0x00000020,
-        0xa325d52f,
+        0x2d5d0c98,
+        0xf4807ce5,
+        0x61273a23,
+        0xbaaf74ba,
+        0xba289a8d,
+        0xc764a7f0,
+        0xc5b67703,
+        0x6e9f3f8a,
         0x00000040,
         0x00000040,
-        0xdb1dfde3,
-        0x00000040,
-        0x23710434,
-        0x00000080,
+        0x8b828615,
+        0xa9a63b26,
+        0xd8445383,
+        0x68daa267,
+        0xdd2ec2bf,
+        0xdc961ad3,
+        0xfbd836dc,
+        0x3c974bfc,
         0x00000080,
-        0x9e3f61a8,
         0x00000080,
-        0xb63bfbc7,
+        0xdfa547cd,
+        0xcffd4977,
+        0x7fc280f4,
+        0x86b9239c,
+        0x67bc638b,
+        0xf3efde12,
+        0xfbc93357,
+        0xd8f61e5a,
         0x00000100,
         0x00000100,
-        0xb36998ad,
-        0x00000000,
-        0x3c7ec351,
+        0x30dd90fa,
+        0x1b5633ea,
+        0xcdb9c012,
+        0xe312205d,
+        0xb4afaa8c,
+        0xa3a3d5f6,
+        0xf13e1d78,
+        0xa2679db5,
         0x00000200,
         0x00000200,
-        0x9d1ffa92,
-        0x00000000,
-        0x66d3315b,
-        0x00000400,
+        0xcceb9cfa,
+        0xcfff3ca8,
+        0xb2309632,
+        0xbfa50a71,
+        0x44f65bec,
+        0x9e61393a,
+        0x6bb354b6,
+        0x4429fbbb,
         0x00000400,
-        0xabf1ba91,
         0x00000400,
-        0x3a3ff525,
+        0x4939d475,
+        0xd706531c,
+        0x084654dc,
+        0x370f0a30,
+        0x8f43f146,
+        0xedd29f21,
+        0xf4e8bdd2,
+        0xfc3af34e,
         0x00000800,
         0x00000800,
-        0xc9055e62,
-        0x00000800,
-        0x57e37d23,
+        0x83148a6f,
+        0x128ce8e0,
+        0xeb74dbb0,
+        0x92b32c3b,
+        0x6e0af594,
+        0xb61d31fa,
+        0xa2fb7551,
+        0x7870209b,
         0x00001000,
         0x00001000,
-        0x532fc768,
-        0x00000000,
-        0x920aacc5,
+        0xf8608973,
+        0x932ee796,
+        0x61e22de1,
+        0xa8a04621,
+        0x7bb02615,
+        0xe752af20,
+        0x964eca31,
+        0xa89e7456,
         0x00002000,
         0x00002000,
-        0xaa77ac12,
-        0x00000000,
-        0xa65f8ed0,
-        0x00004000,
+        0x46b95893,
+        0x8467607f,
+        0xf11afca9,
+        0x3c97c5b6,
+        0x6ffd7b9f,
+        0xa5bf25e9,
+        0xf9869a33,
+        0x01ed75ea,
         0x00004000,
-        0xdd58898e,
         0x00004000,
-        0x54ef1825,
+        0x0782d602,
+        0x26a9f9cf,
+        0x1890b63b,
+        0x16b6e75c,
+        0x3e1db78f,
+        0xe7f3505c,
+        0xe0553e25,
+        0x17aaf7b9,
         0x00008000,
         0x00008000,
-        0x159ba8db,
-        0x00000000,
-        0xbab1131d,
+        0x3d4029c8,
+        0x525b9274,
+        0xee48ea1d,
+        0xe53bdc2e,
+        0xb16cfa2e,
+        0x03484f60,
+        0x337764ef,
+        0x3b24a6ba,
         0x00010000,
         0x00010000,
-        0xd0efd947,
-        0x00000000,
-        0x2b3651b6,
+        0xaa94f5ba,
+        0x10afb66c,
+        0x4d207151,
+        0x43e94b84,
+        0x74b14928,
+        0xa3f50528,
+        0x205e7e87,
+        0xf475d098,
         0x00020000,
         0x00020000,
-        0x157eb310,
-        0x00000000,
-        0xa1dfb28f,
+        0x7af6cad3,
+        0xdb63063c,
+        0x58f0183a,
+        0xe5021575,
+        0x208b718f,
+        0x7f629288,
+        0xa68c37d2,
+        0x15ab4522,
         0x00040000,
         0x00040000,
-        0xf46bbc6e,
-        0x00040000,
-        0x2af96620,
-        0x00080000,
+        0x69745fa7,
+        0xaa23e0fb,
+        0x4911894b,
+        0xb007c78a,
+        0x4048d8b3,
+        0xdcdf01c6,
+        0x16d5ef62,
+        0xfd03f5eb,
         0x00080000,
-        0x84ca4d1a,
         0x00080000,
-        0xe8d778dd,
+        0x667aa775,
+        0x95499aea,
+        0x6bd66f9e,
+        0x99a01a75,
+        0xb0c3989a,
+        0xa46fc9c0,
+        0x8c49035b,
+        0x0d9db265,
         0x00100000,
         0x00100000,
-        0xdc7b7cd6,
-        0x00100000,
-        0x09949a35,
-        0x00200000,
+        0xf64719b9,
+        0x19931764,
+        0xb03e3df1,
+        0x4aa1ff03,
+        0x3cf70cb5,
+        0x45285b5c,
+        0x262564c5,
+        0x62ab1ddd,
         0x00200000,
-        0xc0c08aa8,
         0x00200000,
-        0xb8d6f9ad,
+        0x55aca3c8,
+        0x83930a14,
+        0xf823c016,
+        0x0145d61b,
+        0x76081dbc,
+        0x85ccfb69,
+        0xddd88f1f,
+        0xbd8f2dec,
         0x00400000,
         0x00400000,
-        0x410142c3,
-        0x00000000,
-        0x81c11551,
-        0x00800000,
+        0x8dee92f8,
+        0xe94c86f6,
+        0xc7690e36,
+        0xaa2cae4a,
+        0x33af4234,
+        0x3ad1c1b5,
+        0xaab599b9,
+        0x535284a3,
         0x00800000,
-        0xdea674d5,
         0x00800000,
-        0x82828586,
+        0x76891bf1,
+        0x4ff415a6,
+        0xa4a0a886,
+        0x39b325ef,
+        0x2e5c0112,
+        0x3512766a,
+        0x8693d1a9,
+        0x4133d2bf,
         0x01000000,
         0x01000000,
-        0x2a6f3545,
-        0x00000000,
-        0xbc4ce9ab,
+        0xb3def820,
+        0x2cc13b78,
+        0xf22818e1,
+        0x3c791661,
+        0xe24c6fa5,
+        0xb7946f5b,
+        0x39efdb47,
+        0x04a25d39,
         0x02000000,
         0x02000000,
-        0x166d4584,
-        0x00000000,
-        0x56de6a8a,
+        0x495a1709,
+        0xda54e9dc,
+        0xf2c8403a,
+        0x7c5ba212,
+        0xf384f715,
+        0xbb81d01b,
+        0x2bc399e1,
+        0x70a2e955,
         0x04000000,
         0x04000000,
-        0x3c59c49b,
-        0x04000000,
-        0x28da8b08,
-        0x08000000,
+        0x147f04b6,
+        0x168f235d,
+        0x4bf30d70,
+        0x0b7a3d3f,
+        0x6f3b8d19,
+        0xbece9610,
+        0x98f63192,
+        0x788bd48b,
         0x08000000,
-        0x51e3238a,
         0x08000000,
-        0x78b38936,
+        0x6e7e17b6,
+        0xffaea214,
+        0xcc14153d,
+        0xe9ea2493,
+        0x028a0681,
+        0x29192d16,
+        0x7e578b08,
+        0x060a0032,
         0x10000000,
         0x10000000,
-        0xe5db98b8,
-        0x00000000,
-        0xb3c64714,
-        0x20000000,
+        0x6c7626e2,
+        0xd4a39c94,
+        0x10a952d6,
+        0x8d8584ef,
+        0x45d0c23c,
+        0x069d07be,
+        0xeb312d22,
+        0xc0dc0d65,
         0x20000000,
-        0x2ff64e1b,
         0x20000000,
-        0xcbb73171,
+        0x48001466,
+        0xd9517335,
+        0x071ca81d,
+        0x42b277e8,
+        0x217ccb31,
+        0xfe688f3a,
+        0x69598b45,
+        0x362626da,
         0x40000000,
         0x40000000,
-        0xe299eb10,
-        0x00000000,
-        0x1fec9c36,
+        0x8d4ec040,
+        0xd79c0e06,
+        0xa5ba5be4,
+        0xc1498d99,
+        0x7a596b67,
+        0xb1147d6b,
+        0x342583ae,
+        0x08fd8976,
         0x80000000,
-        0x85bec518,
-        0x16addd6f,
-        0xcd2c6f7f,
-        0xbb2a3fb2,
+        0x80344880,
+        0x3ec1e3c5,
+        0x4eae2118,
+        0xcfef8611,
+        0xc805f923,
+        0x9d99bc55,
+        0x4343a9ed,
+        0x6399b81a,
+        0x621a5538,
         0x00000001,
         0x00000001,
         0x00000002,
         0x00000002,
-        0xfa077d26,
-        0x00000000,
-        0x00000002,
+        0xffd269f1,
+        0xf5a9c8ab,
+        0x70086b07,
+        0x08b468ad,
+        0xd14bf8b6,
+        0x112fc95d,
+        0xbd08c787,
+        0xfa55a695,
+        0x3d2eab4f,
+        0x8f8d3856,
         0x00000004,
         0x00000004,
-        0x69194a06,
-        0x00000004,
-        0xf40efa4d,
-        0x00000008,
+        0xd5dfdc64,
+        0xed9c8b11,
+        0x68961a9f,
+        0x4421c4e9,
+        0x5b1ae5ee,
+        0x4710f2c8,
+        0x4cdf5ac8,
+        0xb29acc48,
         0x00000008,
-        0x43899633,
         0x00000008,
-        0xd232940c,
+        0x16cfff5e,
+        0x0c14d1a4,
+        0x77900ef5,
+        0xcaaa314c,
+        0x710c1eac,
+        0xf4a1eee5,
+        0xe8e6d04e,
+        0xf812980d,
         0x00000010,
         0x00000010,
-        0x6742ac36,
-        0x00000000,
-        0x87132c66,
+        0x319eb6d6,
+        0x6642251c,
+        0x7a235fed,
+        0x7865e350,
+        0x41e4176b,
+        0x34b48e89,
+        0x58af55c5,
+        0x353b07e2,
         0x00000020,
         0x00000020,
-        0x400a2110,
-        0x00000000,
-        0xce85584c,
+        0x042b542b,
+        0x33f2ca2f,
+        0xb37884f2,
+        0x61f9750a,
+        0xd3b6a37f,
+        0x6166ab79,
+        0x367112f0,
+        0x50364bfa,
         0x00000040,
         0x00000040,
-        0x7bb0adce,
-        0x00000040,
-        0x80144220,
-        0x00000080,
+        0xb3ee7479,
+        0xaf220f57,
+        0x88d93a49,
+        0xd78fdb81,
+        0x0fd1cdf1,
+        0x3e800818,
+        0xa54484ce,
+        0x40f35d7e,
         0x00000080,
-        0xfc2699b7,
         0x00000080,
-        0xf7615b1c,
+        0x718228f7,
+        0xace887d8,
+        0x53f67be1,
+        0x7d9ac313,
+        0x8a35d6cd,
+        0xe93fa913,
+        0xed96ab90,
+        0x213327a1,
         0x00000100,
         0x00000100,
-        0xffd75ad9,
-        0x00000000,
-        0xf84d336f,
+        0xe68727ad,
+        0x511ffc2f,
+        0xa56705b6,
+        0x528a4c37,
+        0x95dcfa19,
+        0xc5faf350,
+        0x42d9d77d,
+        0x12f74979,
         0x00000200,
         0x00000200,
-        0xaa7fd3f5,
-        0x00000000,
-        0xffaeb5b3,
+        0x183c68b3,
+        0x90173482,
+        0xe97437b5,
+        0xbd9ab886,
+        0xf399532f,
+        0xf85c5297,
+        0x6a9a6a3c,
+        0x9844cd6d,
         0x00000400,
         0x00000400,
-        0x18dfd27d,
-        0x00000000,
-        0x54ffa3eb,
+        0x5469bdd1,
+        0x504a61aa,
+        0xb49bc137,
+        0x252d006f,
+        0x3603b3a3,
+        0x332d1841,
+        0xb596b6ae,
+        0xc5339cda,
         0x00000800,
         0x00000800,
-        0xac0c9449,
-        0x00000000,
-        0x31bfa4fa,
-        0x00001000,
+        0xb4ba5ea2,
+        0xeb323b12,
+        0xffaf6fd5,
+        0x67fe77ec,
+        0x8747cff1,
+        0x2ad9bcc0,
+        0x0db97954,
+        0xa657418a,
         0x00001000,
-        0x9fa708d2,
         0x00001000,
-        0x58192893,
+        0xb8ef2d35,
+        0xf82ed4ca,
+        0x135e683e,
+        0x27ff2a6f,
+        0xc38487dc,
+        0xba8aee7b,
+        0x2a91e866,
+        0x507cc869,
         0x00002000,
         0x00002000,
-        0xcab2a0b3,
-        0x00000000,
-        0x3f4e31a5,
-        0x00004000,
+        0x7de78ffd,
+        0xc0e06704,
+        0xa4760855,
+        0x1a548fd8,
+        0x79e9ce1c,
+        0x9b7a3293,
+        0xfbe21c23,
+        0x7deb5f6e,
         0x00004000,
-        0xb250cb87,
         0x00004000,
-        0x95650167,
+        0x46e2c66b,
+        0x51c64de0,
+        0x2e809696,
+        0x79fe85ba,
+        0xb0a9cc76,
+        0xcaf21212,
+        0x43ff8379,
+        0xc297c6a6,
         0x00008000,
         0x00008000,
-        0x4ae55c63,
-        0x00000000,
-        0x64a1170f,
+        0xb2c69e7f,
+        0x8b000c35,
+        0x94d7af65,
+        0x0c73ed87,
+        0xc3cea1ae,
+        0xc41bd9fb,
+        0xaac4457a,
+        0x1bfd3d3f,
         0x00010000,
         0x00010000,
-        0xcd8257a1,
-        0x00000000,
-        0x95cbb8c6,
+        0xa6797f27,
+        0xe717876e,
+        0x789b7aa3,
+        0x45ba8671,
+        0x3d7ea568,
+        0x65003ae5,
+        0x618b1d67,
+        0x825850f5,
         0x00020000,
         0x00020000,
-        0xa26e9c2b,
-        0x00020000,
-        0x9b06af43,
-        0x00040000,
+        0x9c50c99d,
+        0x96496ecc,
+        0x17c6cbc1,
+        0x84b4f531,
+        0x4a76e487,
+        0xb537cf82,
+        0xe29c4795,
+        0x31439034,
         0x00040000,
-        0xea01252b,
         0x00040000,
-        0x44d93857,
+        0xe2df846d,
+        0x610e1043,
+        0x0f3a3c5a,
+        0x2a9dd8f1,
+        0xfa9e7cb4,
+        0xc293eb20,
+        0xb4e23fe0,
+        0x9e7dd64b,
         0x00080000,
         0x00080000,
-        0x08c8f3bd,
-        0x00000000,
-        0xd40a4a57,
+        0x4a840c8e,
+        0x6b7cf801,
+        0x9b495318,
+        0x9d340c06,
+        0x5aac7215,
+        0x6f598f27,
+        0x8f445aa5,
+        0x553d08f4,
         0x00100000,
         0x00100000,
-        0xeab81afa,
-        0x00000000,
-        0x1191e77a,
+        0x6cc40a01,
+        0x046f3351,
+        0x45a01636,
+        0xbadfc7dd,
+        0xa5e328f4,
+        0x023de7f8,
+        0x8ad5e06a,
+        0xe948a7f7,
         0x00200000,
         0x00200000,
-        0x15661793,
-        0x00000000,
-        0xd57035f5,
+        0x349356d1,
+        0x53e66434,
+        0x1bd2c65b,
+        0x2e7c9633,
+        0xace4281d,
+        0x59389aa9,
+        0x897b460a,
+        0x884225aa,
         0x00400000,
         0x00400000,
-        0x03fbe027,
-        0x00000000,
-        0x2acc2f26,
+        0xb5d7dbea,
+        0xbf4e1247,
+        0x95cdf26e,
+        0xaa20cbb1,
+        0xa68a7103,
+        0x22425fff,
+        0x1b9c153f,
+        0x8e2d9d4a,
         0x00800000,
         0x00800000,
-        0x318ce55a,
-        0x00000000,
-        0x07f7c04e,
+        0x78f12b11,
+        0xc47d1a7a,
+        0x477d1dc5,
+        0x62ee3358,
+        0x644fef3f,
+        0x01e25bf6,
+        0x9e5c9867,
+        0x5e5f0292,
         0x01000000,
         0x01000000,
-        0xbf4da8ff,
-        0x00000000,
-        0x6219cab4,
+        0x9ca31236,
+        0xe8793fec,
+        0xdfd27d3b,
+        0x8a0a511d,
+        0x1b4e1e69,
+        0x82a7c730,
+        0x32e189b8,
+        0xad9c1e2e,
         0x02000000,
         0x02000000,
-        0xa7e71c18,
-        0x02000000,
-        0x7c9b51ff,
+        0x6675d108,
+        0xac9862c7,
+        0x6e660d5f,
+        0xfbdc1b65,
+        0xbc14f9e0,
+        0xcb77790c,
+        0xd1e83e7c,
+        0xf8232799,
         0x04000000,
         0x04000000,
-        0xbe1bb62a,
-        0x04000000,
-        0x4bce3831,
+        0x95a418fa,
+        0x5b405777,
+        0x849832cb,
+        0x938798df,
+        0xa48371c2,
+        0x892b8f13,
+        0x63d48397,
+        0xca07d9ee,
         0x08000000,
         0x08000000,
-        0x94da8bfb,
-        0x00000000,
-        0x74376c55,
+        0xec0ca100,
+        0x1d862641,
+        0x08d986f0,
+        0xabbfa76d,
+        0xd2853eec,
+        0xd9b16ba7,
+        0xf26754fa,
+        0x65d55baf,
         0x10000000,
         0x10000000,
-        0x1f7c0b95,
-        0x10000000,
-        0x39b517f7,
-        0x20000000,
+        0xeba59915,
+        0x704b2abe,
+        0x98876475,
+        0x604dc815,
+        0x9d9f92d1,
+        0x374a882e,
+        0xb9bccd21,
+        0xbf10ae73,
         0x20000000,
-        0x0fcfbadc,
         0x20000000,
-        0x3ef8172a,
+        0xf33d8313,
+        0x9ce5c743,
+        0x45ee0203,
+        0x61d3b62b,
+        0x410ee15b,
+        0x2a156d13,
+        0x612dca1b,
+        0x137436d8,
         0x40000000,
         0x40000000,
-        0x9df70276,
-        0x40000000,
-        0x5f9f75b8,
+        0xe4d18cdb,
+        0x2ba8b3b3,
+        0x5d8ce332,
+        0x775d2bc5,
+        0x3ac5500c,
+        0xec591020,
+        0x04699c76,
+        0x7390ad7f,
         0x80000000,
         0xffffffff,
         0xffffffff,
         0xffffffff,
-        0x27bb2921,
-        0x75e88c63,
-        0x98e61f9a,
+        0xf0bafa55,
+        0x1c412802,
+        0xa2852104,
+        0xf7c4c4b1,
+        0x82fea2f9,
+        0xff69a3e8,
+        0x5610f2aa,
         0x00000001,
         0x00000001,
         0x00000002,
         0x00000002,
-        0xba649cea,
-        0x00000000,
-        0x00000002,
+        0x39aa64b5,
+        0x75b13cc5,
+        0x58e13918,
+        0x0573c762,
+        0x0b5a2b89,
+        0x3adad1d9,
+        0x8453a160,
+        0x1555391e,
+        0x85fcf916,
+        0xa5f5a54e,
         0x00000004,
         0x00000004,
-        0x51909fe2,
-        0x00000000,
-        0x74c939d1,
+        0x2c0f73b5,
+        0xd56d1ac7,
+        0xbdc497b1,
+        0x2d500096,
+        0x5ed5af74,
+        0xcbe5d6ba,
+        0xd196870d,
+        0x992379d9,
         0x00000008,
         0x00000008,
-        0x4d959578,
-        0x00000008,
-        0xa3213fc4,
-        0x00000010,
+        0xbc0005f4,
+        0xc35447a0,
+        0x4032f24f,
+        0xdeb57a76,
+        0x97610191,
+        0xe1e6de09,
+        0x49e16f83,
+        0x1d9dfb00,
         0x00000010,
-        0x5a632342,
         0x00000010,
-        0x9b2b2af0,
+        0x19c211c2,
+        0x1178c640,
+        0x5ce1ba97,
+        0xeb037045,
+        0x01dd7708,
+        0xac0d5557,
+        0xf98b4f5a,
+        0x3e151109,
         0x00000020,
         0x00000020,
-        0x9d62d560,
-        0x00000020,
-        0xb4c646a4,
-        0x00000040,
+        0x1ab62034,
+        0x82440d34,
+        0xe47c611b,
+        0xb9b60ff3,
+        0x52d43fa7,
+        0xdf3be7c2,
+        0xf161947e,
+        0x17617bae,
         0x00000040,
-        0x8e89bff9,
         0x00000040,
-        0x3ac5aac1,
+        0xd4e79239,
+        0xd1bdf4a9,
+        0x264e7934,
+        0x556a0b6d,
+        0x33f41847,
+        0xdbcb3c10,
+        0xcb2b3bf3,
+        0x29e15062,
         0x00000080,
         0x00000080,
-        0x3267ea3d,
-        0x00000080,
-        0x1d137f73,
+        0x3fb952d8,
+        0x8adfd907,
+        0x92b2ffcc,
+        0xd84f20e1,
+        0xd3f23350,
+        0x0ae8171e,
+        0xfece505d,
+        0x64951c69,
         0x00000100,
         0x00000100,
-        0xa26dcf12,
-        0x00000100,
-        0x64cfd47a,
-        0x00000200,
+        0xc07a1d93,
+        0x7b8adc14,
+        0x517c62b1,
+        0x5fa2b160,
+        0xe14d9ddf,
+        0xa0e6d0e5,
+        0xab27ee0c,
+        0xf2e5fafd,
         0x00000200,
-        0x737b27b1,
         0x00000200,
-        0x44db9c25,
+        0xa1719ebf,
+        0x6d3885ae,
+        0xdc35a603,
+        0x60201e40,
+        0x366bb817,
+        0x27eeec1a,
+        0x01f9eb1f,
+        0xf72737ff,
         0x00000400,
         0x00000400,
-        0x049f3b6a,
-        0x00000000,
-        0xe6f64f62,
+        0xb40ba359,
+        0xe53c0eaa,
+        0x2d97bcf7,
+        0x1cbc7b14,
+        0x58a6dd9b,
+        0xac9a34fd,
+        0x889cf862,
+        0x8417a478,
         0x00000800,
         0x00000800,
-        0x93b92b66,
-        0x00000000,
-        0x093e7ed4,
+        0x705882b2,
+        0x5f7d55f3,
+        0x7e36822f,
+        0x848cbb9c,
+        0xaab4ce22,
+        0x6010d59d,
+        0x9bb74ed1,
+        0x1f25191d,
         0x00001000,
         0x00001000,
-        0x154b65d8,
-        0x00001000,
-        0x277256cd,
+        0xac30a53a,
+        0x7612c150,
+        0x8fb15826,
+        0xabcc5e56,
+        0x57686252,
+        0x5dea8c94,
+        0x5f11d28a,
+        0x56252c91,
         0x00002000,
         0x00002000,
-        0x30a0e916,
-        0x00000000,
-        0x2a96ebb0,
+        0xc8d283ae,
+        0xc45d6cdf,
+        0xf65b7fc7,
+        0xf97bb6ce,
+        0x08d2efec,
+        0x26df7663,
+        0xd4d92939,
+        0xd0a0eb6d,
         0x00004000,
         0x00004000,
-        0x52e8b610,
-        0x00000000,
-        0x6141922c,
+        0x163bbb55,
+        0x4d07a734,
+        0xb5392158,
+        0x5ea93666,
+        0xfab998a6,
+        0x6ab4dae2,
+        0x7e087f7d,
+        0x2a09cabf,
         0x00008000,
         0x00008000,
-        0xbb23597c,
-        0x00008000,
-        0xa5d16c20,
+        0x8c4961e0,
+        0x50dab57b,
+        0x7f31a3ff,
+        0x61330baf,
+        0xa9d49841,
+        0x0c36ce93,
+        0x0640a1b6,
+        0x65aafc43,
         0x00010000,
         0x00010000,
-        0x3eef7ddc,
-        0x00000000,
-        0x7646b2f9,
-        0x00020000,
+        0xd6f3186c,
+        0x8c3d1200,
+        0x2764b6e6,
+        0x4f5f880e,
+        0x3b0ecbcf,
+        0xda97a44a,
+        0x9c32d30f,
+        0xfd6e9309,
         0x00020000,
-        0x0b40708c,
         0x00020000,
-        0x7ddcfbb8,
+        0x62f8a075,
+        0xd1e610db,
+        0x6c3fa20a,
+        0x139e1e71,
+        0x7b9988c2,
+        0xa37a1b54,
+        0x07c08132,
+        0x84276369,
         0x00040000,
         0x00040000,
-        0x09e9d789,
-        0x00040000,
-        0x1684e118,
-        0x00080000,
+        0xf9ccb369,
+        0x1df8dfd4,
+        0x7937aec1,
+        0x0c530bc5,
+        0x621d1f67,
+        0x4d56ee4d,
+        0xb24c3356,
+        0x6c2fca23,
         0x00080000,
-        0x98031fc7,
         0x00080000,
-        0x13dbaf12,
+        0xe22dfecf,
+        0x70c0928f,
+        0xa12e2a4f,
+        0x1a3ace23,
+        0xa27558c3,
+        0x4f16d7b0,
+        0xdea0c97c,
+        0x31637b8e,
         0x00100000,
         0x00100000,
-        0xea577d10,
-        0x00000000,
-        0x30063f8f,
+        0xede33075,
+        0xcf7c459a,
+        0xfaece5de,
+        0x70471061,
+        0x88e29993,
+        0x1ecdbab4,
+        0x4e5cb316,
+        0x82820adb,
         0x00200000,
         0x00200000,
-        0x419c038f,
-        0x00000000,
-        0xd48efa21,
+        0x49a14a27,
+        0x34451881,
+        0x3af172c0,
+        0xc88572e6,
+        0x4c9d8295,
+        0x119f8ff9,
+        0x1a172d0a,
+        0xa4004ccd,
         0x00400000,
         0x00400000,
-        0x711f22ff,
-        0x00000000,
-        0x8338071e,
+        0x3dd1a2ef,
+        0x68cfef74,
+        0x90025bcc,
+        0x872a0a57,
+        0x3494a77a,
+        0xc833c894,
+        0x39bb79b9,
+        0x60b660d3,
         0x00800000,
         0x00800000,
-        0x28385520,
-        0x00000000,
-        0xe2be45fe,
+        0x1774a16b,
+        0x3f133997,
+        0xd45a0dc7,
+        0xbaaf18dc,
+        0x96895a13,
+        0xdfaff486,
+        0xb51b3bae,
+        0x9200f496,
         0x01000000,
         0x01000000,
-        0x9c2b1c95,
-        0x01000000,
-        0x5070aa40,
+        0x970bcf96,
+        0xa9806900,
+        0x64059437,
+        0x93babcd5,
+        0xe087061e,
+        0xaa49e4c4,
+        0x1140d07b,
+        0x452eca2f,
         0x02000000,
         0x02000000,
-        0x6e53e9e1,
-        0x00000000,
-        0x3a56392b,
+        0xd223d212,
+        0xd2e4be27,
+        0x0ecd6ae8,
+        0x0dd87213,
+        0xe217934f,
+        0x0614536a,
+        0xe809c6f8,
+        0x3dd5ff49,
         0x04000000,
         0x04000000,
-        0x0c30578d,
-        0x04000000,
-        0xd8a7d3c2,
-        0x08000000,
+        0xafac63f8,
+        0x55361e69,
+        0x1808f160,
+        0x669a3761,
+        0xc16f72b9,
+        0xbd4095f0,
+        0x9abd6bb8,
+        0xce9efa2f,
         0x08000000,
-        0x3176d3df,
         0x08000000,
-        0x1060af1a,
+        0x2637f5ce,
+        0x5abb1a47,
+        0x624bc3b0,
+        0x302d49c2,
+        0x64e63f7b,
+        0x3e3b7c8e,
+        0x441a6fcc,
+        0xa50ef785,
         0x10000000,
         0x10000000,
-        0xcd07e4ef,
-        0x00000000,
-        0x62eda7be,
+        0x77af7c92,
+        0x475d49d7,
+        0x88b20db2,
+        0x983ef7ca,
+        0xc9098773,
+        0x0b5e0d7c,
+        0x7a17b2f8,
+        0xae234b53,
         0x20000000,
         0x20000000,
-        0xabd2d489,
-        0x20000000,
-        0xba0fc9df,
-        0x40000000,
+        0xb6cd1610,
+        0xd3ac2a12,
+        0xb516389c,
+        0xdbd0bb58,
+        0x661ba811,
+        0xbdb92143,
+        0x2bd58dcc,
+        0xe2e13c45,
         0x40000000,
-        0x90541386,
         0x40000000,
-        0x57a5a913,
+        0x4b8c9a4a,
+        0x76e45f79,
+        0x1afbcbcd,
+        0xa0662827,
+        0x76755674,
+        0xd169a8ae,
+        0xc08ff024,
+        0xebcb8ef3,
         0x80000000,
         0xffffffff,
         0xffffffff,
         0xffffffff,
-        0x7306c339,
-        0x2f6a3bce,
-        0xaa6eb899,
+        0x113b8090,
+        0x26ddb7bc,
+        0xe004bbbe,
+        0x4f707bf6,
+        0x10c7dbef,
+        0x97db7099,
         0x00000001,
         0x00000001,
         0x00000002,
         0x00000002,
-        0xd7595f1a,
-        0x00000002,
-        0x00000002,
+        0xa7dbbb8d,
+        0xb6d4f587,
+        0x05314080,
+        0xb46efac7,
+        0xa279249f,
+        0x0d71f94e,
+        0xf3c6bb78,
+        0x75d7882c,
+        0x922f3ddc,
+        0x9d771440,
         0x00000004,
         0x00000004,
-        0xcf8b53ea,
-        0x00000004,
-        0xaeb2be31,
+        0xbeb712e3,
+        0xed636c09,
+        0xe4af9829,
+        0x8cf85466,
+        0x02524207,
+        0x1ab5dcdf,
+        0xaf9c781b,
+        0x53deb04b,
         0x00000008,
         0x00000008,
-        0xea776055,
-        0x00000000,
-        0x9f16a7d5,
-        0x00000010,
+        0x295fd1fc,
+        0x28e1330d,
+        0x29f33bb6,
+        0x68ae025a,
+        0x65bd955d,
+        0x55cf96fa,
+        0x183fdae8,
+        0x5a0ac8fd,
         0x00000010,
-        0xf58e5a61,
         0x00000010,
-        0xd4eec0ab,
+        0x8b29d563,
+        0xac47ed6e,
+        0x3bc15635,
+        0x1e8ca779,
+        0xc17a3f63,
+        0x9ccd46e7,
+        0x411817ac,
+        0xd8d166c3,
         0x00000020,
         0x00000020,
-        0xc3fe4c62,
-        0x00000000,
-        0xeb1cb4e3,
-        0x00000040,
+        0x8455bcd9,
+        0x1e6f607a,
+        0xe8dbcfd6,
+        0xdce8f066,
+        0x92f67b72,
+        0x5cac7a42,
+        0xbcbb1245,
+        0x980a93b1,
         0x00000040,
-        0x0fd0104d,
         0x00000040,
-        0x87fc9885,
+        0x6e42f05b,
+        0x9bc6a398,
+        0x79ac8312,
+        0xc61408a0,
+        0x7b318421,
+        0x32439eae,
+        0xc55aca1e,
+        0x0e0ac088,
         0x00000080,
         0x00000080,
-        0xe135c8a6,
-        0x00000000,
-        0x1fa0209a,
-        0x00000100,
+        0xbd1b8e40,
+        0x60ac2c1e,
+        0x27cfd75a,
+        0xec6c90d6,
+        0x0b2357d7,
+        0x69ccd9d9,
+        0x9897fb0a,
+        0x70b1b698,
         0x00000100,
-        0x05f6e2cc,
         0x00000100,
-        0xc26b904d,
+        0xdca38044,
+        0x18b5e746,
+        0x40ea116e,
+        0xdbfb7f4f,
+        0x9ad177e9,
+        0x6fb73bb3,
+        0x719a3a36,
+        0x1c6bfcb3,
         0x00000200,
         0x00000200,
-        0x5a231953,
-        0x00000200,
-        0x0bedc798,
+        0x48a7d27c,
+        0xbe07a205,
+        0x2eb5a6f7,
+        0xbd41b2b6,
+        0x26c0a872,
+        0x731b4004,
+        0x8dce3ef8,
+        0xdd20d7c2,
         0x00000400,
         0x00000400,
-        0xf3bd5e7b,
-        0x00000000,
-        0xb44632a6,
+        0x3818fb16,
+        0xeecc8990,
+        0x1a2d9083,
+        0xcd34ab54,
+        0x85c9e6da,
+        0x8dd67902,
+        0xc4e679dd,
+        0x98884d61,
         0x00000800,
         0x00000800,
-        0xe40ce9ae,
-        0x00000000,
-        0xe77abcf7,
+        0xbe6518c6,
+        0xeeb9f386,
+        0xd9ebbb81,
+        0xdd03ef36,
+        0x8e1e958d,
+        0xf4693a1c,
+        0xf340f750,
+        0xadc40911,
         0x00001000,
         0x00001000,
-        0x2659f3d5,
-        0x00000000,
-        0xc819c35d,
+        0xc7774d11,
+        0x13544fb0,
+        0x1312dee6,
+        0x877fb9ff,
+        0xd57b7573,
+        0x2f1209b8,
+        0x3d58b08c,
+        0x634dff2e,
         0x00002000,
         0x00002000,
-        0x8a88bf6e,
-        0x00000000,
-        0x4cb3c7aa,
+        0x8ae586aa,
+        0xd31b303a,
+        0xe41c98e9,
+        0x49c0996b,
+        0xbfb1a3c6,
+        0x32e87290,
+        0x30bbff2f,
+        0x6c2ea251,
         0x00004000,
         0x00004000,
-        0xf2c1ca60,
-        0x00000000,
-        0x15113edd,
+        0x48668382,
+        0x538eaa07,
+        0xaa0bfefa,
+        0x616cbf58,
+        0xad689938,
+        0x54b429f0,
+        0x483f67b7,
+        0xa5174c1e,
         0x00008000,
         0x00008000,
-        0x1b588a2f,
-        0x00008000,
-        0xe58314c1,
+        0x592ba925,
+        0x866ca29e,
+        0xe9794012,
+        0x38d91146,
+        0x70396593,
+        0x2df7d680,
+        0x6bf078ba,
+        0x1e413888,
         0x00010000,
         0x00010000,
-        0x8ce59693,
-        0x00000000,
-        0x36b0145e,
+        0x70e304c5,
+        0xd218071c,
+        0x395ae9af,
+        0xb6978b45,
+        0xe13b06c4,
+        0x1858eb15,
+        0x62c207a7,
+        0xab9abaa0,
         0x00020000,
         0x00020000,
-        0x49876c8c,
-        0x00000000,
-        0x19cb2d27,
+        0x0f6f7b8a,
+        0xe300bc15,
+        0x073099a2,
+        0x32c68b00,
+        0x08f1ef0e,
+        0x21661a5f,
+        0xc2caab6c,
+        0xfc67f738,
         0x00040000,
         0x00040000,
-        0xdbc2d480,
-        0x00040000,
-        0x930ed918,
+        0x59358006,
+        0x249f21ea,
+        0x31d6957d,
+        0x1521bed8,
+        0xc1491529,
+        0x3d3a2f45,
+        0xb00f0efd,
+        0x7a155efb,
         0x00080000,
         0x00080000,
-        0x5c76d14e,
-        0x00000000,
-        0xb785a901,
+        0xdb397329,
+        0x451e1549,
+        0x6cd9fb58,
+        0xbe547396,
+        0x3a936723,
+        0xcf479aeb,
+        0x072260f0,
+        0xc1d2ed2f,
         0x00100000,
         0x00100000,
-        0xbcaa0b27,
-        0x00000000,
-        0xb8fda29c,
+        0x196afa6b,
+        0x2e275a26,
+        0xec1f96bf,
+        0xa33382fc,
+        0x1f4236a8,
+        0x1e52d071,
+        0xda52d02b,
+        0xb7098a23,
         0x00200000,
         0x00200000,
-        0xc53885f8,
-        0x00000000,
-        0x7954164f,
+        0xe6a17e33,
+        0x49acda58,
+        0x0e9e381c,
+        0x23abae5f,
+        0x43b23b2c,
+        0xe0d00682,
+        0x02d1a1d9,
+        0x9a4db744,
         0x00400000,
         0x00400000,
-        0x0b38a131,
-        0x00000000,
-        0x8a310bf1,
+        0x5edd68ca,
+        0xcec5eec5,
+        0x37d8c319,
+        0xc2150859,
+        0x817566ba,
+        0x7eaa2b16,
+        0x585f08ba,
+        0xf8a92369,
         0x00800000,
         0x00800000,
-        0xdb8bc196,
-        0x00000000,
-        0x16f14262,
+        0xc32fde17,
+        0xfc2459e2,
+        0xf87db2a6,
+        0x2dfe7921,
+        0x99221710,
+        0xc8545ffa,
+        0x96807db9,
+        0x706274b6,
         0x01000000,
         0x01000000,
-        0x77ea4d61,
-        0x01000000,
-        0xb717832d,
+        0xfa5c01a3,
+        0xe30d1bcf,
+        0x63d71c15,
+        0xb6e57f85,
+        0xc16918aa,
+        0xc6cc3e69,
+        0x7d2320ba,
+        0x802c1fc7,
         0x02000000,
         0x02000000,
-        0x2c85a30c,
-        0x00000000,
-        0xefd49ac2,
-        0x04000000,
+        0xa648a33c,
+        0x842b271c,
+        0xa184759d,
+        0x4cf31073,
+        0x7e06ae20,
+        0xe4459ed4,
+        0x19a4c149,
+        0x516211d2,
         0x04000000,
-        0x568a3988,
         0x04000000,
-        0x590b4618,
+        0xecc3cee5,
+        0x3ccf7bfd,
+        0x97315aa0,
+        0xb5008ac2,
+        0x20f1120c,
+        0xe89aeaef,
+        0x8f56175b,
+        0x87246c28,
         0x08000000,
         0x08000000,
-        0x83ffd628,
-        0x00000000,
-        0xad147310,
+        0x32eb8a11,
+        0xe156ead9,
+        0x6e84d264,
+        0x6fddb2b6,
+        0x31f23449,
+        0xfc52ab42,
+        0x70992da7,
+        0x6d8400b5,
         0x10000000,
         0x10000000,
-        0xa4dcb4c7,
-        0x00000000,
-        0x17ffac51,
+        0x2ed4ecae,
+        0x6e8eb3f1,
+        0x2b2307c1,
+        0xb7ca870c,
+        0x4f624955,
+        0x46bfe950,
+        0x72a4f89b,
+        0x8db26ac2,
         0x20000000,
         0x20000000,
-        0x32e5c923,
-        0x00000000,
-        0x69b9698f,
+        0xe8dab5f4,
+        0x29064c80,
+        0xc2fbe008,
+        0xe9c8871e,
+        0x76dda5b5,
+        0x2a2008e0,
+        0x7fdab429,
+        0x0b2329ed,
         0x40000000,
         0x40000000,
-        0x66a817f7,
-        0x00000000,
-        0x65cb9246,
+        0xfbf77ba5,
+        0x6282f6b8,
+        0xdbe881cb,
+        0x4454b4a0,
+        0x642ad339,
+        0xa62745e3,
+        0x80909a0c,
+        0x6dee6960,
         0x80000000,
-        0x12fc023a,
-        0xc9f25ea2,
-        0x1f9a98e6,
+        0xe9353e73,
+        0x1da3277f,
+        0xa0470cd6,
+        0x556f7aad,
+        0x5220f262,
+        0x5d6dd0ab,
         0xffffffff,
         0xffffffff,
-        0x0af80051,
-        0x73fe0765,
-        0x79bc0a19,
+        0x1a0a50cb,
+        0xdc366a03,
+        0xce7fd02a,
+        0x87ec087f,
+        0xfe669b99,
+        0xb667dbd6,
         0x00000001,
         0x00000001,
         0x00000002,
         0x00000002,
-        0x4823a14f,
-        0x00000000,
-        0x00000000,
+        0x2b7019b8,
+        0x675c7262,
+        0x3ff1d290,
+        0x054f2a66,
+        0xe4a083cb,
+        0xdf2a7863,
+        0xb2e57a21,
+        0x7545dc46,
+        0xb47d1f2f,
+        0xf96577a9,
         0x00000004,
         0x00000004,
-        0x504746b6,
-        0x00000004,
-        0x9047429e,
-        0x00000008,
+        0xe3f1e57b,
+        0xc913bb17,
+        0xbeee9e7c,
+        0x61226e96,
+        0xbd9663dc,
+        0x897a6b6c,
+        0xa0e0c8a4,
+        0x558fb0f1,
         0x00000008,
-        0x52ce93a4,
         0x00000008,
-        0xa08e8d64,
+        0x6f1bc747,
+        0xef7129ba,
+        0xf4ae7577,
+        0x070cc667,
+        0xc719ba3c,
+        0xcac597c2,
+        0x7918b674,
+        0x71fd2c20,
         0x00000010,
         0x00000010,
-        0xfbdad444,
-        0x00000010,
-        0xa59d2748,
+        0x383d1636,
+        0x90e73d62,
+        0x4e5984a1,
+        0x88ec2e12,
+        0x4d932f3e,
+        0xd57ba991,
+        0x7e6b295a,
+        0x7637dfc0,
         0x00000020,
         0x00000020,
-        0x4806d441,
-        0x00000020,
-        0xf7b5a8a9,
-        0x00000040,
+        0x8dd42fa3,
+        0x47533a2f,
+        0x0b7b3ebd,
+        0x41d220b2,
+        0x8172b44d,
+        0x5a059d55,
+        0x1a8b0229,
+        0xcc85451d,
         0x00000040,
-        0xc983de8c,
         0x00000040,
-        0x900da8c2,
+        0x3ced2287,
+        0x6691eb10,
+        0x73d411ff,
+        0x25b9300c,
+        0xb06af841,
+        0x5c3f7fad,
+        0xc5fd5f84,
+        0xc8e98f39,
         0x00000080,
         0x00000080,
-        0x4fb9360b,
-        0x00000000,
-        0x9307bd19,
+        0xdbf3fe66,
+        0xd7b2c4f5,
+        0x34f70d02,
+        0xa9d0c4ab,
+        0xd89efeed,
+        0x010e0d6e,
+        0xe126c492,
+        0x51c5d72b,
         0x00000100,
         0x00000100,
-        0xee7f88eb,
-        0x00000100,
-        0x9f726c16,
+        0x33d3af9e,
+        0xbf97582a,
+        0x52b24087,
+        0x297c3021,
+        0xee9564a9,
+        0xb3d45f06,
+        0x83b78c9c,
+        0xd528bf55,
         0x00000200,
         0x00000200,
-        0xd7fc04b3,
-        0x00000000,
-        0xdcff11d7,
+        0x19e27119,
+        0xaf0f0a7b,
+        0x3576caa3,
+        0xe9d252b4,
+        0x55a65579,
+        0x210079c8,
+        0xf73d9f70,
+        0x509fbd33,
         0x00000400,
         0x00000400,
-        0x4d0ec225,
-        0x00000400,
-        0xaff80d67,
-        0x00000800,
+        0x16b2d42c,
+        0xe48461a7,
+        0x3f535a98,
+        0xfcdfe9b3,
+        0x8e2b57b3,
+        0xd83120a6,
+        0x9b7f9806,
+        0x01ba1354,
         0x00000800,
-        0xdf192128,
         0x00000800,
-        0x9a1d8c4a,
+        0xf8a71af7,
+        0x822db2cb,
+        0xf16b72e9,
+        0xd87888eb,
+        0xe1abb55b,
+        0x58f848d8,
+        0x32b22f56,
+        0xf73d120d,
         0x00001000,
         0x00001000,
-        0x1143f20e,
-        0x00000000,
-        0xbe325251,
+        0x7cafb74a,
+        0x3a4dcfea,
+        0x87d239ad,
+        0x77c12beb,
+        0xa644c720,
+        0x97c4fce7,
+        0xf0b06aca,
+        0x91d7ff93,
         0x00002000,
         0x00002000,
-        0x7f8ad0d9,
-        0x00000000,
-        0x2287e41c,
+        0x74996511,
+        0x3ce50750,
+        0x3e0faa9e,
+        0x4da4b9d1,
+        0x5fe30d5c,
+        0x9b8d225e,
+        0xb21dc7dd,
+        0xc563b89b,
         0x00004000,
         0x00004000,
-        0xb6b43013,
-        0x00000000,
-        0xff15a1b2,
+        0xf8c6ed72,
+        0x950bc7fe,
+        0xf255bdd9,
+        0x92686c71,
+        0x1b35a7f2,
+        0xb2a0d63a,
+        0x360da69d,
+        0x84380633,
         0x00008000,
         0x00008000,
-        0x8e71a00a,
-        0x00000000,
-        0x6d686027,
+        0x220689a3,
+        0x797e4125,
+        0x6ed5b249,
+        0x8e5603d5,
+        0xb25f1e1f,
+        0xddb4da9c,
+        0x5a46be4c,
+        0xccc6a610,
         0x00010000,
         0x00010000,
-        0x2fdf0759,
-        0x00000000,
-        0x1ce34015,
-        0x00020000,
+        0x2318ddd6,
+        0x0fedb8e4,
+        0x753a6dab,
+        0x4d70da5f,
+        0x3d372d73,
+        0xe81d03ab,
+        0x8ce42641,
+        0xbebf0dbe,
         0x00020000,
-        0xc62563bb,
         0x00020000,
-        0x5fbe0eb2,
+        0x67818513,
+        0x05bd6da7,
+        0xa6c97e02,
+        0xef6ef604,
+        0x6b3a5125,
+        0x79554a51,
+        0xd69a8dc2,
+        0x1465b796,
         0x00040000,
         0x00040000,
-        0x3bd545db,
-        0x00000000,
-        0x8c4ec777,
+        0x2c80a967,
+        0x48840e90,
+        0x9f755705,
+        0xd4cd49d0,
+        0x81e21369,
+        0x8e7f9211,
+        0xf4e8718a,
+        0x052163a6,
         0x00080000,
         0x00080000,
-        0x85729031,
-        0x00000000,
-        0x77a28bb6,
+        0x370a07dc,
+        0x05ac760d,
+        0xccbea708,
+        0x02f28830,
+        0x83d64897,
+        0x91587c58,
+        0xec9ee216,
+        0x07d15b88,
         0x00100000,
         0x00100000,
-        0x1e97491c,
-        0x00000000,
-        0x0ae52063,
+        0xfbb3ff86,
+        0x7727a3ea,
+        0x1c86f0a7,
+        0x2a1eaa9d,
+        0xa1255746,
+        0x877952c1,
+        0xb65ea94c,
+        0x23deb2b7,
         0x00200000,
         0x00200000,
-        0x14492a01,
-        0x00000000,
-        0x3d2e9238,
+        0x15e101cf,
+        0xd72d08ee,
+        0xb98a3585,
+        0x0743e5cc,
+        0x455a6b90,
+        0xabe17100,
+        0x95dd2634,
+        0xabb719d1,
         0x00400000,
         0x00400000,
-        0x6ac017fd,
-        0x00400000,
-        0x28925402,
+        0x1295bfda,
+        0xce532187,
+        0x394005ca,
+        0x515e1e19,
+        0x44560e83,
+        0x66b35f39,
+        0xc763ca2d,
+        0x7b079883,
         0x00800000,
         0x00800000,
-        0xfa6bbf73,
-        0x00000000,
-        0xd5002ffa,
+        0x7ec5ee6c,
+        0x68211df7,
+        0xb58ed5ee,
+        0x02cfc3e9,
+        0x509043f1,
+        0x94c86e0b,
+        0x67320b8f,
+        0xcd3ee137,
         0x01000000,
         0x01000000,
-        0x88a3d149,
-        0x00000000,
-        0xf4d77ee7,
+        0x9cac7517,
+        0x3843c2ff,
+        0xc4cfcee5,
+        0x38eae9a9,
+        0x19247cfd,
+        0xee4e091c,
+        0x974a0cec,
+        0x6d641320,
         0x02000000,
         0x02000000,
-        0xc3e1c809,
-        0x02000000,
-        0x1347a293,
+        0xe3db779c,
+        0x966aca22,
+        0xb13cc123,
+        0x9dce7962,
+        0xe1474ce1,
+        0x3f76fe4d,
+        0x18bcce31,
+        0x73b7f236,
         0x04000000,
         0x04000000,
-        0x37e1eaa1,
-        0x00000000,
-        0x87c39013,
-        0x08000000,
+        0xee2f90bd,
+        0x97006077,
+        0x5c69cc60,
+        0x5fd7d9ed,
+        0xef8e1aad,
+        0x34280c2b,
+        0xfae02a2c,
+        0x4ff7d799,
         0x08000000,
-        0x3e64d41f,
         0x08000000,
-        0x6fc3d542,
+        0xfb9162ce,
+        0x9b095957,
+        0xeb2d1b30,
+        0x6d7d19fb,
+        0x5f79fb94,
+        0x7d134be0,
+        0xa1df90dd,
+        0x908b9cc0,
         0x10000000,
         0x10000000,
-        0x273ce79e,
-        0x00000000,
-        0x7cc9a83e,
+        0x3db1e4d6,
+        0xabe087ab,
+        0xe88fabf4,
+        0x26c1500e,
+        0xbca96424,
+        0x3263462a,
+        0xf014ea87,
+        0x03bac469,
         0x20000000,
         0x20000000,
-        0xb889b148,
-        0x00000000,
-        0x6e79cf3c,
+        0xee3e151f,
+        0x86d39f72,
+        0xbb806a1d,
+        0x38597831,
+        0x84b32464,
+        0x0ca53b33,
+        0x5b7bff27,
+        0xf1bc2f2d,
         0x40000000,
         0x40000000,
-        0x49527f66,
-        0x00000000,
-        0x31136291,
+        0x28e7f113,
+        0x43ba734a,
+        0x65b49713,
+        0x2489e241,
+        0xd10b59fc,
+        0x6ecdb134,
+        0xb12ffd82,
+        0x707902a0,
         0x80000000,
         0xffffffff,
         0xffffffff,
-        0xf8926520,
-        0x85bec518,
-        0x441860af,
+        0xcf2ec1f1,
+        0xe3174e98,
+        0xb926648a,
+        0x47a83b9e,
+        0xb194d665,
+        0x7017253f,
         0xffffffff,
         0xffffffff,
-        0x68fcffbd,
+        0x169cdc5e,
+        0xf3921c1d,
     ];
     let mut r = [0u32; 16];
-    r[9] = a ^ POOL[0];
-    r[12] = b ^ POOL[1];
-    r[1] = r[12] ^ POOL[2];
-    r[12] = r[1] ^ POOL[3];
-    r[1] = {
-        let (t, ma, mb) = (POOL[4], POOL[5], POOL[6]);
+    r[1] = a ^ POOL[0];
+    r[8] = b ^ POOL[1];
+    r[11] = r[8] ^ POOL[2];
+    r[8] = r[11] ^ POOL[3];
+    r[11] = r[1] ^ POOL[4];
+    r[0] = r[11] ^ POOL[5];
+    r[11] = r[8] ^ POOL[6];
+    r[3] = r[11] ^ POOL[7];
+    r[11] = {
+        let (t, s) = (POOL[8], POOL[9]);
+        let (ma, mb) = (t ^ s ^ POOL[10], t ^ s ^ POOL[11]);
         let mut z = t;
-        z ^= r[9] & mb;
-        z ^= r[12] & ma;
-        z ^= r[9] & r[12];
+        z ^= r[0] & mb;
+        z ^= r[3] & ma;
+        z ^= r[0] & r[3];
         z
     };
-    r[11] = r[9] ^ r[12];
-    r[3] = r[11] & POOL[7];
-    r[7] = r[1] & POOL[8];
-    r[0] = r[7].rotate_left(1);
-    r[7] = r[11] & POOL[9];
-    r[15] = r[7] ^ r[0];
-    r[5] = r[3] ^ r[15];
-    r[3] = r[1] & POOL[10];
-    r[15] = {
-        let (t, ma, mb) = (POOL[11], POOL[12], POOL[13]);
+    r[0] = r[1] ^ r[8];
+    r[3] = r[0] & POOL[12];
+    r[14] = r[11] & POOL[13];
+    r[4] = r[14].rotate_left(1);
+    r[14] = r[0] & POOL[14];
+    r[10] = r[14] ^ r[4];
+    r[12] = r[3] ^ r[10];
+    r[3] = r[11] & POOL[15];
+    r[10] = r[14] ^ POOL[16];
+    r[14] = r[10] ^ POOL[17];
+    r[10] = r[14] ^ POOL[18];
+    r[14] = r[4] ^ POOL[19];
+    r[4] = r[14] ^ POOL[20];
+    r[14] = r[4] ^ POOL[21];
+    r[4] = {
+        let (t, s) = (POOL[22], POOL[23]);
+        let (ma, mb) = (t ^ s ^ POOL[24], t ^ s ^ POOL[25]);
         let mut z = t;
-        z ^= r[7] & mb;
-        z ^= r[0] & ma;
-        z ^= r[7] & r[0];
+        z ^= r[10] & mb;
+        z ^= r[14] & ma;
+        z ^= r[10] & r[14];
         z
     };
-    r[7] = r[3] ^ r[15];
-    r[3] = r[7].rotate_left(1);
-    r[7] = r[11] & POOL[14];
-    r[0] = r[7] ^ r[3];
-    r[15] = r[5] ^ r[0];
-    r[0] = r[1] & POOL[15];
-    r[5] = {
-        let (t, ma, mb) = (POOL[16], POOL[17], POOL[18]);
+    r[14] = r[3] ^ r[4];
+    r[3] = r[14].rotate_left(1);
+    r[14] = r[0] & POOL[26];
+    r[4] = r[14] ^ r[3];
+    r[10] = r[12] ^ r[4];
+    r[4] = r[11] & POOL[27];
+    r[12] = r[14] ^ POOL[28];
+    r[14] = r[12] ^ POOL[29];
+    r[12] = r[14] ^ POOL[30];
+    r[14] = r[3] ^ POOL[31];
+    r[3] = {
+        let (t, s) = (POOL[32], POOL[33]);
+        let (ma, mb) = (t ^ s ^ POOL[34], t ^ s ^ POOL[35]);
         let mut z = t;
-        z ^= r[7] & mb;
-        z ^= r[3] & ma;
-        z ^= r[7] & r[3];
+        z ^= r[12] & mb;
+        z ^= r[14] & ma;
+        z ^= r[12] & r[14];
         z
     };
-    r[3] = r[0] ^ r[5];
-    r[7] = r[3].rotate_left(1);
-    r[3] = r[11] & POOL[19];
-    r[0] = r[3] ^ r[7];
-    r[5] = r[15] ^ r[0];
-    r[0] = r[1] & POOL[20];
-    r[15] = {
-        let (t, ma, mb) = (POOL[21], POOL[22], POOL[23]);
+    r[14] = r[4] ^ r[3];
+    r[3] = r[14].rotate_left(1);
+    r[14] = r[0] & POOL[36];
+    r[4] = r[14] ^ r[3];
+    r[12] = r[10] ^ r[4];
+    r[4] = r[11] & POOL[37];
+    r[10] = r[14] ^ POOL[38];
+    r[14] = r[10] ^ POOL[39];
+    r[10] = r[14] ^ POOL[40];
+    r[14] = r[3] ^ POOL[41];
+    r[3] = {
+        let (t, s) = (POOL[42], POOL[43]);
+        let (ma, mb) = (t ^ s ^ POOL[44], t ^ s ^ POOL[45]);
         let mut z = t;
-        z ^= r[3] & mb;
-        z ^= r[7] & ma;
-        z ^= r[3] & r[7];
+        z ^= r[10] & mb;
+        z ^= r[14] & ma;
+        z ^= r[10] & r[14];
         z
     };
-    r[3] = r[0] ^ r[15];
-    r[7] = r[3].rotate_left(1);
-    r[3] = r[11] & POOL[24];
-    r[0] = r[3] ^ r[7];
-    r[15] = r[5] ^ r[0];
-    r[0] = r[1] & POOL[25];
-    r[5] = {
-        let (t, ma, mb) = (POOL[26], POOL[27], POOL[28]);
+    r[14] = r[4] ^ r[3];
+    r[3] = r[14].rotate_left(1);
+    r[14] = r[0] & POOL[46];
+    r[4] = r[14] ^ r[3];
+    r[10] = r[12] ^ r[4];
+    r[4] = r[11] & POOL[47];
+    r[12] = r[14] ^ POOL[48];
+    r[14] = r[12] ^ POOL[49];
+    r[12] = r[14] ^ POOL[50];
+    r[14] = r[3] ^ POOL[51];
+    r[3] = {
+        let (t, s) = (POOL[52], POOL[53]);
+        let (ma, mb) = (t ^ s ^ POOL[54], t ^ s ^ POOL[55]);
         let mut z = t;
-        z ^= r[3] & mb;
-        z ^= r[7] & ma;
-        z ^= r[3] & r[7];
+        z ^= r[12] & mb;
+        z ^= r[14] & ma;
+        z ^= r[12] & r[14];
         z
     };
-    r[3] = r[0] ^ r[5];
-    r[7] = r[3].rotate_left(1);
-    r[3] = r[11] & POOL[29];
-    r[0] = r[3] ^ r[7];
-    r[5] = r[15] ^ r[0];
-    r[0] = r[1] & POOL[30];
-    r[15] = {
-        let (t, ma, mb) = (POOL[31], POOL[32], POOL[33]);
+    r[14] = r[4] ^ r[3];
+    r[3] = r[14].rotate_left(1);
+    r[14] = r[0] & POOL[56];
+    r[4] = r[14] ^ r[3];
+    r[12] = r[10] ^ r[4];
+    r[4] = r[11] & POOL[57];
+    r[10] = r[14] ^ POOL[58];
+    r[14] = r[10] ^ POOL[59];
+    r[10] = r[14] ^ POOL[60];
+    r[14] = r[3] ^ POOL[61];
+    r[3] = {
+        let (t, s) = (POOL[62], POOL[63]);
+        let (ma, mb) = (t ^ s ^ POOL[64], t ^ s ^ POOL[65]);
         let mut z = t;
-        z ^= r[3] & mb;
-        z ^= r[7] & ma;
-        z ^= r[3] & r[7];
+        z ^= r[10] & mb;
+        z ^= r[14] & ma;
+        z ^= r[10] & r[14];
         z
     };
-    r[3] = r[0] ^ r[15];
-    r[7] = r[3].rotate_left(1);
-    r[3] = r[11] & POOL[34];
-    r[0] = r[3] ^ r[7];
-    r[15] = r[5] ^ r[0];
-    r[0] = r[1] & POOL[35];
-    r[5] = {
-        let (t, ma, mb) = (POOL[36], POOL[37], POOL[38]);
+    r[14] = r[4] ^ r[3];
+    r[3] = r[14].rotate_left(1);
+    r[14] = r[0] & POOL[66];
+    r[4] = r[14] ^ r[3];
+    r[10] = r[12] ^ r[4];
+    r[4] = r[11] & POOL[67];
+    r[12] = r[14] ^ POOL[68];
+    r[14] = r[12] ^ POOL[69];
+    r[12] = r[14] ^ POOL[70];
+    r[14] = r[3] ^ POOL[71];
+    r[3] = {
+        let (t, s) = (POOL[72], POOL[73]);
+        let (ma, mb) = (t ^ s ^ POOL[74], t ^ s ^ POOL[75]);
         let mut z = t;
-        z ^= r[3] & mb;
-        z ^= r[7] & ma;
-        z ^= r[3] & r[7];
+        z ^= r[12] & mb;
+        z ^= r[14] & ma;
+        z ^= r[12] & r[14];
         z
     };
-    r[3] = r[0] ^ r[5];
-    r[7] = r[3].rotate_left(1);
-    r[3] = r[11] & POOL[39];
-    r[0] = r[3] ^ r[7];
-    r[5] = r[15] ^ r[0];
-    r[0] = r[1] & POOL[40];
-    r[15] = {
-        let (t, ma, mb) = (POOL[41], POOL[42], POOL[43]);
+    r[14] = r[4] ^ r[3];
+    r[3] = r[14].rotate_left(1);
+    r[14] = r[0] & POOL[76];
+    r[4] = r[14] ^ r[3];
+    r[12] = r[10] ^ r[4];
+    r[4] = r[11] & POOL[77];
+    r[10] = r[14] ^ POOL[78];
+    r[14] = r[10] ^ POOL[79];
+    r[10] = r[14] ^ POOL[80];
+    r[14] = r[3] ^ POOL[81];
+    r[3] = {
+        let (t, s) = (POOL[82], POOL[83]);
+        let (ma, mb) = (t ^ s ^ POOL[84], t ^ s ^ POOL[85]);
         let mut z = t;
-        z ^= r[3] & mb;
-        z ^= r[7] & ma;
-        z ^= r[3] & r[7];
+        z ^= r[10] & mb;
+        z ^= r[14] & ma;
+        z ^= r[10] & r[14];
         z
     };
-    r[3] = r[0] ^ r[15];
-    r[7] = r[3].rotate_left(1);
-    r[3] = r[11] & POOL[44];
-    r[0] = r[3] ^ r[7];
-    r[15] = r[5] ^ r[0];
-    r[0] = r[1] & POOL[45];
-    r[5] = {
-        let (t, ma, mb) = (POOL[46], POOL[47], POOL[48]);
+    r[14] = r[4] ^ r[3];
+    r[3] = r[14].rotate_left(1);
+    r[14] = r[0] & POOL[86];
+    r[4] = r[14] ^ r[3];
+    r[10] = r[12] ^ r[4];
+    r[4] = r[11] & POOL[87];
+    r[12] = r[14] ^ POOL[88];
+    r[14] = r[12] ^ POOL[89];
+    r[12] = r[14] ^ POOL[90];
+    r[14] = r[3] ^ POOL[91];
+    r[3] = {
+        let (t, s) = (POOL[92], POOL[93]);
+        let (ma, mb) = (t ^ s ^ POOL[94], t ^ s ^ POOL[95]);
         let mut z = t;
-        z ^= r[3] & mb;
-        z ^= r[7] & ma;
-        z ^= r[3] & r[7];
+        z ^= r[12] & mb;
+        z ^= r[14] & ma;
+        z ^= r[12] & r[14];
         z
     };
-    r[3] = r[0] ^ r[5];
-    r[7] = r[3].rotate_left(1);
-    r[3] = r[11] & POOL[49];
-    r[0] = r[3] ^ r[7];
-    r[5] = r[15] ^ r[0];
-    r[0] = r[1] & POOL[50];
-    r[15] = {
-        let (t, ma, mb) = (POOL[51], POOL[52], POOL[53]);
+    r[14] = r[4] ^ r[3];
+    r[3] = r[14].rotate_left(1);
+    r[14] = r[0] & POOL[96];
+    r[4] = r[14] ^ r[3];
+    r[12] = r[10] ^ r[4];
+    r[4] = r[11] & POOL[97];
+    r[10] = r[14] ^ POOL[98];
+    r[14] = r[10] ^ POOL[99];
+    r[10] = r[14] ^ POOL[100];
+    r[14] = r[3] ^ POOL[101];
+    r[3] = {
+        let (t, s) = (POOL[102], POOL[103]);
+        let (ma, mb) = (t ^ s ^ POOL[104], t ^ s ^ POOL[105]);
         let mut z = t;
-        z ^= r[3] & mb;
-        z ^= r[7] & ma;
-        z ^= r[3] & r[7];
+        z ^= r[10] & mb;
+        z ^= r[14] & ma;
+        z ^= r[10] & r[14];
         z
     };
-    r[3] = r[0] ^ r[15];
-    r[7] = r[3].rotate_left(1);
-    r[3] = r[11] & POOL[54];
-    r[0] = r[3] ^ r[7];
-    r[15] = r[5] ^ r[0];
-    r[0] = r[1] & POOL[55];
-    r[5] = {
-        let (t, ma, mb) = (POOL[56], POOL[57], POOL[58]);
+    r[14] = r[4] ^ r[3];
+    r[3] = r[14].rotate_left(1);
+    r[14] = r[0] & POOL[106];
+    r[4] = r[14] ^ r[3];
+    r[10] = r[12] ^ r[4];
+    r[4] = r[11] & POOL[107];
+    r[12] = r[14] ^ POOL[108];
+    r[14] = r[12] ^ POOL[109];
+    r[12] = r[14] ^ POOL[110];
+    r[14] = r[3] ^ POOL[111];
+    r[3] = {
+        let (t, s) = (POOL[112], POOL[113]);
+        let (ma, mb) = (t ^ s ^ POOL[114], t ^ s ^ POOL[115]);
         let mut z = t;
-        z ^= r[3] & mb;
-        z ^= r[7] & ma;
-        z ^= r[3] & r[7];
+        z ^= r[12] & mb;
+        z ^= r[14] & ma;
+        z ^= r[12] & r[14];
         z
     };
-    r[3] = r[0] ^ r[5];
-    r[7] = r[3].rotate_left(1);
-    r[3] = r[11] & POOL[59];
-    r[0] = r[3] ^ r[7];
-    r[5] = r[15] ^ r[0];
-    r[0] = r[1] & POOL[60];
-    r[15] = {
-        let (t, ma, mb) = (POOL[61], POOL[62], POOL[63]);
+    r[14] = r[4] ^ r[3];
+    r[3] = r[14].rotate_left(1);
+    r[14] = r[0] & POOL[116];
+    r[4] = r[14] ^ r[3];
+    r[12] = r[10] ^ r[4];
+    r[4] = r[11] & POOL[117];
+    r[10] = r[14] ^ POOL[118];
+    r[14] = r[10] ^ POOL[119];
+    r[10] = r[14] ^ POOL[120];
+    r[14] = r[3] ^ POOL[121];
+    r[3] = {
+        let (t, s) = (POOL[122], POOL[123]);
+        let (ma, mb) = (t ^ s ^ POOL[124], t ^ s ^ POOL[125]);
         let mut z = t;
-        z ^= r[3] & mb;
-        z ^= r[7] & ma;
-        z ^= r[3] & r[7];
+        z ^= r[10] & mb;
+        z ^= r[14] & ma;
+        z ^= r[10] & r[14];
         z
     };
-    r[3] = r[0] ^ r[15];
-    r[7] = r[3].rotate_left(1);
-    r[3] = r[11] & POOL[64];
-    r[0] = r[3] ^ r[7];
-    r[15] = r[5] ^ r[0];
-    r[0] = r[1] & POOL[65];
-    r[5] = {
-        let (t, ma, mb) = (POOL[66], POOL[67], POOL[68]);
+    r[14] = r[4] ^ r[3];
+    r[3] = r[14].rotate_left(1);
+    r[14] = r[0] & POOL[126];
+    r[4] = r[14] ^ r[3];
+    r[10] = r[12] ^ r[4];
+    r[4] = r[11] & POOL[127];
+    r[12] = r[14] ^ POOL[128];
+    r[14] = r[12] ^ POOL[129];
+    r[12] = r[14] ^ POOL[130];
+    r[14] = r[3] ^ POOL[131];
+    r[3] = {
+        let (t, s) = (POOL[132], POOL[133]);
+        let (ma, mb) = (t ^ s ^ POOL[134], t ^ s ^ POOL[135]);
         let mut z = t;
-        z ^= r[3] & mb;
-        z ^= r[7] & ma;
-        z ^= r[3] & r[7];
+        z ^= r[12] & mb;
+        z ^= r[14] & ma;
+        z ^= r[12] & r[14];
         z
     };
-    r[3] = r[0] ^ r[5];
-    r[7] = r[3].rotate_left(1);
-    r[3] = r[11] & POOL[69];
-    r[0] = r[3] ^ r[7];
-    r[5] = r[15] ^ r[0];
-    r[0] = r[1] & POOL[70];
-    r[15] = {
-        let (t, ma, mb) = (POOL[71], POOL[72], POOL[73]);
+    r[14] = r[4] ^ r[3];
+    r[3] = r[14].rotate_left(1);
+    r[14] = r[0] & POOL[136];
+    r[4] = r[14] ^ r[3];
+    r[12] = r[10] ^ r[4];
+    r[4] = r[11] & POOL[137];
+    r[10] = r[14] ^ POOL[138];
+    r[14] = r[10] ^ POOL[139];
+    r[10] = r[14] ^ POOL[140];
+    r[14] = r[3] ^ POOL[141];
+    r[3] = {
+        let (t, s) = (POOL[142], POOL[143]);
+        let (ma, mb) = (t ^ s ^ POOL[144], t ^ s ^ POOL[145]);
         let mut z = t;
-        z ^= r[3] & mb;
-        z ^= r[7] & ma;
-        z ^= r[3] & r[7];
+        z ^= r[10] & mb;
+        z ^= r[14] & ma;
+        z ^= r[10] & r[14];
         z
     };
-    r[3] = r[0] ^ r[15];
-    r[7] = r[3].rotate_left(1);
-    r[3] = r[11] & POOL[74];
-    r[0] = r[3] ^ r[7];
-    r[15] = r[5] ^ r[0];
-    r[0] = r[1] & POOL[75];
-    r[5] = {
-        let (t, ma, mb) = (POOL[76], POOL[77], POOL[78]);
+    r[14] = r[4] ^ r[3];
+    r[3] = r[14].rotate_left(1);
+    r[14] = r[0] & POOL[146];
+    r[4] = r[14] ^ r[3];
+    r[10] = r[12] ^ r[4];
+    r[4] = r[11] & POOL[147];
+    r[12] = r[14] ^ POOL[148];
+    r[14] = r[12] ^ POOL[149];
+    r[12] = r[14] ^ POOL[150];
+    r[14] = r[3] ^ POOL[151];
+    r[3] = {
+        let (t, s) = (POOL[152], POOL[153]);
+        let (ma, mb) = (t ^ s ^ POOL[154], t ^ s ^ POOL[155]);
         let mut z = t;
-        z ^= r[3] & mb;
-        z ^= r[7] & ma;
-        z ^= r[3] & r[7];
+        z ^= r[12] & mb;
+        z ^= r[14] & ma;
+        z ^= r[12] & r[14];
         z
     };
-    r[3] = r[0] ^ r[5];
-    r[7] = r[3].rotate_left(1);
-    r[3] = r[11] & POOL[79];
-    r[0] = r[3] ^ r[7];
-    r[5] = r[15] ^ r[0];
-    r[0] = r[1] & POOL[80];
-    r[15] = {
-        let (t, ma, mb) = (POOL[81], POOL[82], POOL[83]);
+    r[14] = r[4] ^ r[3];
+    r[3] = r[14].rotate_left(1);
+    r[14] = r[0] & POOL[156];
+    r[4] = r[14] ^ r[3];
+    r[12] = r[10] ^ r[4];
+    r[4] = r[11] & POOL[157];
+    r[10] = r[14] ^ POOL[158];
+    r[14] = r[10] ^ POOL[159];
+    r[10] = r[14] ^ POOL[160];
+    r[14] = r[3] ^ POOL[161];
+    r[3] = {
+        let (t, s) = (POOL[162], POOL[163]);
+        let (ma, mb) = (t ^ s ^ POOL[164], t ^ s ^ POOL[165]);
         let mut z = t;
-        z ^= r[3] & mb;
-        z ^= r[7] & ma;
-        z ^= r[3] & r[7];
+        z ^= r[10] & mb;
+        z ^= r[14] & ma;
+        z ^= r[10] & r[14];
         z
     };
-    r[3] = r[0] ^ r[15];
-    r[7] = r[3].rotate_left(1);
-    r[3] = r[11] & POOL[84];
-    r[0] = r[3] ^ r[7];
-    r[15] = r[5] ^ r[0];
-    r[0] = r[1] & POOL[85];
-    r[5] = {
-        let (t, ma, mb) = (POOL[86], POOL[87], POOL[88]);
+    r[14] = r[4] ^ r[3];
+    r[3] = r[14].rotate_left(1);
+    r[14] = r[0] & POOL[166];
+    r[4] = r[14] ^ r[3];
+    r[10] = r[12] ^ r[4];
+    r[4] = r[11] & POOL[167];
+    r[12] = r[14] ^ POOL[168];
+    r[14] = r[12] ^ POOL[169];
+    r[12] = r[14] ^ POOL[170];
+    r[14] = r[3] ^ POOL[171];
+    r[3] = {
+        let (t, s) = (POOL[172], POOL[173]);
+        let (ma, mb) = (t ^ s ^ POOL[174], t ^ s ^ POOL[175]);
         let mut z = t;
-        z ^= r[3] & mb;
-        z ^= r[7] & ma;
-        z ^= r[3] & r[7];
+        z ^= r[12] & mb;
+        z ^= r[14] & ma;
+        z ^= r[12] & r[14];
         z
     };
-    r[3] = r[0] ^ r[5];
-    r[7] = r[3].rotate_left(1);
-    r[3] = r[11] & POOL[89];
-    r[0] = r[3] ^ r[7];
-    r[5] = r[15] ^ r[0];
-    r[0] = r[1] & POOL[90];
-    r[15] = {
-        let (t, ma, mb) = (POOL[91], POOL[92], POOL[93]);
+    r[14] = r[4] ^ r[3];
+    r[3] = r[14].rotate_left(1);
+    r[14] = r[0] & POOL[176];
+    r[4] = r[14] ^ r[3];
+    r[12] = r[10] ^ r[4];
+    r[4] = r[11] & POOL[177];
+    r[10] = r[14] ^ POOL[178];
+    r[14] = r[10] ^ POOL[179];
+    r[10] = r[14] ^ POOL[180];
+    r[14] = r[3] ^ POOL[181];
+    r[3] = {
+        let (t, s) = (POOL[182], POOL[183]);
+        let (ma, mb) = (t ^ s ^ POOL[184], t ^ s ^ POOL[185]);
         let mut z = t;
-        z ^= r[3] & mb;
-        z ^= r[7] & ma;
-        z ^= r[3] & r[7];
+        z ^= r[10] & mb;
+        z ^= r[14] & ma;
+        z ^= r[10] & r[14];
         z
     };
-    r[3] = r[0] ^ r[15];
-    r[7] = r[3].rotate_left(1);
-    r[3] = r[11] & POOL[94];
-    r[0] = r[3] ^ r[7];
-    r[15] = r[5] ^ r[0];
-    r[0] = r[1] & POOL[95];
-    r[5] = {
-        let (t, ma, mb) = (POOL[96], POOL[97], POOL[98]);
+    r[14] = r[4] ^ r[3];
+    r[3] = r[14].rotate_left(1);
+    r[14] = r[0] & POOL[186];
+    r[4] = r[14] ^ r[3];
+    r[10] = r[12] ^ r[4];
+    r[4] = r[11] & POOL[187];
+    r[12] = r[14] ^ POOL[188];
+    r[14] = r[12] ^ POOL[189];
+    r[12] = r[14] ^ POOL[190];
+    r[14] = r[3] ^ POOL[191];
+    r[3] = {
+        let (t, s) = (POOL[192], POOL[193]);
+        let (ma, mb) = (t ^ s ^ POOL[194], t ^ s ^ POOL[195]);
         let mut z = t;
-        z ^= r[3] & mb;
-        z ^= r[7] & ma;
-        z ^= r[3] & r[7];
+        z ^= r[12] & mb;
+        z ^= r[14] & ma;
+        z ^= r[12] & r[14];
         z
     };
-    r[3] = r[0] ^ r[5];
-    r[7] = r[3].rotate_left(1);
-    r[3] = r[11] & POOL[99];
-    r[0] = r[3] ^ r[7];
-    r[5] = r[15] ^ r[0];
-    r[0] = r[1] & POOL[100];
-    r[15] = {
-        let (t, ma, mb) = (POOL[101], POOL[102], POOL[103]);
+    r[14] = r[4] ^ r[3];
+    r[3] = r[14].rotate_left(1);
+    r[14] = r[0] & POOL[196];
+    r[4] = r[14] ^ r[3];
+    r[12] = r[10] ^ r[4];
+    r[4] = r[11] & POOL[197];
+    r[10] = r[14] ^ POOL[198];
+    r[14] = r[10] ^ POOL[199];
+    r[10] = r[14] ^ POOL[200];
+    r[14] = r[3] ^ POOL[201];
+    r[3] = {
+        let (t, s) = (POOL[202], POOL[203]);
+        let (ma, mb) = (t ^ s ^ POOL[204], t ^ s ^ POOL[205]);
         let mut z = t;
-        z ^= r[3] & mb;
-        z ^= r[7] & ma;
-        z ^= r[3] & r[7];
+        z ^= r[10] & mb;
+        z ^= r[14] & ma;
+        z ^= r[10] & r[14];
         z
     };
-    r[3] = r[0] ^ r[15];
-    r[7] = r[3].rotate_left(1);
-    r[3] = r[11] & POOL[104];
-    r[0] = r[3] ^ r[7];
-    r[15] = r[5] ^ r[0];
-    r[0] = r[1] & POOL[105];
-    r[5] = {
-        let (t, ma, mb) = (POOL[106], POOL[107], POOL[108]);
+    r[14] = r[4] ^ r[3];
+    r[3] = r[14].rotate_left(1);
+    r[14] = r[0] & POOL[206];
+    r[4] = r[14] ^ r[3];
+    r[10] = r[12] ^ r[4];
+    r[4] = r[11] & POOL[207];
+    r[12] = r[14] ^ POOL[208];
+    r[14] = r[12] ^ POOL[209];
+    r[12] = r[14] ^ POOL[210];
+    r[14] = r[3] ^ POOL[211];
+    r[3] = {
+        let (t, s) = (POOL[212], POOL[213]);
+        let (ma, mb) = (t ^ s ^ POOL[214], t ^ s ^ POOL[215]);
         let mut z = t;
-        z ^= r[3] & mb;
-        z ^= r[7] & ma;
-        z ^= r[3] & r[7];
+        z ^= r[12] & mb;
+        z ^= r[14] & ma;
+        z ^= r[12] & r[14];
         z
     };
-    r[3] = r[0] ^ r[5];
-    r[7] = r[3].rotate_left(1);
-    r[3] = r[11] & POOL[109];
-    r[0] = r[3] ^ r[7];
-    r[5] = r[15] ^ r[0];
-    r[0] = r[1] & POOL[110];
-    r[15] = {
-        let (t, ma, mb) = (POOL[111], POOL[112], POOL[113]);
+    r[14] = r[4] ^ r[3];
+    r[3] = r[14].rotate_left(1);
+    r[14] = r[0] & POOL[216];
+    r[4] = r[14] ^ r[3];
+    r[12] = r[10] ^ r[4];
+    r[4] = r[11] & POOL[217];
+    r[10] = r[14] ^ POOL[218];
+    r[14] = r[10] ^ POOL[219];
+    r[10] = r[14] ^ POOL[220];
+    r[14] = r[3] ^ POOL[221];
+    r[3] = {
+        let (t, s) = (POOL[222], POOL[223]);
+        let (ma, mb) = (t ^ s ^ POOL[224], t ^ s ^ POOL[225]);
         let mut z = t;
-        z ^= r[3] & mb;
-        z ^= r[7] & ma;
-        z ^= r[3] & r[7];
+        z ^= r[10] & mb;
+        z ^= r[14] & ma;
+        z ^= r[10] & r[14];
         z
     };
-    r[3] = r[0] ^ r[15];
-    r[7] = r[3].rotate_left(1);
-    r[3] = r[11] & POOL[114];
-    r[0] = r[3] ^ r[7];
-    r[15] = r[5] ^ r[0];
-    r[0] = r[1] & POOL[115];
-    r[5] = {
-        let (t, ma, mb) = (POOL[116], POOL[117], POOL[118]);
+    r[14] = r[4] ^ r[3];
+    r[3] = r[14].rotate_left(1);
+    r[14] = r[0] & POOL[226];
+    r[4] = r[14] ^ r[3];
+    r[10] = r[12] ^ r[4];
+    r[4] = r[11] & POOL[227];
+    r[12] = r[14] ^ POOL[228];
+    r[14] = r[12] ^ POOL[229];
+    r[12] = r[14] ^ POOL[230];
+    r[14] = r[3] ^ POOL[231];
+    r[3] = {
+        let (t, s) = (POOL[232], POOL[233]);
+        let (ma, mb) = (t ^ s ^ POOL[234], t ^ s ^ POOL[235]);
         let mut z = t;
-        z ^= r[3] & mb;
-        z ^= r[7] & ma;
-        z ^= r[3] & r[7];
+        z ^= r[12] & mb;
+        z ^= r[14] & ma;
+        z ^= r[12] & r[14];
         z
     };
-    r[3] = r[0] ^ r[5];
-    r[7] = r[3].rotate_left(1);
-    r[3] = r[11] & POOL[119];
-    r[0] = r[3] ^ r[7];
-    r[5] = r[15] ^ r[0];
-    r[0] = r[1] & POOL[120];
-    r[15] = {
-        let (t, ma, mb) = (POOL[121], POOL[122], POOL[123]);
+    r[14] = r[4] ^ r[3];
+    r[3] = r[14].rotate_left(1);
+    r[14] = r[0] & POOL[236];
+    r[4] = r[14] ^ r[3];
+    r[12] = r[10] ^ r[4];
+    r[4] = r[11] & POOL[237];
+    r[10] = r[14] ^ POOL[238];
+    r[14] = r[10] ^ POOL[239];
+    r[10] = r[14] ^ POOL[240];
+    r[14] = r[3] ^ POOL[241];
+    r[3] = {
+        let (t, s) = (POOL[242], POOL[243]);
+        let (ma, mb) = (t ^ s ^ POOL[244], t ^ s ^ POOL[245]);
         let mut z = t;
-        z ^= r[3] & mb;
-        z ^= r[7] & ma;
-        z ^= r[3] & r[7];
+        z ^= r[10] & mb;
+        z ^= r[14] & ma;
+        z ^= r[10] & r[14];
         z
     };
-    r[3] = r[0] ^ r[15];
-    r[7] = r[3].rotate_left(1);
-    r[3] = r[11] & POOL[124];
-    r[0] = r[3] ^ r[7];
-    r[15] = r[5] ^ r[0];
-    r[0] = r[1] & POOL[125];
-    r[5] = {
-        let (t, ma, mb) = (POOL[126], POOL[127], POOL[128]);
+    r[14] = r[4] ^ r[3];
+    r[3] = r[14].rotate_left(1);
+    r[14] = r[0] & POOL[246];
+    r[4] = r[14] ^ r[3];
+    r[10] = r[12] ^ r[4];
+    r[4] = r[11] & POOL[247];
+    r[12] = r[14] ^ POOL[248];
+    r[14] = r[12] ^ POOL[249];
+    r[12] = r[14] ^ POOL[250];
+    r[14] = r[3] ^ POOL[251];
+    r[3] = {
+        let (t, s) = (POOL[252], POOL[253]);
+        let (ma, mb) = (t ^ s ^ POOL[254], t ^ s ^ POOL[255]);
         let mut z = t;
-        z ^= r[3] & mb;
-        z ^= r[7] & ma;
-        z ^= r[3] & r[7];
+        z ^= r[12] & mb;
+        z ^= r[14] & ma;
+        z ^= r[12] & r[14];
         z
     };
-    r[3] = r[0] ^ r[5];
-    r[7] = r[3].rotate_left(1);
-    r[3] = r[11] & POOL[129];
-    r[0] = r[3] ^ r[7];
-    r[5] = r[15] ^ r[0];
-    r[0] = r[1] & POOL[130];
-    r[15] = {
-        let (t, ma, mb) = (POOL[131], POOL[132], POOL[133]);
+    r[14] = r[4] ^ r[3];
+    r[3] = r[14].rotate_left(1);
+    r[14] = r[0] & POOL[256];
+    r[4] = r[14] ^ r[3];
+    r[12] = r[10] ^ r[4];
+    r[4] = r[11] & POOL[257];
+    r[10] = r[14] ^ POOL[258];
+    r[14] = r[10] ^ POOL[259];
+    r[10] = r[14] ^ POOL[260];
+    r[14] = r[3] ^ POOL[261];
+    r[3] = {
+        let (t, s) = (POOL[262], POOL[263]);
+        let (ma, mb) = (t ^ s ^ POOL[264], t ^ s ^ POOL[265]);
         let mut z = t;
-        z ^= r[3] & mb;
-        z ^= r[7] & ma;
-        z ^= r[3] & r[7];
+        z ^= r[10] & mb;
+        z ^= r[14] & ma;
+        z ^= r[10] & r[14];
         z
     };
-    r[3] = r[0] ^ r[15];
-    r[7] = r[3].rotate_left(1);
-    r[3] = r[11] & POOL[134];
-    r[0] = r[3] ^ r[7];
-    r[15] = r[5] ^ r[0];
-    r[0] = r[1] & POOL[135];
-    r[5] = {
-        let (t, ma, mb) = (POOL[136], POOL[137], POOL[138]);
+    r[14] = r[4] ^ r[3];
+    r[3] = r[14].rotate_left(1);
+    r[14] = r[0] & POOL[266];
+    r[4] = r[14] ^ r[3];
+    r[10] = r[12] ^ r[4];
+    r[4] = r[11] & POOL[267];
+    r[12] = r[14] ^ POOL[268];
+    r[14] = r[12] ^ POOL[269];
+    r[12] = r[14] ^ POOL[270];
+    r[14] = r[3] ^ POOL[271];
+    r[3] = {
+        let (t, s) = (POOL[272], POOL[273]);
+        let (ma, mb) = (t ^ s ^ POOL[274], t ^ s ^ POOL[275]);
         let mut z = t;
-        z ^= r[3] & mb;
-        z ^= r[7] & ma;
-        z ^= r[3] & r[7];
+        z ^= r[12] & mb;
+        z ^= r[14] & ma;
+        z ^= r[12] & r[14];
         z
     };
-    r[3] = r[0] ^ r[5];
-    r[7] = r[3].rotate_left(1);
-    r[3] = r[11] & POOL[139];
-    r[0] = r[3] ^ r[7];
-    r[5] = r[15] ^ r[0];
-    r[0] = r[1] & POOL[140];
-    r[15] = {
-        let (t, ma, mb) = (POOL[141], POOL[142], POOL[143]);
+    r[14] = r[4] ^ r[3];
+    r[3] = r[14].rotate_left(1);
+    r[14] = r[0] & POOL[276];
+    r[4] = r[14] ^ r[3];
+    r[12] = r[10] ^ r[4];
+    r[4] = r[11] & POOL[277];
+    r[10] = r[14] ^ POOL[278];
+    r[14] = r[10] ^ POOL[279];
+    r[10] = r[14] ^ POOL[280];
+    r[14] = r[3] ^ POOL[281];
+    r[3] = {
+        let (t, s) = (POOL[282], POOL[283]);
+        let (ma, mb) = (t ^ s ^ POOL[284], t ^ s ^ POOL[285]);
         let mut z = t;
-        z ^= r[3] & mb;
-        z ^= r[7] & ma;
-        z ^= r[3] & r[7];
+        z ^= r[10] & mb;
+        z ^= r[14] & ma;
+        z ^= r[10] & r[14];
         z
     };
-    r[3] = r[0] ^ r[15];
-    r[7] = r[3].rotate_left(1);
-    r[3] = r[11] & POOL[144];
-    r[0] = r[3] ^ r[7];
-    r[15] = r[5] ^ r[0];
-    r[0] = r[1] & POOL[145];
-    r[5] = {
-        let (t, ma, mb) = (POOL[146], POOL[147], POOL[148]);
+    r[14] = r[4] ^ r[3];
+    r[3] = r[14].rotate_left(1);
+    r[14] = r[0] & POOL[286];
+    r[4] = r[14] ^ r[3];
+    r[10] = r[12] ^ r[4];
+    r[4] = r[11] & POOL[287];
+    r[12] = r[14] ^ POOL[288];
+    r[14] = r[12] ^ POOL[289];
+    r[12] = r[14] ^ POOL[290];
+    r[14] = r[3] ^ POOL[291];
+    r[3] = {
+        let (t, s) = (POOL[292], POOL[293]);
+        let (ma, mb) = (t ^ s ^ POOL[294], t ^ s ^ POOL[295]);
         let mut z = t;
-        z ^= r[3] & mb;
-        z ^= r[7] & ma;
-        z ^= r[3] & r[7];
+        z ^= r[12] & mb;
+        z ^= r[14] & ma;
+        z ^= r[12] & r[14];
         z
     };
-    r[3] = r[0] ^ r[5];
-    r[7] = r[3].rotate_left(1);
-    r[3] = r[11] & POOL[149];
-    r[0] = r[3] ^ r[7];
-    r[5] = r[15] ^ r[0];
-    r[0] = r[1] & POOL[150];
-    r[15] = {
-        let (t, ma, mb) = (POOL[151], POOL[152], POOL[153]);
+    r[14] = r[4] ^ r[3];
+    r[3] = r[14].rotate_left(1);
+    r[14] = r[0] & POOL[296];
+    r[4] = r[14] ^ r[3];
+    r[12] = r[10] ^ r[4];
+    r[4] = r[11] & POOL[297];
+    r[10] = r[14] ^ POOL[298];
+    r[14] = r[10] ^ POOL[299];
+    r[10] = r[14] ^ POOL[300];
+    r[14] = r[3] ^ POOL[301];
+    r[3] = {
+        let (t, s) = (POOL[302], POOL[303]);
+        let (ma, mb) = (t ^ s ^ POOL[304], t ^ s ^ POOL[305]);
         let mut z = t;
-        z ^= r[3] & mb;
-        z ^= r[7] & ma;
-        z ^= r[3] & r[7];
+        z ^= r[10] & mb;
+        z ^= r[14] & ma;
+        z ^= r[10] & r[14];
         z
     };
-    r[3] = r[0] ^ r[15];
-    r[7] = r[3].rotate_left(1);
-    r[3] = r[11] & POOL[154];
-    r[0] = r[3] ^ r[7];
-    r[15] = r[5] ^ r[0];
-    r[0] = r[1] & POOL[155];
-    r[1] = {
-        let (t, ma, mb) = (POOL[156], POOL[157], POOL[158]);
+    r[14] = r[4] ^ r[3];
+    r[3] = r[14].rotate_left(1);
+    r[14] = r[0] & POOL[306];
+    r[4] = r[14] ^ r[3];
+    r[10] = r[12] ^ r[4];
+    r[4] = r[11] & POOL[307];
+    r[11] = r[14] ^ POOL[308];
+    r[14] = r[11] ^ POOL[309];
+    r[11] = r[14] ^ POOL[310];
+    r[14] = r[3] ^ POOL[311];
+    r[3] = {
+        let (t, s) = (POOL[312], POOL[313]);
+        let (ma, mb) = (t ^ s ^ POOL[314], t ^ s ^ POOL[315]);
         let mut z = t;
-        z ^= r[3] & mb;
-        z ^= r[7] & ma;
-        z ^= r[3] & r[7];
-        z
-    };
-    r[3] = r[0] ^ r[1];
-    r[1] = r[3].rotate_left(1);
-    r[3] = r[11] & POOL[159];
-    r[11] = r[3] ^ r[1];
-    r[1] = r[15] ^ r[11];
-    r[11] = r[12] ^ POOL[160];
-    r[3] = c ^ POOL[161];
-    r[7] = d ^ POOL[162];
-    r[0] = r[1] ^ r[7];
-    r[15] = r[0].rotate_left(16);
-    r[0] = {
-        let (t, ma, mb) = (POOL[163], POOL[164], POOL[165]);
+        z ^= r[11] & mb;
+        z ^= r[14] & ma;
+        z ^= r[11] & r[14];
+        z
+    };
+    r[11] = r[4] ^ r[3];
+    r[3] = r[11].rotate_left(1);
+    r[11] = r[0] & POOL[316];
+    r[0] = r[11] ^ r[3];
+    r[11] = r[10] ^ r[0];
+    r[0] = r[8] ^ POOL[317];
+    r[3] = c ^ POOL[318];
+    r[14] = d ^ POOL[319];
+    r[4] = r[11] ^ r[14];
+    r[10] = r[4].rotate_left(16);
+    r[4] = r[3] ^ POOL[320];
+    r[12] = r[4] ^ POOL[321];
+    r[4] = r[10] ^ POOL[322];
+    r[13] = {
+        let (t, s) = (POOL[323], POOL[324]);
+        let (ma, mb) = (t ^ s ^ POOL[325], t ^ s ^ POOL[326]);
         let mut z = t;
-        z ^= r[3] & mb;
+        z ^= r[12] & mb;
+        z ^= r[4] & ma;
+        z ^= r[12] & r[4];
+        z
+    };
+    r[4] = r[3] ^ r[10];
+    r[12] = r[4] & POOL[327];
+    r[15] = r[13] & POOL[328];
+    r[2] = r[15].rotate_left(1);
+    r[15] = r[4] & POOL[329];
+    r[9] = r[15] ^ r[2];
+    r[6] = r[12] ^ r[9];
+    r[12] = r[13] & POOL[330];
+    r[9] = r[15] ^ POOL[331];
+    r[15] = r[9] ^ POOL[332];
+    r[9] = r[15] ^ POOL[333];
+    r[15] = r[2] ^ POOL[334];
+    r[2] = r[15] ^ POOL[335];
+    r[15] = r[2] ^ POOL[336];
+    r[2] = {
+        let (t, s) = (POOL[337], POOL[338]);
+        let (ma, mb) = (t ^ s ^ POOL[339], t ^ s ^ POOL[340]);
+        let mut z = t;
+        z ^= r[9] & mb;
         z ^= r[15] & ma;
-        z ^= r[3] & r[15];
-        z
-    };
-    r[5] = r[3] ^ r[15];
-    r[10] = r[5] & POOL[166];
-    r[2] = r[0] & POOL[167];
-    r[4] = r[2].rotate_left(1);
-    r[2] = r[5] & POOL[168];
-    r[14] = r[2] ^ r[4];
-    r[8] = r[10] ^ r[14];
-    r[10] = r[0] & POOL[169];
-    r[14] = {
-        let (t, ma, mb) = (POOL[170], POOL[171], POOL[172]);
+        z ^= r[9] & r[15];
+        z
+    };
+    r[15] = r[12] ^ r[2];
+    r[12] = r[15].rotate_left(1);
+    r[15] = r[4] & POOL[341];
+    r[2] = r[15] ^ r[12];
+    r[9] = r[6] ^ r[2];
+    r[2] = r[13] & POOL[342];
+    r[6] = r[15] ^ POOL[343];
+    r[15] = r[6] ^ POOL[344];
+    r[6] = r[15] ^ POOL[345];
+    r[15] = r[12] ^ POOL[346];
+    r[12] = {
+        let (t, s) = (POOL[347], POOL[348]);
+        let (ma, mb) = (t ^ s ^ POOL[349], t ^ s ^ POOL[350]);
         let mut z = t;
-        z ^= r[2] & mb;
-        z ^= r[4] & ma;
-        z ^= r[2] & r[4];
-        z
-    };
-    r[2] = r[10] ^ r[14];
-    r[10] = r[2].rotate_left(1);
-    r[2] = r[5] & POOL[173];
-    r[4] = r[2] ^ r[10];
-    r[14] = r[8] ^ r[4];
-    r[4] = r[0] & POOL[174];
-    r[8] = {
-        let (t, ma, mb) = (POOL[175], POOL[176], POOL[177]);
+        z ^= r[6] & mb;
+        z ^= r[15] & ma;
+        z ^= r[6] & r[15];
+        z
+    };
+    r[15] = r[2] ^ r[12];
+    r[12] = r[15].rotate_left(1);
+    r[15] = r[4] & POOL[351];
+    r[2] = r[15] ^ r[12];
+    r[6] = r[9] ^ r[2];
+    r[2] = r[13] & POOL[352];
+    r[9] = r[15] ^ POOL[353];
+    r[15] = r[9] ^ POOL[354];
+    r[9] = r[15] ^ POOL[355];
+    r[15] = r[12] ^ POOL[356];
+    r[12] = {
+        let (t, s) = (POOL[357], POOL[358]);
+        let (ma, mb) = (t ^ s ^ POOL[359], t ^ s ^ POOL[360]);
         let mut z = t;
-        z ^= r[2] & mb;
-        z ^= r[10] & ma;
-        z ^= r[2] & r[10];
-        z
-    };
-    r[10] = r[4] ^ r[8];
-    r[2] = r[10].rotate_left(1);
-    r[10] = r[5] & POOL[178];
-    r[4] = r[10] ^ r[2];
-    r[8] = r[14] ^ r[4];
-    r[4] = r[0] & POOL[179];
-    r[14] = {
-        let (t, ma, mb) = (POOL[180], POOL[181], POOL[182]);
+        z ^= r[9] & mb;
+        z ^= r[15] & ma;
+        z ^= r[9] & r[15];
+        z
+    };
+    r[15] = r[2] ^ r[12];
+    r[12] = r[15].rotate_left(1);
+    r[15] = r[4] & POOL[361];
+    r[2] = r[15] ^ r[12];
+    r[9] = r[6] ^ r[2];
+    r[2] = r[13] & POOL[362];
+    r[6] = r[15] ^ POOL[363];
+    r[15] = r[6] ^ POOL[364];
+    r[6] = r[15] ^ POOL[365];
+    r[15] = r[12] ^ POOL[366];
+    r[12] = {
+        let (t, s) = (POOL[367], POOL[368]);
+        let (ma, mb) = (t ^ s ^ POOL[369], t ^ s ^ POOL[370]);
         let mut z = t;
-        z ^= r[10] & mb;
-        z ^= r[2] & ma;
-        z ^= r[10] & r[2];
-        z
-    };
-    r[10] = r[4] ^ r[14];
-    r[2] = r[10].rotate_left(1);
-    r[10] = r[5] & POOL[183];
-    r[4] = r[10] ^ r[2];
-    r[14] = r[8] ^ r[4];
-    r[4] = r[0] & POOL[184];
-    r[8] = {
-        let (t, ma, mb) = (POOL[185], POOL[186], POOL[187]);
+        z ^= r[6] & mb;
+        z ^= r[15] & ma;
+        z ^= r[6] & r[15];
+        z
+    };
+    r[15] = r[2] ^ r[12];
+    r[12] = r[15].rotate_left(1);
+    r[15] = r[4] & POOL[371];
+    r[2] = r[15] ^ r[12];
+    r[6] = r[9] ^ r[2];
+    r[2] = r[13] & POOL[372];
+    r[9] = r[15] ^ POOL[373];
+    r[15] = r[9] ^ POOL[374];
+    r[9] = r[15] ^ POOL[375];
+    r[15] = r[12] ^ POOL[376];
+    r[12] = {
+        let (t, s) = (POOL[377], POOL[378]);
+        let (ma, mb) = (t ^ s ^ POOL[379], t ^ s ^ POOL[380]);
         let mut z = t;
-        z ^= r[10] & mb;
-        z ^= r[2] & ma;
-        z ^= r[10] & r[2];
-        z
-    };
-    r[10] = r[4] ^ r[8];
-    r[2] = r[10].rotate_left(1);
-    r[10] = r[5] & POOL[188];
-    r[4] = r[10] ^ r[2];
-    r[8] = r[14] ^ r[4];
-    r[4] = r[0] & POOL[189];
-    r[14] = {
-        let (t, ma, mb) = (POOL[190], POOL[191], POOL[192]);
+        z ^= r[9] & mb;
+        z ^= r[15] & ma;
+        z ^= r[9] & r[15];
+        z
+    };
+    r[15] = r[2] ^ r[12];
+    r[12] = r[15].rotate_left(1);
+    r[15] = r[4] & POOL[381];
+    r[2] = r[15] ^ r[12];
+    r[9] = r[6] ^ r[2];
+    r[2] = r[13] & POOL[382];
+    r[6] = r[15] ^ POOL[383];
+    r[15] = r[6] ^ POOL[384];
+    r[6] = r[15] ^ POOL[385];
+    r[15] = r[12] ^ POOL[386];
+    r[12] = {
+        let (t, s) = (POOL[387], POOL[388]);
+        let (ma, mb) = (t ^ s ^ POOL[389], t ^ s ^ POOL[390]);
         let mut z = t;
-        z ^= r[10] & mb;
-        z ^= r[2] & ma;
-        z ^= r[10] & r[2];
-        z
-    };
-    r[10] = r[4] ^ r[14];
-    r[2] = r[10].rotate_left(1);
-    r[10] = r[5] & POOL[193];
-    r[4] = r[10] ^ r[2];
-    r[14] = r[8] ^ r[4];
-    r[4] = r[0] & POOL[194];
-    r[8] = {
-        let (t, ma, mb) = (POOL[195], POOL[196], POOL[197]);
+        z ^= r[6] & mb;
+        z ^= r[15] & ma;
+        z ^= r[6] & r[15];
+        z
+    };
+    r[15] = r[2] ^ r[12];
+    r[12] = r[15].rotate_left(1);
+    r[15] = r[4] & POOL[391];
+    r[2] = r[15] ^ r[12];
+    r[6] = r[9] ^ r[2];
+    r[2] = r[13] & POOL[392];
+    r[9] = r[15] ^ POOL[393];
+    r[15] = r[9] ^ POOL[394];
+    r[9] = r[15] ^ POOL[395];
+    r[15] = r[12] ^ POOL[396];
+    r[12] = {
+        let (t, s) = (POOL[397], POOL[398]);
+        let (ma, mb) = (t ^ s ^ POOL[399], t ^ s ^ POOL[400]);
         let mut z = t;
-        z ^= r[10] & mb;
-        z ^= r[2] & ma;
-        z ^= r[10] & r[2];
-        z
-    };
-    r[10] = r[4] ^ r[8];
-    r[2] = r[10].rotate_left(1);
-    r[10] = r[5] & POOL[198];
-    r[4] = r[10] ^ r[2];
-    r[8] = r[14] ^ r[4];
-    r[4] = r[0] & POOL[199];
-    r[14] = {
-        let (t, ma, mb) = (POOL[200], POOL[201], POOL[202]);
+        z ^= r[9] & mb;
+        z ^= r[15] & ma;
+        z ^= r[9] & r[15];
+        z
+    };
+    r[15] = r[2] ^ r[12];
+    r[12] = r[15].rotate_left(1);
+    r[15] = r[4] & POOL[401];
+    r[2] = r[15] ^ r[12];
+    r[9] = r[6] ^ r[2];
+    r[2] = r[13] & POOL[402];
+    r[6] = r[15] ^ POOL[403];
+    r[15] = r[6] ^ POOL[404];
+    r[6] = r[15] ^ POOL[405];
+    r[15] = r[12] ^ POOL[406];
+    r[12] = {
+        let (t, s) = (POOL[407], POOL[408]);
+        let (ma, mb) = (t ^ s ^ POOL[409], t ^ s ^ POOL[410]);
         let mut z = t;
-        z ^= r[10] & mb;
-        z ^= r[2] & ma;
-        z ^= r[10] & r[2];
-        z
-    };
-    r[10] = r[4] ^ r[14];
-    r[2] = r[10].rotate_left(1);
-    r[10] = r[5] & POOL[203];
-    r[4] = r[10] ^ r[2];
-    r[14] = r[8] ^ r[4];
-    r[4] = r[0] & POOL[204];
-    r[8] = {
-        let (t, ma, mb) = (POOL[205], POOL[206], POOL[207]);
+        z ^= r[6] & mb;
+        z ^= r[15] & ma;
+        z ^= r[6] & r[15];
+        z
+    };
+    r[15] = r[2] ^ r[12];
+    r[12] = r[15].rotate_left(1);
+    r[15] = r[4] & POOL[411];
+    r[2] = r[15] ^ r[12];
+    r[6] = r[9] ^ r[2];
+    r[2] = r[13] & POOL[412];
+    r[9] = r[15] ^ POOL[413];
+    r[15] = r[9] ^ POOL[414];
+    r[9] = r[15] ^ POOL[415];
+    r[15] = r[12] ^ POOL[416];
+    r[12] = {
+        let (t, s) = (POOL[417], POOL[418]);
+        let (ma, mb) = (t ^ s ^ POOL[419], t ^ s ^ POOL[420]);
         let mut z = t;
-        z ^= r[10] & mb;
-        z ^= r[2] & ma;
-        z ^= r[10] & r[2];
-        z
-    };
-    r[10] = r[4] ^ r[8];
-    r[2] = r[10].rotate_left(1);
-    r[10] = r[5] & POOL[208];
-    r[4] = r[10] ^ r[2];
-    r[8] = r[14] ^ r[4];
-    r[4] = r[0] & POOL[209];
-    r[14] = {
-        let (t, ma, mb) = (POOL[210], POOL[211], POOL[212]);
+        z ^= r[9] & mb;
+        z ^= r[15] & ma;
+        z ^= r[9] & r[15];
+        z
+    };
+    r[15] = r[2] ^ r[12];
+    r[12] = r[15].rotate_left(1);
+    r[15] = r[4] & POOL[421];
+    r[2] = r[15] ^ r[12];
+    r[9] = r[6] ^ r[2];
+    r[2] = r[13] & POOL[422];
+    r[6] = r[15] ^ POOL[423];
+    r[15] = r[6] ^ POOL[424];
+    r[6] = r[15] ^ POOL[425];
+    r[15] = r[12] ^ POOL[426];
+    r[12] = {
+        let (t, s) = (POOL[427], POOL[428]);
+        let (ma, mb) = (t ^ s ^ POOL[429], t ^ s ^ POOL[430]);
         let mut z = t;
-        z ^= r[10] & mb;
-        z ^= r[2] & ma;
-        z ^= r[10] & r[2];
-        z
-    };
-    r[10] = r[4] ^ r[14];
-    r[2] = r[10].rotate_left(1);
-    r[10] = r[5] & POOL[213];
-    r[4] = r[10] ^ r[2];
-    r[14] = r[8] ^ r[4];
-    r[4] = r[0] & POOL[214];
-    r[8] = {
-        let (t, ma, mb) = (POOL[215], POOL[216], POOL[217]);
+        z ^= r[6] & mb;
+        z ^= r[15] & ma;
+        z ^= r[6] & r[15];
+        z
+    };
+    r[15] = r[2] ^ r[12];
+    r[12] = r[15].rotate_left(1);
+    r[15] = r[4] & POOL[431];
+    r[2] = r[15] ^ r[12];
+    r[6] = r[9] ^ r[2];
+    r[2] = r[13] & POOL[432];
+    r[9] = r[15] ^ POOL[433];
+    r[15] = r[9] ^ POOL[434];
+    r[9] = r[15] ^ POOL[435];
+    r[15] = r[12] ^ POOL[436];
+    r[12] = {
+        let (t, s) = (POOL[437], POOL[438]);
+        let (ma, mb) = (t ^ s ^ POOL[439], t ^ s ^ POOL[440]);
         let mut z = t;
-        z ^= r[10] & mb;
-        z ^= r[2] & ma;
-        z ^= r[10] & r[2];
-        z
-    };
-    r[10] = r[4] ^ r[8];
-    r[2] = r[10].rotate_left(1);
-    r[10] = r[5] & POOL[218];
-    r[4] = r[10] ^ r[2];
-    r[8] = r[14] ^ r[4];
-    r[4] = r[0] & POOL[219];
-    r[14] = {
-        let (t, ma, mb) = (POOL[220], POOL[221], POOL[222]);
+        z ^= r[9] & mb;
+        z ^= r[15] & ma;
+        z ^= r[9] & r[15];
+        z
+    };
+    r[15] = r[2] ^ r[12];
+    r[12] = r[15].rotate_left(1);
+    r[15] = r[4] & POOL[441];
+    r[2] = r[15] ^ r[12];
+    r[9] = r[6] ^ r[2];
+    r[2] = r[13] & POOL[442];
+    r[6] = r[15] ^ POOL[443];
+    r[15] = r[6] ^ POOL[444];
+    r[6] = r[15] ^ POOL[445];
+    r[15] = r[12] ^ POOL[446];
+    r[12] = {
+        let (t, s) = (POOL[447], POOL[448]);
+        let (ma, mb) = (t ^ s ^ POOL[449], t ^ s ^ POOL[450]);
         let mut z = t;
-        z ^= r[10] & mb;
-        z ^= r[2] & ma;
-        z ^= r[10] & r[2];
-        z
-    };
-    r[10] = r[4] ^ r[14];
-    r[2] = r[10].rotate_left(1);
-    r[10] = r[5] & POOL[223];
-    r[4] = r[10] ^ r[2];
-    r[14] = r[8] ^ r[4];
-    r[4] = r[0] & POOL[224];
-    r[8] = {
-        let (t, ma, mb) = (POOL[225], POOL[226], POOL[227]);
+        z ^= r[6] & mb;
+        z ^= r[15] & ma;
+        z ^= r[6] & r[15];
+        z
+    };
+    r[15] = r[2] ^ r[12];
+    r[12] = r[15].rotate_left(1);
+    r[15] = r[4] & POOL[451];
+    r[2] = r[15] ^ r[12];
+    r[6] = r[9] ^ r[2];
+    r[2] = r[13] & POOL[452];
+    r[9] = r[15] ^ POOL[453];
+    r[15] = r[9] ^ POOL[454];
+    r[9] = r[15] ^ POOL[455];
+    r[15] = r[12] ^ POOL[456];
+    r[12] = {
+        let (t, s) = (POOL[457], POOL[458]);
+        let (ma, mb) = (t ^ s ^ POOL[459], t ^ s ^ POOL[460]);
         let mut z = t;
-        z ^= r[10] & mb;
-        z ^= r[2] & ma;
-        z ^= r[10] & r[2];
-        z
-    };
-    r[10] = r[4] ^ r[8];
-    r[2] = r[10].rotate_left(1);
-    r[10] = r[5] & POOL[228];
-    r[4] = r[10] ^ r[2];
-    r[8] = r[14] ^ r[4];
-    r[4] = r[0] & POOL[229];
-    r[14] = {
-        let (t, ma, mb) = (POOL[230], POOL[231], POOL[232]);
+        z ^= r[9] & mb;
+        z ^= r[15] & ma;
+        z ^= r[9] & r[15];
+        z
+    };
+    r[15] = r[2] ^ r[12];
+    r[12] = r[15].rotate_left(1);
+    r[15] = r[4] & POOL[461];
+    r[2] = r[15] ^ r[12];
+    r[9] = r[6] ^ r[2];
+    r[2] = r[13] & POOL[462];
+    r[6] = r[15] ^ POOL[463];
+    r[15] = r[6] ^ POOL[464];
+    r[6] = r[15] ^ POOL[465];
+    r[15] = r[12] ^ POOL[466];
+    r[12] = {
+        let (t, s) = (POOL[467], POOL[468]);
+        let (ma, mb) = (t ^ s ^ POOL[469], t ^ s ^ POOL[470]);
         let mut z = t;
-        z ^= r[10] & mb;
-        z ^= r[2] & ma;
-        z ^= r[10] & r[2];
-        z
-    };
-    r[10] = r[4] ^ r[14];
-    r[2] = r[10].rotate_left(1);
-    r[10] = r[5] & POOL[233];
-    r[4] = r[10] ^ r[2];
-    r[14] = r[8] ^ r[4];
-    r[4] = r[0] & POOL[234];
-    r[8] = {
-        let (t, ma, mb) = (POOL[235], POOL[236], POOL[237]);
+        z ^= r[6] & mb;
+        z ^= r[15] & ma;
+        z ^= r[6] & r[15];
+        z
+    };
+    r[15] = r[2] ^ r[12];
+    r[12] = r[15].rotate_left(1);
+    r[15] = r[4] & POOL[471];
+    r[2] = r[15] ^ r[12];
+    r[6] = r[9] ^ r[2];
+    r[2] = r[13] & POOL[472];
+    r[9] = r[15] ^ POOL[473];
+    r[15] = r[9] ^ POOL[474];
+    r[9] = r[15] ^ POOL[475];
+    r[15] = r[12] ^ POOL[476];
+    r[12] = {
+        let (t, s) = (POOL[477], POOL[478]);
+        let (ma, mb) = (t ^ s ^ POOL[479], t ^ s ^ POOL[480]);
         let mut z = t;
-        z ^= r[10] & mb;
-        z ^= r[2] & ma;
-        z ^= r[10] & r[2];
-        z
-    };
-    r[10] = r[4] ^ r[8];
-    r[2] = r[10].rotate_left(1);
-    r[10] = r[5] & POOL[238];
-    r[4] = r[10] ^ r[2];
-    r[8] = r[14] ^ r[4];
-    r[4] = r[0] & POOL[239];
-    r[14] = {
-        let (t, ma, mb) = (POOL[240], POOL[241], POOL[242]);
+        z ^= r[9] & mb;
+        z ^= r[15] & ma;
+        z ^= r[9] & r[15];
+        z
+    };
+    r[15] = r[2] ^ r[12];
+    r[12] = r[15].rotate_left(1);
+    r[15] = r[4] & POOL[481];
+    r[2] = r[15] ^ r[12];
+    r[9] = r[6] ^ r[2];
+    r[2] = r[13] & POOL[482];
+    r[6] = r[15] ^ POOL[483];
+    r[15] = r[6] ^ POOL[484];
+    r[6] = r[15] ^ POOL[485];
+    r[15] = r[12] ^ POOL[486];
+    r[12] = {
+        let (t, s) = (POOL[487], POOL[488]);
+        let (ma, mb) = (t ^ s ^ POOL[489], t ^ s ^ POOL[490]);
         let mut z = t;
-        z ^= r[10] & mb;
-        z ^= r[2] & ma;
-        z ^= r[10] & r[2];
-        z
-    };
-    r[10] = r[4] ^ r[14];
-    r[2] = r[10].rotate_left(1);
-    r[10] = r[5] & POOL[243];
-    r[4] = r[10] ^ r[2];
-    r[14] = r[8] ^ r[4];
-    r[4] = r[0] & POOL[244];
-    r[8] = {
-        let (t, ma, mb) = (POOL[245], POOL[246], POOL[247]);
+        z ^= r[6] & mb;
+        z ^= r[15] & ma;
+        z ^= r[6] & r[15];
+        z
+    };
+    r[15] = r[2] ^ r[12];
+    r[12] = r[15].rotate_left(1);
+    r[15] = r[4] & POOL[491];
+    r[2] = r[15] ^ r[12];
+    r[6] = r[9] ^ r[2];
+    r[2] = r[13] & POOL[492];
+    r[9] = r[15] ^ POOL[493];
+    r[15] = r[9] ^ POOL[494];
+    r[9] = r[15] ^ POOL[495];
+    r[15] = r[12] ^ POOL[496];
+    r[12] = {
+        let (t, s) = (POOL[497], POOL[498]);
+        let (ma, mb) = (t ^ s ^ POOL[499], t ^ s ^ POOL[500]);
         let mut z = t;
-        z ^= r[10] & mb;
-        z ^= r[2] & ma;
-        z ^= r[10] & r[2];
-        z
-    };
-    r[10] = r[4] ^ r[8];
-    r[2] = r[10].rotate_left(1);
-    r[10] = r[5] & POOL[248];
-    r[4] = r[10] ^ r[2];
-    r[8] = r[14] ^ r[4];
-    r[4] = r[0] & POOL[249];
-    r[14] = {
-        let (t, ma, mb) = (POOL[250], POOL[251], POOL[252]);
+        z ^= r[9] & mb;
+        z ^= r[15] & ma;
+        z ^= r[9] & r[15];
+        z
+    };
+    r[15] = r[2] ^ r[12];
+    r[12] = r[15].rotate_left(1);
+    r[15] = r[4] & POOL[501];
+    r[2] = r[15] ^ r[12];
+    r[9] = r[6] ^ r[2];
+    r[2] = r[13] & POOL[502];
+    r[6] = r[15] ^ POOL[503];
+    r[15] = r[6] ^ POOL[504];
+    r[6] = r[15] ^ POOL[505];
+    r[15] = r[12] ^ POOL[506];
+    r[12] = {
+        let (t, s) = (POOL[507], POOL[508]);
+        let (ma, mb) = (t ^ s ^ POOL[509], t ^ s ^ POOL[510]);
         let mut z = t;
-        z ^= r[10] & mb;
-        z ^= r[2] & ma;
-        z ^= r[10] & r[2];
-        z
-    };
-    r[10] = r[4] ^ r[14];
-    r[2] = r[10].rotate_left(1);
-    r[10] = r[5] & POOL[253];
-    r[4] = r[10] ^ r[2];
-    r[14] = r[8] ^ r[4];
-    r[4] = r[0] & POOL[254];
-    r[8] = {
-        let (t, ma, mb) = (POOL[255], POOL[256], POOL[257]);
+        z ^= r[6] & mb;
+        z ^= r[15] & ma;
+        z ^= r[6] & r[15];
+        z
+    };
+    r[15] = r[2] ^ r[12];
+    r[12] = r[15].rotate_left(1);
+    r[15] = r[4] & POOL[511];
+    r[2] = r[15] ^ r[12];
+    r[6] = r[9] ^ r[2];
+    r[2] = r[13] & POOL[512];
+    r[9] = r[15] ^ POOL[513];
+    r[15] = r[9] ^ POOL[514];
+    r[9] = r[15] ^ POOL[515];
+    r[15] = r[12] ^ POOL[516];
+    r[12] = {
+        let (t, s) = (POOL[517], POOL[518]);
+        let (ma, mb) = (t ^ s ^ POOL[519], t ^ s ^ POOL[520]);
         let mut z = t;
-        z ^= r[10] & mb;
-        z ^= r[2] & ma;
-        z ^= r[10] & r[2];
-        z
-    };
-    r[10] = r[4] ^ r[8];
-    r[2] = r[10].rotate_left(1);
-    r[10] = r[5] & POOL[258];
-    r[4] = r[10] ^ r[2];
-    r[8] = r[14] ^ r[4];
-    r[4] = r[0] & POOL[259];
-    r[14] = {
-        let (t, ma, mb) = (POOL[260], POOL[261], POOL[262]);
+        z ^= r[9] & mb;
+        z ^= r[15] & ma;
+        z ^= r[9] & r[15];
+        z
+    };
+    r[15] = r[2] ^ r[12];
+    r[12] = r[15].rotate_left(1);
+    r[15] = r[4] & POOL[521];
+    r[2] = r[15] ^ r[12];
+    r[9] = r[6] ^ r[2];
+    r[2] = r[13] & POOL[522];
+    r[6] = r[15] ^ POOL[523];
+    r[15] = r[6] ^ POOL[524];
+    r[6] = r[15] ^ POOL[525];
+    r[15] = r[12] ^ POOL[526];
+    r[12] = {
+        let (t, s) = (POOL[527], POOL[528]);
+        let (ma, mb) = (t ^ s ^ POOL[529], t ^ s ^ POOL[530]);
         let mut z = t;
-        z ^= r[10] & mb;
-        z ^= r[2] & ma;
-        z ^= r[10] & r[2];
-        z
-    };
-    r[10] = r[4] ^ r[14];
-    r[2] = r[10].rotate_left(1);
-    r[10] = r[5] & POOL[263];
-    r[4] = r[10] ^ r[2];
-    r[14] = r[8] ^ r[4];
-    r[4] = r[0] & POOL[264];
-    r[8] = {
-        let (t, ma, mb) = (POOL[265], POOL[266], POOL[267]);
+        z ^= r[6] & mb;
+        z ^= r[15] & ma;
+        z ^= r[6] & r[15];
+        z
+    };
+    r[15] = r[2] ^ r[12];
+    r[12] = r[15].rotate_left(1);
+    r[15] = r[4] & POOL[531];
+    r[2] = r[15] ^ r[12];
+    r[6] = r[9] ^ r[2];
+    r[2] = r[13] & POOL[532];
+    r[9] = r[15] ^ POOL[533];
+    r[15] = r[9] ^ POOL[534];
+    r[9] = r[15] ^ POOL[535];
+    r[15] = r[12] ^ POOL[536];
+    r[12] = {
+        let (t, s) = (POOL[537], POOL[538]);
+        let (ma, mb) = (t ^ s ^ POOL[539], t ^ s ^ POOL[540]);
         let mut z = t;
-        z ^= r[10] & mb;
-        z ^= r[2] & ma;
-        z ^= r[10] & r[2];
-        z
-    };
-    r[10] = r[4] ^ r[8];
-    r[2] = r[10].rotate_left(1);
-    r[10] = r[5] & POOL[268];
-    r[4] = r[10] ^ r[2];
-    r[8] = r[14] ^ r[4];
-    r[4] = r[0] & POOL[269];
-    r[14] = {
-        let (t, ma, mb) = (POOL[270], POOL[271], POOL[272]);
+        z ^= r[9] & mb;
+        z ^= r[15] & ma;
+        z ^= r[9] & r[15];
+        z
+    };
+    r[15] = r[2] ^ r[12];
+    r[12] = r[15].rotate_left(1);
+    r[15] = r[4] & POOL[541];
+    r[2] = r[15] ^ r[12];
+    r[9] = r[6] ^ r[2];
+    r[2] = r[13] & POOL[542];
+    r[6] = r[15] ^ POOL[543];
+    r[15] = r[6] ^ POOL[544];
+    r[6] = r[15] ^ POOL[545];
+    r[15] = r[12] ^ POOL[546];
+    r[12] = {
+        let (t, s) = (POOL[547], POOL[548]);
+        let (ma, mb) = (t ^ s ^ POOL[549], t ^ s ^ POOL[550]);
         let mut z = t;
-        z ^= r[10] & mb;
+        z ^= r[6] & mb;
+        z ^= r[15] & ma;
+        z ^= r[6] & r[15];
+        z
+    };
+    r[15] = r[2] ^ r[12];
+    r[12] = r[15].rotate_left(1);
+    r[15] = r[4] & POOL[551];
+    r[2] = r[15] ^ r[12];
+    r[6] = r[9] ^ r[2];
+    r[2] = r[13] & POOL[552];
+    r[9] = r[15] ^ POOL[553];
+    r[15] = r[9] ^ POOL[554];
+    r[9] = r[15] ^ POOL[555];
+    r[15] = r[12] ^ POOL[556];
+    r[12] = {
+        let (t, s) = (POOL[557], POOL[558]);
+        let (ma, mb) = (t ^ s ^ POOL[559], t ^ s ^ POOL[560]);
+        let mut z = t;
+        z ^= r[9] & mb;
+        z ^= r[15] & ma;
+        z ^= r[9] & r[15];
+        z
+    };
+    r[15] = r[2] ^ r[12];
+    r[12] = r[15].rotate_left(1);
+    r[15] = r[4] & POOL[561];
+    r[2] = r[15] ^ r[12];
+    r[9] = r[6] ^ r[2];
+    r[2] = r[13] & POOL[562];
+    r[6] = r[15] ^ POOL[563];
+    r[15] = r[6] ^ POOL[564];
+    r[6] = r[15] ^ POOL[565];
+    r[15] = r[12] ^ POOL[566];
+    r[12] = {
+        let (t, s) = (POOL[567], POOL[568]);
+        let (ma, mb) = (t ^ s ^ POOL[569], t ^ s ^ POOL[570]);
+        let mut z = t;
+        z ^= r[6] & mb;
+        z ^= r[15] & ma;
+        z ^= r[6] & r[15];
+        z
+    };
+    r[15] = r[2] ^ r[12];
+    r[12] = r[15].rotate_left(1);
+    r[15] = r[4] & POOL[571];
+    r[2] = r[15] ^ r[12];
+    r[6] = r[9] ^ r[2];
+    r[2] = r[13] & POOL[572];
+    r[9] = r[15] ^ POOL[573];
+    r[15] = r[9] ^ POOL[574];
+    r[9] = r[15] ^ POOL[575];
+    r[15] = r[12] ^ POOL[576];
+    r[12] = {
+        let (t, s) = (POOL[577], POOL[578]);
+        let (ma, mb) = (t ^ s ^ POOL[579], t ^ s ^ POOL[580]);
+        let mut z = t;
+        z ^= r[9] & mb;
+        z ^= r[15] & ma;
+        z ^= r[9] & r[15];
+        z
+    };
+    r[15] = r[2] ^ r[12];
+    r[12] = r[15].rotate_left(1);
+    r[15] = r[4] & POOL[581];
+    r[2] = r[15] ^ r[12];
+    r[9] = r[6] ^ r[2];
+    r[2] = r[13] & POOL[582];
+    r[6] = r[15] ^ POOL[583];
+    r[15] = r[6] ^ POOL[584];
+    r[6] = r[15] ^ POOL[585];
+    r[15] = r[12] ^ POOL[586];
+    r[12] = {
+        let (t, s) = (POOL[587], POOL[588]);
+        let (ma, mb) = (t ^ s ^ POOL[589], t ^ s ^ POOL[590]);
+        let mut z = t;
+        z ^= r[6] & mb;
+        z ^= r[15] & ma;
+        z ^= r[6] & r[15];
+        z
+    };
+    r[15] = r[2] ^ r[12];
+    r[12] = r[15].rotate_left(1);
+    r[15] = r[4] & POOL[591];
+    r[2] = r[15] ^ r[12];
+    r[6] = r[9] ^ r[2];
+    r[2] = r[13] & POOL[592];
+    r[9] = r[15] ^ POOL[593];
+    r[15] = r[9] ^ POOL[594];
+    r[9] = r[15] ^ POOL[595];
+    r[15] = r[12] ^ POOL[596];
+    r[12] = {
+        let (t, s) = (POOL[597], POOL[598]);
+        let (ma, mb) = (t ^ s ^ POOL[599], t ^ s ^ POOL[600]);
+        let mut z = t;
+        z ^= r[9] & mb;
+        z ^= r[15] & ma;
+        z ^= r[9] & r[15];
+        z
+    };
+    r[15] = r[2] ^ r[12];
+    r[12] = r[15].rotate_left(1);
+    r[15] = r[4] & POOL[601];
+    r[2] = r[15] ^ r[12];
+    r[9] = r[6] ^ r[2];
+    r[2] = r[13] & POOL[602];
+    r[6] = r[15] ^ POOL[603];
+    r[15] = r[6] ^ POOL[604];
+    r[6] = r[15] ^ POOL[605];
+    r[15] = r[12] ^ POOL[606];
+    r[12] = {
+        let (t, s) = (POOL[607], POOL[608]);
+        let (ma, mb) = (t ^ s ^ POOL[609], t ^ s ^ POOL[610]);
+        let mut z = t;
+        z ^= r[6] & mb;
+        z ^= r[15] & ma;
+        z ^= r[6] & r[15];
+        z
+    };
+    r[15] = r[2] ^ r[12];
+    r[12] = r[15].rotate_left(1);
+    r[15] = r[4] & POOL[611];
+    r[2] = r[15] ^ r[12];
+    r[6] = r[9] ^ r[2];
+    r[2] = r[13] & POOL[612];
+    r[9] = r[15] ^ POOL[613];
+    r[15] = r[9] ^ POOL[614];
+    r[9] = r[15] ^ POOL[615];
+    r[15] = r[12] ^ POOL[616];
+    r[12] = {
+        let (t, s) = (POOL[617], POOL[618]);
+        let (ma, mb) = (t ^ s ^ POOL[619], t ^ s ^ POOL[620]);
+        let mut z = t;
+        z ^= r[9] & mb;
+        z ^= r[15] & ma;
+        z ^= r[9] & r[15];
+        z
+    };
+    r[15] = r[2] ^ r[12];
+    r[12] = r[15].rotate_left(1);
+    r[15] = r[4] & POOL[621];
+    r[2] = r[15] ^ r[12];
+    r[9] = r[6] ^ r[2];
+    r[2] = r[13] & POOL[622];
+    r[13] = r[15] ^ POOL[623];
+    r[15] = r[13] ^ POOL[624];
+    r[13] = r[15] ^ POOL[625];
+    r[15] = r[12] ^ POOL[626];
+    r[12] = {
+        let (t, s) = (POOL[627], POOL[628]);
+        let (ma, mb) = (t ^ s ^ POOL[629], t ^ s ^ POOL[630]);
+        let mut z = t;
+        z ^= r[13] & mb;
+        z ^= r[15] & ma;
+        z ^= r[13] & r[15];
+        z
+    };
+    r[13] = r[2] ^ r[12];
+    r[12] = r[13].rotate_left(1);
+    r[13] = r[4] & POOL[631];
+    r[4] = r[13] ^ r[12];
+    r[12] = r[9] ^ r[4];
+    r[4] = r[12] ^ POOL[632];
+    r[12] = r[4] ^ POOL[633];
+    r[4] = r[0] ^ r[12];
+    r[0] = r[4] ^ POOL[634];
+    r[4] = r[0].rotate_left(12);
+    r[0] = r[4] ^ POOL[635];
+    r[4] = r[0] ^ POOL[636];
+    r[0] = r[11] ^ POOL[637];
+    r[13] = r[4] ^ POOL[638];
+    r[15] = {
+        let (t, s) = (POOL[639], POOL[640]);
+        let (ma, mb) = (t ^ s ^ POOL[641], t ^ s ^ POOL[642]);
+        let mut z = t;
+        z ^= r[0] & mb;
+        z ^= r[13] & ma;
+        z ^= r[0] & r[13];
+        z
+    };
+    r[0] = r[11] ^ r[4];
+    r[11] = r[0] & POOL[643];
+    r[13] = r[15] & POOL[644];
+    r[2] = r[13].rotate_left(1);
+    r[13] = r[0] & POOL[645];
+    r[9] = r[13] ^ r[2];
+    r[6] = r[11] ^ r[9];
+    r[11] = r[15] & POOL[646];
+    r[9] = r[13] ^ POOL[647];
+    r[13] = r[9] ^ POOL[648];
+    r[9] = r[13] ^ POOL[649];
+    r[13] = r[2] ^ POOL[650];
+    r[2] = r[13] ^ POOL[651];
+    r[13] = r[2] ^ POOL[652];
+    r[2] = {
+        let (t, s) = (POOL[653], POOL[654]);
+        let (ma, mb) = (t ^ s ^ POOL[655], t ^ s ^ POOL[656]);
+        let mut z = t;
+        z ^= r[9] & mb;
+        z ^= r[13] & ma;
+        z ^= r[9] & r[13];
+        z
+    };
+    r[13] = r[11] ^ r[2];
+    r[11] = r[13].rotate_left(1);
+    r[13] = r[0] & POOL[657];
+    r[2] = r[13] ^ r[11];
+    r[9] = r[6] ^ r[2];
+    r[2] = r[15] & POOL[658];
+    r[6] = r[13] ^ POOL[659];
+    r[13] = r[6] ^ POOL[660];
+    r[6] = r[13] ^ POOL[661];
+    r[13] = r[11] ^ POOL[662];
+    r[11] = {
+        let (t, s) = (POOL[663], POOL[664]);
+        let (ma, mb) = (t ^ s ^ POOL[665], t ^ s ^ POOL[666]);
+        let mut z = t;
+        z ^= r[6] & mb;
+        z ^= r[13] & ma;
+        z ^= r[6] & r[13];
+        z
+    };
+    r[13] = r[2] ^ r[11];
+    r[11] = r[13].rotate_left(1);
+    r[13] = r[0] & POOL[667];
+    r[2] = r[13] ^ r[11];
+    r[6] = r[9] ^ r[2];
+    r[2] = r[15] & POOL[668];
+    r[9] = r[13] ^ POOL[669];
+    r[13] = r[9] ^ POOL[670];
+    r[9] = r[13] ^ POOL[671];
+    r[13] = r[11] ^ POOL[672];
+    r[11] = {
+        let (t, s) = (POOL[673], POOL[674]);
+        let (ma, mb) = (t ^ s ^ POOL[675], t ^ s ^ POOL[676]);
+        let mut z = t;
+        z ^= r[9] & mb;
+        z ^= r[13] & ma;
+        z ^= r[9] & r[13];
+        z
+    };
+    r[13] = r[2] ^ r[11];
+    r[11] = r[13].rotate_left(1);
+    r[13] = r[0] & POOL[677];
+    r[2] = r[13] ^ r[11];
+    r[9] = r[6] ^ r[2];
+    r[2] = r[15] & POOL[678];
+    r[6] = r[13] ^ POOL[679];
+    r[13] = r[6] ^ POOL[680];
+    r[6] = r[13] ^ POOL[681];
+    r[13] = r[11] ^ POOL[682];
+    r[11] = {
+        let (t, s) = (POOL[683], POOL[684]);
+        let (ma, mb) = (t ^ s ^ POOL[685], t ^ s ^ POOL[686]);
+        let mut z = t;
+        z ^= r[6] & mb;
+        z ^= r[13] & ma;
+        z ^= r[6] & r[13];
+        z
+    };
+    r[13] = r[2] ^ r[11];
+    r[11] = r[13].rotate_left(1);
+    r[13] = r[0] & POOL[687];
+    r[2] = r[13] ^ r[11];
+    r[6] = r[9] ^ r[2];
+    r[2] = r[15] & POOL[688];
+    r[9] = r[13] ^ POOL[689];
+    r[13] = r[9] ^ POOL[690];
+    r[9] = r[13] ^ POOL[691];
+    r[13] = r[11] ^ POOL[692];
+    r[11] = {
+        let (t, s) = (POOL[693], POOL[694]);
+        let (ma, mb) = (t ^ s ^ POOL[695], t ^ s ^ POOL[696]);
+        let mut z = t;
+        z ^= r[9] & mb;
+        z ^= r[13] & ma;
+        z ^= r[9] & r[13];
+        z
+    };
+    r[13] = r[2] ^ r[11];
+    r[11] = r[13].rotate_left(1);
+    r[13] = r[0] & POOL[697];
+    r[2] = r[13] ^ r[11];
+    r[9] = r[6] ^ r[2];
+    r[2] = r[15] & POOL[698];
+    r[6] = r[13] ^ POOL[699];
+    r[13] = r[6] ^ POOL[700];
+    r[6] = r[13] ^ POOL[701];
+    r[13] = r[11] ^ POOL[702];
+    r[11] = {
+        let (t, s) = (POOL[703], POOL[704]);
+        let (ma, mb) = (t ^ s ^ POOL[705], t ^ s ^ POOL[706]);
+        let mut z = t;
+        z ^= r[6] & mb;
+        z ^= r[13] & ma;
+        z ^= r[6] & r[13];
+        z
+    };
+    r[13] = r[2] ^ r[11];
+    r[11] = r[13].rotate_left(1);
+    r[13] = r[0] & POOL[707];
+    r[2] = r[13] ^ r[11];
+    r[6] = r[9] ^ r[2];
+    r[2] = r[15] & POOL[708];
+    r[9] = r[13] ^ POOL[709];
+    r[13] = r[9] ^ POOL[710];
+    r[9] = r[13] ^ POOL[711];
+    r[13] = r[11] ^ POOL[712];
+    r[11] = {
+        let (t, s) = (POOL[713], POOL[714]);
+        let (ma, mb) = (t ^ s ^ POOL[715], t ^ s ^ POOL[716]);
+        let mut z = t;
+        z ^= r[9] & mb;
+        z ^= r[13] & ma;
+        z ^= r[9] & r[13];
+        z
+    };
+    r[13] = r[2] ^ r[11];
+    r[11] = r[13].rotate_left(1);
+    r[13] = r[0] & POOL[717];
+    r[2] = r[13] ^ r[11];
+    r[9] = r[6] ^ r[2];
+    r[2] = r[15] & POOL[718];
+    r[6] = r[13] ^ POOL[719];
+    r[13] = r[6] ^ POOL[720];
+    r[6] = r[13] ^ POOL[721];
+    r[13] = r[11] ^ POOL[722];
+    r[11] = {
+        let (t, s) = (POOL[723], POOL[724]);
+        let (ma, mb) = (t ^ s ^ POOL[725], t ^ s ^ POOL[726]);
+        let mut z = t;
+        z ^= r[6] & mb;
+        z ^= r[13] & ma;
+        z ^= r[6] & r[13];
+        z
+    };
+    r[13] = r[2] ^ r[11];
+    r[11] = r[13].rotate_left(1);
+    r[13] = r[0] & POOL[727];
+    r[2] = r[13] ^ r[11];
+    r[6] = r[9] ^ r[2];
+    r[2] = r[15] & POOL[728];
+    r[9] = r[13] ^ POOL[729];
+    r[13] = r[9] ^ POOL[730];
+    r[9] = r[13] ^ POOL[731];
+    r[13] = r[11] ^ POOL[732];
+    r[11] = {
+        let (t, s) = (POOL[733], POOL[734]);
+        let (ma, mb) = (t ^ s ^ POOL[735], t ^ s ^ POOL[736]);
+        let mut z = t;
+        z ^= r[9] & mb;
+        z ^= r[13] & ma;
+        z ^= r[9] & r[13];
+        z
+    };
+    r[13] = r[2] ^ r[11];
+    r[11] = r[13].rotate_left(1);
+    r[13] = r[0] & POOL[737];
+    r[2] = r[13] ^ r[11];
+    r[9] = r[6] ^ r[2];
+    r[2] = r[15] & POOL[738];
+    r[6] = r[13] ^ POOL[739];
+    r[13] = r[6] ^ POOL[740];
+    r[6] = r[13] ^ POOL[741];
+    r[13] = r[11] ^ POOL[742];
+    r[11] = {
+        let (t, s) = (POOL[743], POOL[744]);
+        let (ma, mb) = (t ^ s ^ POOL[745], t ^ s ^ POOL[746]);
+        let mut z = t;
+        z ^= r[6] & mb;
+        z ^= r[13] & ma;
+        z ^= r[6] & r[13];
+        z
+    };
+    r[13] = r[2] ^ r[11];
+    r[11] = r[13].rotate_left(1);
+    r[13] = r[0] & POOL[747];
+    r[2] = r[13] ^ r[11];
+    r[6] = r[9] ^ r[2];
+    r[2] = r[15] & POOL[748];
+    r[9] = r[13] ^ POOL[749];
+    r[13] = r[9] ^ POOL[750];
+    r[9] = r[13] ^ POOL[751];
+    r[13] = r[11] ^ POOL[752];
+    r[11] = {
+        let (t, s) = (POOL[753], POOL[754]);
+        let (ma, mb) = (t ^ s ^ POOL[755], t ^ s ^ POOL[756]);
+        let mut z = t;
+        z ^= r[9] & mb;
+        z ^= r[13] & ma;
+        z ^= r[9] & r[13];
+        z
+    };
+    r[13] = r[2] ^ r[11];
+    r[11] = r[13].rotate_left(1);
+    r[13] = r[0] & POOL[757];
+    r[2] = r[13] ^ r[11];
+    r[9] = r[6] ^ r[2];
+    r[2] = r[15] & POOL[758];
+    r[6] = r[13] ^ POOL[759];
+    r[13] = r[6] ^ POOL[760];
+    r[6] = r[13] ^ POOL[761];
+    r[13] = r[11] ^ POOL[762];
+    r[11] = {
+        let (t, s) = (POOL[763], POOL[764]);
+        let (ma, mb) = (t ^ s ^ POOL[765], t ^ s ^ POOL[766]);
+        let mut z = t;
+        z ^= r[6] & mb;
+        z ^= r[13] & ma;
+        z ^= r[6] & r[13];
+        z
+    };
+    r[13] = r[2] ^ r[11];
+    r[11] = r[13].rotate_left(1);
+    r[13] = r[0] & POOL[767];
+    r[2] = r[13] ^ r[11];
+    r[6] = r[9] ^ r[2];
+    r[2] = r[15] & POOL[768];
+    r[9] = r[13] ^ POOL[769];
+    r[13] = r[9] ^ POOL[770];
+    r[9] = r[13] ^ POOL[771];
+    r[13] = r[11] ^ POOL[772];
+    r[11] = {
+        let (t, s) = (POOL[773], POOL[774]);
+        let (ma, mb) = (t ^ s ^ POOL[775], t ^ s ^ POOL[776]);
+        let mut z = t;
+        z ^= r[9] & mb;
+        z ^= r[13] & ma;
+        z ^= r[9] & r[13];
+        z
+    };
+    r[13] = r[2] ^ r[11];
+    r[11] = r[13].rotate_left(1);
+    r[13] = r[0] & POOL[777];
+    r[2] = r[13] ^ r[11];
+    r[9] = r[6] ^ r[2];
+    r[2] = r[15] & POOL[778];
+    r[6] = r[13] ^ POOL[779];
+    r[13] = r[6] ^ POOL[780];
+    r[6] = r[13] ^ POOL[781];
+    r[13] = r[11] ^ POOL[782];
+    r[11] = {
+        let (t, s) = (POOL[783], POOL[784]);
+        let (ma, mb) = (t ^ s ^ POOL[785], t ^ s ^ POOL[786]);
+        let mut z = t;
+        z ^= r[6] & mb;
+        z ^= r[13] & ma;
+        z ^= r[6] & r[13];
+        z
+    };
+    r[13] = r[2] ^ r[11];
+    r[11] = r[13].rotate_left(1);
+    r[13] = r[0] & POOL[787];
+    r[2] = r[13] ^ r[11];
+    r[6] = r[9] ^ r[2];
+    r[2] = r[15] & POOL[788];
+    r[9] = r[13] ^ POOL[789];
+    r[13] = r[9] ^ POOL[790];
+    r[9] = r[13] ^ POOL[791];
+    r[13] = r[11] ^ POOL[792];
+    r[11] = {
+        let (t, s) = (POOL[793], POOL[794]);
+        let (ma, mb) = (t ^ s ^ POOL[795], t ^ s ^ POOL[796]);
+        let mut z = t;
+        z ^= r[9] & mb;
+        z ^= r[13] & ma;
+        z ^= r[9] & r[13];
+        z
+    };
+    r[13] = r[2] ^ r[11];
+    r[11] = r[13].rotate_left(1);
+    r[13] = r[0] & POOL[797];
+    r[2] = r[13] ^ r[11];
+    r[9] = r[6] ^ r[2];
+    r[2] = r[15] & POOL[798];
+    r[6] = r[13] ^ POOL[799];
+    r[13] = r[6] ^ POOL[800];
+    r[6] = r[13] ^ POOL[801];
+    r[13] = r[11] ^ POOL[802];
+    r[11] = {
+        let (t, s) = (POOL[803], POOL[804]);
+        let (ma, mb) = (t ^ s ^ POOL[805], t ^ s ^ POOL[806]);
+        let mut z = t;
+        z ^= r[6] & mb;
+        z ^= r[13] & ma;
+        z ^= r[6] & r[13];
+        z
+    };
+    r[13] = r[2] ^ r[11];
+    r[11] = r[13].rotate_left(1);
+    r[13] = r[0] & POOL[807];
+    r[2] = r[13] ^ r[11];
+    r[6] = r[9] ^ r[2];
+    r[2] = r[15] & POOL[808];
+    r[9] = r[13] ^ POOL[809];
+    r[13] = r[9] ^ POOL[810];
+    r[9] = r[13] ^ POOL[811];
+    r[13] = r[11] ^ POOL[812];
+    r[11] = {
+        let (t, s) = (POOL[813], POOL[814]);
+        let (ma, mb) = (t ^ s ^ POOL[815], t ^ s ^ POOL[816]);
+        let mut z = t;
+        z ^= r[9] & mb;
+        z ^= r[13] & ma;
+        z ^= r[9] & r[13];
+        z
+    };
+    r[13] = r[2] ^ r[11];
+    r[11] = r[13].rotate_left(1);
+    r[13] = r[0] & POOL[817];
+    r[2] = r[13] ^ r[11];
+    r[9] = r[6] ^ r[2];
+    r[2] = r[15] & POOL[818];
+    r[6] = r[13] ^ POOL[819];
+    r[13] = r[6] ^ POOL[820];
+    r[6] = r[13] ^ POOL[821];
+    r[13] = r[11] ^ POOL[822];
+    r[11] = {
+        let (t, s) = (POOL[823], POOL[824]);
+        let (ma, mb) = (t ^ s ^ POOL[825], t ^ s ^ POOL[826]);
+        let mut z = t;
+        z ^= r[6] & mb;
+        z ^= r[13] & ma;
+        z ^= r[6] & r[13];
+        z
+    };
+    r[13] = r[2] ^ r[11];
+    r[11] = r[13].rotate_left(1);
+    r[13] = r[0] & POOL[827];
+    r[2] = r[13] ^ r[11];
+    r[6] = r[9] ^ r[2];
+    r[2] = r[15] & POOL[828];
+    r[9] = r[13] ^ POOL[829];
+    r[13] = r[9] ^ POOL[830];
+    r[9] = r[13] ^ POOL[831];
+    r[13] = r[11] ^ POOL[832];
+    r[11] = {
+        let (t, s) = (POOL[833], POOL[834]);
+        let (ma, mb) = (t ^ s ^ POOL[835], t ^ s ^ POOL[836]);
+        let mut z = t;
+        z ^= r[9] & mb;
+        z ^= r[13] & ma;
+        z ^= r[9] & r[13];
+        z
+    };
+    r[13] = r[2] ^ r[11];
+    r[11] = r[13].rotate_left(1);
+    r[13] = r[0] & POOL[837];
+    r[2] = r[13] ^ r[11];
+    r[9] = r[6] ^ r[2];
+    r[2] = r[15] & POOL[838];
+    r[6] = r[13] ^ POOL[839];
+    r[13] = r[6] ^ POOL[840];
+    r[6] = r[13] ^ POOL[841];
+    r[13] = r[11] ^ POOL[842];
+    r[11] = {
+        let (t, s) = (POOL[843], POOL[844]);
+        let (ma, mb) = (t ^ s ^ POOL[845], t ^ s ^ POOL[846]);
+        let mut z = t;
+        z ^= r[6] & mb;
+        z ^= r[13] & ma;
+        z ^= r[6] & r[13];
+        z
+    };
+    r[13] = r[2] ^ r[11];
+    r[11] = r[13].rotate_left(1);
+    r[13] = r[0] & POOL[847];
+    r[2] = r[13] ^ r[11];
+    r[6] = r[9] ^ r[2];
+    r[2] = r[15] & POOL[848];
+    r[9] = r[13] ^ POOL[849];
+    r[13] = r[9] ^ POOL[850];
+    r[9] = r[13] ^ POOL[851];
+    r[13] = r[11] ^ POOL[852];
+    r[11] = {
+        let (t, s) = (POOL[853], POOL[854]);
+        let (ma, mb) = (t ^ s ^ POOL[855], t ^ s ^ POOL[856]);
+        let mut z = t;
+        z ^= r[9] & mb;
+        z ^= r[13] & ma;
+        z ^= r[9] & r[13];
+        z
+    };
+    r[13] = r[2] ^ r[11];
+    r[11] = r[13].rotate_left(1);
+    r[13] = r[0] & POOL[857];
+    r[2] = r[13] ^ r[11];
+    r[9] = r[6] ^ r[2];
+    r[2] = r[15] & POOL[858];
+    r[6] = r[13] ^ POOL[859];
+    r[13] = r[6] ^ POOL[860];
+    r[6] = r[13] ^ POOL[861];
+    r[13] = r[11] ^ POOL[862];
+    r[11] = {
+        let (t, s) = (POOL[863], POOL[864]);
+        let (ma, mb) = (t ^ s ^ POOL[865], t ^ s ^ POOL[866]);
+        let mut z = t;
+        z ^= r[6] & mb;
+        z ^= r[13] & ma;
+        z ^= r[6] & r[13];
+        z
+    };
+    r[13] = r[2] ^ r[11];
+    r[11] = r[13].rotate_left(1);
+    r[13] = r[0] & POOL[867];
+    r[2] = r[13] ^ r[11];
+    r[6] = r[9] ^ r[2];
+    r[2] = r[15] & POOL[868];
+    r[9] = r[13] ^ POOL[869];
+    r[13] = r[9] ^ POOL[870];
+    r[9] = r[13] ^ POOL[871];
+    r[13] = r[11] ^ POOL[872];
+    r[11] = {
+        let (t, s) = (POOL[873], POOL[874]);
+        let (ma, mb) = (t ^ s ^ POOL[875], t ^ s ^ POOL[876]);
+        let mut z = t;
+        z ^= r[9] & mb;
+        z ^= r[13] & ma;
+        z ^= r[9] & r[13];
+        z
+    };
+    r[13] = r[2] ^ r[11];
+    r[11] = r[13].rotate_left(1);
+    r[13] = r[0] & POOL[877];
+    r[2] = r[13] ^ r[11];
+    r[9] = r[6] ^ r[2];
+    r[2] = r[15] & POOL[878];
+    r[6] = r[13] ^ POOL[879];
+    r[13] = r[6] ^ POOL[880];
+    r[6] = r[13] ^ POOL[881];
+    r[13] = r[11] ^ POOL[882];
+    r[11] = {
+        let (t, s) = (POOL[883], POOL[884]);
+        let (ma, mb) = (t ^ s ^ POOL[885], t ^ s ^ POOL[886]);
+        let mut z = t;
+        z ^= r[6] & mb;
+        z ^= r[13] & ma;
+        z ^= r[6] & r[13];
+        z
+    };
+    r[13] = r[2] ^ r[11];
+    r[11] = r[13].rotate_left(1);
+    r[13] = r[0] & POOL[887];
+    r[2] = r[13] ^ r[11];
+    r[6] = r[9] ^ r[2];
+    r[2] = r[15] & POOL[888];
+    r[9] = r[13] ^ POOL[889];
+    r[13] = r[9] ^ POOL[890];
+    r[9] = r[13] ^ POOL[891];
+    r[13] = r[11] ^ POOL[892];
+    r[11] = {
+        let (t, s) = (POOL[893], POOL[894]);
+        let (ma, mb) = (t ^ s ^ POOL[895], t ^ s ^ POOL[896]);
+        let mut z = t;
+        z ^= r[9] & mb;
+        z ^= r[13] & ma;
+        z ^= r[9] & r[13];
+        z
+    };
+    r[13] = r[2] ^ r[11];
+    r[11] = r[13].rotate_left(1);
+    r[13] = r[0] & POOL[897];
+    r[2] = r[13] ^ r[11];
+    r[9] = r[6] ^ r[2];
+    r[2] = r[15] & POOL[898];
+    r[6] = r[13] ^ POOL[899];
+    r[13] = r[6] ^ POOL[900];
+    r[6] = r[13] ^ POOL[901];
+    r[13] = r[11] ^ POOL[902];
+    r[11] = {
+        let (t, s) = (POOL[903], POOL[904]);
+        let (ma, mb) = (t ^ s ^ POOL[905], t ^ s ^ POOL[906]);
+        let mut z = t;
+        z ^= r[6] & mb;
+        z ^= r[13] & ma;
+        z ^= r[6] & r[13];
+        z
+    };
+    r[13] = r[2] ^ r[11];
+    r[11] = r[13].rotate_left(1);
+    r[13] = r[0] & POOL[907];
+    r[2] = r[13] ^ r[11];
+    r[6] = r[9] ^ r[2];
+    r[2] = r[15] & POOL[908];
+    r[9] = r[13] ^ POOL[909];
+    r[13] = r[9] ^ POOL[910];
+    r[9] = r[13] ^ POOL[911];
+    r[13] = r[11] ^ POOL[912];
+    r[11] = {
+        let (t, s) = (POOL[913], POOL[914]);
+        let (ma, mb) = (t ^ s ^ POOL[915], t ^ s ^ POOL[916]);
+        let mut z = t;
+        z ^= r[9] & mb;
+        z ^= r[13] & ma;
+        z ^= r[9] & r[13];
+        z
+    };
+    r[13] = r[2] ^ r[11];
+    r[11] = r[13].rotate_left(1);
+    r[13] = r[0] & POOL[917];
+    r[2] = r[13] ^ r[11];
+    r[9] = r[6] ^ r[2];
+    r[2] = r[15] & POOL[918];
+    r[6] = r[13] ^ POOL[919];
+    r[13] = r[6] ^ POOL[920];
+    r[6] = r[13] ^ POOL[921];
+    r[13] = r[11] ^ POOL[922];
+    r[11] = {
+        let (t, s) = (POOL[923], POOL[924]);
+        let (ma, mb) = (t ^ s ^ POOL[925], t ^ s ^ POOL[926]);
+        let mut z = t;
+        z ^= r[6] & mb;
+        z ^= r[13] & ma;
+        z ^= r[6] & r[13];
+        z
+    };
+    r[13] = r[2] ^ r[11];
+    r[11] = r[13].rotate_left(1);
+    r[13] = r[0] & POOL[927];
+    r[2] = r[13] ^ r[11];
+    r[6] = r[9] ^ r[2];
+    r[2] = r[15] & POOL[928];
+    r[9] = r[13] ^ POOL[929];
+    r[13] = r[9] ^ POOL[930];
+    r[9] = r[13] ^ POOL[931];
+    r[13] = r[11] ^ POOL[932];
+    r[11] = {
+        let (t, s) = (POOL[933], POOL[934]);
+        let (ma, mb) = (t ^ s ^ POOL[935], t ^ s ^ POOL[936]);
+        let mut z = t;
+        z ^= r[9] & mb;
+        z ^= r[13] & ma;
+        z ^= r[9] & r[13];
+        z
+    };
+    r[13] = r[2] ^ r[11];
+    r[11] = r[13].rotate_left(1);
+    r[13] = r[0] & POOL[937];
+    r[2] = r[13] ^ r[11];
+    r[9] = r[6] ^ r[2];
+    r[2] = r[15] & POOL[938];
+    r[15] = r[13] ^ POOL[939];
+    r[13] = r[15] ^ POOL[940];
+    r[15] = r[13] ^ POOL[941];
+    r[13] = r[11] ^ POOL[942];
+    r[11] = {
+        let (t, s) = (POOL[943], POOL[944]);
+        let (ma, mb) = (t ^ s ^ POOL[945], t ^ s ^ POOL[946]);
+        let mut z = t;
+        z ^= r[15] & mb;
+        z ^= r[13] & ma;
+        z ^= r[15] & r[13];
+        z
+    };
+    r[13] = r[2] ^ r[11];
+    r[11] = r[13].rotate_left(1);
+    r[13] = r[0] & POOL[947];
+    r[0] = r[13] ^ r[11];
+    r[11] = r[9] ^ r[0];
+    r[0] = POOL[948];
+    r[13] = r[1] ^ POOL[949];
+    r[15] = r[13] ^ POOL[950];
+    r[13] = r[8] ^ POOL[951];
+    r[2] = r[13] ^ POOL[952];
+    r[13] = {
+        let (t, s) = (POOL[953], POOL[954]);
+        let (ma, mb) = (t ^ s ^ POOL[955], t ^ s ^ POOL[956]);
+        let mut z = t;
+        z ^= r[15] & mb;
         z ^= r[2] & ma;
-        z ^= r[10] & r[2];
-        z
-    };
-    r[10] = r[4] ^ r[14];
-    r[2] = r[10].rotate_left(1);
-    r[10] = r[5] & POOL[273];
-    r[4] = r[10] ^ r[2];
-    r[14] = r[8] ^ r[4];
-    r[4] = r[0] & POOL[274];
-    r[8] = {
-        let (t, ma, mb) = (POOL[275], POOL[276], POOL[277]);
+        z ^= r[15] & r[2];
+        z
+    };
+    r[15] = r[1] ^ r[8];
+    r[1] = r[15] & POOL[957];
+    r[2] = r[13] & POOL[958];
+    r[9] = r[2].rotate_left(1);
+    r[2] = r[15] & POOL[959];
+    r[6] = r[2] ^ r[9];
+    r[5] = r[1] ^ r[6];
+    r[1] = r[13] & POOL[960];
+    r[6] = r[2] ^ POOL[961];
+    r[2] = r[6] ^ POOL[962];
+    r[6] = r[2] ^ POOL[963];
+    r[2] = r[9] ^ POOL[964];
+    r[9] = r[2] ^ POOL[965];
+    r[2] = r[9] ^ POOL[966];
+    r[9] = {
+        let (t, s) = (POOL[967], POOL[968]);
+        let (ma, mb) = (t ^ s ^ POOL[969], t ^ s ^ POOL[970]);
         let mut z = t;
-        z ^= r[10] & mb;
+        z ^= r[6] & mb;
         z ^= r[2] & ma;
-        z ^= r[10] & r[2];
+        z ^= r[6] & r[2];
         z
     };
-    r[10] = r[4] ^ r[8];
-    r[2] = r[10].rotate_left(1);
-    r[10] = r[5] & POOL[278];
-    r[4] = r[10] ^ r[2];
-    r[8] = r[14] ^ r[4];
-    r[4] = r[0] & POOL[279];
-    r[14] = {
-        let (t, ma, mb) = (POOL[280], POOL[281], POOL[282]);
+    r[2] = r[1] ^ r[9];
+    r[1] = r[2].rotate_left(1);
+    r[2] = r[15] & POOL[971];
+    r[9] = r[2] ^ r[1];
+    r[6] = r[5] ^ r[9];
+    r[9] = r[13] & POOL[972];
+    r[5] = r[2] ^ POOL[973];
+    r[2] = r[5] ^ POOL[974];
+    r[5] = r[2] ^ POOL[975];
+    r[2] = r[1] ^ POOL[976];
+    r[1] = {
+        let (t, s) = (POOL[977], POOL[978]);
+        let (ma, mb) = (t ^ s ^ POOL[979], t ^ s ^ POOL[980]);
         let mut z = t;
-        z ^= r[10] & mb;
+        z ^= r[5] & mb;
         z ^= r[2] & ma;
-        z ^= r[10] & r[2];
+        z ^= r[5] & r[2];
         z
     };
-    r[10] = r[4] ^ r[14];
-    r[2] = r[10].rotate_left(1);
-    r[10] = r[5] & POOL[283];
-    r[4] = r[10] ^ r[2];
-    r[14] = r[8] ^ r[4];
-    r[4] = r[0] & POOL[284];
-    r[8] = {
-        let (t, ma, mb) = (POOL[285], POOL[286], POOL[287]);
+    r[2] = r[9] ^ r[1];
+    r[1] = r[2].rotate_left(1);
+    r[2] = r[15] & POOL[981];
+    r[9] = r[2] ^ r[1];
+    r[5] = r[6] ^ r[9];
+    r[9] = r[13] & POOL[982];
+    r[6] = r[2] ^ POOL[983];
+    r[2] = r[6] ^ POOL[984];
+    r[6] = r[2] ^ POOL[985];
+    r[2] = r[1] ^ POOL[986];
+    r[1] = {
+        let (t, s) = (POOL[987], POOL[988]);
+        let (ma, mb) = (t ^ s ^ POOL[989], t ^ s ^ POOL[990]);
         let mut z = t;
-        z ^= r[10] & mb;
+        z ^= r[6] & mb;
         z ^= r[2] & ma;
-        z ^= r[10] & r[2];
+        z ^= r[6] & r[2];
         z
     };
-    r[10] = r[4] ^ r[8];
-    r[2] = r[10].rotate_left(1);
-    r[10] = r[5] & POOL[288];
-    r[4] = r[10] ^ r[2];
-    r[8] = r[14] ^ r[4];
-    r[4] = r[0] & POOL[289];
-    r[14] = {
-        let (t, ma, mb) = (POOL[290], POOL[291], POOL[292]);
+    r[2] = r[9] ^ r[1];
+    r[1] = r[2].rotate_left(1);
+    r[2] = r[15] & POOL[991];
+    r[9] = r[2] ^ r[1];
+    r[6] = r[5] ^ r[9];
+    r[9] = r[13] & POOL[992];
+    r[5] = r[2] ^ POOL[993];
+    r[2] = r[5] ^ POOL[994];
+    r[5] = r[2] ^ POOL[995];
+    r[2] = r[1] ^ POOL[996];
+    r[1] = {
+        let (t, s) = (POOL[997], POOL[998]);
+        let (ma, mb) = (t ^ s ^ POOL[999], t ^ s ^ POOL[1000]);
         let mut z = t;
-        z ^= r[10] & mb;
+        z ^= r[5] & mb;
         z ^= r[2] & ma;
-        z ^= r[10] & r[2];
+        z ^= r[5] & r[2];
         z
     };
-    r[10] = r[4] ^ r[14];
-    r[2] = r[10].rotate_left(1);
-    r[10] = r[5] & POOL[293];
-    r[4] = r[10] ^ r[2];
-    r[14] = r[8] ^ r[4];
-    r[4] = r[0] & POOL[294];
-    r[8] = {
-        let (t, ma, mb) = (POOL[295], POOL[296], POOL[297]);
+    r[2] = r[9] ^ r[1];
+    r[1] = r[2].rotate_left(1);
+    r[2] = r[15] & POOL[1001];
+    r[9] = r[2] ^ r[1];
+    r[5] = r[6] ^ r[9];
+    r[9] = r[13] & POOL[1002];
+    r[6] = r[2] ^ POOL[1003];
+    r[2] = r[6] ^ POOL[1004];
+    r[6] = r[2] ^ POOL[1005];
+    r[2] = r[1] ^ POOL[1006];
+    r[1] = {
+        let (t, s) = (POOL[1007], POOL[1008]);
+        let (ma, mb) = (t ^ s ^ POOL[1009], t ^ s ^ POOL[1010]);
         let mut z = t;
-        z ^= r[10] & mb;
+        z ^= r[6] & mb;
         z ^= r[2] & ma;
-        z ^= r[10] & r[2];
+        z ^= r[6] & r[2];
         z
     };
-    r[10] = r[4] ^ r[8];
-    r[2] = r[10].rotate_left(1);
-    r[10] = r[5] & POOL[298];
-    r[4] = r[10] ^ r[2];
-    r[8] = r[14] ^ r[4];
-    r[4] = r[0] & POOL[299];
-    r[14] = {
-        let (t, ma, mb) = (POOL[300], POOL[301], POOL[302]);
+    r[2] = r[9] ^ r[1];
+    r[1] = r[2].rotate_left(1);
+    r[2] = r[15] & POOL[1011];
+    r[9] = r[2] ^ r[1];
+    r[6] = r[5] ^ r[9];
+    r[9] = r[13] & POOL[1012];
+    r[5] = r[2] ^ POOL[1013];
+    r[2] = r[5] ^ POOL[1014];
+    r[5] = r[2] ^ POOL[1015];
+    r[2] = r[1] ^ POOL[1016];
+    r[1] = {
+        let (t, s) = (POOL[1017], POOL[1018]);
+        let (ma, mb) = (t ^ s ^ POOL[1019], t ^ s ^ POOL[1020]);
         let mut z = t;
-        z ^= r[10] & mb;
+        z ^= r[5] & mb;
         z ^= r[2] & ma;
-        z ^= r[10] & r[2];
+        z ^= r[5] & r[2];
         z
     };
-    r[10] = r[4] ^ r[14];
-    r[2] = r[10].rotate_left(1);
-    r[10] = r[5] & POOL[303];
-    r[4] = r[10] ^ r[2];
-    r[14] = r[8] ^ r[4];
-    r[4] = r[0] & POOL[304];
-    r[8] = {
-        let (t, ma, mb) = (POOL[305], POOL[306], POOL[307]);
+    r[2] = r[9] ^ r[1];
+    r[1] = r[2].rotate_left(1);
+    r[2] = r[15] & POOL[1021];
+    r[9] = r[2] ^ r[1];
+    r[5] = r[6] ^ r[9];
+    r[9] = r[13] & POOL[1022];
+    r[6] = r[2] ^ POOL[1023];
+    r[2] = r[6] ^ POOL[1024];
+    r[6] = r[2] ^ POOL[1025];
+    r[2] = r[1] ^ POOL[1026];
+    r[1] = {
+        let (t, s) = (POOL[1027], POOL[1028]);
+        let (ma, mb) = (t ^ s ^ POOL[1029], t ^ s ^ POOL[1030]);
         let mut z = t;
-        z ^= r[10] & mb;
+        z ^= r[6] & mb;
         z ^= r[2] & ma;
-        z ^= r[10] & r[2];
+        z ^= r[6] & r[2];
         z
     };
-    r[10] = r[4] ^ r[8];
-    r[2] = r[10].rotate_left(1);
-    r[10] = r[5] & POOL[308];
-    r[4] = r[10] ^ r[2];
-    r[8] = r[14] ^ r[4];
-    r[4] = r[0] & POOL[309];
-    r[14] = {
-        let (t, ma, mb) = (POOL[310], POOL[311], POOL[312]);
+    r[2] = r[9] ^ r[1];
+    r[1] = r[2].rotate_left(1);
+    r[2] = r[15] & POOL[1031];
+    r[9] = r[2] ^ r[1];
+    r[6] = r[5] ^ r[9];
+    r[9] = r[13] & POOL[1032];
+    r[5] = r[2] ^ POOL[1033];
+    r[2] = r[5] ^ POOL[1034];
+    r[5] = r[2] ^ POOL[1035];
+    r[2] = r[1] ^ POOL[1036];
+    r[1] = {
+        let (t, s) = (POOL[1037], POOL[1038]);
+        let (ma, mb) = (t ^ s ^ POOL[1039], t ^ s ^ POOL[1040]);
         let mut z = t;
-        z ^= r[10] & mb;
+        z ^= r[5] & mb;
         z ^= r[2] & ma;
-        z ^= r[10] & r[2];
+        z ^= r[5] & r[2];
         z
     };
-    r[10] = r[4] ^ r[14];
-    r[2] = r[10].rotate_left(1);
-    r[10] = r[5] & POOL[313];
-    r[4] = r[10] ^ r[2];
-    r[14] = r[8] ^ r[4];
-    r[4] = r[0] & POOL[314];
-    r[0] = {
-        let (t, ma, mb) = (POOL[315], POOL[316], POOL[317]);
+    r[2] = r[9] ^ r[1];
+    r[1] = r[2].rotate_left(1);
+    r[2] = r[15] & POOL[1041];
+    r[9] = r[2] ^ r[1];
+    r[5] = r[6] ^ r[9];
+    r[9] = r[13] & POOL[1042];
+    r[6] = r[2] ^ POOL[1043];
+    r[2] = r[6] ^ POOL[1044];
+    r[6] = r[2] ^ POOL[1045];
+    r[2] = r[1] ^ POOL[1046];
+    r[1] = {
+        let (t, s) = (POOL[1047], POOL[1048]);
+        let (ma, mb) = (t ^ s ^ POOL[1049], t ^ s ^ POOL[1050]);
         let mut z = t;
-        z ^= r[10] & mb;
+        z ^= r[6] & mb;
         z ^= r[2] & ma;
-        z ^= r[10] & r[2];
-        z
-    };
-    r[10] = r[4] ^ r[0];
-    r[0] = r[10].rotate_left(1);
-    r[10] = r[5] & POOL[318];
-    r[5] = r[10] ^ r[0];
-    r[0] = r[14] ^ r[5];
-    r[5] = r[0] ^ POOL[319];
-    r[0] = r[5] ^ POOL[320];
-    r[5] = r[11] ^ r[0];
-    r[11] = r[5] ^ POOL[321];
-    r[5] = r[11].rotate_left(12);
-    r[11] = r[5] ^ POOL[322];
-    r[5] = r[11] ^ POOL[323];
-    r[11] = {
-        let (t, ma, mb) = (POOL[324], POOL[325], POOL[326]);
-        let mut z = t;
-        z ^= r[1] & mb;
-        z ^= r[5] & ma;
-        z ^= r[1] & r[5];
+        z ^= r[6] & r[2];
         z
     };
-    r[10] = r[1] ^ r[5];
-    r[1] = r[10] & POOL[327];
-    r[2] = r[11] & POOL[328];
-    r[4] = r[2].rotate_left(1);
-    r[2] = r[10] & POOL[329];
-    r[14] = r[2] ^ r[4];
-    r[8] = r[1] ^ r[14];
-    r[1] = r[11] & POOL[330];
-    r[14] = {
-        let (t, ma, mb) = (POOL[331], POOL[332], POOL[333]);
+    r[2] = r[9] ^ r[1];
+    r[1] = r[2].rotate_left(1);
+    r[2] = r[15] & POOL[1051];
+    r[9] = r[2] ^ r[1];
+    r[6] = r[5] ^ r[9];
+    r[9] = r[13] & POOL[1052];
+    r[5] = r[2] ^ POOL[1053];
+    r[2] = r[5] ^ POOL[1054];
+    r[5] = r[2] ^ POOL[1055];
+    r[2] = r[1] ^ POOL[1056];
+    r[1] = {
+        let (t, s) = (POOL[1057], POOL[1058]);
+        let (ma, mb) = (t ^ s ^ POOL[1059], t ^ s ^ POOL[1060]);
         let mut z = t;
-        z ^= r[2] & mb;
-        z ^= r[4] & ma;
-        z ^= r[2] & r[4];
+        z ^= r[5] & mb;
+        z ^= r[2] & ma;
+        z ^= r[5] & r[2];
         z
     };
-    r[2] = r[1] ^ r[14];
+    r[2] = r[9] ^ r[1];
     r[1] = r[2].rotate_left(1);
-    r[2] = r[10] & POOL[334];
-    r[4] = r[2] ^ r[1];
-    r[14] = r[8] ^ r[4];
-    r[4] = r[11] & POOL[335];
-    r[8] = {
-        let (t, ma, mb) = (POOL[336], POOL[337], POOL[338]);
+    r[2] = r[15] & POOL[1061];
+    r[9] = r[2] ^ r[1];
+    r[5] = r[6] ^ r[9];
+    r[9] = r[13] & POOL[1062];
+    r[6] = r[2] ^ POOL[1063];
+    r[2] = r[6] ^ POOL[1064];
+    r[6] = r[2] ^ POOL[1065];
+    r[2] = r[1] ^ POOL[1066];
+    r[1] = {
+        let (t, s) = (POOL[1067], POOL[1068]);
+        let (ma, mb) = (t ^ s ^ POOL[1069], t ^ s ^ POOL[1070]);
         let mut z = t;
-        z ^= r[2] & mb;
-        z ^= r[1] & ma;
-        z ^= r[2] & r[1];
+        z ^= r[6] & mb;
+        z ^= r[2] & ma;
+        z ^= r[6] & r[2];
         z
     };
-    r[1] = r[4] ^ r[8];
-    r[2] = r[1].rotate_left(1);
-    r[1] = r[10] & POOL[339];
-    r[4] = r[1] ^ r[2];
-    r[8] = r[14] ^ r[4];
-    r[4] = r[11] & POOL[340];
-    r[14] = {
-        let (t, ma, mb) = (POOL[341], POOL[342], POOL[343]);
+    r[2] = r[9] ^ r[1];
+    r[1] = r[2].rotate_left(1);
+    r[2] = r[15] & POOL[1071];
+    r[9] = r[2] ^ r[1];
+    r[6] = r[5] ^ r[9];
+    r[9] = r[13] & POOL[1072];
+    r[5] = r[2] ^ POOL[1073];
+    r[2] = r[5] ^ POOL[1074];
+    r[5] = r[2] ^ POOL[1075];
+    r[2] = r[1] ^ POOL[1076];
+    r[1] = {
+        let (t, s) = (POOL[1077], POOL[1078]);
+        let (ma, mb) = (t ^ s ^ POOL[1079], t ^ s ^ POOL[1080]);
         let mut z = t;
-        z ^= r[1] & mb;
+        z ^= r[5] & mb;
         z ^= r[2] & ma;
-        z ^= r[1] & r[2];
+        z ^= r[5] & r[2];
         z
     };
-    r[1] = r[4] ^ r[14];
-    r[2] = r[1].rotate_left(1);
-    r[1] = r[10] & POOL[344];
-    r[4] = r[1] ^ r[2];
-    r[14] = r[8] ^ r[4];
-    r[4] = r[11] & POOL[345];
-    r[8] = {
-        let (t, ma, mb) = (POOL[346], POOL[347], POOL[348]);
+    r[2] = r[9] ^ r[1];
+    r[1] = r[2].rotate_left(1);
+    r[2] = r[15] & POOL[1081];
+    r[9] = r[2] ^ r[1];
+    r[5] = r[6] ^ r[9];
+    r[9] = r[13] & POOL[1082];
+    r[6] = r[2] ^ POOL[1083];
+    r[2] = r[6] ^ POOL[1084];
+    r[6] = r[2] ^ POOL[1085];
+    r[2] = r[1] ^ POOL[1086];
+    r[1] = {
+        let (t, s) = (POOL[1087], POOL[1088]);
+        let (ma, mb) = (t ^ s ^ POOL[1089], t ^ s ^ POOL[1090]);
         let mut z = t;
-        z ^= r[1] & mb;
+        z ^= r[6] & mb;
         z ^= r[2] & ma;
-        z ^= r[1] & r[2];
+        z ^= r[6] & r[2];
         z
     };
-    r[1] = r[4] ^ r[8];
-    r[2] = r[1].rotate_left(1);
-    r[1] = r[10] & POOL[349];
-    r[4] = r[1] ^ r[2];
-    r[8] = r[14] ^ r[4];
-    r[4] = r[11] & POOL[350];
-    r[14] = {
-        let (t, ma, mb) = (POOL[351], POOL[352], POOL[353]);
+    r[2] = r[9] ^ r[1];
+    r[1] = r[2].rotate_left(1);
+    r[2] = r[15] & POOL[1091];
+    r[9] = r[2] ^ r[1];
+    r[6] = r[5] ^ r[9];
+    r[9] = r[13] & POOL[1092];
+    r[5] = r[2] ^ POOL[1093];
+    r[2] = r[5] ^ POOL[1094];
+    r[5] = r[2] ^ POOL[1095];
+    r[2] = r[1] ^ POOL[1096];
+    r[1] = {
+        let (t, s) = (POOL[1097], POOL[1098]);
+        let (ma, mb) = (t ^ s ^ POOL[1099], t ^ s ^ POOL[1100]);
         let mut z = t;
-        z ^= r[1] & mb;
+        z ^= r[5] & mb;
         z ^= r[2] & ma;
-        z ^= r[1] & r[2];
+        z ^= r[5] & r[2];
         z
     };
-    r[1] = r[4] ^ r[14];
-    r[2] = r[1].rotate_left(1);
-    r[1] = r[10] & POOL[354];
-    r[4] = r[1] ^ r[2];
-    r[14] = r[8] ^ r[4];
-    r[4] = r[11] & POOL[355];
-    r[8] = {
-        let (t, ma, mb) = (POOL[356], POOL[357], POOL[358]);
+    r[2] = r[9] ^ r[1];
+    r[1] = r[2].rotate_left(1);
+    r[2] = r[15] & POOL[1101];
+    r[9] = r[2] ^ r[1];
+    r[5] = r[6] ^ r[9];
+    r[9] = r[13] & POOL[1102];
+    r[6] = r[2] ^ POOL[1103];
+    r[2] = r[6] ^ POOL[1104];
+    r[6] = r[2] ^ POOL[1105];
+    r[2] = r[1] ^ POOL[1106];
+    r[1] = {
+        let (t, s) = (POOL[1107], POOL[1108]);
+        let (ma, mb) = (t ^ s ^ POOL[1109], t ^ s ^ POOL[1110]);
         let mut z = t;
-        z ^= r[1] & mb;
+        z ^= r[6] & mb;
         z ^= r[2] & ma;
-        z ^= r[1] & r[2];
+        z ^= r[6] & r[2];
         z
     };
-    r[1] = r[4] ^ r[8];
-    r[2] = r[1].rotate_left(1);
-    r[1] = r[10] & POOL[359];
-    r[4] = r[1] ^ r[2];
-    r[8] = r[14] ^ r[4];
-    r[4] = r[11] & POOL[360];
-    r[14] = {
-        let (t, ma, mb) = (POOL[361], POOL[362], POOL[363]);
+    r[2] = r[9] ^ r[1];
+    r[1] = r[2].rotate_left(1);
+    r[2] = r[15] & POOL[1111];
+    r[9] = r[2] ^ r[1];
+    r[6] = r[5] ^ r[9];
+    r[9] = r[13] & POOL[1112];
+    r[5] = r[2] ^ POOL[1113];
+    r[2] = r[5] ^ POOL[1114];
+    r[5] = r[2] ^ POOL[1115];
+    r[2] = r[1] ^ POOL[1116];
+    r[1] = {
+        let (t, s) = (POOL[1117], POOL[1118]);
+        let (ma, mb) = (t ^ s ^ POOL[1119], t ^ s ^ POOL[1120]);
         let mut z = t;
-        z ^= r[1] & mb;
+        z ^= r[5] & mb;
         z ^= r[2] & ma;
-        z ^= r[1] & r[2];
+        z ^= r[5] & r[2];
         z
     };
-    r[1] = r[4] ^ r[14];
-    r[2] = r[1].rotate_left(1);
-    r[1] = r[10] & POOL[364];
-    r[4] = r[1] ^ r[2];
-    r[14] = r[8] ^ r[4];
-    r[4] = r[11] & POOL[365];
-    r[8] = {
-        let (t, ma, mb) = (POOL[366], POOL[367], POOL[368]);
+    r[2] = r[9] ^ r[1];
+    r[1] = r[2].rotate_left(1);
+    r[2] = r[15] & POOL[1121];
+    r[9] = r[2] ^ r[1];
+    r[5] = r[6] ^ r[9];
+    r[9] = r[13] & POOL[1122];
+    r[6] = r[2] ^ POOL[1123];
+    r[2] = r[6] ^ POOL[1124];
+    r[6] = r[2] ^ POOL[1125];
+    r[2] = r[1] ^ POOL[1126];
+    r[1] = {
+        let (t, s) = (POOL[1127], POOL[1128]);
+        let (ma, mb) = (t ^ s ^ POOL[1129], t ^ s ^ POOL[1130]);
         let mut z = t;
-        z ^= r[1] & mb;
+        z ^= r[6] & mb;
         z ^= r[2] & ma;
-        z ^= r[1] & r[2];
+        z ^= r[6] & r[2];
         z
     };
-    r[1] = r[4] ^ r[8];
-    r[2] = r[1].rotate_left(1);
-    r[1] = r[10] & POOL[369];
-    r[4] = r[1] ^ r[2];
-    r[8] = r[14] ^ r[4];
-    r[4] = r[11] & POOL[370];
-    r[14] = {
-        let (t, ma, mb) = (POOL[371], POOL[372], POOL[373]);
+    r[2] = r[9] ^ r[1];
+    r[1] = r[2].rotate_left(1);
+    r[2] = r[15] & POOL[1131];
+    r[9] = r[2] ^ r[1];
+    r[6] = r[5] ^ r[9];
+    r[9] = r[13] & POOL[1132];
+    r[5] = r[2] ^ POOL[1133];
+    r[2] = r[5] ^ POOL[1134];
+    r[5] = r[2] ^ POOL[1135];
+    r[2] = r[1] ^ POOL[1136];
+    r[1] = {
+        let (t, s) = (POOL[1137], POOL[1138]);
+        let (ma, mb) = (t ^ s ^ POOL[1139], t ^ s ^ POOL[1140]);
         let mut z = t;
-        z ^= r[1] & mb;
+        z ^= r[5] & mb;
         z ^= r[2] & ma;
-        z ^= r[1] & r[2];
+        z ^= r[5] & r[2];
         z
     };
-    r[1] = r[4] ^ r[14];
-    r[2] = r[1].rotate_left(1);
-    r[1] = r[10] & POOL[374];
-    r[4] = r[1] ^ r[2];
-    r[14] = r[8] ^ r[4];
-    r[4] = r[11] & POOL[375];
-    r[8] = {
-        let (t, ma, mb) = (POOL[376], POOL[377], POOL[378]);
+    r[2] = r[9] ^ r[1];
+    r[1] = r[2].rotate_left(1);
+    r[2] = r[15] & POOL[1141];
+    r[9] = r[2] ^ r[1];
+    r[5] = r[6] ^ r[9];
+    r[9] = r[13] & POOL[1142];
+    r[6] = r[2] ^ POOL[1143];
+    r[2] = r[6] ^ POOL[1144];
+    r[6] = r[2] ^ POOL[1145];
+    r[2] = r[1] ^ POOL[1146];
+    r[1] = {
+        let (t, s) = (POOL[1147], POOL[1148]);
+        let (ma, mb) = (t ^ s ^ POOL[1149], t ^ s ^ POOL[1150]);
         let mut z = t;
-        z ^= r[1] & mb;
+        z ^= r[6] & mb;
         z ^= r[2] & ma;
-        z ^= r[1] & r[2];
+        z ^= r[6] & r[2];
         z
     };
-    r[1] = r[4] ^ r[8];
-    r[2] = r[1].rotate_left(1);
-    r[1] = r[10] & POOL[379];
-    r[4] = r[1] ^ r[2];
-    r[8] = r[14] ^ r[4];
-    r[4] = r[11] & POOL[380];
-    r[14] = {
-        let (t, ma, mb) = (POOL[381], POOL[382], POOL[383]);
+    r[2] = r[9] ^ r[1];
+    r[1] = r[2].rotate_left(1);
+    r[2] = r[15] & POOL[1151];
+    r[9] = r[2] ^ r[1];
+    r[6] = r[5] ^ r[9];
+    r[9] = r[13] & POOL[1152];
+    r[5] = r[2] ^ POOL[1153];
+    r[2] = r[5] ^ POOL[1154];
+    r[5] = r[2] ^ POOL[1155];
+    r[2] = r[1] ^ POOL[1156];
+    r[1] = {
+        let (t, s) = (POOL[1157], POOL[1158]);
+        let (ma, mb) = (t ^ s ^ POOL[1159], t ^ s ^ POOL[1160]);
         let mut z = t;
-        z ^= r[1] & mb;
+        z ^= r[5] & mb;
         z ^= r[2] & ma;
-        z ^= r[1] & r[2];
+        z ^= r[5] & r[2];
         z
     };
-    r[1] = r[4] ^ r[14];
-    r[2] = r[1].rotate_left(1);
-    r[1] = r[10] & POOL[384];
-    r[4] = r[1] ^ r[2];
-    r[14] = r[8] ^ r[4];
-    r[4] = r[11] & POOL[385];
-    r[8] = {
-        let (t, ma, mb) = (POOL[386], POOL[387], POOL[388]);
+    r[2] = r[9] ^ r[1];
+    r[1] = r[2].rotate_left(1);
+    r[2] = r[15] & POOL[1161];
+    r[9] = r[2] ^ r[1];
+    r[5] = r[6] ^ r[9];
+    r[9] = r[13] & POOL[1162];
+    r[6] = r[2] ^ POOL[1163];
+    r[2] = r[6] ^ POOL[1164];
+    r[6] = r[2] ^ POOL[1165];
+    r[2] = r[1] ^ POOL[1166];
+    r[1] = {
+        let (t, s) = (POOL[1167], POOL[1168]);
+        let (ma, mb) = (t ^ s ^ POOL[1169], t ^ s ^ POOL[1170]);
         let mut z = t;
-        z ^= r[1] & mb;
+        z ^= r[6] & mb;
         z ^= r[2] & ma;
-        z ^= r[1] & r[2];
+        z ^= r[6] & r[2];
         z
     };
-    r[1] = r[4] ^ r[8];
-    r[2] = r[1].rotate_left(1);
-    r[1] = r[10] & POOL[389];
-    r[4] = r[1] ^ r[2];
-    r[8] = r[14] ^ r[4];
-    r[4] = r[11] & POOL[390];
-    r[14] = {
-        let (t, ma, mb) = (POOL[391], POOL[392], POOL[393]);
+    r[2] = r[9] ^ r[1];
+    r[1] = r[2].rotate_left(1);
+    r[2] = r[15] & POOL[1171];
+    r[9] = r[2] ^ r[1];
+    r[6] = r[5] ^ r[9];
+    r[9] = r[13] & POOL[1172];
+    r[5] = r[2] ^ POOL[1173];
+    r[2] = r[5] ^ POOL[1174];
+    r[5] = r[2] ^ POOL[1175];
+    r[2] = r[1] ^ POOL[1176];
+    r[1] = {
+        let (t, s) = (POOL[1177], POOL[1178]);
+        let (ma, mb) = (t ^ s ^ POOL[1179], t ^ s ^ POOL[1180]);
         let mut z = t;
-        z ^= r[1] & mb;
+        z ^= r[5] & mb;
         z ^= r[2] & ma;
-        z ^= r[1] & r[2];
+        z ^= r[5] & r[2];
         z
     };
-    r[1] = r[4] ^ r[14];
-    r[2] = r[1].rotate_left(1);
-    r[1] = r[10] & POOL[394];
-    r[4] = r[1] ^ r[2];
-    r[14] = r[8] ^ r[4];
-    r[4] = r[11] & POOL[395];
-    r[8] = {
-        let (t, ma, mb) = (POOL[396], POOL[397], POOL[398]);
+    r[2] = r[9] ^ r[1];
+    r[1] = r[2].rotate_left(1);
+    r[2] = r[15] & POOL[1181];
+    r[9] = r[2] ^ r[1];
+    r[5] = r[6] ^ r[9];
+    r[9] = r[13] & POOL[1182];
+    r[6] = r[2] ^ POOL[1183];
+    r[2] = r[6] ^ POOL[1184];
+    r[6] = r[2] ^ POOL[1185];
+    r[2] = r[1] ^ POOL[1186];
+    r[1] = {
+        let (t, s) = (POOL[1187], POOL[1188]);
+        let (ma, mb) = (t ^ s ^ POOL[1189], t ^ s ^ POOL[1190]);
         let mut z = t;
-        z ^= r[1] & mb;
+        z ^= r[6] & mb;
         z ^= r[2] & ma;
-        z ^= r[1] & r[2];
+        z ^= r[6] & r[2];
         z
     };
-    r[1] = r[4] ^ r[8];
-    r[2] = r[1].rotate_left(1);
-    r[1] = r[10] & POOL[399];
-    r[4] = r[1] ^ r[2];
-    r[8] = r[14] ^ r[4];
-    r[4] = r[11] & POOL[400];
-    r[14] = {
-        let (t, ma, mb) = (POOL[401], POOL[402], POOL[403]);
+    r[2] = r[9] ^ r[1];
+    r[1] = r[2].rotate_left(1);
+    r[2] = r[15] & POOL[1191];
+    r[9] = r[2] ^ r[1];
+    r[6] = r[5] ^ r[9];
+    r[9] = r[13] & POOL[1192];
+    r[5] = r[2] ^ POOL[1193];
+    r[2] = r[5] ^ POOL[1194];
+    r[5] = r[2] ^ POOL[1195];
+    r[2] = r[1] ^ POOL[1196];
+    r[1] = {
+        let (t, s) = (POOL[1197], POOL[1198]);
+        let (ma, mb) = (t ^ s ^ POOL[1199], t ^ s ^ POOL[1200]);
         let mut z = t;
-        z ^= r[1] & mb;
+        z ^= r[5] & mb;
         z ^= r[2] & ma;
-        z ^= r[1] & r[2];
+        z ^= r[5] & r[2];
         z
     };
-    r[1] = r[4] ^ r[14];
-    r[2] = r[1].rotate_left(1);
-    r[1] = r[10] & POOL[404];
-    r[4] = r[1] ^ r[2];
-    r[14] = r[8] ^ r[4];
-    r[4] = r[11] & POOL[405];
-    r[8] = {
-        let (t, ma, mb) = (POOL[406], POOL[407], POOL[408]);
+    r[2] = r[9] ^ r[1];
+    r[1] = r[2].rotate_left(1);
+    r[2] = r[15] & POOL[1201];
+    r[9] = r[2] ^ r[1];
+    r[5] = r[6] ^ r[9];
+    r[9] = r[13] & POOL[1202];
+    r[6] = r[2] ^ POOL[1203];
+    r[2] = r[6] ^ POOL[1204];
+    r[6] = r[2] ^ POOL[1205];
+    r[2] = r[1] ^ POOL[1206];
+    r[1] = {
+        let (t, s) = (POOL[1207], POOL[1208]);
+        let (ma, mb) = (t ^ s ^ POOL[1209], t ^ s ^ POOL[1210]);
         let mut z = t;
-        z ^= r[1] & mb;
+        z ^= r[6] & mb;
         z ^= r[2] & ma;
-        z ^= r[1] & r[2];
+        z ^= r[6] & r[2];
         z
     };
-    r[1] = r[4] ^ r[8];
-    r[2] = r[1].rotate_left(1);
-    r[1] = r[10] & POOL[409];
-    r[4] = r[1] ^ r[2];
-    r[8] = r[14] ^ r[4];
-    r[4] = r[11] & POOL[410];
-    r[14] = {
-        let (t, ma, mb) = (POOL[411], POOL[412], POOL[413]);
+    r[2] = r[9] ^ r[1];
+    r[1] = r[2].rotate_left(1);
+    r[2] = r[15] & POOL[1211];
+    r[9] = r[2] ^ r[1];
+    r[6] = r[5] ^ r[9];
+    r[9] = r[13] & POOL[1212];
+    r[5] = r[2] ^ POOL[1213];
+    r[2] = r[5] ^ POOL[1214];
+    r[5] = r[2] ^ POOL[1215];
+    r[2] = r[1] ^ POOL[1216];
+    r[1] = {
+        let (t, s) = (POOL[1217], POOL[1218]);
+        let (ma, mb) = (t ^ s ^ POOL[1219], t ^ s ^ POOL[1220]);
         let mut z = t;
-        z ^= r[1] & mb;
+        z ^= r[5] & mb;
         z ^= r[2] & ma;
-        z ^= r[1] & r[2];
+        z ^= r[5] & r[2];
         z
     };
-    r[1] = r[4] ^ r[14];
-    r[2] = r[1].rotate_left(1);
-    r[1] = r[10] & POOL[414];
-    r[4] = r[1] ^ r[2];
-    r[14] = r[8] ^ r[4];
-    r[4] = r[11] & POOL[415];
-    r[8] = {
-        let (t, ma, mb) = (POOL[416], POOL[417], POOL[418]);
+    r[2] = r[9] ^ r[1];
+    r[1] = r[2].rotate_left(1);
+    r[2] = r[15] & POOL[1221];
+    r[9] = r[2] ^ r[1];
+    r[5] = r[6] ^ r[9];
+    r[9] = r[13] & POOL[1222];
+    r[6] = r[2] ^ POOL[1223];
+    r[2] = r[6] ^ POOL[1224];
+    r[6] = r[2] ^ POOL[1225];
+    r[2] = r[1] ^ POOL[1226];
+    r[1] = {
+        let (t, s) = (POOL[1227], POOL[1228]);
+        let (ma, mb) = (t ^ s ^ POOL[1229], t ^ s ^ POOL[1230]);
         let mut z = t;
-        z ^= r[1] & mb;
+        z ^= r[6] & mb;
         z ^= r[2] & ma;
-        z ^= r[1] & r[2];
+        z ^= r[6] & r[2];
         z
     };
-    r[1] = r[4] ^ r[8];
-    r[2] = r[1].rotate_left(1);
-    r[1] = r[10] & POOL[419];
-    r[4] = r[1] ^ r[2];
-    r[8] = r[14] ^ r[4];
-    r[4] = r[11] & POOL[420];
-    r[14] = {
-        let (t, ma, mb) = (POOL[421], POOL[422], POOL[423]);
+    r[2] = r[9] ^ r[1];
+    r[1] = r[2].rotate_left(1);
+    r[2] = r[15] & POOL[1231];
+    r[9] = r[2] ^ r[1];
+    r[6] = r[5] ^ r[9];
+    r[9] = r[13] & POOL[1232];
+    r[5] = r[2] ^ POOL[1233];
+    r[2] = r[5] ^ POOL[1234];
+    r[5] = r[2] ^ POOL[1235];
+    r[2] = r[1] ^ POOL[1236];
+    r[1] = {
+        let (t, s) = (POOL[1237], POOL[1238]);
+        let (ma, mb) = (t ^ s ^ POOL[1239], t ^ s ^ POOL[1240]);
         let mut z = t;
-        z ^= r[1] & mb;
+        z ^= r[5] & mb;
         z ^= r[2] & ma;
-        z ^= r[1] & r[2];
+        z ^= r[5] & r[2];
         z
     };
-    r[1] = r[4] ^ r[14];
-    r[2] = r[1].rotate_left(1);
-    r[1] = r[10] & POOL[424];
-    r[4] = r[1] ^ r[2];
-    r[14] = r[8] ^ r[4];
-    r[4] = r[11] & POOL[425];
-    r[8] = {
-        let (t, ma, mb) = (POOL[426], POOL[427], POOL[428]);
+    r[2] = r[9] ^ r[1];
+    r[1] = r[2].rotate_left(1);
+    r[2] = r[15] & POOL[1241];
+    r[9] = r[2] ^ r[1];
+    r[5] = r[6] ^ r[9];
+    r[9] = r[13] & POOL[1242];
+    r[6] = r[2] ^ POOL[1243];
+    r[2] = r[6] ^ POOL[1244];
+    r[6] = r[2] ^ POOL[1245];
+    r[2] = r[1] ^ POOL[1246];
+    r[1] = {
+        let (t, s) = (POOL[1247], POOL[1248]);
+        let (ma, mb) = (t ^ s ^ POOL[1249], t ^ s ^ POOL[1250]);
         let mut z = t;
-        z ^= r[1] & mb;
+        z ^= r[6] & mb;
         z ^= r[2] & ma;
-        z ^= r[1] & r[2];
+        z ^= r[6] & r[2];
         z
     };
-    r[1] = r[4] ^ r[8];
-    r[2] = r[1].rotate_left(1);
-    r[1] = r[10] & POOL[429];
-    r[4] = r[1] ^ r[2];
-    r[8] = r[14] ^ r[4];
-    r[4] = r[11] & POOL[430];
-    r[14] = {
-        let (t, ma, mb) = (POOL[431], POOL[432], POOL[433]);
+    r[2] = r[9] ^ r[1];
+    r[1] = r[2].rotate_left(1);
+    r[2] = r[15] & POOL[1251];
+    r[9] = r[2] ^ r[1];
+    r[6] = r[5] ^ r[9];
+    r[9] = r[13] & POOL[1252];
+    r[13] = r[2] ^ POOL[1253];
+    r[2] = r[13] ^ POOL[1254];
+    r[13] = r[2] ^ POOL[1255];
+    r[2] = r[1] ^ POOL[1256];
+    r[1] = {
+        let (t, s) = (POOL[1257], POOL[1258]);
+        let (ma, mb) = (t ^ s ^ POOL[1259], t ^ s ^ POOL[1260]);
         let mut z = t;
-        z ^= r[1] & mb;
+        z ^= r[13] & mb;
         z ^= r[2] & ma;
-        z ^= r[1] & r[2];
+        z ^= r[13] & r[2];
         z
     };
-    r[1] = r[4] ^ r[14];
-    r[2] = r[1].rotate_left(1);
-    r[1] = r[10] & POOL[434];
-    r[4] = r[1] ^ r[2];
-    r[14] = r[8] ^ r[4];
-    r[4] = r[11] & POOL[435];
-    r[8] = {
-        let (t, ma, mb) = (POOL[436], POOL[437], POOL[438]);
+    r[13] = r[9] ^ r[1];
+    r[1] = r[13].rotate_left(1);
+    r[13] = r[15] & POOL[1261];
+    r[15] = r[13] ^ r[1];
+    r[1] = r[6] ^ r[15];
+    r[13] = r[1] ^ POOL[1262];
+    r[1] = r[13] ^ POOL[1263];
+    r[13] = r[8] ^ POOL[1264];
+    r[8] = r[1] ^ r[14];
+    r[14] = r[8].rotate_left(16);
+    r[15] = r[3] ^ POOL[1265];
+    r[2] = r[15] ^ POOL[1266];
+    r[15] = r[14] ^ POOL[1267];
+    r[9] = {
+        let (t, s) = (POOL[1268], POOL[1269]);
+        let (ma, mb) = (t ^ s ^ POOL[1270], t ^ s ^ POOL[1271]);
         let mut z = t;
-        z ^= r[1] & mb;
+        z ^= r[2] & mb;
+        z ^= r[15] & ma;
+        z ^= r[2] & r[15];
+        z
+    };
+    r[15] = r[3] ^ r[14];
+    r[3] = r[15] & POOL[1272];
+    r[2] = r[9] & POOL[1273];
+    r[6] = r[2].rotate_left(1);
+    r[2] = r[15] & POOL[1274];
+    r[5] = r[2] ^ r[6];
+    r[7] = r[3] ^ r[5];
+    r[3] = r[9] & POOL[1275];
+    r[5] = r[2] ^ POOL[1276];
+    r[2] = r[5] ^ POOL[1277];
+    r[5] = r[2] ^ POOL[1278];
+    r[2] = r[6] ^ POOL[1279];
+    r[6] = r[2] ^ POOL[1280];
+    r[2] = r[6] ^ POOL[1281];
+    r[6] = {
+        let (t, s) = (POOL[1282], POOL[1283]);
+        let (ma, mb) = (t ^ s ^ POOL[1284], t ^ s ^ POOL[1285]);
+        let mut z = t;
+        z ^= r[5] & mb;
         z ^= r[2] & ma;
-        z ^= r[1] & r[2];
-        z
-    };
-    r[1] = r[4] ^ r[8];
-    r[2] = r[1].rotate_left(1);
-    r[1] = r[10] & POOL[439];
-    r[4] = r[1] ^ r[2];
-    r[8] = r[14] ^ r[4];
-    r[4] = r[11] & POOL[440];
-    r[14] = {
-        let (t, ma, mb) = (POOL[441], POOL[442], POOL[443]);
+        z ^= r[5] & r[2];
+        z
+    };
+    r[2] = r[3] ^ r[6];
+    r[3] = r[2].rotate_left(1);
+    r[2] = r[15] & POOL[1286];
+    r[6] = r[2] ^ r[3];
+    r[5] = r[7] ^ r[6];
+    r[6] = r[9] & POOL[1287];
+    r[7] = r[2] ^ POOL[1288];
+    r[2] = r[7] ^ POOL[1289];
+    r[7] = r[2] ^ POOL[1290];
+    r[2] = r[3] ^ POOL[1291];
+    r[3] = {
+        let (t, s) = (POOL[1292], POOL[1293]);
+        let (ma, mb) = (t ^ s ^ POOL[1294], t ^ s ^ POOL[1295]);
         let mut z = t;
-        z ^= r[1] & mb;
+        z ^= r[7] & mb;
         z ^= r[2] & ma;
-        z ^= r[1] & r[2];
-        z
-    };
-    r[1] = r[4] ^ r[14];
-    r[2] = r[1].rotate_left(1);
-    r[1] = r[10] & POOL[444];
-    r[4] = r[1] ^ r[2];
-    r[14] = r[8] ^ r[4];
-    r[4] = r[11] & POOL[445];
-    r[8] = {
-        let (t, ma, mb) = (POOL[446], POOL[447], POOL[448]);
+        z ^= r[7] & r[2];
+        z
+    };
+    r[2] = r[6] ^ r[3];
+    r[3] = r[2].rotate_left(1);
+    r[2] = r[15] & POOL[1296];
+    r[6] = r[2] ^ r[3];
+    r[7] = r[5] ^ r[6];
+    r[6] = r[9] & POOL[1297];
+    r[5] = r[2] ^ POOL[1298];
+    r[2] = r[5] ^ POOL[1299];
+    r[5] = r[2] ^ POOL[1300];
+    r[2] = r[3] ^ POOL[1301];
+    r[3] = {
+        let (t, s) = (POOL[1302], POOL[1303]);
+        let (ma, mb) = (t ^ s ^ POOL[1304], t ^ s ^ POOL[1305]);
         let mut z = t;
-        z ^= r[1] & mb;
+        z ^= r[5] & mb;
         z ^= r[2] & ma;
-        z ^= r[1] & r[2];
-        z
-    };
-    r[1] = r[4] ^ r[8];
-    r[2] = r[1].rotate_left(1);
-    r[1] = r[10] & POOL[449];
-    r[4] = r[1] ^ r[2];
-    r[8] = r[14] ^ r[4];
-    r[4] = r[11] & POOL[450];
-    r[14] = {
-        let (t, ma, mb) = (POOL[451], POOL[452], POOL[453]);
+        z ^= r[5] & r[2];
+        z
+    };
+    r[2] = r[6] ^ r[3];
+    r[3] = r[2].rotate_left(1);
+    r[2] = r[15] & POOL[1306];
+    r[6] = r[2] ^ r[3];
+    r[5] = r[7] ^ r[6];
+    r[6] = r[9] & POOL[1307];
+    r[7] = r[2] ^ POOL[1308];
+    r[2] = r[7] ^ POOL[1309];
+    r[7] = r[2] ^ POOL[1310];
+    r[2] = r[3] ^ POOL[1311];
+    r[3] = {
+        let (t, s) = (POOL[1312], POOL[1313]);
+        let (ma, mb) = (t ^ s ^ POOL[1314], t ^ s ^ POOL[1315]);
         let mut z = t;
-        z ^= r[1] & mb;
+        z ^= r[7] & mb;
         z ^= r[2] & ma;
-        z ^= r[1] & r[2];
-        z
-    };
-    r[1] = r[4] ^ r[14];
-    r[2] = r[1].rotate_left(1);
-    r[1] = r[10] & POOL[454];
-    r[4] = r[1] ^ r[2];
-    r[14] = r[8] ^ r[4];
-    r[4] = r[11] & POOL[455];
-    r[8] = {
-        let (t, ma, mb) = (POOL[456], POOL[457], POOL[458]);
+        z ^= r[7] & r[2];
+        z
+    };
+    r[2] = r[6] ^ r[3];
+    r[3] = r[2].rotate_left(1);
+    r[2] = r[15] & POOL[1316];
+    r[6] = r[2] ^ r[3];
+    r[7] = r[5] ^ r[6];
+    r[6] = r[9] & POOL[1317];
+    r[5] = r[2] ^ POOL[1318];
+    r[2] = r[5] ^ POOL[1319];
+    r[5] = r[2] ^ POOL[1320];
+    r[2] = r[3] ^ POOL[1321];
+    r[3] = {
+        let (t, s) = (POOL[1322], POOL[1323]);
+        let (ma, mb) = (t ^ s ^ POOL[1324], t ^ s ^ POOL[1325]);
         let mut z = t;
-        z ^= r[1] & mb;
+        z ^= r[5] & mb;
         z ^= r[2] & ma;
-        z ^= r[1] & r[2];
-        z
-    };
-    r[1] = r[4] ^ r[8];
-    r[2] = r[1].rotate_left(1);
-    r[1] = r[10] & POOL[459];
-    r[4] = r[1] ^ r[2];
-    r[8] = r[14] ^ r[4];
-    r[4] = r[11] & POOL[460];
-    r[14] = {
-        let (t, ma, mb) = (POOL[461], POOL[462], POOL[463]);
+        z ^= r[5] & r[2];
+        z
+    };
+    r[2] = r[6] ^ r[3];
+    r[3] = r[2].rotate_left(1);
+    r[2] = r[15] & POOL[1326];
+    r[6] = r[2] ^ r[3];
+    r[5] = r[7] ^ r[6];
+    r[6] = r[9] & POOL[1327];
+    r[7] = r[2] ^ POOL[1328];
+    r[2] = r[7] ^ POOL[1329];
+    r[7] = r[2] ^ POOL[1330];
+    r[2] = r[3] ^ POOL[1331];
+    r[3] = {
+        let (t, s) = (POOL[1332], POOL[1333]);
+        let (ma, mb) = (t ^ s ^ POOL[1334], t ^ s ^ POOL[1335]);
         let mut z = t;
-        z ^= r[1] & mb;
+        z ^= r[7] & mb;
         z ^= r[2] & ma;
-        z ^= r[1] & r[2];
-        z
-    };
-    r[1] = r[4] ^ r[14];
-    r[2] = r[1].rotate_left(1);
-    r[1] = r[10] & POOL[464];
-    r[4] = r[1] ^ r[2];
-    r[14] = r[8] ^ r[4];
-    r[4] = r[11] & POOL[465];
-    r[8] = {
-        let (t, ma, mb) = (POOL[466], POOL[467], POOL[468]);
+        z ^= r[7] & r[2];
+        z
+    };
+    r[2] = r[6] ^ r[3];
+    r[3] = r[2].rotate_left(1);
+    r[2] = r[15] & POOL[1336];
+    r[6] = r[2] ^ r[3];
+    r[7] = r[5] ^ r[6];
+    r[6] = r[9] & POOL[1337];
+    r[5] = r[2] ^ POOL[1338];
+    r[2] = r[5] ^ POOL[1339];
+    r[5] = r[2] ^ POOL[1340];
+    r[2] = r[3] ^ POOL[1341];
+    r[3] = {
+        let (t, s) = (POOL[1342], POOL[1343]);
+        let (ma, mb) = (t ^ s ^ POOL[1344], t ^ s ^ POOL[1345]);
         let mut z = t;
-        z ^= r[1] & mb;
+        z ^= r[5] & mb;
         z ^= r[2] & ma;
-        z ^= r[1] & r[2];
-        z
-    };
-    r[1] = r[4] ^ r[8];
-    r[2] = r[1].rotate_left(1);
-    r[1] = r[10] & POOL[469];
-    r[4] = r[1] ^ r[2];
-    r[8] = r[14] ^ r[4];
-    r[4] = r[11] & POOL[470];
-    r[14] = {
-        let (t, ma, mb) = (POOL[471], POOL[472], POOL[473]);
+        z ^= r[5] & r[2];
+        z
+    };
+    r[2] = r[6] ^ r[3];
+    r[3] = r[2].rotate_left(1);
+    r[2] = r[15] & POOL[1346];
+    r[6] = r[2] ^ r[3];
+    r[5] = r[7] ^ r[6];
+    r[6] = r[9] & POOL[1347];
+    r[7] = r[2] ^ POOL[1348];
+    r[2] = r[7] ^ POOL[1349];
+    r[7] = r[2] ^ POOL[1350];
+    r[2] = r[3] ^ POOL[1351];
+    r[3] = {
+        let (t, s) = (POOL[1352], POOL[1353]);
+        let (ma, mb) = (t ^ s ^ POOL[1354], t ^ s ^ POOL[1355]);
         let mut z = t;
-        z ^= r[1] & mb;
+        z ^= r[7] & mb;
         z ^= r[2] & ma;
-        z ^= r[1] & r[2];
-        z
-    };
-    r[1] = r[4] ^ r[14];
-    r[2] = r[1].rotate_left(1);
-    r[1] = r[10] & POOL[474];
-    r[4] = r[1] ^ r[2];
-    r[14] = r[8] ^ r[4];
-    r[4] = r[11] & POOL[475];
-    r[11] = {
-        let (t, ma, mb) = (POOL[476], POOL[477], POOL[478]);
+        z ^= r[7] & r[2];
+        z
+    };
+    r[2] = r[6] ^ r[3];
+    r[3] = r[2].rotate_left(1);
+    r[2] = r[15] & POOL[1356];
+    r[6] = r[2] ^ r[3];
+    r[7] = r[5] ^ r[6];
+    r[6] = r[9] & POOL[1357];
+    r[5] = r[2] ^ POOL[1358];
+    r[2] = r[5] ^ POOL[1359];
+    r[5] = r[2] ^ POOL[1360];
+    r[2] = r[3] ^ POOL[1361];
+    r[3] = {
+        let (t, s) = (POOL[1362], POOL[1363]);
+        let (ma, mb) = (t ^ s ^ POOL[1364], t ^ s ^ POOL[1365]);
         let mut z = t;
-        z ^= r[1] & mb;
+        z ^= r[5] & mb;
         z ^= r[2] & ma;
-        z ^= r[1] & r[2];
-        z
-    };
-    r[1] = r[4] ^ r[11];
-    r[11] = r[1].rotate_left(1);
-    r[1] = r[10] & POOL[479];
-    r[10] = r[1] ^ r[11];
-    r[1] = r[14] ^ r[10];
-    r[11] = POOL[480];
-    r[10] = {
-        let (t, ma, mb) = (POOL[481], POOL[482], POOL[483]);
+        z ^= r[5] & r[2];
+        z
+    };
+    r[2] = r[6] ^ r[3];
+    r[3] = r[2].rotate_left(1);
+    r[2] = r[15] & POOL[1366];
+    r[6] = r[2] ^ r[3];
+    r[5] = r[7] ^ r[6];
+    r[6] = r[9] & POOL[1367];
+    r[7] = r[2] ^ POOL[1368];
+    r[2] = r[7] ^ POOL[1369];
+    r[7] = r[2] ^ POOL[1370];
+    r[2] = r[3] ^ POOL[1371];
+    r[3] = {
+        let (t, s) = (POOL[1372], POOL[1373]);
+        let (ma, mb) = (t ^ s ^ POOL[1374], t ^ s ^ POOL[1375]);
         let mut z = t;
-        z ^= r[9] & mb;
-        z ^= r[12] & ma;
-        z ^= r[9] & r[12];
-        z
-    };
-    r[2] = r[9] ^ r[12];
-    r[9] = r[2] & POOL[484];
-    r[4] = r[10] & POOL[485];
-    r[14] = r[4].rotate_left(1);
-    r[4] = r[2] & POOL[486];
-    r[8] = r[4] ^ r[14];
-    r[6] = r[9] ^ r[8];
-    r[9] = r[10] & POOL[487];
-    r[8] = {
-        let (t, ma, mb) = (POOL[488], POOL[489], POOL[490]);
+        z ^= r[7] & mb;
+        z ^= r[2] & ma;
+        z ^= r[7] & r[2];
+        z
+    };
+    r[2] = r[6] ^ r[3];
+    r[3] = r[2].rotate_left(1);
+    r[2] = r[15] & POOL[1376];
+    r[6] = r[2] ^ r[3];
+    r[7] = r[5] ^ r[6];
+    r[6] = r[9] & POOL[1377];
+    r[5] = r[2] ^ POOL[1378];
+    r[2] = r[5] ^ POOL[1379];
+    r[5] = r[2] ^ POOL[1380];
+    r[2] = r[3] ^ POOL[1381];
+    r[3] = {
+        let (t, s) = (POOL[1382], POOL[1383]);
+        let (ma, mb) = (t ^ s ^ POOL[1384], t ^ s ^ POOL[1385]);
         let mut z = t;
-        z ^= r[4] & mb;
-        z ^= r[14] & ma;
-        z ^= r[4] & r[14];
-        z
-    };
-    r[4] = r[9] ^ r[8];
-    r[9] = r[4].rotate_left(1);
-    r[4] = r[2] & POOL[491];
-    r[14] = r[4] ^ r[9];
-    r[8] = r[6] ^ r[14];
-    r[14] = r[10] & POOL[492];
-    r[6] = {
-        let (t, ma, mb) = (POOL[493], POOL[494], POOL[495]);
+        z ^= r[5] & mb;
+        z ^= r[2] & ma;
+        z ^= r[5] & r[2];
+        z
+    };
+    r[2] = r[6] ^ r[3];
+    r[3] = r[2].rotate_left(1);
+    r[2] = r[15] & POOL[1386];
+    r[6] = r[2] ^ r[3];
+    r[5] = r[7] ^ r[6];
+    r[6] = r[9] & POOL[1387];
+    r[7] = r[2] ^ POOL[1388];
+    r[2] = r[7] ^ POOL[1389];
+    r[7] = r[2] ^ POOL[1390];
+    r[2] = r[3] ^ POOL[1391];
+    r[3] = {
+        let (t, s) = (POOL[1392], POOL[1393]);
+        let (ma, mb) = (t ^ s ^ POOL[1394], t ^ s ^ POOL[1395]);
         let mut z = t;
-        z ^= r[4] & mb;
-        z ^= r[9] & ma;
-        z ^= r[4] & r[9];
-        z
-    };
-    r[9] = r[14] ^ r[6];
-    r[4] = r[9].rotate_left(1);
-    r[9] = r[2] & POOL[496];
-    r[14] = r[9] ^ r[4];
-    r[6] = r[8] ^ r[14];
-    r[14] = r[10] & POOL[497];
-    r[8] = {
-        let (t, ma, mb) = (POOL[498], POOL[499], POOL[500]);
+        z ^= r[7] & mb;
+        z ^= r[2] & ma;
+        z ^= r[7] & r[2];
+        z
+    };
+    r[2] = r[6] ^ r[3];
+    r[3] = r[2].rotate_left(1);
+    r[2] = r[15] & POOL[1396];
+    r[6] = r[2] ^ r[3];
+    r[7] = r[5] ^ r[6];
+    r[6] = r[9] & POOL[1397];
+    r[5] = r[2] ^ POOL[1398];
+    r[2] = r[5] ^ POOL[1399];
+    r[5] = r[2] ^ POOL[1400];
+    r[2] = r[3] ^ POOL[1401];
+    r[3] = {
+        let (t, s) = (POOL[1402], POOL[1403]);
+        let (ma, mb) = (t ^ s ^ POOL[1404], t ^ s ^ POOL[1405]);
         let mut z = t;
-        z ^= r[9] & mb;
-        z ^= r[4] & ma;
-        z ^= r[9] & r[4];
-        z
-    };
-    r[9] = r[14] ^ r[8];
-    r[4] = r[9].rotate_left(1);
-    r[9] = r[2] & POOL[501];
-    r[14] = r[9] ^ r[4];
-    r[8] = r[6] ^ r[14];
-    r[14] = r[10] & POOL[502];
-    r[6] = {
-        let (t, ma, mb) = (POOL[503], POOL[504], POOL[505]);
+        z ^= r[5] & mb;
+        z ^= r[2] & ma;
+        z ^= r[5] & r[2];
+        z
+    };
+    r[2] = r[6] ^ r[3];
+    r[3] = r[2].rotate_left(1);
+    r[2] = r[15] & POOL[1406];
+    r[6] = r[2] ^ r[3];
+    r[5] = r[7] ^ r[6];
+    r[6] = r[9] & POOL[1407];
+    r[7] = r[2] ^ POOL[1408];
+    r[2] = r[7] ^ POOL[1409];
+    r[7] = r[2] ^ POOL[1410];
+    r[2] = r[3] ^ POOL[1411];
+    r[3] = {
+        let (t, s) = (POOL[1412], POOL[1413]);
+        let (ma, mb) = (t ^ s ^ POOL[1414], t ^ s ^ POOL[1415]);
         let mut z = t;
-        z ^= r[9] & mb;
-        z ^= r[4] & ma;
-        z ^= r[9] & r[4];
-        z
-    };
-    r[9] = r[14] ^ r[6];
-    r[4] = r[9].rotate_left(1);
-    r[9] = r[2] & POOL[506];
-    r[14] = r[9] ^ r[4];
-    r[6] = r[8] ^ r[14];
-    r[14] = r[10] & POOL[507];
-    r[8] = {
-        let (t, ma, mb) = (POOL[508], POOL[509], POOL[510]);
+        z ^= r[7] & mb;
+        z ^= r[2] & ma;
+        z ^= r[7] & r[2];
+        z
+    };
+    r[2] = r[6] ^ r[3];
+    r[3] = r[2].rotate_left(1);
+    r[2] = r[15] & POOL[1416];
+    r[6] = r[2] ^ r[3];
+    r[7] = r[5] ^ r[6];
+    r[6] = r[9] & POOL[1417];
+    r[5] = r[2] ^ POOL[1418];
+    r[2] = r[5] ^ POOL[1419];
+    r[5] = r[2] ^ POOL[1420];
+    r[2] = r[3] ^ POOL[1421];
+    r[3] = {
+        let (t, s) = (POOL[1422], POOL[1423]);
+        let (ma, mb) = (t ^ s ^ POOL[1424], t ^ s ^ POOL[1425]);
         let mut z = t;
-        z ^= r[9] & mb;
-        z ^= r[4] & ma;
-        z ^= r[9] & r[4];
-        z
-    };
-    r[9] = r[14] ^ r[8];
-    r[4] = r[9].rotate_left(1);
-    r[9] = r[2] & POOL[511];
-    r[14] = r[9] ^ r[4];
-    r[8] = r[6] ^ r[14];
-    r[14] = r[10] & POOL[512];
-    r[6] = {
-        let (t, ma, mb) = (POOL[513], POOL[514], POOL[515]);
+        z ^= r[5] & mb;
+        z ^= r[2] & ma;
+        z ^= r[5] & r[2];
+        z
+    };
+    r[2] = r[6] ^ r[3];
+    r[3] = r[2].rotate_left(1);
+    r[2] = r[15] & POOL[1426];
+    r[6] = r[2] ^ r[3];
+    r[5] = r[7] ^ r[6];
+    r[6] = r[9] & POOL[1427];
+    r[7] = r[2] ^ POOL[1428];
+    r[2] = r[7] ^ POOL[1429];
+    r[7] = r[2] ^ POOL[1430];
+    r[2] = r[3] ^ POOL[1431];
+    r[3] = {
+        let (t, s) = (POOL[1432], POOL[1433]);
+        let (ma, mb) = (t ^ s ^ POOL[1434], t ^ s ^ POOL[1435]);
         let mut z = t;
-        z ^= r[9] & mb;
-        z ^= r[4] & ma;
-        z ^= r[9] & r[4];
-        z
-    };
-    r[9] = r[14] ^ r[6];
-    r[4] = r[9].rotate_left(1);
-    r[9] = r[2] & POOL[516];
-    r[14] = r[9] ^ r[4];
-    r[6] = r[8] ^ r[14];
-    r[14] = r[10] & POOL[517];
-    r[8] = {
-        let (t, ma, mb) = (POOL[518], POOL[519], POOL[520]);
+        z ^= r[7] & mb;
+        z ^= r[2] & ma;
+        z ^= r[7] & r[2];
+        z
+    };
+    r[2] = r[6] ^ r[3];
+    r[3] = r[2].rotate_left(1);
+    r[2] = r[15] & POOL[1436];
+    r[6] = r[2] ^ r[3];
+    r[7] = r[5] ^ r[6];
+    r[6] = r[9] & POOL[1437];
+    r[5] = r[2] ^ POOL[1438];
+    r[2] = r[5] ^ POOL[1439];
+    r[5] = r[2] ^ POOL[1440];
+    r[2] = r[3] ^ POOL[1441];
+    r[3] = {
+        let (t, s) = (POOL[1442], POOL[1443]);
+        let (ma, mb) = (t ^ s ^ POOL[1444], t ^ s ^ POOL[1445]);
         let mut z = t;
-        z ^= r[9] & mb;
-        z ^= r[4] & ma;
-        z ^= r[9] & r[4];
-        z
-    };
-    r[9] = r[14] ^ r[8];
-    r[4] = r[9].rotate_left(1);
-    r[9] = r[2] & POOL[521];
-    r[14] = r[9] ^ r[4];
-    r[8] = r[6] ^ r[14];
-    r[14] = r[10] & POOL[522];
-    r[6] = {
-        let (t, ma, mb) = (POOL[523], POOL[524], POOL[525]);
+        z ^= r[5] & mb;
+        z ^= r[2] & ma;
+        z ^= r[5] & r[2];
+        z
+    };
+    r[2] = r[6] ^ r[3];
+    r[3] = r[2].rotate_left(1);
+    r[2] = r[15] & POOL[1446];
+    r[6] = r[2] ^ r[3];
+    r[5] = r[7] ^ r[6];
+    r[6] = r[9] & POOL[1447];
+    r[7] = r[2] ^ POOL[1448];
+    r[2] = r[7] ^ POOL[1449];
+    r[7] = r[2] ^ POOL[1450];
+    r[2] = r[3] ^ POOL[1451];
+    r[3] = {
+        let (t, s) = (POOL[1452], POOL[1453]);
+        let (ma, mb) = (t ^ s ^ POOL[1454], t ^ s ^ POOL[1455]);
         let mut z = t;
-        z ^= r[9] & mb;
-        z ^= r[4] & ma;
-        z ^= r[9] & r[4];
-        z
-    };
-    r[9] = r[14] ^ r[6];
-    r[4] = r[9].rotate_left(1);
-    r[9] = r[2] & POOL[526];
-    r[14] = r[9] ^ r[4];
-    r[6] = r[8] ^ r[14];
-    r[14] = r[10] & POOL[527];
-    r[8] = {
-        let (t, ma, mb) = (POOL[528], POOL[529], POOL[530]);
+        z ^= r[7] & mb;
+        z ^= r[2] & ma;
+        z ^= r[7] & r[2];
+        z
+    };
+    r[2] = r[6] ^ r[3];
+    r[3] = r[2].rotate_left(1);
+    r[2] = r[15] & POOL[1456];
+    r[6] = r[2] ^ r[3];
+    r[7] = r[5] ^ r[6];
+    r[6] = r[9] & POOL[1457];
+    r[5] = r[2] ^ POOL[1458];
+    r[2] = r[5] ^ POOL[1459];
+    r[5] = r[2] ^ POOL[1460];
+    r[2] = r[3] ^ POOL[1461];
+    r[3] = {
+        let (t, s) = (POOL[1462], POOL[1463]);
+        let (ma, mb) = (t ^ s ^ POOL[1464], t ^ s ^ POOL[1465]);
+        let mut z = t;
+        z ^= r[5] & mb;
+        z ^= r[2] & ma;
+        z ^= r[5] & r[2];
+        z
+    };
+    r[2] = r[6] ^ r[3];
+    r[3] = r[2].rotate_left(1);
+    r[2] = r[15] & POOL[1466];
+    r[6] = r[2] ^ r[3];
+    r[5] = r[7] ^ r[6];
+    r[6] = r[9] & POOL[1467];
+    r[7] = r[2] ^ POOL[1468];
+    r[2] = r[7] ^ POOL[1469];
+    r[7] = r[2] ^ POOL[1470];
+    r[2] = r[3] ^ POOL[1471];
+    r[3] = {
+        let (t, s) = (POOL[1472], POOL[1473]);
+        let (ma, mb) = (t ^ s ^ POOL[1474], t ^ s ^ POOL[1475]);
+        let mut z = t;
+        z ^= r[7] & mb;
+        z ^= r[2] & ma;
+        z ^= r[7] & r[2];
+        z
+    };
+    r[2] = r[6] ^ r[3];
+    r[3] = r[2].rotate_left(1);
+    r[2] = r[15] & POOL[1476];
+    r[6] = r[2] ^ r[3];
+    r[7] = r[5] ^ r[6];
+    r[6] = r[9] & POOL[1477];
+    r[5] = r[2] ^ POOL[1478];
+    r[2] = r[5] ^ POOL[1479];
+    r[5] = r[2] ^ POOL[1480];
+    r[2] = r[3] ^ POOL[1481];
+    r[3] = {
+        let (t, s) = (POOL[1482], POOL[1483]);
+        let (ma, mb) = (t ^ s ^ POOL[1484], t ^ s ^ POOL[1485]);
+        let mut z = t;
+        z ^= r[5] & mb;
+        z ^= r[2] & ma;
+        z ^= r[5] & r[2];
+        z
+    };
+    r[2] = r[6] ^ r[3];
+    r[3] = r[2].rotate_left(1);
+    r[2] = r[15] & POOL[1486];
+    r[6] = r[2] ^ r[3];
+    r[5] = r[7] ^ r[6];
+    r[6] = r[9] & POOL[1487];
+    r[7] = r[2] ^ POOL[1488];
+    r[2] = r[7] ^ POOL[1489];
+    r[7] = r[2] ^ POOL[1490];
+    r[2] = r[3] ^ POOL[1491];
+    r[3] = {
+        let (t, s) = (POOL[1492], POOL[1493]);
+        let (ma, mb) = (t ^ s ^ POOL[1494], t ^ s ^ POOL[1495]);
+        let mut z = t;
+        z ^= r[7] & mb;
+        z ^= r[2] & ma;
+        z ^= r[7] & r[2];
+        z
+    };
+    r[2] = r[6] ^ r[3];
+    r[3] = r[2].rotate_left(1);
+    r[2] = r[15] & POOL[1496];
+    r[6] = r[2] ^ r[3];
+    r[7] = r[5] ^ r[6];
+    r[6] = r[9] & POOL[1497];
+    r[5] = r[2] ^ POOL[1498];
+    r[2] = r[5] ^ POOL[1499];
+    r[5] = r[2] ^ POOL[1500];
+    r[2] = r[3] ^ POOL[1501];
+    r[3] = {
+        let (t, s) = (POOL[1502], POOL[1503]);
+        let (ma, mb) = (t ^ s ^ POOL[1504], t ^ s ^ POOL[1505]);
+        let mut z = t;
+        z ^= r[5] & mb;
+        z ^= r[2] & ma;
+        z ^= r[5] & r[2];
+        z
+    };
+    r[2] = r[6] ^ r[3];
+    r[3] = r[2].rotate_left(1);
+    r[2] = r[15] & POOL[1506];
+    r[6] = r[2] ^ r[3];
+    r[5] = r[7] ^ r[6];
+    r[6] = r[9] & POOL[1507];
+    r[7] = r[2] ^ POOL[1508];
+    r[2] = r[7] ^ POOL[1509];
+    r[7] = r[2] ^ POOL[1510];
+    r[2] = r[3] ^ POOL[1511];
+    r[3] = {
+        let (t, s) = (POOL[1512], POOL[1513]);
+        let (ma, mb) = (t ^ s ^ POOL[1514], t ^ s ^ POOL[1515]);
+        let mut z = t;
+        z ^= r[7] & mb;
+        z ^= r[2] & ma;
+        z ^= r[7] & r[2];
+        z
+    };
+    r[2] = r[6] ^ r[3];
+    r[3] = r[2].rotate_left(1);
+    r[2] = r[15] & POOL[1516];
+    r[6] = r[2] ^ r[3];
+    r[7] = r[5] ^ r[6];
+    r[6] = r[9] & POOL[1517];
+    r[5] = r[2] ^ POOL[1518];
+    r[2] = r[5] ^ POOL[1519];
+    r[5] = r[2] ^ POOL[1520];
+    r[2] = r[3] ^ POOL[1521];
+    r[3] = {
+        let (t, s) = (POOL[1522], POOL[1523]);
+        let (ma, mb) = (t ^ s ^ POOL[1524], t ^ s ^ POOL[1525]);
+        let mut z = t;
+        z ^= r[5] & mb;
+        z ^= r[2] & ma;
+        z ^= r[5] & r[2];
+        z
+    };
+    r[2] = r[6] ^ r[3];
+    r[3] = r[2].rotate_left(1);
+    r[2] = r[15] & POOL[1526];
+    r[6] = r[2] ^ r[3];
+    r[5] = r[7] ^ r[6];
+    r[6] = r[9] & POOL[1527];
+    r[7] = r[2] ^ POOL[1528];
+    r[2] = r[7] ^ POOL[1529];
+    r[7] = r[2] ^ POOL[1530];
+    r[2] = r[3] ^ POOL[1531];
+    r[3] = {
+        let (t, s) = (POOL[1532], POOL[1533]);
+        let (ma, mb) = (t ^ s ^ POOL[1534], t ^ s ^ POOL[1535]);
+        let mut z = t;
+        z ^= r[7] & mb;
+        z ^= r[2] & ma;
+        z ^= r[7] & r[2];
+        z
+    };
+    r[2] = r[6] ^ r[3];
+    r[3] = r[2].rotate_left(1);
+    r[2] = r[15] & POOL[1536];
+    r[6] = r[2] ^ r[3];
+    r[7] = r[5] ^ r[6];
+    r[6] = r[9] & POOL[1537];
+    r[5] = r[2] ^ POOL[1538];
+    r[2] = r[5] ^ POOL[1539];
+    r[5] = r[2] ^ POOL[1540];
+    r[2] = r[3] ^ POOL[1541];
+    r[3] = {
+        let (t, s) = (POOL[1542], POOL[1543]);
+        let (ma, mb) = (t ^ s ^ POOL[1544], t ^ s ^ POOL[1545]);
+        let mut z = t;
+        z ^= r[5] & mb;
+        z ^= r[2] & ma;
+        z ^= r[5] & r[2];
+        z
+    };
+    r[2] = r[6] ^ r[3];
+    r[3] = r[2].rotate_left(1);
+    r[2] = r[15] & POOL[1546];
+    r[6] = r[2] ^ r[3];
+    r[5] = r[7] ^ r[6];
+    r[6] = r[9] & POOL[1547];
+    r[7] = r[2] ^ POOL[1548];
+    r[2] = r[7] ^ POOL[1549];
+    r[7] = r[2] ^ POOL[1550];
+    r[2] = r[3] ^ POOL[1551];
+    r[3] = {
+        let (t, s) = (POOL[1552], POOL[1553]);
+        let (ma, mb) = (t ^ s ^ POOL[1554], t ^ s ^ POOL[1555]);
+        let mut z = t;
+        z ^= r[7] & mb;
+        z ^= r[2] & ma;
+        z ^= r[7] & r[2];
+        z
+    };
+    r[2] = r[6] ^ r[3];
+    r[3] = r[2].rotate_left(1);
+    r[2] = r[15] & POOL[1556];
+    r[6] = r[2] ^ r[3];
+    r[7] = r[5] ^ r[6];
+    r[6] = r[9] & POOL[1557];
+    r[5] = r[2] ^ POOL[1558];
+    r[2] = r[5] ^ POOL[1559];
+    r[5] = r[2] ^ POOL[1560];
+    r[2] = r[3] ^ POOL[1561];
+    r[3] = {
+        let (t, s) = (POOL[1562], POOL[1563]);
+        let (ma, mb) = (t ^ s ^ POOL[1564], t ^ s ^ POOL[1565]);
+        let mut z = t;
+        z ^= r[5] & mb;
+        z ^= r[2] & ma;
+        z ^= r[5] & r[2];
+        z
+    };
+    r[2] = r[6] ^ r[3];
+    r[3] = r[2].rotate_left(1);
+    r[2] = r[15] & POOL[1566];
+    r[6] = r[2] ^ r[3];
+    r[5] = r[7] ^ r[6];
+    r[6] = r[9] & POOL[1567];
+    r[9] = r[2] ^ POOL[1568];
+    r[2] = r[9] ^ POOL[1569];
+    r[9] = r[2] ^ POOL[1570];
+    r[2] = r[3] ^ POOL[1571];
+    r[3] = {
+        let (t, s) = (POOL[1572], POOL[1573]);
+        let (ma, mb) = (t ^ s ^ POOL[1574], t ^ s ^ POOL[1575]);
         let mut z = t;
         z ^= r[9] & mb;
-        z ^= r[4] & ma;
-        z ^= r[9] & r[4];
+        z ^= r[2] & ma;
+        z ^= r[9] & r[2];
+        z
+    };
+    r[2] = r[6] ^ r[3];
+    r[3] = r[2].rotate_left(1);
+    r[2] = r[15] & POOL[1576];
+    r[15] = r[2] ^ r[3];
+    r[3] = r[5] ^ r[15];
+    r[15] = r[13] ^ r[3];
+    r[3] = r[15] ^ POOL[1577];
+    r[13] = r[3].rotate_left(12);
+    r[3] = r[13] ^ POOL[1578];
+    r[13] = r[3] ^ POOL[1579];
+    r[3] = r[1] ^ POOL[1580];
+    r[15] = r[13] ^ POOL[1581];
+    r[2] = {
+        let (t, s) = (POOL[1582], POOL[1583]);
+        let (ma, mb) = (t ^ s ^ POOL[1584], t ^ s ^ POOL[1585]);
+        let mut z = t;
+        z ^= r[3] & mb;
+        z ^= r[15] & ma;
+        z ^= r[3] & r[15];
         z
     };
-    r[9] = r[14] ^ r[8];
-    r[4] = r[9].rotate_left(1);
-    r[9] = r[2] & POOL[531];
-    r[14] = r[9] ^ r[4];
-    r[8] = r[6] ^ r[14];
-    r[14] = r[10] & POOL[532];
-    r[6] = {
-        let (t, ma, mb) = (POOL[533], POOL[534], POOL[535]);
+    r[3] = r[1] ^ r[13];
+    r[1] = r[3] & POOL[1586];
+    r[13] = r[2] & POOL[1587];
+    r[15] = r[13].rotate_left(1);
+    r[13] = r[3] & POOL[1588];
+    r[9] = r[13] ^ r[15];
+    r[6] = r[1] ^ r[9];
+    r[1] = r[2] & POOL[1589];
+    r[9] = r[13] ^ POOL[1590];
+    r[13] = r[9] ^ POOL[1591];
+    r[9] = r[13] ^ POOL[1592];
+    r[13] = r[15] ^ POOL[1593];
+    r[15] = r[13] ^ POOL[1594];
+    r[13] = r[15] ^ POOL[1595];
+    r[15] = {
+        let (t, s) = (POOL[1596], POOL[1597]);
+        let (ma, mb) = (t ^ s ^ POOL[1598], t ^ s ^ POOL[1599]);
         let mut z = t;
         z ^= r[9] & mb;
-        z ^= r[4] & ma;
-        z ^= r[9] & r[4];
-        z
-    };
-    r[9] = r[14] ^ r[6];
-    r[4] = r[9].rotate_left(1);
-    r[9] = r[2] & POOL[536];
-    r[14] = r[9] ^ r[4];
-    r[6] = r[8] ^ r[14];
-    r[14] = r[10] & POOL[537];
-    r[8] = {
-        let (t, ma, mb) = (POOL[538], POOL[539], POOL[540]);
+        z ^= r[13] & ma;
+        z ^= r[9] & r[13];
+        z
+    };
+    r[13] = r[1] ^ r[15];
+    r[1] = r[13].rotate_left(1);
+    r[13] = r[3] & POOL[1600];
+    r[15] = r[13] ^ r[1];
+    r[9] = r[6] ^ r[15];
+    r[15] = r[2] & POOL[1601];
+    r[6] = r[13] ^ POOL[1602];
+    r[13] = r[6] ^ POOL[1603];
+    r[6] = r[13] ^ POOL[1604];
+    r[13] = r[1] ^ POOL[1605];
+    r[1] = {
+        let (t, s) = (POOL[1606], POOL[1607]);
+        let (ma, mb) = (t ^ s ^ POOL[1608], t ^ s ^ POOL[1609]);
+        let mut z = t;
+        z ^= r[6] & mb;
+        z ^= r[13] & ma;
+        z ^= r[6] & r[13];
+        z
+    };
+    r[13] = r[15] ^ r[1];
+    r[1] = r[13].rotate_left(1);
+    r[13] = r[3] & POOL[1610];
+    r[15] = r[13] ^ r[1];
+    r[6] = r[9] ^ r[15];
+    r[15] = r[2] & POOL[1611];
+    r[9] = r[13] ^ POOL[1612];
+    r[13] = r[9] ^ POOL[1613];
+    r[9] = r[13] ^ POOL[1614];
+    r[13] = r[1] ^ POOL[1615];
+    r[1] = {
+        let (t, s) = (POOL[1616], POOL[1617]);
+        let (ma, mb) = (t ^ s ^ POOL[1618], t ^ s ^ POOL[1619]);
         let mut z = t;
         z ^= r[9] & mb;
-        z ^= r[4] & ma;
-        z ^= r[9] & r[4];
-        z
-    };
-    r[9] = r[14] ^ r[8];
-    r[4] = r[9].rotate_left(1);
-    r[9] = r[2] & POOL[541];
-    r[14] = r[9] ^ r[4];
-    r[8] = r[6] ^ r[14];
-    r[14] = r[10] & POOL[542];
-    r[6] = {
-        let (t, ma, mb) = (POOL[543], POOL[544], POOL[545]);
+        z ^= r[13] & ma;
+        z ^= r[9] & r[13];
+        z
+    };
+    r[13] = r[15] ^ r[1];
+    r[1] = r[13].rotate_left(1);
+    r[13] = r[3] & POOL[1620];
+    r[15] = r[13] ^ r[1];
+    r[9] = r[6] ^ r[15];
+    r[15] = r[2] & POOL[1621];
+    r[6] = r[13] ^ POOL[1622];
+    r[13] = r[6] ^ POOL[1623];
+    r[6] = r[13] ^ POOL[1624];
+    r[13] = r[1] ^ POOL[1625];
+    r[1] = {
+        let (t, s) = (POOL[1626], POOL[1627]);
+        let (ma, mb) = (t ^ s ^ POOL[1628], t ^ s ^ POOL[1629]);
+        let mut z = t;
+        z ^= r[6] & mb;
+        z ^= r[13] & ma;
+        z ^= r[6] & r[13];
+        z
+    };
+    r[13] = r[15] ^ r[1];
+    r[1] = r[13].rotate_left(1);
+    r[13] = r[3] & POOL[1630];
+    r[15] = r[13] ^ r[1];
+    r[6] = r[9] ^ r[15];
+    r[15] = r[2] & POOL[1631];
+    r[9] = r[13] ^ POOL[1632];
+    r[13] = r[9] ^ POOL[1633];
+    r[9] = r[13] ^ POOL[1634];
+    r[13] = r[1] ^ POOL[1635];
+    r[1] = {
+        let (t, s) = (POOL[1636], POOL[1637]);
+        let (ma, mb) = (t ^ s ^ POOL[1638], t ^ s ^ POOL[1639]);
         let mut z = t;
         z ^= r[9] & mb;
-        z ^= r[4] & ma;
-        z ^= r[9] & r[4];
-        z
-    };
-    r[9] = r[14] ^ r[6];
-    r[4] = r[9].rotate_left(1);
-    r[9] = r[2] & POOL[546];
-    r[14] = r[9] ^ r[4];
-    r[6] = r[8] ^ r[14];
-    r[14] = r[10] & POOL[547];
-    r[8] = {
-        let (t, ma, mb) = (POOL[548], POOL[549], POOL[550]);
+        z ^= r[13] & ma;
+        z ^= r[9] & r[13];
+        z
+    };
+    r[13] = r[15] ^ r[1];
+    r[1] = r[13].rotate_left(1);
+    r[13] = r[3] & POOL[1640];
+    r[15] = r[13] ^ r[1];
+    r[9] = r[6] ^ r[15];
+    r[15] = r[2] & POOL[1641];
+    r[6] = r[13] ^ POOL[1642];
+    r[13] = r[6] ^ POOL[1643];
+    r[6] = r[13] ^ POOL[1644];
+    r[13] = r[1] ^ POOL[1645];
+    r[1] = {
+        let (t, s) = (POOL[1646], POOL[1647]);
+        let (ma, mb) = (t ^ s ^ POOL[1648], t ^ s ^ POOL[1649]);
+        let mut z = t;
+        z ^= r[6] & mb;
+        z ^= r[13] & ma;
+        z ^= r[6] & r[13];
+        z
+    };
+    r[13] = r[15] ^ r[1];
+    r[1] = r[13].rotate_left(1);
+    r[13] = r[3] & POOL[1650];
+    r[15] = r[13] ^ r[1];
+    r[6] = r[9] ^ r[15];
+    r[15] = r[2] & POOL[1651];
+    r[9] = r[13] ^ POOL[1652];
+    r[13] = r[9] ^ POOL[1653];
+    r[9] = r[13] ^ POOL[1654];
+    r[13] = r[1] ^ POOL[1655];
+    r[1] = {
+        let (t, s) = (POOL[1656], POOL[1657]);
+        let (ma, mb) = (t ^ s ^ POOL[1658], t ^ s ^ POOL[1659]);
         let mut z = t;
         z ^= r[9] & mb;
-        z ^= r[4] & ma;
-        z ^= r[9] & r[4];
-        z
-    };
-    r[9] = r[14] ^ r[8];
-    r[4] = r[9].rotate_left(1);
-    r[9] = r[2] & POOL[551];
-    r[14] = r[9] ^ r[4];
-    r[8] = r[6] ^ r[14];
-    r[14] = r[10] & POOL[552];
-    r[6] = {
-        let (t, ma, mb) = (POOL[553], POOL[554], POOL[555]);
+        z ^= r[13] & ma;
+        z ^= r[9] & r[13];
+        z
+    };
+    r[13] = r[15] ^ r[1];
+    r[1] = r[13].rotate_left(1);
+    r[13] = r[3] & POOL[1660];
+    r[15] = r[13] ^ r[1];
+    r[9] = r[6] ^ r[15];
+    r[15] = r[2] & POOL[1661];
+    r[6] = r[13] ^ POOL[1662];
+    r[13] = r[6] ^ POOL[1663];
+    r[6] = r[13] ^ POOL[1664];
+    r[13] = r[1] ^ POOL[1665];
+    r[1] = {
+        let (t, s) = (POOL[1666], POOL[1667]);
+        let (ma, mb) = (t ^ s ^ POOL[1668], t ^ s ^ POOL[1669]);
+        let mut z = t;
+        z ^= r[6] & mb;
+        z ^= r[13] & ma;
+        z ^= r[6] & r[13];
+        z
+    };
+    r[13] = r[15] ^ r[1];
+    r[1] = r[13].rotate_left(1);
+    r[13] = r[3] & POOL[1670];
+    r[15] = r[13] ^ r[1];
+    r[6] = r[9] ^ r[15];
+    r[15] = r[2] & POOL[1671];
+    r[9] = r[13] ^ POOL[1672];
+    r[13] = r[9] ^ POOL[1673];
+    r[9] = r[13] ^ POOL[1674];
+    r[13] = r[1] ^ POOL[1675];
+    r[1] = {
+        let (t, s) = (POOL[1676], POOL[1677]);
+        let (ma, mb) = (t ^ s ^ POOL[1678], t ^ s ^ POOL[1679]);
         let mut z = t;
         z ^= r[9] & mb;
-        z ^= r[4] & ma;
-        z ^= r[9] & r[4];
-        z
-    };
-    r[9] = r[14] ^ r[6];
-    r[4] = r[9].rotate_left(1);
-    r[9] = r[2] & POOL[556];
-    r[14] = r[9] ^ r[4];
-    r[6] = r[8] ^ r[14];
-    r[14] = r[10] & POOL[557];
-    r[8] = {
-        let (t, ma, mb) = (POOL[558], POOL[559], POOL[560]);
+        z ^= r[13] & ma;
+        z ^= r[9] & r[13];
+        z
+    };
+    r[13] = r[15] ^ r[1];
+    r[1] = r[13].rotate_left(1);
+    r[13] = r[3] & POOL[1680];
+    r[15] = r[13] ^ r[1];
+    r[9] = r[6] ^ r[15];
+    r[15] = r[2] & POOL[1681];
+    r[6] = r[13] ^ POOL[1682];
+    r[13] = r[6] ^ POOL[1683];
+    r[6] = r[13] ^ POOL[1684];
+    r[13] = r[1] ^ POOL[1685];
+    r[1] = {
+        let (t, s) = (POOL[1686], POOL[1687]);
+        let (ma, mb) = (t ^ s ^ POOL[1688], t ^ s ^ POOL[1689]);
+        let mut z = t;
+        z ^= r[6] & mb;
+        z ^= r[13] & ma;
+        z ^= r[6] & r[13];
+        z
+    };
+    r[13] = r[15] ^ r[1];
+    r[1] = r[13].rotate_left(1);
+    r[13] = r[3] & POOL[1690];
+    r[15] = r[13] ^ r[1];
+    r[6] = r[9] ^ r[15];
+    r[15] = r[2] & POOL[1691];
+    r[9] = r[13] ^ POOL[1692];
+    r[13] = r[9] ^ POOL[1693];
+    r[9] = r[13] ^ POOL[1694];
+    r[13] = r[1] ^ POOL[1695];
+    r[1] = {
+        let (t, s) = (POOL[1696], POOL[1697]);
+        let (ma, mb) = (t ^ s ^ POOL[1698], t ^ s ^ POOL[1699]);
         let mut z = t;
         z ^= r[9] & mb;
-        z ^= r[4] & ma;
-        z ^= r[9] & r[4];
-        z
-    };
-    r[9] = r[14] ^ r[8];
-    r[4] = r[9].rotate_left(1);
-    r[9] = r[2] & POOL[561];
-    r[14] = r[9] ^ r[4];
-    r[8] = r[6] ^ r[14];
-    r[14] = r[10] & POOL[562];
-    r[6] = {
-        let (t, ma, mb) = (POOL[563], POOL[564], POOL[565]);
+        z ^= r[13] & ma;
+        z ^= r[9] & r[13];
+        z
+    };
+    r[13] = r[15] ^ r[1];
+    r[1] = r[13].rotate_left(1);
+    r[13] = r[3] & POOL[1700];
+    r[15] = r[13] ^ r[1];
+    r[9] = r[6] ^ r[15];
+    r[15] = r[2] & POOL[1701];
+    r[6] = r[13] ^ POOL[1702];
+    r[13] = r[6] ^ POOL[1703];
+    r[6] = r[13] ^ POOL[1704];
+    r[13] = r[1] ^ POOL[1705];
+    r[1] = {
+        let (t, s) = (POOL[1706], POOL[1707]);
+        let (ma, mb) = (t ^ s ^ POOL[1708], t ^ s ^ POOL[1709]);
+        let mut z = t;
+        z ^= r[6] & mb;
+        z ^= r[13] & ma;
+        z ^= r[6] & r[13];
+        z
+    };
+    r[13] = r[15] ^ r[1];
+    r[1] = r[13].rotate_left(1);
+    r[13] = r[3] & POOL[1710];
+    r[15] = r[13] ^ r[1];
+    r[6] = r[9] ^ r[15];
+    r[15] = r[2] & POOL[1711];
+    r[9] = r[13] ^ POOL[1712];
+    r[13] = r[9] ^ POOL[1713];
+    r[9] = r[13] ^ POOL[1714];
+    r[13] = r[1] ^ POOL[1715];
+    r[1] = {
+        let (t, s) = (POOL[1716], POOL[1717]);
+        let (ma, mb) = (t ^ s ^ POOL[1718], t ^ s ^ POOL[1719]);
         let mut z = t;
         z ^= r[9] & mb;
-        z ^= r[4] & ma;
-        z ^= r[9] & r[4];
-        z
-    };
-    r[9] = r[14] ^ r[6];
-    r[4] = r[9].rotate_left(1);
-    r[9] = r[2] & POOL[566];
-    r[14] = r[9] ^ r[4];
-    r[6] = r[8] ^ r[14];
-    r[14] = r[10] & POOL[567];
-    r[8] = {
-        let (t, ma, mb) = (POOL[568], POOL[569], POOL[570]);
+        z ^= r[13] & ma;
+        z ^= r[9] & r[13];
+        z
+    };
+    r[13] = r[15] ^ r[1];
+    r[1] = r[13].rotate_left(1);
+    r[13] = r[3] & POOL[1720];
+    r[15] = r[13] ^ r[1];
+    r[9] = r[6] ^ r[15];
+    r[15] = r[2] & POOL[1721];
+    r[6] = r[13] ^ POOL[1722];
+    r[13] = r[6] ^ POOL[1723];
+    r[6] = r[13] ^ POOL[1724];
+    r[13] = r[1] ^ POOL[1725];
+    r[1] = {
+        let (t, s) = (POOL[1726], POOL[1727]);
+        let (ma, mb) = (t ^ s ^ POOL[1728], t ^ s ^ POOL[1729]);
+        let mut z = t;
+        z ^= r[6] & mb;
+        z ^= r[13] & ma;
+        z ^= r[6] & r[13];
+        z
+    };
+    r[13] = r[15] ^ r[1];
+    r[1] = r[13].rotate_left(1);
+    r[13] = r[3] & POOL[1730];
+    r[15] = r[13] ^ r[1];
+    r[6] = r[9] ^ r[15];
+    r[15] = r[2] & POOL[1731];
+    r[9] = r[13] ^ POOL[1732];
+    r[13] = r[9] ^ POOL[1733];
+    r[9] = r[13] ^ POOL[1734];
+    r[13] = r[1] ^ POOL[1735];
+    r[1] = {
+        let (t, s) = (POOL[1736], POOL[1737]);
+        let (ma, mb) = (t ^ s ^ POOL[1738], t ^ s ^ POOL[1739]);
         let mut z = t;
         z ^= r[9] & mb;
-        z ^= r[4] & ma;
-        z ^= r[9] & r[4];
-        z
-    };
-    r[9] = r[14] ^ r[8];
-    r[4] = r[9].rotate_left(1);
-    r[9] = r[2] & POOL[571];
-    r[14] = r[9] ^ r[4];
-    r[8] = r[6] ^ r[14];
-    r[14] = r[10] & POOL[572];
-    r[6] = {
-        let (t, ma, mb) = (POOL[573], POOL[574], POOL[575]);
+        z ^= r[13] & ma;
+        z ^= r[9] & r[13];
+        z
+    };
+    r[13] = r[15] ^ r[1];
+    r[1] = r[13].rotate_left(1);
+    r[13] = r[3] & POOL[1740];
+    r[15] = r[13] ^ r[1];
+    r[9] = r[6] ^ r[15];
+    r[15] = r[2] & POOL[1741];
+    r[6] = r[13] ^ POOL[1742];
+    r[13] = r[6] ^ POOL[1743];
+    r[6] = r[13] ^ POOL[1744];
+    r[13] = r[1] ^ POOL[1745];
+    r[1] = {
+        let (t, s) = (POOL[1746], POOL[1747]);
+        let (ma, mb) = (t ^ s ^ POOL[1748], t ^ s ^ POOL[1749]);
+        let mut z = t;
+        z ^= r[6] & mb;
+        z ^= r[13] & ma;
+        z ^= r[6] & r[13];
+        z
+    };
+    r[13] = r[15] ^ r[1];
+    r[1] = r[13].rotate_left(1);
+    r[13] = r[3] & POOL[1750];
+    r[15] = r[13] ^ r[1];
+    r[6] = r[9] ^ r[15];
+    r[15] = r[2] & POOL[1751];
+    r[9] = r[13] ^ POOL[1752];
+    r[13] = r[9] ^ POOL[1753];
+    r[9] = r[13] ^ POOL[1754];
+    r[13] = r[1] ^ POOL[1755];
+    r[1] = {
+        let (t, s) = (POOL[1756], POOL[1757]);
+        let (ma, mb) = (t ^ s ^ POOL[1758], t ^ s ^ POOL[1759]);
         let mut z = t;
         z ^= r[9] & mb;
-        z ^= r[4] & ma;
-        z ^= r[9] & r[4];
-        z
-    };
-    r[9] = r[14] ^ r[6];
-    r[4] = r[9].rotate_left(1);
-    r[9] = r[2] & POOL[576];
-    r[14] = r[9] ^ r[4];
-    r[6] = r[8] ^ r[14];
-    r[14] = r[10] & POOL[577];
-    r[8] = {
-        let (t, ma, mb) = (POOL[578], POOL[579], POOL[580]);
+        z ^= r[13] & ma;
+        z ^= r[9] & r[13];
+        z
+    };
+    r[13] = r[15] ^ r[1];
+    r[1] = r[13].rotate_left(1);
+    r[13] = r[3] & POOL[1760];
+    r[15] = r[13] ^ r[1];
+    r[9] = r[6] ^ r[15];
+    r[15] = r[2] & POOL[1761];
+    r[6] = r[13] ^ POOL[1762];
+    r[13] = r[6] ^ POOL[1763];
+    r[6] = r[13] ^ POOL[1764];
+    r[13] = r[1] ^ POOL[1765];
+    r[1] = {
+        let (t, s) = (POOL[1766], POOL[1767]);
+        let (ma, mb) = (t ^ s ^ POOL[1768], t ^ s ^ POOL[1769]);
+        let mut z = t;
+        z ^= r[6] & mb;
+        z ^= r[13] & ma;
+        z ^= r[6] & r[13];
+        z
+    };
+    r[13] = r[15] ^ r[1];
+    r[1] = r[13].rotate_left(1);
+    r[13] = r[3] & POOL[1770];
+    r[15] = r[13] ^ r[1];
+    r[6] = r[9] ^ r[15];
+    r[15] = r[2] & POOL[1771];
+    r[9] = r[13] ^ POOL[1772];
+    r[13] = r[9] ^ POOL[1773];
+    r[9] = r[13] ^ POOL[1774];
+    r[13] = r[1] ^ POOL[1775];
+    r[1] = {
+        let (t, s) = (POOL[1776], POOL[1777]);
+        let (ma, mb) = (t ^ s ^ POOL[1778], t ^ s ^ POOL[1779]);
         let mut z = t;
         z ^= r[9] & mb;
-        z ^= r[4] & ma;
-        z ^= r[9] & r[4];
-        z
-    };
-    r[9] = r[14] ^ r[8];
-    r[4] = r[9].rotate_left(1);
-    r[9] = r[2] & POOL[581];
-    r[14] = r[9] ^ r[4];
-    r[8] = r[6] ^ r[14];
-    r[14] = r[10] & POOL[582];
-    r[6] = {
-        let (t, ma, mb) = (POOL[583], POOL[584], POOL[585]);
+        z ^= r[13] & ma;
+        z ^= r[9] & r[13];
+        z
+    };
+    r[13] = r[15] ^ r[1];
+    r[1] = r[13].rotate_left(1);
+    r[13] = r[3] & POOL[1780];
+    r[15] = r[13] ^ r[1];
+    r[9] = r[6] ^ r[15];
+    r[15] = r[2] & POOL[1781];
+    r[6] = r[13] ^ POOL[1782];
+    r[13] = r[6] ^ POOL[1783];
+    r[6] = r[13] ^ POOL[1784];
+    r[13] = r[1] ^ POOL[1785];
+    r[1] = {
+        let (t, s) = (POOL[1786], POOL[1787]);
+        let (ma, mb) = (t ^ s ^ POOL[1788], t ^ s ^ POOL[1789]);
+        let mut z = t;
+        z ^= r[6] & mb;
+        z ^= r[13] & ma;
+        z ^= r[6] & r[13];
+        z
+    };
+    r[13] = r[15] ^ r[1];
+    r[1] = r[13].rotate_left(1);
+    r[13] = r[3] & POOL[1790];
+    r[15] = r[13] ^ r[1];
+    r[6] = r[9] ^ r[15];
+    r[15] = r[2] & POOL[1791];
+    r[9] = r[13] ^ POOL[1792];
+    r[13] = r[9] ^ POOL[1793];
+    r[9] = r[13] ^ POOL[1794];
+    r[13] = r[1] ^ POOL[1795];
+    r[1] = {
+        let (t, s) = (POOL[1796], POOL[1797]);
+        let (ma, mb) = (t ^ s ^ POOL[1798], t ^ s ^ POOL[1799]);
         let mut z = t;
         z ^= r[9] & mb;
-        z ^= r[4] & ma;
-        z ^= r[9] & r[4];
-        z
-    };
-    r[9] = r[14] ^ r[6];
-    r[4] = r[9].rotate_left(1);
-    r[9] = r[2] & POOL[586];
-    r[14] = r[9] ^ r[4];
-    r[6] = r[8] ^ r[14];
-    r[14] = r[10] & POOL[587];
-    r[8] = {
-        let (t, ma, mb) = (POOL[588], POOL[589], POOL[590]);
+        z ^= r[13] & ma;
+        z ^= r[9] & r[13];
+        z
+    };
+    r[13] = r[15] ^ r[1];
+    r[1] = r[13].rotate_left(1);
+    r[13] = r[3] & POOL[1800];
+    r[15] = r[13] ^ r[1];
+    r[9] = r[6] ^ r[15];
+    r[15] = r[2] & POOL[1801];
+    r[6] = r[13] ^ POOL[1802];
+    r[13] = r[6] ^ POOL[1803];
+    r[6] = r[13] ^ POOL[1804];
+    r[13] = r[1] ^ POOL[1805];
+    r[1] = {
+        let (t, s) = (POOL[1806], POOL[1807]);
+        let (ma, mb) = (t ^ s ^ POOL[1808], t ^ s ^ POOL[1809]);
+        let mut z = t;
+        z ^= r[6] & mb;
+        z ^= r[13] & ma;
+        z ^= r[6] & r[13];
+        z
+    };
+    r[13] = r[15] ^ r[1];
+    r[1] = r[13].rotate_left(1);
+    r[13] = r[3] & POOL[1810];
+    r[15] = r[13] ^ r[1];
+    r[6] = r[9] ^ r[15];
+    r[15] = r[2] & POOL[1811];
+    r[9] = r[13] ^ POOL[1812];
+    r[13] = r[9] ^ POOL[1813];
+    r[9] = r[13] ^ POOL[1814];
+    r[13] = r[1] ^ POOL[1815];
+    r[1] = {
+        let (t, s) = (POOL[1816], POOL[1817]);
+        let (ma, mb) = (t ^ s ^ POOL[1818], t ^ s ^ POOL[1819]);
         let mut z = t;
         z ^= r[9] & mb;
-        z ^= r[4] & ma;
-        z ^= r[9] & r[4];
-        z
-    };
-    r[9] = r[14] ^ r[8];
-    r[4] = r[9].rotate_left(1);
-    r[9] = r[2] & POOL[591];
-    r[14] = r[9] ^ r[4];
-    r[8] = r[6] ^ r[14];
-    r[14] = r[10] & POOL[592];
-    r[6] = {
-        let (t, ma, mb) = (POOL[593], POOL[594], POOL[595]);
+        z ^= r[13] & ma;
+        z ^= r[9] & r[13];
+        z
+    };
+    r[13] = r[15] ^ r[1];
+    r[1] = r[13].rotate_left(1);
+    r[13] = r[3] & POOL[1820];
+    r[15] = r[13] ^ r[1];
+    r[9] = r[6] ^ r[15];
+    r[15] = r[2] & POOL[1821];
+    r[6] = r[13] ^ POOL[1822];
+    r[13] = r[6] ^ POOL[1823];
+    r[6] = r[13] ^ POOL[1824];
+    r[13] = r[1] ^ POOL[1825];
+    r[1] = {
+        let (t, s) = (POOL[1826], POOL[1827]);
+        let (ma, mb) = (t ^ s ^ POOL[1828], t ^ s ^ POOL[1829]);
+        let mut z = t;
+        z ^= r[6] & mb;
+        z ^= r[13] & ma;
+        z ^= r[6] & r[13];
+        z
+    };
+    r[13] = r[15] ^ r[1];
+    r[1] = r[13].rotate_left(1);
+    r[13] = r[3] & POOL[1830];
+    r[15] = r[13] ^ r[1];
+    r[6] = r[9] ^ r[15];
+    r[15] = r[2] & POOL[1831];
+    r[9] = r[13] ^ POOL[1832];
+    r[13] = r[9] ^ POOL[1833];
+    r[9] = r[13] ^ POOL[1834];
+    r[13] = r[1] ^ POOL[1835];
+    r[1] = {
+        let (t, s) = (POOL[1836], POOL[1837]);
+        let (ma, mb) = (t ^ s ^ POOL[1838], t ^ s ^ POOL[1839]);
         let mut z = t;
         z ^= r[9] & mb;
-        z ^= r[4] & ma;
-        z ^= r[9] & r[4];
-        z
-    };
-    r[9] = r[14] ^ r[6];
-    r[4] = r[9].rotate_left(1);
-    r[9] = r[2] & POOL[596];
-    r[14] = r[9] ^ r[4];
-    r[6] = r[8] ^ r[14];
-    r[14] = r[10] & POOL[597];
-    r[8] = {
-        let (t, ma, mb) = (POOL[598], POOL[599], POOL[600]);
+        z ^= r[13] & ma;
+        z ^= r[9] & r[13];
+        z
+    };
+    r[13] = r[15] ^ r[1];
+    r[1] = r[13].rotate_left(1);
+    r[13] = r[3] & POOL[1840];
+    r[15] = r[13] ^ r[1];
+    r[9] = r[6] ^ r[15];
+    r[15] = r[2] & POOL[1841];
+    r[6] = r[13] ^ POOL[1842];
+    r[13] = r[6] ^ POOL[1843];
+    r[6] = r[13] ^ POOL[1844];
+    r[13] = r[1] ^ POOL[1845];
+    r[1] = {
+        let (t, s) = (POOL[1846], POOL[1847]);
+        let (ma, mb) = (t ^ s ^ POOL[1848], t ^ s ^ POOL[1849]);
+        let mut z = t;
+        z ^= r[6] & mb;
+        z ^= r[13] & ma;
+        z ^= r[6] & r[13];
+        z
+    };
+    r[13] = r[15] ^ r[1];
+    r[1] = r[13].rotate_left(1);
+    r[13] = r[3] & POOL[1850];
+    r[15] = r[13] ^ r[1];
+    r[6] = r[9] ^ r[15];
+    r[15] = r[2] & POOL[1851];
+    r[9] = r[13] ^ POOL[1852];
+    r[13] = r[9] ^ POOL[1853];
+    r[9] = r[13] ^ POOL[1854];
+    r[13] = r[1] ^ POOL[1855];
+    r[1] = {
+        let (t, s) = (POOL[1856], POOL[1857]);
+        let (ma, mb) = (t ^ s ^ POOL[1858], t ^ s ^ POOL[1859]);
         let mut z = t;
         z ^= r[9] & mb;
-        z ^= r[4] & ma;
-        z ^= r[9] & r[4];
-        z
-    };
-    r[9] = r[14] ^ r[8];
-    r[4] = r[9].rotate_left(1);
-    r[9] = r[2] & POOL[601];
-    r[14] = r[9] ^ r[4];
-    r[8] = r[6] ^ r[14];
-    r[14] = r[10] & POOL[602];
-    r[6] = {
-        let (t, ma, mb) = (POOL[603], POOL[604], POOL[605]);
+        z ^= r[13] & ma;
+        z ^= r[9] & r[13];
+        z
+    };
+    r[13] = r[15] ^ r[1];
+    r[1] = r[13].rotate_left(1);
+    r[13] = r[3] & POOL[1860];
+    r[15] = r[13] ^ r[1];
+    r[9] = r[6] ^ r[15];
+    r[15] = r[2] & POOL[1861];
+    r[6] = r[13] ^ POOL[1862];
+    r[13] = r[6] ^ POOL[1863];
+    r[6] = r[13] ^ POOL[1864];
+    r[13] = r[1] ^ POOL[1865];
+    r[1] = {
+        let (t, s) = (POOL[1866], POOL[1867]);
+        let (ma, mb) = (t ^ s ^ POOL[1868], t ^ s ^ POOL[1869]);
+        let mut z = t;
+        z ^= r[6] & mb;
+        z ^= r[13] & ma;
+        z ^= r[6] & r[13];
+        z
+    };
+    r[13] = r[15] ^ r[1];
+    r[1] = r[13].rotate_left(1);
+    r[13] = r[3] & POOL[1870];
+    r[15] = r[13] ^ r[1];
+    r[6] = r[9] ^ r[15];
+    r[15] = r[2] & POOL[1871];
+    r[9] = r[13] ^ POOL[1872];
+    r[13] = r[9] ^ POOL[1873];
+    r[9] = r[13] ^ POOL[1874];
+    r[13] = r[1] ^ POOL[1875];
+    r[1] = {
+        let (t, s) = (POOL[1876], POOL[1877]);
+        let (ma, mb) = (t ^ s ^ POOL[1878], t ^ s ^ POOL[1879]);
         let mut z = t;
         z ^= r[9] & mb;
-        z ^= r[4] & ma;
-        z ^= r[9] & r[4];
+        z ^= r[13] & ma;
+        z ^= r[9] & r[13];
+        z
+    };
+    r[13] = r[15] ^ r[1];
+    r[1] = r[13].rotate_left(1);
+    r[13] = r[3] & POOL[1880];
+    r[15] = r[13] ^ r[1];
+    r[9] = r[6] ^ r[15];
+    r[15] = r[2] & POOL[1881];
+    r[2] = r[13] ^ POOL[1882];
+    r[13] = r[2] ^ POOL[1883];
+    r[2] = r[13] ^ POOL[1884];
+    r[13] = r[1] ^ POOL[1885];
+    r[1] = {
+        let (t, s) = (POOL[1886], POOL[1887]);
+        let (ma, mb) = (t ^ s ^ POOL[1888], t ^ s ^ POOL[1889]);
+        let mut z = t;
+        z ^= r[2] & mb;
+        z ^= r[13] & ma;
+        z ^= r[2] & r[13];
         z
     };
-    r[9] = r[14] ^ r[6];
-    r[4] = r[9].rotate_left(1);
-    r[9] = r[2] & POOL[606];
-    r[14] = r[9] ^ r[4];
-    r[6] = r[8] ^ r[14];
-    r[14] = r[10] & POOL[607];
+    r[13] = r[15] ^ r[1];
+    r[1] = r[13].rotate_left(1);
+    r[13] = r[3] & POOL[1890];
+    r[3] = r[13] ^ r[1];
+    r[1] = r[9] ^ r[3];
+    r[3] = r[1] ^ r[14];
+    r[1] = r[3] ^ POOL[1891];
+    r[3] = r[8] ^ POOL[1892];
     r[8] = {
-        let (t, ma, mb) = (POOL[608], POOL[609], POOL[610]);
+        let (t, s) = (POOL[1893], POOL[1894]);
+        let (ma, mb) = (t ^ s ^ POOL[1895], t ^ s ^ POOL[1896]);
         let mut z = t;
-        z ^= r[9] & mb;
-        z ^= r[4] & ma;
-        z ^= r[9] & r[4];
+        z ^= r[1] & mb;
+        z ^= r[3] & ma;
+        z ^= r[1] & r[3];
         z
     };
-    r[9] = r[14] ^ r[8];
-    r[4] = r[9].rotate_left(1);
-    r[9] = r[2] & POOL[611];
-    r[14] = r[9] ^ r[4];
-    r[8] = r[6] ^ r[14];
-    r[14] = r[10] & POOL[612];
-    r[6] = {
-        let (t, ma, mb) = (POOL[613], POOL[614], POOL[615]);
+    r[1] = r[11] ^ POOL[1897];
+    r[3] = r[1] ^ r[10];
+    r[1] = r[3] ^ POOL[1898];
+    r[3] = r[1].rotate_left(8);
+    r[1] = r[12] ^ POOL[1899];
+    r[14] = r[3] ^ POOL[1900];
+    r[10] = {
+        let (t, s) = (POOL[1901], POOL[1902]);
+        let (ma, mb) = (t ^ s ^ POOL[1903], t ^ s ^ POOL[1904]);
         let mut z = t;
-        z ^= r[9] & mb;
-        z ^= r[4] & ma;
-        z ^= r[9] & r[4];
-        z
-    };
-    r[9] = r[14] ^ r[6];
-    r[4] = r[9].rotate_left(1);
-    r[9] = r[2] & POOL[616];
-    r[14] = r[9] ^ r[4];
-    r[6] = r[8] ^ r[14];
-    r[14] = r[10] & POOL[617];
-    r[8] = {
-        let (t, ma, mb) = (POOL[618], POOL[619], POOL[620]);
-        let mut z = t;
-        z ^= r[9] & mb;
-        z ^= r[4] & ma;
-        z ^= r[9] & r[4];
-        z
-    };
-    r[9] = r[14] ^ r[8];
-    r[4] = r[9].rotate_left(1);
-    r[9] = r[2] & POOL[621];
-    r[14] = r[9] ^ r[4];
-    r[8] = r[6] ^ r[14];
-    r[14] = r[10] & POOL[622];
-    r[6] = {
-        let (t, ma, mb) = (POOL[623], POOL[624], POOL[625]);
-        let mut z = t;
-        z ^= r[9] & mb;
-        z ^= r[4] & ma;
-        z ^= r[9] & r[4];
-        z
-    };
-    r[9] = r[14] ^ r[6];
-    r[4] = r[9].rotate_left(1);
-    r[9] = r[2] & POOL[626];
-    r[14] = r[9] ^ r[4];
-    r[6] = r[8] ^ r[14];
-    r[14] = r[10] & POOL[627];
-    r[8] = {
-        let (t, ma, mb) = (POOL[628], POOL[629], POOL[630]);
-        let mut z = t;
-        z ^= r[9] & mb;
-        z ^= r[4] & ma;
-        z ^= r[9] & r[4];
-        z
-    };
-    r[9] = r[14] ^ r[8];
-    r[4] = r[9].rotate_left(1);
-    r[9] = r[2] & POOL[631];
-    r[14] = r[9] ^ r[4];
-    r[8] = r[6] ^ r[14];
-    r[14] = r[10] & POOL[632];
-    r[10] = {
-        let (t, ma, mb) = (POOL[633], POOL[634], POOL[635]);
-        let mut z = t;
-        z ^= r[9] & mb;
-        z ^= r[4] & ma;
-        z ^= r[9] & r[4];
-        z
-    };
-    r[9] = r[14] ^ r[10];
-    r[10] = r[9].rotate_left(1);
-    r[9] = r[2] & POOL[636];
-    r[2] = r[9] ^ r[10];
-    r[9] = r[8] ^ r[2];
-    r[10] = r[9] ^ POOL[637];
-    r[9] = r[10] ^ POOL[638];
-    r[10] = r[12] ^ POOL[639];
-    r[12] = r[9] ^ r[7];
-    r[7] = r[12].rotate_left(16);
-    r[2] = {
-        let (t, ma, mb) = (POOL[640], POOL[641], POOL[642]);
-        let mut z = t;
-        z ^= r[3] & mb;
-        z ^= r[7] & ma;
-        z ^= r[3] & r[7];
-        z
-    };
-    r[4] = r[3] ^ r[7];
-    r[3] = r[4] & POOL[643];
-    r[14] = r[2] & POOL[644];
-    r[8] = r[14].rotate_left(1);
-    r[14] = r[4] & POOL[645];
-    r[6] = r[14] ^ r[8];
-    r[13] = r[3] ^ r[6];
-    r[3] = r[2] & POOL[646];
-    r[6] = {
-        let (t, ma, mb) = (POOL[647], POOL[648], POOL[649]);
-        let mut z = t;
-        z ^= r[14] & mb;
-        z ^= r[8] & ma;
-        z ^= r[14] & r[8];
-        z
-    };
-    r[14] = r[3] ^ r[6];
-    r[3] = r[14].rotate_left(1);
-    r[14] = r[4] & POOL[650];
-    r[8] = r[14] ^ r[3];
-    r[6] = r[13] ^ r[8];
-    r[8] = r[2] & POOL[651];
-    r[13] = {
-        let (t, ma, mb) = (POOL[652], POOL[653], POOL[654]);
-        let mut z = t;
-        z ^= r[14] & mb;
-        z ^= r[3] & ma;
-        z ^= r[14] & r[3];
-        z
-    };
-    r[3] = r[8] ^ r[13];
-    r[14] = r[3].rotate_left(1);
-    r[3] = r[4] & POOL[655];
-    r[8] = r[3] ^ r[14];
-    r[13] = r[6] ^ r[8];
-    r[8] = r[2] & POOL[656];
-    r[6] = {
-        let (t, ma, mb) = (POOL[657], POOL[658], POOL[659]);
-        let mut z = t;
-        z ^= r[3] & mb;
-        z ^= r[14] & ma;
-        z ^= r[3] & r[14];
-        z
-    };
-    r[3] = r[8] ^ r[6];
-    r[14] = r[3].rotate_left(1);
-    r[3] = r[4] & POOL[660];
-    r[8] = r[3] ^ r[14];
-    r[6] = r[13] ^ r[8];
-    r[8] = r[2] & POOL[661];
-    r[13] = {
-        let (t, ma, mb) = (POOL[662], POOL[663], POOL[664]);
-        let mut z = t;
-        z ^= r[3] & mb;
-        z ^= r[14] & ma;
-        z ^= r[3] & r[14];
-        z
-    };
-    r[3] = r[8] ^ r[13];
-    r[14] = r[3].rotate_left(1);
-    r[3] = r[4] & POOL[665];
-    r[8] = r[3] ^ r[14];
-    r[13] = r[6] ^ r[8];
-    r[8] = r[2] & POOL[666];
-    r[6] = {
-        let (t, ma, mb) = (POOL[667], POOL[668], POOL[669]);
-        let mut z = t;
-        z ^= r[3] & mb;
-        z ^= r[14] & ma;
-        z ^= r[3] & r[14];
-        z
-    };
-    r[3] = r[8] ^ r[6];
-    r[14] = r[3].rotate_left(1);
-    r[3] = r[4] & POOL[670];
-    r[8] = r[3] ^ r[14];
-    r[6] = r[13] ^ r[8];
-    r[8] = r[2] & POOL[671];
-    r[13] = {
-        let (t, ma, mb) = (POOL[672], POOL[673], POOL[674]);
-        let mut z = t;
-        z ^= r[3] & mb;
-        z ^= r[14] & ma;
-        z ^= r[3] & r[14];
-        z
-    };
-    r[3] = r[8] ^ r[13];
-    r[14] = r[3].rotate_left(1);
-    r[3] = r[4] & POOL[675];
-    r[8] = r[3] ^ r[14];
-    r[13] = r[6] ^ r[8];
-    r[8] = r[2] & POOL[676];
-    r[6] = {
-        let (t, ma, mb) = (POOL[677], POOL[678], POOL[679]);
-        let mut z = t;
-        z ^= r[3] & mb;
-        z ^= r[14] & ma;
-        z ^= r[3] & r[14];
-        z
-    };
-    r[3] = r[8] ^ r[6];
-    r[14] = r[3].rotate_left(1);
-    r[3] = r[4] & POOL[680];
-    r[8] = r[3] ^ r[14];
-    r[6] = r[13] ^ r[8];
-    r[8] = r[2] & POOL[681];
-    r[13] = {
-        let (t, ma, mb) = (POOL[682], POOL[683], POOL[684]);
-        let mut z = t;
-        z ^= r[3] & mb;
-        z ^= r[14] & ma;
-        z ^= r[3] & r[14];
-        z
-    };
-    r[3] = r[8] ^ r[13];
-    r[14] = r[3].rotate_left(1);
-    r[3] = r[4] & POOL[685];
-    r[8] = r[3] ^ r[14];
-    r[13] = r[6] ^ r[8];
-    r[8] = r[2] & POOL[686];
-    r[6] = {
-        let (t, ma, mb) = (POOL[687], POOL[688], POOL[689]);
-        let mut z = t;
-        z ^= r[3] & mb;
-        z ^= r[14] & ma;
-        z ^= r[3] & r[14];
-        z
-    };
-    r[3] = r[8] ^ r[6];
-    r[14] = r[3].rotate_left(1);
-    r[3] = r[4] & POOL[690];
-    r[8] = r[3] ^ r[14];
-    r[6] = r[13] ^ r[8];
-    r[8] = r[2] & POOL[691];
-    r[13] = {
-        let (t, ma, mb) = (POOL[692], POOL[693], POOL[694]);
-        let mut z = t;
-        z ^= r[3] & mb;
-        z ^= r[14] & ma;
-        z ^= r[3] & r[14];
-        z
-    };
-    r[3] = r[8] ^ r[13];
-    r[14] = r[3].rotate_left(1);
-    r[3] = r[4] & POOL[695];
-    r[8] = r[3] ^ r[14];
-    r[13] = r[6] ^ r[8];
-    r[8] = r[2] & POOL[696];
-    r[6] = {
-        let (t, ma, mb) = (POOL[697], POOL[698], POOL[699]);
-        let mut z = t;
-        z ^= r[3] & mb;
-        z ^= r[14] & ma;
-        z ^= r[3] & r[14];
-        z
-    };
-    r[3] = r[8] ^ r[6];
-    r[14] = r[3].rotate_left(1);
-    r[3] = r[4] & POOL[700];
-    r[8] = r[3] ^ r[14];
-    r[6] = r[13] ^ r[8];
-    r[8] = r[2] & POOL[701];
-    r[13] = {
-        let (t, ma, mb) = (POOL[702], POOL[703], POOL[704]);
-        let mut z = t;
-        z ^= r[3] & mb;
-        z ^= r[14] & ma;
-        z ^= r[3] & r[14];
-        z
-    };
-    r[3] = r[8] ^ r[13];
-    r[14] = r[3].rotate_left(1);
-    r[3] = r[4] & POOL[705];
-    r[8] = r[3] ^ r[14];
-    r[13] = r[6] ^ r[8];
-    r[8] = r[2] & POOL[706];
-    r[6] = {
-        let (t, ma, mb) = (POOL[707], POOL[708], POOL[709]);
-        let mut z = t;
-        z ^= r[3] & mb;
-        z ^= r[14] & ma;
-        z ^= r[3] & r[14];
-        z
-    };
-    r[3] = r[8] ^ r[6];
-    r[14] = r[3].rotate_left(1);
-    r[3] = r[4] & POOL[710];
-    r[8] = r[3] ^ r[14];
-    r[6] = r[13] ^ r[8];
-    r[8] = r[2] & POOL[711];
-    r[13] = {
-        let (t, ma, mb) = (POOL[712], POOL[713], POOL[714]);
-        let mut z = t;
-        z ^= r[3] & mb;
-        z ^= r[14] & ma;
-        z ^= r[3] & r[14];
-        z
-    };
-    r[3] = r[8] ^ r[13];
-    r[14] = r[3].rotate_left(1);
-    r[3] = r[4] & POOL[715];
-    r[8] = r[3] ^ r[14];
-    r[13] = r[6] ^ r[8];
-    r[8] = r[2] & POOL[716];
-    r[6] = {
-        let (t, ma, mb) = (POOL[717], POOL[718], POOL[719]);
-        let mut z = t;
-        z ^= r[3] & mb;
-        z ^= r[14] & ma;
-        z ^= r[3] & r[14];
-        z
-    };
-    r[3] = r[8] ^ r[6];
-    r[14] = r[3].rotate_left(1);
-    r[3] = r[4] & POOL[720];
-    r[8] = r[3] ^ r[14];
-    r[6] = r[13] ^ r[8];
-    r[8] = r[2] & POOL[721];
-    r[13] = {
-        let (t, ma, mb) = (POOL[722], POOL[723], POOL[724]);
-        let mut z = t;
-        z ^= r[3] & mb;
-        z ^= r[14] & ma;
-        z ^= r[3] & r[14];
-        z
-    };
-    r[3] = r[8] ^ r[13];
-    r[14] = r[3].rotate_left(1);
-    r[3] = r[4] & POOL[725];
-    r[8] = r[3] ^ r[14];
-    r[13] = r[6] ^ r[8];
-    r[8] = r[2] & POOL[726];
-    r[6] = {
-        let (t, ma, mb) = (POOL[727], POOL[728], POOL[729]);
-        let mut z = t;
-        z ^= r[3] & mb;
-        z ^= r[14] & ma;
-        z ^= r[3] & r[14];
-        z
-    };
-    r[3] = r[8] ^ r[6];
-    r[14] = r[3].rotate_left(1);
-    r[3] = r[4] & POOL[730];
-    r[8] = r[3] ^ r[14];
-    r[6] = r[13] ^ r[8];
-    r[8] = r[2] & POOL[731];
-    r[13] = {
-        let (t, ma, mb) = (POOL[732], POOL[733], POOL[734]);
-        let mut z = t;
-        z ^= r[3] & mb;
-        z ^= r[14] & ma;
-        z ^= r[3] & r[14];
-        z
-    };
-    r[3] = r[8] ^ r[13];
-    r[14] = r[3].rotate_left(1);
-    r[3] = r[4] & POOL[735];
-    r[8] = r[3] ^ r[14];
-    r[13] = r[6] ^ r[8];
-    r[8] = r[2] & POOL[736];
-    r[6] = {
-        let (t, ma, mb) = (POOL[737], POOL[738], POOL[739]);
-        let mut z = t;
-        z ^= r[3] & mb;
-        z ^= r[14] & ma;
-        z ^= r[3] & r[14];
-        z
-    };
-    r[3] = r[8] ^ r[6];
-    r[14] = r[3].rotate_left(1);
-    r[3] = r[4] & POOL[740];
-    r[8] = r[3] ^ r[14];
-    r[6] = r[13] ^ r[8];
-    r[8] = r[2] & POOL[741];
-    r[13] = {
-        let (t, ma, mb) = (POOL[742], POOL[743], POOL[744]);
-        let mut z = t;
-        z ^= r[3] & mb;
-        z ^= r[14] & ma;
-        z ^= r[3] & r[14];
-        z
-    };
-    r[3] = r[8] ^ r[13];
-    r[14] = r[3].rotate_left(1);
-    r[3] = r[4] & POOL[745];
-    r[8] = r[3] ^ r[14];
-    r[13] = r[6] ^ r[8];
-    r[8] = r[2] & POOL[746];
-    r[6] = {
-        let (t, ma, mb) = (POOL[747], POOL[748], POOL[749]);
-        let mut z = t;
-        z ^= r[3] & mb;
-        z ^= r[14] & ma;
-        z ^= r[3] & r[14];
-        z
-    };
-    r[3] = r[8] ^ r[6];
-    r[14] = r[3].rotate_left(1);
-    r[3] = r[4] & POOL[750];
-    r[8] = r[3] ^ r[14];
-    r[6] = r[13] ^ r[8];
-    r[8] = r[2] & POOL[751];
-    r[13] = {
-        let (t, ma, mb) = (POOL[752], POOL[753], POOL[754]);
-        let mut z = t;
-        z ^= r[3] & mb;
-        z ^= r[14] & ma;
-        z ^= r[3] & r[14];
-        z
-    };
-    r[3] = r[8] ^ r[13];
-    r[14] = r[3].rotate_left(1);
-    r[3] = r[4] & POOL[755];
-    r[8] = r[3] ^ r[14];
-    r[13] = r[6] ^ r[8];
-    r[8] = r[2] & POOL[756];
-    r[6] = {
-        let (t, ma, mb) = (POOL[757], POOL[758], POOL[759]);
-        let mut z = t;
-        z ^= r[3] & mb;
-        z ^= r[14] & ma;
-        z ^= r[3] & r[14];
-        z
-    };
-    r[3] = r[8] ^ r[6];
-    r[14] = r[3].rotate_left(1);
-    r[3] = r[4] & POOL[760];
-    r[8] = r[3] ^ r[14];
-    r[6] = r[13] ^ r[8];
-    r[8] = r[2] & POOL[761];
-    r[13] = {
-        let (t, ma, mb) = (POOL[762], POOL[763], POOL[764]);
-        let mut z = t;
-        z ^= r[3] & mb;
-        z ^= r[14] & ma;
-        z ^= r[3] & r[14];
-        z
-    };
-    r[3] = r[8] ^ r[13];
-    r[14] = r[3].rotate_left(1);
-    r[3] = r[4] & POOL[765];
-    r[8] = r[3] ^ r[14];
-    r[13] = r[6] ^ r[8];
-    r[8] = r[2] & POOL[766];
-    r[6] = {
-        let (t, ma, mb) = (POOL[767], POOL[768], POOL[769]);
-        let mut z = t;
-        z ^= r[3] & mb;
-        z ^= r[14] & ma;
-        z ^= r[3] & r[14];
-        z
-    };
-    r[3] = r[8] ^ r[6];
-    r[14] = r[3].rotate_left(1);
-    r[3] = r[4] & POOL[770];
-    r[8] = r[3] ^ r[14];
-    r[6] = r[13] ^ r[8];
-    r[8] = r[2] & POOL[771];
-    r[13] = {
-        let (t, ma, mb) = (POOL[772], POOL[773], POOL[774]);
-        let mut z = t;
-        z ^= r[3] & mb;
-        z ^= r[14] & ma;
-        z ^= r[3] & r[14];
-        z
-    };
-    r[3] = r[8] ^ r[13];
-    r[14] = r[3].rotate_left(1);
-    r[3] = r[4] & POOL[775];
-    r[8] = r[3] ^ r[14];
-    r[13] = r[6] ^ r[8];
-    r[8] = r[2] & POOL[776];
-    r[6] = {
-        let (t, ma, mb) = (POOL[777], POOL[778], POOL[779]);
-        let mut z = t;
-        z ^= r[3] & mb;
-        z ^= r[14] & ma;
-        z ^= r[3] & r[14];
-        z
-    };
-    r[3] = r[8] ^ r[6];
-    r[14] = r[3].rotate_left(1);
-    r[3] = r[4] & POOL[780];
-    r[8] = r[3] ^ r[14];
-    r[6] = r[13] ^ r[8];
-    r[8] = r[2] & POOL[781];
-    r[13] = {
-        let (t, ma, mb) = (POOL[782], POOL[783], POOL[784]);
-        let mut z = t;
-        z ^= r[3] & mb;
-        z ^= r[14] & ma;
-        z ^= r[3] & r[14];
-        z
-    };
-    r[3] = r[8] ^ r[13];
-    r[14] = r[3].rotate_left(1);
-    r[3] = r[4] & POOL[785];
-    r[8] = r[3] ^ r[14];
-    r[13] = r[6] ^ r[8];
-    r[8] = r[2] & POOL[786];
-    r[6] = {
-        let (t, ma, mb) = (POOL[787], POOL[788], POOL[789]);
-        let mut z = t;
-        z ^= r[3] & mb;
-        z ^= r[14] & ma;
-        z ^= r[3] & r[14];
-        z
-    };
-    r[3] = r[8] ^ r[6];
-    r[14] = r[3].rotate_left(1);
-    r[3] = r[4] & POOL[790];
-    r[8] = r[3] ^ r[14];
-    r[6] = r[13] ^ r[8];
-    r[8] = r[2] & POOL[791];
-    r[2] = {
-        let (t, ma, mb) = (POOL[792], POOL[793], POOL[794]);
-        let mut z = t;
-        z ^= r[3] & mb;
-        z ^= r[14] & ma;
-        z ^= r[3] & r[14];
-        z
-    };
-    r[3] = r[8] ^ r[2];
-    r[2] = r[3].rotate_left(1);
-    r[3] = r[4] & POOL[795];
-    r[4] = r[3] ^ r[2];
-    r[3] = r[6] ^ r[4];
-    r[2] = r[10] ^ r[3];
-    r[3] = r[2] ^ POOL[796];
-    r[10] = r[3].rotate_left(12);
-    r[3] = r[10] ^ POOL[797];
-    r[10] = r[3] ^ POOL[798];
-    r[3] = {
-        let (t, ma, mb) = (POOL[799], POOL[800], POOL[801]);
-        let mut z = t;
-        z ^= r[9] & mb;
-        z ^= r[10] & ma;
-        z ^= r[9] & r[10];
-        z
-    };
-    r[2] = r[9] ^ r[10];
-    r[9] = r[2] & POOL[802];
-    r[10] = r[3] & POOL[803];
-    r[4] = r[10].rotate_left(1);
-    r[10] = r[2] & POOL[804];
-    r[14] = r[10] ^ r[4];
-    r[8] = r[9] ^ r[14];
-    r[9] = r[3] & POOL[805];
-    r[14] = {
-        let (t, ma, mb) = (POOL[806], POOL[807], POOL[808]);
-        let mut z = t;
-        z ^= r[10] & mb;
-        z ^= r[4] & ma;
-        z ^= r[10] & r[4];
-        z
-    };
-    r[10] = r[9] ^ r[14];
-    r[9] = r[10].rotate_left(1);
-    r[10] = r[2] & POOL[809];
-    r[4] = r[10] ^ r[9];
-    r[14] = r[8] ^ r[4];
-    r[4] = r[3] & POOL[810];
-    r[8] = {
-        let (t, ma, mb) = (POOL[811], POOL[812], POOL[813]);
-        let mut z = t;
-        z ^= r[10] & mb;
-        z ^= r[9] & ma;
-        z ^= r[10] & r[9];
-        z
-    };
-    r[9] = r[4] ^ r[8];
-    r[10] = r[9].rotate_left(1);
-    r[9] = r[2] & POOL[814];
-    r[4] = r[9] ^ r[10];
-    r[8] = r[14] ^ r[4];
-    r[4] = r[3] & POOL[815];
-    r[14] = {
-        let (t, ma, mb) = (POOL[816], POOL[817], POOL[818]);
-        let mut z = t;
-        z ^= r[9] & mb;
-        z ^= r[10] & ma;
-        z ^= r[9] & r[10];
-        z
-    };
-    r[9] = r[4] ^ r[14];
-    r[10] = r[9].rotate_left(1);
-    r[9] = r[2] & POOL[819];
-    r[4] = r[9] ^ r[10];
-    r[14] = r[8] ^ r[4];
-    r[4] = r[3] & POOL[820];
-    r[8] = {
-        let (t, ma, mb) = (POOL[821], POOL[822], POOL[823]);
-        let mut z = t;
-        z ^= r[9] & mb;
-        z ^= r[10] & ma;
-        z ^= r[9] & r[10];
-        z
-    };
-    r[9] = r[4] ^ r[8];
-    r[10] = r[9].rotate_left(1);
-    r[9] = r[2] & POOL[824];
-    r[4] = r[9] ^ r[10];
-    r[8] = r[14] ^ r[4];
-    r[4] = r[3] & POOL[825];
-    r[14] = {
-        let (t, ma, mb) = (POOL[826], POOL[827], POOL[828]);
-        let mut z = t;
-        z ^= r[9] & mb;
-        z ^= r[10] & ma;
-        z ^= r[9] & r[10];
-        z
-    };
-    r[9] = r[4] ^ r[14];
-    r[10] = r[9].rotate_left(1);
-    r[9] = r[2] & POOL[829];
-    r[4] = r[9] ^ r[10];
-    r[14] = r[8] ^ r[4];
-    r[4] = r[3] & POOL[830];
-    r[8] = {
-        let (t, ma, mb) = (POOL[831], POOL[832], POOL[833]);
-        let mut z = t;
-        z ^= r[9] & mb;
-        z ^= r[10] & ma;
-        z ^= r[9] & r[10];
-        z
-    };
-    r[9] = r[4] ^ r[8];
-    r[10] = r[9].rotate_left(1);
-    r[9] = r[2] & POOL[834];
-    r[4] = r[9] ^ r[10];
-    r[8] = r[14] ^ r[4];
-    r[4] = r[3] & POOL[835];
-    r[14] = {
-        let (t, ma, mb) = (POOL[836], POOL[837], POOL[838]);
-        let mut z = t;
-        z ^= r[9] & mb;
-        z ^= r[10] & ma;
-        z ^= r[9] & r[10];
-        z
-    };
-    r[9] = r[4] ^ r[14];
-    r[10] = r[9].rotate_left(1);
-    r[9] = r[2] & POOL[839];
-    r[4] = r[9] ^ r[10];
-    r[14] = r[8] ^ r[4];
-    r[4] = r[3] & POOL[840];
-    r[8] = {
-        let (t, ma, mb) = (POOL[841], POOL[842], POOL[843]);
-        let mut z = t;
-        z ^= r[9] & mb;
-        z ^= r[10] & ma;
-        z ^= r[9] & r[10];
-        z
-    };
-    r[9] = r[4] ^ r[8];
-    r[10] = r[9].rotate_left(1);
-    r[9] = r[2] & POOL[844];
-    r[4] = r[9] ^ r[10];
-    r[8] = r[14] ^ r[4];
-    r[4] = r[3] & POOL[845];
-    r[14] = {
-        let (t, ma, mb) = (POOL[846], POOL[847], POOL[848]);
-        let mut z = t;
-        z ^= r[9] & mb;
-        z ^= r[10] & ma;
-        z ^= r[9] & r[10];
-        z
-    };
-    r[9] = r[4] ^ r[14];
-    r[10] = r[9].rotate_left(1);
-    r[9] = r[2] & POOL[849];
-    r[4] = r[9] ^ r[10];
-    r[14] = r[8] ^ r[4];
-    r[4] = r[3] & POOL[850];
-    r[8] = {
-        let (t, ma, mb) = (POOL[851], POOL[852], POOL[853]);
-        let mut z = t;
-        z ^= r[9] & mb;
-        z ^= r[10] & ma;
-        z ^= r[9] & r[10];
-        z
-    };
-    r[9] = r[4] ^ r[8];
-    r[10] = r[9].rotate_left(1);
-    r[9] = r[2] & POOL[854];
-    r[4] = r[9] ^ r[10];
-    r[8] = r[14] ^ r[4];
-    r[4] = r[3] & POOL[855];
-    r[14] = {
-        let (t, ma, mb) = (POOL[856], POOL[857], POOL[858]);
-        let mut z = t;
-        z ^= r[9] & mb;
-        z ^= r[10] & ma;
-        z ^= r[9] & r[10];
-        z
-    };
-    r[9] = r[4] ^ r[14];
-    r[10] = r[9].rotate_left(1);
-    r[9] = r[2] & POOL[859];
-    r[4] = r[9] ^ r[10];
-    r[14] = r[8] ^ r[4];
-    r[4] = r[3] & POOL[860];
-    r[8] = {
-        let (t, ma, mb) = (POOL[861], POOL[862], POOL[863]);
-        let mut z = t;
-        z ^= r[9] & mb;
-        z ^= r[10] & ma;
-        z ^= r[9] & r[10];
-        z
-    };
-    r[9] = r[4] ^ r[8];
-    r[10] = r[9].rotate_left(1);
-    r[9] = r[2] & POOL[864];
-    r[4] = r[9] ^ r[10];
-    r[8] = r[14] ^ r[4];
-    r[4] = r[3] & POOL[865];
-    r[14] = {
-        let (t, ma, mb) = (POOL[866], POOL[867], POOL[868]);
-        let mut z = t;
-        z ^= r[9] & mb;
-        z ^= r[10] & ma;
-        z ^= r[9] & r[10];
-        z
-    };
-    r[9] = r[4] ^ r[14];
-    r[10] = r[9].rotate_left(1);
-    r[9] = r[2] & POOL[869];
-    r[4] = r[9] ^ r[10];
-    r[14] = r[8] ^ r[4];
-    r[4] = r[3] & POOL[870];
-    r[8] = {
-        let (t, ma, mb) = (POOL[871], POOL[872], POOL[873]);
-        let mut z = t;
-        z ^= r[9] & mb;
-        z ^= r[10] & ma;
-        z ^= r[9] & r[10];
-        z
-    };
-    r[9] = r[4] ^ r[8];
-    r[10] = r[9].rotate_left(1);
-    r[9] = r[2] & POOL[874];
-    r[4] = r[9] ^ r[10];
-    r[8] = r[14] ^ r[4];
-    r[4] = r[3] & POOL[875];
-    r[14] = {
-        let (t, ma, mb) = (POOL[876], POOL[877], POOL[878]);
-        let mut z = t;
-        z ^= r[9] & mb;
-        z ^= r[10] & ma;
-        z ^= r[9] & r[10];
-        z
-    };
-    r[9] = r[4] ^ r[14];
-    r[10] = r[9].rotate_left(1);
-    r[9] = r[2] & POOL[879];
-    r[4] = r[9] ^ r[10];
-    r[14] = r[8] ^ r[4];
-    r[4] = r[3] & POOL[880];
-    r[8] = {
-        let (t, ma, mb) = (POOL[881], POOL[882], POOL[883]);
-        let mut z = t;
-        z ^= r[9] & mb;
-        z ^= r[10] & ma;
-        z ^= r[9] & r[10];
-        z
-    };
-    r[9] = r[4] ^ r[8];
-    r[10] = r[9].rotate_left(1);
-    r[9] = r[2] & POOL[884];
-    r[4] = r[9] ^ r[10];
-    r[8] = r[14] ^ r[4];
-    r[4] = r[3] & POOL[885];
-    r[14] = {
-        let (t, ma, mb) = (POOL[886], POOL[887], POOL[888]);
-        let mut z = t;
-        z ^= r[9] & mb;
-        z ^= r[10] & ma;
-        z ^= r[9] & r[10];
-        z
-    };
-    r[9] = r[4] ^ r[14];
-    r[10] = r[9].rotate_left(1);
-    r[9] = r[2] & POOL[889];
-    r[4] = r[9] ^ r[10];
-    r[14] = r[8] ^ r[4];
-    r[4] = r[3] & POOL[890];
-    r[8] = {
-        let (t, ma, mb) = (POOL[891], POOL[892], POOL[893]);
-        let mut z = t;
-        z ^= r[9] & mb;
-        z ^= r[10] & ma;
-        z ^= r[9] & r[10];
-        z
-    };
-    r[9] = r[4] ^ r[8];
-    r[10] = r[9].rotate_left(1);
-    r[9] = r[2] & POOL[894];
-    r[4] = r[9] ^ r[10];
-    r[8] = r[14] ^ r[4];
-    r[4] = r[3] & POOL[895];
-    r[14] = {
-        let (t, ma, mb) = (POOL[896], POOL[897], POOL[898]);
-        let mut z = t;
-        z ^= r[9] & mb;
-        z ^= r[10] & ma;
-        z ^= r[9] & r[10];
-        z
-    };
-    r[9] = r[4] ^ r[14];
-    r[10] = r[9].rotate_left(1);
-    r[9] = r[2] & POOL[899];
-    r[4] = r[9] ^ r[10];
-    r[14] = r[8] ^ r[4];
-    r[4] = r[3] & POOL[900];
-    r[8] = {
-        let (t, ma, mb) = (POOL[901], POOL[902], POOL[903]);
-        let mut z = t;
-        z ^= r[9] & mb;
-        z ^= r[10] & ma;
-        z ^= r[9] & r[10];
-        z
-    };
-    r[9] = r[4] ^ r[8];
-    r[10] = r[9].rotate_left(1);
-    r[9] = r[2] & POOL[904];
-    r[4] = r[9] ^ r[10];
-    r[8] = r[14] ^ r[4];
-    r[4] = r[3] & POOL[905];
-    r[14] = {
-        let (t, ma, mb) = (POOL[906], POOL[907], POOL[908]);
-        let mut z = t;
-        z ^= r[9] & mb;
-        z ^= r[10] & ma;
-        z ^= r[9] & r[10];
-        z
-    };
-    r[9] = r[4] ^ r[14];
-    r[10] = r[9].rotate_left(1);
-    r[9] = r[2] & POOL[909];
-    r[4] = r[9] ^ r[10];
-    r[14] = r[8] ^ r[4];
-    r[4] = r[3] & POOL[910];
-    r[8] = {
-        let (t, ma, mb) = (POOL[911], POOL[912], POOL[913]);
-        let mut z = t;
-        z ^= r[9] & mb;
-        z ^= r[10] & ma;
-        z ^= r[9] & r[10];
-        z
-    };
-    r[9] = r[4] ^ r[8];
-    r[10] = r[9].rotate_left(1);
-    r[9] = r[2] & POOL[914];
-    r[4] = r[9] ^ r[10];
-    r[8] = r[14] ^ r[4];
-    r[4] = r[3] & POOL[915];
-    r[14] = {
-        let (t, ma, mb) = (POOL[916], POOL[917], POOL[918]);
-        let mut z = t;
-        z ^= r[9] & mb;
-        z ^= r[10] & ma;
-        z ^= r[9] & r[10];
-        z
-    };
-    r[9] = r[4] ^ r[14];
-    r[10] = r[9].rotate_left(1);
-    r[9] = r[2] & POOL[919];
-    r[4] = r[9] ^ r[10];
-    r[14] = r[8] ^ r[4];
-    r[4] = r[3] & POOL[920];
-    r[8] = {
-        let (t, ma, mb) = (POOL[921], POOL[922], POOL[923]);
-        let mut z = t;
-        z ^= r[9] & mb;
-        z ^= r[10] & ma;
-        z ^= r[9] & r[10];
-        z
-    };
-    r[9] = r[4] ^ r[8];
-    r[10] = r[9].rotate_left(1);
-    r[9] = r[2] & POOL[924];
-    r[4] = r[9] ^ r[10];
-    r[8] = r[14] ^ r[4];
-    r[4] = r[3] & POOL[925];
-    r[14] = {
-        let (t, ma, mb) = (POOL[926], POOL[927], POOL[928]);
-        let mut z = t;
-        z ^= r[9] & mb;
-        z ^= r[10] & ma;
-        z ^= r[9] & r[10];
-        z
-    };
-    r[9] = r[4] ^ r[14];
-    r[10] = r[9].rotate_left(1);
-    r[9] = r[2] & POOL[929];
-    r[4] = r[9] ^ r[10];
-    r[14] = r[8] ^ r[4];
-    r[4] = r[3] & POOL[930];
-    r[8] = {
-        let (t, ma, mb) = (POOL[931], POOL[932], POOL[933]);
-        let mut z = t;
-        z ^= r[9] & mb;
-        z ^= r[10] & ma;
-        z ^= r[9] & r[10];
-        z
-    };
-    r[9] = r[4] ^ r[8];
-    r[10] = r[9].rotate_left(1);
-    r[9] = r[2] & POOL[934];
-    r[4] = r[9] ^ r[10];
-    r[8] = r[14] ^ r[4];
-    r[4] = r[3] & POOL[935];
-    r[14] = {
-        let (t, ma, mb) = (POOL[936], POOL[937], POOL[938]);
-        let mut z = t;
-        z ^= r[9] & mb;
-        z ^= r[10] & ma;
-        z ^= r[9] & r[10];
-        z
-    };
-    r[9] = r[4] ^ r[14];
-    r[10] = r[9].rotate_left(1);
-    r[9] = r[2] & POOL[939];
-    r[4] = r[9] ^ r[10];
-    r[14] = r[8] ^ r[4];
-    r[4] = r[3] & POOL[940];
-    r[8] = {
-        let (t, ma, mb) = (POOL[941], POOL[942], POOL[943]);
-        let mut z = t;
-        z ^= r[9] & mb;
-        z ^= r[10] & ma;
-        z ^= r[9] & r[10];
-        z
-    };
-    r[9] = r[4] ^ r[8];
-    r[10] = r[9].rotate_left(1);
-    r[9] = r[2] & POOL[944];
-    r[4] = r[9] ^ r[10];
-    r[8] = r[14] ^ r[4];
-    r[4] = r[3] & POOL[945];
-    r[14] = {
-        let (t, ma, mb) = (POOL[946], POOL[947], POOL[948]);
-        let mut z = t;
-        z ^= r[9] & mb;
-        z ^= r[10] & ma;
-        z ^= r[9] & r[10];
-        z
-    };
-    r[9] = r[4] ^ r[14];
-    r[10] = r[9].rotate_left(1);
-    r[9] = r[2] & POOL[949];
-    r[4] = r[9] ^ r[10];
-    r[14] = r[8] ^ r[4];
-    r[4] = r[3] & POOL[950];
-    r[3] = {
-        let (t, ma, mb) = (POOL[951], POOL[952], POOL[953]);
-        let mut z = t;
-        z ^= r[9] & mb;
-        z ^= r[10] & ma;
-        z ^= r[9] & r[10];
-        z
-    };
-    r[9] = r[4] ^ r[3];
-    r[3] = r[9].rotate_left(1);
-    r[9] = r[2] & POOL[954];
-    r[10] = r[9] ^ r[3];
-    r[9] = r[14] ^ r[10];
-    r[3] = r[9] ^ r[7];
-    r[9] = {
-        let (t, ma, mb) = (POOL[955], POOL[956], POOL[957]);
+        z ^= r[1] & mb;
+        z ^= r[14] & ma;
+        z ^= r[1] & r[14];
+        z
+    };
+    r[1] = r[12] ^ r[3];
+    r[14] = r[1] & POOL[1905];
+    r[12] = r[10] & POOL[1906];
+    r[13] = r[12].rotate_left(1);
+    r[12] = r[1] & POOL[1907];
+    r[15] = r[12] ^ r[13];
+    r[2] = r[14] ^ r[15];
+    r[14] = r[10] & POOL[1908];
+    r[15] = r[12] ^ POOL[1909];
+    r[12] = r[15] ^ POOL[1910];
+    r[15] = r[12] ^ POOL[1911];
+    r[12] = r[13] ^ POOL[1912];
+    r[13] = r[12] ^ POOL[1913];
+    r[12] = r[13] ^ POOL[1914];
+    r[13] = {
+        let (t, s) = (POOL[1915], POOL[1916]);
+        let (ma, mb) = (t ^ s ^ POOL[1917], t ^ s ^ POOL[1918]);
         let mut z = t;
-        z ^= r[3] & mb;
+        z ^= r[15] & mb;
         z ^= r[12] & ma;
-        z ^= r[3] & r[12];
-        z
-    };
-    r[12] = r[1] ^ POOL[958];
-    r[3] = r[12] ^ r[15];
-    r[12] = r[3] ^ POOL[959];
-    r[3] = r[12].rotate_left(8);
-    r[12] = {
-        let (t, ma, mb) = (POOL[960], POOL[961], POOL[962]);
-        let mut z = t;
-        z ^= r[0] & mb;
-        z ^= r[3] & ma;
-        z ^= r[0] & r[3];
-        z
-    };
-    r[7] = r[0] ^ r[3];
-    r[0] = r[7] & POOL[963];
-    r[15] = r[12] & POOL[964];
-    r[10] = r[15].rotate_left(1);
-    r[15] = r[7] & POOL[965];
-    r[2] = r[15] ^ r[10];
-    r[4] = r[0] ^ r[2];
-    r[0] = r[12] & POOL[966];
-    r[2] = {
-        let (t, ma, mb) = (POOL[967], POOL[968], POOL[969]);
+        z ^= r[15] & r[12];
+        z
+    };
+    r[12] = r[14] ^ r[13];
+    r[14] = r[12].rotate_left(1);
+    r[12] = r[1] & POOL[1919];
+    r[13] = r[12] ^ r[14];
+    r[15] = r[2] ^ r[13];
+    r[13] = r[10] & POOL[1920];
+    r[2] = r[12] ^ POOL[1921];
+    r[12] = r[2] ^ POOL[1922];
+    r[2] = r[12] ^ POOL[1923];
+    r[12] = r[14] ^ POOL[1924];
+    r[14] = {
+        let (t, s) = (POOL[1925], POOL[1926]);
+        let (ma, mb) = (t ^ s ^ POOL[1927], t ^ s ^ POOL[1928]);
         let mut z = t;
-        z ^= r[15] & mb;
-        z ^= r[10] & ma;
-        z ^= r[15] & r[10];
-        z
-    };
-    r[15] = r[0] ^ r[2];
-    r[0] = r[15].rotate_left(1);
-    r[15] = r[7] & POOL[970];
-    r[10] = r[15] ^ r[0];
-    r[2] = r[4] ^ r[10];
-    r[10] = r[12] & POOL[971];
-    r[4] = {
-        let (t, ma, mb) = (POOL[972], POOL[973], POOL[974]);
+        z ^= r[2] & mb;
+        z ^= r[12] & ma;
+        z ^= r[2] & r[12];
+        z
+    };
+    r[12] = r[13] ^ r[14];
+    r[14] = r[12].rotate_left(1);
+    r[12] = r[1] & POOL[1929];
+    r[13] = r[12] ^ r[14];
+    r[2] = r[15] ^ r[13];
+    r[13] = r[10] & POOL[1930];
+    r[15] = r[12] ^ POOL[1931];
+    r[12] = r[15] ^ POOL[1932];
+    r[15] = r[12] ^ POOL[1933];
+    r[12] = r[14] ^ POOL[1934];
+    r[14] = {
+        let (t, s) = (POOL[1935], POOL[1936]);
+        let (ma, mb) = (t ^ s ^ POOL[1937], t ^ s ^ POOL[1938]);
         let mut z = t;
         z ^= r[15] & mb;
-        z ^= r[0] & ma;
-        z ^= r[15] & r[0];
-        z
-    };
-    r[0] = r[10] ^ r[4];
-    r[15] = r[0].rotate_left(1);
-    r[0] = r[7] & POOL[975];
-    r[10] = r[0] ^ r[15];
-    r[4] = r[2] ^ r[10];
-    r[10] = r[12] & POOL[976];
-    r[2] = {
-        let (t, ma, mb) = (POOL[977], POOL[978], POOL[979]);
-        let mut z = t;
-        z ^= r[0] & mb;
-        z ^= r[15] & ma;
-        z ^= r[0] & r[15];
-        z
-    };
-    r[0] = r[10] ^ r[2];
-    r[15] = r[0].rotate_left(1);
-    r[0] = r[7] & POOL[980];
-    r[10] = r[0] ^ r[15];
-    r[2] = r[4] ^ r[10];
-    r[10] = r[12] & POOL[981];
-    r[4] = {
-        let (t, ma, mb) = (POOL[982], POOL[983], POOL[984]);
+        z ^= r[12] & ma;
+        z ^= r[15] & r[12];
+        z
+    };
+    r[12] = r[13] ^ r[14];
+    r[14] = r[12].rotate_left(1);
+    r[12] = r[1] & POOL[1939];
+    r[13] = r[12] ^ r[14];
+    r[15] = r[2] ^ r[13];
+    r[13] = r[10] & POOL[1940];
+    r[2] = r[12] ^ POOL[1941];
+    r[12] = r[2] ^ POOL[1942];
+    r[2] = r[12] ^ POOL[1943];
+    r[12] = r[14] ^ POOL[1944];
+    r[14] = {
+        let (t, s) = (POOL[1945], POOL[1946]);
+        let (ma, mb) = (t ^ s ^ POOL[1947], t ^ s ^ POOL[1948]);
         let mut z = t;
-        z ^= r[0] & mb;
-        z ^= r[15] & ma;
-        z ^= r[0] & r[15];
-        z
-    };
-    r[0] = r[10] ^ r[4];
-    r[15] = r[0].rotate_left(1);
-    r[0] = r[7] & POOL[985];
-    r[10] = r[0] ^ r[15];
-    r[4] = r[2] ^ r[10];
-    r[10] = r[12] & POOL[986];
-    r[2] = {
-        let (t, ma, mb) = (POOL[987], POOL[988], POOL[989]);
+        z ^= r[2] & mb;
+        z ^= r[12] & ma;
+        z ^= r[2] & r[12];
+        z
+    };
+    r[12] = r[13] ^ r[14];
+    r[14] = r[12].rotate_left(1);
+    r[12] = r[1] & POOL[1949];
+    r[13] = r[12] ^ r[14];
+    r[2] = r[15] ^ r[13];
+    r[13] = r[10] & POOL[1950];
+    r[15] = r[12] ^ POOL[1951];
+    r[12] = r[15] ^ POOL[1952];
+    r[15] = r[12] ^ POOL[1953];
+    r[12] = r[14] ^ POOL[1954];
+    r[14] = {
+        let (t, s) = (POOL[1955], POOL[1956]);
+        let (ma, mb) = (t ^ s ^ POOL[1957], t ^ s ^ POOL[1958]);
         let mut z = t;
-        z ^= r[0] & mb;
-        z ^= r[15] & ma;
-        z ^= r[0] & r[15];
-        z
-    };
-    r[0] = r[10] ^ r[2];
-    r[15] = r[0].rotate_left(1);
-    r[0] = r[7] & POOL[990];
-    r[10] = r[0] ^ r[15];
-    r[2] = r[4] ^ r[10];
-    r[10] = r[12] & POOL[991];
-    r[4] = {
-        let (t, ma, mb) = (POOL[992], POOL[993], POOL[994]);
+        z ^= r[15] & mb;
+        z ^= r[12] & ma;
+        z ^= r[15] & r[12];
+        z
+    };
+    r[12] = r[13] ^ r[14];
+    r[14] = r[12].rotate_left(1);
+    r[12] = r[1] & POOL[1959];
+    r[13] = r[12] ^ r[14];
+    r[15] = r[2] ^ r[13];
+    r[13] = r[10] & POOL[1960];
+    r[2] = r[12] ^ POOL[1961];
+    r[12] = r[2] ^ POOL[1962];
+    r[2] = r[12] ^ POOL[1963];
+    r[12] = r[14] ^ POOL[1964];
+    r[14] = {
+        let (t, s) = (POOL[1965], POOL[1966]);
+        let (ma, mb) = (t ^ s ^ POOL[1967], t ^ s ^ POOL[1968]);
         let mut z = t;
-        z ^= r[0] & mb;
-        z ^= r[15] & ma;
-        z ^= r[0] & r[15];
-        z
-    };
-    r[0] = r[10] ^ r[4];
-    r[15] = r[0].rotate_left(1);
-    r[0] = r[7] & POOL[995];
-    r[10] = r[0] ^ r[15];
-    r[4] = r[2] ^ r[10];
-    r[10] = r[12] & POOL[996];
-    r[2] = {
-        let (t, ma, mb) = (POOL[997], POOL[998], POOL[999]);
+        z ^= r[2] & mb;
+        z ^= r[12] & ma;
+        z ^= r[2] & r[12];
+        z
+    };
+    r[12] = r[13] ^ r[14];
+    r[14] = r[12].rotate_left(1);
+    r[12] = r[1] & POOL[1969];
+    r[13] = r[12] ^ r[14];
+    r[2] = r[15] ^ r[13];
+    r[13] = r[10] & POOL[1970];
+    r[15] = r[12] ^ POOL[1971];
+    r[12] = r[15] ^ POOL[1972];
+    r[15] = r[12] ^ POOL[1973];
+    r[12] = r[14] ^ POOL[1974];
+    r[14] = {
+        let (t, s) = (POOL[1975], POOL[1976]);
+        let (ma, mb) = (t ^ s ^ POOL[1977], t ^ s ^ POOL[1978]);
         let mut z = t;
-        z ^= r[0] & mb;
-        z ^= r[15] & ma;
-        z ^= r[0] & r[15];
-        z
-    };
-    r[0] = r[10] ^ r[2];
-    r[15] = r[0].rotate_left(1);
-    r[0] = r[7] & POOL[1000];
-    r[10] = r[0] ^ r[15];
-    r[2] = r[4] ^ r[10];
-    r[10] = r[12] & POOL[1001];
-    r[4] = {
-        let (t, ma, mb) = (POOL[1002], POOL[1003], POOL[1004]);
+        z ^= r[15] & mb;
+        z ^= r[12] & ma;
+        z ^= r[15] & r[12];
+        z
+    };
+    r[12] = r[13] ^ r[14];
+    r[14] = r[12].rotate_left(1);
+    r[12] = r[1] & POOL[1979];
+    r[13] = r[12] ^ r[14];
+    r[15] = r[2] ^ r[13];
+    r[13] = r[10] & POOL[1980];
+    r[2] = r[12] ^ POOL[1981];
+    r[12] = r[2] ^ POOL[1982];
+    r[2] = r[12] ^ POOL[1983];
+    r[12] = r[14] ^ POOL[1984];
+    r[14] = {
+        let (t, s) = (POOL[1985], POOL[1986]);
+        let (ma, mb) = (t ^ s ^ POOL[1987], t ^ s ^ POOL[1988]);
         let mut z = t;
-        z ^= r[0] & mb;
-        z ^= r[15] & ma;
-        z ^= r[0] & r[15];
-        z
-    };
-    r[0] = r[10] ^ r[4];
-    r[15] = r[0].rotate_left(1);
-    r[0] = r[7] & POOL[1005];
-    r[10] = r[0] ^ r[15];
-    r[4] = r[2] ^ r[10];
-    r[10] = r[12] & POOL[1006];
-    r[2] = {
-        let (t, ma, mb) = (POOL[1007], POOL[1008], POOL[1009]);
+        z ^= r[2] & mb;
+        z ^= r[12] & ma;
+        z ^= r[2] & r[12];
+        z
+    };
+    r[12] = r[13] ^ r[14];
+    r[14] = r[12].rotate_left(1);
+    r[12] = r[1] & POOL[1989];
+    r[13] = r[12] ^ r[14];
+    r[2] = r[15] ^ r[13];
+    r[13] = r[10] & POOL[1990];
+    r[15] = r[12] ^ POOL[1991];
+    r[12] = r[15] ^ POOL[1992];
+    r[15] = r[12] ^ POOL[1993];
+    r[12] = r[14] ^ POOL[1994];
+    r[14] = {
+        let (t, s) = (POOL[1995], POOL[1996]);
+        let (ma, mb) = (t ^ s ^ POOL[1997], t ^ s ^ POOL[1998]);
         let mut z = t;
-        z ^= r[0] & mb;
-        z ^= r[15] & ma;
-        z ^= r[0] & r[15];
-        z
-    };
-    r[0] = r[10] ^ r[2];
-    r[15] = r[0].rotate_left(1);
-    r[0] = r[7] & POOL[1010];
-    r[10] = r[0] ^ r[15];
-    r[2] = r[4] ^ r[10];
-    r[10] = r[12] & POOL[1011];
-    r[4] = {
-        let (t, ma, mb) = (POOL[1012], POOL[1013], POOL[1014]);
+        z ^= r[15] & mb;
+        z ^= r[12] & ma;
+        z ^= r[15] & r[12];
+        z
+    };
+    r[12] = r[13] ^ r[14];
+    r[14] = r[12].rotate_left(1);
+    r[12] = r[1] & POOL[1999];
+    r[13] = r[12] ^ r[14];
+    r[15] = r[2] ^ r[13];
+    r[13] = r[10] & POOL[2000];
+    r[2] = r[12] ^ POOL[2001];
+    r[12] = r[2] ^ POOL[2002];
+    r[2] = r[12] ^ POOL[2003];
+    r[12] = r[14] ^ POOL[2004];
+    r[14] = {
+        let (t, s) = (POOL[2005], POOL[2006]);
+        let (ma, mb) = (t ^ s ^ POOL[2007], t ^ s ^ POOL[2008]);
         let mut z = t;
-        z ^= r[0] & mb;
-        z ^= r[15] & ma;
-        z ^= r[0] & r[15];
-        z
-    };
-    r[0] = r[10] ^ r[4];
-    r[15] = r[0].rotate_left(1);
-    r[0] = r[7] & POOL[1015];
-    r[10] = r[0] ^ r[15];
-    r[4] = r[2] ^ r[10];
-    r[10] = r[12] & POOL[1016];
-    r[2] = {
-        let (t, ma, mb) = (POOL[1017], POOL[1018], POOL[1019]);
+        z ^= r[2] & mb;
+        z ^= r[12] & ma;
+        z ^= r[2] & r[12];
+        z
+    };
+    r[12] = r[13] ^ r[14];
+    r[14] = r[12].rotate_left(1);
+    r[12] = r[1] & POOL[2009];
+    r[13] = r[12] ^ r[14];
+    r[2] = r[15] ^ r[13];
+    r[13] = r[10] & POOL[2010];
+    r[15] = r[12] ^ POOL[2011];
+    r[12] = r[15] ^ POOL[2012];
+    r[15] = r[12] ^ POOL[2013];
+    r[12] = r[14] ^ POOL[2014];
+    r[14] = {
+        let (t, s) = (POOL[2015], POOL[2016]);
+        let (ma, mb) = (t ^ s ^ POOL[2017], t ^ s ^ POOL[2018]);
         let mut z = t;
-        z ^= r[0] & mb;
-        z ^= r[15] & ma;
-        z ^= r[0] & r[15];
-        z
-    };
-    r[0] = r[10] ^ r[2];
-    r[15] = r[0].rotate_left(1);
-    r[0] = r[7] & POOL[1020];
-    r[10] = r[0] ^ r[15];
-    r[2] = r[4] ^ r[10];
-    r[10] = r[12] & POOL[1021];
-    r[4] = {
-        let (t, ma, mb) = (POOL[1022], POOL[1023], POOL[1024]);
+        z ^= r[15] & mb;
+        z ^= r[12] & ma;
+        z ^= r[15] & r[12];
+        z
+    };
+    r[12] = r[13] ^ r[14];
+    r[14] = r[12].rotate_left(1);
+    r[12] = r[1] & POOL[2019];
+    r[13] = r[12] ^ r[14];
+    r[15] = r[2] ^ r[13];
+    r[13] = r[10] & POOL[2020];
+    r[2] = r[12] ^ POOL[2021];
+    r[12] = r[2] ^ POOL[2022];
+    r[2] = r[12] ^ POOL[2023];
+    r[12] = r[14] ^ POOL[2024];
+    r[14] = {
+        let (t, s) = (POOL[2025], POOL[2026]);
+        let (ma, mb) = (t ^ s ^ POOL[2027], t ^ s ^ POOL[2028]);
         let mut z = t;
-        z ^= r[0] & mb;
-        z ^= r[15] & ma;
-        z ^= r[0] & r[15];
-        z
-    };
-    r[0] = r[10] ^ r[4];
-    r[15] = r[0].rotate_left(1);
-    r[0] = r[7] & POOL[1025];
-    r[10] = r[0] ^ r[15];
-    r[4] = r[2] ^ r[10];
-    r[10] = r[12] & POOL[1026];
-    r[2] = {
-        let (t, ma, mb) = (POOL[1027], POOL[1028], POOL[1029]);
+        z ^= r[2] & mb;
+        z ^= r[12] & ma;
+        z ^= r[2] & r[12];
+        z
+    };
+    r[12] = r[13] ^ r[14];
+    r[14] = r[12].rotate_left(1);
+    r[12] = r[1] & POOL[2029];
+    r[13] = r[12] ^ r[14];
+    r[2] = r[15] ^ r[13];
+    r[13] = r[10] & POOL[2030];
+    r[15] = r[12] ^ POOL[2031];
+    r[12] = r[15] ^ POOL[2032];
+    r[15] = r[12] ^ POOL[2033];
+    r[12] = r[14] ^ POOL[2034];
+    r[14] = {
+        let (t, s) = (POOL[2035], POOL[2036]);
+        let (ma, mb) = (t ^ s ^ POOL[2037], t ^ s ^ POOL[2038]);
         let mut z = t;
-        z ^= r[0] & mb;
-        z ^= r[15] & ma;
-        z ^= r[0] & r[15];
-        z
-    };
-    r[0] = r[10] ^ r[2];
-    r[15] = r[0].rotate_left(1);
-    r[0] = r[7] & POOL[1030];
-    r[10] = r[0] ^ r[15];
-    r[2] = r[4] ^ r[10];
-    r[10] = r[12] & POOL[1031];
-    r[4] = {
-        let (t, ma, mb) = (POOL[1032], POOL[1033], POOL[1034]);
+        z ^= r[15] & mb;
+        z ^= r[12] & ma;
+        z ^= r[15] & r[12];
+        z
+    };
+    r[12] = r[13] ^ r[14];
+    r[14] = r[12].rotate_left(1);
+    r[12] = r[1] & POOL[2039];
+    r[13] = r[12] ^ r[14];
+    r[15] = r[2] ^ r[13];
+    r[13] = r[10] & POOL[2040];
+    r[2] = r[12] ^ POOL[2041];
+    r[12] = r[2] ^ POOL[2042];
+    r[2] = r[12] ^ POOL[2043];
+    r[12] = r[14] ^ POOL[2044];
+    r[14] = {
+        let (t, s) = (POOL[2045], POOL[2046]);
+        let (ma, mb) = (t ^ s ^ POOL[2047], t ^ s ^ POOL[2048]);
         let mut z = t;
-        z ^= r[0] & mb;
-        z ^= r[15] & ma;
-        z ^= r[0] & r[15];
-        z
-    };
-    r[0] = r[10] ^ r[4];
-    r[15] = r[0].rotate_left(1);
-    r[0] = r[7] & POOL[1035];
-    r[10] = r[0] ^ r[15];
-    r[4] = r[2] ^ r[10];
-    r[10] = r[12] & POOL[1036];
-    r[2] = {
-        let (t, ma, mb) = (POOL[1037], POOL[1038], POOL[1039]);
+        z ^= r[2] & mb;
+        z ^= r[12] & ma;
+        z ^= r[2] & r[12];
+        z
+    };
+    r[12] = r[13] ^ r[14];
+    r[14] = r[12].rotate_left(1);
+    r[12] = r[1] & POOL[2049];
+    r[13] = r[12] ^ r[14];
+    r[2] = r[15] ^ r[13];
+    r[13] = r[10] & POOL[2050];
+    r[15] = r[12] ^ POOL[2051];
+    r[12] = r[15] ^ POOL[2052];
+    r[15] = r[12] ^ POOL[2053];
+    r[12] = r[14] ^ POOL[2054];
+    r[14] = {
+        let (t, s) = (POOL[2055], POOL[2056]);
+        let (ma, mb) = (t ^ s ^ POOL[2057], t ^ s ^ POOL[2058]);
         let mut z = t;
-        z ^= r[0] & mb;
-        z ^= r[15] & ma;
-        z ^= r[0] & r[15];
-        z
-    };
-    r[0] = r[10] ^ r[2];
-    r[15] = r[0].rotate_left(1);
-    r[0] = r[7] & POOL[1040];
-    r[10] = r[0] ^ r[15];
-    r[2] = r[4] ^ r[10];
-    r[10] = r[12] & POOL[1041];
-    r[4] = {
-        let (t, ma, mb) = (POOL[1042], POOL[1043], POOL[1044]);
+        z ^= r[15] & mb;
+        z ^= r[12] & ma;
+        z ^= r[15] & r[12];
+        z
+    };
+    r[12] = r[13] ^ r[14];
+    r[14] = r[12].rotate_left(1);
+    r[12] = r[1] & POOL[2059];
+    r[13] = r[12] ^ r[14];
+    r[15] = r[2] ^ r[13];
+    r[13] = r[10] & POOL[2060];
+    r[2] = r[12] ^ POOL[2061];
+    r[12] = r[2] ^ POOL[2062];
+    r[2] = r[12] ^ POOL[2063];
+    r[12] = r[14] ^ POOL[2064];
+    r[14] = {
+        let (t, s) = (POOL[2065], POOL[2066]);
+        let (ma, mb) = (t ^ s ^ POOL[2067], t ^ s ^ POOL[2068]);
         let mut z = t;
-        z ^= r[0] & mb;
-        z ^= r[15] & ma;
-        z ^= r[0] & r[15];
-        z
-    };
-    r[0] = r[10] ^ r[4];
-    r[15] = r[0].rotate_left(1);
-    r[0] = r[7] & POOL[1045];
-    r[10] = r[0] ^ r[15];
-    r[4] = r[2] ^ r[10];
-    r[10] = r[12] & POOL[1046];
-    r[2] = {
-        let (t, ma, mb) = (POOL[1047], POOL[1048], POOL[1049]);
+        z ^= r[2] & mb;
+        z ^= r[12] & ma;
+        z ^= r[2] & r[12];
+        z
+    };
+    r[12] = r[13] ^ r[14];
+    r[14] = r[12].rotate_left(1);
+    r[12] = r[1] & POOL[2069];
+    r[13] = r[12] ^ r[14];
+    r[2] = r[15] ^ r[13];
+    r[13] = r[10] & POOL[2070];
+    r[15] = r[12] ^ POOL[2071];
+    r[12] = r[15] ^ POOL[2072];
+    r[15] = r[12] ^ POOL[2073];
+    r[12] = r[14] ^ POOL[2074];
+    r[14] = {
+        let (t, s) = (POOL[2075], POOL[2076]);
+        let (ma, mb) = (t ^ s ^ POOL[2077], t ^ s ^ POOL[2078]);
         let mut z = t;
-        z ^= r[0] & mb;
-        z ^= r[15] & ma;
-        z ^= r[0] & r[15];
-        z
-    };
-    r[0] = r[10] ^ r[2];
-    r[15] = r[0].rotate_left(1);
-    r[0] = r[7] & POOL[1050];
-    r[10] = r[0] ^ r[15];
-    r[2] = r[4] ^ r[10];
-    r[10] = r[12] & POOL[1051];
-    r[4] = {
-        let (t, ma, mb) = (POOL[1052], POOL[1053], POOL[1054]);
+        z ^= r[15] & mb;
+        z ^= r[12] & ma;
+        z ^= r[15] & r[12];
+        z
+    };
+    r[12] = r[13] ^ r[14];
+    r[14] = r[12].rotate_left(1);
+    r[12] = r[1] & POOL[2079];
+    r[13] = r[12] ^ r[14];
+    r[15] = r[2] ^ r[13];
+    r[13] = r[10] & POOL[2080];
+    r[2] = r[12] ^ POOL[2081];
+    r[12] = r[2] ^ POOL[2082];
+    r[2] = r[12] ^ POOL[2083];
+    r[12] = r[14] ^ POOL[2084];
+    r[14] = {
+        let (t, s) = (POOL[2085], POOL[2086]);
+        let (ma, mb) = (t ^ s ^ POOL[2087], t ^ s ^ POOL[2088]);
         let mut z = t;
-        z ^= r[0] & mb;
-        z ^= r[15] & ma;
-        z ^= r[0] & r[15];
-        z
-    };
-    r[0] = r[10] ^ r[4];
-    r[15] = r[0].rotate_left(1);
-    r[0] = r[7] & POOL[1055];
-    r[10] = r[0] ^ r[15];
-    r[4] = r[2] ^ r[10];
-    r[10] = r[12] & POOL[1056];
-    r[2] = {
-        let (t, ma, mb) = (POOL[1057], POOL[1058], POOL[1059]);
+        z ^= r[2] & mb;
+        z ^= r[12] & ma;
+        z ^= r[2] & r[12];
+        z
+    };
+    r[12] = r[13] ^ r[14];
+    r[14] = r[12].rotate_left(1);
+    r[12] = r[1] & POOL[2089];
+    r[13] = r[12] ^ r[14];
+    r[2] = r[15] ^ r[13];
+    r[13] = r[10] & POOL[2090];
+    r[15] = r[12] ^ POOL[2091];
+    r[12] = r[15] ^ POOL[2092];
+    r[15] = r[12] ^ POOL[2093];
+    r[12] = r[14] ^ POOL[2094];
+    r[14] = {
+        let (t, s) = (POOL[2095], POOL[2096]);
+        let (ma, mb) = (t ^ s ^ POOL[2097], t ^ s ^ POOL[2098]);
         let mut z = t;
-        z ^= r[0] & mb;
-        z ^= r[15] & ma;
-        z ^= r[0] & r[15];
-        z
-    };
-    r[0] = r[10] ^ r[2];
-    r[15] = r[0].rotate_left(1);
-    r[0] = r[7] & POOL[1060];
-    r[10] = r[0] ^ r[15];
-    r[2] = r[4] ^ r[10];
-    r[10] = r[12] & POOL[1061];
-    r[4] = {
-        let (t, ma, mb) = (POOL[1062], POOL[1063], POOL[1064]);
+        z ^= r[15] & mb;
+        z ^= r[12] & ma;
+        z ^= r[15] & r[12];
+        z
+    };
+    r[12] = r[13] ^ r[14];
+    r[14] = r[12].rotate_left(1);
+    r[12] = r[1] & POOL[2099];
+    r[13] = r[12] ^ r[14];
+    r[15] = r[2] ^ r[13];
+    r[13] = r[10] & POOL[2100];
+    r[2] = r[12] ^ POOL[2101];
+    r[12] = r[2] ^ POOL[2102];
+    r[2] = r[12] ^ POOL[2103];
+    r[12] = r[14] ^ POOL[2104];
+    r[14] = {
+        let (t, s) = (POOL[2105], POOL[2106]);
+        let (ma, mb) = (t ^ s ^ POOL[2107], t ^ s ^ POOL[2108]);
         let mut z = t;
-        z ^= r[0] & mb;
-        z ^= r[15] & ma;
-        z ^= r[0] & r[15];
-        z
-    };
-    r[0] = r[10] ^ r[4];
-    r[15] = r[0].rotate_left(1);
-    r[0] = r[7] & POOL[1065];
-    r[10] = r[0] ^ r[15];
-    r[4] = r[2] ^ r[10];
-    r[10] = r[12] & POOL[1066];
-    r[2] = {
-        let (t, ma, mb) = (POOL[1067], POOL[1068], POOL[1069]);
+        z ^= r[2] & mb;
+        z ^= r[12] & ma;
+        z ^= r[2] & r[12];
+        z
+    };
+    r[12] = r[13] ^ r[14];
+    r[14] = r[12].rotate_left(1);
+    r[12] = r[1] & POOL[2109];
+    r[13] = r[12] ^ r[14];
+    r[2] = r[15] ^ r[13];
+    r[13] = r[10] & POOL[2110];
+    r[15] = r[12] ^ POOL[2111];
+    r[12] = r[15] ^ POOL[2112];
+    r[15] = r[12] ^ POOL[2113];
+    r[12] = r[14] ^ POOL[2114];
+    r[14] = {
+        let (t, s) = (POOL[2115], POOL[2116]);
+        let (ma, mb) = (t ^ s ^ POOL[2117], t ^ s ^ POOL[2118]);
         let mut z = t;
-        z ^= r[0] & mb;
-        z ^= r[15] & ma;
-        z ^= r[0] & r[15];
-        z
-    };
-    r[0] = r[10] ^ r[2];
-    r[15] = r[0].rotate_left(1);
-    r[0] = r[7] & POOL[1070];
-    r[10] = r[0] ^ r[15];
-    r[2] = r[4] ^ r[10];
-    r[10] = r[12] & POOL[1071];
-    r[4] = {
-        let (t, ma, mb) = (POOL[1072], POOL[1073], POOL[1074]);
+        z ^= r[15] & mb;
+        z ^= r[12] & ma;
+        z ^= r[15] & r[12];
+        z
+    };
+    r[12] = r[13] ^ r[14];
+    r[14] = r[12].rotate_left(1);
+    r[12] = r[1] & POOL[2119];
+    r[13] = r[12] ^ r[14];
+    r[15] = r[2] ^ r[13];
+    r[13] = r[10] & POOL[2120];
+    r[2] = r[12] ^ POOL[2121];
+    r[12] = r[2] ^ POOL[2122];
+    r[2] = r[12] ^ POOL[2123];
+    r[12] = r[14] ^ POOL[2124];
+    r[14] = {
+        let (t, s) = (POOL[2125], POOL[2126]);
+        let (ma, mb) = (t ^ s ^ POOL[2127], t ^ s ^ POOL[2128]);
         let mut z = t;
-        z ^= r[0] & mb;
-        z ^= r[15] & ma;
-        z ^= r[0] & r[15];
-        z
-    };
-    r[0] = r[10] ^ r[4];
-    r[15] = r[0].rotate_left(1);
-    r[0] = r[7] & POOL[1075];
-    r[10] = r[0] ^ r[15];
-    r[4] = r[2] ^ r[10];
-    r[10] = r[12] & POOL[1076];
-    r[2] = {
-        let (t, ma, mb) = (POOL[1077], POOL[1078], POOL[1079]);
+        z ^= r[2] & mb;
+        z ^= r[12] & ma;
+        z ^= r[2] & r[12];
+        z
+    };
+    r[12] = r[13] ^ r[14];
+    r[14] = r[12].rotate_left(1);
+    r[12] = r[1] & POOL[2129];
+    r[13] = r[12] ^ r[14];
+    r[2] = r[15] ^ r[13];
+    r[13] = r[10] & POOL[2130];
+    r[15] = r[12] ^ POOL[2131];
+    r[12] = r[15] ^ POOL[2132];
+    r[15] = r[12] ^ POOL[2133];
+    r[12] = r[14] ^ POOL[2134];
+    r[14] = {
+        let (t, s) = (POOL[2135], POOL[2136]);
+        let (ma, mb) = (t ^ s ^ POOL[2137], t ^ s ^ POOL[2138]);
         let mut z = t;
-        z ^= r[0] & mb;
-        z ^= r[15] & ma;
-        z ^= r[0] & r[15];
-        z
-    };
-    r[0] = r[10] ^ r[2];
-    r[15] = r[0].rotate_left(1);
-    r[0] = r[7] & POOL[1080];
-    r[10] = r[0] ^ r[15];
-    r[2] = r[4] ^ r[10];
-    r[10] = r[12] & POOL[1081];
-    r[4] = {
-        let (t, ma, mb) = (POOL[1082], POOL[1083], POOL[1084]);
+        z ^= r[15] & mb;
+        z ^= r[12] & ma;
+        z ^= r[15] & r[12];
+        z
+    };
+    r[12] = r[13] ^ r[14];
+    r[14] = r[12].rotate_left(1);
+    r[12] = r[1] & POOL[2139];
+    r[13] = r[12] ^ r[14];
+    r[15] = r[2] ^ r[13];
+    r[13] = r[10] & POOL[2140];
+    r[2] = r[12] ^ POOL[2141];
+    r[12] = r[2] ^ POOL[2142];
+    r[2] = r[12] ^ POOL[2143];
+    r[12] = r[14] ^ POOL[2144];
+    r[14] = {
+        let (t, s) = (POOL[2145], POOL[2146]);
+        let (ma, mb) = (t ^ s ^ POOL[2147], t ^ s ^ POOL[2148]);
         let mut z = t;
-        z ^= r[0] & mb;
-        z ^= r[15] & ma;
-        z ^= r[0] & r[15];
-        z
-    };
-    r[0] = r[10] ^ r[4];
-    r[15] = r[0].rotate_left(1);
-    r[0] = r[7] & POOL[1085];
-    r[10] = r[0] ^ r[15];
-    r[4] = r[2] ^ r[10];
-    r[10] = r[12] & POOL[1086];
-    r[2] = {
-        let (t, ma, mb) = (POOL[1087], POOL[1088], POOL[1089]);
+        z ^= r[2] & mb;
+        z ^= r[12] & ma;
+        z ^= r[2] & r[12];
+        z
+    };
+    r[12] = r[13] ^ r[14];
+    r[14] = r[12].rotate_left(1);
+    r[12] = r[1] & POOL[2149];
+    r[13] = r[12] ^ r[14];
+    r[2] = r[15] ^ r[13];
+    r[13] = r[10] & POOL[2150];
+    r[15] = r[12] ^ POOL[2151];
+    r[12] = r[15] ^ POOL[2152];
+    r[15] = r[12] ^ POOL[2153];
+    r[12] = r[14] ^ POOL[2154];
+    r[14] = {
+        let (t, s) = (POOL[2155], POOL[2156]);
+        let (ma, mb) = (t ^ s ^ POOL[2157], t ^ s ^ POOL[2158]);
         let mut z = t;
-        z ^= r[0] & mb;
-        z ^= r[15] & ma;
-        z ^= r[0] & r[15];
-        z
-    };
-    r[0] = r[10] ^ r[2];
-    r[15] = r[0].rotate_left(1);
-    r[0] = r[7] & POOL[1090];
-    r[10] = r[0] ^ r[15];
-    r[2] = r[4] ^ r[10];
-    r[10] = r[12] & POOL[1091];
-    r[4] = {
-        let (t, ma, mb) = (POOL[1092], POOL[1093], POOL[1094]);
+        z ^= r[15] & mb;
+        z ^= r[12] & ma;
+        z ^= r[15] & r[12];
+        z
+    };
+    r[12] = r[13] ^ r[14];
+    r[14] = r[12].rotate_left(1);
+    r[12] = r[1] & POOL[2159];
+    r[13] = r[12] ^ r[14];
+    r[15] = r[2] ^ r[13];
+    r[13] = r[10] & POOL[2160];
+    r[2] = r[12] ^ POOL[2161];
+    r[12] = r[2] ^ POOL[2162];
+    r[2] = r[12] ^ POOL[2163];
+    r[12] = r[14] ^ POOL[2164];
+    r[14] = {
+        let (t, s) = (POOL[2165], POOL[2166]);
+        let (ma, mb) = (t ^ s ^ POOL[2167], t ^ s ^ POOL[2168]);
         let mut z = t;
-        z ^= r[0] & mb;
-        z ^= r[15] & ma;
-        z ^= r[0] & r[15];
-        z
-    };
-    r[0] = r[10] ^ r[4];
-    r[15] = r[0].rotate_left(1);
-    r[0] = r[7] & POOL[1095];
-    r[10] = r[0] ^ r[15];
-    r[4] = r[2] ^ r[10];
-    r[10] = r[12] & POOL[1096];
-    r[2] = {
-        let (t, ma, mb) = (POOL[1097], POOL[1098], POOL[1099]);
+        z ^= r[2] & mb;
+        z ^= r[12] & ma;
+        z ^= r[2] & r[12];
+        z
+    };
+    r[12] = r[13] ^ r[14];
+    r[14] = r[12].rotate_left(1);
+    r[12] = r[1] & POOL[2169];
+    r[13] = r[12] ^ r[14];
+    r[2] = r[15] ^ r[13];
+    r[13] = r[10] & POOL[2170];
+    r[15] = r[12] ^ POOL[2171];
+    r[12] = r[15] ^ POOL[2172];
+    r[15] = r[12] ^ POOL[2173];
+    r[12] = r[14] ^ POOL[2174];
+    r[14] = {
+        let (t, s) = (POOL[2175], POOL[2176]);
+        let (ma, mb) = (t ^ s ^ POOL[2177], t ^ s ^ POOL[2178]);
         let mut z = t;
-        z ^= r[0] & mb;
-        z ^= r[15] & ma;
-        z ^= r[0] & r[15];
-        z
-    };
-    r[0] = r[10] ^ r[2];
-    r[15] = r[0].rotate_left(1);
-    r[0] = r[7] & POOL[1100];
-    r[10] = r[0] ^ r[15];
-    r[2] = r[4] ^ r[10];
-    r[10] = r[12] & POOL[1101];
-    r[4] = {
-        let (t, ma, mb) = (POOL[1102], POOL[1103], POOL[1104]);
+        z ^= r[15] & mb;
+        z ^= r[12] & ma;
+        z ^= r[15] & r[12];
+        z
+    };
+    r[12] = r[13] ^ r[14];
+    r[14] = r[12].rotate_left(1);
+    r[12] = r[1] & POOL[2179];
+    r[13] = r[12] ^ r[14];
+    r[15] = r[2] ^ r[13];
+    r[13] = r[10] & POOL[2180];
+    r[2] = r[12] ^ POOL[2181];
+    r[12] = r[2] ^ POOL[2182];
+    r[2] = r[12] ^ POOL[2183];
+    r[12] = r[14] ^ POOL[2184];
+    r[14] = {
+        let (t, s) = (POOL[2185], POOL[2186]);
+        let (ma, mb) = (t ^ s ^ POOL[2187], t ^ s ^ POOL[2188]);
         let mut z = t;
-        z ^= r[0] & mb;
-        z ^= r[15] & ma;
-        z ^= r[0] & r[15];
-        z
-    };
-    r[0] = r[10] ^ r[4];
-    r[15] = r[0].rotate_left(1);
-    r[0] = r[7] & POOL[1105];
-    r[10] = r[0] ^ r[15];
-    r[4] = r[2] ^ r[10];
-    r[10] = r[12] & POOL[1106];
-    r[2] = {
-        let (t, ma, mb) = (POOL[1107], POOL[1108], POOL[1109]);
+        z ^= r[2] & mb;
+        z ^= r[12] & ma;
+        z ^= r[2] & r[12];
+        z
+    };
+    r[12] = r[13] ^ r[14];
+    r[14] = r[12].rotate_left(1);
+    r[12] = r[1] & POOL[2189];
+    r[13] = r[12] ^ r[14];
+    r[2] = r[15] ^ r[13];
+    r[13] = r[10] & POOL[2190];
+    r[15] = r[12] ^ POOL[2191];
+    r[12] = r[15] ^ POOL[2192];
+    r[15] = r[12] ^ POOL[2193];
+    r[12] = r[14] ^ POOL[2194];
+    r[14] = {
+        let (t, s) = (POOL[2195], POOL[2196]);
+        let (ma, mb) = (t ^ s ^ POOL[2197], t ^ s ^ POOL[2198]);
         let mut z = t;
-        z ^= r[0] & mb;
-        z ^= r[15] & ma;
-        z ^= r[0] & r[15];
-        z
-    };
-    r[0] = r[10] ^ r[2];
-    r[15] = r[0].rotate_left(1);
-    r[0] = r[7] & POOL[1110];
-    r[10] = r[0] ^ r[15];
-    r[2] = r[4] ^ r[10];
-    r[10] = r[12] & POOL[1111];
-    r[12] = {
-        let (t, ma, mb) = (POOL[1112], POOL[1113], POOL[1114]);
+        z ^= r[15] & mb;
+        z ^= r[12] & ma;
+        z ^= r[15] & r[12];
+        z
+    };
+    r[12] = r[13] ^ r[14];
+    r[14] = r[12].rotate_left(1);
+    r[12] = r[1] & POOL[2199];
+    r[13] = r[12] ^ r[14];
+    r[15] = r[2] ^ r[13];
+    r[13] = r[10] & POOL[2200];
+    r[10] = r[12] ^ POOL[2201];
+    r[12] = r[10] ^ POOL[2202];
+    r[10] = r[12] ^ POOL[2203];
+    r[12] = r[14] ^ POOL[2204];
+    r[14] = {
+        let (t, s) = (POOL[2205], POOL[2206]);
+        let (ma, mb) = (t ^ s ^ POOL[2207], t ^ s ^ POOL[2208]);
         let mut z = t;
-        z ^= r[0] & mb;
-        z ^= r[15] & ma;
-        z ^= r[0] & r[15];
-        z
-    };
-    r[0] = r[10] ^ r[12];
-    r[12] = r[0].rotate_left(1);
-    r[0] = r[7] & POOL[1115];
-    r[7] = r[0] ^ r[12];
-    r[12] = r[2] ^ r[7];
-    r[7] = r[12] ^ POOL[1116];
-    r[12] = r[7] ^ POOL[1117];
-    r[7] = r[9] ^ r[12];
-    r[9] = {
-        let (t, ma, mb) = (POOL[1118], POOL[1119], POOL[1120]);
+        z ^= r[10] & mb;
+        z ^= r[12] & ma;
+        z ^= r[10] & r[12];
+        z
+    };
+    r[10] = r[13] ^ r[14];
+    r[14] = r[10].rotate_left(1);
+    r[10] = r[1] & POOL[2209];
+    r[1] = r[10] ^ r[14];
+    r[14] = r[15] ^ r[1];
+    r[1] = r[14] ^ POOL[2210];
+    r[14] = r[1] ^ POOL[2211];
+    r[1] = r[8] ^ r[14];
+    r[8] = r[0] ^ POOL[2212];
+    r[0] = r[1] ^ POOL[2213];
+    r[1] = {
+        let (t, s) = (POOL[2214], POOL[2215]);
+        let (ma, mb) = (t ^ s ^ POOL[2216], t ^ s ^ POOL[2217]);
         let mut z = t;
-        z ^= r[11] & mb;
-        z ^= r[7] & ma;
-        z ^= r[11] & r[7];
+        z ^= r[8] & mb;
+        z ^= r[0] & ma;
+        z ^= r[8] & r[0];
         z
     };
-    r[11] = r[12] ^ r[9];
-    r[9] = r[11] ^ POOL[1121];
-    r[12] = r[9] ^ POOL[1122];
-    r[9] = r[1] ^ r[12];
-    r[1] = r[9] ^ r[3];
-    r[9] = r[5] ^ r[12];
-    r[12] = r[9].rotate_left(7);
-    r[9] = r[1] ^ r[12];
-    r[9] ^ POOL[1123]
+    r[8] = r[14] ^ r[1];
+    r[1] = r[8] ^ POOL[2218];
+    r[8] = r[1] ^ POOL[2219];
+    r[1] = r[11] ^ r[8];
+    r[11] = r[1] ^ r[3];
+    r[1] = r[4] ^ r[8];
+    r[8] = r[1].rotate_left(7);
+    r[1] = r[11] ^ r[8];
+    r[8] = r[1] ^ POOL[2220];
+    r[8] ^ POOL[2221]
 }
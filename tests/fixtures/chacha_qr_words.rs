@@ -0,0 +1,3662 @@
+// Generated by `cargo run --bin regen_fixtures` — do not edit manually.
+pub const EXPR_DIGEST: [u8; 32] = [
+    0x57, 0x37, 0x8d, 0x1d, 0x35, 0x46, 0xa3, 0x80,
+    0xec, 0x05, 0x00, 0x45, 0xfb, 0xd9, 0xfc, 0xfb,
+    0x5c, 0xfc, 0xa5, 0xb5, 0x4a, 0x98, 0x6c, 0x5c,
+    0x85, 0x42, 0x33, 0x3a, 0xf5, 0x59, 0xc9, 0x48,
+];
+pub fn chacha_qr_words(a: u32, b: u32, c: u32, d: u32) -> [u32; 4] {
+    const POOL: &[u32] = &[
+        0xcd2c6f7f,
+        0xbb2a3fb2,
+        0x430b0604,
+        0x482615ef,
+        0x741b35a4,
+        0xc9accb14,
+        0xade4b6d0,
+        0x958b761d,
+        0xfe6ebc59,
+        0x3ef201cf,
+        0x00000001,
+        0x00000001,
+        0x00000002,
+        0x00000002,
+        0xdee17b11,
+        0x9dfce645,
+        0x5c6c5976,
+        0xc5a0ef11,
+        0xd7a39602,
+        0x25fffc5c,
+        0xde8fe5c5,
+        0xcb30ce1a,
+        0x0aceeffd,
+        0x2243ae90,
+        0x00000004,
+        0x00000004,
+        0x9e0d7fa8,
+        0x21d9db02,
+        0x386c9cfa,
+        0x6bf28e6c,
+        0x5990773e,
+        0xff0ea77d,
+        0x2126e817,
+        0x5e929da0,
+        0x00000008,
+        0x00000008,
+        0x48501808,
+        0x6bfe347b,
+        0x287a9bc0,
+        0xafd44686,
+        0xf552b66e,
+        0xf6ff78ef,
+        0x0879793a,
+        0x1f4976fa,
+        0x00000010,
+        0x00000010,
+        0x34bb13f0,
+        0x381e6892,
+        0x629eadc0,
+        0x97d6c32f,
+        0xcadbf41a,
+        0x75e88c63,
+        0xd108aedb,
+        0xd3c9b7fd,
+        0x00000020,
+        0x00000020,
+        0x30f0a328,
+        0xdc6cd914,
+        0x12493486,
+        0x77dd30e5,
+        0x5f6d584d,
+        0x8f3066bc,
+        0x2e88704b,
+        0xea426b01,
+        0x00000040,
+        0x00000040,
+        0xaf976aab,
+        0x8850fff4,
+        0x9f55357b,
+        0x1f137583,
+        0xe66d4fd1,
+        0x345f0997,
+        0x6aa0e622,
+        0x07d10a3f,
+        0x00000080,
+        0x00000080,
+        0x135f3e73,
+        0x93922a5b,
+        0x61935a25,
+        0x4e9d1432,
+        0x1e150e58,
+        0x7a95e009,
+        0x85dea0dc,
+        0xc7c36581,
+        0x00000100,
+        0x00000100,
+        0x582b922f,
+        0xb86d04c8,
+        0x2b6fb3cd,
+        0xa04d91cd,
+        0x7143007e,
+        0x102ff028,
+        0xaa45d57c,
+        0xbf8f692a,
+        0x00000200,
+        0x00000200,
+        0xecdca315,
+        0x10787f2d,
+        0xd5ff8f4b,
+        0x41440926,
+        0x4edc7d04,
+        0xfa202be2,
+        0x9da70595,
+        0x837c5d6d,
+        0x00000400,
+        0x00000400,
+        0x4c0bcc72,
+        0xa6d6549c,
+        0x89bf384b,
+        0x72e5cd1f,
+        0x404e4995,
+        0x03d8ae10,
+        0x20f44720,
+        0xeecbd294,
+        0x00000800,
+        0x00000800,
+        0xd192ea97,
+        0xc02a68ad,
+        0xcaa57fd9,
+        0x5c23ba00,
+        0x2974f98d,
+        0x9d1ffa92,
+        0x6f76fefc,
+        0x2a546237,
+        0x00001000,
+        0x00001000,
+        0xabf1ae91,
+        0x62f4e8f3,
+        0x9a2a910a,
+        0xcca49d49,
+        0xdf7f0d8e,
+        0x159ba8db,
+        0x99cb623d,
+        0x60930947,
+        0x00002000,
+        0x00002000,
+        0xd0efd947,
+        0xc5916a57,
+        0xe1150f7e,
+        0x3e7b7e07,
+        0x582170dc,
+        0xc0c08aa8,
+        0x6c8a461a,
+        0x1c2bb76e,
+        0x00004000,
+        0x00004000,
+        0x410102c3,
+        0x9f273616,
+        0xf4494190,
+        0xaebbfc29,
+        0x3e34c19f,
+        0x59e3238a,
+        0x4db8d750,
+        0x71baa791,
+        0x00008000,
+        0x00008000,
+        0xe5db98b8,
+        0xca2dd6a3,
+        0xcd6fa50b,
+        0xfd0dcc2e,
+        0x1f1d334d,
+        0xfa077d26,
+        0x0783a57b,
+        0x60a48b73,
+        0x00010000,
+        0x00010000,
+        0x69194a02,
+        0x2a90dc39,
+        0x24cb3a0d,
+        0x7f00c5ab,
+        0x3bb28dde,
+        0xfc2699b7,
+        0xa0d6b85f,
+        0x879e3579,
+        0x00020000,
+        0x00020000,
+        0xffd55ad9,
+        0x55a8892c,
+        0xb2a00188,
+        0x5b6fcfd5,
+        0x97ab989b,
+        0xcab2a0b3,
+        0x45c6ea55,
+        0xf115ac61,
+        0x00040000,
+        0x00040000,
+        0xb254cb87,
+        0xf8b597e4,
+        0x87670bc2,
+        0x9d228d8e,
+        0x6a01310a,
+        0x08c8f3bd,
+        0xaf4b9516,
+        0xc0a55e9c,
+        0x00080000,
+        0x00080000,
+        0xeab81afa,
+        0xffde0d69,
+        0x169df7b4,
+        0xe586af0d,
+        0xbec548fd,
+        0xa7e71c18,
+        0x1ad9b4c2,
+        0x28aeb1bf,
+        0x00100000,
+        0x00100000,
+        0xbe1bb62a,
+        0x2ac13dd1,
+        0x9ba6806e,
+        0x5154eb23,
+        0xd2bb08e2,
+        0x375b2523,
+        0xea9c2654,
+        0xca2f971d,
+        0x00200000,
+        0x00200000,
+        0xba649cea,
+        0xebf40308,
+        0x1c050a9a,
+        0xe18d27bf,
+        0xd563d410,
+        0x8e89bfb9,
+        0x167ffed1,
+        0x018948fb,
+        0x00400000,
+        0x00400000,
+        0x3267ea3d,
+        0x900a252f,
+        0xd116e8a3,
+        0x3e1a91eb,
+        0x93a20846,
+        0x154b75d8,
+        0xf5925a2f,
+        0x827646f4,
+        0x00800000,
+        0x00800000,
+        0x30a0e916,
+        0x62485f06,
+        0xe9cbef6c,
+        0x191d2b11,
+        0x316329d0,
+        0x09edd789,
+        0x83ada725,
+        0x06618385,
+        0x01000000,
+        0x01000000,
+        0x980b1fc7,
+        0x725c62d7,
+        0xabcb7e9f,
+        0x669fc3e7,
+        0x692457af,
+        0x9c2b1c95,
+        0xb49348b5,
+        0x841069c5,
+        0x02000000,
+        0x02000000,
+        0x6c53e9e1,
+        0x6263be6c,
+        0x3d468452,
+        0x9f774eaf,
+        0x8ad41446,
+        0xd0541386,
+        0x6bf6d41f,
+        0x9787e32f,
+        0x04000000,
+        0x04000000,
+        0x5d6cfbb1,
+        0x8e35a4a9,
+        0x18d20cf2,
+        0xf9d2c946,
+        0x3f8d1a21,
+        0xc3fe4c62,
+        0x33f805a9,
+        0x16043616,
+        0x08000000,
+        0x08000000,
+        0x0fd0104d,
+        0xeee5d8eb,
+        0xe4c32a6a,
+        0xb13faf90,
+        0xf39f5c3b,
+        0xe40ce9ae,
+        0x12655759,
+        0x4db0aec6,
+        0x10000000,
+        0x10000000,
+        0x3659f3d5,
+        0xacd14cbb,
+        0x7849750e,
+        0xec2236d8,
+        0x9ea51cb3,
+        0x49876c8c,
+        0x25e3ba5f,
+        0xcc7afa10,
+        0x20000000,
+        0x20000000,
+        0xfbc6d480,
+        0x87b005ce,
+        0xe0dcda69,
+        0xdcf3a8df,
+        0x8f10a011,
+        0xdb8bc196,
+        0xe8316aa0,
+        0x91a3e47f,
+        0x40000000,
+        0x40000000,
+        0x36ea4d61,
+        0x5a6fee6d,
+        0x7a0f9a84,
+        0xd58e944a,
+        0xa656a4cf,
+        0x32e5c923,
+        0xc2395464,
+        0x174cbbc4,
+        0x80000000,
+        0x66a817f7,
+        0x1b6e1a98,
+        0x1dec15a7,
+        0x3367a31f,
+        0xd21f2402,
+        0x12cd93a6,
+        0xfbdad444,
+        0xa134e6ad,
+        0xb9500150,
+        0x00000001,
+        0x00000001,
+        0x00000002,
+        0x00000002,
+        0x4806d463,
+        0x81850aad,
+        0x863ae8c7,
+        0xee7f88eb,
+        0x39838c58,
+        0x9af2c296,
+        0x92112f29,
+        0x1143f20e,
+        0xccebeb2c,
+        0xce5c1b02,
+        0x00000004,
+        0x00000004,
+        0x7f8ad0dd,
+        0xc93ee0ca,
+        0x38c59019,
+        0x91ed5508,
+        0xc87663b3,
+        0x3bd545db,
+        0x7dd28662,
+        0xdc7c2131,
+        0x00000008,
+        0x00000008,
+        0x85729031,
+        0x9be5d92d,
+        0x0ade631d,
+        0xe68ed082,
+        0xfa2bbd72,
+        0x88a3d149,
+        0x66c1463a,
+        0x18487bc6,
+        0x00000010,
+        0x00000010,
+        0xc1e1c809,
+        0xf60022a8,
+        0x01853ebe,
+        0xd3eb9979,
+        0x9ead7556,
+        0x49527f66,
+        0xe19bde2f,
+        0xf0c3edae,
+        0x00000020,
+        0x00000020,
+        0xfc8a2528,
+        0xad14bb9c,
+        0x7dbab48f,
+        0xfcc451a7,
+        0x4cdcce0f,
+        0x970ff281,
+        0xf7f716b5,
+        0x56040fb8,
+        0x00000040,
+        0x00000040,
+        0x58421aee,
+        0x1f2c0625,
+        0x0ff622e3,
+        0x224fd1a3,
+        0xe2ef3a4e,
+        0x7da149ec,
+        0xd7d64dca,
+        0x3cb07a3b,
+        0x00000080,
+        0x00000080,
+        0x9946bde5,
+        0xb5faae23,
+        0x1fd5b244,
+        0xbedaec40,
+        0xbb45cfd9,
+        0x4365c98a,
+        0xcb49a7d1,
+        0x82148e9e,
+        0x00000100,
+        0x00000100,
+        0x7f614465,
+        0xb7220c8f,
+        0xe8944c55,
+        0x787e3045,
+        0xf254bf8c,
+        0xab4a8935,
+        0x79c93306,
+        0x33aa994f,
+        0x00000200,
+        0x00000200,
+        0x4fc6bd72,
+        0x49abf6ad,
+        0xcf017e3b,
+        0x62def82a,
+        0x67b1a8f1,
+        0x02fd55ad,
+        0xac20c8b8,
+        0xa2137303,
+        0x00000400,
+        0x00000400,
+        0xe1feeae5,
+        0x84bbd76e,
+        0xf13ff557,
+        0xd0e049a8,
+        0xe74dce53,
+        0xee87013f,
+        0x9db003b0,
+        0x94d1dbaf,
+        0x00000800,
+        0x00000800,
+        0x371c60ab,
+        0x76fee18b,
+        0x20663df0,
+        0x9e60e908,
+        0xee3579c6,
+        0xd49e40d8,
+        0x5b2f85ce,
+        0x42644530,
+        0x00001000,
+        0x00001000,
+        0x9df059c2,
+        0xf3c756a0,
+        0x2e6a2070,
+        0x7c73c1c5,
+        0xbabbd7eb,
+        0x588fdd3b,
+        0xa26935c2,
+        0x83254098,
+        0x00002000,
+        0x00002000,
+        0x1155f7e0,
+        0x55870dad,
+        0x4aeb6199,
+        0x72a2a1b3,
+        0xd03e8158,
+        0x7d1931c4,
+        0xa31e0b48,
+        0x2ad08af8,
+        0x00004000,
+        0x00004000,
+        0x1d579506,
+        0xfd872b28,
+        0xa5ae8c2f,
+        0xb203f268,
+        0x0369cb56,
+        0x29a4223f,
+        0x6fb39b68,
+        0x34d16d38,
+        0x00008000,
+        0x00008000,
+        0xa72528e9,
+        0x1e5d062f,
+        0xbfd2fc5b,
+        0x863b985c,
+        0x0716470b,
+        0x0e57a183,
+        0x0feb3415,
+        0x8191e87a,
+        0x00010000,
+        0x00010000,
+        0x65f59809,
+        0x23362b8e,
+        0x7e768b1e,
+        0x9f58e53f,
+        0x46b5f48c,
+        0xd6c119bd,
+        0xa8c1d5a8,
+        0x00018338,
+        0x00020000,
+        0x00020000,
+        0xe0253871,
+        0xbf178939,
+        0x60bb2afc,
+        0x00fced19,
+        0x98079290,
+        0xa21606fd,
+        0x05980fd9,
+        0x97ecc07e,
+        0x00040000,
+        0x00040000,
+        0x24229b92,
+        0xebe87e35,
+        0x66dece64,
+        0x1fcec93f,
+        0x82f350d3,
+        0xd0316f86,
+        0xfbd61496,
+        0x2615f36b,
+        0x00080000,
+        0x00080000,
+        0xddf17ac9,
+        0xa1426e6b,
+        0x7ce77629,
+        0xeb9b5533,
+        0x5df55eaf,
+        0x6088b870,
+        0x3d298454,
+        0x9320024f,
+        0x00100000,
+        0x00100000,
+        0xbd74afbc,
+        0x9344b0f8,
+        0x242d26d0,
+        0xd4461181,
+        0xa37e8995,
+        0xc6a145f0,
+        0x6fd2f5f1,
+        0x0acba1ba,
+        0x00200000,
+        0x00200000,
+        0x4e038b8d,
+        0x3ffd3146,
+        0xe01f83d5,
+        0x7fb05f16,
+        0x86956195,
+        0x8a272803,
+        0x9d737088,
+        0x21f75683,
+        0x00400000,
+        0x00400000,
+        0xf3246c2b,
+        0x06797bae,
+        0x53eabfa9,
+        0x22289457,
+        0x3287a10d,
+        0xb084c2d6,
+        0x24f4cbf7,
+        0xaf83068f,
+        0x00800000,
+        0x00800000,
+        0xb3c0f0c6,
+        0x9400bb47,
+        0xce18aebd,
+        0xe377d05b,
+        0x668059d9,
+        0x41aefd37,
+        0xce7641d2,
+        0xe8d676a7,
+        0x01000000,
+        0x01000000,
+        0x230e219c,
+        0x878c0927,
+        0x39ab0a7a,
+        0xcbe0f74a,
+        0x4f254ba9,
+        0x30c45281,
+        0xe2c83be9,
+        0xeab0ddc1,
+        0x02000000,
+        0x02000000,
+        0xa250c46e,
+        0xfa45c569,
+        0x23fc495b,
+        0xb3909210,
+        0x888db01c,
+        0xe3894f12,
+        0x10edb752,
+        0x6cdcfbcf,
+        0x04000000,
+        0x04000000,
+        0x84604602,
+        0x04abf9d5,
+        0x9284ca3c,
+        0xad4ac19e,
+        0x7b1cd65f,
+        0x998adf27,
+        0xf4d97c93,
+        0x5d57a85f,
+        0x08000000,
+        0x08000000,
+        0x7d3743b1,
+        0xf15ca7e9,
+        0x1d2b832f,
+        0xfb55c3a9,
+        0x9c4daca3,
+        0xbc9062af,
+        0xb19da97b,
+        0x0133e35d,
+        0x10000000,
+        0x10000000,
+        0x23187b20,
+        0x5123bb21,
+        0x797132bf,
+        0xfdfe47d2,
+        0x71ec6f24,
+        0x435e86ef,
+        0x39f81b75,
+        0xf557bdfc,
+        0x20000000,
+        0x20000000,
+        0x39c1730d,
+        0x009c18d6,
+        0xb092bba0,
+        0x31535b5d,
+        0xb63df6c5,
+        0xbdaad458,
+        0xa258f2e6,
+        0xff9c07e4,
+        0x40000000,
+        0x40000000,
+        0x6289825a,
+        0xee0ca41a,
+        0x6b98bfbc,
+        0xb2e45cd7,
+        0x6b59a1f4,
+        0xb54fd9bf,
+        0x790be1b7,
+        0x409fc9e4,
+        0x80000000,
+        0x2e1afbb5,
+        0x8036186d,
+        0xc40f9c17,
+        0xedd197e1,
+        0x7e1a6883,
+        0xfb2342f6,
+        0x00000001,
+        0x00000001,
+        0x00000002,
+        0x00000002,
+        0x946f0435,
+        0x96eb81a4,
+        0x0fb9d7f7,
+        0x223add86,
+        0x90630a7d,
+        0xf2b6115c,
+        0x0e1a89ae,
+        0x6e24903a,
+        0x6d034bf2,
+        0x20d1df31,
+        0x00000004,
+        0x00000004,
+        0x005c532e,
+        0x669cd1cf,
+        0x7c52821d,
+        0xcd78ca74,
+        0xae91c607,
+        0xeecb1c03,
+        0x5ac8dafc,
+        0x914d8760,
+        0x00000008,
+        0x00000008,
+        0x25752591,
+        0x7558eeda,
+        0xbc6a764e,
+        0x45a468d1,
+        0xca190275,
+        0xdde793c3,
+        0xfbb92cbb,
+        0x2f5d75a0,
+        0x00000010,
+        0x00000010,
+        0x29163d1a,
+        0x289d463e,
+        0x68cdda15,
+        0x083b40fb,
+        0x9cb83b26,
+        0xcac18325,
+        0x3f3f1932,
+        0x9a77b41b,
+        0x00000020,
+        0x00000020,
+        0x2d5d0c98,
+        0xf4807ce5,
+        0x61273a23,
+        0xbaaf74ba,
+        0xba289a8d,
+        0xc764a7f0,
+        0xc5b67703,
+        0x6e9f3f8a,
+        0x00000040,
+        0x00000040,
+        0x8b828615,
+        0xa9a63b26,
+        0xd8445383,
+        0x68daa267,
+        0xdd2ec2bf,
+        0xdc961ad3,
+        0xfbd836dc,
+        0x3c974bfc,
+        0x00000080,
+        0x00000080,
+        0xdfa547cd,
+        0xcffd4977,
+        0x7fc280f4,
+        0x86b9239c,
+        0x67bc638b,
+        0xf3efde12,
+        0xfbc93357,
+        0xd8f61e5a,
+        0x00000100,
+        0x00000100,
+        0x30dd90fa,
+        0x1b5633ea,
+        0xcdb9c012,
+        0xe312205d,
+        0xb4afaa8c,
+        0xa3a3d5f6,
+        0xf13e1d78,
+        0xa2679db5,
+        0x00000200,
+        0x00000200,
+        0xcceb9cfa,
+        0xcfff3ca8,
+        0xb2309632,
+        0xbfa50a71,
+        0x44f65bec,
+        0x9e61393a,
+        0x6bb354b6,
+        0x4429fbbb,
+        0x00000400,
+        0x00000400,
+        0x4939d475,
+        0xd706531c,
+        0x084654dc,
+        0x370f0a30,
+        0x8f43f146,
+        0xedd29f21,
+        0xf4e8bdd2,
+        0xfc3af34e,
+        0x00000800,
+        0x00000800,
+        0x83148a6f,
+        0x128ce8e0,
+        0xeb74dbb0,
+        0x92b32c3b,
+        0x6e0af594,
+        0xb61d31fa,
+        0xa2fb7551,
+        0x7870209b,
+        0x00001000,
+        0x00001000,
+        0xf8608973,
+        0x932ee796,
+        0x61e22de1,
+        0xa8a04621,
+        0x7bb02615,
+        0xe752af20,
+        0x964eca31,
+        0xa89e7456,
+        0x00002000,
+        0x00002000,
+        0x46b95893,
+        0x8467607f,
+        0xf11afca9,
+        0x3c97c5b6,
+        0x6ffd7b9f,
+        0xa5bf25e9,
+        0xf9869a33,
+        0x01ed75ea,
+        0x00004000,
+        0x00004000,
+        0x0782d602,
+        0x26a9f9cf,
+        0x1890b63b,
+        0x16b6e75c,
+        0x3e1db78f,
+        0xe7f3505c,
+        0xe0553e25,
+        0x17aaf7b9,
+        0x00008000,
+        0x00008000,
+        0x3d4029c8,
+        0x525b9274,
+        0xee48ea1d,
+        0xe53bdc2e,
+        0xb16cfa2e,
+        0x03484f60,
+        0x337764ef,
+        0x3b24a6ba,
+        0x00010000,
+        0x00010000,
+        0xaa94f5ba,
+        0x10afb66c,
+        0x4d207151,
+        0x43e94b84,
+        0x74b14928,
+        0xa3f50528,
+        0x205e7e87,
+        0xf475d098,
+        0x00020000,
+        0x00020000,
+        0x7af6cad3,
+        0xdb63063c,
+        0x58f0183a,
+        0xe5021575,
+        0x208b718f,
+        0x7f629288,
+        0xa68c37d2,
+        0x15ab4522,
+        0x00040000,
+        0x00040000,
+        0x69745fa7,
+        0xaa23e0fb,
+        0x4911894b,
+        0xb007c78a,
+        0x4048d8b3,
+        0xdcdf01c6,
+        0x16d5ef62,
+        0xfd03f5eb,
+        0x00080000,
+        0x00080000,
+        0x667aa775,
+        0x95499aea,
+        0x6bd66f9e,
+        0x99a01a75,
+        0xb0c3989a,
+        0xa46fc9c0,
+        0x8c49035b,
+        0x0d9db265,
+        0x00100000,
+        0x00100000,
+        0xf64719b9,
+        0x19931764,
+        0xb03e3df1,
+        0x4aa1ff03,
+        0x3cf70cb5,
+        0x45285b5c,
+        0x262564c5,
+        0x62ab1ddd,
+        0x00200000,
+        0x00200000,
+        0x55aca3c8,
+        0x83930a14,
+        0xf823c016,
+        0x0145d61b,
+        0x76081dbc,
+        0x85ccfb69,
+        0xddd88f1f,
+        0xbd8f2dec,
+        0x00400000,
+        0x00400000,
+        0x8dee92f8,
+        0xe94c86f6,
+        0xc7690e36,
+        0xaa2cae4a,
+        0x33af4234,
+        0x3ad1c1b5,
+        0xaab599b9,
+        0x535284a3,
+        0x00800000,
+        0x00800000,
+        0x76891bf1,
+        0x4ff415a6,
+        0xa4a0a886,
+        0x39b325ef,
+        0x2e5c0112,
+        0x3512766a,
+        0x8693d1a9,
+        0x4133d2bf,
+        0x01000000,
+        0x01000000,
+        0xb3def820,
+        0x2cc13b78,
+        0xf22818e1,
+        0x3c791661,
+        0xe24c6fa5,
+        0xb7946f5b,
+        0x39efdb47,
+        0x04a25d39,
+        0x02000000,
+        0x02000000,
+        0x495a1709,
+        0xda54e9dc,
+        0xf2c8403a,
+        0x7c5ba212,
+        0xf384f715,
+        0xbb81d01b,
+        0x2bc399e1,
+        0x70a2e955,
+        0x04000000,
+        0x04000000,
+        0x147f04b6,
+        0x168f235d,
+        0x4bf30d70,
+        0x0b7a3d3f,
+        0x6f3b8d19,
+        0xbece9610,
+        0x98f63192,
+        0x788bd48b,
+        0x08000000,
+        0x08000000,
+        0x6e7e17b6,
+        0xffaea214,
+        0xcc14153d,
+        0xe9ea2493,
+        0x028a0681,
+        0x29192d16,
+        0x7e578b08,
+        0x060a0032,
+        0x10000000,
+        0x10000000,
+        0x6c7626e2,
+        0xd4a39c94,
+        0x10a952d6,
+        0x8d8584ef,
+        0x45d0c23c,
+        0x069d07be,
+        0xeb312d22,
+        0xc0dc0d65,
+        0x20000000,
+        0x20000000,
+        0x48001466,
+        0xd9517335,
+        0x071ca81d,
+        0x42b277e8,
+        0x217ccb31,
+        0xfe688f3a,
+        0x69598b45,
+        0x362626da,
+        0x40000000,
+        0x40000000,
+        0x8d4ec040,
+        0xd79c0e06,
+        0xa5ba5be4,
+        0xc1498d99,
+        0x7a596b67,
+        0xb1147d6b,
+        0x342583ae,
+        0x08fd8976,
+        0x80000000,
+        0x2dcb5b87,
+        0xd02fe53a,
+        0x3d67a522,
+        0x74c5b9a3,
+        0xc9965401,
+        0xba4f903b,
+        0x00000001,
+        0x00000001,
+        0x00000002,
+        0x00000002,
+        0xbcc04082,
+        0x9d19fc55,
+        0x629a1538,
+        0xffd269f1,
+        0xf5a9c8ab,
+        0x70086b07,
+        0x4af7e0e2,
+        0xd9ff9019,
+        0xd04bd916,
+        0xe97bbaa6,
+        0x00000004,
+        0x00000004,
+        0xc8d05940,
+        0x3d88d687,
+        0x0f0d2956,
+        0xc4b70d3a,
+        0xe816d371,
+        0x50d54dee,
+        0x4296380a,
+        0x6d1c42ff,
+        0x00000008,
+        0x00000008,
+        0xae90db6e,
+        0xf51a77ee,
+        0x1c9a5e48,
+        0x664951b4,
+        0x1cdbdcb2,
+        0x6d4b2007,
+        0x36800e7d,
+        0x675f03e3,
+        0x00000010,
+        0x00000010,
+        0x7dbf6844,
+        0x61b856ec,
+        0xe8a6d04d,
+        0x0428eb32,
+        0x675c350e,
+        0x2dffcc27,
+        0xbe0217cc,
+        0x7b3d4fff,
+        0x00000020,
+        0x00000020,
+        0x406b9e00,
+        0x01e4056b,
+        0x753b15e2,
+        0xab9273bf,
+        0x33f99a0d,
+        0x84a11af6,
+        0x83ec0e72,
+        0xb373d4d0,
+        0x00000040,
+        0x00000040,
+        0xe2e643fc,
+        0xb1f0e277,
+        0x32700af2,
+        0xdc5d4871,
+        0x3daa5b57,
+        0x94154127,
+        0xc8d9b109,
+        0x1a516e49,
+        0x00000080,
+        0x00000080,
+        0x71a29817,
+        0x6e735561,
+        0x2151c5ee,
+        0x481adeab,
+        0xedeaa7bf,
+        0x8e9cd44e,
+        0x5df67be9,
+        0x12f45b86,
+        0x00000100,
+        0x00000100,
+        0x4239587f,
+        0xca14defc,
+        0x61122f90,
+        0x5c5279f2,
+        0x579ffa83,
+        0x12ffde34,
+        0xac5f8da4,
+        0xa3e7031a,
+        0x00000200,
+        0x00000200,
+        0x42d14230,
+        0xd5dcfa19,
+        0x52f74979,
+        0x770dddb6,
+        0x88133c21,
+        0x615f6b84,
+        0x2cb6a4f5,
+        0xf1703f16,
+        0x00000400,
+        0x00000400,
+        0x9381ccd5,
+        0x6119d77e,
+        0x0ac4493c,
+        0x443f05b2,
+        0x546bccea,
+        0xb0b81d4c,
+        0x1c8f8331,
+        0xb0ba6c77,
+        0x00000800,
+        0x00000800,
+        0xc01d3f38,
+        0x76069193,
+        0x8536beea,
+        0xbcfdee54,
+        0x6fa07db0,
+        0xa0270a65,
+        0xfcaa6f94,
+        0x7b3d2977,
+        0x00001000,
+        0x00001000,
+        0x0bc922bb,
+        0x8c8fff6b,
+        0xad9f7110,
+        0x07ffe655,
+        0x6808d5ff,
+        0x539f81c1,
+        0x114ee8fe,
+        0x8378690b,
+        0x00002000,
+        0x00002000,
+        0x297281ce,
+        0xebe4a7d2,
+        0x781ce867,
+        0xfc645c03,
+        0x85856680,
+        0x1971e0ac,
+        0x267e6857,
+        0xe11309d1,
+        0x00004000,
+        0x00004000,
+        0x9f78e3e1,
+        0xffe1cd5d,
+        0xfbe35c2f,
+        0x3ced1798,
+        0x1546c988,
+        0x39a45d1d,
+        0xb798a606,
+        0x6a0012fe,
+        0x00008000,
+        0x00008000,
+        0xb8cc18c2,
+        0x30e1ccb4,
+        0x42dfc664,
+        0x9c8f0969,
+        0xbb048058,
+        0xad113d2f,
+        0xdce7af65,
+        0xa4d32308,
+        0x00010000,
+        0x00010000,
+        0x1c29456a,
+        0xd3e6e1ee,
+        0x0bd57d7f,
+        0xd5f55bb3,
+        0xc577a16a,
+        0x39f582ea,
+        0x3899fa7b,
+        0x5afb5ca7,
+        0x00020000,
+        0x00020000,
+        0xda26cf78,
+        0xff7ce878,
+        0x405a1de5,
+        0x1e8d390f,
+        0x0e1bafd4,
+        0x1ddd6c90,
+        0x76c6f9a1,
+        0x8f940ad9,
+        0x00040000,
+        0x00040000,
+        0xce06bb31,
+        0x887477a6,
+        0xf3410315,
+        0xf6e8cacf,
+        0x23c6102e,
+        0x8cefa874,
+        0x1a1e77d8,
+        0x4df23c37,
+        0x00080000,
+        0x00080000,
+        0xa67841df,
+        0xd88e3d3f,
+        0xbc6d97c0,
+        0x4d2f24d3,
+        0x6378fc8f,
+        0xbab1a797,
+        0x1b5ab038,
+        0x934d5796,
+        0x00100000,
+        0x00100000,
+        0x60d8f5c6,
+        0x1ae42255,
+        0x157558b4,
+        0x2f35e31f,
+        0x04eb3351,
+        0x2d0b2f66,
+        0x46b99310,
+        0x45241636,
+        0x00200000,
+        0x00200000,
+        0x4eb668fb,
+        0xe777686d,
+        0xabdce76e,
+        0xe5c52471,
+        0x67647435,
+        0x7ca7f4be,
+        0x19fe6773,
+        0x2f50d65a,
+        0x00400000,
+        0x00400000,
+        0x7d9e971e,
+        0xadfc2b1d,
+        0x895a26aa,
+        0x7b3dbe20,
+        0x1b895305,
+        0x9f543bc3,
+        0xdde5f26f,
+        0x310ab32c,
+        0x00800000,
+        0x00800000,
+        0x0ae5b3b6,
+        0xa62bf137,
+        0x8e8c1d7e,
+        0x6d42b84b,
+        0x9ccc3a7a,
+        0xfbf12cae,
+        0x457f492b,
+        0x1fcc3dc5,
+        0x01000000,
+        0x01000000,
+        0x3bf2b65b,
+        0x644d7d6e,
+        0x5e5d90c3,
+        0xe5bb70e1,
+        0x74783fec,
+        0xab0850e1,
+        0xde9234fb,
+        0x43d37d3b,
+        0x02000000,
+        0x02000000,
+        0x3675c777,
+        0x0f3b997f,
+        0xb9e99938,
+        0x8fc18abc,
+        0xc8c872cf,
+        0xa68bbe90,
+        0xece40b6f,
+        0x0a361d57,
+        0x04000000,
+        0x04000000,
+        0x8b40a775,
+        0xb554f885,
+        0xf16326fc,
+        0x007f7f65,
+        0x4bc05785,
+        0x4e7c7d46,
+        0xcecb53cf,
+        0x94183239,
+        0x08000000,
+        0x08000000,
+        0xefaf273f,
+        0x050073fb,
+        0x6b84dbd7,
+        0x71c43e1b,
+        0x79820641,
+        0xf95301b1,
+        0x09fa88e3,
+        0x6cdda6f0,
+        0x10000000,
+        0x10000000,
+        0x6ee10ee4,
+        0xd42530a8,
+        0x637555eb,
+        0x08b09796,
+        0x524fbaae,
+        0x1369d7de,
+        0x989706d7,
+        0xba83f465,
+        0x20000000,
+        0x20000000,
+        0x15c5b48c,
+        0x991bb2d1,
+        0xbb948e73,
+        0xe4e0e444,
+        0x5cd0c452,
+        0x0a364653,
+        0x61ac0a2f,
+        0x85db0112,
+        0x40000000,
+        0x40000000,
+        0x786fba90,
+        0x314759db,
+        0x633d8e58,
+        0x7b61047b,
+        0xaf68337b,
+        0xd2f5dc5a,
+        0x57888232,
+        0xd94c63fa,
+        0x80000000,
+        0xa7774d8a,
+        0x0e1645a4,
+        0xcf9b79a0,
+        0x331e00f1,
+        0x84178e53,
+        0x99eb08bc,
+        0x199f5031,
+        0x9612eda2,
+        0x8a53c5a0,
+        0x232a94e2,
+        0xf5c64011,
+    ];
+    let mut r = [0u32; 10];
+    r[2] = a ^ POOL[0];
+    r[3] = b ^ POOL[1];
+    r[5] = r[2] ^ POOL[2];
+    r[8] = r[5] ^ POOL[3];
+    r[5] = r[3] ^ POOL[4];
+    r[0] = r[5] ^ POOL[5];
+    r[5] = {
+        let (t, s) = (POOL[6], POOL[7]);
+        let (ma, mb) = (t ^ s ^ POOL[8], t ^ s ^ POOL[9]);
+        let mut z = t;
+        z ^= r[8] & mb;
+        z ^= r[0] & ma;
+        z ^= r[8] & r[0];
+        z
+    };
+    r[8] = r[2] ^ r[3];
+    r[2] = r[8] & POOL[10];
+    r[0] = r[5] & POOL[11];
+    r[9] = r[0].rotate_left(1);
+    r[0] = r[8] & POOL[12];
+    r[4] = r[0] ^ r[9];
+    r[7] = r[2] ^ r[4];
+    r[2] = r[5] & POOL[13];
+    r[4] = r[0] ^ POOL[14];
+    r[0] = r[4] ^ POOL[15];
+    r[4] = r[0] ^ POOL[16];
+    r[0] = r[9] ^ POOL[17];
+    r[9] = r[0] ^ POOL[18];
+    r[0] = r[9] ^ POOL[19];
+    r[9] = {
+        let (t, s) = (POOL[20], POOL[21]);
+        let (ma, mb) = (t ^ s ^ POOL[22], t ^ s ^ POOL[23]);
+        let mut z = t;
+        z ^= r[4] & mb;
+        z ^= r[0] & ma;
+        z ^= r[4] & r[0];
+        z
+    };
+    r[0] = r[2] ^ r[9];
+    r[2] = r[0].rotate_left(1);
+    r[0] = r[8] & POOL[24];
+    r[9] = r[0] ^ r[2];
+    r[4] = r[7] ^ r[9];
+    r[9] = r[5] & POOL[25];
+    r[7] = r[0] ^ POOL[26];
+    r[0] = r[7] ^ POOL[27];
+    r[7] = r[0] ^ POOL[28];
+    r[0] = r[2] ^ POOL[29];
+    r[2] = {
+        let (t, s) = (POOL[30], POOL[31]);
+        let (ma, mb) = (t ^ s ^ POOL[32], t ^ s ^ POOL[33]);
+        let mut z = t;
+        z ^= r[7] & mb;
+        z ^= r[0] & ma;
+        z ^= r[7] & r[0];
+        z
+    };
+    r[0] = r[9] ^ r[2];
+    r[2] = r[0].rotate_left(1);
+    r[0] = r[8] & POOL[34];
+    r[9] = r[0] ^ r[2];
+    r[7] = r[4] ^ r[9];
+    r[9] = r[5] & POOL[35];
+    r[4] = r[0] ^ POOL[36];
+    r[0] = r[4] ^ POOL[37];
+    r[4] = r[0] ^ POOL[38];
+    r[0] = r[2] ^ POOL[39];
+    r[2] = {
+        let (t, s) = (POOL[40], POOL[41]);
+        let (ma, mb) = (t ^ s ^ POOL[42], t ^ s ^ POOL[43]);
+        let mut z = t;
+        z ^= r[4] & mb;
+        z ^= r[0] & ma;
+        z ^= r[4] & r[0];
+        z
+    };
+    r[0] = r[9] ^ r[2];
+    r[2] = r[0].rotate_left(1);
+    r[0] = r[8] & POOL[44];
+    r[9] = r[0] ^ r[2];
+    r[4] = r[7] ^ r[9];
+    r[9] = r[5] & POOL[45];
+    r[7] = r[0] ^ POOL[46];
+    r[0] = r[7] ^ POOL[47];
+    r[7] = r[0] ^ POOL[48];
+    r[0] = r[2] ^ POOL[49];
+    r[2] = {
+        let (t, s) = (POOL[50], POOL[51]);
+        let (ma, mb) = (t ^ s ^ POOL[52], t ^ s ^ POOL[53]);
+        let mut z = t;
+        z ^= r[7] & mb;
+        z ^= r[0] & ma;
+        z ^= r[7] & r[0];
+        z
+    };
+    r[0] = r[9] ^ r[2];
+    r[2] = r[0].rotate_left(1);
+    r[0] = r[8] & POOL[54];
+    r[9] = r[0] ^ r[2];
+    r[7] = r[4] ^ r[9];
+    r[9] = r[5] & POOL[55];
+    r[4] = r[0] ^ POOL[56];
+    r[0] = r[4] ^ POOL[57];
+    r[4] = r[0] ^ POOL[58];
+    r[0] = r[2] ^ POOL[59];
+    r[2] = {
+        let (t, s) = (POOL[60], POOL[61]);
+        let (ma, mb) = (t ^ s ^ POOL[62], t ^ s ^ POOL[63]);
+        let mut z = t;
+        z ^= r[4] & mb;
+        z ^= r[0] & ma;
+        z ^= r[4] & r[0];
+        z
+    };
+    r[0] = r[9] ^ r[2];
+    r[2] = r[0].rotate_left(1);
+    r[0] = r[8] & POOL[64];
+    r[9] = r[0] ^ r[2];
+    r[4] = r[7] ^ r[9];
+    r[9] = r[5] & POOL[65];
+    r[7] = r[0] ^ POOL[66];
+    r[0] = r[7] ^ POOL[67];
+    r[7] = r[0] ^ POOL[68];
+    r[0] = r[2] ^ POOL[69];
+    r[2] = {
+        let (t, s) = (POOL[70], POOL[71]);
+        let (ma, mb) = (t ^ s ^ POOL[72], t ^ s ^ POOL[73]);
+        let mut z = t;
+        z ^= r[7] & mb;
+        z ^= r[0] & ma;
+        z ^= r[7] & r[0];
+        z
+    };
+    r[0] = r[9] ^ r[2];
+    r[2] = r[0].rotate_left(1);
+    r[0] = r[8] & POOL[74];
+    r[9] = r[0] ^ r[2];
+    r[7] = r[4] ^ r[9];
+    r[9] = r[5] & POOL[75];
+    r[4] = r[0] ^ POOL[76];
+    r[0] = r[4] ^ POOL[77];
+    r[4] = r[0] ^ POOL[78];
+    r[0] = r[2] ^ POOL[79];
+    r[2] = {
+        let (t, s) = (POOL[80], POOL[81]);
+        let (ma, mb) = (t ^ s ^ POOL[82], t ^ s ^ POOL[83]);
+        let mut z = t;
+        z ^= r[4] & mb;
+        z ^= r[0] & ma;
+        z ^= r[4] & r[0];
+        z
+    };
+    r[0] = r[9] ^ r[2];
+    r[2] = r[0].rotate_left(1);
+    r[0] = r[8] & POOL[84];
+    r[9] = r[0] ^ r[2];
+    r[4] = r[7] ^ r[9];
+    r[9] = r[5] & POOL[85];
+    r[7] = r[0] ^ POOL[86];
+    r[0] = r[7] ^ POOL[87];
+    r[7] = r[0] ^ POOL[88];
+    r[0] = r[2] ^ POOL[89];
+    r[2] = {
+        let (t, s) = (POOL[90], POOL[91]);
+        let (ma, mb) = (t ^ s ^ POOL[92], t ^ s ^ POOL[93]);
+        let mut z = t;
+        z ^= r[7] & mb;
+        z ^= r[0] & ma;
+        z ^= r[7] & r[0];
+        z
+    };
+    r[0] = r[9] ^ r[2];
+    r[2] = r[0].rotate_left(1);
+    r[0] = r[8] & POOL[94];
+    r[9] = r[0] ^ r[2];
+    r[7] = r[4] ^ r[9];
+    r[9] = r[5] & POOL[95];
+    r[4] = r[0] ^ POOL[96];
+    r[0] = r[4] ^ POOL[97];
+    r[4] = r[0] ^ POOL[98];
+    r[0] = r[2] ^ POOL[99];
+    r[2] = {
+        let (t, s) = (POOL[100], POOL[101]);
+        let (ma, mb) = (t ^ s ^ POOL[102], t ^ s ^ POOL[103]);
+        let mut z = t;
+        z ^= r[4] & mb;
+        z ^= r[0] & ma;
+        z ^= r[4] & r[0];
+        z
+    };
+    r[0] = r[9] ^ r[2];
+    r[2] = r[0].rotate_left(1);
+    r[0] = r[8] & POOL[104];
+    r[9] = r[0] ^ r[2];
+    r[4] = r[7] ^ r[9];
+    r[9] = r[5] & POOL[105];
+    r[7] = r[0] ^ POOL[106];
+    r[0] = r[7] ^ POOL[107];
+    r[7] = r[0] ^ POOL[108];
+    r[0] = r[2] ^ POOL[109];
+    r[2] = {
+        let (t, s) = (POOL[110], POOL[111]);
+        let (ma, mb) = (t ^ s ^ POOL[112], t ^ s ^ POOL[113]);
+        let mut z = t;
+        z ^= r[7] & mb;
+        z ^= r[0] & ma;
+        z ^= r[7] & r[0];
+        z
+    };
+    r[0] = r[9] ^ r[2];
+    r[2] = r[0].rotate_left(1);
+    r[0] = r[8] & POOL[114];
+    r[9] = r[0] ^ r[2];
+    r[7] = r[4] ^ r[9];
+    r[9] = r[5] & POOL[115];
+    r[4] = r[0] ^ POOL[116];
+    r[0] = r[4] ^ POOL[117];
+    r[4] = r[0] ^ POOL[118];
+    r[0] = r[2] ^ POOL[119];
+    r[2] = {
+        let (t, s) = (POOL[120], POOL[121]);
+        let (ma, mb) = (t ^ s ^ POOL[122], t ^ s ^ POOL[123]);
+        let mut z = t;
+        z ^= r[4] & mb;
+        z ^= r[0] & ma;
+        z ^= r[4] & r[0];
+        z
+    };
+    r[0] = r[9] ^ r[2];
+    r[2] = r[0].rotate_left(1);
+    r[0] = r[8] & POOL[124];
+    r[9] = r[0] ^ r[2];
+    r[4] = r[7] ^ r[9];
+    r[9] = r[5] & POOL[125];
+    r[7] = r[0] ^ POOL[126];
+    r[0] = r[7] ^ POOL[127];
+    r[7] = r[0] ^ POOL[128];
+    r[0] = r[2] ^ POOL[129];
+    r[2] = {
+        let (t, s) = (POOL[130], POOL[131]);
+        let (ma, mb) = (t ^ s ^ POOL[132], t ^ s ^ POOL[133]);
+        let mut z = t;
+        z ^= r[7] & mb;
+        z ^= r[0] & ma;
+        z ^= r[7] & r[0];
+        z
+    };
+    r[0] = r[9] ^ r[2];
+    r[2] = r[0].rotate_left(1);
+    r[0] = r[8] & POOL[134];
+    r[9] = r[0] ^ r[2];
+    r[7] = r[4] ^ r[9];
+    r[9] = r[5] & POOL[135];
+    r[4] = r[0] ^ POOL[136];
+    r[0] = r[4] ^ POOL[137];
+    r[4] = r[0] ^ POOL[138];
+    r[0] = r[2] ^ POOL[139];
+    r[2] = {
+        let (t, s) = (POOL[140], POOL[141]);
+        let (ma, mb) = (t ^ s ^ POOL[142], t ^ s ^ POOL[143]);
+        let mut z = t;
+        z ^= r[4] & mb;
+        z ^= r[0] & ma;
+        z ^= r[4] & r[0];
+        z
+    };
+    r[0] = r[9] ^ r[2];
+    r[2] = r[0].rotate_left(1);
+    r[0] = r[8] & POOL[144];
+    r[9] = r[0] ^ r[2];
+    r[4] = r[7] ^ r[9];
+    r[9] = r[5] & POOL[145];
+    r[7] = r[0] ^ POOL[146];
+    r[0] = r[7] ^ POOL[147];
+    r[7] = r[0] ^ POOL[148];
+    r[0] = r[2] ^ POOL[149];
+    r[2] = {
+        let (t, s) = (POOL[150], POOL[151]);
+        let (ma, mb) = (t ^ s ^ POOL[152], t ^ s ^ POOL[153]);
+        let mut z = t;
+        z ^= r[7] & mb;
+        z ^= r[0] & ma;
+        z ^= r[7] & r[0];
+        z
+    };
+    r[0] = r[9] ^ r[2];
+    r[2] = r[0].rotate_left(1);
+    r[0] = r[8] & POOL[154];
+    r[9] = r[0] ^ r[2];
+    r[7] = r[4] ^ r[9];
+    r[9] = r[5] & POOL[155];
+    r[4] = r[0] ^ POOL[156];
+    r[0] = r[4] ^ POOL[157];
+    r[4] = r[0] ^ POOL[158];
+    r[0] = r[2] ^ POOL[159];
+    r[2] = {
+        let (t, s) = (POOL[160], POOL[161]);
+        let (ma, mb) = (t ^ s ^ POOL[162], t ^ s ^ POOL[163]);
+        let mut z = t;
+        z ^= r[4] & mb;
+        z ^= r[0] & ma;
+        z ^= r[4] & r[0];
+        z
+    };
+    r[0] = r[9] ^ r[2];
+    r[2] = r[0].rotate_left(1);
+    r[0] = r[8] & POOL[164];
+    r[9] = r[0] ^ r[2];
+    r[4] = r[7] ^ r[9];
+    r[9] = r[5] & POOL[165];
+    r[7] = r[0] ^ POOL[166];
+    r[0] = r[7] ^ POOL[167];
+    r[7] = r[0] ^ POOL[168];
+    r[0] = r[2] ^ POOL[169];
+    r[2] = {
+        let (t, s) = (POOL[170], POOL[171]);
+        let (ma, mb) = (t ^ s ^ POOL[172], t ^ s ^ POOL[173]);
+        let mut z = t;
+        z ^= r[7] & mb;
+        z ^= r[0] & ma;
+        z ^= r[7] & r[0];
+        z
+    };
+    r[0] = r[9] ^ r[2];
+    r[2] = r[0].rotate_left(1);
+    r[0] = r[8] & POOL[174];
+    r[9] = r[0] ^ r[2];
+    r[7] = r[4] ^ r[9];
+    r[9] = r[5] & POOL[175];
+    r[4] = r[0] ^ POOL[176];
+    r[0] = r[4] ^ POOL[177];
+    r[4] = r[0] ^ POOL[178];
+    r[0] = r[2] ^ POOL[179];
+    r[2] = {
+        let (t, s) = (POOL[180], POOL[181]);
+        let (ma, mb) = (t ^ s ^ POOL[182], t ^ s ^ POOL[183]);
+        let mut z = t;
+        z ^= r[4] & mb;
+        z ^= r[0] & ma;
+        z ^= r[4] & r[0];
+        z
+    };
+    r[0] = r[9] ^ r[2];
+    r[2] = r[0].rotate_left(1);
+    r[0] = r[8] & POOL[184];
+    r[9] = r[0] ^ r[2];
+    r[4] = r[7] ^ r[9];
+    r[9] = r[5] & POOL[185];
+    r[7] = r[0] ^ POOL[186];
+    r[0] = r[7] ^ POOL[187];
+    r[7] = r[0] ^ POOL[188];
+    r[0] = r[2] ^ POOL[189];
+    r[2] = {
+        let (t, s) = (POOL[190], POOL[191]);
+        let (ma, mb) = (t ^ s ^ POOL[192], t ^ s ^ POOL[193]);
+        let mut z = t;
+        z ^= r[7] & mb;
+        z ^= r[0] & ma;
+        z ^= r[7] & r[0];
+        z
+    };
+    r[0] = r[9] ^ r[2];
+    r[2] = r[0].rotate_left(1);
+    r[0] = r[8] & POOL[194];
+    r[9] = r[0] ^ r[2];
+    r[7] = r[4] ^ r[9];
+    r[9] = r[5] & POOL[195];
+    r[4] = r[0] ^ POOL[196];
+    r[0] = r[4] ^ POOL[197];
+    r[4] = r[0] ^ POOL[198];
+    r[0] = r[2] ^ POOL[199];
+    r[2] = {
+        let (t, s) = (POOL[200], POOL[201]);
+        let (ma, mb) = (t ^ s ^ POOL[202], t ^ s ^ POOL[203]);
+        let mut z = t;
+        z ^= r[4] & mb;
+        z ^= r[0] & ma;
+        z ^= r[4] & r[0];
+        z
+    };
+    r[0] = r[9] ^ r[2];
+    r[2] = r[0].rotate_left(1);
+    r[0] = r[8] & POOL[204];
+    r[9] = r[0] ^ r[2];
+    r[4] = r[7] ^ r[9];
+    r[9] = r[5] & POOL[205];
+    r[7] = r[0] ^ POOL[206];
+    r[0] = r[7] ^ POOL[207];
+    r[7] = r[0] ^ POOL[208];
+    r[0] = r[2] ^ POOL[209];
+    r[2] = {
+        let (t, s) = (POOL[210], POOL[211]);
+        let (ma, mb) = (t ^ s ^ POOL[212], t ^ s ^ POOL[213]);
+        let mut z = t;
+        z ^= r[7] & mb;
+        z ^= r[0] & ma;
+        z ^= r[7] & r[0];
+        z
+    };
+    r[0] = r[9] ^ r[2];
+    r[2] = r[0].rotate_left(1);
+    r[0] = r[8] & POOL[214];
+    r[9] = r[0] ^ r[2];
+    r[7] = r[4] ^ r[9];
+    r[9] = r[5] & POOL[215];
+    r[4] = r[0] ^ POOL[216];
+    r[0] = r[4] ^ POOL[217];
+    r[4] = r[0] ^ POOL[218];
+    r[0] = r[2] ^ POOL[219];
+    r[2] = {
+        let (t, s) = (POOL[220], POOL[221]);
+        let (ma, mb) = (t ^ s ^ POOL[222], t ^ s ^ POOL[223]);
+        let mut z = t;
+        z ^= r[4] & mb;
+        z ^= r[0] & ma;
+        z ^= r[4] & r[0];
+        z
+    };
+    r[0] = r[9] ^ r[2];
+    r[2] = r[0].rotate_left(1);
+    r[0] = r[8] & POOL[224];
+    r[9] = r[0] ^ r[2];
+    r[4] = r[7] ^ r[9];
+    r[9] = r[5] & POOL[225];
+    r[7] = r[0] ^ POOL[226];
+    r[0] = r[7] ^ POOL[227];
+    r[7] = r[0] ^ POOL[228];
+    r[0] = r[2] ^ POOL[229];
+    r[2] = {
+        let (t, s) = (POOL[230], POOL[231]);
+        let (ma, mb) = (t ^ s ^ POOL[232], t ^ s ^ POOL[233]);
+        let mut z = t;
+        z ^= r[7] & mb;
+        z ^= r[0] & ma;
+        z ^= r[7] & r[0];
+        z
+    };
+    r[0] = r[9] ^ r[2];
+    r[2] = r[0].rotate_left(1);
+    r[0] = r[8] & POOL[234];
+    r[9] = r[0] ^ r[2];
+    r[7] = r[4] ^ r[9];
+    r[9] = r[5] & POOL[235];
+    r[4] = r[0] ^ POOL[236];
+    r[0] = r[4] ^ POOL[237];
+    r[4] = r[0] ^ POOL[238];
+    r[0] = r[2] ^ POOL[239];
+    r[2] = {
+        let (t, s) = (POOL[240], POOL[241]);
+        let (ma, mb) = (t ^ s ^ POOL[242], t ^ s ^ POOL[243]);
+        let mut z = t;
+        z ^= r[4] & mb;
+        z ^= r[0] & ma;
+        z ^= r[4] & r[0];
+        z
+    };
+    r[0] = r[9] ^ r[2];
+    r[2] = r[0].rotate_left(1);
+    r[0] = r[8] & POOL[244];
+    r[9] = r[0] ^ r[2];
+    r[4] = r[7] ^ r[9];
+    r[9] = r[5] & POOL[245];
+    r[7] = r[0] ^ POOL[246];
+    r[0] = r[7] ^ POOL[247];
+    r[7] = r[0] ^ POOL[248];
+    r[0] = r[2] ^ POOL[249];
+    r[2] = {
+        let (t, s) = (POOL[250], POOL[251]);
+        let (ma, mb) = (t ^ s ^ POOL[252], t ^ s ^ POOL[253]);
+        let mut z = t;
+        z ^= r[7] & mb;
+        z ^= r[0] & ma;
+        z ^= r[7] & r[0];
+        z
+    };
+    r[0] = r[9] ^ r[2];
+    r[2] = r[0].rotate_left(1);
+    r[0] = r[8] & POOL[254];
+    r[9] = r[0] ^ r[2];
+    r[7] = r[4] ^ r[9];
+    r[9] = r[5] & POOL[255];
+    r[4] = r[0] ^ POOL[256];
+    r[0] = r[4] ^ POOL[257];
+    r[4] = r[0] ^ POOL[258];
+    r[0] = r[2] ^ POOL[259];
+    r[2] = {
+        let (t, s) = (POOL[260], POOL[261]);
+        let (ma, mb) = (t ^ s ^ POOL[262], t ^ s ^ POOL[263]);
+        let mut z = t;
+        z ^= r[4] & mb;
+        z ^= r[0] & ma;
+        z ^= r[4] & r[0];
+        z
+    };
+    r[0] = r[9] ^ r[2];
+    r[2] = r[0].rotate_left(1);
+    r[0] = r[8] & POOL[264];
+    r[9] = r[0] ^ r[2];
+    r[4] = r[7] ^ r[9];
+    r[9] = r[5] & POOL[265];
+    r[7] = r[0] ^ POOL[266];
+    r[0] = r[7] ^ POOL[267];
+    r[7] = r[0] ^ POOL[268];
+    r[0] = r[2] ^ POOL[269];
+    r[2] = {
+        let (t, s) = (POOL[270], POOL[271]);
+        let (ma, mb) = (t ^ s ^ POOL[272], t ^ s ^ POOL[273]);
+        let mut z = t;
+        z ^= r[7] & mb;
+        z ^= r[0] & ma;
+        z ^= r[7] & r[0];
+        z
+    };
+    r[0] = r[9] ^ r[2];
+    r[2] = r[0].rotate_left(1);
+    r[0] = r[8] & POOL[274];
+    r[9] = r[0] ^ r[2];
+    r[7] = r[4] ^ r[9];
+    r[9] = r[5] & POOL[275];
+    r[4] = r[0] ^ POOL[276];
+    r[0] = r[4] ^ POOL[277];
+    r[4] = r[0] ^ POOL[278];
+    r[0] = r[2] ^ POOL[279];
+    r[2] = {
+        let (t, s) = (POOL[280], POOL[281]);
+        let (ma, mb) = (t ^ s ^ POOL[282], t ^ s ^ POOL[283]);
+        let mut z = t;
+        z ^= r[4] & mb;
+        z ^= r[0] & ma;
+        z ^= r[4] & r[0];
+        z
+    };
+    r[0] = r[9] ^ r[2];
+    r[2] = r[0].rotate_left(1);
+    r[0] = r[8] & POOL[284];
+    r[9] = r[0] ^ r[2];
+    r[4] = r[7] ^ r[9];
+    r[9] = r[5] & POOL[285];
+    r[7] = r[0] ^ POOL[286];
+    r[0] = r[7] ^ POOL[287];
+    r[7] = r[0] ^ POOL[288];
+    r[0] = r[2] ^ POOL[289];
+    r[2] = {
+        let (t, s) = (POOL[290], POOL[291]);
+        let (ma, mb) = (t ^ s ^ POOL[292], t ^ s ^ POOL[293]);
+        let mut z = t;
+        z ^= r[7] & mb;
+        z ^= r[0] & ma;
+        z ^= r[7] & r[0];
+        z
+    };
+    r[0] = r[9] ^ r[2];
+    r[2] = r[0].rotate_left(1);
+    r[0] = r[8] & POOL[294];
+    r[9] = r[0] ^ r[2];
+    r[7] = r[4] ^ r[9];
+    r[9] = r[5] & POOL[295];
+    r[4] = r[0] ^ POOL[296];
+    r[0] = r[4] ^ POOL[297];
+    r[4] = r[0] ^ POOL[298];
+    r[0] = r[2] ^ POOL[299];
+    r[2] = {
+        let (t, s) = (POOL[300], POOL[301]);
+        let (ma, mb) = (t ^ s ^ POOL[302], t ^ s ^ POOL[303]);
+        let mut z = t;
+        z ^= r[4] & mb;
+        z ^= r[0] & ma;
+        z ^= r[4] & r[0];
+        z
+    };
+    r[0] = r[9] ^ r[2];
+    r[2] = r[0].rotate_left(1);
+    r[0] = r[8] & POOL[304];
+    r[9] = r[0] ^ r[2];
+    r[4] = r[7] ^ r[9];
+    r[9] = r[5] & POOL[305];
+    r[5] = r[0] ^ POOL[306];
+    r[0] = r[5] ^ POOL[307];
+    r[5] = r[0] ^ POOL[308];
+    r[0] = r[2] ^ POOL[309];
+    r[2] = {
+        let (t, s) = (POOL[310], POOL[311]);
+        let (ma, mb) = (t ^ s ^ POOL[312], t ^ s ^ POOL[313]);
+        let mut z = t;
+        z ^= r[5] & mb;
+        z ^= r[0] & ma;
+        z ^= r[5] & r[0];
+        z
+    };
+    r[5] = r[9] ^ r[2];
+    r[2] = r[5].rotate_left(1);
+    r[5] = r[8] & POOL[314];
+    r[8] = r[5] ^ r[2];
+    r[2] = r[4] ^ r[8];
+    r[5] = c ^ POOL[315];
+    r[8] = d ^ POOL[316];
+    r[0] = r[8] ^ r[2];
+    r[8] = r[0].rotate_left(16);
+    r[0] = r[5] ^ POOL[317];
+    r[9] = r[0] ^ POOL[318];
+    r[0] = r[8] ^ POOL[319];
+    r[4] = {
+        let (t, s) = (POOL[320], POOL[321]);
+        let (ma, mb) = (t ^ s ^ POOL[322], t ^ s ^ POOL[323]);
+        let mut z = t;
+        z ^= r[9] & mb;
+        z ^= r[0] & ma;
+        z ^= r[9] & r[0];
+        z
+    };
+    r[0] = r[5] ^ r[8];
+    r[5] = r[0] & POOL[324];
+    r[9] = r[4] & POOL[325];
+    r[7] = r[9].rotate_left(1);
+    r[9] = r[0] & POOL[326];
+    r[1] = r[9] ^ r[7];
+    r[6] = r[5] ^ r[1];
+    r[5] = r[4] & POOL[327];
+    r[1] = r[9] ^ POOL[328];
+    r[9] = r[1] ^ POOL[329];
+    r[1] = r[9] ^ POOL[330];
+    r[9] = r[7] ^ POOL[331];
+    r[7] = r[9] ^ POOL[332];
+    r[9] = r[7] ^ POOL[333];
+    r[7] = {
+        let (t, s) = (POOL[334], POOL[335]);
+        let (ma, mb) = (t ^ s ^ POOL[336], t ^ s ^ POOL[337]);
+        let mut z = t;
+        z ^= r[1] & mb;
+        z ^= r[9] & ma;
+        z ^= r[1] & r[9];
+        z
+    };
+    r[9] = r[5] ^ r[7];
+    r[5] = r[9].rotate_left(1);
+    r[9] = r[0] & POOL[338];
+    r[7] = r[9] ^ r[5];
+    r[1] = r[6] ^ r[7];
+    r[7] = r[4] & POOL[339];
+    r[6] = r[9] ^ POOL[340];
+    r[9] = r[6] ^ POOL[341];
+    r[6] = r[9] ^ POOL[342];
+    r[9] = r[5] ^ POOL[343];
+    r[5] = {
+        let (t, s) = (POOL[344], POOL[345]);
+        let (ma, mb) = (t ^ s ^ POOL[346], t ^ s ^ POOL[347]);
+        let mut z = t;
+        z ^= r[6] & mb;
+        z ^= r[9] & ma;
+        z ^= r[6] & r[9];
+        z
+    };
+    r[9] = r[7] ^ r[5];
+    r[5] = r[9].rotate_left(1);
+    r[9] = r[0] & POOL[348];
+    r[7] = r[9] ^ r[5];
+    r[6] = r[1] ^ r[7];
+    r[7] = r[4] & POOL[349];
+    r[1] = r[9] ^ POOL[350];
+    r[9] = r[1] ^ POOL[351];
+    r[1] = r[9] ^ POOL[352];
+    r[9] = r[5] ^ POOL[353];
+    r[5] = {
+        let (t, s) = (POOL[354], POOL[355]);
+        let (ma, mb) = (t ^ s ^ POOL[356], t ^ s ^ POOL[357]);
+        let mut z = t;
+        z ^= r[1] & mb;
+        z ^= r[9] & ma;
+        z ^= r[1] & r[9];
+        z
+    };
+    r[9] = r[7] ^ r[5];
+    r[5] = r[9].rotate_left(1);
+    r[9] = r[0] & POOL[358];
+    r[7] = r[9] ^ r[5];
+    r[1] = r[6] ^ r[7];
+    r[7] = r[4] & POOL[359];
+    r[6] = r[9] ^ POOL[360];
+    r[9] = r[6] ^ POOL[361];
+    r[6] = r[9] ^ POOL[362];
+    r[9] = r[5] ^ POOL[363];
+    r[5] = {
+        let (t, s) = (POOL[364], POOL[365]);
+        let (ma, mb) = (t ^ s ^ POOL[366], t ^ s ^ POOL[367]);
+        let mut z = t;
+        z ^= r[6] & mb;
+        z ^= r[9] & ma;
+        z ^= r[6] & r[9];
+        z
+    };
+    r[9] = r[7] ^ r[5];
+    r[5] = r[9].rotate_left(1);
+    r[9] = r[0] & POOL[368];
+    r[7] = r[9] ^ r[5];
+    r[6] = r[1] ^ r[7];
+    r[7] = r[4] & POOL[369];
+    r[1] = r[9] ^ POOL[370];
+    r[9] = r[1] ^ POOL[371];
+    r[1] = r[9] ^ POOL[372];
+    r[9] = r[5] ^ POOL[373];
+    r[5] = {
+        let (t, s) = (POOL[374], POOL[375]);
+        let (ma, mb) = (t ^ s ^ POOL[376], t ^ s ^ POOL[377]);
+        let mut z = t;
+        z ^= r[1] & mb;
+        z ^= r[9] & ma;
+        z ^= r[1] & r[9];
+        z
+    };
+    r[9] = r[7] ^ r[5];
+    r[5] = r[9].rotate_left(1);
+    r[9] = r[0] & POOL[378];
+    r[7] = r[9] ^ r[5];
+    r[1] = r[6] ^ r[7];
+    r[7] = r[4] & POOL[379];
+    r[6] = r[9] ^ POOL[380];
+    r[9] = r[6] ^ POOL[381];
+    r[6] = r[9] ^ POOL[382];
+    r[9] = r[5] ^ POOL[383];
+    r[5] = {
+        let (t, s) = (POOL[384], POOL[385]);
+        let (ma, mb) = (t ^ s ^ POOL[386], t ^ s ^ POOL[387]);
+        let mut z = t;
+        z ^= r[6] & mb;
+        z ^= r[9] & ma;
+        z ^= r[6] & r[9];
+        z
+    };
+    r[9] = r[7] ^ r[5];
+    r[5] = r[9].rotate_left(1);
+    r[9] = r[0] & POOL[388];
+    r[7] = r[9] ^ r[5];
+    r[6] = r[1] ^ r[7];
+    r[7] = r[4] & POOL[389];
+    r[1] = r[9] ^ POOL[390];
+    r[9] = r[1] ^ POOL[391];
+    r[1] = r[9] ^ POOL[392];
+    r[9] = r[5] ^ POOL[393];
+    r[5] = {
+        let (t, s) = (POOL[394], POOL[395]);
+        let (ma, mb) = (t ^ s ^ POOL[396], t ^ s ^ POOL[397]);
+        let mut z = t;
+        z ^= r[1] & mb;
+        z ^= r[9] & ma;
+        z ^= r[1] & r[9];
+        z
+    };
+    r[9] = r[7] ^ r[5];
+    r[5] = r[9].rotate_left(1);
+    r[9] = r[0] & POOL[398];
+    r[7] = r[9] ^ r[5];
+    r[1] = r[6] ^ r[7];
+    r[7] = r[4] & POOL[399];
+    r[6] = r[9] ^ POOL[400];
+    r[9] = r[6] ^ POOL[401];
+    r[6] = r[9] ^ POOL[402];
+    r[9] = r[5] ^ POOL[403];
+    r[5] = {
+        let (t, s) = (POOL[404], POOL[405]);
+        let (ma, mb) = (t ^ s ^ POOL[406], t ^ s ^ POOL[407]);
+        let mut z = t;
+        z ^= r[6] & mb;
+        z ^= r[9] & ma;
+        z ^= r[6] & r[9];
+        z
+    };
+    r[9] = r[7] ^ r[5];
+    r[5] = r[9].rotate_left(1);
+    r[9] = r[0] & POOL[408];
+    r[7] = r[9] ^ r[5];
+    r[6] = r[1] ^ r[7];
+    r[7] = r[4] & POOL[409];
+    r[1] = r[9] ^ POOL[410];
+    r[9] = r[1] ^ POOL[411];
+    r[1] = r[9] ^ POOL[412];
+    r[9] = r[5] ^ POOL[413];
+    r[5] = {
+        let (t, s) = (POOL[414], POOL[415]);
+        let (ma, mb) = (t ^ s ^ POOL[416], t ^ s ^ POOL[417]);
+        let mut z = t;
+        z ^= r[1] & mb;
+        z ^= r[9] & ma;
+        z ^= r[1] & r[9];
+        z
+    };
+    r[9] = r[7] ^ r[5];
+    r[5] = r[9].rotate_left(1);
+    r[9] = r[0] & POOL[418];
+    r[7] = r[9] ^ r[5];
+    r[1] = r[6] ^ r[7];
+    r[7] = r[4] & POOL[419];
+    r[6] = r[9] ^ POOL[420];
+    r[9] = r[6] ^ POOL[421];
+    r[6] = r[9] ^ POOL[422];
+    r[9] = r[5] ^ POOL[423];
+    r[5] = {
+        let (t, s) = (POOL[424], POOL[425]);
+        let (ma, mb) = (t ^ s ^ POOL[426], t ^ s ^ POOL[427]);
+        let mut z = t;
+        z ^= r[6] & mb;
+        z ^= r[9] & ma;
+        z ^= r[6] & r[9];
+        z
+    };
+    r[9] = r[7] ^ r[5];
+    r[5] = r[9].rotate_left(1);
+    r[9] = r[0] & POOL[428];
+    r[7] = r[9] ^ r[5];
+    r[6] = r[1] ^ r[7];
+    r[7] = r[4] & POOL[429];
+    r[1] = r[9] ^ POOL[430];
+    r[9] = r[1] ^ POOL[431];
+    r[1] = r[9] ^ POOL[432];
+    r[9] = r[5] ^ POOL[433];
+    r[5] = {
+        let (t, s) = (POOL[434], POOL[435]);
+        let (ma, mb) = (t ^ s ^ POOL[436], t ^ s ^ POOL[437]);
+        let mut z = t;
+        z ^= r[1] & mb;
+        z ^= r[9] & ma;
+        z ^= r[1] & r[9];
+        z
+    };
+    r[9] = r[7] ^ r[5];
+    r[5] = r[9].rotate_left(1);
+    r[9] = r[0] & POOL[438];
+    r[7] = r[9] ^ r[5];
+    r[1] = r[6] ^ r[7];
+    r[7] = r[4] & POOL[439];
+    r[6] = r[9] ^ POOL[440];
+    r[9] = r[6] ^ POOL[441];
+    r[6] = r[9] ^ POOL[442];
+    r[9] = r[5] ^ POOL[443];
+    r[5] = {
+        let (t, s) = (POOL[444], POOL[445]);
+        let (ma, mb) = (t ^ s ^ POOL[446], t ^ s ^ POOL[447]);
+        let mut z = t;
+        z ^= r[6] & mb;
+        z ^= r[9] & ma;
+        z ^= r[6] & r[9];
+        z
+    };
+    r[9] = r[7] ^ r[5];
+    r[5] = r[9].rotate_left(1);
+    r[9] = r[0] & POOL[448];
+    r[7] = r[9] ^ r[5];
+    r[6] = r[1] ^ r[7];
+    r[7] = r[4] & POOL[449];
+    r[1] = r[9] ^ POOL[450];
+    r[9] = r[1] ^ POOL[451];
+    r[1] = r[9] ^ POOL[452];
+    r[9] = r[5] ^ POOL[453];
+    r[5] = {
+        let (t, s) = (POOL[454], POOL[455]);
+        let (ma, mb) = (t ^ s ^ POOL[456], t ^ s ^ POOL[457]);
+        let mut z = t;
+        z ^= r[1] & mb;
+        z ^= r[9] & ma;
+        z ^= r[1] & r[9];
+        z
+    };
+    r[9] = r[7] ^ r[5];
+    r[5] = r[9].rotate_left(1);
+    r[9] = r[0] & POOL[458];
+    r[7] = r[9] ^ r[5];
+    r[1] = r[6] ^ r[7];
+    r[7] = r[4] & POOL[459];
+    r[6] = r[9] ^ POOL[460];
+    r[9] = r[6] ^ POOL[461];
+    r[6] = r[9] ^ POOL[462];
+    r[9] = r[5] ^ POOL[463];
+    r[5] = {
+        let (t, s) = (POOL[464], POOL[465]);
+        let (ma, mb) = (t ^ s ^ POOL[466], t ^ s ^ POOL[467]);
+        let mut z = t;
+        z ^= r[6] & mb;
+        z ^= r[9] & ma;
+        z ^= r[6] & r[9];
+        z
+    };
+    r[9] = r[7] ^ r[5];
+    r[5] = r[9].rotate_left(1);
+    r[9] = r[0] & POOL[468];
+    r[7] = r[9] ^ r[5];
+    r[6] = r[1] ^ r[7];
+    r[7] = r[4] & POOL[469];
+    r[1] = r[9] ^ POOL[470];
+    r[9] = r[1] ^ POOL[471];
+    r[1] = r[9] ^ POOL[472];
+    r[9] = r[5] ^ POOL[473];
+    r[5] = {
+        let (t, s) = (POOL[474], POOL[475]);
+        let (ma, mb) = (t ^ s ^ POOL[476], t ^ s ^ POOL[477]);
+        let mut z = t;
+        z ^= r[1] & mb;
+        z ^= r[9] & ma;
+        z ^= r[1] & r[9];
+        z
+    };
+    r[9] = r[7] ^ r[5];
+    r[5] = r[9].rotate_left(1);
+    r[9] = r[0] & POOL[478];
+    r[7] = r[9] ^ r[5];
+    r[1] = r[6] ^ r[7];
+    r[7] = r[4] & POOL[479];
+    r[6] = r[9] ^ POOL[480];
+    r[9] = r[6] ^ POOL[481];
+    r[6] = r[9] ^ POOL[482];
+    r[9] = r[5] ^ POOL[483];
+    r[5] = {
+        let (t, s) = (POOL[484], POOL[485]);
+        let (ma, mb) = (t ^ s ^ POOL[486], t ^ s ^ POOL[487]);
+        let mut z = t;
+        z ^= r[6] & mb;
+        z ^= r[9] & ma;
+        z ^= r[6] & r[9];
+        z
+    };
+    r[9] = r[7] ^ r[5];
+    r[5] = r[9].rotate_left(1);
+    r[9] = r[0] & POOL[488];
+    r[7] = r[9] ^ r[5];
+    r[6] = r[1] ^ r[7];
+    r[7] = r[4] & POOL[489];
+    r[1] = r[9] ^ POOL[490];
+    r[9] = r[1] ^ POOL[491];
+    r[1] = r[9] ^ POOL[492];
+    r[9] = r[5] ^ POOL[493];
+    r[5] = {
+        let (t, s) = (POOL[494], POOL[495]);
+        let (ma, mb) = (t ^ s ^ POOL[496], t ^ s ^ POOL[497]);
+        let mut z = t;
+        z ^= r[1] & mb;
+        z ^= r[9] & ma;
+        z ^= r[1] & r[9];
+        z
+    };
+    r[9] = r[7] ^ r[5];
+    r[5] = r[9].rotate_left(1);
+    r[9] = r[0] & POOL[498];
+    r[7] = r[9] ^ r[5];
+    r[1] = r[6] ^ r[7];
+    r[7] = r[4] & POOL[499];
+    r[6] = r[9] ^ POOL[500];
+    r[9] = r[6] ^ POOL[501];
+    r[6] = r[9] ^ POOL[502];
+    r[9] = r[5] ^ POOL[503];
+    r[5] = {
+        let (t, s) = (POOL[504], POOL[505]);
+        let (ma, mb) = (t ^ s ^ POOL[506], t ^ s ^ POOL[507]);
+        let mut z = t;
+        z ^= r[6] & mb;
+        z ^= r[9] & ma;
+        z ^= r[6] & r[9];
+        z
+    };
+    r[9] = r[7] ^ r[5];
+    r[5] = r[9].rotate_left(1);
+    r[9] = r[0] & POOL[508];
+    r[7] = r[9] ^ r[5];
+    r[6] = r[1] ^ r[7];
+    r[7] = r[4] & POOL[509];
+    r[1] = r[9] ^ POOL[510];
+    r[9] = r[1] ^ POOL[511];
+    r[1] = r[9] ^ POOL[512];
+    r[9] = r[5] ^ POOL[513];
+    r[5] = {
+        let (t, s) = (POOL[514], POOL[515]);
+        let (ma, mb) = (t ^ s ^ POOL[516], t ^ s ^ POOL[517]);
+        let mut z = t;
+        z ^= r[1] & mb;
+        z ^= r[9] & ma;
+        z ^= r[1] & r[9];
+        z
+    };
+    r[9] = r[7] ^ r[5];
+    r[5] = r[9].rotate_left(1);
+    r[9] = r[0] & POOL[518];
+    r[7] = r[9] ^ r[5];
+    r[1] = r[6] ^ r[7];
+    r[7] = r[4] & POOL[519];
+    r[6] = r[9] ^ POOL[520];
+    r[9] = r[6] ^ POOL[521];
+    r[6] = r[9] ^ POOL[522];
+    r[9] = r[5] ^ POOL[523];
+    r[5] = {
+        let (t, s) = (POOL[524], POOL[525]);
+        let (ma, mb) = (t ^ s ^ POOL[526], t ^ s ^ POOL[527]);
+        let mut z = t;
+        z ^= r[6] & mb;
+        z ^= r[9] & ma;
+        z ^= r[6] & r[9];
+        z
+    };
+    r[9] = r[7] ^ r[5];
+    r[5] = r[9].rotate_left(1);
+    r[9] = r[0] & POOL[528];
+    r[7] = r[9] ^ r[5];
+    r[6] = r[1] ^ r[7];
+    r[7] = r[4] & POOL[529];
+    r[1] = r[9] ^ POOL[530];
+    r[9] = r[1] ^ POOL[531];
+    r[1] = r[9] ^ POOL[532];
+    r[9] = r[5] ^ POOL[533];
+    r[5] = {
+        let (t, s) = (POOL[534], POOL[535]);
+        let (ma, mb) = (t ^ s ^ POOL[536], t ^ s ^ POOL[537]);
+        let mut z = t;
+        z ^= r[1] & mb;
+        z ^= r[9] & ma;
+        z ^= r[1] & r[9];
+        z
+    };
+    r[9] = r[7] ^ r[5];
+    r[5] = r[9].rotate_left(1);
+    r[9] = r[0] & POOL[538];
+    r[7] = r[9] ^ r[5];
+    r[1] = r[6] ^ r[7];
+    r[7] = r[4] & POOL[539];
+    r[6] = r[9] ^ POOL[540];
+    r[9] = r[6] ^ POOL[541];
+    r[6] = r[9] ^ POOL[542];
+    r[9] = r[5] ^ POOL[543];
+    r[5] = {
+        let (t, s) = (POOL[544], POOL[545]);
+        let (ma, mb) = (t ^ s ^ POOL[546], t ^ s ^ POOL[547]);
+        let mut z = t;
+        z ^= r[6] & mb;
+        z ^= r[9] & ma;
+        z ^= r[6] & r[9];
+        z
+    };
+    r[9] = r[7] ^ r[5];
+    r[5] = r[9].rotate_left(1);
+    r[9] = r[0] & POOL[548];
+    r[7] = r[9] ^ r[5];
+    r[6] = r[1] ^ r[7];
+    r[7] = r[4] & POOL[549];
+    r[1] = r[9] ^ POOL[550];
+    r[9] = r[1] ^ POOL[551];
+    r[1] = r[9] ^ POOL[552];
+    r[9] = r[5] ^ POOL[553];
+    r[5] = {
+        let (t, s) = (POOL[554], POOL[555]);
+        let (ma, mb) = (t ^ s ^ POOL[556], t ^ s ^ POOL[557]);
+        let mut z = t;
+        z ^= r[1] & mb;
+        z ^= r[9] & ma;
+        z ^= r[1] & r[9];
+        z
+    };
+    r[9] = r[7] ^ r[5];
+    r[5] = r[9].rotate_left(1);
+    r[9] = r[0] & POOL[558];
+    r[7] = r[9] ^ r[5];
+    r[1] = r[6] ^ r[7];
+    r[7] = r[4] & POOL[559];
+    r[6] = r[9] ^ POOL[560];
+    r[9] = r[6] ^ POOL[561];
+    r[6] = r[9] ^ POOL[562];
+    r[9] = r[5] ^ POOL[563];
+    r[5] = {
+        let (t, s) = (POOL[564], POOL[565]);
+        let (ma, mb) = (t ^ s ^ POOL[566], t ^ s ^ POOL[567]);
+        let mut z = t;
+        z ^= r[6] & mb;
+        z ^= r[9] & ma;
+        z ^= r[6] & r[9];
+        z
+    };
+    r[9] = r[7] ^ r[5];
+    r[5] = r[9].rotate_left(1);
+    r[9] = r[0] & POOL[568];
+    r[7] = r[9] ^ r[5];
+    r[6] = r[1] ^ r[7];
+    r[7] = r[4] & POOL[569];
+    r[1] = r[9] ^ POOL[570];
+    r[9] = r[1] ^ POOL[571];
+    r[1] = r[9] ^ POOL[572];
+    r[9] = r[5] ^ POOL[573];
+    r[5] = {
+        let (t, s) = (POOL[574], POOL[575]);
+        let (ma, mb) = (t ^ s ^ POOL[576], t ^ s ^ POOL[577]);
+        let mut z = t;
+        z ^= r[1] & mb;
+        z ^= r[9] & ma;
+        z ^= r[1] & r[9];
+        z
+    };
+    r[9] = r[7] ^ r[5];
+    r[5] = r[9].rotate_left(1);
+    r[9] = r[0] & POOL[578];
+    r[7] = r[9] ^ r[5];
+    r[1] = r[6] ^ r[7];
+    r[7] = r[4] & POOL[579];
+    r[6] = r[9] ^ POOL[580];
+    r[9] = r[6] ^ POOL[581];
+    r[6] = r[9] ^ POOL[582];
+    r[9] = r[5] ^ POOL[583];
+    r[5] = {
+        let (t, s) = (POOL[584], POOL[585]);
+        let (ma, mb) = (t ^ s ^ POOL[586], t ^ s ^ POOL[587]);
+        let mut z = t;
+        z ^= r[6] & mb;
+        z ^= r[9] & ma;
+        z ^= r[6] & r[9];
+        z
+    };
+    r[9] = r[7] ^ r[5];
+    r[5] = r[9].rotate_left(1);
+    r[9] = r[0] & POOL[588];
+    r[7] = r[9] ^ r[5];
+    r[6] = r[1] ^ r[7];
+    r[7] = r[4] & POOL[589];
+    r[1] = r[9] ^ POOL[590];
+    r[9] = r[1] ^ POOL[591];
+    r[1] = r[9] ^ POOL[592];
+    r[9] = r[5] ^ POOL[593];
+    r[5] = {
+        let (t, s) = (POOL[594], POOL[595]);
+        let (ma, mb) = (t ^ s ^ POOL[596], t ^ s ^ POOL[597]);
+        let mut z = t;
+        z ^= r[1] & mb;
+        z ^= r[9] & ma;
+        z ^= r[1] & r[9];
+        z
+    };
+    r[9] = r[7] ^ r[5];
+    r[5] = r[9].rotate_left(1);
+    r[9] = r[0] & POOL[598];
+    r[7] = r[9] ^ r[5];
+    r[1] = r[6] ^ r[7];
+    r[7] = r[4] & POOL[599];
+    r[6] = r[9] ^ POOL[600];
+    r[9] = r[6] ^ POOL[601];
+    r[6] = r[9] ^ POOL[602];
+    r[9] = r[5] ^ POOL[603];
+    r[5] = {
+        let (t, s) = (POOL[604], POOL[605]);
+        let (ma, mb) = (t ^ s ^ POOL[606], t ^ s ^ POOL[607]);
+        let mut z = t;
+        z ^= r[6] & mb;
+        z ^= r[9] & ma;
+        z ^= r[6] & r[9];
+        z
+    };
+    r[9] = r[7] ^ r[5];
+    r[5] = r[9].rotate_left(1);
+    r[9] = r[0] & POOL[608];
+    r[7] = r[9] ^ r[5];
+    r[6] = r[1] ^ r[7];
+    r[7] = r[4] & POOL[609];
+    r[1] = r[9] ^ POOL[610];
+    r[9] = r[1] ^ POOL[611];
+    r[1] = r[9] ^ POOL[612];
+    r[9] = r[5] ^ POOL[613];
+    r[5] = {
+        let (t, s) = (POOL[614], POOL[615]);
+        let (ma, mb) = (t ^ s ^ POOL[616], t ^ s ^ POOL[617]);
+        let mut z = t;
+        z ^= r[1] & mb;
+        z ^= r[9] & ma;
+        z ^= r[1] & r[9];
+        z
+    };
+    r[9] = r[7] ^ r[5];
+    r[5] = r[9].rotate_left(1);
+    r[9] = r[0] & POOL[618];
+    r[7] = r[9] ^ r[5];
+    r[1] = r[6] ^ r[7];
+    r[7] = r[4] & POOL[619];
+    r[4] = r[9] ^ POOL[620];
+    r[9] = r[4] ^ POOL[621];
+    r[4] = r[9] ^ POOL[622];
+    r[9] = r[5] ^ POOL[623];
+    r[5] = {
+        let (t, s) = (POOL[624], POOL[625]);
+        let (ma, mb) = (t ^ s ^ POOL[626], t ^ s ^ POOL[627]);
+        let mut z = t;
+        z ^= r[4] & mb;
+        z ^= r[9] & ma;
+        z ^= r[4] & r[9];
+        z
+    };
+    r[9] = r[7] ^ r[5];
+    r[5] = r[9].rotate_left(1);
+    r[9] = r[0] & POOL[628];
+    r[0] = r[9] ^ r[5];
+    r[5] = r[1] ^ r[0];
+    r[0] = r[3] ^ r[5];
+    r[3] = r[0].rotate_left(12);
+    r[0] = r[2] ^ POOL[629];
+    r[9] = r[3] ^ POOL[630];
+    r[4] = {
+        let (t, s) = (POOL[631], POOL[632]);
+        let (ma, mb) = (t ^ s ^ POOL[633], t ^ s ^ POOL[634]);
+        let mut z = t;
+        z ^= r[0] & mb;
+        z ^= r[9] & ma;
+        z ^= r[0] & r[9];
+        z
+    };
+    r[0] = r[2] ^ r[3];
+    r[2] = r[0] & POOL[635];
+    r[9] = r[4] & POOL[636];
+    r[7] = r[9].rotate_left(1);
+    r[9] = r[0] & POOL[637];
+    r[1] = r[9] ^ r[7];
+    r[6] = r[2] ^ r[1];
+    r[2] = r[4] & POOL[638];
+    r[1] = r[9] ^ POOL[639];
+    r[9] = r[1] ^ POOL[640];
+    r[1] = r[9] ^ POOL[641];
+    r[9] = r[7] ^ POOL[642];
+    r[7] = r[9] ^ POOL[643];
+    r[9] = r[7] ^ POOL[644];
+    r[7] = {
+        let (t, s) = (POOL[645], POOL[646]);
+        let (ma, mb) = (t ^ s ^ POOL[647], t ^ s ^ POOL[648]);
+        let mut z = t;
+        z ^= r[1] & mb;
+        z ^= r[9] & ma;
+        z ^= r[1] & r[9];
+        z
+    };
+    r[9] = r[2] ^ r[7];
+    r[2] = r[9].rotate_left(1);
+    r[9] = r[0] & POOL[649];
+    r[7] = r[9] ^ r[2];
+    r[1] = r[6] ^ r[7];
+    r[7] = r[4] & POOL[650];
+    r[6] = r[9] ^ POOL[651];
+    r[9] = r[6] ^ POOL[652];
+    r[6] = r[9] ^ POOL[653];
+    r[9] = r[2] ^ POOL[654];
+    r[2] = {
+        let (t, s) = (POOL[655], POOL[656]);
+        let (ma, mb) = (t ^ s ^ POOL[657], t ^ s ^ POOL[658]);
+        let mut z = t;
+        z ^= r[6] & mb;
+        z ^= r[9] & ma;
+        z ^= r[6] & r[9];
+        z
+    };
+    r[9] = r[7] ^ r[2];
+    r[2] = r[9].rotate_left(1);
+    r[9] = r[0] & POOL[659];
+    r[7] = r[9] ^ r[2];
+    r[6] = r[1] ^ r[7];
+    r[7] = r[4] & POOL[660];
+    r[1] = r[9] ^ POOL[661];
+    r[9] = r[1] ^ POOL[662];
+    r[1] = r[9] ^ POOL[663];
+    r[9] = r[2] ^ POOL[664];
+    r[2] = {
+        let (t, s) = (POOL[665], POOL[666]);
+        let (ma, mb) = (t ^ s ^ POOL[667], t ^ s ^ POOL[668]);
+        let mut z = t;
+        z ^= r[1] & mb;
+        z ^= r[9] & ma;
+        z ^= r[1] & r[9];
+        z
+    };
+    r[9] = r[7] ^ r[2];
+    r[2] = r[9].rotate_left(1);
+    r[9] = r[0] & POOL[669];
+    r[7] = r[9] ^ r[2];
+    r[1] = r[6] ^ r[7];
+    r[7] = r[4] & POOL[670];
+    r[6] = r[9] ^ POOL[671];
+    r[9] = r[6] ^ POOL[672];
+    r[6] = r[9] ^ POOL[673];
+    r[9] = r[2] ^ POOL[674];
+    r[2] = {
+        let (t, s) = (POOL[675], POOL[676]);
+        let (ma, mb) = (t ^ s ^ POOL[677], t ^ s ^ POOL[678]);
+        let mut z = t;
+        z ^= r[6] & mb;
+        z ^= r[9] & ma;
+        z ^= r[6] & r[9];
+        z
+    };
+    r[9] = r[7] ^ r[2];
+    r[2] = r[9].rotate_left(1);
+    r[9] = r[0] & POOL[679];
+    r[7] = r[9] ^ r[2];
+    r[6] = r[1] ^ r[7];
+    r[7] = r[4] & POOL[680];
+    r[1] = r[9] ^ POOL[681];
+    r[9] = r[1] ^ POOL[682];
+    r[1] = r[9] ^ POOL[683];
+    r[9] = r[2] ^ POOL[684];
+    r[2] = {
+        let (t, s) = (POOL[685], POOL[686]);
+        let (ma, mb) = (t ^ s ^ POOL[687], t ^ s ^ POOL[688]);
+        let mut z = t;
+        z ^= r[1] & mb;
+        z ^= r[9] & ma;
+        z ^= r[1] & r[9];
+        z
+    };
+    r[9] = r[7] ^ r[2];
+    r[2] = r[9].rotate_left(1);
+    r[9] = r[0] & POOL[689];
+    r[7] = r[9] ^ r[2];
+    r[1] = r[6] ^ r[7];
+    r[7] = r[4] & POOL[690];
+    r[6] = r[9] ^ POOL[691];
+    r[9] = r[6] ^ POOL[692];
+    r[6] = r[9] ^ POOL[693];
+    r[9] = r[2] ^ POOL[694];
+    r[2] = {
+        let (t, s) = (POOL[695], POOL[696]);
+        let (ma, mb) = (t ^ s ^ POOL[697], t ^ s ^ POOL[698]);
+        let mut z = t;
+        z ^= r[6] & mb;
+        z ^= r[9] & ma;
+        z ^= r[6] & r[9];
+        z
+    };
+    r[9] = r[7] ^ r[2];
+    r[2] = r[9].rotate_left(1);
+    r[9] = r[0] & POOL[699];
+    r[7] = r[9] ^ r[2];
+    r[6] = r[1] ^ r[7];
+    r[7] = r[4] & POOL[700];
+    r[1] = r[9] ^ POOL[701];
+    r[9] = r[1] ^ POOL[702];
+    r[1] = r[9] ^ POOL[703];
+    r[9] = r[2] ^ POOL[704];
+    r[2] = {
+        let (t, s) = (POOL[705], POOL[706]);
+        let (ma, mb) = (t ^ s ^ POOL[707], t ^ s ^ POOL[708]);
+        let mut z = t;
+        z ^= r[1] & mb;
+        z ^= r[9] & ma;
+        z ^= r[1] & r[9];
+        z
+    };
+    r[9] = r[7] ^ r[2];
+    r[2] = r[9].rotate_left(1);
+    r[9] = r[0] & POOL[709];
+    r[7] = r[9] ^ r[2];
+    r[1] = r[6] ^ r[7];
+    r[7] = r[4] & POOL[710];
+    r[6] = r[9] ^ POOL[711];
+    r[9] = r[6] ^ POOL[712];
+    r[6] = r[9] ^ POOL[713];
+    r[9] = r[2] ^ POOL[714];
+    r[2] = {
+        let (t, s) = (POOL[715], POOL[716]);
+        let (ma, mb) = (t ^ s ^ POOL[717], t ^ s ^ POOL[718]);
+        let mut z = t;
+        z ^= r[6] & mb;
+        z ^= r[9] & ma;
+        z ^= r[6] & r[9];
+        z
+    };
+    r[9] = r[7] ^ r[2];
+    r[2] = r[9].rotate_left(1);
+    r[9] = r[0] & POOL[719];
+    r[7] = r[9] ^ r[2];
+    r[6] = r[1] ^ r[7];
+    r[7] = r[4] & POOL[720];
+    r[1] = r[9] ^ POOL[721];
+    r[9] = r[1] ^ POOL[722];
+    r[1] = r[9] ^ POOL[723];
+    r[9] = r[2] ^ POOL[724];
+    r[2] = {
+        let (t, s) = (POOL[725], POOL[726]);
+        let (ma, mb) = (t ^ s ^ POOL[727], t ^ s ^ POOL[728]);
+        let mut z = t;
+        z ^= r[1] & mb;
+        z ^= r[9] & ma;
+        z ^= r[1] & r[9];
+        z
+    };
+    r[9] = r[7] ^ r[2];
+    r[2] = r[9].rotate_left(1);
+    r[9] = r[0] & POOL[729];
+    r[7] = r[9] ^ r[2];
+    r[1] = r[6] ^ r[7];
+    r[7] = r[4] & POOL[730];
+    r[6] = r[9] ^ POOL[731];
+    r[9] = r[6] ^ POOL[732];
+    r[6] = r[9] ^ POOL[733];
+    r[9] = r[2] ^ POOL[734];
+    r[2] = {
+        let (t, s) = (POOL[735], POOL[736]);
+        let (ma, mb) = (t ^ s ^ POOL[737], t ^ s ^ POOL[738]);
+        let mut z = t;
+        z ^= r[6] & mb;
+        z ^= r[9] & ma;
+        z ^= r[6] & r[9];
+        z
+    };
+    r[9] = r[7] ^ r[2];
+    r[2] = r[9].rotate_left(1);
+    r[9] = r[0] & POOL[739];
+    r[7] = r[9] ^ r[2];
+    r[6] = r[1] ^ r[7];
+    r[7] = r[4] & POOL[740];
+    r[1] = r[9] ^ POOL[741];
+    r[9] = r[1] ^ POOL[742];
+    r[1] = r[9] ^ POOL[743];
+    r[9] = r[2] ^ POOL[744];
+    r[2] = {
+        let (t, s) = (POOL[745], POOL[746]);
+        let (ma, mb) = (t ^ s ^ POOL[747], t ^ s ^ POOL[748]);
+        let mut z = t;
+        z ^= r[1] & mb;
+        z ^= r[9] & ma;
+        z ^= r[1] & r[9];
+        z
+    };
+    r[9] = r[7] ^ r[2];
+    r[2] = r[9].rotate_left(1);
+    r[9] = r[0] & POOL[749];
+    r[7] = r[9] ^ r[2];
+    r[1] = r[6] ^ r[7];
+    r[7] = r[4] & POOL[750];
+    r[6] = r[9] ^ POOL[751];
+    r[9] = r[6] ^ POOL[752];
+    r[6] = r[9] ^ POOL[753];
+    r[9] = r[2] ^ POOL[754];
+    r[2] = {
+        let (t, s) = (POOL[755], POOL[756]);
+        let (ma, mb) = (t ^ s ^ POOL[757], t ^ s ^ POOL[758]);
+        let mut z = t;
+        z ^= r[6] & mb;
+        z ^= r[9] & ma;
+        z ^= r[6] & r[9];
+        z
+    };
+    r[9] = r[7] ^ r[2];
+    r[2] = r[9].rotate_left(1);
+    r[9] = r[0] & POOL[759];
+    r[7] = r[9] ^ r[2];
+    r[6] = r[1] ^ r[7];
+    r[7] = r[4] & POOL[760];
+    r[1] = r[9] ^ POOL[761];
+    r[9] = r[1] ^ POOL[762];
+    r[1] = r[9] ^ POOL[763];
+    r[9] = r[2] ^ POOL[764];
+    r[2] = {
+        let (t, s) = (POOL[765], POOL[766]);
+        let (ma, mb) = (t ^ s ^ POOL[767], t ^ s ^ POOL[768]);
+        let mut z = t;
+        z ^= r[1] & mb;
+        z ^= r[9] & ma;
+        z ^= r[1] & r[9];
+        z
+    };
+    r[9] = r[7] ^ r[2];
+    r[2] = r[9].rotate_left(1);
+    r[9] = r[0] & POOL[769];
+    r[7] = r[9] ^ r[2];
+    r[1] = r[6] ^ r[7];
+    r[7] = r[4] & POOL[770];
+    r[6] = r[9] ^ POOL[771];
+    r[9] = r[6] ^ POOL[772];
+    r[6] = r[9] ^ POOL[773];
+    r[9] = r[2] ^ POOL[774];
+    r[2] = {
+        let (t, s) = (POOL[775], POOL[776]);
+        let (ma, mb) = (t ^ s ^ POOL[777], t ^ s ^ POOL[778]);
+        let mut z = t;
+        z ^= r[6] & mb;
+        z ^= r[9] & ma;
+        z ^= r[6] & r[9];
+        z
+    };
+    r[9] = r[7] ^ r[2];
+    r[2] = r[9].rotate_left(1);
+    r[9] = r[0] & POOL[779];
+    r[7] = r[9] ^ r[2];
+    r[6] = r[1] ^ r[7];
+    r[7] = r[4] & POOL[780];
+    r[1] = r[9] ^ POOL[781];
+    r[9] = r[1] ^ POOL[782];
+    r[1] = r[9] ^ POOL[783];
+    r[9] = r[2] ^ POOL[784];
+    r[2] = {
+        let (t, s) = (POOL[785], POOL[786]);
+        let (ma, mb) = (t ^ s ^ POOL[787], t ^ s ^ POOL[788]);
+        let mut z = t;
+        z ^= r[1] & mb;
+        z ^= r[9] & ma;
+        z ^= r[1] & r[9];
+        z
+    };
+    r[9] = r[7] ^ r[2];
+    r[2] = r[9].rotate_left(1);
+    r[9] = r[0] & POOL[789];
+    r[7] = r[9] ^ r[2];
+    r[1] = r[6] ^ r[7];
+    r[7] = r[4] & POOL[790];
+    r[6] = r[9] ^ POOL[791];
+    r[9] = r[6] ^ POOL[792];
+    r[6] = r[9] ^ POOL[793];
+    r[9] = r[2] ^ POOL[794];
+    r[2] = {
+        let (t, s) = (POOL[795], POOL[796]);
+        let (ma, mb) = (t ^ s ^ POOL[797], t ^ s ^ POOL[798]);
+        let mut z = t;
+        z ^= r[6] & mb;
+        z ^= r[9] & ma;
+        z ^= r[6] & r[9];
+        z
+    };
+    r[9] = r[7] ^ r[2];
+    r[2] = r[9].rotate_left(1);
+    r[9] = r[0] & POOL[799];
+    r[7] = r[9] ^ r[2];
+    r[6] = r[1] ^ r[7];
+    r[7] = r[4] & POOL[800];
+    r[1] = r[9] ^ POOL[801];
+    r[9] = r[1] ^ POOL[802];
+    r[1] = r[9] ^ POOL[803];
+    r[9] = r[2] ^ POOL[804];
+    r[2] = {
+        let (t, s) = (POOL[805], POOL[806]);
+        let (ma, mb) = (t ^ s ^ POOL[807], t ^ s ^ POOL[808]);
+        let mut z = t;
+        z ^= r[1] & mb;
+        z ^= r[9] & ma;
+        z ^= r[1] & r[9];
+        z
+    };
+    r[9] = r[7] ^ r[2];
+    r[2] = r[9].rotate_left(1);
+    r[9] = r[0] & POOL[809];
+    r[7] = r[9] ^ r[2];
+    r[1] = r[6] ^ r[7];
+    r[7] = r[4] & POOL[810];
+    r[6] = r[9] ^ POOL[811];
+    r[9] = r[6] ^ POOL[812];
+    r[6] = r[9] ^ POOL[813];
+    r[9] = r[2] ^ POOL[814];
+    r[2] = {
+        let (t, s) = (POOL[815], POOL[816]);
+        let (ma, mb) = (t ^ s ^ POOL[817], t ^ s ^ POOL[818]);
+        let mut z = t;
+        z ^= r[6] & mb;
+        z ^= r[9] & ma;
+        z ^= r[6] & r[9];
+        z
+    };
+    r[9] = r[7] ^ r[2];
+    r[2] = r[9].rotate_left(1);
+    r[9] = r[0] & POOL[819];
+    r[7] = r[9] ^ r[2];
+    r[6] = r[1] ^ r[7];
+    r[7] = r[4] & POOL[820];
+    r[1] = r[9] ^ POOL[821];
+    r[9] = r[1] ^ POOL[822];
+    r[1] = r[9] ^ POOL[823];
+    r[9] = r[2] ^ POOL[824];
+    r[2] = {
+        let (t, s) = (POOL[825], POOL[826]);
+        let (ma, mb) = (t ^ s ^ POOL[827], t ^ s ^ POOL[828]);
+        let mut z = t;
+        z ^= r[1] & mb;
+        z ^= r[9] & ma;
+        z ^= r[1] & r[9];
+        z
+    };
+    r[9] = r[7] ^ r[2];
+    r[2] = r[9].rotate_left(1);
+    r[9] = r[0] & POOL[829];
+    r[7] = r[9] ^ r[2];
+    r[1] = r[6] ^ r[7];
+    r[7] = r[4] & POOL[830];
+    r[6] = r[9] ^ POOL[831];
+    r[9] = r[6] ^ POOL[832];
+    r[6] = r[9] ^ POOL[833];
+    r[9] = r[2] ^ POOL[834];
+    r[2] = {
+        let (t, s) = (POOL[835], POOL[836]);
+        let (ma, mb) = (t ^ s ^ POOL[837], t ^ s ^ POOL[838]);
+        let mut z = t;
+        z ^= r[6] & mb;
+        z ^= r[9] & ma;
+        z ^= r[6] & r[9];
+        z
+    };
+    r[9] = r[7] ^ r[2];
+    r[2] = r[9].rotate_left(1);
+    r[9] = r[0] & POOL[839];
+    r[7] = r[9] ^ r[2];
+    r[6] = r[1] ^ r[7];
+    r[7] = r[4] & POOL[840];
+    r[1] = r[9] ^ POOL[841];
+    r[9] = r[1] ^ POOL[842];
+    r[1] = r[9] ^ POOL[843];
+    r[9] = r[2] ^ POOL[844];
+    r[2] = {
+        let (t, s) = (POOL[845], POOL[846]);
+        let (ma, mb) = (t ^ s ^ POOL[847], t ^ s ^ POOL[848]);
+        let mut z = t;
+        z ^= r[1] & mb;
+        z ^= r[9] & ma;
+        z ^= r[1] & r[9];
+        z
+    };
+    r[9] = r[7] ^ r[2];
+    r[2] = r[9].rotate_left(1);
+    r[9] = r[0] & POOL[849];
+    r[7] = r[9] ^ r[2];
+    r[1] = r[6] ^ r[7];
+    r[7] = r[4] & POOL[850];
+    r[6] = r[9] ^ POOL[851];
+    r[9] = r[6] ^ POOL[852];
+    r[6] = r[9] ^ POOL[853];
+    r[9] = r[2] ^ POOL[854];
+    r[2] = {
+        let (t, s) = (POOL[855], POOL[856]);
+        let (ma, mb) = (t ^ s ^ POOL[857], t ^ s ^ POOL[858]);
+        let mut z = t;
+        z ^= r[6] & mb;
+        z ^= r[9] & ma;
+        z ^= r[6] & r[9];
+        z
+    };
+    r[9] = r[7] ^ r[2];
+    r[2] = r[9].rotate_left(1);
+    r[9] = r[0] & POOL[859];
+    r[7] = r[9] ^ r[2];
+    r[6] = r[1] ^ r[7];
+    r[7] = r[4] & POOL[860];
+    r[1] = r[9] ^ POOL[861];
+    r[9] = r[1] ^ POOL[862];
+    r[1] = r[9] ^ POOL[863];
+    r[9] = r[2] ^ POOL[864];
+    r[2] = {
+        let (t, s) = (POOL[865], POOL[866]);
+        let (ma, mb) = (t ^ s ^ POOL[867], t ^ s ^ POOL[868]);
+        let mut z = t;
+        z ^= r[1] & mb;
+        z ^= r[9] & ma;
+        z ^= r[1] & r[9];
+        z
+    };
+    r[9] = r[7] ^ r[2];
+    r[2] = r[9].rotate_left(1);
+    r[9] = r[0] & POOL[869];
+    r[7] = r[9] ^ r[2];
+    r[1] = r[6] ^ r[7];
+    r[7] = r[4] & POOL[870];
+    r[6] = r[9] ^ POOL[871];
+    r[9] = r[6] ^ POOL[872];
+    r[6] = r[9] ^ POOL[873];
+    r[9] = r[2] ^ POOL[874];
+    r[2] = {
+        let (t, s) = (POOL[875], POOL[876]);
+        let (ma, mb) = (t ^ s ^ POOL[877], t ^ s ^ POOL[878]);
+        let mut z = t;
+        z ^= r[6] & mb;
+        z ^= r[9] & ma;
+        z ^= r[6] & r[9];
+        z
+    };
+    r[9] = r[7] ^ r[2];
+    r[2] = r[9].rotate_left(1);
+    r[9] = r[0] & POOL[879];
+    r[7] = r[9] ^ r[2];
+    r[6] = r[1] ^ r[7];
+    r[7] = r[4] & POOL[880];
+    r[1] = r[9] ^ POOL[881];
+    r[9] = r[1] ^ POOL[882];
+    r[1] = r[9] ^ POOL[883];
+    r[9] = r[2] ^ POOL[884];
+    r[2] = {
+        let (t, s) = (POOL[885], POOL[886]);
+        let (ma, mb) = (t ^ s ^ POOL[887], t ^ s ^ POOL[888]);
+        let mut z = t;
+        z ^= r[1] & mb;
+        z ^= r[9] & ma;
+        z ^= r[1] & r[9];
+        z
+    };
+    r[9] = r[7] ^ r[2];
+    r[2] = r[9].rotate_left(1);
+    r[9] = r[0] & POOL[889];
+    r[7] = r[9] ^ r[2];
+    r[1] = r[6] ^ r[7];
+    r[7] = r[4] & POOL[890];
+    r[6] = r[9] ^ POOL[891];
+    r[9] = r[6] ^ POOL[892];
+    r[6] = r[9] ^ POOL[893];
+    r[9] = r[2] ^ POOL[894];
+    r[2] = {
+        let (t, s) = (POOL[895], POOL[896]);
+        let (ma, mb) = (t ^ s ^ POOL[897], t ^ s ^ POOL[898]);
+        let mut z = t;
+        z ^= r[6] & mb;
+        z ^= r[9] & ma;
+        z ^= r[6] & r[9];
+        z
+    };
+    r[9] = r[7] ^ r[2];
+    r[2] = r[9].rotate_left(1);
+    r[9] = r[0] & POOL[899];
+    r[7] = r[9] ^ r[2];
+    r[6] = r[1] ^ r[7];
+    r[7] = r[4] & POOL[900];
+    r[1] = r[9] ^ POOL[901];
+    r[9] = r[1] ^ POOL[902];
+    r[1] = r[9] ^ POOL[903];
+    r[9] = r[2] ^ POOL[904];
+    r[2] = {
+        let (t, s) = (POOL[905], POOL[906]);
+        let (ma, mb) = (t ^ s ^ POOL[907], t ^ s ^ POOL[908]);
+        let mut z = t;
+        z ^= r[1] & mb;
+        z ^= r[9] & ma;
+        z ^= r[1] & r[9];
+        z
+    };
+    r[9] = r[7] ^ r[2];
+    r[2] = r[9].rotate_left(1);
+    r[9] = r[0] & POOL[909];
+    r[7] = r[9] ^ r[2];
+    r[1] = r[6] ^ r[7];
+    r[7] = r[4] & POOL[910];
+    r[6] = r[9] ^ POOL[911];
+    r[9] = r[6] ^ POOL[912];
+    r[6] = r[9] ^ POOL[913];
+    r[9] = r[2] ^ POOL[914];
+    r[2] = {
+        let (t, s) = (POOL[915], POOL[916]);
+        let (ma, mb) = (t ^ s ^ POOL[917], t ^ s ^ POOL[918]);
+        let mut z = t;
+        z ^= r[6] & mb;
+        z ^= r[9] & ma;
+        z ^= r[6] & r[9];
+        z
+    };
+    r[9] = r[7] ^ r[2];
+    r[2] = r[9].rotate_left(1);
+    r[9] = r[0] & POOL[919];
+    r[7] = r[9] ^ r[2];
+    r[6] = r[1] ^ r[7];
+    r[7] = r[4] & POOL[920];
+    r[1] = r[9] ^ POOL[921];
+    r[9] = r[1] ^ POOL[922];
+    r[1] = r[9] ^ POOL[923];
+    r[9] = r[2] ^ POOL[924];
+    r[2] = {
+        let (t, s) = (POOL[925], POOL[926]);
+        let (ma, mb) = (t ^ s ^ POOL[927], t ^ s ^ POOL[928]);
+        let mut z = t;
+        z ^= r[1] & mb;
+        z ^= r[9] & ma;
+        z ^= r[1] & r[9];
+        z
+    };
+    r[9] = r[7] ^ r[2];
+    r[2] = r[9].rotate_left(1);
+    r[9] = r[0] & POOL[929];
+    r[7] = r[9] ^ r[2];
+    r[1] = r[6] ^ r[7];
+    r[7] = r[4] & POOL[930];
+    r[4] = r[9] ^ POOL[931];
+    r[9] = r[4] ^ POOL[932];
+    r[4] = r[9] ^ POOL[933];
+    r[9] = r[2] ^ POOL[934];
+    r[2] = {
+        let (t, s) = (POOL[935], POOL[936]);
+        let (ma, mb) = (t ^ s ^ POOL[937], t ^ s ^ POOL[938]);
+        let mut z = t;
+        z ^= r[4] & mb;
+        z ^= r[9] & ma;
+        z ^= r[4] & r[9];
+        z
+    };
+    r[9] = r[7] ^ r[2];
+    r[2] = r[9].rotate_left(1);
+    r[9] = r[0] & POOL[939];
+    r[0] = r[9] ^ r[2];
+    r[2] = r[1] ^ r[0];
+    r[0] = r[8] ^ r[2];
+    r[8] = r[0].rotate_left(8);
+    r[0] = r[5] ^ POOL[940];
+    r[9] = r[8] ^ POOL[941];
+    r[4] = {
+        let (t, s) = (POOL[942], POOL[943]);
+        let (ma, mb) = (t ^ s ^ POOL[944], t ^ s ^ POOL[945]);
+        let mut z = t;
+        z ^= r[0] & mb;
+        z ^= r[9] & ma;
+        z ^= r[0] & r[9];
+        z
+    };
+    r[0] = r[5] ^ r[8];
+    r[5] = r[0] & POOL[946];
+    r[9] = r[4] & POOL[947];
+    r[7] = r[9].rotate_left(1);
+    r[9] = r[0] & POOL[948];
+    r[1] = r[9] ^ r[7];
+    r[6] = r[5] ^ r[1];
+    r[5] = r[4] & POOL[949];
+    r[1] = r[9] ^ POOL[950];
+    r[9] = r[1] ^ POOL[951];
+    r[1] = r[9] ^ POOL[952];
+    r[9] = r[7] ^ POOL[953];
+    r[7] = r[9] ^ POOL[954];
+    r[9] = r[7] ^ POOL[955];
+    r[7] = {
+        let (t, s) = (POOL[956], POOL[957]);
+        let (ma, mb) = (t ^ s ^ POOL[958], t ^ s ^ POOL[959]);
+        let mut z = t;
+        z ^= r[1] & mb;
+        z ^= r[9] & ma;
+        z ^= r[1] & r[9];
+        z
+    };
+    r[9] = r[5] ^ r[7];
+    r[5] = r[9].rotate_left(1);
+    r[9] = r[0] & POOL[960];
+    r[7] = r[9] ^ r[5];
+    r[1] = r[6] ^ r[7];
+    r[7] = r[4] & POOL[961];
+    r[6] = r[9] ^ POOL[962];
+    r[9] = r[6] ^ POOL[963];
+    r[6] = r[9] ^ POOL[964];
+    r[9] = r[5] ^ POOL[965];
+    r[5] = {
+        let (t, s) = (POOL[966], POOL[967]);
+        let (ma, mb) = (t ^ s ^ POOL[968], t ^ s ^ POOL[969]);
+        let mut z = t;
+        z ^= r[6] & mb;
+        z ^= r[9] & ma;
+        z ^= r[6] & r[9];
+        z
+    };
+    r[9] = r[7] ^ r[5];
+    r[5] = r[9].rotate_left(1);
+    r[9] = r[0] & POOL[970];
+    r[7] = r[9] ^ r[5];
+    r[6] = r[1] ^ r[7];
+    r[7] = r[4] & POOL[971];
+    r[1] = r[9] ^ POOL[972];
+    r[9] = r[1] ^ POOL[973];
+    r[1] = r[9] ^ POOL[974];
+    r[9] = r[5] ^ POOL[975];
+    r[5] = {
+        let (t, s) = (POOL[976], POOL[977]);
+        let (ma, mb) = (t ^ s ^ POOL[978], t ^ s ^ POOL[979]);
+        let mut z = t;
+        z ^= r[1] & mb;
+        z ^= r[9] & ma;
+        z ^= r[1] & r[9];
+        z
+    };
+    r[9] = r[7] ^ r[5];
+    r[5] = r[9].rotate_left(1);
+    r[9] = r[0] & POOL[980];
+    r[7] = r[9] ^ r[5];
+    r[1] = r[6] ^ r[7];
+    r[7] = r[4] & POOL[981];
+    r[6] = r[9] ^ POOL[982];
+    r[9] = r[6] ^ POOL[983];
+    r[6] = r[9] ^ POOL[984];
+    r[9] = r[5] ^ POOL[985];
+    r[5] = {
+        let (t, s) = (POOL[986], POOL[987]);
+        let (ma, mb) = (t ^ s ^ POOL[988], t ^ s ^ POOL[989]);
+        let mut z = t;
+        z ^= r[6] & mb;
+        z ^= r[9] & ma;
+        z ^= r[6] & r[9];
+        z
+    };
+    r[9] = r[7] ^ r[5];
+    r[5] = r[9].rotate_left(1);
+    r[9] = r[0] & POOL[990];
+    r[7] = r[9] ^ r[5];
+    r[6] = r[1] ^ r[7];
+    r[7] = r[4] & POOL[991];
+    r[1] = r[9] ^ POOL[992];
+    r[9] = r[1] ^ POOL[993];
+    r[1] = r[9] ^ POOL[994];
+    r[9] = r[5] ^ POOL[995];
+    r[5] = {
+        let (t, s) = (POOL[996], POOL[997]);
+        let (ma, mb) = (t ^ s ^ POOL[998], t ^ s ^ POOL[999]);
+        let mut z = t;
+        z ^= r[1] & mb;
+        z ^= r[9] & ma;
+        z ^= r[1] & r[9];
+        z
+    };
+    r[9] = r[7] ^ r[5];
+    r[5] = r[9].rotate_left(1);
+    r[9] = r[0] & POOL[1000];
+    r[7] = r[9] ^ r[5];
+    r[1] = r[6] ^ r[7];
+    r[7] = r[4] & POOL[1001];
+    r[6] = r[9] ^ POOL[1002];
+    r[9] = r[6] ^ POOL[1003];
+    r[6] = r[9] ^ POOL[1004];
+    r[9] = r[5] ^ POOL[1005];
+    r[5] = {
+        let (t, s) = (POOL[1006], POOL[1007]);
+        let (ma, mb) = (t ^ s ^ POOL[1008], t ^ s ^ POOL[1009]);
+        let mut z = t;
+        z ^= r[6] & mb;
+        z ^= r[9] & ma;
+        z ^= r[6] & r[9];
+        z
+    };
+    r[9] = r[7] ^ r[5];
+    r[5] = r[9].rotate_left(1);
+    r[9] = r[0] & POOL[1010];
+    r[7] = r[9] ^ r[5];
+    r[6] = r[1] ^ r[7];
+    r[7] = r[4] & POOL[1011];
+    r[1] = r[9] ^ POOL[1012];
+    r[9] = r[1] ^ POOL[1013];
+    r[1] = r[9] ^ POOL[1014];
+    r[9] = r[5] ^ POOL[1015];
+    r[5] = {
+        let (t, s) = (POOL[1016], POOL[1017]);
+        let (ma, mb) = (t ^ s ^ POOL[1018], t ^ s ^ POOL[1019]);
+        let mut z = t;
+        z ^= r[1] & mb;
+        z ^= r[9] & ma;
+        z ^= r[1] & r[9];
+        z
+    };
+    r[9] = r[7] ^ r[5];
+    r[5] = r[9].rotate_left(1);
+    r[9] = r[0] & POOL[1020];
+    r[7] = r[9] ^ r[5];
+    r[1] = r[6] ^ r[7];
+    r[7] = r[4] & POOL[1021];
+    r[6] = r[9] ^ POOL[1022];
+    r[9] = r[6] ^ POOL[1023];
+    r[6] = r[9] ^ POOL[1024];
+    r[9] = r[5] ^ POOL[1025];
+    r[5] = {
+        let (t, s) = (POOL[1026], POOL[1027]);
+        let (ma, mb) = (t ^ s ^ POOL[1028], t ^ s ^ POOL[1029]);
+        let mut z = t;
+        z ^= r[6] & mb;
+        z ^= r[9] & ma;
+        z ^= r[6] & r[9];
+        z
+    };
+    r[9] = r[7] ^ r[5];
+    r[5] = r[9].rotate_left(1);
+    r[9] = r[0] & POOL[1030];
+    r[7] = r[9] ^ r[5];
+    r[6] = r[1] ^ r[7];
+    r[7] = r[4] & POOL[1031];
+    r[1] = r[9] ^ POOL[1032];
+    r[9] = r[1] ^ POOL[1033];
+    r[1] = r[9] ^ POOL[1034];
+    r[9] = r[5] ^ POOL[1035];
+    r[5] = {
+        let (t, s) = (POOL[1036], POOL[1037]);
+        let (ma, mb) = (t ^ s ^ POOL[1038], t ^ s ^ POOL[1039]);
+        let mut z = t;
+        z ^= r[1] & mb;
+        z ^= r[9] & ma;
+        z ^= r[1] & r[9];
+        z
+    };
+    r[9] = r[7] ^ r[5];
+    r[5] = r[9].rotate_left(1);
+    r[9] = r[0] & POOL[1040];
+    r[7] = r[9] ^ r[5];
+    r[1] = r[6] ^ r[7];
+    r[7] = r[4] & POOL[1041];
+    r[6] = r[9] ^ POOL[1042];
+    r[9] = r[6] ^ POOL[1043];
+    r[6] = r[9] ^ POOL[1044];
+    r[9] = r[5] ^ POOL[1045];
+    r[5] = {
+        let (t, s) = (POOL[1046], POOL[1047]);
+        let (ma, mb) = (t ^ s ^ POOL[1048], t ^ s ^ POOL[1049]);
+        let mut z = t;
+        z ^= r[6] & mb;
+        z ^= r[9] & ma;
+        z ^= r[6] & r[9];
+        z
+    };
+    r[9] = r[7] ^ r[5];
+    r[5] = r[9].rotate_left(1);
+    r[9] = r[0] & POOL[1050];
+    r[7] = r[9] ^ r[5];
+    r[6] = r[1] ^ r[7];
+    r[7] = r[4] & POOL[1051];
+    r[1] = r[9] ^ POOL[1052];
+    r[9] = r[1] ^ POOL[1053];
+    r[1] = r[9] ^ POOL[1054];
+    r[9] = r[5] ^ POOL[1055];
+    r[5] = {
+        let (t, s) = (POOL[1056], POOL[1057]);
+        let (ma, mb) = (t ^ s ^ POOL[1058], t ^ s ^ POOL[1059]);
+        let mut z = t;
+        z ^= r[1] & mb;
+        z ^= r[9] & ma;
+        z ^= r[1] & r[9];
+        z
+    };
+    r[9] = r[7] ^ r[5];
+    r[5] = r[9].rotate_left(1);
+    r[9] = r[0] & POOL[1060];
+    r[7] = r[9] ^ r[5];
+    r[1] = r[6] ^ r[7];
+    r[7] = r[4] & POOL[1061];
+    r[6] = r[9] ^ POOL[1062];
+    r[9] = r[6] ^ POOL[1063];
+    r[6] = r[9] ^ POOL[1064];
+    r[9] = r[5] ^ POOL[1065];
+    r[5] = {
+        let (t, s) = (POOL[1066], POOL[1067]);
+        let (ma, mb) = (t ^ s ^ POOL[1068], t ^ s ^ POOL[1069]);
+        let mut z = t;
+        z ^= r[6] & mb;
+        z ^= r[9] & ma;
+        z ^= r[6] & r[9];
+        z
+    };
+    r[9] = r[7] ^ r[5];
+    r[5] = r[9].rotate_left(1);
+    r[9] = r[0] & POOL[1070];
+    r[7] = r[9] ^ r[5];
+    r[6] = r[1] ^ r[7];
+    r[7] = r[4] & POOL[1071];
+    r[1] = r[9] ^ POOL[1072];
+    r[9] = r[1] ^ POOL[1073];
+    r[1] = r[9] ^ POOL[1074];
+    r[9] = r[5] ^ POOL[1075];
+    r[5] = {
+        let (t, s) = (POOL[1076], POOL[1077]);
+        let (ma, mb) = (t ^ s ^ POOL[1078], t ^ s ^ POOL[1079]);
+        let mut z = t;
+        z ^= r[1] & mb;
+        z ^= r[9] & ma;
+        z ^= r[1] & r[9];
+        z
+    };
+    r[9] = r[7] ^ r[5];
+    r[5] = r[9].rotate_left(1);
+    r[9] = r[0] & POOL[1080];
+    r[7] = r[9] ^ r[5];
+    r[1] = r[6] ^ r[7];
+    r[7] = r[4] & POOL[1081];
+    r[6] = r[9] ^ POOL[1082];
+    r[9] = r[6] ^ POOL[1083];
+    r[6] = r[9] ^ POOL[1084];
+    r[9] = r[5] ^ POOL[1085];
+    r[5] = {
+        let (t, s) = (POOL[1086], POOL[1087]);
+        let (ma, mb) = (t ^ s ^ POOL[1088], t ^ s ^ POOL[1089]);
+        let mut z = t;
+        z ^= r[6] & mb;
+        z ^= r[9] & ma;
+        z ^= r[6] & r[9];
+        z
+    };
+    r[9] = r[7] ^ r[5];
+    r[5] = r[9].rotate_left(1);
+    r[9] = r[0] & POOL[1090];
+    r[7] = r[9] ^ r[5];
+    r[6] = r[1] ^ r[7];
+    r[7] = r[4] & POOL[1091];
+    r[1] = r[9] ^ POOL[1092];
+    r[9] = r[1] ^ POOL[1093];
+    r[1] = r[9] ^ POOL[1094];
+    r[9] = r[5] ^ POOL[1095];
+    r[5] = {
+        let (t, s) = (POOL[1096], POOL[1097]);
+        let (ma, mb) = (t ^ s ^ POOL[1098], t ^ s ^ POOL[1099]);
+        let mut z = t;
+        z ^= r[1] & mb;
+        z ^= r[9] & ma;
+        z ^= r[1] & r[9];
+        z
+    };
+    r[9] = r[7] ^ r[5];
+    r[5] = r[9].rotate_left(1);
+    r[9] = r[0] & POOL[1100];
+    r[7] = r[9] ^ r[5];
+    r[1] = r[6] ^ r[7];
+    r[7] = r[4] & POOL[1101];
+    r[6] = r[9] ^ POOL[1102];
+    r[9] = r[6] ^ POOL[1103];
+    r[6] = r[9] ^ POOL[1104];
+    r[9] = r[5] ^ POOL[1105];
+    r[5] = {
+        let (t, s) = (POOL[1106], POOL[1107]);
+        let (ma, mb) = (t ^ s ^ POOL[1108], t ^ s ^ POOL[1109]);
+        let mut z = t;
+        z ^= r[6] & mb;
+        z ^= r[9] & ma;
+        z ^= r[6] & r[9];
+        z
+    };
+    r[9] = r[7] ^ r[5];
+    r[5] = r[9].rotate_left(1);
+    r[9] = r[0] & POOL[1110];
+    r[7] = r[9] ^ r[5];
+    r[6] = r[1] ^ r[7];
+    r[7] = r[4] & POOL[1111];
+    r[1] = r[9] ^ POOL[1112];
+    r[9] = r[1] ^ POOL[1113];
+    r[1] = r[9] ^ POOL[1114];
+    r[9] = r[5] ^ POOL[1115];
+    r[5] = {
+        let (t, s) = (POOL[1116], POOL[1117]);
+        let (ma, mb) = (t ^ s ^ POOL[1118], t ^ s ^ POOL[1119]);
+        let mut z = t;
+        z ^= r[1] & mb;
+        z ^= r[9] & ma;
+        z ^= r[1] & r[9];
+        z
+    };
+    r[9] = r[7] ^ r[5];
+    r[5] = r[9].rotate_left(1);
+    r[9] = r[0] & POOL[1120];
+    r[7] = r[9] ^ r[5];
+    r[1] = r[6] ^ r[7];
+    r[7] = r[4] & POOL[1121];
+    r[6] = r[9] ^ POOL[1122];
+    r[9] = r[6] ^ POOL[1123];
+    r[6] = r[9] ^ POOL[1124];
+    r[9] = r[5] ^ POOL[1125];
+    r[5] = {
+        let (t, s) = (POOL[1126], POOL[1127]);
+        let (ma, mb) = (t ^ s ^ POOL[1128], t ^ s ^ POOL[1129]);
+        let mut z = t;
+        z ^= r[6] & mb;
+        z ^= r[9] & ma;
+        z ^= r[6] & r[9];
+        z
+    };
+    r[9] = r[7] ^ r[5];
+    r[5] = r[9].rotate_left(1);
+    r[9] = r[0] & POOL[1130];
+    r[7] = r[9] ^ r[5];
+    r[6] = r[1] ^ r[7];
+    r[7] = r[4] & POOL[1131];
+    r[1] = r[9] ^ POOL[1132];
+    r[9] = r[1] ^ POOL[1133];
+    r[1] = r[9] ^ POOL[1134];
+    r[9] = r[5] ^ POOL[1135];
+    r[5] = {
+        let (t, s) = (POOL[1136], POOL[1137]);
+        let (ma, mb) = (t ^ s ^ POOL[1138], t ^ s ^ POOL[1139]);
+        let mut z = t;
+        z ^= r[1] & mb;
+        z ^= r[9] & ma;
+        z ^= r[1] & r[9];
+        z
+    };
+    r[9] = r[7] ^ r[5];
+    r[5] = r[9].rotate_left(1);
+    r[9] = r[0] & POOL[1140];
+    r[7] = r[9] ^ r[5];
+    r[1] = r[6] ^ r[7];
+    r[7] = r[4] & POOL[1141];
+    r[6] = r[9] ^ POOL[1142];
+    r[9] = r[6] ^ POOL[1143];
+    r[6] = r[9] ^ POOL[1144];
+    r[9] = r[5] ^ POOL[1145];
+    r[5] = {
+        let (t, s) = (POOL[1146], POOL[1147]);
+        let (ma, mb) = (t ^ s ^ POOL[1148], t ^ s ^ POOL[1149]);
+        let mut z = t;
+        z ^= r[6] & mb;
+        z ^= r[9] & ma;
+        z ^= r[6] & r[9];
+        z
+    };
+    r[9] = r[7] ^ r[5];
+    r[5] = r[9].rotate_left(1);
+    r[9] = r[0] & POOL[1150];
+    r[7] = r[9] ^ r[5];
+    r[6] = r[1] ^ r[7];
+    r[7] = r[4] & POOL[1151];
+    r[1] = r[9] ^ POOL[1152];
+    r[9] = r[1] ^ POOL[1153];
+    r[1] = r[9] ^ POOL[1154];
+    r[9] = r[5] ^ POOL[1155];
+    r[5] = {
+        let (t, s) = (POOL[1156], POOL[1157]);
+        let (ma, mb) = (t ^ s ^ POOL[1158], t ^ s ^ POOL[1159]);
+        let mut z = t;
+        z ^= r[1] & mb;
+        z ^= r[9] & ma;
+        z ^= r[1] & r[9];
+        z
+    };
+    r[9] = r[7] ^ r[5];
+    r[5] = r[9].rotate_left(1);
+    r[9] = r[0] & POOL[1160];
+    r[7] = r[9] ^ r[5];
+    r[1] = r[6] ^ r[7];
+    r[7] = r[4] & POOL[1161];
+    r[6] = r[9] ^ POOL[1162];
+    r[9] = r[6] ^ POOL[1163];
+    r[6] = r[9] ^ POOL[1164];
+    r[9] = r[5] ^ POOL[1165];
+    r[5] = {
+        let (t, s) = (POOL[1166], POOL[1167]);
+        let (ma, mb) = (t ^ s ^ POOL[1168], t ^ s ^ POOL[1169]);
+        let mut z = t;
+        z ^= r[6] & mb;
+        z ^= r[9] & ma;
+        z ^= r[6] & r[9];
+        z
+    };
+    r[9] = r[7] ^ r[5];
+    r[5] = r[9].rotate_left(1);
+    r[9] = r[0] & POOL[1170];
+    r[7] = r[9] ^ r[5];
+    r[6] = r[1] ^ r[7];
+    r[7] = r[4] & POOL[1171];
+    r[1] = r[9] ^ POOL[1172];
+    r[9] = r[1] ^ POOL[1173];
+    r[1] = r[9] ^ POOL[1174];
+    r[9] = r[5] ^ POOL[1175];
+    r[5] = {
+        let (t, s) = (POOL[1176], POOL[1177]);
+        let (ma, mb) = (t ^ s ^ POOL[1178], t ^ s ^ POOL[1179]);
+        let mut z = t;
+        z ^= r[1] & mb;
+        z ^= r[9] & ma;
+        z ^= r[1] & r[9];
+        z
+    };
+    r[9] = r[7] ^ r[5];
+    r[5] = r[9].rotate_left(1);
+    r[9] = r[0] & POOL[1180];
+    r[7] = r[9] ^ r[5];
+    r[1] = r[6] ^ r[7];
+    r[7] = r[4] & POOL[1181];
+    r[6] = r[9] ^ POOL[1182];
+    r[9] = r[6] ^ POOL[1183];
+    r[6] = r[9] ^ POOL[1184];
+    r[9] = r[5] ^ POOL[1185];
+    r[5] = {
+        let (t, s) = (POOL[1186], POOL[1187]);
+        let (ma, mb) = (t ^ s ^ POOL[1188], t ^ s ^ POOL[1189]);
+        let mut z = t;
+        z ^= r[6] & mb;
+        z ^= r[9] & ma;
+        z ^= r[6] & r[9];
+        z
+    };
+    r[9] = r[7] ^ r[5];
+    r[5] = r[9].rotate_left(1);
+    r[9] = r[0] & POOL[1190];
+    r[7] = r[9] ^ r[5];
+    r[6] = r[1] ^ r[7];
+    r[7] = r[4] & POOL[1191];
+    r[1] = r[9] ^ POOL[1192];
+    r[9] = r[1] ^ POOL[1193];
+    r[1] = r[9] ^ POOL[1194];
+    r[9] = r[5] ^ POOL[1195];
+    r[5] = {
+        let (t, s) = (POOL[1196], POOL[1197]);
+        let (ma, mb) = (t ^ s ^ POOL[1198], t ^ s ^ POOL[1199]);
+        let mut z = t;
+        z ^= r[1] & mb;
+        z ^= r[9] & ma;
+        z ^= r[1] & r[9];
+        z
+    };
+    r[9] = r[7] ^ r[5];
+    r[5] = r[9].rotate_left(1);
+    r[9] = r[0] & POOL[1200];
+    r[7] = r[9] ^ r[5];
+    r[1] = r[6] ^ r[7];
+    r[7] = r[4] & POOL[1201];
+    r[6] = r[9] ^ POOL[1202];
+    r[9] = r[6] ^ POOL[1203];
+    r[6] = r[9] ^ POOL[1204];
+    r[9] = r[5] ^ POOL[1205];
+    r[5] = {
+        let (t, s) = (POOL[1206], POOL[1207]);
+        let (ma, mb) = (t ^ s ^ POOL[1208], t ^ s ^ POOL[1209]);
+        let mut z = t;
+        z ^= r[6] & mb;
+        z ^= r[9] & ma;
+        z ^= r[6] & r[9];
+        z
+    };
+    r[9] = r[7] ^ r[5];
+    r[5] = r[9].rotate_left(1);
+    r[9] = r[0] & POOL[1210];
+    r[7] = r[9] ^ r[5];
+    r[6] = r[1] ^ r[7];
+    r[7] = r[4] & POOL[1211];
+    r[1] = r[9] ^ POOL[1212];
+    r[9] = r[1] ^ POOL[1213];
+    r[1] = r[9] ^ POOL[1214];
+    r[9] = r[5] ^ POOL[1215];
+    r[5] = {
+        let (t, s) = (POOL[1216], POOL[1217]);
+        let (ma, mb) = (t ^ s ^ POOL[1218], t ^ s ^ POOL[1219]);
+        let mut z = t;
+        z ^= r[1] & mb;
+        z ^= r[9] & ma;
+        z ^= r[1] & r[9];
+        z
+    };
+    r[9] = r[7] ^ r[5];
+    r[5] = r[9].rotate_left(1);
+    r[9] = r[0] & POOL[1220];
+    r[7] = r[9] ^ r[5];
+    r[1] = r[6] ^ r[7];
+    r[7] = r[4] & POOL[1221];
+    r[6] = r[9] ^ POOL[1222];
+    r[9] = r[6] ^ POOL[1223];
+    r[6] = r[9] ^ POOL[1224];
+    r[9] = r[5] ^ POOL[1225];
+    r[5] = {
+        let (t, s) = (POOL[1226], POOL[1227]);
+        let (ma, mb) = (t ^ s ^ POOL[1228], t ^ s ^ POOL[1229]);
+        let mut z = t;
+        z ^= r[6] & mb;
+        z ^= r[9] & ma;
+        z ^= r[6] & r[9];
+        z
+    };
+    r[9] = r[7] ^ r[5];
+    r[5] = r[9].rotate_left(1);
+    r[9] = r[0] & POOL[1230];
+    r[7] = r[9] ^ r[5];
+    r[6] = r[1] ^ r[7];
+    r[7] = r[4] & POOL[1231];
+    r[1] = r[9] ^ POOL[1232];
+    r[9] = r[1] ^ POOL[1233];
+    r[1] = r[9] ^ POOL[1234];
+    r[9] = r[5] ^ POOL[1235];
+    r[5] = {
+        let (t, s) = (POOL[1236], POOL[1237]);
+        let (ma, mb) = (t ^ s ^ POOL[1238], t ^ s ^ POOL[1239]);
+        let mut z = t;
+        z ^= r[1] & mb;
+        z ^= r[9] & ma;
+        z ^= r[1] & r[9];
+        z
+    };
+    r[9] = r[7] ^ r[5];
+    r[5] = r[9].rotate_left(1);
+    r[9] = r[0] & POOL[1240];
+    r[7] = r[9] ^ r[5];
+    r[1] = r[6] ^ r[7];
+    r[7] = r[4] & POOL[1241];
+    r[4] = r[9] ^ POOL[1242];
+    r[9] = r[4] ^ POOL[1243];
+    r[4] = r[9] ^ POOL[1244];
+    r[9] = r[5] ^ POOL[1245];
+    r[5] = {
+        let (t, s) = (POOL[1246], POOL[1247]);
+        let (ma, mb) = (t ^ s ^ POOL[1248], t ^ s ^ POOL[1249]);
+        let mut z = t;
+        z ^= r[4] & mb;
+        z ^= r[9] & ma;
+        z ^= r[4] & r[9];
+        z
+    };
+    r[9] = r[7] ^ r[5];
+    r[5] = r[9].rotate_left(1);
+    r[9] = r[0] & POOL[1250];
+    r[0] = r[9] ^ r[5];
+    r[5] = r[1] ^ r[0];
+    r[0] = r[3] ^ r[5];
+    r[3] = r[0].rotate_left(7);
+    r[0] = r[2] ^ POOL[1251];
+    r[2] = r[5] ^ POOL[1252];
+    r[5] = r[8] ^ POOL[1253];
+    r[8] = r[0] ^ POOL[1254];
+    r[0] = r[3] ^ POOL[1255];
+    r[3] = r[2] ^ POOL[1256];
+    r[2] = r[5] ^ POOL[1257];
+    [
+        r[8] ^ POOL[1258],
+        r[0] ^ POOL[1259],
+        r[3] ^ POOL[1260],
+        r[2] ^ POOL[1261],
+    ]
+}
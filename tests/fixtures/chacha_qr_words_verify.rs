@@ -0,0 +1,902 @@
+// Generated by xorpl — do not edit manually.
+pub const EXPR_DIGEST: [u8; 32] = [
+    0x57, 0x37, 0x8d, 0x1d, 0x35, 0x46, 0xa3, 0x80,
+    0xec, 0x05, 0x00, 0x45, 0xfb, 0xd9, 0xfc, 0xfb,
+    0x5c, 0xfc, 0xa5, 0xb5, 0x4a, 0x98, 0x6c, 0x5c,
+    0x85, 0x42, 0x33, 0x3a, 0xf5, 0x59, 0xc9, 0x48,
+];
+pub fn chacha_qr_words_verify(input_a: u32, input_b: u32, input_c: u32, input_d: u32) -> [u32; 4] {
+    let w0 = input_a;
+    let w1 = input_b;
+    let w2 = w0 & w1;
+    let w3 = w0 ^ w1;
+    let w4 = w3 & 0x00000001u32;
+    let w5 = w2 & 0x00000001u32;
+    let w6 = w5.rotate_left(1);
+    let w7 = w3 & 0x00000002u32;
+    let w8 = w7 ^ w6;
+    let w9 = w4 ^ w8;
+    let w10 = w2 & 0x00000002u32;
+    let w11 = w7 & w6;
+    let w12 = w10 ^ w11;
+    let w13 = w12.rotate_left(1);
+    let w14 = w3 & 0x00000004u32;
+    let w15 = w14 ^ w13;
+    let w16 = w9 ^ w15;
+    let w17 = w2 & 0x00000004u32;
+    let w18 = w14 & w13;
+    let w19 = w17 ^ w18;
+    let w20 = w19.rotate_left(1);
+    let w21 = w3 & 0x00000008u32;
+    let w22 = w21 ^ w20;
+    let w23 = w16 ^ w22;
+    let w24 = w2 & 0x00000008u32;
+    let w25 = w21 & w20;
+    let w26 = w24 ^ w25;
+    let w27 = w26.rotate_left(1);
+    let w28 = w3 & 0x00000010u32;
+    let w29 = w28 ^ w27;
+    let w30 = w23 ^ w29;
+    let w31 = w2 & 0x00000010u32;
+    let w32 = w28 & w27;
+    let w33 = w31 ^ w32;
+    let w34 = w33.rotate_left(1);
+    let w35 = w3 & 0x00000020u32;
+    let w36 = w35 ^ w34;
+    let w37 = w30 ^ w36;
+    let w38 = w2 & 0x00000020u32;
+    let w39 = w35 & w34;
+    let w40 = w38 ^ w39;
+    let w41 = w40.rotate_left(1);
+    let w42 = w3 & 0x00000040u32;
+    let w43 = w42 ^ w41;
+    let w44 = w37 ^ w43;
+    let w45 = w2 & 0x00000040u32;
+    let w46 = w42 & w41;
+    let w47 = w45 ^ w46;
+    let w48 = w47.rotate_left(1);
+    let w49 = w3 & 0x00000080u32;
+    let w50 = w49 ^ w48;
+    let w51 = w44 ^ w50;
+    let w52 = w2 & 0x00000080u32;
+    let w53 = w49 & w48;
+    let w54 = w52 ^ w53;
+    let w55 = w54.rotate_left(1);
+    let w56 = w3 & 0x00000100u32;
+    let w57 = w56 ^ w55;
+    let w58 = w51 ^ w57;
+    let w59 = w2 & 0x00000100u32;
+    let w60 = w56 & w55;
+    let w61 = w59 ^ w60;
+    let w62 = w61.rotate_left(1);
+    let w63 = w3 & 0x00000200u32;
+    let w64 = w63 ^ w62;
+    let w65 = w58 ^ w64;
+    let w66 = w2 & 0x00000200u32;
+    let w67 = w63 & w62;
+    let w68 = w66 ^ w67;
+    let w69 = w68.rotate_left(1);
+    let w70 = w3 & 0x00000400u32;
+    let w71 = w70 ^ w69;
+    let w72 = w65 ^ w71;
+    let w73 = w2 & 0x00000400u32;
+    let w74 = w70 & w69;
+    let w75 = w73 ^ w74;
+    let w76 = w75.rotate_left(1);
+    let w77 = w3 & 0x00000800u32;
+    let w78 = w77 ^ w76;
+    let w79 = w72 ^ w78;
+    let w80 = w2 & 0x00000800u32;
+    let w81 = w77 & w76;
+    let w82 = w80 ^ w81;
+    let w83 = w82.rotate_left(1);
+    let w84 = w3 & 0x00001000u32;
+    let w85 = w84 ^ w83;
+    let w86 = w79 ^ w85;
+    let w87 = w2 & 0x00001000u32;
+    let w88 = w84 & w83;
+    let w89 = w87 ^ w88;
+    let w90 = w89.rotate_left(1);
+    let w91 = w3 & 0x00002000u32;
+    let w92 = w91 ^ w90;
+    let w93 = w86 ^ w92;
+    let w94 = w2 & 0x00002000u32;
+    let w95 = w91 & w90;
+    let w96 = w94 ^ w95;
+    let w97 = w96.rotate_left(1);
+    let w98 = w3 & 0x00004000u32;
+    let w99 = w98 ^ w97;
+    let w100 = w93 ^ w99;
+    let w101 = w2 & 0x00004000u32;
+    let w102 = w98 & w97;
+    let w103 = w101 ^ w102;
+    let w104 = w103.rotate_left(1);
+    let w105 = w3 & 0x00008000u32;
+    let w106 = w105 ^ w104;
+    let w107 = w100 ^ w106;
+    let w108 = w2 & 0x00008000u32;
+    let w109 = w105 & w104;
+    let w110 = w108 ^ w109;
+    let w111 = w110.rotate_left(1);
+    let w112 = w3 & 0x00010000u32;
+    let w113 = w112 ^ w111;
+    let w114 = w107 ^ w113;
+    let w115 = w2 & 0x00010000u32;
+    let w116 = w112 & w111;
+    let w117 = w115 ^ w116;
+    let w118 = w117.rotate_left(1);
+    let w119 = w3 & 0x00020000u32;
+    let w120 = w119 ^ w118;
+    let w121 = w114 ^ w120;
+    let w122 = w2 & 0x00020000u32;
+    let w123 = w119 & w118;
+    let w124 = w122 ^ w123;
+    let w125 = w124.rotate_left(1);
+    let w126 = w3 & 0x00040000u32;
+    let w127 = w126 ^ w125;
+    let w128 = w121 ^ w127;
+    let w129 = w2 & 0x00040000u32;
+    let w130 = w126 & w125;
+    let w131 = w129 ^ w130;
+    let w132 = w131.rotate_left(1);
+    let w133 = w3 & 0x00080000u32;
+    let w134 = w133 ^ w132;
+    let w135 = w128 ^ w134;
+    let w136 = w2 & 0x00080000u32;
+    let w137 = w133 & w132;
+    let w138 = w136 ^ w137;
+    let w139 = w138.rotate_left(1);
+    let w140 = w3 & 0x00100000u32;
+    let w141 = w140 ^ w139;
+    let w142 = w135 ^ w141;
+    let w143 = w2 & 0x00100000u32;
+    let w144 = w140 & w139;
+    let w145 = w143 ^ w144;
+    let w146 = w145.rotate_left(1);
+    let w147 = w3 & 0x00200000u32;
+    let w148 = w147 ^ w146;
+    let w149 = w142 ^ w148;
+    let w150 = w2 & 0x00200000u32;
+    let w151 = w147 & w146;
+    let w152 = w150 ^ w151;
+    let w153 = w152.rotate_left(1);
+    let w154 = w3 & 0x00400000u32;
+    let w155 = w154 ^ w153;
+    let w156 = w149 ^ w155;
+    let w157 = w2 & 0x00400000u32;
+    let w158 = w154 & w153;
+    let w159 = w157 ^ w158;
+    let w160 = w159.rotate_left(1);
+    let w161 = w3 & 0x00800000u32;
+    let w162 = w161 ^ w160;
+    let w163 = w156 ^ w162;
+    let w164 = w2 & 0x00800000u32;
+    let w165 = w161 & w160;
+    let w166 = w164 ^ w165;
+    let w167 = w166.rotate_left(1);
+    let w168 = w3 & 0x01000000u32;
+    let w169 = w168 ^ w167;
+    let w170 = w163 ^ w169;
+    let w171 = w2 & 0x01000000u32;
+    let w172 = w168 & w167;
+    let w173 = w171 ^ w172;
+    let w174 = w173.rotate_left(1);
+    let w175 = w3 & 0x02000000u32;
+    let w176 = w175 ^ w174;
+    let w177 = w170 ^ w176;
+    let w178 = w2 & 0x02000000u32;
+    let w179 = w175 & w174;
+    let w180 = w178 ^ w179;
+    let w181 = w180.rotate_left(1);
+    let w182 = w3 & 0x04000000u32;
+    let w183 = w182 ^ w181;
+    let w184 = w177 ^ w183;
+    let w185 = w2 & 0x04000000u32;
+    let w186 = w182 & w181;
+    let w187 = w185 ^ w186;
+    let w188 = w187.rotate_left(1);
+    let w189 = w3 & 0x08000000u32;
+    let w190 = w189 ^ w188;
+    let w191 = w184 ^ w190;
+    let w192 = w2 & 0x08000000u32;
+    let w193 = w189 & w188;
+    let w194 = w192 ^ w193;
+    let w195 = w194.rotate_left(1);
+    let w196 = w3 & 0x10000000u32;
+    let w197 = w196 ^ w195;
+    let w198 = w191 ^ w197;
+    let w199 = w2 & 0x10000000u32;
+    let w200 = w196 & w195;
+    let w201 = w199 ^ w200;
+    let w202 = w201.rotate_left(1);
+    let w203 = w3 & 0x20000000u32;
+    let w204 = w203 ^ w202;
+    let w205 = w198 ^ w204;
+    let w206 = w2 & 0x20000000u32;
+    let w207 = w203 & w202;
+    let w208 = w206 ^ w207;
+    let w209 = w208.rotate_left(1);
+    let w210 = w3 & 0x40000000u32;
+    let w211 = w210 ^ w209;
+    let w212 = w205 ^ w211;
+    let w213 = w2 & 0x40000000u32;
+    let w214 = w210 & w209;
+    let w215 = w213 ^ w214;
+    let w216 = w215.rotate_left(1);
+    let w217 = w3 & 0x80000000u32;
+    let w218 = w217 ^ w216;
+    let w219 = w212 ^ w218;
+    let w220 = input_c;
+    let w221 = input_d;
+    let w222 = w221 ^ w219;
+    let w223 = w222.rotate_left(16);
+    let w224 = w220 & w223;
+    let w225 = w220 ^ w223;
+    let w226 = w225 & 0x00000001u32;
+    let w227 = w224 & 0x00000001u32;
+    let w228 = w227.rotate_left(1);
+    let w229 = w225 & 0x00000002u32;
+    let w230 = w229 ^ w228;
+    let w231 = w226 ^ w230;
+    let w232 = w224 & 0x00000002u32;
+    let w233 = w229 & w228;
+    let w234 = w232 ^ w233;
+    let w235 = w234.rotate_left(1);
+    let w236 = w225 & 0x00000004u32;
+    let w237 = w236 ^ w235;
+    let w238 = w231 ^ w237;
+    let w239 = w224 & 0x00000004u32;
+    let w240 = w236 & w235;
+    let w241 = w239 ^ w240;
+    let w242 = w241.rotate_left(1);
+    let w243 = w225 & 0x00000008u32;
+    let w244 = w243 ^ w242;
+    let w245 = w238 ^ w244;
+    let w246 = w224 & 0x00000008u32;
+    let w247 = w243 & w242;
+    let w248 = w246 ^ w247;
+    let w249 = w248.rotate_left(1);
+    let w250 = w225 & 0x00000010u32;
+    let w251 = w250 ^ w249;
+    let w252 = w245 ^ w251;
+    let w253 = w224 & 0x00000010u32;
+    let w254 = w250 & w249;
+    let w255 = w253 ^ w254;
+    let w256 = w255.rotate_left(1);
+    let w257 = w225 & 0x00000020u32;
+    let w258 = w257 ^ w256;
+    let w259 = w252 ^ w258;
+    let w260 = w224 & 0x00000020u32;
+    let w261 = w257 & w256;
+    let w262 = w260 ^ w261;
+    let w263 = w262.rotate_left(1);
+    let w264 = w225 & 0x00000040u32;
+    let w265 = w264 ^ w263;
+    let w266 = w259 ^ w265;
+    let w267 = w224 & 0x00000040u32;
+    let w268 = w264 & w263;
+    let w269 = w267 ^ w268;
+    let w270 = w269.rotate_left(1);
+    let w271 = w225 & 0x00000080u32;
+    let w272 = w271 ^ w270;
+    let w273 = w266 ^ w272;
+    let w274 = w224 & 0x00000080u32;
+    let w275 = w271 & w270;
+    let w276 = w274 ^ w275;
+    let w277 = w276.rotate_left(1);
+    let w278 = w225 & 0x00000100u32;
+    let w279 = w278 ^ w277;
+    let w280 = w273 ^ w279;
+    let w281 = w224 & 0x00000100u32;
+    let w282 = w278 & w277;
+    let w283 = w281 ^ w282;
+    let w284 = w283.rotate_left(1);
+    let w285 = w225 & 0x00000200u32;
+    let w286 = w285 ^ w284;
+    let w287 = w280 ^ w286;
+    let w288 = w224 & 0x00000200u32;
+    let w289 = w285 & w284;
+    let w290 = w288 ^ w289;
+    let w291 = w290.rotate_left(1);
+    let w292 = w225 & 0x00000400u32;
+    let w293 = w292 ^ w291;
+    let w294 = w287 ^ w293;
+    let w295 = w224 & 0x00000400u32;
+    let w296 = w292 & w291;
+    let w297 = w295 ^ w296;
+    let w298 = w297.rotate_left(1);
+    let w299 = w225 & 0x00000800u32;
+    let w300 = w299 ^ w298;
+    let w301 = w294 ^ w300;
+    let w302 = w224 & 0x00000800u32;
+    let w303 = w299 & w298;
+    let w304 = w302 ^ w303;
+    let w305 = w304.rotate_left(1);
+    let w306 = w225 & 0x00001000u32;
+    let w307 = w306 ^ w305;
+    let w308 = w301 ^ w307;
+    let w309 = w224 & 0x00001000u32;
+    let w310 = w306 & w305;
+    let w311 = w309 ^ w310;
+    let w312 = w311.rotate_left(1);
+    let w313 = w225 & 0x00002000u32;
+    let w314 = w313 ^ w312;
+    let w315 = w308 ^ w314;
+    let w316 = w224 & 0x00002000u32;
+    let w317 = w313 & w312;
+    let w318 = w316 ^ w317;
+    let w319 = w318.rotate_left(1);
+    let w320 = w225 & 0x00004000u32;
+    let w321 = w320 ^ w319;
+    let w322 = w315 ^ w321;
+    let w323 = w224 & 0x00004000u32;
+    let w324 = w320 & w319;
+    let w325 = w323 ^ w324;
+    let w326 = w325.rotate_left(1);
+    let w327 = w225 & 0x00008000u32;
+    let w328 = w327 ^ w326;
+    let w329 = w322 ^ w328;
+    let w330 = w224 & 0x00008000u32;
+    let w331 = w327 & w326;
+    let w332 = w330 ^ w331;
+    let w333 = w332.rotate_left(1);
+    let w334 = w225 & 0x00010000u32;
+    let w335 = w334 ^ w333;
+    let w336 = w329 ^ w335;
+    let w337 = w224 & 0x00010000u32;
+    let w338 = w334 & w333;
+    let w339 = w337 ^ w338;
+    let w340 = w339.rotate_left(1);
+    let w341 = w225 & 0x00020000u32;
+    let w342 = w341 ^ w340;
+    let w343 = w336 ^ w342;
+    let w344 = w224 & 0x00020000u32;
+    let w345 = w341 & w340;
+    let w346 = w344 ^ w345;
+    let w347 = w346.rotate_left(1);
+    let w348 = w225 & 0x00040000u32;
+    let w349 = w348 ^ w347;
+    let w350 = w343 ^ w349;
+    let w351 = w224 & 0x00040000u32;
+    let w352 = w348 & w347;
+    let w353 = w351 ^ w352;
+    let w354 = w353.rotate_left(1);
+    let w355 = w225 & 0x00080000u32;
+    let w356 = w355 ^ w354;
+    let w357 = w350 ^ w356;
+    let w358 = w224 & 0x00080000u32;
+    let w359 = w355 & w354;
+    let w360 = w358 ^ w359;
+    let w361 = w360.rotate_left(1);
+    let w362 = w225 & 0x00100000u32;
+    let w363 = w362 ^ w361;
+    let w364 = w357 ^ w363;
+    let w365 = w224 & 0x00100000u32;
+    let w366 = w362 & w361;
+    let w367 = w365 ^ w366;
+    let w368 = w367.rotate_left(1);
+    let w369 = w225 & 0x00200000u32;
+    let w370 = w369 ^ w368;
+    let w371 = w364 ^ w370;
+    let w372 = w224 & 0x00200000u32;
+    let w373 = w369 & w368;
+    let w374 = w372 ^ w373;
+    let w375 = w374.rotate_left(1);
+    let w376 = w225 & 0x00400000u32;
+    let w377 = w376 ^ w375;
+    let w378 = w371 ^ w377;
+    let w379 = w224 & 0x00400000u32;
+    let w380 = w376 & w375;
+    let w381 = w379 ^ w380;
+    let w382 = w381.rotate_left(1);
+    let w383 = w225 & 0x00800000u32;
+    let w384 = w383 ^ w382;
+    let w385 = w378 ^ w384;
+    let w386 = w224 & 0x00800000u32;
+    let w387 = w383 & w382;
+    let w388 = w386 ^ w387;
+    let w389 = w388.rotate_left(1);
+    let w390 = w225 & 0x01000000u32;
+    let w391 = w390 ^ w389;
+    let w392 = w385 ^ w391;
+    let w393 = w224 & 0x01000000u32;
+    let w394 = w390 & w389;
+    let w395 = w393 ^ w394;
+    let w396 = w395.rotate_left(1);
+    let w397 = w225 & 0x02000000u32;
+    let w398 = w397 ^ w396;
+    let w399 = w392 ^ w398;
+    let w400 = w224 & 0x02000000u32;
+    let w401 = w397 & w396;
+    let w402 = w400 ^ w401;
+    let w403 = w402.rotate_left(1);
+    let w404 = w225 & 0x04000000u32;
+    let w405 = w404 ^ w403;
+    let w406 = w399 ^ w405;
+    let w407 = w224 & 0x04000000u32;
+    let w408 = w404 & w403;
+    let w409 = w407 ^ w408;
+    let w410 = w409.rotate_left(1);
+    let w411 = w225 & 0x08000000u32;
+    let w412 = w411 ^ w410;
+    let w413 = w406 ^ w412;
+    let w414 = w224 & 0x08000000u32;
+    let w415 = w411 & w410;
+    let w416 = w414 ^ w415;
+    let w417 = w416.rotate_left(1);
+    let w418 = w225 & 0x10000000u32;
+    let w419 = w418 ^ w417;
+    let w420 = w413 ^ w419;
+    let w421 = w224 & 0x10000000u32;
+    let w422 = w418 & w417;
+    let w423 = w421 ^ w422;
+    let w424 = w423.rotate_left(1);
+    let w425 = w225 & 0x20000000u32;
+    let w426 = w425 ^ w424;
+    let w427 = w420 ^ w426;
+    let w428 = w224 & 0x20000000u32;
+    let w429 = w425 & w424;
+    let w430 = w428 ^ w429;
+    let w431 = w430.rotate_left(1);
+    let w432 = w225 & 0x40000000u32;
+    let w433 = w432 ^ w431;
+    let w434 = w427 ^ w433;
+    let w435 = w224 & 0x40000000u32;
+    let w436 = w432 & w431;
+    let w437 = w435 ^ w436;
+    let w438 = w437.rotate_left(1);
+    let w439 = w225 & 0x80000000u32;
+    let w440 = w439 ^ w438;
+    let w441 = w434 ^ w440;
+    let w442 = w1 ^ w441;
+    let w443 = w442.rotate_left(12);
+    let w444 = w219 & w443;
+    let w445 = w219 ^ w443;
+    let w446 = w445 & 0x00000001u32;
+    let w447 = w444 & 0x00000001u32;
+    let w448 = w447.rotate_left(1);
+    let w449 = w445 & 0x00000002u32;
+    let w450 = w449 ^ w448;
+    let w451 = w446 ^ w450;
+    let w452 = w444 & 0x00000002u32;
+    let w453 = w449 & w448;
+    let w454 = w452 ^ w453;
+    let w455 = w454.rotate_left(1);
+    let w456 = w445 & 0x00000004u32;
+    let w457 = w456 ^ w455;
+    let w458 = w451 ^ w457;
+    let w459 = w444 & 0x00000004u32;
+    let w460 = w456 & w455;
+    let w461 = w459 ^ w460;
+    let w462 = w461.rotate_left(1);
+    let w463 = w445 & 0x00000008u32;
+    let w464 = w463 ^ w462;
+    let w465 = w458 ^ w464;
+    let w466 = w444 & 0x00000008u32;
+    let w467 = w463 & w462;
+    let w468 = w466 ^ w467;
+    let w469 = w468.rotate_left(1);
+    let w470 = w445 & 0x00000010u32;
+    let w471 = w470 ^ w469;
+    let w472 = w465 ^ w471;
+    let w473 = w444 & 0x00000010u32;
+    let w474 = w470 & w469;
+    let w475 = w473 ^ w474;
+    let w476 = w475.rotate_left(1);
+    let w477 = w445 & 0x00000020u32;
+    let w478 = w477 ^ w476;
+    let w479 = w472 ^ w478;
+    let w480 = w444 & 0x00000020u32;
+    let w481 = w477 & w476;
+    let w482 = w480 ^ w481;
+    let w483 = w482.rotate_left(1);
+    let w484 = w445 & 0x00000040u32;
+    let w485 = w484 ^ w483;
+    let w486 = w479 ^ w485;
+    let w487 = w444 & 0x00000040u32;
+    let w488 = w484 & w483;
+    let w489 = w487 ^ w488;
+    let w490 = w489.rotate_left(1);
+    let w491 = w445 & 0x00000080u32;
+    let w492 = w491 ^ w490;
+    let w493 = w486 ^ w492;
+    let w494 = w444 & 0x00000080u32;
+    let w495 = w491 & w490;
+    let w496 = w494 ^ w495;
+    let w497 = w496.rotate_left(1);
+    let w498 = w445 & 0x00000100u32;
+    let w499 = w498 ^ w497;
+    let w500 = w493 ^ w499;
+    let w501 = w444 & 0x00000100u32;
+    let w502 = w498 & w497;
+    let w503 = w501 ^ w502;
+    let w504 = w503.rotate_left(1);
+    let w505 = w445 & 0x00000200u32;
+    let w506 = w505 ^ w504;
+    let w507 = w500 ^ w506;
+    let w508 = w444 & 0x00000200u32;
+    let w509 = w505 & w504;
+    let w510 = w508 ^ w509;
+    let w511 = w510.rotate_left(1);
+    let w512 = w445 & 0x00000400u32;
+    let w513 = w512 ^ w511;
+    let w514 = w507 ^ w513;
+    let w515 = w444 & 0x00000400u32;
+    let w516 = w512 & w511;
+    let w517 = w515 ^ w516;
+    let w518 = w517.rotate_left(1);
+    let w519 = w445 & 0x00000800u32;
+    let w520 = w519 ^ w518;
+    let w521 = w514 ^ w520;
+    let w522 = w444 & 0x00000800u32;
+    let w523 = w519 & w518;
+    let w524 = w522 ^ w523;
+    let w525 = w524.rotate_left(1);
+    let w526 = w445 & 0x00001000u32;
+    let w527 = w526 ^ w525;
+    let w528 = w521 ^ w527;
+    let w529 = w444 & 0x00001000u32;
+    let w530 = w526 & w525;
+    let w531 = w529 ^ w530;
+    let w532 = w531.rotate_left(1);
+    let w533 = w445 & 0x00002000u32;
+    let w534 = w533 ^ w532;
+    let w535 = w528 ^ w534;
+    let w536 = w444 & 0x00002000u32;
+    let w537 = w533 & w532;
+    let w538 = w536 ^ w537;
+    let w539 = w538.rotate_left(1);
+    let w540 = w445 & 0x00004000u32;
+    let w541 = w540 ^ w539;
+    let w542 = w535 ^ w541;
+    let w543 = w444 & 0x00004000u32;
+    let w544 = w540 & w539;
+    let w545 = w543 ^ w544;
+    let w546 = w545.rotate_left(1);
+    let w547 = w445 & 0x00008000u32;
+    let w548 = w547 ^ w546;
+    let w549 = w542 ^ w548;
+    let w550 = w444 & 0x00008000u32;
+    let w551 = w547 & w546;
+    let w552 = w550 ^ w551;
+    let w553 = w552.rotate_left(1);
+    let w554 = w445 & 0x00010000u32;
+    let w555 = w554 ^ w553;
+    let w556 = w549 ^ w555;
+    let w557 = w444 & 0x00010000u32;
+    let w558 = w554 & w553;
+    let w559 = w557 ^ w558;
+    let w560 = w559.rotate_left(1);
+    let w561 = w445 & 0x00020000u32;
+    let w562 = w561 ^ w560;
+    let w563 = w556 ^ w562;
+    let w564 = w444 & 0x00020000u32;
+    let w565 = w561 & w560;
+    let w566 = w564 ^ w565;
+    let w567 = w566.rotate_left(1);
+    let w568 = w445 & 0x00040000u32;
+    let w569 = w568 ^ w567;
+    let w570 = w563 ^ w569;
+    let w571 = w444 & 0x00040000u32;
+    let w572 = w568 & w567;
+    let w573 = w571 ^ w572;
+    let w574 = w573.rotate_left(1);
+    let w575 = w445 & 0x00080000u32;
+    let w576 = w575 ^ w574;
+    let w577 = w570 ^ w576;
+    let w578 = w444 & 0x00080000u32;
+    let w579 = w575 & w574;
+    let w580 = w578 ^ w579;
+    let w581 = w580.rotate_left(1);
+    let w582 = w445 & 0x00100000u32;
+    let w583 = w582 ^ w581;
+    let w584 = w577 ^ w583;
+    let w585 = w444 & 0x00100000u32;
+    let w586 = w582 & w581;
+    let w587 = w585 ^ w586;
+    let w588 = w587.rotate_left(1);
+    let w589 = w445 & 0x00200000u32;
+    let w590 = w589 ^ w588;
+    let w591 = w584 ^ w590;
+    let w592 = w444 & 0x00200000u32;
+    let w593 = w589 & w588;
+    let w594 = w592 ^ w593;
+    let w595 = w594.rotate_left(1);
+    let w596 = w445 & 0x00400000u32;
+    let w597 = w596 ^ w595;
+    let w598 = w591 ^ w597;
+    let w599 = w444 & 0x00400000u32;
+    let w600 = w596 & w595;
+    let w601 = w599 ^ w600;
+    let w602 = w601.rotate_left(1);
+    let w603 = w445 & 0x00800000u32;
+    let w604 = w603 ^ w602;
+    let w605 = w598 ^ w604;
+    let w606 = w444 & 0x00800000u32;
+    let w607 = w603 & w602;
+    let w608 = w606 ^ w607;
+    let w609 = w608.rotate_left(1);
+    let w610 = w445 & 0x01000000u32;
+    let w611 = w610 ^ w609;
+    let w612 = w605 ^ w611;
+    let w613 = w444 & 0x01000000u32;
+    let w614 = w610 & w609;
+    let w615 = w613 ^ w614;
+    let w616 = w615.rotate_left(1);
+    let w617 = w445 & 0x02000000u32;
+    let w618 = w617 ^ w616;
+    let w619 = w612 ^ w618;
+    let w620 = w444 & 0x02000000u32;
+    let w621 = w617 & w616;
+    let w622 = w620 ^ w621;
+    let w623 = w622.rotate_left(1);
+    let w624 = w445 & 0x04000000u32;
+    let w625 = w624 ^ w623;
+    let w626 = w619 ^ w625;
+    let w627 = w444 & 0x04000000u32;
+    let w628 = w624 & w623;
+    let w629 = w627 ^ w628;
+    let w630 = w629.rotate_left(1);
+    let w631 = w445 & 0x08000000u32;
+    let w632 = w631 ^ w630;
+    let w633 = w626 ^ w632;
+    let w634 = w444 & 0x08000000u32;
+    let w635 = w631 & w630;
+    let w636 = w634 ^ w635;
+    let w637 = w636.rotate_left(1);
+    let w638 = w445 & 0x10000000u32;
+    let w639 = w638 ^ w637;
+    let w640 = w633 ^ w639;
+    let w641 = w444 & 0x10000000u32;
+    let w642 = w638 & w637;
+    let w643 = w641 ^ w642;
+    let w644 = w643.rotate_left(1);
+    let w645 = w445 & 0x20000000u32;
+    let w646 = w645 ^ w644;
+    let w647 = w640 ^ w646;
+    let w648 = w444 & 0x20000000u32;
+    let w649 = w645 & w644;
+    let w650 = w648 ^ w649;
+    let w651 = w650.rotate_left(1);
+    let w652 = w445 & 0x40000000u32;
+    let w653 = w652 ^ w651;
+    let w654 = w647 ^ w653;
+    let w655 = w444 & 0x40000000u32;
+    let w656 = w652 & w651;
+    let w657 = w655 ^ w656;
+    let w658 = w657.rotate_left(1);
+    let w659 = w445 & 0x80000000u32;
+    let w660 = w659 ^ w658;
+    let w661 = w654 ^ w660;
+    let w662 = w223 ^ w661;
+    let w663 = w662.rotate_left(8);
+    let w664 = w441 & w663;
+    let w665 = w441 ^ w663;
+    let w666 = w665 & 0x00000001u32;
+    let w667 = w664 & 0x00000001u32;
+    let w668 = w667.rotate_left(1);
+    let w669 = w665 & 0x00000002u32;
+    let w670 = w669 ^ w668;
+    let w671 = w666 ^ w670;
+    let w672 = w664 & 0x00000002u32;
+    let w673 = w669 & w668;
+    let w674 = w672 ^ w673;
+    let w675 = w674.rotate_left(1);
+    let w676 = w665 & 0x00000004u32;
+    let w677 = w676 ^ w675;
+    let w678 = w671 ^ w677;
+    let w679 = w664 & 0x00000004u32;
+    let w680 = w676 & w675;
+    let w681 = w679 ^ w680;
+    let w682 = w681.rotate_left(1);
+    let w683 = w665 & 0x00000008u32;
+    let w684 = w683 ^ w682;
+    let w685 = w678 ^ w684;
+    let w686 = w664 & 0x00000008u32;
+    let w687 = w683 & w682;
+    let w688 = w686 ^ w687;
+    let w689 = w688.rotate_left(1);
+    let w690 = w665 & 0x00000010u32;
+    let w691 = w690 ^ w689;
+    let w692 = w685 ^ w691;
+    let w693 = w664 & 0x00000010u32;
+    let w694 = w690 & w689;
+    let w695 = w693 ^ w694;
+    let w696 = w695.rotate_left(1);
+    let w697 = w665 & 0x00000020u32;
+    let w698 = w697 ^ w696;
+    let w699 = w692 ^ w698;
+    let w700 = w664 & 0x00000020u32;
+    let w701 = w697 & w696;
+    let w702 = w700 ^ w701;
+    let w703 = w702.rotate_left(1);
+    let w704 = w665 & 0x00000040u32;
+    let w705 = w704 ^ w703;
+    let w706 = w699 ^ w705;
+    let w707 = w664 & 0x00000040u32;
+    let w708 = w704 & w703;
+    let w709 = w707 ^ w708;
+    let w710 = w709.rotate_left(1);
+    let w711 = w665 & 0x00000080u32;
+    let w712 = w711 ^ w710;
+    let w713 = w706 ^ w712;
+    let w714 = w664 & 0x00000080u32;
+    let w715 = w711 & w710;
+    let w716 = w714 ^ w715;
+    let w717 = w716.rotate_left(1);
+    let w718 = w665 & 0x00000100u32;
+    let w719 = w718 ^ w717;
+    let w720 = w713 ^ w719;
+    let w721 = w664 & 0x00000100u32;
+    let w722 = w718 & w717;
+    let w723 = w721 ^ w722;
+    let w724 = w723.rotate_left(1);
+    let w725 = w665 & 0x00000200u32;
+    let w726 = w725 ^ w724;
+    let w727 = w720 ^ w726;
+    let w728 = w664 & 0x00000200u32;
+    let w729 = w725 & w724;
+    let w730 = w728 ^ w729;
+    let w731 = w730.rotate_left(1);
+    let w732 = w665 & 0x00000400u32;
+    let w733 = w732 ^ w731;
+    let w734 = w727 ^ w733;
+    let w735 = w664 & 0x00000400u32;
+    let w736 = w732 & w731;
+    let w737 = w735 ^ w736;
+    let w738 = w737.rotate_left(1);
+    let w739 = w665 & 0x00000800u32;
+    let w740 = w739 ^ w738;
+    let w741 = w734 ^ w740;
+    let w742 = w664 & 0x00000800u32;
+    let w743 = w739 & w738;
+    let w744 = w742 ^ w743;
+    let w745 = w744.rotate_left(1);
+    let w746 = w665 & 0x00001000u32;
+    let w747 = w746 ^ w745;
+    let w748 = w741 ^ w747;
+    let w749 = w664 & 0x00001000u32;
+    let w750 = w746 & w745;
+    let w751 = w749 ^ w750;
+    let w752 = w751.rotate_left(1);
+    let w753 = w665 & 0x00002000u32;
+    let w754 = w753 ^ w752;
+    let w755 = w748 ^ w754;
+    let w756 = w664 & 0x00002000u32;
+    let w757 = w753 & w752;
+    let w758 = w756 ^ w757;
+    let w759 = w758.rotate_left(1);
+    let w760 = w665 & 0x00004000u32;
+    let w761 = w760 ^ w759;
+    let w762 = w755 ^ w761;
+    let w763 = w664 & 0x00004000u32;
+    let w764 = w760 & w759;
+    let w765 = w763 ^ w764;
+    let w766 = w765.rotate_left(1);
+    let w767 = w665 & 0x00008000u32;
+    let w768 = w767 ^ w766;
+    let w769 = w762 ^ w768;
+    let w770 = w664 & 0x00008000u32;
+    let w771 = w767 & w766;
+    let w772 = w770 ^ w771;
+    let w773 = w772.rotate_left(1);
+    let w774 = w665 & 0x00010000u32;
+    let w775 = w774 ^ w773;
+    let w776 = w769 ^ w775;
+    let w777 = w664 & 0x00010000u32;
+    let w778 = w774 & w773;
+    let w779 = w777 ^ w778;
+    let w780 = w779.rotate_left(1);
+    let w781 = w665 & 0x00020000u32;
+    let w782 = w781 ^ w780;
+    let w783 = w776 ^ w782;
+    let w784 = w664 & 0x00020000u32;
+    let w785 = w781 & w780;
+    let w786 = w784 ^ w785;
+    let w787 = w786.rotate_left(1);
+    let w788 = w665 & 0x00040000u32;
+    let w789 = w788 ^ w787;
+    let w790 = w783 ^ w789;
+    let w791 = w664 & 0x00040000u32;
+    let w792 = w788 & w787;
+    let w793 = w791 ^ w792;
+    let w794 = w793.rotate_left(1);
+    let w795 = w665 & 0x00080000u32;
+    let w796 = w795 ^ w794;
+    let w797 = w790 ^ w796;
+    let w798 = w664 & 0x00080000u32;
+    let w799 = w795 & w794;
+    let w800 = w798 ^ w799;
+    let w801 = w800.rotate_left(1);
+    let w802 = w665 & 0x00100000u32;
+    let w803 = w802 ^ w801;
+    let w804 = w797 ^ w803;
+    let w805 = w664 & 0x00100000u32;
+    let w806 = w802 & w801;
+    let w807 = w805 ^ w806;
+    let w808 = w807.rotate_left(1);
+    let w809 = w665 & 0x00200000u32;
+    let w810 = w809 ^ w808;
+    let w811 = w804 ^ w810;
+    let w812 = w664 & 0x00200000u32;
+    let w813 = w809 & w808;
+    let w814 = w812 ^ w813;
+    let w815 = w814.rotate_left(1);
+    let w816 = w665 & 0x00400000u32;
+    let w817 = w816 ^ w815;
+    let w818 = w811 ^ w817;
+    let w819 = w664 & 0x00400000u32;
+    let w820 = w816 & w815;
+    let w821 = w819 ^ w820;
+    let w822 = w821.rotate_left(1);
+    let w823 = w665 & 0x00800000u32;
+    let w824 = w823 ^ w822;
+    let w825 = w818 ^ w824;
+    let w826 = w664 & 0x00800000u32;
+    let w827 = w823 & w822;
+    let w828 = w826 ^ w827;
+    let w829 = w828.rotate_left(1);
+    let w830 = w665 & 0x01000000u32;
+    let w831 = w830 ^ w829;
+    let w832 = w825 ^ w831;
+    let w833 = w664 & 0x01000000u32;
+    let w834 = w830 & w829;
+    let w835 = w833 ^ w834;
+    let w836 = w835.rotate_left(1);
+    let w837 = w665 & 0x02000000u32;
+    let w838 = w837 ^ w836;
+    let w839 = w832 ^ w838;
+    let w840 = w664 & 0x02000000u32;
+    let w841 = w837 & w836;
+    let w842 = w840 ^ w841;
+    let w843 = w842.rotate_left(1);
+    let w844 = w665 & 0x04000000u32;
+    let w845 = w844 ^ w843;
+    let w846 = w839 ^ w845;
+    let w847 = w664 & 0x04000000u32;
+    let w848 = w844 & w843;
+    let w849 = w847 ^ w848;
+    let w850 = w849.rotate_left(1);
+    let w851 = w665 & 0x08000000u32;
+    let w852 = w851 ^ w850;
+    let w853 = w846 ^ w852;
+    let w854 = w664 & 0x08000000u32;
+    let w855 = w851 & w850;
+    let w856 = w854 ^ w855;
+    let w857 = w856.rotate_left(1);
+    let w858 = w665 & 0x10000000u32;
+    let w859 = w858 ^ w857;
+    let w860 = w853 ^ w859;
+    let w861 = w664 & 0x10000000u32;
+    let w862 = w858 & w857;
+    let w863 = w861 ^ w862;
+    let w864 = w863.rotate_left(1);
+    let w865 = w665 & 0x20000000u32;
+    let w866 = w865 ^ w864;
+    let w867 = w860 ^ w866;
+    let w868 = w664 & 0x20000000u32;
+    let w869 = w865 & w864;
+    let w870 = w868 ^ w869;
+    let w871 = w870.rotate_left(1);
+    let w872 = w665 & 0x40000000u32;
+    let w873 = w872 ^ w871;
+    let w874 = w867 ^ w873;
+    let w875 = w664 & 0x40000000u32;
+    let w876 = w872 & w871;
+    let w877 = w875 ^ w876;
+    let w878 = w877.rotate_left(1);
+    let w879 = w665 & 0x80000000u32;
+    let w880 = w879 ^ w878;
+    let w881 = w874 ^ w880;
+    let w882 = w443 ^ w881;
+    let w883 = w882.rotate_left(7);
+    let w884 = w661;
+    let w885 = w881;
+    let w886 = w663;
+    [
+        w884,
+        w883,
+        w885,
+        w886,
+    ]
+}
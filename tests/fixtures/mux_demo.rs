@@ -5,29 +5,39 @@ pub const EXPR_DIGEST: [u8; 32] = [
     0x50, 0x05, 0x36, 0x60, 0x9a, 0xe0, 0xa4, 0x42,
     0x10, 0x7c, 0xda, 0x3e, 0x4e, 0x9c, 0xd6, 0x1b,
 ];
-pub fn mux_demo(cond: u32, t: u32, f: u32) -> u32 {
+pub fn mux_demo(cond: u32, f: u32, t: u32) -> u32 {
     const POOL: &[u32] = &[
         0xcd2c6f7f,
         0xbb2a3fb2,
         0x8e27697b,
-        0xc30d3add,
-        0xcd2c6f7f,
-        0x350d56c9,
-        0x482615ef,
+        0x0b2d13eb,
+        0x09307682,
+        0x339097cb,
+        0xadf4f6d2,
+        0x958b761d,
+        0xf74e8ad9,
+        0x3ee241cd,
+        0xfb23e4ba,
+        0xdee17b11,
     ];
     let mut r = [0u32; 4];
-    r[3] = cond ^ POOL[0];
-    r[0] = t ^ POOL[1];
-    r[1] = f ^ POOL[2];
-    r[2] = r[0] ^ r[1];
-    r[0] = {
-        let (t, ma, mb) = (POOL[3], POOL[4], POOL[5]);
+    r[1] = cond ^ POOL[0];
+    r[3] = t ^ POOL[1];
+    r[0] = f ^ POOL[2];
+    r[2] = r[3] ^ r[0];
+    r[3] = r[1] ^ POOL[3];
+    r[1] = r[3] ^ POOL[4];
+    r[3] = r[2] ^ POOL[5];
+    r[2] = {
+        let (t, s) = (POOL[6], POOL[7]);
+        let (ma, mb) = (t ^ s ^ POOL[8], t ^ s ^ POOL[9]);
         let mut z = t;
-        z ^= r[3] & mb;
-        z ^= r[2] & ma;
-        z ^= r[3] & r[2];
+        z ^= r[1] & mb;
+        z ^= r[3] & ma;
+        z ^= r[1] & r[3];
         z
     };
-    r[3] = r[1] ^ r[0];
-    r[3] ^ POOL[6]
+    r[1] = r[0] ^ r[2];
+    r[3] = r[1] ^ POOL[10];
+    r[3] ^ POOL[11]
 }
@@ -5,7 +5,7 @@ pub const EXPR_DIGEST: [u8; 32] = [
     0x50, 0x05, 0x36, 0x60, 0x9a, 0xe0, 0xa4, 0x42,
     0x10, 0x7c, 0xda, 0x3e, 0x4e, 0x9c, 0xd6, 0x1b,
 ];
-pub fn mux_demo_verify(input_cond: u32, input_t: u32, input_f: u32) -> u32 {
+pub fn mux_demo_verify(input_cond: u32, input_f: u32, input_t: u32) -> u32 {
     let w0 = input_cond;
     let w1 = input_t;
     let w2 = input_f;
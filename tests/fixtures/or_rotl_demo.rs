@@ -9,27 +9,39 @@ pub fn or_rotl_demo(a: u32, b: u32) -> u32 {
     const POOL: &[u32] = &[
         0xcd2c6f7f,
         0xbb2a3fb2,
-        0x070f4649,
-        0xcd2c6f7f,
-        0xbb2a3fb2,
-        0x5836052d,
-        0xc408a447,
+        0x430b0604,
+        0x482615ef,
+        0x741b35a4,
+        0xc9accb14,
+        0xade4b6d0,
+        0x958b761d,
+        0xfe6ebc59,
+        0x3ef201cf,
+        0x40d602a8,
+        0x23463cd4,
+        0x431d9d54,
     ];
     let mut r = [0u32; 4];
-    r[3] = a ^ POOL[0];
-    r[0] = b ^ POOL[1];
-    r[1] = r[3] ^ r[0];
-    r[2] = {
-        let (t, ma, mb) = (POOL[2], POOL[3], POOL[4]);
+    r[0] = a ^ POOL[0];
+    r[1] = b ^ POOL[1];
+    r[2] = r[0] ^ r[1];
+    r[3] = r[0] ^ POOL[2];
+    r[0] = r[3] ^ POOL[3];
+    r[3] = r[1] ^ POOL[4];
+    r[1] = r[3] ^ POOL[5];
+    r[3] = {
+        let (t, s) = (POOL[6], POOL[7]);
+        let (ma, mb) = (t ^ s ^ POOL[8], t ^ s ^ POOL[9]);
         let mut z = t;
-        z ^= r[3] & mb;
-        z ^= r[0] & ma;
-        z ^= r[3] & r[0];
+        z ^= r[0] & mb;
+        z ^= r[1] & ma;
+        z ^= r[0] & r[1];
         z
     };
-    r[3] = r[1] ^ r[2];
-    r[0] = POOL[5];
-    r[1] = r[3] ^ r[0];
-    r[3] = r[1].rotate_left(5);
-    r[3] ^ POOL[6]
+    r[0] = r[2] ^ r[3];
+    r[1] = POOL[10];
+    r[2] = r[0] ^ r[1];
+    r[0] = r[2].rotate_left(5);
+    r[1] = r[0] ^ POOL[11];
+    r[1] ^ POOL[12]
 }
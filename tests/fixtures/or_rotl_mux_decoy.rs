@@ -10,52 +10,80 @@ pub fn or_rotl_mux_decoy(a: u32, b: u32) -> u32 {
         0x32d39080,
         0xbb2a3fb2,
         0x8e27697b,
-        0x4c2355a6,
-        0xbb2a3fb2,
-        0x8e27697b,
-        0x510673af,
-        0x8cbfe830,
-        0xbb2a3fb2,
-        0x8e27697b,
-        0x2acdbeb5,
-        0xcd2c6f7f,
-        0x8139c865,
+        0x7d2b4326,
+        0x09307682,
+        0x88baa879,
         0xad7837d2,
+        0x1eaa740d,
+        0xdee17b11,
+        0x0f7a050a,
+        0x6baef9cc,
+        0xdd2ae4ed,
+        0xa45bfb90,
+        0xdad12b33,
+        0x9c241068,
+        0x25fffc5c,
+        0xcc5fe4c6,
+        0xcb30ce1a,
+        0xc2cfc5cd,
+        0x3093af93,
+        0x532110d3,
+        0x055c7575,
+        0x19bc1cf8,
+        0xf80c4de3,
+        0x7fbd2eb7,
+        0x5e64f5b5,
+        0x97df26ed,
+        0xd9987f7e,
     ];
-    let mut r = [0u32; 6];
-    r[3] = POOL[0];
-    r[1] = a ^ POOL[1];
-    r[5] = b ^ POOL[2];
-    r[0] = r[1] ^ r[5];
-    r[4] = {
-        let (t, ma, mb) = (POOL[3], POOL[4], POOL[5]);
+    let mut r = [0u32; 7];
+    r[6] = POOL[0];
+    r[3] = a ^ POOL[1];
+    r[0] = b ^ POOL[2];
+    r[1] = r[3] ^ r[0];
+    r[2] = r[3] ^ POOL[3];
+    r[4] = r[2] ^ POOL[4];
+    r[2] = r[0] ^ POOL[5];
+    r[5] = r[2] ^ POOL[6];
+    r[2] = {
+        let (t, s) = (POOL[7], POOL[8]);
+        let (ma, mb) = (t ^ s ^ POOL[9], t ^ s ^ POOL[10]);
         let mut z = t;
-        z ^= r[1] & mb;
+        z ^= r[4] & mb;
         z ^= r[5] & ma;
-        z ^= r[1] & r[5];
+        z ^= r[4] & r[5];
         z
     };
-    r[2] = r[0] ^ r[4];
-    r[0] = POOL[6];
-    r[4] = r[2] ^ r[0];
-    r[0] = r[4].rotate_left(5);
-    r[4] = {
-        let (t, ma, mb) = (POOL[7], POOL[8], POOL[9]);
+    r[4] = r[1] ^ r[2];
+    r[1] = POOL[11];
+    r[2] = r[4] ^ r[1];
+    r[1] = r[2].rotate_left(5);
+    r[2] = r[3] ^ POOL[12];
+    r[3] = r[2] ^ POOL[13];
+    r[2] = r[0] ^ POOL[14];
+    r[0] = r[2] ^ POOL[15];
+    r[2] = {
+        let (t, s) = (POOL[16], POOL[17]);
+        let (ma, mb) = (t ^ s ^ POOL[18], t ^ s ^ POOL[19]);
         let mut z = t;
-        z ^= r[1] & mb;
-        z ^= r[5] & ma;
-        z ^= r[1] & r[5];
+        z ^= r[3] & mb;
+        z ^= r[0] & ma;
+        z ^= r[3] & r[0];
         z
     };
-    r[1] = r[0] ^ r[4];
-    r[5] = {
-        let (t, ma, mb) = (POOL[10], POOL[11], POOL[12]);
+    r[3] = r[1] ^ r[2];
+    r[0] = r[6] ^ POOL[20];
+    r[6] = r[3] ^ POOL[21];
+    r[3] = {
+        let (t, s) = (POOL[22], POOL[23]);
+        let (ma, mb) = (t ^ s ^ POOL[24], t ^ s ^ POOL[25]);
         let mut z = t;
-        z ^= r[3] & mb;
-        z ^= r[1] & ma;
-        z ^= r[3] & r[1];
+        z ^= r[0] & mb;
+        z ^= r[6] & ma;
+        z ^= r[0] & r[6];
         z
     };
-    r[3] = r[4] ^ r[5];
-    r[3] ^ POOL[13]
+    r[6] = r[2] ^ r[3];
+    r[3] = r[6] ^ POOL[26];
+    r[3] ^ POOL[27]
 }
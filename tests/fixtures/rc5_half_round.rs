@@ -0,0 +1,1116 @@
+// Generated by `cargo run --bin regen_fixtures` — do not edit manually.
+pub const EXPR_DIGEST: [u8; 32] = [
+    0x1a, 0x98, 0xdd, 0x35, 0x8f, 0x4b, 0x8c, 0x6e,
+    0xa0, 0xb6, 0xfd, 0xaf, 0x83, 0x7e, 0xd0, 0xb5,
+    0xa3, 0xf3, 0x1d, 0x6f, 0x9e, 0x38, 0x8c, 0xcc,
+    0xb3, 0xa3, 0x27, 0x69, 0xef, 0xaa, 0xa8, 0xb2,
+];
+pub fn rc5_half_round(a: u32, b: u32, s: u32) -> u32 {
+    const POOL: &[u32] = &[
+        0xcd2c6f7f,
+        0xbb2a3fb2,
+        0x00000001,
+        0x8e27697b,
+        0x5c0b8dc3,
+        0x4116636d,
+        0xc9accb14,
+        0x6de48a44,
+        0x970eb61d,
+        0xdee17b11,
+        0x4f720c0e,
+        0xe20a3bdc,
+        0x00000002,
+        0xbce262ab,
+        0x72c879b1,
+        0x86bd7245,
+        0xd7a39602,
+        0x288d416d,
+        0xdbff60c4,
+        0xcb30ce1a,
+        0x02ccd7cd,
+        0x27332b91,
+        0x00000004,
+        0x9e0d7fac,
+        0x328292cb,
+        0x19b547f8,
+        0x7fb475b7,
+        0x664cdbd0,
+        0x2706ea1f,
+        0x48501800,
+        0x975abffc,
+        0xb6ce8d61,
+        0x00000008,
+        0x23ae2c7b,
+        0xa2483d04,
+        0x3f4a9400,
+        0xe172be2e,
+        0xfd2bcf54,
+        0xc02d13b5,
+        0x0ca57b62,
+        0x311e6e82,
+        0x3a771038,
+        0x00000010,
+        0x91c4295d,
+        0x7ea7ddd9,
+        0xc8dae438,
+        0xd309bef9,
+        0x5c0a6cac,
+        0xdc7cfa1c,
+        0xfed54eba,
+        0x574138c5,
+        0x1259178e,
+        0x3a1f55f0,
+        0x6823955b,
+        0xb52f334c,
+        0x20a70c57,
+        0x02d79df6,
+        0xb892a064,
+        0xdf3d216f,
+        0x15d25779,
+        0x00000001,
+        0x00000001,
+        0x00000002,
+        0x00000002,
+        0xd5e34c7b,
+        0xa30c03c8,
+        0x42b04626,
+        0x135f3ef1,
+        0x93922a5b,
+        0x61935a25,
+        0x831d8355,
+        0xbf5704d8,
+        0x08158e1a,
+        0xdd14c900,
+        0x00000004,
+        0x00000004,
+        0x7a95e00d,
+        0x22be7226,
+        0xb86d04c8,
+        0x8dae328f,
+        0x1ee39d5e,
+        0xbb620156,
+        0x45c70aef,
+        0x6ea8b922,
+        0x00000008,
+        0x00000008,
+        0x102ff028,
+        0xfcf3533d,
+        0x10787f2d,
+        0x949c6182,
+        0x1f805bbb,
+        0x6fdc7e07,
+        0x8cf8f984,
+        0x590776cf,
+        0x00000010,
+        0x00000010,
+        0xfa202bf2,
+        0xb62be790,
+        0xa6d6549c,
+        0x0c62b7a3,
+        0xcf1db5b5,
+        0x610e6994,
+        0x44ce44cf,
+        0xcd717c84,
+        0x00000020,
+        0x00000020,
+        0x03d8ae10,
+        0xd24a4487,
+        0xc02a68ad,
+        0x81a797e0,
+        0x8f27e10a,
+        0xb36998ad,
+        0x2df6fb9d,
+        0xe7538444,
+        0x00000040,
+        0x00000040,
+        0x9d1ffad2,
+        0x36ee4403,
+        0x62f4e8f3,
+        0x6f510539,
+        0xeb72ea72,
+        0xdd58898e,
+        0xff2f359e,
+        0x6505a494,
+        0x00000080,
+        0x00000080,
+        0x159ba8db,
+        0xc574719c,
+        0xc5916a57,
+        0xa084e44b,
+        0x90a0fd1a,
+        0xdc6b7cd6,
+        0x59b532dc,
+        0xb8a03da2,
+        0x00000100,
+        0x00000100,
+        0xc0c08aa8,
+        0x81c1c86b,
+        0x9f273616,
+        0x23fbaf70,
+        0x1c4b71c1,
+        0x3c59c49b,
+        0xfe34c18f,
+        0x0a7d801f,
+        0x00000200,
+        0x00000200,
+        0x59e3238a,
+        0xbc38bb32,
+        0xca2dd6a3,
+        0xce436218,
+        0xa72e8f08,
+        0x9f85f25d,
+        0x175d334e,
+        0xda329645,
+        0x00000400,
+        0x00000400,
+        0xfa077926,
+        0x931e3724,
+        0x2a90dc39,
+        0x6c3f2607,
+        0x030aa522,
+        0x7bb0adce,
+        0x3b339ed7,
+        0x1ff8a4da,
+        0x00000800,
+        0x00000800,
+        0xfc2691b7,
+        0x03f1c36e,
+        0x55a8892c,
+        0x98cb985d,
+        0xa453463c,
+        0x9fa708d2,
+        0x918b9d1b,
+        0x232b9c93,
+        0x00001000,
+        0x00001000,
+        0xcab2b0b3,
+        0x78e26b34,
+        0xf8b597e4,
+        0x959b7f32,
+        0xeaecc80a,
+        0xea01252b,
+        0x4a08b142,
+        0xcd6fba80,
+        0x00002000,
+        0x00002000,
+        0x08c8f3bd,
+        0xe270e947,
+        0xffde0d69,
+        0x4722f870,
+        0x30eee559,
+        0xbf4da8ff,
+        0x9ac55a35,
+        0x8c58ad81,
+        0x00004000,
+        0x00004000,
+        0xa7e75c18,
+        0x19fcaa32,
+        0x2ac13dd1,
+        0x6c65c121,
+        0x2b97b14d,
+        0xddf70276,
+        0x62ba38c0,
+        0xf91cb8ae,
+        0x00008000,
+        0x00008000,
+        0x375ba523,
+        0x8d3fb9c9,
+        0xebf40308,
+        0x120ae0c0,
+        0x1bf3b632,
+        0x9d62d540,
+        0xd701fc90,
+        0xcb04f60a,
+        0x00010000,
+        0x00010000,
+        0x8e89bfb9,
+        0xbcee5584,
+        0x900a252f,
+        0xc7bc6115,
+        0x26f63c7a,
+        0x93b92b66,
+        0x1722d80e,
+        0xc63430ad,
+        0x00020000,
+        0x00020000,
+        0x154b75d8,
+        0x25eb9cce,
+        0x62485f06,
+        0xb21f2fa8,
+        0x2ccf6dcc,
+        0x0b40708c,
+        0x7567ab50,
+        0x9cac443c,
+        0x00040000,
+        0x00040000,
+        0x09e9d789,
+        0x91e6c84e,
+        0x725c62d7,
+        0x3c46f837,
+        0x310b23ff,
+        0x28385520,
+        0xf3640bcf,
+        0x58af7550,
+        0x00080000,
+        0x00080000,
+        0x9c2b1c95,
+        0xf278f574,
+        0x6263be6c,
+        0xd3409621,
+        0xcd37b762,
+        0x8bd2d489,
+        0x4ad53466,
+        0x7793b034,
+        0x00100000,
+        0x00100000,
+        0xd0541386,
+        0x8938e837,
+        0x8e35a4a9,
+        0x55849a35,
+        0x2d7e335d,
+        0xf58e5a61,
+        0x0fa93624,
+        0x177b3ad6,
+        0x00200000,
+        0x00200000,
+        0xc3fe4c62,
+        0xcc2e5c2f,
+        0xeee5d8eb,
+        0xd1182267,
+        0x5b17dbd7,
+        0xf3bd5e7b,
+        0x499f4d0a,
+        0xad5c6760,
+        0x00400000,
+        0x00400000,
+        0xe44ce9ae,
+        0xc2551a7b,
+        0xacd14cbb,
+        0x4687fcc6,
+        0x99d8004f,
+        0x8ce59693,
+        0x9fb529b2,
+        0xe7fc5cbc,
+        0x00800000,
+        0x00800000,
+        0x49876c8c,
+        0x9241b80c,
+        0x87b005ce,
+        0x8a9b1f79,
+        0xd91a84fe,
+        0x0b38a131,
+        0x8e54f481,
+        0x6e882ee8,
+        0x01000000,
+        0x01000000,
+        0xda8bc196,
+        0xad618cf7,
+        0x5a6fee6d,
+        0xddfb3279,
+        0x877ff720,
+        0xa4dcb4c7,
+        0x0f26e0eb,
+        0x75297a6f,
+        0x02000000,
+        0x02000000,
+        0x30e5c923,
+        0x544dded4,
+        0x7dc60d6f,
+        0x7ebbae01,
+        0x5367a35f,
+        0x504746b2,
+        0x184eff75,
+        0x7864e7bd,
+        0x04000000,
+        0x04000000,
+        0x56ce93a4,
+        0xa91447e0,
+        0xb3dc0025,
+        0x5dc49c52,
+        0x07bbe24b,
+        0xee7f88eb,
+        0xa1c2bec1,
+        0x2047b46c,
+        0x08000000,
+        0x08000000,
+        0xd7fc04b3,
+        0x9af2c296,
+        0x9217ef0d,
+        0x86319e18,
+        0x6e8bf0d1,
+        0xb6b43013,
+        0x0726e9ea,
+        0xc97c32cc,
+        0x10000000,
+        0x10000000,
+        0x9e71a00a,
+        0xa1aea753,
+        0xe9f864e2,
+        0xc4c0e469,
+        0x8777d1aa,
+        0x1e97491c,
+        0x5fc7fb0d,
+        0xa235dd6d,
+        0x20000000,
+        0x20000000,
+        0x34492a01,
+        0x7e893dfc,
+        0x90aba88e,
+        0x8246f12a,
+        0x49c25948,
+        0x37e1eaa1,
+        0x84480c9a,
+        0xf68062a0,
+        0x40000000,
+        0x40000000,
+        0x3664d41f,
+        0x11583381,
+        0x9fb556d6,
+        0x8a91ef75,
+        0xf48a1468,
+        0x519e9eb4,
+        0x1d9d3b94,
+        0xec46f5ba,
+        0x80000000,
+        0x9f771faf,
+        0x2c242a3b,
+    ];
+    let mut r = [0u32; 7];
+    r[0] = a ^ POOL[0];
+    r[5] = b ^ POOL[1];
+    r[6] = r[0] ^ r[5];
+    r[0] = r[5] & POOL[2];
+    r[2] = r[0].rotate_left(1);
+    r[4] = r[0] ^ r[2];
+    r[0] = r[4].rotate_left(2);
+    r[2] = r[4] ^ r[0];
+    r[0] = r[2].rotate_left(4);
+    r[4] = r[2] ^ r[0];
+    r[0] = r[4].rotate_left(8);
+    r[2] = r[4] ^ r[0];
+    r[0] = r[2].rotate_left(16);
+    r[4] = r[2] ^ r[0];
+    r[0] = r[4] ^ POOL[3];
+    r[2] = r[6].rotate_left(1);
+    r[4] = r[2] ^ r[6];
+    r[2] = r[4] ^ POOL[4];
+    r[4] = r[0] ^ POOL[5];
+    r[0] = r[4] ^ POOL[6];
+    r[4] = r[2] ^ POOL[7];
+    r[2] = {
+        let (t, s) = (POOL[8], POOL[9]);
+        let (ma, mb) = (t ^ s ^ POOL[10], t ^ s ^ POOL[11]);
+        let mut z = t;
+        z ^= r[0] & mb;
+        z ^= r[4] & ma;
+        z ^= r[0] & r[4];
+        z
+    };
+    r[0] = r[6] ^ r[2];
+    r[6] = r[5] & POOL[12];
+    r[2] = r[6].rotate_left(1);
+    r[4] = r[6] ^ r[2];
+    r[6] = r[4].rotate_left(2);
+    r[2] = r[4] ^ r[6];
+    r[6] = r[2].rotate_left(4);
+    r[4] = r[2] ^ r[6];
+    r[6] = r[4].rotate_left(8);
+    r[2] = r[4] ^ r[6];
+    r[6] = r[2].rotate_left(16);
+    r[4] = r[2] ^ r[6];
+    r[6] = r[4] ^ POOL[13];
+    r[2] = r[0].rotate_left(2);
+    r[4] = r[2] ^ r[0];
+    r[2] = r[4] ^ POOL[14];
+    r[4] = r[6] ^ POOL[15];
+    r[6] = r[4] ^ POOL[16];
+    r[4] = r[2] ^ POOL[17];
+    r[2] = {
+        let (t, s) = (POOL[18], POOL[19]);
+        let (ma, mb) = (t ^ s ^ POOL[20], t ^ s ^ POOL[21]);
+        let mut z = t;
+        z ^= r[6] & mb;
+        z ^= r[4] & ma;
+        z ^= r[6] & r[4];
+        z
+    };
+    r[6] = r[0] ^ r[2];
+    r[0] = r[5] & POOL[22];
+    r[2] = r[0].rotate_left(1);
+    r[4] = r[0] ^ r[2];
+    r[0] = r[4].rotate_left(2);
+    r[2] = r[4] ^ r[0];
+    r[0] = r[2].rotate_left(4);
+    r[4] = r[2] ^ r[0];
+    r[0] = r[4].rotate_left(8);
+    r[2] = r[4] ^ r[0];
+    r[0] = r[2].rotate_left(16);
+    r[4] = r[2] ^ r[0];
+    r[0] = r[4] ^ POOL[23];
+    r[2] = r[6].rotate_left(4);
+    r[4] = r[2] ^ r[6];
+    r[2] = r[4] ^ POOL[24];
+    r[4] = r[0] ^ POOL[25];
+    r[0] = r[4] ^ POOL[26];
+    r[4] = r[2] ^ POOL[27];
+    r[2] = {
+        let (t, s) = (POOL[28], POOL[29]);
+        let (ma, mb) = (t ^ s ^ POOL[30], t ^ s ^ POOL[31]);
+        let mut z = t;
+        z ^= r[0] & mb;
+        z ^= r[4] & ma;
+        z ^= r[0] & r[4];
+        z
+    };
+    r[0] = r[6] ^ r[2];
+    r[6] = r[5] & POOL[32];
+    r[2] = r[6].rotate_left(1);
+    r[4] = r[6] ^ r[2];
+    r[6] = r[4].rotate_left(2);
+    r[2] = r[4] ^ r[6];
+    r[6] = r[2].rotate_left(4);
+    r[4] = r[2] ^ r[6];
+    r[6] = r[4].rotate_left(8);
+    r[2] = r[4] ^ r[6];
+    r[6] = r[2].rotate_left(16);
+    r[4] = r[2] ^ r[6];
+    r[6] = r[4] ^ POOL[33];
+    r[2] = r[0].rotate_left(8);
+    r[4] = r[2] ^ r[0];
+    r[2] = r[4] ^ POOL[34];
+    r[4] = r[6] ^ POOL[35];
+    r[6] = r[4] ^ POOL[36];
+    r[4] = r[2] ^ POOL[37];
+    r[2] = {
+        let (t, s) = (POOL[38], POOL[39]);
+        let (ma, mb) = (t ^ s ^ POOL[40], t ^ s ^ POOL[41]);
+        let mut z = t;
+        z ^= r[6] & mb;
+        z ^= r[4] & ma;
+        z ^= r[6] & r[4];
+        z
+    };
+    r[6] = r[0] ^ r[2];
+    r[0] = r[5] & POOL[42];
+    r[5] = r[0].rotate_left(1);
+    r[2] = r[0] ^ r[5];
+    r[0] = r[2].rotate_left(2);
+    r[5] = r[2] ^ r[0];
+    r[0] = r[5].rotate_left(4);
+    r[2] = r[5] ^ r[0];
+    r[0] = r[2].rotate_left(8);
+    r[5] = r[2] ^ r[0];
+    r[0] = r[5].rotate_left(16);
+    r[2] = r[5] ^ r[0];
+    r[0] = r[2] ^ POOL[43];
+    r[5] = r[6].rotate_left(16);
+    r[2] = r[5] ^ r[6];
+    r[5] = r[2] ^ POOL[44];
+    r[2] = r[0] ^ POOL[45];
+    r[0] = r[2] ^ POOL[46];
+    r[2] = r[5] ^ POOL[47];
+    r[5] = {
+        let (t, s) = (POOL[48], POOL[49]);
+        let (ma, mb) = (t ^ s ^ POOL[50], t ^ s ^ POOL[51]);
+        let mut z = t;
+        z ^= r[0] & mb;
+        z ^= r[2] & ma;
+        z ^= r[0] & r[2];
+        z
+    };
+    r[0] = r[6] ^ r[5];
+    r[5] = s ^ POOL[52];
+    r[6] = r[0] ^ POOL[53];
+    r[2] = r[5] ^ POOL[54];
+    r[4] = r[2] ^ POOL[55];
+    r[2] = {
+        let (t, s) = (POOL[56], POOL[57]);
+        let (ma, mb) = (t ^ s ^ POOL[58], t ^ s ^ POOL[59]);
+        let mut z = t;
+        z ^= r[6] & mb;
+        z ^= r[4] & ma;
+        z ^= r[6] & r[4];
+        z
+    };
+    r[6] = r[0] ^ r[5];
+    r[0] = r[6] & POOL[60];
+    r[5] = r[2] & POOL[61];
+    r[4] = r[5].rotate_left(1);
+    r[5] = r[6] & POOL[62];
+    r[1] = r[5] ^ r[4];
+    r[3] = r[0] ^ r[1];
+    r[0] = r[2] & POOL[63];
+    r[1] = r[5] ^ POOL[64];
+    r[5] = r[1] ^ POOL[65];
+    r[1] = r[5] ^ POOL[66];
+    r[5] = r[4] ^ POOL[67];
+    r[4] = r[5] ^ POOL[68];
+    r[5] = r[4] ^ POOL[69];
+    r[4] = {
+        let (t, s) = (POOL[70], POOL[71]);
+        let (ma, mb) = (t ^ s ^ POOL[72], t ^ s ^ POOL[73]);
+        let mut z = t;
+        z ^= r[1] & mb;
+        z ^= r[5] & ma;
+        z ^= r[1] & r[5];
+        z
+    };
+    r[5] = r[0] ^ r[4];
+    r[0] = r[5].rotate_left(1);
+    r[5] = r[6] & POOL[74];
+    r[4] = r[5] ^ r[0];
+    r[1] = r[3] ^ r[4];
+    r[4] = r[2] & POOL[75];
+    r[3] = r[5] ^ POOL[76];
+    r[5] = r[3] ^ POOL[77];
+    r[3] = r[5] ^ POOL[78];
+    r[5] = r[0] ^ POOL[79];
+    r[0] = {
+        let (t, s) = (POOL[80], POOL[81]);
+        let (ma, mb) = (t ^ s ^ POOL[82], t ^ s ^ POOL[83]);
+        let mut z = t;
+        z ^= r[3] & mb;
+        z ^= r[5] & ma;
+        z ^= r[3] & r[5];
+        z
+    };
+    r[5] = r[4] ^ r[0];
+    r[0] = r[5].rotate_left(1);
+    r[5] = r[6] & POOL[84];
+    r[4] = r[5] ^ r[0];
+    r[3] = r[1] ^ r[4];
+    r[4] = r[2] & POOL[85];
+    r[1] = r[5] ^ POOL[86];
+    r[5] = r[1] ^ POOL[87];
+    r[1] = r[5] ^ POOL[88];
+    r[5] = r[0] ^ POOL[89];
+    r[0] = {
+        let (t, s) = (POOL[90], POOL[91]);
+        let (ma, mb) = (t ^ s ^ POOL[92], t ^ s ^ POOL[93]);
+        let mut z = t;
+        z ^= r[1] & mb;
+        z ^= r[5] & ma;
+        z ^= r[1] & r[5];
+        z
+    };
+    r[5] = r[4] ^ r[0];
+    r[0] = r[5].rotate_left(1);
+    r[5] = r[6] & POOL[94];
+    r[4] = r[5] ^ r[0];
+    r[1] = r[3] ^ r[4];
+    r[4] = r[2] & POOL[95];
+    r[3] = r[5] ^ POOL[96];
+    r[5] = r[3] ^ POOL[97];
+    r[3] = r[5] ^ POOL[98];
+    r[5] = r[0] ^ POOL[99];
+    r[0] = {
+        let (t, s) = (POOL[100], POOL[101]);
+        let (ma, mb) = (t ^ s ^ POOL[102], t ^ s ^ POOL[103]);
+        let mut z = t;
+        z ^= r[3] & mb;
+        z ^= r[5] & ma;
+        z ^= r[3] & r[5];
+        z
+    };
+    r[5] = r[4] ^ r[0];
+    r[0] = r[5].rotate_left(1);
+    r[5] = r[6] & POOL[104];
+    r[4] = r[5] ^ r[0];
+    r[3] = r[1] ^ r[4];
+    r[4] = r[2] & POOL[105];
+    r[1] = r[5] ^ POOL[106];
+    r[5] = r[1] ^ POOL[107];
+    r[1] = r[5] ^ POOL[108];
+    r[5] = r[0] ^ POOL[109];
+    r[0] = {
+        let (t, s) = (POOL[110], POOL[111]);
+        let (ma, mb) = (t ^ s ^ POOL[112], t ^ s ^ POOL[113]);
+        let mut z = t;
+        z ^= r[1] & mb;
+        z ^= r[5] & ma;
+        z ^= r[1] & r[5];
+        z
+    };
+    r[5] = r[4] ^ r[0];
+    r[0] = r[5].rotate_left(1);
+    r[5] = r[6] & POOL[114];
+    r[4] = r[5] ^ r[0];
+    r[1] = r[3] ^ r[4];
+    r[4] = r[2] & POOL[115];
+    r[3] = r[5] ^ POOL[116];
+    r[5] = r[3] ^ POOL[117];
+    r[3] = r[5] ^ POOL[118];
+    r[5] = r[0] ^ POOL[119];
+    r[0] = {
+        let (t, s) = (POOL[120], POOL[121]);
+        let (ma, mb) = (t ^ s ^ POOL[122], t ^ s ^ POOL[123]);
+        let mut z = t;
+        z ^= r[3] & mb;
+        z ^= r[5] & ma;
+        z ^= r[3] & r[5];
+        z
+    };
+    r[5] = r[4] ^ r[0];
+    r[0] = r[5].rotate_left(1);
+    r[5] = r[6] & POOL[124];
+    r[4] = r[5] ^ r[0];
+    r[3] = r[1] ^ r[4];
+    r[4] = r[2] & POOL[125];
+    r[1] = r[5] ^ POOL[126];
+    r[5] = r[1] ^ POOL[127];
+    r[1] = r[5] ^ POOL[128];
+    r[5] = r[0] ^ POOL[129];
+    r[0] = {
+        let (t, s) = (POOL[130], POOL[131]);
+        let (ma, mb) = (t ^ s ^ POOL[132], t ^ s ^ POOL[133]);
+        let mut z = t;
+        z ^= r[1] & mb;
+        z ^= r[5] & ma;
+        z ^= r[1] & r[5];
+        z
+    };
+    r[5] = r[4] ^ r[0];
+    r[0] = r[5].rotate_left(1);
+    r[5] = r[6] & POOL[134];
+    r[4] = r[5] ^ r[0];
+    r[1] = r[3] ^ r[4];
+    r[4] = r[2] & POOL[135];
+    r[3] = r[5] ^ POOL[136];
+    r[5] = r[3] ^ POOL[137];
+    r[3] = r[5] ^ POOL[138];
+    r[5] = r[0] ^ POOL[139];
+    r[0] = {
+        let (t, s) = (POOL[140], POOL[141]);
+        let (ma, mb) = (t ^ s ^ POOL[142], t ^ s ^ POOL[143]);
+        let mut z = t;
+        z ^= r[3] & mb;
+        z ^= r[5] & ma;
+        z ^= r[3] & r[5];
+        z
+    };
+    r[5] = r[4] ^ r[0];
+    r[0] = r[5].rotate_left(1);
+    r[5] = r[6] & POOL[144];
+    r[4] = r[5] ^ r[0];
+    r[3] = r[1] ^ r[4];
+    r[4] = r[2] & POOL[145];
+    r[1] = r[5] ^ POOL[146];
+    r[5] = r[1] ^ POOL[147];
+    r[1] = r[5] ^ POOL[148];
+    r[5] = r[0] ^ POOL[149];
+    r[0] = {
+        let (t, s) = (POOL[150], POOL[151]);
+        let (ma, mb) = (t ^ s ^ POOL[152], t ^ s ^ POOL[153]);
+        let mut z = t;
+        z ^= r[1] & mb;
+        z ^= r[5] & ma;
+        z ^= r[1] & r[5];
+        z
+    };
+    r[5] = r[4] ^ r[0];
+    r[0] = r[5].rotate_left(1);
+    r[5] = r[6] & POOL[154];
+    r[4] = r[5] ^ r[0];
+    r[1] = r[3] ^ r[4];
+    r[4] = r[2] & POOL[155];
+    r[3] = r[5] ^ POOL[156];
+    r[5] = r[3] ^ POOL[157];
+    r[3] = r[5] ^ POOL[158];
+    r[5] = r[0] ^ POOL[159];
+    r[0] = {
+        let (t, s) = (POOL[160], POOL[161]);
+        let (ma, mb) = (t ^ s ^ POOL[162], t ^ s ^ POOL[163]);
+        let mut z = t;
+        z ^= r[3] & mb;
+        z ^= r[5] & ma;
+        z ^= r[3] & r[5];
+        z
+    };
+    r[5] = r[4] ^ r[0];
+    r[0] = r[5].rotate_left(1);
+    r[5] = r[6] & POOL[164];
+    r[4] = r[5] ^ r[0];
+    r[3] = r[1] ^ r[4];
+    r[4] = r[2] & POOL[165];
+    r[1] = r[5] ^ POOL[166];
+    r[5] = r[1] ^ POOL[167];
+    r[1] = r[5] ^ POOL[168];
+    r[5] = r[0] ^ POOL[169];
+    r[0] = {
+        let (t, s) = (POOL[170], POOL[171]);
+        let (ma, mb) = (t ^ s ^ POOL[172], t ^ s ^ POOL[173]);
+        let mut z = t;
+        z ^= r[1] & mb;
+        z ^= r[5] & ma;
+        z ^= r[1] & r[5];
+        z
+    };
+    r[5] = r[4] ^ r[0];
+    r[0] = r[5].rotate_left(1);
+    r[5] = r[6] & POOL[174];
+    r[4] = r[5] ^ r[0];
+    r[1] = r[3] ^ r[4];
+    r[4] = r[2] & POOL[175];
+    r[3] = r[5] ^ POOL[176];
+    r[5] = r[3] ^ POOL[177];
+    r[3] = r[5] ^ POOL[178];
+    r[5] = r[0] ^ POOL[179];
+    r[0] = {
+        let (t, s) = (POOL[180], POOL[181]);
+        let (ma, mb) = (t ^ s ^ POOL[182], t ^ s ^ POOL[183]);
+        let mut z = t;
+        z ^= r[3] & mb;
+        z ^= r[5] & ma;
+        z ^= r[3] & r[5];
+        z
+    };
+    r[5] = r[4] ^ r[0];
+    r[0] = r[5].rotate_left(1);
+    r[5] = r[6] & POOL[184];
+    r[4] = r[5] ^ r[0];
+    r[3] = r[1] ^ r[4];
+    r[4] = r[2] & POOL[185];
+    r[1] = r[5] ^ POOL[186];
+    r[5] = r[1] ^ POOL[187];
+    r[1] = r[5] ^ POOL[188];
+    r[5] = r[0] ^ POOL[189];
+    r[0] = {
+        let (t, s) = (POOL[190], POOL[191]);
+        let (ma, mb) = (t ^ s ^ POOL[192], t ^ s ^ POOL[193]);
+        let mut z = t;
+        z ^= r[1] & mb;
+        z ^= r[5] & ma;
+        z ^= r[1] & r[5];
+        z
+    };
+    r[5] = r[4] ^ r[0];
+    r[0] = r[5].rotate_left(1);
+    r[5] = r[6] & POOL[194];
+    r[4] = r[5] ^ r[0];
+    r[1] = r[3] ^ r[4];
+    r[4] = r[2] & POOL[195];
+    r[3] = r[5] ^ POOL[196];
+    r[5] = r[3] ^ POOL[197];
+    r[3] = r[5] ^ POOL[198];
+    r[5] = r[0] ^ POOL[199];
+    r[0] = {
+        let (t, s) = (POOL[200], POOL[201]);
+        let (ma, mb) = (t ^ s ^ POOL[202], t ^ s ^ POOL[203]);
+        let mut z = t;
+        z ^= r[3] & mb;
+        z ^= r[5] & ma;
+        z ^= r[3] & r[5];
+        z
+    };
+    r[5] = r[4] ^ r[0];
+    r[0] = r[5].rotate_left(1);
+    r[5] = r[6] & POOL[204];
+    r[4] = r[5] ^ r[0];
+    r[3] = r[1] ^ r[4];
+    r[4] = r[2] & POOL[205];
+    r[1] = r[5] ^ POOL[206];
+    r[5] = r[1] ^ POOL[207];
+    r[1] = r[5] ^ POOL[208];
+    r[5] = r[0] ^ POOL[209];
+    r[0] = {
+        let (t, s) = (POOL[210], POOL[211]);
+        let (ma, mb) = (t ^ s ^ POOL[212], t ^ s ^ POOL[213]);
+        let mut z = t;
+        z ^= r[1] & mb;
+        z ^= r[5] & ma;
+        z ^= r[1] & r[5];
+        z
+    };
+    r[5] = r[4] ^ r[0];
+    r[0] = r[5].rotate_left(1);
+    r[5] = r[6] & POOL[214];
+    r[4] = r[5] ^ r[0];
+    r[1] = r[3] ^ r[4];
+    r[4] = r[2] & POOL[215];
+    r[3] = r[5] ^ POOL[216];
+    r[5] = r[3] ^ POOL[217];
+    r[3] = r[5] ^ POOL[218];
+    r[5] = r[0] ^ POOL[219];
+    r[0] = {
+        let (t, s) = (POOL[220], POOL[221]);
+        let (ma, mb) = (t ^ s ^ POOL[222], t ^ s ^ POOL[223]);
+        let mut z = t;
+        z ^= r[3] & mb;
+        z ^= r[5] & ma;
+        z ^= r[3] & r[5];
+        z
+    };
+    r[5] = r[4] ^ r[0];
+    r[0] = r[5].rotate_left(1);
+    r[5] = r[6] & POOL[224];
+    r[4] = r[5] ^ r[0];
+    r[3] = r[1] ^ r[4];
+    r[4] = r[2] & POOL[225];
+    r[1] = r[5] ^ POOL[226];
+    r[5] = r[1] ^ POOL[227];
+    r[1] = r[5] ^ POOL[228];
+    r[5] = r[0] ^ POOL[229];
+    r[0] = {
+        let (t, s) = (POOL[230], POOL[231]);
+        let (ma, mb) = (t ^ s ^ POOL[232], t ^ s ^ POOL[233]);
+        let mut z = t;
+        z ^= r[1] & mb;
+        z ^= r[5] & ma;
+        z ^= r[1] & r[5];
+        z
+    };
+    r[5] = r[4] ^ r[0];
+    r[0] = r[5].rotate_left(1);
+    r[5] = r[6] & POOL[234];
+    r[4] = r[5] ^ r[0];
+    r[1] = r[3] ^ r[4];
+    r[4] = r[2] & POOL[235];
+    r[3] = r[5] ^ POOL[236];
+    r[5] = r[3] ^ POOL[237];
+    r[3] = r[5] ^ POOL[238];
+    r[5] = r[0] ^ POOL[239];
+    r[0] = {
+        let (t, s) = (POOL[240], POOL[241]);
+        let (ma, mb) = (t ^ s ^ POOL[242], t ^ s ^ POOL[243]);
+        let mut z = t;
+        z ^= r[3] & mb;
+        z ^= r[5] & ma;
+        z ^= r[3] & r[5];
+        z
+    };
+    r[5] = r[4] ^ r[0];
+    r[0] = r[5].rotate_left(1);
+    r[5] = r[6] & POOL[244];
+    r[4] = r[5] ^ r[0];
+    r[3] = r[1] ^ r[4];
+    r[4] = r[2] & POOL[245];
+    r[1] = r[5] ^ POOL[246];
+    r[5] = r[1] ^ POOL[247];
+    r[1] = r[5] ^ POOL[248];
+    r[5] = r[0] ^ POOL[249];
+    r[0] = {
+        let (t, s) = (POOL[250], POOL[251]);
+        let (ma, mb) = (t ^ s ^ POOL[252], t ^ s ^ POOL[253]);
+        let mut z = t;
+        z ^= r[1] & mb;
+        z ^= r[5] & ma;
+        z ^= r[1] & r[5];
+        z
+    };
+    r[5] = r[4] ^ r[0];
+    r[0] = r[5].rotate_left(1);
+    r[5] = r[6] & POOL[254];
+    r[4] = r[5] ^ r[0];
+    r[1] = r[3] ^ r[4];
+    r[4] = r[2] & POOL[255];
+    r[3] = r[5] ^ POOL[256];
+    r[5] = r[3] ^ POOL[257];
+    r[3] = r[5] ^ POOL[258];
+    r[5] = r[0] ^ POOL[259];
+    r[0] = {
+        let (t, s) = (POOL[260], POOL[261]);
+        let (ma, mb) = (t ^ s ^ POOL[262], t ^ s ^ POOL[263]);
+        let mut z = t;
+        z ^= r[3] & mb;
+        z ^= r[5] & ma;
+        z ^= r[3] & r[5];
+        z
+    };
+    r[5] = r[4] ^ r[0];
+    r[0] = r[5].rotate_left(1);
+    r[5] = r[6] & POOL[264];
+    r[4] = r[5] ^ r[0];
+    r[3] = r[1] ^ r[4];
+    r[4] = r[2] & POOL[265];
+    r[1] = r[5] ^ POOL[266];
+    r[5] = r[1] ^ POOL[267];
+    r[1] = r[5] ^ POOL[268];
+    r[5] = r[0] ^ POOL[269];
+    r[0] = {
+        let (t, s) = (POOL[270], POOL[271]);
+        let (ma, mb) = (t ^ s ^ POOL[272], t ^ s ^ POOL[273]);
+        let mut z = t;
+        z ^= r[1] & mb;
+        z ^= r[5] & ma;
+        z ^= r[1] & r[5];
+        z
+    };
+    r[5] = r[4] ^ r[0];
+    r[0] = r[5].rotate_left(1);
+    r[5] = r[6] & POOL[274];
+    r[4] = r[5] ^ r[0];
+    r[1] = r[3] ^ r[4];
+    r[4] = r[2] & POOL[275];
+    r[3] = r[5] ^ POOL[276];
+    r[5] = r[3] ^ POOL[277];
+    r[3] = r[5] ^ POOL[278];
+    r[5] = r[0] ^ POOL[279];
+    r[0] = {
+        let (t, s) = (POOL[280], POOL[281]);
+        let (ma, mb) = (t ^ s ^ POOL[282], t ^ s ^ POOL[283]);
+        let mut z = t;
+        z ^= r[3] & mb;
+        z ^= r[5] & ma;
+        z ^= r[3] & r[5];
+        z
+    };
+    r[5] = r[4] ^ r[0];
+    r[0] = r[5].rotate_left(1);
+    r[5] = r[6] & POOL[284];
+    r[4] = r[5] ^ r[0];
+    r[3] = r[1] ^ r[4];
+    r[4] = r[2] & POOL[285];
+    r[1] = r[5] ^ POOL[286];
+    r[5] = r[1] ^ POOL[287];
+    r[1] = r[5] ^ POOL[288];
+    r[5] = r[0] ^ POOL[289];
+    r[0] = {
+        let (t, s) = (POOL[290], POOL[291]);
+        let (ma, mb) = (t ^ s ^ POOL[292], t ^ s ^ POOL[293]);
+        let mut z = t;
+        z ^= r[1] & mb;
+        z ^= r[5] & ma;
+        z ^= r[1] & r[5];
+        z
+    };
+    r[5] = r[4] ^ r[0];
+    r[0] = r[5].rotate_left(1);
+    r[5] = r[6] & POOL[294];
+    r[4] = r[5] ^ r[0];
+    r[1] = r[3] ^ r[4];
+    r[4] = r[2] & POOL[295];
+    r[3] = r[5] ^ POOL[296];
+    r[5] = r[3] ^ POOL[297];
+    r[3] = r[5] ^ POOL[298];
+    r[5] = r[0] ^ POOL[299];
+    r[0] = {
+        let (t, s) = (POOL[300], POOL[301]);
+        let (ma, mb) = (t ^ s ^ POOL[302], t ^ s ^ POOL[303]);
+        let mut z = t;
+        z ^= r[3] & mb;
+        z ^= r[5] & ma;
+        z ^= r[3] & r[5];
+        z
+    };
+    r[5] = r[4] ^ r[0];
+    r[0] = r[5].rotate_left(1);
+    r[5] = r[6] & POOL[304];
+    r[4] = r[5] ^ r[0];
+    r[3] = r[1] ^ r[4];
+    r[4] = r[2] & POOL[305];
+    r[1] = r[5] ^ POOL[306];
+    r[5] = r[1] ^ POOL[307];
+    r[1] = r[5] ^ POOL[308];
+    r[5] = r[0] ^ POOL[309];
+    r[0] = {
+        let (t, s) = (POOL[310], POOL[311]);
+        let (ma, mb) = (t ^ s ^ POOL[312], t ^ s ^ POOL[313]);
+        let mut z = t;
+        z ^= r[1] & mb;
+        z ^= r[5] & ma;
+        z ^= r[1] & r[5];
+        z
+    };
+    r[5] = r[4] ^ r[0];
+    r[0] = r[5].rotate_left(1);
+    r[5] = r[6] & POOL[314];
+    r[4] = r[5] ^ r[0];
+    r[1] = r[3] ^ r[4];
+    r[4] = r[2] & POOL[315];
+    r[3] = r[5] ^ POOL[316];
+    r[5] = r[3] ^ POOL[317];
+    r[3] = r[5] ^ POOL[318];
+    r[5] = r[0] ^ POOL[319];
+    r[0] = {
+        let (t, s) = (POOL[320], POOL[321]);
+        let (ma, mb) = (t ^ s ^ POOL[322], t ^ s ^ POOL[323]);
+        let mut z = t;
+        z ^= r[3] & mb;
+        z ^= r[5] & ma;
+        z ^= r[3] & r[5];
+        z
+    };
+    r[5] = r[4] ^ r[0];
+    r[0] = r[5].rotate_left(1);
+    r[5] = r[6] & POOL[324];
+    r[4] = r[5] ^ r[0];
+    r[3] = r[1] ^ r[4];
+    r[4] = r[2] & POOL[325];
+    r[1] = r[5] ^ POOL[326];
+    r[5] = r[1] ^ POOL[327];
+    r[1] = r[5] ^ POOL[328];
+    r[5] = r[0] ^ POOL[329];
+    r[0] = {
+        let (t, s) = (POOL[330], POOL[331]);
+        let (ma, mb) = (t ^ s ^ POOL[332], t ^ s ^ POOL[333]);
+        let mut z = t;
+        z ^= r[1] & mb;
+        z ^= r[5] & ma;
+        z ^= r[1] & r[5];
+        z
+    };
+    r[5] = r[4] ^ r[0];
+    r[0] = r[5].rotate_left(1);
+    r[5] = r[6] & POOL[334];
+    r[4] = r[5] ^ r[0];
+    r[1] = r[3] ^ r[4];
+    r[4] = r[2] & POOL[335];
+    r[3] = r[5] ^ POOL[336];
+    r[5] = r[3] ^ POOL[337];
+    r[3] = r[5] ^ POOL[338];
+    r[5] = r[0] ^ POOL[339];
+    r[0] = {
+        let (t, s) = (POOL[340], POOL[341]);
+        let (ma, mb) = (t ^ s ^ POOL[342], t ^ s ^ POOL[343]);
+        let mut z = t;
+        z ^= r[3] & mb;
+        z ^= r[5] & ma;
+        z ^= r[3] & r[5];
+        z
+    };
+    r[5] = r[4] ^ r[0];
+    r[0] = r[5].rotate_left(1);
+    r[5] = r[6] & POOL[344];
+    r[4] = r[5] ^ r[0];
+    r[3] = r[1] ^ r[4];
+    r[4] = r[2] & POOL[345];
+    r[1] = r[5] ^ POOL[346];
+    r[5] = r[1] ^ POOL[347];
+    r[1] = r[5] ^ POOL[348];
+    r[5] = r[0] ^ POOL[349];
+    r[0] = {
+        let (t, s) = (POOL[350], POOL[351]);
+        let (ma, mb) = (t ^ s ^ POOL[352], t ^ s ^ POOL[353]);
+        let mut z = t;
+        z ^= r[1] & mb;
+        z ^= r[5] & ma;
+        z ^= r[1] & r[5];
+        z
+    };
+    r[5] = r[4] ^ r[0];
+    r[0] = r[5].rotate_left(1);
+    r[5] = r[6] & POOL[354];
+    r[4] = r[5] ^ r[0];
+    r[1] = r[3] ^ r[4];
+    r[4] = r[2] & POOL[355];
+    r[2] = r[5] ^ POOL[356];
+    r[5] = r[2] ^ POOL[357];
+    r[2] = r[5] ^ POOL[358];
+    r[5] = r[0] ^ POOL[359];
+    r[0] = {
+        let (t, s) = (POOL[360], POOL[361]);
+        let (ma, mb) = (t ^ s ^ POOL[362], t ^ s ^ POOL[363]);
+        let mut z = t;
+        z ^= r[2] & mb;
+        z ^= r[5] & ma;
+        z ^= r[2] & r[5];
+        z
+    };
+    r[5] = r[4] ^ r[0];
+    r[0] = r[5].rotate_left(1);
+    r[5] = r[6] & POOL[364];
+    r[6] = r[5] ^ r[0];
+    r[0] = r[1] ^ r[6];
+    r[5] = r[0] ^ POOL[365];
+    r[5] ^ POOL[366]
+}
@@ -0,0 +1,317 @@
+// Generated by xorpl — do not edit manually.
+pub const EXPR_DIGEST: [u8; 32] = [
+    0x1a, 0x98, 0xdd, 0x35, 0x8f, 0x4b, 0x8c, 0x6e,
+    0xa0, 0xb6, 0xfd, 0xaf, 0x83, 0x7e, 0xd0, 0xb5,
+    0xa3, 0xf3, 0x1d, 0x6f, 0x9e, 0x38, 0x8c, 0xcc,
+    0xb3, 0xa3, 0x27, 0x69, 0xef, 0xaa, 0xa8, 0xb2,
+];
+pub fn rc5_half_round_verify(input_a: u32, input_b: u32, input_s: u32) -> u32 {
+    let w0 = input_a;
+    let w1 = input_b;
+    let w2 = w0 ^ w1;
+    let w3 = w1 & 0x00000001u32;
+    let w4 = w3.rotate_left(1);
+    let w5 = w3 ^ w4;
+    let w6 = w5.rotate_left(2);
+    let w7 = w5 ^ w6;
+    let w8 = w7.rotate_left(4);
+    let w9 = w7 ^ w8;
+    let w10 = w9.rotate_left(8);
+    let w11 = w9 ^ w10;
+    let w12 = w11.rotate_left(16);
+    let w13 = w11 ^ w12;
+    let w14 = w13;
+    let w15 = w2.rotate_left(1);
+    let w16 = w15 ^ w2;
+    let w17 = w16;
+    let w18 = w14 & w17;
+    let w19 = w2 ^ w18;
+    let w20 = w1 & 0x00000002u32;
+    let w21 = w20.rotate_left(1);
+    let w22 = w20 ^ w21;
+    let w23 = w22.rotate_left(2);
+    let w24 = w22 ^ w23;
+    let w25 = w24.rotate_left(4);
+    let w26 = w24 ^ w25;
+    let w27 = w26.rotate_left(8);
+    let w28 = w26 ^ w27;
+    let w29 = w28.rotate_left(16);
+    let w30 = w28 ^ w29;
+    let w31 = w30;
+    let w32 = w19.rotate_left(2);
+    let w33 = w32 ^ w19;
+    let w34 = w33;
+    let w35 = w31 & w34;
+    let w36 = w19 ^ w35;
+    let w37 = w1 & 0x00000004u32;
+    let w38 = w37.rotate_left(1);
+    let w39 = w37 ^ w38;
+    let w40 = w39.rotate_left(2);
+    let w41 = w39 ^ w40;
+    let w42 = w41.rotate_left(4);
+    let w43 = w41 ^ w42;
+    let w44 = w43.rotate_left(8);
+    let w45 = w43 ^ w44;
+    let w46 = w45.rotate_left(16);
+    let w47 = w45 ^ w46;
+    let w48 = w47;
+    let w49 = w36.rotate_left(4);
+    let w50 = w49 ^ w36;
+    let w51 = w50;
+    let w52 = w48 & w51;
+    let w53 = w36 ^ w52;
+    let w54 = w1 & 0x00000008u32;
+    let w55 = w54.rotate_left(1);
+    let w56 = w54 ^ w55;
+    let w57 = w56.rotate_left(2);
+    let w58 = w56 ^ w57;
+    let w59 = w58.rotate_left(4);
+    let w60 = w58 ^ w59;
+    let w61 = w60.rotate_left(8);
+    let w62 = w60 ^ w61;
+    let w63 = w62.rotate_left(16);
+    let w64 = w62 ^ w63;
+    let w65 = w64;
+    let w66 = w53.rotate_left(8);
+    let w67 = w66 ^ w53;
+    let w68 = w67;
+    let w69 = w65 & w68;
+    let w70 = w53 ^ w69;
+    let w71 = w1 & 0x00000010u32;
+    let w72 = w71.rotate_left(1);
+    let w73 = w71 ^ w72;
+    let w74 = w73.rotate_left(2);
+    let w75 = w73 ^ w74;
+    let w76 = w75.rotate_left(4);
+    let w77 = w75 ^ w76;
+    let w78 = w77.rotate_left(8);
+    let w79 = w77 ^ w78;
+    let w80 = w79.rotate_left(16);
+    let w81 = w79 ^ w80;
+    let w82 = w81;
+    let w83 = w70.rotate_left(16);
+    let w84 = w83 ^ w70;
+    let w85 = w84;
+    let w86 = w82 & w85;
+    let w87 = w70 ^ w86;
+    let w88 = input_s;
+    let w89 = w87 & w88;
+    let w90 = w87 ^ w88;
+    let w91 = w90 & 0x00000001u32;
+    let w92 = w89 & 0x00000001u32;
+    let w93 = w92.rotate_left(1);
+    let w94 = w90 & 0x00000002u32;
+    let w95 = w94 ^ w93;
+    let w96 = w91 ^ w95;
+    let w97 = w89 & 0x00000002u32;
+    let w98 = w94 & w93;
+    let w99 = w97 ^ w98;
+    let w100 = w99.rotate_left(1);
+    let w101 = w90 & 0x00000004u32;
+    let w102 = w101 ^ w100;
+    let w103 = w96 ^ w102;
+    let w104 = w89 & 0x00000004u32;
+    let w105 = w101 & w100;
+    let w106 = w104 ^ w105;
+    let w107 = w106.rotate_left(1);
+    let w108 = w90 & 0x00000008u32;
+    let w109 = w108 ^ w107;
+    let w110 = w103 ^ w109;
+    let w111 = w89 & 0x00000008u32;
+    let w112 = w108 & w107;
+    let w113 = w111 ^ w112;
+    let w114 = w113.rotate_left(1);
+    let w115 = w90 & 0x00000010u32;
+    let w116 = w115 ^ w114;
+    let w117 = w110 ^ w116;
+    let w118 = w89 & 0x00000010u32;
+    let w119 = w115 & w114;
+    let w120 = w118 ^ w119;
+    let w121 = w120.rotate_left(1);
+    let w122 = w90 & 0x00000020u32;
+    let w123 = w122 ^ w121;
+    let w124 = w117 ^ w123;
+    let w125 = w89 & 0x00000020u32;
+    let w126 = w122 & w121;
+    let w127 = w125 ^ w126;
+    let w128 = w127.rotate_left(1);
+    let w129 = w90 & 0x00000040u32;
+    let w130 = w129 ^ w128;
+    let w131 = w124 ^ w130;
+    let w132 = w89 & 0x00000040u32;
+    let w133 = w129 & w128;
+    let w134 = w132 ^ w133;
+    let w135 = w134.rotate_left(1);
+    let w136 = w90 & 0x00000080u32;
+    let w137 = w136 ^ w135;
+    let w138 = w131 ^ w137;
+    let w139 = w89 & 0x00000080u32;
+    let w140 = w136 & w135;
+    let w141 = w139 ^ w140;
+    let w142 = w141.rotate_left(1);
+    let w143 = w90 & 0x00000100u32;
+    let w144 = w143 ^ w142;
+    let w145 = w138 ^ w144;
+    let w146 = w89 & 0x00000100u32;
+    let w147 = w143 & w142;
+    let w148 = w146 ^ w147;
+    let w149 = w148.rotate_left(1);
+    let w150 = w90 & 0x00000200u32;
+    let w151 = w150 ^ w149;
+    let w152 = w145 ^ w151;
+    let w153 = w89 & 0x00000200u32;
+    let w154 = w150 & w149;
+    let w155 = w153 ^ w154;
+    let w156 = w155.rotate_left(1);
+    let w157 = w90 & 0x00000400u32;
+    let w158 = w157 ^ w156;
+    let w159 = w152 ^ w158;
+    let w160 = w89 & 0x00000400u32;
+    let w161 = w157 & w156;
+    let w162 = w160 ^ w161;
+    let w163 = w162.rotate_left(1);
+    let w164 = w90 & 0x00000800u32;
+    let w165 = w164 ^ w163;
+    let w166 = w159 ^ w165;
+    let w167 = w89 & 0x00000800u32;
+    let w168 = w164 & w163;
+    let w169 = w167 ^ w168;
+    let w170 = w169.rotate_left(1);
+    let w171 = w90 & 0x00001000u32;
+    let w172 = w171 ^ w170;
+    let w173 = w166 ^ w172;
+    let w174 = w89 & 0x00001000u32;
+    let w175 = w171 & w170;
+    let w176 = w174 ^ w175;
+    let w177 = w176.rotate_left(1);
+    let w178 = w90 & 0x00002000u32;
+    let w179 = w178 ^ w177;
+    let w180 = w173 ^ w179;
+    let w181 = w89 & 0x00002000u32;
+    let w182 = w178 & w177;
+    let w183 = w181 ^ w182;
+    let w184 = w183.rotate_left(1);
+    let w185 = w90 & 0x00004000u32;
+    let w186 = w185 ^ w184;
+    let w187 = w180 ^ w186;
+    let w188 = w89 & 0x00004000u32;
+    let w189 = w185 & w184;
+    let w190 = w188 ^ w189;
+    let w191 = w190.rotate_left(1);
+    let w192 = w90 & 0x00008000u32;
+    let w193 = w192 ^ w191;
+    let w194 = w187 ^ w193;
+    let w195 = w89 & 0x00008000u32;
+    let w196 = w192 & w191;
+    let w197 = w195 ^ w196;
+    let w198 = w197.rotate_left(1);
+    let w199 = w90 & 0x00010000u32;
+    let w200 = w199 ^ w198;
+    let w201 = w194 ^ w200;
+    let w202 = w89 & 0x00010000u32;
+    let w203 = w199 & w198;
+    let w204 = w202 ^ w203;
+    let w205 = w204.rotate_left(1);
+    let w206 = w90 & 0x00020000u32;
+    let w207 = w206 ^ w205;
+    let w208 = w201 ^ w207;
+    let w209 = w89 & 0x00020000u32;
+    let w210 = w206 & w205;
+    let w211 = w209 ^ w210;
+    let w212 = w211.rotate_left(1);
+    let w213 = w90 & 0x00040000u32;
+    let w214 = w213 ^ w212;
+    let w215 = w208 ^ w214;
+    let w216 = w89 & 0x00040000u32;
+    let w217 = w213 & w212;
+    let w218 = w216 ^ w217;
+    let w219 = w218.rotate_left(1);
+    let w220 = w90 & 0x00080000u32;
+    let w221 = w220 ^ w219;
+    let w222 = w215 ^ w221;
+    let w223 = w89 & 0x00080000u32;
+    let w224 = w220 & w219;
+    let w225 = w223 ^ w224;
+    let w226 = w225.rotate_left(1);
+    let w227 = w90 & 0x00100000u32;
+    let w228 = w227 ^ w226;
+    let w229 = w222 ^ w228;
+    let w230 = w89 & 0x00100000u32;
+    let w231 = w227 & w226;
+    let w232 = w230 ^ w231;
+    let w233 = w232.rotate_left(1);
+    let w234 = w90 & 0x00200000u32;
+    let w235 = w234 ^ w233;
+    let w236 = w229 ^ w235;
+    let w237 = w89 & 0x00200000u32;
+    let w238 = w234 & w233;
+    let w239 = w237 ^ w238;
+    let w240 = w239.rotate_left(1);
+    let w241 = w90 & 0x00400000u32;
+    let w242 = w241 ^ w240;
+    let w243 = w236 ^ w242;
+    let w244 = w89 & 0x00400000u32;
+    let w245 = w241 & w240;
+    let w246 = w244 ^ w245;
+    let w247 = w246.rotate_left(1);
+    let w248 = w90 & 0x00800000u32;
+    let w249 = w248 ^ w247;
+    let w250 = w243 ^ w249;
+    let w251 = w89 & 0x00800000u32;
+    let w252 = w248 & w247;
+    let w253 = w251 ^ w252;
+    let w254 = w253.rotate_left(1);
+    let w255 = w90 & 0x01000000u32;
+    let w256 = w255 ^ w254;
+    let w257 = w250 ^ w256;
+    let w258 = w89 & 0x01000000u32;
+    let w259 = w255 & w254;
+    let w260 = w258 ^ w259;
+    let w261 = w260.rotate_left(1);
+    let w262 = w90 & 0x02000000u32;
+    let w263 = w262 ^ w261;
+    let w264 = w257 ^ w263;
+    let w265 = w89 & 0x02000000u32;
+    let w266 = w262 & w261;
+    let w267 = w265 ^ w266;
+    let w268 = w267.rotate_left(1);
+    let w269 = w90 & 0x04000000u32;
+    let w270 = w269 ^ w268;
+    let w271 = w264 ^ w270;
+    let w272 = w89 & 0x04000000u32;
+    let w273 = w269 & w268;
+    let w274 = w272 ^ w273;
+    let w275 = w274.rotate_left(1);
+    let w276 = w90 & 0x08000000u32;
+    let w277 = w276 ^ w275;
+    let w278 = w271 ^ w277;
+    let w279 = w89 & 0x08000000u32;
+    let w280 = w276 & w275;
+    let w281 = w279 ^ w280;
+    let w282 = w281.rotate_left(1);
+    let w283 = w90 & 0x10000000u32;
+    let w284 = w283 ^ w282;
+    let w285 = w278 ^ w284;
+    let w286 = w89 & 0x10000000u32;
+    let w287 = w283 & w282;
+    let w288 = w286 ^ w287;
+    let w289 = w288.rotate_left(1);
+    let w290 = w90 & 0x20000000u32;
+    let w291 = w290 ^ w289;
+    let w292 = w285 ^ w291;
+    let w293 = w89 & 0x20000000u32;
+    let w294 = w290 & w289;
+    let w295 = w293 ^ w294;
+    let w296 = w295.rotate_left(1);
+    let w297 = w90 & 0x40000000u32;
+    let w298 = w297 ^ w296;
+    let w299 = w292 ^ w298;
+    let w300 = w89 & 0x40000000u32;
+    let w301 = w297 & w296;
+    let w302 = w300 ^ w301;
+    let w303 = w302.rotate_left(1);
+    let w304 = w90 & 0x80000000u32;
+    let w305 = w304 ^ w303;
+    let w306 = w299 ^ w305;
+    w306
+}
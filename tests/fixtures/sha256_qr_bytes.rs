@@ -0,0 +1,10887 @@
+// Generated by `cargo run --bin regen_fixtures` — do not edit manually.
+pub const EXPR_DIGEST: [u8; 32] = [
+    0xaf, 0xa6, 0x8f, 0x3e, 0xce, 0x01, 0xea, 0x08,
+    0x15, 0x42, 0x7a, 0xbc, 0x0f, 0xfb, 0x61, 0x46,
+    0x0e, 0x08, 0xea, 0x4f, 0x8c, 0x9b, 0x8a, 0x00,
+    0xc4, 0x21, 0x5e, 0x40, 0x3b, 0xc2, 0x88, 0xdf,
+];
+pub fn sha256_qr_bytes(digest: &[u8; 32]) -> u32 {
+    const POOL: &[u32] = &[
+        0xcd2c6f7f,
+        0xbb2a3fb2,
+        0x430b0604,
+        0x482615ef,
+        0x741b35a4,
+        0xc9accb14,
+        0xade4b6d0,
+        0x958b761d,
+        0xfe6ebc59,
+        0x3ef201cf,
+        0x00000001,
+        0x00000001,
+        0x00000002,
+        0x00000002,
+        0xdee17b11,
+        0x9dfce645,
+        0x5c6c5976,
+        0xc5a0ef11,
+        0xd7a39602,
+        0x25fffc5c,
+        0xde8fe5c5,
+        0xcb30ce1a,
+        0x0aceeffd,
+        0x2243ae90,
+        0x00000004,
+        0x00000004,
+        0x9e0d7fa8,
+        0x21d9db02,
+        0x386c9cfa,
+        0x6bf28e6c,
+        0x5990773e,
+        0xff0ea77d,
+        0x2126e817,
+        0x5e929da0,
+        0x00000008,
+        0x00000008,
+        0x48501808,
+        0x6bfe347b,
+        0x287a9bc0,
+        0xafd44686,
+        0xf552b66e,
+        0xf6ff78ef,
+        0x0879793a,
+        0x1f4976fa,
+        0x00000010,
+        0x00000010,
+        0x34bb13f0,
+        0x381e6892,
+        0x629eadc0,
+        0x97d6c32f,
+        0xcadbf41a,
+        0x75e88c63,
+        0xd108aedb,
+        0xd3c9b7fd,
+        0x00000020,
+        0x00000020,
+        0x30f0a328,
+        0xdc6cd914,
+        0x12493486,
+        0x77dd30e5,
+        0x5f6d584d,
+        0x8f3066bc,
+        0x2e88704b,
+        0xea426b01,
+        0x00000040,
+        0x00000040,
+        0xaf976aab,
+        0x8850fff4,
+        0x9f55357b,
+        0x1f137583,
+        0xe66d4fd1,
+        0x345f0997,
+        0x6aa0e622,
+        0x07d10a3f,
+        0x00000080,
+        0x00000080,
+        0x135f3e73,
+        0x93922a5b,
+        0x61935a25,
+        0x4e9d1432,
+        0x1e150e58,
+        0x7a95e009,
+        0x85dea0dc,
+        0xc7c36581,
+        0x00000100,
+        0x00000100,
+        0x582b922f,
+        0xb86d04c8,
+        0x2b6fb3cd,
+        0xa04d91cd,
+        0x7143007e,
+        0x102ff028,
+        0xaa45d57c,
+        0xbf8f692a,
+        0x00000200,
+        0x00000200,
+        0xecdca315,
+        0x10787f2d,
+        0xd5ff8f4b,
+        0x41440926,
+        0x4edc7d04,
+        0xfa202be2,
+        0x9da70595,
+        0x837c5d6d,
+        0x00000400,
+        0x00000400,
+        0x4c0bcc72,
+        0xa6d6549c,
+        0x89bf384b,
+        0x72e5cd1f,
+        0x404e4995,
+        0x03d8ae10,
+        0x20f44720,
+        0xeecbd294,
+        0x00000800,
+        0x00000800,
+        0xd192ea97,
+        0xc02a68ad,
+        0xcaa57fd9,
+        0x5c23ba00,
+        0x2974f98d,
+        0x9d1ffa92,
+        0x6f76fefc,
+        0x2a546237,
+        0x00001000,
+        0x00001000,
+        0xabf1ae91,
+        0x62f4e8f3,
+        0x9a2a910a,
+        0xcca49d49,
+        0xdf7f0d8e,
+        0x159ba8db,
+        0x99cb623d,
+        0x60930947,
+        0x00002000,
+        0x00002000,
+        0xd0efd947,
+        0xc5916a57,
+        0xe1150f7e,
+        0x3e7b7e07,
+        0x582170dc,
+        0xc0c08aa8,
+        0x6c8a461a,
+        0x1c2bb76e,
+        0x00004000,
+        0x00004000,
+        0x410102c3,
+        0x9f273616,
+        0xf4494190,
+        0xaebbfc29,
+        0x3e34c19f,
+        0x59e3238a,
+        0x4db8d750,
+        0x71baa791,
+        0x00008000,
+        0x00008000,
+        0xe5db98b8,
+        0xca2dd6a3,
+        0xcd6fa50b,
+        0xfd0dcc2e,
+        0x1f1d334d,
+        0xfa077d26,
+        0x0783a57b,
+        0x60a48b73,
+        0x00010000,
+        0x00010000,
+        0x69194a02,
+        0x2a90dc39,
+        0x24cb3a0d,
+        0x7f00c5ab,
+        0x3bb28dde,
+        0xfc2699b7,
+        0xa0d6b85f,
+        0x879e3579,
+        0x00020000,
+        0x00020000,
+        0xffd55ad9,
+        0x55a8892c,
+        0xb2a00188,
+        0x5b6fcfd5,
+        0x97ab989b,
+        0xcab2a0b3,
+        0x45c6ea55,
+        0xf115ac61,
+        0x00040000,
+        0x00040000,
+        0xb254cb87,
+        0xf8b597e4,
+        0x87670bc2,
+        0x9d228d8e,
+        0x6a01310a,
+        0x08c8f3bd,
+        0xaf4b9516,
+        0xc0a55e9c,
+        0x00080000,
+        0x00080000,
+        0xeab81afa,
+        0xffde0d69,
+        0x169df7b4,
+        0xe586af0d,
+        0xbec548fd,
+        0xa7e71c18,
+        0x1ad9b4c2,
+        0x28aeb1bf,
+        0x00100000,
+        0x00100000,
+        0xbe1bb62a,
+        0x2ac13dd1,
+        0x9ba6806e,
+        0x5154eb23,
+        0xd2bb08e2,
+        0x375b2523,
+        0xea9c2654,
+        0xca2f971d,
+        0x00200000,
+        0x00200000,
+        0xba649cea,
+        0xebf40308,
+        0x1c050a9a,
+        0xe18d27bf,
+        0xd563d410,
+        0x8e89bfb9,
+        0x167ffed1,
+        0x018948fb,
+        0x00400000,
+        0x00400000,
+        0x3267ea3d,
+        0x900a252f,
+        0xd116e8a3,
+        0x3e1a91eb,
+        0x93a20846,
+        0x154b75d8,
+        0xf5925a2f,
+        0x827646f4,
+        0x00800000,
+        0x00800000,
+        0x30a0e916,
+        0x62485f06,
+        0xe9cbef6c,
+        0x191d2b11,
+        0x316329d0,
+        0x09edd789,
+        0x83ada725,
+        0x06618385,
+        0x01000000,
+        0x01000000,
+        0x980b1fc7,
+        0x725c62d7,
+        0xabcb7e9f,
+        0x669fc3e7,
+        0x692457af,
+        0x9c2b1c95,
+        0xb49348b5,
+        0x841069c5,
+        0x02000000,
+        0x02000000,
+        0x6c53e9e1,
+        0x6263be6c,
+        0x3d468452,
+        0x9f774eaf,
+        0x8ad41446,
+        0xd0541386,
+        0x6bf6d41f,
+        0x9787e32f,
+        0x04000000,
+        0x04000000,
+        0x5d6cfbb1,
+        0x8e35a4a9,
+        0x18d20cf2,
+        0xf9d2c946,
+        0x3f8d1a21,
+        0xc3fe4c62,
+        0x33f805a9,
+        0x16043616,
+        0x08000000,
+        0x08000000,
+        0x0fd0104d,
+        0xeee5d8eb,
+        0xe4c32a6a,
+        0xb13faf90,
+        0xf39f5c3b,
+        0xe40ce9ae,
+        0x12655759,
+        0x4db0aec6,
+        0x10000000,
+        0x10000000,
+        0x3659f3d5,
+        0xacd14cbb,
+        0x7849750e,
+        0xec2236d8,
+        0x9ea51cb3,
+        0x49876c8c,
+        0x25e3ba5f,
+        0xcc7afa10,
+        0x20000000,
+        0x20000000,
+        0xfbc6d480,
+        0x87b005ce,
+        0xe0dcda69,
+        0xdcf3a8df,
+        0x8f10a011,
+        0xdb8bc196,
+        0xe8316aa0,
+        0x91a3e47f,
+        0x40000000,
+        0x40000000,
+        0x36ea4d61,
+        0x5a6fee6d,
+        0x7a0f9a84,
+        0xd58e944a,
+        0xa656a4cf,
+        0x32e5c923,
+        0xc2395464,
+        0x174cbbc4,
+        0x80000000,
+        0x66a817f7,
+        0x1b6e1a98,
+        0x1dec15a7,
+        0x3367a31f,
+        0xd21f2402,
+        0x12cd93a6,
+        0xfbdad444,
+        0xa134e6ad,
+        0xb9500150,
+        0x00000001,
+        0x00000001,
+        0x00000002,
+        0x00000002,
+        0x4806d463,
+        0x81850aad,
+        0x863ae8c7,
+        0xee7f88eb,
+        0x39838c58,
+        0x9af2c296,
+        0x92112f29,
+        0x1143f20e,
+        0xccebeb2c,
+        0xce5c1b02,
+        0x00000004,
+        0x00000004,
+        0x7f8ad0dd,
+        0xc93ee0ca,
+        0x38c59019,
+        0x91ed5508,
+        0xc87663b3,
+        0x3bd545db,
+        0x7dd28662,
+        0xdc7c2131,
+        0x00000008,
+        0x00000008,
+        0x85729031,
+        0x9be5d92d,
+        0x0ade631d,
+        0xe68ed082,
+        0xfa2bbd72,
+        0x88a3d149,
+        0x66c1463a,
+        0x18487bc6,
+        0x00000010,
+        0x00000010,
+        0xc1e1c809,
+        0xf60022a8,
+        0x01853ebe,
+        0xd3eb9979,
+        0x9ead7556,
+        0x49527f66,
+        0xe19bde2f,
+        0xf0c3edae,
+        0x00000020,
+        0x00000020,
+        0xfc8a2528,
+        0xad14bb9c,
+        0x7dbab48f,
+        0xfcc451a7,
+        0x4cdcce0f,
+        0x970ff281,
+        0xf7f716b5,
+        0x56040fb8,
+        0x00000040,
+        0x00000040,
+        0x58421aee,
+        0x1f2c0625,
+        0x0ff622e3,
+        0x224fd1a3,
+        0xe2ef3a4e,
+        0x7da149ec,
+        0xd7d64dca,
+        0x3cb07a3b,
+        0x00000080,
+        0x00000080,
+        0x9946bde5,
+        0xb5faae23,
+        0x1fd5b244,
+        0xbedaec40,
+        0xbb45cfd9,
+        0x4365c98a,
+        0xcb49a7d1,
+        0x82148e9e,
+        0x00000100,
+        0x00000100,
+        0x7f614465,
+        0xb7220c8f,
+        0xe8944c55,
+        0x787e3045,
+        0xf254bf8c,
+        0xab4a8935,
+        0x79c93306,
+        0x33aa994f,
+        0x00000200,
+        0x00000200,
+        0x4fc6bd72,
+        0x49abf6ad,
+        0xcf017e3b,
+        0x62def82a,
+        0x67b1a8f1,
+        0x02fd55ad,
+        0xac20c8b8,
+        0xa2137303,
+        0x00000400,
+        0x00000400,
+        0xe1feeae5,
+        0x84bbd76e,
+        0xf13ff557,
+        0xd0e049a8,
+        0xe74dce53,
+        0xee87013f,
+        0x9db003b0,
+        0x94d1dbaf,
+        0x00000800,
+        0x00000800,
+        0x371c60ab,
+        0x76fee18b,
+        0x20663df0,
+        0x9e60e908,
+        0xee3579c6,
+        0xd49e40d8,
+        0x5b2f85ce,
+        0x42644530,
+        0x00001000,
+        0x00001000,
+        0x9df059c2,
+        0xf3c756a0,
+        0x2e6a2070,
+        0x7c73c1c5,
+        0xbabbd7eb,
+        0x588fdd3b,
+        0xa26935c2,
+        0x83254098,
+        0x00002000,
+        0x00002000,
+        0x1155f7e0,
+        0x55870dad,
+        0x4aeb6199,
+        0x72a2a1b3,
+        0xd03e8158,
+        0x7d1931c4,
+        0xa31e0b48,
+        0x2ad08af8,
+        0x00004000,
+        0x00004000,
+        0x1d579506,
+        0xfd872b28,
+        0xa5ae8c2f,
+        0xb203f268,
+        0x0369cb56,
+        0x29a4223f,
+        0x6fb39b68,
+        0x34d16d38,
+        0x00008000,
+        0x00008000,
+        0xa72528e9,
+        0x1e5d062f,
+        0xbfd2fc5b,
+        0x863b985c,
+        0x0716470b,
+        0x0e57a183,
+        0x0feb3415,
+        0x8191e87a,
+        0x00010000,
+        0x00010000,
+        0x65f59809,
+        0x23362b8e,
+        0x7e768b1e,
+        0x9f58e53f,
+        0x46b5f48c,
+        0xd6c119bd,
+        0xa8c1d5a8,
+        0x00018338,
+        0x00020000,
+        0x00020000,
+        0xe0253871,
+        0xbf178939,
+        0x60bb2afc,
+        0x00fced19,
+        0x98079290,
+        0xa21606fd,
+        0x05980fd9,
+        0x97ecc07e,
+        0x00040000,
+        0x00040000,
+        0x24229b92,
+        0xebe87e35,
+        0x66dece64,
+        0x1fcec93f,
+        0x82f350d3,
+        0xd0316f86,
+        0xfbd61496,
+        0x2615f36b,
+        0x00080000,
+        0x00080000,
+        0xddf17ac9,
+        0xa1426e6b,
+        0x7ce77629,
+        0xeb9b5533,
+        0x5df55eaf,
+        0x6088b870,
+        0x3d298454,
+        0x9320024f,
+        0x00100000,
+        0x00100000,
+        0xbd74afbc,
+        0x9344b0f8,
+        0x242d26d0,
+        0xd4461181,
+        0xa37e8995,
+        0xc6a145f0,
+        0x6fd2f5f1,
+        0x0acba1ba,
+        0x00200000,
+        0x00200000,
+        0x4e038b8d,
+        0x3ffd3146,
+        0xe01f83d5,
+        0x7fb05f16,
+        0x86956195,
+        0x8a272803,
+        0x9d737088,
+        0x21f75683,
+        0x00400000,
+        0x00400000,
+        0xf3246c2b,
+        0x06797bae,
+        0x53eabfa9,
+        0x22289457,
+        0x3287a10d,
+        0xb084c2d6,
+        0x24f4cbf7,
+        0xaf83068f,
+        0x00800000,
+        0x00800000,
+        0xb3c0f0c6,
+        0x9400bb47,
+        0xce18aebd,
+        0xe377d05b,
+        0x668059d9,
+        0x41aefd37,
+        0xce7641d2,
+        0xe8d676a7,
+        0x01000000,
+        0x01000000,
+        0x230e219c,
+        0x878c0927,
+        0x39ab0a7a,
+        0xcbe0f74a,
+        0x4f254ba9,
+        0x30c45281,
+        0xe2c83be9,
+        0xeab0ddc1,
+        0x02000000,
+        0x02000000,
+        0xa250c46e,
+        0xfa45c569,
+        0x23fc495b,
+        0xb3909210,
+        0x888db01c,
+        0xe3894f12,
+        0x10edb752,
+        0x6cdcfbcf,
+        0x04000000,
+        0x04000000,
+        0x84604602,
+        0x04abf9d5,
+        0x9284ca3c,
+        0xad4ac19e,
+        0x7b1cd65f,
+        0x998adf27,
+        0xf4d97c93,
+        0x5d57a85f,
+        0x08000000,
+        0x08000000,
+        0x7d3743b1,
+        0xf15ca7e9,
+        0x1d2b832f,
+        0xfb55c3a9,
+        0x9c4daca3,
+        0xbc9062af,
+        0xb19da97b,
+        0x0133e35d,
+        0x10000000,
+        0x10000000,
+        0x23187b20,
+        0x5123bb21,
+        0x797132bf,
+        0xfdfe47d2,
+        0x71ec6f24,
+        0x435e86ef,
+        0x39f81b75,
+        0xf557bdfc,
+        0x20000000,
+        0x20000000,
+        0x39c1730d,
+        0x009c18d6,
+        0xb092bba0,
+        0x31535b5d,
+        0xb63df6c5,
+        0xbdaad458,
+        0xa258f2e6,
+        0xff9c07e4,
+        0x40000000,
+        0x40000000,
+        0x6289825a,
+        0xee0ca41a,
+        0x6b98bfbc,
+        0xb2e45cd7,
+        0x6b59a1f4,
+        0xb54fd9bf,
+        0x790be1b7,
+        0x409fc9e4,
+        0x80000000,
+        0x2e1afbb5,
+        0x8036186d,
+        0xc40f9c17,
+        0xedd197e1,
+        0x7e1a6883,
+        0xfb2342f6,
+        0x00000001,
+        0x00000001,
+        0x00000002,
+        0x00000002,
+        0x946f0435,
+        0x96eb81a4,
+        0x0fb9d7f7,
+        0x223add86,
+        0x90630a7d,
+        0xf2b6115c,
+        0x0e1a89ae,
+        0x6e24903a,
+        0x6d034bf2,
+        0x20d1df31,
+        0x00000004,
+        0x00000004,
+        0x005c532e,
+        0x669cd1cf,
+        0x7c52821d,
+        0xcd78ca74,
+        0xae91c607,
+        0xeecb1c03,
+        0x5ac8dafc,
+        0x914d8760,
+        0x00000008,
+        0x00000008,
+        0x25752591,
+        0x7558eeda,
+        0xbc6a764e,
+        0x45a468d1,
+        0xca190275,
+        0xdde793c3,
+        0xfbb92cbb,
+        0x2f5d75a0,
+        0x00000010,
+        0x00000010,
+        0x29163d1a,
+        0x289d463e,
+        0x68cdda15,
+        0x083b40fb,
+        0x9cb83b26,
+        0xcac18325,
+        0x3f3f1932,
+        0x9a77b41b,
+        0x00000020,
+        0x00000020,
+        0x2d5d0c98,
+        0xf4807ce5,
+        0x61273a23,
+        0xbaaf74ba,
+        0xba289a8d,
+        0xc764a7f0,
+        0xc5b67703,
+        0x6e9f3f8a,
+        0x00000040,
+        0x00000040,
+        0x8b828615,
+        0xa9a63b26,
+        0xd8445383,
+        0x68daa267,
+        0xdd2ec2bf,
+        0xdc961ad3,
+        0xfbd836dc,
+        0x3c974bfc,
+        0x00000080,
+        0x00000080,
+        0xdfa547cd,
+        0xcffd4977,
+        0x7fc280f4,
+        0x86b9239c,
+        0x67bc638b,
+        0xf3efde12,
+        0xfbc93357,
+        0xd8f61e5a,
+        0x00000100,
+        0x00000100,
+        0x30dd90fa,
+        0x1b5633ea,
+        0xcdb9c012,
+        0xe312205d,
+        0xb4afaa8c,
+        0xa3a3d5f6,
+        0xf13e1d78,
+        0xa2679db5,
+        0x00000200,
+        0x00000200,
+        0xcceb9cfa,
+        0xcfff3ca8,
+        0xb2309632,
+        0xbfa50a71,
+        0x44f65bec,
+        0x9e61393a,
+        0x6bb354b6,
+        0x4429fbbb,
+        0x00000400,
+        0x00000400,
+        0x4939d475,
+        0xd706531c,
+        0x084654dc,
+        0x370f0a30,
+        0x8f43f146,
+        0xedd29f21,
+        0xf4e8bdd2,
+        0xfc3af34e,
+        0x00000800,
+        0x00000800,
+        0x83148a6f,
+        0x128ce8e0,
+        0xeb74dbb0,
+        0x92b32c3b,
+        0x6e0af594,
+        0xb61d31fa,
+        0xa2fb7551,
+        0x7870209b,
+        0x00001000,
+        0x00001000,
+        0xf8608973,
+        0x932ee796,
+        0x61e22de1,
+        0xa8a04621,
+        0x7bb02615,
+        0xe752af20,
+        0x964eca31,
+        0xa89e7456,
+        0x00002000,
+        0x00002000,
+        0x46b95893,
+        0x8467607f,
+        0xf11afca9,
+        0x3c97c5b6,
+        0x6ffd7b9f,
+        0xa5bf25e9,
+        0xf9869a33,
+        0x01ed75ea,
+        0x00004000,
+        0x00004000,
+        0x0782d602,
+        0x26a9f9cf,
+        0x1890b63b,
+        0x16b6e75c,
+        0x3e1db78f,
+        0xe7f3505c,
+        0xe0553e25,
+        0x17aaf7b9,
+        0x00008000,
+        0x00008000,
+        0x3d4029c8,
+        0x525b9274,
+        0xee48ea1d,
+        0xe53bdc2e,
+        0xb16cfa2e,
+        0x03484f60,
+        0x337764ef,
+        0x3b24a6ba,
+        0x00010000,
+        0x00010000,
+        0xaa94f5ba,
+        0x10afb66c,
+        0x4d207151,
+        0x43e94b84,
+        0x74b14928,
+        0xa3f50528,
+        0x205e7e87,
+        0xf475d098,
+        0x00020000,
+        0x00020000,
+        0x7af6cad3,
+        0xdb63063c,
+        0x58f0183a,
+        0xe5021575,
+        0x208b718f,
+        0x7f629288,
+        0xa68c37d2,
+        0x15ab4522,
+        0x00040000,
+        0x00040000,
+        0x69745fa7,
+        0xaa23e0fb,
+        0x4911894b,
+        0xb007c78a,
+        0x4048d8b3,
+        0xdcdf01c6,
+        0x16d5ef62,
+        0xfd03f5eb,
+        0x00080000,
+        0x00080000,
+        0x667aa775,
+        0x95499aea,
+        0x6bd66f9e,
+        0x99a01a75,
+        0xb0c3989a,
+        0xa46fc9c0,
+        0x8c49035b,
+        0x0d9db265,
+        0x00100000,
+        0x00100000,
+        0xf64719b9,
+        0x19931764,
+        0xb03e3df1,
+        0x4aa1ff03,
+        0x3cf70cb5,
+        0x45285b5c,
+        0x262564c5,
+        0x62ab1ddd,
+        0x00200000,
+        0x00200000,
+        0x55aca3c8,
+        0x83930a14,
+        0xf823c016,
+        0x0145d61b,
+        0x76081dbc,
+        0x85ccfb69,
+        0xddd88f1f,
+        0xbd8f2dec,
+        0x00400000,
+        0x00400000,
+        0x8dee92f8,
+        0xe94c86f6,
+        0xc7690e36,
+        0xaa2cae4a,
+        0x33af4234,
+        0x3ad1c1b5,
+        0xaab599b9,
+        0x535284a3,
+        0x00800000,
+        0x00800000,
+        0x76891bf1,
+        0x4ff415a6,
+        0xa4a0a886,
+        0x39b325ef,
+        0x2e5c0112,
+        0x3512766a,
+        0x8693d1a9,
+        0x4133d2bf,
+        0x01000000,
+        0x01000000,
+        0xb3def820,
+        0x2cc13b78,
+        0xf22818e1,
+        0x3c791661,
+        0xe24c6fa5,
+        0xb7946f5b,
+        0x39efdb47,
+        0x04a25d39,
+        0x02000000,
+        0x02000000,
+        0x495a1709,
+        0xda54e9dc,
+        0xf2c8403a,
+        0x7c5ba212,
+        0xf384f715,
+        0xbb81d01b,
+        0x2bc399e1,
+        0x70a2e955,
+        0x04000000,
+        0x04000000,
+        0x147f04b6,
+        0x168f235d,
+        0x4bf30d70,
+        0x0b7a3d3f,
+        0x6f3b8d19,
+        0xbece9610,
+        0x98f63192,
+        0x788bd48b,
+        0x08000000,
+        0x08000000,
+        0x6e7e17b6,
+        0xffaea214,
+        0xcc14153d,
+        0xe9ea2493,
+        0x028a0681,
+        0x29192d16,
+        0x7e578b08,
+        0x060a0032,
+        0x10000000,
+        0x10000000,
+        0x6c7626e2,
+        0xd4a39c94,
+        0x10a952d6,
+        0x8d8584ef,
+        0x45d0c23c,
+        0x069d07be,
+        0xeb312d22,
+        0xc0dc0d65,
+        0x20000000,
+        0x20000000,
+        0x48001466,
+        0xd9517335,
+        0x071ca81d,
+        0x42b277e8,
+        0x217ccb31,
+        0xfe688f3a,
+        0x69598b45,
+        0x362626da,
+        0x40000000,
+        0x40000000,
+        0x8d4ec040,
+        0xd79c0e06,
+        0xa5ba5be4,
+        0xc1498d99,
+        0x7a596b67,
+        0xb1147d6b,
+        0x342583ae,
+        0x08fd8976,
+        0x80000000,
+        0x80344880,
+        0xf3ed8cba,
+        0x3d77e522,
+        0xc9861401,
+        0x4f2dcc3a,
+        0x9d19fc55,
+        0x6382116c,
+        0xffd269f1,
+        0xe895c13e,
+        0xbd89c448,
+        0x00000001,
+        0x00000001,
+        0x00000002,
+        0x00000002,
+        0x0a7ba158,
+        0x70086b07,
+        0x72c7a2f2,
+        0xd9ff901b,
+        0x112fc95d,
+        0x3d88d687,
+        0xfa45ae16,
+        0xd5dfdc60,
+        0x272e1ad9,
+        0xdac2fdb5,
+        0x00000004,
+        0x00000004,
+        0x38435771,
+        0x68961a9f,
+        0xfe459680,
+        0xaf21e1ab,
+        0x4d907ac8,
+        0x16cfff56,
+        0xf5cf5ef0,
+        0x00d5291e,
+        0x00000008,
+        0x00000008,
+        0x1adb2efa,
+        0x77900ef5,
+        0x10f44843,
+        0x9226db30,
+        0xe8a6c6e5,
+        0x319eb6d6,
+        0xa4871877,
+        0xc53f4e9b,
+        0x00000010,
+        0x00000010,
+        0x57dc93da,
+        0x7a235fed,
+        0x6d945227,
+        0xa8cc46b0,
+        0x74bf1489,
+        0x042b542b,
+        0x30ffdea2,
+        0x311bdbc9,
+        0x00000020,
+        0x00000020,
+        0x37d99e24,
+        0xb37884f2,
+        0x6647590a,
+        0x3a7fbc99,
+        0x2360aaf1,
+        0xb3ee7439,
+        0x72689d34,
+        0xc3987f43,
+        0x00000040,
+        0x00000040,
+        0x1ccc7b6e,
+        0x88d93a49,
+        0xe5b7d9b0,
+        0xdd1c9b44,
+        0x2f00808e,
+        0x71822877,
+        0x2f20306e,
+        0x4153650f,
+        0x00000080,
+        0x00000080,
+        0xdd6aafaf,
+        0x53f67be1,
+        0xcca58c31,
+        0xf52d9633,
+        0xe916a910,
+        0xe68727ad,
+        0x4da8d6c2,
+        0x87bc083e,
+        0x00000100,
+        0x00000100,
+        0xb798db82,
+        0xa56705b6,
+        0x502e9e04,
+        0x4572e90e,
+        0xc7fbf370,
+        0x183c68b3,
+        0x9d16dbf3,
+        0x48ca21ea,
+        0x00000200,
+        0x00000200,
+        0x882b5c31,
+        0xe97437b5,
+        0xf2dea751,
+        0x796dff0a,
+        0x6adc5a16,
+        0x5469bdd1,
+        0xad342b12,
+        0xcc2dfc6c,
+        0x00000400,
+        0x00000400,
+        0x0423d87b,
+        0xb49bc137,
+        0x70a52a74,
+        0x46a30384,
+        0xb3343e69,
+        0xb4ba5ea2,
+        0xc79357f3,
+        0xb195c660,
+        0x00000800,
+        0x00000800,
+        0x5f8865b0,
+        0xffaf6fd5,
+        0xabee38de,
+        0xe11cfd52,
+        0x2999bc50,
+        0xb8ef3d35,
+        0x9abfb3de,
+        0x16304cb5,
+        0x00001000,
+        0x00001000,
+        0x40c1e9ff,
+        0x135e683e,
+        0x7aed200f,
+        0x97256f9c,
+        0xba98ee77,
+        0x7de78ffd,
+        0xee0dc044,
+        0x05e96796,
+        0x00002000,
+        0x00002000,
+        0xbd07e8f9,
+        0xa4760855,
+        0x8609434d,
+        0x158cb24b,
+        0x9b621033,
+        0x46e2866b,
+        0x42f835b9,
+        0xbd19f8e4,
+        0x00004000,
+        0x00004000,
+        0x17248b8b,
+        0x2e809696,
+        0x816845df,
+        0xbed9f151,
+        0x42fe0250,
+        0xb2c69e7f,
+        0x48f484ed,
+        0x78154859,
+        0x00008000,
+        0x00008000,
+        0x39c6124a,
+        0x94d7af65,
+        0xb1397845,
+        0x5a2a80a1,
+        0xc813ddfb,
+        0xa6797f27,
+        0x7242e7b6,
+        0xa1a40658,
+        0x00010000,
+        0x00010000,
+        0x416ff849,
+        0x789b7aa3,
+        0xe3d34d92,
+        0xad6c94f7,
+        0x65023de5,
+        0x9c52c99d,
+        0x23763b00,
+        0xdc0ad378,
+        0x00020000,
+        0x00020000,
+        0x0a1ba751,
+        0x17c6cbc1,
+        0xd3dfd7a1,
+        0x8c74b95d,
+        0xf3354793,
+        0xe2db846d,
+        0xdfec78cf,
+        0x57980f69,
+        0x00040000,
+        0x00040000,
+        0x83d5942e,
+        0x0f3a3c5a,
+        0x2a9fe9ab,
+        0x1495e3e5,
+        0xe4e3abe0,
+        0x4a840c8e,
+        0x0817e6b1,
+        0xd099db8e,
+        0x00080000,
+        0x00080000,
+        0x21f0f48f,
+        0x9b495318,
+        0xda795251,
+        0xff0b01d2,
+        0x0f515aa5,
+        0x6cc40a01,
+        0x035da562,
+        0x19b98737,
+        0x00100000,
+        0x00100000,
+        0x68bb3950,
+        0x45a01636,
+        0x639d479d,
+        0x77521ed8,
+        0x0abde76a,
+        0x349356d1,
+        0x70b8d940,
+        0x97cfb12d,
+        0x00200000,
+        0x00200000,
+        0x677532e5,
+        0x1bd2c65b,
+        0x013963a0,
+        0xd41973f3,
+        0x093a0eab,
+        0xb5d7dbea,
+        0xc173425f,
+        0x6c8f6942,
+        0x00400000,
+        0x00400000,
+        0x0ad9c9ad,
+        0x95cdf26e,
+        0x95b18875,
+        0x1ebf77d3,
+        0x2a865d7f,
+        0x78f12b11,
+        0x5892c5d8,
+        0xfeb934ef,
+        0x00800000,
+        0x00800000,
+        0xbc0c316b,
+        0x477d1dc5,
+        0xc0039af5,
+        0x1bbb74cb,
+        0x1a50d9e7,
+        0x9ca31236,
+        0xbd017d8a,
+        0xd94c00e4,
+        0x01000000,
+        0x01000000,
+        0x75da2dda,
+        0xdfd27d3b,
+        0x9f7d9796,
+        0x388ae9e4,
+        0xb2e38130,
+        0x6475d108,
+        0xe2e3974f,
+        0xedd80e30,
+        0x02000000,
+        0x02000000,
+        0xcaedb3cf,
+        0x6e660d5f,
+        0x29cb19e5,
+        0x3d5bd191,
+        0xc1777e7c,
+        0x91a418fa,
+        0xdf93c1f3,
+        0x6ac73976,
+        0x04000000,
+        0x04000000,
+        0xcae44f8d,
+        0x849832cb,
+        0xa9d35a79,
+        0x7041a6dd,
+        0x6b848b17,
+        0xec0ca100,
+        0x60270d28,
+        0x65277ed3,
+        0x08000000,
+        0x08000000,
+        0xf18a8741,
+        0x08d986f0,
+        0x97b20f55,
+        0xa893206b,
+        0xf37165e3,
+        0xfba59915,
+        0x6635f212,
+        0xb210c2ba,
+        0x10000000,
+        0x10000000,
+        0x9beeb3ab,
+        0x98876475,
+        0x06ac6352,
+        0x3fbcd112,
+        0x338e8c22,
+        0xd33d8313,
+        0xf576bbbd,
+        0x6c6d096c,
+        0x20000000,
+        0x20000000,
+        0x6fd84450,
+        0x45ee0203,
+        0x7259fcc3,
+        0x27bdf317,
+        0x623dcf13,
+        0xa4d18cdb,
+        0xbe83f958,
+        0x8fc4a083,
+        0x40000000,
+        0x40000000,
+        0xcf793f68,
+        0x5d8ce332,
+        0x77f93109,
+        0xeeeb4678,
+        0x4c599c72,
+        0x9612eda2,
+        0x7f479c83,
+        0x608aed8e,
+        0x80000000,
+        0x8a53c5a0,
+        0x232a94e2,
+        0x7f9585b1,
+        0x7738e2e8,
+        0xedfae96d,
+        0x4cb178c1,
+        0x14fa6168,
+        0xdab5bb50,
+        0x61e17d1c,
+        0x00000001,
+        0x00000001,
+        0x00000002,
+        0x00000002,
+        0x0573c760,
+        0x0b5a2b89,
+        0x3adad1d9,
+        0x90a18040,
+        0x85f4b95e,
+        0x395a4aab,
+        0xdd615842,
+        0x44a6fec3,
+        0xad349bb1,
+        0xb5c8d534,
+        0x00000004,
+        0x00000004,
+        0x0c130017,
+        0x56c4af60,
+        0x913279cd,
+        0x4ec4d711,
+        0xf75446e4,
+        0x3f66b01b,
+        0x03d72045,
+        0x7432f30b,
+        0x00000008,
+        0x00000008,
+        0x6b1a2490,
+        0xd7790511,
+        0x5d85ff80,
+        0xe76a957a,
+        0x0978c792,
+        0x545b6d05,
+        0xbcc5749e,
+        0x44e1bb45,
+        0x00000010,
+        0x00000010,
+        0x93c53346,
+        0x8259655a,
+        0xbd91035b,
+        0x0bc38f10,
+        0x90f62d14,
+        0x7c8e4c1b,
+        0x40753458,
+        0xf6ce413b,
+        0x00000020,
+        0x00000020,
+        0x9a8ea3eb,
+        0xd0d49c67,
+        0x9561d86e,
+        0xe503c818,
+        0xd179e490,
+        0x23141fa4,
+        0x2d561cf6,
+        0x268a690d,
+        0x00000040,
+        0x00000040,
+        0xc1de7475,
+        0xf33e7856,
+        0xe92b3073,
+        0x350d9fb8,
+        0xaeef9bcf,
+        0x27d47413,
+        0x52f0d3cc,
+        0xb682bd04,
+        0x00000080,
+        0x00000080,
+        0xbd8f3827,
+        0x4bf93b74,
+        0xfc9e144d,
+        0xaab70aac,
+        0xbb98d495,
+        0xea8ca336,
+        0x5bfc60bd,
+        0x916e6a30,
+        0x00000100,
+        0x00000100,
+        0xb34eb7c7,
+        0xf00f891e,
+        0xe3a7ee3c,
+        0xd6901fb0,
+        0x6c2989b4,
+        0x107cbf12,
+        0xdcb3e643,
+        0xdd24aa19,
+        0x00000200,
+        0x00000200,
+        0xe6a263f2,
+        0xd0e9f8f7,
+        0x11a5771f,
+        0x2c99917a,
+        0x753d0deb,
+        0x7ca01104,
+        0x2e73f0f5,
+        0xbd96bfb6,
+        0x00000400,
+        0x00000400,
+        0x702b4d1e,
+        0x58ad9181,
+        0x841ce862,
+        0xd237d954,
+        0x0f3dd7f1,
+        0x5113556e,
+        0xf2b4b662,
+        0x2e76002d,
+        0x00000800,
+        0x00000800,
+        0xd5810aa2,
+        0x2e34cca2,
+        0x9ba51b9d,
+        0xf27b0bb8,
+        0xfa32e172,
+        0x55933c4c,
+        0xcfb108a3,
+        0x03917804,
+        0x00001000,
+        0x00001000,
+        0x5ca7c257,
+        0x5f4ca041,
+        0x5e01ee82,
+        0x7c967b7b,
+        0x444d4ff5,
+        0xfad4b0b6,
+        0xe37373d7,
+        0x764b5ced,
+        0x00002000,
+        0x00002000,
+        0xfead72e2,
+        0x0cfbadac,
+        0xd489a92d,
+        0x0f2405b7,
+        0x5d272e20,
+        0xee053d39,
+        0x95fd657a,
+        0xa519a84c,
+        0x00004000,
+        0x00004000,
+        0xba04c8fb,
+        0xeab91864,
+        0x3a094a7d,
+        0x3a31d922,
+        0xd493947b,
+        0xa3a2f764,
+        0x1d85b9fd,
+        0xfb7882ff,
+        0x00008000,
+        0x00008000,
+        0xc048aa91,
+        0xe99c90d0,
+        0x25e2f4d2,
+        0x6fd4315b,
+        0x5efd026c,
+        0x7dabbc8a,
+        0x2f607075,
+        0xf5a4a68a,
+        0x00010000,
+        0x00010000,
+        0x1cf6fc8c,
+        0x3b5a8bcb,
+        0xfd3ad30d,
+        0xd764b4ad,
+        0xf18e10ee,
+        0xdf2312a4,
+        0xf43aa600,
+        0x4c57a23f,
+        0x00020000,
+        0x00020000,
+        0x5cc6f0ff,
+        0x7b5d6899,
+        0x84e38332,
+        0x9ff3d234,
+        0x45787ffd,
+        0x9d07c27c,
+        0x7b05a6d5,
+        0x21b70ee8,
+        0x00040000,
+        0x00040000,
+        0x43643b09,
+        0x207d2666,
+        0x2e4ff322,
+        0x2a4927b4,
+        0xd2e9820d,
+        0x33c3460f,
+        0xac7c2a4f,
+        0x03073acd,
+        0x00080000,
+        0x00080000,
+        0xdc00f4fd,
+        0x6e75e833,
+        0xfd63cb7e,
+        0xc839e8f4,
+        0x6f9d65df,
+        0xd8739031,
+        0xf8f8225e,
+        0x5a0dc59b,
+        0x00100000,
+        0x00100000,
+        0x14ad29fc,
+        0x8c6eb05f,
+        0x860e2317,
+        0x0c8fa1f9,
+        0x75655882,
+        0x47152066,
+        0x2cbdc250,
+        0x7bd132c3,
+        0x00200000,
+        0x00200000,
+        0xf90241a1,
+        0xf49fc314,
+        0x1c020d4c,
+        0xc63907a3,
+        0x448fcf72,
+        0xc51c1657,
+        0x900c56dc,
+        0xbc427bca,
+        0x00400000,
+        0x00400000,
+        0x9c110f3d,
+        0x2c95ae52,
+        0x78b769fb,
+        0xbd483a5d,
+        0x285718fc,
+        0xfc3d953b,
+        0x1c594553,
+        0xc31e2cac,
+        0x00800000,
+        0x00800000,
+        0xdb265a03,
+        0x958b1013,
+        0x9102be96,
+        0xc6c4fe6e,
+        0xa9806210,
+        0x5b8e32a1,
+        0x2da1a437,
+        0x64059f27,
+        0x01000000,
+        0x01000000,
+        0x0fe028f5,
+        0xe4e70e4a,
+        0x414ec27b,
+        0xaf349f3e,
+        0x82c6ac35,
+        0x0c0a06dd,
+        0x24854e2c,
+        0x5eef78fa,
+        0x02000000,
+        0x02000000,
+        0xdbd63f6c,
+        0x33c3aa6f,
+        0xec01c669,
+        0xae22bb92,
+        0xfc9e3ef9,
+        0xe6928cf1,
+        0x1c18e162,
+        0xb1a0d1f0,
+        0x04000000,
+        0x04000000,
+        0xb6b11d66,
+        0xd14e63bf,
+        0xdebfeb29,
+        0xdb030eed,
+        0x588c7a49,
+        0x1ec72c39,
+        0xfb0bc380,
+        0x607ca3be,
+        0x08000000,
+        0x08000000,
+        0xf7d3b470,
+        0x85f6af3b,
+        0x441e67c5,
+        0x86b6a380,
+        0x06d949c7,
+        0xb84038f7,
+        0x80a20dbe,
+        0xc9360da2,
+        0x10000000,
+        0x10000000,
+        0x7c74c15c,
+        0x8d3d467b,
+        0xea178a5b,
+        0xe7297c9a,
+        0x472d3812,
+        0xf077049e,
+        0xbc0431f0,
+        0x21972a9c,
+        0x20000000,
+        0x20000000,
+        0x3943b517,
+        0x6f1b1910,
+        0xebe18d44,
+        0x814ee24e,
+        0x74e0c571,
+        0x67930efe,
+        0xaecaeacc,
+        0x18ff51c5,
+        0x40000000,
+        0x40000000,
+        0x0cd77029,
+        0x7e312675,
+        0xe38ffef2,
+        0x456291c1,
+        0xa98eb877,
+        0xd8a7ab3f,
+        0xa040bbe6,
+        0xce9a08ef,
+        0x80000000,
+        0xe1823b44,
+        0x02bfa14f,
+        0x165f758e,
+        0x143e0e88,
+        0x4d1100f0,
+        0xa5bac089,
+        0x00000001,
+        0x00000001,
+        0x00000002,
+        0x00000002,
+        0xb46efac7,
+        0xa279249f,
+        0x0d71f94e,
+        0xe3e0bd78,
+        0x96373554,
+        0xcb609acf,
+        0x49f27cea,
+        0xb77be6c3,
+        0xe5efbd3d,
+        0x403e88ca,
+        0x00000004,
+        0x00000004,
+        0x4b392e93,
+        0x4a524a17,
+        0x1bdeb85b,
+        0x8ef72c20,
+        0x09ab322d,
+        0x284dd94f,
+        0x3b5337bd,
+        0x08b93a96,
+        0x00000008,
+        0x00000008,
+        0x6a78cb5a,
+        0x278d974d,
+        0x183acaed,
+        0x88541081,
+        0x2667ac6f,
+        0x1caf6e28,
+        0x6f0754bd,
+        0xb1e11734,
+        0x00000010,
+        0x00000010,
+        0x85661f57,
+        0x403a2e24,
+        0x59917784,
+        0xca89ccf3,
+        0x1e7fd862,
+        0x72e11375,
+        0xf0538df0,
+        0xe8cb77ce,
+        0x00000020,
+        0x00000020,
+        0x565092a1,
+        0x96e6fbf2,
+        0x9c1a1331,
+        0x5a37495c,
+        0xb9842381,
+        0x8c28d091,
+        0x69008952,
+        0x5bee030b,
+        0x00000040,
+        0x00000040,
+        0x4778da47,
+        0x38618e27,
+        0x4d5aca8e,
+        0x56132947,
+        0xedb42c5e,
+        0xfa787504,
+        0x258fc7f4,
+        0xaad7d71a,
+        0x00000080,
+        0x00000080,
+        0x125e3896,
+        0x0b255f45,
+        0x70b7be0a,
+        0x67ccc4f9,
+        0x8c96e742,
+        0x84fc766c,
+        0x61a648f7,
+        0xd4c9116a,
+        0x00000100,
+        0x00000100,
+        0xe90db0e9,
+        0xf3d0f768,
+        0x756a7c32,
+        0xc08b1c79,
+        0xbe076249,
+        0xd815d68e,
+        0x09a58f74,
+        0x2eb566bb,
+        0x00000200,
+        0x00000200,
+        0x88fb3db4,
+        0x262a8142,
+        0xddcafef2,
+        0xd5581fe5,
+        0xe6cc3682,
+        0xccf9e605,
+        0x592e9083,
+        0x122d2f91,
+        0x00000400,
+        0x00000400,
+        0x9097d2b9,
+        0x95cff662,
+        0x888e5dd9,
+        0x13cdf9cb,
+        0xdc98f342,
+        0x893758c1,
+        0xd879d281,
+        0xebcabb45,
+        0x00000800,
+        0x00000800,
+        0xd7b3ae80,
+        0xd89e338d,
+        0xfb44af11,
+        0x66ce9391,
+        0x10420ab1,
+        0xc731cc47,
+        0x231afcea,
+        0x10049be7,
+        0x00001000,
+        0x00001000,
+        0x992493e5,
+        0xcd7f76d3,
+        0x7b49fc8e,
+        0x22a3b3e9,
+        0x53feb638,
+        0xbde22e79,
+        0xc10e91f9,
+        0x64f91eeb,
+        0x00002000,
+        0x00002000,
+        0xe1777307,
+        0xffa4f2c0,
+        0x2c3bf357,
+        0xfb9beea2,
+        0x1b882b05,
+        0xb1e3d77f,
+        0x98838eea,
+        0xe20d7ff8,
+        0x00004000,
+        0x00004000,
+        0x5ccbbcd6,
+        0xe160b1b8,
+        0xe91f649e,
+        0x6efb7a2f,
+        0x8f67a29b,
+        0x363ecba9,
+        0xeded40c2,
+        0xe0724017,
+        0x00008000,
+        0x00008000,
+        0x438f8b9b,
+        0x31b86581,
+        0x5fc0389a,
+        0xce6c93b2,
+        0x82180759,
+        0x9ba1ea76,
+        0x344e3baf,
+        0x695ae9ea,
+        0x00010000,
+        0x00010000,
+        0x52f95771,
+        0xa16313c5,
+        0xebc2afa1,
+        0x4a9b7c39,
+        0xe425ac9f,
+        0xeb5d5e3d,
+        0x172019b7,
+        0x00158928,
+        0x00020000,
+        0x00020000,
+        0xd5f20269,
+        0x1c51ef4e,
+        0xe8c7f778,
+        0x81ec0f39,
+        0x7c8ea1ea,
+        0x4c7c3491,
+        0x11948f24,
+        0x69c7157d,
+        0x00040000,
+        0x00040000,
+        0x86626497,
+        0x434b552f,
+        0xf8171efd,
+        0x206030f1,
+        0x871f4561,
+        0xf2f69d38,
+        0x48d3f71c,
+        0xaed8ab70,
+        0x00080000,
+        0x00080000,
+        0x340610e7,
+        0x3e9367e4,
+        0xc5d2ede8,
+        0x252c36aa,
+        0x3e1f3a26,
+        0xdb4236f2,
+        0x2a1a963f,
+        0xfc27f6bf,
+        0x00100000,
+        0x00100000,
+        0xb6096cfa,
+        0x3b5b7ea0,
+        0x9310c22b,
+        0x882a3ea9,
+        0xa92df45a,
+        0xa1b39c77,
+        0x16ccb85c,
+        0xee1f161e,
+        0x00200000,
+        0x00200000,
+        0x392f8aea,
+        0x5bbe39a4,
+        0x8241b5cc,
+        0x00c6201d,
+        0xd0c8868d,
+        0xa7804516,
+        0x9798c519,
+        0x29d5ab51,
+        0x00400000,
+        0x00400000,
+        0x07766ec5,
+        0x81034c7b,
+        0xf8df09a8,
+        0xe31ed208,
+        0x7d218de3,
+        0xc7f63553,
+        0xc47d93a6,
+        0x797866a7,
+        0x00800000,
+        0x00800000,
+        0x21943c5c,
+        0xb9221f1c,
+        0x50627cba,
+        0x854b0e49,
+        0xd1051bce,
+        0x7a860679,
+        0x63d7424d,
+        0x51df1c14,
+        0x01000000,
+        0x01000000,
+        0x86893904,
+        0x446021ae,
+        0x052526c3,
+        0x94ea97e4,
+        0xa42ba608,
+        0x83e7f1bd,
+        0xe10069dc,
+        0x8184f489,
+        0x02000000,
+        0x02000000,
+        0xc9212126,
+        0x3606ae22,
+        0x196211d0,
+        0xaa04c6a5,
+        0x344d3bdc,
+        0x473defb8,
+        0x97354ab0,
+        0x9fb31a81,
+        0x04000000,
+        0x04000000,
+        0x4f4f94cb,
+        0x28b3024f,
+        0x8f667c6b,
+        0x92f2e020,
+        0xf337eac9,
+        0xbd39b2ac,
+        0xa694b28a,
+        0x7ce5d274,
+        0x08000000,
+        0x08000000,
+        0xa8249fbe,
+        0x31f6395b,
+        0x6d800da7,
+        0x99ae2d3f,
+        0x6c0af75d,
+        0x7b79589e,
+        0xeb210481,
+        0x29a7436d,
+        0x10000000,
+        0x10000000,
+        0x846fcac7,
+        0xcb64cb14,
+        0x09b4e883,
+        0x686e0b4a,
+        0xa1465824,
+        0x2327197c,
+        0xc4dea808,
+        0x4abbf4ac,
+        0x20000000,
+        0x20000000,
+        0x57de84b8,
+        0x22052135,
+        0x5ffbad6d,
+        0x184e894c,
+        0xb35585bd,
+        0x429d0cd6,
+        0xdbe8818b,
+        0x0a3ff2ce,
+        0x40000000,
+        0x40000000,
+        0xafe3ffba,
+        0xc9482011,
+        0xc08c9a48,
+        0x95e39e22,
+        0x0e45a2d1,
+        0x004788c6,
+        0xa8256ff4,
+        0xa90aae0e,
+        0x80000000,
+        0xf89069aa,
+        0x94372a4c,
+        0xea91410f,
+        0x798bd3aa,
+        0xc675e808,
+        0x24efd169,
+        0x00000001,
+        0x00000001,
+        0x00000002,
+        0x00000002,
+        0x87ec087d,
+        0xac9c11c5,
+        0x675c7262,
+        0x73ddb94a,
+        0x7692932e,
+        0xe4a083cb,
+        0x7ee9f846,
+        0x8020eb69,
+        0xb2e578f7,
+        0x1f26ba82,
+        0x00000004,
+        0x00000004,
+        0x7545dc42,
+        0x96b43939,
+        0xc913bb17,
+        0xe987638c,
+        0x6163f841,
+        0xbd96e3dc,
+        0xf61745f5,
+        0x48f9db89,
+        0x00000008,
+        0x00000008,
+        0x897a6b6c,
+        0xe661ac2b,
+        0xef7129ba,
+        0xb603eb08,
+        0x7c618b56,
+        0xb319bbb6,
+        0x4f12de1d,
+        0xbbbcab6a,
+        0x00000010,
+        0x00000010,
+        0xcac597c2,
+        0xf2f881f4,
+        0x90e73d62,
+        0x1ec1ac49,
+        0x4e5d723b,
+        0xab10265b,
+        0x4d977f34,
+        0x03cefb95,
+        0x00000020,
+        0x00000020,
+        0xd57ba991,
+        0x58af8632,
+        0x47533a2f,
+        0x1c4299ee,
+        0xd7766d05,
+        0x80829c4c,
+        0x9d73e4c5,
+        0x9608da78,
+        0x00000040,
+        0x00000040,
+        0x5a059d55,
+        0x66e8bfd2,
+        0x6691eb10,
+        0x064c0062,
+        0x2c94c0d5,
+        0x90c2f001,
+        0xe62af943,
+        0x95fee8bc,
+        0x00000080,
+        0x00000080,
+        0x5c3f7f2d,
+        0x87cc814b,
+        0xd7b2c4f5,
+        0x71ce26bb,
+        0x805516b9,
+        0xd08adaed,
+        0x5c9ef647,
+        0x6869fb45,
+        0x00000100,
+        0x00000100,
+        0x010e0d6e,
+        0x32dda2f0,
+        0xbf97582a,
+        0xce5cff62,
+        0x042d33ca,
+        0x66f5e09b,
+        0xee9c24e5,
+        0xbc2e6462,
+        0x00000200,
+        0x00000200,
+        0xb3d45d06,
+        0xaa362c1f,
+        0xaf0f0a7b,
+        0x9348b834,
+        0xa6b0a0c2,
+        0x55bfc4f9,
+        0x45e21d59,
+        0x7094d7fa,
+        0x00000400,
+        0x00000400,
+        0x210079c8,
+        0x37b2ade4,
+        0xe48461a7,
+        0x8516c817,
+        0x9784c142,
+        0xcb0b33b2,
+        0xaeb9477b,
+        0x91ea1de3,
+        0x00000800,
+        0x00000800,
+        0xd83128a6,
+        0x20963251,
+        0x822db2cb,
+        0x24a11a57,
+        0x44ee6f9a,
+        0xebcb77df,
+        0xd5afb879,
+        0x24c4ca90,
+        0x00001000,
+        0x00001000,
+        0x58f858d8,
+        0x2457ef92,
+        0x3a4dcfea,
+        0x5ded8e11,
+        0xe0778454,
+        0x26548724,
+        0x80c16bd0,
+        0x0713527d,
+        0x00002000,
+        0x00002000,
+        0x97c4dce7,
+        0xe35db9f6,
+        0x3ce50750,
+        0x36dc6076,
+        0x417dd7d8,
+        0x5fe28dc5,
+        0x56e3185c,
+        0x68ecb2c2,
+        0x00004000,
+        0x00004000,
+        0x9b8d625e,
+        0x634b8f2c,
+        0x950bc7fe,
+        0x9d83f867,
+        0x202535ff,
+        0x16bdf0a3,
+        0x5b55afd0,
+        0xa9001209,
+        0x00008000,
+        0x00008000,
+        0xb2a0d63a,
+        0x90a65f99,
+        0x797e4125,
+        0x6ef79539,
+        0xb2052a15,
+        0x93727c9c,
+        0x7a0f9e0f,
+        0x14da2c46,
+        0x00010000,
+        0x00010000,
+        0xddb5da9c,
+        0xfead074a,
+        0x0fedb8e4,
+        0x1f94cdbe,
+        0x63512376,
+        0x74b32d73,
+        0x3b166b37,
+        0x4e2c069c,
+        0x00020000,
+        0x00020000,
+        0xe81d03ab,
+        0x8f9c86b8,
+        0x05bd6da7,
+        0x13dfd07a,
+        0x463e2c56,
+        0x6f3ad587,
+        0x4b381165,
+        0xedf16f67,
+        0x00040000,
+        0x00040000,
+        0x79514a51,
+        0x55d1e336,
+        0x48840e90,
+        0xf760a936,
+        0x6abc422c,
+        0x8bd6f3bb,
+        0x856a1660,
+        0x1a1f4165,
+        0x00080000,
+        0x00080000,
+        0x8e779211,
+        0xb97d95cd,
+        0x05ac760d,
+        0xeb611365,
+        0x275f3f96,
+        0x97c60ed6,
+        0x82374091,
+        0x4e89e799,
+        0x00100000,
+        0x00100000,
+        0x91487c58,
+        0x6afb83de,
+        0x7727a3ea,
+        0xbabc7245,
+        0x8582bb78,
+        0xa127f346,
+        0xa8211452,
+        0xb4a7e4f5,
+        0x00200000,
+        0x00200000,
+        0x875952c1,
+        0x92b8530e,
+        0xd72d08ee,
+        0x704352c4,
+        0x07680b61,
+        0x045e6b90,
+        0xc1da69d0,
+        0x78505c55,
+        0x00400000,
+        0x00400000,
+        0xabe17100,
+        0xb974ceda,
+        0xce532187,
+        0x6fde9d15,
+        0x9d64532c,
+        0x05d48792,
+        0x44764ae3,
+        0x7d364f29,
+        0x00800000,
+        0x00800000,
+        0x66b35f39,
+        0x1876b155,
+        0x68211df7,
+        0x102fb407,
+        0x0b06eedc,
+        0x51f247b4,
+        0x4c105af3,
+        0xf99e8f1d,
+        0x01000000,
+        0x01000000,
+        0x94c86e0b,
+        0x08641b1c,
+        0x3843c2ff,
+        0x73ede097,
+        0xba2e57c9,
+        0x19241cfc,
+        0x07e5fcdd,
+        0xc32a3238,
+        0x02000000,
+        0x02000000,
+        0xec4e091c,
+        0x0f957e80,
+        0x966aca22,
+        0xf091b11e,
+        0xeb077c06,
+        0x65c30c79,
+        0xf975cdc1,
+        0x48490ce2,
+        0x04000000,
+        0x04000000,
+        0x3b76fe4d,
+        0xd5596ef0,
+        0x97006077,
+        0x7e4abd9f,
+        0xb557f195,
+        0xefce1aa7,
+        0x27b61bf8,
+        0x7bdfd798,
+        0x08000000,
+        0x08000000,
+        0x3c280c2b,
+        0xc7b96ee5,
+        0x9b095957,
+        0xa316a296,
+        0xb2710c3d,
+        0xddd8db34,
+        0x0731ec90,
+        0xec1cf7a0,
+        0x10000000,
+        0x10000000,
+        0x7d134be0,
+        0x40a2af36,
+        0xabe087ab,
+        0x0b1c91e0,
+        0x9b20a66e,
+        0xb0f9a425,
+        0xbd886136,
+        0x5507cac2,
+        0x20000000,
+        0x20000000,
+        0x3263462a,
+        0xdc5d5335,
+        0x86d39f72,
+        0xe98c2cbf,
+        0x39c7b01a,
+        0xd59b0414,
+        0x84b13e63,
+        0x3f31547e,
+        0x40000000,
+        0x40000000,
+        0x0ca53b33,
+        0x2442ca20,
+        0x43ba734a,
+        0xbdbd75be,
+        0xc5f6ea20,
+        0xdfa259b4,
+        0x710931cd,
+        0x14bda6de,
+        0x80000000,
+        0x312a6bcf,
+        0x1eca6fbc,
+        0xc091fb1b,
+        0xb73e6cab,
+        0x19622684,
+        0x38b51ec1,
+        0x00000001,
+        0x00000001,
+        0x00000002,
+        0x00000002,
+        0x47a83b9c,
+        0xb43a2783,
+        0xca47dae4,
+        0x6cd4ad01,
+        0x45aff0fb,
+        0x039a0876,
+        0x3f0ee854,
+        0xd68caedd,
+        0xd0578070,
+        0xc3631307,
+        0x00000004,
+        0x00000004,
+        0x188e2a49,
+        0xb99fa614,
+        0x5e5d71a7,
+        0xe9292760,
+        0x70f2aa72,
+        0x998c9f41,
+        0x1632c8cd,
+        0x2fc84bef,
+        0x00000008,
+        0x00000008,
+        0x93727023,
+        0xd9b69f55,
+        0xa6b2acb0,
+        0xb163a5f0,
+        0x7c90039e,
+        0x6f1e4e71,
+        0xfff80e29,
+        0xcf004542,
+        0x00000010,
+        0x00000010,
+        0x882317b1,
+        0x4bcb0f29,
+        0xac373fc5,
+        0x1ce1c66d,
+        0x159cef3c,
+        0xa95d6fa3,
+        0xd31ea7d2,
+        0xc10c40d7,
+        0x00000020,
+        0x00000020,
+        0x1f63274f,
+        0xfc1e6328,
+        0xc238684e,
+        0xa7366d61,
+        0x7ac1e206,
+        0x825b5a8e,
+        0xd9df9481,
+        0xaf0f0b01,
+        0x00000040,
+        0x00000040,
+        0xbda60c42,
+        0x09ab8c6d,
+        0x7e9729b3,
+        0x2701e566,
+        0xd32f0323,
+        0xf293f35c,
+        0xeb2659e3,
+        0xf1349147,
+        0x00000080,
+        0x00000080,
+        0xbb260d86,
+        0x02f1fdf2,
+        0x9d013e67,
+        0x79e20c93,
+        0xc2044179,
+        0xa7b93ce0,
+        0x416bb38a,
+        0x3b11357c,
+        0x00000100,
+        0x00000100,
+        0x079a1e52,
+        0x05abf456,
+        0xff2d709d,
+        0xa65979bf,
+        0x124385d2,
+        0x556a1574,
+        0xba350a3f,
+        0x6c70fbe8,
+        0x00000200,
+        0x00000200,
+        0x442e3db8,
+        0x43060dd0,
+        0x052d28dc,
+        0xfa72601d,
+        0xeed7d060,
+        0xd3d725da,
+        0x3f05ed0e,
+        0xb1c58a13,
+        0x00000400,
+        0x00000400,
+        0x912fde47,
+        0xa464fa6b,
+        0x7afa0824,
+        0x4f92f9fa,
+        0xe815cad2,
+        0x11b96bf0,
+        0xb61d892a,
+        0x6b9bcd59,
+        0x00000800,
+        0x00000800,
+        0x031f0e17,
+        0xbea624d3,
+        0x400a2639,
+        0xf9a65e98,
+        0xde446c82,
+        0xacd7aa31,
+        0x8f20c24e,
+        0x5f7c5d9e,
+        0x00001000,
+        0x00001000,
+        0x2a2da702,
+        0x9b336514,
+        0x96e06491,
+        0x47abf12b,
+        0x8181bbc5,
+        0x5ef42a1e,
+        0xf88b275c,
+        0x7f10a9af,
+        0x00002000,
+        0x00002000,
+        0x4a8d995e,
+        0x71670ffe,
+        0xa9502bbd,
+        0x49a23490,
+        0x319f5711,
+        0xbb59f208,
+        0x187c3804,
+        0x80afa60a,
+        0x00004000,
+        0x00004000,
+        0xde735342,
+        0xec8484c2,
+        0x96492fea,
+        0x86fe479c,
+        0x81f13242,
+        0x02da30b3,
+        0x2795fa9b,
+        0x62bba96d,
+        0x00008000,
+        0x00008000,
+        0x62ee6103,
+        0x665c11ad,
+        0xc751d20e,
+        0x33fbaee1,
+        0xa212837c,
+        0xa5ab9b77,
+        0xc45abaab,
+        0x7681027e,
+        0x00010000,
+        0x00010000,
+        0xacfc28c2,
+        0xc3eb106d,
+        0x39a9d207,
+        0x3c106f14,
+        0x7e24d5b4,
+        0x9103cb17,
+        0xb998f40b,
+        0x1552730e,
+        0x00020000,
+        0x00020000,
+        0x1032efa6,
+        0xeb145f91,
+        0x0e6861ce,
+        0x3c7443a4,
+        0x5b74410e,
+        0x85b710f7,
+        0x2b8f8000,
+        0xba966865,
+        0x00040000,
+        0x00040000,
+        0xfa4afcc0,
+        0x9c5d18dd,
+        0x6d3a43ab,
+        0x07fa9b66,
+        0x9674cab3,
+        0xe2544bc1,
+        0x7f0926c4,
+        0x0dbabb38,
+        0x00080000,
+        0x00080000,
+        0xe36bd24c,
+        0x7dd4d519,
+        0xfcac77cc,
+        0x6efbe9a8,
+        0x3b73b79c,
+        0x807243ec,
+        0xd91284e9,
+        0xeb03ccbb,
+        0x00100000,
+        0x00100000,
+        0x78f42316,
+        0x3968f1e6,
+        0x5371eace,
+        0x1842201b,
+        0xc9c8f7c9,
+        0xbdcd85ba,
+        0x66f84a4d,
+        0x9ab45c42,
+        0x00200000,
+        0x00200000,
+        0xdf03d492,
+        0x74b07034,
+        0x6aa6c5a8,
+        0x4a86c37e,
+        0x2b6281c6,
+        0xfb764d7c,
+        0x1121adb4,
+        0x0c41b037,
+        0x00400000,
+        0x00400000,
+        0x60c8155e,
+        0x5e015d5e,
+        0xff3723b1,
+        0x3764eca4,
+        0x7b246445,
+        0x7a114091,
+        0xc0cb4f65,
+        0xe0be0be5,
+        0x00800000,
+        0x00800000,
+        0x92e7daf4,
+        0xf36b8be1,
+        0x0886399e,
+        0xc3b5d975,
+        0x3881d6c2,
+        0x2ff2d28a,
+        0x7e796cc3,
+        0xa19a43d4,
+        0x01000000,
+        0x01000000,
+        0x9c62f372,
+        0x4fa4f6bd,
+        0x38636ac4,
+        0x5bdadb8b,
+        0x57a59646,
+        0x93f46191,
+        0x2ff498dc,
+        0xaf9800c8,
+        0x02000000,
+        0x02000000,
+        0x2fb5768f,
+        0x9319f8df,
+        0x7cbbaaba,
+        0xc10e64eb,
+        0x97bbf827,
+        0x2e11d71d,
+        0x7bbd0bd0,
+        0x00eda94b,
+        0x04000000,
+        0x04000000,
+        0xb95bc1ac,
+        0xa7df30ca,
+        0x363d91aa,
+        0x14cfd4ac,
+        0xe61ff77d,
+        0x2b058968,
+        0xe1a31ed9,
+        0xf2ac5237,
+        0x08000000,
+        0x08000000,
+        0x688190d9,
+        0x56daf7be,
+        0xd39e413e,
+        0x105c47dd,
+        0x19ee14c3,
+        0x3f6b8148,
+        0xc340b3d2,
+        0xab867cad,
+        0x10000000,
+        0x10000000,
+        0xc61d13b4,
+        0x9999d67d,
+        0x813a3436,
+        0xcabca181,
+        0xcf716f3d,
+        0x70561424,
+        0x61998ae6,
+        0x5c45b31f,
+        0x20000000,
+        0x20000000,
+        0x788913b3,
+        0x837028a6,
+        0x75493a09,
+        0xf5c09722,
+        0x52868fa7,
+        0x9068a6ac,
+        0x4c5e2817,
+        0x2d89e05f,
+        0x40000000,
+        0x40000000,
+        0xe72ce1e1,
+        0xe5795ceb,
+        0xf9b0d873,
+        0x1dcaeb1c,
+        0x45b7bffd,
+        0xb6cb13e3,
+        0x0899c967,
+        0x57fb5a65,
+        0x80000000,
+        0x8befc1ff,
+        0x15775f31,
+        0xce2f5260,
+        0xb79aaad3,
+        0xe920c4ab,
+        0xb3d1bb0b,
+        0x00000001,
+        0x00000001,
+        0x00000002,
+        0x00000002,
+        0xcea34509,
+        0x258fcfe4,
+        0xfe411b9d,
+        0xe418c72d,
+        0x44f4ec6f,
+        0xb295286c,
+        0xd129a4b9,
+        0xfe5d268c,
+        0x3a191347,
+        0x3d0d811b,
+        0x00000004,
+        0x00000004,
+        0x87886ced,
+        0x8b27cb44,
+        0xb1c7473c,
+        0xd222bc17,
+        0x73013ce7,
+        0x437ab67f,
+        0x8d136a09,
+        0x60d87db8,
+        0x00000008,
+        0x00000008,
+        0x407e11e5,
+        0x491514a2,
+        0xd6c7e480,
+        0xdb1205a0,
+        0x743e0166,
+        0x00aa99df,
+        0xab387976,
+        0x69c524d7,
+        0x00000010,
+        0x00000010,
+        0x06d3d674,
+        0xa0bb571f,
+        0x20893e66,
+        0x22016828,
+        0xb17ba5ac,
+        0xf20a4bfd,
+        0xc590515c,
+        0xb30dc439,
+        0x00000020,
+        0x00000020,
+        0x92e7662a,
+        0x877888bf,
+        0xa3a78e84,
+        0x571f2172,
+        0xfdb28d36,
+        0xed0fe6b9,
+        0xa6850bbe,
+        0x25955595,
+        0x00000040,
+        0x00000040,
+        0x914c3ca1,
+        0x22f12b7c,
+        0x303068ca,
+        0x2b870720,
+        0x4caac97f,
+        0x6f048935,
+        0xa0233f1d,
+        0x9b1c1d67,
+        0x00000080,
+        0x00000080,
+        0x5269db0a,
+        0xa16d336e,
+        0xa9a3de02,
+        0xdaa405c0,
+        0xe1f0e28a,
+        0x12ebea10,
+        0xa9bc3efc,
+        0xb1ea252f,
+        0x00000100,
+        0x00000100,
+        0xfdcada1f,
+        0xd3f93554,
+        0x32bee05d,
+        0xfde8a8a1,
+        0xb4c0aefa,
+        0x193bb192,
+        0xb176117e,
+        0x16b03a94,
+        0x00000200,
+        0x00000200,
+        0xc449a52c,
+        0x17584c5f,
+        0x285b51e2,
+        0xea5b9270,
+        0xc227c6b2,
+        0x46ca5d04,
+        0x7fa72327,
+        0x37255c1b,
+        0x00000400,
+        0x00000400,
+        0xa4bf8d43,
+        0xddcc5382,
+        0x9db327ab,
+        0x1a6a9081,
+        0x55ff6b1c,
+        0xd5c471d5,
+        0x64fbe3a3,
+        0x788f022e,
+        0x00000800,
+        0x00000800,
+        0xbacefa8a,
+        0x367c22bc,
+        0xf5a0b25a,
+        0x6ed57798,
+        0xde1b9888,
+        0x5639e18f,
+        0xf130136b,
+        0x8c09e862,
+        0x00001000,
+        0x00001000,
+        0x15707073,
+        0x5d583cd4,
+        0xaefa8b11,
+        0xda570e1f,
+        0xc41d8827,
+        0xe3144bf9,
+        0xc1db0468,
+        0x416dfc18,
+        0x00002000,
+        0x00002000,
+        0xcfe23f9e,
+        0x23cb8073,
+        0x1d325220,
+        0x98e80484,
+        0xa2dffded,
+        0x2268a752,
+        0x71ac9772,
+        0x5ce46178,
+        0x00004000,
+        0x00004000,
+        0x1efc0692,
+        0x69386356,
+        0x35870545,
+        0xf8873170,
+        0x6b694c7f,
+        0x25d1f8b9,
+        0x0cfb9447,
+        0x53a66de6,
+        0x00008000,
+        0x00008000,
+        0x9a2a4142,
+        0x53855535,
+        0x58237764,
+        0x7a5c2aeb,
+        0x50c2db12,
+        0xdcc3bc00,
+        0x1d8d8401,
+        0x208b5507,
+        0x00010000,
+        0x00010000,
+        0x7957bc70,
+        0x706bf57d,
+        0xac1b347d,
+        0xb8a38ea3,
+        0x46c1d762,
+        0x85f65ae5,
+        0x6611f0f7,
+        0xdb01f123,
+        0x00020000,
+        0x00020000,
+        0xe80a47a2,
+        0x49606987,
+        0x163f8adc,
+        0x5dc18747,
+        0x8d72cb2f,
+        0x67c659ed,
+        0x5de1363b,
+        0x3ab84301,
+        0x00040000,
+        0x00040000,
+        0x3beb8a8b,
+        0xa8c47c36,
+        0x00fcf35e,
+        0x5b767898,
+        0x791bfb88,
+        0x8a43c335,
+        0x608b3d5e,
+        0x92c6d7f9,
+        0x00080000,
+        0x00080000,
+        0xd76261d8,
+        0x1f1f6c2b,
+        0x49cb495e,
+        0xb71e5ae1,
+        0x7cabb2ce,
+        0x7e18b34e,
+        0x830d452d,
+        0x44bea6f1,
+        0x00100000,
+        0x00100000,
+        0x846480df,
+        0xdc5c2c6d,
+        0x54d992ff,
+        0xbc8c046c,
+        0xe6cfcb0b,
+        0xaf8e37bd,
+        0x45a0c2fb,
+        0x0c929504,
+        0x00200000,
+        0x00200000,
+        0xcd55caf1,
+        0x2c2442f6,
+        0x90309529,
+        0xf09c9db8,
+        0x8d865020,
+        0x22516b76,
+        0xdeb62678,
+        0x9b5660f9,
+        0x00400000,
+        0x00400000,
+        0xfa4918ba,
+        0xc8bb6ee5,
+        0x2a534bfa,
+        0xda7ee93f,
+        0xa733a327,
+        0x52e6c158,
+        0xed345fda,
+        0x54e5195d,
+        0x00800000,
+        0x00800000,
+        0xe268b0eb,
+        0x37f4edb8,
+        0x7984b7b7,
+        0xf2289db0,
+        0x4a53859e,
+        0x8edd90dd,
+        0x6896ffa7,
+        0x7881bcfc,
+        0x01000000,
+        0x01000000,
+        0xc6c8f965,
+        0xf1fdba8f,
+        0xd4a77452,
+        0xae86c149,
+        0xf49c016a,
+        0x08eba661,
+        0x1fe590b3,
+        0x9e473c37,
+        0x02000000,
+        0x02000000,
+        0xf3590339,
+        0xa04549af,
+        0x2aa5e799,
+        0x6dbffba1,
+        0x98483e4f,
+        0xee6e6efc,
+        0x0f9ffdbc,
+        0x36818fb7,
+        0x04000000,
+        0x04000000,
+        0x4767770e,
+        0x48a8e7cf,
+        0xa7e30edd,
+        0x0739d3cb,
+        0x714f2b56,
+        0xa60fbeb3,
+        0x7b6c0bf9,
+        0x65aa20b9,
+        0x08000000,
+        0x08000000,
+        0xecf3f788,
+        0xc8a8b386,
+        0x36fc3ca9,
+        0x79e1dfc3,
+        0xcc534f49,
+        0x7b5e2f88,
+        0xa5aa1866,
+        0x6423c197,
+        0x10000000,
+        0x10000000,
+        0x544aed57,
+        0x49bbc77d,
+        0x561fc211,
+        0xd09d5a2c,
+        0xffff6510,
+        0xa3b64183,
+        0x17a7cca8,
+        0x203ea020,
+        0x20000000,
+        0x20000000,
+        0x4bb1b188,
+        0x6cd74499,
+        0x22b210f8,
+        0xb81031fc,
+        0x79e77a72,
+        0xfa645bfb,
+        0x8657c460,
+        0x54a0da32,
+        0x40000000,
+        0x40000000,
+        0x241f336b,
+        0x5de3e688,
+        0xa1f1534a,
+        0x714aa4a2,
+        0xd967eae4,
+        0x759db56a,
+        0x34f7d927,
+        0x247fcc9a,
+        0x80000000,
+        0x6631d100,
+        0x4c7022e1,
+        0x8b28cf30,
+        0x6ec789ac,
+        0x67cffa08,
+        0x055a79d1,
+        0x00000001,
+        0x00000001,
+        0x00000002,
+        0x00000002,
+        0x2553915d,
+        0xcf07a39f,
+        0x30887a60,
+        0xc0dc2fdc,
+        0x3dc42912,
+        0x87a533bb,
+        0x5a3910e0,
+        0xba2982d1,
+        0x3accda93,
+        0x9aada744,
+        0x00000004,
+        0x00000004,
+        0x602760da,
+        0x385d5ad4,
+        0x46c58a66,
+        0xefa1da81,
+        0x13fc2fe8,
+        0x0cfb90ac,
+        0x01b80f2c,
+        0xf1ec4445,
+        0x00000008,
+        0x00000008,
+        0x8c1051ee,
+        0x582abd3e,
+        0xfbe39407,
+        0xdf11b204,
+        0x930f4011,
+        0x3b6436d5,
+        0x87b20e1b,
+        0x4dd5fb18,
+        0x00000010,
+        0x00000010,
+        0x35e0ad57,
+        0xfe4de55e,
+        0xe967bc50,
+        0x7fa7508b,
+        0x8330536b,
+        0xe1cc6e36,
+        0x4036c914,
+        0x7075fc4d,
+        0x00000020,
+        0x00000020,
+        0xd1c11d44,
+        0xbae9e20d,
+        0x84020b20,
+        0x9bf0754a,
+        0xe09555ab,
+        0xa495d37c,
+        0xab2a729e,
+        0xdc81d56a,
+        0x00000040,
+        0x00000040,
+        0x413ffc11,
+        0xa53d45d1,
+        0x6f548fbf,
+        0x0cbc7afa,
+        0xe195b2e0,
+        0x331166c6,
+        0x59d2e259,
+        0x0f12a5d8,
+        0x00000080,
+        0x00000080,
+        0x76508c00,
+        0xfcd7ae94,
+        0xcf9e37fa,
+        0x2057b1f1,
+        0x508b6f84,
+        0xf618cd45,
+        0xe38ab7af,
+        0x57c3160c,
+        0x00000100,
+        0x00000100,
+        0xaeed5bed,
+        0x618662f4,
+        0xc0689eef,
+        0x05f029d1,
+        0x3df512d2,
+        0x1b60dba8,
+        0x29966e8c,
+        0x0053173b,
+        0x00000200,
+        0x00000200,
+        0x28959831,
+        0x0ef0578b,
+        0xfb3ac2da,
+        0x6d2e2b29,
+        0xff038148,
+        0x1482e959,
+        0x36de6571,
+        0xf140681c,
+        0x00000400,
+        0x00000400,
+        0x7b023fd4,
+        0xff32fe53,
+        0x46445544,
+        0x9bc056f3,
+        0x824dc523,
+        0xd35fd6b7,
+        0x93668757,
+        0x045743f6,
+        0x00000800,
+        0x00000800,
+        0x9674d1a6,
+        0x301b7648,
+        0x86aec6cf,
+        0x9629e2ab,
+        0x6d6b3457,
+        0x6e9e21f0,
+        0x23347486,
+        0x11cf5dcf,
+        0x00001000,
+        0x00001000,
+        0x754d9371,
+        0x3fac22a1,
+        0xf8426cfa,
+        0xebc62e5a,
+        0xe92a7419,
+        0x28f30b2c,
+        0x737ab21f,
+        0xf0c9b981,
+        0x00002000,
+        0x00002000,
+        0xb4bea008,
+        0xe104e098,
+        0x1c15e7ab,
+        0xb416f092,
+        0x7efcd830,
+        0xe521f2e0,
+        0xd272adeb,
+        0x9d9e9a31,
+        0x00004000,
+        0x00004000,
+        0x0c936e95,
+        0xdb828dfc,
+        0x9aa693ba,
+        0xb1e95907,
+        0x34526df1,
+        0xa0d05dd2,
+        0xd93500f0,
+        0xd8959906,
+        0x00008000,
+        0x00008000,
+        0x90814cbc,
+        0x1ded1614,
+        0x1d6bc58f,
+        0x70fcc99b,
+        0xf7af6a7d,
+        0x8cf67fb5,
+        0xeb5e8aef,
+        0xfb2fc7b3,
+        0x00010000,
+        0x00010000,
+        0x4bb92f56,
+        0x8dcc29f6,
+        0xb0e61ef6,
+        0xf9bc290b,
+        0xe35aa08a,
+        0xbbeacf7e,
+        0x2e2377a2,
+        0x4e5eb643,
+        0x00020000,
+        0x00020000,
+        0xe8587a9b,
+        0x757ce350,
+        0x94747444,
+        0xa3fe0385,
+        0x35261673,
+        0xefb39525,
+        0xd3c56ed9,
+        0x92daf1ea,
+        0x00040000,
+        0x00040000,
+        0x16c9bcc6,
+        0x0050e25f,
+        0xcb6db73d,
+        0x1419e124,
+        0x6a767bfa,
+        0x39627c80,
+        0x8ee4eede,
+        0x3dc10ba0,
+        0x00080000,
+        0x00080000,
+        0xcf546966,
+        0x51ff0394,
+        0xbac20b7b,
+        0xc386f7b5,
+        0xc8ff09e0,
+        0xc8fe9238,
+        0x2468fa51,
+        0x8ecb8a99,
+        0x00100000,
+        0x00100000,
+        0x25d919f5,
+        0xc1ed8dbc,
+        0x947d010f,
+        0xc17094d5,
+        0xf2bd4b26,
+        0x12cdfaea,
+        0x9029248a,
+        0xb87e34da,
+        0x00200000,
+        0x00200000,
+        0xe301aefe,
+        0xe91c4513,
+        0x2b2cbdb7,
+        0x7383316e,
+        0x07d9c138,
+        0x2b569c6e,
+        0x0dbe0b0c,
+        0x1a67f079,
+        0x00400000,
+        0x00400000,
+        0x2c7cc3b1,
+        0x7e62d899,
+        0x3f3c936f,
+        0x178aecdf,
+        0x2ddf0b72,
+        0x61051b62,
+        0x21b89857,
+        0x14a376ab,
+        0x00800000,
+        0x00800000,
+        0xceee7f98,
+        0x72047725,
+        0x8a6ab0e4,
+        0x8421d6c1,
+        0xb134d4f4,
+        0xadf25d45,
+        0x2ac631e8,
+        0x53994992,
+        0x01000000,
+        0x01000000,
+        0xbe3e9be2,
+        0xf399b6cc,
+        0x1ddf7e80,
+        0xaa3fc47e,
+        0xfcadce07,
+        0xbd28d4c8,
+        0x11fd4961,
+        0x85d2775a,
+        0x02000000,
+        0x02000000,
+        0xeee34152,
+        0xea73e781,
+        0x7a6d9387,
+        0xb8bbcb5c,
+        0xb31df2c2,
+        0x606ff0e1,
+        0xad8f3777,
+        0x1032d678,
+        0x04000000,
+        0x04000000,
+        0x174c4294,
+        0x41e79876,
+        0xb9ea1213,
+        0xa7a3c415,
+        0x1cf2fe36,
+        0xdb8301eb,
+        0x2830372c,
+        0x8669f6ed,
+        0x08000000,
+        0x08000000,
+        0xda4352a3,
+        0x42ea438f,
+        0xbd9e6a38,
+        0x77f4be61,
+        0x48abd6ea,
+        0x2240dfbf,
+        0x47dc7241,
+        0xa6fa5b38,
+        0x10000000,
+        0x10000000,
+        0xab9d7336,
+        0xce0813fd,
+        0x9cd6e7dc,
+        0x59c1ba0a,
+        0x8c682e20,
+        0x9df62794,
+        0xe8dd8ea3,
+        0xd12aba62,
+        0x20000000,
+        0x20000000,
+        0x33c5a34b,
+        0x454dbac4,
+        0x27eea55d,
+        0xc5b804d5,
+        0x504d335f,
+        0xaa8dcaea,
+        0xaba64567,
+        0xa7a9a70c,
+        0x40000000,
+        0x40000000,
+        0x1529ebbb,
+        0xec1a541d,
+        0x20da396c,
+        0xbd41d92c,
+        0x9a32bcb2,
+        0x9d6dc7db,
+        0x9eb6fda3,
+        0xb844fddb,
+        0x80000000,
+        0x1ace2bbd,
+        0x07641399,
+        0x6024d48d,
+        0x3613d090,
+        0x23470c28,
+        0x39935e71,
+        0x00000001,
+        0x00000001,
+        0x00000002,
+        0x00000002,
+        0x96614156,
+        0xfc62b64a,
+        0x3d034742,
+        0x07446816,
+        0x7310d892,
+        0x80eac152,
+        0x5d6155b9,
+        0x45e9c66e,
+        0x4f882389,
+        0xec36e203,
+        0x00000004,
+        0x00000004,
+        0x05c84350,
+        0x741bbe9f,
+        0x0fb467d4,
+        0xaa31f7e4,
+        0x155471e1,
+        0x71962590,
+        0x1aa5ce6e,
+        0xdc31684f,
+        0x00000008,
+        0x00000008,
+        0x48b52489,
+        0x124ec61c,
+        0x34e21b5b,
+        0x2df74e9b,
+        0x50ab4fd5,
+        0x83765cde,
+        0xbdc4eacd,
+        0xa4448e6e,
+        0x00000010,
+        0x00000010,
+        0x29321766,
+        0x41c6f837,
+        0xbe3d1a2d,
+        0x31c28907,
+        0x4e337ac8,
+        0x9e2cdeaf,
+        0x06d6510b,
+        0x8cb88052,
+        0x00000020,
+        0x00000020,
+        0x744fbdbc,
+        0xc374fac2,
+        0x3338e0bc,
+        0x06866359,
+        0x4c2b5267,
+        0x091a9149,
+        0xc13264cc,
+        0x76d31daf,
+        0x00000040,
+        0x00000040,
+        0x4cb51ead,
+        0x174b63b5,
+        0x0b318199,
+        0x411f1c46,
+        0x1d65a4aa,
+        0x07603091,
+        0x4aca68fa,
+        0xc34921f3,
+        0x00000080,
+        0x00000080,
+        0xb07946de,
+        0x5332595d,
+        0xf8ea51a2,
+        0x50370c52,
+        0xa9589337,
+        0xa2522266,
+        0x10abff70,
+        0xc16f9dd7,
+        0x00000100,
+        0x00000100,
+        0x0df2b488,
+        0x7deb1d65,
+        0xc84d6aac,
+        0x3ccff671,
+        0xf1504a0e,
+        0x0a4ad2d6,
+        0x434e5b99,
+        0x812651c6,
+        0x00000200,
+        0x00000200,
+        0x91fd5609,
+        0x48161f4f,
+        0xe156306f,
+        0x7dfd569a,
+        0x642fa299,
+        0x9cc3f2fb,
+        0xc0512b4b,
+        0x179810e5,
+        0x00000400,
+        0x00000400,
+        0x99954922,
+        0x01232a8c,
+        0x7770a73c,
+        0xb01c4f62,
+        0x7bafbda6,
+        0xa2ef750a,
+        0x36860c3e,
+        0xf16b42fe,
+        0x00000800,
+        0x00000800,
+        0x9a138ada,
+        0x5034bb2c,
+        0xd4a6dac3,
+        0x7fa5d4ff,
+        0xcbed117c,
+        0xfcac96bb,
+        0x29c064f2,
+        0xefbe2050,
+        0x00001000,
+        0x00001000,
+        0xf7dd6edc,
+        0x604dd6b7,
+        0xe8bd8015,
+        0xe7ce68e1,
+        0x95f97631,
+        0x1aa5d747,
+        0xf0719908,
+        0xce4bbd44,
+        0x00002000,
+        0x00002000,
+        0xa7ba0a9f,
+        0x0c9e071b,
+        0xccd2b6a4,
+        0xed319421,
+        0xc2ab6823,
+        0x20f89280,
+        0x85a56183,
+        0xa69a9285,
+        0x00004000,
+        0x00004000,
+        0x013ebb2c,
+        0xfc9725ad,
+        0x1e76d1d6,
+        0xfceec5aa,
+        0x8b898136,
+        0x64fee041,
+        0x0ca86e20,
+        0x1f4f64da,
+        0x00008000,
+        0x00008000,
+        0xf03ec351,
+        0x098ef95b,
+        0x8f7a6ecc,
+        0xcae74a4b,
+        0xbd4346c2,
+        0x320090a8,
+        0xf98982ac,
+        0x92871447,
+        0x00010000,
+        0x00010000,
+        0x084782fd,
+        0x0ed3bb82,
+        0xcdacbbb6,
+        0x004f35aa,
+        0xe7b5fd8e,
+        0xad7086f5,
+        0x81fdf9b2,
+        0x198d435c,
+        0x00020000,
+        0x00020000,
+        0xa657b905,
+        0x87237c88,
+        0x668dfab7,
+        0x4f59b1ff,
+        0xc6fe4471,
+        0x16db9ff1,
+        0x97dee4ba,
+        0xd6079160,
+        0x00040000,
+        0x00040000,
+        0xc6c759f5,
+        0xb3bc6f91,
+        0x9a339e96,
+        0x6afb8130,
+        0x47c1e636,
+        0xc62d4058,
+        0x6ea00e9c,
+        0x6aafbbfd,
+        0x00080000,
+        0x00080000,
+        0x494fc9cd,
+        0xfd27cd48,
+        0x064723be,
+        0xe9ddcd24,
+        0x93603b96,
+        0x9eff7e08,
+        0xbfb862a5,
+        0xbd4955f3,
+        0x00100000,
+        0x00100000,
+        0x726c8948,
+        0x41f50792,
+        0x53547886,
+        0x63298817,
+        0xa9a031b8,
+        0x72ca309a,
+        0xbbb7f77e,
+        0xfe8ffe4b,
+        0x00200000,
+        0x00200000,
+        0x002497a9,
+        0xf68f09d7,
+        0x7f82fa08,
+        0xa016a0bb,
+        0xf9c9bff7,
+        0x2a7bf27f,
+        0x5abb29fe,
+        0x616f62d2,
+        0x00400000,
+        0x00400000,
+        0xa0d249b3,
+        0x524e593d,
+        0x44aacf48,
+        0x6e8330d1,
+        0x16b65860,
+        0xa4dd69b8,
+        0x041dee1e,
+        0x2f693643,
+        0x00800000,
+        0x00800000,
+        0xea1fa364,
+        0xbbd5ec4e,
+        0xf82ace10,
+        0x30d1e380,
+        0xe3fb5ca0,
+        0xbc77f993,
+        0xf66c2409,
+        0x6a35f976,
+        0x01000000,
+        0x01000000,
+        0x4ac8c175,
+        0xb55e1094,
+        0x287bbfed,
+        0x4fea2862,
+        0x33a0a065,
+        0x729f0786,
+        0x97d2c9ef,
+        0x8a6336c0,
+        0x02000000,
+        0x02000000,
+        0xacca93bf,
+        0x3801bd5c,
+        0xf378ed47,
+        0x28126124,
+        0xdb957fa7,
+        0x80fa675f,
+        0x3edcdb5c,
+        0x92a43917,
+        0x04000000,
+        0x04000000,
+        0xb019ce35,
+        0xdde37366,
+        0x09893574,
+        0xc0a535ac,
+        0x1c14cedf,
+        0x34d685bc,
+        0x48b1c344,
+        0xdc4b82c8,
+        0x08000000,
+        0x08000000,
+        0x33d569ea,
+        0x1ca6632a,
+        0x7d506932,
+        0xc5360646,
+        0x26547e1c,
+        0xa1b1bb56,
+        0xd5c6a6b8,
+        0xb2f94ef4,
+        0x10000000,
+        0x10000000,
+        0x4386ac56,
+        0x38523f2f,
+        0x9aa3c6ad,
+        0x6c66d5ec,
+        0xfeeade45,
+        0x8f9490fc,
+        0x90091b6d,
+        0x71b06009,
+        0x20000000,
+        0x20000000,
+        0x248dd574,
+        0xf83a3dd1,
+        0x9ee60c8c,
+        0x4900e38e,
+        0xe6ca5c19,
+        0x7b82538c,
+        0xdf19ebbc,
+        0x291159b0,
+        0x40000000,
+        0x40000000,
+        0x7c33b1ff,
+        0x3ef700d3,
+        0x9834cefc,
+        0xbb1f8dd1,
+        0xd1e30fe7,
+        0x91d9e61e,
+        0x9aca9629,
+        0x36135459,
+        0x80000000,
+        0x13217a9f,
+        0x9101921d,
+        0xcad66ff3,
+        0xa9810c18,
+        0x303389f9,
+        0x2faa7dea,
+        0x00000001,
+        0x00000001,
+        0x00000002,
+        0x00000002,
+        0x2e8c082e,
+        0x78fd2239,
+        0xe273e327,
+        0x39a41020,
+        0xa3e70541,
+        0xcd744d1d,
+        0x3a28658f,
+        0xa767b63a,
+        0x294d1a85,
+        0xca788bcb,
+        0x00000004,
+        0x00000004,
+        0x15fe0c93,
+        0xb623e377,
+        0x4264156a,
+        0x856ef76d,
+        0x0319f44c,
+        0xa1afea76,
+        0x430fe4b4,
+        0x7b8cb22d,
+        0x00000008,
+        0x00000008,
+        0x7bdffdd7,
+        0x05f07fda,
+        0x5aa70bab,
+        0x64d8668f,
+        0xa430fc5c,
+        0x27722365,
+        0xa7ca569f,
+        0x63d80123,
+        0x00000010,
+        0x00000010,
+        0xaf0b95c5,
+        0x80618c4a,
+        0x967f5767,
+        0xd849b0aa,
+        0xc952710b,
+        0x413d1372,
+        0x317a2c81,
+        0x59563a6e,
+        0x00000020,
+        0x00000020,
+        0xeb6477a4,
+        0xbd3485f6,
+        0x6914213e,
+        0x7ba4d2cb,
+        0x05804f2a,
+        0x4f4a29e9,
+        0x758eb5af,
+        0x81e8c61e,
+        0x00000040,
+        0x00000040,
+        0xa80b9d85,
+        0x4750aea5,
+        0xe711a9e8,
+        0xb0d79090,
+        0xe74e9c79,
+        0x5d950ff9,
+        0xb2910948,
+        0x170d9d9c,
+        0x00000080,
+        0x00000080,
+        0xab8a38ff,
+        0x3352528c,
+        0x056f54d5,
+        0xd9db18a3,
+        0x250c5938,
+        0xab36c0fd,
+        0x138da7e3,
+        0x89f8ad05,
+        0x00000100,
+        0x00000100,
+        0x8bcaa067,
+        0x04ca8913,
+        0x4fccc0fc,
+        0xaca0f1de,
+        0x53b3d080,
+        0xb3bdb1b8,
+        0x20c288b0,
+        0x0db24b96,
+        0x00000200,
+        0x00000200,
+        0x2b3ff08e,
+        0xecf09fba,
+        0xcbc8a872,
+        0x9ee78ad2,
+        0x4317a0a3,
+        0x10db779f,
+        0x5fcb107a,
+        0xeb54afff,
+        0x00000400,
+        0x00000400,
+        0x8fae24a7,
+        0x98878a6c,
+        0xfe2beca7,
+        0xe2f9d838,
+        0x97f1adf8,
+        0x1577c41a,
+        0x6b842f8e,
+        0xf6507018,
+        0x00000800,
+        0x00000800,
+        0x34b9bce5,
+        0x196ad12a,
+        0x735276fb,
+        0x09604107,
+        0x3c44a38e,
+        0xbf9a1ee7,
+        0xdd5fae5d,
+        0x6559af4f,
+        0x00001000,
+        0x00001000,
+        0xf7455776,
+        0x8b79dd4f,
+        0x9818b57b,
+        0x46686602,
+        0xcc4dbd53,
+        0xf3a9aecf,
+        0xdbc03cde,
+        0x8c0716ca,
+        0x00002000,
+        0x00002000,
+        0x66765f43,
+        0xade0148e,
+        0x62d5884f,
+        0x45ca3fb4,
+        0x9dc06f49,
+        0x345f5c1d,
+        0x00dcf0d6,
+        0x348e7cc2,
+        0x00004000,
+        0x00004000,
+        0x14deb28f,
+        0x255694d8,
+        0x505d8148,
+        0x67bd6c81,
+        0x871a6f9c,
+        0x6e93853b,
+        0x885c4db8,
+        0xa7b69fb0,
+        0x00008000,
+        0x00008000,
+        0xbf8cbf09,
+        0xb8d23529,
+        0x13e1bee9,
+        0xee234fd3,
+        0xc8a0e5aa,
+        0x96922cbf,
+        0x4a8dfddc,
+        0x3e0f93d1,
+        0x00010000,
+        0x00010000,
+        0x2cec34bd,
+        0x8570fa8a,
+        0x34b23f58,
+        0x7b0b8925,
+        0xd07d811c,
+        0xd8906383,
+        0x95c213f0,
+        0xe2dd816f,
+        0x00020000,
+        0x00020000,
+        0x64d964fb,
+        0x50aa5a2d,
+        0x75e8af8e,
+        0xffd26bb7,
+        0x149890fd,
+        0xdb82da0e,
+        0x8e81dbab,
+        0x8073e1bc,
+        0x00040000,
+        0x00040000,
+        0x28d2ee47,
+        0xb7bbc088,
+        0x185c5b88,
+        0xe3c79bb6,
+        0xa356c6b7,
+        0x3069d09c,
+        0x140a636c,
+        0xdbdeaef7,
+        0x00080000,
+        0x00080000,
+        0xa5a9db72,
+        0x690c01ff,
+        0x5fe05b9f,
+        0x14f622e8,
+        0xa5a2e28a,
+        0x84fe6fcb,
+        0xb2110c53,
+        0x7345424e,
+        0x00100000,
+        0x00100000,
+        0x89f822d4,
+        0x6ef156ff,
+        0x2a5a0c6e,
+        0x2daeef26,
+        0x2f52a660,
+        0xd15595f4,
+        0x33444bd1,
+        0xbcff1ba3,
+        0x00200000,
+        0x00200000,
+        0x6cb35a7f,
+        0x619a4faa,
+        0x8ceae7d3,
+        0xba43f418,
+        0xd882a99b,
+        0xe4936f75,
+        0xbdf234e8,
+        0x58572e3c,
+        0x00400000,
+        0x00400000,
+        0xa4bfb89f,
+        0x1e5711b2,
+        0xc9a11430,
+        0x81c969e7,
+        0x058cda5e,
+        0x5050ef3e,
+        0x2695887d,
+        0x65d5cfb4,
+        0x00800000,
+        0x00800000,
+        0x61112ce5,
+        0x9274555d,
+        0x2fe251d9,
+        0x3135ef90,
+        0x9b7dd0e8,
+        0x34c3ea2c,
+        0x73b912a5,
+        0xf58111c0,
+        0x01000000,
+        0x01000000,
+        0xad7ca26f,
+        0x244944ce,
+        0xe2166ead,
+        0x92320147,
+        0xad8f7d2d,
+        0x599f9a76,
+        0x9e336f57,
+        0xe1d717cd,
+        0x02000000,
+        0x02000000,
+        0x139f4680,
+        0x82eb68ec,
+        0x77a58701,
+        0xbc6e60d4,
+        0x4858f58e,
+        0xfafbb2ba,
+        0x5472ee59,
+        0x55d4ddb3,
+        0x04000000,
+        0x04000000,
+        0x2348bc8f,
+        0xb0eb3976,
+        0xd96434f4,
+        0xa186ddfb,
+        0x2742fa61,
+        0xd8ac297f,
+        0xb1296213,
+        0x067af5f2,
+        0x08000000,
+        0x08000000,
+        0x7059f9bf,
+        0xbfdb0da0,
+        0x1c3aeff4,
+        0xc7e6f2ee,
+        0xe300f24c,
+        0x91393985,
+        0xa981d022,
+        0x6a528dfd,
+        0x10000000,
+        0x10000000,
+        0x6ee37b65,
+        0x6c862965,
+        0x11fce9ec,
+        0xc169daca,
+        0x2e802aae,
+        0xeb0a2244,
+        0xc613b306,
+        0xe2b232f9,
+        0x20000000,
+        0x20000000,
+        0x7a200ce8,
+        0x9748fdef,
+        0x59dc7576,
+        0xce9329f2,
+        0x26e17f99,
+        0x23a379df,
+        0xb1f68237,
+        0x90e10ee8,
+        0x40000000,
+        0x40000000,
+        0x15ef6412,
+        0x774e50f1,
+        0x4d89f02b,
+        0x7c182c41,
+        0x4a9ee65c,
+        0xc3c46957,
+        0xa6724bc3,
+        0x91c05c39,
+        0x80000000,
+        0x32cadd15,
+        0x9156af08,
+    ];
+    let mut r = [0u32; 14];
+    r[9] = u32::from_le_bytes([digest[0], digest[1], digest[2], digest[3]]) ^ POOL[0];
+    r[4] = u32::from_le_bytes([digest[4], digest[5], digest[6], digest[7]]) ^ POOL[1];
+    r[2] = r[9] ^ POOL[2];
+    r[5] = r[2] ^ POOL[3];
+    r[2] = r[4] ^ POOL[4];
+    r[11] = r[2] ^ POOL[5];
+    r[2] = {
+        let (t, s) = (POOL[6], POOL[7]);
+        let (ma, mb) = (t ^ s ^ POOL[8], t ^ s ^ POOL[9]);
+        let mut z = t;
+        z ^= r[5] & mb;
+        z ^= r[11] & ma;
+        z ^= r[5] & r[11];
+        z
+    };
+    r[5] = r[9] ^ r[4];
+    r[9] = r[5] & POOL[10];
+    r[11] = r[2] & POOL[11];
+    r[7] = r[11].rotate_left(1);
+    r[11] = r[5] & POOL[12];
+    r[8] = r[11] ^ r[7];
+    r[0] = r[9] ^ r[8];
+    r[9] = r[2] & POOL[13];
+    r[8] = r[11] ^ POOL[14];
+    r[11] = r[8] ^ POOL[15];
+    r[8] = r[11] ^ POOL[16];
+    r[11] = r[7] ^ POOL[17];
+    r[7] = r[11] ^ POOL[18];
+    r[11] = r[7] ^ POOL[19];
+    r[7] = {
+        let (t, s) = (POOL[20], POOL[21]);
+        let (ma, mb) = (t ^ s ^ POOL[22], t ^ s ^ POOL[23]);
+        let mut z = t;
+        z ^= r[8] & mb;
+        z ^= r[11] & ma;
+        z ^= r[8] & r[11];
+        z
+    };
+    r[11] = r[9] ^ r[7];
+    r[9] = r[11].rotate_left(1);
+    r[11] = r[5] & POOL[24];
+    r[7] = r[11] ^ r[9];
+    r[8] = r[0] ^ r[7];
+    r[7] = r[2] & POOL[25];
+    r[0] = r[11] ^ POOL[26];
+    r[11] = r[0] ^ POOL[27];
+    r[0] = r[11] ^ POOL[28];
+    r[11] = r[9] ^ POOL[29];
+    r[9] = {
+        let (t, s) = (POOL[30], POOL[31]);
+        let (ma, mb) = (t ^ s ^ POOL[32], t ^ s ^ POOL[33]);
+        let mut z = t;
+        z ^= r[0] & mb;
+        z ^= r[11] & ma;
+        z ^= r[0] & r[11];
+        z
+    };
+    r[11] = r[7] ^ r[9];
+    r[9] = r[11].rotate_left(1);
+    r[11] = r[5] & POOL[34];
+    r[7] = r[11] ^ r[9];
+    r[0] = r[8] ^ r[7];
+    r[7] = r[2] & POOL[35];
+    r[8] = r[11] ^ POOL[36];
+    r[11] = r[8] ^ POOL[37];
+    r[8] = r[11] ^ POOL[38];
+    r[11] = r[9] ^ POOL[39];
+    r[9] = {
+        let (t, s) = (POOL[40], POOL[41]);
+        let (ma, mb) = (t ^ s ^ POOL[42], t ^ s ^ POOL[43]);
+        let mut z = t;
+        z ^= r[8] & mb;
+        z ^= r[11] & ma;
+        z ^= r[8] & r[11];
+        z
+    };
+    r[11] = r[7] ^ r[9];
+    r[9] = r[11].rotate_left(1);
+    r[11] = r[5] & POOL[44];
+    r[7] = r[11] ^ r[9];
+    r[8] = r[0] ^ r[7];
+    r[7] = r[2] & POOL[45];
+    r[0] = r[11] ^ POOL[46];
+    r[11] = r[0] ^ POOL[47];
+    r[0] = r[11] ^ POOL[48];
+    r[11] = r[9] ^ POOL[49];
+    r[9] = {
+        let (t, s) = (POOL[50], POOL[51]);
+        let (ma, mb) = (t ^ s ^ POOL[52], t ^ s ^ POOL[53]);
+        let mut z = t;
+        z ^= r[0] & mb;
+        z ^= r[11] & ma;
+        z ^= r[0] & r[11];
+        z
+    };
+    r[11] = r[7] ^ r[9];
+    r[9] = r[11].rotate_left(1);
+    r[11] = r[5] & POOL[54];
+    r[7] = r[11] ^ r[9];
+    r[0] = r[8] ^ r[7];
+    r[7] = r[2] & POOL[55];
+    r[8] = r[11] ^ POOL[56];
+    r[11] = r[8] ^ POOL[57];
+    r[8] = r[11] ^ POOL[58];
+    r[11] = r[9] ^ POOL[59];
+    r[9] = {
+        let (t, s) = (POOL[60], POOL[61]);
+        let (ma, mb) = (t ^ s ^ POOL[62], t ^ s ^ POOL[63]);
+        let mut z = t;
+        z ^= r[8] & mb;
+        z ^= r[11] & ma;
+        z ^= r[8] & r[11];
+        z
+    };
+    r[11] = r[7] ^ r[9];
+    r[9] = r[11].rotate_left(1);
+    r[11] = r[5] & POOL[64];
+    r[7] = r[11] ^ r[9];
+    r[8] = r[0] ^ r[7];
+    r[7] = r[2] & POOL[65];
+    r[0] = r[11] ^ POOL[66];
+    r[11] = r[0] ^ POOL[67];
+    r[0] = r[11] ^ POOL[68];
+    r[11] = r[9] ^ POOL[69];
+    r[9] = {
+        let (t, s) = (POOL[70], POOL[71]);
+        let (ma, mb) = (t ^ s ^ POOL[72], t ^ s ^ POOL[73]);
+        let mut z = t;
+        z ^= r[0] & mb;
+        z ^= r[11] & ma;
+        z ^= r[0] & r[11];
+        z
+    };
+    r[11] = r[7] ^ r[9];
+    r[9] = r[11].rotate_left(1);
+    r[11] = r[5] & POOL[74];
+    r[7] = r[11] ^ r[9];
+    r[0] = r[8] ^ r[7];
+    r[7] = r[2] & POOL[75];
+    r[8] = r[11] ^ POOL[76];
+    r[11] = r[8] ^ POOL[77];
+    r[8] = r[11] ^ POOL[78];
+    r[11] = r[9] ^ POOL[79];
+    r[9] = {
+        let (t, s) = (POOL[80], POOL[81]);
+        let (ma, mb) = (t ^ s ^ POOL[82], t ^ s ^ POOL[83]);
+        let mut z = t;
+        z ^= r[8] & mb;
+        z ^= r[11] & ma;
+        z ^= r[8] & r[11];
+        z
+    };
+    r[11] = r[7] ^ r[9];
+    r[9] = r[11].rotate_left(1);
+    r[11] = r[5] & POOL[84];
+    r[7] = r[11] ^ r[9];
+    r[8] = r[0] ^ r[7];
+    r[7] = r[2] & POOL[85];
+    r[0] = r[11] ^ POOL[86];
+    r[11] = r[0] ^ POOL[87];
+    r[0] = r[11] ^ POOL[88];
+    r[11] = r[9] ^ POOL[89];
+    r[9] = {
+        let (t, s) = (POOL[90], POOL[91]);
+        let (ma, mb) = (t ^ s ^ POOL[92], t ^ s ^ POOL[93]);
+        let mut z = t;
+        z ^= r[0] & mb;
+        z ^= r[11] & ma;
+        z ^= r[0] & r[11];
+        z
+    };
+    r[11] = r[7] ^ r[9];
+    r[9] = r[11].rotate_left(1);
+    r[11] = r[5] & POOL[94];
+    r[7] = r[11] ^ r[9];
+    r[0] = r[8] ^ r[7];
+    r[7] = r[2] & POOL[95];
+    r[8] = r[11] ^ POOL[96];
+    r[11] = r[8] ^ POOL[97];
+    r[8] = r[11] ^ POOL[98];
+    r[11] = r[9] ^ POOL[99];
+    r[9] = {
+        let (t, s) = (POOL[100], POOL[101]);
+        let (ma, mb) = (t ^ s ^ POOL[102], t ^ s ^ POOL[103]);
+        let mut z = t;
+        z ^= r[8] & mb;
+        z ^= r[11] & ma;
+        z ^= r[8] & r[11];
+        z
+    };
+    r[11] = r[7] ^ r[9];
+    r[9] = r[11].rotate_left(1);
+    r[11] = r[5] & POOL[104];
+    r[7] = r[11] ^ r[9];
+    r[8] = r[0] ^ r[7];
+    r[7] = r[2] & POOL[105];
+    r[0] = r[11] ^ POOL[106];
+    r[11] = r[0] ^ POOL[107];
+    r[0] = r[11] ^ POOL[108];
+    r[11] = r[9] ^ POOL[109];
+    r[9] = {
+        let (t, s) = (POOL[110], POOL[111]);
+        let (ma, mb) = (t ^ s ^ POOL[112], t ^ s ^ POOL[113]);
+        let mut z = t;
+        z ^= r[0] & mb;
+        z ^= r[11] & ma;
+        z ^= r[0] & r[11];
+        z
+    };
+    r[11] = r[7] ^ r[9];
+    r[9] = r[11].rotate_left(1);
+    r[11] = r[5] & POOL[114];
+    r[7] = r[11] ^ r[9];
+    r[0] = r[8] ^ r[7];
+    r[7] = r[2] & POOL[115];
+    r[8] = r[11] ^ POOL[116];
+    r[11] = r[8] ^ POOL[117];
+    r[8] = r[11] ^ POOL[118];
+    r[11] = r[9] ^ POOL[119];
+    r[9] = {
+        let (t, s) = (POOL[120], POOL[121]);
+        let (ma, mb) = (t ^ s ^ POOL[122], t ^ s ^ POOL[123]);
+        let mut z = t;
+        z ^= r[8] & mb;
+        z ^= r[11] & ma;
+        z ^= r[8] & r[11];
+        z
+    };
+    r[11] = r[7] ^ r[9];
+    r[9] = r[11].rotate_left(1);
+    r[11] = r[5] & POOL[124];
+    r[7] = r[11] ^ r[9];
+    r[8] = r[0] ^ r[7];
+    r[7] = r[2] & POOL[125];
+    r[0] = r[11] ^ POOL[126];
+    r[11] = r[0] ^ POOL[127];
+    r[0] = r[11] ^ POOL[128];
+    r[11] = r[9] ^ POOL[129];
+    r[9] = {
+        let (t, s) = (POOL[130], POOL[131]);
+        let (ma, mb) = (t ^ s ^ POOL[132], t ^ s ^ POOL[133]);
+        let mut z = t;
+        z ^= r[0] & mb;
+        z ^= r[11] & ma;
+        z ^= r[0] & r[11];
+        z
+    };
+    r[11] = r[7] ^ r[9];
+    r[9] = r[11].rotate_left(1);
+    r[11] = r[5] & POOL[134];
+    r[7] = r[11] ^ r[9];
+    r[0] = r[8] ^ r[7];
+    r[7] = r[2] & POOL[135];
+    r[8] = r[11] ^ POOL[136];
+    r[11] = r[8] ^ POOL[137];
+    r[8] = r[11] ^ POOL[138];
+    r[11] = r[9] ^ POOL[139];
+    r[9] = {
+        let (t, s) = (POOL[140], POOL[141]);
+        let (ma, mb) = (t ^ s ^ POOL[142], t ^ s ^ POOL[143]);
+        let mut z = t;
+        z ^= r[8] & mb;
+        z ^= r[11] & ma;
+        z ^= r[8] & r[11];
+        z
+    };
+    r[11] = r[7] ^ r[9];
+    r[9] = r[11].rotate_left(1);
+    r[11] = r[5] & POOL[144];
+    r[7] = r[11] ^ r[9];
+    r[8] = r[0] ^ r[7];
+    r[7] = r[2] & POOL[145];
+    r[0] = r[11] ^ POOL[146];
+    r[11] = r[0] ^ POOL[147];
+    r[0] = r[11] ^ POOL[148];
+    r[11] = r[9] ^ POOL[149];
+    r[9] = {
+        let (t, s) = (POOL[150], POOL[151]);
+        let (ma, mb) = (t ^ s ^ POOL[152], t ^ s ^ POOL[153]);
+        let mut z = t;
+        z ^= r[0] & mb;
+        z ^= r[11] & ma;
+        z ^= r[0] & r[11];
+        z
+    };
+    r[11] = r[7] ^ r[9];
+    r[9] = r[11].rotate_left(1);
+    r[11] = r[5] & POOL[154];
+    r[7] = r[11] ^ r[9];
+    r[0] = r[8] ^ r[7];
+    r[7] = r[2] & POOL[155];
+    r[8] = r[11] ^ POOL[156];
+    r[11] = r[8] ^ POOL[157];
+    r[8] = r[11] ^ POOL[158];
+    r[11] = r[9] ^ POOL[159];
+    r[9] = {
+        let (t, s) = (POOL[160], POOL[161]);
+        let (ma, mb) = (t ^ s ^ POOL[162], t ^ s ^ POOL[163]);
+        let mut z = t;
+        z ^= r[8] & mb;
+        z ^= r[11] & ma;
+        z ^= r[8] & r[11];
+        z
+    };
+    r[11] = r[7] ^ r[9];
+    r[9] = r[11].rotate_left(1);
+    r[11] = r[5] & POOL[164];
+    r[7] = r[11] ^ r[9];
+    r[8] = r[0] ^ r[7];
+    r[7] = r[2] & POOL[165];
+    r[0] = r[11] ^ POOL[166];
+    r[11] = r[0] ^ POOL[167];
+    r[0] = r[11] ^ POOL[168];
+    r[11] = r[9] ^ POOL[169];
+    r[9] = {
+        let (t, s) = (POOL[170], POOL[171]);
+        let (ma, mb) = (t ^ s ^ POOL[172], t ^ s ^ POOL[173]);
+        let mut z = t;
+        z ^= r[0] & mb;
+        z ^= r[11] & ma;
+        z ^= r[0] & r[11];
+        z
+    };
+    r[11] = r[7] ^ r[9];
+    r[9] = r[11].rotate_left(1);
+    r[11] = r[5] & POOL[174];
+    r[7] = r[11] ^ r[9];
+    r[0] = r[8] ^ r[7];
+    r[7] = r[2] & POOL[175];
+    r[8] = r[11] ^ POOL[176];
+    r[11] = r[8] ^ POOL[177];
+    r[8] = r[11] ^ POOL[178];
+    r[11] = r[9] ^ POOL[179];
+    r[9] = {
+        let (t, s) = (POOL[180], POOL[181]);
+        let (ma, mb) = (t ^ s ^ POOL[182], t ^ s ^ POOL[183]);
+        let mut z = t;
+        z ^= r[8] & mb;
+        z ^= r[11] & ma;
+        z ^= r[8] & r[11];
+        z
+    };
+    r[11] = r[7] ^ r[9];
+    r[9] = r[11].rotate_left(1);
+    r[11] = r[5] & POOL[184];
+    r[7] = r[11] ^ r[9];
+    r[8] = r[0] ^ r[7];
+    r[7] = r[2] & POOL[185];
+    r[0] = r[11] ^ POOL[186];
+    r[11] = r[0] ^ POOL[187];
+    r[0] = r[11] ^ POOL[188];
+    r[11] = r[9] ^ POOL[189];
+    r[9] = {
+        let (t, s) = (POOL[190], POOL[191]);
+        let (ma, mb) = (t ^ s ^ POOL[192], t ^ s ^ POOL[193]);
+        let mut z = t;
+        z ^= r[0] & mb;
+        z ^= r[11] & ma;
+        z ^= r[0] & r[11];
+        z
+    };
+    r[11] = r[7] ^ r[9];
+    r[9] = r[11].rotate_left(1);
+    r[11] = r[5] & POOL[194];
+    r[7] = r[11] ^ r[9];
+    r[0] = r[8] ^ r[7];
+    r[7] = r[2] & POOL[195];
+    r[8] = r[11] ^ POOL[196];
+    r[11] = r[8] ^ POOL[197];
+    r[8] = r[11] ^ POOL[198];
+    r[11] = r[9] ^ POOL[199];
+    r[9] = {
+        let (t, s) = (POOL[200], POOL[201]);
+        let (ma, mb) = (t ^ s ^ POOL[202], t ^ s ^ POOL[203]);
+        let mut z = t;
+        z ^= r[8] & mb;
+        z ^= r[11] & ma;
+        z ^= r[8] & r[11];
+        z
+    };
+    r[11] = r[7] ^ r[9];
+    r[9] = r[11].rotate_left(1);
+    r[11] = r[5] & POOL[204];
+    r[7] = r[11] ^ r[9];
+    r[8] = r[0] ^ r[7];
+    r[7] = r[2] & POOL[205];
+    r[0] = r[11] ^ POOL[206];
+    r[11] = r[0] ^ POOL[207];
+    r[0] = r[11] ^ POOL[208];
+    r[11] = r[9] ^ POOL[209];
+    r[9] = {
+        let (t, s) = (POOL[210], POOL[211]);
+        let (ma, mb) = (t ^ s ^ POOL[212], t ^ s ^ POOL[213]);
+        let mut z = t;
+        z ^= r[0] & mb;
+        z ^= r[11] & ma;
+        z ^= r[0] & r[11];
+        z
+    };
+    r[11] = r[7] ^ r[9];
+    r[9] = r[11].rotate_left(1);
+    r[11] = r[5] & POOL[214];
+    r[7] = r[11] ^ r[9];
+    r[0] = r[8] ^ r[7];
+    r[7] = r[2] & POOL[215];
+    r[8] = r[11] ^ POOL[216];
+    r[11] = r[8] ^ POOL[217];
+    r[8] = r[11] ^ POOL[218];
+    r[11] = r[9] ^ POOL[219];
+    r[9] = {
+        let (t, s) = (POOL[220], POOL[221]);
+        let (ma, mb) = (t ^ s ^ POOL[222], t ^ s ^ POOL[223]);
+        let mut z = t;
+        z ^= r[8] & mb;
+        z ^= r[11] & ma;
+        z ^= r[8] & r[11];
+        z
+    };
+    r[11] = r[7] ^ r[9];
+    r[9] = r[11].rotate_left(1);
+    r[11] = r[5] & POOL[224];
+    r[7] = r[11] ^ r[9];
+    r[8] = r[0] ^ r[7];
+    r[7] = r[2] & POOL[225];
+    r[0] = r[11] ^ POOL[226];
+    r[11] = r[0] ^ POOL[227];
+    r[0] = r[11] ^ POOL[228];
+    r[11] = r[9] ^ POOL[229];
+    r[9] = {
+        let (t, s) = (POOL[230], POOL[231]);
+        let (ma, mb) = (t ^ s ^ POOL[232], t ^ s ^ POOL[233]);
+        let mut z = t;
+        z ^= r[0] & mb;
+        z ^= r[11] & ma;
+        z ^= r[0] & r[11];
+        z
+    };
+    r[11] = r[7] ^ r[9];
+    r[9] = r[11].rotate_left(1);
+    r[11] = r[5] & POOL[234];
+    r[7] = r[11] ^ r[9];
+    r[0] = r[8] ^ r[7];
+    r[7] = r[2] & POOL[235];
+    r[8] = r[11] ^ POOL[236];
+    r[11] = r[8] ^ POOL[237];
+    r[8] = r[11] ^ POOL[238];
+    r[11] = r[9] ^ POOL[239];
+    r[9] = {
+        let (t, s) = (POOL[240], POOL[241]);
+        let (ma, mb) = (t ^ s ^ POOL[242], t ^ s ^ POOL[243]);
+        let mut z = t;
+        z ^= r[8] & mb;
+        z ^= r[11] & ma;
+        z ^= r[8] & r[11];
+        z
+    };
+    r[11] = r[7] ^ r[9];
+    r[9] = r[11].rotate_left(1);
+    r[11] = r[5] & POOL[244];
+    r[7] = r[11] ^ r[9];
+    r[8] = r[0] ^ r[7];
+    r[7] = r[2] & POOL[245];
+    r[0] = r[11] ^ POOL[246];
+    r[11] = r[0] ^ POOL[247];
+    r[0] = r[11] ^ POOL[248];
+    r[11] = r[9] ^ POOL[249];
+    r[9] = {
+        let (t, s) = (POOL[250], POOL[251]);
+        let (ma, mb) = (t ^ s ^ POOL[252], t ^ s ^ POOL[253]);
+        let mut z = t;
+        z ^= r[0] & mb;
+        z ^= r[11] & ma;
+        z ^= r[0] & r[11];
+        z
+    };
+    r[11] = r[7] ^ r[9];
+    r[9] = r[11].rotate_left(1);
+    r[11] = r[5] & POOL[254];
+    r[7] = r[11] ^ r[9];
+    r[0] = r[8] ^ r[7];
+    r[7] = r[2] & POOL[255];
+    r[8] = r[11] ^ POOL[256];
+    r[11] = r[8] ^ POOL[257];
+    r[8] = r[11] ^ POOL[258];
+    r[11] = r[9] ^ POOL[259];
+    r[9] = {
+        let (t, s) = (POOL[260], POOL[261]);
+        let (ma, mb) = (t ^ s ^ POOL[262], t ^ s ^ POOL[263]);
+        let mut z = t;
+        z ^= r[8] & mb;
+        z ^= r[11] & ma;
+        z ^= r[8] & r[11];
+        z
+    };
+    r[11] = r[7] ^ r[9];
+    r[9] = r[11].rotate_left(1);
+    r[11] = r[5] & POOL[264];
+    r[7] = r[11] ^ r[9];
+    r[8] = r[0] ^ r[7];
+    r[7] = r[2] & POOL[265];
+    r[0] = r[11] ^ POOL[266];
+    r[11] = r[0] ^ POOL[267];
+    r[0] = r[11] ^ POOL[268];
+    r[11] = r[9] ^ POOL[269];
+    r[9] = {
+        let (t, s) = (POOL[270], POOL[271]);
+        let (ma, mb) = (t ^ s ^ POOL[272], t ^ s ^ POOL[273]);
+        let mut z = t;
+        z ^= r[0] & mb;
+        z ^= r[11] & ma;
+        z ^= r[0] & r[11];
+        z
+    };
+    r[11] = r[7] ^ r[9];
+    r[9] = r[11].rotate_left(1);
+    r[11] = r[5] & POOL[274];
+    r[7] = r[11] ^ r[9];
+    r[0] = r[8] ^ r[7];
+    r[7] = r[2] & POOL[275];
+    r[8] = r[11] ^ POOL[276];
+    r[11] = r[8] ^ POOL[277];
+    r[8] = r[11] ^ POOL[278];
+    r[11] = r[9] ^ POOL[279];
+    r[9] = {
+        let (t, s) = (POOL[280], POOL[281]);
+        let (ma, mb) = (t ^ s ^ POOL[282], t ^ s ^ POOL[283]);
+        let mut z = t;
+        z ^= r[8] & mb;
+        z ^= r[11] & ma;
+        z ^= r[8] & r[11];
+        z
+    };
+    r[11] = r[7] ^ r[9];
+    r[9] = r[11].rotate_left(1);
+    r[11] = r[5] & POOL[284];
+    r[7] = r[11] ^ r[9];
+    r[8] = r[0] ^ r[7];
+    r[7] = r[2] & POOL[285];
+    r[0] = r[11] ^ POOL[286];
+    r[11] = r[0] ^ POOL[287];
+    r[0] = r[11] ^ POOL[288];
+    r[11] = r[9] ^ POOL[289];
+    r[9] = {
+        let (t, s) = (POOL[290], POOL[291]);
+        let (ma, mb) = (t ^ s ^ POOL[292], t ^ s ^ POOL[293]);
+        let mut z = t;
+        z ^= r[0] & mb;
+        z ^= r[11] & ma;
+        z ^= r[0] & r[11];
+        z
+    };
+    r[11] = r[7] ^ r[9];
+    r[9] = r[11].rotate_left(1);
+    r[11] = r[5] & POOL[294];
+    r[7] = r[11] ^ r[9];
+    r[0] = r[8] ^ r[7];
+    r[7] = r[2] & POOL[295];
+    r[8] = r[11] ^ POOL[296];
+    r[11] = r[8] ^ POOL[297];
+    r[8] = r[11] ^ POOL[298];
+    r[11] = r[9] ^ POOL[299];
+    r[9] = {
+        let (t, s) = (POOL[300], POOL[301]);
+        let (ma, mb) = (t ^ s ^ POOL[302], t ^ s ^ POOL[303]);
+        let mut z = t;
+        z ^= r[8] & mb;
+        z ^= r[11] & ma;
+        z ^= r[8] & r[11];
+        z
+    };
+    r[11] = r[7] ^ r[9];
+    r[9] = r[11].rotate_left(1);
+    r[11] = r[5] & POOL[304];
+    r[7] = r[11] ^ r[9];
+    r[8] = r[0] ^ r[7];
+    r[7] = r[2] & POOL[305];
+    r[2] = r[11] ^ POOL[306];
+    r[11] = r[2] ^ POOL[307];
+    r[2] = r[11] ^ POOL[308];
+    r[11] = r[9] ^ POOL[309];
+    r[9] = {
+        let (t, s) = (POOL[310], POOL[311]);
+        let (ma, mb) = (t ^ s ^ POOL[312], t ^ s ^ POOL[313]);
+        let mut z = t;
+        z ^= r[2] & mb;
+        z ^= r[11] & ma;
+        z ^= r[2] & r[11];
+        z
+    };
+    r[2] = r[7] ^ r[9];
+    r[9] = r[2].rotate_left(1);
+    r[2] = r[5] & POOL[314];
+    r[5] = r[2] ^ r[9];
+    r[9] = r[8] ^ r[5];
+    r[2] = u32::from_le_bytes([digest[8], digest[9], digest[10], digest[11]]) ^ POOL[315];
+    r[5] = u32::from_le_bytes([digest[12], digest[13], digest[14], digest[15]]) ^ POOL[316];
+    r[11] = r[5] ^ r[9];
+    r[5] = r[11].rotate_left(16);
+    r[11] = r[2] ^ POOL[317];
+    r[7] = r[11] ^ POOL[318];
+    r[11] = r[5] ^ POOL[319];
+    r[8] = {
+        let (t, s) = (POOL[320], POOL[321]);
+        let (ma, mb) = (t ^ s ^ POOL[322], t ^ s ^ POOL[323]);
+        let mut z = t;
+        z ^= r[7] & mb;
+        z ^= r[11] & ma;
+        z ^= r[7] & r[11];
+        z
+    };
+    r[11] = r[2] ^ r[5];
+    r[2] = r[11] & POOL[324];
+    r[7] = r[8] & POOL[325];
+    r[0] = r[7].rotate_left(1);
+    r[7] = r[11] & POOL[326];
+    r[12] = r[7] ^ r[0];
+    r[3] = r[2] ^ r[12];
+    r[2] = r[8] & POOL[327];
+    r[12] = r[7] ^ POOL[328];
+    r[7] = r[12] ^ POOL[329];
+    r[12] = r[7] ^ POOL[330];
+    r[7] = r[0] ^ POOL[331];
+    r[0] = r[7] ^ POOL[332];
+    r[7] = r[0] ^ POOL[333];
+    r[0] = {
+        let (t, s) = (POOL[334], POOL[335]);
+        let (ma, mb) = (t ^ s ^ POOL[336], t ^ s ^ POOL[337]);
+        let mut z = t;
+        z ^= r[12] & mb;
+        z ^= r[7] & ma;
+        z ^= r[12] & r[7];
+        z
+    };
+    r[7] = r[2] ^ r[0];
+    r[2] = r[7].rotate_left(1);
+    r[7] = r[11] & POOL[338];
+    r[0] = r[7] ^ r[2];
+    r[12] = r[3] ^ r[0];
+    r[0] = r[8] & POOL[339];
+    r[3] = r[7] ^ POOL[340];
+    r[7] = r[3] ^ POOL[341];
+    r[3] = r[7] ^ POOL[342];
+    r[7] = r[2] ^ POOL[343];
+    r[2] = {
+        let (t, s) = (POOL[344], POOL[345]);
+        let (ma, mb) = (t ^ s ^ POOL[346], t ^ s ^ POOL[347]);
+        let mut z = t;
+        z ^= r[3] & mb;
+        z ^= r[7] & ma;
+        z ^= r[3] & r[7];
+        z
+    };
+    r[7] = r[0] ^ r[2];
+    r[2] = r[7].rotate_left(1);
+    r[7] = r[11] & POOL[348];
+    r[0] = r[7] ^ r[2];
+    r[3] = r[12] ^ r[0];
+    r[0] = r[8] & POOL[349];
+    r[12] = r[7] ^ POOL[350];
+    r[7] = r[12] ^ POOL[351];
+    r[12] = r[7] ^ POOL[352];
+    r[7] = r[2] ^ POOL[353];
+    r[2] = {
+        let (t, s) = (POOL[354], POOL[355]);
+        let (ma, mb) = (t ^ s ^ POOL[356], t ^ s ^ POOL[357]);
+        let mut z = t;
+        z ^= r[12] & mb;
+        z ^= r[7] & ma;
+        z ^= r[12] & r[7];
+        z
+    };
+    r[7] = r[0] ^ r[2];
+    r[2] = r[7].rotate_left(1);
+    r[7] = r[11] & POOL[358];
+    r[0] = r[7] ^ r[2];
+    r[12] = r[3] ^ r[0];
+    r[0] = r[8] & POOL[359];
+    r[3] = r[7] ^ POOL[360];
+    r[7] = r[3] ^ POOL[361];
+    r[3] = r[7] ^ POOL[362];
+    r[7] = r[2] ^ POOL[363];
+    r[2] = {
+        let (t, s) = (POOL[364], POOL[365]);
+        let (ma, mb) = (t ^ s ^ POOL[366], t ^ s ^ POOL[367]);
+        let mut z = t;
+        z ^= r[3] & mb;
+        z ^= r[7] & ma;
+        z ^= r[3] & r[7];
+        z
+    };
+    r[7] = r[0] ^ r[2];
+    r[2] = r[7].rotate_left(1);
+    r[7] = r[11] & POOL[368];
+    r[0] = r[7] ^ r[2];
+    r[3] = r[12] ^ r[0];
+    r[0] = r[8] & POOL[369];
+    r[12] = r[7] ^ POOL[370];
+    r[7] = r[12] ^ POOL[371];
+    r[12] = r[7] ^ POOL[372];
+    r[7] = r[2] ^ POOL[373];
+    r[2] = {
+        let (t, s) = (POOL[374], POOL[375]);
+        let (ma, mb) = (t ^ s ^ POOL[376], t ^ s ^ POOL[377]);
+        let mut z = t;
+        z ^= r[12] & mb;
+        z ^= r[7] & ma;
+        z ^= r[12] & r[7];
+        z
+    };
+    r[7] = r[0] ^ r[2];
+    r[2] = r[7].rotate_left(1);
+    r[7] = r[11] & POOL[378];
+    r[0] = r[7] ^ r[2];
+    r[12] = r[3] ^ r[0];
+    r[0] = r[8] & POOL[379];
+    r[3] = r[7] ^ POOL[380];
+    r[7] = r[3] ^ POOL[381];
+    r[3] = r[7] ^ POOL[382];
+    r[7] = r[2] ^ POOL[383];
+    r[2] = {
+        let (t, s) = (POOL[384], POOL[385]);
+        let (ma, mb) = (t ^ s ^ POOL[386], t ^ s ^ POOL[387]);
+        let mut z = t;
+        z ^= r[3] & mb;
+        z ^= r[7] & ma;
+        z ^= r[3] & r[7];
+        z
+    };
+    r[7] = r[0] ^ r[2];
+    r[2] = r[7].rotate_left(1);
+    r[7] = r[11] & POOL[388];
+    r[0] = r[7] ^ r[2];
+    r[3] = r[12] ^ r[0];
+    r[0] = r[8] & POOL[389];
+    r[12] = r[7] ^ POOL[390];
+    r[7] = r[12] ^ POOL[391];
+    r[12] = r[7] ^ POOL[392];
+    r[7] = r[2] ^ POOL[393];
+    r[2] = {
+        let (t, s) = (POOL[394], POOL[395]);
+        let (ma, mb) = (t ^ s ^ POOL[396], t ^ s ^ POOL[397]);
+        let mut z = t;
+        z ^= r[12] & mb;
+        z ^= r[7] & ma;
+        z ^= r[12] & r[7];
+        z
+    };
+    r[7] = r[0] ^ r[2];
+    r[2] = r[7].rotate_left(1);
+    r[7] = r[11] & POOL[398];
+    r[0] = r[7] ^ r[2];
+    r[12] = r[3] ^ r[0];
+    r[0] = r[8] & POOL[399];
+    r[3] = r[7] ^ POOL[400];
+    r[7] = r[3] ^ POOL[401];
+    r[3] = r[7] ^ POOL[402];
+    r[7] = r[2] ^ POOL[403];
+    r[2] = {
+        let (t, s) = (POOL[404], POOL[405]);
+        let (ma, mb) = (t ^ s ^ POOL[406], t ^ s ^ POOL[407]);
+        let mut z = t;
+        z ^= r[3] & mb;
+        z ^= r[7] & ma;
+        z ^= r[3] & r[7];
+        z
+    };
+    r[7] = r[0] ^ r[2];
+    r[2] = r[7].rotate_left(1);
+    r[7] = r[11] & POOL[408];
+    r[0] = r[7] ^ r[2];
+    r[3] = r[12] ^ r[0];
+    r[0] = r[8] & POOL[409];
+    r[12] = r[7] ^ POOL[410];
+    r[7] = r[12] ^ POOL[411];
+    r[12] = r[7] ^ POOL[412];
+    r[7] = r[2] ^ POOL[413];
+    r[2] = {
+        let (t, s) = (POOL[414], POOL[415]);
+        let (ma, mb) = (t ^ s ^ POOL[416], t ^ s ^ POOL[417]);
+        let mut z = t;
+        z ^= r[12] & mb;
+        z ^= r[7] & ma;
+        z ^= r[12] & r[7];
+        z
+    };
+    r[7] = r[0] ^ r[2];
+    r[2] = r[7].rotate_left(1);
+    r[7] = r[11] & POOL[418];
+    r[0] = r[7] ^ r[2];
+    r[12] = r[3] ^ r[0];
+    r[0] = r[8] & POOL[419];
+    r[3] = r[7] ^ POOL[420];
+    r[7] = r[3] ^ POOL[421];
+    r[3] = r[7] ^ POOL[422];
+    r[7] = r[2] ^ POOL[423];
+    r[2] = {
+        let (t, s) = (POOL[424], POOL[425]);
+        let (ma, mb) = (t ^ s ^ POOL[426], t ^ s ^ POOL[427]);
+        let mut z = t;
+        z ^= r[3] & mb;
+        z ^= r[7] & ma;
+        z ^= r[3] & r[7];
+        z
+    };
+    r[7] = r[0] ^ r[2];
+    r[2] = r[7].rotate_left(1);
+    r[7] = r[11] & POOL[428];
+    r[0] = r[7] ^ r[2];
+    r[3] = r[12] ^ r[0];
+    r[0] = r[8] & POOL[429];
+    r[12] = r[7] ^ POOL[430];
+    r[7] = r[12] ^ POOL[431];
+    r[12] = r[7] ^ POOL[432];
+    r[7] = r[2] ^ POOL[433];
+    r[2] = {
+        let (t, s) = (POOL[434], POOL[435]);
+        let (ma, mb) = (t ^ s ^ POOL[436], t ^ s ^ POOL[437]);
+        let mut z = t;
+        z ^= r[12] & mb;
+        z ^= r[7] & ma;
+        z ^= r[12] & r[7];
+        z
+    };
+    r[7] = r[0] ^ r[2];
+    r[2] = r[7].rotate_left(1);
+    r[7] = r[11] & POOL[438];
+    r[0] = r[7] ^ r[2];
+    r[12] = r[3] ^ r[0];
+    r[0] = r[8] & POOL[439];
+    r[3] = r[7] ^ POOL[440];
+    r[7] = r[3] ^ POOL[441];
+    r[3] = r[7] ^ POOL[442];
+    r[7] = r[2] ^ POOL[443];
+    r[2] = {
+        let (t, s) = (POOL[444], POOL[445]);
+        let (ma, mb) = (t ^ s ^ POOL[446], t ^ s ^ POOL[447]);
+        let mut z = t;
+        z ^= r[3] & mb;
+        z ^= r[7] & ma;
+        z ^= r[3] & r[7];
+        z
+    };
+    r[7] = r[0] ^ r[2];
+    r[2] = r[7].rotate_left(1);
+    r[7] = r[11] & POOL[448];
+    r[0] = r[7] ^ r[2];
+    r[3] = r[12] ^ r[0];
+    r[0] = r[8] & POOL[449];
+    r[12] = r[7] ^ POOL[450];
+    r[7] = r[12] ^ POOL[451];
+    r[12] = r[7] ^ POOL[452];
+    r[7] = r[2] ^ POOL[453];
+    r[2] = {
+        let (t, s) = (POOL[454], POOL[455]);
+        let (ma, mb) = (t ^ s ^ POOL[456], t ^ s ^ POOL[457]);
+        let mut z = t;
+        z ^= r[12] & mb;
+        z ^= r[7] & ma;
+        z ^= r[12] & r[7];
+        z
+    };
+    r[7] = r[0] ^ r[2];
+    r[2] = r[7].rotate_left(1);
+    r[7] = r[11] & POOL[458];
+    r[0] = r[7] ^ r[2];
+    r[12] = r[3] ^ r[0];
+    r[0] = r[8] & POOL[459];
+    r[3] = r[7] ^ POOL[460];
+    r[7] = r[3] ^ POOL[461];
+    r[3] = r[7] ^ POOL[462];
+    r[7] = r[2] ^ POOL[463];
+    r[2] = {
+        let (t, s) = (POOL[464], POOL[465]);
+        let (ma, mb) = (t ^ s ^ POOL[466], t ^ s ^ POOL[467]);
+        let mut z = t;
+        z ^= r[3] & mb;
+        z ^= r[7] & ma;
+        z ^= r[3] & r[7];
+        z
+    };
+    r[7] = r[0] ^ r[2];
+    r[2] = r[7].rotate_left(1);
+    r[7] = r[11] & POOL[468];
+    r[0] = r[7] ^ r[2];
+    r[3] = r[12] ^ r[0];
+    r[0] = r[8] & POOL[469];
+    r[12] = r[7] ^ POOL[470];
+    r[7] = r[12] ^ POOL[471];
+    r[12] = r[7] ^ POOL[472];
+    r[7] = r[2] ^ POOL[473];
+    r[2] = {
+        let (t, s) = (POOL[474], POOL[475]);
+        let (ma, mb) = (t ^ s ^ POOL[476], t ^ s ^ POOL[477]);
+        let mut z = t;
+        z ^= r[12] & mb;
+        z ^= r[7] & ma;
+        z ^= r[12] & r[7];
+        z
+    };
+    r[7] = r[0] ^ r[2];
+    r[2] = r[7].rotate_left(1);
+    r[7] = r[11] & POOL[478];
+    r[0] = r[7] ^ r[2];
+    r[12] = r[3] ^ r[0];
+    r[0] = r[8] & POOL[479];
+    r[3] = r[7] ^ POOL[480];
+    r[7] = r[3] ^ POOL[481];
+    r[3] = r[7] ^ POOL[482];
+    r[7] = r[2] ^ POOL[483];
+    r[2] = {
+        let (t, s) = (POOL[484], POOL[485]);
+        let (ma, mb) = (t ^ s ^ POOL[486], t ^ s ^ POOL[487]);
+        let mut z = t;
+        z ^= r[3] & mb;
+        z ^= r[7] & ma;
+        z ^= r[3] & r[7];
+        z
+    };
+    r[7] = r[0] ^ r[2];
+    r[2] = r[7].rotate_left(1);
+    r[7] = r[11] & POOL[488];
+    r[0] = r[7] ^ r[2];
+    r[3] = r[12] ^ r[0];
+    r[0] = r[8] & POOL[489];
+    r[12] = r[7] ^ POOL[490];
+    r[7] = r[12] ^ POOL[491];
+    r[12] = r[7] ^ POOL[492];
+    r[7] = r[2] ^ POOL[493];
+    r[2] = {
+        let (t, s) = (POOL[494], POOL[495]);
+        let (ma, mb) = (t ^ s ^ POOL[496], t ^ s ^ POOL[497]);
+        let mut z = t;
+        z ^= r[12] & mb;
+        z ^= r[7] & ma;
+        z ^= r[12] & r[7];
+        z
+    };
+    r[7] = r[0] ^ r[2];
+    r[2] = r[7].rotate_left(1);
+    r[7] = r[11] & POOL[498];
+    r[0] = r[7] ^ r[2];
+    r[12] = r[3] ^ r[0];
+    r[0] = r[8] & POOL[499];
+    r[3] = r[7] ^ POOL[500];
+    r[7] = r[3] ^ POOL[501];
+    r[3] = r[7] ^ POOL[502];
+    r[7] = r[2] ^ POOL[503];
+    r[2] = {
+        let (t, s) = (POOL[504], POOL[505]);
+        let (ma, mb) = (t ^ s ^ POOL[506], t ^ s ^ POOL[507]);
+        let mut z = t;
+        z ^= r[3] & mb;
+        z ^= r[7] & ma;
+        z ^= r[3] & r[7];
+        z
+    };
+    r[7] = r[0] ^ r[2];
+    r[2] = r[7].rotate_left(1);
+    r[7] = r[11] & POOL[508];
+    r[0] = r[7] ^ r[2];
+    r[3] = r[12] ^ r[0];
+    r[0] = r[8] & POOL[509];
+    r[12] = r[7] ^ POOL[510];
+    r[7] = r[12] ^ POOL[511];
+    r[12] = r[7] ^ POOL[512];
+    r[7] = r[2] ^ POOL[513];
+    r[2] = {
+        let (t, s) = (POOL[514], POOL[515]);
+        let (ma, mb) = (t ^ s ^ POOL[516], t ^ s ^ POOL[517]);
+        let mut z = t;
+        z ^= r[12] & mb;
+        z ^= r[7] & ma;
+        z ^= r[12] & r[7];
+        z
+    };
+    r[7] = r[0] ^ r[2];
+    r[2] = r[7].rotate_left(1);
+    r[7] = r[11] & POOL[518];
+    r[0] = r[7] ^ r[2];
+    r[12] = r[3] ^ r[0];
+    r[0] = r[8] & POOL[519];
+    r[3] = r[7] ^ POOL[520];
+    r[7] = r[3] ^ POOL[521];
+    r[3] = r[7] ^ POOL[522];
+    r[7] = r[2] ^ POOL[523];
+    r[2] = {
+        let (t, s) = (POOL[524], POOL[525]);
+        let (ma, mb) = (t ^ s ^ POOL[526], t ^ s ^ POOL[527]);
+        let mut z = t;
+        z ^= r[3] & mb;
+        z ^= r[7] & ma;
+        z ^= r[3] & r[7];
+        z
+    };
+    r[7] = r[0] ^ r[2];
+    r[2] = r[7].rotate_left(1);
+    r[7] = r[11] & POOL[528];
+    r[0] = r[7] ^ r[2];
+    r[3] = r[12] ^ r[0];
+    r[0] = r[8] & POOL[529];
+    r[12] = r[7] ^ POOL[530];
+    r[7] = r[12] ^ POOL[531];
+    r[12] = r[7] ^ POOL[532];
+    r[7] = r[2] ^ POOL[533];
+    r[2] = {
+        let (t, s) = (POOL[534], POOL[535]);
+        let (ma, mb) = (t ^ s ^ POOL[536], t ^ s ^ POOL[537]);
+        let mut z = t;
+        z ^= r[12] & mb;
+        z ^= r[7] & ma;
+        z ^= r[12] & r[7];
+        z
+    };
+    r[7] = r[0] ^ r[2];
+    r[2] = r[7].rotate_left(1);
+    r[7] = r[11] & POOL[538];
+    r[0] = r[7] ^ r[2];
+    r[12] = r[3] ^ r[0];
+    r[0] = r[8] & POOL[539];
+    r[3] = r[7] ^ POOL[540];
+    r[7] = r[3] ^ POOL[541];
+    r[3] = r[7] ^ POOL[542];
+    r[7] = r[2] ^ POOL[543];
+    r[2] = {
+        let (t, s) = (POOL[544], POOL[545]);
+        let (ma, mb) = (t ^ s ^ POOL[546], t ^ s ^ POOL[547]);
+        let mut z = t;
+        z ^= r[3] & mb;
+        z ^= r[7] & ma;
+        z ^= r[3] & r[7];
+        z
+    };
+    r[7] = r[0] ^ r[2];
+    r[2] = r[7].rotate_left(1);
+    r[7] = r[11] & POOL[548];
+    r[0] = r[7] ^ r[2];
+    r[3] = r[12] ^ r[0];
+    r[0] = r[8] & POOL[549];
+    r[12] = r[7] ^ POOL[550];
+    r[7] = r[12] ^ POOL[551];
+    r[12] = r[7] ^ POOL[552];
+    r[7] = r[2] ^ POOL[553];
+    r[2] = {
+        let (t, s) = (POOL[554], POOL[555]);
+        let (ma, mb) = (t ^ s ^ POOL[556], t ^ s ^ POOL[557]);
+        let mut z = t;
+        z ^= r[12] & mb;
+        z ^= r[7] & ma;
+        z ^= r[12] & r[7];
+        z
+    };
+    r[7] = r[0] ^ r[2];
+    r[2] = r[7].rotate_left(1);
+    r[7] = r[11] & POOL[558];
+    r[0] = r[7] ^ r[2];
+    r[12] = r[3] ^ r[0];
+    r[0] = r[8] & POOL[559];
+    r[3] = r[7] ^ POOL[560];
+    r[7] = r[3] ^ POOL[561];
+    r[3] = r[7] ^ POOL[562];
+    r[7] = r[2] ^ POOL[563];
+    r[2] = {
+        let (t, s) = (POOL[564], POOL[565]);
+        let (ma, mb) = (t ^ s ^ POOL[566], t ^ s ^ POOL[567]);
+        let mut z = t;
+        z ^= r[3] & mb;
+        z ^= r[7] & ma;
+        z ^= r[3] & r[7];
+        z
+    };
+    r[7] = r[0] ^ r[2];
+    r[2] = r[7].rotate_left(1);
+    r[7] = r[11] & POOL[568];
+    r[0] = r[7] ^ r[2];
+    r[3] = r[12] ^ r[0];
+    r[0] = r[8] & POOL[569];
+    r[12] = r[7] ^ POOL[570];
+    r[7] = r[12] ^ POOL[571];
+    r[12] = r[7] ^ POOL[572];
+    r[7] = r[2] ^ POOL[573];
+    r[2] = {
+        let (t, s) = (POOL[574], POOL[575]);
+        let (ma, mb) = (t ^ s ^ POOL[576], t ^ s ^ POOL[577]);
+        let mut z = t;
+        z ^= r[12] & mb;
+        z ^= r[7] & ma;
+        z ^= r[12] & r[7];
+        z
+    };
+    r[7] = r[0] ^ r[2];
+    r[2] = r[7].rotate_left(1);
+    r[7] = r[11] & POOL[578];
+    r[0] = r[7] ^ r[2];
+    r[12] = r[3] ^ r[0];
+    r[0] = r[8] & POOL[579];
+    r[3] = r[7] ^ POOL[580];
+    r[7] = r[3] ^ POOL[581];
+    r[3] = r[7] ^ POOL[582];
+    r[7] = r[2] ^ POOL[583];
+    r[2] = {
+        let (t, s) = (POOL[584], POOL[585]);
+        let (ma, mb) = (t ^ s ^ POOL[586], t ^ s ^ POOL[587]);
+        let mut z = t;
+        z ^= r[3] & mb;
+        z ^= r[7] & ma;
+        z ^= r[3] & r[7];
+        z
+    };
+    r[7] = r[0] ^ r[2];
+    r[2] = r[7].rotate_left(1);
+    r[7] = r[11] & POOL[588];
+    r[0] = r[7] ^ r[2];
+    r[3] = r[12] ^ r[0];
+    r[0] = r[8] & POOL[589];
+    r[12] = r[7] ^ POOL[590];
+    r[7] = r[12] ^ POOL[591];
+    r[12] = r[7] ^ POOL[592];
+    r[7] = r[2] ^ POOL[593];
+    r[2] = {
+        let (t, s) = (POOL[594], POOL[595]);
+        let (ma, mb) = (t ^ s ^ POOL[596], t ^ s ^ POOL[597]);
+        let mut z = t;
+        z ^= r[12] & mb;
+        z ^= r[7] & ma;
+        z ^= r[12] & r[7];
+        z
+    };
+    r[7] = r[0] ^ r[2];
+    r[2] = r[7].rotate_left(1);
+    r[7] = r[11] & POOL[598];
+    r[0] = r[7] ^ r[2];
+    r[12] = r[3] ^ r[0];
+    r[0] = r[8] & POOL[599];
+    r[3] = r[7] ^ POOL[600];
+    r[7] = r[3] ^ POOL[601];
+    r[3] = r[7] ^ POOL[602];
+    r[7] = r[2] ^ POOL[603];
+    r[2] = {
+        let (t, s) = (POOL[604], POOL[605]);
+        let (ma, mb) = (t ^ s ^ POOL[606], t ^ s ^ POOL[607]);
+        let mut z = t;
+        z ^= r[3] & mb;
+        z ^= r[7] & ma;
+        z ^= r[3] & r[7];
+        z
+    };
+    r[7] = r[0] ^ r[2];
+    r[2] = r[7].rotate_left(1);
+    r[7] = r[11] & POOL[608];
+    r[0] = r[7] ^ r[2];
+    r[3] = r[12] ^ r[0];
+    r[0] = r[8] & POOL[609];
+    r[12] = r[7] ^ POOL[610];
+    r[7] = r[12] ^ POOL[611];
+    r[12] = r[7] ^ POOL[612];
+    r[7] = r[2] ^ POOL[613];
+    r[2] = {
+        let (t, s) = (POOL[614], POOL[615]);
+        let (ma, mb) = (t ^ s ^ POOL[616], t ^ s ^ POOL[617]);
+        let mut z = t;
+        z ^= r[12] & mb;
+        z ^= r[7] & ma;
+        z ^= r[12] & r[7];
+        z
+    };
+    r[7] = r[0] ^ r[2];
+    r[2] = r[7].rotate_left(1);
+    r[7] = r[11] & POOL[618];
+    r[0] = r[7] ^ r[2];
+    r[12] = r[3] ^ r[0];
+    r[0] = r[8] & POOL[619];
+    r[8] = r[7] ^ POOL[620];
+    r[7] = r[8] ^ POOL[621];
+    r[8] = r[7] ^ POOL[622];
+    r[7] = r[2] ^ POOL[623];
+    r[2] = {
+        let (t, s) = (POOL[624], POOL[625]);
+        let (ma, mb) = (t ^ s ^ POOL[626], t ^ s ^ POOL[627]);
+        let mut z = t;
+        z ^= r[8] & mb;
+        z ^= r[7] & ma;
+        z ^= r[8] & r[7];
+        z
+    };
+    r[7] = r[0] ^ r[2];
+    r[2] = r[7].rotate_left(1);
+    r[7] = r[11] & POOL[628];
+    r[11] = r[7] ^ r[2];
+    r[2] = r[12] ^ r[11];
+    r[11] = r[4] ^ r[2];
+    r[4] = r[11].rotate_left(12);
+    r[11] = r[9] ^ POOL[629];
+    r[7] = r[4] ^ POOL[630];
+    r[8] = {
+        let (t, s) = (POOL[631], POOL[632]);
+        let (ma, mb) = (t ^ s ^ POOL[633], t ^ s ^ POOL[634]);
+        let mut z = t;
+        z ^= r[11] & mb;
+        z ^= r[7] & ma;
+        z ^= r[11] & r[7];
+        z
+    };
+    r[11] = r[9] ^ r[4];
+    r[9] = r[11] & POOL[635];
+    r[7] = r[8] & POOL[636];
+    r[0] = r[7].rotate_left(1);
+    r[7] = r[11] & POOL[637];
+    r[12] = r[7] ^ r[0];
+    r[3] = r[9] ^ r[12];
+    r[9] = r[8] & POOL[638];
+    r[12] = r[7] ^ POOL[639];
+    r[7] = r[12] ^ POOL[640];
+    r[12] = r[7] ^ POOL[641];
+    r[7] = r[0] ^ POOL[642];
+    r[0] = r[7] ^ POOL[643];
+    r[7] = r[0] ^ POOL[644];
+    r[0] = {
+        let (t, s) = (POOL[645], POOL[646]);
+        let (ma, mb) = (t ^ s ^ POOL[647], t ^ s ^ POOL[648]);
+        let mut z = t;
+        z ^= r[12] & mb;
+        z ^= r[7] & ma;
+        z ^= r[12] & r[7];
+        z
+    };
+    r[7] = r[9] ^ r[0];
+    r[9] = r[7].rotate_left(1);
+    r[7] = r[11] & POOL[649];
+    r[0] = r[7] ^ r[9];
+    r[12] = r[3] ^ r[0];
+    r[0] = r[8] & POOL[650];
+    r[3] = r[7] ^ POOL[651];
+    r[7] = r[3] ^ POOL[652];
+    r[3] = r[7] ^ POOL[653];
+    r[7] = r[9] ^ POOL[654];
+    r[9] = {
+        let (t, s) = (POOL[655], POOL[656]);
+        let (ma, mb) = (t ^ s ^ POOL[657], t ^ s ^ POOL[658]);
+        let mut z = t;
+        z ^= r[3] & mb;
+        z ^= r[7] & ma;
+        z ^= r[3] & r[7];
+        z
+    };
+    r[7] = r[0] ^ r[9];
+    r[9] = r[7].rotate_left(1);
+    r[7] = r[11] & POOL[659];
+    r[0] = r[7] ^ r[9];
+    r[3] = r[12] ^ r[0];
+    r[0] = r[8] & POOL[660];
+    r[12] = r[7] ^ POOL[661];
+    r[7] = r[12] ^ POOL[662];
+    r[12] = r[7] ^ POOL[663];
+    r[7] = r[9] ^ POOL[664];
+    r[9] = {
+        let (t, s) = (POOL[665], POOL[666]);
+        let (ma, mb) = (t ^ s ^ POOL[667], t ^ s ^ POOL[668]);
+        let mut z = t;
+        z ^= r[12] & mb;
+        z ^= r[7] & ma;
+        z ^= r[12] & r[7];
+        z
+    };
+    r[7] = r[0] ^ r[9];
+    r[9] = r[7].rotate_left(1);
+    r[7] = r[11] & POOL[669];
+    r[0] = r[7] ^ r[9];
+    r[12] = r[3] ^ r[0];
+    r[0] = r[8] & POOL[670];
+    r[3] = r[7] ^ POOL[671];
+    r[7] = r[3] ^ POOL[672];
+    r[3] = r[7] ^ POOL[673];
+    r[7] = r[9] ^ POOL[674];
+    r[9] = {
+        let (t, s) = (POOL[675], POOL[676]);
+        let (ma, mb) = (t ^ s ^ POOL[677], t ^ s ^ POOL[678]);
+        let mut z = t;
+        z ^= r[3] & mb;
+        z ^= r[7] & ma;
+        z ^= r[3] & r[7];
+        z
+    };
+    r[7] = r[0] ^ r[9];
+    r[9] = r[7].rotate_left(1);
+    r[7] = r[11] & POOL[679];
+    r[0] = r[7] ^ r[9];
+    r[3] = r[12] ^ r[0];
+    r[0] = r[8] & POOL[680];
+    r[12] = r[7] ^ POOL[681];
+    r[7] = r[12] ^ POOL[682];
+    r[12] = r[7] ^ POOL[683];
+    r[7] = r[9] ^ POOL[684];
+    r[9] = {
+        let (t, s) = (POOL[685], POOL[686]);
+        let (ma, mb) = (t ^ s ^ POOL[687], t ^ s ^ POOL[688]);
+        let mut z = t;
+        z ^= r[12] & mb;
+        z ^= r[7] & ma;
+        z ^= r[12] & r[7];
+        z
+    };
+    r[7] = r[0] ^ r[9];
+    r[9] = r[7].rotate_left(1);
+    r[7] = r[11] & POOL[689];
+    r[0] = r[7] ^ r[9];
+    r[12] = r[3] ^ r[0];
+    r[0] = r[8] & POOL[690];
+    r[3] = r[7] ^ POOL[691];
+    r[7] = r[3] ^ POOL[692];
+    r[3] = r[7] ^ POOL[693];
+    r[7] = r[9] ^ POOL[694];
+    r[9] = {
+        let (t, s) = (POOL[695], POOL[696]);
+        let (ma, mb) = (t ^ s ^ POOL[697], t ^ s ^ POOL[698]);
+        let mut z = t;
+        z ^= r[3] & mb;
+        z ^= r[7] & ma;
+        z ^= r[3] & r[7];
+        z
+    };
+    r[7] = r[0] ^ r[9];
+    r[9] = r[7].rotate_left(1);
+    r[7] = r[11] & POOL[699];
+    r[0] = r[7] ^ r[9];
+    r[3] = r[12] ^ r[0];
+    r[0] = r[8] & POOL[700];
+    r[12] = r[7] ^ POOL[701];
+    r[7] = r[12] ^ POOL[702];
+    r[12] = r[7] ^ POOL[703];
+    r[7] = r[9] ^ POOL[704];
+    r[9] = {
+        let (t, s) = (POOL[705], POOL[706]);
+        let (ma, mb) = (t ^ s ^ POOL[707], t ^ s ^ POOL[708]);
+        let mut z = t;
+        z ^= r[12] & mb;
+        z ^= r[7] & ma;
+        z ^= r[12] & r[7];
+        z
+    };
+    r[7] = r[0] ^ r[9];
+    r[9] = r[7].rotate_left(1);
+    r[7] = r[11] & POOL[709];
+    r[0] = r[7] ^ r[9];
+    r[12] = r[3] ^ r[0];
+    r[0] = r[8] & POOL[710];
+    r[3] = r[7] ^ POOL[711];
+    r[7] = r[3] ^ POOL[712];
+    r[3] = r[7] ^ POOL[713];
+    r[7] = r[9] ^ POOL[714];
+    r[9] = {
+        let (t, s) = (POOL[715], POOL[716]);
+        let (ma, mb) = (t ^ s ^ POOL[717], t ^ s ^ POOL[718]);
+        let mut z = t;
+        z ^= r[3] & mb;
+        z ^= r[7] & ma;
+        z ^= r[3] & r[7];
+        z
+    };
+    r[7] = r[0] ^ r[9];
+    r[9] = r[7].rotate_left(1);
+    r[7] = r[11] & POOL[719];
+    r[0] = r[7] ^ r[9];
+    r[3] = r[12] ^ r[0];
+    r[0] = r[8] & POOL[720];
+    r[12] = r[7] ^ POOL[721];
+    r[7] = r[12] ^ POOL[722];
+    r[12] = r[7] ^ POOL[723];
+    r[7] = r[9] ^ POOL[724];
+    r[9] = {
+        let (t, s) = (POOL[725], POOL[726]);
+        let (ma, mb) = (t ^ s ^ POOL[727], t ^ s ^ POOL[728]);
+        let mut z = t;
+        z ^= r[12] & mb;
+        z ^= r[7] & ma;
+        z ^= r[12] & r[7];
+        z
+    };
+    r[7] = r[0] ^ r[9];
+    r[9] = r[7].rotate_left(1);
+    r[7] = r[11] & POOL[729];
+    r[0] = r[7] ^ r[9];
+    r[12] = r[3] ^ r[0];
+    r[0] = r[8] & POOL[730];
+    r[3] = r[7] ^ POOL[731];
+    r[7] = r[3] ^ POOL[732];
+    r[3] = r[7] ^ POOL[733];
+    r[7] = r[9] ^ POOL[734];
+    r[9] = {
+        let (t, s) = (POOL[735], POOL[736]);
+        let (ma, mb) = (t ^ s ^ POOL[737], t ^ s ^ POOL[738]);
+        let mut z = t;
+        z ^= r[3] & mb;
+        z ^= r[7] & ma;
+        z ^= r[3] & r[7];
+        z
+    };
+    r[7] = r[0] ^ r[9];
+    r[9] = r[7].rotate_left(1);
+    r[7] = r[11] & POOL[739];
+    r[0] = r[7] ^ r[9];
+    r[3] = r[12] ^ r[0];
+    r[0] = r[8] & POOL[740];
+    r[12] = r[7] ^ POOL[741];
+    r[7] = r[12] ^ POOL[742];
+    r[12] = r[7] ^ POOL[743];
+    r[7] = r[9] ^ POOL[744];
+    r[9] = {
+        let (t, s) = (POOL[745], POOL[746]);
+        let (ma, mb) = (t ^ s ^ POOL[747], t ^ s ^ POOL[748]);
+        let mut z = t;
+        z ^= r[12] & mb;
+        z ^= r[7] & ma;
+        z ^= r[12] & r[7];
+        z
+    };
+    r[7] = r[0] ^ r[9];
+    r[9] = r[7].rotate_left(1);
+    r[7] = r[11] & POOL[749];
+    r[0] = r[7] ^ r[9];
+    r[12] = r[3] ^ r[0];
+    r[0] = r[8] & POOL[750];
+    r[3] = r[7] ^ POOL[751];
+    r[7] = r[3] ^ POOL[752];
+    r[3] = r[7] ^ POOL[753];
+    r[7] = r[9] ^ POOL[754];
+    r[9] = {
+        let (t, s) = (POOL[755], POOL[756]);
+        let (ma, mb) = (t ^ s ^ POOL[757], t ^ s ^ POOL[758]);
+        let mut z = t;
+        z ^= r[3] & mb;
+        z ^= r[7] & ma;
+        z ^= r[3] & r[7];
+        z
+    };
+    r[7] = r[0] ^ r[9];
+    r[9] = r[7].rotate_left(1);
+    r[7] = r[11] & POOL[759];
+    r[0] = r[7] ^ r[9];
+    r[3] = r[12] ^ r[0];
+    r[0] = r[8] & POOL[760];
+    r[12] = r[7] ^ POOL[761];
+    r[7] = r[12] ^ POOL[762];
+    r[12] = r[7] ^ POOL[763];
+    r[7] = r[9] ^ POOL[764];
+    r[9] = {
+        let (t, s) = (POOL[765], POOL[766]);
+        let (ma, mb) = (t ^ s ^ POOL[767], t ^ s ^ POOL[768]);
+        let mut z = t;
+        z ^= r[12] & mb;
+        z ^= r[7] & ma;
+        z ^= r[12] & r[7];
+        z
+    };
+    r[7] = r[0] ^ r[9];
+    r[9] = r[7].rotate_left(1);
+    r[7] = r[11] & POOL[769];
+    r[0] = r[7] ^ r[9];
+    r[12] = r[3] ^ r[0];
+    r[0] = r[8] & POOL[770];
+    r[3] = r[7] ^ POOL[771];
+    r[7] = r[3] ^ POOL[772];
+    r[3] = r[7] ^ POOL[773];
+    r[7] = r[9] ^ POOL[774];
+    r[9] = {
+        let (t, s) = (POOL[775], POOL[776]);
+        let (ma, mb) = (t ^ s ^ POOL[777], t ^ s ^ POOL[778]);
+        let mut z = t;
+        z ^= r[3] & mb;
+        z ^= r[7] & ma;
+        z ^= r[3] & r[7];
+        z
+    };
+    r[7] = r[0] ^ r[9];
+    r[9] = r[7].rotate_left(1);
+    r[7] = r[11] & POOL[779];
+    r[0] = r[7] ^ r[9];
+    r[3] = r[12] ^ r[0];
+    r[0] = r[8] & POOL[780];
+    r[12] = r[7] ^ POOL[781];
+    r[7] = r[12] ^ POOL[782];
+    r[12] = r[7] ^ POOL[783];
+    r[7] = r[9] ^ POOL[784];
+    r[9] = {
+        let (t, s) = (POOL[785], POOL[786]);
+        let (ma, mb) = (t ^ s ^ POOL[787], t ^ s ^ POOL[788]);
+        let mut z = t;
+        z ^= r[12] & mb;
+        z ^= r[7] & ma;
+        z ^= r[12] & r[7];
+        z
+    };
+    r[7] = r[0] ^ r[9];
+    r[9] = r[7].rotate_left(1);
+    r[7] = r[11] & POOL[789];
+    r[0] = r[7] ^ r[9];
+    r[12] = r[3] ^ r[0];
+    r[0] = r[8] & POOL[790];
+    r[3] = r[7] ^ POOL[791];
+    r[7] = r[3] ^ POOL[792];
+    r[3] = r[7] ^ POOL[793];
+    r[7] = r[9] ^ POOL[794];
+    r[9] = {
+        let (t, s) = (POOL[795], POOL[796]);
+        let (ma, mb) = (t ^ s ^ POOL[797], t ^ s ^ POOL[798]);
+        let mut z = t;
+        z ^= r[3] & mb;
+        z ^= r[7] & ma;
+        z ^= r[3] & r[7];
+        z
+    };
+    r[7] = r[0] ^ r[9];
+    r[9] = r[7].rotate_left(1);
+    r[7] = r[11] & POOL[799];
+    r[0] = r[7] ^ r[9];
+    r[3] = r[12] ^ r[0];
+    r[0] = r[8] & POOL[800];
+    r[12] = r[7] ^ POOL[801];
+    r[7] = r[12] ^ POOL[802];
+    r[12] = r[7] ^ POOL[803];
+    r[7] = r[9] ^ POOL[804];
+    r[9] = {
+        let (t, s) = (POOL[805], POOL[806]);
+        let (ma, mb) = (t ^ s ^ POOL[807], t ^ s ^ POOL[808]);
+        let mut z = t;
+        z ^= r[12] & mb;
+        z ^= r[7] & ma;
+        z ^= r[12] & r[7];
+        z
+    };
+    r[7] = r[0] ^ r[9];
+    r[9] = r[7].rotate_left(1);
+    r[7] = r[11] & POOL[809];
+    r[0] = r[7] ^ r[9];
+    r[12] = r[3] ^ r[0];
+    r[0] = r[8] & POOL[810];
+    r[3] = r[7] ^ POOL[811];
+    r[7] = r[3] ^ POOL[812];
+    r[3] = r[7] ^ POOL[813];
+    r[7] = r[9] ^ POOL[814];
+    r[9] = {
+        let (t, s) = (POOL[815], POOL[816]);
+        let (ma, mb) = (t ^ s ^ POOL[817], t ^ s ^ POOL[818]);
+        let mut z = t;
+        z ^= r[3] & mb;
+        z ^= r[7] & ma;
+        z ^= r[3] & r[7];
+        z
+    };
+    r[7] = r[0] ^ r[9];
+    r[9] = r[7].rotate_left(1);
+    r[7] = r[11] & POOL[819];
+    r[0] = r[7] ^ r[9];
+    r[3] = r[12] ^ r[0];
+    r[0] = r[8] & POOL[820];
+    r[12] = r[7] ^ POOL[821];
+    r[7] = r[12] ^ POOL[822];
+    r[12] = r[7] ^ POOL[823];
+    r[7] = r[9] ^ POOL[824];
+    r[9] = {
+        let (t, s) = (POOL[825], POOL[826]);
+        let (ma, mb) = (t ^ s ^ POOL[827], t ^ s ^ POOL[828]);
+        let mut z = t;
+        z ^= r[12] & mb;
+        z ^= r[7] & ma;
+        z ^= r[12] & r[7];
+        z
+    };
+    r[7] = r[0] ^ r[9];
+    r[9] = r[7].rotate_left(1);
+    r[7] = r[11] & POOL[829];
+    r[0] = r[7] ^ r[9];
+    r[12] = r[3] ^ r[0];
+    r[0] = r[8] & POOL[830];
+    r[3] = r[7] ^ POOL[831];
+    r[7] = r[3] ^ POOL[832];
+    r[3] = r[7] ^ POOL[833];
+    r[7] = r[9] ^ POOL[834];
+    r[9] = {
+        let (t, s) = (POOL[835], POOL[836]);
+        let (ma, mb) = (t ^ s ^ POOL[837], t ^ s ^ POOL[838]);
+        let mut z = t;
+        z ^= r[3] & mb;
+        z ^= r[7] & ma;
+        z ^= r[3] & r[7];
+        z
+    };
+    r[7] = r[0] ^ r[9];
+    r[9] = r[7].rotate_left(1);
+    r[7] = r[11] & POOL[839];
+    r[0] = r[7] ^ r[9];
+    r[3] = r[12] ^ r[0];
+    r[0] = r[8] & POOL[840];
+    r[12] = r[7] ^ POOL[841];
+    r[7] = r[12] ^ POOL[842];
+    r[12] = r[7] ^ POOL[843];
+    r[7] = r[9] ^ POOL[844];
+    r[9] = {
+        let (t, s) = (POOL[845], POOL[846]);
+        let (ma, mb) = (t ^ s ^ POOL[847], t ^ s ^ POOL[848]);
+        let mut z = t;
+        z ^= r[12] & mb;
+        z ^= r[7] & ma;
+        z ^= r[12] & r[7];
+        z
+    };
+    r[7] = r[0] ^ r[9];
+    r[9] = r[7].rotate_left(1);
+    r[7] = r[11] & POOL[849];
+    r[0] = r[7] ^ r[9];
+    r[12] = r[3] ^ r[0];
+    r[0] = r[8] & POOL[850];
+    r[3] = r[7] ^ POOL[851];
+    r[7] = r[3] ^ POOL[852];
+    r[3] = r[7] ^ POOL[853];
+    r[7] = r[9] ^ POOL[854];
+    r[9] = {
+        let (t, s) = (POOL[855], POOL[856]);
+        let (ma, mb) = (t ^ s ^ POOL[857], t ^ s ^ POOL[858]);
+        let mut z = t;
+        z ^= r[3] & mb;
+        z ^= r[7] & ma;
+        z ^= r[3] & r[7];
+        z
+    };
+    r[7] = r[0] ^ r[9];
+    r[9] = r[7].rotate_left(1);
+    r[7] = r[11] & POOL[859];
+    r[0] = r[7] ^ r[9];
+    r[3] = r[12] ^ r[0];
+    r[0] = r[8] & POOL[860];
+    r[12] = r[7] ^ POOL[861];
+    r[7] = r[12] ^ POOL[862];
+    r[12] = r[7] ^ POOL[863];
+    r[7] = r[9] ^ POOL[864];
+    r[9] = {
+        let (t, s) = (POOL[865], POOL[866]);
+        let (ma, mb) = (t ^ s ^ POOL[867], t ^ s ^ POOL[868]);
+        let mut z = t;
+        z ^= r[12] & mb;
+        z ^= r[7] & ma;
+        z ^= r[12] & r[7];
+        z
+    };
+    r[7] = r[0] ^ r[9];
+    r[9] = r[7].rotate_left(1);
+    r[7] = r[11] & POOL[869];
+    r[0] = r[7] ^ r[9];
+    r[12] = r[3] ^ r[0];
+    r[0] = r[8] & POOL[870];
+    r[3] = r[7] ^ POOL[871];
+    r[7] = r[3] ^ POOL[872];
+    r[3] = r[7] ^ POOL[873];
+    r[7] = r[9] ^ POOL[874];
+    r[9] = {
+        let (t, s) = (POOL[875], POOL[876]);
+        let (ma, mb) = (t ^ s ^ POOL[877], t ^ s ^ POOL[878]);
+        let mut z = t;
+        z ^= r[3] & mb;
+        z ^= r[7] & ma;
+        z ^= r[3] & r[7];
+        z
+    };
+    r[7] = r[0] ^ r[9];
+    r[9] = r[7].rotate_left(1);
+    r[7] = r[11] & POOL[879];
+    r[0] = r[7] ^ r[9];
+    r[3] = r[12] ^ r[0];
+    r[0] = r[8] & POOL[880];
+    r[12] = r[7] ^ POOL[881];
+    r[7] = r[12] ^ POOL[882];
+    r[12] = r[7] ^ POOL[883];
+    r[7] = r[9] ^ POOL[884];
+    r[9] = {
+        let (t, s) = (POOL[885], POOL[886]);
+        let (ma, mb) = (t ^ s ^ POOL[887], t ^ s ^ POOL[888]);
+        let mut z = t;
+        z ^= r[12] & mb;
+        z ^= r[7] & ma;
+        z ^= r[12] & r[7];
+        z
+    };
+    r[7] = r[0] ^ r[9];
+    r[9] = r[7].rotate_left(1);
+    r[7] = r[11] & POOL[889];
+    r[0] = r[7] ^ r[9];
+    r[12] = r[3] ^ r[0];
+    r[0] = r[8] & POOL[890];
+    r[3] = r[7] ^ POOL[891];
+    r[7] = r[3] ^ POOL[892];
+    r[3] = r[7] ^ POOL[893];
+    r[7] = r[9] ^ POOL[894];
+    r[9] = {
+        let (t, s) = (POOL[895], POOL[896]);
+        let (ma, mb) = (t ^ s ^ POOL[897], t ^ s ^ POOL[898]);
+        let mut z = t;
+        z ^= r[3] & mb;
+        z ^= r[7] & ma;
+        z ^= r[3] & r[7];
+        z
+    };
+    r[7] = r[0] ^ r[9];
+    r[9] = r[7].rotate_left(1);
+    r[7] = r[11] & POOL[899];
+    r[0] = r[7] ^ r[9];
+    r[3] = r[12] ^ r[0];
+    r[0] = r[8] & POOL[900];
+    r[12] = r[7] ^ POOL[901];
+    r[7] = r[12] ^ POOL[902];
+    r[12] = r[7] ^ POOL[903];
+    r[7] = r[9] ^ POOL[904];
+    r[9] = {
+        let (t, s) = (POOL[905], POOL[906]);
+        let (ma, mb) = (t ^ s ^ POOL[907], t ^ s ^ POOL[908]);
+        let mut z = t;
+        z ^= r[12] & mb;
+        z ^= r[7] & ma;
+        z ^= r[12] & r[7];
+        z
+    };
+    r[7] = r[0] ^ r[9];
+    r[9] = r[7].rotate_left(1);
+    r[7] = r[11] & POOL[909];
+    r[0] = r[7] ^ r[9];
+    r[12] = r[3] ^ r[0];
+    r[0] = r[8] & POOL[910];
+    r[3] = r[7] ^ POOL[911];
+    r[7] = r[3] ^ POOL[912];
+    r[3] = r[7] ^ POOL[913];
+    r[7] = r[9] ^ POOL[914];
+    r[9] = {
+        let (t, s) = (POOL[915], POOL[916]);
+        let (ma, mb) = (t ^ s ^ POOL[917], t ^ s ^ POOL[918]);
+        let mut z = t;
+        z ^= r[3] & mb;
+        z ^= r[7] & ma;
+        z ^= r[3] & r[7];
+        z
+    };
+    r[7] = r[0] ^ r[9];
+    r[9] = r[7].rotate_left(1);
+    r[7] = r[11] & POOL[919];
+    r[0] = r[7] ^ r[9];
+    r[3] = r[12] ^ r[0];
+    r[0] = r[8] & POOL[920];
+    r[12] = r[7] ^ POOL[921];
+    r[7] = r[12] ^ POOL[922];
+    r[12] = r[7] ^ POOL[923];
+    r[7] = r[9] ^ POOL[924];
+    r[9] = {
+        let (t, s) = (POOL[925], POOL[926]);
+        let (ma, mb) = (t ^ s ^ POOL[927], t ^ s ^ POOL[928]);
+        let mut z = t;
+        z ^= r[12] & mb;
+        z ^= r[7] & ma;
+        z ^= r[12] & r[7];
+        z
+    };
+    r[7] = r[0] ^ r[9];
+    r[9] = r[7].rotate_left(1);
+    r[7] = r[11] & POOL[929];
+    r[0] = r[7] ^ r[9];
+    r[12] = r[3] ^ r[0];
+    r[0] = r[8] & POOL[930];
+    r[8] = r[7] ^ POOL[931];
+    r[7] = r[8] ^ POOL[932];
+    r[8] = r[7] ^ POOL[933];
+    r[7] = r[9] ^ POOL[934];
+    r[9] = {
+        let (t, s) = (POOL[935], POOL[936]);
+        let (ma, mb) = (t ^ s ^ POOL[937], t ^ s ^ POOL[938]);
+        let mut z = t;
+        z ^= r[8] & mb;
+        z ^= r[7] & ma;
+        z ^= r[8] & r[7];
+        z
+    };
+    r[7] = r[0] ^ r[9];
+    r[9] = r[7].rotate_left(1);
+    r[7] = r[11] & POOL[939];
+    r[11] = r[7] ^ r[9];
+    r[9] = r[12] ^ r[11];
+    r[11] = u32::from_le_bytes([digest[16], digest[17], digest[18], digest[19]]) ^ POOL[940];
+    r[7] = u32::from_le_bytes([digest[20], digest[21], digest[22], digest[23]]) ^ POOL[941];
+    r[8] = r[11] ^ POOL[942];
+    r[0] = r[8] ^ POOL[943];
+    r[8] = r[7] ^ POOL[944];
+    r[12] = r[8] ^ POOL[945];
+    r[8] = {
+        let (t, s) = (POOL[946], POOL[947]);
+        let (ma, mb) = (t ^ s ^ POOL[948], t ^ s ^ POOL[949]);
+        let mut z = t;
+        z ^= r[0] & mb;
+        z ^= r[12] & ma;
+        z ^= r[0] & r[12];
+        z
+    };
+    r[0] = r[11] ^ r[7];
+    r[11] = r[0] & POOL[950];
+    r[12] = r[8] & POOL[951];
+    r[3] = r[12].rotate_left(1);
+    r[12] = r[0] & POOL[952];
+    r[6] = r[12] ^ r[3];
+    r[13] = r[11] ^ r[6];
+    r[11] = r[8] & POOL[953];
+    r[6] = r[12] ^ POOL[954];
+    r[12] = r[6] ^ POOL[955];
+    r[6] = r[12] ^ POOL[956];
+    r[12] = r[3] ^ POOL[957];
+    r[3] = r[12] ^ POOL[958];
+    r[12] = r[3] ^ POOL[959];
+    r[3] = {
+        let (t, s) = (POOL[960], POOL[961]);
+        let (ma, mb) = (t ^ s ^ POOL[962], t ^ s ^ POOL[963]);
+        let mut z = t;
+        z ^= r[6] & mb;
+        z ^= r[12] & ma;
+        z ^= r[6] & r[12];
+        z
+    };
+    r[12] = r[11] ^ r[3];
+    r[11] = r[12].rotate_left(1);
+    r[12] = r[0] & POOL[964];
+    r[3] = r[12] ^ r[11];
+    r[6] = r[13] ^ r[3];
+    r[3] = r[8] & POOL[965];
+    r[13] = r[12] ^ POOL[966];
+    r[12] = r[13] ^ POOL[967];
+    r[13] = r[12] ^ POOL[968];
+    r[12] = r[11] ^ POOL[969];
+    r[11] = {
+        let (t, s) = (POOL[970], POOL[971]);
+        let (ma, mb) = (t ^ s ^ POOL[972], t ^ s ^ POOL[973]);
+        let mut z = t;
+        z ^= r[13] & mb;
+        z ^= r[12] & ma;
+        z ^= r[13] & r[12];
+        z
+    };
+    r[12] = r[3] ^ r[11];
+    r[11] = r[12].rotate_left(1);
+    r[12] = r[0] & POOL[974];
+    r[3] = r[12] ^ r[11];
+    r[13] = r[6] ^ r[3];
+    r[3] = r[8] & POOL[975];
+    r[6] = r[12] ^ POOL[976];
+    r[12] = r[6] ^ POOL[977];
+    r[6] = r[12] ^ POOL[978];
+    r[12] = r[11] ^ POOL[979];
+    r[11] = {
+        let (t, s) = (POOL[980], POOL[981]);
+        let (ma, mb) = (t ^ s ^ POOL[982], t ^ s ^ POOL[983]);
+        let mut z = t;
+        z ^= r[6] & mb;
+        z ^= r[12] & ma;
+        z ^= r[6] & r[12];
+        z
+    };
+    r[12] = r[3] ^ r[11];
+    r[11] = r[12].rotate_left(1);
+    r[12] = r[0] & POOL[984];
+    r[3] = r[12] ^ r[11];
+    r[6] = r[13] ^ r[3];
+    r[3] = r[8] & POOL[985];
+    r[13] = r[12] ^ POOL[986];
+    r[12] = r[13] ^ POOL[987];
+    r[13] = r[12] ^ POOL[988];
+    r[12] = r[11] ^ POOL[989];
+    r[11] = {
+        let (t, s) = (POOL[990], POOL[991]);
+        let (ma, mb) = (t ^ s ^ POOL[992], t ^ s ^ POOL[993]);
+        let mut z = t;
+        z ^= r[13] & mb;
+        z ^= r[12] & ma;
+        z ^= r[13] & r[12];
+        z
+    };
+    r[12] = r[3] ^ r[11];
+    r[11] = r[12].rotate_left(1);
+    r[12] = r[0] & POOL[994];
+    r[3] = r[12] ^ r[11];
+    r[13] = r[6] ^ r[3];
+    r[3] = r[8] & POOL[995];
+    r[6] = r[12] ^ POOL[996];
+    r[12] = r[6] ^ POOL[997];
+    r[6] = r[12] ^ POOL[998];
+    r[12] = r[11] ^ POOL[999];
+    r[11] = {
+        let (t, s) = (POOL[1000], POOL[1001]);
+        let (ma, mb) = (t ^ s ^ POOL[1002], t ^ s ^ POOL[1003]);
+        let mut z = t;
+        z ^= r[6] & mb;
+        z ^= r[12] & ma;
+        z ^= r[6] & r[12];
+        z
+    };
+    r[12] = r[3] ^ r[11];
+    r[11] = r[12].rotate_left(1);
+    r[12] = r[0] & POOL[1004];
+    r[3] = r[12] ^ r[11];
+    r[6] = r[13] ^ r[3];
+    r[3] = r[8] & POOL[1005];
+    r[13] = r[12] ^ POOL[1006];
+    r[12] = r[13] ^ POOL[1007];
+    r[13] = r[12] ^ POOL[1008];
+    r[12] = r[11] ^ POOL[1009];
+    r[11] = {
+        let (t, s) = (POOL[1010], POOL[1011]);
+        let (ma, mb) = (t ^ s ^ POOL[1012], t ^ s ^ POOL[1013]);
+        let mut z = t;
+        z ^= r[13] & mb;
+        z ^= r[12] & ma;
+        z ^= r[13] & r[12];
+        z
+    };
+    r[12] = r[3] ^ r[11];
+    r[11] = r[12].rotate_left(1);
+    r[12] = r[0] & POOL[1014];
+    r[3] = r[12] ^ r[11];
+    r[13] = r[6] ^ r[3];
+    r[3] = r[8] & POOL[1015];
+    r[6] = r[12] ^ POOL[1016];
+    r[12] = r[6] ^ POOL[1017];
+    r[6] = r[12] ^ POOL[1018];
+    r[12] = r[11] ^ POOL[1019];
+    r[11] = {
+        let (t, s) = (POOL[1020], POOL[1021]);
+        let (ma, mb) = (t ^ s ^ POOL[1022], t ^ s ^ POOL[1023]);
+        let mut z = t;
+        z ^= r[6] & mb;
+        z ^= r[12] & ma;
+        z ^= r[6] & r[12];
+        z
+    };
+    r[12] = r[3] ^ r[11];
+    r[11] = r[12].rotate_left(1);
+    r[12] = r[0] & POOL[1024];
+    r[3] = r[12] ^ r[11];
+    r[6] = r[13] ^ r[3];
+    r[3] = r[8] & POOL[1025];
+    r[13] = r[12] ^ POOL[1026];
+    r[12] = r[13] ^ POOL[1027];
+    r[13] = r[12] ^ POOL[1028];
+    r[12] = r[11] ^ POOL[1029];
+    r[11] = {
+        let (t, s) = (POOL[1030], POOL[1031]);
+        let (ma, mb) = (t ^ s ^ POOL[1032], t ^ s ^ POOL[1033]);
+        let mut z = t;
+        z ^= r[13] & mb;
+        z ^= r[12] & ma;
+        z ^= r[13] & r[12];
+        z
+    };
+    r[12] = r[3] ^ r[11];
+    r[11] = r[12].rotate_left(1);
+    r[12] = r[0] & POOL[1034];
+    r[3] = r[12] ^ r[11];
+    r[13] = r[6] ^ r[3];
+    r[3] = r[8] & POOL[1035];
+    r[6] = r[12] ^ POOL[1036];
+    r[12] = r[6] ^ POOL[1037];
+    r[6] = r[12] ^ POOL[1038];
+    r[12] = r[11] ^ POOL[1039];
+    r[11] = {
+        let (t, s) = (POOL[1040], POOL[1041]);
+        let (ma, mb) = (t ^ s ^ POOL[1042], t ^ s ^ POOL[1043]);
+        let mut z = t;
+        z ^= r[6] & mb;
+        z ^= r[12] & ma;
+        z ^= r[6] & r[12];
+        z
+    };
+    r[12] = r[3] ^ r[11];
+    r[11] = r[12].rotate_left(1);
+    r[12] = r[0] & POOL[1044];
+    r[3] = r[12] ^ r[11];
+    r[6] = r[13] ^ r[3];
+    r[3] = r[8] & POOL[1045];
+    r[13] = r[12] ^ POOL[1046];
+    r[12] = r[13] ^ POOL[1047];
+    r[13] = r[12] ^ POOL[1048];
+    r[12] = r[11] ^ POOL[1049];
+    r[11] = {
+        let (t, s) = (POOL[1050], POOL[1051]);
+        let (ma, mb) = (t ^ s ^ POOL[1052], t ^ s ^ POOL[1053]);
+        let mut z = t;
+        z ^= r[13] & mb;
+        z ^= r[12] & ma;
+        z ^= r[13] & r[12];
+        z
+    };
+    r[12] = r[3] ^ r[11];
+    r[11] = r[12].rotate_left(1);
+    r[12] = r[0] & POOL[1054];
+    r[3] = r[12] ^ r[11];
+    r[13] = r[6] ^ r[3];
+    r[3] = r[8] & POOL[1055];
+    r[6] = r[12] ^ POOL[1056];
+    r[12] = r[6] ^ POOL[1057];
+    r[6] = r[12] ^ POOL[1058];
+    r[12] = r[11] ^ POOL[1059];
+    r[11] = {
+        let (t, s) = (POOL[1060], POOL[1061]);
+        let (ma, mb) = (t ^ s ^ POOL[1062], t ^ s ^ POOL[1063]);
+        let mut z = t;
+        z ^= r[6] & mb;
+        z ^= r[12] & ma;
+        z ^= r[6] & r[12];
+        z
+    };
+    r[12] = r[3] ^ r[11];
+    r[11] = r[12].rotate_left(1);
+    r[12] = r[0] & POOL[1064];
+    r[3] = r[12] ^ r[11];
+    r[6] = r[13] ^ r[3];
+    r[3] = r[8] & POOL[1065];
+    r[13] = r[12] ^ POOL[1066];
+    r[12] = r[13] ^ POOL[1067];
+    r[13] = r[12] ^ POOL[1068];
+    r[12] = r[11] ^ POOL[1069];
+    r[11] = {
+        let (t, s) = (POOL[1070], POOL[1071]);
+        let (ma, mb) = (t ^ s ^ POOL[1072], t ^ s ^ POOL[1073]);
+        let mut z = t;
+        z ^= r[13] & mb;
+        z ^= r[12] & ma;
+        z ^= r[13] & r[12];
+        z
+    };
+    r[12] = r[3] ^ r[11];
+    r[11] = r[12].rotate_left(1);
+    r[12] = r[0] & POOL[1074];
+    r[3] = r[12] ^ r[11];
+    r[13] = r[6] ^ r[3];
+    r[3] = r[8] & POOL[1075];
+    r[6] = r[12] ^ POOL[1076];
+    r[12] = r[6] ^ POOL[1077];
+    r[6] = r[12] ^ POOL[1078];
+    r[12] = r[11] ^ POOL[1079];
+    r[11] = {
+        let (t, s) = (POOL[1080], POOL[1081]);
+        let (ma, mb) = (t ^ s ^ POOL[1082], t ^ s ^ POOL[1083]);
+        let mut z = t;
+        z ^= r[6] & mb;
+        z ^= r[12] & ma;
+        z ^= r[6] & r[12];
+        z
+    };
+    r[12] = r[3] ^ r[11];
+    r[11] = r[12].rotate_left(1);
+    r[12] = r[0] & POOL[1084];
+    r[3] = r[12] ^ r[11];
+    r[6] = r[13] ^ r[3];
+    r[3] = r[8] & POOL[1085];
+    r[13] = r[12] ^ POOL[1086];
+    r[12] = r[13] ^ POOL[1087];
+    r[13] = r[12] ^ POOL[1088];
+    r[12] = r[11] ^ POOL[1089];
+    r[11] = {
+        let (t, s) = (POOL[1090], POOL[1091]);
+        let (ma, mb) = (t ^ s ^ POOL[1092], t ^ s ^ POOL[1093]);
+        let mut z = t;
+        z ^= r[13] & mb;
+        z ^= r[12] & ma;
+        z ^= r[13] & r[12];
+        z
+    };
+    r[12] = r[3] ^ r[11];
+    r[11] = r[12].rotate_left(1);
+    r[12] = r[0] & POOL[1094];
+    r[3] = r[12] ^ r[11];
+    r[13] = r[6] ^ r[3];
+    r[3] = r[8] & POOL[1095];
+    r[6] = r[12] ^ POOL[1096];
+    r[12] = r[6] ^ POOL[1097];
+    r[6] = r[12] ^ POOL[1098];
+    r[12] = r[11] ^ POOL[1099];
+    r[11] = {
+        let (t, s) = (POOL[1100], POOL[1101]);
+        let (ma, mb) = (t ^ s ^ POOL[1102], t ^ s ^ POOL[1103]);
+        let mut z = t;
+        z ^= r[6] & mb;
+        z ^= r[12] & ma;
+        z ^= r[6] & r[12];
+        z
+    };
+    r[12] = r[3] ^ r[11];
+    r[11] = r[12].rotate_left(1);
+    r[12] = r[0] & POOL[1104];
+    r[3] = r[12] ^ r[11];
+    r[6] = r[13] ^ r[3];
+    r[3] = r[8] & POOL[1105];
+    r[13] = r[12] ^ POOL[1106];
+    r[12] = r[13] ^ POOL[1107];
+    r[13] = r[12] ^ POOL[1108];
+    r[12] = r[11] ^ POOL[1109];
+    r[11] = {
+        let (t, s) = (POOL[1110], POOL[1111]);
+        let (ma, mb) = (t ^ s ^ POOL[1112], t ^ s ^ POOL[1113]);
+        let mut z = t;
+        z ^= r[13] & mb;
+        z ^= r[12] & ma;
+        z ^= r[13] & r[12];
+        z
+    };
+    r[12] = r[3] ^ r[11];
+    r[11] = r[12].rotate_left(1);
+    r[12] = r[0] & POOL[1114];
+    r[3] = r[12] ^ r[11];
+    r[13] = r[6] ^ r[3];
+    r[3] = r[8] & POOL[1115];
+    r[6] = r[12] ^ POOL[1116];
+    r[12] = r[6] ^ POOL[1117];
+    r[6] = r[12] ^ POOL[1118];
+    r[12] = r[11] ^ POOL[1119];
+    r[11] = {
+        let (t, s) = (POOL[1120], POOL[1121]);
+        let (ma, mb) = (t ^ s ^ POOL[1122], t ^ s ^ POOL[1123]);
+        let mut z = t;
+        z ^= r[6] & mb;
+        z ^= r[12] & ma;
+        z ^= r[6] & r[12];
+        z
+    };
+    r[12] = r[3] ^ r[11];
+    r[11] = r[12].rotate_left(1);
+    r[12] = r[0] & POOL[1124];
+    r[3] = r[12] ^ r[11];
+    r[6] = r[13] ^ r[3];
+    r[3] = r[8] & POOL[1125];
+    r[13] = r[12] ^ POOL[1126];
+    r[12] = r[13] ^ POOL[1127];
+    r[13] = r[12] ^ POOL[1128];
+    r[12] = r[11] ^ POOL[1129];
+    r[11] = {
+        let (t, s) = (POOL[1130], POOL[1131]);
+        let (ma, mb) = (t ^ s ^ POOL[1132], t ^ s ^ POOL[1133]);
+        let mut z = t;
+        z ^= r[13] & mb;
+        z ^= r[12] & ma;
+        z ^= r[13] & r[12];
+        z
+    };
+    r[12] = r[3] ^ r[11];
+    r[11] = r[12].rotate_left(1);
+    r[12] = r[0] & POOL[1134];
+    r[3] = r[12] ^ r[11];
+    r[13] = r[6] ^ r[3];
+    r[3] = r[8] & POOL[1135];
+    r[6] = r[12] ^ POOL[1136];
+    r[12] = r[6] ^ POOL[1137];
+    r[6] = r[12] ^ POOL[1138];
+    r[12] = r[11] ^ POOL[1139];
+    r[11] = {
+        let (t, s) = (POOL[1140], POOL[1141]);
+        let (ma, mb) = (t ^ s ^ POOL[1142], t ^ s ^ POOL[1143]);
+        let mut z = t;
+        z ^= r[6] & mb;
+        z ^= r[12] & ma;
+        z ^= r[6] & r[12];
+        z
+    };
+    r[12] = r[3] ^ r[11];
+    r[11] = r[12].rotate_left(1);
+    r[12] = r[0] & POOL[1144];
+    r[3] = r[12] ^ r[11];
+    r[6] = r[13] ^ r[3];
+    r[3] = r[8] & POOL[1145];
+    r[13] = r[12] ^ POOL[1146];
+    r[12] = r[13] ^ POOL[1147];
+    r[13] = r[12] ^ POOL[1148];
+    r[12] = r[11] ^ POOL[1149];
+    r[11] = {
+        let (t, s) = (POOL[1150], POOL[1151]);
+        let (ma, mb) = (t ^ s ^ POOL[1152], t ^ s ^ POOL[1153]);
+        let mut z = t;
+        z ^= r[13] & mb;
+        z ^= r[12] & ma;
+        z ^= r[13] & r[12];
+        z
+    };
+    r[12] = r[3] ^ r[11];
+    r[11] = r[12].rotate_left(1);
+    r[12] = r[0] & POOL[1154];
+    r[3] = r[12] ^ r[11];
+    r[13] = r[6] ^ r[3];
+    r[3] = r[8] & POOL[1155];
+    r[6] = r[12] ^ POOL[1156];
+    r[12] = r[6] ^ POOL[1157];
+    r[6] = r[12] ^ POOL[1158];
+    r[12] = r[11] ^ POOL[1159];
+    r[11] = {
+        let (t, s) = (POOL[1160], POOL[1161]);
+        let (ma, mb) = (t ^ s ^ POOL[1162], t ^ s ^ POOL[1163]);
+        let mut z = t;
+        z ^= r[6] & mb;
+        z ^= r[12] & ma;
+        z ^= r[6] & r[12];
+        z
+    };
+    r[12] = r[3] ^ r[11];
+    r[11] = r[12].rotate_left(1);
+    r[12] = r[0] & POOL[1164];
+    r[3] = r[12] ^ r[11];
+    r[6] = r[13] ^ r[3];
+    r[3] = r[8] & POOL[1165];
+    r[13] = r[12] ^ POOL[1166];
+    r[12] = r[13] ^ POOL[1167];
+    r[13] = r[12] ^ POOL[1168];
+    r[12] = r[11] ^ POOL[1169];
+    r[11] = {
+        let (t, s) = (POOL[1170], POOL[1171]);
+        let (ma, mb) = (t ^ s ^ POOL[1172], t ^ s ^ POOL[1173]);
+        let mut z = t;
+        z ^= r[13] & mb;
+        z ^= r[12] & ma;
+        z ^= r[13] & r[12];
+        z
+    };
+    r[12] = r[3] ^ r[11];
+    r[11] = r[12].rotate_left(1);
+    r[12] = r[0] & POOL[1174];
+    r[3] = r[12] ^ r[11];
+    r[13] = r[6] ^ r[3];
+    r[3] = r[8] & POOL[1175];
+    r[6] = r[12] ^ POOL[1176];
+    r[12] = r[6] ^ POOL[1177];
+    r[6] = r[12] ^ POOL[1178];
+    r[12] = r[11] ^ POOL[1179];
+    r[11] = {
+        let (t, s) = (POOL[1180], POOL[1181]);
+        let (ma, mb) = (t ^ s ^ POOL[1182], t ^ s ^ POOL[1183]);
+        let mut z = t;
+        z ^= r[6] & mb;
+        z ^= r[12] & ma;
+        z ^= r[6] & r[12];
+        z
+    };
+    r[12] = r[3] ^ r[11];
+    r[11] = r[12].rotate_left(1);
+    r[12] = r[0] & POOL[1184];
+    r[3] = r[12] ^ r[11];
+    r[6] = r[13] ^ r[3];
+    r[3] = r[8] & POOL[1185];
+    r[13] = r[12] ^ POOL[1186];
+    r[12] = r[13] ^ POOL[1187];
+    r[13] = r[12] ^ POOL[1188];
+    r[12] = r[11] ^ POOL[1189];
+    r[11] = {
+        let (t, s) = (POOL[1190], POOL[1191]);
+        let (ma, mb) = (t ^ s ^ POOL[1192], t ^ s ^ POOL[1193]);
+        let mut z = t;
+        z ^= r[13] & mb;
+        z ^= r[12] & ma;
+        z ^= r[13] & r[12];
+        z
+    };
+    r[12] = r[3] ^ r[11];
+    r[11] = r[12].rotate_left(1);
+    r[12] = r[0] & POOL[1194];
+    r[3] = r[12] ^ r[11];
+    r[13] = r[6] ^ r[3];
+    r[3] = r[8] & POOL[1195];
+    r[6] = r[12] ^ POOL[1196];
+    r[12] = r[6] ^ POOL[1197];
+    r[6] = r[12] ^ POOL[1198];
+    r[12] = r[11] ^ POOL[1199];
+    r[11] = {
+        let (t, s) = (POOL[1200], POOL[1201]);
+        let (ma, mb) = (t ^ s ^ POOL[1202], t ^ s ^ POOL[1203]);
+        let mut z = t;
+        z ^= r[6] & mb;
+        z ^= r[12] & ma;
+        z ^= r[6] & r[12];
+        z
+    };
+    r[12] = r[3] ^ r[11];
+    r[11] = r[12].rotate_left(1);
+    r[12] = r[0] & POOL[1204];
+    r[3] = r[12] ^ r[11];
+    r[6] = r[13] ^ r[3];
+    r[3] = r[8] & POOL[1205];
+    r[13] = r[12] ^ POOL[1206];
+    r[12] = r[13] ^ POOL[1207];
+    r[13] = r[12] ^ POOL[1208];
+    r[12] = r[11] ^ POOL[1209];
+    r[11] = {
+        let (t, s) = (POOL[1210], POOL[1211]);
+        let (ma, mb) = (t ^ s ^ POOL[1212], t ^ s ^ POOL[1213]);
+        let mut z = t;
+        z ^= r[13] & mb;
+        z ^= r[12] & ma;
+        z ^= r[13] & r[12];
+        z
+    };
+    r[12] = r[3] ^ r[11];
+    r[11] = r[12].rotate_left(1);
+    r[12] = r[0] & POOL[1214];
+    r[3] = r[12] ^ r[11];
+    r[13] = r[6] ^ r[3];
+    r[3] = r[8] & POOL[1215];
+    r[6] = r[12] ^ POOL[1216];
+    r[12] = r[6] ^ POOL[1217];
+    r[6] = r[12] ^ POOL[1218];
+    r[12] = r[11] ^ POOL[1219];
+    r[11] = {
+        let (t, s) = (POOL[1220], POOL[1221]);
+        let (ma, mb) = (t ^ s ^ POOL[1222], t ^ s ^ POOL[1223]);
+        let mut z = t;
+        z ^= r[6] & mb;
+        z ^= r[12] & ma;
+        z ^= r[6] & r[12];
+        z
+    };
+    r[12] = r[3] ^ r[11];
+    r[11] = r[12].rotate_left(1);
+    r[12] = r[0] & POOL[1224];
+    r[3] = r[12] ^ r[11];
+    r[6] = r[13] ^ r[3];
+    r[3] = r[8] & POOL[1225];
+    r[13] = r[12] ^ POOL[1226];
+    r[12] = r[13] ^ POOL[1227];
+    r[13] = r[12] ^ POOL[1228];
+    r[12] = r[11] ^ POOL[1229];
+    r[11] = {
+        let (t, s) = (POOL[1230], POOL[1231]);
+        let (ma, mb) = (t ^ s ^ POOL[1232], t ^ s ^ POOL[1233]);
+        let mut z = t;
+        z ^= r[13] & mb;
+        z ^= r[12] & ma;
+        z ^= r[13] & r[12];
+        z
+    };
+    r[12] = r[3] ^ r[11];
+    r[11] = r[12].rotate_left(1);
+    r[12] = r[0] & POOL[1234];
+    r[3] = r[12] ^ r[11];
+    r[13] = r[6] ^ r[3];
+    r[3] = r[8] & POOL[1235];
+    r[6] = r[12] ^ POOL[1236];
+    r[12] = r[6] ^ POOL[1237];
+    r[6] = r[12] ^ POOL[1238];
+    r[12] = r[11] ^ POOL[1239];
+    r[11] = {
+        let (t, s) = (POOL[1240], POOL[1241]);
+        let (ma, mb) = (t ^ s ^ POOL[1242], t ^ s ^ POOL[1243]);
+        let mut z = t;
+        z ^= r[6] & mb;
+        z ^= r[12] & ma;
+        z ^= r[6] & r[12];
+        z
+    };
+    r[12] = r[3] ^ r[11];
+    r[11] = r[12].rotate_left(1);
+    r[12] = r[0] & POOL[1244];
+    r[3] = r[12] ^ r[11];
+    r[6] = r[13] ^ r[3];
+    r[3] = r[8] & POOL[1245];
+    r[8] = r[12] ^ POOL[1246];
+    r[12] = r[8] ^ POOL[1247];
+    r[8] = r[12] ^ POOL[1248];
+    r[12] = r[11] ^ POOL[1249];
+    r[11] = {
+        let (t, s) = (POOL[1250], POOL[1251]);
+        let (ma, mb) = (t ^ s ^ POOL[1252], t ^ s ^ POOL[1253]);
+        let mut z = t;
+        z ^= r[8] & mb;
+        z ^= r[12] & ma;
+        z ^= r[8] & r[12];
+        z
+    };
+    r[8] = r[3] ^ r[11];
+    r[11] = r[8].rotate_left(1);
+    r[8] = r[0] & POOL[1254];
+    r[0] = r[8] ^ r[11];
+    r[11] = r[6] ^ r[0];
+    r[8] = u32::from_le_bytes([digest[24], digest[25], digest[26], digest[27]]) ^ POOL[1255];
+    r[0] = u32::from_le_bytes([digest[28], digest[29], digest[30], digest[31]]) ^ POOL[1256];
+    r[12] = r[0] ^ r[11];
+    r[0] = r[12].rotate_left(16);
+    r[12] = r[8] ^ POOL[1257];
+    r[3] = r[12] ^ POOL[1258];
+    r[12] = r[0] ^ POOL[1259];
+    r[6] = {
+        let (t, s) = (POOL[1260], POOL[1261]);
+        let (ma, mb) = (t ^ s ^ POOL[1262], t ^ s ^ POOL[1263]);
+        let mut z = t;
+        z ^= r[3] & mb;
+        z ^= r[12] & ma;
+        z ^= r[3] & r[12];
+        z
+    };
+    r[12] = r[8] ^ r[0];
+    r[8] = r[12] & POOL[1264];
+    r[3] = r[6] & POOL[1265];
+    r[13] = r[3].rotate_left(1);
+    r[3] = r[12] & POOL[1266];
+    r[1] = r[3] ^ r[13];
+    r[10] = r[8] ^ r[1];
+    r[8] = r[6] & POOL[1267];
+    r[1] = r[3] ^ POOL[1268];
+    r[3] = r[1] ^ POOL[1269];
+    r[1] = r[3] ^ POOL[1270];
+    r[3] = r[13] ^ POOL[1271];
+    r[13] = r[3] ^ POOL[1272];
+    r[3] = r[13] ^ POOL[1273];
+    r[13] = {
+        let (t, s) = (POOL[1274], POOL[1275]);
+        let (ma, mb) = (t ^ s ^ POOL[1276], t ^ s ^ POOL[1277]);
+        let mut z = t;
+        z ^= r[1] & mb;
+        z ^= r[3] & ma;
+        z ^= r[1] & r[3];
+        z
+    };
+    r[3] = r[8] ^ r[13];
+    r[8] = r[3].rotate_left(1);
+    r[3] = r[12] & POOL[1278];
+    r[13] = r[3] ^ r[8];
+    r[1] = r[10] ^ r[13];
+    r[13] = r[6] & POOL[1279];
+    r[10] = r[3] ^ POOL[1280];
+    r[3] = r[10] ^ POOL[1281];
+    r[10] = r[3] ^ POOL[1282];
+    r[3] = r[8] ^ POOL[1283];
+    r[8] = {
+        let (t, s) = (POOL[1284], POOL[1285]);
+        let (ma, mb) = (t ^ s ^ POOL[1286], t ^ s ^ POOL[1287]);
+        let mut z = t;
+        z ^= r[10] & mb;
+        z ^= r[3] & ma;
+        z ^= r[10] & r[3];
+        z
+    };
+    r[3] = r[13] ^ r[8];
+    r[8] = r[3].rotate_left(1);
+    r[3] = r[12] & POOL[1288];
+    r[13] = r[3] ^ r[8];
+    r[10] = r[1] ^ r[13];
+    r[13] = r[6] & POOL[1289];
+    r[1] = r[3] ^ POOL[1290];
+    r[3] = r[1] ^ POOL[1291];
+    r[1] = r[3] ^ POOL[1292];
+    r[3] = r[8] ^ POOL[1293];
+    r[8] = {
+        let (t, s) = (POOL[1294], POOL[1295]);
+        let (ma, mb) = (t ^ s ^ POOL[1296], t ^ s ^ POOL[1297]);
+        let mut z = t;
+        z ^= r[1] & mb;
+        z ^= r[3] & ma;
+        z ^= r[1] & r[3];
+        z
+    };
+    r[3] = r[13] ^ r[8];
+    r[8] = r[3].rotate_left(1);
+    r[3] = r[12] & POOL[1298];
+    r[13] = r[3] ^ r[8];
+    r[1] = r[10] ^ r[13];
+    r[13] = r[6] & POOL[1299];
+    r[10] = r[3] ^ POOL[1300];
+    r[3] = r[10] ^ POOL[1301];
+    r[10] = r[3] ^ POOL[1302];
+    r[3] = r[8] ^ POOL[1303];
+    r[8] = {
+        let (t, s) = (POOL[1304], POOL[1305]);
+        let (ma, mb) = (t ^ s ^ POOL[1306], t ^ s ^ POOL[1307]);
+        let mut z = t;
+        z ^= r[10] & mb;
+        z ^= r[3] & ma;
+        z ^= r[10] & r[3];
+        z
+    };
+    r[3] = r[13] ^ r[8];
+    r[8] = r[3].rotate_left(1);
+    r[3] = r[12] & POOL[1308];
+    r[13] = r[3] ^ r[8];
+    r[10] = r[1] ^ r[13];
+    r[13] = r[6] & POOL[1309];
+    r[1] = r[3] ^ POOL[1310];
+    r[3] = r[1] ^ POOL[1311];
+    r[1] = r[3] ^ POOL[1312];
+    r[3] = r[8] ^ POOL[1313];
+    r[8] = {
+        let (t, s) = (POOL[1314], POOL[1315]);
+        let (ma, mb) = (t ^ s ^ POOL[1316], t ^ s ^ POOL[1317]);
+        let mut z = t;
+        z ^= r[1] & mb;
+        z ^= r[3] & ma;
+        z ^= r[1] & r[3];
+        z
+    };
+    r[3] = r[13] ^ r[8];
+    r[8] = r[3].rotate_left(1);
+    r[3] = r[12] & POOL[1318];
+    r[13] = r[3] ^ r[8];
+    r[1] = r[10] ^ r[13];
+    r[13] = r[6] & POOL[1319];
+    r[10] = r[3] ^ POOL[1320];
+    r[3] = r[10] ^ POOL[1321];
+    r[10] = r[3] ^ POOL[1322];
+    r[3] = r[8] ^ POOL[1323];
+    r[8] = {
+        let (t, s) = (POOL[1324], POOL[1325]);
+        let (ma, mb) = (t ^ s ^ POOL[1326], t ^ s ^ POOL[1327]);
+        let mut z = t;
+        z ^= r[10] & mb;
+        z ^= r[3] & ma;
+        z ^= r[10] & r[3];
+        z
+    };
+    r[3] = r[13] ^ r[8];
+    r[8] = r[3].rotate_left(1);
+    r[3] = r[12] & POOL[1328];
+    r[13] = r[3] ^ r[8];
+    r[10] = r[1] ^ r[13];
+    r[13] = r[6] & POOL[1329];
+    r[1] = r[3] ^ POOL[1330];
+    r[3] = r[1] ^ POOL[1331];
+    r[1] = r[3] ^ POOL[1332];
+    r[3] = r[8] ^ POOL[1333];
+    r[8] = {
+        let (t, s) = (POOL[1334], POOL[1335]);
+        let (ma, mb) = (t ^ s ^ POOL[1336], t ^ s ^ POOL[1337]);
+        let mut z = t;
+        z ^= r[1] & mb;
+        z ^= r[3] & ma;
+        z ^= r[1] & r[3];
+        z
+    };
+    r[3] = r[13] ^ r[8];
+    r[8] = r[3].rotate_left(1);
+    r[3] = r[12] & POOL[1338];
+    r[13] = r[3] ^ r[8];
+    r[1] = r[10] ^ r[13];
+    r[13] = r[6] & POOL[1339];
+    r[10] = r[3] ^ POOL[1340];
+    r[3] = r[10] ^ POOL[1341];
+    r[10] = r[3] ^ POOL[1342];
+    r[3] = r[8] ^ POOL[1343];
+    r[8] = {
+        let (t, s) = (POOL[1344], POOL[1345]);
+        let (ma, mb) = (t ^ s ^ POOL[1346], t ^ s ^ POOL[1347]);
+        let mut z = t;
+        z ^= r[10] & mb;
+        z ^= r[3] & ma;
+        z ^= r[10] & r[3];
+        z
+    };
+    r[3] = r[13] ^ r[8];
+    r[8] = r[3].rotate_left(1);
+    r[3] = r[12] & POOL[1348];
+    r[13] = r[3] ^ r[8];
+    r[10] = r[1] ^ r[13];
+    r[13] = r[6] & POOL[1349];
+    r[1] = r[3] ^ POOL[1350];
+    r[3] = r[1] ^ POOL[1351];
+    r[1] = r[3] ^ POOL[1352];
+    r[3] = r[8] ^ POOL[1353];
+    r[8] = {
+        let (t, s) = (POOL[1354], POOL[1355]);
+        let (ma, mb) = (t ^ s ^ POOL[1356], t ^ s ^ POOL[1357]);
+        let mut z = t;
+        z ^= r[1] & mb;
+        z ^= r[3] & ma;
+        z ^= r[1] & r[3];
+        z
+    };
+    r[3] = r[13] ^ r[8];
+    r[8] = r[3].rotate_left(1);
+    r[3] = r[12] & POOL[1358];
+    r[13] = r[3] ^ r[8];
+    r[1] = r[10] ^ r[13];
+    r[13] = r[6] & POOL[1359];
+    r[10] = r[3] ^ POOL[1360];
+    r[3] = r[10] ^ POOL[1361];
+    r[10] = r[3] ^ POOL[1362];
+    r[3] = r[8] ^ POOL[1363];
+    r[8] = {
+        let (t, s) = (POOL[1364], POOL[1365]);
+        let (ma, mb) = (t ^ s ^ POOL[1366], t ^ s ^ POOL[1367]);
+        let mut z = t;
+        z ^= r[10] & mb;
+        z ^= r[3] & ma;
+        z ^= r[10] & r[3];
+        z
+    };
+    r[3] = r[13] ^ r[8];
+    r[8] = r[3].rotate_left(1);
+    r[3] = r[12] & POOL[1368];
+    r[13] = r[3] ^ r[8];
+    r[10] = r[1] ^ r[13];
+    r[13] = r[6] & POOL[1369];
+    r[1] = r[3] ^ POOL[1370];
+    r[3] = r[1] ^ POOL[1371];
+    r[1] = r[3] ^ POOL[1372];
+    r[3] = r[8] ^ POOL[1373];
+    r[8] = {
+        let (t, s) = (POOL[1374], POOL[1375]);
+        let (ma, mb) = (t ^ s ^ POOL[1376], t ^ s ^ POOL[1377]);
+        let mut z = t;
+        z ^= r[1] & mb;
+        z ^= r[3] & ma;
+        z ^= r[1] & r[3];
+        z
+    };
+    r[3] = r[13] ^ r[8];
+    r[8] = r[3].rotate_left(1);
+    r[3] = r[12] & POOL[1378];
+    r[13] = r[3] ^ r[8];
+    r[1] = r[10] ^ r[13];
+    r[13] = r[6] & POOL[1379];
+    r[10] = r[3] ^ POOL[1380];
+    r[3] = r[10] ^ POOL[1381];
+    r[10] = r[3] ^ POOL[1382];
+    r[3] = r[8] ^ POOL[1383];
+    r[8] = {
+        let (t, s) = (POOL[1384], POOL[1385]);
+        let (ma, mb) = (t ^ s ^ POOL[1386], t ^ s ^ POOL[1387]);
+        let mut z = t;
+        z ^= r[10] & mb;
+        z ^= r[3] & ma;
+        z ^= r[10] & r[3];
+        z
+    };
+    r[3] = r[13] ^ r[8];
+    r[8] = r[3].rotate_left(1);
+    r[3] = r[12] & POOL[1388];
+    r[13] = r[3] ^ r[8];
+    r[10] = r[1] ^ r[13];
+    r[13] = r[6] & POOL[1389];
+    r[1] = r[3] ^ POOL[1390];
+    r[3] = r[1] ^ POOL[1391];
+    r[1] = r[3] ^ POOL[1392];
+    r[3] = r[8] ^ POOL[1393];
+    r[8] = {
+        let (t, s) = (POOL[1394], POOL[1395]);
+        let (ma, mb) = (t ^ s ^ POOL[1396], t ^ s ^ POOL[1397]);
+        let mut z = t;
+        z ^= r[1] & mb;
+        z ^= r[3] & ma;
+        z ^= r[1] & r[3];
+        z
+    };
+    r[3] = r[13] ^ r[8];
+    r[8] = r[3].rotate_left(1);
+    r[3] = r[12] & POOL[1398];
+    r[13] = r[3] ^ r[8];
+    r[1] = r[10] ^ r[13];
+    r[13] = r[6] & POOL[1399];
+    r[10] = r[3] ^ POOL[1400];
+    r[3] = r[10] ^ POOL[1401];
+    r[10] = r[3] ^ POOL[1402];
+    r[3] = r[8] ^ POOL[1403];
+    r[8] = {
+        let (t, s) = (POOL[1404], POOL[1405]);
+        let (ma, mb) = (t ^ s ^ POOL[1406], t ^ s ^ POOL[1407]);
+        let mut z = t;
+        z ^= r[10] & mb;
+        z ^= r[3] & ma;
+        z ^= r[10] & r[3];
+        z
+    };
+    r[3] = r[13] ^ r[8];
+    r[8] = r[3].rotate_left(1);
+    r[3] = r[12] & POOL[1408];
+    r[13] = r[3] ^ r[8];
+    r[10] = r[1] ^ r[13];
+    r[13] = r[6] & POOL[1409];
+    r[1] = r[3] ^ POOL[1410];
+    r[3] = r[1] ^ POOL[1411];
+    r[1] = r[3] ^ POOL[1412];
+    r[3] = r[8] ^ POOL[1413];
+    r[8] = {
+        let (t, s) = (POOL[1414], POOL[1415]);
+        let (ma, mb) = (t ^ s ^ POOL[1416], t ^ s ^ POOL[1417]);
+        let mut z = t;
+        z ^= r[1] & mb;
+        z ^= r[3] & ma;
+        z ^= r[1] & r[3];
+        z
+    };
+    r[3] = r[13] ^ r[8];
+    r[8] = r[3].rotate_left(1);
+    r[3] = r[12] & POOL[1418];
+    r[13] = r[3] ^ r[8];
+    r[1] = r[10] ^ r[13];
+    r[13] = r[6] & POOL[1419];
+    r[10] = r[3] ^ POOL[1420];
+    r[3] = r[10] ^ POOL[1421];
+    r[10] = r[3] ^ POOL[1422];
+    r[3] = r[8] ^ POOL[1423];
+    r[8] = {
+        let (t, s) = (POOL[1424], POOL[1425]);
+        let (ma, mb) = (t ^ s ^ POOL[1426], t ^ s ^ POOL[1427]);
+        let mut z = t;
+        z ^= r[10] & mb;
+        z ^= r[3] & ma;
+        z ^= r[10] & r[3];
+        z
+    };
+    r[3] = r[13] ^ r[8];
+    r[8] = r[3].rotate_left(1);
+    r[3] = r[12] & POOL[1428];
+    r[13] = r[3] ^ r[8];
+    r[10] = r[1] ^ r[13];
+    r[13] = r[6] & POOL[1429];
+    r[1] = r[3] ^ POOL[1430];
+    r[3] = r[1] ^ POOL[1431];
+    r[1] = r[3] ^ POOL[1432];
+    r[3] = r[8] ^ POOL[1433];
+    r[8] = {
+        let (t, s) = (POOL[1434], POOL[1435]);
+        let (ma, mb) = (t ^ s ^ POOL[1436], t ^ s ^ POOL[1437]);
+        let mut z = t;
+        z ^= r[1] & mb;
+        z ^= r[3] & ma;
+        z ^= r[1] & r[3];
+        z
+    };
+    r[3] = r[13] ^ r[8];
+    r[8] = r[3].rotate_left(1);
+    r[3] = r[12] & POOL[1438];
+    r[13] = r[3] ^ r[8];
+    r[1] = r[10] ^ r[13];
+    r[13] = r[6] & POOL[1439];
+    r[10] = r[3] ^ POOL[1440];
+    r[3] = r[10] ^ POOL[1441];
+    r[10] = r[3] ^ POOL[1442];
+    r[3] = r[8] ^ POOL[1443];
+    r[8] = {
+        let (t, s) = (POOL[1444], POOL[1445]);
+        let (ma, mb) = (t ^ s ^ POOL[1446], t ^ s ^ POOL[1447]);
+        let mut z = t;
+        z ^= r[10] & mb;
+        z ^= r[3] & ma;
+        z ^= r[10] & r[3];
+        z
+    };
+    r[3] = r[13] ^ r[8];
+    r[8] = r[3].rotate_left(1);
+    r[3] = r[12] & POOL[1448];
+    r[13] = r[3] ^ r[8];
+    r[10] = r[1] ^ r[13];
+    r[13] = r[6] & POOL[1449];
+    r[1] = r[3] ^ POOL[1450];
+    r[3] = r[1] ^ POOL[1451];
+    r[1] = r[3] ^ POOL[1452];
+    r[3] = r[8] ^ POOL[1453];
+    r[8] = {
+        let (t, s) = (POOL[1454], POOL[1455]);
+        let (ma, mb) = (t ^ s ^ POOL[1456], t ^ s ^ POOL[1457]);
+        let mut z = t;
+        z ^= r[1] & mb;
+        z ^= r[3] & ma;
+        z ^= r[1] & r[3];
+        z
+    };
+    r[3] = r[13] ^ r[8];
+    r[8] = r[3].rotate_left(1);
+    r[3] = r[12] & POOL[1458];
+    r[13] = r[3] ^ r[8];
+    r[1] = r[10] ^ r[13];
+    r[13] = r[6] & POOL[1459];
+    r[10] = r[3] ^ POOL[1460];
+    r[3] = r[10] ^ POOL[1461];
+    r[10] = r[3] ^ POOL[1462];
+    r[3] = r[8] ^ POOL[1463];
+    r[8] = {
+        let (t, s) = (POOL[1464], POOL[1465]);
+        let (ma, mb) = (t ^ s ^ POOL[1466], t ^ s ^ POOL[1467]);
+        let mut z = t;
+        z ^= r[10] & mb;
+        z ^= r[3] & ma;
+        z ^= r[10] & r[3];
+        z
+    };
+    r[3] = r[13] ^ r[8];
+    r[8] = r[3].rotate_left(1);
+    r[3] = r[12] & POOL[1468];
+    r[13] = r[3] ^ r[8];
+    r[10] = r[1] ^ r[13];
+    r[13] = r[6] & POOL[1469];
+    r[1] = r[3] ^ POOL[1470];
+    r[3] = r[1] ^ POOL[1471];
+    r[1] = r[3] ^ POOL[1472];
+    r[3] = r[8] ^ POOL[1473];
+    r[8] = {
+        let (t, s) = (POOL[1474], POOL[1475]);
+        let (ma, mb) = (t ^ s ^ POOL[1476], t ^ s ^ POOL[1477]);
+        let mut z = t;
+        z ^= r[1] & mb;
+        z ^= r[3] & ma;
+        z ^= r[1] & r[3];
+        z
+    };
+    r[3] = r[13] ^ r[8];
+    r[8] = r[3].rotate_left(1);
+    r[3] = r[12] & POOL[1478];
+    r[13] = r[3] ^ r[8];
+    r[1] = r[10] ^ r[13];
+    r[13] = r[6] & POOL[1479];
+    r[10] = r[3] ^ POOL[1480];
+    r[3] = r[10] ^ POOL[1481];
+    r[10] = r[3] ^ POOL[1482];
+    r[3] = r[8] ^ POOL[1483];
+    r[8] = {
+        let (t, s) = (POOL[1484], POOL[1485]);
+        let (ma, mb) = (t ^ s ^ POOL[1486], t ^ s ^ POOL[1487]);
+        let mut z = t;
+        z ^= r[10] & mb;
+        z ^= r[3] & ma;
+        z ^= r[10] & r[3];
+        z
+    };
+    r[3] = r[13] ^ r[8];
+    r[8] = r[3].rotate_left(1);
+    r[3] = r[12] & POOL[1488];
+    r[13] = r[3] ^ r[8];
+    r[10] = r[1] ^ r[13];
+    r[13] = r[6] & POOL[1489];
+    r[1] = r[3] ^ POOL[1490];
+    r[3] = r[1] ^ POOL[1491];
+    r[1] = r[3] ^ POOL[1492];
+    r[3] = r[8] ^ POOL[1493];
+    r[8] = {
+        let (t, s) = (POOL[1494], POOL[1495]);
+        let (ma, mb) = (t ^ s ^ POOL[1496], t ^ s ^ POOL[1497]);
+        let mut z = t;
+        z ^= r[1] & mb;
+        z ^= r[3] & ma;
+        z ^= r[1] & r[3];
+        z
+    };
+    r[3] = r[13] ^ r[8];
+    r[8] = r[3].rotate_left(1);
+    r[3] = r[12] & POOL[1498];
+    r[13] = r[3] ^ r[8];
+    r[1] = r[10] ^ r[13];
+    r[13] = r[6] & POOL[1499];
+    r[10] = r[3] ^ POOL[1500];
+    r[3] = r[10] ^ POOL[1501];
+    r[10] = r[3] ^ POOL[1502];
+    r[3] = r[8] ^ POOL[1503];
+    r[8] = {
+        let (t, s) = (POOL[1504], POOL[1505]);
+        let (ma, mb) = (t ^ s ^ POOL[1506], t ^ s ^ POOL[1507]);
+        let mut z = t;
+        z ^= r[10] & mb;
+        z ^= r[3] & ma;
+        z ^= r[10] & r[3];
+        z
+    };
+    r[3] = r[13] ^ r[8];
+    r[8] = r[3].rotate_left(1);
+    r[3] = r[12] & POOL[1508];
+    r[13] = r[3] ^ r[8];
+    r[10] = r[1] ^ r[13];
+    r[13] = r[6] & POOL[1509];
+    r[1] = r[3] ^ POOL[1510];
+    r[3] = r[1] ^ POOL[1511];
+    r[1] = r[3] ^ POOL[1512];
+    r[3] = r[8] ^ POOL[1513];
+    r[8] = {
+        let (t, s) = (POOL[1514], POOL[1515]);
+        let (ma, mb) = (t ^ s ^ POOL[1516], t ^ s ^ POOL[1517]);
+        let mut z = t;
+        z ^= r[1] & mb;
+        z ^= r[3] & ma;
+        z ^= r[1] & r[3];
+        z
+    };
+    r[3] = r[13] ^ r[8];
+    r[8] = r[3].rotate_left(1);
+    r[3] = r[12] & POOL[1518];
+    r[13] = r[3] ^ r[8];
+    r[1] = r[10] ^ r[13];
+    r[13] = r[6] & POOL[1519];
+    r[10] = r[3] ^ POOL[1520];
+    r[3] = r[10] ^ POOL[1521];
+    r[10] = r[3] ^ POOL[1522];
+    r[3] = r[8] ^ POOL[1523];
+    r[8] = {
+        let (t, s) = (POOL[1524], POOL[1525]);
+        let (ma, mb) = (t ^ s ^ POOL[1526], t ^ s ^ POOL[1527]);
+        let mut z = t;
+        z ^= r[10] & mb;
+        z ^= r[3] & ma;
+        z ^= r[10] & r[3];
+        z
+    };
+    r[3] = r[13] ^ r[8];
+    r[8] = r[3].rotate_left(1);
+    r[3] = r[12] & POOL[1528];
+    r[13] = r[3] ^ r[8];
+    r[10] = r[1] ^ r[13];
+    r[13] = r[6] & POOL[1529];
+    r[1] = r[3] ^ POOL[1530];
+    r[3] = r[1] ^ POOL[1531];
+    r[1] = r[3] ^ POOL[1532];
+    r[3] = r[8] ^ POOL[1533];
+    r[8] = {
+        let (t, s) = (POOL[1534], POOL[1535]);
+        let (ma, mb) = (t ^ s ^ POOL[1536], t ^ s ^ POOL[1537]);
+        let mut z = t;
+        z ^= r[1] & mb;
+        z ^= r[3] & ma;
+        z ^= r[1] & r[3];
+        z
+    };
+    r[3] = r[13] ^ r[8];
+    r[8] = r[3].rotate_left(1);
+    r[3] = r[12] & POOL[1538];
+    r[13] = r[3] ^ r[8];
+    r[1] = r[10] ^ r[13];
+    r[13] = r[6] & POOL[1539];
+    r[10] = r[3] ^ POOL[1540];
+    r[3] = r[10] ^ POOL[1541];
+    r[10] = r[3] ^ POOL[1542];
+    r[3] = r[8] ^ POOL[1543];
+    r[8] = {
+        let (t, s) = (POOL[1544], POOL[1545]);
+        let (ma, mb) = (t ^ s ^ POOL[1546], t ^ s ^ POOL[1547]);
+        let mut z = t;
+        z ^= r[10] & mb;
+        z ^= r[3] & ma;
+        z ^= r[10] & r[3];
+        z
+    };
+    r[3] = r[13] ^ r[8];
+    r[8] = r[3].rotate_left(1);
+    r[3] = r[12] & POOL[1548];
+    r[13] = r[3] ^ r[8];
+    r[10] = r[1] ^ r[13];
+    r[13] = r[6] & POOL[1549];
+    r[1] = r[3] ^ POOL[1550];
+    r[3] = r[1] ^ POOL[1551];
+    r[1] = r[3] ^ POOL[1552];
+    r[3] = r[8] ^ POOL[1553];
+    r[8] = {
+        let (t, s) = (POOL[1554], POOL[1555]);
+        let (ma, mb) = (t ^ s ^ POOL[1556], t ^ s ^ POOL[1557]);
+        let mut z = t;
+        z ^= r[1] & mb;
+        z ^= r[3] & ma;
+        z ^= r[1] & r[3];
+        z
+    };
+    r[3] = r[13] ^ r[8];
+    r[8] = r[3].rotate_left(1);
+    r[3] = r[12] & POOL[1558];
+    r[13] = r[3] ^ r[8];
+    r[1] = r[10] ^ r[13];
+    r[13] = r[6] & POOL[1559];
+    r[6] = r[3] ^ POOL[1560];
+    r[3] = r[6] ^ POOL[1561];
+    r[6] = r[3] ^ POOL[1562];
+    r[3] = r[8] ^ POOL[1563];
+    r[8] = {
+        let (t, s) = (POOL[1564], POOL[1565]);
+        let (ma, mb) = (t ^ s ^ POOL[1566], t ^ s ^ POOL[1567]);
+        let mut z = t;
+        z ^= r[6] & mb;
+        z ^= r[3] & ma;
+        z ^= r[6] & r[3];
+        z
+    };
+    r[3] = r[13] ^ r[8];
+    r[8] = r[3].rotate_left(1);
+    r[3] = r[12] & POOL[1568];
+    r[12] = r[3] ^ r[8];
+    r[8] = r[1] ^ r[12];
+    r[12] = r[7] ^ r[8];
+    r[7] = r[12].rotate_left(12);
+    r[12] = r[11] ^ POOL[1569];
+    r[3] = r[7] ^ POOL[1570];
+    r[6] = {
+        let (t, s) = (POOL[1571], POOL[1572]);
+        let (ma, mb) = (t ^ s ^ POOL[1573], t ^ s ^ POOL[1574]);
+        let mut z = t;
+        z ^= r[12] & mb;
+        z ^= r[3] & ma;
+        z ^= r[12] & r[3];
+        z
+    };
+    r[12] = r[11] ^ r[7];
+    r[11] = r[12] & POOL[1575];
+    r[3] = r[6] & POOL[1576];
+    r[13] = r[3].rotate_left(1);
+    r[3] = r[12] & POOL[1577];
+    r[1] = r[3] ^ r[13];
+    r[10] = r[11] ^ r[1];
+    r[11] = r[6] & POOL[1578];
+    r[1] = r[3] ^ POOL[1579];
+    r[3] = r[1] ^ POOL[1580];
+    r[1] = r[3] ^ POOL[1581];
+    r[3] = r[13] ^ POOL[1582];
+    r[13] = r[3] ^ POOL[1583];
+    r[3] = r[13] ^ POOL[1584];
+    r[13] = {
+        let (t, s) = (POOL[1585], POOL[1586]);
+        let (ma, mb) = (t ^ s ^ POOL[1587], t ^ s ^ POOL[1588]);
+        let mut z = t;
+        z ^= r[1] & mb;
+        z ^= r[3] & ma;
+        z ^= r[1] & r[3];
+        z
+    };
+    r[3] = r[11] ^ r[13];
+    r[11] = r[3].rotate_left(1);
+    r[3] = r[12] & POOL[1589];
+    r[13] = r[3] ^ r[11];
+    r[1] = r[10] ^ r[13];
+    r[13] = r[6] & POOL[1590];
+    r[10] = r[3] ^ POOL[1591];
+    r[3] = r[10] ^ POOL[1592];
+    r[10] = r[3] ^ POOL[1593];
+    r[3] = r[11] ^ POOL[1594];
+    r[11] = {
+        let (t, s) = (POOL[1595], POOL[1596]);
+        let (ma, mb) = (t ^ s ^ POOL[1597], t ^ s ^ POOL[1598]);
+        let mut z = t;
+        z ^= r[10] & mb;
+        z ^= r[3] & ma;
+        z ^= r[10] & r[3];
+        z
+    };
+    r[3] = r[13] ^ r[11];
+    r[11] = r[3].rotate_left(1);
+    r[3] = r[12] & POOL[1599];
+    r[13] = r[3] ^ r[11];
+    r[10] = r[1] ^ r[13];
+    r[13] = r[6] & POOL[1600];
+    r[1] = r[3] ^ POOL[1601];
+    r[3] = r[1] ^ POOL[1602];
+    r[1] = r[3] ^ POOL[1603];
+    r[3] = r[11] ^ POOL[1604];
+    r[11] = {
+        let (t, s) = (POOL[1605], POOL[1606]);
+        let (ma, mb) = (t ^ s ^ POOL[1607], t ^ s ^ POOL[1608]);
+        let mut z = t;
+        z ^= r[1] & mb;
+        z ^= r[3] & ma;
+        z ^= r[1] & r[3];
+        z
+    };
+    r[3] = r[13] ^ r[11];
+    r[11] = r[3].rotate_left(1);
+    r[3] = r[12] & POOL[1609];
+    r[13] = r[3] ^ r[11];
+    r[1] = r[10] ^ r[13];
+    r[13] = r[6] & POOL[1610];
+    r[10] = r[3] ^ POOL[1611];
+    r[3] = r[10] ^ POOL[1612];
+    r[10] = r[3] ^ POOL[1613];
+    r[3] = r[11] ^ POOL[1614];
+    r[11] = {
+        let (t, s) = (POOL[1615], POOL[1616]);
+        let (ma, mb) = (t ^ s ^ POOL[1617], t ^ s ^ POOL[1618]);
+        let mut z = t;
+        z ^= r[10] & mb;
+        z ^= r[3] & ma;
+        z ^= r[10] & r[3];
+        z
+    };
+    r[3] = r[13] ^ r[11];
+    r[11] = r[3].rotate_left(1);
+    r[3] = r[12] & POOL[1619];
+    r[13] = r[3] ^ r[11];
+    r[10] = r[1] ^ r[13];
+    r[13] = r[6] & POOL[1620];
+    r[1] = r[3] ^ POOL[1621];
+    r[3] = r[1] ^ POOL[1622];
+    r[1] = r[3] ^ POOL[1623];
+    r[3] = r[11] ^ POOL[1624];
+    r[11] = {
+        let (t, s) = (POOL[1625], POOL[1626]);
+        let (ma, mb) = (t ^ s ^ POOL[1627], t ^ s ^ POOL[1628]);
+        let mut z = t;
+        z ^= r[1] & mb;
+        z ^= r[3] & ma;
+        z ^= r[1] & r[3];
+        z
+    };
+    r[3] = r[13] ^ r[11];
+    r[11] = r[3].rotate_left(1);
+    r[3] = r[12] & POOL[1629];
+    r[13] = r[3] ^ r[11];
+    r[1] = r[10] ^ r[13];
+    r[13] = r[6] & POOL[1630];
+    r[10] = r[3] ^ POOL[1631];
+    r[3] = r[10] ^ POOL[1632];
+    r[10] = r[3] ^ POOL[1633];
+    r[3] = r[11] ^ POOL[1634];
+    r[11] = {
+        let (t, s) = (POOL[1635], POOL[1636]);
+        let (ma, mb) = (t ^ s ^ POOL[1637], t ^ s ^ POOL[1638]);
+        let mut z = t;
+        z ^= r[10] & mb;
+        z ^= r[3] & ma;
+        z ^= r[10] & r[3];
+        z
+    };
+    r[3] = r[13] ^ r[11];
+    r[11] = r[3].rotate_left(1);
+    r[3] = r[12] & POOL[1639];
+    r[13] = r[3] ^ r[11];
+    r[10] = r[1] ^ r[13];
+    r[13] = r[6] & POOL[1640];
+    r[1] = r[3] ^ POOL[1641];
+    r[3] = r[1] ^ POOL[1642];
+    r[1] = r[3] ^ POOL[1643];
+    r[3] = r[11] ^ POOL[1644];
+    r[11] = {
+        let (t, s) = (POOL[1645], POOL[1646]);
+        let (ma, mb) = (t ^ s ^ POOL[1647], t ^ s ^ POOL[1648]);
+        let mut z = t;
+        z ^= r[1] & mb;
+        z ^= r[3] & ma;
+        z ^= r[1] & r[3];
+        z
+    };
+    r[3] = r[13] ^ r[11];
+    r[11] = r[3].rotate_left(1);
+    r[3] = r[12] & POOL[1649];
+    r[13] = r[3] ^ r[11];
+    r[1] = r[10] ^ r[13];
+    r[13] = r[6] & POOL[1650];
+    r[10] = r[3] ^ POOL[1651];
+    r[3] = r[10] ^ POOL[1652];
+    r[10] = r[3] ^ POOL[1653];
+    r[3] = r[11] ^ POOL[1654];
+    r[11] = {
+        let (t, s) = (POOL[1655], POOL[1656]);
+        let (ma, mb) = (t ^ s ^ POOL[1657], t ^ s ^ POOL[1658]);
+        let mut z = t;
+        z ^= r[10] & mb;
+        z ^= r[3] & ma;
+        z ^= r[10] & r[3];
+        z
+    };
+    r[3] = r[13] ^ r[11];
+    r[11] = r[3].rotate_left(1);
+    r[3] = r[12] & POOL[1659];
+    r[13] = r[3] ^ r[11];
+    r[10] = r[1] ^ r[13];
+    r[13] = r[6] & POOL[1660];
+    r[1] = r[3] ^ POOL[1661];
+    r[3] = r[1] ^ POOL[1662];
+    r[1] = r[3] ^ POOL[1663];
+    r[3] = r[11] ^ POOL[1664];
+    r[11] = {
+        let (t, s) = (POOL[1665], POOL[1666]);
+        let (ma, mb) = (t ^ s ^ POOL[1667], t ^ s ^ POOL[1668]);
+        let mut z = t;
+        z ^= r[1] & mb;
+        z ^= r[3] & ma;
+        z ^= r[1] & r[3];
+        z
+    };
+    r[3] = r[13] ^ r[11];
+    r[11] = r[3].rotate_left(1);
+    r[3] = r[12] & POOL[1669];
+    r[13] = r[3] ^ r[11];
+    r[1] = r[10] ^ r[13];
+    r[13] = r[6] & POOL[1670];
+    r[10] = r[3] ^ POOL[1671];
+    r[3] = r[10] ^ POOL[1672];
+    r[10] = r[3] ^ POOL[1673];
+    r[3] = r[11] ^ POOL[1674];
+    r[11] = {
+        let (t, s) = (POOL[1675], POOL[1676]);
+        let (ma, mb) = (t ^ s ^ POOL[1677], t ^ s ^ POOL[1678]);
+        let mut z = t;
+        z ^= r[10] & mb;
+        z ^= r[3] & ma;
+        z ^= r[10] & r[3];
+        z
+    };
+    r[3] = r[13] ^ r[11];
+    r[11] = r[3].rotate_left(1);
+    r[3] = r[12] & POOL[1679];
+    r[13] = r[3] ^ r[11];
+    r[10] = r[1] ^ r[13];
+    r[13] = r[6] & POOL[1680];
+    r[1] = r[3] ^ POOL[1681];
+    r[3] = r[1] ^ POOL[1682];
+    r[1] = r[3] ^ POOL[1683];
+    r[3] = r[11] ^ POOL[1684];
+    r[11] = {
+        let (t, s) = (POOL[1685], POOL[1686]);
+        let (ma, mb) = (t ^ s ^ POOL[1687], t ^ s ^ POOL[1688]);
+        let mut z = t;
+        z ^= r[1] & mb;
+        z ^= r[3] & ma;
+        z ^= r[1] & r[3];
+        z
+    };
+    r[3] = r[13] ^ r[11];
+    r[11] = r[3].rotate_left(1);
+    r[3] = r[12] & POOL[1689];
+    r[13] = r[3] ^ r[11];
+    r[1] = r[10] ^ r[13];
+    r[13] = r[6] & POOL[1690];
+    r[10] = r[3] ^ POOL[1691];
+    r[3] = r[10] ^ POOL[1692];
+    r[10] = r[3] ^ POOL[1693];
+    r[3] = r[11] ^ POOL[1694];
+    r[11] = {
+        let (t, s) = (POOL[1695], POOL[1696]);
+        let (ma, mb) = (t ^ s ^ POOL[1697], t ^ s ^ POOL[1698]);
+        let mut z = t;
+        z ^= r[10] & mb;
+        z ^= r[3] & ma;
+        z ^= r[10] & r[3];
+        z
+    };
+    r[3] = r[13] ^ r[11];
+    r[11] = r[3].rotate_left(1);
+    r[3] = r[12] & POOL[1699];
+    r[13] = r[3] ^ r[11];
+    r[10] = r[1] ^ r[13];
+    r[13] = r[6] & POOL[1700];
+    r[1] = r[3] ^ POOL[1701];
+    r[3] = r[1] ^ POOL[1702];
+    r[1] = r[3] ^ POOL[1703];
+    r[3] = r[11] ^ POOL[1704];
+    r[11] = {
+        let (t, s) = (POOL[1705], POOL[1706]);
+        let (ma, mb) = (t ^ s ^ POOL[1707], t ^ s ^ POOL[1708]);
+        let mut z = t;
+        z ^= r[1] & mb;
+        z ^= r[3] & ma;
+        z ^= r[1] & r[3];
+        z
+    };
+    r[3] = r[13] ^ r[11];
+    r[11] = r[3].rotate_left(1);
+    r[3] = r[12] & POOL[1709];
+    r[13] = r[3] ^ r[11];
+    r[1] = r[10] ^ r[13];
+    r[13] = r[6] & POOL[1710];
+    r[10] = r[3] ^ POOL[1711];
+    r[3] = r[10] ^ POOL[1712];
+    r[10] = r[3] ^ POOL[1713];
+    r[3] = r[11] ^ POOL[1714];
+    r[11] = {
+        let (t, s) = (POOL[1715], POOL[1716]);
+        let (ma, mb) = (t ^ s ^ POOL[1717], t ^ s ^ POOL[1718]);
+        let mut z = t;
+        z ^= r[10] & mb;
+        z ^= r[3] & ma;
+        z ^= r[10] & r[3];
+        z
+    };
+    r[3] = r[13] ^ r[11];
+    r[11] = r[3].rotate_left(1);
+    r[3] = r[12] & POOL[1719];
+    r[13] = r[3] ^ r[11];
+    r[10] = r[1] ^ r[13];
+    r[13] = r[6] & POOL[1720];
+    r[1] = r[3] ^ POOL[1721];
+    r[3] = r[1] ^ POOL[1722];
+    r[1] = r[3] ^ POOL[1723];
+    r[3] = r[11] ^ POOL[1724];
+    r[11] = {
+        let (t, s) = (POOL[1725], POOL[1726]);
+        let (ma, mb) = (t ^ s ^ POOL[1727], t ^ s ^ POOL[1728]);
+        let mut z = t;
+        z ^= r[1] & mb;
+        z ^= r[3] & ma;
+        z ^= r[1] & r[3];
+        z
+    };
+    r[3] = r[13] ^ r[11];
+    r[11] = r[3].rotate_left(1);
+    r[3] = r[12] & POOL[1729];
+    r[13] = r[3] ^ r[11];
+    r[1] = r[10] ^ r[13];
+    r[13] = r[6] & POOL[1730];
+    r[10] = r[3] ^ POOL[1731];
+    r[3] = r[10] ^ POOL[1732];
+    r[10] = r[3] ^ POOL[1733];
+    r[3] = r[11] ^ POOL[1734];
+    r[11] = {
+        let (t, s) = (POOL[1735], POOL[1736]);
+        let (ma, mb) = (t ^ s ^ POOL[1737], t ^ s ^ POOL[1738]);
+        let mut z = t;
+        z ^= r[10] & mb;
+        z ^= r[3] & ma;
+        z ^= r[10] & r[3];
+        z
+    };
+    r[3] = r[13] ^ r[11];
+    r[11] = r[3].rotate_left(1);
+    r[3] = r[12] & POOL[1739];
+    r[13] = r[3] ^ r[11];
+    r[10] = r[1] ^ r[13];
+    r[13] = r[6] & POOL[1740];
+    r[1] = r[3] ^ POOL[1741];
+    r[3] = r[1] ^ POOL[1742];
+    r[1] = r[3] ^ POOL[1743];
+    r[3] = r[11] ^ POOL[1744];
+    r[11] = {
+        let (t, s) = (POOL[1745], POOL[1746]);
+        let (ma, mb) = (t ^ s ^ POOL[1747], t ^ s ^ POOL[1748]);
+        let mut z = t;
+        z ^= r[1] & mb;
+        z ^= r[3] & ma;
+        z ^= r[1] & r[3];
+        z
+    };
+    r[3] = r[13] ^ r[11];
+    r[11] = r[3].rotate_left(1);
+    r[3] = r[12] & POOL[1749];
+    r[13] = r[3] ^ r[11];
+    r[1] = r[10] ^ r[13];
+    r[13] = r[6] & POOL[1750];
+    r[10] = r[3] ^ POOL[1751];
+    r[3] = r[10] ^ POOL[1752];
+    r[10] = r[3] ^ POOL[1753];
+    r[3] = r[11] ^ POOL[1754];
+    r[11] = {
+        let (t, s) = (POOL[1755], POOL[1756]);
+        let (ma, mb) = (t ^ s ^ POOL[1757], t ^ s ^ POOL[1758]);
+        let mut z = t;
+        z ^= r[10] & mb;
+        z ^= r[3] & ma;
+        z ^= r[10] & r[3];
+        z
+    };
+    r[3] = r[13] ^ r[11];
+    r[11] = r[3].rotate_left(1);
+    r[3] = r[12] & POOL[1759];
+    r[13] = r[3] ^ r[11];
+    r[10] = r[1] ^ r[13];
+    r[13] = r[6] & POOL[1760];
+    r[1] = r[3] ^ POOL[1761];
+    r[3] = r[1] ^ POOL[1762];
+    r[1] = r[3] ^ POOL[1763];
+    r[3] = r[11] ^ POOL[1764];
+    r[11] = {
+        let (t, s) = (POOL[1765], POOL[1766]);
+        let (ma, mb) = (t ^ s ^ POOL[1767], t ^ s ^ POOL[1768]);
+        let mut z = t;
+        z ^= r[1] & mb;
+        z ^= r[3] & ma;
+        z ^= r[1] & r[3];
+        z
+    };
+    r[3] = r[13] ^ r[11];
+    r[11] = r[3].rotate_left(1);
+    r[3] = r[12] & POOL[1769];
+    r[13] = r[3] ^ r[11];
+    r[1] = r[10] ^ r[13];
+    r[13] = r[6] & POOL[1770];
+    r[10] = r[3] ^ POOL[1771];
+    r[3] = r[10] ^ POOL[1772];
+    r[10] = r[3] ^ POOL[1773];
+    r[3] = r[11] ^ POOL[1774];
+    r[11] = {
+        let (t, s) = (POOL[1775], POOL[1776]);
+        let (ma, mb) = (t ^ s ^ POOL[1777], t ^ s ^ POOL[1778]);
+        let mut z = t;
+        z ^= r[10] & mb;
+        z ^= r[3] & ma;
+        z ^= r[10] & r[3];
+        z
+    };
+    r[3] = r[13] ^ r[11];
+    r[11] = r[3].rotate_left(1);
+    r[3] = r[12] & POOL[1779];
+    r[13] = r[3] ^ r[11];
+    r[10] = r[1] ^ r[13];
+    r[13] = r[6] & POOL[1780];
+    r[1] = r[3] ^ POOL[1781];
+    r[3] = r[1] ^ POOL[1782];
+    r[1] = r[3] ^ POOL[1783];
+    r[3] = r[11] ^ POOL[1784];
+    r[11] = {
+        let (t, s) = (POOL[1785], POOL[1786]);
+        let (ma, mb) = (t ^ s ^ POOL[1787], t ^ s ^ POOL[1788]);
+        let mut z = t;
+        z ^= r[1] & mb;
+        z ^= r[3] & ma;
+        z ^= r[1] & r[3];
+        z
+    };
+    r[3] = r[13] ^ r[11];
+    r[11] = r[3].rotate_left(1);
+    r[3] = r[12] & POOL[1789];
+    r[13] = r[3] ^ r[11];
+    r[1] = r[10] ^ r[13];
+    r[13] = r[6] & POOL[1790];
+    r[10] = r[3] ^ POOL[1791];
+    r[3] = r[10] ^ POOL[1792];
+    r[10] = r[3] ^ POOL[1793];
+    r[3] = r[11] ^ POOL[1794];
+    r[11] = {
+        let (t, s) = (POOL[1795], POOL[1796]);
+        let (ma, mb) = (t ^ s ^ POOL[1797], t ^ s ^ POOL[1798]);
+        let mut z = t;
+        z ^= r[10] & mb;
+        z ^= r[3] & ma;
+        z ^= r[10] & r[3];
+        z
+    };
+    r[3] = r[13] ^ r[11];
+    r[11] = r[3].rotate_left(1);
+    r[3] = r[12] & POOL[1799];
+    r[13] = r[3] ^ r[11];
+    r[10] = r[1] ^ r[13];
+    r[13] = r[6] & POOL[1800];
+    r[1] = r[3] ^ POOL[1801];
+    r[3] = r[1] ^ POOL[1802];
+    r[1] = r[3] ^ POOL[1803];
+    r[3] = r[11] ^ POOL[1804];
+    r[11] = {
+        let (t, s) = (POOL[1805], POOL[1806]);
+        let (ma, mb) = (t ^ s ^ POOL[1807], t ^ s ^ POOL[1808]);
+        let mut z = t;
+        z ^= r[1] & mb;
+        z ^= r[3] & ma;
+        z ^= r[1] & r[3];
+        z
+    };
+    r[3] = r[13] ^ r[11];
+    r[11] = r[3].rotate_left(1);
+    r[3] = r[12] & POOL[1809];
+    r[13] = r[3] ^ r[11];
+    r[1] = r[10] ^ r[13];
+    r[13] = r[6] & POOL[1810];
+    r[10] = r[3] ^ POOL[1811];
+    r[3] = r[10] ^ POOL[1812];
+    r[10] = r[3] ^ POOL[1813];
+    r[3] = r[11] ^ POOL[1814];
+    r[11] = {
+        let (t, s) = (POOL[1815], POOL[1816]);
+        let (ma, mb) = (t ^ s ^ POOL[1817], t ^ s ^ POOL[1818]);
+        let mut z = t;
+        z ^= r[10] & mb;
+        z ^= r[3] & ma;
+        z ^= r[10] & r[3];
+        z
+    };
+    r[3] = r[13] ^ r[11];
+    r[11] = r[3].rotate_left(1);
+    r[3] = r[12] & POOL[1819];
+    r[13] = r[3] ^ r[11];
+    r[10] = r[1] ^ r[13];
+    r[13] = r[6] & POOL[1820];
+    r[1] = r[3] ^ POOL[1821];
+    r[3] = r[1] ^ POOL[1822];
+    r[1] = r[3] ^ POOL[1823];
+    r[3] = r[11] ^ POOL[1824];
+    r[11] = {
+        let (t, s) = (POOL[1825], POOL[1826]);
+        let (ma, mb) = (t ^ s ^ POOL[1827], t ^ s ^ POOL[1828]);
+        let mut z = t;
+        z ^= r[1] & mb;
+        z ^= r[3] & ma;
+        z ^= r[1] & r[3];
+        z
+    };
+    r[3] = r[13] ^ r[11];
+    r[11] = r[3].rotate_left(1);
+    r[3] = r[12] & POOL[1829];
+    r[13] = r[3] ^ r[11];
+    r[1] = r[10] ^ r[13];
+    r[13] = r[6] & POOL[1830];
+    r[10] = r[3] ^ POOL[1831];
+    r[3] = r[10] ^ POOL[1832];
+    r[10] = r[3] ^ POOL[1833];
+    r[3] = r[11] ^ POOL[1834];
+    r[11] = {
+        let (t, s) = (POOL[1835], POOL[1836]);
+        let (ma, mb) = (t ^ s ^ POOL[1837], t ^ s ^ POOL[1838]);
+        let mut z = t;
+        z ^= r[10] & mb;
+        z ^= r[3] & ma;
+        z ^= r[10] & r[3];
+        z
+    };
+    r[3] = r[13] ^ r[11];
+    r[11] = r[3].rotate_left(1);
+    r[3] = r[12] & POOL[1839];
+    r[13] = r[3] ^ r[11];
+    r[10] = r[1] ^ r[13];
+    r[13] = r[6] & POOL[1840];
+    r[1] = r[3] ^ POOL[1841];
+    r[3] = r[1] ^ POOL[1842];
+    r[1] = r[3] ^ POOL[1843];
+    r[3] = r[11] ^ POOL[1844];
+    r[11] = {
+        let (t, s) = (POOL[1845], POOL[1846]);
+        let (ma, mb) = (t ^ s ^ POOL[1847], t ^ s ^ POOL[1848]);
+        let mut z = t;
+        z ^= r[1] & mb;
+        z ^= r[3] & ma;
+        z ^= r[1] & r[3];
+        z
+    };
+    r[3] = r[13] ^ r[11];
+    r[11] = r[3].rotate_left(1);
+    r[3] = r[12] & POOL[1849];
+    r[13] = r[3] ^ r[11];
+    r[1] = r[10] ^ r[13];
+    r[13] = r[6] & POOL[1850];
+    r[10] = r[3] ^ POOL[1851];
+    r[3] = r[10] ^ POOL[1852];
+    r[10] = r[3] ^ POOL[1853];
+    r[3] = r[11] ^ POOL[1854];
+    r[11] = {
+        let (t, s) = (POOL[1855], POOL[1856]);
+        let (ma, mb) = (t ^ s ^ POOL[1857], t ^ s ^ POOL[1858]);
+        let mut z = t;
+        z ^= r[10] & mb;
+        z ^= r[3] & ma;
+        z ^= r[10] & r[3];
+        z
+    };
+    r[3] = r[13] ^ r[11];
+    r[11] = r[3].rotate_left(1);
+    r[3] = r[12] & POOL[1859];
+    r[13] = r[3] ^ r[11];
+    r[10] = r[1] ^ r[13];
+    r[13] = r[6] & POOL[1860];
+    r[1] = r[3] ^ POOL[1861];
+    r[3] = r[1] ^ POOL[1862];
+    r[1] = r[3] ^ POOL[1863];
+    r[3] = r[11] ^ POOL[1864];
+    r[11] = {
+        let (t, s) = (POOL[1865], POOL[1866]);
+        let (ma, mb) = (t ^ s ^ POOL[1867], t ^ s ^ POOL[1868]);
+        let mut z = t;
+        z ^= r[1] & mb;
+        z ^= r[3] & ma;
+        z ^= r[1] & r[3];
+        z
+    };
+    r[3] = r[13] ^ r[11];
+    r[11] = r[3].rotate_left(1);
+    r[3] = r[12] & POOL[1869];
+    r[13] = r[3] ^ r[11];
+    r[1] = r[10] ^ r[13];
+    r[13] = r[6] & POOL[1870];
+    r[6] = r[3] ^ POOL[1871];
+    r[3] = r[6] ^ POOL[1872];
+    r[6] = r[3] ^ POOL[1873];
+    r[3] = r[11] ^ POOL[1874];
+    r[11] = {
+        let (t, s) = (POOL[1875], POOL[1876]);
+        let (ma, mb) = (t ^ s ^ POOL[1877], t ^ s ^ POOL[1878]);
+        let mut z = t;
+        z ^= r[6] & mb;
+        z ^= r[3] & ma;
+        z ^= r[6] & r[3];
+        z
+    };
+    r[3] = r[13] ^ r[11];
+    r[11] = r[3].rotate_left(1);
+    r[3] = r[12] & POOL[1879];
+    r[12] = r[3] ^ r[11];
+    r[11] = r[1] ^ r[12];
+    r[12] = r[9] ^ r[11];
+    r[3] = r[5] ^ r[9];
+    r[9] = r[3].rotate_left(8);
+    r[5] = r[2] ^ POOL[1880];
+    r[3] = r[9] ^ POOL[1881];
+    r[6] = {
+        let (t, s) = (POOL[1882], POOL[1883]);
+        let (ma, mb) = (t ^ s ^ POOL[1884], t ^ s ^ POOL[1885]);
+        let mut z = t;
+        z ^= r[5] & mb;
+        z ^= r[3] & ma;
+        z ^= r[5] & r[3];
+        z
+    };
+    r[5] = r[2] ^ r[9];
+    r[2] = r[5] & POOL[1886];
+    r[3] = r[6] & POOL[1887];
+    r[13] = r[3].rotate_left(1);
+    r[3] = r[5] & POOL[1888];
+    r[1] = r[3] ^ r[13];
+    r[10] = r[2] ^ r[1];
+    r[2] = r[6] & POOL[1889];
+    r[1] = r[3] ^ POOL[1890];
+    r[3] = r[1] ^ POOL[1891];
+    r[1] = r[3] ^ POOL[1892];
+    r[3] = r[13] ^ POOL[1893];
+    r[13] = r[3] ^ POOL[1894];
+    r[3] = r[13] ^ POOL[1895];
+    r[13] = {
+        let (t, s) = (POOL[1896], POOL[1897]);
+        let (ma, mb) = (t ^ s ^ POOL[1898], t ^ s ^ POOL[1899]);
+        let mut z = t;
+        z ^= r[1] & mb;
+        z ^= r[3] & ma;
+        z ^= r[1] & r[3];
+        z
+    };
+    r[3] = r[2] ^ r[13];
+    r[2] = r[3].rotate_left(1);
+    r[3] = r[5] & POOL[1900];
+    r[13] = r[3] ^ r[2];
+    r[1] = r[10] ^ r[13];
+    r[13] = r[6] & POOL[1901];
+    r[10] = r[3] ^ POOL[1902];
+    r[3] = r[10] ^ POOL[1903];
+    r[10] = r[3] ^ POOL[1904];
+    r[3] = r[2] ^ POOL[1905];
+    r[2] = {
+        let (t, s) = (POOL[1906], POOL[1907]);
+        let (ma, mb) = (t ^ s ^ POOL[1908], t ^ s ^ POOL[1909]);
+        let mut z = t;
+        z ^= r[10] & mb;
+        z ^= r[3] & ma;
+        z ^= r[10] & r[3];
+        z
+    };
+    r[3] = r[13] ^ r[2];
+    r[2] = r[3].rotate_left(1);
+    r[3] = r[5] & POOL[1910];
+    r[13] = r[3] ^ r[2];
+    r[10] = r[1] ^ r[13];
+    r[13] = r[6] & POOL[1911];
+    r[1] = r[3] ^ POOL[1912];
+    r[3] = r[1] ^ POOL[1913];
+    r[1] = r[3] ^ POOL[1914];
+    r[3] = r[2] ^ POOL[1915];
+    r[2] = {
+        let (t, s) = (POOL[1916], POOL[1917]);
+        let (ma, mb) = (t ^ s ^ POOL[1918], t ^ s ^ POOL[1919]);
+        let mut z = t;
+        z ^= r[1] & mb;
+        z ^= r[3] & ma;
+        z ^= r[1] & r[3];
+        z
+    };
+    r[3] = r[13] ^ r[2];
+    r[2] = r[3].rotate_left(1);
+    r[3] = r[5] & POOL[1920];
+    r[13] = r[3] ^ r[2];
+    r[1] = r[10] ^ r[13];
+    r[13] = r[6] & POOL[1921];
+    r[10] = r[3] ^ POOL[1922];
+    r[3] = r[10] ^ POOL[1923];
+    r[10] = r[3] ^ POOL[1924];
+    r[3] = r[2] ^ POOL[1925];
+    r[2] = {
+        let (t, s) = (POOL[1926], POOL[1927]);
+        let (ma, mb) = (t ^ s ^ POOL[1928], t ^ s ^ POOL[1929]);
+        let mut z = t;
+        z ^= r[10] & mb;
+        z ^= r[3] & ma;
+        z ^= r[10] & r[3];
+        z
+    };
+    r[3] = r[13] ^ r[2];
+    r[2] = r[3].rotate_left(1);
+    r[3] = r[5] & POOL[1930];
+    r[13] = r[3] ^ r[2];
+    r[10] = r[1] ^ r[13];
+    r[13] = r[6] & POOL[1931];
+    r[1] = r[3] ^ POOL[1932];
+    r[3] = r[1] ^ POOL[1933];
+    r[1] = r[3] ^ POOL[1934];
+    r[3] = r[2] ^ POOL[1935];
+    r[2] = {
+        let (t, s) = (POOL[1936], POOL[1937]);
+        let (ma, mb) = (t ^ s ^ POOL[1938], t ^ s ^ POOL[1939]);
+        let mut z = t;
+        z ^= r[1] & mb;
+        z ^= r[3] & ma;
+        z ^= r[1] & r[3];
+        z
+    };
+    r[3] = r[13] ^ r[2];
+    r[2] = r[3].rotate_left(1);
+    r[3] = r[5] & POOL[1940];
+    r[13] = r[3] ^ r[2];
+    r[1] = r[10] ^ r[13];
+    r[13] = r[6] & POOL[1941];
+    r[10] = r[3] ^ POOL[1942];
+    r[3] = r[10] ^ POOL[1943];
+    r[10] = r[3] ^ POOL[1944];
+    r[3] = r[2] ^ POOL[1945];
+    r[2] = {
+        let (t, s) = (POOL[1946], POOL[1947]);
+        let (ma, mb) = (t ^ s ^ POOL[1948], t ^ s ^ POOL[1949]);
+        let mut z = t;
+        z ^= r[10] & mb;
+        z ^= r[3] & ma;
+        z ^= r[10] & r[3];
+        z
+    };
+    r[3] = r[13] ^ r[2];
+    r[2] = r[3].rotate_left(1);
+    r[3] = r[5] & POOL[1950];
+    r[13] = r[3] ^ r[2];
+    r[10] = r[1] ^ r[13];
+    r[13] = r[6] & POOL[1951];
+    r[1] = r[3] ^ POOL[1952];
+    r[3] = r[1] ^ POOL[1953];
+    r[1] = r[3] ^ POOL[1954];
+    r[3] = r[2] ^ POOL[1955];
+    r[2] = {
+        let (t, s) = (POOL[1956], POOL[1957]);
+        let (ma, mb) = (t ^ s ^ POOL[1958], t ^ s ^ POOL[1959]);
+        let mut z = t;
+        z ^= r[1] & mb;
+        z ^= r[3] & ma;
+        z ^= r[1] & r[3];
+        z
+    };
+    r[3] = r[13] ^ r[2];
+    r[2] = r[3].rotate_left(1);
+    r[3] = r[5] & POOL[1960];
+    r[13] = r[3] ^ r[2];
+    r[1] = r[10] ^ r[13];
+    r[13] = r[6] & POOL[1961];
+    r[10] = r[3] ^ POOL[1962];
+    r[3] = r[10] ^ POOL[1963];
+    r[10] = r[3] ^ POOL[1964];
+    r[3] = r[2] ^ POOL[1965];
+    r[2] = {
+        let (t, s) = (POOL[1966], POOL[1967]);
+        let (ma, mb) = (t ^ s ^ POOL[1968], t ^ s ^ POOL[1969]);
+        let mut z = t;
+        z ^= r[10] & mb;
+        z ^= r[3] & ma;
+        z ^= r[10] & r[3];
+        z
+    };
+    r[3] = r[13] ^ r[2];
+    r[2] = r[3].rotate_left(1);
+    r[3] = r[5] & POOL[1970];
+    r[13] = r[3] ^ r[2];
+    r[10] = r[1] ^ r[13];
+    r[13] = r[6] & POOL[1971];
+    r[1] = r[3] ^ POOL[1972];
+    r[3] = r[1] ^ POOL[1973];
+    r[1] = r[3] ^ POOL[1974];
+    r[3] = r[2] ^ POOL[1975];
+    r[2] = {
+        let (t, s) = (POOL[1976], POOL[1977]);
+        let (ma, mb) = (t ^ s ^ POOL[1978], t ^ s ^ POOL[1979]);
+        let mut z = t;
+        z ^= r[1] & mb;
+        z ^= r[3] & ma;
+        z ^= r[1] & r[3];
+        z
+    };
+    r[3] = r[13] ^ r[2];
+    r[2] = r[3].rotate_left(1);
+    r[3] = r[5] & POOL[1980];
+    r[13] = r[3] ^ r[2];
+    r[1] = r[10] ^ r[13];
+    r[13] = r[6] & POOL[1981];
+    r[10] = r[3] ^ POOL[1982];
+    r[3] = r[10] ^ POOL[1983];
+    r[10] = r[3] ^ POOL[1984];
+    r[3] = r[2] ^ POOL[1985];
+    r[2] = {
+        let (t, s) = (POOL[1986], POOL[1987]);
+        let (ma, mb) = (t ^ s ^ POOL[1988], t ^ s ^ POOL[1989]);
+        let mut z = t;
+        z ^= r[10] & mb;
+        z ^= r[3] & ma;
+        z ^= r[10] & r[3];
+        z
+    };
+    r[3] = r[13] ^ r[2];
+    r[2] = r[3].rotate_left(1);
+    r[3] = r[5] & POOL[1990];
+    r[13] = r[3] ^ r[2];
+    r[10] = r[1] ^ r[13];
+    r[13] = r[6] & POOL[1991];
+    r[1] = r[3] ^ POOL[1992];
+    r[3] = r[1] ^ POOL[1993];
+    r[1] = r[3] ^ POOL[1994];
+    r[3] = r[2] ^ POOL[1995];
+    r[2] = {
+        let (t, s) = (POOL[1996], POOL[1997]);
+        let (ma, mb) = (t ^ s ^ POOL[1998], t ^ s ^ POOL[1999]);
+        let mut z = t;
+        z ^= r[1] & mb;
+        z ^= r[3] & ma;
+        z ^= r[1] & r[3];
+        z
+    };
+    r[3] = r[13] ^ r[2];
+    r[2] = r[3].rotate_left(1);
+    r[3] = r[5] & POOL[2000];
+    r[13] = r[3] ^ r[2];
+    r[1] = r[10] ^ r[13];
+    r[13] = r[6] & POOL[2001];
+    r[10] = r[3] ^ POOL[2002];
+    r[3] = r[10] ^ POOL[2003];
+    r[10] = r[3] ^ POOL[2004];
+    r[3] = r[2] ^ POOL[2005];
+    r[2] = {
+        let (t, s) = (POOL[2006], POOL[2007]);
+        let (ma, mb) = (t ^ s ^ POOL[2008], t ^ s ^ POOL[2009]);
+        let mut z = t;
+        z ^= r[10] & mb;
+        z ^= r[3] & ma;
+        z ^= r[10] & r[3];
+        z
+    };
+    r[3] = r[13] ^ r[2];
+    r[2] = r[3].rotate_left(1);
+    r[3] = r[5] & POOL[2010];
+    r[13] = r[3] ^ r[2];
+    r[10] = r[1] ^ r[13];
+    r[13] = r[6] & POOL[2011];
+    r[1] = r[3] ^ POOL[2012];
+    r[3] = r[1] ^ POOL[2013];
+    r[1] = r[3] ^ POOL[2014];
+    r[3] = r[2] ^ POOL[2015];
+    r[2] = {
+        let (t, s) = (POOL[2016], POOL[2017]);
+        let (ma, mb) = (t ^ s ^ POOL[2018], t ^ s ^ POOL[2019]);
+        let mut z = t;
+        z ^= r[1] & mb;
+        z ^= r[3] & ma;
+        z ^= r[1] & r[3];
+        z
+    };
+    r[3] = r[13] ^ r[2];
+    r[2] = r[3].rotate_left(1);
+    r[3] = r[5] & POOL[2020];
+    r[13] = r[3] ^ r[2];
+    r[1] = r[10] ^ r[13];
+    r[13] = r[6] & POOL[2021];
+    r[10] = r[3] ^ POOL[2022];
+    r[3] = r[10] ^ POOL[2023];
+    r[10] = r[3] ^ POOL[2024];
+    r[3] = r[2] ^ POOL[2025];
+    r[2] = {
+        let (t, s) = (POOL[2026], POOL[2027]);
+        let (ma, mb) = (t ^ s ^ POOL[2028], t ^ s ^ POOL[2029]);
+        let mut z = t;
+        z ^= r[10] & mb;
+        z ^= r[3] & ma;
+        z ^= r[10] & r[3];
+        z
+    };
+    r[3] = r[13] ^ r[2];
+    r[2] = r[3].rotate_left(1);
+    r[3] = r[5] & POOL[2030];
+    r[13] = r[3] ^ r[2];
+    r[10] = r[1] ^ r[13];
+    r[13] = r[6] & POOL[2031];
+    r[1] = r[3] ^ POOL[2032];
+    r[3] = r[1] ^ POOL[2033];
+    r[1] = r[3] ^ POOL[2034];
+    r[3] = r[2] ^ POOL[2035];
+    r[2] = {
+        let (t, s) = (POOL[2036], POOL[2037]);
+        let (ma, mb) = (t ^ s ^ POOL[2038], t ^ s ^ POOL[2039]);
+        let mut z = t;
+        z ^= r[1] & mb;
+        z ^= r[3] & ma;
+        z ^= r[1] & r[3];
+        z
+    };
+    r[3] = r[13] ^ r[2];
+    r[2] = r[3].rotate_left(1);
+    r[3] = r[5] & POOL[2040];
+    r[13] = r[3] ^ r[2];
+    r[1] = r[10] ^ r[13];
+    r[13] = r[6] & POOL[2041];
+    r[10] = r[3] ^ POOL[2042];
+    r[3] = r[10] ^ POOL[2043];
+    r[10] = r[3] ^ POOL[2044];
+    r[3] = r[2] ^ POOL[2045];
+    r[2] = {
+        let (t, s) = (POOL[2046], POOL[2047]);
+        let (ma, mb) = (t ^ s ^ POOL[2048], t ^ s ^ POOL[2049]);
+        let mut z = t;
+        z ^= r[10] & mb;
+        z ^= r[3] & ma;
+        z ^= r[10] & r[3];
+        z
+    };
+    r[3] = r[13] ^ r[2];
+    r[2] = r[3].rotate_left(1);
+    r[3] = r[5] & POOL[2050];
+    r[13] = r[3] ^ r[2];
+    r[10] = r[1] ^ r[13];
+    r[13] = r[6] & POOL[2051];
+    r[1] = r[3] ^ POOL[2052];
+    r[3] = r[1] ^ POOL[2053];
+    r[1] = r[3] ^ POOL[2054];
+    r[3] = r[2] ^ POOL[2055];
+    r[2] = {
+        let (t, s) = (POOL[2056], POOL[2057]);
+        let (ma, mb) = (t ^ s ^ POOL[2058], t ^ s ^ POOL[2059]);
+        let mut z = t;
+        z ^= r[1] & mb;
+        z ^= r[3] & ma;
+        z ^= r[1] & r[3];
+        z
+    };
+    r[3] = r[13] ^ r[2];
+    r[2] = r[3].rotate_left(1);
+    r[3] = r[5] & POOL[2060];
+    r[13] = r[3] ^ r[2];
+    r[1] = r[10] ^ r[13];
+    r[13] = r[6] & POOL[2061];
+    r[10] = r[3] ^ POOL[2062];
+    r[3] = r[10] ^ POOL[2063];
+    r[10] = r[3] ^ POOL[2064];
+    r[3] = r[2] ^ POOL[2065];
+    r[2] = {
+        let (t, s) = (POOL[2066], POOL[2067]);
+        let (ma, mb) = (t ^ s ^ POOL[2068], t ^ s ^ POOL[2069]);
+        let mut z = t;
+        z ^= r[10] & mb;
+        z ^= r[3] & ma;
+        z ^= r[10] & r[3];
+        z
+    };
+    r[3] = r[13] ^ r[2];
+    r[2] = r[3].rotate_left(1);
+    r[3] = r[5] & POOL[2070];
+    r[13] = r[3] ^ r[2];
+    r[10] = r[1] ^ r[13];
+    r[13] = r[6] & POOL[2071];
+    r[1] = r[3] ^ POOL[2072];
+    r[3] = r[1] ^ POOL[2073];
+    r[1] = r[3] ^ POOL[2074];
+    r[3] = r[2] ^ POOL[2075];
+    r[2] = {
+        let (t, s) = (POOL[2076], POOL[2077]);
+        let (ma, mb) = (t ^ s ^ POOL[2078], t ^ s ^ POOL[2079]);
+        let mut z = t;
+        z ^= r[1] & mb;
+        z ^= r[3] & ma;
+        z ^= r[1] & r[3];
+        z
+    };
+    r[3] = r[13] ^ r[2];
+    r[2] = r[3].rotate_left(1);
+    r[3] = r[5] & POOL[2080];
+    r[13] = r[3] ^ r[2];
+    r[1] = r[10] ^ r[13];
+    r[13] = r[6] & POOL[2081];
+    r[10] = r[3] ^ POOL[2082];
+    r[3] = r[10] ^ POOL[2083];
+    r[10] = r[3] ^ POOL[2084];
+    r[3] = r[2] ^ POOL[2085];
+    r[2] = {
+        let (t, s) = (POOL[2086], POOL[2087]);
+        let (ma, mb) = (t ^ s ^ POOL[2088], t ^ s ^ POOL[2089]);
+        let mut z = t;
+        z ^= r[10] & mb;
+        z ^= r[3] & ma;
+        z ^= r[10] & r[3];
+        z
+    };
+    r[3] = r[13] ^ r[2];
+    r[2] = r[3].rotate_left(1);
+    r[3] = r[5] & POOL[2090];
+    r[13] = r[3] ^ r[2];
+    r[10] = r[1] ^ r[13];
+    r[13] = r[6] & POOL[2091];
+    r[1] = r[3] ^ POOL[2092];
+    r[3] = r[1] ^ POOL[2093];
+    r[1] = r[3] ^ POOL[2094];
+    r[3] = r[2] ^ POOL[2095];
+    r[2] = {
+        let (t, s) = (POOL[2096], POOL[2097]);
+        let (ma, mb) = (t ^ s ^ POOL[2098], t ^ s ^ POOL[2099]);
+        let mut z = t;
+        z ^= r[1] & mb;
+        z ^= r[3] & ma;
+        z ^= r[1] & r[3];
+        z
+    };
+    r[3] = r[13] ^ r[2];
+    r[2] = r[3].rotate_left(1);
+    r[3] = r[5] & POOL[2100];
+    r[13] = r[3] ^ r[2];
+    r[1] = r[10] ^ r[13];
+    r[13] = r[6] & POOL[2101];
+    r[10] = r[3] ^ POOL[2102];
+    r[3] = r[10] ^ POOL[2103];
+    r[10] = r[3] ^ POOL[2104];
+    r[3] = r[2] ^ POOL[2105];
+    r[2] = {
+        let (t, s) = (POOL[2106], POOL[2107]);
+        let (ma, mb) = (t ^ s ^ POOL[2108], t ^ s ^ POOL[2109]);
+        let mut z = t;
+        z ^= r[10] & mb;
+        z ^= r[3] & ma;
+        z ^= r[10] & r[3];
+        z
+    };
+    r[3] = r[13] ^ r[2];
+    r[2] = r[3].rotate_left(1);
+    r[3] = r[5] & POOL[2110];
+    r[13] = r[3] ^ r[2];
+    r[10] = r[1] ^ r[13];
+    r[13] = r[6] & POOL[2111];
+    r[1] = r[3] ^ POOL[2112];
+    r[3] = r[1] ^ POOL[2113];
+    r[1] = r[3] ^ POOL[2114];
+    r[3] = r[2] ^ POOL[2115];
+    r[2] = {
+        let (t, s) = (POOL[2116], POOL[2117]);
+        let (ma, mb) = (t ^ s ^ POOL[2118], t ^ s ^ POOL[2119]);
+        let mut z = t;
+        z ^= r[1] & mb;
+        z ^= r[3] & ma;
+        z ^= r[1] & r[3];
+        z
+    };
+    r[3] = r[13] ^ r[2];
+    r[2] = r[3].rotate_left(1);
+    r[3] = r[5] & POOL[2120];
+    r[13] = r[3] ^ r[2];
+    r[1] = r[10] ^ r[13];
+    r[13] = r[6] & POOL[2121];
+    r[10] = r[3] ^ POOL[2122];
+    r[3] = r[10] ^ POOL[2123];
+    r[10] = r[3] ^ POOL[2124];
+    r[3] = r[2] ^ POOL[2125];
+    r[2] = {
+        let (t, s) = (POOL[2126], POOL[2127]);
+        let (ma, mb) = (t ^ s ^ POOL[2128], t ^ s ^ POOL[2129]);
+        let mut z = t;
+        z ^= r[10] & mb;
+        z ^= r[3] & ma;
+        z ^= r[10] & r[3];
+        z
+    };
+    r[3] = r[13] ^ r[2];
+    r[2] = r[3].rotate_left(1);
+    r[3] = r[5] & POOL[2130];
+    r[13] = r[3] ^ r[2];
+    r[10] = r[1] ^ r[13];
+    r[13] = r[6] & POOL[2131];
+    r[1] = r[3] ^ POOL[2132];
+    r[3] = r[1] ^ POOL[2133];
+    r[1] = r[3] ^ POOL[2134];
+    r[3] = r[2] ^ POOL[2135];
+    r[2] = {
+        let (t, s) = (POOL[2136], POOL[2137]);
+        let (ma, mb) = (t ^ s ^ POOL[2138], t ^ s ^ POOL[2139]);
+        let mut z = t;
+        z ^= r[1] & mb;
+        z ^= r[3] & ma;
+        z ^= r[1] & r[3];
+        z
+    };
+    r[3] = r[13] ^ r[2];
+    r[2] = r[3].rotate_left(1);
+    r[3] = r[5] & POOL[2140];
+    r[13] = r[3] ^ r[2];
+    r[1] = r[10] ^ r[13];
+    r[13] = r[6] & POOL[2141];
+    r[10] = r[3] ^ POOL[2142];
+    r[3] = r[10] ^ POOL[2143];
+    r[10] = r[3] ^ POOL[2144];
+    r[3] = r[2] ^ POOL[2145];
+    r[2] = {
+        let (t, s) = (POOL[2146], POOL[2147]);
+        let (ma, mb) = (t ^ s ^ POOL[2148], t ^ s ^ POOL[2149]);
+        let mut z = t;
+        z ^= r[10] & mb;
+        z ^= r[3] & ma;
+        z ^= r[10] & r[3];
+        z
+    };
+    r[3] = r[13] ^ r[2];
+    r[2] = r[3].rotate_left(1);
+    r[3] = r[5] & POOL[2150];
+    r[13] = r[3] ^ r[2];
+    r[10] = r[1] ^ r[13];
+    r[13] = r[6] & POOL[2151];
+    r[1] = r[3] ^ POOL[2152];
+    r[3] = r[1] ^ POOL[2153];
+    r[1] = r[3] ^ POOL[2154];
+    r[3] = r[2] ^ POOL[2155];
+    r[2] = {
+        let (t, s) = (POOL[2156], POOL[2157]);
+        let (ma, mb) = (t ^ s ^ POOL[2158], t ^ s ^ POOL[2159]);
+        let mut z = t;
+        z ^= r[1] & mb;
+        z ^= r[3] & ma;
+        z ^= r[1] & r[3];
+        z
+    };
+    r[3] = r[13] ^ r[2];
+    r[2] = r[3].rotate_left(1);
+    r[3] = r[5] & POOL[2160];
+    r[13] = r[3] ^ r[2];
+    r[1] = r[10] ^ r[13];
+    r[13] = r[6] & POOL[2161];
+    r[10] = r[3] ^ POOL[2162];
+    r[3] = r[10] ^ POOL[2163];
+    r[10] = r[3] ^ POOL[2164];
+    r[3] = r[2] ^ POOL[2165];
+    r[2] = {
+        let (t, s) = (POOL[2166], POOL[2167]);
+        let (ma, mb) = (t ^ s ^ POOL[2168], t ^ s ^ POOL[2169]);
+        let mut z = t;
+        z ^= r[10] & mb;
+        z ^= r[3] & ma;
+        z ^= r[10] & r[3];
+        z
+    };
+    r[3] = r[13] ^ r[2];
+    r[2] = r[3].rotate_left(1);
+    r[3] = r[5] & POOL[2170];
+    r[13] = r[3] ^ r[2];
+    r[10] = r[1] ^ r[13];
+    r[13] = r[6] & POOL[2171];
+    r[1] = r[3] ^ POOL[2172];
+    r[3] = r[1] ^ POOL[2173];
+    r[1] = r[3] ^ POOL[2174];
+    r[3] = r[2] ^ POOL[2175];
+    r[2] = {
+        let (t, s) = (POOL[2176], POOL[2177]);
+        let (ma, mb) = (t ^ s ^ POOL[2178], t ^ s ^ POOL[2179]);
+        let mut z = t;
+        z ^= r[1] & mb;
+        z ^= r[3] & ma;
+        z ^= r[1] & r[3];
+        z
+    };
+    r[3] = r[13] ^ r[2];
+    r[2] = r[3].rotate_left(1);
+    r[3] = r[5] & POOL[2180];
+    r[13] = r[3] ^ r[2];
+    r[1] = r[10] ^ r[13];
+    r[13] = r[6] & POOL[2181];
+    r[6] = r[3] ^ POOL[2182];
+    r[3] = r[6] ^ POOL[2183];
+    r[6] = r[3] ^ POOL[2184];
+    r[3] = r[2] ^ POOL[2185];
+    r[2] = {
+        let (t, s) = (POOL[2186], POOL[2187]);
+        let (ma, mb) = (t ^ s ^ POOL[2188], t ^ s ^ POOL[2189]);
+        let mut z = t;
+        z ^= r[6] & mb;
+        z ^= r[3] & ma;
+        z ^= r[6] & r[3];
+        z
+    };
+    r[3] = r[13] ^ r[2];
+    r[2] = r[3].rotate_left(1);
+    r[3] = r[5] & POOL[2190];
+    r[5] = r[3] ^ r[2];
+    r[2] = r[1] ^ r[5];
+    r[5] = r[4] ^ r[2];
+    r[4] = r[5].rotate_left(7);
+    r[5] = r[0] ^ r[11];
+    r[11] = r[5].rotate_left(8);
+    r[5] = r[8] ^ POOL[2191];
+    r[0] = r[11] ^ POOL[2192];
+    r[3] = {
+        let (t, s) = (POOL[2193], POOL[2194]);
+        let (ma, mb) = (t ^ s ^ POOL[2195], t ^ s ^ POOL[2196]);
+        let mut z = t;
+        z ^= r[5] & mb;
+        z ^= r[0] & ma;
+        z ^= r[5] & r[0];
+        z
+    };
+    r[5] = r[8] ^ r[11];
+    r[8] = r[5] & POOL[2197];
+    r[0] = r[3] & POOL[2198];
+    r[6] = r[0].rotate_left(1);
+    r[0] = r[5] & POOL[2199];
+    r[13] = r[0] ^ r[6];
+    r[1] = r[8] ^ r[13];
+    r[8] = r[3] & POOL[2200];
+    r[13] = r[0] ^ POOL[2201];
+    r[0] = r[13] ^ POOL[2202];
+    r[13] = r[0] ^ POOL[2203];
+    r[0] = r[6] ^ POOL[2204];
+    r[6] = r[0] ^ POOL[2205];
+    r[0] = r[6] ^ POOL[2206];
+    r[6] = {
+        let (t, s) = (POOL[2207], POOL[2208]);
+        let (ma, mb) = (t ^ s ^ POOL[2209], t ^ s ^ POOL[2210]);
+        let mut z = t;
+        z ^= r[13] & mb;
+        z ^= r[0] & ma;
+        z ^= r[13] & r[0];
+        z
+    };
+    r[0] = r[8] ^ r[6];
+    r[8] = r[0].rotate_left(1);
+    r[0] = r[5] & POOL[2211];
+    r[6] = r[0] ^ r[8];
+    r[13] = r[1] ^ r[6];
+    r[6] = r[3] & POOL[2212];
+    r[1] = r[0] ^ POOL[2213];
+    r[0] = r[1] ^ POOL[2214];
+    r[1] = r[0] ^ POOL[2215];
+    r[0] = r[8] ^ POOL[2216];
+    r[8] = {
+        let (t, s) = (POOL[2217], POOL[2218]);
+        let (ma, mb) = (t ^ s ^ POOL[2219], t ^ s ^ POOL[2220]);
+        let mut z = t;
+        z ^= r[1] & mb;
+        z ^= r[0] & ma;
+        z ^= r[1] & r[0];
+        z
+    };
+    r[0] = r[6] ^ r[8];
+    r[8] = r[0].rotate_left(1);
+    r[0] = r[5] & POOL[2221];
+    r[6] = r[0] ^ r[8];
+    r[1] = r[13] ^ r[6];
+    r[6] = r[3] & POOL[2222];
+    r[13] = r[0] ^ POOL[2223];
+    r[0] = r[13] ^ POOL[2224];
+    r[13] = r[0] ^ POOL[2225];
+    r[0] = r[8] ^ POOL[2226];
+    r[8] = {
+        let (t, s) = (POOL[2227], POOL[2228]);
+        let (ma, mb) = (t ^ s ^ POOL[2229], t ^ s ^ POOL[2230]);
+        let mut z = t;
+        z ^= r[13] & mb;
+        z ^= r[0] & ma;
+        z ^= r[13] & r[0];
+        z
+    };
+    r[0] = r[6] ^ r[8];
+    r[8] = r[0].rotate_left(1);
+    r[0] = r[5] & POOL[2231];
+    r[6] = r[0] ^ r[8];
+    r[13] = r[1] ^ r[6];
+    r[6] = r[3] & POOL[2232];
+    r[1] = r[0] ^ POOL[2233];
+    r[0] = r[1] ^ POOL[2234];
+    r[1] = r[0] ^ POOL[2235];
+    r[0] = r[8] ^ POOL[2236];
+    r[8] = {
+        let (t, s) = (POOL[2237], POOL[2238]);
+        let (ma, mb) = (t ^ s ^ POOL[2239], t ^ s ^ POOL[2240]);
+        let mut z = t;
+        z ^= r[1] & mb;
+        z ^= r[0] & ma;
+        z ^= r[1] & r[0];
+        z
+    };
+    r[0] = r[6] ^ r[8];
+    r[8] = r[0].rotate_left(1);
+    r[0] = r[5] & POOL[2241];
+    r[6] = r[0] ^ r[8];
+    r[1] = r[13] ^ r[6];
+    r[6] = r[3] & POOL[2242];
+    r[13] = r[0] ^ POOL[2243];
+    r[0] = r[13] ^ POOL[2244];
+    r[13] = r[0] ^ POOL[2245];
+    r[0] = r[8] ^ POOL[2246];
+    r[8] = {
+        let (t, s) = (POOL[2247], POOL[2248]);
+        let (ma, mb) = (t ^ s ^ POOL[2249], t ^ s ^ POOL[2250]);
+        let mut z = t;
+        z ^= r[13] & mb;
+        z ^= r[0] & ma;
+        z ^= r[13] & r[0];
+        z
+    };
+    r[0] = r[6] ^ r[8];
+    r[8] = r[0].rotate_left(1);
+    r[0] = r[5] & POOL[2251];
+    r[6] = r[0] ^ r[8];
+    r[13] = r[1] ^ r[6];
+    r[6] = r[3] & POOL[2252];
+    r[1] = r[0] ^ POOL[2253];
+    r[0] = r[1] ^ POOL[2254];
+    r[1] = r[0] ^ POOL[2255];
+    r[0] = r[8] ^ POOL[2256];
+    r[8] = {
+        let (t, s) = (POOL[2257], POOL[2258]);
+        let (ma, mb) = (t ^ s ^ POOL[2259], t ^ s ^ POOL[2260]);
+        let mut z = t;
+        z ^= r[1] & mb;
+        z ^= r[0] & ma;
+        z ^= r[1] & r[0];
+        z
+    };
+    r[0] = r[6] ^ r[8];
+    r[8] = r[0].rotate_left(1);
+    r[0] = r[5] & POOL[2261];
+    r[6] = r[0] ^ r[8];
+    r[1] = r[13] ^ r[6];
+    r[6] = r[3] & POOL[2262];
+    r[13] = r[0] ^ POOL[2263];
+    r[0] = r[13] ^ POOL[2264];
+    r[13] = r[0] ^ POOL[2265];
+    r[0] = r[8] ^ POOL[2266];
+    r[8] = {
+        let (t, s) = (POOL[2267], POOL[2268]);
+        let (ma, mb) = (t ^ s ^ POOL[2269], t ^ s ^ POOL[2270]);
+        let mut z = t;
+        z ^= r[13] & mb;
+        z ^= r[0] & ma;
+        z ^= r[13] & r[0];
+        z
+    };
+    r[0] = r[6] ^ r[8];
+    r[8] = r[0].rotate_left(1);
+    r[0] = r[5] & POOL[2271];
+    r[6] = r[0] ^ r[8];
+    r[13] = r[1] ^ r[6];
+    r[6] = r[3] & POOL[2272];
+    r[1] = r[0] ^ POOL[2273];
+    r[0] = r[1] ^ POOL[2274];
+    r[1] = r[0] ^ POOL[2275];
+    r[0] = r[8] ^ POOL[2276];
+    r[8] = {
+        let (t, s) = (POOL[2277], POOL[2278]);
+        let (ma, mb) = (t ^ s ^ POOL[2279], t ^ s ^ POOL[2280]);
+        let mut z = t;
+        z ^= r[1] & mb;
+        z ^= r[0] & ma;
+        z ^= r[1] & r[0];
+        z
+    };
+    r[0] = r[6] ^ r[8];
+    r[8] = r[0].rotate_left(1);
+    r[0] = r[5] & POOL[2281];
+    r[6] = r[0] ^ r[8];
+    r[1] = r[13] ^ r[6];
+    r[6] = r[3] & POOL[2282];
+    r[13] = r[0] ^ POOL[2283];
+    r[0] = r[13] ^ POOL[2284];
+    r[13] = r[0] ^ POOL[2285];
+    r[0] = r[8] ^ POOL[2286];
+    r[8] = {
+        let (t, s) = (POOL[2287], POOL[2288]);
+        let (ma, mb) = (t ^ s ^ POOL[2289], t ^ s ^ POOL[2290]);
+        let mut z = t;
+        z ^= r[13] & mb;
+        z ^= r[0] & ma;
+        z ^= r[13] & r[0];
+        z
+    };
+    r[0] = r[6] ^ r[8];
+    r[8] = r[0].rotate_left(1);
+    r[0] = r[5] & POOL[2291];
+    r[6] = r[0] ^ r[8];
+    r[13] = r[1] ^ r[6];
+    r[6] = r[3] & POOL[2292];
+    r[1] = r[0] ^ POOL[2293];
+    r[0] = r[1] ^ POOL[2294];
+    r[1] = r[0] ^ POOL[2295];
+    r[0] = r[8] ^ POOL[2296];
+    r[8] = {
+        let (t, s) = (POOL[2297], POOL[2298]);
+        let (ma, mb) = (t ^ s ^ POOL[2299], t ^ s ^ POOL[2300]);
+        let mut z = t;
+        z ^= r[1] & mb;
+        z ^= r[0] & ma;
+        z ^= r[1] & r[0];
+        z
+    };
+    r[0] = r[6] ^ r[8];
+    r[8] = r[0].rotate_left(1);
+    r[0] = r[5] & POOL[2301];
+    r[6] = r[0] ^ r[8];
+    r[1] = r[13] ^ r[6];
+    r[6] = r[3] & POOL[2302];
+    r[13] = r[0] ^ POOL[2303];
+    r[0] = r[13] ^ POOL[2304];
+    r[13] = r[0] ^ POOL[2305];
+    r[0] = r[8] ^ POOL[2306];
+    r[8] = {
+        let (t, s) = (POOL[2307], POOL[2308]);
+        let (ma, mb) = (t ^ s ^ POOL[2309], t ^ s ^ POOL[2310]);
+        let mut z = t;
+        z ^= r[13] & mb;
+        z ^= r[0] & ma;
+        z ^= r[13] & r[0];
+        z
+    };
+    r[0] = r[6] ^ r[8];
+    r[8] = r[0].rotate_left(1);
+    r[0] = r[5] & POOL[2311];
+    r[6] = r[0] ^ r[8];
+    r[13] = r[1] ^ r[6];
+    r[6] = r[3] & POOL[2312];
+    r[1] = r[0] ^ POOL[2313];
+    r[0] = r[1] ^ POOL[2314];
+    r[1] = r[0] ^ POOL[2315];
+    r[0] = r[8] ^ POOL[2316];
+    r[8] = {
+        let (t, s) = (POOL[2317], POOL[2318]);
+        let (ma, mb) = (t ^ s ^ POOL[2319], t ^ s ^ POOL[2320]);
+        let mut z = t;
+        z ^= r[1] & mb;
+        z ^= r[0] & ma;
+        z ^= r[1] & r[0];
+        z
+    };
+    r[0] = r[6] ^ r[8];
+    r[8] = r[0].rotate_left(1);
+    r[0] = r[5] & POOL[2321];
+    r[6] = r[0] ^ r[8];
+    r[1] = r[13] ^ r[6];
+    r[6] = r[3] & POOL[2322];
+    r[13] = r[0] ^ POOL[2323];
+    r[0] = r[13] ^ POOL[2324];
+    r[13] = r[0] ^ POOL[2325];
+    r[0] = r[8] ^ POOL[2326];
+    r[8] = {
+        let (t, s) = (POOL[2327], POOL[2328]);
+        let (ma, mb) = (t ^ s ^ POOL[2329], t ^ s ^ POOL[2330]);
+        let mut z = t;
+        z ^= r[13] & mb;
+        z ^= r[0] & ma;
+        z ^= r[13] & r[0];
+        z
+    };
+    r[0] = r[6] ^ r[8];
+    r[8] = r[0].rotate_left(1);
+    r[0] = r[5] & POOL[2331];
+    r[6] = r[0] ^ r[8];
+    r[13] = r[1] ^ r[6];
+    r[6] = r[3] & POOL[2332];
+    r[1] = r[0] ^ POOL[2333];
+    r[0] = r[1] ^ POOL[2334];
+    r[1] = r[0] ^ POOL[2335];
+    r[0] = r[8] ^ POOL[2336];
+    r[8] = {
+        let (t, s) = (POOL[2337], POOL[2338]);
+        let (ma, mb) = (t ^ s ^ POOL[2339], t ^ s ^ POOL[2340]);
+        let mut z = t;
+        z ^= r[1] & mb;
+        z ^= r[0] & ma;
+        z ^= r[1] & r[0];
+        z
+    };
+    r[0] = r[6] ^ r[8];
+    r[8] = r[0].rotate_left(1);
+    r[0] = r[5] & POOL[2341];
+    r[6] = r[0] ^ r[8];
+    r[1] = r[13] ^ r[6];
+    r[6] = r[3] & POOL[2342];
+    r[13] = r[0] ^ POOL[2343];
+    r[0] = r[13] ^ POOL[2344];
+    r[13] = r[0] ^ POOL[2345];
+    r[0] = r[8] ^ POOL[2346];
+    r[8] = {
+        let (t, s) = (POOL[2347], POOL[2348]);
+        let (ma, mb) = (t ^ s ^ POOL[2349], t ^ s ^ POOL[2350]);
+        let mut z = t;
+        z ^= r[13] & mb;
+        z ^= r[0] & ma;
+        z ^= r[13] & r[0];
+        z
+    };
+    r[0] = r[6] ^ r[8];
+    r[8] = r[0].rotate_left(1);
+    r[0] = r[5] & POOL[2351];
+    r[6] = r[0] ^ r[8];
+    r[13] = r[1] ^ r[6];
+    r[6] = r[3] & POOL[2352];
+    r[1] = r[0] ^ POOL[2353];
+    r[0] = r[1] ^ POOL[2354];
+    r[1] = r[0] ^ POOL[2355];
+    r[0] = r[8] ^ POOL[2356];
+    r[8] = {
+        let (t, s) = (POOL[2357], POOL[2358]);
+        let (ma, mb) = (t ^ s ^ POOL[2359], t ^ s ^ POOL[2360]);
+        let mut z = t;
+        z ^= r[1] & mb;
+        z ^= r[0] & ma;
+        z ^= r[1] & r[0];
+        z
+    };
+    r[0] = r[6] ^ r[8];
+    r[8] = r[0].rotate_left(1);
+    r[0] = r[5] & POOL[2361];
+    r[6] = r[0] ^ r[8];
+    r[1] = r[13] ^ r[6];
+    r[6] = r[3] & POOL[2362];
+    r[13] = r[0] ^ POOL[2363];
+    r[0] = r[13] ^ POOL[2364];
+    r[13] = r[0] ^ POOL[2365];
+    r[0] = r[8] ^ POOL[2366];
+    r[8] = {
+        let (t, s) = (POOL[2367], POOL[2368]);
+        let (ma, mb) = (t ^ s ^ POOL[2369], t ^ s ^ POOL[2370]);
+        let mut z = t;
+        z ^= r[13] & mb;
+        z ^= r[0] & ma;
+        z ^= r[13] & r[0];
+        z
+    };
+    r[0] = r[6] ^ r[8];
+    r[8] = r[0].rotate_left(1);
+    r[0] = r[5] & POOL[2371];
+    r[6] = r[0] ^ r[8];
+    r[13] = r[1] ^ r[6];
+    r[6] = r[3] & POOL[2372];
+    r[1] = r[0] ^ POOL[2373];
+    r[0] = r[1] ^ POOL[2374];
+    r[1] = r[0] ^ POOL[2375];
+    r[0] = r[8] ^ POOL[2376];
+    r[8] = {
+        let (t, s) = (POOL[2377], POOL[2378]);
+        let (ma, mb) = (t ^ s ^ POOL[2379], t ^ s ^ POOL[2380]);
+        let mut z = t;
+        z ^= r[1] & mb;
+        z ^= r[0] & ma;
+        z ^= r[1] & r[0];
+        z
+    };
+    r[0] = r[6] ^ r[8];
+    r[8] = r[0].rotate_left(1);
+    r[0] = r[5] & POOL[2381];
+    r[6] = r[0] ^ r[8];
+    r[1] = r[13] ^ r[6];
+    r[6] = r[3] & POOL[2382];
+    r[13] = r[0] ^ POOL[2383];
+    r[0] = r[13] ^ POOL[2384];
+    r[13] = r[0] ^ POOL[2385];
+    r[0] = r[8] ^ POOL[2386];
+    r[8] = {
+        let (t, s) = (POOL[2387], POOL[2388]);
+        let (ma, mb) = (t ^ s ^ POOL[2389], t ^ s ^ POOL[2390]);
+        let mut z = t;
+        z ^= r[13] & mb;
+        z ^= r[0] & ma;
+        z ^= r[13] & r[0];
+        z
+    };
+    r[0] = r[6] ^ r[8];
+    r[8] = r[0].rotate_left(1);
+    r[0] = r[5] & POOL[2391];
+    r[6] = r[0] ^ r[8];
+    r[13] = r[1] ^ r[6];
+    r[6] = r[3] & POOL[2392];
+    r[1] = r[0] ^ POOL[2393];
+    r[0] = r[1] ^ POOL[2394];
+    r[1] = r[0] ^ POOL[2395];
+    r[0] = r[8] ^ POOL[2396];
+    r[8] = {
+        let (t, s) = (POOL[2397], POOL[2398]);
+        let (ma, mb) = (t ^ s ^ POOL[2399], t ^ s ^ POOL[2400]);
+        let mut z = t;
+        z ^= r[1] & mb;
+        z ^= r[0] & ma;
+        z ^= r[1] & r[0];
+        z
+    };
+    r[0] = r[6] ^ r[8];
+    r[8] = r[0].rotate_left(1);
+    r[0] = r[5] & POOL[2401];
+    r[6] = r[0] ^ r[8];
+    r[1] = r[13] ^ r[6];
+    r[6] = r[3] & POOL[2402];
+    r[13] = r[0] ^ POOL[2403];
+    r[0] = r[13] ^ POOL[2404];
+    r[13] = r[0] ^ POOL[2405];
+    r[0] = r[8] ^ POOL[2406];
+    r[8] = {
+        let (t, s) = (POOL[2407], POOL[2408]);
+        let (ma, mb) = (t ^ s ^ POOL[2409], t ^ s ^ POOL[2410]);
+        let mut z = t;
+        z ^= r[13] & mb;
+        z ^= r[0] & ma;
+        z ^= r[13] & r[0];
+        z
+    };
+    r[0] = r[6] ^ r[8];
+    r[8] = r[0].rotate_left(1);
+    r[0] = r[5] & POOL[2411];
+    r[6] = r[0] ^ r[8];
+    r[13] = r[1] ^ r[6];
+    r[6] = r[3] & POOL[2412];
+    r[1] = r[0] ^ POOL[2413];
+    r[0] = r[1] ^ POOL[2414];
+    r[1] = r[0] ^ POOL[2415];
+    r[0] = r[8] ^ POOL[2416];
+    r[8] = {
+        let (t, s) = (POOL[2417], POOL[2418]);
+        let (ma, mb) = (t ^ s ^ POOL[2419], t ^ s ^ POOL[2420]);
+        let mut z = t;
+        z ^= r[1] & mb;
+        z ^= r[0] & ma;
+        z ^= r[1] & r[0];
+        z
+    };
+    r[0] = r[6] ^ r[8];
+    r[8] = r[0].rotate_left(1);
+    r[0] = r[5] & POOL[2421];
+    r[6] = r[0] ^ r[8];
+    r[1] = r[13] ^ r[6];
+    r[6] = r[3] & POOL[2422];
+    r[13] = r[0] ^ POOL[2423];
+    r[0] = r[13] ^ POOL[2424];
+    r[13] = r[0] ^ POOL[2425];
+    r[0] = r[8] ^ POOL[2426];
+    r[8] = {
+        let (t, s) = (POOL[2427], POOL[2428]);
+        let (ma, mb) = (t ^ s ^ POOL[2429], t ^ s ^ POOL[2430]);
+        let mut z = t;
+        z ^= r[13] & mb;
+        z ^= r[0] & ma;
+        z ^= r[13] & r[0];
+        z
+    };
+    r[0] = r[6] ^ r[8];
+    r[8] = r[0].rotate_left(1);
+    r[0] = r[5] & POOL[2431];
+    r[6] = r[0] ^ r[8];
+    r[13] = r[1] ^ r[6];
+    r[6] = r[3] & POOL[2432];
+    r[1] = r[0] ^ POOL[2433];
+    r[0] = r[1] ^ POOL[2434];
+    r[1] = r[0] ^ POOL[2435];
+    r[0] = r[8] ^ POOL[2436];
+    r[8] = {
+        let (t, s) = (POOL[2437], POOL[2438]);
+        let (ma, mb) = (t ^ s ^ POOL[2439], t ^ s ^ POOL[2440]);
+        let mut z = t;
+        z ^= r[1] & mb;
+        z ^= r[0] & ma;
+        z ^= r[1] & r[0];
+        z
+    };
+    r[0] = r[6] ^ r[8];
+    r[8] = r[0].rotate_left(1);
+    r[0] = r[5] & POOL[2441];
+    r[6] = r[0] ^ r[8];
+    r[1] = r[13] ^ r[6];
+    r[6] = r[3] & POOL[2442];
+    r[13] = r[0] ^ POOL[2443];
+    r[0] = r[13] ^ POOL[2444];
+    r[13] = r[0] ^ POOL[2445];
+    r[0] = r[8] ^ POOL[2446];
+    r[8] = {
+        let (t, s) = (POOL[2447], POOL[2448]);
+        let (ma, mb) = (t ^ s ^ POOL[2449], t ^ s ^ POOL[2450]);
+        let mut z = t;
+        z ^= r[13] & mb;
+        z ^= r[0] & ma;
+        z ^= r[13] & r[0];
+        z
+    };
+    r[0] = r[6] ^ r[8];
+    r[8] = r[0].rotate_left(1);
+    r[0] = r[5] & POOL[2451];
+    r[6] = r[0] ^ r[8];
+    r[13] = r[1] ^ r[6];
+    r[6] = r[3] & POOL[2452];
+    r[1] = r[0] ^ POOL[2453];
+    r[0] = r[1] ^ POOL[2454];
+    r[1] = r[0] ^ POOL[2455];
+    r[0] = r[8] ^ POOL[2456];
+    r[8] = {
+        let (t, s) = (POOL[2457], POOL[2458]);
+        let (ma, mb) = (t ^ s ^ POOL[2459], t ^ s ^ POOL[2460]);
+        let mut z = t;
+        z ^= r[1] & mb;
+        z ^= r[0] & ma;
+        z ^= r[1] & r[0];
+        z
+    };
+    r[0] = r[6] ^ r[8];
+    r[8] = r[0].rotate_left(1);
+    r[0] = r[5] & POOL[2461];
+    r[6] = r[0] ^ r[8];
+    r[1] = r[13] ^ r[6];
+    r[6] = r[3] & POOL[2462];
+    r[13] = r[0] ^ POOL[2463];
+    r[0] = r[13] ^ POOL[2464];
+    r[13] = r[0] ^ POOL[2465];
+    r[0] = r[8] ^ POOL[2466];
+    r[8] = {
+        let (t, s) = (POOL[2467], POOL[2468]);
+        let (ma, mb) = (t ^ s ^ POOL[2469], t ^ s ^ POOL[2470]);
+        let mut z = t;
+        z ^= r[13] & mb;
+        z ^= r[0] & ma;
+        z ^= r[13] & r[0];
+        z
+    };
+    r[0] = r[6] ^ r[8];
+    r[8] = r[0].rotate_left(1);
+    r[0] = r[5] & POOL[2471];
+    r[6] = r[0] ^ r[8];
+    r[13] = r[1] ^ r[6];
+    r[6] = r[3] & POOL[2472];
+    r[1] = r[0] ^ POOL[2473];
+    r[0] = r[1] ^ POOL[2474];
+    r[1] = r[0] ^ POOL[2475];
+    r[0] = r[8] ^ POOL[2476];
+    r[8] = {
+        let (t, s) = (POOL[2477], POOL[2478]);
+        let (ma, mb) = (t ^ s ^ POOL[2479], t ^ s ^ POOL[2480]);
+        let mut z = t;
+        z ^= r[1] & mb;
+        z ^= r[0] & ma;
+        z ^= r[1] & r[0];
+        z
+    };
+    r[0] = r[6] ^ r[8];
+    r[8] = r[0].rotate_left(1);
+    r[0] = r[5] & POOL[2481];
+    r[6] = r[0] ^ r[8];
+    r[1] = r[13] ^ r[6];
+    r[6] = r[3] & POOL[2482];
+    r[13] = r[0] ^ POOL[2483];
+    r[0] = r[13] ^ POOL[2484];
+    r[13] = r[0] ^ POOL[2485];
+    r[0] = r[8] ^ POOL[2486];
+    r[8] = {
+        let (t, s) = (POOL[2487], POOL[2488]);
+        let (ma, mb) = (t ^ s ^ POOL[2489], t ^ s ^ POOL[2490]);
+        let mut z = t;
+        z ^= r[13] & mb;
+        z ^= r[0] & ma;
+        z ^= r[13] & r[0];
+        z
+    };
+    r[0] = r[6] ^ r[8];
+    r[8] = r[0].rotate_left(1);
+    r[0] = r[5] & POOL[2491];
+    r[6] = r[0] ^ r[8];
+    r[13] = r[1] ^ r[6];
+    r[6] = r[3] & POOL[2492];
+    r[3] = r[0] ^ POOL[2493];
+    r[0] = r[3] ^ POOL[2494];
+    r[3] = r[0] ^ POOL[2495];
+    r[0] = r[8] ^ POOL[2496];
+    r[8] = {
+        let (t, s) = (POOL[2497], POOL[2498]);
+        let (ma, mb) = (t ^ s ^ POOL[2499], t ^ s ^ POOL[2500]);
+        let mut z = t;
+        z ^= r[3] & mb;
+        z ^= r[0] & ma;
+        z ^= r[3] & r[0];
+        z
+    };
+    r[0] = r[6] ^ r[8];
+    r[8] = r[0].rotate_left(1);
+    r[0] = r[5] & POOL[2501];
+    r[5] = r[0] ^ r[8];
+    r[8] = r[13] ^ r[5];
+    r[5] = r[7] ^ r[8];
+    r[7] = r[5].rotate_left(7);
+    r[5] = r[4] ^ r[7];
+    r[4] = r[12] ^ POOL[2502];
+    r[7] = r[5] ^ POOL[2503];
+    r[0] = {
+        let (t, s) = (POOL[2504], POOL[2505]);
+        let (ma, mb) = (t ^ s ^ POOL[2506], t ^ s ^ POOL[2507]);
+        let mut z = t;
+        z ^= r[4] & mb;
+        z ^= r[7] & ma;
+        z ^= r[4] & r[7];
+        z
+    };
+    r[4] = r[12] ^ r[5];
+    r[7] = r[4] & POOL[2508];
+    r[12] = r[0] & POOL[2509];
+    r[3] = r[12].rotate_left(1);
+    r[12] = r[4] & POOL[2510];
+    r[6] = r[12] ^ r[3];
+    r[13] = r[7] ^ r[6];
+    r[7] = r[0] & POOL[2511];
+    r[6] = r[12] ^ POOL[2512];
+    r[12] = r[6] ^ POOL[2513];
+    r[6] = r[12] ^ POOL[2514];
+    r[12] = r[3] ^ POOL[2515];
+    r[3] = r[12] ^ POOL[2516];
+    r[12] = r[3] ^ POOL[2517];
+    r[3] = {
+        let (t, s) = (POOL[2518], POOL[2519]);
+        let (ma, mb) = (t ^ s ^ POOL[2520], t ^ s ^ POOL[2521]);
+        let mut z = t;
+        z ^= r[6] & mb;
+        z ^= r[12] & ma;
+        z ^= r[6] & r[12];
+        z
+    };
+    r[12] = r[7] ^ r[3];
+    r[7] = r[12].rotate_left(1);
+    r[12] = r[4] & POOL[2522];
+    r[3] = r[12] ^ r[7];
+    r[6] = r[13] ^ r[3];
+    r[3] = r[0] & POOL[2523];
+    r[13] = r[12] ^ POOL[2524];
+    r[12] = r[13] ^ POOL[2525];
+    r[13] = r[12] ^ POOL[2526];
+    r[12] = r[7] ^ POOL[2527];
+    r[7] = {
+        let (t, s) = (POOL[2528], POOL[2529]);
+        let (ma, mb) = (t ^ s ^ POOL[2530], t ^ s ^ POOL[2531]);
+        let mut z = t;
+        z ^= r[13] & mb;
+        z ^= r[12] & ma;
+        z ^= r[13] & r[12];
+        z
+    };
+    r[12] = r[3] ^ r[7];
+    r[7] = r[12].rotate_left(1);
+    r[12] = r[4] & POOL[2532];
+    r[3] = r[12] ^ r[7];
+    r[13] = r[6] ^ r[3];
+    r[3] = r[0] & POOL[2533];
+    r[6] = r[12] ^ POOL[2534];
+    r[12] = r[6] ^ POOL[2535];
+    r[6] = r[12] ^ POOL[2536];
+    r[12] = r[7] ^ POOL[2537];
+    r[7] = {
+        let (t, s) = (POOL[2538], POOL[2539]);
+        let (ma, mb) = (t ^ s ^ POOL[2540], t ^ s ^ POOL[2541]);
+        let mut z = t;
+        z ^= r[6] & mb;
+        z ^= r[12] & ma;
+        z ^= r[6] & r[12];
+        z
+    };
+    r[12] = r[3] ^ r[7];
+    r[7] = r[12].rotate_left(1);
+    r[12] = r[4] & POOL[2542];
+    r[3] = r[12] ^ r[7];
+    r[6] = r[13] ^ r[3];
+    r[3] = r[0] & POOL[2543];
+    r[13] = r[12] ^ POOL[2544];
+    r[12] = r[13] ^ POOL[2545];
+    r[13] = r[12] ^ POOL[2546];
+    r[12] = r[7] ^ POOL[2547];
+    r[7] = {
+        let (t, s) = (POOL[2548], POOL[2549]);
+        let (ma, mb) = (t ^ s ^ POOL[2550], t ^ s ^ POOL[2551]);
+        let mut z = t;
+        z ^= r[13] & mb;
+        z ^= r[12] & ma;
+        z ^= r[13] & r[12];
+        z
+    };
+    r[12] = r[3] ^ r[7];
+    r[7] = r[12].rotate_left(1);
+    r[12] = r[4] & POOL[2552];
+    r[3] = r[12] ^ r[7];
+    r[13] = r[6] ^ r[3];
+    r[3] = r[0] & POOL[2553];
+    r[6] = r[12] ^ POOL[2554];
+    r[12] = r[6] ^ POOL[2555];
+    r[6] = r[12] ^ POOL[2556];
+    r[12] = r[7] ^ POOL[2557];
+    r[7] = {
+        let (t, s) = (POOL[2558], POOL[2559]);
+        let (ma, mb) = (t ^ s ^ POOL[2560], t ^ s ^ POOL[2561]);
+        let mut z = t;
+        z ^= r[6] & mb;
+        z ^= r[12] & ma;
+        z ^= r[6] & r[12];
+        z
+    };
+    r[12] = r[3] ^ r[7];
+    r[7] = r[12].rotate_left(1);
+    r[12] = r[4] & POOL[2562];
+    r[3] = r[12] ^ r[7];
+    r[6] = r[13] ^ r[3];
+    r[3] = r[0] & POOL[2563];
+    r[13] = r[12] ^ POOL[2564];
+    r[12] = r[13] ^ POOL[2565];
+    r[13] = r[12] ^ POOL[2566];
+    r[12] = r[7] ^ POOL[2567];
+    r[7] = {
+        let (t, s) = (POOL[2568], POOL[2569]);
+        let (ma, mb) = (t ^ s ^ POOL[2570], t ^ s ^ POOL[2571]);
+        let mut z = t;
+        z ^= r[13] & mb;
+        z ^= r[12] & ma;
+        z ^= r[13] & r[12];
+        z
+    };
+    r[12] = r[3] ^ r[7];
+    r[7] = r[12].rotate_left(1);
+    r[12] = r[4] & POOL[2572];
+    r[3] = r[12] ^ r[7];
+    r[13] = r[6] ^ r[3];
+    r[3] = r[0] & POOL[2573];
+    r[6] = r[12] ^ POOL[2574];
+    r[12] = r[6] ^ POOL[2575];
+    r[6] = r[12] ^ POOL[2576];
+    r[12] = r[7] ^ POOL[2577];
+    r[7] = {
+        let (t, s) = (POOL[2578], POOL[2579]);
+        let (ma, mb) = (t ^ s ^ POOL[2580], t ^ s ^ POOL[2581]);
+        let mut z = t;
+        z ^= r[6] & mb;
+        z ^= r[12] & ma;
+        z ^= r[6] & r[12];
+        z
+    };
+    r[12] = r[3] ^ r[7];
+    r[7] = r[12].rotate_left(1);
+    r[12] = r[4] & POOL[2582];
+    r[3] = r[12] ^ r[7];
+    r[6] = r[13] ^ r[3];
+    r[3] = r[0] & POOL[2583];
+    r[13] = r[12] ^ POOL[2584];
+    r[12] = r[13] ^ POOL[2585];
+    r[13] = r[12] ^ POOL[2586];
+    r[12] = r[7] ^ POOL[2587];
+    r[7] = {
+        let (t, s) = (POOL[2588], POOL[2589]);
+        let (ma, mb) = (t ^ s ^ POOL[2590], t ^ s ^ POOL[2591]);
+        let mut z = t;
+        z ^= r[13] & mb;
+        z ^= r[12] & ma;
+        z ^= r[13] & r[12];
+        z
+    };
+    r[12] = r[3] ^ r[7];
+    r[7] = r[12].rotate_left(1);
+    r[12] = r[4] & POOL[2592];
+    r[3] = r[12] ^ r[7];
+    r[13] = r[6] ^ r[3];
+    r[3] = r[0] & POOL[2593];
+    r[6] = r[12] ^ POOL[2594];
+    r[12] = r[6] ^ POOL[2595];
+    r[6] = r[12] ^ POOL[2596];
+    r[12] = r[7] ^ POOL[2597];
+    r[7] = {
+        let (t, s) = (POOL[2598], POOL[2599]);
+        let (ma, mb) = (t ^ s ^ POOL[2600], t ^ s ^ POOL[2601]);
+        let mut z = t;
+        z ^= r[6] & mb;
+        z ^= r[12] & ma;
+        z ^= r[6] & r[12];
+        z
+    };
+    r[12] = r[3] ^ r[7];
+    r[7] = r[12].rotate_left(1);
+    r[12] = r[4] & POOL[2602];
+    r[3] = r[12] ^ r[7];
+    r[6] = r[13] ^ r[3];
+    r[3] = r[0] & POOL[2603];
+    r[13] = r[12] ^ POOL[2604];
+    r[12] = r[13] ^ POOL[2605];
+    r[13] = r[12] ^ POOL[2606];
+    r[12] = r[7] ^ POOL[2607];
+    r[7] = {
+        let (t, s) = (POOL[2608], POOL[2609]);
+        let (ma, mb) = (t ^ s ^ POOL[2610], t ^ s ^ POOL[2611]);
+        let mut z = t;
+        z ^= r[13] & mb;
+        z ^= r[12] & ma;
+        z ^= r[13] & r[12];
+        z
+    };
+    r[12] = r[3] ^ r[7];
+    r[7] = r[12].rotate_left(1);
+    r[12] = r[4] & POOL[2612];
+    r[3] = r[12] ^ r[7];
+    r[13] = r[6] ^ r[3];
+    r[3] = r[0] & POOL[2613];
+    r[6] = r[12] ^ POOL[2614];
+    r[12] = r[6] ^ POOL[2615];
+    r[6] = r[12] ^ POOL[2616];
+    r[12] = r[7] ^ POOL[2617];
+    r[7] = {
+        let (t, s) = (POOL[2618], POOL[2619]);
+        let (ma, mb) = (t ^ s ^ POOL[2620], t ^ s ^ POOL[2621]);
+        let mut z = t;
+        z ^= r[6] & mb;
+        z ^= r[12] & ma;
+        z ^= r[6] & r[12];
+        z
+    };
+    r[12] = r[3] ^ r[7];
+    r[7] = r[12].rotate_left(1);
+    r[12] = r[4] & POOL[2622];
+    r[3] = r[12] ^ r[7];
+    r[6] = r[13] ^ r[3];
+    r[3] = r[0] & POOL[2623];
+    r[13] = r[12] ^ POOL[2624];
+    r[12] = r[13] ^ POOL[2625];
+    r[13] = r[12] ^ POOL[2626];
+    r[12] = r[7] ^ POOL[2627];
+    r[7] = {
+        let (t, s) = (POOL[2628], POOL[2629]);
+        let (ma, mb) = (t ^ s ^ POOL[2630], t ^ s ^ POOL[2631]);
+        let mut z = t;
+        z ^= r[13] & mb;
+        z ^= r[12] & ma;
+        z ^= r[13] & r[12];
+        z
+    };
+    r[12] = r[3] ^ r[7];
+    r[7] = r[12].rotate_left(1);
+    r[12] = r[4] & POOL[2632];
+    r[3] = r[12] ^ r[7];
+    r[13] = r[6] ^ r[3];
+    r[3] = r[0] & POOL[2633];
+    r[6] = r[12] ^ POOL[2634];
+    r[12] = r[6] ^ POOL[2635];
+    r[6] = r[12] ^ POOL[2636];
+    r[12] = r[7] ^ POOL[2637];
+    r[7] = {
+        let (t, s) = (POOL[2638], POOL[2639]);
+        let (ma, mb) = (t ^ s ^ POOL[2640], t ^ s ^ POOL[2641]);
+        let mut z = t;
+        z ^= r[6] & mb;
+        z ^= r[12] & ma;
+        z ^= r[6] & r[12];
+        z
+    };
+    r[12] = r[3] ^ r[7];
+    r[7] = r[12].rotate_left(1);
+    r[12] = r[4] & POOL[2642];
+    r[3] = r[12] ^ r[7];
+    r[6] = r[13] ^ r[3];
+    r[3] = r[0] & POOL[2643];
+    r[13] = r[12] ^ POOL[2644];
+    r[12] = r[13] ^ POOL[2645];
+    r[13] = r[12] ^ POOL[2646];
+    r[12] = r[7] ^ POOL[2647];
+    r[7] = {
+        let (t, s) = (POOL[2648], POOL[2649]);
+        let (ma, mb) = (t ^ s ^ POOL[2650], t ^ s ^ POOL[2651]);
+        let mut z = t;
+        z ^= r[13] & mb;
+        z ^= r[12] & ma;
+        z ^= r[13] & r[12];
+        z
+    };
+    r[12] = r[3] ^ r[7];
+    r[7] = r[12].rotate_left(1);
+    r[12] = r[4] & POOL[2652];
+    r[3] = r[12] ^ r[7];
+    r[13] = r[6] ^ r[3];
+    r[3] = r[0] & POOL[2653];
+    r[6] = r[12] ^ POOL[2654];
+    r[12] = r[6] ^ POOL[2655];
+    r[6] = r[12] ^ POOL[2656];
+    r[12] = r[7] ^ POOL[2657];
+    r[7] = {
+        let (t, s) = (POOL[2658], POOL[2659]);
+        let (ma, mb) = (t ^ s ^ POOL[2660], t ^ s ^ POOL[2661]);
+        let mut z = t;
+        z ^= r[6] & mb;
+        z ^= r[12] & ma;
+        z ^= r[6] & r[12];
+        z
+    };
+    r[12] = r[3] ^ r[7];
+    r[7] = r[12].rotate_left(1);
+    r[12] = r[4] & POOL[2662];
+    r[3] = r[12] ^ r[7];
+    r[6] = r[13] ^ r[3];
+    r[3] = r[0] & POOL[2663];
+    r[13] = r[12] ^ POOL[2664];
+    r[12] = r[13] ^ POOL[2665];
+    r[13] = r[12] ^ POOL[2666];
+    r[12] = r[7] ^ POOL[2667];
+    r[7] = {
+        let (t, s) = (POOL[2668], POOL[2669]);
+        let (ma, mb) = (t ^ s ^ POOL[2670], t ^ s ^ POOL[2671]);
+        let mut z = t;
+        z ^= r[13] & mb;
+        z ^= r[12] & ma;
+        z ^= r[13] & r[12];
+        z
+    };
+    r[12] = r[3] ^ r[7];
+    r[7] = r[12].rotate_left(1);
+    r[12] = r[4] & POOL[2672];
+    r[3] = r[12] ^ r[7];
+    r[13] = r[6] ^ r[3];
+    r[3] = r[0] & POOL[2673];
+    r[6] = r[12] ^ POOL[2674];
+    r[12] = r[6] ^ POOL[2675];
+    r[6] = r[12] ^ POOL[2676];
+    r[12] = r[7] ^ POOL[2677];
+    r[7] = {
+        let (t, s) = (POOL[2678], POOL[2679]);
+        let (ma, mb) = (t ^ s ^ POOL[2680], t ^ s ^ POOL[2681]);
+        let mut z = t;
+        z ^= r[6] & mb;
+        z ^= r[12] & ma;
+        z ^= r[6] & r[12];
+        z
+    };
+    r[12] = r[3] ^ r[7];
+    r[7] = r[12].rotate_left(1);
+    r[12] = r[4] & POOL[2682];
+    r[3] = r[12] ^ r[7];
+    r[6] = r[13] ^ r[3];
+    r[3] = r[0] & POOL[2683];
+    r[13] = r[12] ^ POOL[2684];
+    r[12] = r[13] ^ POOL[2685];
+    r[13] = r[12] ^ POOL[2686];
+    r[12] = r[7] ^ POOL[2687];
+    r[7] = {
+        let (t, s) = (POOL[2688], POOL[2689]);
+        let (ma, mb) = (t ^ s ^ POOL[2690], t ^ s ^ POOL[2691]);
+        let mut z = t;
+        z ^= r[13] & mb;
+        z ^= r[12] & ma;
+        z ^= r[13] & r[12];
+        z
+    };
+    r[12] = r[3] ^ r[7];
+    r[7] = r[12].rotate_left(1);
+    r[12] = r[4] & POOL[2692];
+    r[3] = r[12] ^ r[7];
+    r[13] = r[6] ^ r[3];
+    r[3] = r[0] & POOL[2693];
+    r[6] = r[12] ^ POOL[2694];
+    r[12] = r[6] ^ POOL[2695];
+    r[6] = r[12] ^ POOL[2696];
+    r[12] = r[7] ^ POOL[2697];
+    r[7] = {
+        let (t, s) = (POOL[2698], POOL[2699]);
+        let (ma, mb) = (t ^ s ^ POOL[2700], t ^ s ^ POOL[2701]);
+        let mut z = t;
+        z ^= r[6] & mb;
+        z ^= r[12] & ma;
+        z ^= r[6] & r[12];
+        z
+    };
+    r[12] = r[3] ^ r[7];
+    r[7] = r[12].rotate_left(1);
+    r[12] = r[4] & POOL[2702];
+    r[3] = r[12] ^ r[7];
+    r[6] = r[13] ^ r[3];
+    r[3] = r[0] & POOL[2703];
+    r[13] = r[12] ^ POOL[2704];
+    r[12] = r[13] ^ POOL[2705];
+    r[13] = r[12] ^ POOL[2706];
+    r[12] = r[7] ^ POOL[2707];
+    r[7] = {
+        let (t, s) = (POOL[2708], POOL[2709]);
+        let (ma, mb) = (t ^ s ^ POOL[2710], t ^ s ^ POOL[2711]);
+        let mut z = t;
+        z ^= r[13] & mb;
+        z ^= r[12] & ma;
+        z ^= r[13] & r[12];
+        z
+    };
+    r[12] = r[3] ^ r[7];
+    r[7] = r[12].rotate_left(1);
+    r[12] = r[4] & POOL[2712];
+    r[3] = r[12] ^ r[7];
+    r[13] = r[6] ^ r[3];
+    r[3] = r[0] & POOL[2713];
+    r[6] = r[12] ^ POOL[2714];
+    r[12] = r[6] ^ POOL[2715];
+    r[6] = r[12] ^ POOL[2716];
+    r[12] = r[7] ^ POOL[2717];
+    r[7] = {
+        let (t, s) = (POOL[2718], POOL[2719]);
+        let (ma, mb) = (t ^ s ^ POOL[2720], t ^ s ^ POOL[2721]);
+        let mut z = t;
+        z ^= r[6] & mb;
+        z ^= r[12] & ma;
+        z ^= r[6] & r[12];
+        z
+    };
+    r[12] = r[3] ^ r[7];
+    r[7] = r[12].rotate_left(1);
+    r[12] = r[4] & POOL[2722];
+    r[3] = r[12] ^ r[7];
+    r[6] = r[13] ^ r[3];
+    r[3] = r[0] & POOL[2723];
+    r[13] = r[12] ^ POOL[2724];
+    r[12] = r[13] ^ POOL[2725];
+    r[13] = r[12] ^ POOL[2726];
+    r[12] = r[7] ^ POOL[2727];
+    r[7] = {
+        let (t, s) = (POOL[2728], POOL[2729]);
+        let (ma, mb) = (t ^ s ^ POOL[2730], t ^ s ^ POOL[2731]);
+        let mut z = t;
+        z ^= r[13] & mb;
+        z ^= r[12] & ma;
+        z ^= r[13] & r[12];
+        z
+    };
+    r[12] = r[3] ^ r[7];
+    r[7] = r[12].rotate_left(1);
+    r[12] = r[4] & POOL[2732];
+    r[3] = r[12] ^ r[7];
+    r[13] = r[6] ^ r[3];
+    r[3] = r[0] & POOL[2733];
+    r[6] = r[12] ^ POOL[2734];
+    r[12] = r[6] ^ POOL[2735];
+    r[6] = r[12] ^ POOL[2736];
+    r[12] = r[7] ^ POOL[2737];
+    r[7] = {
+        let (t, s) = (POOL[2738], POOL[2739]);
+        let (ma, mb) = (t ^ s ^ POOL[2740], t ^ s ^ POOL[2741]);
+        let mut z = t;
+        z ^= r[6] & mb;
+        z ^= r[12] & ma;
+        z ^= r[6] & r[12];
+        z
+    };
+    r[12] = r[3] ^ r[7];
+    r[7] = r[12].rotate_left(1);
+    r[12] = r[4] & POOL[2742];
+    r[3] = r[12] ^ r[7];
+    r[6] = r[13] ^ r[3];
+    r[3] = r[0] & POOL[2743];
+    r[13] = r[12] ^ POOL[2744];
+    r[12] = r[13] ^ POOL[2745];
+    r[13] = r[12] ^ POOL[2746];
+    r[12] = r[7] ^ POOL[2747];
+    r[7] = {
+        let (t, s) = (POOL[2748], POOL[2749]);
+        let (ma, mb) = (t ^ s ^ POOL[2750], t ^ s ^ POOL[2751]);
+        let mut z = t;
+        z ^= r[13] & mb;
+        z ^= r[12] & ma;
+        z ^= r[13] & r[12];
+        z
+    };
+    r[12] = r[3] ^ r[7];
+    r[7] = r[12].rotate_left(1);
+    r[12] = r[4] & POOL[2752];
+    r[3] = r[12] ^ r[7];
+    r[13] = r[6] ^ r[3];
+    r[3] = r[0] & POOL[2753];
+    r[6] = r[12] ^ POOL[2754];
+    r[12] = r[6] ^ POOL[2755];
+    r[6] = r[12] ^ POOL[2756];
+    r[12] = r[7] ^ POOL[2757];
+    r[7] = {
+        let (t, s) = (POOL[2758], POOL[2759]);
+        let (ma, mb) = (t ^ s ^ POOL[2760], t ^ s ^ POOL[2761]);
+        let mut z = t;
+        z ^= r[6] & mb;
+        z ^= r[12] & ma;
+        z ^= r[6] & r[12];
+        z
+    };
+    r[12] = r[3] ^ r[7];
+    r[7] = r[12].rotate_left(1);
+    r[12] = r[4] & POOL[2762];
+    r[3] = r[12] ^ r[7];
+    r[6] = r[13] ^ r[3];
+    r[3] = r[0] & POOL[2763];
+    r[13] = r[12] ^ POOL[2764];
+    r[12] = r[13] ^ POOL[2765];
+    r[13] = r[12] ^ POOL[2766];
+    r[12] = r[7] ^ POOL[2767];
+    r[7] = {
+        let (t, s) = (POOL[2768], POOL[2769]);
+        let (ma, mb) = (t ^ s ^ POOL[2770], t ^ s ^ POOL[2771]);
+        let mut z = t;
+        z ^= r[13] & mb;
+        z ^= r[12] & ma;
+        z ^= r[13] & r[12];
+        z
+    };
+    r[12] = r[3] ^ r[7];
+    r[7] = r[12].rotate_left(1);
+    r[12] = r[4] & POOL[2772];
+    r[3] = r[12] ^ r[7];
+    r[13] = r[6] ^ r[3];
+    r[3] = r[0] & POOL[2773];
+    r[6] = r[12] ^ POOL[2774];
+    r[12] = r[6] ^ POOL[2775];
+    r[6] = r[12] ^ POOL[2776];
+    r[12] = r[7] ^ POOL[2777];
+    r[7] = {
+        let (t, s) = (POOL[2778], POOL[2779]);
+        let (ma, mb) = (t ^ s ^ POOL[2780], t ^ s ^ POOL[2781]);
+        let mut z = t;
+        z ^= r[6] & mb;
+        z ^= r[12] & ma;
+        z ^= r[6] & r[12];
+        z
+    };
+    r[12] = r[3] ^ r[7];
+    r[7] = r[12].rotate_left(1);
+    r[12] = r[4] & POOL[2782];
+    r[3] = r[12] ^ r[7];
+    r[6] = r[13] ^ r[3];
+    r[3] = r[0] & POOL[2783];
+    r[13] = r[12] ^ POOL[2784];
+    r[12] = r[13] ^ POOL[2785];
+    r[13] = r[12] ^ POOL[2786];
+    r[12] = r[7] ^ POOL[2787];
+    r[7] = {
+        let (t, s) = (POOL[2788], POOL[2789]);
+        let (ma, mb) = (t ^ s ^ POOL[2790], t ^ s ^ POOL[2791]);
+        let mut z = t;
+        z ^= r[13] & mb;
+        z ^= r[12] & ma;
+        z ^= r[13] & r[12];
+        z
+    };
+    r[12] = r[3] ^ r[7];
+    r[7] = r[12].rotate_left(1);
+    r[12] = r[4] & POOL[2792];
+    r[3] = r[12] ^ r[7];
+    r[13] = r[6] ^ r[3];
+    r[3] = r[0] & POOL[2793];
+    r[6] = r[12] ^ POOL[2794];
+    r[12] = r[6] ^ POOL[2795];
+    r[6] = r[12] ^ POOL[2796];
+    r[12] = r[7] ^ POOL[2797];
+    r[7] = {
+        let (t, s) = (POOL[2798], POOL[2799]);
+        let (ma, mb) = (t ^ s ^ POOL[2800], t ^ s ^ POOL[2801]);
+        let mut z = t;
+        z ^= r[6] & mb;
+        z ^= r[12] & ma;
+        z ^= r[6] & r[12];
+        z
+    };
+    r[12] = r[3] ^ r[7];
+    r[7] = r[12].rotate_left(1);
+    r[12] = r[4] & POOL[2802];
+    r[3] = r[12] ^ r[7];
+    r[6] = r[13] ^ r[3];
+    r[3] = r[0] & POOL[2803];
+    r[0] = r[12] ^ POOL[2804];
+    r[12] = r[0] ^ POOL[2805];
+    r[0] = r[12] ^ POOL[2806];
+    r[12] = r[7] ^ POOL[2807];
+    r[7] = {
+        let (t, s) = (POOL[2808], POOL[2809]);
+        let (ma, mb) = (t ^ s ^ POOL[2810], t ^ s ^ POOL[2811]);
+        let mut z = t;
+        z ^= r[0] & mb;
+        z ^= r[12] & ma;
+        z ^= r[0] & r[12];
+        z
+    };
+    r[0] = r[3] ^ r[7];
+    r[7] = r[0].rotate_left(1);
+    r[0] = r[4] & POOL[2812];
+    r[4] = r[0] ^ r[7];
+    r[7] = r[6] ^ r[4];
+    r[4] = r[2] ^ r[8];
+    r[2] = r[9] ^ r[11];
+    r[9] = r[2] ^ r[7];
+    r[2] = r[9].rotate_left(16);
+    r[9] = r[4] ^ POOL[2813];
+    r[11] = r[2] ^ POOL[2814];
+    r[8] = {
+        let (t, s) = (POOL[2815], POOL[2816]);
+        let (ma, mb) = (t ^ s ^ POOL[2817], t ^ s ^ POOL[2818]);
+        let mut z = t;
+        z ^= r[9] & mb;
+        z ^= r[11] & ma;
+        z ^= r[9] & r[11];
+        z
+    };
+    r[9] = r[4] ^ r[2];
+    r[4] = r[9] & POOL[2819];
+    r[11] = r[8] & POOL[2820];
+    r[0] = r[11].rotate_left(1);
+    r[11] = r[9] & POOL[2821];
+    r[12] = r[11] ^ r[0];
+    r[3] = r[4] ^ r[12];
+    r[4] = r[8] & POOL[2822];
+    r[12] = r[11] ^ POOL[2823];
+    r[11] = r[12] ^ POOL[2824];
+    r[12] = r[11] ^ POOL[2825];
+    r[11] = r[0] ^ POOL[2826];
+    r[0] = r[11] ^ POOL[2827];
+    r[11] = r[0] ^ POOL[2828];
+    r[0] = {
+        let (t, s) = (POOL[2829], POOL[2830]);
+        let (ma, mb) = (t ^ s ^ POOL[2831], t ^ s ^ POOL[2832]);
+        let mut z = t;
+        z ^= r[12] & mb;
+        z ^= r[11] & ma;
+        z ^= r[12] & r[11];
+        z
+    };
+    r[11] = r[4] ^ r[0];
+    r[4] = r[11].rotate_left(1);
+    r[11] = r[9] & POOL[2833];
+    r[0] = r[11] ^ r[4];
+    r[12] = r[3] ^ r[0];
+    r[0] = r[8] & POOL[2834];
+    r[3] = r[11] ^ POOL[2835];
+    r[11] = r[3] ^ POOL[2836];
+    r[3] = r[11] ^ POOL[2837];
+    r[11] = r[4] ^ POOL[2838];
+    r[4] = {
+        let (t, s) = (POOL[2839], POOL[2840]);
+        let (ma, mb) = (t ^ s ^ POOL[2841], t ^ s ^ POOL[2842]);
+        let mut z = t;
+        z ^= r[3] & mb;
+        z ^= r[11] & ma;
+        z ^= r[3] & r[11];
+        z
+    };
+    r[11] = r[0] ^ r[4];
+    r[4] = r[11].rotate_left(1);
+    r[11] = r[9] & POOL[2843];
+    r[0] = r[11] ^ r[4];
+    r[3] = r[12] ^ r[0];
+    r[0] = r[8] & POOL[2844];
+    r[12] = r[11] ^ POOL[2845];
+    r[11] = r[12] ^ POOL[2846];
+    r[12] = r[11] ^ POOL[2847];
+    r[11] = r[4] ^ POOL[2848];
+    r[4] = {
+        let (t, s) = (POOL[2849], POOL[2850]);
+        let (ma, mb) = (t ^ s ^ POOL[2851], t ^ s ^ POOL[2852]);
+        let mut z = t;
+        z ^= r[12] & mb;
+        z ^= r[11] & ma;
+        z ^= r[12] & r[11];
+        z
+    };
+    r[11] = r[0] ^ r[4];
+    r[4] = r[11].rotate_left(1);
+    r[11] = r[9] & POOL[2853];
+    r[0] = r[11] ^ r[4];
+    r[12] = r[3] ^ r[0];
+    r[0] = r[8] & POOL[2854];
+    r[3] = r[11] ^ POOL[2855];
+    r[11] = r[3] ^ POOL[2856];
+    r[3] = r[11] ^ POOL[2857];
+    r[11] = r[4] ^ POOL[2858];
+    r[4] = {
+        let (t, s) = (POOL[2859], POOL[2860]);
+        let (ma, mb) = (t ^ s ^ POOL[2861], t ^ s ^ POOL[2862]);
+        let mut z = t;
+        z ^= r[3] & mb;
+        z ^= r[11] & ma;
+        z ^= r[3] & r[11];
+        z
+    };
+    r[11] = r[0] ^ r[4];
+    r[4] = r[11].rotate_left(1);
+    r[11] = r[9] & POOL[2863];
+    r[0] = r[11] ^ r[4];
+    r[3] = r[12] ^ r[0];
+    r[0] = r[8] & POOL[2864];
+    r[12] = r[11] ^ POOL[2865];
+    r[11] = r[12] ^ POOL[2866];
+    r[12] = r[11] ^ POOL[2867];
+    r[11] = r[4] ^ POOL[2868];
+    r[4] = {
+        let (t, s) = (POOL[2869], POOL[2870]);
+        let (ma, mb) = (t ^ s ^ POOL[2871], t ^ s ^ POOL[2872]);
+        let mut z = t;
+        z ^= r[12] & mb;
+        z ^= r[11] & ma;
+        z ^= r[12] & r[11];
+        z
+    };
+    r[11] = r[0] ^ r[4];
+    r[4] = r[11].rotate_left(1);
+    r[11] = r[9] & POOL[2873];
+    r[0] = r[11] ^ r[4];
+    r[12] = r[3] ^ r[0];
+    r[0] = r[8] & POOL[2874];
+    r[3] = r[11] ^ POOL[2875];
+    r[11] = r[3] ^ POOL[2876];
+    r[3] = r[11] ^ POOL[2877];
+    r[11] = r[4] ^ POOL[2878];
+    r[4] = {
+        let (t, s) = (POOL[2879], POOL[2880]);
+        let (ma, mb) = (t ^ s ^ POOL[2881], t ^ s ^ POOL[2882]);
+        let mut z = t;
+        z ^= r[3] & mb;
+        z ^= r[11] & ma;
+        z ^= r[3] & r[11];
+        z
+    };
+    r[11] = r[0] ^ r[4];
+    r[4] = r[11].rotate_left(1);
+    r[11] = r[9] & POOL[2883];
+    r[0] = r[11] ^ r[4];
+    r[3] = r[12] ^ r[0];
+    r[0] = r[8] & POOL[2884];
+    r[12] = r[11] ^ POOL[2885];
+    r[11] = r[12] ^ POOL[2886];
+    r[12] = r[11] ^ POOL[2887];
+    r[11] = r[4] ^ POOL[2888];
+    r[4] = {
+        let (t, s) = (POOL[2889], POOL[2890]);
+        let (ma, mb) = (t ^ s ^ POOL[2891], t ^ s ^ POOL[2892]);
+        let mut z = t;
+        z ^= r[12] & mb;
+        z ^= r[11] & ma;
+        z ^= r[12] & r[11];
+        z
+    };
+    r[11] = r[0] ^ r[4];
+    r[4] = r[11].rotate_left(1);
+    r[11] = r[9] & POOL[2893];
+    r[0] = r[11] ^ r[4];
+    r[12] = r[3] ^ r[0];
+    r[0] = r[8] & POOL[2894];
+    r[3] = r[11] ^ POOL[2895];
+    r[11] = r[3] ^ POOL[2896];
+    r[3] = r[11] ^ POOL[2897];
+    r[11] = r[4] ^ POOL[2898];
+    r[4] = {
+        let (t, s) = (POOL[2899], POOL[2900]);
+        let (ma, mb) = (t ^ s ^ POOL[2901], t ^ s ^ POOL[2902]);
+        let mut z = t;
+        z ^= r[3] & mb;
+        z ^= r[11] & ma;
+        z ^= r[3] & r[11];
+        z
+    };
+    r[11] = r[0] ^ r[4];
+    r[4] = r[11].rotate_left(1);
+    r[11] = r[9] & POOL[2903];
+    r[0] = r[11] ^ r[4];
+    r[3] = r[12] ^ r[0];
+    r[0] = r[8] & POOL[2904];
+    r[12] = r[11] ^ POOL[2905];
+    r[11] = r[12] ^ POOL[2906];
+    r[12] = r[11] ^ POOL[2907];
+    r[11] = r[4] ^ POOL[2908];
+    r[4] = {
+        let (t, s) = (POOL[2909], POOL[2910]);
+        let (ma, mb) = (t ^ s ^ POOL[2911], t ^ s ^ POOL[2912]);
+        let mut z = t;
+        z ^= r[12] & mb;
+        z ^= r[11] & ma;
+        z ^= r[12] & r[11];
+        z
+    };
+    r[11] = r[0] ^ r[4];
+    r[4] = r[11].rotate_left(1);
+    r[11] = r[9] & POOL[2913];
+    r[0] = r[11] ^ r[4];
+    r[12] = r[3] ^ r[0];
+    r[0] = r[8] & POOL[2914];
+    r[3] = r[11] ^ POOL[2915];
+    r[11] = r[3] ^ POOL[2916];
+    r[3] = r[11] ^ POOL[2917];
+    r[11] = r[4] ^ POOL[2918];
+    r[4] = {
+        let (t, s) = (POOL[2919], POOL[2920]);
+        let (ma, mb) = (t ^ s ^ POOL[2921], t ^ s ^ POOL[2922]);
+        let mut z = t;
+        z ^= r[3] & mb;
+        z ^= r[11] & ma;
+        z ^= r[3] & r[11];
+        z
+    };
+    r[11] = r[0] ^ r[4];
+    r[4] = r[11].rotate_left(1);
+    r[11] = r[9] & POOL[2923];
+    r[0] = r[11] ^ r[4];
+    r[3] = r[12] ^ r[0];
+    r[0] = r[8] & POOL[2924];
+    r[12] = r[11] ^ POOL[2925];
+    r[11] = r[12] ^ POOL[2926];
+    r[12] = r[11] ^ POOL[2927];
+    r[11] = r[4] ^ POOL[2928];
+    r[4] = {
+        let (t, s) = (POOL[2929], POOL[2930]);
+        let (ma, mb) = (t ^ s ^ POOL[2931], t ^ s ^ POOL[2932]);
+        let mut z = t;
+        z ^= r[12] & mb;
+        z ^= r[11] & ma;
+        z ^= r[12] & r[11];
+        z
+    };
+    r[11] = r[0] ^ r[4];
+    r[4] = r[11].rotate_left(1);
+    r[11] = r[9] & POOL[2933];
+    r[0] = r[11] ^ r[4];
+    r[12] = r[3] ^ r[0];
+    r[0] = r[8] & POOL[2934];
+    r[3] = r[11] ^ POOL[2935];
+    r[11] = r[3] ^ POOL[2936];
+    r[3] = r[11] ^ POOL[2937];
+    r[11] = r[4] ^ POOL[2938];
+    r[4] = {
+        let (t, s) = (POOL[2939], POOL[2940]);
+        let (ma, mb) = (t ^ s ^ POOL[2941], t ^ s ^ POOL[2942]);
+        let mut z = t;
+        z ^= r[3] & mb;
+        z ^= r[11] & ma;
+        z ^= r[3] & r[11];
+        z
+    };
+    r[11] = r[0] ^ r[4];
+    r[4] = r[11].rotate_left(1);
+    r[11] = r[9] & POOL[2943];
+    r[0] = r[11] ^ r[4];
+    r[3] = r[12] ^ r[0];
+    r[0] = r[8] & POOL[2944];
+    r[12] = r[11] ^ POOL[2945];
+    r[11] = r[12] ^ POOL[2946];
+    r[12] = r[11] ^ POOL[2947];
+    r[11] = r[4] ^ POOL[2948];
+    r[4] = {
+        let (t, s) = (POOL[2949], POOL[2950]);
+        let (ma, mb) = (t ^ s ^ POOL[2951], t ^ s ^ POOL[2952]);
+        let mut z = t;
+        z ^= r[12] & mb;
+        z ^= r[11] & ma;
+        z ^= r[12] & r[11];
+        z
+    };
+    r[11] = r[0] ^ r[4];
+    r[4] = r[11].rotate_left(1);
+    r[11] = r[9] & POOL[2953];
+    r[0] = r[11] ^ r[4];
+    r[12] = r[3] ^ r[0];
+    r[0] = r[8] & POOL[2954];
+    r[3] = r[11] ^ POOL[2955];
+    r[11] = r[3] ^ POOL[2956];
+    r[3] = r[11] ^ POOL[2957];
+    r[11] = r[4] ^ POOL[2958];
+    r[4] = {
+        let (t, s) = (POOL[2959], POOL[2960]);
+        let (ma, mb) = (t ^ s ^ POOL[2961], t ^ s ^ POOL[2962]);
+        let mut z = t;
+        z ^= r[3] & mb;
+        z ^= r[11] & ma;
+        z ^= r[3] & r[11];
+        z
+    };
+    r[11] = r[0] ^ r[4];
+    r[4] = r[11].rotate_left(1);
+    r[11] = r[9] & POOL[2963];
+    r[0] = r[11] ^ r[4];
+    r[3] = r[12] ^ r[0];
+    r[0] = r[8] & POOL[2964];
+    r[12] = r[11] ^ POOL[2965];
+    r[11] = r[12] ^ POOL[2966];
+    r[12] = r[11] ^ POOL[2967];
+    r[11] = r[4] ^ POOL[2968];
+    r[4] = {
+        let (t, s) = (POOL[2969], POOL[2970]);
+        let (ma, mb) = (t ^ s ^ POOL[2971], t ^ s ^ POOL[2972]);
+        let mut z = t;
+        z ^= r[12] & mb;
+        z ^= r[11] & ma;
+        z ^= r[12] & r[11];
+        z
+    };
+    r[11] = r[0] ^ r[4];
+    r[4] = r[11].rotate_left(1);
+    r[11] = r[9] & POOL[2973];
+    r[0] = r[11] ^ r[4];
+    r[12] = r[3] ^ r[0];
+    r[0] = r[8] & POOL[2974];
+    r[3] = r[11] ^ POOL[2975];
+    r[11] = r[3] ^ POOL[2976];
+    r[3] = r[11] ^ POOL[2977];
+    r[11] = r[4] ^ POOL[2978];
+    r[4] = {
+        let (t, s) = (POOL[2979], POOL[2980]);
+        let (ma, mb) = (t ^ s ^ POOL[2981], t ^ s ^ POOL[2982]);
+        let mut z = t;
+        z ^= r[3] & mb;
+        z ^= r[11] & ma;
+        z ^= r[3] & r[11];
+        z
+    };
+    r[11] = r[0] ^ r[4];
+    r[4] = r[11].rotate_left(1);
+    r[11] = r[9] & POOL[2983];
+    r[0] = r[11] ^ r[4];
+    r[3] = r[12] ^ r[0];
+    r[0] = r[8] & POOL[2984];
+    r[12] = r[11] ^ POOL[2985];
+    r[11] = r[12] ^ POOL[2986];
+    r[12] = r[11] ^ POOL[2987];
+    r[11] = r[4] ^ POOL[2988];
+    r[4] = {
+        let (t, s) = (POOL[2989], POOL[2990]);
+        let (ma, mb) = (t ^ s ^ POOL[2991], t ^ s ^ POOL[2992]);
+        let mut z = t;
+        z ^= r[12] & mb;
+        z ^= r[11] & ma;
+        z ^= r[12] & r[11];
+        z
+    };
+    r[11] = r[0] ^ r[4];
+    r[4] = r[11].rotate_left(1);
+    r[11] = r[9] & POOL[2993];
+    r[0] = r[11] ^ r[4];
+    r[12] = r[3] ^ r[0];
+    r[0] = r[8] & POOL[2994];
+    r[3] = r[11] ^ POOL[2995];
+    r[11] = r[3] ^ POOL[2996];
+    r[3] = r[11] ^ POOL[2997];
+    r[11] = r[4] ^ POOL[2998];
+    r[4] = {
+        let (t, s) = (POOL[2999], POOL[3000]);
+        let (ma, mb) = (t ^ s ^ POOL[3001], t ^ s ^ POOL[3002]);
+        let mut z = t;
+        z ^= r[3] & mb;
+        z ^= r[11] & ma;
+        z ^= r[3] & r[11];
+        z
+    };
+    r[11] = r[0] ^ r[4];
+    r[4] = r[11].rotate_left(1);
+    r[11] = r[9] & POOL[3003];
+    r[0] = r[11] ^ r[4];
+    r[3] = r[12] ^ r[0];
+    r[0] = r[8] & POOL[3004];
+    r[12] = r[11] ^ POOL[3005];
+    r[11] = r[12] ^ POOL[3006];
+    r[12] = r[11] ^ POOL[3007];
+    r[11] = r[4] ^ POOL[3008];
+    r[4] = {
+        let (t, s) = (POOL[3009], POOL[3010]);
+        let (ma, mb) = (t ^ s ^ POOL[3011], t ^ s ^ POOL[3012]);
+        let mut z = t;
+        z ^= r[12] & mb;
+        z ^= r[11] & ma;
+        z ^= r[12] & r[11];
+        z
+    };
+    r[11] = r[0] ^ r[4];
+    r[4] = r[11].rotate_left(1);
+    r[11] = r[9] & POOL[3013];
+    r[0] = r[11] ^ r[4];
+    r[12] = r[3] ^ r[0];
+    r[0] = r[8] & POOL[3014];
+    r[3] = r[11] ^ POOL[3015];
+    r[11] = r[3] ^ POOL[3016];
+    r[3] = r[11] ^ POOL[3017];
+    r[11] = r[4] ^ POOL[3018];
+    r[4] = {
+        let (t, s) = (POOL[3019], POOL[3020]);
+        let (ma, mb) = (t ^ s ^ POOL[3021], t ^ s ^ POOL[3022]);
+        let mut z = t;
+        z ^= r[3] & mb;
+        z ^= r[11] & ma;
+        z ^= r[3] & r[11];
+        z
+    };
+    r[11] = r[0] ^ r[4];
+    r[4] = r[11].rotate_left(1);
+    r[11] = r[9] & POOL[3023];
+    r[0] = r[11] ^ r[4];
+    r[3] = r[12] ^ r[0];
+    r[0] = r[8] & POOL[3024];
+    r[12] = r[11] ^ POOL[3025];
+    r[11] = r[12] ^ POOL[3026];
+    r[12] = r[11] ^ POOL[3027];
+    r[11] = r[4] ^ POOL[3028];
+    r[4] = {
+        let (t, s) = (POOL[3029], POOL[3030]);
+        let (ma, mb) = (t ^ s ^ POOL[3031], t ^ s ^ POOL[3032]);
+        let mut z = t;
+        z ^= r[12] & mb;
+        z ^= r[11] & ma;
+        z ^= r[12] & r[11];
+        z
+    };
+    r[11] = r[0] ^ r[4];
+    r[4] = r[11].rotate_left(1);
+    r[11] = r[9] & POOL[3033];
+    r[0] = r[11] ^ r[4];
+    r[12] = r[3] ^ r[0];
+    r[0] = r[8] & POOL[3034];
+    r[3] = r[11] ^ POOL[3035];
+    r[11] = r[3] ^ POOL[3036];
+    r[3] = r[11] ^ POOL[3037];
+    r[11] = r[4] ^ POOL[3038];
+    r[4] = {
+        let (t, s) = (POOL[3039], POOL[3040]);
+        let (ma, mb) = (t ^ s ^ POOL[3041], t ^ s ^ POOL[3042]);
+        let mut z = t;
+        z ^= r[3] & mb;
+        z ^= r[11] & ma;
+        z ^= r[3] & r[11];
+        z
+    };
+    r[11] = r[0] ^ r[4];
+    r[4] = r[11].rotate_left(1);
+    r[11] = r[9] & POOL[3043];
+    r[0] = r[11] ^ r[4];
+    r[3] = r[12] ^ r[0];
+    r[0] = r[8] & POOL[3044];
+    r[12] = r[11] ^ POOL[3045];
+    r[11] = r[12] ^ POOL[3046];
+    r[12] = r[11] ^ POOL[3047];
+    r[11] = r[4] ^ POOL[3048];
+    r[4] = {
+        let (t, s) = (POOL[3049], POOL[3050]);
+        let (ma, mb) = (t ^ s ^ POOL[3051], t ^ s ^ POOL[3052]);
+        let mut z = t;
+        z ^= r[12] & mb;
+        z ^= r[11] & ma;
+        z ^= r[12] & r[11];
+        z
+    };
+    r[11] = r[0] ^ r[4];
+    r[4] = r[11].rotate_left(1);
+    r[11] = r[9] & POOL[3053];
+    r[0] = r[11] ^ r[4];
+    r[12] = r[3] ^ r[0];
+    r[0] = r[8] & POOL[3054];
+    r[3] = r[11] ^ POOL[3055];
+    r[11] = r[3] ^ POOL[3056];
+    r[3] = r[11] ^ POOL[3057];
+    r[11] = r[4] ^ POOL[3058];
+    r[4] = {
+        let (t, s) = (POOL[3059], POOL[3060]);
+        let (ma, mb) = (t ^ s ^ POOL[3061], t ^ s ^ POOL[3062]);
+        let mut z = t;
+        z ^= r[3] & mb;
+        z ^= r[11] & ma;
+        z ^= r[3] & r[11];
+        z
+    };
+    r[11] = r[0] ^ r[4];
+    r[4] = r[11].rotate_left(1);
+    r[11] = r[9] & POOL[3063];
+    r[0] = r[11] ^ r[4];
+    r[3] = r[12] ^ r[0];
+    r[0] = r[8] & POOL[3064];
+    r[12] = r[11] ^ POOL[3065];
+    r[11] = r[12] ^ POOL[3066];
+    r[12] = r[11] ^ POOL[3067];
+    r[11] = r[4] ^ POOL[3068];
+    r[4] = {
+        let (t, s) = (POOL[3069], POOL[3070]);
+        let (ma, mb) = (t ^ s ^ POOL[3071], t ^ s ^ POOL[3072]);
+        let mut z = t;
+        z ^= r[12] & mb;
+        z ^= r[11] & ma;
+        z ^= r[12] & r[11];
+        z
+    };
+    r[11] = r[0] ^ r[4];
+    r[4] = r[11].rotate_left(1);
+    r[11] = r[9] & POOL[3073];
+    r[0] = r[11] ^ r[4];
+    r[12] = r[3] ^ r[0];
+    r[0] = r[8] & POOL[3074];
+    r[3] = r[11] ^ POOL[3075];
+    r[11] = r[3] ^ POOL[3076];
+    r[3] = r[11] ^ POOL[3077];
+    r[11] = r[4] ^ POOL[3078];
+    r[4] = {
+        let (t, s) = (POOL[3079], POOL[3080]);
+        let (ma, mb) = (t ^ s ^ POOL[3081], t ^ s ^ POOL[3082]);
+        let mut z = t;
+        z ^= r[3] & mb;
+        z ^= r[11] & ma;
+        z ^= r[3] & r[11];
+        z
+    };
+    r[11] = r[0] ^ r[4];
+    r[4] = r[11].rotate_left(1);
+    r[11] = r[9] & POOL[3083];
+    r[0] = r[11] ^ r[4];
+    r[3] = r[12] ^ r[0];
+    r[0] = r[8] & POOL[3084];
+    r[12] = r[11] ^ POOL[3085];
+    r[11] = r[12] ^ POOL[3086];
+    r[12] = r[11] ^ POOL[3087];
+    r[11] = r[4] ^ POOL[3088];
+    r[4] = {
+        let (t, s) = (POOL[3089], POOL[3090]);
+        let (ma, mb) = (t ^ s ^ POOL[3091], t ^ s ^ POOL[3092]);
+        let mut z = t;
+        z ^= r[12] & mb;
+        z ^= r[11] & ma;
+        z ^= r[12] & r[11];
+        z
+    };
+    r[11] = r[0] ^ r[4];
+    r[4] = r[11].rotate_left(1);
+    r[11] = r[9] & POOL[3093];
+    r[0] = r[11] ^ r[4];
+    r[12] = r[3] ^ r[0];
+    r[0] = r[8] & POOL[3094];
+    r[3] = r[11] ^ POOL[3095];
+    r[11] = r[3] ^ POOL[3096];
+    r[3] = r[11] ^ POOL[3097];
+    r[11] = r[4] ^ POOL[3098];
+    r[4] = {
+        let (t, s) = (POOL[3099], POOL[3100]);
+        let (ma, mb) = (t ^ s ^ POOL[3101], t ^ s ^ POOL[3102]);
+        let mut z = t;
+        z ^= r[3] & mb;
+        z ^= r[11] & ma;
+        z ^= r[3] & r[11];
+        z
+    };
+    r[11] = r[0] ^ r[4];
+    r[4] = r[11].rotate_left(1);
+    r[11] = r[9] & POOL[3103];
+    r[0] = r[11] ^ r[4];
+    r[3] = r[12] ^ r[0];
+    r[0] = r[8] & POOL[3104];
+    r[12] = r[11] ^ POOL[3105];
+    r[11] = r[12] ^ POOL[3106];
+    r[12] = r[11] ^ POOL[3107];
+    r[11] = r[4] ^ POOL[3108];
+    r[4] = {
+        let (t, s) = (POOL[3109], POOL[3110]);
+        let (ma, mb) = (t ^ s ^ POOL[3111], t ^ s ^ POOL[3112]);
+        let mut z = t;
+        z ^= r[12] & mb;
+        z ^= r[11] & ma;
+        z ^= r[12] & r[11];
+        z
+    };
+    r[11] = r[0] ^ r[4];
+    r[4] = r[11].rotate_left(1);
+    r[11] = r[9] & POOL[3113];
+    r[0] = r[11] ^ r[4];
+    r[12] = r[3] ^ r[0];
+    r[0] = r[8] & POOL[3114];
+    r[8] = r[11] ^ POOL[3115];
+    r[11] = r[8] ^ POOL[3116];
+    r[8] = r[11] ^ POOL[3117];
+    r[11] = r[4] ^ POOL[3118];
+    r[4] = {
+        let (t, s) = (POOL[3119], POOL[3120]);
+        let (ma, mb) = (t ^ s ^ POOL[3121], t ^ s ^ POOL[3122]);
+        let mut z = t;
+        z ^= r[8] & mb;
+        z ^= r[11] & ma;
+        z ^= r[8] & r[11];
+        z
+    };
+    r[11] = r[0] ^ r[4];
+    r[4] = r[11].rotate_left(1);
+    r[11] = r[9] & POOL[3123];
+    r[9] = r[11] ^ r[4];
+    r[4] = r[12] ^ r[9];
+    r[9] = r[5] ^ r[4];
+    r[5] = r[9].rotate_left(12);
+    r[9] = r[7] ^ POOL[3124];
+    r[11] = r[5] ^ POOL[3125];
+    r[8] = {
+        let (t, s) = (POOL[3126], POOL[3127]);
+        let (ma, mb) = (t ^ s ^ POOL[3128], t ^ s ^ POOL[3129]);
+        let mut z = t;
+        z ^= r[9] & mb;
+        z ^= r[11] & ma;
+        z ^= r[9] & r[11];
+        z
+    };
+    r[9] = r[7] ^ r[5];
+    r[11] = r[9] & POOL[3130];
+    r[7] = r[8] & POOL[3131];
+    r[0] = r[7].rotate_left(1);
+    r[7] = r[9] & POOL[3132];
+    r[12] = r[7] ^ r[0];
+    r[3] = r[11] ^ r[12];
+    r[11] = r[8] & POOL[3133];
+    r[12] = r[7] ^ POOL[3134];
+    r[7] = r[12] ^ POOL[3135];
+    r[12] = r[7] ^ POOL[3136];
+    r[7] = r[0] ^ POOL[3137];
+    r[0] = r[7] ^ POOL[3138];
+    r[7] = r[0] ^ POOL[3139];
+    r[0] = {
+        let (t, s) = (POOL[3140], POOL[3141]);
+        let (ma, mb) = (t ^ s ^ POOL[3142], t ^ s ^ POOL[3143]);
+        let mut z = t;
+        z ^= r[12] & mb;
+        z ^= r[7] & ma;
+        z ^= r[12] & r[7];
+        z
+    };
+    r[7] = r[11] ^ r[0];
+    r[11] = r[7].rotate_left(1);
+    r[7] = r[9] & POOL[3144];
+    r[0] = r[7] ^ r[11];
+    r[12] = r[3] ^ r[0];
+    r[0] = r[8] & POOL[3145];
+    r[3] = r[7] ^ POOL[3146];
+    r[7] = r[3] ^ POOL[3147];
+    r[3] = r[7] ^ POOL[3148];
+    r[7] = r[11] ^ POOL[3149];
+    r[11] = {
+        let (t, s) = (POOL[3150], POOL[3151]);
+        let (ma, mb) = (t ^ s ^ POOL[3152], t ^ s ^ POOL[3153]);
+        let mut z = t;
+        z ^= r[3] & mb;
+        z ^= r[7] & ma;
+        z ^= r[3] & r[7];
+        z
+    };
+    r[7] = r[0] ^ r[11];
+    r[11] = r[7].rotate_left(1);
+    r[7] = r[9] & POOL[3154];
+    r[0] = r[7] ^ r[11];
+    r[3] = r[12] ^ r[0];
+    r[0] = r[8] & POOL[3155];
+    r[12] = r[7] ^ POOL[3156];
+    r[7] = r[12] ^ POOL[3157];
+    r[12] = r[7] ^ POOL[3158];
+    r[7] = r[11] ^ POOL[3159];
+    r[11] = {
+        let (t, s) = (POOL[3160], POOL[3161]);
+        let (ma, mb) = (t ^ s ^ POOL[3162], t ^ s ^ POOL[3163]);
+        let mut z = t;
+        z ^= r[12] & mb;
+        z ^= r[7] & ma;
+        z ^= r[12] & r[7];
+        z
+    };
+    r[7] = r[0] ^ r[11];
+    r[11] = r[7].rotate_left(1);
+    r[7] = r[9] & POOL[3164];
+    r[0] = r[7] ^ r[11];
+    r[12] = r[3] ^ r[0];
+    r[0] = r[8] & POOL[3165];
+    r[3] = r[7] ^ POOL[3166];
+    r[7] = r[3] ^ POOL[3167];
+    r[3] = r[7] ^ POOL[3168];
+    r[7] = r[11] ^ POOL[3169];
+    r[11] = {
+        let (t, s) = (POOL[3170], POOL[3171]);
+        let (ma, mb) = (t ^ s ^ POOL[3172], t ^ s ^ POOL[3173]);
+        let mut z = t;
+        z ^= r[3] & mb;
+        z ^= r[7] & ma;
+        z ^= r[3] & r[7];
+        z
+    };
+    r[7] = r[0] ^ r[11];
+    r[11] = r[7].rotate_left(1);
+    r[7] = r[9] & POOL[3174];
+    r[0] = r[7] ^ r[11];
+    r[3] = r[12] ^ r[0];
+    r[0] = r[8] & POOL[3175];
+    r[12] = r[7] ^ POOL[3176];
+    r[7] = r[12] ^ POOL[3177];
+    r[12] = r[7] ^ POOL[3178];
+    r[7] = r[11] ^ POOL[3179];
+    r[11] = {
+        let (t, s) = (POOL[3180], POOL[3181]);
+        let (ma, mb) = (t ^ s ^ POOL[3182], t ^ s ^ POOL[3183]);
+        let mut z = t;
+        z ^= r[12] & mb;
+        z ^= r[7] & ma;
+        z ^= r[12] & r[7];
+        z
+    };
+    r[7] = r[0] ^ r[11];
+    r[11] = r[7].rotate_left(1);
+    r[7] = r[9] & POOL[3184];
+    r[0] = r[7] ^ r[11];
+    r[12] = r[3] ^ r[0];
+    r[0] = r[8] & POOL[3185];
+    r[3] = r[7] ^ POOL[3186];
+    r[7] = r[3] ^ POOL[3187];
+    r[3] = r[7] ^ POOL[3188];
+    r[7] = r[11] ^ POOL[3189];
+    r[11] = {
+        let (t, s) = (POOL[3190], POOL[3191]);
+        let (ma, mb) = (t ^ s ^ POOL[3192], t ^ s ^ POOL[3193]);
+        let mut z = t;
+        z ^= r[3] & mb;
+        z ^= r[7] & ma;
+        z ^= r[3] & r[7];
+        z
+    };
+    r[7] = r[0] ^ r[11];
+    r[11] = r[7].rotate_left(1);
+    r[7] = r[9] & POOL[3194];
+    r[0] = r[7] ^ r[11];
+    r[3] = r[12] ^ r[0];
+    r[0] = r[8] & POOL[3195];
+    r[12] = r[7] ^ POOL[3196];
+    r[7] = r[12] ^ POOL[3197];
+    r[12] = r[7] ^ POOL[3198];
+    r[7] = r[11] ^ POOL[3199];
+    r[11] = {
+        let (t, s) = (POOL[3200], POOL[3201]);
+        let (ma, mb) = (t ^ s ^ POOL[3202], t ^ s ^ POOL[3203]);
+        let mut z = t;
+        z ^= r[12] & mb;
+        z ^= r[7] & ma;
+        z ^= r[12] & r[7];
+        z
+    };
+    r[7] = r[0] ^ r[11];
+    r[11] = r[7].rotate_left(1);
+    r[7] = r[9] & POOL[3204];
+    r[0] = r[7] ^ r[11];
+    r[12] = r[3] ^ r[0];
+    r[0] = r[8] & POOL[3205];
+    r[3] = r[7] ^ POOL[3206];
+    r[7] = r[3] ^ POOL[3207];
+    r[3] = r[7] ^ POOL[3208];
+    r[7] = r[11] ^ POOL[3209];
+    r[11] = {
+        let (t, s) = (POOL[3210], POOL[3211]);
+        let (ma, mb) = (t ^ s ^ POOL[3212], t ^ s ^ POOL[3213]);
+        let mut z = t;
+        z ^= r[3] & mb;
+        z ^= r[7] & ma;
+        z ^= r[3] & r[7];
+        z
+    };
+    r[7] = r[0] ^ r[11];
+    r[11] = r[7].rotate_left(1);
+    r[7] = r[9] & POOL[3214];
+    r[0] = r[7] ^ r[11];
+    r[3] = r[12] ^ r[0];
+    r[0] = r[8] & POOL[3215];
+    r[12] = r[7] ^ POOL[3216];
+    r[7] = r[12] ^ POOL[3217];
+    r[12] = r[7] ^ POOL[3218];
+    r[7] = r[11] ^ POOL[3219];
+    r[11] = {
+        let (t, s) = (POOL[3220], POOL[3221]);
+        let (ma, mb) = (t ^ s ^ POOL[3222], t ^ s ^ POOL[3223]);
+        let mut z = t;
+        z ^= r[12] & mb;
+        z ^= r[7] & ma;
+        z ^= r[12] & r[7];
+        z
+    };
+    r[7] = r[0] ^ r[11];
+    r[11] = r[7].rotate_left(1);
+    r[7] = r[9] & POOL[3224];
+    r[0] = r[7] ^ r[11];
+    r[12] = r[3] ^ r[0];
+    r[0] = r[8] & POOL[3225];
+    r[3] = r[7] ^ POOL[3226];
+    r[7] = r[3] ^ POOL[3227];
+    r[3] = r[7] ^ POOL[3228];
+    r[7] = r[11] ^ POOL[3229];
+    r[11] = {
+        let (t, s) = (POOL[3230], POOL[3231]);
+        let (ma, mb) = (t ^ s ^ POOL[3232], t ^ s ^ POOL[3233]);
+        let mut z = t;
+        z ^= r[3] & mb;
+        z ^= r[7] & ma;
+        z ^= r[3] & r[7];
+        z
+    };
+    r[7] = r[0] ^ r[11];
+    r[11] = r[7].rotate_left(1);
+    r[7] = r[9] & POOL[3234];
+    r[0] = r[7] ^ r[11];
+    r[3] = r[12] ^ r[0];
+    r[0] = r[8] & POOL[3235];
+    r[12] = r[7] ^ POOL[3236];
+    r[7] = r[12] ^ POOL[3237];
+    r[12] = r[7] ^ POOL[3238];
+    r[7] = r[11] ^ POOL[3239];
+    r[11] = {
+        let (t, s) = (POOL[3240], POOL[3241]);
+        let (ma, mb) = (t ^ s ^ POOL[3242], t ^ s ^ POOL[3243]);
+        let mut z = t;
+        z ^= r[12] & mb;
+        z ^= r[7] & ma;
+        z ^= r[12] & r[7];
+        z
+    };
+    r[7] = r[0] ^ r[11];
+    r[11] = r[7].rotate_left(1);
+    r[7] = r[9] & POOL[3244];
+    r[0] = r[7] ^ r[11];
+    r[12] = r[3] ^ r[0];
+    r[0] = r[8] & POOL[3245];
+    r[3] = r[7] ^ POOL[3246];
+    r[7] = r[3] ^ POOL[3247];
+    r[3] = r[7] ^ POOL[3248];
+    r[7] = r[11] ^ POOL[3249];
+    r[11] = {
+        let (t, s) = (POOL[3250], POOL[3251]);
+        let (ma, mb) = (t ^ s ^ POOL[3252], t ^ s ^ POOL[3253]);
+        let mut z = t;
+        z ^= r[3] & mb;
+        z ^= r[7] & ma;
+        z ^= r[3] & r[7];
+        z
+    };
+    r[7] = r[0] ^ r[11];
+    r[11] = r[7].rotate_left(1);
+    r[7] = r[9] & POOL[3254];
+    r[0] = r[7] ^ r[11];
+    r[3] = r[12] ^ r[0];
+    r[0] = r[8] & POOL[3255];
+    r[12] = r[7] ^ POOL[3256];
+    r[7] = r[12] ^ POOL[3257];
+    r[12] = r[7] ^ POOL[3258];
+    r[7] = r[11] ^ POOL[3259];
+    r[11] = {
+        let (t, s) = (POOL[3260], POOL[3261]);
+        let (ma, mb) = (t ^ s ^ POOL[3262], t ^ s ^ POOL[3263]);
+        let mut z = t;
+        z ^= r[12] & mb;
+        z ^= r[7] & ma;
+        z ^= r[12] & r[7];
+        z
+    };
+    r[7] = r[0] ^ r[11];
+    r[11] = r[7].rotate_left(1);
+    r[7] = r[9] & POOL[3264];
+    r[0] = r[7] ^ r[11];
+    r[12] = r[3] ^ r[0];
+    r[0] = r[8] & POOL[3265];
+    r[3] = r[7] ^ POOL[3266];
+    r[7] = r[3] ^ POOL[3267];
+    r[3] = r[7] ^ POOL[3268];
+    r[7] = r[11] ^ POOL[3269];
+    r[11] = {
+        let (t, s) = (POOL[3270], POOL[3271]);
+        let (ma, mb) = (t ^ s ^ POOL[3272], t ^ s ^ POOL[3273]);
+        let mut z = t;
+        z ^= r[3] & mb;
+        z ^= r[7] & ma;
+        z ^= r[3] & r[7];
+        z
+    };
+    r[7] = r[0] ^ r[11];
+    r[11] = r[7].rotate_left(1);
+    r[7] = r[9] & POOL[3274];
+    r[0] = r[7] ^ r[11];
+    r[3] = r[12] ^ r[0];
+    r[0] = r[8] & POOL[3275];
+    r[12] = r[7] ^ POOL[3276];
+    r[7] = r[12] ^ POOL[3277];
+    r[12] = r[7] ^ POOL[3278];
+    r[7] = r[11] ^ POOL[3279];
+    r[11] = {
+        let (t, s) = (POOL[3280], POOL[3281]);
+        let (ma, mb) = (t ^ s ^ POOL[3282], t ^ s ^ POOL[3283]);
+        let mut z = t;
+        z ^= r[12] & mb;
+        z ^= r[7] & ma;
+        z ^= r[12] & r[7];
+        z
+    };
+    r[7] = r[0] ^ r[11];
+    r[11] = r[7].rotate_left(1);
+    r[7] = r[9] & POOL[3284];
+    r[0] = r[7] ^ r[11];
+    r[12] = r[3] ^ r[0];
+    r[0] = r[8] & POOL[3285];
+    r[3] = r[7] ^ POOL[3286];
+    r[7] = r[3] ^ POOL[3287];
+    r[3] = r[7] ^ POOL[3288];
+    r[7] = r[11] ^ POOL[3289];
+    r[11] = {
+        let (t, s) = (POOL[3290], POOL[3291]);
+        let (ma, mb) = (t ^ s ^ POOL[3292], t ^ s ^ POOL[3293]);
+        let mut z = t;
+        z ^= r[3] & mb;
+        z ^= r[7] & ma;
+        z ^= r[3] & r[7];
+        z
+    };
+    r[7] = r[0] ^ r[11];
+    r[11] = r[7].rotate_left(1);
+    r[7] = r[9] & POOL[3294];
+    r[0] = r[7] ^ r[11];
+    r[3] = r[12] ^ r[0];
+    r[0] = r[8] & POOL[3295];
+    r[12] = r[7] ^ POOL[3296];
+    r[7] = r[12] ^ POOL[3297];
+    r[12] = r[7] ^ POOL[3298];
+    r[7] = r[11] ^ POOL[3299];
+    r[11] = {
+        let (t, s) = (POOL[3300], POOL[3301]);
+        let (ma, mb) = (t ^ s ^ POOL[3302], t ^ s ^ POOL[3303]);
+        let mut z = t;
+        z ^= r[12] & mb;
+        z ^= r[7] & ma;
+        z ^= r[12] & r[7];
+        z
+    };
+    r[7] = r[0] ^ r[11];
+    r[11] = r[7].rotate_left(1);
+    r[7] = r[9] & POOL[3304];
+    r[0] = r[7] ^ r[11];
+    r[12] = r[3] ^ r[0];
+    r[0] = r[8] & POOL[3305];
+    r[3] = r[7] ^ POOL[3306];
+    r[7] = r[3] ^ POOL[3307];
+    r[3] = r[7] ^ POOL[3308];
+    r[7] = r[11] ^ POOL[3309];
+    r[11] = {
+        let (t, s) = (POOL[3310], POOL[3311]);
+        let (ma, mb) = (t ^ s ^ POOL[3312], t ^ s ^ POOL[3313]);
+        let mut z = t;
+        z ^= r[3] & mb;
+        z ^= r[7] & ma;
+        z ^= r[3] & r[7];
+        z
+    };
+    r[7] = r[0] ^ r[11];
+    r[11] = r[7].rotate_left(1);
+    r[7] = r[9] & POOL[3314];
+    r[0] = r[7] ^ r[11];
+    r[3] = r[12] ^ r[0];
+    r[0] = r[8] & POOL[3315];
+    r[12] = r[7] ^ POOL[3316];
+    r[7] = r[12] ^ POOL[3317];
+    r[12] = r[7] ^ POOL[3318];
+    r[7] = r[11] ^ POOL[3319];
+    r[11] = {
+        let (t, s) = (POOL[3320], POOL[3321]);
+        let (ma, mb) = (t ^ s ^ POOL[3322], t ^ s ^ POOL[3323]);
+        let mut z = t;
+        z ^= r[12] & mb;
+        z ^= r[7] & ma;
+        z ^= r[12] & r[7];
+        z
+    };
+    r[7] = r[0] ^ r[11];
+    r[11] = r[7].rotate_left(1);
+    r[7] = r[9] & POOL[3324];
+    r[0] = r[7] ^ r[11];
+    r[12] = r[3] ^ r[0];
+    r[0] = r[8] & POOL[3325];
+    r[3] = r[7] ^ POOL[3326];
+    r[7] = r[3] ^ POOL[3327];
+    r[3] = r[7] ^ POOL[3328];
+    r[7] = r[11] ^ POOL[3329];
+    r[11] = {
+        let (t, s) = (POOL[3330], POOL[3331]);
+        let (ma, mb) = (t ^ s ^ POOL[3332], t ^ s ^ POOL[3333]);
+        let mut z = t;
+        z ^= r[3] & mb;
+        z ^= r[7] & ma;
+        z ^= r[3] & r[7];
+        z
+    };
+    r[7] = r[0] ^ r[11];
+    r[11] = r[7].rotate_left(1);
+    r[7] = r[9] & POOL[3334];
+    r[0] = r[7] ^ r[11];
+    r[3] = r[12] ^ r[0];
+    r[0] = r[8] & POOL[3335];
+    r[12] = r[7] ^ POOL[3336];
+    r[7] = r[12] ^ POOL[3337];
+    r[12] = r[7] ^ POOL[3338];
+    r[7] = r[11] ^ POOL[3339];
+    r[11] = {
+        let (t, s) = (POOL[3340], POOL[3341]);
+        let (ma, mb) = (t ^ s ^ POOL[3342], t ^ s ^ POOL[3343]);
+        let mut z = t;
+        z ^= r[12] & mb;
+        z ^= r[7] & ma;
+        z ^= r[12] & r[7];
+        z
+    };
+    r[7] = r[0] ^ r[11];
+    r[11] = r[7].rotate_left(1);
+    r[7] = r[9] & POOL[3344];
+    r[0] = r[7] ^ r[11];
+    r[12] = r[3] ^ r[0];
+    r[0] = r[8] & POOL[3345];
+    r[3] = r[7] ^ POOL[3346];
+    r[7] = r[3] ^ POOL[3347];
+    r[3] = r[7] ^ POOL[3348];
+    r[7] = r[11] ^ POOL[3349];
+    r[11] = {
+        let (t, s) = (POOL[3350], POOL[3351]);
+        let (ma, mb) = (t ^ s ^ POOL[3352], t ^ s ^ POOL[3353]);
+        let mut z = t;
+        z ^= r[3] & mb;
+        z ^= r[7] & ma;
+        z ^= r[3] & r[7];
+        z
+    };
+    r[7] = r[0] ^ r[11];
+    r[11] = r[7].rotate_left(1);
+    r[7] = r[9] & POOL[3354];
+    r[0] = r[7] ^ r[11];
+    r[3] = r[12] ^ r[0];
+    r[0] = r[8] & POOL[3355];
+    r[12] = r[7] ^ POOL[3356];
+    r[7] = r[12] ^ POOL[3357];
+    r[12] = r[7] ^ POOL[3358];
+    r[7] = r[11] ^ POOL[3359];
+    r[11] = {
+        let (t, s) = (POOL[3360], POOL[3361]);
+        let (ma, mb) = (t ^ s ^ POOL[3362], t ^ s ^ POOL[3363]);
+        let mut z = t;
+        z ^= r[12] & mb;
+        z ^= r[7] & ma;
+        z ^= r[12] & r[7];
+        z
+    };
+    r[7] = r[0] ^ r[11];
+    r[11] = r[7].rotate_left(1);
+    r[7] = r[9] & POOL[3364];
+    r[0] = r[7] ^ r[11];
+    r[12] = r[3] ^ r[0];
+    r[0] = r[8] & POOL[3365];
+    r[3] = r[7] ^ POOL[3366];
+    r[7] = r[3] ^ POOL[3367];
+    r[3] = r[7] ^ POOL[3368];
+    r[7] = r[11] ^ POOL[3369];
+    r[11] = {
+        let (t, s) = (POOL[3370], POOL[3371]);
+        let (ma, mb) = (t ^ s ^ POOL[3372], t ^ s ^ POOL[3373]);
+        let mut z = t;
+        z ^= r[3] & mb;
+        z ^= r[7] & ma;
+        z ^= r[3] & r[7];
+        z
+    };
+    r[7] = r[0] ^ r[11];
+    r[11] = r[7].rotate_left(1);
+    r[7] = r[9] & POOL[3374];
+    r[0] = r[7] ^ r[11];
+    r[3] = r[12] ^ r[0];
+    r[0] = r[8] & POOL[3375];
+    r[12] = r[7] ^ POOL[3376];
+    r[7] = r[12] ^ POOL[3377];
+    r[12] = r[7] ^ POOL[3378];
+    r[7] = r[11] ^ POOL[3379];
+    r[11] = {
+        let (t, s) = (POOL[3380], POOL[3381]);
+        let (ma, mb) = (t ^ s ^ POOL[3382], t ^ s ^ POOL[3383]);
+        let mut z = t;
+        z ^= r[12] & mb;
+        z ^= r[7] & ma;
+        z ^= r[12] & r[7];
+        z
+    };
+    r[7] = r[0] ^ r[11];
+    r[11] = r[7].rotate_left(1);
+    r[7] = r[9] & POOL[3384];
+    r[0] = r[7] ^ r[11];
+    r[12] = r[3] ^ r[0];
+    r[0] = r[8] & POOL[3385];
+    r[3] = r[7] ^ POOL[3386];
+    r[7] = r[3] ^ POOL[3387];
+    r[3] = r[7] ^ POOL[3388];
+    r[7] = r[11] ^ POOL[3389];
+    r[11] = {
+        let (t, s) = (POOL[3390], POOL[3391]);
+        let (ma, mb) = (t ^ s ^ POOL[3392], t ^ s ^ POOL[3393]);
+        let mut z = t;
+        z ^= r[3] & mb;
+        z ^= r[7] & ma;
+        z ^= r[3] & r[7];
+        z
+    };
+    r[7] = r[0] ^ r[11];
+    r[11] = r[7].rotate_left(1);
+    r[7] = r[9] & POOL[3394];
+    r[0] = r[7] ^ r[11];
+    r[3] = r[12] ^ r[0];
+    r[0] = r[8] & POOL[3395];
+    r[12] = r[7] ^ POOL[3396];
+    r[7] = r[12] ^ POOL[3397];
+    r[12] = r[7] ^ POOL[3398];
+    r[7] = r[11] ^ POOL[3399];
+    r[11] = {
+        let (t, s) = (POOL[3400], POOL[3401]);
+        let (ma, mb) = (t ^ s ^ POOL[3402], t ^ s ^ POOL[3403]);
+        let mut z = t;
+        z ^= r[12] & mb;
+        z ^= r[7] & ma;
+        z ^= r[12] & r[7];
+        z
+    };
+    r[7] = r[0] ^ r[11];
+    r[11] = r[7].rotate_left(1);
+    r[7] = r[9] & POOL[3404];
+    r[0] = r[7] ^ r[11];
+    r[12] = r[3] ^ r[0];
+    r[0] = r[8] & POOL[3405];
+    r[3] = r[7] ^ POOL[3406];
+    r[7] = r[3] ^ POOL[3407];
+    r[3] = r[7] ^ POOL[3408];
+    r[7] = r[11] ^ POOL[3409];
+    r[11] = {
+        let (t, s) = (POOL[3410], POOL[3411]);
+        let (ma, mb) = (t ^ s ^ POOL[3412], t ^ s ^ POOL[3413]);
+        let mut z = t;
+        z ^= r[3] & mb;
+        z ^= r[7] & ma;
+        z ^= r[3] & r[7];
+        z
+    };
+    r[7] = r[0] ^ r[11];
+    r[11] = r[7].rotate_left(1);
+    r[7] = r[9] & POOL[3414];
+    r[0] = r[7] ^ r[11];
+    r[3] = r[12] ^ r[0];
+    r[0] = r[8] & POOL[3415];
+    r[12] = r[7] ^ POOL[3416];
+    r[7] = r[12] ^ POOL[3417];
+    r[12] = r[7] ^ POOL[3418];
+    r[7] = r[11] ^ POOL[3419];
+    r[11] = {
+        let (t, s) = (POOL[3420], POOL[3421]);
+        let (ma, mb) = (t ^ s ^ POOL[3422], t ^ s ^ POOL[3423]);
+        let mut z = t;
+        z ^= r[12] & mb;
+        z ^= r[7] & ma;
+        z ^= r[12] & r[7];
+        z
+    };
+    r[7] = r[0] ^ r[11];
+    r[11] = r[7].rotate_left(1);
+    r[7] = r[9] & POOL[3424];
+    r[0] = r[7] ^ r[11];
+    r[12] = r[3] ^ r[0];
+    r[0] = r[8] & POOL[3425];
+    r[8] = r[7] ^ POOL[3426];
+    r[7] = r[8] ^ POOL[3427];
+    r[8] = r[7] ^ POOL[3428];
+    r[7] = r[11] ^ POOL[3429];
+    r[11] = {
+        let (t, s) = (POOL[3430], POOL[3431]);
+        let (ma, mb) = (t ^ s ^ POOL[3432], t ^ s ^ POOL[3433]);
+        let mut z = t;
+        z ^= r[8] & mb;
+        z ^= r[7] & ma;
+        z ^= r[8] & r[7];
+        z
+    };
+    r[7] = r[0] ^ r[11];
+    r[11] = r[7].rotate_left(1);
+    r[7] = r[9] & POOL[3434];
+    r[9] = r[7] ^ r[11];
+    r[11] = r[12] ^ r[9];
+    r[9] = r[2] ^ r[11];
+    r[2] = r[9].rotate_left(8);
+    r[9] = r[4] ^ POOL[3435];
+    r[7] = r[2] ^ POOL[3436];
+    r[8] = {
+        let (t, s) = (POOL[3437], POOL[3438]);
+        let (ma, mb) = (t ^ s ^ POOL[3439], t ^ s ^ POOL[3440]);
+        let mut z = t;
+        z ^= r[9] & mb;
+        z ^= r[7] & ma;
+        z ^= r[9] & r[7];
+        z
+    };
+    r[9] = r[4] ^ r[2];
+    r[4] = r[9] & POOL[3441];
+    r[7] = r[8] & POOL[3442];
+    r[0] = r[7].rotate_left(1);
+    r[7] = r[9] & POOL[3443];
+    r[12] = r[7] ^ r[0];
+    r[3] = r[4] ^ r[12];
+    r[4] = r[8] & POOL[3444];
+    r[12] = r[7] ^ POOL[3445];
+    r[7] = r[12] ^ POOL[3446];
+    r[12] = r[7] ^ POOL[3447];
+    r[7] = r[0] ^ POOL[3448];
+    r[0] = r[7] ^ POOL[3449];
+    r[7] = r[0] ^ POOL[3450];
+    r[0] = {
+        let (t, s) = (POOL[3451], POOL[3452]);
+        let (ma, mb) = (t ^ s ^ POOL[3453], t ^ s ^ POOL[3454]);
+        let mut z = t;
+        z ^= r[12] & mb;
+        z ^= r[7] & ma;
+        z ^= r[12] & r[7];
+        z
+    };
+    r[7] = r[4] ^ r[0];
+    r[4] = r[7].rotate_left(1);
+    r[7] = r[9] & POOL[3455];
+    r[0] = r[7] ^ r[4];
+    r[12] = r[3] ^ r[0];
+    r[0] = r[8] & POOL[3456];
+    r[3] = r[7] ^ POOL[3457];
+    r[7] = r[3] ^ POOL[3458];
+    r[3] = r[7] ^ POOL[3459];
+    r[7] = r[4] ^ POOL[3460];
+    r[4] = {
+        let (t, s) = (POOL[3461], POOL[3462]);
+        let (ma, mb) = (t ^ s ^ POOL[3463], t ^ s ^ POOL[3464]);
+        let mut z = t;
+        z ^= r[3] & mb;
+        z ^= r[7] & ma;
+        z ^= r[3] & r[7];
+        z
+    };
+    r[7] = r[0] ^ r[4];
+    r[4] = r[7].rotate_left(1);
+    r[7] = r[9] & POOL[3465];
+    r[0] = r[7] ^ r[4];
+    r[3] = r[12] ^ r[0];
+    r[0] = r[8] & POOL[3466];
+    r[12] = r[7] ^ POOL[3467];
+    r[7] = r[12] ^ POOL[3468];
+    r[12] = r[7] ^ POOL[3469];
+    r[7] = r[4] ^ POOL[3470];
+    r[4] = {
+        let (t, s) = (POOL[3471], POOL[3472]);
+        let (ma, mb) = (t ^ s ^ POOL[3473], t ^ s ^ POOL[3474]);
+        let mut z = t;
+        z ^= r[12] & mb;
+        z ^= r[7] & ma;
+        z ^= r[12] & r[7];
+        z
+    };
+    r[7] = r[0] ^ r[4];
+    r[4] = r[7].rotate_left(1);
+    r[7] = r[9] & POOL[3475];
+    r[0] = r[7] ^ r[4];
+    r[12] = r[3] ^ r[0];
+    r[0] = r[8] & POOL[3476];
+    r[3] = r[7] ^ POOL[3477];
+    r[7] = r[3] ^ POOL[3478];
+    r[3] = r[7] ^ POOL[3479];
+    r[7] = r[4] ^ POOL[3480];
+    r[4] = {
+        let (t, s) = (POOL[3481], POOL[3482]);
+        let (ma, mb) = (t ^ s ^ POOL[3483], t ^ s ^ POOL[3484]);
+        let mut z = t;
+        z ^= r[3] & mb;
+        z ^= r[7] & ma;
+        z ^= r[3] & r[7];
+        z
+    };
+    r[7] = r[0] ^ r[4];
+    r[4] = r[7].rotate_left(1);
+    r[7] = r[9] & POOL[3485];
+    r[0] = r[7] ^ r[4];
+    r[3] = r[12] ^ r[0];
+    r[0] = r[8] & POOL[3486];
+    r[12] = r[7] ^ POOL[3487];
+    r[7] = r[12] ^ POOL[3488];
+    r[12] = r[7] ^ POOL[3489];
+    r[7] = r[4] ^ POOL[3490];
+    r[4] = {
+        let (t, s) = (POOL[3491], POOL[3492]);
+        let (ma, mb) = (t ^ s ^ POOL[3493], t ^ s ^ POOL[3494]);
+        let mut z = t;
+        z ^= r[12] & mb;
+        z ^= r[7] & ma;
+        z ^= r[12] & r[7];
+        z
+    };
+    r[7] = r[0] ^ r[4];
+    r[4] = r[7].rotate_left(1);
+    r[7] = r[9] & POOL[3495];
+    r[0] = r[7] ^ r[4];
+    r[12] = r[3] ^ r[0];
+    r[0] = r[8] & POOL[3496];
+    r[3] = r[7] ^ POOL[3497];
+    r[7] = r[3] ^ POOL[3498];
+    r[3] = r[7] ^ POOL[3499];
+    r[7] = r[4] ^ POOL[3500];
+    r[4] = {
+        let (t, s) = (POOL[3501], POOL[3502]);
+        let (ma, mb) = (t ^ s ^ POOL[3503], t ^ s ^ POOL[3504]);
+        let mut z = t;
+        z ^= r[3] & mb;
+        z ^= r[7] & ma;
+        z ^= r[3] & r[7];
+        z
+    };
+    r[7] = r[0] ^ r[4];
+    r[4] = r[7].rotate_left(1);
+    r[7] = r[9] & POOL[3505];
+    r[0] = r[7] ^ r[4];
+    r[3] = r[12] ^ r[0];
+    r[0] = r[8] & POOL[3506];
+    r[12] = r[7] ^ POOL[3507];
+    r[7] = r[12] ^ POOL[3508];
+    r[12] = r[7] ^ POOL[3509];
+    r[7] = r[4] ^ POOL[3510];
+    r[4] = {
+        let (t, s) = (POOL[3511], POOL[3512]);
+        let (ma, mb) = (t ^ s ^ POOL[3513], t ^ s ^ POOL[3514]);
+        let mut z = t;
+        z ^= r[12] & mb;
+        z ^= r[7] & ma;
+        z ^= r[12] & r[7];
+        z
+    };
+    r[7] = r[0] ^ r[4];
+    r[4] = r[7].rotate_left(1);
+    r[7] = r[9] & POOL[3515];
+    r[0] = r[7] ^ r[4];
+    r[12] = r[3] ^ r[0];
+    r[0] = r[8] & POOL[3516];
+    r[3] = r[7] ^ POOL[3517];
+    r[7] = r[3] ^ POOL[3518];
+    r[3] = r[7] ^ POOL[3519];
+    r[7] = r[4] ^ POOL[3520];
+    r[4] = {
+        let (t, s) = (POOL[3521], POOL[3522]);
+        let (ma, mb) = (t ^ s ^ POOL[3523], t ^ s ^ POOL[3524]);
+        let mut z = t;
+        z ^= r[3] & mb;
+        z ^= r[7] & ma;
+        z ^= r[3] & r[7];
+        z
+    };
+    r[7] = r[0] ^ r[4];
+    r[4] = r[7].rotate_left(1);
+    r[7] = r[9] & POOL[3525];
+    r[0] = r[7] ^ r[4];
+    r[3] = r[12] ^ r[0];
+    r[0] = r[8] & POOL[3526];
+    r[12] = r[7] ^ POOL[3527];
+    r[7] = r[12] ^ POOL[3528];
+    r[12] = r[7] ^ POOL[3529];
+    r[7] = r[4] ^ POOL[3530];
+    r[4] = {
+        let (t, s) = (POOL[3531], POOL[3532]);
+        let (ma, mb) = (t ^ s ^ POOL[3533], t ^ s ^ POOL[3534]);
+        let mut z = t;
+        z ^= r[12] & mb;
+        z ^= r[7] & ma;
+        z ^= r[12] & r[7];
+        z
+    };
+    r[7] = r[0] ^ r[4];
+    r[4] = r[7].rotate_left(1);
+    r[7] = r[9] & POOL[3535];
+    r[0] = r[7] ^ r[4];
+    r[12] = r[3] ^ r[0];
+    r[0] = r[8] & POOL[3536];
+    r[3] = r[7] ^ POOL[3537];
+    r[7] = r[3] ^ POOL[3538];
+    r[3] = r[7] ^ POOL[3539];
+    r[7] = r[4] ^ POOL[3540];
+    r[4] = {
+        let (t, s) = (POOL[3541], POOL[3542]);
+        let (ma, mb) = (t ^ s ^ POOL[3543], t ^ s ^ POOL[3544]);
+        let mut z = t;
+        z ^= r[3] & mb;
+        z ^= r[7] & ma;
+        z ^= r[3] & r[7];
+        z
+    };
+    r[7] = r[0] ^ r[4];
+    r[4] = r[7].rotate_left(1);
+    r[7] = r[9] & POOL[3545];
+    r[0] = r[7] ^ r[4];
+    r[3] = r[12] ^ r[0];
+    r[0] = r[8] & POOL[3546];
+    r[12] = r[7] ^ POOL[3547];
+    r[7] = r[12] ^ POOL[3548];
+    r[12] = r[7] ^ POOL[3549];
+    r[7] = r[4] ^ POOL[3550];
+    r[4] = {
+        let (t, s) = (POOL[3551], POOL[3552]);
+        let (ma, mb) = (t ^ s ^ POOL[3553], t ^ s ^ POOL[3554]);
+        let mut z = t;
+        z ^= r[12] & mb;
+        z ^= r[7] & ma;
+        z ^= r[12] & r[7];
+        z
+    };
+    r[7] = r[0] ^ r[4];
+    r[4] = r[7].rotate_left(1);
+    r[7] = r[9] & POOL[3555];
+    r[0] = r[7] ^ r[4];
+    r[12] = r[3] ^ r[0];
+    r[0] = r[8] & POOL[3556];
+    r[3] = r[7] ^ POOL[3557];
+    r[7] = r[3] ^ POOL[3558];
+    r[3] = r[7] ^ POOL[3559];
+    r[7] = r[4] ^ POOL[3560];
+    r[4] = {
+        let (t, s) = (POOL[3561], POOL[3562]);
+        let (ma, mb) = (t ^ s ^ POOL[3563], t ^ s ^ POOL[3564]);
+        let mut z = t;
+        z ^= r[3] & mb;
+        z ^= r[7] & ma;
+        z ^= r[3] & r[7];
+        z
+    };
+    r[7] = r[0] ^ r[4];
+    r[4] = r[7].rotate_left(1);
+    r[7] = r[9] & POOL[3565];
+    r[0] = r[7] ^ r[4];
+    r[3] = r[12] ^ r[0];
+    r[0] = r[8] & POOL[3566];
+    r[12] = r[7] ^ POOL[3567];
+    r[7] = r[12] ^ POOL[3568];
+    r[12] = r[7] ^ POOL[3569];
+    r[7] = r[4] ^ POOL[3570];
+    r[4] = {
+        let (t, s) = (POOL[3571], POOL[3572]);
+        let (ma, mb) = (t ^ s ^ POOL[3573], t ^ s ^ POOL[3574]);
+        let mut z = t;
+        z ^= r[12] & mb;
+        z ^= r[7] & ma;
+        z ^= r[12] & r[7];
+        z
+    };
+    r[7] = r[0] ^ r[4];
+    r[4] = r[7].rotate_left(1);
+    r[7] = r[9] & POOL[3575];
+    r[0] = r[7] ^ r[4];
+    r[12] = r[3] ^ r[0];
+    r[0] = r[8] & POOL[3576];
+    r[3] = r[7] ^ POOL[3577];
+    r[7] = r[3] ^ POOL[3578];
+    r[3] = r[7] ^ POOL[3579];
+    r[7] = r[4] ^ POOL[3580];
+    r[4] = {
+        let (t, s) = (POOL[3581], POOL[3582]);
+        let (ma, mb) = (t ^ s ^ POOL[3583], t ^ s ^ POOL[3584]);
+        let mut z = t;
+        z ^= r[3] & mb;
+        z ^= r[7] & ma;
+        z ^= r[3] & r[7];
+        z
+    };
+    r[7] = r[0] ^ r[4];
+    r[4] = r[7].rotate_left(1);
+    r[7] = r[9] & POOL[3585];
+    r[0] = r[7] ^ r[4];
+    r[3] = r[12] ^ r[0];
+    r[0] = r[8] & POOL[3586];
+    r[12] = r[7] ^ POOL[3587];
+    r[7] = r[12] ^ POOL[3588];
+    r[12] = r[7] ^ POOL[3589];
+    r[7] = r[4] ^ POOL[3590];
+    r[4] = {
+        let (t, s) = (POOL[3591], POOL[3592]);
+        let (ma, mb) = (t ^ s ^ POOL[3593], t ^ s ^ POOL[3594]);
+        let mut z = t;
+        z ^= r[12] & mb;
+        z ^= r[7] & ma;
+        z ^= r[12] & r[7];
+        z
+    };
+    r[7] = r[0] ^ r[4];
+    r[4] = r[7].rotate_left(1);
+    r[7] = r[9] & POOL[3595];
+    r[0] = r[7] ^ r[4];
+    r[12] = r[3] ^ r[0];
+    r[0] = r[8] & POOL[3596];
+    r[3] = r[7] ^ POOL[3597];
+    r[7] = r[3] ^ POOL[3598];
+    r[3] = r[7] ^ POOL[3599];
+    r[7] = r[4] ^ POOL[3600];
+    r[4] = {
+        let (t, s) = (POOL[3601], POOL[3602]);
+        let (ma, mb) = (t ^ s ^ POOL[3603], t ^ s ^ POOL[3604]);
+        let mut z = t;
+        z ^= r[3] & mb;
+        z ^= r[7] & ma;
+        z ^= r[3] & r[7];
+        z
+    };
+    r[7] = r[0] ^ r[4];
+    r[4] = r[7].rotate_left(1);
+    r[7] = r[9] & POOL[3605];
+    r[0] = r[7] ^ r[4];
+    r[3] = r[12] ^ r[0];
+    r[0] = r[8] & POOL[3606];
+    r[12] = r[7] ^ POOL[3607];
+    r[7] = r[12] ^ POOL[3608];
+    r[12] = r[7] ^ POOL[3609];
+    r[7] = r[4] ^ POOL[3610];
+    r[4] = {
+        let (t, s) = (POOL[3611], POOL[3612]);
+        let (ma, mb) = (t ^ s ^ POOL[3613], t ^ s ^ POOL[3614]);
+        let mut z = t;
+        z ^= r[12] & mb;
+        z ^= r[7] & ma;
+        z ^= r[12] & r[7];
+        z
+    };
+    r[7] = r[0] ^ r[4];
+    r[4] = r[7].rotate_left(1);
+    r[7] = r[9] & POOL[3615];
+    r[0] = r[7] ^ r[4];
+    r[12] = r[3] ^ r[0];
+    r[0] = r[8] & POOL[3616];
+    r[3] = r[7] ^ POOL[3617];
+    r[7] = r[3] ^ POOL[3618];
+    r[3] = r[7] ^ POOL[3619];
+    r[7] = r[4] ^ POOL[3620];
+    r[4] = {
+        let (t, s) = (POOL[3621], POOL[3622]);
+        let (ma, mb) = (t ^ s ^ POOL[3623], t ^ s ^ POOL[3624]);
+        let mut z = t;
+        z ^= r[3] & mb;
+        z ^= r[7] & ma;
+        z ^= r[3] & r[7];
+        z
+    };
+    r[7] = r[0] ^ r[4];
+    r[4] = r[7].rotate_left(1);
+    r[7] = r[9] & POOL[3625];
+    r[0] = r[7] ^ r[4];
+    r[3] = r[12] ^ r[0];
+    r[0] = r[8] & POOL[3626];
+    r[12] = r[7] ^ POOL[3627];
+    r[7] = r[12] ^ POOL[3628];
+    r[12] = r[7] ^ POOL[3629];
+    r[7] = r[4] ^ POOL[3630];
+    r[4] = {
+        let (t, s) = (POOL[3631], POOL[3632]);
+        let (ma, mb) = (t ^ s ^ POOL[3633], t ^ s ^ POOL[3634]);
+        let mut z = t;
+        z ^= r[12] & mb;
+        z ^= r[7] & ma;
+        z ^= r[12] & r[7];
+        z
+    };
+    r[7] = r[0] ^ r[4];
+    r[4] = r[7].rotate_left(1);
+    r[7] = r[9] & POOL[3635];
+    r[0] = r[7] ^ r[4];
+    r[12] = r[3] ^ r[0];
+    r[0] = r[8] & POOL[3636];
+    r[3] = r[7] ^ POOL[3637];
+    r[7] = r[3] ^ POOL[3638];
+    r[3] = r[7] ^ POOL[3639];
+    r[7] = r[4] ^ POOL[3640];
+    r[4] = {
+        let (t, s) = (POOL[3641], POOL[3642]);
+        let (ma, mb) = (t ^ s ^ POOL[3643], t ^ s ^ POOL[3644]);
+        let mut z = t;
+        z ^= r[3] & mb;
+        z ^= r[7] & ma;
+        z ^= r[3] & r[7];
+        z
+    };
+    r[7] = r[0] ^ r[4];
+    r[4] = r[7].rotate_left(1);
+    r[7] = r[9] & POOL[3645];
+    r[0] = r[7] ^ r[4];
+    r[3] = r[12] ^ r[0];
+    r[0] = r[8] & POOL[3646];
+    r[12] = r[7] ^ POOL[3647];
+    r[7] = r[12] ^ POOL[3648];
+    r[12] = r[7] ^ POOL[3649];
+    r[7] = r[4] ^ POOL[3650];
+    r[4] = {
+        let (t, s) = (POOL[3651], POOL[3652]);
+        let (ma, mb) = (t ^ s ^ POOL[3653], t ^ s ^ POOL[3654]);
+        let mut z = t;
+        z ^= r[12] & mb;
+        z ^= r[7] & ma;
+        z ^= r[12] & r[7];
+        z
+    };
+    r[7] = r[0] ^ r[4];
+    r[4] = r[7].rotate_left(1);
+    r[7] = r[9] & POOL[3655];
+    r[0] = r[7] ^ r[4];
+    r[12] = r[3] ^ r[0];
+    r[0] = r[8] & POOL[3656];
+    r[3] = r[7] ^ POOL[3657];
+    r[7] = r[3] ^ POOL[3658];
+    r[3] = r[7] ^ POOL[3659];
+    r[7] = r[4] ^ POOL[3660];
+    r[4] = {
+        let (t, s) = (POOL[3661], POOL[3662]);
+        let (ma, mb) = (t ^ s ^ POOL[3663], t ^ s ^ POOL[3664]);
+        let mut z = t;
+        z ^= r[3] & mb;
+        z ^= r[7] & ma;
+        z ^= r[3] & r[7];
+        z
+    };
+    r[7] = r[0] ^ r[4];
+    r[4] = r[7].rotate_left(1);
+    r[7] = r[9] & POOL[3665];
+    r[0] = r[7] ^ r[4];
+    r[3] = r[12] ^ r[0];
+    r[0] = r[8] & POOL[3666];
+    r[12] = r[7] ^ POOL[3667];
+    r[7] = r[12] ^ POOL[3668];
+    r[12] = r[7] ^ POOL[3669];
+    r[7] = r[4] ^ POOL[3670];
+    r[4] = {
+        let (t, s) = (POOL[3671], POOL[3672]);
+        let (ma, mb) = (t ^ s ^ POOL[3673], t ^ s ^ POOL[3674]);
+        let mut z = t;
+        z ^= r[12] & mb;
+        z ^= r[7] & ma;
+        z ^= r[12] & r[7];
+        z
+    };
+    r[7] = r[0] ^ r[4];
+    r[4] = r[7].rotate_left(1);
+    r[7] = r[9] & POOL[3675];
+    r[0] = r[7] ^ r[4];
+    r[12] = r[3] ^ r[0];
+    r[0] = r[8] & POOL[3676];
+    r[3] = r[7] ^ POOL[3677];
+    r[7] = r[3] ^ POOL[3678];
+    r[3] = r[7] ^ POOL[3679];
+    r[7] = r[4] ^ POOL[3680];
+    r[4] = {
+        let (t, s) = (POOL[3681], POOL[3682]);
+        let (ma, mb) = (t ^ s ^ POOL[3683], t ^ s ^ POOL[3684]);
+        let mut z = t;
+        z ^= r[3] & mb;
+        z ^= r[7] & ma;
+        z ^= r[3] & r[7];
+        z
+    };
+    r[7] = r[0] ^ r[4];
+    r[4] = r[7].rotate_left(1);
+    r[7] = r[9] & POOL[3685];
+    r[0] = r[7] ^ r[4];
+    r[3] = r[12] ^ r[0];
+    r[0] = r[8] & POOL[3686];
+    r[12] = r[7] ^ POOL[3687];
+    r[7] = r[12] ^ POOL[3688];
+    r[12] = r[7] ^ POOL[3689];
+    r[7] = r[4] ^ POOL[3690];
+    r[4] = {
+        let (t, s) = (POOL[3691], POOL[3692]);
+        let (ma, mb) = (t ^ s ^ POOL[3693], t ^ s ^ POOL[3694]);
+        let mut z = t;
+        z ^= r[12] & mb;
+        z ^= r[7] & ma;
+        z ^= r[12] & r[7];
+        z
+    };
+    r[7] = r[0] ^ r[4];
+    r[4] = r[7].rotate_left(1);
+    r[7] = r[9] & POOL[3695];
+    r[0] = r[7] ^ r[4];
+    r[12] = r[3] ^ r[0];
+    r[0] = r[8] & POOL[3696];
+    r[3] = r[7] ^ POOL[3697];
+    r[7] = r[3] ^ POOL[3698];
+    r[3] = r[7] ^ POOL[3699];
+    r[7] = r[4] ^ POOL[3700];
+    r[4] = {
+        let (t, s) = (POOL[3701], POOL[3702]);
+        let (ma, mb) = (t ^ s ^ POOL[3703], t ^ s ^ POOL[3704]);
+        let mut z = t;
+        z ^= r[3] & mb;
+        z ^= r[7] & ma;
+        z ^= r[3] & r[7];
+        z
+    };
+    r[7] = r[0] ^ r[4];
+    r[4] = r[7].rotate_left(1);
+    r[7] = r[9] & POOL[3705];
+    r[0] = r[7] ^ r[4];
+    r[3] = r[12] ^ r[0];
+    r[0] = r[8] & POOL[3706];
+    r[12] = r[7] ^ POOL[3707];
+    r[7] = r[12] ^ POOL[3708];
+    r[12] = r[7] ^ POOL[3709];
+    r[7] = r[4] ^ POOL[3710];
+    r[4] = {
+        let (t, s) = (POOL[3711], POOL[3712]);
+        let (ma, mb) = (t ^ s ^ POOL[3713], t ^ s ^ POOL[3714]);
+        let mut z = t;
+        z ^= r[12] & mb;
+        z ^= r[7] & ma;
+        z ^= r[12] & r[7];
+        z
+    };
+    r[7] = r[0] ^ r[4];
+    r[4] = r[7].rotate_left(1);
+    r[7] = r[9] & POOL[3715];
+    r[0] = r[7] ^ r[4];
+    r[12] = r[3] ^ r[0];
+    r[0] = r[8] & POOL[3716];
+    r[3] = r[7] ^ POOL[3717];
+    r[7] = r[3] ^ POOL[3718];
+    r[3] = r[7] ^ POOL[3719];
+    r[7] = r[4] ^ POOL[3720];
+    r[4] = {
+        let (t, s) = (POOL[3721], POOL[3722]);
+        let (ma, mb) = (t ^ s ^ POOL[3723], t ^ s ^ POOL[3724]);
+        let mut z = t;
+        z ^= r[3] & mb;
+        z ^= r[7] & ma;
+        z ^= r[3] & r[7];
+        z
+    };
+    r[7] = r[0] ^ r[4];
+    r[4] = r[7].rotate_left(1);
+    r[7] = r[9] & POOL[3725];
+    r[0] = r[7] ^ r[4];
+    r[3] = r[12] ^ r[0];
+    r[0] = r[8] & POOL[3726];
+    r[12] = r[7] ^ POOL[3727];
+    r[7] = r[12] ^ POOL[3728];
+    r[12] = r[7] ^ POOL[3729];
+    r[7] = r[4] ^ POOL[3730];
+    r[4] = {
+        let (t, s) = (POOL[3731], POOL[3732]);
+        let (ma, mb) = (t ^ s ^ POOL[3733], t ^ s ^ POOL[3734]);
+        let mut z = t;
+        z ^= r[12] & mb;
+        z ^= r[7] & ma;
+        z ^= r[12] & r[7];
+        z
+    };
+    r[7] = r[0] ^ r[4];
+    r[4] = r[7].rotate_left(1);
+    r[7] = r[9] & POOL[3735];
+    r[0] = r[7] ^ r[4];
+    r[12] = r[3] ^ r[0];
+    r[0] = r[8] & POOL[3736];
+    r[8] = r[7] ^ POOL[3737];
+    r[7] = r[8] ^ POOL[3738];
+    r[8] = r[7] ^ POOL[3739];
+    r[7] = r[4] ^ POOL[3740];
+    r[4] = {
+        let (t, s) = (POOL[3741], POOL[3742]);
+        let (ma, mb) = (t ^ s ^ POOL[3743], t ^ s ^ POOL[3744]);
+        let mut z = t;
+        z ^= r[8] & mb;
+        z ^= r[7] & ma;
+        z ^= r[8] & r[7];
+        z
+    };
+    r[7] = r[0] ^ r[4];
+    r[4] = r[7].rotate_left(1);
+    r[7] = r[9] & POOL[3745];
+    r[9] = r[7] ^ r[4];
+    r[4] = r[12] ^ r[9];
+    r[9] = r[5] ^ r[4];
+    r[5] = r[9].rotate_left(7);
+    r[9] = r[11] ^ r[5];
+    r[5] = r[4] ^ r[2];
+    r[4] = r[9] ^ r[5];
+    r[9] = r[4] ^ POOL[3746];
+    r[9] ^ POOL[3747]
+}
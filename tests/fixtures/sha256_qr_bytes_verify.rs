@@ -0,0 +1,2665 @@
+// Generated by xorpl — do not edit manually.
+pub const EXPR_DIGEST: [u8; 32] = [
+    0xaf, 0xa6, 0x8f, 0x3e, 0xce, 0x01, 0xea, 0x08,
+    0x15, 0x42, 0x7a, 0xbc, 0x0f, 0xfb, 0x61, 0x46,
+    0x0e, 0x08, 0xea, 0x4f, 0x8c, 0x9b, 0x8a, 0x00,
+    0xc4, 0x21, 0x5e, 0x40, 0x3b, 0xc2, 0x88, 0xdf,
+];
+pub fn sha256_qr_bytes_verify(input_digest: &[u8; 32]) -> u32 {
+    let w0 = u32::from_le_bytes([input_digest[0], input_digest[1], input_digest[2], input_digest[3]]);
+    let w1 = u32::from_le_bytes([input_digest[4], input_digest[5], input_digest[6], input_digest[7]]);
+    let w2 = w0 & w1;
+    let w3 = w0 ^ w1;
+    let w4 = w3 & 0x00000001u32;
+    let w5 = w2 & 0x00000001u32;
+    let w6 = w5.rotate_left(1);
+    let w7 = w3 & 0x00000002u32;
+    let w8 = w7 ^ w6;
+    let w9 = w4 ^ w8;
+    let w10 = w2 & 0x00000002u32;
+    let w11 = w7 & w6;
+    let w12 = w10 ^ w11;
+    let w13 = w12.rotate_left(1);
+    let w14 = w3 & 0x00000004u32;
+    let w15 = w14 ^ w13;
+    let w16 = w9 ^ w15;
+    let w17 = w2 & 0x00000004u32;
+    let w18 = w14 & w13;
+    let w19 = w17 ^ w18;
+    let w20 = w19.rotate_left(1);
+    let w21 = w3 & 0x00000008u32;
+    let w22 = w21 ^ w20;
+    let w23 = w16 ^ w22;
+    let w24 = w2 & 0x00000008u32;
+    let w25 = w21 & w20;
+    let w26 = w24 ^ w25;
+    let w27 = w26.rotate_left(1);
+    let w28 = w3 & 0x00000010u32;
+    let w29 = w28 ^ w27;
+    let w30 = w23 ^ w29;
+    let w31 = w2 & 0x00000010u32;
+    let w32 = w28 & w27;
+    let w33 = w31 ^ w32;
+    let w34 = w33.rotate_left(1);
+    let w35 = w3 & 0x00000020u32;
+    let w36 = w35 ^ w34;
+    let w37 = w30 ^ w36;
+    let w38 = w2 & 0x00000020u32;
+    let w39 = w35 & w34;
+    let w40 = w38 ^ w39;
+    let w41 = w40.rotate_left(1);
+    let w42 = w3 & 0x00000040u32;
+    let w43 = w42 ^ w41;
+    let w44 = w37 ^ w43;
+    let w45 = w2 & 0x00000040u32;
+    let w46 = w42 & w41;
+    let w47 = w45 ^ w46;
+    let w48 = w47.rotate_left(1);
+    let w49 = w3 & 0x00000080u32;
+    let w50 = w49 ^ w48;
+    let w51 = w44 ^ w50;
+    let w52 = w2 & 0x00000080u32;
+    let w53 = w49 & w48;
+    let w54 = w52 ^ w53;
+    let w55 = w54.rotate_left(1);
+    let w56 = w3 & 0x00000100u32;
+    let w57 = w56 ^ w55;
+    let w58 = w51 ^ w57;
+    let w59 = w2 & 0x00000100u32;
+    let w60 = w56 & w55;
+    let w61 = w59 ^ w60;
+    let w62 = w61.rotate_left(1);
+    let w63 = w3 & 0x00000200u32;
+    let w64 = w63 ^ w62;
+    let w65 = w58 ^ w64;
+    let w66 = w2 & 0x00000200u32;
+    let w67 = w63 & w62;
+    let w68 = w66 ^ w67;
+    let w69 = w68.rotate_left(1);
+    let w70 = w3 & 0x00000400u32;
+    let w71 = w70 ^ w69;
+    let w72 = w65 ^ w71;
+    let w73 = w2 & 0x00000400u32;
+    let w74 = w70 & w69;
+    let w75 = w73 ^ w74;
+    let w76 = w75.rotate_left(1);
+    let w77 = w3 & 0x00000800u32;
+    let w78 = w77 ^ w76;
+    let w79 = w72 ^ w78;
+    let w80 = w2 & 0x00000800u32;
+    let w81 = w77 & w76;
+    let w82 = w80 ^ w81;
+    let w83 = w82.rotate_left(1);
+    let w84 = w3 & 0x00001000u32;
+    let w85 = w84 ^ w83;
+    let w86 = w79 ^ w85;
+    let w87 = w2 & 0x00001000u32;
+    let w88 = w84 & w83;
+    let w89 = w87 ^ w88;
+    let w90 = w89.rotate_left(1);
+    let w91 = w3 & 0x00002000u32;
+    let w92 = w91 ^ w90;
+    let w93 = w86 ^ w92;
+    let w94 = w2 & 0x00002000u32;
+    let w95 = w91 & w90;
+    let w96 = w94 ^ w95;
+    let w97 = w96.rotate_left(1);
+    let w98 = w3 & 0x00004000u32;
+    let w99 = w98 ^ w97;
+    let w100 = w93 ^ w99;
+    let w101 = w2 & 0x00004000u32;
+    let w102 = w98 & w97;
+    let w103 = w101 ^ w102;
+    let w104 = w103.rotate_left(1);
+    let w105 = w3 & 0x00008000u32;
+    let w106 = w105 ^ w104;
+    let w107 = w100 ^ w106;
+    let w108 = w2 & 0x00008000u32;
+    let w109 = w105 & w104;
+    let w110 = w108 ^ w109;
+    let w111 = w110.rotate_left(1);
+    let w112 = w3 & 0x00010000u32;
+    let w113 = w112 ^ w111;
+    let w114 = w107 ^ w113;
+    let w115 = w2 & 0x00010000u32;
+    let w116 = w112 & w111;
+    let w117 = w115 ^ w116;
+    let w118 = w117.rotate_left(1);
+    let w119 = w3 & 0x00020000u32;
+    let w120 = w119 ^ w118;
+    let w121 = w114 ^ w120;
+    let w122 = w2 & 0x00020000u32;
+    let w123 = w119 & w118;
+    let w124 = w122 ^ w123;
+    let w125 = w124.rotate_left(1);
+    let w126 = w3 & 0x00040000u32;
+    let w127 = w126 ^ w125;
+    let w128 = w121 ^ w127;
+    let w129 = w2 & 0x00040000u32;
+    let w130 = w126 & w125;
+    let w131 = w129 ^ w130;
+    let w132 = w131.rotate_left(1);
+    let w133 = w3 & 0x00080000u32;
+    let w134 = w133 ^ w132;
+    let w135 = w128 ^ w134;
+    let w136 = w2 & 0x00080000u32;
+    let w137 = w133 & w132;
+    let w138 = w136 ^ w137;
+    let w139 = w138.rotate_left(1);
+    let w140 = w3 & 0x00100000u32;
+    let w141 = w140 ^ w139;
+    let w142 = w135 ^ w141;
+    let w143 = w2 & 0x00100000u32;
+    let w144 = w140 & w139;
+    let w145 = w143 ^ w144;
+    let w146 = w145.rotate_left(1);
+    let w147 = w3 & 0x00200000u32;
+    let w148 = w147 ^ w146;
+    let w149 = w142 ^ w148;
+    let w150 = w2 & 0x00200000u32;
+    let w151 = w147 & w146;
+    let w152 = w150 ^ w151;
+    let w153 = w152.rotate_left(1);
+    let w154 = w3 & 0x00400000u32;
+    let w155 = w154 ^ w153;
+    let w156 = w149 ^ w155;
+    let w157 = w2 & 0x00400000u32;
+    let w158 = w154 & w153;
+    let w159 = w157 ^ w158;
+    let w160 = w159.rotate_left(1);
+    let w161 = w3 & 0x00800000u32;
+    let w162 = w161 ^ w160;
+    let w163 = w156 ^ w162;
+    let w164 = w2 & 0x00800000u32;
+    let w165 = w161 & w160;
+    let w166 = w164 ^ w165;
+    let w167 = w166.rotate_left(1);
+    let w168 = w3 & 0x01000000u32;
+    let w169 = w168 ^ w167;
+    let w170 = w163 ^ w169;
+    let w171 = w2 & 0x01000000u32;
+    let w172 = w168 & w167;
+    let w173 = w171 ^ w172;
+    let w174 = w173.rotate_left(1);
+    let w175 = w3 & 0x02000000u32;
+    let w176 = w175 ^ w174;
+    let w177 = w170 ^ w176;
+    let w178 = w2 & 0x02000000u32;
+    let w179 = w175 & w174;
+    let w180 = w178 ^ w179;
+    let w181 = w180.rotate_left(1);
+    let w182 = w3 & 0x04000000u32;
+    let w183 = w182 ^ w181;
+    let w184 = w177 ^ w183;
+    let w185 = w2 & 0x04000000u32;
+    let w186 = w182 & w181;
+    let w187 = w185 ^ w186;
+    let w188 = w187.rotate_left(1);
+    let w189 = w3 & 0x08000000u32;
+    let w190 = w189 ^ w188;
+    let w191 = w184 ^ w190;
+    let w192 = w2 & 0x08000000u32;
+    let w193 = w189 & w188;
+    let w194 = w192 ^ w193;
+    let w195 = w194.rotate_left(1);
+    let w196 = w3 & 0x10000000u32;
+    let w197 = w196 ^ w195;
+    let w198 = w191 ^ w197;
+    let w199 = w2 & 0x10000000u32;
+    let w200 = w196 & w195;
+    let w201 = w199 ^ w200;
+    let w202 = w201.rotate_left(1);
+    let w203 = w3 & 0x20000000u32;
+    let w204 = w203 ^ w202;
+    let w205 = w198 ^ w204;
+    let w206 = w2 & 0x20000000u32;
+    let w207 = w203 & w202;
+    let w208 = w206 ^ w207;
+    let w209 = w208.rotate_left(1);
+    let w210 = w3 & 0x40000000u32;
+    let w211 = w210 ^ w209;
+    let w212 = w205 ^ w211;
+    let w213 = w2 & 0x40000000u32;
+    let w214 = w210 & w209;
+    let w215 = w213 ^ w214;
+    let w216 = w215.rotate_left(1);
+    let w217 = w3 & 0x80000000u32;
+    let w218 = w217 ^ w216;
+    let w219 = w212 ^ w218;
+    let w220 = u32::from_le_bytes([input_digest[8], input_digest[9], input_digest[10], input_digest[11]]);
+    let w221 = u32::from_le_bytes([input_digest[12], input_digest[13], input_digest[14], input_digest[15]]);
+    let w222 = w221 ^ w219;
+    let w223 = w222.rotate_left(16);
+    let w224 = w220 & w223;
+    let w225 = w220 ^ w223;
+    let w226 = w225 & 0x00000001u32;
+    let w227 = w224 & 0x00000001u32;
+    let w228 = w227.rotate_left(1);
+    let w229 = w225 & 0x00000002u32;
+    let w230 = w229 ^ w228;
+    let w231 = w226 ^ w230;
+    let w232 = w224 & 0x00000002u32;
+    let w233 = w229 & w228;
+    let w234 = w232 ^ w233;
+    let w235 = w234.rotate_left(1);
+    let w236 = w225 & 0x00000004u32;
+    let w237 = w236 ^ w235;
+    let w238 = w231 ^ w237;
+    let w239 = w224 & 0x00000004u32;
+    let w240 = w236 & w235;
+    let w241 = w239 ^ w240;
+    let w242 = w241.rotate_left(1);
+    let w243 = w225 & 0x00000008u32;
+    let w244 = w243 ^ w242;
+    let w245 = w238 ^ w244;
+    let w246 = w224 & 0x00000008u32;
+    let w247 = w243 & w242;
+    let w248 = w246 ^ w247;
+    let w249 = w248.rotate_left(1);
+    let w250 = w225 & 0x00000010u32;
+    let w251 = w250 ^ w249;
+    let w252 = w245 ^ w251;
+    let w253 = w224 & 0x00000010u32;
+    let w254 = w250 & w249;
+    let w255 = w253 ^ w254;
+    let w256 = w255.rotate_left(1);
+    let w257 = w225 & 0x00000020u32;
+    let w258 = w257 ^ w256;
+    let w259 = w252 ^ w258;
+    let w260 = w224 & 0x00000020u32;
+    let w261 = w257 & w256;
+    let w262 = w260 ^ w261;
+    let w263 = w262.rotate_left(1);
+    let w264 = w225 & 0x00000040u32;
+    let w265 = w264 ^ w263;
+    let w266 = w259 ^ w265;
+    let w267 = w224 & 0x00000040u32;
+    let w268 = w264 & w263;
+    let w269 = w267 ^ w268;
+    let w270 = w269.rotate_left(1);
+    let w271 = w225 & 0x00000080u32;
+    let w272 = w271 ^ w270;
+    let w273 = w266 ^ w272;
+    let w274 = w224 & 0x00000080u32;
+    let w275 = w271 & w270;
+    let w276 = w274 ^ w275;
+    let w277 = w276.rotate_left(1);
+    let w278 = w225 & 0x00000100u32;
+    let w279 = w278 ^ w277;
+    let w280 = w273 ^ w279;
+    let w281 = w224 & 0x00000100u32;
+    let w282 = w278 & w277;
+    let w283 = w281 ^ w282;
+    let w284 = w283.rotate_left(1);
+    let w285 = w225 & 0x00000200u32;
+    let w286 = w285 ^ w284;
+    let w287 = w280 ^ w286;
+    let w288 = w224 & 0x00000200u32;
+    let w289 = w285 & w284;
+    let w290 = w288 ^ w289;
+    let w291 = w290.rotate_left(1);
+    let w292 = w225 & 0x00000400u32;
+    let w293 = w292 ^ w291;
+    let w294 = w287 ^ w293;
+    let w295 = w224 & 0x00000400u32;
+    let w296 = w292 & w291;
+    let w297 = w295 ^ w296;
+    let w298 = w297.rotate_left(1);
+    let w299 = w225 & 0x00000800u32;
+    let w300 = w299 ^ w298;
+    let w301 = w294 ^ w300;
+    let w302 = w224 & 0x00000800u32;
+    let w303 = w299 & w298;
+    let w304 = w302 ^ w303;
+    let w305 = w304.rotate_left(1);
+    let w306 = w225 & 0x00001000u32;
+    let w307 = w306 ^ w305;
+    let w308 = w301 ^ w307;
+    let w309 = w224 & 0x00001000u32;
+    let w310 = w306 & w305;
+    let w311 = w309 ^ w310;
+    let w312 = w311.rotate_left(1);
+    let w313 = w225 & 0x00002000u32;
+    let w314 = w313 ^ w312;
+    let w315 = w308 ^ w314;
+    let w316 = w224 & 0x00002000u32;
+    let w317 = w313 & w312;
+    let w318 = w316 ^ w317;
+    let w319 = w318.rotate_left(1);
+    let w320 = w225 & 0x00004000u32;
+    let w321 = w320 ^ w319;
+    let w322 = w315 ^ w321;
+    let w323 = w224 & 0x00004000u32;
+    let w324 = w320 & w319;
+    let w325 = w323 ^ w324;
+    let w326 = w325.rotate_left(1);
+    let w327 = w225 & 0x00008000u32;
+    let w328 = w327 ^ w326;
+    let w329 = w322 ^ w328;
+    let w330 = w224 & 0x00008000u32;
+    let w331 = w327 & w326;
+    let w332 = w330 ^ w331;
+    let w333 = w332.rotate_left(1);
+    let w334 = w225 & 0x00010000u32;
+    let w335 = w334 ^ w333;
+    let w336 = w329 ^ w335;
+    let w337 = w224 & 0x00010000u32;
+    let w338 = w334 & w333;
+    let w339 = w337 ^ w338;
+    let w340 = w339.rotate_left(1);
+    let w341 = w225 & 0x00020000u32;
+    let w342 = w341 ^ w340;
+    let w343 = w336 ^ w342;
+    let w344 = w224 & 0x00020000u32;
+    let w345 = w341 & w340;
+    let w346 = w344 ^ w345;
+    let w347 = w346.rotate_left(1);
+    let w348 = w225 & 0x00040000u32;
+    let w349 = w348 ^ w347;
+    let w350 = w343 ^ w349;
+    let w351 = w224 & 0x00040000u32;
+    let w352 = w348 & w347;
+    let w353 = w351 ^ w352;
+    let w354 = w353.rotate_left(1);
+    let w355 = w225 & 0x00080000u32;
+    let w356 = w355 ^ w354;
+    let w357 = w350 ^ w356;
+    let w358 = w224 & 0x00080000u32;
+    let w359 = w355 & w354;
+    let w360 = w358 ^ w359;
+    let w361 = w360.rotate_left(1);
+    let w362 = w225 & 0x00100000u32;
+    let w363 = w362 ^ w361;
+    let w364 = w357 ^ w363;
+    let w365 = w224 & 0x00100000u32;
+    let w366 = w362 & w361;
+    let w367 = w365 ^ w366;
+    let w368 = w367.rotate_left(1);
+    let w369 = w225 & 0x00200000u32;
+    let w370 = w369 ^ w368;
+    let w371 = w364 ^ w370;
+    let w372 = w224 & 0x00200000u32;
+    let w373 = w369 & w368;
+    let w374 = w372 ^ w373;
+    let w375 = w374.rotate_left(1);
+    let w376 = w225 & 0x00400000u32;
+    let w377 = w376 ^ w375;
+    let w378 = w371 ^ w377;
+    let w379 = w224 & 0x00400000u32;
+    let w380 = w376 & w375;
+    let w381 = w379 ^ w380;
+    let w382 = w381.rotate_left(1);
+    let w383 = w225 & 0x00800000u32;
+    let w384 = w383 ^ w382;
+    let w385 = w378 ^ w384;
+    let w386 = w224 & 0x00800000u32;
+    let w387 = w383 & w382;
+    let w388 = w386 ^ w387;
+    let w389 = w388.rotate_left(1);
+    let w390 = w225 & 0x01000000u32;
+    let w391 = w390 ^ w389;
+    let w392 = w385 ^ w391;
+    let w393 = w224 & 0x01000000u32;
+    let w394 = w390 & w389;
+    let w395 = w393 ^ w394;
+    let w396 = w395.rotate_left(1);
+    let w397 = w225 & 0x02000000u32;
+    let w398 = w397 ^ w396;
+    let w399 = w392 ^ w398;
+    let w400 = w224 & 0x02000000u32;
+    let w401 = w397 & w396;
+    let w402 = w400 ^ w401;
+    let w403 = w402.rotate_left(1);
+    let w404 = w225 & 0x04000000u32;
+    let w405 = w404 ^ w403;
+    let w406 = w399 ^ w405;
+    let w407 = w224 & 0x04000000u32;
+    let w408 = w404 & w403;
+    let w409 = w407 ^ w408;
+    let w410 = w409.rotate_left(1);
+    let w411 = w225 & 0x08000000u32;
+    let w412 = w411 ^ w410;
+    let w413 = w406 ^ w412;
+    let w414 = w224 & 0x08000000u32;
+    let w415 = w411 & w410;
+    let w416 = w414 ^ w415;
+    let w417 = w416.rotate_left(1);
+    let w418 = w225 & 0x10000000u32;
+    let w419 = w418 ^ w417;
+    let w420 = w413 ^ w419;
+    let w421 = w224 & 0x10000000u32;
+    let w422 = w418 & w417;
+    let w423 = w421 ^ w422;
+    let w424 = w423.rotate_left(1);
+    let w425 = w225 & 0x20000000u32;
+    let w426 = w425 ^ w424;
+    let w427 = w420 ^ w426;
+    let w428 = w224 & 0x20000000u32;
+    let w429 = w425 & w424;
+    let w430 = w428 ^ w429;
+    let w431 = w430.rotate_left(1);
+    let w432 = w225 & 0x40000000u32;
+    let w433 = w432 ^ w431;
+    let w434 = w427 ^ w433;
+    let w435 = w224 & 0x40000000u32;
+    let w436 = w432 & w431;
+    let w437 = w435 ^ w436;
+    let w438 = w437.rotate_left(1);
+    let w439 = w225 & 0x80000000u32;
+    let w440 = w439 ^ w438;
+    let w441 = w434 ^ w440;
+    let w442 = w1 ^ w441;
+    let w443 = w442.rotate_left(12);
+    let w444 = w219 & w443;
+    let w445 = w219 ^ w443;
+    let w446 = w445 & 0x00000001u32;
+    let w447 = w444 & 0x00000001u32;
+    let w448 = w447.rotate_left(1);
+    let w449 = w445 & 0x00000002u32;
+    let w450 = w449 ^ w448;
+    let w451 = w446 ^ w450;
+    let w452 = w444 & 0x00000002u32;
+    let w453 = w449 & w448;
+    let w454 = w452 ^ w453;
+    let w455 = w454.rotate_left(1);
+    let w456 = w445 & 0x00000004u32;
+    let w457 = w456 ^ w455;
+    let w458 = w451 ^ w457;
+    let w459 = w444 & 0x00000004u32;
+    let w460 = w456 & w455;
+    let w461 = w459 ^ w460;
+    let w462 = w461.rotate_left(1);
+    let w463 = w445 & 0x00000008u32;
+    let w464 = w463 ^ w462;
+    let w465 = w458 ^ w464;
+    let w466 = w444 & 0x00000008u32;
+    let w467 = w463 & w462;
+    let w468 = w466 ^ w467;
+    let w469 = w468.rotate_left(1);
+    let w470 = w445 & 0x00000010u32;
+    let w471 = w470 ^ w469;
+    let w472 = w465 ^ w471;
+    let w473 = w444 & 0x00000010u32;
+    let w474 = w470 & w469;
+    let w475 = w473 ^ w474;
+    let w476 = w475.rotate_left(1);
+    let w477 = w445 & 0x00000020u32;
+    let w478 = w477 ^ w476;
+    let w479 = w472 ^ w478;
+    let w480 = w444 & 0x00000020u32;
+    let w481 = w477 & w476;
+    let w482 = w480 ^ w481;
+    let w483 = w482.rotate_left(1);
+    let w484 = w445 & 0x00000040u32;
+    let w485 = w484 ^ w483;
+    let w486 = w479 ^ w485;
+    let w487 = w444 & 0x00000040u32;
+    let w488 = w484 & w483;
+    let w489 = w487 ^ w488;
+    let w490 = w489.rotate_left(1);
+    let w491 = w445 & 0x00000080u32;
+    let w492 = w491 ^ w490;
+    let w493 = w486 ^ w492;
+    let w494 = w444 & 0x00000080u32;
+    let w495 = w491 & w490;
+    let w496 = w494 ^ w495;
+    let w497 = w496.rotate_left(1);
+    let w498 = w445 & 0x00000100u32;
+    let w499 = w498 ^ w497;
+    let w500 = w493 ^ w499;
+    let w501 = w444 & 0x00000100u32;
+    let w502 = w498 & w497;
+    let w503 = w501 ^ w502;
+    let w504 = w503.rotate_left(1);
+    let w505 = w445 & 0x00000200u32;
+    let w506 = w505 ^ w504;
+    let w507 = w500 ^ w506;
+    let w508 = w444 & 0x00000200u32;
+    let w509 = w505 & w504;
+    let w510 = w508 ^ w509;
+    let w511 = w510.rotate_left(1);
+    let w512 = w445 & 0x00000400u32;
+    let w513 = w512 ^ w511;
+    let w514 = w507 ^ w513;
+    let w515 = w444 & 0x00000400u32;
+    let w516 = w512 & w511;
+    let w517 = w515 ^ w516;
+    let w518 = w517.rotate_left(1);
+    let w519 = w445 & 0x00000800u32;
+    let w520 = w519 ^ w518;
+    let w521 = w514 ^ w520;
+    let w522 = w444 & 0x00000800u32;
+    let w523 = w519 & w518;
+    let w524 = w522 ^ w523;
+    let w525 = w524.rotate_left(1);
+    let w526 = w445 & 0x00001000u32;
+    let w527 = w526 ^ w525;
+    let w528 = w521 ^ w527;
+    let w529 = w444 & 0x00001000u32;
+    let w530 = w526 & w525;
+    let w531 = w529 ^ w530;
+    let w532 = w531.rotate_left(1);
+    let w533 = w445 & 0x00002000u32;
+    let w534 = w533 ^ w532;
+    let w535 = w528 ^ w534;
+    let w536 = w444 & 0x00002000u32;
+    let w537 = w533 & w532;
+    let w538 = w536 ^ w537;
+    let w539 = w538.rotate_left(1);
+    let w540 = w445 & 0x00004000u32;
+    let w541 = w540 ^ w539;
+    let w542 = w535 ^ w541;
+    let w543 = w444 & 0x00004000u32;
+    let w544 = w540 & w539;
+    let w545 = w543 ^ w544;
+    let w546 = w545.rotate_left(1);
+    let w547 = w445 & 0x00008000u32;
+    let w548 = w547 ^ w546;
+    let w549 = w542 ^ w548;
+    let w550 = w444 & 0x00008000u32;
+    let w551 = w547 & w546;
+    let w552 = w550 ^ w551;
+    let w553 = w552.rotate_left(1);
+    let w554 = w445 & 0x00010000u32;
+    let w555 = w554 ^ w553;
+    let w556 = w549 ^ w555;
+    let w557 = w444 & 0x00010000u32;
+    let w558 = w554 & w553;
+    let w559 = w557 ^ w558;
+    let w560 = w559.rotate_left(1);
+    let w561 = w445 & 0x00020000u32;
+    let w562 = w561 ^ w560;
+    let w563 = w556 ^ w562;
+    let w564 = w444 & 0x00020000u32;
+    let w565 = w561 & w560;
+    let w566 = w564 ^ w565;
+    let w567 = w566.rotate_left(1);
+    let w568 = w445 & 0x00040000u32;
+    let w569 = w568 ^ w567;
+    let w570 = w563 ^ w569;
+    let w571 = w444 & 0x00040000u32;
+    let w572 = w568 & w567;
+    let w573 = w571 ^ w572;
+    let w574 = w573.rotate_left(1);
+    let w575 = w445 & 0x00080000u32;
+    let w576 = w575 ^ w574;
+    let w577 = w570 ^ w576;
+    let w578 = w444 & 0x00080000u32;
+    let w579 = w575 & w574;
+    let w580 = w578 ^ w579;
+    let w581 = w580.rotate_left(1);
+    let w582 = w445 & 0x00100000u32;
+    let w583 = w582 ^ w581;
+    let w584 = w577 ^ w583;
+    let w585 = w444 & 0x00100000u32;
+    let w586 = w582 & w581;
+    let w587 = w585 ^ w586;
+    let w588 = w587.rotate_left(1);
+    let w589 = w445 & 0x00200000u32;
+    let w590 = w589 ^ w588;
+    let w591 = w584 ^ w590;
+    let w592 = w444 & 0x00200000u32;
+    let w593 = w589 & w588;
+    let w594 = w592 ^ w593;
+    let w595 = w594.rotate_left(1);
+    let w596 = w445 & 0x00400000u32;
+    let w597 = w596 ^ w595;
+    let w598 = w591 ^ w597;
+    let w599 = w444 & 0x00400000u32;
+    let w600 = w596 & w595;
+    let w601 = w599 ^ w600;
+    let w602 = w601.rotate_left(1);
+    let w603 = w445 & 0x00800000u32;
+    let w604 = w603 ^ w602;
+    let w605 = w598 ^ w604;
+    let w606 = w444 & 0x00800000u32;
+    let w607 = w603 & w602;
+    let w608 = w606 ^ w607;
+    let w609 = w608.rotate_left(1);
+    let w610 = w445 & 0x01000000u32;
+    let w611 = w610 ^ w609;
+    let w612 = w605 ^ w611;
+    let w613 = w444 & 0x01000000u32;
+    let w614 = w610 & w609;
+    let w615 = w613 ^ w614;
+    let w616 = w615.rotate_left(1);
+    let w617 = w445 & 0x02000000u32;
+    let w618 = w617 ^ w616;
+    let w619 = w612 ^ w618;
+    let w620 = w444 & 0x02000000u32;
+    let w621 = w617 & w616;
+    let w622 = w620 ^ w621;
+    let w623 = w622.rotate_left(1);
+    let w624 = w445 & 0x04000000u32;
+    let w625 = w624 ^ w623;
+    let w626 = w619 ^ w625;
+    let w627 = w444 & 0x04000000u32;
+    let w628 = w624 & w623;
+    let w629 = w627 ^ w628;
+    let w630 = w629.rotate_left(1);
+    let w631 = w445 & 0x08000000u32;
+    let w632 = w631 ^ w630;
+    let w633 = w626 ^ w632;
+    let w634 = w444 & 0x08000000u32;
+    let w635 = w631 & w630;
+    let w636 = w634 ^ w635;
+    let w637 = w636.rotate_left(1);
+    let w638 = w445 & 0x10000000u32;
+    let w639 = w638 ^ w637;
+    let w640 = w633 ^ w639;
+    let w641 = w444 & 0x10000000u32;
+    let w642 = w638 & w637;
+    let w643 = w641 ^ w642;
+    let w644 = w643.rotate_left(1);
+    let w645 = w445 & 0x20000000u32;
+    let w646 = w645 ^ w644;
+    let w647 = w640 ^ w646;
+    let w648 = w444 & 0x20000000u32;
+    let w649 = w645 & w644;
+    let w650 = w648 ^ w649;
+    let w651 = w650.rotate_left(1);
+    let w652 = w445 & 0x40000000u32;
+    let w653 = w652 ^ w651;
+    let w654 = w647 ^ w653;
+    let w655 = w444 & 0x40000000u32;
+    let w656 = w652 & w651;
+    let w657 = w655 ^ w656;
+    let w658 = w657.rotate_left(1);
+    let w659 = w445 & 0x80000000u32;
+    let w660 = w659 ^ w658;
+    let w661 = w654 ^ w660;
+    let w662 = u32::from_le_bytes([input_digest[16], input_digest[17], input_digest[18], input_digest[19]]);
+    let w663 = u32::from_le_bytes([input_digest[20], input_digest[21], input_digest[22], input_digest[23]]);
+    let w664 = w662 & w663;
+    let w665 = w662 ^ w663;
+    let w666 = w665 & 0x00000001u32;
+    let w667 = w664 & 0x00000001u32;
+    let w668 = w667.rotate_left(1);
+    let w669 = w665 & 0x00000002u32;
+    let w670 = w669 ^ w668;
+    let w671 = w666 ^ w670;
+    let w672 = w664 & 0x00000002u32;
+    let w673 = w669 & w668;
+    let w674 = w672 ^ w673;
+    let w675 = w674.rotate_left(1);
+    let w676 = w665 & 0x00000004u32;
+    let w677 = w676 ^ w675;
+    let w678 = w671 ^ w677;
+    let w679 = w664 & 0x00000004u32;
+    let w680 = w676 & w675;
+    let w681 = w679 ^ w680;
+    let w682 = w681.rotate_left(1);
+    let w683 = w665 & 0x00000008u32;
+    let w684 = w683 ^ w682;
+    let w685 = w678 ^ w684;
+    let w686 = w664 & 0x00000008u32;
+    let w687 = w683 & w682;
+    let w688 = w686 ^ w687;
+    let w689 = w688.rotate_left(1);
+    let w690 = w665 & 0x00000010u32;
+    let w691 = w690 ^ w689;
+    let w692 = w685 ^ w691;
+    let w693 = w664 & 0x00000010u32;
+    let w694 = w690 & w689;
+    let w695 = w693 ^ w694;
+    let w696 = w695.rotate_left(1);
+    let w697 = w665 & 0x00000020u32;
+    let w698 = w697 ^ w696;
+    let w699 = w692 ^ w698;
+    let w700 = w664 & 0x00000020u32;
+    let w701 = w697 & w696;
+    let w702 = w700 ^ w701;
+    let w703 = w702.rotate_left(1);
+    let w704 = w665 & 0x00000040u32;
+    let w705 = w704 ^ w703;
+    let w706 = w699 ^ w705;
+    let w707 = w664 & 0x00000040u32;
+    let w708 = w704 & w703;
+    let w709 = w707 ^ w708;
+    let w710 = w709.rotate_left(1);
+    let w711 = w665 & 0x00000080u32;
+    let w712 = w711 ^ w710;
+    let w713 = w706 ^ w712;
+    let w714 = w664 & 0x00000080u32;
+    let w715 = w711 & w710;
+    let w716 = w714 ^ w715;
+    let w717 = w716.rotate_left(1);
+    let w718 = w665 & 0x00000100u32;
+    let w719 = w718 ^ w717;
+    let w720 = w713 ^ w719;
+    let w721 = w664 & 0x00000100u32;
+    let w722 = w718 & w717;
+    let w723 = w721 ^ w722;
+    let w724 = w723.rotate_left(1);
+    let w725 = w665 & 0x00000200u32;
+    let w726 = w725 ^ w724;
+    let w727 = w720 ^ w726;
+    let w728 = w664 & 0x00000200u32;
+    let w729 = w725 & w724;
+    let w730 = w728 ^ w729;
+    let w731 = w730.rotate_left(1);
+    let w732 = w665 & 0x00000400u32;
+    let w733 = w732 ^ w731;
+    let w734 = w727 ^ w733;
+    let w735 = w664 & 0x00000400u32;
+    let w736 = w732 & w731;
+    let w737 = w735 ^ w736;
+    let w738 = w737.rotate_left(1);
+    let w739 = w665 & 0x00000800u32;
+    let w740 = w739 ^ w738;
+    let w741 = w734 ^ w740;
+    let w742 = w664 & 0x00000800u32;
+    let w743 = w739 & w738;
+    let w744 = w742 ^ w743;
+    let w745 = w744.rotate_left(1);
+    let w746 = w665 & 0x00001000u32;
+    let w747 = w746 ^ w745;
+    let w748 = w741 ^ w747;
+    let w749 = w664 & 0x00001000u32;
+    let w750 = w746 & w745;
+    let w751 = w749 ^ w750;
+    let w752 = w751.rotate_left(1);
+    let w753 = w665 & 0x00002000u32;
+    let w754 = w753 ^ w752;
+    let w755 = w748 ^ w754;
+    let w756 = w664 & 0x00002000u32;
+    let w757 = w753 & w752;
+    let w758 = w756 ^ w757;
+    let w759 = w758.rotate_left(1);
+    let w760 = w665 & 0x00004000u32;
+    let w761 = w760 ^ w759;
+    let w762 = w755 ^ w761;
+    let w763 = w664 & 0x00004000u32;
+    let w764 = w760 & w759;
+    let w765 = w763 ^ w764;
+    let w766 = w765.rotate_left(1);
+    let w767 = w665 & 0x00008000u32;
+    let w768 = w767 ^ w766;
+    let w769 = w762 ^ w768;
+    let w770 = w664 & 0x00008000u32;
+    let w771 = w767 & w766;
+    let w772 = w770 ^ w771;
+    let w773 = w772.rotate_left(1);
+    let w774 = w665 & 0x00010000u32;
+    let w775 = w774 ^ w773;
+    let w776 = w769 ^ w775;
+    let w777 = w664 & 0x00010000u32;
+    let w778 = w774 & w773;
+    let w779 = w777 ^ w778;
+    let w780 = w779.rotate_left(1);
+    let w781 = w665 & 0x00020000u32;
+    let w782 = w781 ^ w780;
+    let w783 = w776 ^ w782;
+    let w784 = w664 & 0x00020000u32;
+    let w785 = w781 & w780;
+    let w786 = w784 ^ w785;
+    let w787 = w786.rotate_left(1);
+    let w788 = w665 & 0x00040000u32;
+    let w789 = w788 ^ w787;
+    let w790 = w783 ^ w789;
+    let w791 = w664 & 0x00040000u32;
+    let w792 = w788 & w787;
+    let w793 = w791 ^ w792;
+    let w794 = w793.rotate_left(1);
+    let w795 = w665 & 0x00080000u32;
+    let w796 = w795 ^ w794;
+    let w797 = w790 ^ w796;
+    let w798 = w664 & 0x00080000u32;
+    let w799 = w795 & w794;
+    let w800 = w798 ^ w799;
+    let w801 = w800.rotate_left(1);
+    let w802 = w665 & 0x00100000u32;
+    let w803 = w802 ^ w801;
+    let w804 = w797 ^ w803;
+    let w805 = w664 & 0x00100000u32;
+    let w806 = w802 & w801;
+    let w807 = w805 ^ w806;
+    let w808 = w807.rotate_left(1);
+    let w809 = w665 & 0x00200000u32;
+    let w810 = w809 ^ w808;
+    let w811 = w804 ^ w810;
+    let w812 = w664 & 0x00200000u32;
+    let w813 = w809 & w808;
+    let w814 = w812 ^ w813;
+    let w815 = w814.rotate_left(1);
+    let w816 = w665 & 0x00400000u32;
+    let w817 = w816 ^ w815;
+    let w818 = w811 ^ w817;
+    let w819 = w664 & 0x00400000u32;
+    let w820 = w816 & w815;
+    let w821 = w819 ^ w820;
+    let w822 = w821.rotate_left(1);
+    let w823 = w665 & 0x00800000u32;
+    let w824 = w823 ^ w822;
+    let w825 = w818 ^ w824;
+    let w826 = w664 & 0x00800000u32;
+    let w827 = w823 & w822;
+    let w828 = w826 ^ w827;
+    let w829 = w828.rotate_left(1);
+    let w830 = w665 & 0x01000000u32;
+    let w831 = w830 ^ w829;
+    let w832 = w825 ^ w831;
+    let w833 = w664 & 0x01000000u32;
+    let w834 = w830 & w829;
+    let w835 = w833 ^ w834;
+    let w836 = w835.rotate_left(1);
+    let w837 = w665 & 0x02000000u32;
+    let w838 = w837 ^ w836;
+    let w839 = w832 ^ w838;
+    let w840 = w664 & 0x02000000u32;
+    let w841 = w837 & w836;
+    let w842 = w840 ^ w841;
+    let w843 = w842.rotate_left(1);
+    let w844 = w665 & 0x04000000u32;
+    let w845 = w844 ^ w843;
+    let w846 = w839 ^ w845;
+    let w847 = w664 & 0x04000000u32;
+    let w848 = w844 & w843;
+    let w849 = w847 ^ w848;
+    let w850 = w849.rotate_left(1);
+    let w851 = w665 & 0x08000000u32;
+    let w852 = w851 ^ w850;
+    let w853 = w846 ^ w852;
+    let w854 = w664 & 0x08000000u32;
+    let w855 = w851 & w850;
+    let w856 = w854 ^ w855;
+    let w857 = w856.rotate_left(1);
+    let w858 = w665 & 0x10000000u32;
+    let w859 = w858 ^ w857;
+    let w860 = w853 ^ w859;
+    let w861 = w664 & 0x10000000u32;
+    let w862 = w858 & w857;
+    let w863 = w861 ^ w862;
+    let w864 = w863.rotate_left(1);
+    let w865 = w665 & 0x20000000u32;
+    let w866 = w865 ^ w864;
+    let w867 = w860 ^ w866;
+    let w868 = w664 & 0x20000000u32;
+    let w869 = w865 & w864;
+    let w870 = w868 ^ w869;
+    let w871 = w870.rotate_left(1);
+    let w872 = w665 & 0x40000000u32;
+    let w873 = w872 ^ w871;
+    let w874 = w867 ^ w873;
+    let w875 = w664 & 0x40000000u32;
+    let w876 = w872 & w871;
+    let w877 = w875 ^ w876;
+    let w878 = w877.rotate_left(1);
+    let w879 = w665 & 0x80000000u32;
+    let w880 = w879 ^ w878;
+    let w881 = w874 ^ w880;
+    let w882 = u32::from_le_bytes([input_digest[24], input_digest[25], input_digest[26], input_digest[27]]);
+    let w883 = u32::from_le_bytes([input_digest[28], input_digest[29], input_digest[30], input_digest[31]]);
+    let w884 = w883 ^ w881;
+    let w885 = w884.rotate_left(16);
+    let w886 = w882 & w885;
+    let w887 = w882 ^ w885;
+    let w888 = w887 & 0x00000001u32;
+    let w889 = w886 & 0x00000001u32;
+    let w890 = w889.rotate_left(1);
+    let w891 = w887 & 0x00000002u32;
+    let w892 = w891 ^ w890;
+    let w893 = w888 ^ w892;
+    let w894 = w886 & 0x00000002u32;
+    let w895 = w891 & w890;
+    let w896 = w894 ^ w895;
+    let w897 = w896.rotate_left(1);
+    let w898 = w887 & 0x00000004u32;
+    let w899 = w898 ^ w897;
+    let w900 = w893 ^ w899;
+    let w901 = w886 & 0x00000004u32;
+    let w902 = w898 & w897;
+    let w903 = w901 ^ w902;
+    let w904 = w903.rotate_left(1);
+    let w905 = w887 & 0x00000008u32;
+    let w906 = w905 ^ w904;
+    let w907 = w900 ^ w906;
+    let w908 = w886 & 0x00000008u32;
+    let w909 = w905 & w904;
+    let w910 = w908 ^ w909;
+    let w911 = w910.rotate_left(1);
+    let w912 = w887 & 0x00000010u32;
+    let w913 = w912 ^ w911;
+    let w914 = w907 ^ w913;
+    let w915 = w886 & 0x00000010u32;
+    let w916 = w912 & w911;
+    let w917 = w915 ^ w916;
+    let w918 = w917.rotate_left(1);
+    let w919 = w887 & 0x00000020u32;
+    let w920 = w919 ^ w918;
+    let w921 = w914 ^ w920;
+    let w922 = w886 & 0x00000020u32;
+    let w923 = w919 & w918;
+    let w924 = w922 ^ w923;
+    let w925 = w924.rotate_left(1);
+    let w926 = w887 & 0x00000040u32;
+    let w927 = w926 ^ w925;
+    let w928 = w921 ^ w927;
+    let w929 = w886 & 0x00000040u32;
+    let w930 = w926 & w925;
+    let w931 = w929 ^ w930;
+    let w932 = w931.rotate_left(1);
+    let w933 = w887 & 0x00000080u32;
+    let w934 = w933 ^ w932;
+    let w935 = w928 ^ w934;
+    let w936 = w886 & 0x00000080u32;
+    let w937 = w933 & w932;
+    let w938 = w936 ^ w937;
+    let w939 = w938.rotate_left(1);
+    let w940 = w887 & 0x00000100u32;
+    let w941 = w940 ^ w939;
+    let w942 = w935 ^ w941;
+    let w943 = w886 & 0x00000100u32;
+    let w944 = w940 & w939;
+    let w945 = w943 ^ w944;
+    let w946 = w945.rotate_left(1);
+    let w947 = w887 & 0x00000200u32;
+    let w948 = w947 ^ w946;
+    let w949 = w942 ^ w948;
+    let w950 = w886 & 0x00000200u32;
+    let w951 = w947 & w946;
+    let w952 = w950 ^ w951;
+    let w953 = w952.rotate_left(1);
+    let w954 = w887 & 0x00000400u32;
+    let w955 = w954 ^ w953;
+    let w956 = w949 ^ w955;
+    let w957 = w886 & 0x00000400u32;
+    let w958 = w954 & w953;
+    let w959 = w957 ^ w958;
+    let w960 = w959.rotate_left(1);
+    let w961 = w887 & 0x00000800u32;
+    let w962 = w961 ^ w960;
+    let w963 = w956 ^ w962;
+    let w964 = w886 & 0x00000800u32;
+    let w965 = w961 & w960;
+    let w966 = w964 ^ w965;
+    let w967 = w966.rotate_left(1);
+    let w968 = w887 & 0x00001000u32;
+    let w969 = w968 ^ w967;
+    let w970 = w963 ^ w969;
+    let w971 = w886 & 0x00001000u32;
+    let w972 = w968 & w967;
+    let w973 = w971 ^ w972;
+    let w974 = w973.rotate_left(1);
+    let w975 = w887 & 0x00002000u32;
+    let w976 = w975 ^ w974;
+    let w977 = w970 ^ w976;
+    let w978 = w886 & 0x00002000u32;
+    let w979 = w975 & w974;
+    let w980 = w978 ^ w979;
+    let w981 = w980.rotate_left(1);
+    let w982 = w887 & 0x00004000u32;
+    let w983 = w982 ^ w981;
+    let w984 = w977 ^ w983;
+    let w985 = w886 & 0x00004000u32;
+    let w986 = w982 & w981;
+    let w987 = w985 ^ w986;
+    let w988 = w987.rotate_left(1);
+    let w989 = w887 & 0x00008000u32;
+    let w990 = w989 ^ w988;
+    let w991 = w984 ^ w990;
+    let w992 = w886 & 0x00008000u32;
+    let w993 = w989 & w988;
+    let w994 = w992 ^ w993;
+    let w995 = w994.rotate_left(1);
+    let w996 = w887 & 0x00010000u32;
+    let w997 = w996 ^ w995;
+    let w998 = w991 ^ w997;
+    let w999 = w886 & 0x00010000u32;
+    let w1000 = w996 & w995;
+    let w1001 = w999 ^ w1000;
+    let w1002 = w1001.rotate_left(1);
+    let w1003 = w887 & 0x00020000u32;
+    let w1004 = w1003 ^ w1002;
+    let w1005 = w998 ^ w1004;
+    let w1006 = w886 & 0x00020000u32;
+    let w1007 = w1003 & w1002;
+    let w1008 = w1006 ^ w1007;
+    let w1009 = w1008.rotate_left(1);
+    let w1010 = w887 & 0x00040000u32;
+    let w1011 = w1010 ^ w1009;
+    let w1012 = w1005 ^ w1011;
+    let w1013 = w886 & 0x00040000u32;
+    let w1014 = w1010 & w1009;
+    let w1015 = w1013 ^ w1014;
+    let w1016 = w1015.rotate_left(1);
+    let w1017 = w887 & 0x00080000u32;
+    let w1018 = w1017 ^ w1016;
+    let w1019 = w1012 ^ w1018;
+    let w1020 = w886 & 0x00080000u32;
+    let w1021 = w1017 & w1016;
+    let w1022 = w1020 ^ w1021;
+    let w1023 = w1022.rotate_left(1);
+    let w1024 = w887 & 0x00100000u32;
+    let w1025 = w1024 ^ w1023;
+    let w1026 = w1019 ^ w1025;
+    let w1027 = w886 & 0x00100000u32;
+    let w1028 = w1024 & w1023;
+    let w1029 = w1027 ^ w1028;
+    let w1030 = w1029.rotate_left(1);
+    let w1031 = w887 & 0x00200000u32;
+    let w1032 = w1031 ^ w1030;
+    let w1033 = w1026 ^ w1032;
+    let w1034 = w886 & 0x00200000u32;
+    let w1035 = w1031 & w1030;
+    let w1036 = w1034 ^ w1035;
+    let w1037 = w1036.rotate_left(1);
+    let w1038 = w887 & 0x00400000u32;
+    let w1039 = w1038 ^ w1037;
+    let w1040 = w1033 ^ w1039;
+    let w1041 = w886 & 0x00400000u32;
+    let w1042 = w1038 & w1037;
+    let w1043 = w1041 ^ w1042;
+    let w1044 = w1043.rotate_left(1);
+    let w1045 = w887 & 0x00800000u32;
+    let w1046 = w1045 ^ w1044;
+    let w1047 = w1040 ^ w1046;
+    let w1048 = w886 & 0x00800000u32;
+    let w1049 = w1045 & w1044;
+    let w1050 = w1048 ^ w1049;
+    let w1051 = w1050.rotate_left(1);
+    let w1052 = w887 & 0x01000000u32;
+    let w1053 = w1052 ^ w1051;
+    let w1054 = w1047 ^ w1053;
+    let w1055 = w886 & 0x01000000u32;
+    let w1056 = w1052 & w1051;
+    let w1057 = w1055 ^ w1056;
+    let w1058 = w1057.rotate_left(1);
+    let w1059 = w887 & 0x02000000u32;
+    let w1060 = w1059 ^ w1058;
+    let w1061 = w1054 ^ w1060;
+    let w1062 = w886 & 0x02000000u32;
+    let w1063 = w1059 & w1058;
+    let w1064 = w1062 ^ w1063;
+    let w1065 = w1064.rotate_left(1);
+    let w1066 = w887 & 0x04000000u32;
+    let w1067 = w1066 ^ w1065;
+    let w1068 = w1061 ^ w1067;
+    let w1069 = w886 & 0x04000000u32;
+    let w1070 = w1066 & w1065;
+    let w1071 = w1069 ^ w1070;
+    let w1072 = w1071.rotate_left(1);
+    let w1073 = w887 & 0x08000000u32;
+    let w1074 = w1073 ^ w1072;
+    let w1075 = w1068 ^ w1074;
+    let w1076 = w886 & 0x08000000u32;
+    let w1077 = w1073 & w1072;
+    let w1078 = w1076 ^ w1077;
+    let w1079 = w1078.rotate_left(1);
+    let w1080 = w887 & 0x10000000u32;
+    let w1081 = w1080 ^ w1079;
+    let w1082 = w1075 ^ w1081;
+    let w1083 = w886 & 0x10000000u32;
+    let w1084 = w1080 & w1079;
+    let w1085 = w1083 ^ w1084;
+    let w1086 = w1085.rotate_left(1);
+    let w1087 = w887 & 0x20000000u32;
+    let w1088 = w1087 ^ w1086;
+    let w1089 = w1082 ^ w1088;
+    let w1090 = w886 & 0x20000000u32;
+    let w1091 = w1087 & w1086;
+    let w1092 = w1090 ^ w1091;
+    let w1093 = w1092.rotate_left(1);
+    let w1094 = w887 & 0x40000000u32;
+    let w1095 = w1094 ^ w1093;
+    let w1096 = w1089 ^ w1095;
+    let w1097 = w886 & 0x40000000u32;
+    let w1098 = w1094 & w1093;
+    let w1099 = w1097 ^ w1098;
+    let w1100 = w1099.rotate_left(1);
+    let w1101 = w887 & 0x80000000u32;
+    let w1102 = w1101 ^ w1100;
+    let w1103 = w1096 ^ w1102;
+    let w1104 = w663 ^ w1103;
+    let w1105 = w1104.rotate_left(12);
+    let w1106 = w881 & w1105;
+    let w1107 = w881 ^ w1105;
+    let w1108 = w1107 & 0x00000001u32;
+    let w1109 = w1106 & 0x00000001u32;
+    let w1110 = w1109.rotate_left(1);
+    let w1111 = w1107 & 0x00000002u32;
+    let w1112 = w1111 ^ w1110;
+    let w1113 = w1108 ^ w1112;
+    let w1114 = w1106 & 0x00000002u32;
+    let w1115 = w1111 & w1110;
+    let w1116 = w1114 ^ w1115;
+    let w1117 = w1116.rotate_left(1);
+    let w1118 = w1107 & 0x00000004u32;
+    let w1119 = w1118 ^ w1117;
+    let w1120 = w1113 ^ w1119;
+    let w1121 = w1106 & 0x00000004u32;
+    let w1122 = w1118 & w1117;
+    let w1123 = w1121 ^ w1122;
+    let w1124 = w1123.rotate_left(1);
+    let w1125 = w1107 & 0x00000008u32;
+    let w1126 = w1125 ^ w1124;
+    let w1127 = w1120 ^ w1126;
+    let w1128 = w1106 & 0x00000008u32;
+    let w1129 = w1125 & w1124;
+    let w1130 = w1128 ^ w1129;
+    let w1131 = w1130.rotate_left(1);
+    let w1132 = w1107 & 0x00000010u32;
+    let w1133 = w1132 ^ w1131;
+    let w1134 = w1127 ^ w1133;
+    let w1135 = w1106 & 0x00000010u32;
+    let w1136 = w1132 & w1131;
+    let w1137 = w1135 ^ w1136;
+    let w1138 = w1137.rotate_left(1);
+    let w1139 = w1107 & 0x00000020u32;
+    let w1140 = w1139 ^ w1138;
+    let w1141 = w1134 ^ w1140;
+    let w1142 = w1106 & 0x00000020u32;
+    let w1143 = w1139 & w1138;
+    let w1144 = w1142 ^ w1143;
+    let w1145 = w1144.rotate_left(1);
+    let w1146 = w1107 & 0x00000040u32;
+    let w1147 = w1146 ^ w1145;
+    let w1148 = w1141 ^ w1147;
+    let w1149 = w1106 & 0x00000040u32;
+    let w1150 = w1146 & w1145;
+    let w1151 = w1149 ^ w1150;
+    let w1152 = w1151.rotate_left(1);
+    let w1153 = w1107 & 0x00000080u32;
+    let w1154 = w1153 ^ w1152;
+    let w1155 = w1148 ^ w1154;
+    let w1156 = w1106 & 0x00000080u32;
+    let w1157 = w1153 & w1152;
+    let w1158 = w1156 ^ w1157;
+    let w1159 = w1158.rotate_left(1);
+    let w1160 = w1107 & 0x00000100u32;
+    let w1161 = w1160 ^ w1159;
+    let w1162 = w1155 ^ w1161;
+    let w1163 = w1106 & 0x00000100u32;
+    let w1164 = w1160 & w1159;
+    let w1165 = w1163 ^ w1164;
+    let w1166 = w1165.rotate_left(1);
+    let w1167 = w1107 & 0x00000200u32;
+    let w1168 = w1167 ^ w1166;
+    let w1169 = w1162 ^ w1168;
+    let w1170 = w1106 & 0x00000200u32;
+    let w1171 = w1167 & w1166;
+    let w1172 = w1170 ^ w1171;
+    let w1173 = w1172.rotate_left(1);
+    let w1174 = w1107 & 0x00000400u32;
+    let w1175 = w1174 ^ w1173;
+    let w1176 = w1169 ^ w1175;
+    let w1177 = w1106 & 0x00000400u32;
+    let w1178 = w1174 & w1173;
+    let w1179 = w1177 ^ w1178;
+    let w1180 = w1179.rotate_left(1);
+    let w1181 = w1107 & 0x00000800u32;
+    let w1182 = w1181 ^ w1180;
+    let w1183 = w1176 ^ w1182;
+    let w1184 = w1106 & 0x00000800u32;
+    let w1185 = w1181 & w1180;
+    let w1186 = w1184 ^ w1185;
+    let w1187 = w1186.rotate_left(1);
+    let w1188 = w1107 & 0x00001000u32;
+    let w1189 = w1188 ^ w1187;
+    let w1190 = w1183 ^ w1189;
+    let w1191 = w1106 & 0x00001000u32;
+    let w1192 = w1188 & w1187;
+    let w1193 = w1191 ^ w1192;
+    let w1194 = w1193.rotate_left(1);
+    let w1195 = w1107 & 0x00002000u32;
+    let w1196 = w1195 ^ w1194;
+    let w1197 = w1190 ^ w1196;
+    let w1198 = w1106 & 0x00002000u32;
+    let w1199 = w1195 & w1194;
+    let w1200 = w1198 ^ w1199;
+    let w1201 = w1200.rotate_left(1);
+    let w1202 = w1107 & 0x00004000u32;
+    let w1203 = w1202 ^ w1201;
+    let w1204 = w1197 ^ w1203;
+    let w1205 = w1106 & 0x00004000u32;
+    let w1206 = w1202 & w1201;
+    let w1207 = w1205 ^ w1206;
+    let w1208 = w1207.rotate_left(1);
+    let w1209 = w1107 & 0x00008000u32;
+    let w1210 = w1209 ^ w1208;
+    let w1211 = w1204 ^ w1210;
+    let w1212 = w1106 & 0x00008000u32;
+    let w1213 = w1209 & w1208;
+    let w1214 = w1212 ^ w1213;
+    let w1215 = w1214.rotate_left(1);
+    let w1216 = w1107 & 0x00010000u32;
+    let w1217 = w1216 ^ w1215;
+    let w1218 = w1211 ^ w1217;
+    let w1219 = w1106 & 0x00010000u32;
+    let w1220 = w1216 & w1215;
+    let w1221 = w1219 ^ w1220;
+    let w1222 = w1221.rotate_left(1);
+    let w1223 = w1107 & 0x00020000u32;
+    let w1224 = w1223 ^ w1222;
+    let w1225 = w1218 ^ w1224;
+    let w1226 = w1106 & 0x00020000u32;
+    let w1227 = w1223 & w1222;
+    let w1228 = w1226 ^ w1227;
+    let w1229 = w1228.rotate_left(1);
+    let w1230 = w1107 & 0x00040000u32;
+    let w1231 = w1230 ^ w1229;
+    let w1232 = w1225 ^ w1231;
+    let w1233 = w1106 & 0x00040000u32;
+    let w1234 = w1230 & w1229;
+    let w1235 = w1233 ^ w1234;
+    let w1236 = w1235.rotate_left(1);
+    let w1237 = w1107 & 0x00080000u32;
+    let w1238 = w1237 ^ w1236;
+    let w1239 = w1232 ^ w1238;
+    let w1240 = w1106 & 0x00080000u32;
+    let w1241 = w1237 & w1236;
+    let w1242 = w1240 ^ w1241;
+    let w1243 = w1242.rotate_left(1);
+    let w1244 = w1107 & 0x00100000u32;
+    let w1245 = w1244 ^ w1243;
+    let w1246 = w1239 ^ w1245;
+    let w1247 = w1106 & 0x00100000u32;
+    let w1248 = w1244 & w1243;
+    let w1249 = w1247 ^ w1248;
+    let w1250 = w1249.rotate_left(1);
+    let w1251 = w1107 & 0x00200000u32;
+    let w1252 = w1251 ^ w1250;
+    let w1253 = w1246 ^ w1252;
+    let w1254 = w1106 & 0x00200000u32;
+    let w1255 = w1251 & w1250;
+    let w1256 = w1254 ^ w1255;
+    let w1257 = w1256.rotate_left(1);
+    let w1258 = w1107 & 0x00400000u32;
+    let w1259 = w1258 ^ w1257;
+    let w1260 = w1253 ^ w1259;
+    let w1261 = w1106 & 0x00400000u32;
+    let w1262 = w1258 & w1257;
+    let w1263 = w1261 ^ w1262;
+    let w1264 = w1263.rotate_left(1);
+    let w1265 = w1107 & 0x00800000u32;
+    let w1266 = w1265 ^ w1264;
+    let w1267 = w1260 ^ w1266;
+    let w1268 = w1106 & 0x00800000u32;
+    let w1269 = w1265 & w1264;
+    let w1270 = w1268 ^ w1269;
+    let w1271 = w1270.rotate_left(1);
+    let w1272 = w1107 & 0x01000000u32;
+    let w1273 = w1272 ^ w1271;
+    let w1274 = w1267 ^ w1273;
+    let w1275 = w1106 & 0x01000000u32;
+    let w1276 = w1272 & w1271;
+    let w1277 = w1275 ^ w1276;
+    let w1278 = w1277.rotate_left(1);
+    let w1279 = w1107 & 0x02000000u32;
+    let w1280 = w1279 ^ w1278;
+    let w1281 = w1274 ^ w1280;
+    let w1282 = w1106 & 0x02000000u32;
+    let w1283 = w1279 & w1278;
+    let w1284 = w1282 ^ w1283;
+    let w1285 = w1284.rotate_left(1);
+    let w1286 = w1107 & 0x04000000u32;
+    let w1287 = w1286 ^ w1285;
+    let w1288 = w1281 ^ w1287;
+    let w1289 = w1106 & 0x04000000u32;
+    let w1290 = w1286 & w1285;
+    let w1291 = w1289 ^ w1290;
+    let w1292 = w1291.rotate_left(1);
+    let w1293 = w1107 & 0x08000000u32;
+    let w1294 = w1293 ^ w1292;
+    let w1295 = w1288 ^ w1294;
+    let w1296 = w1106 & 0x08000000u32;
+    let w1297 = w1293 & w1292;
+    let w1298 = w1296 ^ w1297;
+    let w1299 = w1298.rotate_left(1);
+    let w1300 = w1107 & 0x10000000u32;
+    let w1301 = w1300 ^ w1299;
+    let w1302 = w1295 ^ w1301;
+    let w1303 = w1106 & 0x10000000u32;
+    let w1304 = w1300 & w1299;
+    let w1305 = w1303 ^ w1304;
+    let w1306 = w1305.rotate_left(1);
+    let w1307 = w1107 & 0x20000000u32;
+    let w1308 = w1307 ^ w1306;
+    let w1309 = w1302 ^ w1308;
+    let w1310 = w1106 & 0x20000000u32;
+    let w1311 = w1307 & w1306;
+    let w1312 = w1310 ^ w1311;
+    let w1313 = w1312.rotate_left(1);
+    let w1314 = w1107 & 0x40000000u32;
+    let w1315 = w1314 ^ w1313;
+    let w1316 = w1309 ^ w1315;
+    let w1317 = w1106 & 0x40000000u32;
+    let w1318 = w1314 & w1313;
+    let w1319 = w1317 ^ w1318;
+    let w1320 = w1319.rotate_left(1);
+    let w1321 = w1107 & 0x80000000u32;
+    let w1322 = w1321 ^ w1320;
+    let w1323 = w1316 ^ w1322;
+    let w1324 = w661 ^ w1323;
+    let w1325 = w223 ^ w661;
+    let w1326 = w1325.rotate_left(8);
+    let w1327 = w441 & w1326;
+    let w1328 = w441 ^ w1326;
+    let w1329 = w1328 & 0x00000001u32;
+    let w1330 = w1327 & 0x00000001u32;
+    let w1331 = w1330.rotate_left(1);
+    let w1332 = w1328 & 0x00000002u32;
+    let w1333 = w1332 ^ w1331;
+    let w1334 = w1329 ^ w1333;
+    let w1335 = w1327 & 0x00000002u32;
+    let w1336 = w1332 & w1331;
+    let w1337 = w1335 ^ w1336;
+    let w1338 = w1337.rotate_left(1);
+    let w1339 = w1328 & 0x00000004u32;
+    let w1340 = w1339 ^ w1338;
+    let w1341 = w1334 ^ w1340;
+    let w1342 = w1327 & 0x00000004u32;
+    let w1343 = w1339 & w1338;
+    let w1344 = w1342 ^ w1343;
+    let w1345 = w1344.rotate_left(1);
+    let w1346 = w1328 & 0x00000008u32;
+    let w1347 = w1346 ^ w1345;
+    let w1348 = w1341 ^ w1347;
+    let w1349 = w1327 & 0x00000008u32;
+    let w1350 = w1346 & w1345;
+    let w1351 = w1349 ^ w1350;
+    let w1352 = w1351.rotate_left(1);
+    let w1353 = w1328 & 0x00000010u32;
+    let w1354 = w1353 ^ w1352;
+    let w1355 = w1348 ^ w1354;
+    let w1356 = w1327 & 0x00000010u32;
+    let w1357 = w1353 & w1352;
+    let w1358 = w1356 ^ w1357;
+    let w1359 = w1358.rotate_left(1);
+    let w1360 = w1328 & 0x00000020u32;
+    let w1361 = w1360 ^ w1359;
+    let w1362 = w1355 ^ w1361;
+    let w1363 = w1327 & 0x00000020u32;
+    let w1364 = w1360 & w1359;
+    let w1365 = w1363 ^ w1364;
+    let w1366 = w1365.rotate_left(1);
+    let w1367 = w1328 & 0x00000040u32;
+    let w1368 = w1367 ^ w1366;
+    let w1369 = w1362 ^ w1368;
+    let w1370 = w1327 & 0x00000040u32;
+    let w1371 = w1367 & w1366;
+    let w1372 = w1370 ^ w1371;
+    let w1373 = w1372.rotate_left(1);
+    let w1374 = w1328 & 0x00000080u32;
+    let w1375 = w1374 ^ w1373;
+    let w1376 = w1369 ^ w1375;
+    let w1377 = w1327 & 0x00000080u32;
+    let w1378 = w1374 & w1373;
+    let w1379 = w1377 ^ w1378;
+    let w1380 = w1379.rotate_left(1);
+    let w1381 = w1328 & 0x00000100u32;
+    let w1382 = w1381 ^ w1380;
+    let w1383 = w1376 ^ w1382;
+    let w1384 = w1327 & 0x00000100u32;
+    let w1385 = w1381 & w1380;
+    let w1386 = w1384 ^ w1385;
+    let w1387 = w1386.rotate_left(1);
+    let w1388 = w1328 & 0x00000200u32;
+    let w1389 = w1388 ^ w1387;
+    let w1390 = w1383 ^ w1389;
+    let w1391 = w1327 & 0x00000200u32;
+    let w1392 = w1388 & w1387;
+    let w1393 = w1391 ^ w1392;
+    let w1394 = w1393.rotate_left(1);
+    let w1395 = w1328 & 0x00000400u32;
+    let w1396 = w1395 ^ w1394;
+    let w1397 = w1390 ^ w1396;
+    let w1398 = w1327 & 0x00000400u32;
+    let w1399 = w1395 & w1394;
+    let w1400 = w1398 ^ w1399;
+    let w1401 = w1400.rotate_left(1);
+    let w1402 = w1328 & 0x00000800u32;
+    let w1403 = w1402 ^ w1401;
+    let w1404 = w1397 ^ w1403;
+    let w1405 = w1327 & 0x00000800u32;
+    let w1406 = w1402 & w1401;
+    let w1407 = w1405 ^ w1406;
+    let w1408 = w1407.rotate_left(1);
+    let w1409 = w1328 & 0x00001000u32;
+    let w1410 = w1409 ^ w1408;
+    let w1411 = w1404 ^ w1410;
+    let w1412 = w1327 & 0x00001000u32;
+    let w1413 = w1409 & w1408;
+    let w1414 = w1412 ^ w1413;
+    let w1415 = w1414.rotate_left(1);
+    let w1416 = w1328 & 0x00002000u32;
+    let w1417 = w1416 ^ w1415;
+    let w1418 = w1411 ^ w1417;
+    let w1419 = w1327 & 0x00002000u32;
+    let w1420 = w1416 & w1415;
+    let w1421 = w1419 ^ w1420;
+    let w1422 = w1421.rotate_left(1);
+    let w1423 = w1328 & 0x00004000u32;
+    let w1424 = w1423 ^ w1422;
+    let w1425 = w1418 ^ w1424;
+    let w1426 = w1327 & 0x00004000u32;
+    let w1427 = w1423 & w1422;
+    let w1428 = w1426 ^ w1427;
+    let w1429 = w1428.rotate_left(1);
+    let w1430 = w1328 & 0x00008000u32;
+    let w1431 = w1430 ^ w1429;
+    let w1432 = w1425 ^ w1431;
+    let w1433 = w1327 & 0x00008000u32;
+    let w1434 = w1430 & w1429;
+    let w1435 = w1433 ^ w1434;
+    let w1436 = w1435.rotate_left(1);
+    let w1437 = w1328 & 0x00010000u32;
+    let w1438 = w1437 ^ w1436;
+    let w1439 = w1432 ^ w1438;
+    let w1440 = w1327 & 0x00010000u32;
+    let w1441 = w1437 & w1436;
+    let w1442 = w1440 ^ w1441;
+    let w1443 = w1442.rotate_left(1);
+    let w1444 = w1328 & 0x00020000u32;
+    let w1445 = w1444 ^ w1443;
+    let w1446 = w1439 ^ w1445;
+    let w1447 = w1327 & 0x00020000u32;
+    let w1448 = w1444 & w1443;
+    let w1449 = w1447 ^ w1448;
+    let w1450 = w1449.rotate_left(1);
+    let w1451 = w1328 & 0x00040000u32;
+    let w1452 = w1451 ^ w1450;
+    let w1453 = w1446 ^ w1452;
+    let w1454 = w1327 & 0x00040000u32;
+    let w1455 = w1451 & w1450;
+    let w1456 = w1454 ^ w1455;
+    let w1457 = w1456.rotate_left(1);
+    let w1458 = w1328 & 0x00080000u32;
+    let w1459 = w1458 ^ w1457;
+    let w1460 = w1453 ^ w1459;
+    let w1461 = w1327 & 0x00080000u32;
+    let w1462 = w1458 & w1457;
+    let w1463 = w1461 ^ w1462;
+    let w1464 = w1463.rotate_left(1);
+    let w1465 = w1328 & 0x00100000u32;
+    let w1466 = w1465 ^ w1464;
+    let w1467 = w1460 ^ w1466;
+    let w1468 = w1327 & 0x00100000u32;
+    let w1469 = w1465 & w1464;
+    let w1470 = w1468 ^ w1469;
+    let w1471 = w1470.rotate_left(1);
+    let w1472 = w1328 & 0x00200000u32;
+    let w1473 = w1472 ^ w1471;
+    let w1474 = w1467 ^ w1473;
+    let w1475 = w1327 & 0x00200000u32;
+    let w1476 = w1472 & w1471;
+    let w1477 = w1475 ^ w1476;
+    let w1478 = w1477.rotate_left(1);
+    let w1479 = w1328 & 0x00400000u32;
+    let w1480 = w1479 ^ w1478;
+    let w1481 = w1474 ^ w1480;
+    let w1482 = w1327 & 0x00400000u32;
+    let w1483 = w1479 & w1478;
+    let w1484 = w1482 ^ w1483;
+    let w1485 = w1484.rotate_left(1);
+    let w1486 = w1328 & 0x00800000u32;
+    let w1487 = w1486 ^ w1485;
+    let w1488 = w1481 ^ w1487;
+    let w1489 = w1327 & 0x00800000u32;
+    let w1490 = w1486 & w1485;
+    let w1491 = w1489 ^ w1490;
+    let w1492 = w1491.rotate_left(1);
+    let w1493 = w1328 & 0x01000000u32;
+    let w1494 = w1493 ^ w1492;
+    let w1495 = w1488 ^ w1494;
+    let w1496 = w1327 & 0x01000000u32;
+    let w1497 = w1493 & w1492;
+    let w1498 = w1496 ^ w1497;
+    let w1499 = w1498.rotate_left(1);
+    let w1500 = w1328 & 0x02000000u32;
+    let w1501 = w1500 ^ w1499;
+    let w1502 = w1495 ^ w1501;
+    let w1503 = w1327 & 0x02000000u32;
+    let w1504 = w1500 & w1499;
+    let w1505 = w1503 ^ w1504;
+    let w1506 = w1505.rotate_left(1);
+    let w1507 = w1328 & 0x04000000u32;
+    let w1508 = w1507 ^ w1506;
+    let w1509 = w1502 ^ w1508;
+    let w1510 = w1327 & 0x04000000u32;
+    let w1511 = w1507 & w1506;
+    let w1512 = w1510 ^ w1511;
+    let w1513 = w1512.rotate_left(1);
+    let w1514 = w1328 & 0x08000000u32;
+    let w1515 = w1514 ^ w1513;
+    let w1516 = w1509 ^ w1515;
+    let w1517 = w1327 & 0x08000000u32;
+    let w1518 = w1514 & w1513;
+    let w1519 = w1517 ^ w1518;
+    let w1520 = w1519.rotate_left(1);
+    let w1521 = w1328 & 0x10000000u32;
+    let w1522 = w1521 ^ w1520;
+    let w1523 = w1516 ^ w1522;
+    let w1524 = w1327 & 0x10000000u32;
+    let w1525 = w1521 & w1520;
+    let w1526 = w1524 ^ w1525;
+    let w1527 = w1526.rotate_left(1);
+    let w1528 = w1328 & 0x20000000u32;
+    let w1529 = w1528 ^ w1527;
+    let w1530 = w1523 ^ w1529;
+    let w1531 = w1327 & 0x20000000u32;
+    let w1532 = w1528 & w1527;
+    let w1533 = w1531 ^ w1532;
+    let w1534 = w1533.rotate_left(1);
+    let w1535 = w1328 & 0x40000000u32;
+    let w1536 = w1535 ^ w1534;
+    let w1537 = w1530 ^ w1536;
+    let w1538 = w1327 & 0x40000000u32;
+    let w1539 = w1535 & w1534;
+    let w1540 = w1538 ^ w1539;
+    let w1541 = w1540.rotate_left(1);
+    let w1542 = w1328 & 0x80000000u32;
+    let w1543 = w1542 ^ w1541;
+    let w1544 = w1537 ^ w1543;
+    let w1545 = w443 ^ w1544;
+    let w1546 = w1545.rotate_left(7);
+    let w1547 = w885 ^ w1323;
+    let w1548 = w1547.rotate_left(8);
+    let w1549 = w1103 & w1548;
+    let w1550 = w1103 ^ w1548;
+    let w1551 = w1550 & 0x00000001u32;
+    let w1552 = w1549 & 0x00000001u32;
+    let w1553 = w1552.rotate_left(1);
+    let w1554 = w1550 & 0x00000002u32;
+    let w1555 = w1554 ^ w1553;
+    let w1556 = w1551 ^ w1555;
+    let w1557 = w1549 & 0x00000002u32;
+    let w1558 = w1554 & w1553;
+    let w1559 = w1557 ^ w1558;
+    let w1560 = w1559.rotate_left(1);
+    let w1561 = w1550 & 0x00000004u32;
+    let w1562 = w1561 ^ w1560;
+    let w1563 = w1556 ^ w1562;
+    let w1564 = w1549 & 0x00000004u32;
+    let w1565 = w1561 & w1560;
+    let w1566 = w1564 ^ w1565;
+    let w1567 = w1566.rotate_left(1);
+    let w1568 = w1550 & 0x00000008u32;
+    let w1569 = w1568 ^ w1567;
+    let w1570 = w1563 ^ w1569;
+    let w1571 = w1549 & 0x00000008u32;
+    let w1572 = w1568 & w1567;
+    let w1573 = w1571 ^ w1572;
+    let w1574 = w1573.rotate_left(1);
+    let w1575 = w1550 & 0x00000010u32;
+    let w1576 = w1575 ^ w1574;
+    let w1577 = w1570 ^ w1576;
+    let w1578 = w1549 & 0x00000010u32;
+    let w1579 = w1575 & w1574;
+    let w1580 = w1578 ^ w1579;
+    let w1581 = w1580.rotate_left(1);
+    let w1582 = w1550 & 0x00000020u32;
+    let w1583 = w1582 ^ w1581;
+    let w1584 = w1577 ^ w1583;
+    let w1585 = w1549 & 0x00000020u32;
+    let w1586 = w1582 & w1581;
+    let w1587 = w1585 ^ w1586;
+    let w1588 = w1587.rotate_left(1);
+    let w1589 = w1550 & 0x00000040u32;
+    let w1590 = w1589 ^ w1588;
+    let w1591 = w1584 ^ w1590;
+    let w1592 = w1549 & 0x00000040u32;
+    let w1593 = w1589 & w1588;
+    let w1594 = w1592 ^ w1593;
+    let w1595 = w1594.rotate_left(1);
+    let w1596 = w1550 & 0x00000080u32;
+    let w1597 = w1596 ^ w1595;
+    let w1598 = w1591 ^ w1597;
+    let w1599 = w1549 & 0x00000080u32;
+    let w1600 = w1596 & w1595;
+    let w1601 = w1599 ^ w1600;
+    let w1602 = w1601.rotate_left(1);
+    let w1603 = w1550 & 0x00000100u32;
+    let w1604 = w1603 ^ w1602;
+    let w1605 = w1598 ^ w1604;
+    let w1606 = w1549 & 0x00000100u32;
+    let w1607 = w1603 & w1602;
+    let w1608 = w1606 ^ w1607;
+    let w1609 = w1608.rotate_left(1);
+    let w1610 = w1550 & 0x00000200u32;
+    let w1611 = w1610 ^ w1609;
+    let w1612 = w1605 ^ w1611;
+    let w1613 = w1549 & 0x00000200u32;
+    let w1614 = w1610 & w1609;
+    let w1615 = w1613 ^ w1614;
+    let w1616 = w1615.rotate_left(1);
+    let w1617 = w1550 & 0x00000400u32;
+    let w1618 = w1617 ^ w1616;
+    let w1619 = w1612 ^ w1618;
+    let w1620 = w1549 & 0x00000400u32;
+    let w1621 = w1617 & w1616;
+    let w1622 = w1620 ^ w1621;
+    let w1623 = w1622.rotate_left(1);
+    let w1624 = w1550 & 0x00000800u32;
+    let w1625 = w1624 ^ w1623;
+    let w1626 = w1619 ^ w1625;
+    let w1627 = w1549 & 0x00000800u32;
+    let w1628 = w1624 & w1623;
+    let w1629 = w1627 ^ w1628;
+    let w1630 = w1629.rotate_left(1);
+    let w1631 = w1550 & 0x00001000u32;
+    let w1632 = w1631 ^ w1630;
+    let w1633 = w1626 ^ w1632;
+    let w1634 = w1549 & 0x00001000u32;
+    let w1635 = w1631 & w1630;
+    let w1636 = w1634 ^ w1635;
+    let w1637 = w1636.rotate_left(1);
+    let w1638 = w1550 & 0x00002000u32;
+    let w1639 = w1638 ^ w1637;
+    let w1640 = w1633 ^ w1639;
+    let w1641 = w1549 & 0x00002000u32;
+    let w1642 = w1638 & w1637;
+    let w1643 = w1641 ^ w1642;
+    let w1644 = w1643.rotate_left(1);
+    let w1645 = w1550 & 0x00004000u32;
+    let w1646 = w1645 ^ w1644;
+    let w1647 = w1640 ^ w1646;
+    let w1648 = w1549 & 0x00004000u32;
+    let w1649 = w1645 & w1644;
+    let w1650 = w1648 ^ w1649;
+    let w1651 = w1650.rotate_left(1);
+    let w1652 = w1550 & 0x00008000u32;
+    let w1653 = w1652 ^ w1651;
+    let w1654 = w1647 ^ w1653;
+    let w1655 = w1549 & 0x00008000u32;
+    let w1656 = w1652 & w1651;
+    let w1657 = w1655 ^ w1656;
+    let w1658 = w1657.rotate_left(1);
+    let w1659 = w1550 & 0x00010000u32;
+    let w1660 = w1659 ^ w1658;
+    let w1661 = w1654 ^ w1660;
+    let w1662 = w1549 & 0x00010000u32;
+    let w1663 = w1659 & w1658;
+    let w1664 = w1662 ^ w1663;
+    let w1665 = w1664.rotate_left(1);
+    let w1666 = w1550 & 0x00020000u32;
+    let w1667 = w1666 ^ w1665;
+    let w1668 = w1661 ^ w1667;
+    let w1669 = w1549 & 0x00020000u32;
+    let w1670 = w1666 & w1665;
+    let w1671 = w1669 ^ w1670;
+    let w1672 = w1671.rotate_left(1);
+    let w1673 = w1550 & 0x00040000u32;
+    let w1674 = w1673 ^ w1672;
+    let w1675 = w1668 ^ w1674;
+    let w1676 = w1549 & 0x00040000u32;
+    let w1677 = w1673 & w1672;
+    let w1678 = w1676 ^ w1677;
+    let w1679 = w1678.rotate_left(1);
+    let w1680 = w1550 & 0x00080000u32;
+    let w1681 = w1680 ^ w1679;
+    let w1682 = w1675 ^ w1681;
+    let w1683 = w1549 & 0x00080000u32;
+    let w1684 = w1680 & w1679;
+    let w1685 = w1683 ^ w1684;
+    let w1686 = w1685.rotate_left(1);
+    let w1687 = w1550 & 0x00100000u32;
+    let w1688 = w1687 ^ w1686;
+    let w1689 = w1682 ^ w1688;
+    let w1690 = w1549 & 0x00100000u32;
+    let w1691 = w1687 & w1686;
+    let w1692 = w1690 ^ w1691;
+    let w1693 = w1692.rotate_left(1);
+    let w1694 = w1550 & 0x00200000u32;
+    let w1695 = w1694 ^ w1693;
+    let w1696 = w1689 ^ w1695;
+    let w1697 = w1549 & 0x00200000u32;
+    let w1698 = w1694 & w1693;
+    let w1699 = w1697 ^ w1698;
+    let w1700 = w1699.rotate_left(1);
+    let w1701 = w1550 & 0x00400000u32;
+    let w1702 = w1701 ^ w1700;
+    let w1703 = w1696 ^ w1702;
+    let w1704 = w1549 & 0x00400000u32;
+    let w1705 = w1701 & w1700;
+    let w1706 = w1704 ^ w1705;
+    let w1707 = w1706.rotate_left(1);
+    let w1708 = w1550 & 0x00800000u32;
+    let w1709 = w1708 ^ w1707;
+    let w1710 = w1703 ^ w1709;
+    let w1711 = w1549 & 0x00800000u32;
+    let w1712 = w1708 & w1707;
+    let w1713 = w1711 ^ w1712;
+    let w1714 = w1713.rotate_left(1);
+    let w1715 = w1550 & 0x01000000u32;
+    let w1716 = w1715 ^ w1714;
+    let w1717 = w1710 ^ w1716;
+    let w1718 = w1549 & 0x01000000u32;
+    let w1719 = w1715 & w1714;
+    let w1720 = w1718 ^ w1719;
+    let w1721 = w1720.rotate_left(1);
+    let w1722 = w1550 & 0x02000000u32;
+    let w1723 = w1722 ^ w1721;
+    let w1724 = w1717 ^ w1723;
+    let w1725 = w1549 & 0x02000000u32;
+    let w1726 = w1722 & w1721;
+    let w1727 = w1725 ^ w1726;
+    let w1728 = w1727.rotate_left(1);
+    let w1729 = w1550 & 0x04000000u32;
+    let w1730 = w1729 ^ w1728;
+    let w1731 = w1724 ^ w1730;
+    let w1732 = w1549 & 0x04000000u32;
+    let w1733 = w1729 & w1728;
+    let w1734 = w1732 ^ w1733;
+    let w1735 = w1734.rotate_left(1);
+    let w1736 = w1550 & 0x08000000u32;
+    let w1737 = w1736 ^ w1735;
+    let w1738 = w1731 ^ w1737;
+    let w1739 = w1549 & 0x08000000u32;
+    let w1740 = w1736 & w1735;
+    let w1741 = w1739 ^ w1740;
+    let w1742 = w1741.rotate_left(1);
+    let w1743 = w1550 & 0x10000000u32;
+    let w1744 = w1743 ^ w1742;
+    let w1745 = w1738 ^ w1744;
+    let w1746 = w1549 & 0x10000000u32;
+    let w1747 = w1743 & w1742;
+    let w1748 = w1746 ^ w1747;
+    let w1749 = w1748.rotate_left(1);
+    let w1750 = w1550 & 0x20000000u32;
+    let w1751 = w1750 ^ w1749;
+    let w1752 = w1745 ^ w1751;
+    let w1753 = w1549 & 0x20000000u32;
+    let w1754 = w1750 & w1749;
+    let w1755 = w1753 ^ w1754;
+    let w1756 = w1755.rotate_left(1);
+    let w1757 = w1550 & 0x40000000u32;
+    let w1758 = w1757 ^ w1756;
+    let w1759 = w1752 ^ w1758;
+    let w1760 = w1549 & 0x40000000u32;
+    let w1761 = w1757 & w1756;
+    let w1762 = w1760 ^ w1761;
+    let w1763 = w1762.rotate_left(1);
+    let w1764 = w1550 & 0x80000000u32;
+    let w1765 = w1764 ^ w1763;
+    let w1766 = w1759 ^ w1765;
+    let w1767 = w1105 ^ w1766;
+    let w1768 = w1767.rotate_left(7);
+    let w1769 = w1546 ^ w1768;
+    let w1770 = w1324 & w1769;
+    let w1771 = w1324 ^ w1769;
+    let w1772 = w1771 & 0x00000001u32;
+    let w1773 = w1770 & 0x00000001u32;
+    let w1774 = w1773.rotate_left(1);
+    let w1775 = w1771 & 0x00000002u32;
+    let w1776 = w1775 ^ w1774;
+    let w1777 = w1772 ^ w1776;
+    let w1778 = w1770 & 0x00000002u32;
+    let w1779 = w1775 & w1774;
+    let w1780 = w1778 ^ w1779;
+    let w1781 = w1780.rotate_left(1);
+    let w1782 = w1771 & 0x00000004u32;
+    let w1783 = w1782 ^ w1781;
+    let w1784 = w1777 ^ w1783;
+    let w1785 = w1770 & 0x00000004u32;
+    let w1786 = w1782 & w1781;
+    let w1787 = w1785 ^ w1786;
+    let w1788 = w1787.rotate_left(1);
+    let w1789 = w1771 & 0x00000008u32;
+    let w1790 = w1789 ^ w1788;
+    let w1791 = w1784 ^ w1790;
+    let w1792 = w1770 & 0x00000008u32;
+    let w1793 = w1789 & w1788;
+    let w1794 = w1792 ^ w1793;
+    let w1795 = w1794.rotate_left(1);
+    let w1796 = w1771 & 0x00000010u32;
+    let w1797 = w1796 ^ w1795;
+    let w1798 = w1791 ^ w1797;
+    let w1799 = w1770 & 0x00000010u32;
+    let w1800 = w1796 & w1795;
+    let w1801 = w1799 ^ w1800;
+    let w1802 = w1801.rotate_left(1);
+    let w1803 = w1771 & 0x00000020u32;
+    let w1804 = w1803 ^ w1802;
+    let w1805 = w1798 ^ w1804;
+    let w1806 = w1770 & 0x00000020u32;
+    let w1807 = w1803 & w1802;
+    let w1808 = w1806 ^ w1807;
+    let w1809 = w1808.rotate_left(1);
+    let w1810 = w1771 & 0x00000040u32;
+    let w1811 = w1810 ^ w1809;
+    let w1812 = w1805 ^ w1811;
+    let w1813 = w1770 & 0x00000040u32;
+    let w1814 = w1810 & w1809;
+    let w1815 = w1813 ^ w1814;
+    let w1816 = w1815.rotate_left(1);
+    let w1817 = w1771 & 0x00000080u32;
+    let w1818 = w1817 ^ w1816;
+    let w1819 = w1812 ^ w1818;
+    let w1820 = w1770 & 0x00000080u32;
+    let w1821 = w1817 & w1816;
+    let w1822 = w1820 ^ w1821;
+    let w1823 = w1822.rotate_left(1);
+    let w1824 = w1771 & 0x00000100u32;
+    let w1825 = w1824 ^ w1823;
+    let w1826 = w1819 ^ w1825;
+    let w1827 = w1770 & 0x00000100u32;
+    let w1828 = w1824 & w1823;
+    let w1829 = w1827 ^ w1828;
+    let w1830 = w1829.rotate_left(1);
+    let w1831 = w1771 & 0x00000200u32;
+    let w1832 = w1831 ^ w1830;
+    let w1833 = w1826 ^ w1832;
+    let w1834 = w1770 & 0x00000200u32;
+    let w1835 = w1831 & w1830;
+    let w1836 = w1834 ^ w1835;
+    let w1837 = w1836.rotate_left(1);
+    let w1838 = w1771 & 0x00000400u32;
+    let w1839 = w1838 ^ w1837;
+    let w1840 = w1833 ^ w1839;
+    let w1841 = w1770 & 0x00000400u32;
+    let w1842 = w1838 & w1837;
+    let w1843 = w1841 ^ w1842;
+    let w1844 = w1843.rotate_left(1);
+    let w1845 = w1771 & 0x00000800u32;
+    let w1846 = w1845 ^ w1844;
+    let w1847 = w1840 ^ w1846;
+    let w1848 = w1770 & 0x00000800u32;
+    let w1849 = w1845 & w1844;
+    let w1850 = w1848 ^ w1849;
+    let w1851 = w1850.rotate_left(1);
+    let w1852 = w1771 & 0x00001000u32;
+    let w1853 = w1852 ^ w1851;
+    let w1854 = w1847 ^ w1853;
+    let w1855 = w1770 & 0x00001000u32;
+    let w1856 = w1852 & w1851;
+    let w1857 = w1855 ^ w1856;
+    let w1858 = w1857.rotate_left(1);
+    let w1859 = w1771 & 0x00002000u32;
+    let w1860 = w1859 ^ w1858;
+    let w1861 = w1854 ^ w1860;
+    let w1862 = w1770 & 0x00002000u32;
+    let w1863 = w1859 & w1858;
+    let w1864 = w1862 ^ w1863;
+    let w1865 = w1864.rotate_left(1);
+    let w1866 = w1771 & 0x00004000u32;
+    let w1867 = w1866 ^ w1865;
+    let w1868 = w1861 ^ w1867;
+    let w1869 = w1770 & 0x00004000u32;
+    let w1870 = w1866 & w1865;
+    let w1871 = w1869 ^ w1870;
+    let w1872 = w1871.rotate_left(1);
+    let w1873 = w1771 & 0x00008000u32;
+    let w1874 = w1873 ^ w1872;
+    let w1875 = w1868 ^ w1874;
+    let w1876 = w1770 & 0x00008000u32;
+    let w1877 = w1873 & w1872;
+    let w1878 = w1876 ^ w1877;
+    let w1879 = w1878.rotate_left(1);
+    let w1880 = w1771 & 0x00010000u32;
+    let w1881 = w1880 ^ w1879;
+    let w1882 = w1875 ^ w1881;
+    let w1883 = w1770 & 0x00010000u32;
+    let w1884 = w1880 & w1879;
+    let w1885 = w1883 ^ w1884;
+    let w1886 = w1885.rotate_left(1);
+    let w1887 = w1771 & 0x00020000u32;
+    let w1888 = w1887 ^ w1886;
+    let w1889 = w1882 ^ w1888;
+    let w1890 = w1770 & 0x00020000u32;
+    let w1891 = w1887 & w1886;
+    let w1892 = w1890 ^ w1891;
+    let w1893 = w1892.rotate_left(1);
+    let w1894 = w1771 & 0x00040000u32;
+    let w1895 = w1894 ^ w1893;
+    let w1896 = w1889 ^ w1895;
+    let w1897 = w1770 & 0x00040000u32;
+    let w1898 = w1894 & w1893;
+    let w1899 = w1897 ^ w1898;
+    let w1900 = w1899.rotate_left(1);
+    let w1901 = w1771 & 0x00080000u32;
+    let w1902 = w1901 ^ w1900;
+    let w1903 = w1896 ^ w1902;
+    let w1904 = w1770 & 0x00080000u32;
+    let w1905 = w1901 & w1900;
+    let w1906 = w1904 ^ w1905;
+    let w1907 = w1906.rotate_left(1);
+    let w1908 = w1771 & 0x00100000u32;
+    let w1909 = w1908 ^ w1907;
+    let w1910 = w1903 ^ w1909;
+    let w1911 = w1770 & 0x00100000u32;
+    let w1912 = w1908 & w1907;
+    let w1913 = w1911 ^ w1912;
+    let w1914 = w1913.rotate_left(1);
+    let w1915 = w1771 & 0x00200000u32;
+    let w1916 = w1915 ^ w1914;
+    let w1917 = w1910 ^ w1916;
+    let w1918 = w1770 & 0x00200000u32;
+    let w1919 = w1915 & w1914;
+    let w1920 = w1918 ^ w1919;
+    let w1921 = w1920.rotate_left(1);
+    let w1922 = w1771 & 0x00400000u32;
+    let w1923 = w1922 ^ w1921;
+    let w1924 = w1917 ^ w1923;
+    let w1925 = w1770 & 0x00400000u32;
+    let w1926 = w1922 & w1921;
+    let w1927 = w1925 ^ w1926;
+    let w1928 = w1927.rotate_left(1);
+    let w1929 = w1771 & 0x00800000u32;
+    let w1930 = w1929 ^ w1928;
+    let w1931 = w1924 ^ w1930;
+    let w1932 = w1770 & 0x00800000u32;
+    let w1933 = w1929 & w1928;
+    let w1934 = w1932 ^ w1933;
+    let w1935 = w1934.rotate_left(1);
+    let w1936 = w1771 & 0x01000000u32;
+    let w1937 = w1936 ^ w1935;
+    let w1938 = w1931 ^ w1937;
+    let w1939 = w1770 & 0x01000000u32;
+    let w1940 = w1936 & w1935;
+    let w1941 = w1939 ^ w1940;
+    let w1942 = w1941.rotate_left(1);
+    let w1943 = w1771 & 0x02000000u32;
+    let w1944 = w1943 ^ w1942;
+    let w1945 = w1938 ^ w1944;
+    let w1946 = w1770 & 0x02000000u32;
+    let w1947 = w1943 & w1942;
+    let w1948 = w1946 ^ w1947;
+    let w1949 = w1948.rotate_left(1);
+    let w1950 = w1771 & 0x04000000u32;
+    let w1951 = w1950 ^ w1949;
+    let w1952 = w1945 ^ w1951;
+    let w1953 = w1770 & 0x04000000u32;
+    let w1954 = w1950 & w1949;
+    let w1955 = w1953 ^ w1954;
+    let w1956 = w1955.rotate_left(1);
+    let w1957 = w1771 & 0x08000000u32;
+    let w1958 = w1957 ^ w1956;
+    let w1959 = w1952 ^ w1958;
+    let w1960 = w1770 & 0x08000000u32;
+    let w1961 = w1957 & w1956;
+    let w1962 = w1960 ^ w1961;
+    let w1963 = w1962.rotate_left(1);
+    let w1964 = w1771 & 0x10000000u32;
+    let w1965 = w1964 ^ w1963;
+    let w1966 = w1959 ^ w1965;
+    let w1967 = w1770 & 0x10000000u32;
+    let w1968 = w1964 & w1963;
+    let w1969 = w1967 ^ w1968;
+    let w1970 = w1969.rotate_left(1);
+    let w1971 = w1771 & 0x20000000u32;
+    let w1972 = w1971 ^ w1970;
+    let w1973 = w1966 ^ w1972;
+    let w1974 = w1770 & 0x20000000u32;
+    let w1975 = w1971 & w1970;
+    let w1976 = w1974 ^ w1975;
+    let w1977 = w1976.rotate_left(1);
+    let w1978 = w1771 & 0x40000000u32;
+    let w1979 = w1978 ^ w1977;
+    let w1980 = w1973 ^ w1979;
+    let w1981 = w1770 & 0x40000000u32;
+    let w1982 = w1978 & w1977;
+    let w1983 = w1981 ^ w1982;
+    let w1984 = w1983.rotate_left(1);
+    let w1985 = w1771 & 0x80000000u32;
+    let w1986 = w1985 ^ w1984;
+    let w1987 = w1980 ^ w1986;
+    let w1988 = w1544 ^ w1766;
+    let w1989 = w1326 ^ w1548;
+    let w1990 = w1989 ^ w1987;
+    let w1991 = w1990.rotate_left(16);
+    let w1992 = w1988 & w1991;
+    let w1993 = w1988 ^ w1991;
+    let w1994 = w1993 & 0x00000001u32;
+    let w1995 = w1992 & 0x00000001u32;
+    let w1996 = w1995.rotate_left(1);
+    let w1997 = w1993 & 0x00000002u32;
+    let w1998 = w1997 ^ w1996;
+    let w1999 = w1994 ^ w1998;
+    let w2000 = w1992 & 0x00000002u32;
+    let w2001 = w1997 & w1996;
+    let w2002 = w2000 ^ w2001;
+    let w2003 = w2002.rotate_left(1);
+    let w2004 = w1993 & 0x00000004u32;
+    let w2005 = w2004 ^ w2003;
+    let w2006 = w1999 ^ w2005;
+    let w2007 = w1992 & 0x00000004u32;
+    let w2008 = w2004 & w2003;
+    let w2009 = w2007 ^ w2008;
+    let w2010 = w2009.rotate_left(1);
+    let w2011 = w1993 & 0x00000008u32;
+    let w2012 = w2011 ^ w2010;
+    let w2013 = w2006 ^ w2012;
+    let w2014 = w1992 & 0x00000008u32;
+    let w2015 = w2011 & w2010;
+    let w2016 = w2014 ^ w2015;
+    let w2017 = w2016.rotate_left(1);
+    let w2018 = w1993 & 0x00000010u32;
+    let w2019 = w2018 ^ w2017;
+    let w2020 = w2013 ^ w2019;
+    let w2021 = w1992 & 0x00000010u32;
+    let w2022 = w2018 & w2017;
+    let w2023 = w2021 ^ w2022;
+    let w2024 = w2023.rotate_left(1);
+    let w2025 = w1993 & 0x00000020u32;
+    let w2026 = w2025 ^ w2024;
+    let w2027 = w2020 ^ w2026;
+    let w2028 = w1992 & 0x00000020u32;
+    let w2029 = w2025 & w2024;
+    let w2030 = w2028 ^ w2029;
+    let w2031 = w2030.rotate_left(1);
+    let w2032 = w1993 & 0x00000040u32;
+    let w2033 = w2032 ^ w2031;
+    let w2034 = w2027 ^ w2033;
+    let w2035 = w1992 & 0x00000040u32;
+    let w2036 = w2032 & w2031;
+    let w2037 = w2035 ^ w2036;
+    let w2038 = w2037.rotate_left(1);
+    let w2039 = w1993 & 0x00000080u32;
+    let w2040 = w2039 ^ w2038;
+    let w2041 = w2034 ^ w2040;
+    let w2042 = w1992 & 0x00000080u32;
+    let w2043 = w2039 & w2038;
+    let w2044 = w2042 ^ w2043;
+    let w2045 = w2044.rotate_left(1);
+    let w2046 = w1993 & 0x00000100u32;
+    let w2047 = w2046 ^ w2045;
+    let w2048 = w2041 ^ w2047;
+    let w2049 = w1992 & 0x00000100u32;
+    let w2050 = w2046 & w2045;
+    let w2051 = w2049 ^ w2050;
+    let w2052 = w2051.rotate_left(1);
+    let w2053 = w1993 & 0x00000200u32;
+    let w2054 = w2053 ^ w2052;
+    let w2055 = w2048 ^ w2054;
+    let w2056 = w1992 & 0x00000200u32;
+    let w2057 = w2053 & w2052;
+    let w2058 = w2056 ^ w2057;
+    let w2059 = w2058.rotate_left(1);
+    let w2060 = w1993 & 0x00000400u32;
+    let w2061 = w2060 ^ w2059;
+    let w2062 = w2055 ^ w2061;
+    let w2063 = w1992 & 0x00000400u32;
+    let w2064 = w2060 & w2059;
+    let w2065 = w2063 ^ w2064;
+    let w2066 = w2065.rotate_left(1);
+    let w2067 = w1993 & 0x00000800u32;
+    let w2068 = w2067 ^ w2066;
+    let w2069 = w2062 ^ w2068;
+    let w2070 = w1992 & 0x00000800u32;
+    let w2071 = w2067 & w2066;
+    let w2072 = w2070 ^ w2071;
+    let w2073 = w2072.rotate_left(1);
+    let w2074 = w1993 & 0x00001000u32;
+    let w2075 = w2074 ^ w2073;
+    let w2076 = w2069 ^ w2075;
+    let w2077 = w1992 & 0x00001000u32;
+    let w2078 = w2074 & w2073;
+    let w2079 = w2077 ^ w2078;
+    let w2080 = w2079.rotate_left(1);
+    let w2081 = w1993 & 0x00002000u32;
+    let w2082 = w2081 ^ w2080;
+    let w2083 = w2076 ^ w2082;
+    let w2084 = w1992 & 0x00002000u32;
+    let w2085 = w2081 & w2080;
+    let w2086 = w2084 ^ w2085;
+    let w2087 = w2086.rotate_left(1);
+    let w2088 = w1993 & 0x00004000u32;
+    let w2089 = w2088 ^ w2087;
+    let w2090 = w2083 ^ w2089;
+    let w2091 = w1992 & 0x00004000u32;
+    let w2092 = w2088 & w2087;
+    let w2093 = w2091 ^ w2092;
+    let w2094 = w2093.rotate_left(1);
+    let w2095 = w1993 & 0x00008000u32;
+    let w2096 = w2095 ^ w2094;
+    let w2097 = w2090 ^ w2096;
+    let w2098 = w1992 & 0x00008000u32;
+    let w2099 = w2095 & w2094;
+    let w2100 = w2098 ^ w2099;
+    let w2101 = w2100.rotate_left(1);
+    let w2102 = w1993 & 0x00010000u32;
+    let w2103 = w2102 ^ w2101;
+    let w2104 = w2097 ^ w2103;
+    let w2105 = w1992 & 0x00010000u32;
+    let w2106 = w2102 & w2101;
+    let w2107 = w2105 ^ w2106;
+    let w2108 = w2107.rotate_left(1);
+    let w2109 = w1993 & 0x00020000u32;
+    let w2110 = w2109 ^ w2108;
+    let w2111 = w2104 ^ w2110;
+    let w2112 = w1992 & 0x00020000u32;
+    let w2113 = w2109 & w2108;
+    let w2114 = w2112 ^ w2113;
+    let w2115 = w2114.rotate_left(1);
+    let w2116 = w1993 & 0x00040000u32;
+    let w2117 = w2116 ^ w2115;
+    let w2118 = w2111 ^ w2117;
+    let w2119 = w1992 & 0x00040000u32;
+    let w2120 = w2116 & w2115;
+    let w2121 = w2119 ^ w2120;
+    let w2122 = w2121.rotate_left(1);
+    let w2123 = w1993 & 0x00080000u32;
+    let w2124 = w2123 ^ w2122;
+    let w2125 = w2118 ^ w2124;
+    let w2126 = w1992 & 0x00080000u32;
+    let w2127 = w2123 & w2122;
+    let w2128 = w2126 ^ w2127;
+    let w2129 = w2128.rotate_left(1);
+    let w2130 = w1993 & 0x00100000u32;
+    let w2131 = w2130 ^ w2129;
+    let w2132 = w2125 ^ w2131;
+    let w2133 = w1992 & 0x00100000u32;
+    let w2134 = w2130 & w2129;
+    let w2135 = w2133 ^ w2134;
+    let w2136 = w2135.rotate_left(1);
+    let w2137 = w1993 & 0x00200000u32;
+    let w2138 = w2137 ^ w2136;
+    let w2139 = w2132 ^ w2138;
+    let w2140 = w1992 & 0x00200000u32;
+    let w2141 = w2137 & w2136;
+    let w2142 = w2140 ^ w2141;
+    let w2143 = w2142.rotate_left(1);
+    let w2144 = w1993 & 0x00400000u32;
+    let w2145 = w2144 ^ w2143;
+    let w2146 = w2139 ^ w2145;
+    let w2147 = w1992 & 0x00400000u32;
+    let w2148 = w2144 & w2143;
+    let w2149 = w2147 ^ w2148;
+    let w2150 = w2149.rotate_left(1);
+    let w2151 = w1993 & 0x00800000u32;
+    let w2152 = w2151 ^ w2150;
+    let w2153 = w2146 ^ w2152;
+    let w2154 = w1992 & 0x00800000u32;
+    let w2155 = w2151 & w2150;
+    let w2156 = w2154 ^ w2155;
+    let w2157 = w2156.rotate_left(1);
+    let w2158 = w1993 & 0x01000000u32;
+    let w2159 = w2158 ^ w2157;
+    let w2160 = w2153 ^ w2159;
+    let w2161 = w1992 & 0x01000000u32;
+    let w2162 = w2158 & w2157;
+    let w2163 = w2161 ^ w2162;
+    let w2164 = w2163.rotate_left(1);
+    let w2165 = w1993 & 0x02000000u32;
+    let w2166 = w2165 ^ w2164;
+    let w2167 = w2160 ^ w2166;
+    let w2168 = w1992 & 0x02000000u32;
+    let w2169 = w2165 & w2164;
+    let w2170 = w2168 ^ w2169;
+    let w2171 = w2170.rotate_left(1);
+    let w2172 = w1993 & 0x04000000u32;
+    let w2173 = w2172 ^ w2171;
+    let w2174 = w2167 ^ w2173;
+    let w2175 = w1992 & 0x04000000u32;
+    let w2176 = w2172 & w2171;
+    let w2177 = w2175 ^ w2176;
+    let w2178 = w2177.rotate_left(1);
+    let w2179 = w1993 & 0x08000000u32;
+    let w2180 = w2179 ^ w2178;
+    let w2181 = w2174 ^ w2180;
+    let w2182 = w1992 & 0x08000000u32;
+    let w2183 = w2179 & w2178;
+    let w2184 = w2182 ^ w2183;
+    let w2185 = w2184.rotate_left(1);
+    let w2186 = w1993 & 0x10000000u32;
+    let w2187 = w2186 ^ w2185;
+    let w2188 = w2181 ^ w2187;
+    let w2189 = w1992 & 0x10000000u32;
+    let w2190 = w2186 & w2185;
+    let w2191 = w2189 ^ w2190;
+    let w2192 = w2191.rotate_left(1);
+    let w2193 = w1993 & 0x20000000u32;
+    let w2194 = w2193 ^ w2192;
+    let w2195 = w2188 ^ w2194;
+    let w2196 = w1992 & 0x20000000u32;
+    let w2197 = w2193 & w2192;
+    let w2198 = w2196 ^ w2197;
+    let w2199 = w2198.rotate_left(1);
+    let w2200 = w1993 & 0x40000000u32;
+    let w2201 = w2200 ^ w2199;
+    let w2202 = w2195 ^ w2201;
+    let w2203 = w1992 & 0x40000000u32;
+    let w2204 = w2200 & w2199;
+    let w2205 = w2203 ^ w2204;
+    let w2206 = w2205.rotate_left(1);
+    let w2207 = w1993 & 0x80000000u32;
+    let w2208 = w2207 ^ w2206;
+    let w2209 = w2202 ^ w2208;
+    let w2210 = w1769 ^ w2209;
+    let w2211 = w2210.rotate_left(12);
+    let w2212 = w1987 & w2211;
+    let w2213 = w1987 ^ w2211;
+    let w2214 = w2213 & 0x00000001u32;
+    let w2215 = w2212 & 0x00000001u32;
+    let w2216 = w2215.rotate_left(1);
+    let w2217 = w2213 & 0x00000002u32;
+    let w2218 = w2217 ^ w2216;
+    let w2219 = w2214 ^ w2218;
+    let w2220 = w2212 & 0x00000002u32;
+    let w2221 = w2217 & w2216;
+    let w2222 = w2220 ^ w2221;
+    let w2223 = w2222.rotate_left(1);
+    let w2224 = w2213 & 0x00000004u32;
+    let w2225 = w2224 ^ w2223;
+    let w2226 = w2219 ^ w2225;
+    let w2227 = w2212 & 0x00000004u32;
+    let w2228 = w2224 & w2223;
+    let w2229 = w2227 ^ w2228;
+    let w2230 = w2229.rotate_left(1);
+    let w2231 = w2213 & 0x00000008u32;
+    let w2232 = w2231 ^ w2230;
+    let w2233 = w2226 ^ w2232;
+    let w2234 = w2212 & 0x00000008u32;
+    let w2235 = w2231 & w2230;
+    let w2236 = w2234 ^ w2235;
+    let w2237 = w2236.rotate_left(1);
+    let w2238 = w2213 & 0x00000010u32;
+    let w2239 = w2238 ^ w2237;
+    let w2240 = w2233 ^ w2239;
+    let w2241 = w2212 & 0x00000010u32;
+    let w2242 = w2238 & w2237;
+    let w2243 = w2241 ^ w2242;
+    let w2244 = w2243.rotate_left(1);
+    let w2245 = w2213 & 0x00000020u32;
+    let w2246 = w2245 ^ w2244;
+    let w2247 = w2240 ^ w2246;
+    let w2248 = w2212 & 0x00000020u32;
+    let w2249 = w2245 & w2244;
+    let w2250 = w2248 ^ w2249;
+    let w2251 = w2250.rotate_left(1);
+    let w2252 = w2213 & 0x00000040u32;
+    let w2253 = w2252 ^ w2251;
+    let w2254 = w2247 ^ w2253;
+    let w2255 = w2212 & 0x00000040u32;
+    let w2256 = w2252 & w2251;
+    let w2257 = w2255 ^ w2256;
+    let w2258 = w2257.rotate_left(1);
+    let w2259 = w2213 & 0x00000080u32;
+    let w2260 = w2259 ^ w2258;
+    let w2261 = w2254 ^ w2260;
+    let w2262 = w2212 & 0x00000080u32;
+    let w2263 = w2259 & w2258;
+    let w2264 = w2262 ^ w2263;
+    let w2265 = w2264.rotate_left(1);
+    let w2266 = w2213 & 0x00000100u32;
+    let w2267 = w2266 ^ w2265;
+    let w2268 = w2261 ^ w2267;
+    let w2269 = w2212 & 0x00000100u32;
+    let w2270 = w2266 & w2265;
+    let w2271 = w2269 ^ w2270;
+    let w2272 = w2271.rotate_left(1);
+    let w2273 = w2213 & 0x00000200u32;
+    let w2274 = w2273 ^ w2272;
+    let w2275 = w2268 ^ w2274;
+    let w2276 = w2212 & 0x00000200u32;
+    let w2277 = w2273 & w2272;
+    let w2278 = w2276 ^ w2277;
+    let w2279 = w2278.rotate_left(1);
+    let w2280 = w2213 & 0x00000400u32;
+    let w2281 = w2280 ^ w2279;
+    let w2282 = w2275 ^ w2281;
+    let w2283 = w2212 & 0x00000400u32;
+    let w2284 = w2280 & w2279;
+    let w2285 = w2283 ^ w2284;
+    let w2286 = w2285.rotate_left(1);
+    let w2287 = w2213 & 0x00000800u32;
+    let w2288 = w2287 ^ w2286;
+    let w2289 = w2282 ^ w2288;
+    let w2290 = w2212 & 0x00000800u32;
+    let w2291 = w2287 & w2286;
+    let w2292 = w2290 ^ w2291;
+    let w2293 = w2292.rotate_left(1);
+    let w2294 = w2213 & 0x00001000u32;
+    let w2295 = w2294 ^ w2293;
+    let w2296 = w2289 ^ w2295;
+    let w2297 = w2212 & 0x00001000u32;
+    let w2298 = w2294 & w2293;
+    let w2299 = w2297 ^ w2298;
+    let w2300 = w2299.rotate_left(1);
+    let w2301 = w2213 & 0x00002000u32;
+    let w2302 = w2301 ^ w2300;
+    let w2303 = w2296 ^ w2302;
+    let w2304 = w2212 & 0x00002000u32;
+    let w2305 = w2301 & w2300;
+    let w2306 = w2304 ^ w2305;
+    let w2307 = w2306.rotate_left(1);
+    let w2308 = w2213 & 0x00004000u32;
+    let w2309 = w2308 ^ w2307;
+    let w2310 = w2303 ^ w2309;
+    let w2311 = w2212 & 0x00004000u32;
+    let w2312 = w2308 & w2307;
+    let w2313 = w2311 ^ w2312;
+    let w2314 = w2313.rotate_left(1);
+    let w2315 = w2213 & 0x00008000u32;
+    let w2316 = w2315 ^ w2314;
+    let w2317 = w2310 ^ w2316;
+    let w2318 = w2212 & 0x00008000u32;
+    let w2319 = w2315 & w2314;
+    let w2320 = w2318 ^ w2319;
+    let w2321 = w2320.rotate_left(1);
+    let w2322 = w2213 & 0x00010000u32;
+    let w2323 = w2322 ^ w2321;
+    let w2324 = w2317 ^ w2323;
+    let w2325 = w2212 & 0x00010000u32;
+    let w2326 = w2322 & w2321;
+    let w2327 = w2325 ^ w2326;
+    let w2328 = w2327.rotate_left(1);
+    let w2329 = w2213 & 0x00020000u32;
+    let w2330 = w2329 ^ w2328;
+    let w2331 = w2324 ^ w2330;
+    let w2332 = w2212 & 0x00020000u32;
+    let w2333 = w2329 & w2328;
+    let w2334 = w2332 ^ w2333;
+    let w2335 = w2334.rotate_left(1);
+    let w2336 = w2213 & 0x00040000u32;
+    let w2337 = w2336 ^ w2335;
+    let w2338 = w2331 ^ w2337;
+    let w2339 = w2212 & 0x00040000u32;
+    let w2340 = w2336 & w2335;
+    let w2341 = w2339 ^ w2340;
+    let w2342 = w2341.rotate_left(1);
+    let w2343 = w2213 & 0x00080000u32;
+    let w2344 = w2343 ^ w2342;
+    let w2345 = w2338 ^ w2344;
+    let w2346 = w2212 & 0x00080000u32;
+    let w2347 = w2343 & w2342;
+    let w2348 = w2346 ^ w2347;
+    let w2349 = w2348.rotate_left(1);
+    let w2350 = w2213 & 0x00100000u32;
+    let w2351 = w2350 ^ w2349;
+    let w2352 = w2345 ^ w2351;
+    let w2353 = w2212 & 0x00100000u32;
+    let w2354 = w2350 & w2349;
+    let w2355 = w2353 ^ w2354;
+    let w2356 = w2355.rotate_left(1);
+    let w2357 = w2213 & 0x00200000u32;
+    let w2358 = w2357 ^ w2356;
+    let w2359 = w2352 ^ w2358;
+    let w2360 = w2212 & 0x00200000u32;
+    let w2361 = w2357 & w2356;
+    let w2362 = w2360 ^ w2361;
+    let w2363 = w2362.rotate_left(1);
+    let w2364 = w2213 & 0x00400000u32;
+    let w2365 = w2364 ^ w2363;
+    let w2366 = w2359 ^ w2365;
+    let w2367 = w2212 & 0x00400000u32;
+    let w2368 = w2364 & w2363;
+    let w2369 = w2367 ^ w2368;
+    let w2370 = w2369.rotate_left(1);
+    let w2371 = w2213 & 0x00800000u32;
+    let w2372 = w2371 ^ w2370;
+    let w2373 = w2366 ^ w2372;
+    let w2374 = w2212 & 0x00800000u32;
+    let w2375 = w2371 & w2370;
+    let w2376 = w2374 ^ w2375;
+    let w2377 = w2376.rotate_left(1);
+    let w2378 = w2213 & 0x01000000u32;
+    let w2379 = w2378 ^ w2377;
+    let w2380 = w2373 ^ w2379;
+    let w2381 = w2212 & 0x01000000u32;
+    let w2382 = w2378 & w2377;
+    let w2383 = w2381 ^ w2382;
+    let w2384 = w2383.rotate_left(1);
+    let w2385 = w2213 & 0x02000000u32;
+    let w2386 = w2385 ^ w2384;
+    let w2387 = w2380 ^ w2386;
+    let w2388 = w2212 & 0x02000000u32;
+    let w2389 = w2385 & w2384;
+    let w2390 = w2388 ^ w2389;
+    let w2391 = w2390.rotate_left(1);
+    let w2392 = w2213 & 0x04000000u32;
+    let w2393 = w2392 ^ w2391;
+    let w2394 = w2387 ^ w2393;
+    let w2395 = w2212 & 0x04000000u32;
+    let w2396 = w2392 & w2391;
+    let w2397 = w2395 ^ w2396;
+    let w2398 = w2397.rotate_left(1);
+    let w2399 = w2213 & 0x08000000u32;
+    let w2400 = w2399 ^ w2398;
+    let w2401 = w2394 ^ w2400;
+    let w2402 = w2212 & 0x08000000u32;
+    let w2403 = w2399 & w2398;
+    let w2404 = w2402 ^ w2403;
+    let w2405 = w2404.rotate_left(1);
+    let w2406 = w2213 & 0x10000000u32;
+    let w2407 = w2406 ^ w2405;
+    let w2408 = w2401 ^ w2407;
+    let w2409 = w2212 & 0x10000000u32;
+    let w2410 = w2406 & w2405;
+    let w2411 = w2409 ^ w2410;
+    let w2412 = w2411.rotate_left(1);
+    let w2413 = w2213 & 0x20000000u32;
+    let w2414 = w2413 ^ w2412;
+    let w2415 = w2408 ^ w2414;
+    let w2416 = w2212 & 0x20000000u32;
+    let w2417 = w2413 & w2412;
+    let w2418 = w2416 ^ w2417;
+    let w2419 = w2418.rotate_left(1);
+    let w2420 = w2213 & 0x40000000u32;
+    let w2421 = w2420 ^ w2419;
+    let w2422 = w2415 ^ w2421;
+    let w2423 = w2212 & 0x40000000u32;
+    let w2424 = w2420 & w2419;
+    let w2425 = w2423 ^ w2424;
+    let w2426 = w2425.rotate_left(1);
+    let w2427 = w2213 & 0x80000000u32;
+    let w2428 = w2427 ^ w2426;
+    let w2429 = w2422 ^ w2428;
+    let w2430 = w1991 ^ w2429;
+    let w2431 = w2430.rotate_left(8);
+    let w2432 = w2209 & w2431;
+    let w2433 = w2209 ^ w2431;
+    let w2434 = w2433 & 0x00000001u32;
+    let w2435 = w2432 & 0x00000001u32;
+    let w2436 = w2435.rotate_left(1);
+    let w2437 = w2433 & 0x00000002u32;
+    let w2438 = w2437 ^ w2436;
+    let w2439 = w2434 ^ w2438;
+    let w2440 = w2432 & 0x00000002u32;
+    let w2441 = w2437 & w2436;
+    let w2442 = w2440 ^ w2441;
+    let w2443 = w2442.rotate_left(1);
+    let w2444 = w2433 & 0x00000004u32;
+    let w2445 = w2444 ^ w2443;
+    let w2446 = w2439 ^ w2445;
+    let w2447 = w2432 & 0x00000004u32;
+    let w2448 = w2444 & w2443;
+    let w2449 = w2447 ^ w2448;
+    let w2450 = w2449.rotate_left(1);
+    let w2451 = w2433 & 0x00000008u32;
+    let w2452 = w2451 ^ w2450;
+    let w2453 = w2446 ^ w2452;
+    let w2454 = w2432 & 0x00000008u32;
+    let w2455 = w2451 & w2450;
+    let w2456 = w2454 ^ w2455;
+    let w2457 = w2456.rotate_left(1);
+    let w2458 = w2433 & 0x00000010u32;
+    let w2459 = w2458 ^ w2457;
+    let w2460 = w2453 ^ w2459;
+    let w2461 = w2432 & 0x00000010u32;
+    let w2462 = w2458 & w2457;
+    let w2463 = w2461 ^ w2462;
+    let w2464 = w2463.rotate_left(1);
+    let w2465 = w2433 & 0x00000020u32;
+    let w2466 = w2465 ^ w2464;
+    let w2467 = w2460 ^ w2466;
+    let w2468 = w2432 & 0x00000020u32;
+    let w2469 = w2465 & w2464;
+    let w2470 = w2468 ^ w2469;
+    let w2471 = w2470.rotate_left(1);
+    let w2472 = w2433 & 0x00000040u32;
+    let w2473 = w2472 ^ w2471;
+    let w2474 = w2467 ^ w2473;
+    let w2475 = w2432 & 0x00000040u32;
+    let w2476 = w2472 & w2471;
+    let w2477 = w2475 ^ w2476;
+    let w2478 = w2477.rotate_left(1);
+    let w2479 = w2433 & 0x00000080u32;
+    let w2480 = w2479 ^ w2478;
+    let w2481 = w2474 ^ w2480;
+    let w2482 = w2432 & 0x00000080u32;
+    let w2483 = w2479 & w2478;
+    let w2484 = w2482 ^ w2483;
+    let w2485 = w2484.rotate_left(1);
+    let w2486 = w2433 & 0x00000100u32;
+    let w2487 = w2486 ^ w2485;
+    let w2488 = w2481 ^ w2487;
+    let w2489 = w2432 & 0x00000100u32;
+    let w2490 = w2486 & w2485;
+    let w2491 = w2489 ^ w2490;
+    let w2492 = w2491.rotate_left(1);
+    let w2493 = w2433 & 0x00000200u32;
+    let w2494 = w2493 ^ w2492;
+    let w2495 = w2488 ^ w2494;
+    let w2496 = w2432 & 0x00000200u32;
+    let w2497 = w2493 & w2492;
+    let w2498 = w2496 ^ w2497;
+    let w2499 = w2498.rotate_left(1);
+    let w2500 = w2433 & 0x00000400u32;
+    let w2501 = w2500 ^ w2499;
+    let w2502 = w2495 ^ w2501;
+    let w2503 = w2432 & 0x00000400u32;
+    let w2504 = w2500 & w2499;
+    let w2505 = w2503 ^ w2504;
+    let w2506 = w2505.rotate_left(1);
+    let w2507 = w2433 & 0x00000800u32;
+    let w2508 = w2507 ^ w2506;
+    let w2509 = w2502 ^ w2508;
+    let w2510 = w2432 & 0x00000800u32;
+    let w2511 = w2507 & w2506;
+    let w2512 = w2510 ^ w2511;
+    let w2513 = w2512.rotate_left(1);
+    let w2514 = w2433 & 0x00001000u32;
+    let w2515 = w2514 ^ w2513;
+    let w2516 = w2509 ^ w2515;
+    let w2517 = w2432 & 0x00001000u32;
+    let w2518 = w2514 & w2513;
+    let w2519 = w2517 ^ w2518;
+    let w2520 = w2519.rotate_left(1);
+    let w2521 = w2433 & 0x00002000u32;
+    let w2522 = w2521 ^ w2520;
+    let w2523 = w2516 ^ w2522;
+    let w2524 = w2432 & 0x00002000u32;
+    let w2525 = w2521 & w2520;
+    let w2526 = w2524 ^ w2525;
+    let w2527 = w2526.rotate_left(1);
+    let w2528 = w2433 & 0x00004000u32;
+    let w2529 = w2528 ^ w2527;
+    let w2530 = w2523 ^ w2529;
+    let w2531 = w2432 & 0x00004000u32;
+    let w2532 = w2528 & w2527;
+    let w2533 = w2531 ^ w2532;
+    let w2534 = w2533.rotate_left(1);
+    let w2535 = w2433 & 0x00008000u32;
+    let w2536 = w2535 ^ w2534;
+    let w2537 = w2530 ^ w2536;
+    let w2538 = w2432 & 0x00008000u32;
+    let w2539 = w2535 & w2534;
+    let w2540 = w2538 ^ w2539;
+    let w2541 = w2540.rotate_left(1);
+    let w2542 = w2433 & 0x00010000u32;
+    let w2543 = w2542 ^ w2541;
+    let w2544 = w2537 ^ w2543;
+    let w2545 = w2432 & 0x00010000u32;
+    let w2546 = w2542 & w2541;
+    let w2547 = w2545 ^ w2546;
+    let w2548 = w2547.rotate_left(1);
+    let w2549 = w2433 & 0x00020000u32;
+    let w2550 = w2549 ^ w2548;
+    let w2551 = w2544 ^ w2550;
+    let w2552 = w2432 & 0x00020000u32;
+    let w2553 = w2549 & w2548;
+    let w2554 = w2552 ^ w2553;
+    let w2555 = w2554.rotate_left(1);
+    let w2556 = w2433 & 0x00040000u32;
+    let w2557 = w2556 ^ w2555;
+    let w2558 = w2551 ^ w2557;
+    let w2559 = w2432 & 0x00040000u32;
+    let w2560 = w2556 & w2555;
+    let w2561 = w2559 ^ w2560;
+    let w2562 = w2561.rotate_left(1);
+    let w2563 = w2433 & 0x00080000u32;
+    let w2564 = w2563 ^ w2562;
+    let w2565 = w2558 ^ w2564;
+    let w2566 = w2432 & 0x00080000u32;
+    let w2567 = w2563 & w2562;
+    let w2568 = w2566 ^ w2567;
+    let w2569 = w2568.rotate_left(1);
+    let w2570 = w2433 & 0x00100000u32;
+    let w2571 = w2570 ^ w2569;
+    let w2572 = w2565 ^ w2571;
+    let w2573 = w2432 & 0x00100000u32;
+    let w2574 = w2570 & w2569;
+    let w2575 = w2573 ^ w2574;
+    let w2576 = w2575.rotate_left(1);
+    let w2577 = w2433 & 0x00200000u32;
+    let w2578 = w2577 ^ w2576;
+    let w2579 = w2572 ^ w2578;
+    let w2580 = w2432 & 0x00200000u32;
+    let w2581 = w2577 & w2576;
+    let w2582 = w2580 ^ w2581;
+    let w2583 = w2582.rotate_left(1);
+    let w2584 = w2433 & 0x00400000u32;
+    let w2585 = w2584 ^ w2583;
+    let w2586 = w2579 ^ w2585;
+    let w2587 = w2432 & 0x00400000u32;
+    let w2588 = w2584 & w2583;
+    let w2589 = w2587 ^ w2588;
+    let w2590 = w2589.rotate_left(1);
+    let w2591 = w2433 & 0x00800000u32;
+    let w2592 = w2591 ^ w2590;
+    let w2593 = w2586 ^ w2592;
+    let w2594 = w2432 & 0x00800000u32;
+    let w2595 = w2591 & w2590;
+    let w2596 = w2594 ^ w2595;
+    let w2597 = w2596.rotate_left(1);
+    let w2598 = w2433 & 0x01000000u32;
+    let w2599 = w2598 ^ w2597;
+    let w2600 = w2593 ^ w2599;
+    let w2601 = w2432 & 0x01000000u32;
+    let w2602 = w2598 & w2597;
+    let w2603 = w2601 ^ w2602;
+    let w2604 = w2603.rotate_left(1);
+    let w2605 = w2433 & 0x02000000u32;
+    let w2606 = w2605 ^ w2604;
+    let w2607 = w2600 ^ w2606;
+    let w2608 = w2432 & 0x02000000u32;
+    let w2609 = w2605 & w2604;
+    let w2610 = w2608 ^ w2609;
+    let w2611 = w2610.rotate_left(1);
+    let w2612 = w2433 & 0x04000000u32;
+    let w2613 = w2612 ^ w2611;
+    let w2614 = w2607 ^ w2613;
+    let w2615 = w2432 & 0x04000000u32;
+    let w2616 = w2612 & w2611;
+    let w2617 = w2615 ^ w2616;
+    let w2618 = w2617.rotate_left(1);
+    let w2619 = w2433 & 0x08000000u32;
+    let w2620 = w2619 ^ w2618;
+    let w2621 = w2614 ^ w2620;
+    let w2622 = w2432 & 0x08000000u32;
+    let w2623 = w2619 & w2618;
+    let w2624 = w2622 ^ w2623;
+    let w2625 = w2624.rotate_left(1);
+    let w2626 = w2433 & 0x10000000u32;
+    let w2627 = w2626 ^ w2625;
+    let w2628 = w2621 ^ w2627;
+    let w2629 = w2432 & 0x10000000u32;
+    let w2630 = w2626 & w2625;
+    let w2631 = w2629 ^ w2630;
+    let w2632 = w2631.rotate_left(1);
+    let w2633 = w2433 & 0x20000000u32;
+    let w2634 = w2633 ^ w2632;
+    let w2635 = w2628 ^ w2634;
+    let w2636 = w2432 & 0x20000000u32;
+    let w2637 = w2633 & w2632;
+    let w2638 = w2636 ^ w2637;
+    let w2639 = w2638.rotate_left(1);
+    let w2640 = w2433 & 0x40000000u32;
+    let w2641 = w2640 ^ w2639;
+    let w2642 = w2635 ^ w2641;
+    let w2643 = w2432 & 0x40000000u32;
+    let w2644 = w2640 & w2639;
+    let w2645 = w2643 ^ w2644;
+    let w2646 = w2645.rotate_left(1);
+    let w2647 = w2433 & 0x80000000u32;
+    let w2648 = w2647 ^ w2646;
+    let w2649 = w2642 ^ w2648;
+    let w2650 = w2211 ^ w2649;
+    let w2651 = w2650.rotate_left(7);
+    let w2652 = w2429 ^ w2651;
+    let w2653 = w2649 ^ w2431;
+    let w2654 = w2652 ^ w2653;
+    w2654
+}
@@ -0,0 +1,289 @@
+// Generated by xorpl — do not edit manually.
+pub const INPUT_ARITY: usize = 2;
+
+pub fn verify(digest: &[u8; 32], inputs: &[u32]) -> Option<u32> {
+    let &[i0, i1] = inputs else { return None };
+    match *digest {
+        or_rotl_demo::EXPR_DIGEST => Some(or_rotl_demo::or_rotl_demo(i0, i1)),
+        add32_demo::EXPR_DIGEST => Some(add32_demo::add32_demo(i0, i1)),
+        or_rotl_mux_decoy::EXPR_DIGEST => Some(or_rotl_mux_decoy::or_rotl_mux_decoy(i0, i1)),
+        _ => None,
+    }
+}
+
+pub mod or_rotl_demo {
+    pub const EXPR_DIGEST: [u8; 32] = [
+        0x2c, 0x78, 0x66, 0x85, 0x7a, 0xce, 0x2e, 0x6a,
+        0x4f, 0xc4, 0xbf, 0xf6, 0x51, 0xa1, 0x56, 0xb7,
+        0x9c, 0x7c, 0x30, 0xe0, 0x28, 0x84, 0xd1, 0x40,
+        0x42, 0xb1, 0xca, 0x93, 0xc4, 0xcd, 0x72, 0x37,
+    ];
+    pub fn or_rotl_demo(input_a: u32, input_b: u32) -> u32 {
+        let w0 = input_a;
+        let w1 = input_b;
+        let w2 = w0 ^ w1;
+        let w3 = w0 & w1;
+        let w4 = w2 ^ w3;
+        let w5 = 0x9e3779b9u32;
+        let w6 = w4 ^ w5;
+        let w7 = w6.rotate_left(5);
+        w7
+    }
+}
+
+pub mod add32_demo {
+    pub const EXPR_DIGEST: [u8; 32] = [
+        0x7c, 0x70, 0x31, 0x08, 0x15, 0x5a, 0x8c, 0x7d,
+        0xba, 0x21, 0xbd, 0xa0, 0xa2, 0x03, 0x28, 0xb4,
+        0x49, 0x91, 0x81, 0x38, 0x43, 0x0c, 0xb3, 0xff,
+        0xc6, 0xfe, 0x7b, 0xcb, 0x12, 0xed, 0x39, 0x3d,
+    ];
+    pub fn add32_demo(input_a: u32, input_b: u32) -> u32 {
+        let w0 = input_a;
+        let w1 = input_b;
+        let w2 = w0 & w1;
+        let w3 = w0 ^ w1;
+        let w4 = w3 & 0x00000001u32;
+        let w5 = w2 & 0x00000001u32;
+        let w6 = w5.rotate_left(1);
+        let w7 = w3 & 0x00000002u32;
+        let w8 = w7 ^ w6;
+        let w9 = w4 ^ w8;
+        let w10 = w2 & 0x00000002u32;
+        let w11 = w7 & w6;
+        let w12 = w10 ^ w11;
+        let w13 = w12.rotate_left(1);
+        let w14 = w3 & 0x00000004u32;
+        let w15 = w14 ^ w13;
+        let w16 = w9 ^ w15;
+        let w17 = w2 & 0x00000004u32;
+        let w18 = w14 & w13;
+        let w19 = w17 ^ w18;
+        let w20 = w19.rotate_left(1);
+        let w21 = w3 & 0x00000008u32;
+        let w22 = w21 ^ w20;
+        let w23 = w16 ^ w22;
+        let w24 = w2 & 0x00000008u32;
+        let w25 = w21 & w20;
+        let w26 = w24 ^ w25;
+        let w27 = w26.rotate_left(1);
+        let w28 = w3 & 0x00000010u32;
+        let w29 = w28 ^ w27;
+        let w30 = w23 ^ w29;
+        let w31 = w2 & 0x00000010u32;
+        let w32 = w28 & w27;
+        let w33 = w31 ^ w32;
+        let w34 = w33.rotate_left(1);
+        let w35 = w3 & 0x00000020u32;
+        let w36 = w35 ^ w34;
+        let w37 = w30 ^ w36;
+        let w38 = w2 & 0x00000020u32;
+        let w39 = w35 & w34;
+        let w40 = w38 ^ w39;
+        let w41 = w40.rotate_left(1);
+        let w42 = w3 & 0x00000040u32;
+        let w43 = w42 ^ w41;
+        let w44 = w37 ^ w43;
+        let w45 = w2 & 0x00000040u32;
+        let w46 = w42 & w41;
+        let w47 = w45 ^ w46;
+        let w48 = w47.rotate_left(1);
+        let w49 = w3 & 0x00000080u32;
+        let w50 = w49 ^ w48;
+        let w51 = w44 ^ w50;
+        let w52 = w2 & 0x00000080u32;
+        let w53 = w49 & w48;
+        let w54 = w52 ^ w53;
+        let w55 = w54.rotate_left(1);
+        let w56 = w3 & 0x00000100u32;
+        let w57 = w56 ^ w55;
+        let w58 = w51 ^ w57;
+        let w59 = w2 & 0x00000100u32;
+        let w60 = w56 & w55;
+        let w61 = w59 ^ w60;
+        let w62 = w61.rotate_left(1);
+        let w63 = w3 & 0x00000200u32;
+        let w64 = w63 ^ w62;
+        let w65 = w58 ^ w64;
+        let w66 = w2 & 0x00000200u32;
+        let w67 = w63 & w62;
+        let w68 = w66 ^ w67;
+        let w69 = w68.rotate_left(1);
+        let w70 = w3 & 0x00000400u32;
+        let w71 = w70 ^ w69;
+        let w72 = w65 ^ w71;
+        let w73 = w2 & 0x00000400u32;
+        let w74 = w70 & w69;
+        let w75 = w73 ^ w74;
+        let w76 = w75.rotate_left(1);
+        let w77 = w3 & 0x00000800u32;
+        let w78 = w77 ^ w76;
+        let w79 = w72 ^ w78;
+        let w80 = w2 & 0x00000800u32;
+        let w81 = w77 & w76;
+        let w82 = w80 ^ w81;
+        let w83 = w82.rotate_left(1);
+        let w84 = w3 & 0x00001000u32;
+        let w85 = w84 ^ w83;
+        let w86 = w79 ^ w85;
+        let w87 = w2 & 0x00001000u32;
+        let w88 = w84 & w83;
+        let w89 = w87 ^ w88;
+        let w90 = w89.rotate_left(1);
+        let w91 = w3 & 0x00002000u32;
+        let w92 = w91 ^ w90;
+        let w93 = w86 ^ w92;
+        let w94 = w2 & 0x00002000u32;
+        let w95 = w91 & w90;
+        let w96 = w94 ^ w95;
+        let w97 = w96.rotate_left(1);
+        let w98 = w3 & 0x00004000u32;
+        let w99 = w98 ^ w97;
+        let w100 = w93 ^ w99;
+        let w101 = w2 & 0x00004000u32;
+        let w102 = w98 & w97;
+        let w103 = w101 ^ w102;
+        let w104 = w103.rotate_left(1);
+        let w105 = w3 & 0x00008000u32;
+        let w106 = w105 ^ w104;
+        let w107 = w100 ^ w106;
+        let w108 = w2 & 0x00008000u32;
+        let w109 = w105 & w104;
+        let w110 = w108 ^ w109;
+        let w111 = w110.rotate_left(1);
+        let w112 = w3 & 0x00010000u32;
+        let w113 = w112 ^ w111;
+        let w114 = w107 ^ w113;
+        let w115 = w2 & 0x00010000u32;
+        let w116 = w112 & w111;
+        let w117 = w115 ^ w116;
+        let w118 = w117.rotate_left(1);
+        let w119 = w3 & 0x00020000u32;
+        let w120 = w119 ^ w118;
+        let w121 = w114 ^ w120;
+        let w122 = w2 & 0x00020000u32;
+        let w123 = w119 & w118;
+        let w124 = w122 ^ w123;
+        let w125 = w124.rotate_left(1);
+        let w126 = w3 & 0x00040000u32;
+        let w127 = w126 ^ w125;
+        let w128 = w121 ^ w127;
+        let w129 = w2 & 0x00040000u32;
+        let w130 = w126 & w125;
+        let w131 = w129 ^ w130;
+        let w132 = w131.rotate_left(1);
+        let w133 = w3 & 0x00080000u32;
+        let w134 = w133 ^ w132;
+        let w135 = w128 ^ w134;
+        let w136 = w2 & 0x00080000u32;
+        let w137 = w133 & w132;
+        let w138 = w136 ^ w137;
+        let w139 = w138.rotate_left(1);
+        let w140 = w3 & 0x00100000u32;
+        let w141 = w140 ^ w139;
+        let w142 = w135 ^ w141;
+        let w143 = w2 & 0x00100000u32;
+        let w144 = w140 & w139;
+        let w145 = w143 ^ w144;
+        let w146 = w145.rotate_left(1);
+        let w147 = w3 & 0x00200000u32;
+        let w148 = w147 ^ w146;
+        let w149 = w142 ^ w148;
+        let w150 = w2 & 0x00200000u32;
+        let w151 = w147 & w146;
+        let w152 = w150 ^ w151;
+        let w153 = w152.rotate_left(1);
+        let w154 = w3 & 0x00400000u32;
+        let w155 = w154 ^ w153;
+        let w156 = w149 ^ w155;
+        let w157 = w2 & 0x00400000u32;
+        let w158 = w154 & w153;
+        let w159 = w157 ^ w158;
+        let w160 = w159.rotate_left(1);
+        let w161 = w3 & 0x00800000u32;
+        let w162 = w161 ^ w160;
+        let w163 = w156 ^ w162;
+        let w164 = w2 & 0x00800000u32;
+        let w165 = w161 & w160;
+        let w166 = w164 ^ w165;
+        let w167 = w166.rotate_left(1);
+        let w168 = w3 & 0x01000000u32;
+        let w169 = w168 ^ w167;
+        let w170 = w163 ^ w169;
+        let w171 = w2 & 0x01000000u32;
+        let w172 = w168 & w167;
+        let w173 = w171 ^ w172;
+        let w174 = w173.rotate_left(1);
+        let w175 = w3 & 0x02000000u32;
+        let w176 = w175 ^ w174;
+        let w177 = w170 ^ w176;
+        let w178 = w2 & 0x02000000u32;
+        let w179 = w175 & w174;
+        let w180 = w178 ^ w179;
+        let w181 = w180.rotate_left(1);
+        let w182 = w3 & 0x04000000u32;
+        let w183 = w182 ^ w181;
+        let w184 = w177 ^ w183;
+        let w185 = w2 & 0x04000000u32;
+        let w186 = w182 & w181;
+        let w187 = w185 ^ w186;
+        let w188 = w187.rotate_left(1);
+        let w189 = w3 & 0x08000000u32;
+        let w190 = w189 ^ w188;
+        let w191 = w184 ^ w190;
+        let w192 = w2 & 0x08000000u32;
+        let w193 = w189 & w188;
+        let w194 = w192 ^ w193;
+        let w195 = w194.rotate_left(1);
+        let w196 = w3 & 0x10000000u32;
+        let w197 = w196 ^ w195;
+        let w198 = w191 ^ w197;
+        let w199 = w2 & 0x10000000u32;
+        let w200 = w196 & w195;
+        let w201 = w199 ^ w200;
+        let w202 = w201.rotate_left(1);
+        let w203 = w3 & 0x20000000u32;
+        let w204 = w203 ^ w202;
+        let w205 = w198 ^ w204;
+        let w206 = w2 & 0x20000000u32;
+        let w207 = w203 & w202;
+        let w208 = w206 ^ w207;
+        let w209 = w208.rotate_left(1);
+        let w210 = w3 & 0x40000000u32;
+        let w211 = w210 ^ w209;
+        let w212 = w205 ^ w211;
+        let w213 = w2 & 0x40000000u32;
+        let w214 = w210 & w209;
+        let w215 = w213 ^ w214;
+        let w216 = w215.rotate_left(1);
+        let w217 = w3 & 0x80000000u32;
+        let w218 = w217 ^ w216;
+        let w219 = w212 ^ w218;
+        w219
+    }
+}
+
+pub mod or_rotl_mux_decoy {
+    pub const EXPR_DIGEST: [u8; 32] = [
+        0xe1, 0x43, 0xce, 0xb2, 0x2a, 0xa9, 0xa2, 0xae,
+        0x3a, 0x84, 0x4f, 0x61, 0x9b, 0x1d, 0xf2, 0x5f,
+        0x09, 0xfc, 0xb2, 0x56, 0xeb, 0x39, 0xf5, 0xe4,
+        0xc6, 0x2e, 0x19, 0xcf, 0xaf, 0xd9, 0x44, 0x40,
+    ];
+    pub fn or_rotl_mux_decoy(input_a: u32, input_b: u32) -> u32 {
+        let w0 = 0xffffffffu32;
+        let w1 = input_a;
+        let w2 = input_b;
+        let w3 = w1 ^ w2;
+        let w4 = w1 & w2;
+        let w5 = w3 ^ w4;
+        let w6 = 0x9e3779b9u32;
+        let w7 = w5 ^ w6;
+        let w8 = w7.rotate_left(5);
+        let w9 = w1 & w2;
+        let w10 = w8 ^ w9;
+        let w11 = w0 & w10;
+        let w12 = w9 ^ w11;
+        w12
+    }
+}
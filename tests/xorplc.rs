@@ -0,0 +1,139 @@
+//! End-to-end runs of the `xorplc` binary against a small `.xorpl` source.
+
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+
+use xorpl::dsl;
+use xorpl::expr::exprs_digest;
+
+const SOURCE: &str = "\
+input a, b, msg: bytes[6];
+let t = (a | b) ^ secret 0x9e3779b9;
+output t <<< 5, t + msg[1];
+";
+
+fn scratch(test: &str) -> PathBuf {
+    let dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join("xorplc").join(test);
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir).unwrap();
+    std::fs::write(dir.join("tag.xorpl"), SOURCE).unwrap();
+    dir
+}
+
+fn xorplc(dir: &Path, args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_xorplc"))
+        .current_dir(dir)
+        .args(args)
+        .output()
+        .expect("failed to run xorplc")
+}
+
+fn read(dir: &Path, file: &str) -> String {
+    std::fs::read_to_string(dir.join(file)).unwrap_or_else(|e| panic!("{file}: {e}"))
+}
+
+/// The `pub const POOL` body of an emitted browser file.
+fn pool(code: &str) -> &str {
+    let start = code.find("const POOL").expect("no POOL");
+    &code[start..start + code[start..].find("];").unwrap()]
+}
+
+fn digest_hex(key: Option<&[u8]>) -> String {
+    let exprs = dsl::parse(SOURCE).unwrap();
+    exprs_digest(&exprs, key).iter().map(|b| format!("{b:02x}")).collect()
+}
+
+#[test]
+fn compile_writes_all_artifacts() {
+    let dir = scratch("compile");
+    let out = xorplc(&dir, &["compile", "tag.xorpl", "--seed", "7", "--key", "00ff10", "--out", "build"]);
+    assert!(out.status.success(), "{}", String::from_utf8_lossy(&out.stderr));
+
+    let build = dir.join("build");
+    let browser  = read(&build, "tag.rs");
+    let verifier = read(&build, "tag_verify.rs");
+    let manifest = read(&build, "tag.manifest.json");
+
+    assert!(browser.contains("pub fn tag(a: u32, b: u32, msg: &[u8; 6]) -> [u32; 2] {"), "{browser}");
+    assert!(verifier.contains("pub fn tag_verify(input_a: u32, input_b: u32, input_msg: &[u8; 6]) -> [u32; 2] {"));
+    assert!(manifest.contains(&format!("\"expr_digest\": \"{}\"", digest_hex(Some(&[0x00, 0xff, 0x10])))), "{manifest}");
+    assert!(manifest.contains("\"digest_keyed\": true"));
+    assert!(manifest.contains(
+        "\"files\": {\"browser\": \"tag.rs\", \"circuit\": \"tag.circuit.json\", \"verifier\": \"tag_verify.rs\"}"
+    ));
+    assert!(xorpl::circuit::Circuit::from_json(&read(&build, "tag.circuit.json")).is_ok());
+    assert!(manifest.contains("{\"name\": \"msg\", \"param\": \"msg\", \"type\": \"bytes\", \"len\": 6}"));
+    assert!(manifest.contains("\"outputs\": 2"));
+}
+
+#[test]
+fn rotations_keep_digest_and_change_pool() {
+    let dir = scratch("rotate");
+    let run = |args: &[&str]| {
+        let out = xorplc(&dir, args);
+        assert!(out.status.success(), "{}", String::from_utf8_lossy(&out.stderr));
+        (read(&dir, "tag.rs"), read(&dir, "tag.manifest.json"))
+    };
+
+    let (base, _)          = run(&["compile", "tag.xorpl", "--seed", "1"]);
+    std::fs::rename(dir.join("tag.circuit.json"), dir.join("saved.circuit.json")).unwrap();
+    let (cheap, cheap_m)   = run(&["rotate-cheap", "tag.xorpl", "--base-seed", "1", "--seed", "2"]);
+    let (saved, _)         = run(&["rotate-cheap", "tag.xorpl", "--circuit", "saved.circuit.json", "--seed", "2"]);
+    let (strong, strong_m) = run(&["rotate-strong", "tag.xorpl", "--seed", "0x3"]);
+
+    let digest = format!("\"expr_digest\": \"{}\"", digest_hex(None));
+    assert!(cheap_m.contains(&digest) && strong_m.contains(&digest));
+    assert!(cheap_m.contains("\"files\": {\"browser\": \"tag.rs\"}"), "{cheap_m}");
+    assert_ne!(pool(&base), pool(&cheap));
+    assert_ne!(pool(&base), pool(&strong));
+    // A cheap rotation keeps the circuit: the pool has the same length.
+    assert_eq!(pool(&base).lines().count(), pool(&cheap).lines().count());
+    // Rotating the saved circuit is the same as recompiling it from the seed.
+    assert_eq!(saved, cheap);
+}
+
+#[test]
+fn rotate_cheap_rejects_circuit_of_other_source() {
+    let dir = scratch("rotate_other");
+    std::fs::write(dir.join("other.xorpl"), SOURCE.replace("(a | b)", "(a & b)")).unwrap();
+    let out = xorplc(&dir, &["compile", "other.xorpl", "--seed", "1", "--name", "tag"]);
+    assert!(out.status.success(), "{}", String::from_utf8_lossy(&out.stderr));
+
+    let out = xorplc(&dir, &["rotate-cheap", "tag.xorpl", "--circuit", "tag.circuit.json", "--seed", "2"]);
+    assert_eq!(out.status.code(), Some(1));
+    assert!(String::from_utf8_lossy(&out.stderr).contains("tag.circuit.json: circuit does not compute the source"));
+}
+
+#[test]
+fn verifier_and_inspect() {
+    let dir = scratch("verifier");
+    let out = xorplc(&dir, &["verifier", "tag.xorpl", "--name", "mac"]);
+    assert!(out.status.success(), "{}", String::from_utf8_lossy(&out.stderr));
+    assert!(read(&dir, "mac_verify.rs").contains("pub fn mac_verify("));
+    assert!(!dir.join("mac.rs").exists());
+
+    let out = xorplc(&dir, &["inspect", "tag.xorpl"]);
+    assert!(out.status.success());
+    let stdout = String::from_utf8(out.stdout).unwrap();
+    assert!(stdout.contains("\"command\": \"inspect\""));
+    assert!(stdout.contains("\"browser_circuit\": null"));
+    assert!(!dir.join("tag.manifest.json").exists());
+}
+
+#[test]
+fn errors_are_reported() {
+    let dir = scratch("errors");
+    std::fs::write(dir.join("bad.xorpl"), "input a;\noutput a ^ b;\n").unwrap();
+
+    let out = xorplc(&dir, &["compile", "bad.xorpl", "--seed", "1"]);
+    assert_eq!(out.status.code(), Some(1));
+    assert!(String::from_utf8_lossy(&out.stderr).contains("bad.xorpl:2:12: undefined name `b`"));
+
+    let out = xorplc(&dir, &["compile", "tag.xorpl", "--seed", "1", "--name", "type"]);
+    assert_eq!(out.status.code(), Some(1));
+    assert!(String::from_utf8_lossy(&out.stderr).contains("\"type\" is not a Rust identifier or is reserved by the emitter"));
+
+    let out = xorplc(&dir, &["compile", "tag.xorpl"]);
+    assert_eq!(out.status.code(), Some(2));
+    assert!(String::from_utf8_lossy(&out.stderr).contains("compile requires --seed"));
+}